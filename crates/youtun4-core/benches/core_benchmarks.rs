@@ -0,0 +1,96 @@
+//! Benchmarks for the core hot paths.
+//!
+//! Run with `cargo bench -p youtun4-core` and compare against a saved
+//! baseline (`--save-baseline main` / `--baseline main`) before a release.
+
+#![allow(clippy::unwrap_used, clippy::expect_used, missing_docs)]
+
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use tempfile::TempDir;
+use youtun4_core::integrity::IntegrityVerifier;
+use youtun4_core::transfer::{TransferEngine, TransferOptions, TransferProgress};
+use youtun4_core::youtube::{extract_playlist_id, sanitize_filename, validate_youtube_url};
+
+const PLAYLIST_URL: &str =
+    "https://www.youtube.com/playlist?list=PLrAXtmErZgOeiKm4sgNOknGvNjby9efdf";
+
+fn write_file(dir: &TempDir, name: &str, size: usize) -> PathBuf {
+    let path = dir.path().join(name);
+    let data: Vec<u8> = (0..size).map(|i| (i % 251) as u8).collect();
+    fs::write(&path, data).unwrap();
+    path
+}
+
+fn bench_checksum(c: &mut Criterion) {
+    let dir = TempDir::new().unwrap();
+    let verifier = IntegrityVerifier::new();
+    let mut group = c.benchmark_group("integrity_checksum");
+
+    for size in [1024 * 1024, 16 * 1024 * 1024] {
+        let path = write_file(&dir, &format!("{size}.mp3"), size);
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &path, |b, path| {
+            b.iter(|| verifier.compute_checksum(black_box(path)).unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_transfer(c: &mut Criterion) {
+    let source = TempDir::new().unwrap();
+    let files: Vec<PathBuf> = (0..8)
+        .map(|i| write_file(&source, &format!("track_{i}.mp3"), 4 * 1024 * 1024))
+        .collect();
+    let options = TransferOptions {
+        skip_existing: false,
+        ..TransferOptions::default()
+    };
+    let mut group = c.benchmark_group("transfer_engine");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(8 * 4 * 1024 * 1024));
+
+    group.bench_function("verified_copy", |b| {
+        b.iter(|| {
+            let destination = TempDir::new().unwrap();
+            let mut engine = TransferEngine::new();
+            engine
+                .transfer_files(
+                    &files,
+                    destination.path(),
+                    &options,
+                    None::<fn(&TransferProgress)>,
+                )
+                .unwrap()
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_url_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("youtube_urls");
+
+    group.bench_function("validate_youtube_url", |b| {
+        b.iter(|| validate_youtube_url(black_box(PLAYLIST_URL)));
+    });
+    group.bench_function("extract_playlist_id", |b| {
+        b.iter(|| extract_playlist_id(black_box(PLAYLIST_URL)).unwrap());
+    });
+    group.bench_function("sanitize_filename", |b| {
+        b.iter(|| {
+            sanitize_filename(black_box(
+                "AC/DC - Back In Black: Live? <2024> *Remastered*",
+            ))
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_checksum, bench_transfer, bench_url_parsing);
+criterion_main!(benches);
@@ -0,0 +1,464 @@
+//! Audio transcoding of downloaded streams to MP3.
+//!
+//! `YouTube` serves audio inside MP4/WebM containers that most cheap MP3
+//! players can't play. When `ffmpeg` is available, downloaded streams are
+//! converted to real MP3 files at the configured bitrate. `ffmpeg` reports
+//! its position on stdout (`-progress pipe:1`), which is turned into a
+//! 0.0 - 1.0 progress fraction when the track duration is known. Time
+//! ranges (e.g. `SponsorBlock` segments) can be cut out in the same pass,
+//! and parts of a converted MP3 (e.g. chapters) copied into their own files.
+
+use std::ffi::OsString;
+use std::io::{BufRead, BufReader, Read};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use tracing::{debug, info};
+
+use crate::config::DownloadQuality;
+use crate::error::{DownloadError, Error, Result};
+
+/// Name of the `ffmpeg` executable.
+#[cfg(windows)]
+const FFMPEG_BINARY: &str = "ffmpeg.exe";
+/// Name of the `ffmpeg` executable.
+#[cfg(not(windows))]
+const FFMPEG_BINARY: &str = "ffmpeg";
+
+/// Options for converting downloaded streams to MP3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscodeOptions {
+    /// MP3 bitrate in kbps.
+    pub bitrate_kbps: u32,
+    /// Path to the `ffmpeg` executable. If None, searches PATH.
+    pub ffmpeg_path: Option<PathBuf>,
+}
+
+impl Default for TranscodeOptions {
+    fn default() -> Self {
+        Self {
+            bitrate_kbps: DownloadQuality::default().bitrate_kbps(),
+            ffmpeg_path: None,
+        }
+    }
+}
+
+impl TranscodeOptions {
+    /// Options converting at `bitrate_kbps`, with `ffmpeg` from PATH.
+    #[must_use]
+    pub fn with_bitrate(bitrate_kbps: u32) -> Self {
+        Self {
+            bitrate_kbps,
+            ..Self::default()
+        }
+    }
+}
+
+/// Converts audio files to MP3 with `ffmpeg`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcoder {
+    ffmpeg: PathBuf,
+    bitrate_kbps: u32,
+    cuts: Vec<Range<Duration>>,
+}
+
+impl Transcoder {
+    /// Find `ffmpeg` for `options`, or `None` if it isn't installed.
+    #[must_use]
+    pub fn locate(options: &TranscodeOptions) -> Option<Self> {
+        let ffmpeg = match &options.ffmpeg_path {
+            Some(path) => path.is_file().then(|| path.clone()),
+            None => find_in_path(FFMPEG_BINARY),
+        }?;
+        debug!("Using ffmpeg at {}", ffmpeg.display());
+        Some(Self {
+            ffmpeg,
+            bitrate_kbps: options.bitrate_kbps,
+            cuts: Vec::new(),
+        })
+    }
+
+    /// A transcoder that also removes the time ranges `cuts` (sorted and
+    /// non-overlapping) from the audio.
+    #[must_use]
+    pub fn with_cuts(&self, cuts: Vec<Range<Duration>>) -> Self {
+        Self {
+            cuts,
+            ..self.clone()
+        }
+    }
+
+    /// MP3 bitrate in kbps.
+    #[must_use]
+    pub const fn bitrate_kbps(&self) -> u32 {
+        self.bitrate_kbps
+    }
+
+    /// Convert the audio track of `input` to an MP3 file at `output`.
+    ///
+    /// `on_progress` receives the fraction converted so far; it is only
+    /// called with intermediate values when `duration_secs` is known.
+    /// Once `is_cancelled` returns true, `ffmpeg` is stopped and
+    /// [`DownloadError::Cancelled`] is returned. `output` may be partially
+    /// written on failure and should be a temp file.
+    pub fn transcode(
+        &self,
+        input: &Path,
+        output: &Path,
+        title: &str,
+        duration_secs: Option<u64>,
+        is_cancelled: impl Fn() -> bool,
+        mut on_progress: impl FnMut(f64),
+    ) -> Result<()> {
+        info!(
+            "Converting '{}' to MP3 at {} kbps",
+            title, self.bitrate_kbps
+        );
+
+        let mut child = Command::new(&self.ffmpeg)
+            .args(ffmpeg_args(input, output, self.bitrate_kbps, &self.cuts))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| conversion_failed(title, format!("failed to start ffmpeg: {e}")))?;
+
+        // Drain stderr on its own thread so a chatty ffmpeg can't block on a full pipe
+        let stderr = child.stderr.take();
+        let stderr_reader = std::thread::spawn(move || {
+            let mut text = String::new();
+            if let Some(mut stderr) = stderr {
+                let _ = stderr.read_to_string(&mut text);
+            }
+            text
+        });
+
+        // ffmpeg reports its position in the output, which is shorter by the cuts
+        let cut_secs: f64 = self
+            .cuts
+            .iter()
+            .map(|cut| cut.end.saturating_sub(cut.start).as_secs_f64())
+            .sum();
+        let total_secs = duration_secs
+            .map(|secs| secs as f64 - cut_secs)
+            .filter(|secs| *secs > 0.0);
+        if let Some(stdout) = child.stdout.take() {
+            for line in BufReader::new(stdout)
+                .lines()
+                .map_while(std::result::Result::ok)
+            {
+                if is_cancelled() {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(Error::Download(DownloadError::Cancelled));
+                }
+                if let (Some(position), Some(total)) = (parse_progress_line(&line), total_secs) {
+                    on_progress((position.as_secs_f64() / total).min(1.0));
+                }
+            }
+        }
+
+        let status = child
+            .wait()
+            .map_err(|e| conversion_failed(title, format!("failed to wait for ffmpeg: {e}")))?;
+        let stderr = stderr_reader.join().unwrap_or_default();
+        if is_cancelled() {
+            return Err(Error::Download(DownloadError::Cancelled));
+        }
+        if !status.success() {
+            let reason = stderr
+                .lines()
+                .rev()
+                .find(|line| !line.trim().is_empty())
+                .map_or_else(|| format!("ffmpeg exited with {status}"), str::to_string);
+            return Err(conversion_failed(title, reason));
+        }
+
+        on_progress(1.0);
+        debug!("Converted '{}' to {}", title, output.display());
+        Ok(())
+    }
+
+    /// Copy the part of the MP3 `input` from `start` to `end` (or to the
+    /// end of the file) into `output`, without re-encoding.
+    ///
+    /// `output` may be partially written on failure and should be a temp
+    /// file.
+    pub fn extract(
+        &self,
+        input: &Path,
+        output: &Path,
+        start: Duration,
+        end: Option<Duration>,
+        title: &str,
+    ) -> Result<()> {
+        let result = Command::new(&self.ffmpeg)
+            .args(extract_args(input, output, start, end))
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| conversion_failed(title, format!("failed to start ffmpeg: {e}")))?;
+        if !result.status.success() {
+            let stderr = String::from_utf8_lossy(&result.stderr);
+            let reason = stderr
+                .lines()
+                .rev()
+                .find(|line| !line.trim().is_empty())
+                .map_or_else(
+                    || format!("ffmpeg exited with {}", result.status),
+                    str::to_string,
+                );
+            return Err(conversion_failed(title, reason));
+        }
+        debug!("Extracted '{}' to {}", title, output.display());
+        Ok(())
+    }
+}
+
+/// Arguments copying the audio of `input` from `start` to `end` into the
+/// MP3 `output`.
+fn extract_args(
+    input: &Path,
+    output: &Path,
+    start: Duration,
+    end: Option<Duration>,
+) -> Vec<OsString> {
+    let mut args: Vec<OsString> = [
+        "-hide_banner".to_string(),
+        "-nostdin".to_string(),
+        "-loglevel".to_string(),
+        "error".to_string(),
+        "-y".to_string(),
+        "-ss".to_string(),
+        format!("{:.3}", start.as_secs_f64()),
+        "-i".to_string(),
+    ]
+    .into_iter()
+    .map(OsString::from)
+    .collect();
+    args.push(input.as_os_str().to_owned());
+    if let Some(end) = end {
+        args.push(OsString::from("-t"));
+        args.push(OsString::from(format!(
+            "{:.3}",
+            end.saturating_sub(start).as_secs_f64()
+        )));
+    }
+    args.extend(
+        ["-map", "0:a", "-codec:a", "copy", "-f", "mp3"]
+            .into_iter()
+            .map(OsString::from),
+    );
+    args.push(output.as_os_str().to_owned());
+    args
+}
+
+/// Arguments converting `input` to a CBR MP3 at `output` without the time
+/// ranges `cuts`, reporting progress on stdout.
+fn ffmpeg_args(
+    input: &Path,
+    output: &Path,
+    bitrate_kbps: u32,
+    cuts: &[Range<Duration>],
+) -> Vec<OsString> {
+    let mut args: Vec<OsString> = ["-hide_banner", "-nostdin", "-loglevel", "error", "-y", "-i"]
+        .into_iter()
+        .map(OsString::from)
+        .collect();
+    args.push(input.as_os_str().to_owned());
+    if let Some(filter) = cut_filter(cuts) {
+        args.push(OsString::from("-af"));
+        args.push(OsString::from(filter));
+    }
+    args.extend(
+        [
+            "-vn".to_string(),
+            "-codec:a".to_string(),
+            "libmp3lame".to_string(),
+            "-b:a".to_string(),
+            format!("{bitrate_kbps}k"),
+            "-progress".to_string(),
+            "pipe:1".to_string(),
+            "-nostats".to_string(),
+            "-f".to_string(),
+            "mp3".to_string(),
+        ]
+        .into_iter()
+        .map(OsString::from),
+    );
+    args.push(output.as_os_str().to_owned());
+    args
+}
+
+/// Audio filter dropping the samples inside `cuts` and closing the gaps.
+fn cut_filter(cuts: &[Range<Duration>]) -> Option<String> {
+    if cuts.is_empty() {
+        return None;
+    }
+    let ranges = cuts
+        .iter()
+        .map(|cut| {
+            format!(
+                "between(t,{:.3},{:.3})",
+                cut.start.as_secs_f64(),
+                cut.end.as_secs_f64()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("+");
+    Some(format!("aselect='not({ranges})',asetpts=N/SR/TB"))
+}
+
+/// Position reported by an `ffmpeg -progress` line, if it is one.
+///
+/// Both `out_time_us` and `out_time_ms` are in microseconds (the latter is
+/// misnamed in ffmpeg).
+fn parse_progress_line(line: &str) -> Option<Duration> {
+    let (key, value) = line.trim().split_once('=')?;
+    match key {
+        "out_time_us" | "out_time_ms" => value.parse().ok().map(Duration::from_micros),
+        _ => None,
+    }
+}
+
+/// First executable named `binary` in PATH.
+fn find_in_path(binary: &str) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path)
+        .map(|dir| dir.join(binary))
+        .find(|candidate| candidate.is_file())
+}
+
+fn conversion_failed(title: &str, reason: impl Into<String>) -> Error {
+    Error::Download(DownloadError::ConversionFailed {
+        title: title.to_string(),
+        reason: reason.into(),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_progress_line() {
+        assert_eq!(
+            parse_progress_line("out_time_us=12500000"),
+            Some(Duration::from_millis(12_500))
+        );
+        assert_eq!(
+            parse_progress_line("out_time_ms=1000000\n"),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(parse_progress_line("out_time_us=N/A"), None);
+        assert_eq!(parse_progress_line("progress=continue"), None);
+        assert_eq!(parse_progress_line("garbage"), None);
+    }
+
+    #[test]
+    fn test_ffmpeg_args() {
+        let args = ffmpeg_args(Path::new("in.mp4"), Path::new("out.mp3"), 320, &[]);
+        let args: Vec<String> = args
+            .iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+
+        let input = args.iter().position(|arg| arg == "-i").unwrap();
+        assert_eq!(args[input + 1], "in.mp4");
+        let bitrate = args.iter().position(|arg| arg == "-b:a").unwrap();
+        assert_eq!(args[bitrate + 1], "320k");
+        assert!(args.contains(&"-vn".to_string()));
+        assert!(!args.contains(&"-af".to_string()));
+        assert_eq!(args.last().unwrap(), "out.mp3");
+    }
+
+    #[test]
+    fn test_cut_filter() {
+        assert_eq!(cut_filter(&[]), None);
+        let cuts = [
+            Duration::ZERO..Duration::from_millis(5_250),
+            Duration::from_secs(30)..Duration::from_secs(45),
+        ];
+        assert_eq!(
+            cut_filter(&cuts).unwrap(),
+            "aselect='not(between(t,0.000,5.250)+between(t,30.000,45.000))',asetpts=N/SR/TB"
+        );
+
+        let args = ffmpeg_args(Path::new("in.mp4"), Path::new("out.mp3"), 192, &cuts);
+        let filter = args.iter().position(|arg| arg == "-af").unwrap();
+        assert!(args[filter + 1].to_string_lossy().starts_with("aselect="));
+    }
+
+    #[test]
+    fn test_extract_args() {
+        let args = extract_args(
+            Path::new("album.mp3"),
+            Path::new("track.mp3"),
+            Duration::from_millis(90_500),
+            Some(Duration::from_secs(180)),
+        );
+        let args: Vec<String> = args
+            .iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+
+        let seek = args.iter().position(|arg| arg == "-ss").unwrap();
+        assert_eq!(args[seek + 1], "90.500");
+        let input = args.iter().position(|arg| arg == "-i").unwrap();
+        assert!(seek < input);
+        assert_eq!(args[input + 1], "album.mp3");
+        let length = args.iter().position(|arg| arg == "-t").unwrap();
+        assert_eq!(args[length + 1], "89.500");
+        assert!(args.contains(&"copy".to_string()));
+        assert_eq!(args.last().unwrap(), "track.mp3");
+
+        let to_end = extract_args(
+            Path::new("album.mp3"),
+            Path::new("track.mp3"),
+            Duration::ZERO,
+            None,
+        );
+        assert!(!to_end.contains(&OsString::from("-t")));
+    }
+
+    #[test]
+    fn test_locate_with_explicit_path() {
+        let temp = TempDir::new().unwrap();
+        let missing = TranscodeOptions {
+            bitrate_kbps: 128,
+            ffmpeg_path: Some(temp.path().join("ffmpeg")),
+        };
+        assert_eq!(Transcoder::locate(&missing), None);
+
+        std::fs::write(temp.path().join("ffmpeg"), b"").unwrap();
+        let transcoder = Transcoder::locate(&missing).unwrap();
+        assert_eq!(transcoder.bitrate_kbps(), 128);
+    }
+
+    #[test]
+    fn test_failed_start_is_conversion_error() {
+        let temp = TempDir::new().unwrap();
+        let transcoder = Transcoder {
+            ffmpeg: temp.path().join("no-such-ffmpeg"),
+            bitrate_kbps: 192,
+            cuts: Vec::new(),
+        };
+        let err = transcoder
+            .transcode(
+                &temp.path().join("in.mp4"),
+                &temp.path().join("out.mp3"),
+                "Song",
+                Some(180),
+                || false,
+                |_| {},
+            )
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Download(DownloadError::ConversionFailed { .. })
+        ));
+    }
+}
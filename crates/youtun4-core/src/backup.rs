@@ -0,0 +1,530 @@
+//! Backup and restore of the library.
+//!
+//! A backup is a zip archive holding the app config (including device
+//! profiles) and every file of every playlist folder: `playlist.json` with
+//! the saved track metadata, checksum manifests and covers. Audio files are
+//! only included when asked for, since they can be downloaded again.
+//!
+//! The archive contains a [`BackupManifest`] listing each file with its size
+//! and SHA-256 checksum. A restore verifies the whole archive against the
+//! manifest before writing anything, so a damaged backup never leaves the
+//! library half restored.
+
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::{debug, info};
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::config::{AppConfig, migrate_config};
+use crate::error::{Error, FileSystemError, Result, TransferError};
+use crate::integrity::compute_file_checksum;
+use crate::playlist::is_audio_file;
+
+/// Current backup format version.
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// Name of the manifest inside the archive.
+pub const BACKUP_MANIFEST_FILE: &str = "backup.json";
+
+/// Name of the config file inside the archive.
+const CONFIG_ENTRY: &str = "config.json";
+
+/// Prefix of playlist files inside the archive.
+const PLAYLISTS_PREFIX: &str = "playlists/";
+
+/// What to include in a backup.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupOptions {
+    /// Include audio files, not just metadata.
+    pub include_audio: bool,
+}
+
+/// A file in a backup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupFile {
+    /// Path inside the archive, with `/` separators.
+    pub path: String,
+    /// Size in bytes.
+    pub size: u64,
+    /// SHA-256 checksum, hex encoded.
+    pub sha256: String,
+}
+
+/// Contents of a backup, stored in the archive as [`BACKUP_MANIFEST_FILE`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    /// Backup format version.
+    pub format_version: u32,
+    /// When the backup was made (seconds since the Unix epoch).
+    pub created_at: u64,
+    /// Whether audio files are included.
+    pub includes_audio: bool,
+    /// Backed up playlists, sorted.
+    pub playlists: Vec<String>,
+    /// Every file in the backup except the manifest.
+    pub files: Vec<BackupFile>,
+}
+
+impl BackupManifest {
+    /// Total size of the backed up files in bytes.
+    #[must_use]
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|file| file.size).sum()
+    }
+}
+
+/// Result of restoring a backup.
+#[derive(Debug, Clone)]
+pub struct RestoredLibrary {
+    /// Manifest of the restored backup.
+    pub manifest: BackupManifest,
+    /// Config stored in the backup, migrated to the current version.
+    ///
+    /// It is not applied: the caller decides what to keep from it, such as
+    /// the current playlists directory.
+    pub config: AppConfig,
+    /// Number of playlist files written.
+    pub files_restored: usize,
+}
+
+/// Back up `config` and the playlists in its playlists directory to a zip
+/// archive at `destination`, replacing any file there.
+///
+/// Hidden folders in the playlists directory are skipped.
+pub fn backup_library(
+    config: &AppConfig,
+    destination: &Path,
+    options: &BackupOptions,
+) -> Result<BackupManifest> {
+    let playlists_dir = &config.playlists_directory;
+    let files = playlist_files(playlists_dir, options.include_audio)?;
+    let mut playlists: Vec<String> = files
+        .iter()
+        .filter_map(|(name, _)| name.strip_prefix(PLAYLISTS_PREFIX)?.split('/').next())
+        .map(str::to_string)
+        .collect();
+    playlists.dedup();
+
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = destination.with_extension("zip.tmp");
+    let mut zip = ZipWriter::new(File::create(&tmp).map_err(|e| write_failed(&tmp, e))?);
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    // Audio is already compressed
+    let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+
+    let mut manifest = BackupManifest {
+        format_version: BACKUP_FORMAT_VERSION,
+        created_at: unix_now(),
+        includes_audio: options.include_audio,
+        playlists,
+        files: Vec::with_capacity(files.len() + 1),
+    };
+
+    let config_json = serde_json::to_vec_pretty(config)?;
+    zip.start_file(CONFIG_ENTRY, deflated)
+        .map_err(|e| write_failed(&tmp, e))?;
+    zip.write_all(&config_json)
+        .map_err(|e| write_failed(&tmp, e))?;
+    manifest.files.push(BackupFile {
+        path: CONFIG_ENTRY.to_string(),
+        size: config_json.len() as u64,
+        sha256: format!("{:x}", Sha256::digest(&config_json)),
+    });
+
+    for (name, path) in files {
+        let sha256 = compute_file_checksum(&path)?;
+        let entry_options = if is_audio_file(&path) {
+            stored
+        } else {
+            deflated
+        };
+        zip.start_file(name.as_str(), entry_options)
+            .map_err(|e| write_failed(&tmp, e))?;
+        let mut source = File::open(&path).map_err(|e| read_failed(&path, e))?;
+        let size = io::copy(&mut source, &mut zip).map_err(|e| write_failed(&tmp, e))?;
+        manifest.files.push(BackupFile {
+            path: name,
+            size,
+            sha256,
+        });
+    }
+
+    zip.start_file(BACKUP_MANIFEST_FILE, deflated)
+        .map_err(|e| write_failed(&tmp, e))?;
+    zip.write_all(&serde_json::to_vec_pretty(&manifest)?)
+        .map_err(|e| write_failed(&tmp, e))?;
+    zip.finish().map_err(|e| write_failed(&tmp, e))?;
+    fs::rename(&tmp, destination).map_err(|e| write_failed(destination, e))?;
+
+    info!(
+        "Backed up {} playlist(s), {} files to {}",
+        manifest.playlists.len(),
+        manifest.files.len(),
+        destination.display()
+    );
+    Ok(manifest)
+}
+
+/// Check that every file listed in the manifest of the backup at `archive`
+/// is present and intact.
+pub fn verify_backup(archive: &Path) -> Result<BackupManifest> {
+    let (mut zip, manifest) = open_backup(archive)?;
+    if !manifest.files.iter().any(|file| file.path == CONFIG_ENTRY) {
+        return Err(read_failed(archive, "the backup has no config"));
+    }
+
+    for file in &manifest.files {
+        if file.path != CONFIG_ENTRY && playlist_relative_path(&file.path).is_none() {
+            return Err(read_failed(
+                archive,
+                format!("unexpected file in backup: {}", file.path),
+            ));
+        }
+        let entry = zip
+            .by_name(&file.path)
+            .map_err(|e| read_failed(archive, format!("{}: {e}", file.path)))?;
+        let (size, sha256) = hash_reader(entry).map_err(|e| read_failed(archive, e))?;
+        if size != file.size || sha256 != file.sha256 {
+            return Err(Error::Transfer(TransferError::IntegrityCheckFailed {
+                file: PathBuf::from(&file.path),
+                expected: file.sha256.clone(),
+                actual: sha256,
+            }));
+        }
+    }
+    debug!(
+        "Verified {} files in {}",
+        manifest.files.len(),
+        archive.display()
+    );
+    Ok(manifest)
+}
+
+/// Restore the backup at `archive` into `playlists_dir`.
+///
+/// The archive is verified first; nothing is written if any file is
+/// missing or damaged. Restored files replace files at the same path, and
+/// nothing else in the library is touched.
+pub fn restore_library(archive: &Path, playlists_dir: &Path) -> Result<RestoredLibrary> {
+    let manifest = verify_backup(archive)?;
+    let (mut zip, _) = open_backup(archive)?;
+
+    let mut value: serde_json::Value = {
+        let entry = zip
+            .by_name(CONFIG_ENTRY)
+            .map_err(|e| read_failed(archive, e))?;
+        serde_json::from_reader(entry)?
+    };
+    migrate_config(&mut value);
+    let config: AppConfig = serde_json::from_value(value)
+        .map_err(|e| Error::Configuration(format!("Invalid config in backup: {e}")))?;
+
+    let mut files_restored = 0;
+    for file in &manifest.files {
+        let Some(relative) = playlist_relative_path(&file.path) else {
+            continue;
+        };
+        let target = playlists_dir.join(relative);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                Error::FileSystem(FileSystemError::CreateDirFailed {
+                    path: parent.to_path_buf(),
+                    reason: e.to_string(),
+                })
+            })?;
+        }
+        let mut entry = zip
+            .by_name(&file.path)
+            .map_err(|e| read_failed(archive, e))?;
+        let mut output = File::create(&target).map_err(|e| write_failed(&target, e))?;
+        io::copy(&mut entry, &mut output).map_err(|e| write_failed(&target, e))?;
+        files_restored += 1;
+    }
+
+    info!(
+        "Restored {} playlist(s), {} files from {}",
+        manifest.playlists.len(),
+        files_restored,
+        archive.display()
+    );
+    Ok(RestoredLibrary {
+        manifest,
+        config,
+        files_restored,
+    })
+}
+
+/// Open the backup at `archive` and read its manifest.
+fn open_backup(archive: &Path) -> Result<(ZipArchive<File>, BackupManifest)> {
+    let file = File::open(archive).map_err(|e| read_failed(archive, e))?;
+    let mut zip = ZipArchive::new(file).map_err(|e| read_failed(archive, e))?;
+    let manifest: BackupManifest = {
+        let entry = zip
+            .by_name(BACKUP_MANIFEST_FILE)
+            .map_err(|_| read_failed(archive, "not a Youtun4 backup"))?;
+        serde_json::from_reader(entry)
+            .map_err(|e| read_failed(archive, format!("invalid backup manifest: {e}")))?
+    };
+    if manifest.format_version > BACKUP_FORMAT_VERSION {
+        return Err(read_failed(
+            archive,
+            format!(
+                "backup format version {} is newer than supported version {}",
+                manifest.format_version, BACKUP_FORMAT_VERSION
+            ),
+        ));
+    }
+    Ok((zip, manifest))
+}
+
+/// Files of the playlist folders in `playlists_dir` with their archive
+/// names, sorted.
+fn playlist_files(playlists_dir: &Path, include_audio: bool) -> Result<Vec<(String, PathBuf)>> {
+    let mut files = Vec::new();
+    let walker = WalkDir::new(playlists_dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| entry.depth() != 1 || !is_hidden(entry.path()));
+    for entry in walker {
+        let entry =
+            entry.map_err(|e| read_failed(e.path().unwrap_or(playlists_dir), e.to_string()))?;
+        // Files directly in the playlists directory belong to no playlist
+        if entry.depth() < 2
+            || !entry.file_type().is_file()
+            || (!include_audio && is_audio_file(entry.path()))
+        {
+            continue;
+        }
+        let Ok(relative) = entry.path().strip_prefix(playlists_dir) else {
+            continue;
+        };
+        let name = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        files.push((format!("{PLAYLISTS_PREFIX}{name}"), entry.into_path()));
+    }
+    Ok(files)
+}
+
+/// Path inside the playlists directory of the archive entry `name`, or
+/// `None` if it is not a playlist file or would escape the directory.
+fn playlist_relative_path(name: &str) -> Option<PathBuf> {
+    let relative = Path::new(name.strip_prefix(PLAYLISTS_PREFIX)?);
+    (relative.components().count() >= 2
+        && relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_))))
+    .then(|| relative.to_path_buf())
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+}
+
+/// Size and SHA-256 checksum of everything read from `reader`.
+fn hash_reader(mut reader: impl Read) -> io::Result<(u64, String)> {
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    let mut size = 0;
+    loop {
+        let read = reader.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        size += read as u64;
+    }
+    Ok((size, format!("{:x}", hasher.finalize())))
+}
+
+fn read_failed(path: &Path, reason: impl ToString) -> Error {
+    Error::FileSystem(FileSystemError::ReadFailed {
+        path: path.to_path_buf(),
+        reason: reason.to_string(),
+    })
+}
+
+fn write_failed(path: &Path, reason: impl ToString) -> Error {
+    Error::FileSystem(FileSystemError::WriteFailed {
+        path: path.to_path_buf(),
+        reason: reason.to_string(),
+    })
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::config::DeviceProfile;
+    use tempfile::TempDir;
+
+    /// A library with two playlists and a hidden folder.
+    fn library(temp: &TempDir) -> AppConfig {
+        let playlists_dir = temp.path().join("library");
+        fs::create_dir_all(playlists_dir.join("Mix/covers")).unwrap();
+        fs::create_dir_all(playlists_dir.join("Chill")).unwrap();
+        fs::create_dir_all(playlists_dir.join(".trash")).unwrap();
+        fs::write(
+            playlists_dir.join("Mix/playlist.json"),
+            r#"{"title":"Mix"}"#,
+        )
+        .unwrap();
+        fs::write(playlists_dir.join("Mix/checksums.json"), "{}").unwrap();
+        fs::write(playlists_dir.join("Mix/covers/front.jpg"), "jpg").unwrap();
+        fs::write(playlists_dir.join("Mix/song.mp3"), "audio").unwrap();
+        fs::write(playlists_dir.join("Chill/playlist.json"), "{}").unwrap();
+        fs::write(playlists_dir.join(".trash/old.mp3"), "audio").unwrap();
+
+        let mut config = AppConfig {
+            playlists_directory: playlists_dir,
+            ..AppConfig::default()
+        };
+        config
+            .device_profiles
+            .insert("/media/PLAYER".to_string(), DeviceProfile::default());
+        config
+    }
+
+    fn names(manifest: &BackupManifest) -> Vec<&str> {
+        manifest.files.iter().map(|f| f.path.as_str()).collect()
+    }
+
+    #[test]
+    fn test_backup_without_audio() {
+        let temp = TempDir::new().unwrap();
+        let config = library(&temp);
+        let archive = temp.path().join("backup.zip");
+
+        let manifest = backup_library(&config, &archive, &BackupOptions::default()).unwrap();
+        assert_eq!(manifest.playlists, vec!["Chill", "Mix"]);
+        assert!(!manifest.includes_audio);
+        assert_eq!(
+            names(&manifest),
+            vec![
+                "config.json",
+                "playlists/Chill/playlist.json",
+                "playlists/Mix/checksums.json",
+                "playlists/Mix/covers/front.jpg",
+                "playlists/Mix/playlist.json",
+            ]
+        );
+        assert_eq!(verify_backup(&archive).unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_backup_and_restore_with_audio() {
+        let temp = TempDir::new().unwrap();
+        let config = library(&temp);
+        let archive = temp.path().join("backup.zip");
+        let manifest = backup_library(
+            &config,
+            &archive,
+            &BackupOptions {
+                include_audio: true,
+            },
+        )
+        .unwrap();
+        assert!(names(&manifest).contains(&"playlists/Mix/song.mp3"));
+
+        let target = temp.path().join("restored");
+        fs::create_dir_all(target.join("Other")).unwrap();
+        fs::write(target.join("Other/playlist.json"), "other").unwrap();
+
+        let restored = restore_library(&archive, &target).unwrap();
+        assert_eq!(restored.files_restored, 5);
+        assert_eq!(restored.config.device_profiles.len(), 1);
+        assert_eq!(
+            fs::read_to_string(target.join("Mix/covers/front.jpg")).unwrap(),
+            "jpg"
+        );
+        assert_eq!(
+            fs::read_to_string(target.join("Mix/song.mp3")).unwrap(),
+            "audio"
+        );
+        // Playlists not in the backup are left alone
+        assert_eq!(
+            fs::read_to_string(target.join("Other/playlist.json")).unwrap(),
+            "other"
+        );
+    }
+
+    #[test]
+    fn test_restore_rejects_damaged_backup() {
+        let temp = TempDir::new().unwrap();
+        let config = library(&temp);
+        let archive = temp.path().join("backup.zip");
+        let mut manifest = backup_library(&config, &archive, &BackupOptions::default()).unwrap();
+
+        // Rewrite the archive with a manifest that doesn't match a file
+        manifest.files[1].sha256 = "0".repeat(64);
+        let damaged = temp.path().join("damaged.zip");
+        {
+            let mut source = ZipArchive::new(File::open(&archive).unwrap()).unwrap();
+            let mut zip = ZipWriter::new(File::create(&damaged).unwrap());
+            for i in 0..source.len() {
+                let entry = source.by_index(i).unwrap();
+                if entry.name() != BACKUP_MANIFEST_FILE {
+                    zip.raw_copy_file(entry).unwrap();
+                }
+            }
+            zip.start_file(BACKUP_MANIFEST_FILE, SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(&serde_json::to_vec(&manifest).unwrap())
+                .unwrap();
+            zip.finish().unwrap();
+        }
+
+        let target = temp.path().join("restored");
+        let err = restore_library(&damaged, &target).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Transfer(TransferError::IntegrityCheckFailed { .. })
+        ));
+        // Nothing was written
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn test_restore_rejects_non_backup() {
+        let temp = TempDir::new().unwrap();
+        let archive = temp.path().join("other.zip");
+        let mut zip = ZipWriter::new(File::create(&archive).unwrap());
+        zip.start_file("readme.txt", SimpleFileOptions::default())
+            .unwrap();
+        zip.finish().unwrap();
+
+        assert!(restore_library(&archive, temp.path()).is_err());
+        assert!(verify_backup(&temp.path().join("missing.zip")).is_err());
+    }
+
+    #[test]
+    fn test_playlist_relative_path() {
+        assert_eq!(
+            playlist_relative_path("playlists/Mix/song.mp3"),
+            Some(PathBuf::from("Mix/song.mp3"))
+        );
+        assert_eq!(playlist_relative_path("playlists/song.mp3"), None);
+        assert_eq!(playlist_relative_path("playlists/../etc/passwd"), None);
+        assert_eq!(playlist_relative_path("playlists//abs"), None);
+        assert_eq!(playlist_relative_path("config.json"), None);
+    }
+}
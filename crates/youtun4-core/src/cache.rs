@@ -3,6 +3,8 @@
 //! This module provides a comprehensive caching system with:
 //! - Metadata caching for extracted MP3 ID3 tags
 //! - Thumbnail caching for `YouTube` video thumbnails
+//! - Playlist and video info caching for offline access to `YouTube` playlists
+//! - Cache warming to pre-fetch info and thumbnails for a whole playlist
 //! - Configurable cache size limits and TTL (time-to-live)
 //! - Automatic cache cleanup policies
 //!
@@ -29,13 +31,17 @@
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info, warn};
 
 use crate::error::{CacheError, Error, FileSystemError, Result};
 use crate::metadata::Mp3Metadata;
+use crate::thumbnail::{ThumbnailManager, youtube_thumbnail_url};
+use crate::youtube::{PlaylistInfo, VideoInfo};
 
 /// Default maximum cache size in bytes (100 MB).
 pub const DEFAULT_MAX_CACHE_SIZE: u64 = 100 * 1024 * 1024;
@@ -159,6 +165,20 @@ impl CacheConfig {
         self
     }
 
+    /// Get the resolved cache directory.
+    #[must_use]
+    pub fn cache_directory(&self) -> PathBuf {
+        self.custom_cache_dir
+            .clone()
+            .unwrap_or_else(default_cache_directory)
+    }
+
+    /// Get the directory used for intermediate files.
+    #[must_use]
+    pub fn temp_directory(&self) -> PathBuf {
+        self.cache_directory().join(TEMP_CACHE_DIR)
+    }
+
     /// Disable caching entirely.
     #[must_use]
     pub fn disabled() -> Self {
@@ -268,6 +288,54 @@ pub struct CacheCleanupStats {
     pub duration_ms: u64,
 }
 
+/// Statistics from a cache warming operation.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct CacheWarmStats {
+    /// Number of video info entries newly cached.
+    pub videos_cached: usize,
+    /// Number of video info entries that were already cached.
+    pub videos_skipped: usize,
+    /// Number of thumbnails newly cached.
+    pub thumbnails_cached: usize,
+    /// Number of thumbnails that were already cached.
+    pub thumbnails_skipped: usize,
+    /// Number of thumbnails that could not be fetched.
+    pub thumbnails_failed: usize,
+    /// Whether warming was cancelled before finishing.
+    pub cancelled: bool,
+}
+
+/// Report from a cache integrity check.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct CacheVerifyReport {
+    /// Number of manifest entries checked.
+    pub entries_checked: usize,
+    /// Keys of manifest entries whose file is missing on disk.
+    pub missing_files: Vec<String>,
+    /// Keys of manifest entries whose recorded size differs from the file size.
+    pub size_mismatches: Vec<String>,
+    /// Cache files on disk that are not tracked by the manifest (relative paths).
+    pub orphaned_files: Vec<PathBuf>,
+    /// Whether the recorded total size differed from the sum of entry sizes.
+    pub total_size_mismatch: bool,
+    /// Whether the on-disk manifest was unreadable when the cache was loaded.
+    pub manifest_corrupted: bool,
+    /// Whether the manifest was repaired and saved.
+    pub repaired: bool,
+}
+
+impl CacheVerifyReport {
+    /// Check if the cache is consistent (no issues found).
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.missing_files.is_empty()
+            && self.size_mismatches.is_empty()
+            && self.orphaned_files.is_empty()
+            && !self.total_size_mismatch
+            && !self.manifest_corrupted
+    }
+}
+
 /// Statistics about the current cache state.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheStats {
@@ -289,6 +357,114 @@ pub struct CacheStats {
     pub enabled: bool,
 }
 
+/// Counter used to keep temp file names unique within a process.
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// An intermediate file living in the cache temp area.
+///
+/// The file is deleted when the guard is dropped unless it has been moved to
+/// its final location with [`TempFile::persist`], so failed or cancelled
+/// operations never leave partial files behind. Files left over by a crash
+/// are removed by [`CacheManager::cleanup_temp`] on the next start.
+#[derive(Debug)]
+pub struct TempFile {
+    path: PathBuf,
+    persisted: bool,
+}
+
+impl TempFile {
+    /// Create a new empty temp file in the given directory.
+    ///
+    /// The directory is created if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or the file cannot be created.
+    pub fn create_in(dir: &Path, prefix: &str, extension: &str) -> Result<Self> {
+        fs::create_dir_all(dir).map_err(|e| {
+            Error::FileSystem(FileSystemError::CreateDirFailed {
+                path: dir.to_path_buf(),
+                reason: e.to_string(),
+            })
+        })?;
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+        let counter = TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = dir.join(format!(
+            "{prefix}_{timestamp}_{}_{counter}.{extension}",
+            std::process::id()
+        ));
+
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|e| {
+                Error::FileSystem(FileSystemError::WriteFailed {
+                    path: path.clone(),
+                    reason: e.to_string(),
+                })
+            })?;
+
+        debug!("Created temp file {}", path.display());
+        Ok(Self {
+            path,
+            persisted: false,
+        })
+    }
+
+    /// Get the path of the temp file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Move the temp file to its final destination.
+    ///
+    /// Falls back to copy-and-delete when the destination is on another
+    /// filesystem. The destination is only ever written as a complete file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be moved; the temp file is then
+    /// removed when the guard is dropped.
+    pub fn persist(mut self, destination: &Path) -> Result<PathBuf> {
+        if fs::rename(&self.path, destination).is_err() {
+            // Copy next to the destination first so the final rename is atomic
+            let staging = destination.with_extension("youtun4-partial");
+            let copied =
+                fs::copy(&self.path, &staging).and_then(|_| fs::rename(&staging, destination));
+            if let Err(e) = copied {
+                let _ = fs::remove_file(&staging);
+                return Err(Error::FileSystem(FileSystemError::WriteFailed {
+                    path: destination.to_path_buf(),
+                    reason: e.to_string(),
+                }));
+            }
+            let _ = fs::remove_file(&self.path);
+        }
+
+        self.persisted = true;
+        debug!(
+            "Persisted temp file {} -> {}",
+            self.path.display(),
+            destination.display()
+        );
+        Ok(destination.to_path_buf())
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        if !self.persisted && fs::remove_file(&self.path).is_ok() {
+            debug!("Removed temp file {}", self.path.display());
+        }
+    }
+}
+
 /// Cache manager for handling all caching operations.
 pub struct CacheManager {
     /// Cache configuration.
@@ -297,6 +473,8 @@ pub struct CacheManager {
     cache_dir: PathBuf,
     /// Cache manifest (in-memory).
     manifest: CacheManifest,
+    /// Whether the on-disk manifest was unreadable when the cache was loaded.
+    manifest_corrupted: bool,
 }
 
 impl CacheManager {
@@ -307,10 +485,7 @@ impl CacheManager {
     /// Returns an error if the cache directory cannot be created or the manifest
     /// cannot be loaded.
     pub fn new(config: CacheConfig) -> Result<Self> {
-        let cache_dir = config
-            .custom_cache_dir
-            .clone()
-            .unwrap_or_else(default_cache_directory);
+        let cache_dir = config.cache_directory();
 
         if !config.enabled {
             debug!("Cache is disabled");
@@ -318,6 +493,7 @@ impl CacheManager {
                 config,
                 cache_dir,
                 manifest: CacheManifest::default(),
+                manifest_corrupted: false,
             });
         }
 
@@ -325,20 +501,33 @@ impl CacheManager {
         Self::ensure_cache_directories(&cache_dir)?;
 
         // Load or create manifest
-        let manifest = Self::load_or_create_manifest(&cache_dir)?;
+        let (manifest, manifest_corrupted) = Self::load_or_create_manifest(&cache_dir)?;
+
+        let mut cache = Self {
+            config,
+            cache_dir,
+            manifest,
+            manifest_corrupted,
+        };
+
+        // Rebuild the manifest from the files on disk rather than silently
+        // starting over with an empty one
+        if manifest_corrupted {
+            let report = cache.verify(true)?;
+            warn!(
+                "Rebuilt corrupted cache manifest: {} entries recovered",
+                report.orphaned_files.len()
+            );
+        }
 
         info!(
             "Cache initialized at {} with {} entries ({} bytes)",
-            cache_dir.display(),
-            manifest.entries.len(),
-            manifest.total_size_bytes
+            cache.cache_dir.display(),
+            cache.manifest.entries.len(),
+            cache.manifest.total_size_bytes
         );
 
-        Ok(Self {
-            config,
-            cache_dir,
-            manifest,
-        })
+        Ok(cache)
     }
 
     /// Create cache directories if they don't exist.
@@ -368,7 +557,9 @@ impl CacheManager {
     }
 
     /// Load the cache manifest from disk or create a new one.
-    fn load_or_create_manifest(cache_dir: &Path) -> Result<CacheManifest> {
+    ///
+    /// The returned flag is `true` when a manifest existed but could not be parsed.
+    fn load_or_create_manifest(cache_dir: &Path) -> Result<(CacheManifest, bool)> {
         let manifest_path = cache_dir.join(CACHE_MANIFEST_FILE);
 
         if manifest_path.exists() {
@@ -382,15 +573,16 @@ impl CacheManager {
             match serde_json::from_str(&content) {
                 Ok(manifest) => {
                     debug!("Loaded cache manifest from {}", manifest_path.display());
-                    return Ok(manifest);
+                    return Ok((manifest, false));
                 }
                 Err(e) => {
-                    warn!("Failed to parse cache manifest, creating new one: {}", e);
+                    warn!("Failed to parse cache manifest, rebuilding it: {}", e);
+                    return Ok((CacheManifest::default(), true));
                 }
             }
         }
 
-        Ok(CacheManifest::default())
+        Ok((CacheManifest::default(), false))
     }
 
     /// Save the cache manifest to disk.
@@ -507,12 +699,7 @@ impl CacheManager {
         // Check if we need to make space
         self.ensure_space(size)?;
 
-        fs::write(&cache_path, &content).map_err(|e| {
-            Error::FileSystem(FileSystemError::WriteFailed {
-                path: cache_path.clone(),
-                reason: e.to_string(),
-            })
-        })?;
+        self.write_via_temp(&cache_path, content.as_bytes())?;
 
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -630,12 +817,7 @@ impl CacheManager {
         // Check if we need to make space
         self.ensure_space(size)?;
 
-        fs::write(&cache_path, data).map_err(|e| {
-            Error::FileSystem(FileSystemError::WriteFailed {
-                path: cache_path.clone(),
-                reason: e.to_string(),
-            })
-        })?;
+        self.write_via_temp(&cache_path, data)?;
 
         let now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -713,6 +895,299 @@ impl CacheManager {
             .is_some_and(|e| !e.is_expired(self.config.ttl_secs))
     }
 
+    // =========================================================================
+    // YouTube Info Caching
+    // =========================================================================
+
+    /// Generate a cache key for `YouTube` playlist info.
+    fn playlist_info_cache_key(playlist_id: &str) -> String {
+        format!("playlist_{playlist_id}")
+    }
+
+    /// Generate a cache key for `YouTube` video info.
+    fn video_info_cache_key(video_id: &str) -> String {
+        format!("video_{video_id}")
+    }
+
+    /// Generate a cache key for the size estimate of a playlist's audio
+    /// saved at `bitrate_kbps`, or as the original streams.
+    fn size_estimate_cache_key(
+        playlist_id: &str,
+        bitrate_kbps: u32,
+        original_audio: bool,
+    ) -> String {
+        if original_audio {
+            format!("size_{playlist_id}_original")
+        } else {
+            format!("size_{playlist_id}_{bitrate_kbps}")
+        }
+    }
+
+    /// Write a JSON value into the metadata cache under the given key.
+    fn put_json_entry<T: Serialize>(&mut self, key: String, value: &T) -> Result<()> {
+        let relative_path = PathBuf::from(METADATA_CACHE_DIR).join(format!("{key}.json"));
+        let cache_path = self.cache_dir.join(&relative_path);
+
+        let content = serde_json::to_string(value)?;
+        let size = content.len() as u64;
+
+        // Check if we need to make space
+        self.ensure_space(size)?;
+
+        self.write_via_temp(&cache_path, content.as_bytes())?;
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        let entry = CacheEntry {
+            key: key.clone(),
+            path: relative_path,
+            size_bytes: size,
+            created_at: now,
+            last_accessed_at: now,
+            entry_type: CacheEntryType::Metadata,
+        };
+
+        // Remove old entry if exists
+        if let Some(old_entry) = self.manifest.entries.remove(&key) {
+            self.manifest.total_size_bytes = self
+                .manifest
+                .total_size_bytes
+                .saturating_sub(old_entry.size_bytes);
+        }
+
+        self.manifest.total_size_bytes += size;
+        self.manifest.entries.insert(key, entry);
+        self.save_manifest()
+    }
+
+    /// Read a JSON value from the metadata cache.
+    ///
+    /// Missing, expired, or unreadable entries are treated as cache misses.
+    fn get_json_entry<T: DeserializeOwned>(&mut self, key: &str) -> Result<Option<T>> {
+        // Check if entry exists and is not expired
+        let entry = match self.manifest.entries.get(key) {
+            Some(e) if !e.is_expired(self.config.ttl_secs) => e.clone(),
+            _ => return Ok(None),
+        };
+
+        let cache_path = self.cache_dir.join(&entry.path);
+
+        let Ok(content) = fs::read_to_string(&cache_path) else {
+            self.remove_entry(key)?;
+            return Ok(None);
+        };
+
+        let Ok(value) = serde_json::from_str::<T>(&content) else {
+            // Invalid data, remove from manifest
+            self.remove_entry(key)?;
+            return Ok(None);
+        };
+
+        // Update last accessed time
+        if let Some(entry) = self.manifest.entries.get_mut(key) {
+            entry.touch();
+        }
+
+        Ok(Some(value))
+    }
+
+    /// Cache `YouTube` playlist info so it can be served in offline mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if caching fails.
+    pub fn put_playlist_info(&mut self, info: &PlaylistInfo) -> Result<()> {
+        if !self.config.enabled || !self.config.cache_metadata {
+            return Ok(());
+        }
+
+        self.put_json_entry(Self::playlist_info_cache_key(&info.id), info)?;
+        debug!("Cached playlist info for {}", info.id);
+        Ok(())
+    }
+
+    /// Get cached `YouTube` playlist info.
+    ///
+    /// Returns `None` if not cached or expired.
+    pub fn get_playlist_info(&mut self, playlist_id: &str) -> Result<Option<PlaylistInfo>> {
+        if !self.config.enabled || !self.config.cache_metadata {
+            return Ok(None);
+        }
+
+        let info = self.get_json_entry(&Self::playlist_info_cache_key(playlist_id))?;
+        if info.is_some() {
+            debug!("Cache hit for playlist info: {}", playlist_id);
+        }
+        Ok(info)
+    }
+
+    /// Cache `YouTube` video info.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if caching fails.
+    pub fn put_video_info(&mut self, info: &VideoInfo) -> Result<()> {
+        if !self.config.enabled || !self.config.cache_metadata {
+            return Ok(());
+        }
+
+        self.put_json_entry(Self::video_info_cache_key(&info.id), info)?;
+        debug!("Cached video info for {}", info.id);
+        Ok(())
+    }
+
+    /// Get cached `YouTube` video info.
+    ///
+    /// Returns `None` if not cached or expired.
+    pub fn get_video_info(&mut self, video_id: &str) -> Result<Option<VideoInfo>> {
+        if !self.config.enabled || !self.config.cache_metadata {
+            return Ok(None);
+        }
+
+        let info = self.get_json_entry(&Self::video_info_cache_key(video_id))?;
+        if info.is_some() {
+            debug!("Cache hit for video info: {}", video_id);
+        }
+        Ok(info)
+    }
+
+    /// Check if video info is cached.
+    #[must_use]
+    pub fn has_video_info(&self, video_id: &str) -> bool {
+        if !self.config.enabled || !self.config.cache_metadata {
+            return false;
+        }
+
+        let key = Self::video_info_cache_key(video_id);
+        self.manifest
+            .entries
+            .get(&key)
+            .is_some_and(|e| !e.is_expired(self.config.ttl_secs))
+    }
+
+    /// Cache the size estimate of a playlist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if caching fails.
+    pub fn put_size_estimate(&mut self, estimate: &PlaylistSizeEstimate) -> Result<()> {
+        if !self.config.enabled || !self.config.cache_metadata {
+            return Ok(());
+        }
+
+        let key = Self::size_estimate_cache_key(
+            &estimate.playlist_id,
+            estimate.bitrate_kbps,
+            estimate.original_audio,
+        );
+        self.put_json_entry(key, estimate)?;
+        debug!("Cached size estimate for {}", estimate.playlist_id);
+        Ok(())
+    }
+
+    /// Get the cached size estimate of a playlist saved at `bitrate_kbps`,
+    /// or as the original streams.
+    ///
+    /// Returns `None` if not cached or expired.
+    pub fn get_size_estimate(
+        &mut self,
+        playlist_id: &str,
+        bitrate_kbps: u32,
+        original_audio: bool,
+    ) -> Result<Option<PlaylistSizeEstimate>> {
+        if !self.config.enabled || !self.config.cache_metadata {
+            return Ok(None);
+        }
+
+        let key = Self::size_estimate_cache_key(playlist_id, bitrate_kbps, original_audio);
+        let estimate = self.get_json_entry(&key)?;
+        if estimate.is_some() {
+            debug!("Cache hit for size estimate: {}", playlist_id);
+        }
+        Ok(estimate)
+    }
+
+    // =========================================================================
+    // Cache Warming
+    // =========================================================================
+
+    /// Pre-populate the cache with info and thumbnails for a whole playlist.
+    ///
+    /// Entries that are already cached are skipped. Individual fetch failures
+    /// are logged and counted rather than aborting the warm-up. When `offline`
+    /// is set, only info already present in `playlist` is cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the playlist info itself cannot be cached.
+    pub fn warm_playlist(
+        &mut self,
+        playlist: &PlaylistInfo,
+        offline: bool,
+        cancel_flag: &AtomicBool,
+    ) -> Result<CacheWarmStats> {
+        let mut stats = CacheWarmStats::default();
+
+        if !self.config.enabled {
+            return Ok(stats);
+        }
+
+        info!(
+            "Warming cache for playlist '{}' ({} videos)",
+            playlist.title,
+            playlist.videos.len()
+        );
+
+        self.put_playlist_info(playlist)?;
+
+        for video in &playlist.videos {
+            if cancel_flag.load(Ordering::SeqCst) {
+                info!("Cache warming cancelled for playlist '{}'", playlist.title);
+                stats.cancelled = true;
+                break;
+            }
+
+            if self.has_video_info(&video.id) {
+                stats.videos_skipped += 1;
+            } else if let Err(e) = self.put_video_info(video) {
+                warn!("Failed to cache video info for {}: {}", video.id, e);
+            } else {
+                stats.videos_cached += 1;
+            }
+
+            if !self.config.cache_thumbnails {
+                continue;
+            }
+
+            if self.has_thumbnail(&video.id) {
+                stats.thumbnails_skipped += 1;
+                continue;
+            }
+
+            let url = video
+                .thumbnail_url
+                .clone()
+                .unwrap_or_else(|| youtube_thumbnail_url(&video.id));
+            let mut thumbnails = ThumbnailManager::new(self).with_offline(offline);
+            match thumbnails.fetch_and_cache(&video.id, &url) {
+                Ok(_) => stats.thumbnails_cached += 1,
+                Err(e) => {
+                    debug!("Failed to warm thumbnail for {}: {}", video.id, e);
+                    stats.thumbnails_failed += 1;
+                }
+            }
+        }
+
+        info!(
+            "Cache warming done for '{}': {} videos cached, {} thumbnails cached, {} thumbnails failed",
+            playlist.title, stats.videos_cached, stats.thumbnails_cached, stats.thumbnails_failed
+        );
+        Ok(stats)
+    }
+
     // =========================================================================
     // Temp File Management
     // =========================================================================
@@ -734,6 +1209,31 @@ impl CacheManager {
         self.temp_dir().join(filename)
     }
 
+    /// Create a new temp file in the cache temp area.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the temp file cannot be created.
+    pub fn create_temp_file(&self, prefix: &str, extension: &str) -> Result<TempFile> {
+        TempFile::create_in(&self.temp_dir(), prefix, extension)
+    }
+
+    /// Write data to a cache file through the temp area.
+    ///
+    /// Readers either see the previous content or the complete new content,
+    /// never a partially written file.
+    fn write_via_temp(&self, destination: &Path, data: &[u8]) -> Result<()> {
+        let temp = self.create_temp_file("write", "tmp")?;
+        fs::write(temp.path(), data).map_err(|e| {
+            Error::FileSystem(FileSystemError::WriteFailed {
+                path: destination.to_path_buf(),
+                reason: e.to_string(),
+            })
+        })?;
+        temp.persist(destination)?;
+        Ok(())
+    }
+
     /// Clean up all temporary files.
     pub fn cleanup_temp(&mut self) -> Result<CacheCleanupStats> {
         let temp_dir = self.temp_dir();
@@ -856,16 +1356,18 @@ impl CacheManager {
         // If still over target, remove by LRU
         if self.manifest.total_size_bytes > target_size {
             // Sort entries by last accessed time (oldest first)
-            let mut entries: Vec<_> = self.manifest.entries.iter().collect();
-            entries.sort_by_key(|(_, e)| e.last_accessed_at);
-
-            let keys_to_remove: Vec<String> = entries
-                .into_iter()
-                .take_while(|_| self.manifest.total_size_bytes > target_size)
-                .map(|(k, _)| k.clone())
+            let mut entries: Vec<_> = self
+                .manifest
+                .entries
+                .iter()
+                .map(|(k, e)| (k.clone(), e.last_accessed_at))
                 .collect();
+            entries.sort_by_key(|(_, last_accessed_at)| *last_accessed_at);
 
-            for key in keys_to_remove {
+            for (key, _) in entries {
+                if self.manifest.total_size_bytes <= target_size {
+                    break;
+                }
                 if let Some(entry) = self.manifest.entries.remove(&key) {
                     let cache_path = self.cache_dir.join(&entry.path);
                     let _ = fs::remove_file(&cache_path);
@@ -1004,6 +1506,135 @@ impl CacheManager {
         stats
     }
 
+    // =========================================================================
+    // Integrity Verification
+    // =========================================================================
+
+    /// Find files in the metadata and thumbnail directories that the manifest
+    /// does not track. Temp files are not tracked by design.
+    fn find_untracked_files(&self) -> Vec<(PathBuf, u64, CacheEntryType)> {
+        let tracked: std::collections::HashSet<&PathBuf> =
+            self.manifest.entries.values().map(|e| &e.path).collect();
+        let mut untracked = Vec::new();
+
+        for (subdir, entry_type) in [
+            (METADATA_CACHE_DIR, CacheEntryType::Metadata),
+            (THUMBNAIL_CACHE_DIR, CacheEntryType::Thumbnail),
+        ] {
+            let Ok(entries) = fs::read_dir(self.cache_dir.join(subdir)) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let relative_path = PathBuf::from(subdir).join(entry.file_name());
+                if entry.path().is_file() && !tracked.contains(&relative_path) {
+                    let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+                    untracked.push((relative_path, size, entry_type));
+                }
+            }
+        }
+
+        untracked
+    }
+
+    /// Cross-check the manifest against the files on disk.
+    ///
+    /// Detects entries whose file is missing, entries whose recorded size is
+    /// wrong, and untracked files in the metadata and thumbnail directories.
+    /// When `repair` is set, missing entries are dropped, sizes are corrected,
+    /// untracked files are adopted into the manifest, and the manifest is saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repaired manifest cannot be saved.
+    pub fn verify(&mut self, repair: bool) -> Result<CacheVerifyReport> {
+        let mut report = CacheVerifyReport {
+            entries_checked: self.manifest.entries.len(),
+            manifest_corrupted: self.manifest_corrupted,
+            ..Default::default()
+        };
+
+        if !self.config.enabled {
+            return Ok(report);
+        }
+
+        // Check tracked entries against disk
+        let mut actual_sizes = HashMap::new();
+        for (key, entry) in &self.manifest.entries {
+            match fs::metadata(self.cache_dir.join(&entry.path)) {
+                Ok(meta) if meta.is_file() => {
+                    if meta.len() != entry.size_bytes {
+                        report.size_mismatches.push(key.clone());
+                    }
+                    actual_sizes.insert(key.clone(), meta.len());
+                }
+                _ => report.missing_files.push(key.clone()),
+            }
+        }
+
+        let orphans = self.find_untracked_files();
+        report.orphaned_files = orphans.iter().map(|(p, _, _)| p.clone()).collect();
+
+        let summed: u64 = self.manifest.entries.values().map(|e| e.size_bytes).sum();
+        report.total_size_mismatch = summed != self.manifest.total_size_bytes;
+
+        report.missing_files.sort();
+        report.size_mismatches.sort();
+        report.orphaned_files.sort();
+
+        if !repair || report.is_consistent() {
+            return Ok(report);
+        }
+
+        for key in &report.missing_files {
+            self.manifest.entries.remove(key);
+        }
+        for key in &report.size_mismatches {
+            if let (Some(entry), Some(size)) =
+                (self.manifest.entries.get_mut(key), actual_sizes.get(key))
+            {
+                entry.size_bytes = *size;
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        for (relative_path, size, entry_type) in orphans {
+            let Some(key) = relative_path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .map(String::from)
+            else {
+                continue;
+            };
+            self.manifest.entries.insert(
+                key.clone(),
+                CacheEntry {
+                    key,
+                    path: relative_path,
+                    size_bytes: size,
+                    created_at: now,
+                    last_accessed_at: now,
+                    entry_type,
+                },
+            );
+        }
+
+        self.manifest.total_size_bytes = self.manifest.entries.values().map(|e| e.size_bytes).sum();
+        self.save_manifest()?;
+        self.manifest_corrupted = false;
+        report.repaired = true;
+
+        info!(
+            "Repaired cache manifest: {} missing, {} size mismatches, {} orphaned files",
+            report.missing_files.len(),
+            report.size_mismatches.len(),
+            report.orphaned_files.len()
+        );
+        Ok(report)
+    }
+
     /// Clear all cached data.
     pub fn clear(&mut self) -> Result<CacheCleanupStats> {
         let stats = CacheCleanupStats {
@@ -1067,6 +1698,7 @@ pub fn default_cache_directory() -> PathBuf {
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
+    use crate::youtube::{PlaylistHealth, VideoAvailability};
     use tempfile::TempDir;
 
     fn create_test_cache(temp_dir: &TempDir) -> CacheManager {
@@ -1159,6 +1791,197 @@ mod tests {
         assert_eq!(stats.thumbnail_entries, 1);
     }
 
+    #[test]
+    fn test_playlist_info_caching() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let mut cache = create_test_cache(&temp_dir);
+
+        let info = PlaylistInfo {
+            id: "PLtest123".to_string(),
+            title: "Test Playlist".to_string(),
+            video_count: 0,
+            videos: Vec::new(),
+            thumbnail_url: None,
+            health: PlaylistHealth::default(),
+        };
+
+        assert!(
+            cache
+                .get_playlist_info("PLtest123")
+                .expect("Failed to query cache")
+                .is_none()
+        );
+
+        cache
+            .put_playlist_info(&info)
+            .expect("Failed to cache playlist info");
+
+        let retrieved = cache
+            .get_playlist_info("PLtest123")
+            .expect("Failed to get playlist info")
+            .expect("Playlist info should exist");
+        assert_eq!(retrieved.title, "Test Playlist");
+
+        let stats = cache.stats();
+        assert_eq!(stats.metadata_entries, 1);
+    }
+
+    fn test_video(id: &str) -> VideoInfo {
+        VideoInfo {
+            id: id.to_string(),
+            title: format!("Video {id}"),
+            duration_secs: Some(180),
+            channel: Some("Channel".to_string()),
+            thumbnail_url: None,
+            availability: VideoAvailability::Available,
+            chapters: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_video_info_caching() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let mut cache = create_test_cache(&temp_dir);
+
+        assert!(!cache.has_video_info("abc"));
+        cache
+            .put_video_info(&test_video("abc"))
+            .expect("Failed to cache video info");
+        assert!(cache.has_video_info("abc"));
+
+        let retrieved = cache
+            .get_video_info("abc")
+            .expect("Failed to get video info")
+            .expect("Video info should exist");
+        assert_eq!(retrieved.title, "Video abc");
+        assert_eq!(retrieved.duration_secs, Some(180));
+    }
+
+    #[test]
+    fn test_warm_playlist_offline_caches_info_only() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let mut cache = create_test_cache(&temp_dir);
+        cache
+            .put_thumbnail("v1", &[0xFF, 0xD8])
+            .expect("Failed to cache thumbnail");
+
+        let playlist = PlaylistInfo {
+            id: "PLwarm".to_string(),
+            title: "Warm".to_string(),
+            video_count: 2,
+            videos: vec![test_video("v1"), test_video("v2")],
+            thumbnail_url: None,
+            health: PlaylistHealth::default(),
+        };
+
+        let cancel = AtomicBool::new(false);
+        let stats = cache
+            .warm_playlist(&playlist, true, &cancel)
+            .expect("Warming should succeed");
+
+        assert_eq!(stats.videos_cached, 2);
+        assert_eq!(stats.thumbnails_skipped, 1);
+        assert_eq!(stats.thumbnails_failed, 1);
+        assert!(!stats.cancelled);
+        assert!(cache.get_playlist_info("PLwarm").expect("query").is_some());
+
+        // Second run skips everything already cached
+        let stats = cache
+            .warm_playlist(&playlist, true, &cancel)
+            .expect("Warming should succeed");
+        assert_eq!(stats.videos_cached, 0);
+        assert_eq!(stats.videos_skipped, 2);
+    }
+
+    #[test]
+    fn test_warm_playlist_cancelled() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let mut cache = create_test_cache(&temp_dir);
+
+        let playlist = PlaylistInfo {
+            id: "PLcancel".to_string(),
+            title: "Cancel".to_string(),
+            video_count: 1,
+            videos: vec![test_video("v1")],
+            thumbnail_url: None,
+            health: PlaylistHealth::default(),
+        };
+
+        let cancel = AtomicBool::new(true);
+        let stats = cache
+            .warm_playlist(&playlist, true, &cancel)
+            .expect("Warming should succeed");
+        assert!(stats.cancelled);
+        assert_eq!(stats.videos_cached, 0);
+    }
+
+    #[test]
+    fn test_verify_consistent_cache() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let mut cache = create_test_cache(&temp_dir);
+        cache
+            .put_thumbnail("abc", &[1, 2, 3])
+            .expect("Failed to cache thumbnail");
+
+        let report = cache.verify(false).expect("Verify should succeed");
+        assert!(report.is_consistent());
+        assert_eq!(report.entries_checked, 1);
+        assert!(!report.repaired);
+    }
+
+    #[test]
+    fn test_verify_detects_and_repairs_issues() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let mut cache = create_test_cache(&temp_dir);
+        cache
+            .put_thumbnail("missing", &[1, 2, 3])
+            .expect("Failed to cache thumbnail");
+        cache
+            .put_thumbnail("resized", &[1, 2, 3])
+            .expect("Failed to cache thumbnail");
+
+        let thumbs = temp_dir.path().join(THUMBNAIL_CACHE_DIR);
+        fs::remove_file(thumbs.join("thumb_missing.jpg")).expect("remove");
+        fs::write(thumbs.join("thumb_resized.jpg"), [1, 2, 3, 4, 5]).expect("write");
+        fs::write(thumbs.join("thumb_orphan.jpg"), [9, 9]).expect("write");
+
+        let report = cache.verify(false).expect("Verify should succeed");
+        assert_eq!(report.missing_files, vec!["thumb_missing".to_string()]);
+        assert_eq!(report.size_mismatches, vec!["thumb_resized".to_string()]);
+        assert_eq!(
+            report.orphaned_files,
+            vec![PathBuf::from(THUMBNAIL_CACHE_DIR).join("thumb_orphan.jpg")]
+        );
+        assert!(!report.repaired);
+
+        let report = cache.verify(true).expect("Repair should succeed");
+        assert!(report.repaired);
+        assert!(!cache.has_thumbnail("missing"));
+        assert!(cache.has_thumbnail("orphan"));
+        assert_eq!(cache.stats().total_size_bytes, 7);
+
+        let report = cache.verify(false).expect("Verify should succeed");
+        assert!(report.is_consistent());
+    }
+
+    #[test]
+    fn test_corrupted_manifest_is_rebuilt() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        {
+            let mut cache = create_test_cache(&temp_dir);
+            cache
+                .put_thumbnail("abc", &[1, 2, 3])
+                .expect("Failed to cache thumbnail");
+        }
+
+        fs::write(temp_dir.path().join(CACHE_MANIFEST_FILE), "{not json").expect("write");
+
+        let mut cache = create_test_cache(&temp_dir);
+        assert!(cache.has_thumbnail("abc"));
+        let report = cache.verify(false).expect("Verify should succeed");
+        assert!(report.is_consistent());
+    }
+
     #[test]
     fn test_cache_entry_expiration() {
         let entry = CacheEntry {
@@ -1196,6 +2019,36 @@ mod tests {
         assert_eq!(stats_after.total_entries, 0);
     }
 
+    #[test]
+    fn test_full_cache_evicts_least_recently_used() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config = CacheConfig::new()
+            .with_max_size(3000)
+            .with_cache_dir(temp_dir.path().to_path_buf());
+        let mut cache = CacheManager::new(config).unwrap();
+
+        for i in 0..3 {
+            cache
+                .put_thumbnail(&format!("video_{i}"), &[0u8; 1000])
+                .unwrap();
+            let key = CacheManager::thumbnail_cache_key(&format!("video_{i}"));
+            cache
+                .manifest
+                .entries
+                .get_mut(&key)
+                .unwrap()
+                .last_accessed_at = i;
+        }
+
+        // Eviction stops once the cache is down to its cleanup target
+        cache.put_thumbnail("video_3", &[0u8; 1000]).unwrap();
+        assert!(!cache.has_thumbnail("video_0"));
+        assert!(!cache.has_thumbnail("video_1"));
+        assert!(cache.has_thumbnail("video_2"));
+        assert!(cache.has_thumbnail("video_3"));
+        assert_eq!(cache.stats().total_size_bytes, 2000);
+    }
+
     #[test]
     fn test_cache_temp_dir() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
@@ -1216,6 +2069,72 @@ mod tests {
         assert!(path.to_string_lossy().ends_with(".mp3"));
     }
 
+    #[test]
+    fn test_temp_file_removed_on_drop() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = create_test_cache(&temp_dir);
+
+        let temp = cache.create_temp_file("download", "mp4").expect("create");
+        let path = temp.path().to_path_buf();
+        fs::write(&path, b"partial").expect("write");
+        assert!(path.starts_with(cache.temp_dir()));
+
+        drop(temp);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_temp_file_persist() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = create_test_cache(&temp_dir);
+        let output_dir = TempDir::new().expect("Failed to create output dir");
+
+        let temp = cache.create_temp_file("download", "mp4").expect("create");
+        let temp_path = temp.path().to_path_buf();
+        fs::write(&temp_path, b"complete").expect("write");
+
+        let destination = output_dir.path().join("song.mp4");
+        let persisted = temp.persist(&destination).expect("persist");
+
+        assert_eq!(persisted, destination);
+        assert_eq!(fs::read(&destination).expect("read"), b"complete");
+        assert!(!temp_path.exists());
+    }
+
+    #[test]
+    fn test_temp_file_names_are_unique() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = create_test_cache(&temp_dir);
+
+        let first = cache.create_temp_file("download", "mp4").expect("create");
+        let second = cache.create_temp_file("download", "mp4").expect("create");
+        assert_ne!(first.path(), second.path());
+    }
+
+    #[test]
+    fn test_cache_writes_leave_no_temp_files() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let mut cache = create_test_cache(&temp_dir);
+
+        cache.put_thumbnail("abc", &[1, 2, 3]).expect("put");
+
+        let leftovers = fs::read_dir(cache.temp_dir()).expect("read dir").count();
+        assert_eq!(leftovers, 0);
+        assert_eq!(
+            cache.get_thumbnail("abc").expect("get"),
+            Some(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_cache_config_temp_directory() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config = CacheConfig::new().with_cache_dir(temp_dir.path().to_path_buf());
+        let cache = CacheManager::new(config.clone()).expect("create");
+
+        assert_eq!(config.temp_directory(), cache.temp_dir());
+    }
+
     #[test]
     fn test_default_cache_directory() {
         let dir = default_cache_directory();
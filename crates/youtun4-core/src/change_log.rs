@@ -0,0 +1,356 @@
+//! Persistent journal of changes to the library.
+//!
+//! Every mutation of the library (playlists created, renamed or deleted,
+//! tracks added, moved or removed, syncs to a device) is appended to the
+//! change log with a sequence number and a timestamp. Readers keep the last
+//! sequence number they saw and ask for the changes [`since`](ChangeLog::since)
+//! it, which is what an activity feed or reconciliation with another device
+//! needs.
+//!
+//! The log is capped at [`MAX_CHANGE_LOG_ENTRIES`]; the oldest changes are
+//! dropped first. A reader that finds the first returned sequence number
+//! more than one past its own has missed changes and must rescan the library.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::config::AppConfig;
+use crate::error::{Error, Result};
+use crate::youtube::DownloadResult;
+
+/// File name of the change log, stored next to the config file.
+pub const CHANGE_LOG_FILE: &str = "change_log.json";
+
+/// Maximum number of changes kept in the log.
+pub const MAX_CHANGE_LOG_ENTRIES: usize = 10_000;
+
+/// Sequence number of a change. Sequence numbers start at 1 and only grow.
+pub type ChangeSeq = u64;
+
+/// A mutation of the library.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LibraryChange {
+    /// A playlist was created, imported or restored from the trash.
+    PlaylistCreated {
+        /// Playlist name.
+        playlist: String,
+    },
+    /// A playlist was deleted or moved to the trash.
+    PlaylistDeleted {
+        /// Playlist name.
+        playlist: String,
+    },
+    /// A playlist was renamed.
+    PlaylistRenamed {
+        /// Name before the rename.
+        from: String,
+        /// Name after the rename.
+        to: String,
+    },
+    /// Tracks were downloaded into a playlist or restored from the trash.
+    TracksAdded {
+        /// Playlist name.
+        playlist: String,
+        /// Track file names.
+        file_names: Vec<String>,
+    },
+    /// Tracks were deleted from a playlist or moved to the trash.
+    TracksRemoved {
+        /// Playlist name.
+        playlist: String,
+        /// Track file names.
+        file_names: Vec<String>,
+    },
+    /// Tracks were moved from one playlist to another.
+    TracksMoved {
+        /// Source playlist.
+        from: String,
+        /// Destination playlist.
+        to: String,
+        /// Track file names.
+        file_names: Vec<String>,
+    },
+    /// Playlists were synced to a device.
+    SyncPerformed {
+        /// Synced playlists.
+        playlists: Vec<String>,
+        /// Device mount point.
+        device_mount_point: String,
+    },
+}
+
+impl LibraryChange {
+    /// Tracks added to `playlist` by a download, or `None` if nothing was
+    /// downloaded.
+    #[must_use]
+    pub fn tracks_downloaded(
+        playlist: impl Into<String>,
+        results: &[DownloadResult],
+    ) -> Option<Self> {
+        let file_names: Vec<String> = results
+            .iter()
+            .filter(|r| r.success)
+            .flat_map(DownloadResult::output_paths)
+            .filter_map(|path| path.file_name())
+            .map(|name| name.to_string_lossy().into_owned())
+            .collect();
+        (!file_names.is_empty()).then(|| Self::TracksAdded {
+            playlist: playlist.into(),
+            file_names,
+        })
+    }
+}
+
+/// A change in the log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeRecord {
+    /// Sequence number, assigned when the change is recorded.
+    pub seq: ChangeSeq,
+    /// When the change happened (seconds since the Unix epoch).
+    pub recorded_at: u64,
+    /// What changed.
+    pub change: LibraryChange,
+}
+
+/// Persistent, append-only log of library changes.
+#[derive(Debug)]
+pub struct ChangeLog {
+    path: PathBuf,
+    records: Mutex<Vec<ChangeRecord>>,
+}
+
+impl ChangeLog {
+    /// Open the change log at `path`.
+    ///
+    /// A missing file yields an empty log; an unreadable one is logged and
+    /// replaced on the next write.
+    #[must_use]
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let records = Self::load(&path);
+        Self {
+            path,
+            records: Mutex::new(records),
+        }
+    }
+
+    /// Open the change log in its default location next to the config file.
+    #[must_use]
+    pub fn open_default() -> Self {
+        let config_path = AppConfig::config_file_path();
+        let dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+        Self::open(dir.join(CHANGE_LOG_FILE))
+    }
+
+    fn load(path: &Path) -> Vec<ChangeRecord> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+            Err(e) => {
+                warn!("Failed to read change log {}: {}", path.display(), e);
+                return Vec::new();
+            }
+        };
+        serde_json::from_str(&contents).unwrap_or_else(|e| {
+            warn!("Discarding corrupt change log {}: {}", path.display(), e);
+            Vec::new()
+        })
+    }
+
+    fn save(&self, records: &[ChangeRecord]) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string(records)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Vec<ChangeRecord>>> {
+        self.records
+            .lock()
+            .map_err(|_| Error::internal("Change log lock poisoned"))
+    }
+
+    /// Append `change` to the log, returning its sequence number.
+    pub fn record(&self, change: LibraryChange) -> Result<ChangeSeq> {
+        let mut records = self.lock()?;
+        let seq = records.last().map_or(1, |last| last.seq + 1);
+        records.push(ChangeRecord {
+            seq,
+            recorded_at: unix_now(),
+            change,
+        });
+        let excess = records.len().saturating_sub(MAX_CHANGE_LOG_ENTRIES);
+        records.drain(..excess);
+        self.save(&records)?;
+        debug!("Recorded library change {}", seq);
+        Ok(seq)
+    }
+
+    /// Changes recorded after `since`, oldest first. `None` returns every
+    /// change still in the log.
+    #[must_use]
+    pub fn since(&self, since: Option<ChangeSeq>) -> Vec<ChangeRecord> {
+        let since = since.unwrap_or(0);
+        self.lock()
+            .map(|records| {
+                let start = records.partition_point(|record| record.seq <= since);
+                records[start..].to_vec()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Sequence number of the latest change, or 0 if nothing was recorded.
+    #[must_use]
+    pub fn latest_seq(&self) -> ChangeSeq {
+        self.lock()
+            .ok()
+            .and_then(|records| records.last().map(|record| record.seq))
+            .unwrap_or(0)
+    }
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::youtube::{VideoAvailability, VideoInfo};
+    use tempfile::TempDir;
+
+    fn created(playlist: &str) -> LibraryChange {
+        LibraryChange::PlaylistCreated {
+            playlist: playlist.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_record_since_and_reopen() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(CHANGE_LOG_FILE);
+        let log = ChangeLog::open(&path);
+        assert_eq!(log.latest_seq(), 0);
+
+        assert_eq!(log.record(created("Mix")).unwrap(), 1);
+        let renamed = LibraryChange::PlaylistRenamed {
+            from: "Mix".to_string(),
+            to: "Road Trip".to_string(),
+        };
+        assert_eq!(log.record(renamed.clone()).unwrap(), 2);
+
+        let reopened = ChangeLog::open(&path);
+        assert_eq!(reopened.latest_seq(), 2);
+        assert_eq!(reopened.since(None).len(), 2);
+        let later = reopened.since(Some(1));
+        assert_eq!(later.len(), 1);
+        assert_eq!(later[0].seq, 2);
+        assert_eq!(later[0].change, renamed);
+        assert!(reopened.since(Some(2)).is_empty());
+
+        // Sequence numbers continue after reopening
+        assert_eq!(reopened.record(created("Chill")).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_log_is_capped() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(CHANGE_LOG_FILE);
+        let full: Vec<ChangeRecord> = (1..=MAX_CHANGE_LOG_ENTRIES as u64)
+            .map(|seq| ChangeRecord {
+                seq,
+                recorded_at: seq,
+                change: created("Mix"),
+            })
+            .collect();
+        fs::write(&path, serde_json::to_string(&full).unwrap()).unwrap();
+
+        let log = ChangeLog::open(&path);
+        log.record(created("Chill")).unwrap();
+
+        let records = log.since(None);
+        assert_eq!(records.len(), MAX_CHANGE_LOG_ENTRIES);
+        // The oldest change was dropped, which readers see as a gap
+        assert_eq!(records[0].seq, 2);
+        assert_eq!(log.latest_seq(), MAX_CHANGE_LOG_ENTRIES as u64 + 1);
+    }
+
+    #[test]
+    fn test_corrupt_log_is_discarded() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(CHANGE_LOG_FILE);
+        fs::write(&path, "not json").unwrap();
+
+        let log = ChangeLog::open(&path);
+        assert!(log.since(None).is_empty());
+        log.record(created("Mix")).unwrap();
+        assert_eq!(ChangeLog::open(&path).since(None).len(), 1);
+    }
+
+    #[test]
+    fn test_tracks_downloaded() {
+        let result = |path: Option<&str>, success: bool| DownloadResult {
+            video: VideoInfo {
+                id: "id".to_string(),
+                title: "Title".to_string(),
+                duration_secs: None,
+                channel: None,
+                thumbnail_url: None,
+                availability: VideoAvailability::Available,
+                chapters: Vec::new(),
+            },
+            success,
+            output_path: path.map(PathBuf::from),
+            error: None,
+            sponsor_secs_removed: 0.0,
+            audio_bitrate_kbps: None,
+            audio_codec: None,
+            stream_path: None,
+            chapter_paths: Vec::new(),
+            estimated_size_bytes: None,
+        };
+
+        assert_eq!(
+            LibraryChange::tracks_downloaded("Mix", &[result(None, false)]),
+            None
+        );
+        assert_eq!(
+            LibraryChange::tracks_downloaded(
+                "Mix",
+                &[
+                    result(Some("/music/Mix/a.mp3"), true),
+                    result(Some("/music/Mix/b.mp3"), false),
+                ]
+            ),
+            Some(LibraryChange::TracksAdded {
+                playlist: "Mix".to_string(),
+                file_names: vec!["a.mp3".to_string()],
+            })
+        );
+    }
+
+    #[test]
+    fn test_change_serialization() {
+        let change = LibraryChange::SyncPerformed {
+            playlists: vec!["Mix".to_string()],
+            device_mount_point: "/media/PLAYER".to_string(),
+        };
+        let json = serde_json::to_value(&change).unwrap();
+        assert_eq!(json["type"], "sync_performed");
+        assert_eq!(json["playlists"][0], "Mix");
+        let parsed: LibraryChange = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, change);
+    }
+}
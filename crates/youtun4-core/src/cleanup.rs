@@ -20,16 +20,17 @@
 //! ```
 
 use std::collections::HashSet;
-use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::SystemTime;
 
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info, warn};
-use walkdir::WalkDir;
 
 use crate::device::{DeviceDetector, DeviceInfo};
 use crate::error::{DeviceError, Error, FileSystemError, Result};
+use crate::fs::{FileMetadata, FileSystem, RealFileSystem};
 
 /// Configuration options for device cleanup operations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,6 +47,10 @@ pub struct CleanupOptions {
     pub dry_run: bool,
     /// Maximum depth to traverse (-1 for unlimited).
     pub max_depth: i32,
+    /// Paths relative to the mount point that must be kept, along with the
+    /// folders containing them.
+    #[serde(default)]
+    pub protected_paths: Vec<PathBuf>,
 }
 
 impl Default for CleanupOptions {
@@ -57,6 +62,7 @@ impl Default for CleanupOptions {
             verify_deletions: true,
             dry_run: false,
             max_depth: -1,
+            protected_paths: Vec::new(),
         }
     }
 }
@@ -72,6 +78,7 @@ impl CleanupOptions {
             verify_deletions: true,
             dry_run: false,
             max_depth: -1,
+            protected_paths: Vec::new(),
         }
     }
 
@@ -90,6 +97,24 @@ impl CleanupOptions {
         self.protected_patterns.push(pattern.into());
         self
     }
+
+    /// Add paths, relative to the mount point, that must be kept.
+    #[must_use]
+    pub fn with_protected_paths(mut self, paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.protected_paths.extend(paths);
+        self
+    }
+
+    /// Whether `path` under `mount_point` is one of the protected paths, or a
+    /// folder containing one.
+    #[must_use]
+    pub fn keeps_path(&self, path: &Path, mount_point: &Path) -> bool {
+        path.strip_prefix(mount_point).is_ok_and(|relative| {
+            self.protected_paths
+                .iter()
+                .any(|kept| kept.starts_with(relative))
+        })
+    }
 }
 
 /// Information about a single file or directory that will be/was deleted.
@@ -132,6 +157,9 @@ pub struct CleanupResult {
     pub verification_passed: Option<bool>,
     /// Duration of the cleanup operation in milliseconds.
     pub duration_ms: u64,
+    /// Whether the cleanup was cancelled before all entries were processed.
+    #[serde(default)]
+    pub cancelled: bool,
 }
 
 impl CleanupResult {
@@ -151,7 +179,12 @@ impl CleanupResult {
             )
         } else {
             format!(
-                "Deleted {} files and {} directories, freed {} bytes ({} skipped, {} failed)",
+                "{} {} files and {} directories, freed {} bytes ({} skipped, {} failed)",
+                if self.cancelled {
+                    "Cancelled after deleting"
+                } else {
+                    "Deleted"
+                },
                 self.files_deleted,
                 self.directories_deleted,
                 self.bytes_freed,
@@ -183,6 +216,10 @@ const SYSTEM_PATTERNS: &[&str] = &[
 pub struct DeviceCleanupHandler {
     /// System patterns that are always protected.
     system_patterns: HashSet<String>,
+    /// Flag checked between deletions to stop a cleanup early.
+    cancel_flag: Option<Arc<AtomicBool>>,
+    /// File system the device is accessed through.
+    fs: Arc<dyn FileSystem>,
 }
 
 impl Default for DeviceCleanupHandler {
@@ -197,7 +234,34 @@ impl DeviceCleanupHandler {
     pub fn new() -> Self {
         let system_patterns: HashSet<String> =
             SYSTEM_PATTERNS.iter().map(|s| s.to_lowercase()).collect();
-        Self { system_patterns }
+        Self {
+            system_patterns,
+            cancel_flag: None,
+            fs: Arc::new(RealFileSystem),
+        }
+    }
+
+    /// Access the device through `fs` instead of the real file system.
+    #[must_use]
+    pub fn with_file_system(mut self, fs: Arc<dyn FileSystem>) -> Self {
+        self.fs = fs;
+        self
+    }
+
+    /// Stop deleting once `flag` is set.
+    ///
+    /// Entries already deleted stay deleted; the remaining entries are left
+    /// untouched and the result is marked as cancelled.
+    #[must_use]
+    pub fn with_cancel_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.cancel_flag = Some(flag);
+        self
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancel_flag
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::SeqCst))
     }
 
     /// Check if a path is protected and should not be deleted.
@@ -229,13 +293,13 @@ impl DeviceCleanupHandler {
 
     /// Verify that the device is accessible and writable.
     fn verify_device_writable(&self, mount_point: &Path) -> Result<()> {
-        if !mount_point.exists() {
+        if !self.fs.exists(mount_point) {
             return Err(Error::Device(DeviceError::NotMounted {
                 mount_point: mount_point.to_path_buf(),
             }));
         }
 
-        if !mount_point.is_dir() {
+        if !self.fs.is_dir(mount_point) {
             return Err(Error::FileSystem(FileSystemError::InvalidPath {
                 path: mount_point.to_path_buf(),
                 reason: "mount point is not a directory".to_string(),
@@ -243,14 +307,14 @@ impl DeviceCleanupHandler {
         }
 
         // Try to verify write access by checking directory metadata
-        let metadata = fs::metadata(mount_point).map_err(|e| {
+        let metadata = self.fs.metadata(mount_point).map_err(|e| {
             Error::Device(DeviceError::PermissionDenied {
                 path: mount_point.to_path_buf(),
                 reason: format!("cannot read device metadata: {e}"),
             })
         })?;
 
-        if metadata.permissions().readonly() {
+        if metadata.readonly {
             return Err(Error::Device(DeviceError::ReadOnly {
                 name: mount_point.display().to_string(),
             }));
@@ -259,8 +323,40 @@ impl DeviceCleanupHandler {
         Ok(())
     }
 
+    /// Canonicalize the mount point, resolving symlinks.
+    fn canonical_mount(&self, mount_point: &Path) -> Result<PathBuf> {
+        self.fs.canonicalize(mount_point).map_err(|e| {
+            Error::FileSystem(FileSystemError::InvalidPath {
+                path: mount_point.to_path_buf(),
+                reason: format!("failed to canonicalize mount point: {e}"),
+            })
+        })
+    }
+
+    /// Every entry below the mount point, up to the configured depth, with
+    /// metadata that doesn't follow symlinks.
+    ///
+    /// SECURITY: symlinked directories are not descended into, so the walk
+    /// can't leave the mount point.
+    fn walk_device(
+        &self,
+        mount_point: &Path,
+        options: &CleanupOptions,
+    ) -> Vec<(PathBuf, FileMetadata)> {
+        let max_depth = usize::try_from(options.max_depth).ok();
+        self.fs
+            .walk_dir(mount_point, max_depth)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|path| {
+                let metadata = self.fs.symlink_metadata(&path).ok()?;
+                Some((path, metadata))
+            })
+            .collect()
+    }
+
     /// Scan the device to collect files that will be deleted.
-    #[allow(clippy::type_complexity, clippy::unnecessary_wraps)]
+    #[allow(clippy::type_complexity)]
     fn scan_for_cleanup(
         &self,
         mount_point: &Path,
@@ -270,45 +366,24 @@ impl DeviceCleanupHandler {
         let mut skipped = Vec::new();
 
         // SECURITY: Canonicalize mount_point to resolve any symlinks and get absolute path
-        let canonical_mount = mount_point.canonicalize().map_err(|e| {
-            Error::FileSystem(FileSystemError::InvalidPath {
-                path: mount_point.to_path_buf(),
-                reason: format!("failed to canonicalize mount point: {e}"),
-            })
-        })?;
-
-        // Build walker with depth configuration
-        // SECURITY: follow_links(false) prevents following symlinks outside mount point
-        let walker = if options.max_depth < 0 {
-            WalkDir::new(mount_point).min_depth(1).follow_links(false)
-        } else {
-            WalkDir::new(mount_point)
-                .min_depth(1)
-                .max_depth(options.max_depth as usize)
-                .follow_links(false)
-        };
+        let canonical_mount = self.canonical_mount(mount_point)?;
 
         // Collect all entries first (we'll process from deepest to shallowest)
-        let mut all_entries: Vec<_> = walker
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-            .collect();
+        let mut all_entries = self.walk_device(mount_point, options);
 
         // Sort by depth (deepest first) so we can delete children before parents
-        all_entries.sort_by_key(|b| std::cmp::Reverse(b.depth()));
-
-        for entry in all_entries {
-            let path = entry.path().to_path_buf();
+        all_entries.sort_by_key(|(path, _)| std::cmp::Reverse(path.components().count()));
 
+        for (path, metadata) in all_entries {
             // SECURITY: Skip symlinks entirely to prevent path traversal attacks
-            if entry.file_type().is_symlink() {
+            if metadata.is_symlink {
                 debug!("Skipping symlink: {}", path.display());
                 skipped.push((path, "symlink".to_string()));
                 continue;
             }
 
             // SECURITY: Verify the path resolves within the mount point
-            if let Ok(canonical_path) = path.canonicalize()
+            if let Ok(canonical_path) = self.fs.canonicalize(&path)
                 && !canonical_path.starts_with(&canonical_mount)
             {
                 warn!(
@@ -319,6 +394,12 @@ impl DeviceCleanupHandler {
                 continue;
             }
 
+            if options.keeps_path(&path, mount_point) {
+                debug!("Keeping path chosen by the user: {}", path.display());
+                skipped.push((path, "kept by user".to_string()));
+                continue;
+            }
+
             // Check if protected
             if let Some(reason) = self.is_protected(&path, options) {
                 debug!("Skipping protected path: {} ({})", path.display(), reason);
@@ -326,12 +407,8 @@ impl DeviceCleanupHandler {
                 continue;
             }
 
-            let is_directory = entry.file_type().is_dir();
-            let size_bytes = if is_directory {
-                0
-            } else {
-                fs::metadata(&path).map(|m| m.len()).unwrap_or(0)
-            };
+            let is_directory = metadata.is_dir;
+            let size_bytes = if is_directory { 0 } else { metadata.len };
 
             entries_to_delete.push(CleanupEntry {
                 path,
@@ -350,9 +427,9 @@ impl DeviceCleanupHandler {
         let result = if entry.is_directory {
             // For directories, only try to remove if empty
             // (children should have been deleted already due to sorting)
-            fs::remove_dir(&entry.path)
+            self.fs.remove_dir(&entry.path)
         } else {
-            fs::remove_file(&entry.path)
+            self.fs.remove_file(&entry.path)
         };
 
         match result {
@@ -372,7 +449,7 @@ impl DeviceCleanupHandler {
     /// Verify that all marked-deleted files are actually gone.
     fn verify_cleanup(&self, entries: &[CleanupEntry]) -> bool {
         for entry in entries {
-            if entry.deleted == Some(true) && entry.path.exists() {
+            if entry.deleted == Some(true) && self.fs.exists(&entry.path) {
                 warn!(
                     "Verification failed: {} still exists after deletion",
                     entry.path.display()
@@ -411,7 +488,7 @@ impl DeviceCleanupHandler {
         // Verify device is accessible and writable (unless dry run)
         if !options.dry_run {
             self.verify_device_writable(mount_point)?;
-        } else if !mount_point.exists() {
+        } else if !self.fs.exists(mount_point) {
             return Err(Error::Device(DeviceError::NotMounted {
                 mount_point: mount_point.to_path_buf(),
             }));
@@ -448,6 +525,7 @@ impl DeviceCleanupHandler {
                 skipped_entries,
                 verification_passed: None,
                 duration_ms,
+                cancelled: false,
             });
         }
 
@@ -456,8 +534,14 @@ impl DeviceCleanupHandler {
         let mut directories_deleted = 0;
         let mut bytes_freed = 0u64;
         let mut files_failed = 0;
+        let mut cancelled = false;
 
         for entry in &mut entries {
+            if self.is_cancelled() {
+                warn!("Cleanup of {} cancelled", mount_point.display());
+                cancelled = true;
+                break;
+            }
             if self.delete_entry(entry) {
                 if entry.is_directory {
                     directories_deleted += 1;
@@ -494,6 +578,7 @@ impl DeviceCleanupHandler {
             skipped_entries,
             verification_passed,
             duration_ms,
+            cancelled,
         };
 
         info!("{}", result.summary());
@@ -554,55 +639,38 @@ impl DeviceCleanupHandler {
 
         if !options.dry_run {
             self.verify_device_writable(mount_point)?;
-        } else if !mount_point.exists() {
+        } else if !self.fs.exists(mount_point) {
             return Err(Error::Device(DeviceError::NotMounted {
                 mount_point: mount_point.to_path_buf(),
             }));
         }
 
         // SECURITY: Canonicalize mount_point to resolve any symlinks and get absolute path
-        let canonical_mount = mount_point.canonicalize().map_err(|e| {
-            Error::FileSystem(FileSystemError::InvalidPath {
-                path: mount_point.to_path_buf(),
-                reason: format!("failed to canonicalize mount point: {e}"),
-            })
-        })?;
+        let canonical_mount = self.canonical_mount(mount_point)?;
 
         let audio_extensions: HashSet<&str> = ["mp3", "m4a", "wav", "flac", "ogg", "aac"]
             .iter()
             .copied()
             .collect();
 
-        // SECURITY: follow_links(false) prevents following symlinks outside mount point
-        let walker = if options.max_depth < 0 {
-            WalkDir::new(mount_point).min_depth(1).follow_links(false)
-        } else {
-            WalkDir::new(mount_point)
-                .min_depth(1)
-                .max_depth(options.max_depth as usize)
-                .follow_links(false)
-        };
-
         let mut entries = Vec::new();
         let mut skipped_entries = Vec::new();
 
-        for entry in walker.into_iter().filter_map(std::result::Result::ok) {
-            let path = entry.path().to_path_buf();
-
+        for (path, metadata) in self.walk_device(mount_point, options) {
             // Skip directories for audio-only cleanup
-            if entry.file_type().is_dir() {
+            if metadata.is_dir {
                 continue;
             }
 
             // SECURITY: Skip symlinks entirely to prevent path traversal attacks
-            if entry.file_type().is_symlink() {
+            if metadata.is_symlink {
                 debug!("Skipping symlink: {}", path.display());
                 skipped_entries.push((path, "symlink".to_string()));
                 continue;
             }
 
             // SECURITY: Verify the path resolves within the mount point
-            if let Ok(canonical_path) = path.canonicalize()
+            if let Ok(canonical_path) = self.fs.canonicalize(&path)
                 && !canonical_path.starts_with(&canonical_mount)
             {
                 warn!(
@@ -613,6 +681,11 @@ impl DeviceCleanupHandler {
                 continue;
             }
 
+            if options.keeps_path(&path, mount_point) {
+                skipped_entries.push((path, "kept by user".to_string()));
+                continue;
+            }
+
             // Check if protected
             if let Some(reason) = self.is_protected(&path, options) {
                 skipped_entries.push((path, reason));
@@ -630,7 +703,7 @@ impl DeviceCleanupHandler {
                 continue;
             }
 
-            let size_bytes = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+            let size_bytes = metadata.len;
 
             entries.push(CleanupEntry {
                 path,
@@ -662,14 +735,21 @@ impl DeviceCleanupHandler {
                 skipped_entries,
                 verification_passed: None,
                 duration_ms,
+                cancelled: false,
             });
         }
 
         let mut files_deleted = 0;
         let mut bytes_freed = 0u64;
         let mut files_failed = 0;
+        let mut cancelled = false;
 
         for entry in &mut entries {
+            if self.is_cancelled() {
+                warn!("Cleanup of {} cancelled", mount_point.display());
+                cancelled = true;
+                break;
+            }
             if self.delete_entry(entry) {
                 files_deleted += 1;
                 bytes_freed += entry.size_bytes;
@@ -701,6 +781,7 @@ impl DeviceCleanupHandler {
             skipped_entries,
             verification_passed,
             duration_ms,
+            cancelled,
         })
     }
 }
@@ -710,6 +791,8 @@ impl DeviceCleanupHandler {
 mod tests {
     use super::*;
     use crate::device::MockDeviceDetector;
+    use crate::fs::MemoryFileSystem;
+    use std::fs;
     use tempfile::TempDir;
 
     fn setup_test_device() -> TempDir {
@@ -1014,6 +1097,53 @@ mod tests {
         assert!(temp_dir.path().join("track1.mp3").exists());
     }
 
+    #[test]
+    fn test_cleanup_keeps_protected_paths() {
+        let temp_dir = setup_test_device();
+        let handler = DeviceCleanupHandler::new();
+        let options = CleanupOptions::default().with_protected_paths([
+            PathBuf::from("track1.mp3"),
+            PathBuf::from("subfolder/track3.mp3"),
+        ]);
+
+        let result = handler
+            .cleanup_device(temp_dir.path(), &options)
+            .expect("Cleanup should succeed");
+
+        assert!(temp_dir.path().join("track1.mp3").exists());
+        assert!(temp_dir.path().join("subfolder/track3.mp3").exists());
+        assert!(!temp_dir.path().join("track2.mp3").exists());
+        assert!(
+            result
+                .skipped_entries
+                .iter()
+                .any(|(path, reason)| path.ends_with("subfolder") && reason == "kept by user")
+        );
+    }
+
+    #[test]
+    fn test_cancelled_cleanup_stops_deleting() {
+        let temp_dir = setup_test_device();
+        let flag = Arc::new(AtomicBool::new(true));
+        let handler = DeviceCleanupHandler::new().with_cancel_flag(Arc::clone(&flag));
+
+        let result = handler
+            .cleanup_device(temp_dir.path(), &CleanupOptions::default())
+            .expect("Cleanup should succeed");
+
+        assert!(result.cancelled);
+        assert_eq!(result.files_deleted, 0);
+        assert!(result.summary().starts_with("Cancelled"));
+        assert!(temp_dir.path().join("track1.mp3").exists());
+
+        flag.store(false, Ordering::SeqCst);
+        let result = handler
+            .cleanup_device(temp_dir.path(), &CleanupOptions::default())
+            .expect("Cleanup should succeed");
+        assert!(!result.cancelled);
+        assert!(!temp_dir.path().join("track1.mp3").exists());
+    }
+
     #[test]
     fn test_cleanup_result_summary() {
         let result = CleanupResult {
@@ -1028,6 +1158,7 @@ mod tests {
             skipped_entries: Vec::new(),
             verification_passed: Some(true),
             duration_ms: 100,
+            cancelled: false,
         };
 
         let summary = result.summary();
@@ -1050,6 +1181,7 @@ mod tests {
             skipped_entries: Vec::new(),
             verification_passed: None,
             duration_ms: 50,
+            cancelled: false,
         };
 
         let summary = result.summary();
@@ -1071,6 +1203,7 @@ mod tests {
             skipped_entries: Vec::new(),
             verification_passed: Some(true),
             duration_ms: 100,
+            cancelled: false,
         };
         assert!(success_result.is_success());
 
@@ -1225,6 +1358,7 @@ mod tests {
             skipped_entries: vec![(PathBuf::from("/.hidden"), "hidden file".to_string())],
             verification_passed: Some(true),
             duration_ms: 150,
+            cancelled: false,
         };
 
         let json = serde_json::to_string(&result).expect("serialize");
@@ -1475,4 +1609,56 @@ mod tests {
         // Just verify the field exists and result is valid
         assert!(result.files_deleted > 0);
     }
+
+    fn memory_device() -> (DeviceCleanupHandler, MemoryFileSystem) {
+        let fs = MemoryFileSystem::new();
+        fs.add_file("/device/track1.mp3", "fake mp3 data 1");
+        fs.add_file("/device/music/track2.mp3", "fake mp3 data 2");
+        fs.add_file("/device/readme.txt", "readme content");
+        fs.add_file("/device/.hidden", "hidden content");
+        let handler = DeviceCleanupHandler::new().with_file_system(Arc::new(fs.clone()));
+        (handler, fs)
+    }
+
+    #[test]
+    fn test_cleanup_in_memory() {
+        let (handler, fs) = memory_device();
+
+        let result = handler
+            .cleanup_device(Path::new("/device"), &CleanupOptions::full_cleanup())
+            .expect("Cleanup should succeed");
+
+        assert_eq!(result.files_deleted, 3);
+        assert_eq!(result.directories_deleted, 1);
+        assert_eq!(result.verification_passed, Some(true));
+        assert_eq!(fs.list_all_files(), vec![PathBuf::from("/device/.hidden")]);
+    }
+
+    #[test]
+    fn test_cleanup_read_only_device_in_memory() {
+        let (handler, fs) = memory_device();
+        fs.set_readonly("/device", true);
+
+        let result = handler.cleanup_device(Path::new("/device"), &CleanupOptions::full_cleanup());
+        assert!(matches!(
+            result,
+            Err(Error::Device(DeviceError::ReadOnly { .. }))
+        ));
+        assert_eq!(fs.list_all_files().len(), 4);
+    }
+
+    #[test]
+    fn test_cleanup_locked_file_in_memory() {
+        let (handler, fs) = memory_device();
+        fs.set_readonly("/device/music/track2.mp3", true);
+
+        let result = handler
+            .cleanup_device(Path::new("/device"), &CleanupOptions::full_cleanup())
+            .expect("Cleanup should succeed");
+
+        // The locked file stays, and so does its folder
+        assert_eq!(result.files_deleted, 2);
+        assert_eq!(result.files_failed, 2);
+        assert!(fs.is_file(Path::new("/device/music/track2.mp3")));
+    }
 }
@@ -3,6 +3,7 @@
 //! Handles loading, saving, and managing application-wide settings,
 //! including the local storage directory for playlists.
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -11,9 +12,22 @@ use tracing::{debug, info, warn};
 
 use crate::cache::CacheConfig;
 use crate::error::{Error, FileSystemError, Result};
+use crate::history::{HistoryKind, HistoryStatus};
+use crate::i18n::Locale;
+use crate::lyrics::LyricsConfig;
+use crate::notifications::webhook::WebhookConfig;
+use crate::power::PowerPreferences;
 use crate::queue::QueueConfig;
+use crate::schedule::PlaylistSchedule;
+use crate::storage::LibraryLimits;
+use crate::youtube::sponsorblock::SponsorBlockConfig;
+use crate::youtube::template::{DEFAULT_FILENAME_TEMPLATE, FilenameTemplate};
+use crate::youtube::yt_dlp::ExtractionConfig;
 
 /// Download quality setting for `YouTube` downloads.
+///
+/// Downloads fetch the stream whose audio bitrate is closest to the
+/// preset's bitrate and convert it to MP3 at that bitrate.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum DownloadQuality {
@@ -36,6 +50,18 @@ impl std::fmt::Display for DownloadQuality {
     }
 }
 
+impl DownloadQuality {
+    /// MP3 bitrate in kbps.
+    #[must_use]
+    pub const fn bitrate_kbps(self) -> u32 {
+        match self {
+            Self::Low => 128,
+            Self::Medium => 192,
+            Self::High => 320,
+        }
+    }
+}
+
 /// Theme setting for the application.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
@@ -59,6 +85,140 @@ impl std::fmt::Display for Theme {
     }
 }
 
+/// Release channel used for update checks.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    /// Stable releases only (default).
+    #[default]
+    Stable,
+    /// Pre-releases for early testing.
+    Beta,
+}
+
+impl UpdateChannel {
+    /// Update manifest endpoint for this channel.
+    ///
+    /// Stable points at the latest published release; beta at the rolling
+    /// `beta` pre-release.
+    #[must_use]
+    pub const fn endpoint(self) -> &'static str {
+        match self {
+            Self::Stable => {
+                "https://github.com/vincentserpoul/youtun4/releases/latest/download/latest.json"
+            }
+            Self::Beta => {
+                "https://github.com/vincentserpoul/youtun4/releases/download/beta/latest.json"
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for UpdateChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stable => write!(f, "Stable"),
+            Self::Beta => write!(f, "Beta"),
+        }
+    }
+}
+
+/// How the library shows playlists.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PlaylistLayout {
+    /// Cards with details and actions (default).
+    #[default]
+    List,
+    /// Cover art tiles.
+    Grid,
+}
+
+/// Order of the playlists in the library.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaylistSort {
+    /// Alphabetical by name (default).
+    #[default]
+    Name,
+    /// Most recently modified first.
+    RecentlyUpdated,
+    /// Largest first.
+    Size,
+}
+
+/// Layout and order of the playlists in the library.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PlaylistViewPreferences {
+    /// List or grid layout.
+    #[serde(default)]
+    pub layout: PlaylistLayout,
+    /// Sort order.
+    #[serde(default)]
+    pub sort: PlaylistSort,
+}
+
+/// Main view of the app.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UiView {
+    /// Playlist library (default).
+    #[default]
+    Library,
+    /// Choosing playlists to sync.
+    Sync,
+    /// Download and sync history.
+    History,
+    /// Storage overview.
+    Storage,
+}
+
+const fn default_history_range_days() -> Option<u32> {
+    Some(30)
+}
+
+/// History view filters.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HistoryFilterState {
+    /// How many days back to show (everything when `None`).
+    #[serde(default = "default_history_range_days")]
+    pub range_days: Option<u32>,
+    /// Only downloads or only syncs.
+    #[serde(default)]
+    pub kind: Option<HistoryKind>,
+    /// Only entries with this status.
+    #[serde(default)]
+    pub status: Option<HistoryStatus>,
+}
+
+impl Default for HistoryFilterState {
+    fn default() -> Self {
+        Self {
+            range_days: default_history_range_days(),
+            kind: None,
+            status: None,
+        }
+    }
+}
+
+/// Interface state restored on the next start: the open view, selected
+/// playlist, sidebar and filter choices.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UiState {
+    /// View shown at startup.
+    #[serde(default)]
+    pub view: UiView,
+    /// Playlist whose details were open.
+    #[serde(default)]
+    pub selected_playlist: Option<String>,
+    /// Whether the sidebar is collapsed.
+    #[serde(default)]
+    pub sidebar_collapsed: bool,
+    /// History view filters.
+    #[serde(default)]
+    pub history_filter: HistoryFilterState,
+}
+
 /// Notification preferences for the application.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct NotificationPreferences {
@@ -91,37 +251,334 @@ impl Default for NotificationPreferences {
     }
 }
 
+/// Kind of OS notification, matched against [`NotificationPreferences`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// A download finished.
+    DownloadComplete,
+    /// A sync finished.
+    SyncComplete,
+    /// An operation failed.
+    Error,
+    /// A device was connected.
+    DeviceConnected,
+}
+
+impl NotificationPreferences {
+    /// Check whether notifications of the given kind are enabled.
+    #[must_use]
+    pub const fn allows(&self, kind: NotificationKind) -> bool {
+        match kind {
+            NotificationKind::DownloadComplete => self.download_complete,
+            NotificationKind::SyncComplete => self.sync_complete,
+            NotificationKind::Error => self.errors,
+            NotificationKind::DeviceConnected => self.device_connected,
+        }
+    }
+}
+
+/// Default device watcher polling interval in milliseconds.
+pub const DEFAULT_DEVICE_POLL_INTERVAL_MS: u64 = 2000;
+
+/// Default number of videos of a playlist downloaded at the same time.
+pub const DEFAULT_DOWNLOAD_CONCURRENCY: usize = 1;
+
+/// Maximum number of videos of a playlist downloaded at the same time.
+pub const MAX_DOWNLOAD_CONCURRENCY: usize = 8;
+
+/// A top-level section of the configuration, used to report what changed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigSection {
+    /// Playlists storage directory.
+    Storage,
+    /// Download quality, concurrency, speed limit, tagging, lyrics, file
+    /// names, `SponsorBlock` and extraction settings.
+    Downloads,
+    /// Theme.
+    Appearance,
+    /// Display language.
+    Language,
+    /// Notification preferences and the webhook.
+    Notifications,
+    /// Cache configuration.
+    Cache,
+    /// Download queue configuration.
+    Queue,
+    /// Pausing heavy work on battery power or metered networks.
+    Power,
+    /// Offline mode.
+    Network,
+    /// System tray and background operation.
+    Background,
+    /// Update release channel.
+    Updates,
+    /// Crash report submission.
+    CrashReporting,
+    /// Simple mode (parental controls).
+    ParentalControls,
+    /// Preferred device and device watcher settings.
+    Devices,
+    /// Saved profiles and the active profile.
+    Profiles,
+    /// Per-device nicknames and auto-sync settings.
+    DeviceProfiles,
+    /// Per-playlist setting overrides.
+    PlaylistOverrides,
+    /// Scheduled playlist refreshes.
+    RefreshSchedules,
+    /// Layout and order of the playlists in the library.
+    PlaylistView,
+    /// Interface state restored on the next start.
+    UiState,
+}
+
+/// A named set of machine- or user-specific settings (e.g. "Home", "Kids laptop").
+///
+/// Switching profiles swaps these settings in the active configuration while
+/// leaving everything else (theme, notifications, queue) untouched.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConfigProfile {
+    /// Directory where playlists are stored.
+    pub playlists_directory: PathBuf,
+    /// Custom cache directory (platform default when `None`).
+    #[serde(default)]
+    pub cache_directory: Option<PathBuf>,
+    /// Download quality for `YouTube` downloads.
+    #[serde(default)]
+    pub download_quality: DownloadQuality,
+    /// Mount point of the preferred sync device.
+    #[serde(default)]
+    pub preferred_device: Option<PathBuf>,
+}
+
+impl ConfigProfile {
+    /// Capture the profile settings from a configuration.
+    #[must_use]
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            playlists_directory: config.playlists_directory.clone(),
+            cache_directory: config.cache.custom_cache_dir.clone(),
+            download_quality: config.download_quality,
+            preferred_device: config.preferred_device.clone(),
+        }
+    }
+
+    /// Apply the profile settings to a configuration.
+    pub fn apply_to(&self, config: &mut AppConfig) {
+        config
+            .playlists_directory
+            .clone_from(&self.playlists_directory);
+        config
+            .cache
+            .custom_cache_dir
+            .clone_from(&self.cache_directory);
+        config.download_quality = self.download_quality;
+        config.preferred_device.clone_from(&self.preferred_device);
+    }
+}
+
+/// Settings remembered for a single sync device.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeviceProfile {
+    /// Name shown instead of the volume label.
+    #[serde(default)]
+    pub nickname: Option<String>,
+    /// Sync the device's last playlists again whenever it is connected.
+    #[serde(default)]
+    pub auto_sync: bool,
+    /// Do nothing when the device is connected: no banner and no auto-sync.
+    #[serde(default)]
+    pub ignored: bool,
+}
+
+/// Settings that replace the global ones for a single playlist.
+///
+/// Unset fields fall back to the global configuration.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PlaylistOverrides {
+    /// Download quality for this playlist.
+    #[serde(default)]
+    pub download_quality: Option<DownloadQuality>,
+    /// Whether lyrics are fetched for this playlist's downloads.
+    #[serde(default)]
+    pub fetch_lyrics: Option<bool>,
+}
+
 /// Application configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AppConfig {
+    /// Schema version of this configuration (see [`CURRENT_CONFIG_VERSION`]).
+    #[serde(default = "default_config_version")]
+    pub config_version: u32,
     /// Directory where playlists are stored.
     pub playlists_directory: PathBuf,
+    /// Disk usage limits for the playlists library.
+    #[serde(default)]
+    pub library_limits: LibraryLimits,
     /// Download quality for `YouTube` downloads.
     #[serde(default)]
     pub download_quality: DownloadQuality,
+    /// Number of videos of a playlist downloaded at the same time.
+    #[serde(default = "default_download_concurrency")]
+    pub download_concurrency: usize,
+    /// Download speed limit in bytes per second (unlimited when `None`).
+    /// Queued downloads can override it.
+    #[serde(default)]
+    pub max_download_speed_bps: Option<u64>,
+    /// `SponsorBlock` segment removal from downloaded audio.
+    #[serde(default)]
+    pub sponsorblock: SponsorBlockConfig,
+    /// Write ID3 tags (title, artist, album, track number) into downloads.
+    #[serde(default = "default_true")]
+    pub write_tags: bool,
+    /// Embed the video thumbnail as cover art of downloads.
+    #[serde(default = "default_true")]
+    pub embed_cover_art: bool,
+    /// Lyrics lookup after downloads.
+    #[serde(default)]
+    pub lyrics: LyricsConfig,
+    /// Template of downloaded file names, such as
+    /// `{index:02} - {artist} - {title}`. Playlists can override it in
+    /// their download profile.
+    #[serde(default = "default_filename_template")]
+    pub filename_template: String,
+    /// How playlists and videos are read from `YouTube`: built in, with
+    /// `yt-dlp`, or built in with `yt-dlp` as fallback.
+    #[serde(default)]
+    pub extraction: ExtractionConfig,
     /// Theme preference.
     #[serde(default)]
     pub theme: Theme,
+    /// Custom accent color as `#rrggbb` (the brand violet when `None`).
+    #[serde(default)]
+    pub accent_color: Option<String>,
+    /// High-contrast colors for the chosen theme.
+    #[serde(default)]
+    pub high_contrast: bool,
+    /// Display language (the system language when `None`).
+    #[serde(default)]
+    pub language: Option<Locale>,
+    /// Layout and order of the playlists in the library.
+    #[serde(default)]
+    pub playlist_view: PlaylistViewPreferences,
+    /// Interface state restored on the next start.
+    #[serde(default)]
+    pub ui_state: UiState,
     /// Notification preferences.
     #[serde(default)]
     pub notification_preferences: NotificationPreferences,
+    /// Webhook called when a download or sync ends.
+    #[serde(default)]
+    pub webhook: WebhookConfig,
     /// Cache configuration.
     #[serde(default)]
     pub cache: CacheConfig,
     /// Download queue configuration.
     #[serde(default)]
     pub queue: QueueConfig,
+    /// When heavy background work pauses for battery power or metered networks.
+    #[serde(default)]
+    pub power: PowerPreferences,
+    /// Offline mode: skip all network access and serve cached data only.
+    #[serde(default)]
+    pub offline_mode: bool,
+    /// Keep running in the system tray when the main window is closed.
+    #[serde(default)]
+    pub minimize_to_tray: bool,
+    /// Release channel used for update checks.
+    #[serde(default)]
+    pub update_channel: UpdateChannel,
+    /// Endpoint crash reports are submitted to. Crash reports are only sent
+    /// when the user opts in by setting this.
+    #[serde(default)]
+    pub crash_report_endpoint: Option<String>,
+    /// Simple mode: destructive operations need the parent PIN. Only changed
+    /// through the PIN-checked parental commands.
+    #[serde(default)]
+    pub simple_mode: bool,
+    /// Mount point of the preferred sync device.
+    #[serde(default)]
+    pub preferred_device: Option<PathBuf>,
+    /// Polling interval of the device watcher in milliseconds.
+    #[serde(default = "default_device_poll_interval_ms")]
+    pub device_poll_interval_ms: u64,
+    /// Device settings by mount point.
+    #[serde(default)]
+    pub device_profiles: BTreeMap<String, DeviceProfile>,
+    /// Setting overrides by playlist name.
+    #[serde(default)]
+    pub playlist_overrides: BTreeMap<String, PlaylistOverrides>,
+    /// Refresh schedules by playlist name.
+    #[serde(default)]
+    pub refresh_schedules: BTreeMap<String, PlaylistSchedule>,
+    /// Saved configuration profiles by name.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, ConfigProfile>,
+    /// Name of the active profile, if any.
+    #[serde(default)]
+    pub active_profile: Option<String>,
+    /// Whether the first-run setup has been completed.
+    #[serde(default)]
+    pub onboarding_completed: bool,
+}
+
+const fn default_config_version() -> u32 {
+    CURRENT_CONFIG_VERSION
+}
+
+fn default_filename_template() -> String {
+    DEFAULT_FILENAME_TEMPLATE.to_string()
+}
+
+const fn default_device_poll_interval_ms() -> u64 {
+    DEFAULT_DEVICE_POLL_INTERVAL_MS
+}
+
+const fn default_download_concurrency() -> usize {
+    DEFAULT_DOWNLOAD_CONCURRENCY
 }
 
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
+            config_version: CURRENT_CONFIG_VERSION,
             playlists_directory: default_playlists_directory(),
+            library_limits: LibraryLimits::default(),
             download_quality: DownloadQuality::default(),
+            download_concurrency: DEFAULT_DOWNLOAD_CONCURRENCY,
+            max_download_speed_bps: None,
+            sponsorblock: SponsorBlockConfig::default(),
+            write_tags: true,
+            embed_cover_art: true,
+            lyrics: LyricsConfig::default(),
+            filename_template: default_filename_template(),
+            extraction: ExtractionConfig::default(),
             theme: Theme::default(),
+            accent_color: None,
+            high_contrast: false,
+            language: None,
+            playlist_view: PlaylistViewPreferences::default(),
+            ui_state: UiState::default(),
             notification_preferences: NotificationPreferences::default(),
+            webhook: WebhookConfig::default(),
             cache: CacheConfig::default(),
             queue: QueueConfig::default(),
+            power: PowerPreferences::default(),
+            offline_mode: false,
+            minimize_to_tray: false,
+            update_channel: UpdateChannel::default(),
+            crash_report_endpoint: None,
+            simple_mode: false,
+            preferred_device: None,
+            device_poll_interval_ms: DEFAULT_DEVICE_POLL_INTERVAL_MS,
+            device_profiles: BTreeMap::new(),
+            playlist_overrides: BTreeMap::new(),
+            refresh_schedules: BTreeMap::new(),
+            profiles: BTreeMap::new(),
+            active_profile: None,
+            onboarding_completed: false,
         }
     }
 }
@@ -152,9 +609,24 @@ impl AppConfig {
             })
         })?;
 
-        let config: Self = serde_json::from_str(&content)
+        let mut value: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| Error::Configuration(format!("Failed to parse config file: {e}")))?;
+        let migrated = migrate_config(&mut value);
+
+        let config: Self = serde_json::from_value(value)
             .map_err(|e| Error::Configuration(format!("Failed to parse config file: {e}")))?;
 
+        // Persist the upgraded config, keeping the original next to it
+        if migrated {
+            let backup_path = config_path.with_extension("json.bak");
+            if let Err(e) = fs::write(&backup_path, &content) {
+                warn!("Failed to back up config before migration: {}", e);
+            }
+            if let Err(e) = config.save() {
+                warn!("Failed to save migrated config: {}", e);
+            }
+        }
+
         info!("Loaded config from {}", config_path.display());
         debug!(
             "Playlists directory: {}",
@@ -218,6 +690,273 @@ impl AppConfig {
     pub fn config_file_path() -> PathBuf {
         config_file_path()
     }
+
+    /// The display language: the configured one, or the system language.
+    #[must_use]
+    pub fn locale(&self) -> Locale {
+        self.language.unwrap_or_else(Locale::system)
+    }
+
+    /// List the sections that differ between this configuration and `other`.
+    #[must_use]
+    pub fn changed_sections(&self, other: &Self) -> Vec<ConfigSection> {
+        let checks = [
+            (
+                ConfigSection::Storage,
+                self.playlists_directory != other.playlists_directory
+                    || self.library_limits != other.library_limits,
+            ),
+            (
+                ConfigSection::Downloads,
+                self.download_quality != other.download_quality
+                    || self.download_concurrency != other.download_concurrency
+                    || self.max_download_speed_bps != other.max_download_speed_bps
+                    || self.sponsorblock != other.sponsorblock
+                    || self.write_tags != other.write_tags
+                    || self.embed_cover_art != other.embed_cover_art
+                    || self.lyrics != other.lyrics
+                    || self.filename_template != other.filename_template
+                    || self.extraction != other.extraction,
+            ),
+            (
+                ConfigSection::Appearance,
+                self.theme != other.theme
+                    || self.accent_color != other.accent_color
+                    || self.high_contrast != other.high_contrast,
+            ),
+            (ConfigSection::Language, self.language != other.language),
+            (
+                ConfigSection::Notifications,
+                self.notification_preferences != other.notification_preferences
+                    || self.webhook != other.webhook,
+            ),
+            (ConfigSection::Cache, self.cache != other.cache),
+            (ConfigSection::Queue, self.queue != other.queue),
+            (ConfigSection::Power, self.power != other.power),
+            (
+                ConfigSection::Network,
+                self.offline_mode != other.offline_mode,
+            ),
+            (
+                ConfigSection::Background,
+                self.minimize_to_tray != other.minimize_to_tray,
+            ),
+            (
+                ConfigSection::Updates,
+                self.update_channel != other.update_channel,
+            ),
+            (
+                ConfigSection::CrashReporting,
+                self.crash_report_endpoint != other.crash_report_endpoint,
+            ),
+            (
+                ConfigSection::ParentalControls,
+                self.simple_mode != other.simple_mode,
+            ),
+            (
+                ConfigSection::Devices,
+                self.preferred_device != other.preferred_device
+                    || self.device_poll_interval_ms != other.device_poll_interval_ms,
+            ),
+            (
+                ConfigSection::Profiles,
+                self.profiles != other.profiles || self.active_profile != other.active_profile,
+            ),
+            (
+                ConfigSection::DeviceProfiles,
+                self.device_profiles != other.device_profiles,
+            ),
+            (
+                ConfigSection::PlaylistOverrides,
+                self.playlist_overrides != other.playlist_overrides,
+            ),
+            (
+                ConfigSection::RefreshSchedules,
+                self.refresh_schedules != other.refresh_schedules,
+            ),
+            (
+                ConfigSection::PlaylistView,
+                self.playlist_view != other.playlist_view,
+            ),
+            (ConfigSection::UiState, self.ui_state != other.ui_state),
+        ];
+
+        checks
+            .into_iter()
+            .filter_map(|(section, changed)| changed.then_some(section))
+            .collect()
+    }
+
+    /// The settings for the device mounted at `mount_point`.
+    #[must_use]
+    pub fn device_profile(&self, mount_point: &Path) -> DeviceProfile {
+        self.device_profiles
+            .get(&mount_point.to_string_lossy().into_owned())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Store the settings for the device mounted at `mount_point`.
+    ///
+    /// Blank nicknames are dropped, and devices left with default settings
+    /// are forgotten.
+    pub fn set_device_profile(&mut self, mount_point: &Path, mut profile: DeviceProfile) {
+        profile.nickname = profile
+            .nickname
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty());
+        let key = mount_point.to_string_lossy().into_owned();
+        if profile == DeviceProfile::default() {
+            self.device_profiles.remove(&key);
+        } else {
+            self.device_profiles.insert(key, profile);
+        }
+    }
+
+    /// The setting overrides for the playlist named `playlist`.
+    #[must_use]
+    pub fn playlist_overrides(&self, playlist: &str) -> PlaylistOverrides {
+        self.playlist_overrides
+            .get(playlist)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Store the setting overrides for the playlist named `playlist`.
+    ///
+    /// Playlists left without overrides are forgotten.
+    pub fn set_playlist_overrides(&mut self, playlist: &str, overrides: PlaylistOverrides) {
+        if overrides == PlaylistOverrides::default() {
+            self.playlist_overrides.remove(playlist);
+        } else {
+            self.playlist_overrides
+                .insert(playlist.to_string(), overrides);
+        }
+    }
+
+    /// The download quality for `playlist`: its override, or the global
+    /// setting.
+    #[must_use]
+    pub fn download_quality_for(&self, playlist: &str) -> DownloadQuality {
+        self.playlist_overrides(playlist)
+            .download_quality
+            .unwrap_or(self.download_quality)
+    }
+
+    /// Lyrics settings for downloads into `playlist`, or `None` when its
+    /// lyrics aren't fetched.
+    #[must_use]
+    pub fn lyrics_for(&self, playlist: &str) -> Option<LyricsConfig> {
+        self.playlist_overrides(playlist)
+            .fetch_lyrics
+            .unwrap_or(self.lyrics.enabled)
+            .then(|| self.lyrics.clone())
+    }
+
+    /// Set or, with `None`, remove the refresh schedule of the playlist
+    /// named `playlist`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schedule is invalid.
+    pub fn set_refresh_schedule(
+        &mut self,
+        playlist: &str,
+        schedule: Option<PlaylistSchedule>,
+    ) -> Result<()> {
+        match schedule {
+            Some(schedule) => {
+                schedule.schedule.validate()?;
+                self.refresh_schedules
+                    .insert(playlist.to_string(), schedule);
+            }
+            None => {
+                self.refresh_schedules.remove(playlist);
+            }
+        }
+        Ok(())
+    }
+
+    /// Save the current profile settings under the given name.
+    ///
+    /// An existing profile with the same name is overwritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile name is empty.
+    pub fn save_profile(&mut self, name: &str) -> Result<()> {
+        let name = validate_profile_name(name)?;
+        let profile = ConfigProfile::from_config(self);
+        self.profiles.insert(name.to_string(), profile);
+        info!("Saved config profile '{}'", name);
+        Ok(())
+    }
+
+    /// Delete a saved profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile does not exist or is currently active.
+    pub fn delete_profile(&mut self, name: &str) -> Result<()> {
+        if self.active_profile.as_deref() == Some(name) {
+            return Err(Error::Configuration(format!(
+                "Cannot delete the active profile '{name}'"
+            )));
+        }
+        if self.profiles.remove(name).is_none() {
+            return Err(Error::Configuration(format!("Profile not found: {name}")));
+        }
+        info!("Deleted config profile '{}'", name);
+        Ok(())
+    }
+
+    /// Switch to a saved profile.
+    ///
+    /// The current settings are first stored back into the active profile so
+    /// edits made since the last switch are kept. Nothing is modified if the
+    /// target profile is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile does not exist or its playlists
+    /// directory is not usable.
+    pub fn switch_profile(&mut self, name: &str) -> Result<()> {
+        let Some(profile) = self.profiles.get(name).cloned() else {
+            return Err(Error::Configuration(format!("Profile not found: {name}")));
+        };
+
+        validate_storage_directory(&profile.playlists_directory)?;
+
+        if let Some(current) = self.active_profile.clone()
+            && self.profiles.contains_key(&current)
+        {
+            let snapshot = ConfigProfile::from_config(self);
+            self.profiles.insert(current, snapshot);
+        }
+
+        profile.apply_to(self);
+        self.active_profile = Some(name.to_string());
+        info!("Switched to config profile '{}'", name);
+        Ok(())
+    }
+}
+
+/// Validate a profile name, returning it trimmed.
+fn validate_profile_name(name: &str) -> Result<&str> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Error::Configuration(
+            "Profile name cannot be empty".to_string(),
+        ));
+    }
+    Ok(name)
+}
+
+/// Whether `value` is a `#rrggbb` hex color.
+fn is_hex_color(value: &str) -> bool {
+    value
+        .strip_prefix('#')
+        .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
 }
 
 /// Get the default playlists directory.
@@ -229,6 +968,25 @@ pub fn default_playlists_directory() -> PathBuf {
         .join("playlists")
 }
 
+/// Get the default directory archived playlists are moved to.
+#[must_use]
+pub fn default_archive_directory() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("youtun4")
+        .join("archive")
+}
+
+/// Get the default directory deleted playlists and tracks are kept in until
+/// the next session.
+#[must_use]
+pub fn default_trash_directory() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("youtun4")
+        .join("trash")
+}
+
 /// Get the path to the config file.
 fn config_file_path() -> PathBuf {
     dirs::config_dir()
@@ -238,7 +996,7 @@ fn config_file_path() -> PathBuf {
 }
 
 /// Validate that a directory is suitable for storing playlists.
-fn validate_storage_directory(path: &Path) -> Result<()> {
+pub(crate) fn validate_storage_directory(path: &Path) -> Result<()> {
     // Check if path is absolute
     if !path.is_absolute() {
         return Err(Error::Configuration(
@@ -279,45 +1037,591 @@ fn validate_storage_directory(path: &Path) -> Result<()> {
     Ok(())
 }
 
-/// Configuration manager that handles loading and caching config.
-pub struct ConfigManager {
-    config: AppConfig,
-}
-
-impl ConfigManager {
-    /// Create a new config manager, loading config from disk.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the config cannot be loaded.
-    pub fn new() -> Result<Self> {
-        let config = AppConfig::load()?;
-        Ok(Self { config })
+// =============================================================================
+// Migrations
+// =============================================================================
+
+/// Current config schema version.
+///
+/// Bump this and append a step to [`CONFIG_MIGRATIONS`] whenever a change to
+/// [`AppConfig`] would make older files fail to deserialize.
+pub const CURRENT_CONFIG_VERSION: u32 = 2;
+
+/// A single migration step, upgrading a raw config object by one version.
+type ConfigMigration = fn(&mut serde_json::Map<String, serde_json::Value>);
+
+/// Migration steps; the step at index `n` upgrades version `n` to `n + 1`.
+const CONFIG_MIGRATIONS: &[ConfigMigration] = &[migrate_v0_to_v1, migrate_v1_to_v2];
+
+/// Upgrade a raw config value to [`CURRENT_CONFIG_VERSION`].
+///
+/// Files without a `config_version` field are treated as version 0. Files
+/// from a newer version are left untouched so they still load on a best-effort
+/// basis. Returns `true` if any migration was applied.
+pub fn migrate_config(value: &mut serde_json::Value) -> bool {
+    let Some(object) = value.as_object_mut() else {
+        return false;
+    };
+
+    let version = object
+        .get("config_version")
+        .and_then(serde_json::Value::as_u64)
+        .and_then(|v| u32::try_from(v).ok())
+        .unwrap_or(0);
+
+    if version > CURRENT_CONFIG_VERSION {
+        warn!(
+            "Config version {} is newer than supported version {}",
+            version, CURRENT_CONFIG_VERSION
+        );
+        return false;
     }
-
-    /// Get a reference to the current configuration.
-    #[must_use]
-    pub const fn config(&self) -> &AppConfig {
-        &self.config
+    if version == CURRENT_CONFIG_VERSION {
+        return false;
     }
 
-    /// Get the playlists directory.
-    #[must_use]
-    pub fn playlists_directory(&self) -> &Path {
-        &self.config.playlists_directory
+    for (step, migration) in CONFIG_MIGRATIONS.iter().enumerate().skip(version as usize) {
+        debug!("Migrating config from version {} to {}", step, step + 1);
+        migration(object);
     }
+    object.insert(
+        "config_version".to_string(),
+        serde_json::Value::from(CURRENT_CONFIG_VERSION),
+    );
+
+    info!(
+        "Migrated config from version {} to {}",
+        version, CURRENT_CONFIG_VERSION
+    );
+    true
+}
 
-    /// Update the configuration.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the config cannot be saved.
-    pub fn update(&mut self, config: AppConfig) -> Result<()> {
-        // Validate the new playlists directory
-        validate_storage_directory(&config.playlists_directory)?;
+/// Version 0 (unversioned) to 1.
+///
+/// Early builds could write `null` for sections that were added later; drop
+/// them so the defaults apply instead of failing to deserialize.
+fn migrate_v0_to_v1(config: &mut serde_json::Map<String, serde_json::Value>) {
+    config.retain(|key, value| key == "playlists_directory" || !value.is_null());
+}
+
+/// v1 -> v2: configs written before onboarding existed belong to users who
+/// already set the app up, so they skip the first-run wizard.
+fn migrate_v1_to_v2(config: &mut serde_json::Map<String, serde_json::Value>) {
+    config
+        .entry("onboarding_completed")
+        .or_insert(serde_json::Value::Bool(true));
+}
+
+// =============================================================================
+// Export / Import
+// =============================================================================
+
+/// Version of the config export file format.
+pub const CONFIG_EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// Config keys holding paths that only make sense on the exporting machine.
+const MACHINE_SPECIFIC_KEYS: &[&[&str]] = &[
+    &["playlists_directory"],
+    &["cache", "custom_cache_dir"],
+    &["extraction", "yt_dlp_path"],
+    &["preferred_device"],
+    &["device_profiles"],
+    &["profiles"],
+    &["active_profile"],
+];
+
+/// On-disk format of an exported configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigExport {
+    /// Export file format version.
+    pub format_version: u32,
+    /// Timestamp when the export was created (Unix epoch seconds).
+    pub exported_at: u64,
+    /// Whether machine-specific paths are included.
+    pub includes_machine_paths: bool,
+    /// The exported settings (possibly partial when paths are omitted).
+    pub config: serde_json::Value,
+}
+
+/// A single setting that would change when importing a configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConfigChange {
+    /// Dotted path of the setting (e.g. `cache.max_size_bytes`).
+    pub key: String,
+    /// Current value (`null` if not set).
+    pub old_value: serde_json::Value,
+    /// Imported value (`null` if removed).
+    pub new_value: serde_json::Value,
+}
+
+/// Result of importing (or previewing the import of) a configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConfigImportPreview {
+    /// The configuration that results from the import.
+    pub config: AppConfig,
+    /// Settings that differ from the current configuration.
+    pub changes: Vec<ConfigChange>,
+}
+
+impl AppConfig {
+    /// Check that the configuration values are consistent.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error describing the first invalid value.
+    pub fn validate(&self) -> Result<()> {
+        if !self.playlists_directory.is_absolute() {
+            return Err(Error::Configuration(
+                "Storage directory must be an absolute path".to_string(),
+            ));
+        }
+        for (name, value) in [
+            ("cache.cleanup_threshold", self.cache.cleanup_threshold),
+            ("cache.cleanup_target", self.cache.cleanup_target),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(Error::Configuration(format!(
+                    "{name} must be between 0.0 and 1.0, got {value}"
+                )));
+            }
+        }
+        if self.cache.cleanup_target > self.cache.cleanup_threshold {
+            return Err(Error::Configuration(
+                "cache.cleanup_target must not exceed cache.cleanup_threshold".to_string(),
+            ));
+        }
+        if let Some(color) = &self.accent_color
+            && !is_hex_color(color)
+        {
+            return Err(Error::Configuration(format!(
+                "accent_color must be a #rrggbb color, got '{color}'"
+            )));
+        }
+        if self.device_poll_interval_ms == 0 {
+            return Err(Error::Configuration(
+                "device_poll_interval_ms must be greater than 0".to_string(),
+            ));
+        }
+        if !(1..=MAX_DOWNLOAD_CONCURRENCY).contains(&self.download_concurrency) {
+            return Err(Error::Configuration(format!(
+                "download_concurrency must be between 1 and {MAX_DOWNLOAD_CONCURRENCY}, got {}",
+                self.download_concurrency
+            )));
+        }
+        FilenameTemplate::parse(&self.filename_template)?;
+        self.webhook.validate()?;
+        if self.max_download_speed_bps == Some(0) {
+            return Err(Error::Configuration(
+                "max_download_speed_bps must be greater than 0".to_string(),
+            ));
+        }
+        if self.queue.max_concurrent_downloads == 0 {
+            return Err(Error::Configuration(
+                "queue.max_concurrent_downloads must be at least 1".to_string(),
+            ));
+        }
+        for (playlist, schedule) in &self.refresh_schedules {
+            if let Err(Error::Configuration(message)) = schedule.schedule.validate() {
+                return Err(Error::Configuration(format!(
+                    "Refresh schedule of '{playlist}': {message}"
+                )));
+            }
+        }
+        if let Some(active) = &self.active_profile
+            && !self.profiles.contains_key(active)
+        {
+            return Err(Error::Configuration(format!(
+                "Active profile '{active}' is not defined"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Export the configuration to a file.
+    ///
+    /// When `include_machine_paths` is `false`, the playlists directory, cache
+    /// directory, preferred device and profiles are left out so the file can
+    /// be imported on another machine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn export_to(&self, path: &Path, include_machine_paths: bool) -> Result<()> {
+        let mut config = serde_json::to_value(self)?;
+        if !include_machine_paths {
+            for key in MACHINE_SPECIFIC_KEYS {
+                remove_json_path(&mut config, key);
+            }
+        }
+
+        let export = ConfigExport {
+            format_version: CONFIG_EXPORT_FORMAT_VERSION,
+            exported_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            includes_machine_paths: include_machine_paths,
+            config,
+        };
+
+        let content = serde_json::to_string_pretty(&export)?;
+        fs::write(path, content).map_err(|e| {
+            Error::FileSystem(FileSystemError::WriteFailed {
+                path: path.to_path_buf(),
+                reason: format!("Failed to write config export: {e}"),
+            })
+        })?;
+
+        info!("Exported config to {}", path.display());
+        Ok(())
+    }
+
+    /// Read an exported configuration and compute the result of importing it.
+    ///
+    /// Settings missing from the export (such as omitted machine paths) keep
+    /// their current values. Nothing is applied; use the returned config.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is not a valid export, or
+    /// the resulting configuration does not validate.
+    pub fn preview_import(&self, path: &Path) -> Result<ConfigImportPreview> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            Error::FileSystem(FileSystemError::ReadFailed {
+                path: path.to_path_buf(),
+                reason: format!("Failed to read config export: {e}"),
+            })
+        })?;
+
+        let export: ConfigExport = serde_json::from_str(&content)
+            .map_err(|e| Error::Configuration(format!("Invalid config export file: {e}")))?;
+
+        if export.format_version > CONFIG_EXPORT_FORMAT_VERSION {
+            return Err(Error::Configuration(format!(
+                "Config export format version {} is newer than supported version {}",
+                export.format_version, CONFIG_EXPORT_FORMAT_VERSION
+            )));
+        }
+        if !export.config.is_object() {
+            return Err(Error::Configuration(
+                "Config export does not contain a settings object".to_string(),
+            ));
+        }
+
+        let mut imported = export.config;
+        migrate_config(&mut imported);
+
+        let current = serde_json::to_value(self)?;
+        let mut merged = current.clone();
+        merge_json(&mut merged, imported);
+
+        let config: Self = serde_json::from_value(merged.clone())
+            .map_err(|e| Error::Configuration(format!("Invalid settings in config export: {e}")))?;
+        config.validate()?;
+
+        let mut changes = Vec::new();
+        diff_json("", &current, &merged, &mut changes);
+
+        Ok(ConfigImportPreview { config, changes })
+    }
+}
+
+/// Remove the value at the given key path from a JSON object.
+fn remove_json_path(value: &mut serde_json::Value, path: &[&str]) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut current = value;
+    for key in parents {
+        let Some(next) = current.get_mut(*key) else {
+            return;
+        };
+        current = next;
+    }
+    if let Some(object) = current.as_object_mut() {
+        object.remove(*last);
+    }
+}
+
+/// Recursively merge `overlay` into `base`, replacing non-object values.
+fn merge_json(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    match (base, overlay) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_json(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Collect the leaf settings that differ between two JSON values.
+fn diff_json(
+    prefix: &str,
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+    changes: &mut Vec<ConfigChange>,
+) {
+    if let (Some(old_map), Some(new_map)) = (old.as_object(), new.as_object()) {
+        let keys: std::collections::BTreeSet<&String> =
+            old_map.keys().chain(new_map.keys()).collect();
+        for key in keys {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+            let null = serde_json::Value::Null;
+            diff_json(
+                &path,
+                old_map.get(key).unwrap_or(&null),
+                new_map.get(key).unwrap_or(&null),
+                changes,
+            );
+        }
+    } else if old != new {
+        changes.push(ConfigChange {
+            key: prefix.to_string(),
+            old_value: old.clone(),
+            new_value: new.clone(),
+        });
+    }
+}
+
+// =============================================================================
+// Startup Overrides
+// =============================================================================
+
+/// Environment variable overriding the playlists directory.
+pub const ENV_STORAGE_DIR: &str = "YOUTUN4_STORAGE_DIR";
+/// Environment variable overriding offline mode (`1`/`true`/`yes`/`on` or `0`/`false`/`no`/`off`).
+pub const ENV_OFFLINE: &str = "YOUTUN4_OFFLINE";
+/// Environment variable overriding the log level.
+pub const ENV_LOG_LEVEL: &str = "YOUTUN4_LOG_LEVEL";
+
+/// Configuration values overridden at startup from `YOUTUN4_*` environment
+/// variables or command-line flags.
+///
+/// Overrides only affect the running app; they are never written to the
+/// config file unless the user changes the setting explicitly.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigOverrides {
+    /// Playlists directory (`--storage-dir`, `YOUTUN4_STORAGE_DIR`).
+    pub playlists_directory: Option<PathBuf>,
+    /// Offline mode (`--offline`/`--online`, `YOUTUN4_OFFLINE`).
+    pub offline_mode: Option<bool>,
+    /// Log level (`--log-level`, `YOUTUN4_LOG_LEVEL`).
+    pub log_level: Option<String>,
+}
+
+impl ConfigOverrides {
+    /// Read overrides from the process environment.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::from_vars(std::env::vars())
+    }
+
+    /// Read overrides from a set of environment variables.
+    ///
+    /// Unparseable values are ignored with a warning.
+    #[must_use]
+    pub fn from_vars(vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut overrides = Self::default();
+        for (key, value) in vars {
+            match key.as_str() {
+                ENV_STORAGE_DIR if !value.is_empty() => {
+                    overrides.playlists_directory = Some(PathBuf::from(value));
+                }
+                ENV_OFFLINE => match parse_bool(&value) {
+                    Some(offline) => overrides.offline_mode = Some(offline),
+                    None => warn!("Ignoring invalid {} value: {}", ENV_OFFLINE, value),
+                },
+                ENV_LOG_LEVEL if !value.is_empty() => overrides.log_level = Some(value),
+                _ => {}
+            }
+        }
+        overrides
+    }
+
+    /// Parse overrides from command-line arguments (without the program name).
+    ///
+    /// Supported flags: `--storage-dir <path>`, `--offline`, `--online` and
+    /// `--log-level <level>`; `--flag=value` is accepted too. Unknown
+    /// arguments are ignored so platform-specific arguments pass through.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a flag is missing its value.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut overrides = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+                None => (arg, None),
+            };
+            let mut value = |name: &str| {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .filter(|v| !v.is_empty())
+                    .ok_or_else(|| Error::Configuration(format!("Missing value for {name}")))
+            };
+
+            match flag.as_str() {
+                "--storage-dir" => {
+                    overrides.playlists_directory = Some(PathBuf::from(value("--storage-dir")?));
+                }
+                "--log-level" => overrides.log_level = Some(value("--log-level")?),
+                "--offline" => overrides.offline_mode = Some(true),
+                "--online" => overrides.offline_mode = Some(false),
+                _ => debug!("Ignoring unrecognized argument: {}", flag),
+            }
+        }
+
+        Ok(overrides)
+    }
+
+    /// Combine with higher-priority overrides (e.g. CLI flags over env vars).
+    #[must_use]
+    pub fn merge(self, higher: Self) -> Self {
+        Self {
+            playlists_directory: higher.playlists_directory.or(self.playlists_directory),
+            offline_mode: higher.offline_mode.or(self.offline_mode),
+            log_level: higher.log_level.or(self.log_level),
+        }
+    }
+
+    /// Check whether any override is set.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.playlists_directory.is_none()
+            && self.offline_mode.is_none()
+            && self.log_level.is_none()
+    }
+
+    /// Apply the overrides to a configuration.
+    pub fn apply_to(&self, config: &mut AppConfig) {
+        if let Some(dir) = &self.playlists_directory {
+            config.playlists_directory.clone_from(dir);
+        }
+        if let Some(offline) = self.offline_mode {
+            config.offline_mode = offline;
+        }
+    }
+
+    /// Get the version of `config` that should be written to disk.
+    ///
+    /// Settings still holding their override value are reset to the values
+    /// from `original` (the config as loaded from disk).
+    #[must_use]
+    pub fn strip_from(&self, config: &AppConfig, original: &AppConfig) -> AppConfig {
+        let mut persisted = config.clone();
+        if self.playlists_directory.as_ref() == Some(&config.playlists_directory) {
+            persisted
+                .playlists_directory
+                .clone_from(&original.playlists_directory);
+        }
+        if self.offline_mode == Some(config.offline_mode) {
+            persisted.offline_mode = original.offline_mode;
+        }
+        persisted
+    }
+}
+
+/// Parse a boolean flag value from the environment.
+fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// Configuration manager that handles loading and caching config.
+pub struct ConfigManager {
+    config: AppConfig,
+    /// Startup overrides applied on top of the config file.
+    overrides: ConfigOverrides,
+    /// Config as loaded from disk, before overrides.
+    original: AppConfig,
+}
+
+impl ConfigManager {
+    /// Create a new config manager, loading config from disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be loaded.
+    pub fn new() -> Result<Self> {
+        Self::with_overrides(ConfigOverrides::default())
+    }
+
+    /// Create a new config manager with startup overrides applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be loaded or an overridden
+    /// playlists directory is not usable.
+    pub fn with_overrides(overrides: ConfigOverrides) -> Result<Self> {
+        let original = AppConfig::load()?;
+        let mut config = original.clone();
+
+        if let Some(dir) = &overrides.playlists_directory {
+            validate_storage_directory(dir)?;
+        }
+        overrides.apply_to(&mut config);
+        if !overrides.is_empty() {
+            info!("Applied startup config overrides: {:?}", overrides);
+        }
+
+        Ok(Self {
+            config,
+            overrides,
+            original,
+        })
+    }
+
+    /// Get the startup overrides in effect.
+    #[must_use]
+    pub const fn overrides(&self) -> &ConfigOverrides {
+        &self.overrides
+    }
+
+    /// Save a config to disk without the startup overrides.
+    fn persist(&self, config: &AppConfig) -> Result<()> {
+        self.overrides.strip_from(config, &self.original).save()
+    }
+
+    /// Get a reference to the current configuration.
+    #[must_use]
+    pub const fn config(&self) -> &AppConfig {
+        &self.config
+    }
+
+    /// Get the playlists directory.
+    #[must_use]
+    pub fn playlists_directory(&self) -> &Path {
+        &self.config.playlists_directory
+    }
+
+    /// Update the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be saved.
+    pub fn update(&mut self, config: AppConfig) -> Result<()> {
+        // Validate the new playlists directory
+        validate_storage_directory(&config.playlists_directory)?;
 
         self.config = config;
-        self.config.save()?;
+        self.persist(&self.config)?;
         Ok(())
     }
 
@@ -328,7 +1632,161 @@ impl ConfigManager {
     /// Returns an error if the directory is invalid or config cannot be saved.
     pub fn set_playlists_directory(&mut self, path: PathBuf) -> Result<()> {
         self.config.set_playlists_directory(path)?;
-        self.config.save()?;
+        self.persist(&self.config)?;
+        Ok(())
+    }
+
+    /// List the names of saved profiles.
+    #[must_use]
+    pub fn profile_names(&self) -> Vec<String> {
+        self.config.profiles.keys().cloned().collect()
+    }
+
+    /// Save the current settings as a named profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is invalid or the config cannot be saved.
+    pub fn save_profile(&mut self, name: &str) -> Result<()> {
+        self.apply_and_save(|config| config.save_profile(name))
+    }
+
+    /// Delete a saved profile.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile cannot be deleted or the config cannot be saved.
+    pub fn delete_profile(&mut self, name: &str) -> Result<()> {
+        self.apply_and_save(|config| config.delete_profile(name))
+    }
+
+    /// Switch to a saved profile atomically.
+    ///
+    /// Either the whole profile is applied and persisted, or the current
+    /// configuration is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile is invalid or the config cannot be saved.
+    pub fn switch_profile(&mut self, name: &str) -> Result<()> {
+        self.apply_and_save(|config| config.switch_profile(name))
+    }
+
+    /// Store the settings for the device mounted at `mount_point`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be saved.
+    pub fn set_device_profile(&mut self, mount_point: &Path, profile: DeviceProfile) -> Result<()> {
+        self.apply_and_save(|config| {
+            config.set_device_profile(mount_point, profile);
+            Ok(())
+        })
+    }
+
+    /// Store the setting overrides for the playlist named `playlist`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be saved.
+    pub fn set_playlist_overrides(
+        &mut self,
+        playlist: &str,
+        overrides: PlaylistOverrides,
+    ) -> Result<()> {
+        self.apply_and_save(|config| {
+            config.set_playlist_overrides(playlist, overrides);
+            Ok(())
+        })
+    }
+
+    /// Set or, with `None`, remove the refresh schedule of the playlist
+    /// named `playlist`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schedule is invalid or the config cannot be
+    /// saved.
+    pub fn set_refresh_schedule(
+        &mut self,
+        playlist: &str,
+        schedule: Option<PlaylistSchedule>,
+    ) -> Result<()> {
+        self.apply_and_save(|config| config.set_refresh_schedule(playlist, schedule))
+    }
+
+    /// Record that the scheduled refresh of the playlist named `playlist`
+    /// ran at `at` (seconds since the Unix epoch).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be saved.
+    pub fn record_scheduled_refresh(&mut self, playlist: &str, at: u64) -> Result<()> {
+        self.apply_and_save(|config| {
+            if let Some(schedule) = config.refresh_schedules.get_mut(playlist) {
+                schedule.last_run_at = Some(at);
+            }
+            Ok(())
+        })
+    }
+
+    /// Set the layout and order of the playlists in the library.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be saved.
+    pub fn set_playlist_view(&mut self, view: PlaylistViewPreferences) -> Result<()> {
+        self.apply_and_save(|config| {
+            config.playlist_view = view;
+            Ok(())
+        })
+    }
+
+    /// Save the interface state to restore on the next start.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be saved.
+    pub fn set_ui_state(&mut self, ui_state: UiState) -> Result<()> {
+        self.apply_and_save(|config| {
+            config.ui_state = ui_state;
+            Ok(())
+        })
+    }
+
+    /// Finish first-run setup, optionally choosing the playlists directory,
+    /// preferred device and download quality.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory is invalid or the config cannot be saved.
+    pub fn complete_onboarding(
+        &mut self,
+        playlists_directory: Option<PathBuf>,
+        preferred_device: Option<PathBuf>,
+        download_quality: Option<DownloadQuality>,
+    ) -> Result<()> {
+        self.apply_and_save(|config| {
+            if let Some(dir) = playlists_directory {
+                config.set_playlists_directory(dir)?;
+            }
+            if preferred_device.is_some() {
+                config.preferred_device = preferred_device;
+            }
+            if let Some(quality) = download_quality {
+                config.download_quality = quality;
+            }
+            config.onboarding_completed = true;
+            Ok(())
+        })
+    }
+
+    /// Apply a change to a copy of the config and commit it only once saved.
+    fn apply_and_save(&mut self, change: impl FnOnce(&mut AppConfig) -> Result<()>) -> Result<()> {
+        let mut candidate = self.config.clone();
+        change(&mut candidate)?;
+        self.persist(&candidate)?;
+        self.config = candidate;
         Ok(())
     }
 
@@ -339,7 +1797,7 @@ impl ConfigManager {
     /// Returns an error if the config cannot be saved.
     pub fn reset(&mut self) -> Result<()> {
         self.config = AppConfig::default();
-        self.config.save()?;
+        self.persist(&self.config)?;
         Ok(())
     }
 }
@@ -369,6 +1827,19 @@ mod tests {
         assert_eq!(config, deserialized);
     }
 
+    #[test]
+    fn test_notification_preferences_allows() {
+        let prefs = NotificationPreferences {
+            sync_complete: false,
+            ..Default::default()
+        };
+
+        assert!(prefs.allows(NotificationKind::DownloadComplete));
+        assert!(!prefs.allows(NotificationKind::SyncComplete));
+        assert!(prefs.allows(NotificationKind::Error));
+        assert!(prefs.allows(NotificationKind::DeviceConnected));
+    }
+
     #[test]
     fn test_validate_storage_directory_success() {
         let temp_dir = TempDir::new().expect("Should create temp dir");
@@ -530,10 +2001,36 @@ mod tests {
         let json = r#"{"playlists_directory":"/custom/path"}"#;
         let config: AppConfig = serde_json::from_str(json).expect("Should deserialize");
         assert_eq!(config.playlists_directory, PathBuf::from("/custom/path"));
+        assert!(!config.offline_mode);
     }
 
     #[test]
-    fn test_validate_storage_directory_already_exists() {
+    fn test_config_offline_mode_roundtrip() {
+        let json = r#"{"playlists_directory":"/custom/path","offline_mode":true}"#;
+        let config: AppConfig = serde_json::from_str(json).expect("Should deserialize");
+        assert!(config.offline_mode);
+
+        let serialized = serde_json::to_string(&config).expect("Should serialize");
+        assert!(serialized.contains("\"offline_mode\":true"));
+    }
+
+    #[test]
+    fn test_config_update_channel_roundtrip() {
+        let json = r#"{"playlists_directory":"/custom/path"}"#;
+        let config: AppConfig = serde_json::from_str(json).expect("Should deserialize");
+        assert_eq!(config.update_channel, UpdateChannel::Stable);
+
+        let json = r#"{"playlists_directory":"/custom/path","update_channel":"beta"}"#;
+        let config: AppConfig = serde_json::from_str(json).expect("Should deserialize");
+        assert_eq!(config.update_channel, UpdateChannel::Beta);
+        assert_ne!(
+            UpdateChannel::Beta.endpoint(),
+            UpdateChannel::Stable.endpoint()
+        );
+    }
+
+    #[test]
+    fn test_validate_storage_directory_already_exists() {
         let temp_dir = TempDir::new().expect("Should create temp dir");
         // Call twice - second time it already exists
         let result1 = validate_storage_directory(temp_dir.path());
@@ -604,6 +2101,675 @@ mod tests {
         assert!(value.get("playlists_directory").is_some());
     }
 
+    // =============================================================================
+    // Profile Tests
+    // =============================================================================
+
+    #[test]
+    fn test_save_profile_captures_settings() {
+        let mut config = AppConfig {
+            playlists_directory: PathBuf::from("/home/music"),
+            download_quality: DownloadQuality::High,
+            preferred_device: Some(PathBuf::from("/Volumes/PLAYER")),
+            ..Default::default()
+        };
+        config.cache.custom_cache_dir = Some(PathBuf::from("/home/cache"));
+
+        config.save_profile("  Home  ").expect("save");
+
+        let profile = config.profiles.get("Home").expect("profile saved");
+        assert_eq!(profile.playlists_directory, PathBuf::from("/home/music"));
+        assert_eq!(profile.cache_directory, Some(PathBuf::from("/home/cache")));
+        assert_eq!(profile.download_quality, DownloadQuality::High);
+        assert_eq!(
+            profile.preferred_device,
+            Some(PathBuf::from("/Volumes/PLAYER"))
+        );
+    }
+
+    #[test]
+    fn test_save_profile_empty_name_fails() {
+        let mut config = AppConfig::default();
+        assert!(config.save_profile("   ").is_err());
+        assert!(config.profiles.is_empty());
+    }
+
+    #[test]
+    fn test_switch_profile_swaps_settings() {
+        let home_dir = TempDir::new().expect("Should create temp dir");
+        let kids_dir = TempDir::new().expect("Should create temp dir");
+
+        let mut config = AppConfig {
+            playlists_directory: home_dir.path().to_path_buf(),
+            download_quality: DownloadQuality::High,
+            theme: Theme::Light,
+            ..Default::default()
+        };
+        config.save_profile("Home").expect("save");
+        config.active_profile = Some("Home".to_string());
+        config.profiles.insert(
+            "Kids laptop".to_string(),
+            ConfigProfile {
+                playlists_directory: kids_dir.path().to_path_buf(),
+                cache_directory: Some(kids_dir.path().join("cache")),
+                download_quality: DownloadQuality::Low,
+                preferred_device: None,
+            },
+        );
+
+        // Edits made while "Home" is active are kept when switching away
+        config.download_quality = DownloadQuality::Medium;
+        config.switch_profile("Kids laptop").expect("switch");
+
+        assert_eq!(config.active_profile.as_deref(), Some("Kids laptop"));
+        assert_eq!(config.playlists_directory, kids_dir.path().to_path_buf());
+        assert_eq!(
+            config.cache.custom_cache_dir,
+            Some(kids_dir.path().join("cache"))
+        );
+        assert_eq!(config.download_quality, DownloadQuality::Low);
+        assert_eq!(config.theme, Theme::Light);
+        assert_eq!(
+            config.profiles["Home"].download_quality,
+            DownloadQuality::Medium
+        );
+
+        config.switch_profile("Home").expect("switch back");
+        assert_eq!(config.playlists_directory, home_dir.path().to_path_buf());
+        assert_eq!(config.download_quality, DownloadQuality::Medium);
+    }
+
+    #[test]
+    fn test_switch_profile_invalid_leaves_config_unchanged() {
+        let mut config = AppConfig::default();
+        config.profiles.insert(
+            "Broken".to_string(),
+            ConfigProfile {
+                playlists_directory: PathBuf::from("relative/path"),
+                cache_directory: None,
+                download_quality: DownloadQuality::High,
+                preferred_device: None,
+            },
+        );
+        let before = config.clone();
+
+        assert!(config.switch_profile("Broken").is_err());
+        assert!(config.switch_profile("Missing").is_err());
+        assert_eq!(config, before);
+    }
+
+    #[test]
+    fn test_delete_profile() {
+        let mut config = AppConfig::default();
+        config.save_profile("Home").expect("save");
+        config.save_profile("Travel").expect("save");
+        config.active_profile = Some("Home".to_string());
+
+        assert!(config.delete_profile("Home").is_err());
+        assert!(config.delete_profile("Missing").is_err());
+        config.delete_profile("Travel").expect("delete");
+        assert_eq!(config.profiles.len(), 1);
+    }
+
+    #[test]
+    fn test_config_without_profiles_deserializes() {
+        let json = r#"{"playlists_directory":"/custom/path"}"#;
+        let config: AppConfig = serde_json::from_str(json).expect("Should deserialize");
+        assert!(config.profiles.is_empty());
+        assert!(config.active_profile.is_none());
+        assert!(config.preferred_device.is_none());
+    }
+
+    #[test]
+    fn test_changed_sections() {
+        let old = AppConfig::default();
+        assert!(old.changed_sections(&old.clone()).is_empty());
+
+        let mut new = old.clone();
+        new.theme = Theme::Light;
+        new.cache.max_size_bytes /= 2;
+        new.device_poll_interval_ms = 5000;
+        new.offline_mode = true;
+
+        assert_eq!(
+            old.changed_sections(&new),
+            vec![
+                ConfigSection::Appearance,
+                ConfigSection::Cache,
+                ConfigSection::Network,
+                ConfigSection::Devices,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_device_profiles() {
+        let mut config = AppConfig::default();
+        let mount = Path::new("/media/player");
+        assert_eq!(config.device_profile(mount), DeviceProfile::default());
+
+        config.set_device_profile(
+            mount,
+            DeviceProfile {
+                nickname: Some("  Kids player ".to_string()),
+                auto_sync: true,
+                ignored: true,
+            },
+        );
+        let profile = config.device_profile(mount);
+        assert_eq!(profile.nickname.as_deref(), Some("Kids player"));
+        assert!(profile.auto_sync);
+        assert!(profile.ignored);
+        assert_eq!(
+            AppConfig::default().changed_sections(&config),
+            vec![ConfigSection::DeviceProfiles]
+        );
+
+        // A blank nickname without auto-sync is the default, so it's forgotten
+        config.set_device_profile(
+            mount,
+            DeviceProfile {
+                nickname: Some("  ".to_string()),
+                auto_sync: false,
+                ignored: false,
+            },
+        );
+        assert!(config.device_profiles.is_empty());
+    }
+
+    #[test]
+    fn test_playlist_overrides() {
+        let mut config = AppConfig::default();
+        assert_eq!(
+            config.download_quality_for("Chill"),
+            DownloadQuality::Medium
+        );
+
+        config.set_playlist_overrides(
+            "Chill",
+            PlaylistOverrides {
+                download_quality: Some(DownloadQuality::High),
+                fetch_lyrics: Some(true),
+            },
+        );
+        assert_eq!(config.download_quality_for("Chill"), DownloadQuality::High);
+        assert!(config.lyrics_for("Chill").is_some());
+        assert!(config.lyrics_for("Road Trip").is_none());
+        assert_eq!(
+            config.download_quality_for("Road Trip"),
+            DownloadQuality::Medium
+        );
+        assert_eq!(
+            AppConfig::default().changed_sections(&config),
+            vec![ConfigSection::PlaylistOverrides]
+        );
+
+        // Clearing every override forgets the playlist
+        config.set_playlist_overrides("Chill", PlaylistOverrides::default());
+        assert!(config.playlist_overrides.is_empty());
+    }
+
+    #[test]
+    fn test_refresh_schedules() {
+        use crate::schedule::RefreshSchedule;
+
+        let mut config = AppConfig::default();
+        let schedule = PlaylistSchedule::new(RefreshSchedule::Interval { hours: 24 }, true, 100);
+        config
+            .set_refresh_schedule("Podcasts", Some(schedule))
+            .unwrap();
+        assert_eq!(config.refresh_schedules["Podcasts"], schedule);
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            AppConfig::default().changed_sections(&config),
+            vec![ConfigSection::RefreshSchedules]
+        );
+
+        let invalid = PlaylistSchedule::new(RefreshSchedule::Interval { hours: 0 }, false, 100);
+        assert!(config.set_refresh_schedule("Mix", Some(invalid)).is_err());
+        assert!(!config.refresh_schedules.contains_key("Mix"));
+
+        config.set_refresh_schedule("Podcasts", None).unwrap();
+        assert!(config.refresh_schedules.is_empty());
+    }
+
+    #[test]
+    fn test_playlist_view_preferences() {
+        let config: AppConfig = serde_json::from_str(r#"{"playlists_directory": "/music"}"#)
+            .expect("parse config without playlist_view");
+        assert_eq!(config.playlist_view, PlaylistViewPreferences::default());
+
+        let changed = AppConfig {
+            playlist_view: PlaylistViewPreferences {
+                layout: PlaylistLayout::Grid,
+                sort: PlaylistSort::RecentlyUpdated,
+            },
+            ..AppConfig::default()
+        };
+        assert_eq!(
+            AppConfig::default().changed_sections(&changed),
+            vec![ConfigSection::PlaylistView]
+        );
+        let json = serde_json::to_value(changed.playlist_view).expect("serialize");
+        assert_eq!(
+            json,
+            serde_json::json!({"layout": "grid", "sort": "recently_updated"})
+        );
+    }
+
+    #[test]
+    fn test_sponsorblock_config() {
+        let config: AppConfig = serde_json::from_str(r#"{"playlists_directory": "/music"}"#)
+            .expect("parse config without sponsorblock");
+        assert_eq!(config.sponsorblock, SponsorBlockConfig::default());
+        assert!(!config.sponsorblock.enabled);
+
+        let mut changed = AppConfig::default();
+        changed.sponsorblock.enabled = true;
+        assert_eq!(
+            AppConfig::default().changed_sections(&changed),
+            vec![ConfigSection::Downloads]
+        );
+    }
+
+    #[test]
+    fn test_ui_state() {
+        let config: AppConfig = serde_json::from_str(r#"{"playlists_directory": "/music"}"#)
+            .expect("parse config without ui_state");
+        assert_eq!(config.ui_state, UiState::default());
+        assert_eq!(config.ui_state.history_filter.range_days, Some(30));
+
+        // "All time" is stored as null and must not fall back to the default
+        let ui_state: UiState = serde_json::from_str(
+            r#"{"view": "history", "history_filter": {"range_days": null, "kind": "sync"}}"#,
+        )
+        .expect("parse ui_state");
+        assert_eq!(ui_state.view, UiView::History);
+        assert_eq!(ui_state.history_filter.range_days, None);
+        assert_eq!(ui_state.history_filter.kind, Some(HistoryKind::Sync));
+
+        let changed = AppConfig {
+            ui_state,
+            ..AppConfig::default()
+        };
+        assert_eq!(
+            AppConfig::default().changed_sections(&changed),
+            vec![ConfigSection::UiState]
+        );
+    }
+
+    #[test]
+    fn test_config_section_serializes_snake_case() {
+        let json = serde_json::to_string(&ConfigSection::Notifications).expect("serialize");
+        assert_eq!(json, "\"notifications\"");
+    }
+
+    // =============================================================================
+    // Override Tests
+    // =============================================================================
+
+    fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|a| (*a).to_string()).collect()
+    }
+
+    #[test]
+    fn test_overrides_from_vars() {
+        let overrides = ConfigOverrides::from_vars(vars(&[
+            (ENV_STORAGE_DIR, "/srv/music"),
+            (ENV_OFFLINE, "yes"),
+            (ENV_LOG_LEVEL, "debug"),
+            ("HOME", "/root"),
+        ]));
+
+        assert_eq!(
+            overrides.playlists_directory,
+            Some(PathBuf::from("/srv/music"))
+        );
+        assert_eq!(overrides.offline_mode, Some(true));
+        assert_eq!(overrides.log_level.as_deref(), Some("debug"));
+    }
+
+    #[test]
+    fn test_overrides_from_vars_ignores_invalid() {
+        let overrides =
+            ConfigOverrides::from_vars(vars(&[(ENV_OFFLINE, "maybe"), (ENV_STORAGE_DIR, "")]));
+        assert!(overrides.is_empty());
+    }
+
+    #[test]
+    fn test_overrides_from_args() {
+        let overrides = ConfigOverrides::from_args(args(&[
+            "--storage-dir",
+            "/srv/music",
+            "--log-level=trace",
+            "--offline",
+            "-psn_0_12345",
+        ]))
+        .expect("parse");
+
+        assert_eq!(
+            overrides.playlists_directory,
+            Some(PathBuf::from("/srv/music"))
+        );
+        assert_eq!(overrides.log_level.as_deref(), Some("trace"));
+        assert_eq!(overrides.offline_mode, Some(true));
+
+        assert!(ConfigOverrides::from_args(args(&["--storage-dir"])).is_err());
+        assert!(ConfigOverrides::from_args(args(&["--log-level="])).is_err());
+    }
+
+    #[test]
+    fn test_overrides_merge_prefers_higher() {
+        let env = ConfigOverrides::from_vars(vars(&[
+            (ENV_STORAGE_DIR, "/env/music"),
+            (ENV_OFFLINE, "1"),
+        ]));
+        let cli = ConfigOverrides::from_args(args(&["--online"])).expect("parse");
+
+        let merged = env.merge(cli);
+        assert_eq!(
+            merged.playlists_directory,
+            Some(PathBuf::from("/env/music"))
+        );
+        assert_eq!(merged.offline_mode, Some(false));
+    }
+
+    #[test]
+    fn test_overrides_not_persisted() {
+        let original = AppConfig {
+            playlists_directory: PathBuf::from("/home/music"),
+            ..Default::default()
+        };
+        let overrides = ConfigOverrides {
+            playlists_directory: Some(PathBuf::from("/srv/music")),
+            offline_mode: Some(true),
+            log_level: None,
+        };
+        let mut effective = original.clone();
+        overrides.apply_to(&mut effective);
+        assert_eq!(effective.playlists_directory, PathBuf::from("/srv/music"));
+        assert!(effective.offline_mode);
+
+        // Unrelated edits are saved, overridden values are not
+        effective.theme = Theme::Light;
+        let persisted = overrides.strip_from(&effective, &original);
+        assert_eq!(persisted.playlists_directory, PathBuf::from("/home/music"));
+        assert!(!persisted.offline_mode);
+        assert_eq!(persisted.theme, Theme::Light);
+
+        // An explicit change away from the override value is saved
+        effective.offline_mode = false;
+        effective.playlists_directory = PathBuf::from("/other/music");
+        let persisted = overrides.strip_from(&effective, &original);
+        assert_eq!(persisted.playlists_directory, PathBuf::from("/other/music"));
+        assert!(!persisted.offline_mode);
+    }
+
+    // =============================================================================
+    // Migration Tests
+    // =============================================================================
+
+    #[test]
+    fn test_migrate_unversioned_config() {
+        let mut value = serde_json::json!({
+            "playlists_directory": "/custom/path",
+            "cache": null,
+            "queue": null,
+            "theme": "light"
+        });
+
+        assert!(migrate_config(&mut value));
+        assert_eq!(value["config_version"], CURRENT_CONFIG_VERSION);
+
+        let config: AppConfig = serde_json::from_value(value).expect("Should deserialize");
+        assert_eq!(config.playlists_directory, PathBuf::from("/custom/path"));
+        assert_eq!(config.theme, Theme::Light);
+        assert_eq!(config.cache, CacheConfig::default());
+    }
+
+    #[test]
+    fn test_migrate_v1_marks_onboarding_completed() {
+        let mut value = serde_json::json!({
+            "config_version": 1,
+            "playlists_directory": "/custom/path"
+        });
+
+        assert!(migrate_config(&mut value));
+        let config: AppConfig = serde_json::from_value(value).expect("Should deserialize");
+        assert!(config.onboarding_completed);
+        assert!(!AppConfig::default().onboarding_completed);
+    }
+
+    #[test]
+    fn test_migrate_current_config_is_noop() {
+        let mut value = serde_json::to_value(AppConfig::default()).expect("serialize");
+        let before = value.clone();
+
+        assert!(!migrate_config(&mut value));
+        assert_eq!(value, before);
+    }
+
+    #[test]
+    fn test_migrate_newer_config_left_untouched() {
+        let mut value = serde_json::json!({
+            "config_version": CURRENT_CONFIG_VERSION + 1,
+            "playlists_directory": "/custom/path",
+            "cache": null
+        });
+        let before = value.clone();
+
+        assert!(!migrate_config(&mut value));
+        assert_eq!(value, before);
+    }
+
+    #[test]
+    fn test_missing_config_version_defaults_to_current() {
+        let json = r#"{"playlists_directory":"/custom/path"}"#;
+        let config: AppConfig = serde_json::from_str(json).expect("Should deserialize");
+        assert_eq!(config.config_version, CURRENT_CONFIG_VERSION);
+        assert_eq!(CONFIG_MIGRATIONS.len(), CURRENT_CONFIG_VERSION as usize);
+    }
+
+    // =============================================================================
+    // Export / Import Tests
+    // =============================================================================
+
+    #[test]
+    fn test_export_import_roundtrip_without_changes() {
+        let temp_dir = TempDir::new().expect("Should create temp dir");
+        let export_path = temp_dir.path().join("export.json");
+        let config = AppConfig {
+            playlists_directory: temp_dir.path().to_path_buf(),
+            download_quality: DownloadQuality::High,
+            ..Default::default()
+        };
+
+        config.export_to(&export_path, true).expect("export");
+        let preview = config.preview_import(&export_path).expect("preview");
+
+        assert_eq!(preview.config, config);
+        assert!(preview.changes.is_empty());
+    }
+
+    #[test]
+    fn test_export_without_machine_paths_keeps_local_paths() {
+        let temp_dir = TempDir::new().expect("Should create temp dir");
+        let export_path = temp_dir.path().join("export.json");
+        let source = AppConfig {
+            playlists_directory: PathBuf::from("/other/machine/playlists"),
+            download_quality: DownloadQuality::Low,
+            theme: Theme::Light,
+            ..Default::default()
+        };
+        source.export_to(&export_path, false).expect("export");
+
+        let content = fs::read_to_string(&export_path).expect("read");
+        assert!(!content.contains("/other/machine/playlists"));
+
+        let local = AppConfig {
+            playlists_directory: temp_dir.path().to_path_buf(),
+            ..Default::default()
+        };
+        let preview = local.preview_import(&export_path).expect("preview");
+
+        assert_eq!(preview.config.playlists_directory, temp_dir.path());
+        assert_eq!(preview.config.download_quality, DownloadQuality::Low);
+        assert_eq!(preview.config.theme, Theme::Light);
+
+        let keys: Vec<&str> = preview.changes.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["download_quality", "theme"]);
+        assert_eq!(preview.changes[0].old_value, serde_json::json!("medium"));
+        assert_eq!(preview.changes[0].new_value, serde_json::json!("low"));
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_settings() {
+        let temp_dir = TempDir::new().expect("Should create temp dir");
+        let export_path = temp_dir.path().join("export.json");
+        let config = AppConfig {
+            playlists_directory: temp_dir.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        let invalid = serde_json::json!({
+            "format_version": CONFIG_EXPORT_FORMAT_VERSION,
+            "exported_at": 0,
+            "includes_machine_paths": false,
+            "config": { "cache": { "cleanup_threshold": 2.5 } }
+        });
+        fs::write(&export_path, invalid.to_string()).expect("write");
+        let err = config.preview_import(&export_path).unwrap_err();
+        assert!(err.to_string().contains("cleanup_threshold"));
+
+        let wrong_type = serde_json::json!({
+            "format_version": CONFIG_EXPORT_FORMAT_VERSION,
+            "exported_at": 0,
+            "includes_machine_paths": false,
+            "config": { "download_quality": "ultra" }
+        });
+        fs::write(&export_path, wrong_type.to_string()).expect("write");
+        assert!(config.preview_import(&export_path).is_err());
+
+        fs::write(&export_path, r#"{"playlists_directory":"/x"}"#).expect("write");
+        assert!(config.preview_import(&export_path).is_err());
+    }
+
+    #[test]
+    fn test_import_rejects_newer_format_version() {
+        let temp_dir = TempDir::new().expect("Should create temp dir");
+        let export_path = temp_dir.path().join("export.json");
+        let future = serde_json::json!({
+            "format_version": CONFIG_EXPORT_FORMAT_VERSION + 1,
+            "exported_at": 0,
+            "includes_machine_paths": true,
+            "config": {}
+        });
+        fs::write(&export_path, future.to_string()).expect("write");
+
+        let err = AppConfig::default()
+            .preview_import(&export_path)
+            .unwrap_err();
+        assert!(err.to_string().contains("newer"));
+    }
+
+    #[test]
+    fn test_validate_default_config() {
+        assert!(AppConfig::default().validate().is_ok());
+
+        let config = AppConfig {
+            active_profile: Some("Missing".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_download_concurrency() {
+        let config = AppConfig {
+            download_concurrency: 4,
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            AppConfig::default().changed_sections(&config),
+            vec![ConfigSection::Downloads]
+        );
+
+        for invalid in [0, MAX_DOWNLOAD_CONCURRENCY + 1] {
+            let config = AppConfig {
+                download_concurrency: invalid,
+                ..Default::default()
+            };
+            assert!(config.validate().is_err(), "{invalid} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_validate_max_download_speed() {
+        let config = AppConfig {
+            max_download_speed_bps: Some(1_000_000),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            AppConfig::default().changed_sections(&config),
+            vec![ConfigSection::Downloads]
+        );
+
+        let config = AppConfig {
+            max_download_speed_bps: Some(0),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_filename_template() {
+        let config = AppConfig {
+            filename_template: "{index:02} - {artist} - {title}".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            AppConfig::default().changed_sections(&config),
+            vec![ConfigSection::Downloads]
+        );
+
+        let config = AppConfig {
+            filename_template: "{album}".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_accent_color() {
+        let config = AppConfig {
+            accent_color: Some("#22D3ee".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            AppConfig::default().changed_sections(&config),
+            vec![ConfigSection::Appearance]
+        );
+
+        for invalid in ["22d3ee", "#22d3e", "#22d3eeff", "#gggggg"] {
+            let config = AppConfig {
+                accent_color: Some(invalid.to_string()),
+                ..Default::default()
+            };
+            assert!(config.validate().is_err(), "{invalid} should be rejected");
+        }
+    }
+
     #[test]
     fn test_validate_error_message_for_relative_path() {
         let result = validate_storage_directory(Path::new("./relative"));
@@ -0,0 +1,276 @@
+//! Crash reports from panics.
+//!
+//! A panic hook writes a report for the panic next to the config file before
+//! the application goes down, so the next launch can tell the user it closed
+//! unexpectedly. Reports stay on disk until dismissed and are only sent
+//! anywhere if the user configured a crash report endpoint.
+
+use std::any::Any;
+use std::fs;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, error, info, warn};
+
+use crate::config::AppConfig;
+use crate::error::{Error, Result};
+
+/// File name of the last crash report, stored next to the config file.
+pub const CRASH_REPORT_FILE: &str = "last_crash.json";
+
+/// Timeout for submitting a crash report.
+const SUBMIT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Where a crash happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrashSource {
+    /// The native application process.
+    Native,
+    /// The web frontend.
+    Frontend,
+}
+
+/// A persisted report of a panic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrashReport {
+    /// Where the panic happened.
+    pub source: CrashSource,
+    /// Panic message.
+    pub message: String,
+    /// Source location of the panic, if known.
+    pub location: Option<String>,
+    /// Name of the panicking thread, if known.
+    pub thread: Option<String>,
+    /// Backtrace or stack trace, if captured.
+    pub backtrace: Option<String>,
+    /// Application version that crashed.
+    pub app_version: String,
+    /// Operating system.
+    pub os: String,
+    /// When the crash happened (Unix timestamp in seconds).
+    pub timestamp: u64,
+    /// Whether the report was sent to the crash report endpoint.
+    #[serde(default)]
+    pub submitted: bool,
+}
+
+impl CrashReport {
+    /// Create a report for a crash happening now.
+    #[must_use]
+    pub fn new(source: CrashSource, message: impl Into<String>, location: Option<String>) -> Self {
+        Self {
+            source,
+            message: message.into(),
+            location,
+            thread: None,
+            backtrace: None,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: std::env::consts::OS.to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            submitted: false,
+        }
+    }
+
+    /// Create a report from a native panic.
+    #[must_use]
+    pub fn from_panic(info: &PanicHookInfo<'_>) -> Self {
+        let mut report = Self::new(
+            CrashSource::Native,
+            panic_message(info.payload()),
+            info.location().map(ToString::to_string),
+        );
+        report.thread = std::thread::current().name().map(String::from);
+        report.backtrace = Some(std::backtrace::Backtrace::force_capture().to_string());
+        report
+    }
+}
+
+/// Extract the message of a panic payload.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| (*message).to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Unknown panic".to_string())
+}
+
+/// Stores the last crash report on disk.
+#[derive(Debug, Clone)]
+pub struct CrashReporter {
+    path: PathBuf,
+}
+
+impl CrashReporter {
+    /// Create a reporter storing its report at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Create a reporter in its default location next to the config file.
+    #[must_use]
+    pub fn open_default() -> Self {
+        let config_path = AppConfig::config_file_path();
+        let dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+        Self::new(dir.join(CRASH_REPORT_FILE))
+    }
+
+    /// Path of the crash report file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Persist a crash report, replacing any previous one.
+    pub fn save(&self, report: &CrashReport) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(report)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    /// The last crash report, if one has not been dismissed.
+    #[must_use]
+    pub fn last_report(&self) -> Option<CrashReport> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+            Err(e) => {
+                warn!("Failed to read crash report {}: {}", self.path.display(), e);
+                return None;
+            }
+        };
+        match serde_json::from_str(&contents) {
+            Ok(report) => Some(report),
+            Err(e) => {
+                warn!(
+                    "Ignoring corrupt crash report {}: {}",
+                    self.path.display(),
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    /// Delete the last crash report. Returns `false` if there was none.
+    pub fn dismiss(&self) -> Result<bool> {
+        match fs::remove_file(&self.path) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Install a panic hook that saves a report before running the
+    /// previously installed hook.
+    pub fn install_panic_hook(&self) {
+        let reporter = self.clone();
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let report = CrashReport::from_panic(info);
+            error!(
+                "Panic at {}: {}",
+                report.location.as_deref().unwrap_or("unknown location"),
+                report.message
+            );
+            if let Err(e) = reporter.save(&report) {
+                eprintln!("Failed to save crash report: {e}");
+            }
+            previous(info);
+        }));
+        debug!(
+            "Panic hook installed, reports go to {}",
+            self.path.display()
+        );
+    }
+
+    /// Send the last crash report to `endpoint` if it hasn't been sent yet.
+    ///
+    /// Returns `true` if a report was sent.
+    pub fn submit_pending(&self, endpoint: &str) -> Result<bool> {
+        let Some(mut report) = self.last_report().filter(|report| !report.submitted) else {
+            return Ok(false);
+        };
+
+        let client = reqwest::blocking::Client::builder()
+            .timeout(SUBMIT_TIMEOUT)
+            .build()
+            .map_err(|e| Error::network_error(format!("Failed to create HTTP client: {e}")))?;
+        client
+            .post(endpoint)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&report)?)
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .map_err(|e| Error::network_error(format!("Failed to submit crash report: {e}")))?;
+
+        report.submitted = true;
+        self.save(&report)?;
+        info!("Crash report submitted");
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_save_load_dismiss() {
+        let temp = TempDir::new().unwrap();
+        let reporter = CrashReporter::new(temp.path().join("nested").join(CRASH_REPORT_FILE));
+        assert!(reporter.last_report().is_none());
+        assert!(!reporter.dismiss().unwrap());
+
+        let report = CrashReport::new(
+            CrashSource::Frontend,
+            "index out of bounds",
+            Some("src/app.rs:10:5".to_string()),
+        );
+        reporter.save(&report).unwrap();
+        assert_eq!(reporter.last_report(), Some(report));
+
+        assert!(reporter.dismiss().unwrap());
+        assert!(reporter.last_report().is_none());
+    }
+
+    #[test]
+    fn test_corrupt_report_is_ignored() {
+        let temp = TempDir::new().unwrap();
+        let reporter = CrashReporter::new(temp.path().join(CRASH_REPORT_FILE));
+        fs::write(reporter.path(), "{not json").unwrap();
+        assert!(reporter.last_report().is_none());
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let payload: Box<dyn Any + Send> = Box::new("static message");
+        assert_eq!(panic_message(payload.as_ref()), "static message");
+        let payload: Box<dyn Any + Send> = Box::new(format!("formatted {}", 42));
+        assert_eq!(panic_message(payload.as_ref()), "formatted 42");
+        let payload: Box<dyn Any + Send> = Box::new(42);
+        assert_eq!(panic_message(payload.as_ref()), "Unknown panic");
+    }
+
+    #[test]
+    fn test_submitted_report_is_not_resent() {
+        let temp = TempDir::new().unwrap();
+        let reporter = CrashReporter::new(temp.path().join(CRASH_REPORT_FILE));
+        let mut report = CrashReport::new(CrashSource::Native, "boom", None);
+        report.submitted = true;
+        reporter.save(&report).unwrap();
+
+        // Already submitted, so the (unreachable) endpoint is never contacted
+        assert!(!reporter.submit_pending("http://127.0.0.1:9/crash").unwrap());
+    }
+}
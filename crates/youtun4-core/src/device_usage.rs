@@ -0,0 +1,260 @@
+//! Device usage breakdown and last-sync records.
+//!
+//! Each sync leaves a small hidden record at the root of the device. Hidden
+//! files survive cleanup, so the record stays with the device and shows the
+//! last sync whichever machine it was made from.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+use walkdir::WalkDir;
+
+use crate::cleanup::{CleanupOptions, DeviceCleanupHandler};
+use crate::device::DeviceInfo;
+use crate::error::{DeviceError, Error, FileSystemError, Result};
+use crate::playlist::is_audio_file;
+
+/// File name of the last-sync record at the root of a synced device.
+pub const SYNC_RECORD_FILE: &str = ".youtun4-sync.json";
+
+/// What the last sync to a device did.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceSyncRecord {
+    /// When the sync finished (seconds since the Unix epoch).
+    pub synced_at: u64,
+    /// Playlists that were synced, in order.
+    pub playlists: Vec<String>,
+    /// Files copied to the device.
+    pub files_transferred: usize,
+    /// Files that failed to copy.
+    pub files_failed: usize,
+    /// Bytes copied to the device.
+    pub bytes_transferred: u64,
+}
+
+impl DeviceSyncRecord {
+    /// Create a record of a sync that just finished.
+    #[must_use]
+    pub fn new(
+        playlists: Vec<String>,
+        files_transferred: usize,
+        files_failed: usize,
+        bytes_transferred: u64,
+    ) -> Self {
+        Self {
+            synced_at: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            playlists,
+            files_transferred,
+            files_failed,
+            bytes_transferred,
+        }
+    }
+
+    /// Read the record of the device mounted at `mount_point`.
+    ///
+    /// Returns `None` if the device was never synced or the record is
+    /// unreadable.
+    #[must_use]
+    pub fn read(mount_point: &Path) -> Option<Self> {
+        let path = mount_point.join(SYNC_RECORD_FILE);
+        let content = fs::read_to_string(&path).ok()?;
+        match serde_json::from_str(&content) {
+            Ok(record) => Some(record),
+            Err(e) => {
+                warn!("Ignoring invalid sync record {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    /// Write this record to the device mounted at `mount_point`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be written.
+    pub fn write(&self, mount_point: &Path) -> Result<()> {
+        let path = mount_point.join(SYNC_RECORD_FILE);
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(&path, content).map_err(|e| {
+            Error::FileSystem(FileSystemError::WriteFailed {
+                path: path.clone(),
+                reason: format!("Failed to write sync record: {e}"),
+            })
+        })?;
+        debug!("Wrote sync record to {}", path.display());
+        Ok(())
+    }
+}
+
+/// How the space on a device is used.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceUsage {
+    /// Total capacity in bytes.
+    pub total_bytes: u64,
+    /// Free space in bytes.
+    pub available_bytes: u64,
+    /// Bytes taken by audio files.
+    pub music_bytes: u64,
+    /// Number of audio files.
+    pub music_files: usize,
+    /// Bytes taken by other files the user can see.
+    pub other_bytes: u64,
+    /// Number of other files.
+    pub other_files: usize,
+    /// Bytes taken by hidden and system files and file system overhead.
+    pub system_bytes: u64,
+    /// Files and folders that couldn't be read while scanning.
+    pub unreadable_entries: usize,
+    /// The last sync to this device, if any.
+    pub last_sync: Option<DeviceSyncRecord>,
+}
+
+impl DeviceUsage {
+    /// Returns the usage percentage (0.0 - 100.0).
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn usage_percentage(&self) -> f64 {
+        if self.total_bytes == 0 {
+            return 0.0;
+        }
+        let used = self.total_bytes.saturating_sub(self.available_bytes);
+        (used as f64 / self.total_bytes as f64) * 100.0
+    }
+}
+
+/// Break down how the space on `device` is used.
+///
+/// Hidden and system files are the ones cleanup leaves alone; they are not
+/// scanned and count, along with file system overhead, as system space.
+///
+/// # Errors
+///
+/// Returns an error if the device is not mounted.
+pub fn analyze_device_usage(device: &DeviceInfo) -> Result<DeviceUsage> {
+    if !device.mount_point.is_dir() {
+        return Err(Error::Device(DeviceError::NotMounted {
+            mount_point: device.mount_point.clone(),
+        }));
+    }
+
+    let handler = DeviceCleanupHandler::new();
+    let options = CleanupOptions::default();
+    let mut usage = DeviceUsage {
+        total_bytes: device.total_bytes,
+        available_bytes: device.available_bytes,
+        music_bytes: 0,
+        music_files: 0,
+        other_bytes: 0,
+        other_files: 0,
+        system_bytes: 0,
+        unreadable_entries: 0,
+        last_sync: DeviceSyncRecord::read(&device.mount_point),
+    };
+
+    // SECURITY: follow_links(false) keeps the scan on the device
+    let walker = WalkDir::new(&device.mount_point)
+        .min_depth(1)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| handler.is_protected(entry.path(), &options).is_none());
+    for entry in walker {
+        let Ok(entry) = entry else {
+            usage.unreadable_entries += 1;
+            continue;
+        };
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let Ok(metadata) = entry.metadata() else {
+            usage.unreadable_entries += 1;
+            continue;
+        };
+        if is_audio_file(entry.path()) {
+            usage.music_bytes += metadata.len();
+            usage.music_files += 1;
+        } else {
+            usage.other_bytes += metadata.len();
+            usage.other_files += 1;
+        }
+    }
+
+    usage.system_bytes = device
+        .used_bytes()
+        .saturating_sub(usage.music_bytes)
+        .saturating_sub(usage.other_bytes);
+    Ok(usage)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn device(mount: &Path, total_bytes: u64, available_bytes: u64) -> DeviceInfo {
+        DeviceInfo {
+            name: "PLAYER".to_string(),
+            mount_point: mount.to_path_buf(),
+            total_bytes,
+            available_bytes,
+            file_system: "vfat".to_string(),
+            is_removable: true,
+        }
+    }
+
+    #[test]
+    fn test_analyze_device_usage() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("a.mp3"), [0u8; 100]).unwrap();
+        fs::create_dir(temp.path().join("Podcasts")).unwrap();
+        fs::write(temp.path().join("Podcasts").join("b.m4a"), [0u8; 50]).unwrap();
+        fs::write(temp.path().join("notes.txt"), [0u8; 20]).unwrap();
+        fs::write(temp.path().join(".hidden.mp3"), [0u8; 500]).unwrap();
+        fs::create_dir(temp.path().join("System Volume Information")).unwrap();
+        fs::write(
+            temp.path().join("System Volume Information").join("x.mp3"),
+            [0u8; 500],
+        )
+        .unwrap();
+
+        let usage = analyze_device_usage(&device(temp.path(), 10_000, 8_000)).unwrap();
+        assert_eq!(usage.music_files, 2);
+        assert_eq!(usage.music_bytes, 150);
+        assert_eq!(usage.other_files, 1);
+        assert_eq!(usage.other_bytes, 20);
+        // Everything else in the 2000 used bytes is system space
+        assert_eq!(usage.system_bytes, 1_830);
+        assert!(usage.last_sync.is_none());
+        assert!((usage.usage_percentage() - 20.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_analyze_device_usage_not_mounted() {
+        let temp = TempDir::new().unwrap();
+        let missing = temp.path().join("missing");
+        assert!(analyze_device_usage(&device(&missing, 0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_sync_record_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        assert!(DeviceSyncRecord::read(temp.path()).is_none());
+
+        let record = DeviceSyncRecord::new(vec!["Road trip".to_string()], 12, 1, 4_096);
+        record.write(temp.path()).unwrap();
+        assert_eq!(DeviceSyncRecord::read(temp.path()), Some(record.clone()));
+
+        let usage = analyze_device_usage(&device(temp.path(), 10_000, 5_000)).unwrap();
+        assert_eq!(usage.last_sync, Some(record));
+        // The record itself is hidden, so it isn't counted as a file
+        assert_eq!(usage.other_files, 0);
+
+        fs::write(temp.path().join(SYNC_RECORD_FILE), "not json").unwrap();
+        assert!(DeviceSyncRecord::read(temp.path()).is_none());
+    }
+}
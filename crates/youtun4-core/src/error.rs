@@ -231,9 +231,52 @@ pub enum DownloadError {
         reason: String,
     },
 
+    /// `YouTube` search results could not be read.
+    #[error("YouTube search for '{query}' failed: {reason}")]
+    SearchFailed {
+        /// Search query.
+        query: String,
+        /// Reason for failure.
+        reason: String,
+    },
+
+    /// The external `yt-dlp` could not be run or failed.
+    #[error("yt-dlp failed: {reason}")]
+    YtDlpFailed {
+        /// Reason for failure.
+        reason: String,
+    },
+
     /// Download was cancelled.
     #[error("download cancelled by user")]
     Cancelled,
+
+    /// Network access was skipped because offline mode is enabled.
+    #[error("offline mode is enabled: {operation} requires network access")]
+    Offline {
+        /// Operation that needed the network.
+        operation: String,
+    },
+
+    /// The library has reached its configured maximum size.
+    #[error("library size limit reached: {library_bytes} bytes used, limit is {limit_bytes} bytes")]
+    LibraryLimitReached {
+        /// Current library size in bytes.
+        library_bytes: u64,
+        /// Configured maximum library size in bytes.
+        limit_bytes: u64,
+    },
+
+    /// Free disk space is below the configured minimum.
+    #[error(
+        "not enough free disk space: {available_bytes} bytes available, {min_free_bytes} bytes must stay free"
+    )]
+    LowDiskSpace {
+        /// Free space on the library's disk in bytes.
+        available_bytes: u64,
+        /// Configured minimum free space in bytes.
+        min_free_bytes: u64,
+    },
 }
 
 // ============================================================================
@@ -624,6 +667,10 @@ pub enum Error {
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
+    /// Secrets store error.
+    #[error("Secrets error: {0}")]
+    Secrets(String),
+
     /// Operation was cancelled.
     #[error("Operation cancelled")]
     Cancelled,
@@ -719,12 +766,115 @@ pub enum ErrorKind {
     Io,
     /// Serialization errors.
     Serialization,
+    /// Secrets store errors.
+    Secrets,
     /// Cancelled operation.
     Cancelled,
     /// Internal/unexpected errors.
     Internal,
 }
 
+/// Stable, machine-readable error codes.
+///
+/// Unlike [`ErrorKind`], these identify the specific failure, and their
+/// serialized (`snake_case`) names are a stable contract with the frontend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// Device not found or not connected.
+    DeviceNotFound,
+    /// Device is not mounted.
+    DeviceNotMounted,
+    /// Device was disconnected during an operation.
+    DeviceDisconnected,
+    /// Device is read-only.
+    DeviceReadOnly,
+    /// Device is busy.
+    DeviceBusy,
+    /// Not enough space on the device.
+    InsufficientSpace,
+    /// Access to a device or file was denied.
+    PermissionDenied,
+    /// The operation is locked by simple mode and needs the parent PIN.
+    PinRequired,
+    /// Device file system is not supported.
+    UnsupportedFileSystem,
+    /// Mounting or unmounting a device failed.
+    MountFailed,
+    /// Operation is not supported on this platform.
+    PlatformNotSupported,
+    /// Other device error.
+    DeviceError,
+    /// URL is not a valid `YouTube` URL.
+    InvalidUrl,
+    /// URL is not a playlist.
+    NotAPlaylist,
+    /// Network request failed.
+    Network,
+    /// Operation timed out.
+    Timeout,
+    /// Rate limited by the remote service.
+    RateLimited,
+    /// Video is unavailable.
+    VideoUnavailable,
+    /// Audio extraction or conversion failed.
+    ConversionFailed,
+    /// Playlist could not be parsed.
+    PlaylistParseFailed,
+    /// Network access is disabled by offline mode.
+    Offline,
+    /// Library size limit reached.
+    LibraryLimitReached,
+    /// Not enough free disk space for the library.
+    LowDiskSpace,
+    /// Other download error.
+    DownloadFailed,
+    /// Transfer was interrupted.
+    TransferInterrupted,
+    /// Integrity check failed.
+    IntegrityCheckFailed,
+    /// Other transfer or sync error.
+    TransferFailed,
+    /// Playlist not found.
+    PlaylistNotFound,
+    /// Playlist already exists.
+    PlaylistExists,
+    /// Playlist name is invalid.
+    InvalidPlaylistName,
+    /// Playlist metadata is corrupted.
+    PlaylistCorrupted,
+    /// Playlist has no tracks.
+    PlaylistEmpty,
+    /// Track not found.
+    TrackNotFound,
+    /// Cache error.
+    CacheError,
+    /// File or directory not found.
+    NotFound,
+    /// File or directory already exists.
+    AlreadyExists,
+    /// Path is invalid.
+    InvalidPath,
+    /// Other file system error.
+    FileSystemError,
+    /// Configuration is invalid.
+    Configuration,
+    /// I/O error.
+    Io,
+    /// Serialization error.
+    Serialization,
+    /// Secrets store error.
+    Secrets,
+    /// Operation was cancelled.
+    Cancelled,
+    /// A conflicting task is already running.
+    TaskBusy,
+    /// Invalid input to a command.
+    InvalidInput,
+    /// Internal/unexpected error.
+    Internal,
+}
+
 impl Error {
     /// Get the kind/category of this error.
     #[must_use]
@@ -739,6 +889,7 @@ impl Error {
             Self::Configuration(_) => ErrorKind::Configuration,
             Self::Io(_) => ErrorKind::Io,
             Self::Serialization(_) => ErrorKind::Serialization,
+            Self::Secrets(_) => ErrorKind::Secrets,
             Self::Cancelled => ErrorKind::Cancelled,
             Self::Internal(_) => ErrorKind::Internal,
             Self::WithContext { source, .. } => source.kind(),
@@ -758,6 +909,100 @@ impl Error {
         }
     }
 
+    /// Get the stable error code of this error.
+    #[must_use]
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::Device(e) => match e {
+                DeviceError::NotFound { .. } => ErrorCode::DeviceNotFound,
+                DeviceError::NotMounted { .. } => ErrorCode::DeviceNotMounted,
+                DeviceError::Disconnected { .. } => ErrorCode::DeviceDisconnected,
+                DeviceError::ReadOnly { .. } => ErrorCode::DeviceReadOnly,
+                DeviceError::DeviceBusy { .. } => ErrorCode::DeviceBusy,
+                DeviceError::InsufficientSpace { .. } => ErrorCode::InsufficientSpace,
+                DeviceError::PermissionDenied { .. } => ErrorCode::PermissionDenied,
+                DeviceError::UnsupportedFileSystem { .. } => ErrorCode::UnsupportedFileSystem,
+                DeviceError::MountFailed { .. }
+                | DeviceError::UnmountFailed { .. }
+                | DeviceError::MountPointInUse { .. } => ErrorCode::MountFailed,
+                DeviceError::PlatformNotSupported { .. } => ErrorCode::PlatformNotSupported,
+                DeviceError::EnumerationFailed { .. } => ErrorCode::DeviceError,
+            },
+            Self::Download(e) => match e {
+                DownloadError::InvalidUrl { .. } => ErrorCode::InvalidUrl,
+                DownloadError::NotAPlaylist { .. } => ErrorCode::NotAPlaylist,
+                DownloadError::Network { .. } => ErrorCode::Network,
+                DownloadError::Timeout { .. } => ErrorCode::Timeout,
+                DownloadError::RateLimited { .. } => ErrorCode::RateLimited,
+                DownloadError::VideoUnavailable { .. } => ErrorCode::VideoUnavailable,
+                DownloadError::AudioExtractionFailed { .. }
+                | DownloadError::ConversionFailed { .. } => ErrorCode::ConversionFailed,
+                DownloadError::PlaylistParseFailed { .. } => ErrorCode::PlaylistParseFailed,
+                DownloadError::SearchFailed { .. } | DownloadError::YtDlpFailed { .. } => {
+                    ErrorCode::DownloadFailed
+                }
+                DownloadError::Cancelled => ErrorCode::Cancelled,
+                DownloadError::Offline { .. } => ErrorCode::Offline,
+                DownloadError::LibraryLimitReached { .. } => ErrorCode::LibraryLimitReached,
+                DownloadError::LowDiskSpace { .. } => ErrorCode::LowDiskSpace,
+            },
+            Self::Transfer(e) => match e {
+                TransferError::Interrupted { .. } => ErrorCode::TransferInterrupted,
+                TransferError::IntegrityCheckFailed { .. } => ErrorCode::IntegrityCheckFailed,
+                TransferError::PartialTransfer { .. }
+                | TransferError::SourceNotFound { .. }
+                | TransferError::DestinationNotWritable { .. }
+                | TransferError::CopyFailed { .. } => ErrorCode::TransferFailed,
+            },
+            Self::Playlist(e) => match e {
+                PlaylistError::AlreadyExists { .. } => ErrorCode::PlaylistExists,
+                PlaylistError::NotFound { .. } => ErrorCode::PlaylistNotFound,
+                PlaylistError::InvalidName { .. } => ErrorCode::InvalidPlaylistName,
+                PlaylistError::MetadataCorrupted { .. } => ErrorCode::PlaylistCorrupted,
+                PlaylistError::Empty { .. } => ErrorCode::PlaylistEmpty,
+                PlaylistError::TrackNotFound { .. } => ErrorCode::TrackNotFound,
+            },
+            Self::FileSystem(e) => match e {
+                FileSystemError::NotFound { .. } => ErrorCode::NotFound,
+                FileSystemError::PermissionDenied { .. } => ErrorCode::PermissionDenied,
+                FileSystemError::AlreadyExists { .. } => ErrorCode::AlreadyExists,
+                FileSystemError::InvalidPath { .. } => ErrorCode::InvalidPath,
+                FileSystemError::CreateDirFailed { .. }
+                | FileSystemError::ReadFailed { .. }
+                | FileSystemError::WriteFailed { .. }
+                | FileSystemError::DeleteFailed { .. }
+                | FileSystemError::CopyFailed { .. } => ErrorCode::FileSystemError,
+            },
+            Self::Cache(_) => ErrorCode::CacheError,
+            Self::Configuration(_) => ErrorCode::Configuration,
+            Self::Io(_) => ErrorCode::Io,
+            Self::Serialization(_) => ErrorCode::Serialization,
+            Self::Secrets(_) => ErrorCode::Secrets,
+            Self::Cancelled => ErrorCode::Cancelled,
+            Self::Internal(_) => ErrorCode::Internal,
+            Self::WithContext { source, .. } => source.code(),
+            // Legacy variants
+            #[allow(deprecated)]
+            Self::DeviceNotFound(_) => ErrorCode::DeviceNotFound,
+            #[allow(deprecated)]
+            Self::DeviceNotMounted(_) => ErrorCode::DeviceNotMounted,
+            #[allow(deprecated)]
+            Self::PlaylistAlreadyExists(_) => ErrorCode::PlaylistExists,
+            #[allow(deprecated)]
+            Self::PlaylistNotFound(_) => ErrorCode::PlaylistNotFound,
+            #[allow(deprecated)]
+            Self::InvalidPlaylistName(_) => ErrorCode::InvalidPlaylistName,
+            #[allow(deprecated)]
+            Self::InvalidYouTubeUrl(_) => ErrorCode::InvalidUrl,
+            #[allow(deprecated)]
+            Self::NotAPlaylist(_) => ErrorCode::NotAPlaylist,
+            #[allow(deprecated)]
+            Self::DownloadFailed(_) => ErrorCode::DownloadFailed,
+            #[allow(deprecated)]
+            Self::SyncFailed(_) => ErrorCode::TransferFailed,
+        }
+    }
+
     /// Check if this error is retryable.
     #[must_use]
     pub fn is_retryable(&self) -> bool {
@@ -778,6 +1023,28 @@ impl Error {
         }
     }
 
+    /// Check if this error was caused by offline mode being enabled.
+    #[must_use]
+    pub fn is_offline(&self) -> bool {
+        match self {
+            Self::Download(DownloadError::Offline { .. }) => true,
+            Self::WithContext { source, .. } => source.is_offline(),
+            _ => false,
+        }
+    }
+
+    /// Check if this error was caused by a library space guardrail.
+    #[must_use]
+    pub fn is_library_space_low(&self) -> bool {
+        match self {
+            Self::Download(
+                DownloadError::LibraryLimitReached { .. } | DownloadError::LowDiskSpace { .. },
+            ) => true,
+            Self::WithContext { source, .. } => source.is_library_space_low(),
+            _ => false,
+        }
+    }
+
     /// Check if this is a user-facing error (should be shown to user).
     #[must_use]
     pub const fn is_user_facing(&self) -> bool {
@@ -873,6 +1140,32 @@ impl Error {
         })
     }
 
+    /// Create an offline mode error.
+    #[must_use]
+    pub fn offline(operation: impl Into<String>) -> Self {
+        Self::Download(DownloadError::Offline {
+            operation: operation.into(),
+        })
+    }
+
+    /// Create a library size limit reached error.
+    #[must_use]
+    pub const fn library_limit_reached(library_bytes: u64, limit_bytes: u64) -> Self {
+        Self::Download(DownloadError::LibraryLimitReached {
+            library_bytes,
+            limit_bytes,
+        })
+    }
+
+    /// Create a low disk space error.
+    #[must_use]
+    pub const fn low_disk_space(available_bytes: u64, min_free_bytes: u64) -> Self {
+        Self::Download(DownloadError::LowDiskSpace {
+            available_bytes,
+            min_free_bytes,
+        })
+    }
+
     /// Create a file system read error.
     #[must_use]
     pub fn fs_read_failed(path: impl Into<PathBuf>, reason: impl Into<String>) -> Self {
@@ -967,6 +1260,12 @@ impl Error {
             reason: reason.into(),
         })
     }
+
+    /// Create a secrets store error.
+    #[must_use]
+    pub fn secrets(reason: impl Into<String>) -> Self {
+        Self::Secrets(reason.into())
+    }
 }
 
 // ============================================================================
@@ -1228,6 +1527,47 @@ mod tests {
         assert_eq!(err.kind(), ErrorKind::Io);
     }
 
+    #[test]
+    fn test_error_codes() {
+        assert_eq!(
+            Error::device_not_found("test").code(),
+            ErrorCode::DeviceNotFound
+        );
+        assert_eq!(Error::offline("download").code(), ErrorCode::Offline);
+        assert_eq!(
+            Error::library_limit_reached(200, 100).code(),
+            ErrorCode::LibraryLimitReached
+        );
+        let wrapped: Result<()> = Err(Error::playlist_not_found("mix"));
+        assert_eq!(
+            wrapped.context("Loading").unwrap_err().code(),
+            ErrorCode::PlaylistNotFound
+        );
+        assert_eq!(
+            serde_json::to_string(&ErrorCode::PlaylistNotFound).unwrap(),
+            "\"playlist_not_found\""
+        );
+    }
+
+    #[test]
+    fn test_library_space_errors() {
+        let err = Error::library_limit_reached(200, 100);
+        assert!(err.is_library_space_low());
+        assert_eq!(err.kind(), ErrorKind::Download);
+        assert!(!err.is_retryable());
+
+        let err = Error::low_disk_space(10, 100).context("queue");
+        assert!(err.is_library_space_low());
+        assert!(!Error::offline("download").is_library_space_low());
+    }
+
+    #[test]
+    fn test_error_kind_secrets() {
+        let err = Error::secrets("keychain locked");
+        assert_eq!(err.kind(), ErrorKind::Secrets);
+        assert_eq!(err.to_string(), "Secrets error: keychain locked");
+    }
+
     // -------------------------------------------------------------------------
     // From Implementations Tests
     // -------------------------------------------------------------------------
@@ -1491,6 +1831,16 @@ mod tests {
         assert!(err.to_string().contains("PLabc123"));
     }
 
+    #[test]
+    fn test_search_failed_error() {
+        let err = Error::Download(DownloadError::SearchFailed {
+            query: "lofi beats".to_string(),
+            reason: "no results section".to_string(),
+        });
+        assert!(err.to_string().contains("lofi beats"));
+        assert_eq!(err.code(), ErrorCode::DownloadFailed);
+    }
+
     #[test]
     fn test_download_cancelled_error() {
         let err = Error::Download(DownloadError::Cancelled);
@@ -1498,6 +1848,25 @@ mod tests {
         assert!(!err.is_retryable());
     }
 
+    #[test]
+    fn test_offline_error() {
+        let err = Error::offline("fetching playlist info");
+        assert!(err.to_string().contains("offline mode"));
+        assert!(err.to_string().contains("fetching playlist info"));
+        assert_eq!(err.kind(), ErrorKind::Download);
+        assert!(err.is_offline());
+        assert!(!err.is_retryable());
+        assert_eq!(err.retry_delay_secs(), None);
+    }
+
+    #[test]
+    fn test_offline_error_with_context() {
+        let result: Result<()> = Err(Error::offline("fetching thumbnail"));
+        let err = result.context("Failed to load playlist").unwrap_err();
+        assert!(err.is_offline());
+        assert!(!Error::network_error("connection refused").is_offline());
+    }
+
     #[test]
     fn test_network_error_with_source() {
         let io_err =
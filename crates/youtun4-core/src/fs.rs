@@ -1,12 +1,15 @@
 //! File system abstraction for testability.
 //!
-//! This module provides a `FileSystem` trait that abstracts file system operations,
-//! allowing for easy mocking in tests.
+//! This module provides a `FileSystem` trait that abstracts file system operations.
+//! [`PlaylistManager`](crate::playlist::PlaylistManager), the session
+//! [`Trash`](crate::trash::Trash) and
+//! [`DeviceCleanupHandler`](crate::cleanup::DeviceCleanupHandler) do their disk
+//! I/O through it, so they can run against [`MemoryFileSystem`] in tests.
 //!
 //! # Example
 //!
 //! ```rust,ignore
-//! use youtun4_core::fs::{FileSystem, RealFileSystem};
+//! use youtun4_core::fs::{FileSystem, MemoryFileSystem, RealFileSystem};
 //!
 //! fn read_config<F: FileSystem>(fs: &F, path: &Path) -> Result<String> {
 //!     fs.read_to_string(path)
@@ -17,14 +20,16 @@
 //! let config = read_config(&fs, Path::new("config.json"))?;
 //!
 //! // In tests:
-//! let mut mock = MockFileSystem::new();
-//! mock.add_file("config.json", r#"{"key": "value"}"#);
-//! let config = read_config(&mock, Path::new("config.json"))?;
+//! let memory = MemoryFileSystem::new();
+//! memory.add_file("config.json", r#"{"key": "value"}"#);
+//! let config = read_config(&memory, Path::new("config.json"))?;
 //! ```
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::{self, Metadata};
 use std::io;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::time::SystemTime;
 
 use crate::error::{Error, FileSystemError, Result};
@@ -73,7 +78,7 @@ fn copy_error(src: &Path, dst: &Path, e: io::Error) -> Error {
 /// Abstraction over file system operations for testability.
 ///
 /// This trait allows components to be tested without touching the real file system.
-pub trait FileSystem: Send + Sync {
+pub trait FileSystem: std::fmt::Debug + Send + Sync {
     /// Read a file's contents as a string.
     fn read_to_string(&self, path: &Path) -> Result<String>;
 
@@ -101,6 +106,9 @@ pub trait FileSystem: Send + Sync {
     /// Remove a file.
     fn remove_file(&self, path: &Path) -> Result<()>;
 
+    /// Remove an empty directory.
+    fn remove_dir(&self, path: &Path) -> Result<()>;
+
     /// Remove a directory and all its contents.
     fn remove_dir_all(&self, path: &Path) -> Result<()>;
 
@@ -116,6 +124,9 @@ pub trait FileSystem: Send + Sync {
     /// Get file metadata (size, modified time, etc.).
     fn metadata(&self, path: &Path) -> Result<FileMetadata>;
 
+    /// Get file metadata without following a final symlink.
+    fn symlink_metadata(&self, path: &Path) -> Result<FileMetadata>;
+
     /// Get the canonical, absolute form of a path.
     fn canonicalize(&self, path: &Path) -> Result<PathBuf>;
 
@@ -125,7 +136,9 @@ pub trait FileSystem: Send + Sync {
     /// * `path` - The root directory to walk
     /// * `max_depth` - Maximum depth to recurse (1 = direct children only, None = unlimited)
     ///
-    /// Returns a list of all file and directory paths found.
+    /// Returns a list of all file and directory paths found. Symlinked
+    /// directories are listed but not descended into, and subdirectories
+    /// that can't be read are skipped.
     fn walk_dir(&self, path: &Path, max_depth: Option<usize>) -> Result<Vec<PathBuf>>;
 }
 
@@ -138,18 +151,28 @@ pub struct FileMetadata {
     pub is_dir: bool,
     /// Whether this is a file.
     pub is_file: bool,
+    /// Whether this is a symlink (only set by `symlink_metadata`).
+    pub is_symlink: bool,
+    /// Whether the entry is read-only.
+    pub readonly: bool,
     /// Last modified time.
     pub modified: Option<SystemTime>,
+    /// Creation time, where the platform records it.
+    pub created: Option<SystemTime>,
 }
 
 impl FileMetadata {
     /// Create metadata from std::fs::Metadata.
+    #[must_use]
     pub fn from_std(meta: Metadata) -> Self {
         Self {
             len: meta.len(),
             is_dir: meta.is_dir(),
             is_file: meta.is_file(),
+            is_symlink: meta.is_symlink(),
+            readonly: meta.permissions().readonly(),
             modified: meta.modified().ok(),
+            created: meta.created().ok(),
         }
     }
 }
@@ -215,6 +238,10 @@ impl FileSystem for RealFileSystem {
         fs::remove_file(path).map_err(|e| delete_error(path, e))
     }
 
+    fn remove_dir(&self, path: &Path) -> Result<()> {
+        fs::remove_dir(path).map_err(|e| delete_error(path, e))
+    }
+
     fn remove_dir_all(&self, path: &Path) -> Result<()> {
         fs::remove_dir_all(path).map_err(|e| delete_error(path, e))
     }
@@ -245,6 +272,11 @@ impl FileSystem for RealFileSystem {
         Ok(FileMetadata::from_std(meta))
     }
 
+    fn symlink_metadata(&self, path: &Path) -> Result<FileMetadata> {
+        let meta = fs::symlink_metadata(path).map_err(|e| read_error(path, e))?;
+        Ok(FileMetadata::from_std(meta))
+    }
+
     fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
         fs::canonicalize(path).map_err(|e| read_error(path, e))
     }
@@ -262,13 +294,14 @@ impl FileSystem for RealFileSystem {
                 return Ok(());
             }
 
-            for entry in fs::read_dir(dir)? {
-                let entry = entry?;
+            for entry in fs::read_dir(dir)?.flatten() {
                 let path = entry.path();
                 results.push(path.clone());
 
-                if path.is_dir() {
-                    walk_recursive(&path, current_depth + 1, max_depth, results)?;
+                // file_type() does not follow symlinks. Unreadable
+                // subdirectories are skipped rather than failing the walk.
+                if entry.file_type().is_ok_and(|t| t.is_dir()) {
+                    let _ = walk_recursive(&path, current_depth + 1, max_depth, results);
                 }
             }
             Ok(())
@@ -280,265 +313,368 @@ impl FileSystem for RealFileSystem {
     }
 }
 
-#[cfg(test)]
-#[allow(clippy::unwrap_used, clippy::expect_used, clippy::missing_panics_doc)]
-pub mod mock {
-    //! Mock file system for testing.
+/// Converts a missing path into an error.
+fn not_found(path: &Path) -> Error {
+    Error::FileSystem(FileSystemError::NotFound {
+        path: path.to_path_buf(),
+    })
+}
 
-    use super::*;
-    use std::collections::HashMap;
-    use std::sync::{Arc, RwLock};
-
-    /// In-memory mock file system for testing.
-    #[derive(Debug, Clone, Default)]
-    pub struct MockFileSystem {
-        files: Arc<RwLock<HashMap<PathBuf, Vec<u8>>>>,
-        dirs: Arc<RwLock<std::collections::HashSet<PathBuf>>>,
-    }
-
-    impl MockFileSystem {
-        /// Create a new empty mock file system.
-        #[must_use]
-        pub fn new() -> Self {
-            Self {
-                files: Arc::new(RwLock::new(HashMap::new())),
-                dirs: Arc::new(RwLock::new(std::collections::HashSet::new())),
-            }
-        }
+/// A file stored in a [`MemoryFileSystem`].
+#[derive(Debug, Clone)]
+struct MemoryFile {
+    contents: Vec<u8>,
+    modified: SystemTime,
+}
 
-        /// Add a file with string contents.
-        pub fn add_file(&self, path: impl AsRef<Path>, contents: &str) {
-            let path = path.as_ref().to_path_buf();
-            // Add parent directories
-            if let Some(parent) = path.parent() {
-                self.add_dir(parent);
-            }
-            self.files
-                .write()
-                .expect("lock poisoned")
-                .insert(path, contents.as_bytes().to_vec());
-        }
+/// In-memory file system for deterministic tests.
+///
+/// Entries are kept in sorted maps, so `read_dir` and `walk_dir` list them in
+/// path order. Clones share the same contents, so a test can keep a handle to
+/// inspect what the code under test wrote.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFileSystem {
+    files: Arc<RwLock<BTreeMap<PathBuf, MemoryFile>>>,
+    dirs: Arc<RwLock<BTreeSet<PathBuf>>>,
+    readonly: Arc<RwLock<BTreeSet<PathBuf>>>,
+}
 
-        /// Add a file with byte contents.
-        pub fn add_file_bytes(&self, path: impl AsRef<Path>, contents: &[u8]) {
-            let path = path.as_ref().to_path_buf();
-            if let Some(parent) = path.parent() {
-                self.add_dir(parent);
-            }
-            self.files
-                .write()
-                .expect("lock poisoned")
-                .insert(path, contents.to_vec());
+impl MemoryFileSystem {
+    /// Create a new empty in-memory file system.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file with string contents, creating its parent directories.
+    pub fn add_file(&self, path: impl AsRef<Path>, contents: &str) {
+        self.add_file_bytes(path, contents.as_bytes());
+    }
+
+    /// Add a file with byte contents, creating its parent directories.
+    pub fn add_file_bytes(&self, path: impl AsRef<Path>, contents: &[u8]) {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            self.add_dir(parent);
         }
+        self.files_mut().insert(
+            path.to_path_buf(),
+            MemoryFile {
+                contents: contents.to_vec(),
+                modified: SystemTime::now(),
+            },
+        );
+    }
 
-        /// Add a directory.
-        pub fn add_dir(&self, path: impl AsRef<Path>) {
-            let path = path.as_ref();
-            let mut dirs = self.dirs.write().expect("lock poisoned");
-
-            // Add all parent directories too
-            let mut current = path.to_path_buf();
-            while current.parent().is_some() {
-                dirs.insert(current.clone());
-                if let Some(parent) = current.parent() {
-                    current = parent.to_path_buf();
-                } else {
-                    break;
-                }
+    /// Add a directory and all its parents.
+    pub fn add_dir(&self, path: impl AsRef<Path>) {
+        let mut dirs = self.dirs_mut();
+        for ancestor in path.as_ref().ancestors() {
+            if !ancestor.as_os_str().is_empty() {
+                dirs.insert(ancestor.to_path_buf());
             }
         }
+    }
 
-        /// Get all files in the mock filesystem.
-        #[must_use]
-        pub fn list_all_files(&self) -> Vec<PathBuf> {
-            self.files
-                .read()
-                .expect("lock poisoned")
-                .keys()
-                .cloned()
-                .collect()
+    /// Make `path` and everything below it read-only, or writable again.
+    ///
+    /// Writing to or removing a read-only entry fails with
+    /// [`FileSystemError::PermissionDenied`].
+    pub fn set_readonly(&self, path: impl AsRef<Path>, readonly: bool) {
+        let path = path.as_ref().to_path_buf();
+        let mut set = self
+            .readonly
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        if readonly {
+            set.insert(path);
+        } else {
+            set.remove(&path);
         }
     }
 
-    impl FileSystem for MockFileSystem {
-        fn read_to_string(&self, path: &Path) -> Result<String> {
-            let files = self.files.read().expect("lock poisoned");
-            files
-                .get(path)
-                .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
-                .ok_or_else(|| {
-                    Error::FileSystem(FileSystemError::NotFound {
-                        path: path.to_path_buf(),
-                    })
-                })
-        }
+    /// All files in the file system, in path order.
+    #[must_use]
+    pub fn list_all_files(&self) -> Vec<PathBuf> {
+        self.files().keys().cloned().collect()
+    }
 
-        fn read(&self, path: &Path) -> Result<Vec<u8>> {
-            let files = self.files.read().expect("lock poisoned");
-            files.get(path).cloned().ok_or_else(|| {
-                Error::FileSystem(FileSystemError::NotFound {
-                    path: path.to_path_buf(),
-                })
-            })
-        }
+    fn files(&self) -> RwLockReadGuard<'_, BTreeMap<PathBuf, MemoryFile>> {
+        self.files.read().unwrap_or_else(PoisonError::into_inner)
+    }
 
-        fn write(&self, path: &Path, contents: &str) -> Result<()> {
-            self.add_file(path, contents);
-            Ok(())
-        }
+    fn files_mut(&self) -> RwLockWriteGuard<'_, BTreeMap<PathBuf, MemoryFile>> {
+        self.files.write().unwrap_or_else(PoisonError::into_inner)
+    }
 
-        fn write_bytes(&self, path: &Path, contents: &[u8]) -> Result<()> {
-            self.add_file_bytes(path, contents);
-            Ok(())
-        }
+    fn dirs(&self) -> RwLockReadGuard<'_, BTreeSet<PathBuf>> {
+        self.dirs.read().unwrap_or_else(PoisonError::into_inner)
+    }
 
-        fn exists(&self, path: &Path) -> bool {
-            let files = self.files.read().expect("lock poisoned");
-            let dirs = self.dirs.read().expect("lock poisoned");
-            files.contains_key(path) || dirs.contains(path)
-        }
+    fn dirs_mut(&self) -> RwLockWriteGuard<'_, BTreeSet<PathBuf>> {
+        self.dirs.write().unwrap_or_else(PoisonError::into_inner)
+    }
 
-        fn is_file(&self, path: &Path) -> bool {
-            let files = self.files.read().expect("lock poisoned");
-            files.contains_key(path)
-        }
+    fn is_readonly(&self, path: &Path) -> bool {
+        self.readonly
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .any(|p| path.starts_with(p))
+    }
 
-        fn is_dir(&self, path: &Path) -> bool {
-            let dirs = self.dirs.read().expect("lock poisoned");
-            dirs.contains(path)
+    fn check_writable(&self, path: &Path) -> Result<()> {
+        if self.is_readonly(path) {
+            return Err(Error::FileSystem(FileSystemError::PermissionDenied {
+                path: path.to_path_buf(),
+            }));
         }
+        Ok(())
+    }
 
-        fn create_dir_all(&self, path: &Path) -> Result<()> {
-            self.add_dir(path);
-            Ok(())
+    fn entry_metadata(&self, path: &Path) -> Result<FileMetadata> {
+        let readonly = self.is_readonly(path);
+        if let Some(file) = self.files().get(path) {
+            return Ok(FileMetadata {
+                len: file.contents.len() as u64,
+                is_dir: false,
+                is_file: true,
+                is_symlink: false,
+                readonly,
+                modified: Some(file.modified),
+                created: Some(file.modified),
+            });
         }
-
-        fn remove_file(&self, path: &Path) -> Result<()> {
-            let mut files = self.files.write().expect("lock poisoned");
-            files.remove(path);
-            Ok(())
+        if self.dirs().contains(path) {
+            return Ok(FileMetadata {
+                len: 0,
+                is_dir: true,
+                is_file: false,
+                is_symlink: false,
+                readonly,
+                modified: None,
+                created: None,
+            });
         }
+        Err(not_found(path))
+    }
+}
 
-        fn remove_dir_all(&self, path: &Path) -> Result<()> {
-            let mut files = self.files.write().expect("lock poisoned");
-            let mut dirs = self.dirs.write().expect("lock poisoned");
+impl FileSystem for MemoryFileSystem {
+    fn read_to_string(&self, path: &Path) -> Result<String> {
+        self.read(path)
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+    }
 
-            // Remove all files under this path
-            files.retain(|p, _| !p.starts_with(path));
-            // Remove all dirs under this path
-            dirs.retain(|p| !p.starts_with(path));
+    fn read(&self, path: &Path) -> Result<Vec<u8>> {
+        self.files()
+            .get(path)
+            .map(|file| file.contents.clone())
+            .ok_or_else(|| not_found(path))
+    }
 
-            Ok(())
+    fn write(&self, path: &Path, contents: &str) -> Result<()> {
+        self.write_bytes(path, contents.as_bytes())
+    }
+
+    fn write_bytes(&self, path: &Path, contents: &[u8]) -> Result<()> {
+        self.check_writable(path)?;
+        if self.is_dir(path) {
+            return Err(Error::FileSystem(FileSystemError::WriteFailed {
+                path: path.to_path_buf(),
+                reason: "is a directory".to_string(),
+            }));
         }
+        self.add_file_bytes(path, contents);
+        Ok(())
+    }
 
-        fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
-            let files = self.files.read().expect("lock poisoned");
-            let dirs = self.dirs.read().expect("lock poisoned");
+    fn exists(&self, path: &Path) -> bool {
+        self.is_file(path) || self.is_dir(path)
+    }
 
-            let mut entries = std::collections::HashSet::new();
+    fn is_file(&self, path: &Path) -> bool {
+        self.files().contains_key(path)
+    }
 
-            // Find direct children (files)
-            for file_path in files.keys() {
-                if file_path.parent() == Some(path) {
-                    entries.insert(file_path.clone());
-                }
-            }
+    fn is_dir(&self, path: &Path) -> bool {
+        self.dirs().contains(path)
+    }
 
-            // Find direct children (dirs)
-            for dir_path in dirs.iter() {
-                if dir_path.parent() == Some(path) && dir_path != path {
-                    entries.insert(dir_path.clone());
-                }
-            }
+    fn create_dir_all(&self, path: &Path) -> Result<()> {
+        if self.is_file(path) {
+            return Err(Error::FileSystem(FileSystemError::CreateDirFailed {
+                path: path.to_path_buf(),
+                reason: "a file exists at this path".to_string(),
+            }));
+        }
+        self.check_writable(path)?;
+        self.add_dir(path);
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &Path) -> Result<()> {
+        self.check_writable(path)?;
+        self.files_mut()
+            .remove(path)
+            .map(|_| ())
+            .ok_or_else(|| not_found(path))
+    }
 
-            Ok(entries.into_iter().collect())
+    fn remove_dir(&self, path: &Path) -> Result<()> {
+        if !self.is_dir(path) {
+            return Err(not_found(path));
+        }
+        self.check_writable(path)?;
+        if !self.read_dir(path)?.is_empty() {
+            return Err(Error::FileSystem(FileSystemError::DeleteFailed {
+                path: path.to_path_buf(),
+                reason: "directory not empty".to_string(),
+            }));
         }
+        self.dirs_mut().remove(path);
+        Ok(())
+    }
 
-        fn copy(&self, src: &Path, dst: &Path) -> Result<u64> {
-            let contents = self.read(src)?;
-            let len = contents.len() as u64;
-            self.write_bytes(dst, &contents)?;
-            Ok(len)
+    fn remove_dir_all(&self, path: &Path) -> Result<()> {
+        if !self.is_dir(path) {
+            return Err(not_found(path));
         }
+        self.check_writable(path)?;
+        self.files_mut().retain(|p, _| !p.starts_with(path));
+        self.dirs_mut().retain(|p| !p.starts_with(path));
+        Ok(())
+    }
 
-        fn rename(&self, from: &Path, to: &Path) -> Result<()> {
-            let contents = self.read(from)?;
-            self.write_bytes(to, &contents)?;
-            self.remove_file(from)?;
-            Ok(())
+    fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        if !self.is_dir(path) {
+            return Err(not_found(path));
         }
+        let files = self.files();
+        let dirs = self.dirs();
+        let mut entries: Vec<PathBuf> = files
+            .keys()
+            .chain(dirs.iter())
+            .filter(|p| p.parent() == Some(path) && p.as_path() != path)
+            .cloned()
+            .collect();
+        entries.sort();
+        Ok(entries)
+    }
 
-        fn metadata(&self, path: &Path) -> Result<FileMetadata> {
-            let files = self.files.read().expect("lock poisoned");
-            let dirs = self.dirs.read().expect("lock poisoned");
-
-            if let Some(contents) = files.get(path) {
-                Ok(FileMetadata {
-                    len: contents.len() as u64,
-                    is_dir: false,
-                    is_file: true,
-                    modified: Some(SystemTime::now()),
-                })
-            } else if dirs.contains(path) {
-                Ok(FileMetadata {
-                    len: 0,
-                    is_dir: true,
-                    is_file: false,
-                    modified: Some(SystemTime::now()),
-                })
-            } else {
-                Err(Error::FileSystem(FileSystemError::NotFound {
-                    path: path.to_path_buf(),
-                }))
+    fn copy(&self, src: &Path, dst: &Path) -> Result<u64> {
+        let contents = self.read(src)?;
+        self.write_bytes(dst, &contents)?;
+        Ok(contents.len() as u64)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> Result<()> {
+        self.check_writable(from)?;
+        self.check_writable(to)?;
+        if let Some(parent) = to.parent()
+            && !self.is_dir(parent)
+        {
+            return Err(not_found(parent));
+        }
+        if self.is_file(from) {
+            let file = self
+                .files_mut()
+                .remove(from)
+                .ok_or_else(|| not_found(from))?;
+            self.files_mut().insert(to.to_path_buf(), file);
+            return Ok(());
+        }
+        if !self.is_dir(from) {
+            return Err(not_found(from));
+        }
+        if to.starts_with(from) {
+            return Err(Error::FileSystem(FileSystemError::InvalidPath {
+                path: to.to_path_buf(),
+                reason: "cannot move a directory into itself".to_string(),
+            }));
+        }
+        let rebase = |p: &Path| p.strip_prefix(from).map(|rest| to.join(rest)).ok();
+        {
+            let mut files = self.files_mut();
+            let moved: Vec<PathBuf> = files
+                .keys()
+                .filter(|p| p.starts_with(from))
+                .cloned()
+                .collect();
+            for path in moved {
+                if let (Some(file), Some(target)) = (files.remove(&path), rebase(&path)) {
+                    files.insert(target, file);
+                }
+            }
+        }
+        let mut dirs = self.dirs_mut();
+        let moved: Vec<PathBuf> = dirs
+            .iter()
+            .filter(|p| p.starts_with(from))
+            .cloned()
+            .collect();
+        for path in moved {
+            dirs.remove(&path);
+            if let Some(target) = rebase(&path) {
+                dirs.insert(target);
             }
         }
+        Ok(())
+    }
 
-        fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
-            // In mock, just return the path as-is (normalized)
+    fn metadata(&self, path: &Path) -> Result<FileMetadata> {
+        self.entry_metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> Result<FileMetadata> {
+        // There are no symlinks in memory
+        self.entry_metadata(path)
+    }
+
+    fn canonicalize(&self, path: &Path) -> Result<PathBuf> {
+        if self.exists(path) {
             Ok(path.to_path_buf())
+        } else {
+            Err(not_found(path))
         }
+    }
 
-        fn walk_dir(&self, path: &Path, max_depth: Option<usize>) -> Result<Vec<PathBuf>> {
-            fn collect_entries(
-                fs: &MockFileSystem,
-                dir: &Path,
-                current_depth: usize,
-                max_depth: Option<usize>,
-                results: &mut Vec<PathBuf>,
-            ) {
-                if let Some(max) = max_depth
-                    && current_depth > max
-                {
-                    return;
-                }
-
-                // Get direct children
-                if let Ok(entries) = fs.read_dir(dir) {
-                    for entry in entries {
-                        results.push(entry.clone());
-                        if fs.is_dir(&entry) {
-                            collect_entries(fs, &entry, current_depth + 1, max_depth, results);
-                        }
-                    }
+    fn walk_dir(&self, path: &Path, max_depth: Option<usize>) -> Result<Vec<PathBuf>> {
+        fn collect_entries(
+            fs: &MemoryFileSystem,
+            dir: &Path,
+            current_depth: usize,
+            max_depth: Option<usize>,
+            results: &mut Vec<PathBuf>,
+        ) -> Result<()> {
+            if let Some(max) = max_depth
+                && current_depth > max
+            {
+                return Ok(());
+            }
+            for entry in fs.read_dir(dir)? {
+                results.push(entry.clone());
+                if fs.is_dir(&entry) {
+                    collect_entries(fs, &entry, current_depth + 1, max_depth, results)?;
                 }
             }
-
-            let mut results = Vec::new();
-            collect_entries(self, path, 1, max_depth, &mut results);
-            Ok(results)
+            Ok(())
         }
+
+        let mut results = Vec::new();
+        collect_entries(self, path, 1, max_depth, &mut results)?;
+        Ok(results)
     }
 }
 
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
-    use super::mock::MockFileSystem;
     use super::*;
 
     #[test]
     fn test_mock_fs_read_write() {
-        let fs = MockFileSystem::new();
+        let fs = MemoryFileSystem::new();
 
         fs.write(Path::new("/test/file.txt"), "hello world")
             .unwrap();
@@ -549,7 +685,7 @@ mod tests {
 
     #[test]
     fn test_mock_fs_exists() {
-        let fs = MockFileSystem::new();
+        let fs = MemoryFileSystem::new();
 
         assert!(!fs.exists(Path::new("/test/file.txt")));
 
@@ -561,7 +697,7 @@ mod tests {
 
     #[test]
     fn test_mock_fs_is_file_is_dir() {
-        let fs = MockFileSystem::new();
+        let fs = MemoryFileSystem::new();
 
         fs.write(Path::new("/test/file.txt"), "hello").unwrap();
         fs.create_dir_all(Path::new("/test/subdir")).unwrap();
@@ -575,7 +711,7 @@ mod tests {
 
     #[test]
     fn test_mock_fs_remove() {
-        let fs = MockFileSystem::new();
+        let fs = MemoryFileSystem::new();
 
         fs.write(Path::new("/test/file.txt"), "hello").unwrap();
         assert!(fs.exists(Path::new("/test/file.txt")));
@@ -586,7 +722,7 @@ mod tests {
 
     #[test]
     fn test_mock_fs_read_dir() {
-        let fs = MockFileSystem::new();
+        let fs = MemoryFileSystem::new();
 
         fs.write(Path::new("/test/a.txt"), "a").unwrap();
         fs.write(Path::new("/test/b.txt"), "b").unwrap();
@@ -598,7 +734,7 @@ mod tests {
 
     #[test]
     fn test_mock_fs_copy() {
-        let fs = MockFileSystem::new();
+        let fs = MemoryFileSystem::new();
 
         fs.write(Path::new("/src/file.txt"), "content").unwrap();
 
@@ -616,7 +752,7 @@ mod tests {
 
     #[test]
     fn test_mock_fs_metadata() {
-        let fs = MockFileSystem::new();
+        let fs = MemoryFileSystem::new();
 
         fs.write(Path::new("/test/file.txt"), "hello").unwrap();
 
@@ -626,6 +762,79 @@ mod tests {
         assert!(!meta.is_dir);
     }
 
+    #[test]
+    fn test_memory_fs_rename_dir() {
+        let fs = MemoryFileSystem::new();
+        fs.write(Path::new("/lib/Mix/a.mp3"), "a").unwrap();
+        fs.write(Path::new("/lib/Mix/nested/b.mp3"), "b").unwrap();
+
+        fs.rename(Path::new("/lib/Mix"), Path::new("/lib/Road Trip"))
+            .unwrap();
+
+        assert!(!fs.exists(Path::new("/lib/Mix")));
+        assert!(fs.is_dir(Path::new("/lib/Road Trip/nested")));
+        assert_eq!(
+            fs.read_to_string(Path::new("/lib/Road Trip/nested/b.mp3"))
+                .unwrap(),
+            "b"
+        );
+        // Moving into a missing directory fails like the real thing
+        assert!(
+            fs.rename(Path::new("/lib/Road Trip"), Path::new("/missing/x"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_memory_fs_remove_dir_requires_empty() {
+        let fs = MemoryFileSystem::new();
+        fs.write(Path::new("/dev/music/a.mp3"), "a").unwrap();
+
+        assert!(fs.remove_dir(Path::new("/dev/music")).is_err());
+        fs.remove_file(Path::new("/dev/music/a.mp3")).unwrap();
+        fs.remove_dir(Path::new("/dev/music")).unwrap();
+        assert!(!fs.exists(Path::new("/dev/music")));
+        assert!(fs.remove_file(Path::new("/dev/music/a.mp3")).is_err());
+    }
+
+    #[test]
+    fn test_memory_fs_readonly() {
+        let fs = MemoryFileSystem::new();
+        fs.write(Path::new("/dev/a.mp3"), "a").unwrap();
+        fs.set_readonly("/dev", true);
+
+        assert!(fs.metadata(Path::new("/dev")).unwrap().readonly);
+        assert!(matches!(
+            fs.remove_file(Path::new("/dev/a.mp3")),
+            Err(Error::FileSystem(FileSystemError::PermissionDenied { .. }))
+        ));
+        assert!(fs.write(Path::new("/dev/b.mp3"), "b").is_err());
+
+        fs.set_readonly("/dev", false);
+        fs.remove_file(Path::new("/dev/a.mp3")).unwrap();
+    }
+
+    #[test]
+    fn test_memory_fs_walk_dir_is_ordered() {
+        let fs = MemoryFileSystem::new();
+        fs.write(Path::new("/root/b.mp3"), "b").unwrap();
+        fs.write(Path::new("/root/a/c.mp3"), "c").unwrap();
+        fs.write(Path::new("/root/a.mp3"), "a").unwrap();
+
+        let all = fs.walk_dir(Path::new("/root"), None).unwrap();
+        assert_eq!(
+            all,
+            vec![
+                PathBuf::from("/root/a"),
+                PathBuf::from("/root/a/c.mp3"),
+                PathBuf::from("/root/a.mp3"),
+                PathBuf::from("/root/b.mp3"),
+            ]
+        );
+        let top = fs.walk_dir(Path::new("/root"), Some(1)).unwrap();
+        assert_eq!(top.len(), 3);
+    }
+
     #[test]
     fn test_real_fs_basic() {
         // Quick sanity test for RealFileSystem
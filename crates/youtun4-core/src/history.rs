@@ -0,0 +1,804 @@
+//! Persistent history of finished downloads and syncs.
+//!
+//! Every download and sync adds an entry when it ends, whether it succeeded,
+//! failed or was cancelled. Download entries list each video with its size,
+//! length and error, and how long the download took, so totals such as the
+//! bytes downloaded this month and the average speed can be worked out. The
+//! history is capped at [`MAX_HISTORY_ENTRIES`]; the oldest entries are
+//! dropped first, and can be pruned sooner with [`HistoryStore::prune`].
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::config::AppConfig;
+use crate::error::{Error, Result};
+use crate::youtube::DownloadResult;
+
+/// File name of the history, stored next to the config file.
+pub const HISTORY_FILE: &str = "history.json";
+
+/// Maximum number of entries kept in the history.
+pub const MAX_HISTORY_ENTRIES: usize = 1_000;
+
+/// Length of a week in seconds.
+const WEEK_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// The Unix epoch was a Thursday; weeks start on the Monday before it.
+const EPOCH_WEEKDAY_OFFSET_SECS: u64 = 3 * 24 * 60 * 60;
+
+/// Length of a day in seconds.
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+/// Identifier of a history entry.
+pub type HistoryEntryId = u64;
+
+/// What a history entry records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryKind {
+    /// A `YouTube` playlist download.
+    Download,
+    /// A sync to a device.
+    Sync,
+}
+
+/// How a download or sync ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryStatus {
+    /// Every item succeeded.
+    Completed,
+    /// Some items succeeded and some failed.
+    CompletedWithErrors,
+    /// The operation failed.
+    Failed,
+    /// The operation was cancelled.
+    Cancelled,
+}
+
+impl HistoryStatus {
+    /// Status of an operation that ran to the end with these item counts.
+    #[must_use]
+    pub const fn from_counts(succeeded: usize, failed: usize) -> Self {
+        match (succeeded, failed) {
+            (_, 0) => Self::Completed,
+            (0, _) => Self::Failed,
+            _ => Self::CompletedWithErrors,
+        }
+    }
+}
+
+/// A video of a finished download.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryVideo {
+    /// Video ID.
+    pub video_id: String,
+    /// Video title.
+    pub title: String,
+    /// Whether the video was downloaded.
+    pub success: bool,
+    /// Size of the saved files in bytes.
+    pub bytes: u64,
+    /// Length of the audio in seconds, if known.
+    pub duration_secs: Option<u64>,
+    /// Why the video failed, if it did.
+    pub error: Option<String>,
+}
+
+/// A finished download or sync.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Entry identifier, assigned when the entry is recorded.
+    pub id: HistoryEntryId,
+    /// Whether this was a download or a sync.
+    pub kind: HistoryKind,
+    /// How it ended.
+    pub status: HistoryStatus,
+    /// When it ended (seconds since the Unix epoch).
+    pub finished_at: u64,
+    /// Playlists involved: the target playlist of a download, or the synced
+    /// playlists.
+    pub playlists: Vec<String>,
+    /// Source URL of a download.
+    pub source_url: Option<String>,
+    /// Device mount point of a sync.
+    pub device_mount_point: Option<String>,
+    /// Tracks downloaded or files copied.
+    pub items_succeeded: usize,
+    /// Tracks or files that failed.
+    pub items_failed: usize,
+    /// Bytes downloaded or copied.
+    pub bytes: u64,
+    /// Error message, if the operation failed.
+    pub error: Option<String>,
+    /// How long the operation took, in milliseconds, if measured.
+    #[serde(default)]
+    pub elapsed_ms: Option<u64>,
+    /// Videos downloaded or failed by a download; skipped videos are left
+    /// out.
+    #[serde(default)]
+    pub videos: Vec<HistoryVideo>,
+}
+
+impl HistoryEntry {
+    fn new(kind: HistoryKind, playlists: Vec<String>) -> Self {
+        Self {
+            id: 0,
+            kind,
+            status: HistoryStatus::Completed,
+            finished_at: unix_now(),
+            playlists,
+            source_url: None,
+            device_mount_point: None,
+            items_succeeded: 0,
+            items_failed: 0,
+            bytes: 0,
+            error: None,
+            elapsed_ms: None,
+            videos: Vec::new(),
+        }
+    }
+
+    /// A download of `source_url` into `playlist` that just ended.
+    #[must_use]
+    pub fn download(playlist: impl Into<String>, source_url: impl Into<String>) -> Self {
+        Self {
+            source_url: Some(source_url.into()),
+            ..Self::new(HistoryKind::Download, vec![playlist.into()])
+        }
+    }
+
+    /// A sync of `playlists` to the device at `device_mount_point` that just
+    /// ended.
+    #[must_use]
+    pub fn sync(playlists: Vec<String>, device_mount_point: impl Into<String>) -> Self {
+        Self {
+            device_mount_point: Some(device_mount_point.into()),
+            ..Self::new(HistoryKind::Sync, playlists)
+        }
+    }
+
+    /// Set the item counts and bytes, deriving the status from the counts.
+    #[must_use]
+    pub const fn with_counts(mut self, succeeded: usize, failed: usize, bytes: u64) -> Self {
+        self.items_succeeded = succeeded;
+        self.items_failed = failed;
+        self.bytes = bytes;
+        self.status = HistoryStatus::from_counts(succeeded, failed);
+        self
+    }
+
+    /// Set the counts and videos from download results, measuring the
+    /// downloaded files.
+    ///
+    /// Skipped videos count neither as succeeded nor as failed.
+    #[must_use]
+    pub fn with_download_results(mut self, results: &[DownloadResult]) -> Self {
+        self.videos = results
+            .iter()
+            .filter(|r| r.success || r.error.is_some())
+            .map(|r| HistoryVideo {
+                video_id: r.video.id.clone(),
+                title: r.video.title.clone(),
+                success: r.success,
+                bytes: if r.success {
+                    r.output_paths()
+                        .iter()
+                        .filter_map(|path| fs::metadata(path).ok())
+                        .map(|metadata| metadata.len())
+                        .sum()
+                } else {
+                    0
+                },
+                duration_secs: r.video.duration_secs,
+                error: r.error.clone(),
+            })
+            .collect();
+        let succeeded = self.videos.iter().filter(|video| video.success).count();
+        let failed = self.videos.len() - succeeded;
+        let bytes = self.videos.iter().map(|video| video.bytes).sum();
+        self.with_counts(succeeded, failed, bytes)
+    }
+
+    /// Set how long the operation took.
+    #[must_use]
+    pub fn with_elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed_ms = Some(u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX));
+        self
+    }
+
+    /// Average transfer speed in bytes per second, if the operation was
+    /// timed.
+    #[must_use]
+    pub fn speed_bps(&self) -> Option<u64> {
+        self.elapsed_ms
+            .filter(|&ms| ms > 0)
+            .map(|ms| self.bytes.saturating_mul(1000) / ms)
+    }
+
+    /// Mark the operation as failed with `error`.
+    #[must_use]
+    pub fn failed(mut self, error: impl Into<String>) -> Self {
+        self.status = HistoryStatus::Failed;
+        self.error = Some(error.into());
+        self
+    }
+
+    /// Mark the operation as cancelled.
+    #[must_use]
+    pub const fn cancelled(mut self) -> Self {
+        self.status = HistoryStatus::Cancelled;
+        self
+    }
+}
+
+/// Which history entries to return.
+///
+/// Unset fields match every entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HistoryFilter {
+    /// Only downloads or only syncs.
+    pub kind: Option<HistoryKind>,
+    /// Only entries with this status.
+    pub status: Option<HistoryStatus>,
+    /// Only entries involving this playlist.
+    pub playlist: Option<String>,
+    /// Only entries that ended at or after this time (Unix seconds).
+    pub since: Option<u64>,
+    /// Only entries that ended before this time (Unix seconds).
+    pub until: Option<u64>,
+    /// Only downloads of this video.
+    pub video_id: Option<String>,
+}
+
+impl HistoryFilter {
+    /// Whether `entry` passes the filter.
+    #[must_use]
+    pub fn matches(&self, entry: &HistoryEntry) -> bool {
+        self.kind.is_none_or(|kind| entry.kind == kind)
+            && self.status.is_none_or(|status| entry.status == status)
+            && self
+                .playlist
+                .as_ref()
+                .is_none_or(|playlist| entry.playlists.contains(playlist))
+            && self.since.is_none_or(|since| entry.finished_at >= since)
+            && self.until.is_none_or(|until| entry.finished_at < until)
+            && self
+                .video_id
+                .as_ref()
+                .is_none_or(|video_id| entry.videos.iter().any(|video| &video.video_id == video_id))
+    }
+}
+
+/// Bytes downloaded in one week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeeklyBytes {
+    /// Start of the week: Monday 00:00 UTC, in Unix seconds.
+    pub week_start: u64,
+    /// Bytes downloaded that week.
+    pub bytes: u64,
+}
+
+/// Start of the week containing `timestamp`: Monday 00:00 UTC.
+#[must_use]
+pub const fn week_start(timestamp: u64) -> u64 {
+    let shifted = timestamp + EPOCH_WEEKDAY_OFFSET_SECS;
+    (shifted - shifted % WEEK_SECS).saturating_sub(EPOCH_WEEKDAY_OFFSET_SECS)
+}
+
+/// Bytes downloaded per week over the `weeks` weeks up to the one containing
+/// `now`, oldest first. Weeks without downloads are included with zero bytes.
+#[must_use]
+pub fn weekly_download_bytes(entries: &[HistoryEntry], weeks: usize, now: u64) -> Vec<WeeklyBytes> {
+    let current = week_start(now);
+    let mut buckets: Vec<WeeklyBytes> = (0..weeks as u64)
+        .rev()
+        .filter_map(|back| current.checked_sub(back * WEEK_SECS))
+        .map(|week_start| WeeklyBytes {
+            week_start,
+            bytes: 0,
+        })
+        .collect();
+
+    for entry in entries
+        .iter()
+        .filter(|entry| entry.kind == HistoryKind::Download)
+    {
+        let start = week_start(entry.finished_at);
+        if let Some(bucket) = buckets.iter_mut().find(|b| b.week_start == start) {
+            bucket.bytes += entry.bytes;
+        }
+    }
+    buckets
+}
+
+/// Start of the month containing `timestamp`: the 1st at 00:00 UTC.
+#[must_use]
+pub const fn month_start(timestamp: u64) -> u64 {
+    // Civil from days, counting from 0000-03-01 so leap days end the year
+    let days = timestamp / DAY_SECS + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let first_of_month = era * 146_097 + 365 * year_of_era + year_of_era / 4 - year_of_era / 100
+        + (153 * month_from_march + 2) / 5;
+    (first_of_month - 719_468) * DAY_SECS
+}
+
+/// Totals over the downloads in the history.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryStats {
+    /// Number of downloads.
+    pub downloads: usize,
+    /// Videos downloaded.
+    pub videos_downloaded: usize,
+    /// Videos that failed.
+    pub videos_failed: usize,
+    /// Bytes downloaded.
+    pub total_bytes: u64,
+    /// Start of the current month (Unix seconds).
+    pub month_start: u64,
+    /// Bytes downloaded since the start of the month.
+    pub bytes_this_month: u64,
+    /// Length of the downloaded audio in seconds, where known.
+    pub total_audio_secs: u64,
+    /// Average download speed in bytes per second, over the timed
+    /// downloads.
+    pub average_speed_bps: Option<u64>,
+}
+
+/// Totals over the downloads among `entries`, with the month containing
+/// `now` as the current one.
+#[must_use]
+pub fn history_stats(entries: &[HistoryEntry], now: u64) -> HistoryStats {
+    let month_start = month_start(now);
+    let mut stats = HistoryStats {
+        month_start,
+        ..HistoryStats::default()
+    };
+    let (mut timed_bytes, mut timed_ms) = (0_u64, 0_u64);
+    for entry in entries
+        .iter()
+        .filter(|entry| entry.kind == HistoryKind::Download)
+    {
+        stats.downloads += 1;
+        stats.videos_downloaded += entry.items_succeeded;
+        stats.videos_failed += entry.items_failed;
+        stats.total_bytes += entry.bytes;
+        if entry.finished_at >= month_start {
+            stats.bytes_this_month += entry.bytes;
+        }
+        stats.total_audio_secs += entry
+            .videos
+            .iter()
+            .filter(|video| video.success)
+            .filter_map(|video| video.duration_secs)
+            .sum::<u64>();
+        if let Some(ms) = entry.elapsed_ms.filter(|&ms| ms > 0 && entry.bytes > 0) {
+            timed_bytes += entry.bytes;
+            timed_ms += ms;
+        }
+    }
+    stats.average_speed_bps = (timed_ms > 0).then(|| timed_bytes.saturating_mul(1000) / timed_ms);
+    stats
+}
+
+/// Filtered history with its aggregates, as shown on the history page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryReport {
+    /// Matching entries, newest first.
+    pub entries: Vec<HistoryEntry>,
+    /// Every playlist that appears in the history, sorted.
+    pub playlists: Vec<String>,
+    /// Bytes downloaded per week by the matching entries, oldest first.
+    pub weekly_download_bytes: Vec<WeeklyBytes>,
+}
+
+/// Persistent history of downloads and syncs.
+#[derive(Debug)]
+pub struct HistoryStore {
+    path: PathBuf,
+    entries: Mutex<Vec<HistoryEntry>>,
+}
+
+impl HistoryStore {
+    /// Open the history at `path`.
+    ///
+    /// A missing file yields an empty history; an unreadable one is logged
+    /// and replaced on the next write.
+    #[must_use]
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let entries = Self::load(&path);
+        Self {
+            path,
+            entries: Mutex::new(entries),
+        }
+    }
+
+    /// Open the history in its default location next to the config file.
+    #[must_use]
+    pub fn open_default() -> Self {
+        let config_path = AppConfig::config_file_path();
+        let dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+        Self::open(dir.join(HISTORY_FILE))
+    }
+
+    fn load(path: &Path) -> Vec<HistoryEntry> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+            Err(e) => {
+                warn!("Failed to read history {}: {}", path.display(), e);
+                return Vec::new();
+            }
+        };
+        serde_json::from_str(&contents).unwrap_or_else(|e| {
+            warn!("Discarding corrupt history {}: {}", path.display(), e);
+            Vec::new()
+        })
+    }
+
+    fn save(&self, entries: &[HistoryEntry]) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(entries)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Vec<HistoryEntry>>> {
+        self.entries
+            .lock()
+            .map_err(|_| Error::internal("History lock poisoned"))
+    }
+
+    /// Add `entry` to the history, returning its assigned ID.
+    pub fn record(&self, mut entry: HistoryEntry) -> Result<HistoryEntryId> {
+        let mut entries = self.lock()?;
+        entry.id = entries.last().map_or(0, |last| last.id + 1);
+        let id = entry.id;
+        entries.push(entry);
+        let excess = entries.len().saturating_sub(MAX_HISTORY_ENTRIES);
+        entries.drain(..excess);
+        self.save(&entries)?;
+        debug!("Recorded history entry {}", id);
+        Ok(id)
+    }
+
+    /// Entries matching `filter`, newest first.
+    #[must_use]
+    pub fn entries(&self, filter: &HistoryFilter) -> Vec<HistoryEntry> {
+        self.lock()
+            .map(|entries| {
+                entries
+                    .iter()
+                    .rev()
+                    .filter(|entry| filter.matches(entry))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Entries matching `filter` with the playlists in the history and the
+    /// bytes downloaded over the last `weeks` weeks.
+    #[must_use]
+    pub fn report(&self, filter: &HistoryFilter, weeks: usize) -> HistoryReport {
+        let entries = self.entries(filter);
+        let playlists = self
+            .lock()
+            .map(|all| {
+                all.iter()
+                    .flat_map(|entry| entry.playlists.iter().cloned())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect()
+            })
+            .unwrap_or_default();
+        let weekly_download_bytes = weekly_download_bytes(&entries, weeks, unix_now());
+        HistoryReport {
+            entries,
+            playlists,
+            weekly_download_bytes,
+        }
+    }
+
+    /// Totals over every download in the history.
+    #[must_use]
+    pub fn stats(&self) -> HistoryStats {
+        self.lock()
+            .map(|entries| history_stats(&entries, unix_now()))
+            .unwrap_or_default()
+    }
+
+    /// Remove the entries that ended before `before` (Unix seconds),
+    /// returning how many were removed.
+    pub fn prune(&self, before: u64) -> Result<usize> {
+        let mut entries = self.lock()?;
+        let count = entries.len();
+        entries.retain(|entry| entry.finished_at >= before);
+        let removed = count - entries.len();
+        if removed > 0 {
+            self.save(&entries)?;
+            debug!("Pruned {} history entries", removed);
+        }
+        Ok(removed)
+    }
+
+    /// Remove every entry, returning how many were removed.
+    pub fn clear(&self) -> Result<usize> {
+        let mut entries = self.lock()?;
+        let removed = entries.len();
+        entries.clear();
+        self.save(&entries)?;
+        Ok(removed)
+    }
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Monday 2024-01-08 00:00 UTC.
+    const MONDAY: u64 = 1_704_672_000;
+
+    fn download_at(playlist: &str, finished_at: u64, bytes: u64) -> HistoryEntry {
+        HistoryEntry {
+            finished_at,
+            ..HistoryEntry::download(playlist, "https://youtube.com/playlist?list=x")
+                .with_counts(1, 0, bytes)
+        }
+    }
+
+    #[test]
+    fn test_status_from_counts() {
+        assert_eq!(HistoryStatus::from_counts(3, 0), HistoryStatus::Completed);
+        assert_eq!(HistoryStatus::from_counts(0, 0), HistoryStatus::Completed);
+        assert_eq!(
+            HistoryStatus::from_counts(2, 1),
+            HistoryStatus::CompletedWithErrors
+        );
+        assert_eq!(HistoryStatus::from_counts(0, 4), HistoryStatus::Failed);
+    }
+
+    #[test]
+    fn test_record_and_reopen() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(HISTORY_FILE);
+        let store = HistoryStore::open(&path);
+
+        store.record(download_at("Mix", MONDAY, 100)).unwrap();
+        store
+            .record(HistoryEntry::sync(vec!["Mix".to_string()], "/media/PLAYER").cancelled())
+            .unwrap();
+
+        let reopened = HistoryStore::open(&path);
+        let entries = reopened.entries(&HistoryFilter::default());
+        assert_eq!(entries.len(), 2);
+        // Newest first, with increasing IDs
+        assert_eq!(entries[0].id, 1);
+        assert_eq!(entries[0].kind, HistoryKind::Sync);
+        assert_eq!(entries[0].status, HistoryStatus::Cancelled);
+        assert_eq!(entries[1].id, 0);
+
+        assert_eq!(reopened.clear().unwrap(), 2);
+        assert!(
+            HistoryStore::open(&path)
+                .entries(&HistoryFilter::default())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let temp = TempDir::new().unwrap();
+        let store = HistoryStore::open(temp.path().join(HISTORY_FILE));
+        for i in 0..=MAX_HISTORY_ENTRIES as u64 {
+            store.record(download_at("Mix", MONDAY + i, 1)).unwrap();
+        }
+
+        let entries = store.entries(&HistoryFilter::default());
+        assert_eq!(entries.len(), MAX_HISTORY_ENTRIES);
+        // The oldest entry was dropped
+        assert_eq!(entries.last().unwrap().id, 1);
+    }
+
+    #[test]
+    fn test_corrupt_history_is_discarded() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(HISTORY_FILE);
+        fs::write(&path, "not json").unwrap();
+
+        let store = HistoryStore::open(&path);
+        assert!(store.entries(&HistoryFilter::default()).is_empty());
+        store.record(download_at("Mix", MONDAY, 1)).unwrap();
+        assert_eq!(
+            HistoryStore::open(&path)
+                .entries(&HistoryFilter::default())
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_filter() {
+        let download = download_at("Mix", MONDAY, 100);
+        let sync = HistoryEntry {
+            finished_at: MONDAY + 10,
+            ..HistoryEntry::sync(vec!["Mix".to_string(), "Chill".to_string()], "/media/P")
+                .failed("Device removed")
+        };
+
+        let by_kind = HistoryFilter {
+            kind: Some(HistoryKind::Sync),
+            ..HistoryFilter::default()
+        };
+        assert!(!by_kind.matches(&download));
+        assert!(by_kind.matches(&sync));
+
+        let by_status = HistoryFilter {
+            status: Some(HistoryStatus::Failed),
+            ..HistoryFilter::default()
+        };
+        assert!(!by_status.matches(&download));
+        assert!(by_status.matches(&sync));
+
+        let by_playlist = HistoryFilter {
+            playlist: Some("Chill".to_string()),
+            ..HistoryFilter::default()
+        };
+        assert!(!by_playlist.matches(&download));
+        assert!(by_playlist.matches(&sync));
+
+        let by_date = HistoryFilter {
+            since: Some(MONDAY + 5),
+            until: Some(MONDAY + 10),
+            ..HistoryFilter::default()
+        };
+        assert!(!by_date.matches(&download));
+        // `until` is exclusive
+        assert!(!by_date.matches(&sync));
+    }
+
+    #[test]
+    fn test_prune_and_filter_by_video() {
+        let temp = TempDir::new().unwrap();
+        let store = HistoryStore::open(temp.path().join(HISTORY_FILE));
+        store.record(download_at("Mix", MONDAY, 1)).unwrap();
+        let mut recent = download_at("Mix", MONDAY + WEEK_SECS, 1);
+        recent.videos.push(HistoryVideo {
+            video_id: "abc".to_string(),
+            title: "Song".to_string(),
+            success: true,
+            bytes: 1,
+            duration_secs: Some(200),
+            error: None,
+        });
+        store.record(recent).unwrap();
+
+        let by_video = HistoryFilter {
+            video_id: Some("abc".to_string()),
+            ..HistoryFilter::default()
+        };
+        assert_eq!(store.entries(&by_video).len(), 1);
+
+        assert_eq!(store.prune(MONDAY + 1).unwrap(), 1);
+        assert_eq!(store.prune(MONDAY + 1).unwrap(), 0);
+        let entries = store.entries(&HistoryFilter::default());
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].videos[0].video_id, "abc");
+    }
+
+    #[test]
+    fn test_month_start() {
+        // 2024-01-01
+        assert_eq!(month_start(MONDAY), 1_704_067_200);
+        // 2024-02-29 12:00 is in February of a leap year
+        assert_eq!(month_start(1_709_208_000), 1_706_745_600);
+        assert_eq!(month_start(1_709_251_200), 1_709_251_200);
+        assert_eq!(month_start(0), 0);
+    }
+
+    #[test]
+    fn test_history_stats() {
+        let timed = |finished_at, bytes, secs| {
+            download_at("Mix", finished_at, bytes).with_elapsed(Duration::from_secs(secs))
+        };
+        let mut this_month = timed(MONDAY, 3_000, 1);
+        this_month.videos.push(HistoryVideo {
+            video_id: "abc".to_string(),
+            title: "Song".to_string(),
+            success: true,
+            bytes: 3_000,
+            duration_secs: Some(200),
+            error: None,
+        });
+        let entries = vec![
+            this_month,
+            // December
+            timed(MONDAY - 2 * WEEK_SECS, 1_000, 3),
+            // Untimed downloads don't count towards the speed
+            download_at("Mix", MONDAY, 500),
+            HistoryEntry {
+                finished_at: MONDAY,
+                ..HistoryEntry::sync(vec!["Mix".to_string()], "/media/P").with_counts(1, 0, 900)
+            },
+        ];
+
+        let stats = history_stats(&entries, MONDAY + 1_000);
+        assert_eq!(stats.downloads, 3);
+        assert_eq!(stats.videos_downloaded, 3);
+        assert_eq!(stats.total_bytes, 4_500);
+        assert_eq!(stats.month_start, 1_704_067_200);
+        assert_eq!(stats.bytes_this_month, 3_500);
+        assert_eq!(stats.total_audio_secs, 200);
+        assert_eq!(stats.average_speed_bps, Some(1_000));
+        assert_eq!(entries[0].speed_bps(), Some(3_000));
+        assert_eq!(entries[2].speed_bps(), None);
+    }
+
+    #[test]
+    fn test_week_start() {
+        assert_eq!(week_start(MONDAY), MONDAY);
+        // Sunday 23:59 belongs to the week before
+        assert_eq!(week_start(MONDAY - 60), MONDAY - WEEK_SECS);
+        assert_eq!(week_start(MONDAY + 6 * 24 * 60 * 60), MONDAY);
+        assert_eq!(week_start(0), 0);
+    }
+
+    #[test]
+    fn test_weekly_download_bytes() {
+        let entries = vec![
+            download_at("Mix", MONDAY + 100, 10),
+            download_at("Mix", MONDAY + 200, 5),
+            download_at("Mix", MONDAY - WEEK_SECS, 7),
+            // Older than the range
+            download_at("Mix", MONDAY - 5 * WEEK_SECS, 1_000),
+            HistoryEntry {
+                finished_at: MONDAY,
+                ..HistoryEntry::sync(vec!["Mix".to_string()], "/media/P").with_counts(1, 0, 500)
+            },
+        ];
+
+        let weeks = weekly_download_bytes(&entries, 3, MONDAY + 1_000);
+        assert_eq!(
+            weeks,
+            vec![
+                WeeklyBytes {
+                    week_start: MONDAY - 2 * WEEK_SECS,
+                    bytes: 0,
+                },
+                WeeklyBytes {
+                    week_start: MONDAY - WEEK_SECS,
+                    bytes: 7,
+                },
+                WeeklyBytes {
+                    week_start: MONDAY,
+                    bytes: 15,
+                },
+            ]
+        );
+    }
+}
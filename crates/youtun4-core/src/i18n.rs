@@ -0,0 +1,894 @@
+//! Message keys and translation catalogs for backend-generated text.
+//!
+//! Text the backend hands to the frontend (error descriptions, capacity
+//! messages) is described by a [`Message`]: a stable key plus named
+//! parameters, so the frontend can show it in the user's language. The
+//! backend renders messages itself with [`translate`] for text it shows
+//! directly, such as OS notifications.
+//!
+//! Catalogs map keys to templates with `{name}` placeholders. A key missing
+//! from a catalog falls back to English, and a key missing from English
+//! renders as the key itself.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, ErrorCode};
+
+/// A language the app ships translations for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    /// English.
+    #[default]
+    En,
+    /// French.
+    Fr,
+    /// Spanish.
+    Es,
+    /// German.
+    De,
+}
+
+impl Locale {
+    /// Every supported locale.
+    pub const ALL: [Self; 4] = [Self::En, Self::Fr, Self::Es, Self::De];
+
+    /// The two-letter language code.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Fr => "fr",
+            Self::Es => "es",
+            Self::De => "de",
+        }
+    }
+
+    /// Parse a language tag such as `fr`, `fr-FR` or `de_DE.UTF-8`.
+    ///
+    /// Returns `None` for languages without a translation.
+    #[must_use]
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag
+            .split(['-', '_', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.code() == language)
+    }
+
+    /// The locale of the user's environment (`LC_ALL`, `LC_MESSAGES` or
+    /// `LANG`), falling back to English.
+    #[must_use]
+    pub fn system() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Self::from_tag(&value))
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// A translatable message: a catalog key and its parameters.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Message {
+    /// Catalog key, e.g. `capacity.ready`.
+    pub key: String,
+    /// Values for the template's `{name}` placeholders.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+}
+
+impl Message {
+    /// Create a message without parameters.
+    #[must_use]
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    /// Add a parameter.
+    #[must_use]
+    pub fn with(mut self, name: &str, value: impl ToString) -> Self {
+        self.params.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Describe an error for the user.
+    ///
+    /// Codes with a catalog entry use it; others carry the error's own
+    /// (English) text under [`keys::ERROR_GENERIC`].
+    #[must_use]
+    pub fn for_error(error: &Error) -> Self {
+        let code = error.code();
+        if code == ErrorCode::RateLimited
+            && let Some(seconds) = error.retry_delay_secs()
+        {
+            return Self::new(keys::ERROR_RATE_LIMITED_RETRY).with("seconds", seconds);
+        }
+        let key = error_key(code);
+        if template(Locale::En, &key).is_some() {
+            Self::new(key)
+        } else {
+            Self::new(keys::ERROR_GENERIC).with("details", error)
+        }
+    }
+
+    /// Render the message in `locale`.
+    #[must_use]
+    pub fn render(&self, locale: Locale) -> String {
+        translate(locale, self)
+    }
+}
+
+/// Catalog key describing an error code, e.g. `error.device_not_found`.
+#[must_use]
+pub fn error_key(code: ErrorCode) -> String {
+    let name = serde_json::to_value(code)
+        .ok()
+        .and_then(|value| value.as_str().map(String::from))
+        .unwrap_or_default();
+    format!("error.{name}")
+}
+
+/// Keys of messages built by the backend.
+pub mod keys {
+    /// An error without a dedicated message: `{details}`.
+    pub const ERROR_GENERIC: &str = "error.generic";
+    /// Rate limited with a known delay: `{seconds}`.
+    pub const ERROR_RATE_LIMITED_RETRY: &str = "error.rate_limited.retry";
+
+    /// The playlists don't fit: `{deficit}`, `{device}`.
+    pub const CAPACITY_INSUFFICIENT: &str = "capacity.insufficient";
+    /// The device will be nearly full: `{device}`, `{percent}`.
+    pub const CAPACITY_LIMITED: &str = "capacity.limited";
+    /// The playlists fit: `{available}`, `{device}`.
+    pub const CAPACITY_READY: &str = "capacity.ready";
+
+    /// Title for a finished sync.
+    pub const NOTIFY_SYNC_COMPLETE: &str = "notification.sync_complete";
+    /// A playlist was synced: `{playlist}`, `{files}`.
+    pub const NOTIFY_SYNC_COMPLETE_BODY: &str = "notification.sync_complete.body";
+    /// Several playlists were synced: `{playlists}`, `{files}`.
+    pub const NOTIFY_SYNC_ALL_COMPLETE_BODY: &str = "notification.sync_all_complete.body";
+    /// Title for a failed sync.
+    pub const NOTIFY_SYNC_FAILED: &str = "notification.sync_failed";
+    /// Title for a finished download.
+    pub const NOTIFY_DOWNLOAD_COMPLETE: &str = "notification.download_complete";
+    /// A playlist was downloaded: `{downloaded}`, `{total}`.
+    pub const NOTIFY_DOWNLOAD_COMPLETE_BODY: &str = "notification.download_complete.body";
+    /// Title for a download that finished with errors.
+    pub const NOTIFY_DOWNLOAD_ERRORS: &str = "notification.download_errors";
+    /// Title for a failed download.
+    pub const NOTIFY_DOWNLOAD_FAILED: &str = "notification.download_failed";
+    /// A queued download finished.
+    pub const NOTIFY_QUEUE_ITEM_COMPLETE_BODY: &str = "notification.queue_item_complete.body";
+    /// Title for a failed queued download.
+    pub const NOTIFY_QUEUE_ITEM_FAILED: &str = "notification.queue_item_failed";
+    /// Title for a connected device.
+    pub const NOTIFY_DEVICE_CONNECTED: &str = "notification.device_connected";
+    /// A device is ready: `{device}`.
+    pub const NOTIFY_DEVICE_CONNECTED_BODY: &str = "notification.device_connected.body";
+    /// Body for a failure without details.
+    pub const NOTIFY_UNKNOWN_ERROR: &str = "notification.unknown_error";
+    /// Body of the test notification.
+    pub const NOTIFY_TEST_BODY: &str = "notification.test.body";
+}
+
+type Catalog = &'static [(&'static str, &'static str)];
+
+const EN: Catalog = &[
+    ("error.generic", "{details}"),
+    (
+        "error.rate_limited.retry",
+        "YouTube is limiting requests. Try again in {seconds} seconds.",
+    ),
+    (
+        "error.device_not_found",
+        "The device could not be found. Make sure it is connected.",
+    ),
+    ("error.device_not_mounted", "The device is not mounted."),
+    (
+        "error.device_disconnected",
+        "The device was disconnected. Reconnect it and try again.",
+    ),
+    ("error.device_read_only", "The device is read-only."),
+    (
+        "error.device_busy",
+        "The device is busy. Close any apps using it and try again.",
+    ),
+    (
+        "error.insufficient_space",
+        "There isn't enough space on the device.",
+    ),
+    ("error.permission_denied", "Permission denied."),
+    ("error.pin_required", "This needs the parent PIN."),
+    (
+        "error.unsupported_file_system",
+        "The device's file system is not supported.",
+    ),
+    (
+        "error.platform_not_supported",
+        "This isn't supported on your platform.",
+    ),
+    (
+        "error.not_a_playlist",
+        "That URL is not a YouTube playlist.",
+    ),
+    (
+        "error.network",
+        "Network error. Check your connection and try again.",
+    ),
+    ("error.timeout", "The request timed out. Try again."),
+    (
+        "error.rate_limited",
+        "YouTube is limiting requests. Try again later.",
+    ),
+    ("error.video_unavailable", "The video is unavailable."),
+    (
+        "error.offline",
+        "Offline mode is enabled. Turn it off in Settings to go online.",
+    ),
+    (
+        "error.library_limit_reached",
+        "Your library has reached its size limit. Free up space or raise the limit in Settings.",
+    ),
+    (
+        "error.low_disk_space",
+        "Your disk is almost full. Free up space before downloading.",
+    ),
+    (
+        "error.transfer_interrupted",
+        "The transfer was interrupted. Try again.",
+    ),
+    (
+        "error.integrity_check_failed",
+        "A file failed its integrity check after transfer.",
+    ),
+    (
+        "error.playlist_not_found",
+        "The playlist could not be found.",
+    ),
+    (
+        "error.playlist_exists",
+        "A playlist with that name already exists.",
+    ),
+    ("error.playlist_empty", "The playlist has no tracks."),
+    ("error.cancelled", "The operation was cancelled."),
+    (
+        "error.task_busy",
+        "Another task is already using this. Wait for it to finish or cancel it.",
+    ),
+    (
+        "capacity.insufficient",
+        "Insufficient space: need {deficit} more on {device}",
+    ),
+    (
+        "capacity.limited",
+        "Limited space: {device} will be {percent}% full after sync",
+    ),
+    (
+        "capacity.ready",
+        "Ready to sync: {available} available on {device}",
+    ),
+    ("notification.sync_complete", "Sync complete"),
+    (
+        "notification.sync_complete.body",
+        "'{playlist}' synced ({files} files transferred)",
+    ),
+    (
+        "notification.sync_all_complete.body",
+        "{playlists} playlists synced ({files} files transferred)",
+    ),
+    ("notification.sync_failed", "Sync failed"),
+    ("notification.download_complete", "Download complete"),
+    (
+        "notification.download_complete.body",
+        "{downloaded} of {total} tracks downloaded",
+    ),
+    (
+        "notification.download_errors",
+        "Download finished with errors",
+    ),
+    ("notification.download_failed", "Download failed"),
+    (
+        "notification.queue_item_complete.body",
+        "A queued download finished",
+    ),
+    ("notification.queue_item_failed", "Queued download failed"),
+    ("notification.device_connected", "Device connected"),
+    ("notification.device_connected.body", "{device} is ready"),
+    ("notification.unknown_error", "An unknown error occurred"),
+    (
+        "notification.test.body",
+        "Notifications are working. You'll be notified about syncs, downloads and errors.",
+    ),
+];
+
+const FR: Catalog = &[
+    ("error.generic", "{details}"),
+    (
+        "error.rate_limited.retry",
+        "YouTube limite les requêtes. Réessayez dans {seconds} secondes.",
+    ),
+    (
+        "error.device_not_found",
+        "L'appareil est introuvable. Vérifiez qu'il est connecté.",
+    ),
+    ("error.device_not_mounted", "L'appareil n'est pas monté."),
+    (
+        "error.device_disconnected",
+        "L'appareil a été déconnecté. Reconnectez-le et réessayez.",
+    ),
+    ("error.device_read_only", "L'appareil est en lecture seule."),
+    (
+        "error.device_busy",
+        "L'appareil est occupé. Fermez les applications qui l'utilisent et réessayez.",
+    ),
+    (
+        "error.insufficient_space",
+        "Il n'y a pas assez d'espace sur l'appareil.",
+    ),
+    ("error.permission_denied", "Autorisation refusée."),
+    (
+        "error.pin_required",
+        "Cette action nécessite le code parental.",
+    ),
+    (
+        "error.unsupported_file_system",
+        "Le système de fichiers de l'appareil n'est pas pris en charge.",
+    ),
+    (
+        "error.platform_not_supported",
+        "Cette fonction n'est pas prise en charge sur votre système.",
+    ),
+    (
+        "error.not_a_playlist",
+        "Cette URL n'est pas une playlist YouTube.",
+    ),
+    (
+        "error.network",
+        "Erreur réseau. Vérifiez votre connexion et réessayez.",
+    ),
+    ("error.timeout", "La requête a expiré. Réessayez."),
+    (
+        "error.rate_limited",
+        "YouTube limite les requêtes. Réessayez plus tard.",
+    ),
+    ("error.video_unavailable", "La vidéo n'est pas disponible."),
+    (
+        "error.offline",
+        "Le mode hors ligne est activé. Désactivez-le dans les paramètres pour vous connecter.",
+    ),
+    (
+        "error.library_limit_reached",
+        "Votre bibliothèque a atteint sa taille maximale. Libérez de l'espace ou augmentez la limite dans les paramètres.",
+    ),
+    (
+        "error.low_disk_space",
+        "Votre disque est presque plein. Libérez de l'espace avant de télécharger.",
+    ),
+    (
+        "error.transfer_interrupted",
+        "Le transfert a été interrompu. Réessayez.",
+    ),
+    (
+        "error.integrity_check_failed",
+        "Un fichier n'a pas passé la vérification d'intégrité après le transfert.",
+    ),
+    ("error.playlist_not_found", "La playlist est introuvable."),
+    (
+        "error.playlist_exists",
+        "Une playlist portant ce nom existe déjà.",
+    ),
+    (
+        "error.playlist_empty",
+        "La playlist ne contient aucune piste.",
+    ),
+    ("error.cancelled", "L'opération a été annulée."),
+    (
+        "error.task_busy",
+        "Une autre tâche utilise déjà cette ressource. Attendez qu'elle se termine ou annulez-la.",
+    ),
+    (
+        "capacity.insufficient",
+        "Espace insuffisant : il manque {deficit} sur {device}",
+    ),
+    (
+        "capacity.limited",
+        "Espace limité : {device} sera plein à {percent} % après la synchronisation",
+    ),
+    (
+        "capacity.ready",
+        "Prêt à synchroniser : {available} disponibles sur {device}",
+    ),
+    ("notification.sync_complete", "Synchronisation terminée"),
+    (
+        "notification.sync_complete.body",
+        "« {playlist} » synchronisée ({files} fichiers transférés)",
+    ),
+    (
+        "notification.sync_all_complete.body",
+        "{playlists} playlists synchronisées ({files} fichiers transférés)",
+    ),
+    ("notification.sync_failed", "Échec de la synchronisation"),
+    ("notification.download_complete", "Téléchargement terminé"),
+    (
+        "notification.download_complete.body",
+        "{downloaded} pistes sur {total} téléchargées",
+    ),
+    (
+        "notification.download_errors",
+        "Téléchargement terminé avec des erreurs",
+    ),
+    ("notification.download_failed", "Échec du téléchargement"),
+    (
+        "notification.queue_item_complete.body",
+        "Un téléchargement en file d'attente est terminé",
+    ),
+    (
+        "notification.queue_item_failed",
+        "Échec d'un téléchargement en file d'attente",
+    ),
+    ("notification.device_connected", "Appareil connecté"),
+    ("notification.device_connected.body", "{device} est prêt"),
+    (
+        "notification.unknown_error",
+        "Une erreur inconnue s'est produite",
+    ),
+    (
+        "notification.test.body",
+        "Les notifications fonctionnent. Vous serez averti des synchronisations, des téléchargements et des erreurs.",
+    ),
+];
+
+const ES: Catalog = &[
+    ("error.generic", "{details}"),
+    (
+        "error.rate_limited.retry",
+        "YouTube está limitando las solicitudes. Inténtalo de nuevo en {seconds} segundos.",
+    ),
+    (
+        "error.device_not_found",
+        "No se encontró el dispositivo. Asegúrate de que esté conectado.",
+    ),
+    (
+        "error.device_not_mounted",
+        "El dispositivo no está montado.",
+    ),
+    (
+        "error.device_disconnected",
+        "El dispositivo se desconectó. Vuelve a conectarlo e inténtalo de nuevo.",
+    ),
+    (
+        "error.device_read_only",
+        "El dispositivo es de solo lectura.",
+    ),
+    (
+        "error.device_busy",
+        "El dispositivo está ocupado. Cierra las aplicaciones que lo usan e inténtalo de nuevo.",
+    ),
+    (
+        "error.insufficient_space",
+        "No hay suficiente espacio en el dispositivo.",
+    ),
+    ("error.permission_denied", "Permiso denegado."),
+    (
+        "error.pin_required",
+        "Esta acción requiere el PIN parental.",
+    ),
+    (
+        "error.unsupported_file_system",
+        "El sistema de archivos del dispositivo no es compatible.",
+    ),
+    (
+        "error.platform_not_supported",
+        "Esto no es compatible con tu sistema.",
+    ),
+    (
+        "error.not_a_playlist",
+        "Esa URL no es una lista de reproducción de YouTube.",
+    ),
+    (
+        "error.network",
+        "Error de red. Comprueba tu conexión e inténtalo de nuevo.",
+    ),
+    (
+        "error.timeout",
+        "La solicitud ha caducado. Inténtalo de nuevo.",
+    ),
+    (
+        "error.rate_limited",
+        "YouTube está limitando las solicitudes. Inténtalo más tarde.",
+    ),
+    ("error.video_unavailable", "El vídeo no está disponible."),
+    (
+        "error.offline",
+        "El modo sin conexión está activado. Desactívalo en Ajustes para conectarte.",
+    ),
+    (
+        "error.library_limit_reached",
+        "Tu biblioteca ha alcanzado su tamaño máximo. Libera espacio o aumenta el límite en Ajustes.",
+    ),
+    (
+        "error.low_disk_space",
+        "Tu disco está casi lleno. Libera espacio antes de descargar.",
+    ),
+    (
+        "error.transfer_interrupted",
+        "La transferencia se interrumpió. Inténtalo de nuevo.",
+    ),
+    (
+        "error.integrity_check_failed",
+        "Un archivo no superó la comprobación de integridad tras la transferencia.",
+    ),
+    (
+        "error.playlist_not_found",
+        "No se encontró la lista de reproducción.",
+    ),
+    (
+        "error.playlist_exists",
+        "Ya existe una lista de reproducción con ese nombre.",
+    ),
+    (
+        "error.playlist_empty",
+        "La lista de reproducción no tiene pistas.",
+    ),
+    ("error.cancelled", "Se canceló la operación."),
+    (
+        "error.task_busy",
+        "Otra tarea ya está usando esto. Espera a que termine o cancélala.",
+    ),
+    (
+        "capacity.insufficient",
+        "Espacio insuficiente: faltan {deficit} en {device}",
+    ),
+    (
+        "capacity.limited",
+        "Espacio limitado: {device} estará al {percent} % tras la sincronización",
+    ),
+    (
+        "capacity.ready",
+        "Listo para sincronizar: {available} disponibles en {device}",
+    ),
+    ("notification.sync_complete", "Sincronización completada"),
+    (
+        "notification.sync_complete.body",
+        "«{playlist}» sincronizada ({files} archivos transferidos)",
+    ),
+    (
+        "notification.sync_all_complete.body",
+        "{playlists} listas sincronizadas ({files} archivos transferidos)",
+    ),
+    ("notification.sync_failed", "Error de sincronización"),
+    ("notification.download_complete", "Descarga completada"),
+    (
+        "notification.download_complete.body",
+        "{downloaded} de {total} pistas descargadas",
+    ),
+    (
+        "notification.download_errors",
+        "Descarga completada con errores",
+    ),
+    ("notification.download_failed", "Error en la descarga"),
+    (
+        "notification.queue_item_complete.body",
+        "Ha terminado una descarga de la cola",
+    ),
+    (
+        "notification.queue_item_failed",
+        "Error en una descarga de la cola",
+    ),
+    ("notification.device_connected", "Dispositivo conectado"),
+    ("notification.device_connected.body", "{device} está listo"),
+    (
+        "notification.unknown_error",
+        "Se produjo un error desconocido",
+    ),
+    (
+        "notification.test.body",
+        "Las notificaciones funcionan. Recibirás avisos sobre sincronizaciones, descargas y errores.",
+    ),
+];
+
+const DE: Catalog = &[
+    ("error.generic", "{details}"),
+    (
+        "error.rate_limited.retry",
+        "YouTube begrenzt die Anfragen. Versuche es in {seconds} Sekunden erneut.",
+    ),
+    (
+        "error.device_not_found",
+        "Das Gerät wurde nicht gefunden. Stelle sicher, dass es verbunden ist.",
+    ),
+    (
+        "error.device_not_mounted",
+        "Das Gerät ist nicht eingebunden.",
+    ),
+    (
+        "error.device_disconnected",
+        "Die Verbindung zum Gerät wurde getrennt. Schließe es erneut an und versuche es noch einmal.",
+    ),
+    ("error.device_read_only", "Das Gerät ist schreibgeschützt."),
+    (
+        "error.device_busy",
+        "Das Gerät wird verwendet. Schließe alle Apps, die darauf zugreifen, und versuche es erneut.",
+    ),
+    (
+        "error.insufficient_space",
+        "Auf dem Gerät ist nicht genug Speicherplatz.",
+    ),
+    ("error.permission_denied", "Zugriff verweigert."),
+    (
+        "error.pin_required",
+        "Dafür ist die Eltern-PIN erforderlich.",
+    ),
+    (
+        "error.unsupported_file_system",
+        "Das Dateisystem des Geräts wird nicht unterstützt.",
+    ),
+    (
+        "error.platform_not_supported",
+        "Das wird auf deinem System nicht unterstützt.",
+    ),
+    (
+        "error.not_a_playlist",
+        "Diese URL ist keine YouTube-Playlist.",
+    ),
+    (
+        "error.network",
+        "Netzwerkfehler. Prüfe deine Verbindung und versuche es erneut.",
+    ),
+    (
+        "error.timeout",
+        "Zeitüberschreitung der Anfrage. Versuche es erneut.",
+    ),
+    (
+        "error.rate_limited",
+        "YouTube begrenzt die Anfragen. Versuche es später erneut.",
+    ),
+    ("error.video_unavailable", "Das Video ist nicht verfügbar."),
+    (
+        "error.offline",
+        "Der Offline-Modus ist aktiviert. Deaktiviere ihn in den Einstellungen, um online zu gehen.",
+    ),
+    (
+        "error.library_limit_reached",
+        "Deine Bibliothek hat ihre Größenbegrenzung erreicht. Gib Speicherplatz frei oder erhöhe die Grenze in den Einstellungen.",
+    ),
+    (
+        "error.low_disk_space",
+        "Dein Datenträger ist fast voll. Gib vor dem Herunterladen Speicherplatz frei.",
+    ),
+    (
+        "error.transfer_interrupted",
+        "Die Übertragung wurde unterbrochen. Versuche es erneut.",
+    ),
+    (
+        "error.integrity_check_failed",
+        "Eine Datei hat nach der Übertragung die Integritätsprüfung nicht bestanden.",
+    ),
+    (
+        "error.playlist_not_found",
+        "Die Playlist wurde nicht gefunden.",
+    ),
+    (
+        "error.playlist_exists",
+        "Eine Playlist mit diesem Namen existiert bereits.",
+    ),
+    ("error.playlist_empty", "Die Playlist enthält keine Titel."),
+    ("error.cancelled", "Der Vorgang wurde abgebrochen."),
+    (
+        "error.task_busy",
+        "Eine andere Aufgabe verwendet dies bereits. Warte, bis sie fertig ist, oder brich sie ab.",
+    ),
+    (
+        "capacity.insufficient",
+        "Nicht genug Speicherplatz: Auf {device} fehlen {deficit}",
+    ),
+    (
+        "capacity.limited",
+        "Wenig Speicherplatz: {device} ist nach der Synchronisierung zu {percent} % belegt",
+    ),
+    (
+        "capacity.ready",
+        "Bereit zur Synchronisierung: {available} frei auf {device}",
+    ),
+    (
+        "notification.sync_complete",
+        "Synchronisierung abgeschlossen",
+    ),
+    (
+        "notification.sync_complete.body",
+        "„{playlist}“ synchronisiert ({files} Dateien übertragen)",
+    ),
+    (
+        "notification.sync_all_complete.body",
+        "{playlists} Playlists synchronisiert ({files} Dateien übertragen)",
+    ),
+    (
+        "notification.sync_failed",
+        "Synchronisierung fehlgeschlagen",
+    ),
+    ("notification.download_complete", "Download abgeschlossen"),
+    (
+        "notification.download_complete.body",
+        "{downloaded} von {total} Titeln heruntergeladen",
+    ),
+    (
+        "notification.download_errors",
+        "Download mit Fehlern abgeschlossen",
+    ),
+    ("notification.download_failed", "Download fehlgeschlagen"),
+    (
+        "notification.queue_item_complete.body",
+        "Ein Download aus der Warteschlange ist fertig",
+    ),
+    (
+        "notification.queue_item_failed",
+        "Download aus der Warteschlange fehlgeschlagen",
+    ),
+    ("notification.device_connected", "Gerät verbunden"),
+    ("notification.device_connected.body", "{device} ist bereit"),
+    (
+        "notification.unknown_error",
+        "Ein unbekannter Fehler ist aufgetreten",
+    ),
+    (
+        "notification.test.body",
+        "Benachrichtigungen funktionieren. Du wirst über Synchronisierungen, Downloads und Fehler informiert.",
+    ),
+];
+
+const fn catalog(locale: Locale) -> Catalog {
+    match locale {
+        Locale::En => EN,
+        Locale::Fr => FR,
+        Locale::Es => ES,
+        Locale::De => DE,
+    }
+}
+
+fn template(locale: Locale, key: &str) -> Option<&'static str> {
+    catalog(locale)
+        .iter()
+        .find(|(entry, _)| *entry == key)
+        .map(|(_, template)| *template)
+}
+
+/// All templates for `locale` by key, with English filling any gaps.
+///
+/// The frontend renders [`Message`]s from this.
+#[must_use]
+pub fn translations(locale: Locale) -> BTreeMap<String, String> {
+    EN.iter()
+        .chain(catalog(locale))
+        .map(|(key, template)| ((*key).to_string(), (*template).to_string()))
+        .collect()
+}
+
+/// Render `message` in `locale`.
+#[must_use]
+pub fn translate(locale: Locale, message: &Message) -> String {
+    template(locale, &message.key)
+        .or_else(|| template(Locale::En, &message.key))
+        .map_or_else(
+            || message.key.clone(),
+            |template| interpolate(template, &message.params),
+        )
+}
+
+/// Replace `{name}` placeholders with parameters. Unknown placeholders are
+/// left as they are.
+fn interpolate(template: &str, params: &BTreeMap<String, String>) -> String {
+    params
+        .iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    fn placeholders(template: &str) -> BTreeSet<&str> {
+        template
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+
+    #[test]
+    fn test_catalogs_match_english() {
+        let english: BTreeMap<&str, &str> = EN.iter().copied().collect();
+        assert_eq!(english.len(), EN.len(), "duplicate English keys");
+
+        for locale in Locale::ALL {
+            let entries: BTreeMap<&str, &str> = catalog(locale).iter().copied().collect();
+            assert_eq!(
+                entries.keys().collect::<Vec<_>>(),
+                english.keys().collect::<Vec<_>>(),
+                "{locale} keys differ from English"
+            );
+            for (key, template) in entries {
+                assert_eq!(
+                    placeholders(template),
+                    placeholders(english[key]),
+                    "{locale} placeholders differ for {key}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_translate() {
+        let message = Message::new(keys::CAPACITY_READY)
+            .with("available", "1.20 GB")
+            .with("device", "Walkman");
+        assert_eq!(
+            message.render(Locale::En),
+            "Ready to sync: 1.20 GB available on Walkman"
+        );
+        assert_eq!(
+            message.render(Locale::Fr),
+            "Prêt à synchroniser : 1.20 GB disponibles sur Walkman"
+        );
+
+        // Unknown keys render as the key
+        assert_eq!(
+            Message::new("no.such.key").render(Locale::De),
+            "no.such.key"
+        );
+    }
+
+    #[test]
+    fn test_message_for_error() {
+        let message = Message::for_error(&Error::device_not_found("/media/player"));
+        assert_eq!(message.key, "error.device_not_found");
+        assert!(message.params.is_empty());
+
+        // Codes without a catalog entry carry the error text
+        let message = Message::for_error(&Error::Configuration("bad value".to_string()));
+        assert_eq!(message.key, keys::ERROR_GENERIC);
+        assert!(message.render(Locale::Es).contains("bad value"));
+    }
+
+    #[test]
+    fn test_locale_from_tag() {
+        assert_eq!(Locale::from_tag("fr"), Some(Locale::Fr));
+        assert_eq!(Locale::from_tag("de_DE.UTF-8"), Some(Locale::De));
+        assert_eq!(Locale::from_tag("es-MX"), Some(Locale::Es));
+        assert_eq!(Locale::from_tag("EN-us"), Some(Locale::En));
+        assert_eq!(Locale::from_tag("ja_JP"), None);
+        assert_eq!(Locale::from_tag(""), None);
+    }
+
+    #[test]
+    fn test_translations_fill_gaps_with_english() {
+        let translations = translations(Locale::De);
+        assert_eq!(translations.len(), EN.len());
+        assert_eq!(
+            translations["notification.sync_complete"],
+            "Synchronisierung abgeschlossen"
+        );
+    }
+}
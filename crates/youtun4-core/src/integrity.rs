@@ -26,6 +26,8 @@ use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 use serde::{Deserialize, Serialize};
@@ -33,6 +35,7 @@ use sha2::{Digest, Sha256};
 use tracing::{debug, info, warn};
 
 use crate::error::{Error, FileSystemError, Result};
+use crate::metrics;
 use crate::transfer::{DEFAULT_CHUNK_SIZE, TransferResult, TransferredFile};
 
 // =============================================================================
@@ -635,6 +638,8 @@ impl VerificationOptions {
 /// Provides methods for verifying files against checksum manifests.
 pub struct IntegrityVerifier {
     options: VerificationOptions,
+    /// Flag checked between files to stop creating a manifest early.
+    cancel_flag: Option<Arc<AtomicBool>>,
 }
 
 impl Default for IntegrityVerifier {
@@ -649,13 +654,32 @@ impl IntegrityVerifier {
     pub fn new() -> Self {
         Self {
             options: VerificationOptions::default(),
+            cancel_flag: None,
         }
     }
 
     /// Create a verifier with custom options.
     #[must_use]
     pub const fn with_options(options: VerificationOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            cancel_flag: None,
+        }
+    }
+
+    /// Stop creating a manifest once `flag` is set.
+    ///
+    /// A cancelled manifest is discarded and [`Error::Cancelled`] is returned.
+    #[must_use]
+    pub fn with_cancel_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.cancel_flag = Some(flag);
+        self
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancel_flag
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::SeqCst))
     }
 
     /// Compute the SHA-256 checksum of a file.
@@ -671,9 +695,11 @@ impl IntegrityVerifier {
             })
         })?;
 
+        let start = Instant::now();
         let mut reader = BufReader::new(file);
         let mut hasher = Sha256::new();
         let mut buffer = vec![0u8; self.options.chunk_size];
+        let mut total_read = 0u64;
 
         loop {
             let bytes_read = reader.read(&mut buffer).map_err(|e| {
@@ -688,8 +714,10 @@ impl IntegrityVerifier {
             }
 
             hasher.update(&buffer[..bytes_read]);
+            total_read += bytes_read as u64;
         }
 
+        metrics::record_hash(total_read, start.elapsed());
         Ok(format!("{:x}", hasher.finalize()))
     }
 
@@ -938,7 +966,8 @@ impl IntegrityVerifier {
     ///
     /// # Errors
     ///
-    /// Returns an error if files cannot be read.
+    /// Returns an error if files cannot be read, or [`Error::Cancelled`] if
+    /// the cancel flag was set.
     pub fn create_manifest_from_directory<F>(
         &self,
         directory: &Path,
@@ -1020,6 +1049,11 @@ impl IntegrityVerifier {
         );
 
         for (index, (file_name, path, size)) in files_to_process.iter().enumerate() {
+            if self.is_cancelled() {
+                info!("Manifest creation cancelled for {}", directory.display());
+                return Err(Error::Cancelled);
+            }
+
             progress.current_file = index + 1;
             progress.current_file_name.clone_from(file_name);
             progress.elapsed_secs = start.elapsed().as_secs_f64();
@@ -1399,6 +1433,18 @@ mod tests {
         assert!(manifest.get_file("readme.txt").is_none());
     }
 
+    #[test]
+    fn test_create_manifest_cancelled() {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        create_test_file(temp_dir.path(), "song1.mp3", b"Content 1");
+
+        let verifier = IntegrityVerifier::new().with_cancel_flag(Arc::new(AtomicBool::new(true)));
+        let result = verifier
+            .create_manifest_from_directory(temp_dir.path(), None::<fn(&VerificationProgress)>);
+
+        assert!(matches!(result, Err(Error::Cancelled)));
+    }
+
     #[test]
     fn test_verification_options_default() {
         let options = VerificationOptions::default();
@@ -4,9 +4,14 @@
 //! - Device detection for USB-mounted MP3 players
 //! - Device cleanup for safe data deletion before syncing
 //! - Playlist management (create, delete, sync)
-//! - `YouTube` audio downloading
+//! - `YouTube` audio downloading, with resumable streams, bandwidth
+//!   throttling and MP3 conversion through `ffmpeg`
+//! - Lyrics lookup for downloaded tracks
 //! - Application configuration management
 //! - Cache management for thumbnails, metadata, and temporary files
+//! - A change log of library mutations
+//! - Backup and restore of the library
+//! - Performance counters for hashing, transfers and scraping
 //!
 //! # Error Handling
 //!
@@ -22,67 +27,138 @@
 //! }
 //! ```
 
+pub mod audio;
+pub mod backup;
 pub mod cache;
+pub mod change_log;
 pub mod cleanup;
 pub mod config;
+pub mod crash;
 pub mod device;
+pub mod device_usage;
 pub mod error;
 pub mod fs;
+pub mod history;
+pub mod i18n;
 pub mod integrity;
+pub mod lyrics;
 pub mod metadata;
+pub mod metrics;
+pub mod notifications;
+pub mod onboarding;
+pub mod parental;
 pub mod playlist;
+pub mod power;
 pub mod queue;
+pub mod resume;
+pub mod schedule;
+pub mod secrets;
+pub mod storage;
 pub mod sync;
+pub mod task_journal;
+pub mod throttle;
 pub mod thumbnail;
 pub mod transfer;
+pub mod trash;
 pub mod youtube;
 
+pub use audio::{TranscodeOptions, Transcoder};
+pub use backup::{
+    BACKUP_FORMAT_VERSION, BACKUP_MANIFEST_FILE, BackupFile, BackupManifest, BackupOptions,
+    RestoredLibrary, backup_library, restore_library, verify_backup,
+};
 pub use cache::{
     CacheCleanupStats, CacheConfig, CacheEntry, CacheEntryType, CacheManager, CacheManifest,
-    CacheStats, CachedMetadata, DEFAULT_CACHE_TTL_SECS, DEFAULT_CLEANUP_TARGET,
-    DEFAULT_CLEANUP_THRESHOLD, DEFAULT_MAX_CACHE_SIZE, default_cache_directory,
+    CacheStats, CacheVerifyReport, CacheWarmStats, CachedMetadata, DEFAULT_CACHE_TTL_SECS,
+    DEFAULT_CLEANUP_TARGET, DEFAULT_CLEANUP_THRESHOLD, DEFAULT_MAX_CACHE_SIZE, TempFile,
+    default_cache_directory,
+};
+pub use change_log::{
+    CHANGE_LOG_FILE, ChangeLog, ChangeRecord, ChangeSeq, LibraryChange, MAX_CHANGE_LOG_ENTRIES,
 };
 pub use cleanup::{CleanupEntry, CleanupOptions, CleanupResult, DeviceCleanupHandler};
-pub use config::{AppConfig, ConfigManager, DownloadQuality, NotificationPreferences, Theme};
+pub use config::{
+    AppConfig, CURRENT_CONFIG_VERSION, ConfigChange, ConfigExport, ConfigImportPreview,
+    ConfigManager, ConfigOverrides, ConfigProfile, ConfigSection, DEFAULT_DEVICE_POLL_INTERVAL_MS,
+    DEFAULT_DOWNLOAD_CONCURRENCY, DeviceProfile, DownloadQuality, HistoryFilterState,
+    MAX_DOWNLOAD_CONCURRENCY, NotificationKind, NotificationPreferences, PlaylistLayout,
+    PlaylistOverrides, PlaylistSort, PlaylistViewPreferences, Theme, UiState, UiView,
+    UpdateChannel,
+};
+pub use crash::{CRASH_REPORT_FILE, CrashReport, CrashReporter, CrashSource};
 pub use device::{
     DEFAULT_POLL_INTERVAL, DeviceDetector, DeviceEvent, DeviceInfo, DeviceManager,
     DeviceMountHandler, DeviceWatcher, DeviceWatcherHandle, MountResult, MountStatus,
     PlatformMountHandler, UnmountResult,
 };
+pub use device_usage::{DeviceSyncRecord, DeviceUsage, SYNC_RECORD_FILE, analyze_device_usage};
 pub use error::{
-    CacheError, DeviceError, DownloadError, Error, ErrorContext, ErrorKind, FileSystemError,
-    PathError, PlaylistError, Result, TransferError,
+    CacheError, DeviceError, DownloadError, Error, ErrorCode, ErrorContext, ErrorKind,
+    FileSystemError, PathError, PlaylistError, Result, TransferError,
+};
+pub use fs::{FileMetadata, FileSystem, MemoryFileSystem, RealFileSystem};
+pub use history::{
+    HISTORY_FILE, HistoryEntry, HistoryEntryId, HistoryFilter, HistoryKind, HistoryReport,
+    HistoryStats, HistoryStatus, HistoryStore, HistoryVideo, MAX_HISTORY_ENTRIES, WeeklyBytes,
+    history_stats, month_start, week_start, weekly_download_bytes,
 };
-pub use fs::{FileMetadata, FileSystem, RealFileSystem};
+pub use i18n::{Locale, Message};
 pub use integrity::{
     ChecksumManifest, DEFAULT_MANIFEST_FILE, FileChecksum, FileVerificationResult,
     IntegrityVerifier, MANIFEST_VERSION, VerificationOptions, VerificationProgress,
     VerificationResult, compute_file_checksum, create_and_save_manifest, verify_directory,
 };
-pub use metadata::{Mp3Metadata, extract_metadata, extract_metadata_batch};
+pub use lyrics::{Lyrics, LyricsClient, LyricsConfig, SavedLyrics, save_lyrics};
+pub use metadata::{
+    CoverArt, Mp3Metadata, TrackTags, extract_metadata, extract_metadata_batch, read_lyrics,
+    write_lyrics, write_tags,
+};
+pub use metrics::{OperationStats, PerformanceMetrics};
+pub use onboarding::{DetectedMusicFolder, KnownDevice, OnboardingState};
+pub use parental::{ParentalControls, PinCheck, ProtectedOperation};
 pub use playlist::{
-    FolderStatistics, FolderValidationResult, PlaylistManager, PlaylistMetadata,
-    SavedPlaylistMetadata, TrackInfo, is_audio_file, validate_playlist_name,
+    BatchFailure, BatchResult, DownloadProfile, FolderStatistics, FolderValidationResult,
+    LibrarySearchResults, OutputFormat, PlaylistManager, PlaylistMetadata, SavedPlaylistMetadata,
+    TagPolicy, TrackInfo, TrackPage, TrackSearchMatch, is_audio_file, validate_playlist_name,
 };
+pub use power::{PauseReason, PowerPreferences, PowerState};
 pub use queue::{
     DEFAULT_MAX_CONCURRENT_DOWNLOADS, DownloadPriority, DownloadQueueManager, DownloadRequest,
-    MAX_CONCURRENT_DOWNLOADS, MIN_CONCURRENT_DOWNLOADS, QueueConfig, QueueEvent, QueueItem,
-    QueueItemId, QueueItemStatus, QueueStats,
+    MAX_CONCURRENT_DOWNLOADS, MIN_CONCURRENT_DOWNLOADS, QUEUE_STATE_FILE, QueueConfig, QueueEvent,
+    QueueItem, QueueItemId, QueueItemStatus, QueueStats, default_queue_state_path,
+    load_saved_queue,
+};
+pub use resume::{PARTIAL_DOWNLOAD_DIR, PartialDownload};
+pub use schedule::{PlaylistSchedule, RefreshSchedule};
+pub use secrets::{EncryptedFileBackend, KeyringBackend, SecretBackend, SecretsStore, secret_keys};
+pub use storage::{
+    DEFAULT_MIN_FREE_SPACE_BYTES, LibraryLimits, LibrarySpace, StorageMigrationPhase,
+    StorageMigrationProgress, StorageMigrationResult, migrate_storage_directory,
+    validate_migration_target,
 };
 pub use sync::{
-    PlaylistTransferResult, SyncOptions, SyncOrchestrator, SyncPhase, SyncProgress, SyncRequest,
-    SyncResult,
+    PlaylistTransferResult, SyncChange, SyncOptions, SyncOrchestrator, SyncPhase, SyncPreview,
+    SyncPreviewEntry, SyncProgress, SyncRequest, SyncResult, preview_sync,
+};
+pub use task_journal::{
+    JournalTaskId, PersistedTask, PersistedTaskKind, PersistedTaskStatus, TASK_JOURNAL_FILE,
+    TaskJournal, TrackedTask,
 };
+pub use throttle::RateLimiter;
 pub use thumbnail::{
-    DEFAULT_FETCH_TIMEOUT_SECS, ThumbnailManager, get_playlist_thumbnail_url,
-    youtube_thumbnail_url, youtube_thumbnail_url_maxres,
+    CachedThumbnail, DEFAULT_FETCH_TIMEOUT_SECS, ThumbnailManager, cover_thumbnail_id,
+    get_playlist_thumbnail_url, youtube_thumbnail_url, youtube_thumbnail_url_maxres,
 };
 pub use transfer::{
     DEFAULT_CHUNK_SIZE, FailedTransfer, TransferEngine, TransferItem, TransferOptions,
     TransferProgress, TransferResult, TransferStatus, TransferredFile,
 };
+pub use trash::{Trash, TrashEntry, TrashId, TrashedItem, TrashedTracks};
 pub use youtube::{
-    DefaultYouTubeDownloader, DownloadProgress, DownloadResult, DownloadStatus, PlaylistInfo,
-    RustyYtdlConfig, RustyYtdlDownloader, VideoInfo, YouTubeDownloader, YouTubeUrlType,
-    YouTubeUrlValidation, extract_playlist_id, sanitize_filename, validate_youtube_url,
+    AsyncYouTubeDownloader, DefaultYouTubeDownloader, DownloadControl, DownloadPlan,
+    DownloadProgress, DownloadResult, DownloadStatus, PlannedDownload, PlaylistDownloadEstimate,
+    PlaylistFetchProgress, PlaylistHealth, PlaylistInfo, PlaylistSizeEstimate, RustyYtdlConfig,
+    RustyYtdlDownloader, UrlCandidate, UrlCandidateKind, VideoAvailability, VideoInfo,
+    YouTubeDownloader, YouTubeUrlType, YouTubeUrlValidation, extract_playlist_id, extract_video_id,
+    find_url_candidates, sanitize_filename, validate_youtube_url,
 };
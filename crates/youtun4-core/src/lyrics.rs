@@ -0,0 +1,374 @@
+//! Lyrics lookup for downloaded tracks.
+//!
+//! Lyrics are looked up on [LRCLIB](https://lrclib.net), a free database of
+//! synced (LRC) and plain lyrics, by track name, artist and duration. Since
+//! `YouTube` titles often carry the artist ("Artist - Song") and suffixes
+//! like "(Official Video)", they are cleaned up before searching. Found
+//! lyrics are saved as an `.lrc` file next to the track, readable by most
+//! players, and embedded in the MP3 as an unsynchronised lyrics frame.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::error::{Error, Result};
+use crate::metadata;
+
+/// Default LRCLIB API server.
+pub const DEFAULT_LRCLIB_API_URL: &str = "https://lrclib.net";
+
+/// Largest difference in seconds between a track and a search result for
+/// the result to count as the same recording.
+const DURATION_TOLERANCE_SECS: u64 = 5;
+
+/// Lyrics settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LyricsConfig {
+    /// Whether lyrics are fetched after downloads. Playlists can override it.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Save lyrics as an `.lrc` file next to the track.
+    #[serde(default = "default_true")]
+    pub save_sidecar: bool,
+    /// Embed lyrics in the MP3's ID3 tag.
+    #[serde(default = "default_true")]
+    pub embed: bool,
+    /// LRCLIB API server.
+    #[serde(default = "default_api_url")]
+    pub api_url: String,
+}
+
+const fn default_true() -> bool {
+    true
+}
+
+fn default_api_url() -> String {
+    DEFAULT_LRCLIB_API_URL.to_string()
+}
+
+impl Default for LyricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            save_sidecar: true,
+            embed: true,
+            api_url: default_api_url(),
+        }
+    }
+}
+
+/// Lyrics of a track.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lyrics {
+    /// Plain lyrics, one line per line.
+    pub plain: Option<String>,
+    /// Synced lyrics in LRC format (`[mm:ss.xx] line`).
+    pub synced: Option<String>,
+    /// Whether the track is instrumental.
+    #[serde(default)]
+    pub instrumental: bool,
+}
+
+impl Lyrics {
+    /// Whether there are no lyrics to save.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.plain.as_deref().is_none_or(str::is_empty)
+            && self.synced.as_deref().is_none_or(str::is_empty)
+    }
+
+    /// Text of the `.lrc` file: the synced lyrics, or the plain ones.
+    #[must_use]
+    pub fn lrc(&self) -> Option<&str> {
+        self.synced
+            .as_deref()
+            .or(self.plain.as_deref())
+            .filter(|text| !text.is_empty())
+    }
+
+    /// Lyrics without timestamps: the plain lyrics, or the synced ones with
+    /// their timestamps removed.
+    #[must_use]
+    pub fn text(&self) -> Option<String> {
+        if let Some(plain) = self.plain.as_deref().filter(|text| !text.is_empty()) {
+            return Some(plain.to_string());
+        }
+        let synced = self.synced.as_deref().filter(|text| !text.is_empty())?;
+        Some(
+            synced
+                .lines()
+                .map(strip_timestamps)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+}
+
+/// `line` without its leading `[...]` timestamps.
+fn strip_timestamps(line: &str) -> &str {
+    let mut rest = line.trim_start();
+    while let Some(tail) = rest.strip_prefix('[')
+        && let Some(end) = tail.find(']')
+    {
+        rest = &tail[end + 1..];
+    }
+    rest.trim_start()
+}
+
+/// Where lyrics were saved.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedLyrics {
+    /// The `.lrc` file, if one was written.
+    pub sidecar: Option<PathBuf>,
+    /// Whether the lyrics were embedded in the track.
+    pub embedded: bool,
+}
+
+/// A track as returned by the LRCLIB search endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiTrack {
+    #[serde(default)]
+    duration: Option<f64>,
+    #[serde(default)]
+    instrumental: bool,
+    #[serde(default)]
+    plain_lyrics: Option<String>,
+    #[serde(default)]
+    synced_lyrics: Option<String>,
+}
+
+/// Client for the LRCLIB API.
+#[derive(Debug, Clone)]
+pub struct LyricsClient {
+    config: LyricsConfig,
+}
+
+impl LyricsClient {
+    /// Create a client for `config`.
+    #[must_use]
+    pub const fn new(config: LyricsConfig) -> Self {
+        Self { config }
+    }
+
+    /// Look up the lyrics of the video `title` uploaded by `channel`,
+    /// lasting `duration_secs`.
+    ///
+    /// Returns `None` when no lyrics were found.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API can't be reached or answers with an
+    /// unexpected response.
+    pub fn fetch(
+        &self,
+        title: &str,
+        channel: Option<&str>,
+        duration_secs: Option<u64>,
+    ) -> Result<Option<Lyrics>> {
+        let (track, artist) = search_terms(title, channel);
+        if track.is_empty() {
+            return Ok(None);
+        }
+
+        let mut params = vec![("track_name", track.as_str())];
+        if let Some(artist) = artist.as_deref() {
+            params.push(("artist_name", artist));
+        }
+        let request_failed =
+            |reason: String| Error::network_error(format!("Lyrics lookup failed: {reason}"));
+        let url = reqwest::Url::parse_with_params(
+            &format!("{}/api/search", self.config.api_url.trim_end_matches('/')),
+            &params,
+        )
+        .map_err(|e| request_failed(e.to_string()))?;
+        debug!("Fetching lyrics: {}", url);
+
+        let body = reqwest::blocking::Client::new()
+            .get(url)
+            .header(reqwest::header::USER_AGENT, "youtun4")
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .and_then(reqwest::blocking::Response::text)
+            .map_err(|e| request_failed(e.to_string()))?;
+        let tracks: Vec<ApiTrack> = serde_json::from_str(&body)
+            .map_err(|e| request_failed(format!("invalid response: {e}")))?;
+        Ok(best_match(tracks, duration_secs))
+    }
+}
+
+/// The result matching `duration_secs` best, preferring synced lyrics.
+fn best_match(tracks: Vec<ApiTrack>, duration_secs: Option<u64>) -> Option<Lyrics> {
+    let distance = |track: &ApiTrack| match (track.duration, duration_secs) {
+        (Some(found), Some(wanted)) => (found.round() as u64).abs_diff(wanted),
+        _ => 0,
+    };
+    tracks
+        .into_iter()
+        .filter(|track| distance(track) <= DURATION_TOLERANCE_SECS)
+        .map(|track| {
+            let key = (track.synced_lyrics.is_none(), distance(&track));
+            let lyrics = Lyrics {
+                plain: track.plain_lyrics,
+                synced: track.synced_lyrics,
+                instrumental: track.instrumental,
+            };
+            (key, lyrics)
+        })
+        .filter(|(_, lyrics)| !lyrics.is_empty() || lyrics.instrumental)
+        .min_by_key(|(key, _)| *key)
+        .map(|(_, lyrics)| lyrics)
+}
+
+/// Track name and artist to search for a video `title` uploaded by
+/// `channel`.
+///
+/// Bracketed parts like "(Official Video)" are dropped, and an
+/// "Artist - Song" title wins over the channel name.
+#[must_use]
+pub fn search_terms(title: &str, channel: Option<&str>) -> (String, Option<String>) {
+    let title = strip_brackets(title);
+    if let Some((artist, track)) = title.split_once(" - ")
+        && !artist.trim().is_empty()
+        && !track.trim().is_empty()
+    {
+        return (track.trim().to_string(), Some(artist.trim().to_string()));
+    }
+
+    let artist = channel
+        .map(|channel| {
+            channel
+                .trim_end_matches(" - Topic")
+                .trim_end_matches("VEVO")
+                .trim()
+                .to_string()
+        })
+        .filter(|artist| !artist.is_empty());
+    (title.trim().to_string(), artist)
+}
+
+/// `text` without `(...)` and `[...]` parts.
+fn strip_brackets(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut depth = 0_usize;
+    for c in text.chars() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' if depth > 0 => depth -= 1,
+            _ if depth == 0 => result.push(c),
+            _ => {}
+        }
+    }
+    result.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Save `lyrics` of the track at `track` as configured in `config`.
+///
+/// Lyrics are only embedded in MP3 files.
+///
+/// # Errors
+///
+/// Returns an error if the `.lrc` file or the tag can't be written.
+pub fn save_lyrics(track: &Path, lyrics: &Lyrics, config: &LyricsConfig) -> Result<SavedLyrics> {
+    let mut saved = SavedLyrics::default();
+    if config.save_sidecar
+        && let Some(lrc) = lyrics.lrc()
+    {
+        let sidecar = track.with_extension("lrc");
+        std::fs::write(&sidecar, lrc)?;
+        saved.sidecar = Some(sidecar);
+    }
+    let is_mp3 = track
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("mp3"));
+    if config.embed
+        && is_mp3
+        && let Some(text) = lyrics.text()
+    {
+        metadata::write_lyrics(track, &text)?;
+        saved.embedded = true;
+    }
+    Ok(saved)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn api_track(duration: f64, plain: Option<&str>, synced: Option<&str>) -> ApiTrack {
+        ApiTrack {
+            duration: Some(duration),
+            instrumental: false,
+            plain_lyrics: plain.map(str::to_string),
+            synced_lyrics: synced.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_search_terms() {
+        assert_eq!(
+            search_terms(
+                "Daft Punk - One More Time (Official Video)",
+                Some("DaftPunkVEVO")
+            ),
+            ("One More Time".to_string(), Some("Daft Punk".to_string()))
+        );
+        assert_eq!(
+            search_terms("Harder Better [HD]", Some("Daft Punk - Topic")),
+            ("Harder Better".to_string(), Some("Daft Punk".to_string()))
+        );
+        assert_eq!(search_terms("Song", None), ("Song".to_string(), None));
+    }
+
+    #[test]
+    fn test_best_match_prefers_synced_within_tolerance() {
+        let tracks = vec![
+            api_track(200.0, Some("far"), Some("[00:01.00] far")),
+            api_track(181.0, Some("plain"), None),
+            api_track(183.0, Some("synced"), Some("[00:01.00] synced")),
+        ];
+        let lyrics = best_match(tracks, Some(180)).unwrap();
+        assert_eq!(lyrics.plain.as_deref(), Some("synced"));
+
+        assert!(best_match(vec![api_track(200.0, Some("far"), None)], Some(180)).is_none());
+        assert!(best_match(Vec::new(), None).is_none());
+    }
+
+    #[test]
+    fn test_text_strips_timestamps() {
+        let lyrics = Lyrics {
+            plain: None,
+            synced: Some("[00:01.00]First\n[00:02.50][00:10.00] Second".to_string()),
+            instrumental: false,
+        };
+        assert_eq!(lyrics.text().as_deref(), Some("First\nSecond"));
+        assert_eq!(lyrics.lrc(), lyrics.synced.as_deref());
+    }
+
+    #[test]
+    fn test_save_lyrics_writes_sidecar_and_tag() {
+        let dir = TempDir::new().unwrap();
+        let track = dir.path().join("Song.mp3");
+        std::fs::write(&track, [0xFF_u8, 0xFB, 0x90, 0x00]).unwrap();
+        let lyrics = Lyrics {
+            plain: Some("Hello".to_string()),
+            synced: Some("[00:01.00] Hello".to_string()),
+            instrumental: false,
+        };
+
+        let saved = save_lyrics(&track, &lyrics, &LyricsConfig::default()).unwrap();
+        assert_eq!(saved.sidecar, Some(dir.path().join("Song.lrc")));
+        assert!(saved.embedded);
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("Song.lrc")).unwrap(),
+            "[00:01.00] Hello"
+        );
+        assert_eq!(
+            metadata::read_lyrics(&track).unwrap().as_deref(),
+            Some("Hello")
+        );
+    }
+}
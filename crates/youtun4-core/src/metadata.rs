@@ -1,7 +1,8 @@
 //! MP3 metadata extraction module.
 //!
 //! This module provides functionality to extract ID3 tags and other metadata
-//! from MP3 files, including title, artist, album, duration, and track number.
+//! from MP3 files, including title, artist, album, duration, and track number,
+//! and to write the tags and cover art of downloaded tracks.
 //!
 //! # Supported Formats
 //!
@@ -21,7 +22,8 @@
 
 use std::path::Path;
 
-use id3::{Tag, TagLike};
+use id3::frame::{Picture, PictureType};
+use id3::{Tag, TagLike, Version};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, warn};
 
@@ -186,6 +188,138 @@ pub fn extract_metadata(path: &Path) -> Result<Mp3Metadata> {
     Ok(metadata)
 }
 
+/// Tags written into a downloaded track.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrackTags {
+    /// Track title.
+    pub title: String,
+    /// Artist, usually the channel that uploaded the video.
+    pub artist: Option<String>,
+    /// Album, usually the playlist title.
+    pub album: Option<String>,
+    /// Position of the track in the playlist, starting at 1.
+    pub track_number: Option<u32>,
+    /// Number of tracks in the playlist.
+    pub total_tracks: Option<u32>,
+    /// Front cover image.
+    pub cover: Option<CoverArt>,
+}
+
+/// An image embedded as cover art.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoverArt {
+    /// MIME type of the image (e.g. "image/jpeg").
+    pub mime_type: String,
+    /// Image bytes.
+    pub data: Vec<u8>,
+}
+
+/// Write `tags` into the MP3 file at `path` as an ID3v2.4 tag.
+///
+/// Tags already in the file are kept unless `tags` replaces them.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or written.
+pub fn write_tags(path: &Path, tags: &TrackTags) -> Result<()> {
+    let write_failed = |reason: String| {
+        Error::FileSystem(FileSystemError::WriteFailed {
+            path: path.to_path_buf(),
+            reason,
+        })
+    };
+
+    let mut tag = match Tag::read_from_path(path) {
+        Ok(tag) => tag,
+        Err(id3::Error {
+            kind: id3::ErrorKind::NoTag,
+            ..
+        }) => Tag::new(),
+        Err(e) => return Err(write_failed(e.to_string())),
+    };
+
+    tag.set_title(tags.title.as_str());
+    if let Some(artist) = &tags.artist {
+        tag.set_artist(artist.as_str());
+    }
+    if let Some(album) = &tags.album {
+        tag.set_album(album.as_str());
+    }
+    if let Some(track) = tags.track_number {
+        tag.set_track(track);
+    }
+    if let Some(total) = tags.total_tracks {
+        tag.set_total_tracks(total);
+    }
+    if let Some(cover) = &tags.cover {
+        tag.remove_picture_by_type(PictureType::CoverFront);
+        tag.add_frame(Picture {
+            mime_type: cover.mime_type.clone(),
+            picture_type: PictureType::CoverFront,
+            description: String::new(),
+            data: cover.data.clone(),
+        });
+    }
+
+    tag.write_to_path(path, Version::Id3v24)
+        .map_err(|e| write_failed(e.to_string()))?;
+    debug!("Wrote ID3 tags to: {}", path.display());
+    Ok(())
+}
+
+/// Embed `text` as the unsynchronised lyrics of the MP3 file at `path`,
+/// replacing any lyrics already there.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or written.
+pub fn write_lyrics(path: &Path, text: &str) -> Result<()> {
+    let write_failed = |reason: String| {
+        Error::FileSystem(FileSystemError::WriteFailed {
+            path: path.to_path_buf(),
+            reason,
+        })
+    };
+
+    let mut tag = match Tag::read_from_path(path) {
+        Ok(tag) => tag,
+        Err(id3::Error {
+            kind: id3::ErrorKind::NoTag,
+            ..
+        }) => Tag::new(),
+        Err(e) => return Err(write_failed(e.to_string())),
+    };
+    tag.remove_all_lyrics();
+    tag.add_frame(id3::frame::Lyrics {
+        lang: "eng".to_string(),
+        description: String::new(),
+        text: text.to_string(),
+    });
+    tag.write_to_path(path, Version::Id3v24)
+        .map_err(|e| write_failed(e.to_string()))?;
+    debug!("Wrote lyrics to: {}", path.display());
+    Ok(())
+}
+
+/// Unsynchronised lyrics embedded in the MP3 file at `path`, if any.
+///
+/// # Errors
+///
+/// Returns an error if the file exists but its tag cannot be read.
+pub fn read_lyrics(path: &Path) -> Result<Option<String>> {
+    match Tag::read_from_path(path) {
+        Ok(tag) => Ok(tag.lyrics().next().map(|lyrics| lyrics.text.clone())),
+        Err(id3::Error {
+            kind: id3::ErrorKind::NoTag,
+            ..
+        }) => Ok(None),
+        Err(e) => Err(Error::FileSystem(FileSystemError::ReadFailed {
+            path: path.to_path_buf(),
+            reason: e.to_string(),
+        })),
+    }
+}
+
 /// Parse track number from ID3 tag, handling "track/total" format.
 fn parse_track_number(tag: &Tag) -> (Option<u32>, Option<u32>) {
     if let Some(track) = tag.track() {
@@ -328,6 +462,39 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    #[test]
+    fn test_write_tags_round_trip() {
+        let temp_dir = create_test_dir();
+        let file_path = temp_dir.path().join("song.mp3");
+        fs::write(&file_path, [0xFF_u8, 0xFB, 0x90, 0x00]).expect("Write should succeed");
+
+        let tags = TrackTags {
+            title: "My Song".to_string(),
+            artist: Some("My Channel".to_string()),
+            album: Some("My Playlist".to_string()),
+            track_number: Some(3),
+            total_tracks: Some(12),
+            cover: Some(CoverArt {
+                mime_type: "image/jpeg".to_string(),
+                data: vec![1, 2, 3],
+            }),
+        };
+        write_tags(&file_path, &tags).expect("Writing tags should succeed");
+        // Writing again replaces the cover instead of adding a second one
+        write_tags(&file_path, &tags).expect("Writing tags should succeed");
+
+        let metadata = extract_metadata(&file_path).expect("Extraction should succeed");
+        assert_eq!(metadata.title.as_deref(), Some("My Song"));
+        assert_eq!(metadata.artist.as_deref(), Some("My Channel"));
+        assert_eq!(metadata.album.as_deref(), Some("My Playlist"));
+        assert_eq!(metadata.formatted_track_number().as_deref(), Some("3/12"));
+
+        let tag = Tag::read_from_path(&file_path).expect("Tag should be readable");
+        let pictures: Vec<_> = tag.pictures().collect();
+        assert_eq!(pictures.len(), 1);
+        assert_eq!(pictures[0].data, vec![1, 2, 3]);
+    }
+
     #[test]
     fn test_metadata_serialization() {
         let metadata = Mp3Metadata {
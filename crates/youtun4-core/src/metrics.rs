@@ -0,0 +1,226 @@
+//! Runtime performance counters for the core hot paths.
+//!
+//! The integrity verifier, the transfer engine and the playlist scraper add
+//! to process-wide counters as they work. [`snapshot`] turns the counters
+//! into rates (files hashed per second, MB/s transferred, scrape latency) so
+//! a slowdown can be spotted in a running app, not just in the benchmarks.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+const BYTES_PER_MB: f64 = 1024.0 * 1024.0;
+
+/// Counts, bytes and time of one kind of operation.
+#[derive(Debug)]
+struct Counter {
+    count: AtomicU64,
+    bytes: AtomicU64,
+    nanos: AtomicU64,
+    max_nanos: AtomicU64,
+}
+
+impl Counter {
+    const fn new() -> Self {
+        Self {
+            count: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            nanos: AtomicU64::new(0),
+            max_nanos: AtomicU64::new(0),
+        }
+    }
+
+    fn record(&self, bytes: u64, elapsed: Duration) {
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.count.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+        self.nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.max_nanos.fetch_max(nanos, Ordering::Relaxed);
+    }
+
+    fn stats(&self) -> OperationStats {
+        OperationStats {
+            count: self.count.load(Ordering::Relaxed),
+            bytes: self.bytes.load(Ordering::Relaxed),
+            total_secs: Duration::from_nanos(self.nanos.load(Ordering::Relaxed)).as_secs_f64(),
+            max_secs: Duration::from_nanos(self.max_nanos.load(Ordering::Relaxed)).as_secs_f64(),
+        }
+    }
+
+    fn reset(&self) {
+        self.count.store(0, Ordering::Relaxed);
+        self.bytes.store(0, Ordering::Relaxed);
+        self.nanos.store(0, Ordering::Relaxed);
+        self.max_nanos.store(0, Ordering::Relaxed);
+    }
+}
+
+static HASHING: Counter = Counter::new();
+static TRANSFER: Counter = Counter::new();
+static SCRAPING: Counter = Counter::new();
+
+/// Totals for one kind of operation since the app started or the counters
+/// were reset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct OperationStats {
+    /// Number of operations.
+    pub count: u64,
+    /// Bytes processed.
+    pub bytes: u64,
+    /// Time spent in the operations, in seconds.
+    pub total_secs: f64,
+    /// Longest single operation, in seconds.
+    pub max_secs: f64,
+}
+
+impl OperationStats {
+    /// Operations per second of time spent, or 0 if nothing was recorded.
+    #[must_use]
+    pub fn per_sec(&self) -> f64 {
+        if self.total_secs > 0.0 {
+            self.count as f64 / self.total_secs
+        } else {
+            0.0
+        }
+    }
+
+    /// Megabytes per second of time spent, or 0 if nothing was recorded.
+    #[must_use]
+    pub fn mb_per_sec(&self) -> f64 {
+        if self.total_secs > 0.0 {
+            self.bytes as f64 / BYTES_PER_MB / self.total_secs
+        } else {
+            0.0
+        }
+    }
+
+    /// Average time of an operation in seconds, or 0 if nothing was recorded.
+    #[must_use]
+    pub fn average_secs(&self) -> f64 {
+        if self.count > 0 {
+            self.total_secs / self.count as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Performance of the core hot paths.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PerformanceMetrics {
+    /// Files checksummed by the integrity verifier.
+    pub hashing: OperationStats,
+    /// Files copied by the transfer engine.
+    pub transfer: OperationStats,
+    /// `YouTube` playlist pages fetched and parsed.
+    pub scraping: OperationStats,
+    /// Files hashed per second.
+    pub files_hashed_per_sec: f64,
+    /// Hashing throughput in MB/s.
+    pub hash_mb_per_sec: f64,
+    /// Transfer throughput in MB/s.
+    pub transfer_mb_per_sec: f64,
+    /// Average playlist scrape latency in milliseconds.
+    pub average_scrape_latency_ms: f64,
+    /// Slowest playlist scrape in milliseconds.
+    pub max_scrape_latency_ms: f64,
+}
+
+impl PerformanceMetrics {
+    fn from_stats(
+        hashing: OperationStats,
+        transfer: OperationStats,
+        scraping: OperationStats,
+    ) -> Self {
+        Self {
+            hashing,
+            transfer,
+            scraping,
+            files_hashed_per_sec: hashing.per_sec(),
+            hash_mb_per_sec: hashing.mb_per_sec(),
+            transfer_mb_per_sec: transfer.mb_per_sec(),
+            average_scrape_latency_ms: scraping.average_secs() * 1000.0,
+            max_scrape_latency_ms: scraping.max_secs * 1000.0,
+        }
+    }
+}
+
+/// Record a file of `bytes` checksummed in `elapsed`.
+pub fn record_hash(bytes: u64, elapsed: Duration) {
+    HASHING.record(bytes, elapsed);
+}
+
+/// Record a file of `bytes` copied to a device in `elapsed`.
+pub fn record_transfer(bytes: u64, elapsed: Duration) {
+    TRANSFER.record(bytes, elapsed);
+}
+
+/// Record a playlist page fetched and parsed in `elapsed`.
+pub fn record_scrape(elapsed: Duration) {
+    SCRAPING.record(0, elapsed);
+}
+
+/// Current performance metrics.
+#[must_use]
+pub fn snapshot() -> PerformanceMetrics {
+    PerformanceMetrics::from_stats(HASHING.stats(), TRANSFER.stats(), SCRAPING.stats())
+}
+
+/// Reset every counter to zero.
+pub fn reset() {
+    HASHING.reset();
+    TRANSFER.reset();
+    SCRAPING.reset();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_stats() {
+        let counter = Counter::new();
+        counter.record(2 * 1024 * 1024, Duration::from_millis(500));
+        counter.record(1024 * 1024, Duration::from_millis(1500));
+
+        let stats = counter.stats();
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.bytes, 3 * 1024 * 1024);
+        assert!((stats.total_secs - 2.0).abs() < 1e-9);
+        assert!((stats.max_secs - 1.5).abs() < 1e-9);
+        assert!((stats.per_sec() - 1.0).abs() < 1e-9);
+        assert!((stats.mb_per_sec() - 1.5).abs() < 1e-9);
+        assert!((stats.average_secs() - 1.0).abs() < 1e-9);
+
+        counter.reset();
+        assert_eq!(counter.stats(), OperationStats::default());
+    }
+
+    #[test]
+    fn test_empty_stats_have_zero_rates() {
+        let metrics = PerformanceMetrics::from_stats(
+            OperationStats::default(),
+            OperationStats::default(),
+            OperationStats::default(),
+        );
+        assert_eq!(metrics, PerformanceMetrics::default());
+    }
+
+    #[test]
+    fn test_scrape_latency_in_ms() {
+        let scraping = OperationStats {
+            count: 4,
+            bytes: 0,
+            total_secs: 2.0,
+            max_secs: 1.25,
+        };
+        let metrics = PerformanceMetrics::from_stats(
+            OperationStats::default(),
+            OperationStats::default(),
+            scraping,
+        );
+        assert!((metrics.average_scrape_latency_ms - 500.0).abs() < 1e-9);
+        assert!((metrics.max_scrape_latency_ms - 1250.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,7 @@
+//! Notifications sent outside the application.
+//!
+//! OS notifications are shown by the app itself; this module covers the
+//! ones sent to other services, such as the [`webhook`] called when a
+//! download or sync ends.
+
+pub mod webhook;
@@ -0,0 +1,393 @@
+//! Webhook called when a download or sync ends.
+//!
+//! The webhook is a URL the payload is posted to, such as an ntfy topic or a
+//! Discord webhook. Webhook URLs often carry their access token, so the URL
+//! is kept in the secrets store under [`crate::secrets::secret_keys::WEBHOOK_URL`] rather
+//! than in the config file. The payload is rendered from a template with
+//! placeholders in double braces:
+//!
+//! - `{{event}}`: `download_completed`, `download_failed`, `sync_completed`
+//!   or `sync_failed`
+//! - `{{message}}`: a sentence describing what happened
+//! - `{{playlist}}`: the playlists involved, separated by commas
+//! - `{{succeeded}}` and `{{failed}}`: the tracks or files that succeeded and
+//!   failed
+//! - `{{error}}`: the error of a failed operation, or nothing
+//!
+//! Templates starting with `{` or `[` are sent as JSON, with the values
+//! escaped as JSON strings (e.g. `{"content": "{{message}}"}` for Discord);
+//! other templates are sent as plain text (e.g. `{{message}}` for ntfy).
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::error::{Error, Result};
+use crate::history::{HistoryEntry, HistoryKind, HistoryStatus};
+
+/// Default payload: the message as plain text.
+pub const DEFAULT_PAYLOAD_TEMPLATE: &str = "{{message}}";
+
+/// Timeout for calling the webhook.
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An event the webhook can be called for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// A playlist download finished, possibly with some tracks failing.
+    DownloadCompleted,
+    /// A playlist download failed.
+    DownloadFailed,
+    /// A sync to a device finished.
+    SyncCompleted,
+    /// A sync to a device failed.
+    SyncFailed,
+}
+
+impl WebhookEvent {
+    /// Every event, in display order.
+    pub const ALL: [Self; 4] = [
+        Self::DownloadCompleted,
+        Self::DownloadFailed,
+        Self::SyncCompleted,
+        Self::SyncFailed,
+    ];
+
+    /// Name of the event in payloads.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::DownloadCompleted => "download_completed",
+            Self::DownloadFailed => "download_failed",
+            Self::SyncCompleted => "sync_completed",
+            Self::SyncFailed => "sync_failed",
+        }
+    }
+}
+
+fn default_events() -> Vec<WebhookEvent> {
+    WebhookEvent::ALL.to_vec()
+}
+
+fn default_payload_template() -> String {
+    DEFAULT_PAYLOAD_TEMPLATE.to_string()
+}
+
+/// What to post to the webhook, and when.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    /// Whether the webhook is called.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Template of the payload.
+    #[serde(default = "default_payload_template")]
+    pub payload_template: String,
+    /// Events the webhook is called for.
+    #[serde(default = "default_events")]
+    pub events: Vec<WebhookEvent>,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            payload_template: default_payload_template(),
+            events: default_events(),
+        }
+    }
+}
+
+impl WebhookConfig {
+    /// Whether the webhook is called for `event`.
+    #[must_use]
+    pub fn allows(&self, event: WebhookEvent) -> bool {
+        self.enabled && self.events.contains(&event)
+    }
+
+    /// Check the payload template.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error if the template is invalid.
+    pub fn validate(&self) -> Result<()> {
+        render_payload(&self.payload_template, &WebhookMessage::test()).map(|_| ())
+    }
+}
+
+/// Check that `url` can be posted to.
+///
+/// # Errors
+///
+/// Returns a configuration error if `url` is not an http(s) URL.
+pub fn validate_url(url: &str) -> Result<()> {
+    if url.starts_with("https://") || url.starts_with("http://") {
+        Ok(())
+    } else {
+        Err(Error::Configuration(
+            "The webhook URL must start with http:// or https://".to_string(),
+        ))
+    }
+}
+
+/// What the webhook reports.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookMessage {
+    /// The event.
+    pub event: WebhookEvent,
+    /// A sentence describing what happened.
+    pub message: String,
+    /// Playlists involved, separated by commas.
+    pub playlist: String,
+    /// Tracks or files that succeeded.
+    pub succeeded: usize,
+    /// Tracks or files that failed.
+    pub failed: usize,
+    /// Error of a failed operation.
+    pub error: Option<String>,
+}
+
+impl WebhookMessage {
+    /// The message for a download or sync that ended with `entry`, or `None`
+    /// if it was cancelled.
+    #[must_use]
+    pub fn from_history(entry: &HistoryEntry) -> Option<Self> {
+        let failed = entry.status == HistoryStatus::Failed;
+        let playlist = entry.playlists.join(", ");
+        let (event, message) = match (entry.kind, entry.status) {
+            (_, HistoryStatus::Cancelled) => return None,
+            (HistoryKind::Download, _) if failed => (
+                WebhookEvent::DownloadFailed,
+                format!(
+                    "Download of {playlist} failed: {}",
+                    entry
+                        .error
+                        .as_deref()
+                        .unwrap_or("no track could be downloaded")
+                ),
+            ),
+            (HistoryKind::Download, _) => (
+                WebhookEvent::DownloadCompleted,
+                with_failures(
+                    format!(
+                        "Downloaded {} track(s) of {playlist}",
+                        entry.items_succeeded
+                    ),
+                    entry.items_failed,
+                ),
+            ),
+            (HistoryKind::Sync, _) => {
+                let device = entry.device_mount_point.as_deref().unwrap_or("the device");
+                if failed {
+                    (
+                        WebhookEvent::SyncFailed,
+                        format!(
+                            "Sync of {playlist} to {device} failed: {}",
+                            entry.error.as_deref().unwrap_or("no file could be copied")
+                        ),
+                    )
+                } else {
+                    (
+                        WebhookEvent::SyncCompleted,
+                        with_failures(
+                            format!(
+                                "Synced {playlist} to {device}: {} file(s) copied",
+                                entry.items_succeeded
+                            ),
+                            entry.items_failed,
+                        ),
+                    )
+                }
+            }
+        };
+
+        Some(Self {
+            event,
+            message,
+            playlist,
+            succeeded: entry.items_succeeded,
+            failed: entry.items_failed,
+            error: entry.error.clone(),
+        })
+    }
+
+    /// A message to try the webhook with.
+    #[must_use]
+    pub fn test() -> Self {
+        Self {
+            event: WebhookEvent::DownloadCompleted,
+            message: "Youtun4 webhook test".to_string(),
+            playlist: "Test Playlist".to_string(),
+            succeeded: 1,
+            failed: 0,
+            error: None,
+        }
+    }
+}
+
+fn with_failures(message: String, failed: usize) -> String {
+    if failed == 0 {
+        message
+    } else {
+        format!("{message}, {failed} failed")
+    }
+}
+
+/// Whether payloads rendered from `template` are JSON.
+fn is_json_template(template: &str) -> bool {
+    template.trim_start().starts_with(['{', '['])
+}
+
+/// Render the payload of `message` from `template`.
+///
+/// # Errors
+///
+/// Returns a configuration error if a placeholder is unknown or not closed.
+pub fn render_payload(template: &str, message: &WebhookMessage) -> Result<String> {
+    let json = is_json_template(template);
+    let mut payload = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        payload.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| {
+            Error::Configuration("Unclosed placeholder in the webhook payload".to_string())
+        })?;
+        let value = match after[..end].trim() {
+            "event" => message.event.name().to_string(),
+            "message" => message.message.clone(),
+            "playlist" => message.playlist.clone(),
+            "succeeded" => message.succeeded.to_string(),
+            "failed" => message.failed.to_string(),
+            "error" => message.error.clone().unwrap_or_default(),
+            name => {
+                return Err(Error::Configuration(format!(
+                    "Unknown placeholder '{{{{{name}}}}}' in the webhook payload \
+                     (use event, message, playlist, succeeded, failed or error)"
+                )));
+            }
+        };
+        if json {
+            // Inside a JSON string: escape, without the surrounding quotes
+            let escaped = serde_json::to_string(&value)?;
+            payload.push_str(&escaped[1..escaped.len() - 1]);
+        } else {
+            payload.push_str(&value);
+        }
+        rest = &after[end + 2..];
+    }
+    payload.push_str(rest);
+    Ok(payload)
+}
+
+/// Post `message` to the webhook at `url`, rendered as `config` says.
+///
+/// # Errors
+///
+/// Returns an error if the URL or payload is invalid or the request fails.
+pub fn send(url: &str, config: &WebhookConfig, message: &WebhookMessage) -> Result<()> {
+    validate_url(url)?;
+    let payload = render_payload(&config.payload_template, message)?;
+    let content_type = if is_json_template(&config.payload_template) {
+        "application/json"
+    } else {
+        "text/plain; charset=utf-8"
+    };
+    debug!("Calling webhook for {}", message.event.name());
+
+    let client = reqwest::blocking::Client::builder()
+        .timeout(SEND_TIMEOUT)
+        .build()
+        .map_err(|e| Error::network_error(format!("Failed to create HTTP client: {e}")))?;
+    client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, content_type)
+        .body(payload)
+        .send()
+        .and_then(reqwest::blocking::Response::error_for_status)
+        .map_err(|e| Error::network_error(format!("Failed to call webhook: {e}")))?;
+
+    info!("Webhook called for {}", message.event.name());
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn download(succeeded: usize, failed: usize) -> HistoryEntry {
+        HistoryEntry::download("Mix", "https://youtube.com/playlist?list=x")
+            .with_counts(succeeded, failed, 0)
+    }
+
+    #[test]
+    fn test_message_from_history() {
+        let completed = WebhookMessage::from_history(&download(10, 2)).unwrap();
+        assert_eq!(completed.event, WebhookEvent::DownloadCompleted);
+        assert_eq!(completed.message, "Downloaded 10 track(s) of Mix, 2 failed");
+
+        let failed = WebhookMessage::from_history(&download(0, 0).failed("Offline")).unwrap();
+        assert_eq!(failed.event, WebhookEvent::DownloadFailed);
+        assert_eq!(failed.message, "Download of Mix failed: Offline");
+
+        let sync = HistoryEntry::sync(vec!["Mix".to_string(), "Chill".to_string()], "/media/P")
+            .with_counts(40, 0, 0);
+        let synced = WebhookMessage::from_history(&sync).unwrap();
+        assert_eq!(synced.event, WebhookEvent::SyncCompleted);
+        assert_eq!(
+            synced.message,
+            "Synced Mix, Chill to /media/P: 40 file(s) copied"
+        );
+
+        assert!(WebhookMessage::from_history(&download(1, 0).cancelled()).is_none());
+    }
+
+    #[test]
+    fn test_render_text_and_json_payloads() {
+        let mut message = WebhookMessage::test();
+        message.message = "Downloaded \"Best\" of\nMix".to_string();
+
+        assert_eq!(
+            render_payload(
+                "[{{event}}] {{message}} ({{succeeded}}/{{failed}})",
+                &message
+            )
+            .unwrap(),
+            "[download_completed] Downloaded \"Best\" of\nMix (1/0)"
+        );
+        let json = render_payload(
+            r#"{"content": "{{ message }}", "error": "{{error}}"}"#,
+            &message,
+        )
+        .unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["content"], "Downloaded \"Best\" of\nMix");
+        assert_eq!(parsed["error"], "");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(WebhookConfig::default().validate().is_ok());
+
+        assert!(validate_url("ntfy.sh/topic").is_err());
+        assert!(validate_url("https://ntfy.sh/topic").is_ok());
+
+        let mut config = WebhookConfig {
+            enabled: true,
+            ..WebhookConfig::default()
+        };
+        assert!(config.allows(WebhookEvent::SyncFailed));
+
+        for template in ["{{nope}}", "{{message"] {
+            config.payload_template = template.to_string();
+            assert!(config.validate().is_err(), "{template} should be rejected");
+        }
+
+        config.events = vec![WebhookEvent::DownloadFailed];
+        assert!(!config.allows(WebhookEvent::SyncFailed));
+        config.enabled = false;
+        assert!(!config.allows(WebhookEvent::DownloadFailed));
+    }
+}
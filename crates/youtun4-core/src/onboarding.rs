@@ -0,0 +1,237 @@
+//! First-run setup state and smart default detection.
+//!
+//! The first-run wizard in the UI is driven entirely by [`OnboardingState`]:
+//! whether setup has been completed, the suggested playlists directory, any
+//! existing music folders found on this machine, and devices used before.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{AppConfig, default_playlists_directory};
+use crate::playlist::is_audio_file;
+
+/// How deep below a candidate folder to look for audio files.
+const MUSIC_SCAN_DEPTH: usize = 3;
+
+/// Stop counting audio files in a folder after this many.
+const MUSIC_SCAN_LIMIT: usize = 1000;
+
+/// An existing folder containing music.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DetectedMusicFolder {
+    /// Folder path.
+    pub path: PathBuf,
+    /// Number of audio files found (capped at an internal scan limit).
+    pub audio_file_count: usize,
+}
+
+/// A device referenced by the configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownDevice {
+    /// Device mount point.
+    pub mount_point: PathBuf,
+    /// Whether the device is currently mounted.
+    pub connected: bool,
+}
+
+/// Everything the first-run wizard needs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OnboardingState {
+    /// Whether first-run setup has been completed.
+    pub completed: bool,
+    /// Currently configured playlists directory.
+    pub playlists_directory: PathBuf,
+    /// Suggested playlists directory for a fresh setup.
+    pub suggested_playlists_directory: PathBuf,
+    /// Existing music folders found on this machine.
+    pub music_folders: Vec<DetectedMusicFolder>,
+    /// Devices used before (the preferred device and those of saved profiles).
+    pub known_devices: Vec<KnownDevice>,
+}
+
+impl OnboardingState {
+    /// Build the onboarding state for `config`, scanning for music folders.
+    #[must_use]
+    pub fn detect(config: &AppConfig) -> Self {
+        let mut candidates = vec![config.playlists_directory.clone()];
+        candidates.extend(default_music_directories());
+
+        Self {
+            completed: config.onboarding_completed,
+            playlists_directory: config.playlists_directory.clone(),
+            suggested_playlists_directory: default_playlists_directory(),
+            music_folders: detect_music_folders(&candidates),
+            known_devices: known_devices(config),
+        }
+    }
+}
+
+/// Platform music directories worth checking for an existing library.
+fn default_music_directories() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(audio) = dirs::audio_dir() {
+        dirs.push(audio);
+    }
+    if let Some(home) = dirs::home_dir() {
+        dirs.push(home.join("Music"));
+    }
+    dirs
+}
+
+/// Return the candidates that exist and contain audio files, most populated first.
+#[must_use]
+pub fn detect_music_folders(candidates: &[PathBuf]) -> Vec<DetectedMusicFolder> {
+    let mut seen = BTreeSet::new();
+    let mut folders: Vec<DetectedMusicFolder> = candidates
+        .iter()
+        .filter(|path| path.is_dir())
+        .filter(|path| seen.insert(path.canonicalize().unwrap_or_else(|_| (*path).clone())))
+        .filter_map(|path| {
+            let audio_file_count = count_audio_files(path, MUSIC_SCAN_DEPTH);
+            (audio_file_count > 0).then(|| DetectedMusicFolder {
+                path: path.clone(),
+                audio_file_count,
+            })
+        })
+        .collect();
+
+    folders.sort_by(|a, b| b.audio_file_count.cmp(&a.audio_file_count));
+    folders
+}
+
+/// Count audio files below `dir`, up to `depth` levels and [`MUSIC_SCAN_LIMIT`] files.
+fn count_audio_files(dir: &Path, depth: usize) -> usize {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+
+    let mut count = 0;
+    for entry in entries.flatten() {
+        if count >= MUSIC_SCAN_LIMIT {
+            break;
+        }
+        let path = entry.path();
+        match entry.file_type() {
+            Ok(t) if t.is_dir() && depth > 0 => count += count_audio_files(&path, depth - 1),
+            Ok(t) if t.is_file() && is_audio_file(&path) => count += 1,
+            _ => {}
+        }
+    }
+    count.min(MUSIC_SCAN_LIMIT)
+}
+
+/// Devices referenced by the config, deduplicated and sorted.
+#[must_use]
+pub fn known_devices(config: &AppConfig) -> Vec<KnownDevice> {
+    let mount_points: BTreeSet<&PathBuf> = config
+        .preferred_device
+        .iter()
+        .chain(
+            config
+                .profiles
+                .values()
+                .filter_map(|p| p.preferred_device.as_ref()),
+        )
+        .collect();
+
+    mount_points
+        .into_iter()
+        .map(|mount_point| KnownDevice {
+            mount_point: mount_point.clone(),
+            connected: mount_point.is_dir(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::config::ConfigProfile;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_music_folders() {
+        let temp = TempDir::new().unwrap();
+        let small = temp.path().join("small");
+        let large = temp.path().join("large");
+        let empty = temp.path().join("empty");
+        fs::create_dir_all(small.join("album")).unwrap();
+        fs::create_dir_all(large.join("a").join("b")).unwrap();
+        fs::create_dir_all(&empty).unwrap();
+        fs::write(small.join("album").join("1.mp3"), b"x").unwrap();
+        fs::write(small.join("notes.txt"), b"x").unwrap();
+        fs::write(large.join("2.flac"), b"x").unwrap();
+        fs::write(large.join("a").join("b").join("3.m4a"), b"x").unwrap();
+
+        let folders = detect_music_folders(&[
+            small.clone(),
+            large.clone(),
+            empty,
+            temp.path().join("missing"),
+            small.clone(),
+        ]);
+
+        assert_eq!(
+            folders,
+            vec![
+                DetectedMusicFolder {
+                    path: large,
+                    audio_file_count: 2
+                },
+                DetectedMusicFolder {
+                    path: small,
+                    audio_file_count: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_known_devices_from_config_and_profiles() {
+        let temp = TempDir::new().unwrap();
+        let connected = temp.path().to_path_buf();
+        let missing = PathBuf::from("/definitely/not/mounted");
+
+        let mut config = AppConfig {
+            preferred_device: Some(connected.clone()),
+            ..Default::default()
+        };
+        let mut profile = ConfigProfile::from_config(&config);
+        profile.preferred_device = Some(missing.clone());
+        config
+            .profiles
+            .insert("travel".to_string(), profile.clone());
+        profile.preferred_device = Some(connected.clone());
+        config.profiles.insert("home".to_string(), profile);
+
+        let devices = known_devices(&config);
+        assert_eq!(devices.len(), 2);
+        assert!(devices.contains(&KnownDevice {
+            mount_point: connected,
+            connected: true
+        }));
+        assert!(devices.contains(&KnownDevice {
+            mount_point: missing,
+            connected: false
+        }));
+    }
+
+    #[test]
+    fn test_onboarding_state_reflects_config() {
+        let temp = TempDir::new().unwrap();
+        let config = AppConfig {
+            playlists_directory: temp.path().to_path_buf(),
+            onboarding_completed: true,
+            ..Default::default()
+        };
+
+        let state = OnboardingState::detect(&config);
+        assert!(state.completed);
+        assert_eq!(state.playlists_directory, temp.path());
+        assert!(state.known_devices.is_empty());
+    }
+}
@@ -0,0 +1,337 @@
+//! Simple mode (parental controls).
+//!
+//! In simple mode, destructive operations such as deleting a playlist or
+//! cleaning up a device only run when the parent PIN is provided. The PIN
+//! lives in the [`SecretsStore`] as a salted SHA-256 hash, never in plain
+//! text. [`ParentalControls`] locks PIN entry for a growing delay after
+//! repeated wrong guesses.
+
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chacha20poly1305::aead::OsRng;
+use chacha20poly1305::aead::rand_core::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::{info, warn};
+
+use crate::error::{Error, Result};
+use crate::secrets::{SecretsStore, hex_encode, secret_keys};
+
+/// Shortest accepted PIN.
+pub const MIN_PIN_LENGTH: usize = 4;
+
+/// Longest accepted PIN.
+pub const MAX_PIN_LENGTH: usize = 12;
+
+/// Length of the random salt mixed into the PIN hash, in bytes.
+const SALT_LENGTH: usize = 16;
+
+/// Wrong PINs accepted before PIN entry is locked.
+pub const MAX_PIN_ATTEMPTS: u32 = 5;
+
+/// Lockout after [`MAX_PIN_ATTEMPTS`] wrong PINs, doubled for each further one.
+const BASE_LOCKOUT: Duration = Duration::from_secs(30);
+
+/// Longest lockout.
+const MAX_LOCKOUT: Duration = Duration::from_secs(60 * 60);
+
+/// An operation that needs the parent PIN in simple mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProtectedOperation {
+    /// Deleting a playlist from the library.
+    DeletePlaylist,
+    /// Deleting tracks from a playlist.
+    DeleteTracks,
+    /// Deleting files from a device.
+    CleanupDevice,
+}
+
+impl fmt::Display for ProtectedOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::DeletePlaylist => "Deleting a playlist",
+            Self::DeleteTracks => "Deleting tracks",
+            Self::CleanupDevice => "Deleting files from a device",
+        })
+    }
+}
+
+/// Check that a PIN is 4 to 12 digits.
+#[must_use]
+pub fn is_valid_pin(pin: &str) -> bool {
+    (MIN_PIN_LENGTH..=MAX_PIN_LENGTH).contains(&pin.len())
+        && pin.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Hash a PIN with a fresh random salt, as `salt:hash` in hex.
+#[must_use]
+pub fn hash_pin(pin: &str) -> String {
+    let mut salt = [0u8; SALT_LENGTH];
+    OsRng.fill_bytes(&mut salt);
+    let salt = hex_encode(&salt);
+    let hash = salted_hash(&salt, pin);
+    format!("{salt}:{hash}")
+}
+
+/// Check a PIN against a hash from [`hash_pin`].
+#[must_use]
+pub fn verify_pin_hash(stored: &str, pin: &str) -> bool {
+    let Some((salt, hash)) = stored.split_once(':') else {
+        return false;
+    };
+    let candidate = salted_hash(salt, pin);
+    // Compare without returning early so timing doesn't reveal the hash
+    candidate.len() == hash.len()
+        && candidate
+            .bytes()
+            .zip(hash.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn salted_hash(salt: &str, pin: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(salt.as_bytes());
+    hasher.update(pin.as_bytes());
+    hex_encode(&hasher.finalize())
+}
+
+/// Check whether a parent PIN has been set.
+pub fn pin_is_set(secrets: &SecretsStore) -> Result<bool> {
+    secrets.contains(secret_keys::PARENT_PIN)
+}
+
+/// Set or replace the parent PIN.
+pub fn set_pin(secrets: &SecretsStore, pin: &str) -> Result<()> {
+    secrets.set(secret_keys::PARENT_PIN, &hash_pin(pin))?;
+    info!("Parent PIN set");
+    Ok(())
+}
+
+/// Remove the parent PIN. Returns `false` if none was set.
+pub fn clear_pin(secrets: &SecretsStore) -> Result<bool> {
+    let removed = secrets.delete(secret_keys::PARENT_PIN)?;
+    if removed {
+        info!("Parent PIN removed");
+    }
+    Ok(removed)
+}
+
+/// Check a PIN against the stored parent PIN.
+///
+/// Returns `false` if the PIN is wrong or no PIN is set.
+pub fn check_pin(secrets: &SecretsStore, pin: &str) -> Result<bool> {
+    Ok(secrets
+        .get(secret_keys::PARENT_PIN)?
+        .is_some_and(|stored| verify_pin_hash(&stored, pin)))
+}
+
+/// Outcome of a PIN check through [`ParentalControls`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PinCheck {
+    /// The PIN matched.
+    Accepted,
+    /// The PIN was wrong (or no PIN is set).
+    Rejected {
+        /// Wrong PINs left before PIN entry is locked.
+        attempts_left: u32,
+    },
+    /// Too many wrong PINs; the PIN was not checked.
+    LockedOut {
+        /// Time until a PIN can be entered again.
+        retry_after: Duration,
+    },
+}
+
+/// Counts wrong PINs and locks PIN entry after too many.
+#[derive(Debug, Default)]
+struct PinAttempts {
+    failures: u32,
+    locked_until: Option<Instant>,
+}
+
+impl PinAttempts {
+    fn lockout_remaining(&self, now: Instant) -> Option<Duration> {
+        self.locked_until
+            .map(|until| until.saturating_duration_since(now))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    fn record_failure(&mut self, now: Instant) -> PinCheck {
+        self.failures = self.failures.saturating_add(1);
+        if self.failures < MAX_PIN_ATTEMPTS {
+            return PinCheck::Rejected {
+                attempts_left: MAX_PIN_ATTEMPTS - self.failures,
+            };
+        }
+        let doublings = (self.failures - MAX_PIN_ATTEMPTS).min(16);
+        let retry_after = BASE_LOCKOUT.saturating_mul(1 << doublings).min(MAX_LOCKOUT);
+        self.locked_until = Some(now + retry_after);
+        PinCheck::LockedOut { retry_after }
+    }
+
+    fn record_success(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Checks PINs against the parent PIN, with a lockout after repeated wrong
+/// guesses.
+///
+/// After [`MAX_PIN_ATTEMPTS`] wrong PINs in a row, PIN entry is locked for
+/// 30 seconds, and each further wrong PIN doubles the lockout, up to an
+/// hour. A correct PIN resets the count.
+#[derive(Debug, Default)]
+pub struct ParentalControls {
+    attempts: Mutex<PinAttempts>,
+}
+
+impl ParentalControls {
+    /// Create parental controls with no failed attempts.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check a PIN against the stored parent PIN, counting wrong guesses.
+    ///
+    /// While locked out, the PIN is not checked at all.
+    pub fn check_pin(&self, secrets: &SecretsStore, pin: &str) -> Result<PinCheck> {
+        let mut attempts = self
+            .attempts
+            .lock()
+            .map_err(|_| Error::internal("Parental controls lock poisoned"))?;
+        let now = Instant::now();
+        if let Some(retry_after) = attempts.lockout_remaining(now) {
+            return Ok(PinCheck::LockedOut { retry_after });
+        }
+        if check_pin(secrets, pin)? {
+            attempts.record_success();
+            Ok(PinCheck::Accepted)
+        } else {
+            warn!("Incorrect parent PIN ({} in a row)", attempts.failures + 1);
+            Ok(attempts.record_failure(now))
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::secrets::EncryptedFileBackend;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_is_valid_pin() {
+        assert!(is_valid_pin("1234"));
+        assert!(is_valid_pin("123456789012"));
+        assert!(!is_valid_pin("123"));
+        assert!(!is_valid_pin("1234567890123"));
+        assert!(!is_valid_pin("12a4"));
+        assert!(!is_valid_pin(""));
+    }
+
+    #[test]
+    fn test_hash_and_verify_pin() {
+        let hash = hash_pin("2468");
+        assert!(verify_pin_hash(&hash, "2468"));
+        assert!(!verify_pin_hash(&hash, "2469"));
+        assert!(!verify_pin_hash("not a hash", "2468"));
+
+        // Salted, so the same PIN hashes differently each time
+        assert_ne!(hash_pin("2468"), hash);
+    }
+
+    #[test]
+    fn test_pin_lifecycle() {
+        let temp = TempDir::new().unwrap();
+        let secrets = SecretsStore::with_backend(EncryptedFileBackend::in_directory(temp.path()));
+
+        assert!(!pin_is_set(&secrets).unwrap());
+        assert!(!check_pin(&secrets, "1234").unwrap());
+
+        set_pin(&secrets, "1234").unwrap();
+        assert!(pin_is_set(&secrets).unwrap());
+        assert!(check_pin(&secrets, "1234").unwrap());
+        assert!(!check_pin(&secrets, "4321").unwrap());
+
+        assert!(clear_pin(&secrets).unwrap());
+        assert!(!clear_pin(&secrets).unwrap());
+        assert!(!pin_is_set(&secrets).unwrap());
+    }
+
+    #[test]
+    fn test_lockout_escalates() {
+        let mut attempts = PinAttempts::default();
+        let now = Instant::now();
+
+        for left in (1..MAX_PIN_ATTEMPTS).rev() {
+            assert_eq!(
+                attempts.record_failure(now),
+                PinCheck::Rejected {
+                    attempts_left: left
+                }
+            );
+            assert!(attempts.lockout_remaining(now).is_none());
+        }
+
+        assert_eq!(
+            attempts.record_failure(now),
+            PinCheck::LockedOut {
+                retry_after: BASE_LOCKOUT
+            }
+        );
+        assert_eq!(attempts.lockout_remaining(now), Some(BASE_LOCKOUT));
+        assert!(attempts.lockout_remaining(now + BASE_LOCKOUT).is_none());
+
+        let later = now + BASE_LOCKOUT;
+        assert_eq!(
+            attempts.record_failure(later),
+            PinCheck::LockedOut {
+                retry_after: BASE_LOCKOUT * 2
+            }
+        );
+
+        for _ in 0..20 {
+            attempts.record_failure(later);
+        }
+        assert_eq!(attempts.lockout_remaining(later), Some(MAX_LOCKOUT));
+
+        attempts.record_success();
+        assert_eq!(attempts.failures, 0);
+        assert!(attempts.lockout_remaining(later).is_none());
+    }
+
+    #[test]
+    fn test_parental_controls_lock_out_wrong_pins() {
+        let temp = TempDir::new().unwrap();
+        let secrets = SecretsStore::with_backend(EncryptedFileBackend::in_directory(temp.path()));
+        set_pin(&secrets, "1234").unwrap();
+        let controls = ParentalControls::new();
+
+        assert_eq!(
+            controls.check_pin(&secrets, "0000").unwrap(),
+            PinCheck::Rejected {
+                attempts_left: MAX_PIN_ATTEMPTS - 1
+            }
+        );
+        // A correct PIN resets the count
+        assert_eq!(
+            controls.check_pin(&secrets, "1234").unwrap(),
+            PinCheck::Accepted
+        );
+
+        for _ in 0..MAX_PIN_ATTEMPTS {
+            controls.check_pin(&secrets, "0000").unwrap();
+        }
+        // Locked out, even with the right PIN
+        assert!(matches!(
+            controls.check_pin(&secrets, "1234").unwrap(),
+            PinCheck::LockedOut { .. }
+        ));
+    }
+}
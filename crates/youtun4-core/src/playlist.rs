@@ -3,14 +3,18 @@
 //! Handles creating, deleting, and syncing playlists.
 //! Each playlist is represented as a folder containing MP3 files.
 
-use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info, warn};
-use walkdir::WalkDir;
 
 use crate::error::{Error, FileSystemError, Result};
+use crate::fs::{FileSystem, RealFileSystem};
+use crate::trash::{StoredEntry, Trash, TrashEntry, TrashId, TrashedItem, TrashedTracks};
+use crate::youtube::archive::{DOWNLOAD_ARCHIVE_FILE, DownloadArchive};
+use crate::youtube::diff::PlaylistDiff;
+use crate::youtube::template::FilenameTemplate;
 
 /// Metadata for a playlist.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -27,6 +31,9 @@ pub struct PlaylistMetadata {
     pub track_count: usize,
     /// Total size in bytes.
     pub total_bytes: u64,
+    /// Tags given to the playlist.
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 /// Information about a single track.
@@ -42,6 +49,74 @@ pub struct TrackInfo {
     pub metadata: Option<crate::metadata::Mp3Metadata>,
 }
 
+/// A page of a playlist's tracks, ordered by file name.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrackPage {
+    /// Index of the first track in the page.
+    pub offset: usize,
+    /// Total number of tracks in the playlist.
+    pub total: usize,
+    /// Tracks in the page, with ID3 metadata.
+    pub tracks: Vec<TrackInfo>,
+    /// Sum of the track durations recorded at download time, if any were.
+    pub total_duration_secs: Option<u64>,
+}
+
+/// A track matching a library search.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrackSearchMatch {
+    /// Playlist containing the track.
+    pub playlist: String,
+    /// Track file name.
+    pub file_name: String,
+    /// Title recorded at download time, if any.
+    pub title: Option<String>,
+    /// Channel recorded at download time, if any.
+    pub channel: Option<String>,
+}
+
+/// Results of a library search, best matches first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LibrarySearchResults {
+    /// Playlists whose name or title matches.
+    pub playlists: Vec<PlaylistMetadata>,
+    /// Tracks whose file name, title or channel matches.
+    pub tracks: Vec<TrackSearchMatch>,
+}
+
+/// An item a batch operation couldn't process.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BatchFailure {
+    /// Playlist name or track file name.
+    pub item: String,
+    /// Why the item failed.
+    pub error: String,
+}
+
+/// Outcome of an operation on several playlists or tracks.
+///
+/// Each item is processed on its own, so one failure doesn't stop the rest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BatchResult {
+    /// Items processed successfully, in order.
+    pub succeeded: Vec<String>,
+    /// Items that failed, in order.
+    pub failed: Vec<BatchFailure>,
+}
+
+impl BatchResult {
+    /// Record the result of processing `item`.
+    fn record(&mut self, item: &str, result: Result<()>) {
+        match result {
+            Ok(()) => self.succeeded.push(item.to_string()),
+            Err(e) => self.failed.push(BatchFailure {
+                item: item.to_string(),
+                error: e.to_string(),
+            }),
+        }
+    }
+}
+
 /// Statistics about a playlist folder.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct FolderStatistics {
@@ -86,6 +161,8 @@ impl FolderValidationResult {
 pub struct PlaylistManager {
     /// Base directory where playlists are stored.
     base_path: PathBuf,
+    /// File system the playlists are read from and written to.
+    fs: Arc<dyn FileSystem>,
 }
 
 impl PlaylistManager {
@@ -95,15 +172,22 @@ impl PlaylistManager {
     ///
     /// Returns an error if the base path cannot be created.
     pub fn new(base_path: PathBuf) -> Result<Self> {
-        if !base_path.exists() {
-            fs::create_dir_all(&base_path).map_err(|e| {
-                Error::FileSystem(FileSystemError::CreateDirFailed {
-                    path: base_path.clone(),
-                    reason: e.to_string(),
-                })
-            })?;
+        Self::with_file_system(base_path, Arc::new(RealFileSystem))
+    }
+
+    /// Create a playlist manager working on `fs`.
+    ///
+    /// Track metadata is still read from the real file system, since ID3
+    /// tags are parsed from the track files directly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the base path cannot be created.
+    pub fn with_file_system(base_path: PathBuf, fs: Arc<dyn FileSystem>) -> Result<Self> {
+        if !fs.exists(&base_path) {
+            fs.create_dir_all(&base_path)?;
         }
-        Ok(Self { base_path })
+        Ok(Self { base_path, fs })
     }
 
     /// Get the base path for playlists.
@@ -112,6 +196,27 @@ impl PlaylistManager {
         &self.base_path
     }
 
+    /// The file system playlists are stored on.
+    #[must_use]
+    pub fn file_system(&self) -> Arc<dyn FileSystem> {
+        Arc::clone(&self.fs)
+    }
+
+    /// Files directly inside a folder, skipping anything that can't be read.
+    fn folder_files(&self, path: &Path) -> Vec<PathBuf> {
+        self.fs
+            .read_dir(path)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|p| self.fs.is_file(p))
+            .collect()
+    }
+
+    /// Size of a file in bytes, or 0 if it can't be read.
+    fn file_size(&self, path: &Path) -> u64 {
+        self.fs.metadata(path).map_or(0, |m| m.len)
+    }
+
     /// List all playlists.
     ///
     /// # Errors
@@ -120,23 +225,8 @@ impl PlaylistManager {
     pub fn list_playlists(&self) -> Result<Vec<PlaylistMetadata>> {
         let mut playlists = Vec::new();
 
-        let entries = fs::read_dir(&self.base_path).map_err(|e| {
-            Error::FileSystem(FileSystemError::ReadFailed {
-                path: self.base_path.clone(),
-                reason: e.to_string(),
-            })
-        })?;
-
-        for entry in entries {
-            let entry = entry.map_err(|e| {
-                Error::FileSystem(FileSystemError::ReadFailed {
-                    path: self.base_path.clone(),
-                    reason: e.to_string(),
-                })
-            })?;
-
-            let path = entry.path();
-            if path.is_dir() {
+        for path in self.fs.read_dir(&self.base_path)? {
+            if self.fs.is_dir(&path) {
                 match self.get_playlist_metadata(&path) {
                     Ok(metadata) => playlists.push(metadata),
                     Err(e) => {
@@ -169,28 +259,23 @@ impl PlaylistManager {
             .to_string();
 
         let metadata_file = playlist_path.join("playlist.json");
-        let (source_url, created_at) = if metadata_file.exists() {
-            let content = fs::read_to_string(&metadata_file).map_err(|e| {
-                Error::FileSystem(FileSystemError::ReadFailed {
-                    path: metadata_file.clone(),
-                    reason: e.to_string(),
-                })
-            })?;
+        let folder = self.fs.metadata(playlist_path).ok();
+        let (source_url, created_at, tags) = if self.fs.exists(&metadata_file) {
+            let content = self.fs.read_to_string(&metadata_file)?;
             let saved: SavedPlaylistMetadata =
                 serde_json::from_str(&content).map_err(Error::Serialization)?;
-            (saved.source_url, saved.created_at)
+            (saved.source_url, saved.created_at, saved.tags)
         } else {
-            let created = fs::metadata(playlist_path)
-                .and_then(|m| m.created())
-                .ok()
+            let created = folder
+                .as_ref()
+                .and_then(|m| m.created)
                 .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
                 .map_or(0, |d| d.as_secs());
-            (None, created)
+            (None, created, Vec::new())
         };
 
-        let modified_at = fs::metadata(playlist_path)
-            .and_then(|m| m.modified())
-            .ok()
+        let modified_at = folder
+            .and_then(|m| m.modified)
             .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
             .map_or(0, |d| d.as_secs());
 
@@ -203,6 +288,7 @@ impl PlaylistManager {
             modified_at,
             track_count,
             total_bytes,
+            tags,
         })
     }
 
@@ -212,40 +298,28 @@ impl PlaylistManager {
     ///
     /// Returns an error if the playlist doesn't exist.
     pub fn get_folder_statistics(&self, name: &str) -> Result<FolderStatistics> {
-        let playlist_path = self.base_path.join(name);
-        if !playlist_path.exists() {
-            return Err(Error::Playlist(crate::error::PlaylistError::NotFound {
-                name: name.to_string(),
-            }));
-        }
+        let playlist_path = self.get_playlist_path(name)?;
 
         let mut audio_files = 0;
         let mut other_files = 0;
         let mut audio_size_bytes = 0u64;
         let mut total_size_bytes = 0u64;
         let metadata_file = playlist_path.join("playlist.json");
-        let has_metadata = metadata_file.exists();
+        let has_metadata = self.fs.exists(&metadata_file);
 
-        for entry in WalkDir::new(&playlist_path)
-            .min_depth(1)
-            .max_depth(1)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-        {
-            let path = entry.path();
-            if path.is_file() {
-                let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
-                total_size_bytes += size;
-
-                if is_audio_file(path) {
-                    audio_files += 1;
-                    audio_size_bytes += size;
-                } else {
-                    // Exclude playlist.json from "other" files count
-                    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-                    if file_name != "playlist.json" {
-                        other_files += 1;
-                    }
+        for path in self.folder_files(&playlist_path) {
+            let size = self.file_size(&path);
+            total_size_bytes += size;
+
+            if is_audio_file(&path) {
+                audio_files += 1;
+                audio_size_bytes += size;
+            } else {
+                // Exclude playlist.json and the download archive from
+                // "other" files count
+                let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+                if file_name != "playlist.json" && file_name != DOWNLOAD_ARCHIVE_FILE {
+                    other_files += 1;
                 }
             }
         }
@@ -265,18 +339,10 @@ impl PlaylistManager {
         let mut count = 0;
         let mut total_bytes = 0;
 
-        for entry in WalkDir::new(playlist_path)
-            .min_depth(1)
-            .max_depth(1)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-        {
-            let path = entry.path();
-            if path.is_file() && is_audio_file(path) {
+        for path in self.folder_files(playlist_path) {
+            if is_audio_file(&path) {
                 count += 1;
-                if let Ok(meta) = fs::metadata(path) {
-                    total_bytes += meta.len();
-                }
+                total_bytes += self.file_size(&path);
             }
         }
 
@@ -292,7 +358,7 @@ impl PlaylistManager {
         validate_playlist_name(name)?;
 
         let playlist_path = self.base_path.join(name);
-        if playlist_path.exists() {
+        if self.fs.exists(&playlist_path) {
             return Err(Error::Playlist(
                 crate::error::PlaylistError::AlreadyExists {
                     name: name.to_string(),
@@ -300,12 +366,7 @@ impl PlaylistManager {
             ));
         }
 
-        fs::create_dir_all(&playlist_path).map_err(|e| {
-            Error::FileSystem(FileSystemError::CreateDirFailed {
-                path: playlist_path.clone(),
-                reason: e.to_string(),
-            })
-        })?;
+        self.fs.create_dir_all(&playlist_path)?;
 
         // Save metadata
         let now = std::time::SystemTime::now()
@@ -322,16 +383,13 @@ impl PlaylistManager {
             track_count: 0,
             total_size_bytes: 0,
             tracks: Vec::new(),
+            tags: Vec::new(),
+            download_profile: None,
         };
 
         let metadata_path = playlist_path.join("playlist.json");
         let content = serde_json::to_string_pretty(&metadata)?;
-        fs::write(&metadata_path, content).map_err(|e| {
-            Error::FileSystem(FileSystemError::WriteFailed {
-                path: metadata_path,
-                reason: e.to_string(),
-            })
-        })?;
+        self.fs.write(&metadata_path, &content)?;
 
         info!("Created playlist: {}", name);
         Ok(playlist_path)
@@ -343,22 +401,228 @@ impl PlaylistManager {
     ///
     /// Returns an error if the playlist doesn't exist or cannot be deleted.
     pub fn delete_playlist(&self, name: &str) -> Result<()> {
-        let playlist_path = self.base_path.join(name);
-        if !playlist_path.exists() {
-            return Err(Error::Playlist(crate::error::PlaylistError::NotFound {
+        let playlist_path = self.get_playlist_path(name)?;
+        self.fs.remove_dir_all(&playlist_path)?;
+
+        info!("Deleted playlist: {}", name);
+        Ok(())
+    }
+
+    /// Move a playlist out of the library into `archive_dir`.
+    ///
+    /// The folder keeps its name unless an archived playlist already uses it,
+    /// in which case a numeric suffix is added. Returns the archived folder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the playlist doesn't exist or cannot be moved.
+    pub fn archive_playlist(&self, name: &str, archive_dir: &Path) -> Result<PathBuf> {
+        let playlist_path = self.get_playlist_path(name)?;
+
+        self.fs.create_dir_all(archive_dir)?;
+
+        let mut target = archive_dir.join(name);
+        let mut suffix = 2;
+        while self.fs.exists(&target) {
+            target = archive_dir.join(format!("{name} ({suffix})"));
+            suffix += 1;
+        }
+
+        move_path(self.fs.as_ref(), &playlist_path, &target)?;
+
+        info!("Archived playlist {} to {}", name, target.display());
+        Ok(target)
+    }
+
+    /// Move a playlist to the trash, so it can be restored this session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the playlist doesn't exist or cannot be moved.
+    pub fn trash_playlist(&self, name: &str, trash: &Trash) -> Result<TrashEntry> {
+        let playlist_path = self.get_playlist_path(name)?;
+        let (id, dir) = trash.reserve()?;
+        move_path(self.fs.as_ref(), &playlist_path, &dir.join(name))?;
+
+        let entry = TrashEntry {
+            id,
+            item: TrashedItem::Playlist {
                 name: name.to_string(),
-            }));
+            },
+            trashed_at: unix_now(),
+        };
+        trash.insert(StoredEntry {
+            entry: entry.clone(),
+            tracks: Vec::new(),
+        })?;
+        info!("Moved playlist to trash: {}", name);
+        Ok(entry)
+    }
+
+    /// Move several tracks of a playlist to the trash, along with their
+    /// saved metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the playlist doesn't exist or its metadata cannot
+    /// be updated.
+    pub fn trash_tracks(
+        &self,
+        playlist: &str,
+        file_names: &[String],
+        trash: &Trash,
+    ) -> Result<TrashedTracks> {
+        let playlist_path = self.get_playlist_path(playlist)?;
+        let (id, dir) = trash.reserve()?;
+        let mut result = BatchResult::default();
+        for file_name in file_names {
+            let moved = self
+                .track_path(&playlist_path, playlist, file_name)
+                .and_then(|path| move_path(self.fs.as_ref(), &path, &dir.join(file_name)));
+            result.record(file_name, moved);
         }
 
-        fs::remove_dir_all(&playlist_path).map_err(|e| {
-            Error::FileSystem(FileSystemError::DeleteFailed {
-                path: playlist_path,
-                reason: e.to_string(),
-            })
+        if result.succeeded.is_empty() {
+            trash.forget(id);
+            return Ok(TrashedTracks {
+                entry: None,
+                result,
+            });
+        }
+
+        let mut metadata = self.get_saved_metadata(playlist)?;
+        let (trashed, kept): (Vec<_>, Vec<_>) = metadata
+            .tracks
+            .into_iter()
+            .partition(|t| result.succeeded.contains(&t.file_name));
+        metadata.tracks = kept;
+        self.write_saved_metadata(playlist, &metadata)?;
+
+        let entry = TrashEntry {
+            id,
+            item: TrashedItem::Tracks {
+                playlist: playlist.to_string(),
+                file_names: result.succeeded.clone(),
+            },
+            trashed_at: unix_now(),
+        };
+        trash.insert(StoredEntry {
+            entry: entry.clone(),
+            tracks: trashed,
         })?;
+        info!(
+            "Moved {} track(s) of playlist {} to trash",
+            result.succeeded.len(),
+            playlist
+        );
+        Ok(TrashedTracks {
+            entry: Some(entry),
+            result,
+        })
+    }
 
-        info!("Deleted playlist: {}", name);
-        Ok(())
+    /// Move the tracks of the videos `diff` found removed from the
+    /// playlist's `YouTube` source to the trash, and refresh the playlist's
+    /// stats.
+    ///
+    /// Removed videos are also forgotten by the download archive, unless
+    /// their track couldn't be trashed, so they're downloaded again if they
+    /// come back to the source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the playlist doesn't exist, or its metadata or
+    /// download archive cannot be updated.
+    pub fn trash_removed_videos(
+        &self,
+        playlist: &str,
+        diff: &PlaylistDiff,
+        trash: &Trash,
+    ) -> Result<TrashedTracks> {
+        let playlist_path = self.get_playlist_path(playlist)?;
+        let file_names = diff.removed_file_names();
+        let trashed = if file_names.is_empty() {
+            TrashedTracks {
+                entry: None,
+                result: BatchResult::default(),
+            }
+        } else {
+            self.trash_tracks(playlist, &file_names, trash)?
+        };
+
+        let forgotten: Vec<&str> = diff
+            .removed_videos
+            .iter()
+            .filter(|video| {
+                video.file_name.as_ref().is_none_or(|file_name| {
+                    !trashed
+                        .result
+                        .failed
+                        .iter()
+                        .any(|failure| &failure.item == file_name)
+                        || !self.fs.exists(&playlist_path.join(file_name))
+                })
+            })
+            .map(|video| video.video_id.as_str())
+            .collect();
+        DownloadArchive::open(&playlist_path).forget(&forgotten)?;
+        self.refresh_playlist_stats(playlist)?;
+        Ok(trashed)
+    }
+
+    /// Put a trashed playlist or tracks back where they were.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry is not in the trash, a playlist with the
+    /// same name was created since, the tracks' playlist no longer exists, or
+    /// the files cannot be moved back. The entry stays in the trash if
+    /// nothing was restored.
+    pub fn restore_from_trash(&self, id: TrashId, trash: &Trash) -> Result<TrashedItem> {
+        let (stored, dir) = trash.take(id)?;
+        let restored = match &stored.entry.item {
+            TrashedItem::Playlist { name } => {
+                let target = self.base_path.join(name);
+                if self.fs.exists(&target) {
+                    Err(Error::Playlist(
+                        crate::error::PlaylistError::AlreadyExists { name: name.clone() },
+                    ))
+                } else {
+                    move_path(self.fs.as_ref(), &dir.join(name), &target)
+                }
+            }
+            TrashedItem::Tracks {
+                playlist,
+                file_names,
+            } => self.get_playlist_path(playlist).and_then(|playlist_path| {
+                for file_name in file_names {
+                    let target = playlist_path.join(file_name);
+                    if self.fs.exists(&target) {
+                        return Err(Error::FileSystem(FileSystemError::AlreadyExists {
+                            path: target,
+                        }));
+                    }
+                }
+                for file_name in file_names {
+                    move_path(
+                        self.fs.as_ref(),
+                        &dir.join(file_name),
+                        &playlist_path.join(file_name),
+                    )?;
+                }
+                let mut metadata = self.get_saved_metadata(playlist)?;
+                metadata.tracks.extend(stored.tracks.iter().cloned());
+                self.write_saved_metadata(playlist, &metadata)
+            }),
+        };
+
+        if let Err(e) = restored {
+            trash.insert(stored)?;
+            return Err(e);
+        }
+        trash.forget(id);
+        info!("Restored from trash: {:?}", stored.entry.item);
+        Ok(stored.entry.item)
     }
 
     /// Get the path to a playlist.
@@ -368,7 +632,7 @@ impl PlaylistManager {
     /// Returns an error if the playlist doesn't exist.
     pub fn get_playlist_path(&self, name: &str) -> Result<PathBuf> {
         let playlist_path = self.base_path.join(name);
-        if !playlist_path.exists() {
+        if !self.fs.exists(&playlist_path) {
             return Err(Error::Playlist(crate::error::PlaylistError::NotFound {
                 name: name.to_string(),
             }));
@@ -376,6 +640,21 @@ impl PlaylistManager {
         Ok(playlist_path)
     }
 
+    /// Whether `path` is inside the playlists directory.
+    ///
+    /// Both paths are resolved first, so `..` components and symlinks can't
+    /// escape the library. Paths that don't exist are never inside.
+    #[must_use]
+    pub fn contains_path(&self, path: &Path) -> bool {
+        match (
+            self.fs.canonicalize(path),
+            self.fs.canonicalize(&self.base_path),
+        ) {
+            (Ok(path), Ok(base)) => path.starts_with(base),
+            _ => false,
+        }
+    }
+
     /// List tracks in a playlist.
     ///
     /// # Errors
@@ -395,7 +674,7 @@ impl PlaylistManager {
     pub fn sync_to_device(&self, playlist_name: &str, device_mount_point: &Path) -> Result<()> {
         let playlist_path = self.get_playlist_path(playlist_name)?;
 
-        if !device_mount_point.exists() {
+        if !self.fs.exists(device_mount_point) {
             return Err(Error::Device(crate::error::DeviceError::NotMounted {
                 mount_point: device_mount_point.to_path_buf(),
             }));
@@ -409,11 +688,11 @@ impl PlaylistManager {
 
         // Clear device contents (except hidden files/system files)
         debug!("Clearing device contents...");
-        clear_directory(device_mount_point)?;
+        clear_directory(self.fs.as_ref(), device_mount_point)?;
 
         // Copy playlist contents
         debug!("Copying playlist contents...");
-        copy_directory_contents(&playlist_path, device_mount_point)?;
+        copy_directory_contents(self.fs.as_ref(), &playlist_path, device_mount_point)?;
 
         info!("Sync completed successfully");
         Ok(())
@@ -427,7 +706,7 @@ impl PlaylistManager {
         let playlist_path = self.base_path.join(name);
         let mut issues = Vec::new();
 
-        let exists = playlist_path.exists() && playlist_path.is_dir();
+        let exists = self.fs.is_dir(&playlist_path);
         if !exists {
             return FolderValidationResult {
                 exists: false,
@@ -439,11 +718,11 @@ impl PlaylistManager {
         }
 
         let metadata_file = playlist_path.join("playlist.json");
-        let has_metadata = metadata_file.exists();
+        let has_metadata = self.fs.exists(&metadata_file);
         let mut metadata_valid = false;
 
         if has_metadata {
-            if let Ok(content) = fs::read_to_string(&metadata_file) {
+            if let Ok(content) = self.fs.read_to_string(&metadata_file) {
                 if serde_json::from_str::<SavedPlaylistMetadata>(&content).is_ok() {
                     metadata_valid = true;
                 } else {
@@ -456,12 +735,10 @@ impl PlaylistManager {
             issues.push("Missing playlist.json metadata file".to_string());
         }
 
-        let audio_file_count = WalkDir::new(&playlist_path)
-            .min_depth(1)
-            .max_depth(1)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-            .filter(|e| e.path().is_file() && is_audio_file(e.path()))
+        let audio_file_count = self
+            .folder_files(&playlist_path)
+            .iter()
+            .filter(|path| is_audio_file(path))
             .count();
 
         if audio_file_count == 0 {
@@ -485,15 +762,10 @@ impl PlaylistManager {
     ///
     /// Returns an error if the playlist doesn't exist or metadata cannot be created.
     pub fn ensure_folder_structure(&self, name: &str) -> Result<()> {
-        let playlist_path = self.base_path.join(name);
-        if !playlist_path.exists() {
-            return Err(Error::Playlist(crate::error::PlaylistError::NotFound {
-                name: name.to_string(),
-            }));
-        }
+        let playlist_path = self.get_playlist_path(name)?;
 
         let metadata_file = playlist_path.join("playlist.json");
-        if !metadata_file.exists() {
+        if !self.fs.exists(&metadata_file) {
             let now = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .map_or(0, |d| d.as_secs());
@@ -510,15 +782,12 @@ impl PlaylistManager {
                 track_count,
                 total_size_bytes,
                 tracks: Vec::new(),
+                tags: Vec::new(),
+                download_profile: None,
             };
 
             let content = serde_json::to_string_pretty(&metadata)?;
-            fs::write(&metadata_file, content).map_err(|e| {
-                Error::FileSystem(FileSystemError::WriteFailed {
-                    path: metadata_file,
-                    reason: e.to_string(),
-                })
-            })?;
+            self.fs.write(&metadata_file, &content)?;
         }
 
         Ok(())
@@ -530,19 +799,14 @@ impl PlaylistManager {
     ///
     /// Returns an error if the playlist doesn't exist.
     pub fn repair_folder(&self, name: &str) -> Result<Vec<String>> {
-        let playlist_path = self.base_path.join(name);
-        if !playlist_path.exists() {
-            return Err(Error::Playlist(crate::error::PlaylistError::NotFound {
-                name: name.to_string(),
-            }));
-        }
+        let playlist_path = self.get_playlist_path(name)?;
 
         let mut repairs = Vec::new();
 
         // Check and fix metadata
         let metadata_file = playlist_path.join("playlist.json");
-        let needs_new_metadata = if metadata_file.exists() {
-            match fs::read_to_string(&metadata_file) {
+        let needs_new_metadata = if self.fs.exists(&metadata_file) {
+            match self.fs.read_to_string(&metadata_file) {
                 Ok(content) => serde_json::from_str::<SavedPlaylistMetadata>(&content).is_err(),
                 Err(_) => true,
             }
@@ -567,15 +831,12 @@ impl PlaylistManager {
                 track_count,
                 total_size_bytes,
                 tracks: Vec::new(),
+                tags: Vec::new(),
+                download_profile: None,
             };
 
             let content = serde_json::to_string_pretty(&metadata)?;
-            fs::write(&metadata_file, content).map_err(|e| {
-                Error::FileSystem(FileSystemError::WriteFailed {
-                    path: metadata_file,
-                    reason: e.to_string(),
-                })
-            })?;
+            self.fs.write(&metadata_file, &content)?;
 
             repairs.push("Created or fixed metadata file".to_string());
         }
@@ -591,7 +852,7 @@ impl PlaylistManager {
     ///
     /// Returns an error if the folder doesn't exist or metadata cannot be created.
     pub fn import_folder(&self, folder_path: &Path, source_url: Option<String>) -> Result<String> {
-        if !folder_path.exists() {
+        if !self.fs.exists(folder_path) {
             return Err(Error::FileSystem(FileSystemError::ReadFailed {
                 path: folder_path.to_path_buf(),
                 reason: "Folder does not exist".to_string(),
@@ -625,16 +886,13 @@ impl PlaylistManager {
             track_count,
             total_size_bytes,
             tracks: Vec::new(),
+            tags: Vec::new(),
+            download_profile: None,
         };
 
         let metadata_file = folder_path.join("playlist.json");
         let content = serde_json::to_string_pretty(&metadata)?;
-        fs::write(&metadata_file, content).map_err(|e| {
-            Error::FileSystem(FileSystemError::WriteFailed {
-                path: metadata_file,
-                reason: e.to_string(),
-            })
-        })?;
+        self.fs.write(&metadata_file, &content)?;
 
         info!("Imported folder '{}' as playlist", name);
         Ok(name)
@@ -658,30 +916,24 @@ impl PlaylistManager {
         let playlist_path = self.get_playlist_path(name)?;
         let mut tracks = Vec::new();
 
-        for entry in WalkDir::new(&playlist_path)
-            .min_depth(1)
-            .max_depth(1)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-        {
-            let path = entry.path();
-            if path.is_file() && is_audio_file(path) {
+        for path in self.folder_files(&playlist_path) {
+            if is_audio_file(&path) {
                 let file_name = path
                     .file_name()
                     .and_then(|n| n.to_str())
                     .unwrap_or("unknown")
                     .to_string();
-                let size_bytes = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+                let size_bytes = self.file_size(&path);
 
                 let metadata = if include_metadata {
-                    crate::metadata::extract_metadata(path).ok()
+                    crate::metadata::extract_metadata(&path).ok()
                 } else {
                     None
                 };
 
                 tracks.push(TrackInfo {
                     file_name,
-                    path: path.to_path_buf(),
+                    path,
                     size_bytes,
                     metadata,
                 });
@@ -693,27 +945,184 @@ impl PlaylistManager {
         Ok(tracks)
     }
 
+    /// List one page of tracks in a playlist.
+    ///
+    /// Only the tracks in the page have their ID3 metadata extracted, so
+    /// large playlists can be shown without reading every file up front.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the playlist doesn't exist.
+    pub fn list_tracks_paged(&self, name: &str, offset: usize, limit: usize) -> Result<TrackPage> {
+        let tracks = self.list_tracks_with_options(name, false)?;
+        let total = tracks.len();
+        let tracks = tracks
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|mut track| {
+                track.metadata = crate::metadata::extract_metadata(&track.path).ok();
+                track
+            })
+            .collect();
+        let total_duration_secs = self
+            .get_saved_metadata(name)
+            .ok()
+            .map(|saved| {
+                saved
+                    .tracks
+                    .iter()
+                    .filter_map(|track| track.duration_secs)
+                    .sum()
+            })
+            .filter(|&secs: &u64| secs > 0);
+
+        Ok(TrackPage {
+            offset,
+            total,
+            tracks,
+            total_duration_secs,
+        })
+    }
+
+    /// Export a playlist as an extended M3U file at `destination`.
+    ///
+    /// Entries use absolute track paths, so the file works from anywhere on
+    /// this machine, and carry the duration and "artist - title" from the
+    /// ID3 tags when present.
+    ///
+    /// Returns the number of tracks written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the playlist doesn't exist or the file cannot be
+    /// written.
+    pub fn export_m3u(&self, name: &str, destination: &Path) -> Result<usize> {
+        use std::fmt::Write as _;
+
+        let tracks = self.list_tracks_with_options(name, true)?;
+        let mut content = String::from("#EXTM3U\n");
+        for track in &tracks {
+            let metadata = track.metadata.as_ref();
+            let duration = metadata
+                .and_then(|m| m.duration_secs)
+                .and_then(|secs| i64::try_from(secs).ok())
+                .unwrap_or(-1);
+            let title = metadata.and_then(|m| m.title.as_deref());
+            let display = match (metadata.and_then(|m| m.artist.as_deref()), title) {
+                (Some(artist), Some(title)) => format!("{artist} - {title}"),
+                (None, Some(title)) => title.to_string(),
+                _ => track.path.file_stem().map_or_else(
+                    || track.file_name.clone(),
+                    |s| s.to_string_lossy().into_owned(),
+                ),
+            };
+            let _ = writeln!(content, "#EXTINF:{duration},{display}");
+            let _ = writeln!(content, "{}", track.path.display());
+        }
+
+        self.fs.write(destination, &content)?;
+        Ok(tracks.len())
+    }
+
+    /// Search playlists and tracks for `query`, case-insensitively.
+    ///
+    /// Playlists match on their name, title or tags, tracks on their file
+    /// name, tags or the title and channel recorded at download time. Matches
+    /// starting with the query come first. Each group holds at most `limit`
+    /// results; an empty query matches nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the library directory cannot be read.
+    pub fn search_library(&self, query: &str, limit: usize) -> Result<LibrarySearchResults> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Ok(LibrarySearchResults::default());
+        }
+        // 0 for a match at the start, 1 for a match elsewhere
+        let rank = |text: &str| {
+            let text = text.to_lowercase();
+            if text.starts_with(&query) {
+                Some(0)
+            } else {
+                text.contains(&query).then_some(1)
+            }
+        };
+
+        let mut playlists = Vec::new();
+        let mut tracks = Vec::new();
+        for playlist in self.list_playlists()? {
+            let saved = self.get_saved_metadata(&playlist.name).ok();
+            let playlist_rank = [
+                rank(&playlist.name),
+                saved
+                    .as_ref()
+                    .and_then(|s| s.title.as_deref())
+                    .and_then(rank),
+            ]
+            .into_iter()
+            .flatten()
+            .chain(playlist.tags.iter().filter_map(|tag| rank(tag)))
+            .min();
+
+            let saved_tracks = saved.map(|s| s.tracks).unwrap_or_default();
+            for track in self.list_tracks(&playlist.name).unwrap_or_default() {
+                let saved_track = saved_tracks.iter().find(|t| t.file_name == track.file_name);
+                let title = saved_track.and_then(|t| t.title.clone());
+                let channel = saved_track.and_then(|t| t.channel.clone());
+                let track_rank = [
+                    rank(&track.file_name),
+                    title.as_deref().and_then(rank),
+                    channel.as_deref().and_then(rank),
+                ]
+                .into_iter()
+                .flatten()
+                .chain(
+                    saved_track
+                        .into_iter()
+                        .flat_map(|t| &t.tags)
+                        .filter_map(|tag| rank(tag)),
+                )
+                .min();
+                if let Some(track_rank) = track_rank {
+                    tracks.push((
+                        track_rank,
+                        TrackSearchMatch {
+                            playlist: playlist.name.clone(),
+                            file_name: track.file_name,
+                            title,
+                            channel,
+                        },
+                    ));
+                }
+            }
+
+            if let Some(playlist_rank) = playlist_rank {
+                playlists.push((playlist_rank, playlist));
+            }
+        }
+
+        // Stable sorts keep the name order within a rank
+        playlists.sort_by_key(|(rank, _)| *rank);
+        tracks.sort_by_key(|(rank, _)| *rank);
+        Ok(LibrarySearchResults {
+            playlists: playlists.into_iter().take(limit).map(|(_, p)| p).collect(),
+            tracks: tracks.into_iter().take(limit).map(|(_, t)| t).collect(),
+        })
+    }
+
     /// Get the saved metadata for a playlist.
     ///
     /// # Errors
     ///
     /// Returns an error if the playlist doesn't exist or metadata cannot be read.
     pub fn get_saved_metadata(&self, name: &str) -> Result<SavedPlaylistMetadata> {
-        let playlist_path = self.base_path.join(name);
-        if !playlist_path.exists() {
-            return Err(Error::Playlist(crate::error::PlaylistError::NotFound {
-                name: name.to_string(),
-            }));
-        }
+        let playlist_path = self.get_playlist_path(name)?;
 
         let metadata_file = playlist_path.join("playlist.json");
-        if metadata_file.exists() {
-            let content = fs::read_to_string(&metadata_file).map_err(|e| {
-                Error::FileSystem(FileSystemError::ReadFailed {
-                    path: metadata_file.clone(),
-                    reason: e.to_string(),
-                })
-            })?;
+        if self.fs.exists(&metadata_file) {
+            let content = self.fs.read_to_string(&metadata_file)?;
             serde_json::from_str(&content).map_err(Error::Serialization)
         } else {
             // Return default metadata if file doesn't exist
@@ -732,6 +1141,8 @@ impl PlaylistManager {
                 track_count,
                 total_size_bytes,
                 tracks: Vec::new(),
+                tags: Vec::new(),
+                download_profile: None,
             })
         }
     }
@@ -752,12 +1163,7 @@ impl PlaylistManager {
         source_url: Option<Option<String>>,
         thumbnail_url: Option<Option<String>>,
     ) -> Result<SavedPlaylistMetadata> {
-        let playlist_path = self.base_path.join(name);
-        if !playlist_path.exists() {
-            return Err(Error::Playlist(crate::error::PlaylistError::NotFound {
-                name: name.to_string(),
-            }));
-        }
+        let playlist_path = self.get_playlist_path(name)?;
 
         let mut metadata = self.get_saved_metadata(name)?;
 
@@ -783,12 +1189,7 @@ impl PlaylistManager {
         // Save
         let metadata_file = playlist_path.join("playlist.json");
         let content = serde_json::to_string_pretty(&metadata)?;
-        fs::write(&metadata_file, content).map_err(|e| {
-            Error::FileSystem(FileSystemError::WriteFailed {
-                path: metadata_file,
-                reason: e.to_string(),
-            })
-        })?;
+        self.fs.write(&metadata_file, &content)?;
 
         Ok(metadata)
     }
@@ -799,12 +1200,7 @@ impl PlaylistManager {
     ///
     /// Returns an error if the playlist doesn't exist or metadata cannot be updated.
     pub fn refresh_playlist_stats(&self, name: &str) -> Result<SavedPlaylistMetadata> {
-        let playlist_path = self.base_path.join(name);
-        if !playlist_path.exists() {
-            return Err(Error::Playlist(crate::error::PlaylistError::NotFound {
-                name: name.to_string(),
-            }));
-        }
+        let playlist_path = self.get_playlist_path(name)?;
 
         let mut metadata = self.get_saved_metadata(name)?;
 
@@ -819,12 +1215,7 @@ impl PlaylistManager {
         // Save
         let metadata_file = playlist_path.join("playlist.json");
         let content = serde_json::to_string_pretty(&metadata)?;
-        fs::write(&metadata_file, content).map_err(|e| {
-            Error::FileSystem(FileSystemError::WriteFailed {
-                path: metadata_file,
-                reason: e.to_string(),
-            })
-        })?;
+        self.fs.write(&metadata_file, &content)?;
 
         Ok(metadata)
     }
@@ -841,12 +1232,7 @@ impl PlaylistManager {
         name: &str,
         track: SavedTrackMetadata,
     ) -> Result<SavedPlaylistMetadata> {
-        let playlist_path = self.base_path.join(name);
-        if !playlist_path.exists() {
-            return Err(Error::Playlist(crate::error::PlaylistError::NotFound {
-                name: name.to_string(),
-            }));
-        }
+        let playlist_path = self.get_playlist_path(name)?;
 
         let mut metadata = self.get_saved_metadata(name)?;
 
@@ -874,12 +1260,7 @@ impl PlaylistManager {
         // Save
         let metadata_file = playlist_path.join("playlist.json");
         let content = serde_json::to_string_pretty(&metadata)?;
-        fs::write(&metadata_file, content).map_err(|e| {
-            Error::FileSystem(FileSystemError::WriteFailed {
-                path: metadata_file,
-                reason: e.to_string(),
-            })
-        })?;
+        self.fs.write(&metadata_file, &content)?;
 
         Ok(metadata)
     }
@@ -894,12 +1275,7 @@ impl PlaylistManager {
         name: &str,
         tracks: Vec<SavedTrackMetadata>,
     ) -> Result<SavedPlaylistMetadata> {
-        let playlist_path = self.base_path.join(name);
-        if !playlist_path.exists() {
-            return Err(Error::Playlist(crate::error::PlaylistError::NotFound {
-                name: name.to_string(),
-            }));
-        }
+        let playlist_path = self.get_playlist_path(name)?;
 
         let mut metadata = self.get_saved_metadata(name)?;
 
@@ -918,7 +1294,221 @@ impl PlaylistManager {
             }
         }
 
-        // Update counts
+        // Update counts
+        let (track_count, total_size_bytes) = self.count_tracks(&playlist_path);
+        metadata.track_count = track_count;
+        metadata.total_size_bytes = total_size_bytes;
+        metadata.modified_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        // Save
+        let metadata_file = playlist_path.join("playlist.json");
+        let content = serde_json::to_string_pretty(&metadata)?;
+        self.fs.write(&metadata_file, &content)?;
+
+        Ok(metadata)
+    }
+
+    /// Delete several playlists.
+    ///
+    /// Playlists that can't be deleted are reported in the result.
+    #[must_use]
+    pub fn delete_playlists(&self, names: &[String]) -> BatchResult {
+        let mut result = BatchResult::default();
+        for name in names {
+            result.record(name, self.delete_playlist(name));
+        }
+        result
+    }
+
+    /// Add `tag` to several playlists.
+    ///
+    /// Playlists that already have the tag count as tagged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tag is empty.
+    pub fn tag_playlists(&self, names: &[String], tag: &str) -> Result<BatchResult> {
+        let tag = normalize_tag(tag)?;
+        let mut result = BatchResult::default();
+        for name in names {
+            let tagged = self.get_saved_metadata(name).and_then(|mut metadata| {
+                if !metadata.tags.contains(&tag) {
+                    metadata.tags.push(tag.clone());
+                    self.write_saved_metadata(name, &metadata)?;
+                }
+                Ok(())
+            });
+            result.record(name, tagged);
+        }
+        Ok(result)
+    }
+
+    /// Delete several tracks from a playlist, along with their saved metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the playlist doesn't exist or its metadata cannot
+    /// be updated.
+    pub fn delete_tracks(&self, playlist: &str, file_names: &[String]) -> Result<BatchResult> {
+        let playlist_path = self.get_playlist_path(playlist)?;
+        let mut result = BatchResult::default();
+        for file_name in file_names {
+            let deleted = self
+                .track_path(&playlist_path, playlist, file_name)
+                .and_then(|path| self.fs.remove_file(&path));
+            result.record(file_name, deleted);
+        }
+
+        let mut metadata = self.get_saved_metadata(playlist)?;
+        metadata
+            .tracks
+            .retain(|t| !result.succeeded.contains(&t.file_name));
+        self.write_saved_metadata(playlist, &metadata)?;
+        info!(
+            "Deleted {} track(s) from playlist: {}",
+            result.succeeded.len(),
+            playlist
+        );
+        Ok(result)
+    }
+
+    /// Move several tracks from one playlist to another, along with their
+    /// saved metadata.
+    ///
+    /// Tracks whose file name is already taken in the destination are left
+    /// where they are and reported as failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either playlist doesn't exist, they are the same
+    /// playlist, or their metadata cannot be updated.
+    pub fn move_tracks(&self, from: &str, to: &str, file_names: &[String]) -> Result<BatchResult> {
+        let from_path = self.get_playlist_path(from)?;
+        let to_path = self.get_playlist_path(to)?;
+        if from == to {
+            return Err(Error::Playlist(crate::error::PlaylistError::InvalidName {
+                name: to.to_string(),
+                reason: "Tracks are already in this playlist".to_string(),
+            }));
+        }
+
+        let mut result = BatchResult::default();
+        for file_name in file_names {
+            let moved = self
+                .track_path(&from_path, from, file_name)
+                .and_then(|source| {
+                    let destination = to_path.join(file_name);
+                    if self.fs.exists(&destination) {
+                        return Err(Error::FileSystem(FileSystemError::AlreadyExists {
+                            path: destination,
+                        }));
+                    }
+                    self.fs.rename(&source, &destination)
+                });
+            result.record(file_name, moved);
+        }
+
+        let mut from_metadata = self.get_saved_metadata(from)?;
+        let mut to_metadata = self.get_saved_metadata(to)?;
+        let (moved, kept): (Vec<_>, Vec<_>) = from_metadata
+            .tracks
+            .into_iter()
+            .partition(|t| result.succeeded.contains(&t.file_name));
+        from_metadata.tracks = kept;
+        to_metadata.tracks.extend(moved);
+        self.write_saved_metadata(from, &from_metadata)?;
+        self.write_saved_metadata(to, &to_metadata)?;
+        info!(
+            "Moved {} track(s) from '{}' to '{}'",
+            result.succeeded.len(),
+            from,
+            to
+        );
+        Ok(result)
+    }
+
+    /// Add `tag` to several tracks in a playlist.
+    ///
+    /// Tracks without saved metadata get an entry holding just the tag.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tag is empty, the playlist doesn't exist or
+    /// its metadata cannot be updated.
+    pub fn tag_tracks(
+        &self,
+        playlist: &str,
+        file_names: &[String],
+        tag: &str,
+    ) -> Result<BatchResult> {
+        let tag = normalize_tag(tag)?;
+        let playlist_path = self.get_playlist_path(playlist)?;
+        let mut metadata = self.get_saved_metadata(playlist)?;
+        let mut result = BatchResult::default();
+        for file_name in file_names {
+            let tagged = self
+                .track_path(&playlist_path, playlist, file_name)
+                .map(|_| {
+                    let index = metadata
+                        .tracks
+                        .iter()
+                        .position(|t| t.file_name == *file_name)
+                        .unwrap_or_else(|| {
+                            metadata.tracks.push(SavedTrackMetadata {
+                                file_name: file_name.clone(),
+                                ..SavedTrackMetadata::default()
+                            });
+                            metadata.tracks.len() - 1
+                        });
+                    let tags = &mut metadata.tracks[index].tags;
+                    if !tags.contains(&tag) {
+                        tags.push(tag.clone());
+                    }
+                });
+            result.record(file_name, tagged);
+        }
+        self.write_saved_metadata(playlist, &metadata)?;
+        Ok(result)
+    }
+
+    /// Get the download profile of a playlist, if it has one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the playlist doesn't exist or its metadata cannot
+    /// be read.
+    pub fn get_download_profile(&self, name: &str) -> Result<Option<DownloadProfile>> {
+        Ok(self.get_saved_metadata(name)?.download_profile)
+    }
+
+    /// Save the download profile of a playlist, or remove it with `None`.
+    /// An empty profile is removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile is invalid, the playlist doesn't
+    /// exist or its metadata cannot be updated.
+    pub fn set_download_profile(
+        &self,
+        name: &str,
+        profile: Option<DownloadProfile>,
+    ) -> Result<SavedPlaylistMetadata> {
+        if let Some(profile) = &profile {
+            profile.validate()?;
+        }
+        let mut metadata = self.get_saved_metadata(name)?;
+        metadata.download_profile = profile.filter(|profile| !profile.is_empty());
+        self.write_saved_metadata(name, &metadata)?;
+        info!("Saved download profile of playlist '{}'", name);
+        Ok(metadata)
+    }
+
+    /// Save `metadata` to a playlist's playlist.json, refreshing its counts.
+    fn write_saved_metadata(&self, name: &str, metadata: &SavedPlaylistMetadata) -> Result<()> {
+        let playlist_path = self.base_path.join(name);
+        let mut metadata = metadata.clone();
         let (track_count, total_size_bytes) = self.count_tracks(&playlist_path);
         metadata.track_count = track_count;
         metadata.total_size_bytes = total_size_bytes;
@@ -926,17 +1516,46 @@ impl PlaylistManager {
             .duration_since(std::time::UNIX_EPOCH)
             .map_or(0, |d| d.as_secs());
 
-        // Save
         let metadata_file = playlist_path.join("playlist.json");
         let content = serde_json::to_string_pretty(&metadata)?;
-        fs::write(&metadata_file, content).map_err(|e| {
-            Error::FileSystem(FileSystemError::WriteFailed {
-                path: metadata_file,
-                reason: e.to_string(),
-            })
-        })?;
+        self.fs.write(&metadata_file, &content)
+    }
 
-        Ok(metadata)
+    /// Path of the track `file_name` in a playlist folder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `file_name` is not a plain file name or the track
+    /// doesn't exist.
+    fn track_path(&self, playlist_path: &Path, playlist: &str, file_name: &str) -> Result<PathBuf> {
+        // SECURITY: reject separators and `..` so batch operations stay inside
+        // the playlist folder
+        let is_plain = Path::new(file_name)
+            .file_name()
+            .is_some_and(|name| name == file_name);
+        let path = playlist_path.join(file_name);
+        if !is_plain || !self.fs.is_file(&path) || !is_audio_file(&path) {
+            return Err(Error::Playlist(
+                crate::error::PlaylistError::TrackNotFound {
+                    playlist: playlist.to_string(),
+                    track: file_name.to_string(),
+                },
+            ));
+        }
+        Ok(path)
+    }
+
+    /// Files of a playlist to copy to a device, i.e. all but playlist.json
+    /// and the download archive.
+    fn files_to_sync(&self, playlist_path: &Path) -> Vec<PathBuf> {
+        self.folder_files(playlist_path)
+            .into_iter()
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_none_or(|n| n != "playlist.json" && n != DOWNLOAD_ARCHIVE_FILE)
+            })
+            .collect()
     }
 
     /// Sync a playlist to a device with progress reporting.
@@ -960,7 +1579,7 @@ impl PlaylistManager {
 
         let playlist_path = self.get_playlist_path(playlist_name)?;
 
-        if !device_mount_point.exists() {
+        if !self.fs.exists(device_mount_point) {
             return Err(Error::Device(crate::error::DeviceError::NotMounted {
                 mount_point: device_mount_point.to_path_buf(),
             }));
@@ -973,24 +1592,10 @@ impl PlaylistManager {
         );
 
         // Clear device contents first
-        clear_directory(device_mount_point)?;
+        clear_directory(self.fs.as_ref(), device_mount_point)?;
 
         // Collect source files
-        let source_files: Vec<PathBuf> = WalkDir::new(&playlist_path)
-            .min_depth(1)
-            .max_depth(1)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-            .filter(|e| {
-                let path = e.path();
-                path.is_file()
-                    && path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .is_none_or(|n| n != "playlist.json")
-            })
-            .map(|e| e.path().to_path_buf())
-            .collect();
+        let source_files = self.files_to_sync(&playlist_path);
 
         // Use transfer engine
         let mut engine = TransferEngine::new();
@@ -1024,7 +1629,7 @@ impl PlaylistManager {
 
         let playlist_path = self.get_playlist_path(playlist_name)?;
 
-        if !device_mount_point.exists() {
+        if !self.fs.exists(device_mount_point) {
             return Err(Error::Device(crate::error::DeviceError::NotMounted {
                 mount_point: device_mount_point.to_path_buf(),
             }));
@@ -1037,24 +1642,10 @@ impl PlaylistManager {
         );
 
         // Clear device contents first
-        clear_directory(device_mount_point)?;
+        clear_directory(self.fs.as_ref(), device_mount_point)?;
 
         // Collect source files
-        let source_files: Vec<PathBuf> = WalkDir::new(&playlist_path)
-            .min_depth(1)
-            .max_depth(1)
-            .into_iter()
-            .filter_map(std::result::Result::ok)
-            .filter(|e| {
-                let path = e.path();
-                path.is_file()
-                    && path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .is_none_or(|n| n != "playlist.json")
-            })
-            .map(|e| e.path().to_path_buf())
-            .collect();
+        let source_files = self.files_to_sync(&playlist_path);
 
         // Use transfer engine with cancellation
         let mut engine = TransferEngine::with_cancellation(cancel_token);
@@ -1093,6 +1684,9 @@ pub struct SavedTrackMetadata {
     /// Download timestamp (Unix epoch seconds).
     #[serde(default)]
     pub downloaded_at: u64,
+    /// Tags given to the track.
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl SavedTrackMetadata {
@@ -1120,6 +1714,7 @@ impl SavedTrackMetadata {
             duration_secs,
             thumbnail_url,
             downloaded_at: now,
+            tags: Vec::new(),
         }
     }
 }
@@ -1154,6 +1749,103 @@ pub struct SavedPlaylistMetadata {
     /// Metadata for individual tracks (includes YouTube source URLs).
     #[serde(default)]
     pub tracks: Vec<SavedTrackMetadata>,
+    /// Tags given to the playlist.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Download settings overriding the global ones for this playlist.
+    #[serde(default)]
+    pub download_profile: Option<DownloadProfile>,
+}
+
+/// Lowest bitrate a download profile can ask for, in kbps.
+pub const MIN_PROFILE_BITRATE_KBPS: u32 = 32;
+
+/// Highest bitrate a download profile can ask for, in kbps (the MP3 maximum).
+pub const MAX_PROFILE_BITRATE_KBPS: u32 = 320;
+
+/// File format of downloaded tracks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    /// Converted to MP3.
+    #[default]
+    Mp3,
+    /// The downloaded stream as is (usually M4A or WebM).
+    Original,
+}
+
+/// Which tags are written into downloaded tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TagPolicy {
+    /// No tags.
+    None,
+    /// Title, artist, album and track number.
+    Tags,
+    /// Tags and the video thumbnail as cover art.
+    TagsAndCoverArt,
+}
+
+/// Download settings of a playlist, saved in its playlist.json.
+///
+/// Unset fields fall back to the global configuration and the playlist's
+/// setting overrides.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DownloadProfile {
+    /// Audio bitrate in kbps.
+    #[serde(default)]
+    pub bitrate_kbps: Option<u32>,
+    /// File format of the tracks.
+    #[serde(default)]
+    pub output_format: Option<OutputFormat>,
+    /// Tags written into the tracks.
+    #[serde(default)]
+    pub tag_policy: Option<TagPolicy>,
+    /// File names of the tracks (see [`FilenameTemplate`]).
+    #[serde(default)]
+    pub filename_template: Option<String>,
+}
+
+impl DownloadProfile {
+    /// Whether the profile overrides nothing.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bitrate_kbps.is_none()
+            && self.output_format.is_none()
+            && self.tag_policy.is_none()
+            && self.filename_template.is_none()
+    }
+
+    /// Check that the profile's settings are usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bitrate is out of range or the filename
+    /// template is invalid.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(template) = &self.filename_template {
+            FilenameTemplate::parse(template)?;
+        }
+        match self.bitrate_kbps {
+            Some(kbps)
+                if !(MIN_PROFILE_BITRATE_KBPS..=MAX_PROFILE_BITRATE_KBPS).contains(&kbps) =>
+            {
+                Err(Error::Configuration(format!(
+                    "Bitrate must be between {MIN_PROFILE_BITRATE_KBPS} and {MAX_PROFILE_BITRATE_KBPS} kbps, got {kbps}"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Trim a tag, rejecting empty ones.
+fn normalize_tag(tag: &str) -> Result<String> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err(Error::Configuration("Tag cannot be empty".to_string()));
+    }
+    Ok(tag.to_string())
 }
 
 /// Check if a file is an audio file based on extension.
@@ -1215,23 +1907,8 @@ pub fn validate_playlist_name(name: &str) -> Result<()> {
 }
 
 /// Clear all non-hidden contents of a directory.
-fn clear_directory(path: &Path) -> Result<()> {
-    let entries = fs::read_dir(path).map_err(|e| {
-        Error::FileSystem(FileSystemError::ReadFailed {
-            path: path.to_path_buf(),
-            reason: e.to_string(),
-        })
-    })?;
-
-    for entry in entries {
-        let entry = entry.map_err(|e| {
-            Error::FileSystem(FileSystemError::ReadFailed {
-                path: path.to_path_buf(),
-                reason: e.to_string(),
-            })
-        })?;
-
-        let entry_path = entry.path();
+fn clear_directory(fs: &dyn FileSystem, path: &Path) -> Result<()> {
+    for entry_path in fs.read_dir(path)? {
         let file_name = entry_path
             .file_name()
             .and_then(|n| n.to_str())
@@ -1243,20 +1920,10 @@ fn clear_directory(path: &Path) -> Result<()> {
             continue;
         }
 
-        if entry_path.is_dir() {
-            fs::remove_dir_all(&entry_path).map_err(|e| {
-                Error::FileSystem(FileSystemError::DeleteFailed {
-                    path: entry_path.clone(),
-                    reason: e.to_string(),
-                })
-            })?;
+        if fs.is_dir(&entry_path) {
+            fs.remove_dir_all(&entry_path)?;
         } else {
-            fs::remove_file(&entry_path).map_err(|e| {
-                Error::FileSystem(FileSystemError::DeleteFailed {
-                    path: entry_path.clone(),
-                    reason: e.to_string(),
-                })
-            })?;
+            fs.remove_file(&entry_path)?;
         }
     }
 
@@ -1264,14 +1931,8 @@ fn clear_directory(path: &Path) -> Result<()> {
 }
 
 /// Copy contents of one directory to another.
-fn copy_directory_contents(src: &Path, dst: &Path) -> Result<()> {
-    for entry in WalkDir::new(src)
-        .min_depth(1)
-        .max_depth(1)
-        .into_iter()
-        .filter_map(std::result::Result::ok)
-    {
-        let src_path = entry.path();
+fn copy_directory_contents(fs: &dyn FileSystem, src: &Path, dst: &Path) -> Result<()> {
+    for src_path in fs.read_dir(src)? {
         let file_name = src_path
             .file_name()
             .and_then(|n| n.to_str())
@@ -1284,22 +1945,63 @@ fn copy_directory_contents(src: &Path, dst: &Path) -> Result<()> {
 
         let dst_path = dst.join(file_name);
 
-        if src_path.is_file() {
-            fs::copy(src_path, &dst_path).map_err(|e| {
-                Error::FileSystem(FileSystemError::CopyFailed {
-                    source_path: src_path.to_path_buf(),
-                    destination: dst_path.clone(),
-                    reason: e.to_string(),
-                })
-            })?;
-        } else if src_path.is_dir() {
-            fs::create_dir_all(&dst_path).map_err(|e| {
-                Error::FileSystem(FileSystemError::CreateDirFailed {
-                    path: dst_path.clone(),
-                    reason: e.to_string(),
-                })
-            })?;
-            copy_directory_contents(src_path, &dst_path)?;
+        if fs.is_file(&src_path) {
+            fs.copy(&src_path, &dst_path)?;
+        } else if fs.is_dir(&src_path) {
+            fs.create_dir_all(&dst_path)?;
+            copy_directory_contents(fs, &src_path, &dst_path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Current time in seconds since the Unix epoch.
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Move a file or directory, copying it when it can't be renamed (e.g. to
+/// another file system).
+fn move_path(fs: &dyn FileSystem, src: &Path, dst: &Path) -> Result<()> {
+    if fs.rename(src, dst).is_ok() {
+        return Ok(());
+    }
+
+    let copied = if fs.is_dir(src) {
+        copy_directory(fs, src, dst)
+    } else {
+        fs.copy(src, dst).map(|_| ())
+    };
+    if let Err(e) = copied {
+        let _ = if fs.is_dir(dst) {
+            fs.remove_dir_all(dst)
+        } else {
+            fs.remove_file(dst)
+        };
+        return Err(e);
+    }
+
+    if fs.is_dir(src) {
+        fs.remove_dir_all(src)
+    } else {
+        fs.remove_file(src)
+    }
+}
+
+/// Copy a directory and everything in it, metadata included, to `dst`.
+fn copy_directory(fs: &dyn FileSystem, src: &Path, dst: &Path) -> Result<()> {
+    fs.create_dir_all(dst)?;
+    for path in fs.walk_dir(src, None)? {
+        let relative = path.strip_prefix(src).unwrap_or(&path);
+        let dst_path = dst.join(relative);
+
+        if fs.is_dir(&path) {
+            fs.create_dir_all(&dst_path)?;
+        } else {
+            fs.copy(&path, &dst_path)?;
         }
     }
 
@@ -1310,6 +2012,8 @@ fn copy_directory_contents(src: &Path, dst: &Path) -> Result<()> {
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
     use super::*;
+    use crate::fs::MemoryFileSystem;
+    use std::fs;
     use tempfile::TempDir;
 
     fn setup_test_manager() -> (PlaylistManager, TempDir) {
@@ -1393,6 +2097,256 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_archive_playlist() {
+        let (manager, _temp) = setup_test_manager();
+        let archive = TempDir::new().expect("Failed to create temp dir");
+
+        for _ in 0..2 {
+            let path = manager
+                .create_playlist("Old Mix", None)
+                .expect("Creation should succeed");
+            fs::write(path.join("song.mp3"), b"audio").unwrap();
+            manager
+                .archive_playlist("Old Mix", archive.path())
+                .expect("Archiving should succeed");
+            assert!(!path.exists());
+        }
+
+        // The second archive doesn't overwrite the first
+        let first = archive.path().join("Old Mix");
+        let second = archive.path().join("Old Mix (2)");
+        assert!(first.join("playlist.json").exists());
+        assert!(second.join("song.mp3").exists());
+
+        let result = manager.archive_playlist("Missing", archive.path());
+        assert!(matches!(
+            result,
+            Err(Error::Playlist(
+                crate::error::PlaylistError::NotFound { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_trash_and_restore_playlist() {
+        let (manager, _temp) = setup_test_manager();
+        let trash_dir = TempDir::new().unwrap();
+        let trash = Trash::open(trash_dir.path().join("trash"));
+        let path = manager
+            .create_playlist("Mix", None)
+            .expect("Creation should succeed");
+        fs::write(path.join("song.mp3"), b"audio").unwrap();
+
+        let entry = manager
+            .trash_playlist("Mix", &trash)
+            .expect("Trashing should succeed");
+        assert!(!path.exists());
+        assert_eq!(trash.entries().unwrap(), vec![entry.clone()]);
+
+        let item = manager
+            .restore_from_trash(entry.id, &trash)
+            .expect("Restoring should succeed");
+        assert_eq!(
+            item,
+            TrashedItem::Playlist {
+                name: "Mix".to_string()
+            }
+        );
+        assert!(path.join("song.mp3").exists());
+        assert!(trash.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_restore_playlist_name_taken() {
+        let (manager, _temp) = setup_test_manager();
+        let trash_dir = TempDir::new().unwrap();
+        let trash = Trash::open(trash_dir.path().join("trash"));
+        manager.create_playlist("Mix", None).unwrap();
+        let entry = manager.trash_playlist("Mix", &trash).unwrap();
+        manager.create_playlist("Mix", None).unwrap();
+
+        assert!(manager.restore_from_trash(entry.id, &trash).is_err());
+        // Still in the trash, so it can be restored once the name is free
+        assert_eq!(trash.entries().unwrap().len(), 1);
+        manager.delete_playlist("Mix").unwrap();
+        assert!(manager.restore_from_trash(entry.id, &trash).is_ok());
+    }
+
+    #[test]
+    fn test_trash_and_restore_tracks() {
+        let (manager, _temp) = setup_test_manager();
+        let trash_dir = TempDir::new().unwrap();
+        let trash = Trash::open(trash_dir.path().join("trash"));
+        let path = manager.create_playlist("Mix", None).unwrap();
+        fs::write(path.join("a.mp3"), b"audio").unwrap();
+        fs::write(path.join("b.mp3"), b"audio").unwrap();
+        manager
+            .add_track_metadata(
+                "Mix",
+                SavedTrackMetadata {
+                    file_name: "a.mp3".to_string(),
+                    video_id: Some("abc".to_string()),
+                    ..SavedTrackMetadata::default()
+                },
+            )
+            .unwrap();
+
+        let trashed = manager
+            .trash_tracks(
+                "Mix",
+                &["a.mp3".to_string(), "missing.mp3".to_string()],
+                &trash,
+            )
+            .unwrap();
+        assert_eq!(trashed.result.succeeded, vec!["a.mp3"]);
+        assert_eq!(trashed.result.failed.len(), 1);
+        assert!(!path.join("a.mp3").exists());
+        assert!(manager.get_saved_metadata("Mix").unwrap().tracks.is_empty());
+
+        let entry = trashed.entry.expect("A track was trashed");
+        manager.restore_from_trash(entry.id, &trash).unwrap();
+        assert!(path.join("a.mp3").exists());
+        let tracks = manager.get_saved_metadata("Mix").unwrap().tracks;
+        assert_eq!(tracks[0].video_id.as_deref(), Some("abc"));
+
+        // Nothing trashed, no entry
+        let trashed = manager
+            .trash_tracks("Mix", &["missing.mp3".to_string()], &trash)
+            .unwrap();
+        assert!(trashed.entry.is_none());
+        assert!(trash.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_trash_removed_videos() {
+        use crate::youtube::archive::ArchivedVideo;
+
+        let (manager, _temp) = setup_test_manager();
+        let trash_dir = TempDir::new().unwrap();
+        let trash = Trash::open(trash_dir.path().join("trash"));
+        let path = manager.create_playlist("Mix", None).unwrap();
+        fs::write(path.join("a.mp3"), b"audio").unwrap();
+        fs::write(path.join("b.mp3"), b"audio").unwrap();
+        fs::write(
+            path.join(DOWNLOAD_ARCHIVE_FILE),
+            r#"{
+                "a": {"video_id": "a", "title": "a", "file_name": "a.mp3", "archived_at": 0},
+                "b": {"video_id": "b", "title": "b", "file_name": "b.mp3", "archived_at": 0},
+                "gone": {"video_id": "gone", "title": "gone", "file_name": "gone.mp3", "archived_at": 0}
+            }"#,
+        )
+        .unwrap();
+        let removed = |id: &str| ArchivedVideo {
+            video_id: id.to_string(),
+            title: id.to_string(),
+            file_name: Some(format!("{id}.mp3")),
+            archived_at: 0,
+            position: None,
+        };
+        let diff = PlaylistDiff {
+            removed_videos: vec![removed("a"), removed("gone")],
+            ..PlaylistDiff::default()
+        };
+
+        let trashed = manager.trash_removed_videos("Mix", &diff, &trash).unwrap();
+        assert_eq!(trashed.result.succeeded, vec!["a.mp3"]);
+        assert!(trashed.entry.is_some());
+        assert!(!path.join("a.mp3").exists());
+        assert_eq!(manager.get_saved_metadata("Mix").unwrap().track_count, 1);
+
+        // The missing file's video is forgotten too
+        let archive = DownloadArchive::open(&path);
+        assert!(!archive.contains("a"));
+        assert!(!archive.contains("gone"));
+        assert!(archive.contains("b"));
+    }
+
+    fn setup_memory_manager() -> (PlaylistManager, MemoryFileSystem) {
+        let fs = MemoryFileSystem::new();
+        let manager =
+            PlaylistManager::with_file_system(PathBuf::from("/library"), Arc::new(fs.clone()))
+                .expect("Failed to create manager");
+        (manager, fs)
+    }
+
+    #[test]
+    fn test_sync_to_device_in_memory() {
+        let (manager, fs) = setup_memory_manager();
+        manager.create_playlist("Mix", None).unwrap();
+        fs.add_file("/library/Mix/a.mp3", "a");
+        fs.add_file("/device/old/b.mp3", "old");
+        fs.add_file("/device/.hidden", "keep");
+
+        manager.sync_to_device("Mix", Path::new("/device")).unwrap();
+        assert_eq!(
+            fs.list_all_files(),
+            vec![
+                PathBuf::from("/device/.hidden"),
+                PathBuf::from("/device/a.mp3"),
+                PathBuf::from("/library/Mix/a.mp3"),
+                PathBuf::from("/library/Mix/playlist.json"),
+            ]
+        );
+        assert!(!fs.exists(Path::new("/device/old")));
+    }
+
+    #[test]
+    fn test_move_tracks_name_taken_in_memory() {
+        let (manager, fs) = setup_memory_manager();
+        manager.create_playlist("Mix", None).unwrap();
+        manager.create_playlist("Other", None).unwrap();
+        fs.add_file("/library/Mix/a.mp3", "mine");
+        fs.add_file("/library/Mix/b.mp3", "b");
+        fs.add_file("/library/Other/a.mp3", "theirs");
+
+        let result = manager
+            .move_tracks("Mix", "Other", &["a.mp3".to_string(), "b.mp3".to_string()])
+            .unwrap();
+        assert_eq!(result.succeeded, vec!["b.mp3"]);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(
+            fs.read_to_string(Path::new("/library/Other/a.mp3"))
+                .unwrap(),
+            "theirs"
+        );
+        assert!(fs.is_file(Path::new("/library/Other/b.mp3")));
+        assert_eq!(manager.get_saved_metadata("Other").unwrap().track_count, 2);
+    }
+
+    #[test]
+    fn test_trash_and_restore_in_memory() {
+        let (manager, fs) = setup_memory_manager();
+        let trash = Trash::with_file_system("/trash", Arc::new(fs.clone()));
+        manager.create_playlist("Mix", None).unwrap();
+        fs.add_file("/library/Mix/a.mp3", "a");
+
+        let entry = manager.trash_playlist("Mix", &trash).unwrap();
+        assert!(fs.is_file(Path::new("/trash/1/Mix/a.mp3")));
+        assert!(manager.list_playlists().unwrap().is_empty());
+
+        manager.restore_from_trash(entry.id, &trash).unwrap();
+        assert!(fs.is_file(Path::new("/library/Mix/a.mp3")));
+        assert!(!fs.exists(Path::new("/trash/1")));
+    }
+
+    #[test]
+    fn test_copy_directory_keeps_metadata() {
+        let temp = TempDir::new().expect("Failed to create temp dir");
+        let src = temp.path().join("src");
+        fs::create_dir_all(src.join("nested")).unwrap();
+        fs::write(src.join("playlist.json"), b"{}").unwrap();
+        fs::write(src.join("nested").join("a.mp3"), b"audio").unwrap();
+
+        let dst = temp.path().join("dst");
+        copy_directory(&RealFileSystem, &src, &dst).expect("Copy should succeed");
+        assert!(dst.join("playlist.json").exists());
+        assert_eq!(
+            fs::read(dst.join("nested").join("a.mp3")).unwrap(),
+            b"audio"
+        );
+    }
+
     #[test]
     fn test_list_playlists() {
         let (manager, _temp) = setup_test_manager();
@@ -1479,6 +2433,7 @@ mod tests {
             .expect("Should create");
         fs::write(playlist_path.join("track1.mp3"), "mp3 data 1").expect("Write should succeed");
         fs::write(playlist_path.join("track2.mp3"), "mp3 data 2").expect("Write should succeed");
+        fs::write(playlist_path.join(DOWNLOAD_ARCHIVE_FILE), "{}").expect("Write should succeed");
 
         // Add some existing content to device
         fs::write(device_dir.path().join("old_file.txt"), "old content")
@@ -1495,8 +2450,9 @@ mod tests {
         assert!(device_dir.path().join("track1.mp3").exists());
         assert!(device_dir.path().join("track2.mp3").exists());
 
-        // Verify playlist.json is NOT copied
+        // Verify playlist.json and the download archive are NOT copied
         assert!(!device_dir.path().join("playlist.json").exists());
+        assert!(!device_dir.path().join(DOWNLOAD_ARCHIVE_FILE).exists());
     }
 
     // =========================================================================
@@ -1742,6 +2698,82 @@ mod tests {
         assert!(tracks.is_empty());
     }
 
+    #[test]
+    fn test_list_tracks_paged() {
+        let (manager, _temp) = setup_test_manager();
+        let playlist_path = manager
+            .create_playlist("PagedTest", None)
+            .expect("Should create");
+        for i in 0..5 {
+            fs::write(playlist_path.join(format!("song{i}.mp3")), "fake mp3 data")
+                .expect("Write should succeed");
+        }
+
+        let page = manager
+            .list_tracks_paged("PagedTest", 1, 2)
+            .expect("Should list page");
+        assert_eq!(page.offset, 1);
+        assert_eq!(page.total, 5);
+        let names: Vec<_> = page.tracks.iter().map(|t| t.file_name.as_str()).collect();
+        assert_eq!(names, ["song1.mp3", "song2.mp3"]);
+        assert_eq!(page.total_duration_secs, None);
+
+        let last = manager
+            .list_tracks_paged("PagedTest", 4, 10)
+            .expect("Should list page");
+        assert_eq!(last.tracks.len(), 1);
+
+        let past_end = manager
+            .list_tracks_paged("PagedTest", 10, 10)
+            .expect("Should list page");
+        assert!(past_end.tracks.is_empty());
+        assert_eq!(past_end.total, 5);
+
+        assert!(manager.list_tracks_paged("NonExistent", 0, 10).is_err());
+    }
+
+    #[test]
+    fn test_contains_path() {
+        let (manager, _temp) = setup_test_manager();
+        let outside = TempDir::new().expect("Failed to create temp dir");
+        let playlist_path = manager
+            .create_playlist("Inside", None)
+            .expect("Should create");
+        let track = playlist_path.join("song.mp3");
+        fs::write(&track, "fake mp3 data").expect("Write should succeed");
+
+        assert!(manager.contains_path(&playlist_path));
+        assert!(manager.contains_path(&track));
+        assert!(!manager.contains_path(&playlist_path.join("missing.mp3")));
+        assert!(!manager.contains_path(outside.path()));
+        assert!(!manager.contains_path(&playlist_path.join("..").join("..")));
+    }
+
+    #[test]
+    fn test_export_m3u() {
+        let (manager, temp) = setup_test_manager();
+        let playlist_path = manager
+            .create_playlist("ExportTest", None)
+            .expect("Should create");
+        fs::write(playlist_path.join("b.mp3"), "fake mp3 data").expect("Write should succeed");
+        fs::write(playlist_path.join("a.mp3"), "fake mp3 data").expect("Write should succeed");
+
+        let destination = temp.path().join("ExportTest.m3u");
+        let count = manager
+            .export_m3u("ExportTest", &destination)
+            .expect("Should export");
+        assert_eq!(count, 2);
+
+        let content = fs::read_to_string(&destination).expect("Should read export");
+        let lines: Vec<_> = content.lines().collect();
+        assert_eq!(lines[0], "#EXTM3U");
+        assert_eq!(lines[1], "#EXTINF:-1,a");
+        assert_eq!(lines[2], playlist_path.join("a.mp3").display().to_string());
+        assert_eq!(lines[3], "#EXTINF:-1,b");
+
+        assert!(manager.export_m3u("NonExistent", &destination).is_err());
+    }
+
     #[test]
     fn test_list_tracks_nonexistent() {
         let (manager, _temp) = setup_test_manager();
@@ -1785,6 +2817,7 @@ mod tests {
             modified_at: 2000,
             track_count: 5,
             total_bytes: 1024,
+            tags: Vec::new(),
         };
         let meta2 = meta1.clone();
         assert_eq!(meta1, meta2);
@@ -1890,4 +2923,210 @@ mod tests {
             .expect("Should create");
         assert!(path.is_dir());
     }
+
+    #[test]
+    fn test_search_library() {
+        let (manager, temp) = setup_test_manager();
+        manager.create_playlist("Road Trip", None).unwrap();
+        manager.create_playlist("Chill", None).unwrap();
+        fs::write(temp.path().join("Road Trip").join("sunset drive.mp3"), b"x").unwrap();
+        fs::write(temp.path().join("Chill").join("track01.mp3"), b"x").unwrap();
+        manager
+            .add_track_metadata(
+                "Chill",
+                SavedTrackMetadata {
+                    file_name: "track01.mp3".to_string(),
+                    title: Some("Ocean Waves".to_string()),
+                    channel: Some("Road Sounds".to_string()),
+                    ..SavedTrackMetadata::default()
+                },
+            )
+            .unwrap();
+
+        let results = manager.search_library("road", 10).unwrap();
+        assert_eq!(results.playlists.len(), 1);
+        assert_eq!(results.playlists[0].name, "Road Trip");
+        // Matches on the channel as well as the file name
+        assert_eq!(results.tracks.len(), 1);
+        assert_eq!(results.tracks[0].file_name, "track01.mp3");
+
+        let results = manager.search_library("DRIVE", 10).unwrap();
+        assert!(results.playlists.is_empty());
+        assert_eq!(results.tracks[0].playlist, "Road Trip");
+
+        let results = manager.search_library("waves", 10).unwrap();
+        assert_eq!(results.tracks[0].title.as_deref(), Some("Ocean Waves"));
+
+        assert_eq!(
+            manager.search_library("  ", 10).unwrap(),
+            LibrarySearchResults::default()
+        );
+    }
+
+    #[test]
+    fn test_search_library_ranks_prefix_matches_first() {
+        let (manager, _temp) = setup_test_manager();
+        manager.create_playlist("Best of Jazz", None).unwrap();
+        manager.create_playlist("Jazz Classics", None).unwrap();
+        manager.create_playlist("Modern Jazz", None).unwrap();
+
+        let results = manager.search_library("jazz", 2).unwrap();
+        let names: Vec<_> = results.playlists.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["Jazz Classics", "Best of Jazz"]);
+    }
+
+    #[test]
+    fn test_delete_playlists() {
+        let (manager, _temp) = setup_test_manager();
+        manager.create_playlist("One", None).unwrap();
+        manager.create_playlist("Two", None).unwrap();
+
+        let names = ["One", "Missing", "Two"].map(String::from);
+        let result = manager.delete_playlists(&names);
+        assert_eq!(result.succeeded, ["One", "Two"]);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].item, "Missing");
+        assert!(manager.list_playlists().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_delete_tracks() {
+        let (manager, temp) = setup_test_manager();
+        manager.create_playlist("Mix", None).unwrap();
+        let dir = temp.path().join("Mix");
+        fs::write(dir.join("a.mp3"), b"aa").unwrap();
+        fs::write(dir.join("b.mp3"), b"bb").unwrap();
+        manager
+            .add_track_metadata(
+                "Mix",
+                SavedTrackMetadata {
+                    file_name: "a.mp3".to_string(),
+                    ..SavedTrackMetadata::default()
+                },
+            )
+            .unwrap();
+
+        let names = ["a.mp3", "../Mix/b.mp3", "playlist.json"].map(String::from);
+        let result = manager.delete_tracks("Mix", &names).unwrap();
+        assert_eq!(result.succeeded, ["a.mp3"]);
+        // Paths and non-audio files are refused
+        assert_eq!(result.failed.len(), 2);
+        assert!(dir.join("b.mp3").exists());
+        assert!(dir.join("playlist.json").exists());
+
+        let saved = manager.get_saved_metadata("Mix").unwrap();
+        assert!(saved.tracks.is_empty());
+        assert_eq!(saved.track_count, 1);
+    }
+
+    #[test]
+    fn test_move_tracks() {
+        let (manager, temp) = setup_test_manager();
+        manager.create_playlist("From", None).unwrap();
+        manager.create_playlist("To", None).unwrap();
+        fs::write(temp.path().join("From").join("a.mp3"), b"a").unwrap();
+        fs::write(temp.path().join("From").join("b.mp3"), b"b").unwrap();
+        fs::write(temp.path().join("To").join("b.mp3"), b"taken").unwrap();
+        manager
+            .add_track_metadata(
+                "From",
+                SavedTrackMetadata {
+                    file_name: "a.mp3".to_string(),
+                    title: Some("Song A".to_string()),
+                    ..SavedTrackMetadata::default()
+                },
+            )
+            .unwrap();
+
+        let names = ["a.mp3", "b.mp3"].map(String::from);
+        let result = manager.move_tracks("From", "To", &names).unwrap();
+        assert_eq!(result.succeeded, ["a.mp3"]);
+        // The destination already has a b.mp3
+        assert_eq!(result.failed[0].item, "b.mp3");
+        assert!(temp.path().join("From").join("b.mp3").exists());
+
+        let to = manager.get_saved_metadata("To").unwrap();
+        assert_eq!(to.tracks[0].title.as_deref(), Some("Song A"));
+        assert_eq!(to.track_count, 2);
+        assert!(
+            manager
+                .get_saved_metadata("From")
+                .unwrap()
+                .tracks
+                .is_empty()
+        );
+
+        assert!(manager.move_tracks("To", "To", &names).is_err());
+    }
+
+    #[test]
+    fn test_tag_playlists_and_tracks() {
+        let (manager, temp) = setup_test_manager();
+        manager.create_playlist("Gym", None).unwrap();
+        fs::write(temp.path().join("Gym").join("run.mp3"), b"x").unwrap();
+
+        let playlists = ["Gym".to_string()];
+        manager.tag_playlists(&playlists, " workout ").unwrap();
+        // Tagging twice doesn't duplicate the tag
+        manager.tag_playlists(&playlists, "workout").unwrap();
+        assert_eq!(manager.get_saved_metadata("Gym").unwrap().tags, ["workout"]);
+        assert_eq!(manager.list_playlists().unwrap()[0].tags, ["workout"]);
+
+        let tracks = ["run.mp3".to_string(), "missing.mp3".to_string()];
+        let result = manager.tag_tracks("Gym", &tracks, "fast").unwrap();
+        assert_eq!(result.succeeded, ["run.mp3"]);
+        assert_eq!(result.failed[0].item, "missing.mp3");
+        let saved = manager.get_saved_metadata("Gym").unwrap();
+        assert_eq!(saved.tracks[0].tags, ["fast"]);
+
+        // Tags are searchable
+        let results = manager.search_library("fast", 10).unwrap();
+        assert_eq!(results.tracks[0].file_name, "run.mp3");
+        assert_eq!(
+            manager.search_library("work", 10).unwrap().playlists.len(),
+            1
+        );
+
+        assert!(manager.tag_playlists(&playlists, "  ").is_err());
+    }
+
+    #[test]
+    fn test_download_profile_round_trip() {
+        let (manager, _temp) = setup_test_manager();
+        manager.create_playlist("Mix", None).unwrap();
+        assert_eq!(manager.get_download_profile("Mix").unwrap(), None);
+
+        let profile = DownloadProfile {
+            bitrate_kbps: Some(256),
+            output_format: Some(OutputFormat::Original),
+            tag_policy: Some(TagPolicy::Tags),
+            filename_template: Some("{index:02} - {title}".to_string()),
+        };
+        manager
+            .set_download_profile("Mix", Some(profile.clone()))
+            .unwrap();
+        assert_eq!(manager.get_download_profile("Mix").unwrap(), Some(profile));
+
+        let too_high = DownloadProfile {
+            bitrate_kbps: Some(512),
+            ..DownloadProfile::default()
+        };
+        assert!(manager.set_download_profile("Mix", Some(too_high)).is_err());
+        let bad_template = DownloadProfile {
+            filename_template: Some("{album}".to_string()),
+            ..DownloadProfile::default()
+        };
+        assert!(
+            manager
+                .set_download_profile("Mix", Some(bad_template))
+                .is_err()
+        );
+
+        // Empty profiles are removed
+        manager
+            .set_download_profile("Mix", Some(DownloadProfile::default()))
+            .unwrap();
+        assert_eq!(manager.get_download_profile("Mix").unwrap(), None);
+        assert!(manager.get_download_profile("Missing").is_err());
+    }
 }
@@ -0,0 +1,329 @@
+//! Battery and metered network awareness.
+//!
+//! Heavy background work (the download queue, scheduled refreshes and
+//! integrity scans) can be paused while the machine runs on battery power or
+//! is on a metered connection. Detection is best effort: battery power is
+//! read from `/sys/class/power_supply` on Linux and `pmset` on macOS, and
+//! whether the active (default route) connection is metered from
+//! `NetworkManager` on Linux. Anything that can't be detected reads as mains
+//! power on an unmetered network, so unsupported platforms behave as if power
+//! awareness were off. Both toggles are off by default.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Power and network conditions relevant to heavy background work.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PowerState {
+    /// Whether the machine is running on battery power.
+    pub on_battery: bool,
+    /// Whether the active network connection is metered.
+    pub metered_network: bool,
+    /// Whether `NetworkManager` only guessed that the active connection is
+    /// metered (for example from a phone hotspot).
+    #[serde(default)]
+    pub metered_guessed: bool,
+}
+
+impl PowerState {
+    /// Detect the current power state.
+    ///
+    /// This may run external commands, so call it off the async runtime.
+    #[must_use]
+    pub fn detect() -> Self {
+        let metered = detect_metered_network();
+        Self {
+            on_battery: detect_on_battery(),
+            metered_network: metered == Metered::Yes,
+            metered_guessed: metered == Metered::Guessed,
+        }
+    }
+}
+
+/// Why heavy background work is paused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseReason {
+    /// The machine is running on battery power.
+    Battery,
+    /// The network connection is metered.
+    MeteredNetwork,
+}
+
+impl fmt::Display for PauseReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Battery => "running on battery power",
+            Self::MeteredNetwork => "on a metered network",
+        })
+    }
+}
+
+/// Which power conditions pause heavy background work.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PowerPreferences {
+    /// Pause heavy work while running on battery power.
+    #[serde(default)]
+    pub pause_on_battery: bool,
+    /// Pause heavy work while on a metered network.
+    #[serde(default)]
+    pub pause_on_metered: bool,
+    /// Also treat connections `NetworkManager` guessed to be metered as
+    /// metered.
+    #[serde(default)]
+    pub pause_on_guessed_metered: bool,
+}
+
+impl PowerPreferences {
+    /// Why heavy work should be paused in `state`, or `None` to let it run.
+    #[must_use]
+    pub const fn pause_reason(&self, state: &PowerState) -> Option<PauseReason> {
+        if self.pause_on_battery && state.on_battery {
+            Some(PauseReason::Battery)
+        } else if self.pause_on_metered
+            && (state.metered_network || (self.pause_on_guessed_metered && state.metered_guessed))
+        {
+            Some(PauseReason::MeteredNetwork)
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether a network connection is metered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Metered {
+    /// Not metered, or unknown.
+    No,
+    /// `NetworkManager` guessed that the connection is metered.
+    Guessed,
+    /// The connection is marked as metered.
+    Yes,
+}
+
+/// A power supply as listed in `/sys/class/power_supply`.
+#[cfg(any(target_os = "linux", test))]
+#[derive(Debug, Default)]
+struct PowerSupply {
+    /// Supply type (`Mains`, `Battery`, `USB`, ...).
+    kind: String,
+    /// Whether an external supply is connected.
+    online: Option<bool>,
+    /// Battery charging status (`Charging`, `Discharging`, ...).
+    status: Option<String>,
+}
+
+/// Whether the supplies show the machine running on its battery.
+///
+/// Machines without a battery, or with any external supply online, are on
+/// mains power.
+#[cfg(any(target_os = "linux", test))]
+fn supplies_on_battery(supplies: &[PowerSupply]) -> bool {
+    let external_online = supplies
+        .iter()
+        .any(|supply| supply.kind != "Battery" && supply.online == Some(true));
+    !external_online
+        && supplies.iter().any(|supply| {
+            supply.kind == "Battery" && supply.status.as_deref() == Some("Discharging")
+        })
+}
+
+/// Whether `pmset -g batt` output shows the machine running on its battery.
+#[cfg(any(target_os = "macos", test))]
+fn pmset_on_battery(output: &str) -> bool {
+    output.contains("'Battery Power'")
+}
+
+/// The device of the connection holding the default route, from
+/// `nmcli -t -f DEVICE,DEFAULT connection show --active` output.
+#[cfg(any(target_os = "linux", test))]
+fn nmcli_default_device(output: &str) -> Option<&str> {
+    output
+        .lines()
+        .filter_map(|line| line.rsplit_once(':'))
+        .find(|(device, default)| *default == "yes" && !device.is_empty())
+        .map(|(device, _)| device)
+}
+
+/// Whether `nmcli -t -f GENERAL.METERED device show <device>` output shows a
+/// metered device.
+#[cfg(any(target_os = "linux", test))]
+fn nmcli_metered(output: &str) -> Metered {
+    let value = output
+        .lines()
+        .find_map(|line| line.strip_prefix("GENERAL.METERED:"))
+        .map(str::trim);
+    match value {
+        Some("yes") => Metered::Yes,
+        Some(value) if value.starts_with("yes") => Metered::Guessed,
+        _ => Metered::No,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_on_battery() -> bool {
+    let Ok(entries) = std::fs::read_dir("/sys/class/power_supply") else {
+        return false;
+    };
+    let read = |path: std::path::PathBuf| {
+        std::fs::read_to_string(path)
+            .ok()
+            .map(|value| value.trim().to_string())
+    };
+    let supplies: Vec<PowerSupply> = entries
+        .flatten()
+        .map(|entry| {
+            let path = entry.path();
+            PowerSupply {
+                kind: read(path.join("type")).unwrap_or_default(),
+                online: read(path.join("online")).map(|online| online == "1"),
+                status: read(path.join("status")),
+            }
+        })
+        .collect();
+    supplies_on_battery(&supplies)
+}
+
+#[cfg(target_os = "macos")]
+fn detect_on_battery() -> bool {
+    std::process::Command::new("pmset")
+        .args(["-g", "batt"])
+        .output()
+        .is_ok_and(|output| pmset_on_battery(&String::from_utf8_lossy(&output.stdout)))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+const fn detect_on_battery() -> bool {
+    false
+}
+
+#[cfg(target_os = "linux")]
+fn detect_metered_network() -> Metered {
+    let nmcli = |args: &[&str]| {
+        std::process::Command::new("nmcli")
+            .args(args)
+            .output()
+            .ok()
+            .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+    let Some(active) = nmcli(&[
+        "-t",
+        "-f",
+        "DEVICE,DEFAULT",
+        "connection",
+        "show",
+        "--active",
+    ]) else {
+        return Metered::No;
+    };
+    let Some(device) = nmcli_default_device(&active) else {
+        return Metered::No;
+    };
+    nmcli(&["-t", "-f", "GENERAL.METERED", "device", "show", device])
+        .map_or(Metered::No, |output| nmcli_metered(&output))
+}
+
+#[cfg(not(target_os = "linux"))]
+const fn detect_metered_network() -> Metered {
+    Metered::No
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn supply(kind: &str, online: Option<bool>, status: Option<&str>) -> PowerSupply {
+        PowerSupply {
+            kind: kind.to_string(),
+            online,
+            status: status.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_supplies_on_battery() {
+        let discharging = || supply("Battery", None, Some("Discharging"));
+        let mains = |online| supply("Mains", Some(online), None);
+
+        assert!(supplies_on_battery(&[mains(false), discharging()]));
+        assert!(!supplies_on_battery(&[mains(true), discharging()]));
+        assert!(!supplies_on_battery(&[supply(
+            "Battery",
+            None,
+            Some("Charging")
+        )]));
+
+        // Desktops without a battery
+        assert!(!supplies_on_battery(&[mains(true)]));
+        assert!(!supplies_on_battery(&[]));
+    }
+
+    #[test]
+    fn test_pmset_and_nmcli_parsing() {
+        assert!(pmset_on_battery(
+            "Now drawing from 'Battery Power'\n -InternalBattery-0 (id=1234)\t85%; discharging"
+        ));
+        assert!(!pmset_on_battery("Now drawing from 'AC Power'"));
+
+        assert_eq!(
+            nmcli_default_device("docker0:no\nwlp2s0:yes\nenp3s0:no\n"),
+            Some("wlp2s0")
+        );
+        assert_eq!(nmcli_default_device(":yes\nlo:no\n"), None);
+
+        assert_eq!(nmcli_metered("GENERAL.METERED:yes\n"), Metered::Yes);
+        assert_eq!(
+            nmcli_metered("GENERAL.METERED:yes (guessed)\n"),
+            Metered::Guessed
+        );
+        assert_eq!(nmcli_metered("GENERAL.METERED:no (guessed)\n"), Metered::No);
+        assert_eq!(nmcli_metered("GENERAL.METERED:unknown\n"), Metered::No);
+        assert_eq!(nmcli_metered(""), Metered::No);
+    }
+
+    #[test]
+    fn test_pause_reason() {
+        let state = PowerState {
+            on_battery: true,
+            metered_network: true,
+            metered_guessed: false,
+        };
+        let prefs = PowerPreferences {
+            pause_on_battery: true,
+            pause_on_metered: true,
+            pause_on_guessed_metered: false,
+        };
+        assert_eq!(prefs.pause_reason(&state), Some(PauseReason::Battery));
+
+        let metered_only = PowerPreferences {
+            pause_on_battery: false,
+            ..prefs
+        };
+        assert_eq!(
+            metered_only.pause_reason(&state),
+            Some(PauseReason::MeteredNetwork)
+        );
+        assert_eq!(metered_only.pause_reason(&PowerState::default()), None);
+
+        // Guessed metered connections only count when opted in
+        let guessed = PowerState {
+            on_battery: false,
+            metered_network: false,
+            metered_guessed: true,
+        };
+        assert_eq!(metered_only.pause_reason(&guessed), None);
+        assert_eq!(
+            PowerPreferences {
+                pause_on_guessed_metered: true,
+                ..metered_only
+            }
+            .pause_reason(&guessed),
+            Some(PauseReason::MeteredNetwork)
+        );
+
+        // Nothing pauses by default
+        assert_eq!(PowerPreferences::default().pause_reason(&state), None);
+    }
+}
@@ -2,18 +2,31 @@
 //!
 //! This module provides a queue system for managing concurrent downloads with:
 //! - Configurable concurrent download limits
-//! - Priority-based ordering
-//! - Queue item lifecycle management (pending, downloading, completed, failed, cancelled)
+//! - Priority-based ordering, with a user-defined order within each priority
+//! - Queue item lifecycle management (pending, downloading, completed, failed, cancelled,
+//!   interrupted)
 //! - Event emission for queue state changes
+//! - Persistence of unfinished items across restarts
+//!
+//! Pending, failed and downloading items are saved to [`QUEUE_STATE_FILE`]
+//! whenever the queue changes. Items that were downloading when the
+//! application stopped come back as interrupted, ready to be resumed.
 
 use std::collections::VecDeque;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 use tokio::sync::{RwLock, mpsc};
 use tracing::{debug, error, info, warn};
 
+use crate::config::AppConfig;
+use crate::error::Result;
+
+/// File name of the saved queue, stored next to the config file.
+pub const QUEUE_STATE_FILE: &str = "queue.json";
+
 /// Unique identifier for a queue item.
 pub type QueueItemId = u64;
 
@@ -63,6 +76,8 @@ pub enum QueueItemStatus {
     Failed(String),
     /// Download was cancelled.
     Cancelled,
+    /// Item was downloading when the application last stopped.
+    Interrupted,
 }
 
 impl std::fmt::Display for QueueItemStatus {
@@ -73,6 +88,7 @@ impl std::fmt::Display for QueueItemStatus {
             Self::Completed => write!(f, "Completed"),
             Self::Failed(msg) => write!(f, "Failed: {msg}"),
             Self::Cancelled => write!(f, "Cancelled"),
+            Self::Interrupted => write!(f, "Interrupted"),
         }
     }
 }
@@ -127,7 +143,7 @@ impl QueueConfig {
 }
 
 /// A download request to be queued.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DownloadRequest {
     /// `YouTube` playlist URL.
     pub url: String,
@@ -142,6 +158,17 @@ pub struct DownloadRequest {
     /// Priority level for this download.
     #[serde(default)]
     pub priority: DownloadPriority,
+    /// Download speed limit in bytes per second, overriding the global
+    /// limit.
+    #[serde(default)]
+    pub max_speed_bps: Option<u64>,
+    /// Split videos with chapters into one track per chapter.
+    #[serde(default)]
+    pub split_by_chapters: bool,
+    /// Only work out what would be downloaded and how big it would be,
+    /// without downloading or writing any file.
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 impl DownloadRequest {
@@ -154,6 +181,9 @@ impl DownloadRequest {
             audio_quality: None,
             embed_thumbnail: None,
             priority: DownloadPriority::default(),
+            max_speed_bps: None,
+            split_by_chapters: false,
+            dry_run: false,
         }
     }
 
@@ -184,6 +214,28 @@ impl DownloadRequest {
         self.priority = priority;
         self
     }
+
+    /// Set the download speed limit in bytes per second.
+    #[must_use]
+    pub const fn with_max_speed(mut self, bytes_per_sec: u64) -> Self {
+        self.max_speed_bps = Some(bytes_per_sec);
+        self
+    }
+
+    /// Set whether videos with chapters are split into one track per
+    /// chapter.
+    #[must_use]
+    pub const fn with_split_by_chapters(mut self, split: bool) -> Self {
+        self.split_by_chapters = split;
+        self
+    }
+
+    /// Set whether the download is only simulated.
+    #[must_use]
+    pub const fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
 }
 
 /// A queued download item with tracking information.
@@ -248,19 +300,37 @@ impl QueueItem {
             .unwrap_or(&self.request.url)
     }
 
-    /// Check if the item is in a terminal state (completed, failed, or cancelled).
+    /// Check if the item is in a terminal state (completed, failed,
+    /// cancelled, or interrupted until resumed).
     #[must_use]
     pub const fn is_finished(&self) -> bool {
         matches!(
             self.status,
-            QueueItemStatus::Completed | QueueItemStatus::Failed(_) | QueueItemStatus::Cancelled
+            QueueItemStatus::Completed
+                | QueueItemStatus::Failed(_)
+                | QueueItemStatus::Cancelled
+                | QueueItemStatus::Interrupted
         )
     }
 
-    /// Check if the item can be retried.
+    /// Check if the item can be retried. Interrupted items can always be
+    /// resumed.
     #[must_use]
     pub const fn can_retry(&self, max_retries: u32) -> bool {
-        matches!(self.status, QueueItemStatus::Failed(_)) && self.retry_count < max_retries
+        match self.status {
+            QueueItemStatus::Failed(_) => self.retry_count < max_retries,
+            QueueItemStatus::Interrupted => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the item is saved with the queue: everything not completed
+    /// or cancelled.
+    const fn is_saved(&self) -> bool {
+        !matches!(
+            self.status,
+            QueueItemStatus::Completed | QueueItemStatus::Cancelled
+        )
     }
 }
 
@@ -319,6 +389,11 @@ pub enum QueueEvent {
         /// The new priority.
         priority: DownloadPriority,
     },
+    /// Pending items were reordered.
+    ItemsReordered {
+        /// The pending items in the order they start.
+        item_ids: Vec<QueueItemId>,
+    },
     /// The queue was cleared.
     QueueCleared,
     /// Queue processing was paused.
@@ -344,6 +419,9 @@ pub struct QueueStats {
     pub failed_count: usize,
     /// Number of cancelled items.
     pub cancelled_count: usize,
+    /// Number of items interrupted when the application last stopped.
+    #[serde(default)]
+    pub interrupted_count: usize,
 }
 
 /// Internal state for the queue manager.
@@ -385,17 +463,56 @@ impl QueueState {
 
     /// Get the next pending item that should be started, respecting priority.
     /// Higher priority items are processed first, and within the same priority,
-    /// older items (smaller `added_at`) are processed first (FIFO).
+    /// items are processed in queue order: the order they were added in,
+    /// unless the user reordered them.
     fn next_pending_item(&self) -> Option<QueueItemId> {
         self.items
             .iter()
             .filter(|item| matches!(item.status, QueueItemStatus::Pending))
-            // Sort by: highest priority first, then oldest first (smallest added_at)
-            // Use min_by_key with negated priority to get highest priority first
-            .min_by_key(|item| (std::cmp::Reverse(item.request.priority), item.added_at))
+            // The first of the highest priority items
+            .min_by_key(|item| std::cmp::Reverse(item.request.priority))
             .map(|item| item.id)
     }
 
+    /// IDs of the pending items in the order they start.
+    fn pending_order(&self) -> Vec<QueueItemId> {
+        let mut pending: Vec<_> = self
+            .items
+            .iter()
+            .filter(|item| matches!(item.status, QueueItemStatus::Pending))
+            .collect();
+        // Stable, so queue order is kept within each priority
+        pending.sort_by_key(|item| std::cmp::Reverse(item.request.priority));
+        pending.into_iter().map(|item| item.id).collect()
+    }
+
+    /// Put the pending items `ordered_ids` in that order: each takes the
+    /// queue slot of the listed item at its position, so unlisted items
+    /// keep their place. Returns false, changing nothing, if an item is
+    /// listed twice or isn't pending.
+    fn apply_order(&mut self, ordered_ids: &[QueueItemId]) -> bool {
+        let mut slots = Vec::with_capacity(ordered_ids.len());
+        for id in ordered_ids {
+            match self.items.iter().position(|item| item.id == *id) {
+                Some(slot)
+                    if matches!(self.items[slot].status, QueueItemStatus::Pending)
+                        && !slots.contains(&slot) =>
+                {
+                    slots.push(slot);
+                }
+                _ => return false,
+            }
+        }
+
+        let reordered: Vec<QueueItem> =
+            slots.iter().map(|&slot| self.items[slot].clone()).collect();
+        slots.sort_unstable();
+        for (slot, item) in slots.into_iter().zip(reordered) {
+            self.items[slot] = item;
+        }
+        true
+    }
+
     /// Find an item by ID.
     fn find_item(&self, id: QueueItemId) -> Option<&QueueItem> {
         self.items.iter().find(|item| item.id == id)
@@ -413,6 +530,7 @@ impl QueueState {
         let mut completed_count = 0;
         let mut failed_count = 0;
         let mut cancelled_count = 0;
+        let mut interrupted_count = 0;
 
         for item in &self.items {
             match &item.status {
@@ -421,6 +539,7 @@ impl QueueState {
                 QueueItemStatus::Completed => completed_count += 1,
                 QueueItemStatus::Failed(_) => failed_count += 1,
                 QueueItemStatus::Cancelled => cancelled_count += 1,
+                QueueItemStatus::Interrupted => interrupted_count += 1,
             }
         }
 
@@ -431,8 +550,61 @@ impl QueueState {
             completed_count,
             failed_count,
             cancelled_count,
+            interrupted_count,
+        }
+    }
+}
+
+/// Path of the saved queue in its default location next to the config file.
+#[must_use]
+pub fn default_queue_state_path() -> PathBuf {
+    let config_path = AppConfig::config_file_path();
+    let dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+    dir.join(QUEUE_STATE_FILE)
+}
+
+fn write_saved_queue(path: &Path, items: &[&QueueItem]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, serde_json::to_string_pretty(items)?)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Load the items saved at `path`, marking those that were downloading as
+/// interrupted.
+///
+/// A missing file yields no items; an unreadable one is logged and ignored.
+#[must_use]
+pub fn load_saved_queue(path: &Path) -> Vec<QueueItem> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+        Err(e) => {
+            warn!("Failed to read saved queue {}: {}", path.display(), e);
+            return Vec::new();
+        }
+    };
+    let mut items = match serde_json::from_str::<Vec<QueueItem>>(&contents) {
+        Ok(items) => items,
+        Err(e) => {
+            warn!("Discarding corrupt saved queue {}: {}", path.display(), e);
+            return Vec::new();
+        }
+    };
+    for item in &mut items {
+        if item.status == QueueItemStatus::Downloading {
+            item.status = QueueItemStatus::Interrupted;
+            item.task_id = None;
         }
     }
+    items.retain(QueueItem::is_saved);
+    if !items.is_empty() {
+        info!("Loaded {} saved queue item(s)", items.len());
+    }
+    items
 }
 
 /// Manages a queue of download requests with concurrent processing support.
@@ -443,6 +615,8 @@ pub struct DownloadQueueManager {
     event_tx: mpsc::UnboundedSender<QueueEvent>,
     /// Channel for receiving queue events.
     event_rx: Arc<RwLock<mpsc::UnboundedReceiver<QueueEvent>>>,
+    /// Where unfinished items are saved, if anywhere.
+    state_path: Option<PathBuf>,
 }
 
 impl DownloadQueueManager {
@@ -456,12 +630,44 @@ impl DownloadQueueManager {
     #[must_use]
     pub fn with_config(mut config: QueueConfig) -> Self {
         config.validate();
+        Self::from_state(QueueState::new(config), None)
+    }
+
+    /// Create a download queue manager holding `items` (from
+    /// [`load_saved_queue`]), saving its unfinished items to `path`.
+    #[must_use]
+    pub fn restore(
+        mut config: QueueConfig,
+        items: Vec<QueueItem>,
+        path: impl Into<PathBuf>,
+    ) -> Self {
+        config.validate();
+        let mut state = QueueState::new(config);
+        state.next_id = items.iter().map(|item| item.id + 1).max().unwrap_or(0);
+        state.items = items.into();
+        Self::from_state(state, Some(path.into()))
+    }
+
+    fn from_state(state: QueueState, state_path: Option<PathBuf>) -> Self {
         let (event_tx, event_rx) = mpsc::unbounded_channel();
 
         Self {
-            state: Arc::new(RwLock::new(QueueState::new(config))),
+            state: Arc::new(RwLock::new(state)),
             event_tx,
             event_rx: Arc::new(RwLock::new(event_rx)),
+            state_path,
+        }
+    }
+
+    /// Save the unfinished items, if the queue is persisted. Failures are
+    /// logged; the queue keeps working in memory.
+    fn save(&self, state: &QueueState) {
+        let Some(path) = &self.state_path else {
+            return;
+        };
+        let items: Vec<&QueueItem> = state.items.iter().filter(|i| i.is_saved()).collect();
+        if let Err(e) = write_saved_queue(path, &items) {
+            warn!("Failed to save queue to {}: {}", path.display(), e);
         }
     }
 
@@ -482,6 +688,7 @@ impl DownloadQueueManager {
         let _ = self.event_tx.send(QueueEvent::ItemAdded(item.clone()));
 
         state.items.push_back(item);
+        self.save(&state);
         id
     }
 
@@ -505,6 +712,7 @@ impl DownloadQueueManager {
             state.items.push_back(item);
             ids.push(id);
         }
+        self.save(&state);
 
         ids
     }
@@ -526,6 +734,7 @@ impl DownloadQueueManager {
             }
 
             state.items.remove(pos);
+            self.save(&state);
             let _ = self.event_tx.send(QueueEvent::ItemRemoved { item_id: id });
             info!("Removed item {} from queue", id);
             true
@@ -554,6 +763,7 @@ impl DownloadQueueManager {
 
             item.status = QueueItemStatus::Cancelled;
             item.finished_at = Some(now);
+            self.save(&state);
 
             let _ = self
                 .event_tx
@@ -579,6 +789,7 @@ impl DownloadQueueManager {
             }
 
             item.request.priority = priority;
+            self.save(&state);
             let _ = self.event_tx.send(QueueEvent::ItemPriorityChanged {
                 item_id: id,
                 priority,
@@ -591,9 +802,109 @@ impl DownloadQueueManager {
         }
     }
 
-    /// Move an item to the front of the queue (highest priority for pending items).
+    /// Set whether a pending item splits videos with chapters into one track
+    /// per chapter.
+    ///
+    /// Returns true if the item was updated.
+    pub async fn set_split_by_chapters(&self, id: QueueItemId, split: bool) -> bool {
+        let mut state = self.state.write().await;
+
+        if let Some(item) = state.find_item_mut(id) {
+            if !matches!(item.status, QueueItemStatus::Pending) {
+                warn!(
+                    "Cannot change chapter splitting of item {} - not pending",
+                    id
+                );
+                return false;
+            }
+
+            item.request.split_by_chapters = split;
+            self.save(&state);
+            info!("Set chapter splitting of item {} to {}", id, split);
+            true
+        } else {
+            warn!("Cannot update chapter splitting of item {} - not found", id);
+            false
+        }
+    }
+
+    /// Move an item to the front of the queue: high priority, first among
+    /// the high priority items.
     pub async fn move_to_front(&self, id: QueueItemId) -> bool {
-        self.set_priority(id, DownloadPriority::High).await
+        if !self.set_priority(id, DownloadPriority::High).await {
+            return false;
+        }
+        // Only pending items have a place in the order
+        let pending = self
+            .get_item(id)
+            .await
+            .is_some_and(|item| matches!(item.status, QueueItemStatus::Pending));
+        if pending {
+            self.move_item(id, 0).await;
+        }
+        true
+    }
+
+    /// Put pending items in the order of `ordered_ids`.
+    ///
+    /// Priorities still come first: the order applies within each priority,
+    /// and listed items take the queue places of the listed items, so
+    /// unlisted items keep theirs. Returns true if the items were
+    /// reordered; false, changing nothing, if an item is listed twice or
+    /// isn't pending.
+    pub async fn reorder(&self, ordered_ids: &[QueueItemId]) -> bool {
+        let mut state = self.state.write().await;
+
+        if !state.apply_order(ordered_ids) {
+            warn!("Cannot reorder items {:?} - not all pending", ordered_ids);
+            return false;
+        }
+        self.save(&state);
+        let _ = self.event_tx.send(QueueEvent::ItemsReordered {
+            item_ids: state.pending_order(),
+        });
+        info!("Reordered {} pending items", ordered_ids.len());
+        true
+    }
+
+    /// Move a pending item to `new_position` among the pending items of the
+    /// same priority, 0 being the first to start. Positions past the end
+    /// move it last.
+    ///
+    /// Returns true if the item was moved.
+    pub async fn move_item(&self, id: QueueItemId, new_position: usize) -> bool {
+        let mut state = self.state.write().await;
+
+        let Some(priority) = state
+            .find_item(id)
+            .filter(|item| matches!(item.status, QueueItemStatus::Pending))
+            .map(|item| item.request.priority)
+        else {
+            warn!("Cannot move item {} - not pending", id);
+            return false;
+        };
+
+        let mut band: Vec<QueueItemId> = state
+            .pending_order()
+            .into_iter()
+            .filter(|&other| {
+                other != id
+                    && state
+                        .find_item(other)
+                        .is_some_and(|item| item.request.priority == priority)
+            })
+            .collect();
+        band.insert(new_position.min(band.len()), id);
+        if !state.apply_order(&band) {
+            return false;
+        }
+
+        self.save(&state);
+        let _ = self.event_tx.send(QueueEvent::ItemsReordered {
+            item_ids: state.pending_order(),
+        });
+        info!("Moved item {} to position {}", id, new_position);
+        true
     }
 
     /// Get a specific queue item by ID.
@@ -617,7 +928,8 @@ impl DownloadQueueManager {
             .filter(|item| matches!(item.status, QueueItemStatus::Pending))
             .cloned()
             .collect();
-        items.sort_by_key(|item| (std::cmp::Reverse(item.request.priority), item.added_at));
+        // Stable, so queue order is kept within each priority
+        items.sort_by_key(|item| std::cmp::Reverse(item.request.priority));
         items
     }
 
@@ -683,7 +995,9 @@ impl DownloadQueueManager {
                 "Starting download for item {}: {}",
                 item.id, item.request.url
             );
-            Some(item.clone())
+            let item = item.clone();
+            self.save(&state);
+            Some(item)
         } else {
             None
         }
@@ -748,6 +1062,7 @@ impl DownloadQueueManager {
             item.status = QueueItemStatus::Completed;
             item.finished_at = Some(now);
             item.progress = 1.0;
+            self.save(&state);
 
             let _ = self
                 .event_tx
@@ -768,6 +1083,7 @@ impl DownloadQueueManager {
 
             item.status = QueueItemStatus::Failed(error.clone());
             item.finished_at = Some(now);
+            self.save(&state);
 
             let _ = self.event_tx.send(QueueEvent::ItemFailed {
                 item_id: id,
@@ -777,7 +1093,7 @@ impl DownloadQueueManager {
         }
     }
 
-    /// Retry a failed item.
+    /// Retry a failed item, or resume an interrupted one.
     ///
     /// Returns true if the item was reset for retry.
     pub async fn retry(&self, id: QueueItemId) -> bool {
@@ -793,8 +1109,11 @@ impl DownloadQueueManager {
                 return false;
             }
 
+            // Resuming an interrupted item doesn't count as a retry
+            if matches!(item.status, QueueItemStatus::Failed(_)) {
+                item.retry_count += 1;
+            }
             item.status = QueueItemStatus::Pending;
-            item.retry_count += 1;
             item.task_id = None;
             item.started_at = None;
             item.finished_at = None;
@@ -803,6 +1122,7 @@ impl DownloadQueueManager {
             item.videos_completed = None;
 
             info!("Retrying item {} (attempt {})", id, item.retry_count);
+            self.save(&state);
             true
         } else {
             warn!("Cannot retry item {} - not found", id);
@@ -830,6 +1150,25 @@ impl DownloadQueueManager {
         }
     }
 
+    /// Pause the queue and mark downloading items as interrupted, so the
+    /// application can stop without losing them. Returns how many items
+    /// were interrupted.
+    pub async fn suspend(&self) -> usize {
+        let mut state = self.state.write().await;
+        state.paused = true;
+        let mut interrupted = 0;
+        for item in &mut state.items {
+            if item.status == QueueItemStatus::Downloading {
+                item.status = QueueItemStatus::Interrupted;
+                item.task_id = None;
+                interrupted += 1;
+            }
+        }
+        self.save(&state);
+        info!("Queue suspended, {} download(s) interrupted", interrupted);
+        interrupted
+    }
+
     /// Check if the queue is paused.
     pub async fn is_paused(&self) -> bool {
         let state = self.state.read().await;
@@ -842,6 +1181,7 @@ impl DownloadQueueManager {
         let before = state.items.len();
         state.items.retain(|item| !item.is_finished());
         let removed = before - state.items.len();
+        self.save(&state);
         if removed > 0 {
             info!("Cleared {} finished items from queue", removed);
         }
@@ -856,6 +1196,7 @@ impl DownloadQueueManager {
             .items
             .retain(|item| matches!(item.status, QueueItemStatus::Downloading));
         let removed = before - state.items.len();
+        self.save(&state);
         let _ = self.event_tx.send(QueueEvent::QueueCleared);
         if removed > 0 {
             info!("Cleared {} items from queue", removed);
@@ -1047,6 +1388,9 @@ mod tests {
         assert!(request.audio_quality.is_none());
         assert!(request.embed_thumbnail.is_none());
         assert_eq!(request.priority, DownloadPriority::Normal);
+        assert!(request.max_speed_bps.is_none());
+        assert!(!request.split_by_chapters);
+        assert!(!request.dry_run);
     }
 
     #[test]
@@ -1055,12 +1399,18 @@ mod tests {
             .with_playlist_name("My Playlist")
             .with_audio_quality("320")
             .with_embed_thumbnail(true)
-            .with_priority(DownloadPriority::High);
+            .with_priority(DownloadPriority::High)
+            .with_max_speed(500_000)
+            .with_split_by_chapters(true)
+            .with_dry_run(true);
 
         assert_eq!(request.playlist_name, Some("My Playlist".to_string()));
         assert_eq!(request.audio_quality, Some("320".to_string()));
         assert_eq!(request.embed_thumbnail, Some(true));
         assert_eq!(request.priority, DownloadPriority::High);
+        assert_eq!(request.max_speed_bps, Some(500_000));
+        assert!(request.split_by_chapters);
+        assert!(request.dry_run);
     }
 
     // ========== QueueItem Tests ==========
@@ -1270,6 +1620,20 @@ mod tests {
         assert!(!result);
     }
 
+    #[tokio::test]
+    async fn test_queue_set_split_by_chapters() {
+        let queue = DownloadQueueManager::new();
+
+        let id = queue.add(DownloadRequest::new("url", "/tmp")).await;
+        assert!(queue.set_split_by_chapters(id, true).await);
+        assert!(queue.get_item(id).await.unwrap().request.split_by_chapters);
+
+        // Only pending items can change
+        queue.cancel(id).await;
+        assert!(!queue.set_split_by_chapters(id, false).await);
+        assert!(!queue.set_split_by_chapters(999, true).await);
+    }
+
     #[tokio::test]
     async fn test_queue_move_to_front() {
         let queue = DownloadQueueManager::new();
@@ -1817,6 +2181,9 @@ mod tests {
                 total_videos: Some(10),
                 videos_completed: Some(5),
             },
+            QueueEvent::ItemsReordered {
+                item_ids: vec![8, 9],
+            },
         ];
 
         for event in events {
@@ -1884,4 +2251,145 @@ mod tests {
         assert!(item.finished_at.is_some());
         assert!(item.finished_at.unwrap() >= item.started_at.unwrap());
     }
+
+    #[tokio::test]
+    async fn test_queue_saved_and_restored() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(QUEUE_STATE_FILE);
+        let queue = DownloadQueueManager::restore(QueueConfig::default(), Vec::new(), &path);
+
+        let downloading = queue.add(DownloadRequest::new("url1", "/tmp")).await;
+        let completed = queue.add(DownloadRequest::new("url2", "/tmp")).await;
+        let failed = queue.add(DownloadRequest::new("url3", "/tmp")).await;
+        let pending = queue
+            .add(
+                DownloadRequest::new("url4", "/tmp")
+                    .with_priority(DownloadPriority::Low)
+                    .with_split_by_chapters(true),
+            )
+            .await;
+        queue.start_next().await;
+        queue.start_next().await;
+        queue.mark_completed(completed).await;
+        queue.start_next().await;
+        queue.mark_failed(failed, "Network error".to_string()).await;
+
+        let items = load_saved_queue(&path);
+        let status = |id| {
+            items
+                .iter()
+                .find(|item| item.id == id)
+                .map(|item| &item.status)
+        };
+        assert_eq!(items.len(), 3);
+        assert_eq!(status(downloading), Some(&QueueItemStatus::Interrupted));
+        assert_eq!(status(completed), None);
+        assert_eq!(
+            status(failed),
+            Some(&QueueItemStatus::Failed("Network error".to_string()))
+        );
+        assert_eq!(status(pending), Some(&QueueItemStatus::Pending));
+
+        let restored = DownloadQueueManager::restore(QueueConfig::default(), items, &path);
+        let request = restored.get_item(pending).await.unwrap().request;
+        assert_eq!(request.priority, DownloadPriority::Low);
+        assert!(request.split_by_chapters);
+        assert_eq!(restored.stats().await.interrupted_count, 1);
+        assert!(restored.add(DownloadRequest::new("url5", "/tmp")).await > pending);
+
+        // Resuming doesn't count as a retry
+        assert!(restored.retry(downloading).await);
+        let item = restored.get_item(downloading).await.unwrap();
+        assert_eq!(item.status, QueueItemStatus::Pending);
+        assert_eq!(item.retry_count, 0);
+    }
+
+    #[test]
+    fn test_load_saved_queue_missing_or_corrupt() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(QUEUE_STATE_FILE);
+        assert!(load_saved_queue(&path).is_empty());
+        fs::write(&path, "not json").unwrap();
+        assert!(load_saved_queue(&path).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_suspend_interrupts_downloads() {
+        let queue = DownloadQueueManager::new();
+        let downloading = queue.add(DownloadRequest::new("url1", "/tmp")).await;
+        let pending = queue.add(DownloadRequest::new("url2", "/tmp")).await;
+        queue.set_max_concurrent(1).await;
+        queue.start_next().await;
+
+        assert_eq!(queue.suspend().await, 1);
+        assert!(queue.is_paused().await);
+        assert!(!queue.cancel(downloading).await);
+        let item = queue.get_item(downloading).await.unwrap();
+        assert_eq!(item.status, QueueItemStatus::Interrupted);
+        assert_eq!(
+            queue.get_item(pending).await.unwrap().status,
+            QueueItemStatus::Pending
+        );
+    }
+
+    // ========== Reordering Tests ==========
+
+    async fn pending_ids(queue: &DownloadQueueManager) -> Vec<QueueItemId> {
+        queue
+            .get_pending_items()
+            .await
+            .iter()
+            .map(|item| item.id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_reorder_within_priority() {
+        let queue = DownloadQueueManager::new();
+        let a = queue.add(DownloadRequest::new("a", "/tmp")).await;
+        let b = queue.add(DownloadRequest::new("b", "/tmp")).await;
+        let high = queue
+            .add(DownloadRequest::new("high", "/tmp").with_priority(DownloadPriority::High))
+            .await;
+        let c = queue.add(DownloadRequest::new("c", "/tmp")).await;
+
+        assert!(queue.reorder(&[c, a]).await);
+        // b keeps its place; the high priority item still starts first
+        assert_eq!(pending_ids(&queue).await, vec![high, c, b, a]);
+
+        assert!(!queue.reorder(&[a, a]).await);
+        assert!(!queue.reorder(&[a, 99]).await);
+        assert_eq!(pending_ids(&queue).await, vec![high, c, b, a]);
+
+        assert_eq!(queue.start_next().await.unwrap().id, high);
+        assert_eq!(queue.start_next().await.unwrap().id, c);
+        assert!(!queue.reorder(&[c, b]).await);
+    }
+
+    #[tokio::test]
+    async fn test_move_item() {
+        let queue = DownloadQueueManager::new();
+        let a = queue.add(DownloadRequest::new("a", "/tmp")).await;
+        let low = queue
+            .add(DownloadRequest::new("low", "/tmp").with_priority(DownloadPriority::Low))
+            .await;
+        let b = queue.add(DownloadRequest::new("b", "/tmp")).await;
+        let c = queue.add(DownloadRequest::new("c", "/tmp")).await;
+
+        assert!(queue.move_item(c, 0).await);
+        assert_eq!(pending_ids(&queue).await, vec![c, a, b, low]);
+        assert!(queue.move_item(c, 99).await);
+        assert_eq!(pending_ids(&queue).await, vec![a, b, c, low]);
+        // Positions count within the item's priority
+        assert!(queue.move_item(low, 0).await);
+        assert_eq!(pending_ids(&queue).await, vec![a, b, c, low]);
+
+        assert!(queue.move_to_front(b).await);
+        assert_eq!(pending_ids(&queue).await, vec![b, a, c, low]);
+        assert!(queue.move_to_front(c).await);
+        assert_eq!(pending_ids(&queue).await, vec![c, b, a, low]);
+
+        queue.cancel(a).await;
+        assert!(!queue.move_item(a, 0).await);
+    }
 }
@@ -0,0 +1,255 @@
+//! Resumable video stream downloads.
+//!
+//! A stream is written to `<video_id>.mp4` in the `partial` subdirectory of
+//! the temp area, next to a `<video_id>.part` sidecar recording the expected
+//! content length. If the download is interrupted (sleep, network drop, app
+//! exit), the next attempt finds both files and continues from the current
+//! file length with an HTTP range request instead of starting from byte zero.
+//!
+//! The `partial` subdirectory survives [`crate::cache::CacheManager::cleanup_temp`],
+//! which only removes loose files from the temp area.
+
+use std::fs::{self, File, OpenOptions};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use crate::cache::TempFile;
+use crate::error::{Error, FileSystemError, Result};
+
+/// Subdirectory of the temp area holding resumable downloads.
+pub const PARTIAL_DOWNLOAD_DIR: &str = "partial";
+
+/// Contents of the `.part` sidecar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct PartSidecar {
+    video_id: String,
+    content_length: u64,
+}
+
+/// A stream download that can be resumed after an interruption.
+#[derive(Debug)]
+pub struct PartialDownload {
+    video_id: String,
+    path: PathBuf,
+    sidecar: PathBuf,
+    content_length: u64,
+    resume_from: u64,
+}
+
+impl PartialDownload {
+    /// Open the partial download of `video_id` in `temp_dir`.
+    ///
+    /// Existing data is kept when its sidecar records the same
+    /// `content_length` and it isn't longer than that; otherwise the
+    /// download starts over.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the partial files cannot be created.
+    pub fn open(temp_dir: &Path, video_id: &str, content_length: u64) -> Result<Self> {
+        let dir = temp_dir.join(PARTIAL_DOWNLOAD_DIR);
+        fs::create_dir_all(&dir).map_err(|e| {
+            Error::FileSystem(FileSystemError::CreateDirFailed {
+                path: dir.clone(),
+                reason: e.to_string(),
+            })
+        })?;
+
+        let path = dir.join(format!("{video_id}.mp4"));
+        let sidecar = dir.join(format!("{video_id}.part"));
+        let expected = PartSidecar {
+            video_id: video_id.to_string(),
+            content_length,
+        };
+
+        let recorded = fs::read_to_string(&sidecar)
+            .ok()
+            .and_then(|json| serde_json::from_str::<PartSidecar>(&json).ok());
+        let existing_len = fs::metadata(&path).map(|m| m.len()).ok();
+        let resume_from = match (recorded, existing_len) {
+            (Some(recorded), Some(len)) if recorded == expected && len <= content_length => len,
+            _ => 0,
+        };
+
+        let mut partial = Self {
+            video_id: video_id.to_string(),
+            path,
+            sidecar,
+            content_length,
+            resume_from,
+        };
+        if resume_from > 0 {
+            info!(
+                "Resuming download of {} at byte {} of {}",
+                video_id, resume_from, content_length
+            );
+        } else {
+            partial.start_over(&expected)?;
+        }
+        Ok(partial)
+    }
+
+    /// Truncate the data file and write a fresh sidecar.
+    fn start_over(&mut self, sidecar: &PartSidecar) -> Result<()> {
+        File::create(&self.path).map_err(|e| self.write_failed(&self.path, &e))?;
+        let json = serde_json::to_string(sidecar).map_err(|e| Error::internal(e.to_string()))?;
+        fs::write(&self.sidecar, json).map_err(|e| self.write_failed(&self.sidecar, &e))?;
+        self.resume_from = 0;
+        debug!("Started partial download {}", self.path.display());
+        Ok(())
+    }
+
+    fn write_failed(&self, path: &Path, e: &std::io::Error) -> Error {
+        Error::FileSystem(FileSystemError::WriteFailed {
+            path: path.to_path_buf(),
+            reason: format!("partial download of {}: {e}", self.video_id),
+        })
+    }
+
+    /// Path of the partial data file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Byte offset the download continues from (0 when starting fresh).
+    #[must_use]
+    pub const fn resume_from(&self) -> u64 {
+        self.resume_from
+    }
+
+    /// Expected size of the complete stream in bytes.
+    #[must_use]
+    pub const fn content_length(&self) -> u64 {
+        self.content_length
+    }
+
+    /// Open the data file for appending the next bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn append(&self) -> Result<File> {
+        OpenOptions::new()
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| self.write_failed(&self.path, &e))
+    }
+
+    /// Hand the completed stream over to a temp file in `temp_dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data file isn't `content_length` bytes long,
+    /// in which case the partial download is discarded, or if it cannot be
+    /// moved.
+    pub fn finish(self, temp_dir: &Path) -> Result<TempFile> {
+        let len = fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        if len != self.content_length {
+            let reason = format!(
+                "partial download of {} has {len} of {} bytes",
+                self.video_id, self.content_length
+            );
+            self.discard();
+            return Err(Error::internal(reason));
+        }
+
+        let temp = TempFile::create_in(temp_dir, &self.video_id, "mp4")?;
+        fs::rename(&self.path, temp.path()).map_err(|e| self.write_failed(temp.path(), &e))?;
+        let _ = fs::remove_file(&self.sidecar);
+        Ok(temp)
+    }
+
+    /// Remove the partial data and its sidecar so the next attempt starts
+    /// from byte zero.
+    pub fn discard(self) {
+        let _ = fs::remove_file(&self.path);
+        let _ = fs::remove_file(&self.sidecar);
+        debug!("Discarded partial download of {}", self.video_id);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    fn write_bytes(partial: &PartialDownload, bytes: &[u8]) {
+        partial.append().unwrap().write_all(bytes).unwrap();
+    }
+
+    #[test]
+    fn test_fresh_download_starts_at_zero() {
+        let temp = TempDir::new().unwrap();
+        let partial = PartialDownload::open(temp.path(), "abc", 10).unwrap();
+        assert_eq!(partial.resume_from(), 0);
+        assert_eq!(partial.content_length(), 10);
+        assert!(partial.path().exists());
+        assert!(
+            temp.path()
+                .join(PARTIAL_DOWNLOAD_DIR)
+                .join("abc.part")
+                .exists()
+        );
+    }
+
+    #[test]
+    fn test_resumes_from_existing_length() {
+        let temp = TempDir::new().unwrap();
+        let partial = PartialDownload::open(temp.path(), "abc", 10).unwrap();
+        write_bytes(&partial, b"hello");
+        drop(partial);
+
+        let partial = PartialDownload::open(temp.path(), "abc", 10).unwrap();
+        assert_eq!(partial.resume_from(), 5);
+        write_bytes(&partial, b"world");
+
+        let finished = partial.finish(temp.path()).unwrap();
+        assert_eq!(fs::read(finished.path()).unwrap(), b"helloworld");
+        assert!(
+            !temp
+                .path()
+                .join(PARTIAL_DOWNLOAD_DIR)
+                .join("abc.part")
+                .exists()
+        );
+    }
+
+    #[test]
+    fn test_changed_content_length_starts_over() {
+        let temp = TempDir::new().unwrap();
+        let partial = PartialDownload::open(temp.path(), "abc", 10).unwrap();
+        write_bytes(&partial, b"hello");
+        drop(partial);
+
+        let partial = PartialDownload::open(temp.path(), "abc", 12).unwrap();
+        assert_eq!(partial.resume_from(), 0);
+        assert_eq!(fs::metadata(partial.path()).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_missing_sidecar_starts_over() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join(PARTIAL_DOWNLOAD_DIR);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("abc.mp4"), b"stale").unwrap();
+
+        let partial = PartialDownload::open(temp.path(), "abc", 10).unwrap();
+        assert_eq!(partial.resume_from(), 0);
+    }
+
+    #[test]
+    fn test_incomplete_finish_discards() {
+        let temp = TempDir::new().unwrap();
+        let partial = PartialDownload::open(temp.path(), "abc", 10).unwrap();
+        write_bytes(&partial, b"short");
+        let path = partial.path().to_path_buf();
+
+        assert!(partial.finish(temp.path()).is_err());
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,256 @@
+//! Scheduled refreshes of playlists from their `YouTube` source.
+//!
+//! A playlist can be refreshed every few hours or once a day at a set time.
+//! Schedules are stored in the configuration by playlist name, along with
+//! when each playlist was last refreshed, so a refresh missed while the app
+//! was closed runs once on the next start. The app checks for due refreshes
+//! periodically and downloads the new videos of each due playlist.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+
+/// Seconds in a day.
+const DAY_SECS: u64 = 24 * 60 * 60;
+
+/// Shortest interval between two refreshes of a playlist, in hours.
+pub const MIN_REFRESH_INTERVAL_HOURS: u32 = 1;
+
+/// Longest interval between two refreshes of a playlist, in hours.
+pub const MAX_REFRESH_INTERVAL_HOURS: u32 = 24 * 30;
+
+/// When a playlist is refreshed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RefreshSchedule {
+    /// Every `hours` hours.
+    Interval {
+        /// Hours between two refreshes.
+        hours: u32,
+    },
+    /// Once a day at `minute_of_day` minutes past midnight, in the time zone
+    /// `utc_offset_minutes` ahead of UTC.
+    Daily {
+        /// Minutes past midnight, below 1440.
+        minute_of_day: u32,
+        /// Offset of the time zone from UTC, in minutes.
+        #[serde(default)]
+        utc_offset_minutes: i32,
+    },
+}
+
+impl RefreshSchedule {
+    /// Check that the schedule can be used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the interval is out of range, or the time of day
+    /// or time zone offset is invalid.
+    pub fn validate(&self) -> Result<()> {
+        match *self {
+            Self::Interval { hours } => {
+                if !(MIN_REFRESH_INTERVAL_HOURS..=MAX_REFRESH_INTERVAL_HOURS).contains(&hours) {
+                    return Err(Error::Configuration(format!(
+                        "Refresh interval must be between {MIN_REFRESH_INTERVAL_HOURS} and \
+                         {MAX_REFRESH_INTERVAL_HOURS} hours, got {hours}"
+                    )));
+                }
+            }
+            Self::Daily {
+                minute_of_day,
+                utc_offset_minutes,
+            } => {
+                if minute_of_day >= 24 * 60 {
+                    return Err(Error::Configuration(format!(
+                        "Refresh time must be before midnight, got minute {minute_of_day}"
+                    )));
+                }
+                if utc_offset_minutes.unsigned_abs() > 14 * 60 {
+                    return Err(Error::Configuration(format!(
+                        "Invalid time zone offset: {utc_offset_minutes} minutes"
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// First time the schedule fires after `since` (seconds since the Unix
+    /// epoch).
+    #[must_use]
+    pub fn next_after(&self, since: u64) -> u64 {
+        match *self {
+            Self::Interval { hours } => since + u64::from(hours.max(1)) * 3600,
+            Self::Daily {
+                minute_of_day,
+                utc_offset_minutes,
+            } => {
+                // Work in local time, where the refresh is at the same
+                // second of every day
+                let offset = i64::from(utc_offset_minutes) * 60;
+                let local = since.saturating_add_signed(offset);
+                let at = u64::from(minute_of_day) * 60;
+                let today = local - local % DAY_SECS + at;
+                let next = if today > local {
+                    today
+                } else {
+                    today + DAY_SECS
+                };
+                next.saturating_add_signed(-offset)
+            }
+        }
+    }
+}
+
+/// The refresh schedule of a playlist.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistSchedule {
+    /// When the playlist is refreshed.
+    pub schedule: RefreshSchedule,
+    /// Whether tracks of videos removed from the source are moved to the
+    /// trash on refresh.
+    #[serde(default)]
+    pub remove_missing: bool,
+    /// When the schedule was set (seconds since the Unix epoch).
+    pub created_at: u64,
+    /// When the playlist was last refreshed by the schedule, if ever.
+    #[serde(default)]
+    pub last_run_at: Option<u64>,
+}
+
+impl PlaylistSchedule {
+    /// A new schedule set at `now`.
+    #[must_use]
+    pub const fn new(schedule: RefreshSchedule, remove_missing: bool, now: u64) -> Self {
+        Self {
+            schedule,
+            remove_missing,
+            created_at: now,
+            last_run_at: None,
+        }
+    }
+
+    /// When the playlist is next refreshed (seconds since the Unix epoch).
+    #[must_use]
+    pub fn next_run_at(&self) -> u64 {
+        self.schedule
+            .next_after(self.last_run_at.unwrap_or(self.created_at))
+    }
+
+    /// Whether the playlist is due for a refresh at `now`.
+    #[must_use]
+    pub fn is_due(&self, now: u64) -> bool {
+        self.next_run_at() <= now
+    }
+}
+
+/// Names of the playlists of `schedules` due for a refresh at `now`, most
+/// overdue first.
+#[must_use]
+pub fn due_refreshes(schedules: &BTreeMap<String, PlaylistSchedule>, now: u64) -> Vec<String> {
+    let mut due: Vec<(&String, u64)> = schedules
+        .iter()
+        .filter(|(_, schedule)| schedule.is_due(now))
+        .map(|(playlist, schedule)| (playlist, schedule.next_run_at()))
+        .collect();
+    due.sort_by_key(|(_, next_run_at)| *next_run_at);
+    due.into_iter()
+        .map(|(playlist, _)| playlist.clone())
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    const HOUR: u64 = 3600;
+
+    #[test]
+    fn test_interval_schedule() {
+        let schedule = PlaylistSchedule::new(RefreshSchedule::Interval { hours: 6 }, false, 1000);
+        assert_eq!(schedule.next_run_at(), 1000 + 6 * HOUR);
+        assert!(!schedule.is_due(1000 + 6 * HOUR - 1));
+        assert!(schedule.is_due(1000 + 6 * HOUR));
+
+        let ran = PlaylistSchedule {
+            last_run_at: Some(50_000),
+            ..schedule
+        };
+        assert_eq!(ran.next_run_at(), 50_000 + 6 * HOUR);
+    }
+
+    #[test]
+    fn test_daily_schedule() {
+        // 02:30 UTC
+        let daily = RefreshSchedule::Daily {
+            minute_of_day: 150,
+            utc_offset_minutes: 0,
+        };
+        let day = 20_000 * DAY_SECS;
+        assert_eq!(daily.next_after(day), day + 150 * 60);
+        assert_eq!(daily.next_after(day + 150 * 60), day + DAY_SECS + 150 * 60);
+        assert_eq!(daily.next_after(day + 23 * HOUR), day + DAY_SECS + 150 * 60);
+
+        // 02:30 at UTC+2 is 00:30 UTC
+        let local = RefreshSchedule::Daily {
+            minute_of_day: 150,
+            utc_offset_minutes: 120,
+        };
+        assert_eq!(local.next_after(day), day + 30 * 60);
+        // 23:30 at UTC-5 is 04:30 UTC the next day
+        let west = RefreshSchedule::Daily {
+            minute_of_day: 23 * 60 + 30,
+            utc_offset_minutes: -300,
+        };
+        assert_eq!(
+            west.next_after(day + 5 * HOUR),
+            day + DAY_SECS + 4 * HOUR + 1800
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(RefreshSchedule::Interval { hours: 24 }.validate().is_ok());
+        assert!(RefreshSchedule::Interval { hours: 0 }.validate().is_err());
+        assert!(
+            RefreshSchedule::Daily {
+                minute_of_day: 24 * 60,
+                utc_offset_minutes: 0,
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            RefreshSchedule::Daily {
+                minute_of_day: 0,
+                utc_offset_minutes: 15 * 60,
+            }
+            .validate()
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_due_refreshes_most_overdue_first() {
+        let interval = |hours| RefreshSchedule::Interval { hours };
+        let schedules = BTreeMap::from([
+            (
+                "a".to_string(),
+                PlaylistSchedule::new(interval(2), false, 0),
+            ),
+            (
+                "b".to_string(),
+                PlaylistSchedule::new(interval(1), false, 0),
+            ),
+            (
+                "c".to_string(),
+                PlaylistSchedule::new(interval(24), false, 0),
+            ),
+        ]);
+        assert_eq!(due_refreshes(&schedules, 3 * HOUR), ["b", "a"]);
+        assert!(due_refreshes(&schedules, 0).is_empty());
+    }
+}
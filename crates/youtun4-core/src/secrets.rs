@@ -0,0 +1,507 @@
+//! Secure storage for sensitive settings.
+//!
+//! Values such as cookies file paths, webhook URLs and proxy credentials must
+//! not be written to `config.json` in plain text. This module provides a small
+//! key/value [`SecretsStore`] backed by the OS keychain when one is available,
+//! falling back to a ChaCha20-Poly1305 encrypted file next to the config file.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use youtun4_core::secrets::{SecretsStore, secret_keys};
+//!
+//! let store = SecretsStore::open_default();
+//! store.set(secret_keys::WEBHOOK_URL, "https://example.com/hook")?;
+//! let url = store.get(secret_keys::WEBHOOK_URL)?;
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::config::AppConfig;
+use crate::error::{Error, Result};
+
+/// Service name used for OS keychain entries.
+pub const KEYRING_SERVICE: &str = "youtun4";
+
+/// File name of the encrypted secrets file.
+pub const SECRETS_FILE: &str = "secrets.enc";
+
+/// File name of the key used to encrypt the secrets file.
+pub const SECRETS_KEY_FILE: &str = "secrets.key";
+
+/// Version of the encrypted secrets file format.
+const SECRETS_FILE_VERSION: u32 = 1;
+
+/// Well-known secret keys used by config-dependent modules.
+pub mod secret_keys {
+    /// Path to a cookies file used for authenticated downloads.
+    pub const COOKIES_FILE: &str = "cookies_file";
+    /// URL notified when downloads complete.
+    pub const WEBHOOK_URL: &str = "webhook_url";
+    /// Proxy credentials in `user:password` form.
+    pub const PROXY_CREDENTIALS: &str = "proxy_credentials";
+
+    /// Hash of the parent PIN guarding destructive operations in simple mode.
+    ///
+    /// Only managed by [`crate::parental`]; kept out of [`ALL`] so the generic
+    /// secret commands can't read or overwrite it.
+    pub const PARENT_PIN: &str = "parent_pin";
+
+    /// All known secret keys.
+    pub const ALL: &[&str] = &[COOKIES_FILE, WEBHOOK_URL, PROXY_CREDENTIALS];
+}
+
+// =============================================================================
+// Backends
+// =============================================================================
+
+/// A storage backend for secrets.
+pub trait SecretBackend: Send + Sync {
+    /// Read a secret, returning `None` if it is not set.
+    fn get(&self, key: &str) -> Result<Option<String>>;
+
+    /// Store a secret, replacing any existing value.
+    fn set(&self, key: &str, value: &str) -> Result<()>;
+
+    /// Delete a secret. Returns `true` if a value was removed.
+    fn delete(&self, key: &str) -> Result<bool>;
+
+    /// Human-readable backend name.
+    fn name(&self) -> &'static str;
+}
+
+/// Backend storing secrets in the OS keychain (Keychain, Credential Manager,
+/// Secret Service).
+#[derive(Debug, Clone)]
+pub struct KeyringBackend {
+    service: String,
+}
+
+impl KeyringBackend {
+    /// Create a keychain backend for the given service name.
+    #[must_use]
+    pub fn new(service: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+        }
+    }
+
+    /// Check whether the OS keychain can be reached.
+    #[must_use]
+    pub fn is_available(&self) -> bool {
+        match self.get("__youtun4_probe__") {
+            Ok(_) => true,
+            Err(e) => {
+                debug!("OS keychain unavailable: {e}");
+                false
+            }
+        }
+    }
+
+    fn entry(&self, key: &str) -> Result<keyring::Entry> {
+        keyring::Entry::new(&self.service, key).map_err(|e| Error::secrets(e.to_string()))
+    }
+}
+
+impl SecretBackend for KeyringBackend {
+    fn get(&self, key: &str) -> Result<Option<String>> {
+        match self.entry(key)?.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(Error::secrets(e.to_string())),
+        }
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<()> {
+        self.entry(key)?
+            .set_password(value)
+            .map_err(|e| Error::secrets(e.to_string()))
+    }
+
+    fn delete(&self, key: &str) -> Result<bool> {
+        match self.entry(key)?.delete_credential() {
+            Ok(()) => Ok(true),
+            Err(keyring::Error::NoEntry) => Ok(false),
+            Err(e) => Err(Error::secrets(e.to_string())),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "keychain"
+    }
+}
+
+/// On-disk layout of the encrypted secrets file.
+#[derive(Debug, Serialize, Deserialize)]
+struct EncryptedSecretsFile {
+    version: u32,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Backend storing secrets in a ChaCha20-Poly1305 encrypted file.
+///
+/// The 256-bit key is generated on first use and kept in a separate file
+/// readable only by the current user.
+#[derive(Debug)]
+pub struct EncryptedFileBackend {
+    path: PathBuf,
+    key_path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl EncryptedFileBackend {
+    /// Create a backend using the given secrets and key files.
+    #[must_use]
+    pub fn new(path: PathBuf, key_path: PathBuf) -> Self {
+        Self {
+            path,
+            key_path,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Create a backend storing its files in `dir`.
+    #[must_use]
+    pub fn in_directory(dir: &Path) -> Self {
+        Self::new(dir.join(SECRETS_FILE), dir.join(SECRETS_KEY_FILE))
+    }
+
+    /// Path of the encrypted secrets file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn load_or_create_key(&self) -> Result<Key> {
+        if self.key_path.exists() {
+            let bytes = fs::read(&self.key_path)?;
+            if bytes.len() != 32 {
+                return Err(Error::secrets("Secrets key file is corrupted"));
+            }
+            return Ok(*Key::from_slice(&bytes));
+        }
+
+        if let Some(parent) = self.key_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+        write_private(&self.key_path, key.as_slice())?;
+        Ok(key)
+    }
+
+    fn read_all(&self, cipher: &ChaCha20Poly1305) -> Result<BTreeMap<String, String>> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        let file: EncryptedSecretsFile = serde_json::from_str(&fs::read_to_string(&self.path)?)?;
+        if file.version > SECRETS_FILE_VERSION {
+            return Err(Error::secrets(format!(
+                "Unsupported secrets file version {}",
+                file.version
+            )));
+        }
+
+        let nonce = hex_decode(&file.nonce)?;
+        if nonce.len() != 12 {
+            return Err(Error::secrets("Secrets file is corrupted"));
+        }
+        let ciphertext = hex_decode(&file.ciphertext)?;
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| Error::secrets("Failed to decrypt secrets file"))?;
+
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+
+    fn write_all(
+        &self,
+        cipher: &ChaCha20Poly1305,
+        secrets: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        let plaintext = serde_json::to_vec(secrets)?;
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|_| Error::secrets("Failed to encrypt secrets"))?;
+
+        let file = EncryptedSecretsFile {
+            version: SECRETS_FILE_VERSION,
+            nonce: hex_encode(&nonce),
+            ciphertext: hex_encode(&ciphertext),
+        };
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = self.path.with_extension("enc.tmp");
+        if tmp.exists() {
+            fs::remove_file(&tmp)?;
+        }
+        write_private(&tmp, serde_json::to_string_pretty(&file)?.as_bytes())?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    /// Run `f` on the decrypted secrets map, persisting it if `f` returns `true`.
+    fn with_secrets<T>(
+        &self,
+        f: impl FnOnce(&mut BTreeMap<String, String>) -> (T, bool),
+    ) -> Result<T> {
+        let _guard = self
+            .lock
+            .lock()
+            .map_err(|_| Error::secrets("Secrets lock poisoned"))?;
+        let cipher = ChaCha20Poly1305::new(&self.load_or_create_key()?);
+        let mut secrets = self.read_all(&cipher)?;
+        let (result, changed) = f(&mut secrets);
+        if changed {
+            self.write_all(&cipher, &secrets)?;
+        }
+        Ok(result)
+    }
+}
+
+impl SecretBackend for EncryptedFileBackend {
+    fn get(&self, key: &str) -> Result<Option<String>> {
+        self.with_secrets(|secrets| (secrets.get(key).cloned(), false))
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<()> {
+        self.with_secrets(|secrets| {
+            secrets.insert(key.to_string(), value.to_string());
+            ((), true)
+        })
+    }
+
+    fn delete(&self, key: &str) -> Result<bool> {
+        self.with_secrets(|secrets| {
+            let removed = secrets.remove(key).is_some();
+            (removed, removed)
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "encrypted_file"
+    }
+}
+
+/// Create a new file readable only by the current user and write `contents`.
+///
+/// The file is created with its final permissions, so it is never readable
+/// by others, even briefly. Fails if the file already exists.
+fn write_private(path: &Path, contents: &[u8]) -> Result<()> {
+    use std::io::Write;
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    Ok(())
+}
+
+pub(crate) fn hex_encode(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut s, b| {
+            let _ = write!(s, "{b:02x}");
+            s
+        })
+}
+
+fn hex_decode(s: &str) -> Result<Vec<u8>> {
+    if s.len() % 2 != 0 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(Error::secrets("Invalid hex data in secrets file"));
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&s[i..i + 2], 16)
+                .map_err(|_| Error::secrets("Invalid hex data in secrets file"))
+        })
+        .collect()
+}
+
+// =============================================================================
+// Store
+// =============================================================================
+
+/// Key/value store for sensitive settings.
+pub struct SecretsStore {
+    backend: Box<dyn SecretBackend>,
+}
+
+impl std::fmt::Debug for SecretsStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretsStore")
+            .field("backend", &self.backend.name())
+            .finish()
+    }
+}
+
+impl SecretsStore {
+    /// Open the default store: the OS keychain if available, otherwise an
+    /// encrypted file next to the config file.
+    #[must_use]
+    pub fn open_default() -> Self {
+        let keyring = KeyringBackend::new(KEYRING_SERVICE);
+        if keyring.is_available() {
+            debug!("Using OS keychain for secrets");
+            return Self::with_backend(keyring);
+        }
+
+        let config_path = AppConfig::config_file_path();
+        let dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+        warn!(
+            "OS keychain unavailable, storing secrets in encrypted file under {}",
+            dir.display()
+        );
+        Self::with_backend(EncryptedFileBackend::in_directory(dir))
+    }
+
+    /// Create a store using a specific backend.
+    #[must_use]
+    pub fn with_backend(backend: impl SecretBackend + 'static) -> Self {
+        Self {
+            backend: Box::new(backend),
+        }
+    }
+
+    /// Read a secret.
+    pub fn get(&self, key: &str) -> Result<Option<String>> {
+        self.backend.get(key)
+    }
+
+    /// Store a secret.
+    pub fn set(&self, key: &str, value: &str) -> Result<()> {
+        self.backend.set(key, value)
+    }
+
+    /// Delete a secret. Returns `true` if a value was removed.
+    pub fn delete(&self, key: &str) -> Result<bool> {
+        self.backend.delete(key)
+    }
+
+    /// Check whether a secret is set.
+    pub fn contains(&self, key: &str) -> Result<bool> {
+        Ok(self.get(key)?.is_some())
+    }
+
+    /// Name of the active backend.
+    #[must_use]
+    pub fn backend_name(&self) -> &'static str {
+        self.backend.name()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn file_store(dir: &TempDir) -> SecretsStore {
+        SecretsStore::with_backend(EncryptedFileBackend::in_directory(dir.path()))
+    }
+
+    #[test]
+    fn test_encrypted_file_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let store = file_store(&dir);
+
+        assert_eq!(store.get(secret_keys::WEBHOOK_URL).unwrap(), None);
+        store
+            .set(secret_keys::WEBHOOK_URL, "https://example.com/hook")
+            .unwrap();
+        assert_eq!(
+            store.get(secret_keys::WEBHOOK_URL).unwrap().as_deref(),
+            Some("https://example.com/hook")
+        );
+
+        // A fresh store over the same files sees the value.
+        let reopened = file_store(&dir);
+        assert!(reopened.contains(secret_keys::WEBHOOK_URL).unwrap());
+        assert_eq!(store.backend_name(), "encrypted_file");
+    }
+
+    #[test]
+    fn test_encrypted_file_does_not_contain_plaintext() {
+        let dir = TempDir::new().unwrap();
+        let store = file_store(&dir);
+        store
+            .set(secret_keys::PROXY_CREDENTIALS, "alice:hunter2")
+            .unwrap();
+
+        let contents = fs::read_to_string(dir.path().join(SECRETS_FILE)).unwrap();
+        assert!(!contents.contains("hunter2"));
+        assert!(!contents.contains("proxy_credentials"));
+    }
+
+    #[test]
+    fn test_encrypted_file_wrong_key_fails() {
+        let dir = TempDir::new().unwrap();
+        file_store(&dir)
+            .set(secret_keys::COOKIES_FILE, "/home/me/cookies.txt")
+            .unwrap();
+
+        fs::write(dir.path().join(SECRETS_KEY_FILE), [7u8; 32]).unwrap();
+        let result = file_store(&dir).get(secret_keys::COOKIES_FILE);
+        assert!(matches!(result, Err(Error::Secrets(_))));
+    }
+
+    #[test]
+    fn test_encrypted_file_delete() {
+        let dir = TempDir::new().unwrap();
+        let store = file_store(&dir);
+        store.set(secret_keys::COOKIES_FILE, "cookies.txt").unwrap();
+
+        assert!(store.delete(secret_keys::COOKIES_FILE).unwrap());
+        assert!(!store.delete(secret_keys::COOKIES_FILE).unwrap());
+        assert!(!store.contains(secret_keys::COOKIES_FILE).unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_key_file_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        file_store(&dir).set("k", "v").unwrap();
+        let mode = fs::metadata(dir.path().join(SECRETS_KEY_FILE))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+        let mode = fs::metadata(dir.path().join(SECRETS_FILE))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        let bytes = [0u8, 1, 127, 255];
+        assert_eq!(hex_decode(&hex_encode(&bytes)).unwrap(), bytes);
+        assert!(hex_decode("abc").is_err());
+        assert!(hex_decode("zz").is_err());
+        assert!(hex_decode("+f").is_err());
+        assert!(hex_decode("a\u{e9}1").is_err());
+    }
+}
@@ -0,0 +1,517 @@
+//! Storage directory validation, migration and disk usage guardrails.
+//!
+//! When the playlists directory changes, existing data can be copied to the
+//! new location with progress reporting. Every copied file is verified against
+//! its SHA-256 checksum before the migration is reported as complete, so the
+//! caller only switches configuration once the destination is known-good.
+//!
+//! [`LibraryLimits`] caps the library size and keeps a minimum of free disk
+//! space; downloads check them before starting.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use youtun4_core::storage::migrate_storage_directory;
+//!
+//! let result = migrate_storage_directory(&old_dir, &new_dir, &cancel, |progress| {
+//!     println!("{:?}: {}/{}", progress.phase, progress.files_done, progress.files_total);
+//! })?;
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Deserialize, Serialize};
+use sysinfo::Disks;
+use tracing::{debug, info, warn};
+
+use crate::config::validate_storage_directory;
+use crate::error::{Error, Result};
+use crate::integrity::compute_file_checksum;
+
+/// Default minimum free disk space kept on the library's disk (1 GB).
+pub const DEFAULT_MIN_FREE_SPACE_BYTES: u64 = 1024 * 1024 * 1024;
+
+// =============================================================================
+// Disk Usage Guardrails
+// =============================================================================
+
+/// Disk usage limits for the playlists library.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LibraryLimits {
+    /// Maximum total size of the library in bytes (unlimited when `None`).
+    #[serde(default)]
+    pub max_library_size_bytes: Option<u64>,
+    /// Free space to keep on the library's disk in bytes (0 disables the check).
+    #[serde(default = "default_min_free_space_bytes")]
+    pub min_free_space_bytes: u64,
+}
+
+const fn default_min_free_space_bytes() -> u64 {
+    DEFAULT_MIN_FREE_SPACE_BYTES
+}
+
+impl Default for LibraryLimits {
+    fn default() -> Self {
+        Self {
+            max_library_size_bytes: None,
+            min_free_space_bytes: DEFAULT_MIN_FREE_SPACE_BYTES,
+        }
+    }
+}
+
+/// Current disk usage of the library, measured against its limits.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LibrarySpace {
+    /// Total size of the library in bytes.
+    pub library_bytes: u64,
+    /// Free space on the library's disk in bytes, if it could be determined.
+    pub available_bytes: Option<u64>,
+    /// Limits the usage was checked against.
+    pub limits: LibraryLimits,
+}
+
+impl LibrarySpace {
+    /// Measure the library at `library_dir`.
+    #[must_use]
+    pub fn measure(library_dir: &Path, limits: LibraryLimits) -> Self {
+        Self {
+            library_bytes: directory_size(library_dir),
+            available_bytes: available_space(library_dir),
+            limits,
+        }
+    }
+
+    /// Check the usage against the limits.
+    ///
+    /// Free space that cannot be determined is not treated as a failure.
+    pub fn check(&self) -> Result<()> {
+        if let Some(limit) = self.limits.max_library_size_bytes
+            && self.library_bytes >= limit
+        {
+            return Err(Error::library_limit_reached(self.library_bytes, limit));
+        }
+        if let Some(available) = self.available_bytes
+            && available < self.limits.min_free_space_bytes
+        {
+            return Err(Error::low_disk_space(
+                available,
+                self.limits.min_free_space_bytes,
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Total size in bytes of all files below `dir` (0 if it does not exist).
+#[must_use]
+pub fn directory_size(dir: &Path) -> u64 {
+    let mut files = Vec::new();
+    if let Err(e) = collect_files(dir, &mut files) {
+        debug!("Failed to measure {}: {}", dir.display(), e);
+    }
+    files.iter().map(|(_, size)| size).sum()
+}
+
+/// Free space in bytes on the disk holding `path`.
+///
+/// Picks the disk with the longest mount point that contains `path`.
+#[must_use]
+pub fn available_space(path: &Path) -> Option<u64> {
+    let path = path.canonicalize().ok()?;
+    let disks = Disks::new_with_refreshed_list();
+    disks
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(sysinfo::Disk::available_space)
+}
+
+// =============================================================================
+// Storage Migration
+// =============================================================================
+
+/// Phase of a storage migration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageMigrationPhase {
+    /// Enumerating files in the source directory.
+    Scanning,
+    /// Copying files to the destination.
+    Copying,
+    /// Verifying copied files against source checksums.
+    Verifying,
+    /// Migration finished successfully.
+    Completed,
+}
+
+/// Progress of a storage migration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StorageMigrationProgress {
+    /// Current phase.
+    pub phase: StorageMigrationPhase,
+    /// Files processed in the current phase.
+    pub files_done: usize,
+    /// Total files to migrate.
+    pub files_total: usize,
+    /// Bytes processed in the current phase.
+    pub bytes_done: u64,
+    /// Total bytes to migrate.
+    pub bytes_total: u64,
+    /// File currently being processed, relative to the source directory.
+    pub current_file: Option<String>,
+}
+
+/// Result of a completed storage migration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StorageMigrationResult {
+    /// Directory the data was copied from (left untouched).
+    pub source: PathBuf,
+    /// Directory the data was copied to.
+    pub destination: PathBuf,
+    /// Number of files copied and verified.
+    pub files_copied: usize,
+    /// Number of bytes copied.
+    pub bytes_copied: u64,
+}
+
+/// Check that `destination` can receive the contents of `source`.
+///
+/// The destination must be a usable storage directory, must differ from the
+/// source, must not be nested inside it, and must not already contain any
+/// top-level entry of the source.
+pub fn validate_migration_target(source: &Path, destination: &Path) -> Result<()> {
+    validate_storage_directory(destination)?;
+
+    let source = source.canonicalize()?;
+    let destination = destination.canonicalize()?;
+    if source == destination {
+        return Err(Error::Configuration(
+            "New storage directory is the same as the current one".to_string(),
+        ));
+    }
+    if destination.starts_with(&source) {
+        return Err(Error::Configuration(format!(
+            "New storage directory {} is inside the current one",
+            destination.display()
+        )));
+    }
+
+    for entry in fs::read_dir(&source)? {
+        let name = entry?.file_name();
+        if destination.join(&name).exists() {
+            return Err(Error::Configuration(format!(
+                "{} already exists in {}",
+                name.to_string_lossy(),
+                destination.display()
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Recursively list all files under `dir` with their sizes.
+fn collect_files(dir: &Path, files: &mut Vec<(PathBuf, u64)>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            collect_files(&path, files)?;
+        } else if file_type.is_file() {
+            files.push((path, entry.metadata()?.len()));
+        }
+    }
+    Ok(())
+}
+
+/// Copy the contents of `source` into `destination`, verifying every file.
+///
+/// The source is left untouched. If copying or verification fails, or the
+/// operation is cancelled, anything copied so far is removed from the
+/// destination.
+pub fn migrate_storage_directory<F>(
+    source: &Path,
+    destination: &Path,
+    cancel: &AtomicBool,
+    mut on_progress: F,
+) -> Result<StorageMigrationResult>
+where
+    F: FnMut(&StorageMigrationProgress),
+{
+    validate_migration_target(source, destination)?;
+
+    info!(
+        "Migrating storage from {} to {}",
+        source.display(),
+        destination.display()
+    );
+
+    let mut progress = StorageMigrationProgress {
+        phase: StorageMigrationPhase::Scanning,
+        files_done: 0,
+        files_total: 0,
+        bytes_done: 0,
+        bytes_total: 0,
+        current_file: None,
+    };
+    on_progress(&progress);
+
+    let mut files = Vec::new();
+    collect_files(source, &mut files)?;
+    progress.files_total = files.len();
+    progress.bytes_total = files.iter().map(|(_, size)| size).sum();
+
+    if let Err(e) = copy_and_verify(
+        source,
+        destination,
+        &files,
+        cancel,
+        &mut progress,
+        &mut on_progress,
+    ) {
+        remove_copied_entries(source, destination);
+        return Err(e);
+    }
+
+    progress.phase = StorageMigrationPhase::Completed;
+    progress.current_file = None;
+    on_progress(&progress);
+
+    info!(
+        "Storage migration complete: {} files, {} bytes",
+        progress.files_total, progress.bytes_total
+    );
+
+    Ok(StorageMigrationResult {
+        source: source.to_path_buf(),
+        destination: destination.to_path_buf(),
+        files_copied: progress.files_total,
+        bytes_copied: progress.bytes_total,
+    })
+}
+
+fn copy_and_verify<F>(
+    source: &Path,
+    destination: &Path,
+    files: &[(PathBuf, u64)],
+    cancel: &AtomicBool,
+    progress: &mut StorageMigrationProgress,
+    on_progress: &mut F,
+) -> Result<()>
+where
+    F: FnMut(&StorageMigrationProgress),
+{
+    let relative = |path: &Path| path.strip_prefix(source).unwrap_or(path).to_path_buf();
+
+    progress.phase = StorageMigrationPhase::Copying;
+    for (path, size) in files {
+        if cancel.load(Ordering::SeqCst) {
+            return Err(Error::Cancelled);
+        }
+        let rel = relative(path);
+        progress.current_file = Some(rel.display().to_string());
+        on_progress(progress);
+
+        let target = destination.join(&rel);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(path, &target)?;
+
+        progress.files_done += 1;
+        progress.bytes_done += size;
+    }
+
+    progress.phase = StorageMigrationPhase::Verifying;
+    progress.files_done = 0;
+    progress.bytes_done = 0;
+    for (path, size) in files {
+        if cancel.load(Ordering::SeqCst) {
+            return Err(Error::Cancelled);
+        }
+        let rel = relative(path);
+        progress.current_file = Some(rel.display().to_string());
+        on_progress(progress);
+
+        let expected = compute_file_checksum(path)?;
+        let actual = compute_file_checksum(&destination.join(&rel))?;
+        if expected != actual {
+            return Err(Error::Configuration(format!(
+                "Checksum mismatch after copying {}",
+                rel.display()
+            )));
+        }
+
+        progress.files_done += 1;
+        progress.bytes_done += size;
+    }
+
+    Ok(())
+}
+
+/// Remove the top-level entries of `source` from `destination`.
+///
+/// Only called after a failed migration; [`validate_migration_target`]
+/// guarantees none of these existed beforehand.
+fn remove_copied_entries(source: &Path, destination: &Path) {
+    let Ok(entries) = fs::read_dir(source) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let target = destination.join(entry.file_name());
+        if !target.exists() {
+            continue;
+        }
+        let removed = if target.is_dir() {
+            fs::remove_dir_all(&target)
+        } else {
+            fs::remove_file(&target)
+        };
+        if let Err(e) = removed {
+            warn!("Failed to remove {}: {}", target.display(), e);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn populate(dir: &Path) {
+        fs::create_dir_all(dir.join("Road Trip")).unwrap();
+        fs::write(dir.join("Road Trip").join("song.mp3"), b"audio data").unwrap();
+        fs::write(dir.join("Road Trip").join("playlist.json"), b"{}").unwrap();
+        fs::create_dir_all(dir.join("Chill").join("nested")).unwrap();
+        fs::write(
+            dir.join("Chill").join("nested").join("a.mp3"),
+            b"more audio",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_library_space_within_limits() {
+        let space = LibrarySpace {
+            library_bytes: 500,
+            available_bytes: Some(10_000),
+            limits: LibraryLimits {
+                max_library_size_bytes: Some(1000),
+                min_free_space_bytes: 1000,
+            },
+        };
+        assert!(space.check().is_ok());
+    }
+
+    #[test]
+    fn test_library_space_limit_reached() {
+        let space = LibrarySpace {
+            library_bytes: 1000,
+            available_bytes: Some(10_000),
+            limits: LibraryLimits {
+                max_library_size_bytes: Some(1000),
+                min_free_space_bytes: 0,
+            },
+        };
+        let err = space.check().expect_err("limit should be enforced");
+        assert!(err.is_library_space_low());
+        assert!(err.to_string().contains("library size limit"));
+    }
+
+    #[test]
+    fn test_library_space_low_disk() {
+        let mut space = LibrarySpace {
+            library_bytes: 0,
+            available_bytes: Some(100),
+            limits: LibraryLimits::default(),
+        };
+        let err = space.check().expect_err("free space should be enforced");
+        assert!(err.to_string().contains("free disk space"));
+
+        // Unknown free space and a disabled minimum both pass
+        space.available_bytes = None;
+        assert!(space.check().is_ok());
+        space.available_bytes = Some(0);
+        space.limits.min_free_space_bytes = 0;
+        assert!(space.check().is_ok());
+    }
+
+    #[test]
+    fn test_directory_size() {
+        let dir = TempDir::new().unwrap();
+        populate(dir.path());
+        assert_eq!(directory_size(dir.path()), 22);
+        assert_eq!(directory_size(&dir.path().join("missing")), 0);
+    }
+
+    #[test]
+    fn test_library_limits_serde_defaults() {
+        let limits: LibraryLimits = serde_json::from_str("{}").unwrap();
+        assert_eq!(limits, LibraryLimits::default());
+    }
+
+    #[test]
+    fn test_migrate_copies_and_verifies() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        populate(src.path());
+
+        let mut phases = Vec::new();
+        let result =
+            migrate_storage_directory(src.path(), dst.path(), &AtomicBool::new(false), |p| {
+                phases.push(p.phase)
+            })
+            .unwrap();
+
+        assert_eq!(result.files_copied, 3);
+        assert_eq!(result.bytes_copied, 22);
+        assert_eq!(
+            fs::read(dst.path().join("Chill").join("nested").join("a.mp3")).unwrap(),
+            b"more audio"
+        );
+        // Source is left intact.
+        assert!(src.path().join("Road Trip").join("song.mp3").exists());
+        assert_eq!(phases.first(), Some(&StorageMigrationPhase::Scanning));
+        assert!(phases.contains(&StorageMigrationPhase::Verifying));
+        assert_eq!(phases.last(), Some(&StorageMigrationPhase::Completed));
+    }
+
+    #[test]
+    fn test_migrate_rejects_conflicting_destination() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        populate(src.path());
+        fs::create_dir_all(dst.path().join("Chill")).unwrap();
+
+        let result =
+            migrate_storage_directory(src.path(), dst.path(), &AtomicBool::new(false), |_| {});
+        assert!(matches!(result, Err(Error::Configuration(_))));
+        assert!(!dst.path().join("Road Trip").exists());
+    }
+
+    #[test]
+    fn test_migrate_rejects_nested_destination() {
+        let src = TempDir::new().unwrap();
+        populate(src.path());
+
+        let nested = src.path().join("Road Trip").join("inner");
+        assert!(validate_migration_target(src.path(), &nested).is_err());
+        assert!(validate_migration_target(src.path(), src.path()).is_err());
+    }
+
+    #[test]
+    fn test_migrate_cancelled_cleans_up() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        populate(src.path());
+
+        let result =
+            migrate_storage_directory(src.path(), dst.path(), &AtomicBool::new(true), |_| {});
+        assert!(matches!(result, Err(Error::Cancelled)));
+        assert_eq!(fs::read_dir(dst.path()).unwrap().count(), 0);
+    }
+}
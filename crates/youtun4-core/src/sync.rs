@@ -105,6 +105,12 @@ pub struct SyncOptions {
 
     /// Whether to preserve existing files on device that match source files.
     pub skip_existing_matches: bool,
+
+    /// Device files, relative to the mount point, to leave as they are: the
+    /// cleanup phase doesn't delete them and the transfer doesn't overwrite
+    /// them.
+    #[serde(default)]
+    pub exclusions: Vec<PathBuf>,
 }
 
 impl Default for SyncOptions {
@@ -116,6 +122,7 @@ impl Default for SyncOptions {
             verify_device_between_phases: true,
             abort_on_cleanup_failure: true,
             skip_existing_matches: true,
+            exclusions: Vec::new(),
         }
     }
 }
@@ -131,6 +138,7 @@ impl SyncOptions {
             verify_device_between_phases: false,
             abort_on_cleanup_failure: true,
             skip_existing_matches: true,
+            exclusions: Vec::new(),
         }
     }
 
@@ -144,6 +152,7 @@ impl SyncOptions {
             verify_device_between_phases: true,
             abort_on_cleanup_failure: true,
             skip_existing_matches: false, // Re-transfer everything for verification
+            exclusions: Vec::new(),
         }
     }
 
@@ -157,6 +166,7 @@ impl SyncOptions {
             verify_device_between_phases: false,
             abort_on_cleanup_failure: false,
             skip_existing_matches: true,
+            exclusions: Vec::new(),
         }
     }
 
@@ -180,6 +190,29 @@ impl SyncOptions {
         self.transfer_options = options;
         self
     }
+
+    /// Set the device files to leave as they are.
+    #[must_use]
+    pub fn with_exclusions(mut self, exclusions: Vec<PathBuf>) -> Self {
+        self.exclusions = exclusions;
+        self
+    }
+
+    /// Cleanup options that also keep the excluded files.
+    fn effective_cleanup_options(&self) -> CleanupOptions {
+        self.cleanup_options
+            .clone()
+            .with_protected_paths(self.exclusions.iter().cloned())
+    }
+
+    /// Transfer options that also keep the excluded files.
+    fn effective_transfer_options(&self) -> TransferOptions {
+        let mut options = self.transfer_options.clone();
+        options
+            .keep_existing
+            .extend(self.exclusions.iter().cloned());
+        options
+    }
 }
 
 // =============================================================================
@@ -484,6 +517,146 @@ impl SyncResult {
     }
 }
 
+// =============================================================================
+// Sync Preview
+// =============================================================================
+
+/// What a sync would do to one file on the device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncChange {
+    /// The track isn't on the device and will be copied.
+    Add,
+    /// The track is on the device and will be copied again.
+    Replace,
+    /// The track is already on the device and will be kept.
+    Unchanged,
+    /// The file is on the device but in no selected playlist, and will be
+    /// deleted by the cleanup phase.
+    Remove,
+}
+
+/// One file in a [`SyncPreview`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncPreviewEntry {
+    /// Playlist the track comes from, or `None` for files being removed.
+    pub playlist: Option<String>,
+    /// File path relative to the device mount point.
+    pub file_name: String,
+    /// File size in bytes (the device copy's size for removals).
+    pub size_bytes: u64,
+    /// What the sync will do with the file.
+    pub change: SyncChange,
+}
+
+/// Preview of the changes a sync would make to a device, without making them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncPreview {
+    /// Every file the sync touches or keeps, in playlist order followed by
+    /// removals.
+    pub entries: Vec<SyncPreviewEntry>,
+    /// Bytes that will be written to the device.
+    pub bytes_to_write: u64,
+    /// Bytes that will be deleted from the device.
+    pub bytes_to_remove: u64,
+    /// Bytes of existing device copies that replaced tracks overwrite.
+    pub bytes_replaced: u64,
+}
+
+impl SyncPreview {
+    /// Number of entries with the given change.
+    #[must_use]
+    pub fn count(&self, change: SyncChange) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.change == change)
+            .count()
+    }
+}
+
+/// Work out what syncing `request` with `options` would change on the device.
+///
+/// With cleanup enabled every existing file is deleted first, so tracks
+/// already on the device are copied again; without it, tracks whose size
+/// matches are kept when `skip_existing` is set. Excluded files are always
+/// kept.
+///
+/// # Errors
+///
+/// Returns an error if a playlist doesn't exist or the device can't be read.
+pub fn preview_sync(
+    playlist_manager: &PlaylistManager,
+    request: &SyncRequest,
+    options: &SyncOptions,
+) -> Result<SyncPreview> {
+    let mount_point = &request.device_mount_point;
+    let mut preview = SyncPreview::default();
+    let mut incoming = std::collections::HashSet::new();
+
+    for playlist in &request.playlists {
+        for track in playlist_manager.list_tracks(playlist)? {
+            let existing_size = std::fs::metadata(mount_point.join(&track.file_name))
+                .ok()
+                .filter(std::fs::Metadata::is_file)
+                .map(|meta| meta.len());
+            let excluded = options
+                .exclusions
+                .iter()
+                .any(|kept| kept.as_path() == Path::new(&track.file_name));
+            let change = match existing_size {
+                None => SyncChange::Add,
+                Some(_) if excluded => SyncChange::Unchanged,
+                Some(size)
+                    if !options.cleanup_enabled
+                        && options.transfer_options.skip_existing
+                        && size == track.size_bytes =>
+                {
+                    SyncChange::Unchanged
+                }
+                Some(size) => {
+                    preview.bytes_replaced += size;
+                    SyncChange::Replace
+                }
+            };
+            if change != SyncChange::Unchanged {
+                preview.bytes_to_write += track.size_bytes;
+            }
+            incoming.insert(mount_point.join(&track.file_name));
+            preview.entries.push(SyncPreviewEntry {
+                playlist: Some(playlist.clone()),
+                file_name: track.file_name,
+                size_bytes: track.size_bytes,
+                change,
+            });
+        }
+    }
+
+    if options.cleanup_enabled {
+        let cleanup = DeviceCleanupHandler::new()
+            .preview_cleanup(mount_point, &options.effective_cleanup_options())?;
+        for entry in cleanup.entries {
+            // Replaced tracks are already listed
+            if entry.is_directory || incoming.contains(&entry.path) {
+                continue;
+            }
+            preview.bytes_to_remove += entry.size_bytes;
+            preview.entries.push(SyncPreviewEntry {
+                playlist: None,
+                file_name: entry
+                    .path
+                    .strip_prefix(mount_point)
+                    .unwrap_or(&entry.path)
+                    .display()
+                    .to_string(),
+                size_bytes: entry.size_bytes,
+                change: SyncChange::Remove,
+            });
+        }
+    }
+
+    Ok(preview)
+}
+
 // =============================================================================
 // Sync Orchestrator
 // =============================================================================
@@ -742,7 +915,7 @@ impl SyncOrchestrator {
         match self.cleanup_device(
             device_detector,
             &request.device_mount_point,
-            &options.cleanup_options,
+            &options.effective_cleanup_options(),
         ) {
             Ok(cleanup_result) => {
                 info!(
@@ -885,7 +1058,7 @@ impl SyncOrchestrator {
         let transfer_result = transfer_engine.transfer_playlist(
             &playlist_path,
             &request.device_mount_point,
-            &options.transfer_options,
+            &options.effective_transfer_options(),
             None::<fn(&TransferProgress)>,
         );
 
@@ -1141,6 +1314,100 @@ mod tests {
         (manager, playlists_dir, device_dir)
     }
 
+    #[test]
+    fn test_preview_sync() {
+        let (manager, _playlists_dir, device_dir) = setup_test_environment();
+        let playlist_path = manager
+            .create_playlist("Preview", None)
+            .expect("create playlist");
+        fs::write(playlist_path.join("new.mp3"), b"new track").expect("write");
+        fs::write(playlist_path.join("same.mp3"), b"same").expect("write");
+        fs::write(playlist_path.join("changed.mp3"), b"changed track").expect("write");
+        fs::write(device_dir.path().join("same.mp3"), b"same").expect("write");
+        fs::write(device_dir.path().join("changed.mp3"), b"old").expect("write");
+        fs::write(device_dir.path().join("stale.mp3"), b"stale").expect("write");
+
+        let request = SyncRequest::single("Preview", device_dir.path());
+        let change_of = |preview: &SyncPreview, name: &str| {
+            preview
+                .entries
+                .iter()
+                .find(|entry| entry.file_name == name)
+                .map(|entry| entry.change)
+        };
+
+        // Without cleanup, matching tracks are kept and nothing is removed
+        let options = SyncOptions::default().with_cleanup(false);
+        let preview = preview_sync(&manager, &request, &options).expect("preview");
+        assert_eq!(change_of(&preview, "new.mp3"), Some(SyncChange::Add));
+        assert_eq!(change_of(&preview, "same.mp3"), Some(SyncChange::Unchanged));
+        assert_eq!(
+            change_of(&preview, "changed.mp3"),
+            Some(SyncChange::Replace)
+        );
+        assert_eq!(change_of(&preview, "stale.mp3"), None);
+        assert_eq!(preview.bytes_to_write, 9 + 13);
+        assert_eq!(preview.bytes_to_remove, 0);
+        assert_eq!(preview.bytes_replaced, 3);
+
+        // With cleanup, everything is rewritten and stale files are removed
+        let preview = preview_sync(&manager, &request, &SyncOptions::default()).expect("preview");
+        assert_eq!(change_of(&preview, "same.mp3"), Some(SyncChange::Replace));
+        assert_eq!(change_of(&preview, "stale.mp3"), Some(SyncChange::Remove));
+        assert_eq!(preview.count(SyncChange::Remove), 1);
+        assert_eq!(preview.bytes_to_write, 9 + 4 + 13);
+        assert_eq!(preview.bytes_to_remove, 5);
+        assert_eq!(preview.bytes_replaced, 4 + 3);
+
+        let missing = SyncRequest::single("Missing", device_dir.path());
+        assert!(preview_sync(&manager, &missing, &options).is_err());
+    }
+
+    #[test]
+    fn test_sync_with_exclusions() {
+        let (manager, _playlists_dir, device_dir) = setup_test_environment();
+        let playlist_path = manager
+            .create_playlist("Excluded", None)
+            .expect("create playlist");
+        fs::write(playlist_path.join("changed.mp3"), b"changed track").expect("write");
+        fs::write(device_dir.path().join("changed.mp3"), b"old").expect("write");
+        fs::write(device_dir.path().join("stale.mp3"), b"stale").expect("write");
+        fs::write(device_dir.path().join("other.mp3"), b"other").expect("write");
+
+        let request = SyncRequest::single("Excluded", device_dir.path());
+        let options = SyncOptions::default().with_exclusions(vec![
+            PathBuf::from("changed.mp3"),
+            PathBuf::from("stale.mp3"),
+        ]);
+
+        let preview = preview_sync(&manager, &request, &options).expect("preview");
+        assert_eq!(preview.count(SyncChange::Unchanged), 1);
+        assert_eq!(preview.count(SyncChange::Replace), 0);
+        assert_eq!(preview.count(SyncChange::Remove), 1);
+        assert_eq!(preview.bytes_to_write, 0);
+        assert_eq!(preview.bytes_to_remove, 5);
+
+        let detector = MockDeviceDetector::new().with_device(device_dir.path().to_path_buf());
+        let result = SyncOrchestrator::new()
+            .sync(
+                &manager,
+                &detector,
+                request,
+                &options,
+                None::<fn(&SyncProgress)>,
+            )
+            .expect("sync should succeed");
+
+        assert!(result.success);
+        assert_eq!(result.total_files_transferred, 0);
+        assert_eq!(
+            fs::read(device_dir.path().join("changed.mp3")).expect("read"),
+            b"old"
+        );
+        assert!(device_dir.path().join("stale.mp3").exists());
+        assert!(!device_dir.path().join("other.mp3").exists());
+    }
+
     #[test]
     fn test_sync_options_default() {
         let options = SyncOptions::default();
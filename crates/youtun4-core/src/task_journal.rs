@@ -0,0 +1,378 @@
+//! Persistent journal of long-running tasks.
+//!
+//! Tasks are recorded when they start and removed when they finish. Anything
+//! still in the journal when the application starts was interrupted by a
+//! crash or forced quit, and is surfaced so the user can resume it or mark it
+//! failed instead of it being silently forgotten.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::config::AppConfig;
+use crate::error::{Error, Result};
+use crate::queue::DownloadRequest;
+
+/// File name of the task journal, stored next to the config file.
+pub const TASK_JOURNAL_FILE: &str = "tasks.json";
+
+/// Identifier of a journaled task, stable across restarts.
+pub type JournalTaskId = u64;
+
+/// What a journaled task was doing, with the inputs needed to resume it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PersistedTaskKind {
+    /// Download of a `YouTube` playlist into a directory.
+    PlaylistDownload {
+        /// Playlist URL.
+        url: String,
+        /// Output directory.
+        output_dir: PathBuf,
+    },
+    /// Download of a `YouTube` playlist into a library playlist.
+    LibraryDownload {
+        /// Playlist URL.
+        url: String,
+        /// Name of the library playlist.
+        playlist_name: String,
+    },
+    /// Download started from the download queue.
+    QueueDownload {
+        /// The queued request.
+        request: DownloadRequest,
+    },
+    /// Sync of a playlist to a device.
+    Sync {
+        /// Playlist being synced.
+        playlist_name: String,
+        /// Device mount point.
+        device_mount_point: PathBuf,
+        /// Whether the sync uses integrity verification.
+        verify_integrity: bool,
+        /// Whether to skip existing files.
+        skip_existing: bool,
+    },
+}
+
+impl PersistedTaskKind {
+    /// Task category name, matching the runtime's category names.
+    #[must_use]
+    pub const fn category(&self) -> &'static str {
+        match self {
+            Self::PlaylistDownload { .. }
+            | Self::LibraryDownload { .. }
+            | Self::QueueDownload { .. } => "download",
+            Self::Sync { .. } => "file_transfer",
+        }
+    }
+}
+
+/// Status of a journaled task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PersistedTaskStatus {
+    /// Running in this session.
+    Running,
+    /// Was running when the application last stopped.
+    Interrupted,
+}
+
+/// A task descriptor stored in the journal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistedTask {
+    /// Journal identifier.
+    pub id: JournalTaskId,
+    /// What the task was doing.
+    pub kind: PersistedTaskKind,
+    /// Human-readable description.
+    pub description: String,
+    /// Current status.
+    pub status: PersistedTaskStatus,
+    /// When the task started (Unix seconds).
+    pub started_at: u64,
+}
+
+/// Persistent task journal.
+///
+/// Shared via `Arc`; each running task holds a [`TrackedTask`] that removes
+/// its entry when dropped.
+#[derive(Debug)]
+pub struct TaskJournal {
+    path: PathBuf,
+    tasks: Mutex<BTreeMap<JournalTaskId, PersistedTask>>,
+}
+
+impl TaskJournal {
+    /// Open the journal at `path`, marking every entry left from a previous
+    /// session as interrupted.
+    ///
+    /// A missing file yields an empty journal; an unreadable one is logged
+    /// and replaced.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let mut tasks = Self::load(&path);
+        for task in tasks.values_mut() {
+            task.status = PersistedTaskStatus::Interrupted;
+        }
+        if !tasks.is_empty() {
+            info!("Found {} interrupted task(s) in journal", tasks.len());
+        }
+
+        let journal = Self {
+            path,
+            tasks: Mutex::new(tasks),
+        };
+        journal.with_tasks(|_| ((), true))?;
+        Ok(journal)
+    }
+
+    /// Open the journal in its default location next to the config file.
+    pub fn open_default() -> Result<Self> {
+        let config_path = AppConfig::config_file_path();
+        let dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+        Self::open(dir.join(TASK_JOURNAL_FILE))
+    }
+
+    fn load(path: &Path) -> BTreeMap<JournalTaskId, PersistedTask> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return BTreeMap::new(),
+            Err(e) => {
+                warn!("Failed to read task journal {}: {}", path.display(), e);
+                return BTreeMap::new();
+            }
+        };
+        match serde_json::from_str::<Vec<PersistedTask>>(&contents) {
+            Ok(tasks) => tasks.into_iter().map(|task| (task.id, task)).collect(),
+            Err(e) => {
+                warn!("Discarding corrupt task journal {}: {}", path.display(), e);
+                BTreeMap::new()
+            }
+        }
+    }
+
+    fn save(&self, tasks: &BTreeMap<JournalTaskId, PersistedTask>) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let list: Vec<&PersistedTask> = tasks.values().collect();
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(&list)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    /// Run `f` on the journal entries, persisting them if `f` returns `true`.
+    fn with_tasks<T>(
+        &self,
+        f: impl FnOnce(&mut BTreeMap<JournalTaskId, PersistedTask>) -> (T, bool),
+    ) -> Result<T> {
+        let mut tasks = self
+            .tasks
+            .lock()
+            .map_err(|_| Error::internal("Task journal lock poisoned"))?;
+        let (result, changed) = f(&mut tasks);
+        if changed {
+            self.save(&tasks)?;
+        }
+        Ok(result)
+    }
+
+    /// Record a task as running. The entry is removed when the returned
+    /// [`TrackedTask`] is dropped.
+    pub fn track(
+        self: &Arc<Self>,
+        kind: PersistedTaskKind,
+        description: impl Into<String>,
+    ) -> Result<TrackedTask> {
+        let description = description.into();
+        let id = self.with_tasks(|tasks| {
+            let id = tasks.keys().next_back().map_or(0, |last| last + 1);
+            tasks.insert(
+                id,
+                PersistedTask {
+                    id,
+                    kind,
+                    description,
+                    status: PersistedTaskStatus::Running,
+                    started_at: unix_now(),
+                },
+            );
+            (id, true)
+        })?;
+        debug!("Journaled task {}", id);
+
+        Ok(TrackedTask {
+            journal: Arc::clone(self),
+            id,
+        })
+    }
+
+    /// Tasks interrupted in a previous session, oldest first.
+    #[must_use]
+    pub fn interrupted(&self) -> Vec<PersistedTask> {
+        self.with_status(PersistedTaskStatus::Interrupted)
+    }
+
+    /// Tasks running in this session, oldest first.
+    #[must_use]
+    pub fn running(&self) -> Vec<PersistedTask> {
+        self.with_status(PersistedTaskStatus::Running)
+    }
+
+    fn with_status(&self, status: PersistedTaskStatus) -> Vec<PersistedTask> {
+        self.with_tasks(|tasks| {
+            let matching = tasks
+                .values()
+                .filter(|task| task.status == status)
+                .cloned()
+                .collect();
+            (matching, false)
+        })
+        .unwrap_or_default()
+    }
+
+    /// Remove an interrupted task from the journal, returning it.
+    ///
+    /// Returns `None` if no interrupted task has this ID.
+    pub fn resolve(&self, id: JournalTaskId) -> Result<Option<PersistedTask>> {
+        self.with_tasks(|tasks| {
+            if tasks
+                .get(&id)
+                .is_some_and(|task| task.status == PersistedTaskStatus::Interrupted)
+            {
+                (tasks.remove(&id), true)
+            } else {
+                (None, false)
+            }
+        })
+    }
+
+    fn finish(&self, id: JournalTaskId) -> Result<()> {
+        self.with_tasks(|tasks| ((), tasks.remove(&id).is_some()))
+    }
+}
+
+/// A running task recorded in the journal.
+///
+/// Dropping it removes the entry, so a task only remains in the journal if
+/// the process stops before the task ends.
+#[derive(Debug)]
+pub struct TrackedTask {
+    journal: Arc<TaskJournal>,
+    id: JournalTaskId,
+}
+
+impl TrackedTask {
+    /// Journal identifier of this task.
+    #[must_use]
+    pub const fn id(&self) -> JournalTaskId {
+        self.id
+    }
+}
+
+impl Drop for TrackedTask {
+    fn drop(&mut self) {
+        if let Err(e) = self.journal.finish(self.id) {
+            warn!("Failed to remove task {} from journal: {}", self.id, e);
+        }
+    }
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn download(url: &str) -> PersistedTaskKind {
+        PersistedTaskKind::LibraryDownload {
+            url: url.to_string(),
+            playlist_name: "Mix".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_finished_tasks_leave_journal() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(TASK_JOURNAL_FILE);
+        let journal = Arc::new(TaskJournal::open(&path).unwrap());
+
+        let tracked = journal.track(download("a"), "Download a").unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("Download a"));
+        assert_eq!(journal.running().len(), 1);
+        drop(tracked);
+        assert!(journal.running().is_empty());
+
+        let reopened = TaskJournal::open(&path).unwrap();
+        assert!(reopened.interrupted().is_empty());
+    }
+
+    #[test]
+    fn test_unfinished_tasks_are_interrupted_on_reopen() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(TASK_JOURNAL_FILE);
+        let journal = Arc::new(TaskJournal::open(&path).unwrap());
+
+        let tracked = journal.track(download("a"), "Download a").unwrap();
+        let id = tracked.id();
+        // Simulate a crash: the entry is never removed.
+        std::mem::forget(tracked);
+        assert!(journal.interrupted().is_empty());
+
+        let reopened = Arc::new(TaskJournal::open(&path).unwrap());
+        let interrupted = reopened.interrupted();
+        assert!(reopened.running().is_empty());
+        assert_eq!(interrupted.len(), 1);
+        assert_eq!(interrupted[0].id, id);
+        assert_eq!(interrupted[0].kind, download("a"));
+        assert_eq!(interrupted[0].status, PersistedTaskStatus::Interrupted);
+
+        // New tasks don't reuse the interrupted task's ID.
+        let next = reopened.track(download("b"), "Download b").unwrap();
+        assert_ne!(next.id(), id);
+    }
+
+    #[test]
+    fn test_resolve_removes_only_interrupted_tasks() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(TASK_JOURNAL_FILE);
+        std::mem::forget(
+            Arc::new(TaskJournal::open(&path).unwrap())
+                .track(download("a"), "Download a")
+                .unwrap(),
+        );
+
+        let journal = Arc::new(TaskJournal::open(&path).unwrap());
+        let running = journal.track(download("b"), "Download b").unwrap();
+        let interrupted_id = journal.interrupted()[0].id;
+
+        assert!(journal.resolve(running.id()).unwrap().is_none());
+        let resolved = journal.resolve(interrupted_id).unwrap().unwrap();
+        assert_eq!(resolved.kind, download("a"));
+        assert!(journal.resolve(interrupted_id).unwrap().is_none());
+        assert!(journal.interrupted().is_empty());
+    }
+
+    #[test]
+    fn test_corrupt_journal_is_discarded() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(TASK_JOURNAL_FILE);
+        fs::write(&path, "not json").unwrap();
+
+        let journal = TaskJournal::open(&path).unwrap();
+        assert!(journal.interrupted().is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+//! Bandwidth throttling for downloads.
+//!
+//! A [`RateLimiter`] is a token bucket handing out bytes at a fixed rate.
+//! The bucket holds up to one second of tokens, so short bursts go through
+//! at full speed while the average stays at the limit. All videos of a
+//! download share one limiter, so videos downloaded at the same time split
+//! the bandwidth instead of each getting the full limit.
+
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Token-bucket limiter for a download speed in bytes per second.
+#[derive(Debug)]
+pub struct RateLimiter {
+    bytes_per_sec: u64,
+    bucket: Mutex<Bucket>,
+}
+
+/// Tokens left in the bucket. Negative when chunks were taken on credit.
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `bytes_per_sec` bytes per second (at least 1).
+    #[must_use]
+    pub fn new(bytes_per_sec: u64) -> Self {
+        let bytes_per_sec = bytes_per_sec.max(1);
+        Self {
+            bytes_per_sec,
+            bucket: Mutex::new(Bucket {
+                tokens: bytes_per_sec as f64,
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// The speed limit in bytes per second.
+    #[must_use]
+    pub const fn bytes_per_sec(&self) -> u64 {
+        self.bytes_per_sec
+    }
+
+    /// Take `bytes` tokens, returning how long to wait before using them.
+    #[must_use]
+    pub fn reserve(&self, bytes: u64) -> Duration {
+        self.reserve_at(bytes, Instant::now())
+    }
+
+    fn reserve_at(&self, bytes: u64, now: Instant) -> Duration {
+        let rate = self.bytes_per_sec as f64;
+        let mut bucket = self.bucket.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        bucket.tokens = elapsed.as_secs_f64().mul_add(rate, bucket.tokens).min(rate);
+        bucket.refilled_at = bucket.refilled_at.max(now);
+        bucket.tokens -= bytes as f64;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / rate)
+        }
+    }
+
+    /// Wait until `bytes` more bytes may be downloaded.
+    pub async fn acquire(&self, bytes: u64) {
+        let wait = self.reserve(bytes);
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_within_one_second_is_free() {
+        let limiter = RateLimiter::new(1000);
+        let start = Instant::now();
+        assert_eq!(limiter.reserve_at(600, start), Duration::ZERO);
+        assert_eq!(limiter.reserve_at(400, start), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_excess_waits_at_the_limit() {
+        let limiter = RateLimiter::new(1000);
+        let start = Instant::now();
+        assert_eq!(limiter.reserve_at(1000, start), Duration::ZERO);
+        assert_eq!(limiter.reserve_at(500, start), Duration::from_millis(500));
+        // The debt carries over until it is paid back by elapsed time
+        assert_eq!(
+            limiter.reserve_at(500, start + Duration::from_millis(500)),
+            Duration::from_millis(500)
+        );
+    }
+
+    #[test]
+    fn test_idle_time_refills_up_to_one_second() {
+        let limiter = RateLimiter::new(1000);
+        let start = Instant::now();
+        assert_eq!(limiter.reserve_at(1000, start), Duration::ZERO);
+        let later = start + Duration::from_secs(10);
+        assert_eq!(limiter.reserve_at(1000, later), Duration::ZERO);
+        assert_eq!(limiter.reserve_at(250, later), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_zero_rate_is_clamped() {
+        let limiter = RateLimiter::new(0);
+        assert_eq!(limiter.bytes_per_sec(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_acquire_sleeps_for_the_excess() {
+        let limiter = RateLimiter::new(1000);
+        let start = tokio::time::Instant::now();
+        limiter.acquire(1000).await;
+        limiter.acquire(2000).await;
+        let waited = start.elapsed();
+        assert!(waited >= Duration::from_millis(1990), "{waited:?}");
+    }
+}
@@ -4,11 +4,16 @@
 //! - Fetch thumbnails from `YouTube` URLs
 //! - Cache thumbnails locally
 //! - Generate playlist thumbnails from first video
+//! - Resolve a thumbnail URL or local image to something the UI can show
 //!
-//! Thumbnails are cached using the cache module infrastructure.
+//! Thumbnails are cached using the cache module infrastructure, so they are
+//! evicted by its cleanup policy like any other cache entry.
 
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tracing::{debug, info, warn};
 
 use crate::cache::CacheManager;
@@ -17,10 +22,26 @@ use crate::error::{Error, Result};
 /// Default timeout for thumbnail fetch requests.
 pub const DEFAULT_FETCH_TIMEOUT_SECS: u64 = 30;
 
+/// Extensions of local files accepted as thumbnails.
+const IMAGE_EXTENSIONS: [&str; 5] = ["jpg", "jpeg", "png", "webp", "gif"];
+
+/// A thumbnail ready to be displayed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum CachedThumbnail {
+    /// A local image file: the cached thumbnail, or the local image asked
+    /// for.
+    File(PathBuf),
+    /// The image as a base64 `data:` URL, when thumbnails aren't cached on
+    /// disk.
+    DataUrl(String),
+}
+
 /// Thumbnail manager for fetching and caching playlist/video thumbnails.
 pub struct ThumbnailManager<'a> {
     cache: &'a mut CacheManager,
     timeout: Duration,
+    offline: bool,
 }
 
 impl<'a> ThumbnailManager<'a> {
@@ -29,6 +50,7 @@ impl<'a> ThumbnailManager<'a> {
         Self {
             cache,
             timeout: Duration::from_secs(DEFAULT_FETCH_TIMEOUT_SECS),
+            offline: false,
         }
     }
 
@@ -39,6 +61,16 @@ impl<'a> ThumbnailManager<'a> {
         self
     }
 
+    /// Enable or disable offline mode.
+    ///
+    /// When offline, only cached thumbnails are returned and no network
+    /// requests are made.
+    #[must_use]
+    pub const fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
     /// Fetch a thumbnail from URL and cache it.
     ///
     /// Returns the cached thumbnail data if successful.
@@ -50,7 +82,8 @@ impl<'a> ThumbnailManager<'a> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the fetch fails or caching fails.
+    /// Returns an error if the fetch fails or caching fails, or an offline
+    /// error if offline mode is enabled and the thumbnail is not cached.
     pub fn fetch_and_cache(&mut self, id: &str, url: &str) -> Result<Vec<u8>> {
         // Check if already cached
         if let Ok(Some(data)) = self.cache.get_thumbnail(id) {
@@ -58,6 +91,10 @@ impl<'a> ThumbnailManager<'a> {
             return Ok(data);
         }
 
+        if self.offline {
+            return Err(Error::offline(format!("fetching thumbnail for {id}")));
+        }
+
         info!("Fetching thumbnail for {} from {}", id, url);
 
         // Fetch the thumbnail
@@ -70,6 +107,39 @@ impl<'a> ThumbnailManager<'a> {
         Ok(data)
     }
 
+    /// Resolve `path_or_url` to a thumbnail the UI can display.
+    ///
+    /// `http(s)` URLs are fetched once and then served from the cache, under
+    /// the same ID as playlist covers with that URL. Anything else is taken
+    /// as the path of a local image.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL can't be fetched (or, offline, isn't
+    /// cached), or the path isn't an existing image file.
+    pub fn resolve(&mut self, path_or_url: &str) -> Result<CachedThumbnail> {
+        if path_or_url.starts_with("http://") || path_or_url.starts_with("https://") {
+            let id = cover_thumbnail_id(path_or_url);
+            let data = self.fetch_and_cache(&id, path_or_url)?;
+            return Ok(self.get_thumbnail_path(&id).map_or_else(
+                || CachedThumbnail::DataUrl(data_url(&data)),
+                CachedThumbnail::File,
+            ));
+        }
+
+        let path = Path::new(path_or_url);
+        let is_image = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_lowercase().as_str()));
+        if !is_image || !path.is_file() {
+            return Err(Error::Configuration(format!(
+                "Not a thumbnail URL or image file: {path_or_url}"
+            )));
+        }
+        Ok(CachedThumbnail::File(path.to_path_buf()))
+    }
+
     /// Get a cached thumbnail without fetching.
     ///
     /// Returns `None` if not cached.
@@ -135,6 +205,42 @@ fn fetch_thumbnail_data(url: &str, timeout: Duration) -> Result<Vec<u8>> {
     Ok(data.to_vec())
 }
 
+/// `data:` URL embedding the image `data`, typed from its magic bytes.
+fn data_url(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mime = if data.starts_with(b"\x89PNG") {
+        "image/png"
+    } else if data.starts_with(b"RIFF") && data.get(8..12) == Some(b"WEBP") {
+        "image/webp"
+    } else if data.starts_with(b"GIF8") {
+        "image/gif"
+    } else {
+        "image/jpeg"
+    };
+
+    let mut url = format!("data:{mime};base64,");
+    url.reserve(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let group = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                url.push(char::from(
+                    ALPHABET[(group >> (18 - 6 * i)) as usize & 0x3F],
+                ));
+            } else {
+                url.push('=');
+            }
+        }
+    }
+    url
+}
+
 /// Generate a `YouTube` thumbnail URL for a video ID.
 ///
 /// Returns the high-quality thumbnail URL (hqdefault).
@@ -168,6 +274,17 @@ pub fn get_playlist_thumbnail_url(
         .or_else(|| first_video_id.map(youtube_thumbnail_url))
 }
 
+/// Cache ID for a playlist cover image, derived from its URL.
+///
+/// Covers are cached under their URL so playlists sharing a cover share the
+/// cached file, and renaming a playlist keeps its cover.
+#[must_use]
+pub fn cover_thumbnail_id(url: &str) -> String {
+    let digest = Sha256::digest(url.as_bytes());
+    let hex: String = digest[..8].iter().map(|b| format!("{b:02x}")).collect();
+    format!("cover_{hex}")
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used)]
 mod tests {
@@ -208,4 +325,91 @@ mod tests {
         let url = get_playlist_thumbnail_url(None, None);
         assert_eq!(url, None);
     }
+
+    #[test]
+    fn test_cover_thumbnail_id() {
+        let id = cover_thumbnail_id("https://i.ytimg.com/vi/abc123/hqdefault.jpg");
+        assert!(id.starts_with("cover_"));
+        assert_eq!(id.len(), "cover_".len() + 16);
+        assert_eq!(
+            id,
+            cover_thumbnail_id("https://i.ytimg.com/vi/abc123/hqdefault.jpg")
+        );
+        assert_ne!(
+            id,
+            cover_thumbnail_id("https://i.ytimg.com/vi/xyz789/hqdefault.jpg")
+        );
+    }
+
+    fn create_test_cache(temp_dir: &tempfile::TempDir) -> CacheManager {
+        let config = crate::cache::CacheConfig::new().with_cache_dir(temp_dir.path().to_path_buf());
+        CacheManager::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_data_url() {
+        assert_eq!(data_url(b"\xFF\xD8\xFF"), "data:image/jpeg;base64,/9j/");
+        assert_eq!(data_url(b"\x89PNG"), "data:image/png;base64,iVBORw==");
+        assert_eq!(data_url(b"GIF89"), "data:image/gif;base64,R0lGODk=");
+    }
+
+    #[test]
+    fn test_resolve_cached_url_and_local_image() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let url = "https://i.ytimg.com/vi/abc123/hqdefault.jpg";
+        let mut cache = create_test_cache(&temp_dir);
+        cache
+            .put_thumbnail(&cover_thumbnail_id(url), b"image data")
+            .unwrap();
+
+        let mut manager = ThumbnailManager::new(&mut cache).with_offline(true);
+        let path = manager
+            .get_thumbnail_path(&cover_thumbnail_id(url))
+            .unwrap();
+        assert_eq!(
+            manager.resolve(url).unwrap(),
+            CachedThumbnail::File(path.clone())
+        );
+        assert_eq!(std::fs::read(&path).unwrap(), b"image data");
+
+        // Local images are used in place; other files are refused
+        let image = temp_dir.path().join("cover.PNG");
+        std::fs::write(&image, b"png").unwrap();
+        assert_eq!(
+            manager.resolve(&image.to_string_lossy()).unwrap(),
+            CachedThumbnail::File(image)
+        );
+        let text = temp_dir.path().join("notes.txt");
+        std::fs::write(&text, b"text").unwrap();
+        assert!(manager.resolve(&text.to_string_lossy()).is_err());
+        assert!(manager.resolve("/nonexistent/cover.jpg").is_err());
+    }
+
+    #[test]
+    fn test_offline_returns_cached_thumbnail() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut cache = create_test_cache(&temp_dir);
+        cache.put_thumbnail("abc123", b"image data").unwrap();
+
+        let mut manager = ThumbnailManager::new(&mut cache).with_offline(true);
+        let data = manager
+            .fetch_and_cache("abc123", "https://img.youtube.com/vi/abc123/hqdefault.jpg")
+            .unwrap();
+        assert_eq!(data, b"image data");
+    }
+
+    #[test]
+    fn test_offline_uncached_thumbnail_returns_offline_error() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut cache = create_test_cache(&temp_dir);
+
+        let mut manager = ThumbnailManager::new(&mut cache).with_offline(true);
+        let err = manager
+            .fetch_and_cache(
+                "missing",
+                "https://img.youtube.com/vi/missing/hqdefault.jpg",
+            )
+            .unwrap_err();
+        assert!(err.is_offline());
+    }
 }
@@ -37,6 +37,7 @@ use sha2::{Digest, Sha256};
 use tracing::{debug, error, info, warn};
 
 use crate::error::{Error, FileSystemError, Result, TransferError};
+use crate::metrics;
 use crate::playlist::is_audio_file;
 
 // =============================================================================
@@ -103,6 +104,12 @@ pub struct TransferOptions {
     /// Delay between retry attempts.
     /// Default: 1 second
     pub retry_delay: Duration,
+
+    /// Destination paths, relative to the destination directory, that are
+    /// left untouched when they already exist.
+    /// Default: empty
+    #[serde(default)]
+    pub keep_existing: Vec<PathBuf>,
 }
 
 impl Default for TransferOptions {
@@ -117,6 +124,7 @@ impl Default for TransferOptions {
             continue_on_error: true,
             max_retries: 3,
             retry_delay: Duration::from_secs(1),
+            keep_existing: Vec::new(),
         }
     }
 }
@@ -524,6 +532,18 @@ impl TransferEngine {
             progress.current_file_bytes = 0;
             progress.current_file_total = item.size_bytes;
 
+            // Leave files the caller chose to keep as they are
+            if item.destination.exists()
+                && options
+                    .keep_existing
+                    .iter()
+                    .any(|kept| destination_dir.join(kept) == item.destination)
+            {
+                debug!("Keeping existing file: {}", item.destination.display());
+                Self::record_skipped(item, &mut result, &mut progress);
+                continue;
+            }
+
             // Check if file should be skipped
             if options.skip_existing
                 && item.destination.exists()
@@ -538,20 +558,7 @@ impl TransferEngine {
 
                 if checksums_match {
                     debug!("Skipping existing file: {}", item.destination.display());
-                    result.files_skipped += 1;
-                    result.bytes_skipped += item.size_bytes;
-                    progress.files_skipped += 1;
-                    progress.total_bytes_transferred += item.size_bytes;
-
-                    result.transferred_files.push(TransferredFile {
-                        source: item.source.clone(),
-                        destination: item.destination.clone(),
-                        size_bytes: item.size_bytes,
-                        checksum: None,
-                        duration_secs: 0.0,
-                        skipped: true,
-                    });
-
+                    Self::record_skipped(item, &mut result, &mut progress);
                     continue;
                 }
             }
@@ -671,6 +678,27 @@ impl TransferEngine {
         Ok(result)
     }
 
+    /// Count `item` as skipped, leaving the destination as it is.
+    fn record_skipped(
+        item: &TransferItem,
+        result: &mut TransferResult,
+        progress: &mut TransferProgress,
+    ) {
+        result.files_skipped += 1;
+        result.bytes_skipped += item.size_bytes;
+        progress.files_skipped += 1;
+        progress.total_bytes_transferred += item.size_bytes;
+
+        result.transferred_files.push(TransferredFile {
+            source: item.source.clone(),
+            destination: item.destination.clone(),
+            size_bytes: item.size_bytes,
+            checksum: None,
+            duration_secs: 0.0,
+            skipped: true,
+        });
+    }
+
     /// Build transfer items from source paths.
     fn build_transfer_items(
         &self,
@@ -743,6 +771,7 @@ impl TransferEngine {
             })
         })?;
         let mut reader = BufReader::with_capacity(options.chunk_size, source_file);
+        let file_start = Instant::now();
 
         // Create destination file
         let dest_file = File::create(&item.destination).map_err(|e| {
@@ -843,6 +872,7 @@ impl TransferEngine {
                 reason: format!("Failed to flush: {e}"),
             })
         })?;
+        metrics::record_transfer(bytes_written, file_start.elapsed());
 
         // Get checksum
         let checksum = hasher.map(|h| format!("{:x}", h.finalize()));
@@ -1698,6 +1728,37 @@ mod tests {
         assert_eq!(result.files_skipped, 0);
     }
 
+    #[test]
+    fn test_transfer_keep_existing_option() {
+        let source_dir = TempDir::new().expect("create source dir");
+        let dest_dir = TempDir::new().expect("create dest dir");
+
+        let source_path = create_test_file(source_dir.path(), "test.mp3", b"new version");
+        create_test_file(dest_dir.path(), "test.mp3", b"old");
+
+        let mut engine = TransferEngine::new();
+        let options = TransferOptions {
+            skip_existing: false,
+            keep_existing: vec![PathBuf::from("test.mp3")],
+            ..Default::default()
+        };
+
+        let result = engine
+            .transfer_files(
+                &[source_path],
+                dest_dir.path(),
+                &options,
+                None::<fn(&TransferProgress)>,
+            )
+            .expect("transfer should succeed");
+
+        // The existing copy is kept even though it differs
+        assert_eq!(result.files_transferred, 0);
+        assert_eq!(result.files_skipped, 1);
+        let dest_content = fs::read(dest_dir.path().join("test.mp3")).expect("read dest");
+        assert_eq!(dest_content, b"old");
+    }
+
     #[test]
     fn test_transfer_with_checksum_verification_existing() {
         let source_dir = TempDir::new().expect("create source dir");
@@ -0,0 +1,219 @@
+//! Session trash for undoable deletions.
+//!
+//! Playlists and tracks deleted through the trash are moved into a trash
+//! directory instead of being removed, and can be put back with
+//! [`PlaylistManager::restore_from_trash`](crate::playlist::PlaylistManager::restore_from_trash).
+//! The trash only lives for one session: whatever is left in it is removed
+//! when it is opened again.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::config::default_trash_directory;
+use crate::error::{Error, FileSystemError, Result};
+use crate::fs::{FileSystem, RealFileSystem};
+use crate::playlist::{BatchResult, SavedTrackMetadata};
+
+/// Identifier of a trash entry, unique within a session.
+pub type TrashId = u64;
+
+/// What a trash entry holds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TrashedItem {
+    /// A whole playlist folder.
+    Playlist {
+        /// Playlist name.
+        name: String,
+    },
+    /// Tracks removed from a playlist.
+    Tracks {
+        /// Playlist the tracks belong to.
+        playlist: String,
+        /// Track file names.
+        file_names: Vec<String>,
+    },
+}
+
+/// A deletion that can be undone.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashEntry {
+    /// Entry ID.
+    pub id: TrashId,
+    /// What was deleted.
+    pub item: TrashedItem,
+    /// When it was deleted (Unix epoch seconds).
+    pub trashed_at: u64,
+}
+
+/// Result of moving tracks to the trash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashedTracks {
+    /// The trash entry, if any track was moved.
+    pub entry: Option<TrashEntry>,
+    /// Which tracks were moved.
+    pub result: BatchResult,
+}
+
+/// A trash entry along with what is needed to restore it.
+#[derive(Debug, Clone)]
+pub(crate) struct StoredEntry {
+    /// The entry.
+    pub(crate) entry: TrashEntry,
+    /// Saved metadata of trashed tracks, written back on restore.
+    pub(crate) tracks: Vec<SavedTrackMetadata>,
+}
+
+/// Deleted playlists and tracks of the current session.
+#[derive(Debug)]
+pub struct Trash {
+    dir: PathBuf,
+    fs: Arc<dyn FileSystem>,
+    entries: Mutex<Vec<StoredEntry>>,
+    next_id: AtomicU64,
+}
+
+impl Trash {
+    /// Open the trash at `dir`, removing anything left from a previous
+    /// session.
+    #[must_use]
+    pub fn open(dir: impl Into<PathBuf>) -> Self {
+        Self::with_file_system(dir, Arc::new(RealFileSystem))
+    }
+
+    /// Open the trash at `dir` on `fs`, which should be the file system the
+    /// playlists are stored on.
+    #[must_use]
+    pub fn with_file_system(dir: impl Into<PathBuf>, fs: Arc<dyn FileSystem>) -> Self {
+        let dir = dir.into();
+        if fs.exists(&dir) {
+            match fs.remove_dir_all(&dir) {
+                Ok(()) => info!("Emptied trash from a previous session"),
+                Err(e) => warn!("Failed to empty trash {}: {}", dir.display(), e),
+            }
+        }
+        Self {
+            dir,
+            fs,
+            entries: Mutex::new(Vec::new()),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Open the trash in its default location.
+    #[must_use]
+    pub fn open_default() -> Self {
+        Self::open(default_trash_directory())
+    }
+
+    /// Directory the trash is stored in.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Entries in the trash, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trash lock is poisoned.
+    pub fn entries(&self) -> Result<Vec<TrashEntry>> {
+        Ok(self.lock()?.iter().map(|s| s.entry.clone()).collect())
+    }
+
+    /// Permanently remove everything in the trash.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the trash directory cannot be removed.
+    pub fn empty(&self) -> Result<()> {
+        let mut entries = self.lock()?;
+        if self.fs.exists(&self.dir) {
+            self.fs.remove_dir_all(&self.dir)?;
+        }
+        entries.clear();
+        info!("Emptied trash");
+        Ok(())
+    }
+
+    /// Create a directory for a new entry.
+    pub(crate) fn reserve(&self) -> Result<(TrashId, PathBuf)> {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        let dir = self.entry_dir(id);
+        self.fs.create_dir_all(&dir)?;
+        Ok((id, dir))
+    }
+
+    /// Record a new entry whose files were moved into its directory.
+    pub(crate) fn insert(&self, stored: StoredEntry) -> Result<()> {
+        debug!("Trashed {:?}", stored.entry.item);
+        self.lock()?.push(stored);
+        Ok(())
+    }
+
+    /// Take an entry out of the trash to restore it.
+    pub(crate) fn take(&self, id: TrashId) -> Result<(StoredEntry, PathBuf)> {
+        let mut entries = self.lock()?;
+        let index = entries
+            .iter()
+            .position(|s| s.entry.id == id)
+            .ok_or_else(|| {
+                Error::FileSystem(FileSystemError::NotFound {
+                    path: self.entry_dir(id),
+                })
+            })?;
+        Ok((entries.remove(index), self.entry_dir(id)))
+    }
+
+    /// Remove an entry's directory once it has been restored.
+    pub(crate) fn forget(&self, id: TrashId) {
+        let dir = self.entry_dir(id);
+        if let Err(e) = self.fs.remove_dir_all(&dir) {
+            warn!("Failed to remove trash entry {}: {}", dir.display(), e);
+        }
+    }
+
+    fn entry_dir(&self, id: TrashId) -> PathBuf {
+        self.dir.join(id.to_string())
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Vec<StoredEntry>>> {
+        self.entries
+            .lock()
+            .map_err(|_| Error::internal("Trash lock poisoned"))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_open_empties_previous_session() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("trash");
+        fs::create_dir_all(dir.join("1")).unwrap();
+        fs::write(dir.join("1").join("song.mp3"), b"audio").unwrap();
+
+        let trash = Trash::open(&dir);
+        assert!(!dir.exists());
+        assert!(trash.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_take_unknown_entry() {
+        let temp = TempDir::new().unwrap();
+        let trash = Trash::open(temp.path().join("trash"));
+        assert!(matches!(
+            trash.take(42),
+            Err(Error::FileSystem(FileSystemError::NotFound { .. }))
+        ));
+    }
+}
@@ -12,7 +12,9 @@
 //!
 //! Downloads are configured to:
 //! - Extract the best audio stream available
-//! - Save as the original format (usually m4a/webm)
+//! - Convert it to MP3 at the configured bitrate when `ffmpeg` is installed
+//!   (see [`crate::audio`])
+//! - Otherwise save it in the original format (usually mp4)
 //!
 //! ## Usage
 //!
@@ -32,9 +34,43 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use regex::Regex;
 use rusty_ytdl::{Video, VideoOptions, VideoQuality, VideoSearchOptions};
 use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
+use crate::audio::{TranscodeOptions, Transcoder};
+use crate::cache::{CacheManager, TempFile};
+use crate::config::DownloadQuality;
 use crate::error::{DownloadError, Error, Result};
+use crate::lyrics::{self, LyricsClient, LyricsConfig};
+use crate::metadata::{self, CoverArt, TrackTags};
+use crate::metrics;
+use crate::playlist::{DownloadProfile, OutputFormat, TagPolicy};
+use crate::resume::PartialDownload;
+use crate::throttle::RateLimiter;
+use archive::DownloadArchive;
+use chapters::{Chapter, ChapterTrack};
+use player::PlayerClient;
+use quality::{AudioFormat, StreamPath};
+use retry::{RetryCategory, RetryPolicy};
+
+pub mod archive;
+pub mod chapters;
+pub mod diff;
+pub mod mix;
+pub mod nonblocking;
+pub mod player;
+pub mod quality;
+pub mod retry;
+pub mod search;
+pub mod sponsorblock;
+pub mod template;
+pub mod yt_dlp;
+
+use sponsorblock::{SponsorBlockClient, SponsorBlockConfig};
+use template::{FilenameTemplate, TrackFields};
+use yt_dlp::{ExtractionBackend, ExtractionConfig, YtDlpBackend};
+
+pub use nonblocking::AsyncYouTubeDownloader;
 
 /// Information about a `YouTube` video.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,6 +85,76 @@ pub struct VideoInfo {
     pub channel: Option<String>,
     /// Thumbnail URL for the video.
     pub thumbnail_url: Option<String>,
+    /// Whether the video can be downloaded, as listed in its playlist.
+    #[serde(default)]
+    pub availability: VideoAvailability,
+    /// Chapter markers, sorted by start. Only known once the video's own
+    /// page was fetched; playlist listings carry none.
+    #[serde(default)]
+    pub chapters: Vec<Chapter>,
+}
+
+/// Whether a video of a playlist can be downloaded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VideoAvailability {
+    /// The video can be downloaded.
+    #[default]
+    Available,
+    /// The uploader made the video private.
+    Private,
+    /// The video was deleted.
+    Deleted,
+    /// The video can't be played in this country.
+    GeoBlocked,
+}
+
+impl VideoAvailability {
+    /// Why a video with this availability can't be downloaded, if it can't.
+    #[must_use]
+    pub const fn unavailable_reason(self) -> Option<&'static str> {
+        match self {
+            Self::Available => None,
+            Self::Private => Some("video is private"),
+            Self::Deleted => Some("video was deleted"),
+            Self::GeoBlocked => Some("video is not available in your country"),
+        }
+    }
+}
+
+/// How many videos of a playlist can be downloaded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistHealth {
+    /// Videos that can be downloaded.
+    pub available: usize,
+    /// Private videos.
+    pub private: usize,
+    /// Deleted videos.
+    pub deleted: usize,
+    /// Videos blocked in this country.
+    pub geo_blocked: usize,
+}
+
+impl PlaylistHealth {
+    /// Count the availability of `videos`.
+    #[must_use]
+    pub fn from_videos(videos: &[VideoInfo]) -> Self {
+        videos.iter().fold(Self::default(), |mut health, video| {
+            match video.availability {
+                VideoAvailability::Available => health.available += 1,
+                VideoAvailability::Private => health.private += 1,
+                VideoAvailability::Deleted => health.deleted += 1,
+                VideoAvailability::GeoBlocked => health.geo_blocked += 1,
+            }
+            health
+        })
+    }
+
+    /// Number of videos that can't be downloaded.
+    #[must_use]
+    pub const fn unavailable(&self) -> usize {
+        self.private + self.deleted + self.geo_blocked
+    }
 }
 
 /// Information about a `YouTube` playlist.
@@ -64,6 +170,308 @@ pub struct PlaylistInfo {
     pub videos: Vec<VideoInfo>,
     /// Thumbnail URL for the playlist (or first video's thumbnail).
     pub thumbnail_url: Option<String>,
+    /// Availability of the videos when the playlist was fetched.
+    #[serde(default)]
+    pub health: PlaylistHealth,
+}
+
+/// Duration assumed for videos whose length is unknown, when no other video
+/// in the playlist has a known length.
+const DEFAULT_TRACK_DURATION_SECS: u64 = 240;
+
+/// Typical download throughput used to estimate download time, in bytes per
+/// second.
+const ESTIMATED_THROUGHPUT_BPS: u64 = 1_000_000;
+
+/// Typical fixed cost per video (metadata lookup, conversion, tagging).
+const ESTIMATED_OVERHEAD_PER_VIDEO_SECS: u64 = 3;
+
+/// Most videos whose format metadata is looked up to estimate the size of a
+/// playlist.
+const SIZE_ESTIMATE_SAMPLES: usize = 8;
+
+/// Size of each range request when downloading a stream. `YouTube`
+/// throttles large single requests.
+const RANGE_CHUNK_SIZE: u64 = 10 * 1024 * 1024;
+
+/// How often a paused download checks whether it was resumed or cancelled.
+const PAUSE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+
+/// Shortest time between two reports of a video's downloaded bytes.
+const BYTE_PROGRESS_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+/// `youtubei` endpoint returning further pages of a playlist.
+const INNERTUBE_BROWSE_URL: &str = "https://www.youtube.com/youtubei/v1/browse";
+
+/// `youtubei` endpoint returning further videos of a Mix.
+const INNERTUBE_NEXT_URL: &str = "https://www.youtube.com/youtubei/v1/next";
+
+/// Web client version sent to `youtubei` when the playlist page doesn't
+/// name one.
+const DEFAULT_INNERTUBE_CLIENT_VERSION: &str = "2.20240101.00.00";
+
+/// Upper bound on the pages fetched for one playlist. `YouTube` returns 100
+/// videos per page and caps playlists at 5000 videos.
+const MAX_PLAYLIST_PAGES: usize = 100;
+
+/// Progress of fetching the video list of a playlist, page by page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistFetchProgress {
+    /// Playlist ID.
+    pub playlist_id: String,
+    /// Number of pages fetched so far.
+    pub pages_fetched: usize,
+    /// Number of videos found so far.
+    pub videos_found: usize,
+}
+
+/// Videos of one page of a playlist and the token of the next page.
+#[derive(Debug, Default)]
+struct PlaylistPage {
+    videos: Vec<VideoInfo>,
+    continuation: Option<String>,
+}
+
+/// `youtubei` client details scraped from a playlist page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct InnertubeClient {
+    version: String,
+    api_key: Option<String>,
+}
+
+impl InnertubeClient {
+    fn from_html(html: &str) -> Self {
+        let config_value = |key: &str| {
+            let re = Regex::new(&format!(r#""{key}":"([^"]+)""#)).ok()?;
+            Some(re.captures(html)?.get(1)?.as_str().to_string())
+        };
+        Self {
+            version: config_value("INNERTUBE_CLIENT_VERSION")
+                .unwrap_or_else(|| DEFAULT_INNERTUBE_CLIENT_VERSION.to_string()),
+            api_key: config_value("INNERTUBE_API_KEY"),
+        }
+    }
+
+    fn browse_url(&self) -> String {
+        self.endpoint_url(INNERTUBE_BROWSE_URL)
+    }
+
+    fn next_url(&self) -> String {
+        self.endpoint_url(INNERTUBE_NEXT_URL)
+    }
+
+    fn endpoint_url(&self, endpoint: &str) -> String {
+        match &self.api_key {
+            Some(key) => format!("{endpoint}?prettyPrint=false&key={key}"),
+            None => format!("{endpoint}?prettyPrint=false"),
+        }
+    }
+
+    fn context(&self) -> serde_json::Value {
+        serde_json::json!({
+            "client": {
+                "clientName": "WEB",
+                "clientVersion": self.version,
+                "hl": "en",
+                "gl": "US",
+            }
+        })
+    }
+
+    fn continuation_body(&self, token: &str) -> serde_json::Value {
+        serde_json::json!({
+            "context": self.context(),
+            "continuation": token,
+        })
+    }
+}
+
+/// Estimated size and time of downloading a playlist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistDownloadEstimate {
+    /// Playlist ID.
+    pub playlist_id: String,
+    /// Playlist title.
+    pub title: String,
+    /// Number of videos in the playlist.
+    pub video_count: usize,
+    /// Number of videos whose duration was unknown and had to be guessed.
+    pub unknown_duration_count: usize,
+    /// Total audio duration in seconds.
+    pub total_duration_secs: u64,
+    /// Bitrate the estimate assumes, in kbps.
+    pub bitrate_kbps: u32,
+    /// Estimated total size of the MP3 files in bytes.
+    pub estimated_size_bytes: u64,
+    /// Estimated download time in seconds.
+    pub estimated_download_secs: u64,
+}
+
+/// Size and length of a playlist's audio, estimated from the format
+/// metadata of a sample of its videos.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistSizeEstimate {
+    /// Playlist ID.
+    pub playlist_id: String,
+    /// Playlist title.
+    pub title: String,
+    /// Number of videos that can be downloaded.
+    pub video_count: usize,
+    /// Number of videos whose format metadata was looked up.
+    pub sampled_count: usize,
+    /// Total audio length in seconds.
+    pub total_duration_secs: u64,
+    /// Bitrate of the saved files, in kbps; the streams' own bitrate when
+    /// `original_audio` is set.
+    pub bitrate_kbps: u32,
+    /// Whether the streams are saved as is rather than converted to MP3.
+    pub original_audio: bool,
+    /// Estimated total size of the saved files in bytes.
+    pub estimated_size_bytes: u64,
+}
+
+/// What downloading a video would do, found by a dry run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedDownload {
+    /// Video ID.
+    pub video_id: String,
+    /// Video title.
+    pub title: String,
+    /// File the video would be saved as, or already is.
+    pub output_path: PathBuf,
+    /// Whether the video is already in the playlist folder.
+    pub already_downloaded: bool,
+    /// Why the video can't be downloaded, if it can't.
+    pub unavailable_reason: Option<String>,
+    /// Length of the video in seconds, if known.
+    pub duration_secs: Option<u64>,
+    /// Estimated size of the saved file in bytes; 0 for videos that won't
+    /// be downloaded.
+    pub estimated_size_bytes: u64,
+    /// Whether the estimate comes from the stream's format metadata rather
+    /// than the video's length alone.
+    pub from_format: bool,
+}
+
+impl PlannedDownload {
+    /// Whether the video would be downloaded.
+    #[must_use]
+    pub const fn will_download(&self) -> bool {
+        !self.already_downloaded && self.unavailable_reason.is_none()
+    }
+}
+
+/// What downloading a playlist would do, worked out without downloading or
+/// writing anything.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownloadPlan {
+    /// Playlist ID.
+    pub playlist_id: String,
+    /// Playlist title.
+    pub title: String,
+    /// Bitrate the estimates assume, in kbps.
+    pub bitrate_kbps: u32,
+    /// Each video of the playlist, in order.
+    pub videos: Vec<PlannedDownload>,
+}
+
+impl DownloadPlan {
+    /// Number of videos that would be downloaded.
+    #[must_use]
+    pub fn download_count(&self) -> usize {
+        self.videos
+            .iter()
+            .filter(|video| video.will_download())
+            .count()
+    }
+
+    /// Estimated size of the new files in bytes.
+    #[must_use]
+    pub fn estimated_size_bytes(&self) -> u64 {
+        self.videos
+            .iter()
+            .map(|video| video.estimated_size_bytes)
+            .sum()
+    }
+
+    /// Total length in seconds of the videos that would be downloaded.
+    #[must_use]
+    pub fn total_duration_secs(&self) -> u64 {
+        self.videos
+            .iter()
+            .filter(|video| video.will_download())
+            .filter_map(|video| video.duration_secs)
+            .sum()
+    }
+}
+
+impl PlaylistInfo {
+    /// A one-video playlist holding `video`, for downloading a single video.
+    #[must_use]
+    pub fn from_video(video: VideoInfo) -> Self {
+        Self {
+            id: video.id.clone(),
+            title: video.title.clone(),
+            video_count: 1,
+            thumbnail_url: video.thumbnail_url.clone(),
+            health: PlaylistHealth::from_videos(std::slice::from_ref(&video)),
+            videos: vec![video],
+        }
+    }
+
+    /// Album tag of the playlist's tracks; `None` when it holds a single
+    /// video, which is wrapped in a playlist of its own ID.
+    fn album(&self) -> Option<&str> {
+        self.videos
+            .first()
+            .is_none_or(|video| video.id != self.id)
+            .then_some(self.title.as_str())
+    }
+
+    /// Videos that can't be downloaded.
+    pub fn unavailable_videos(&self) -> impl Iterator<Item = &VideoInfo> {
+        self.videos
+            .iter()
+            .filter(|video| video.availability != VideoAvailability::Available)
+    }
+
+    /// Estimate the size and time of downloading this playlist at
+    /// `bitrate_kbps`.
+    ///
+    /// Videos of unknown length count as the average of the known ones. The
+    /// time assumes a typical connection and is only a rough guide.
+    #[must_use]
+    pub fn estimate_download(&self, bitrate_kbps: u32) -> PlaylistDownloadEstimate {
+        let known: Vec<u64> = self
+            .videos
+            .iter()
+            .filter_map(|video| video.duration_secs)
+            .collect();
+        let video_count = self.video_count.max(self.videos.len());
+        let unknown_duration_count = video_count - known.len();
+        let known_total: u64 = known.iter().sum();
+        let average = if known.is_empty() {
+            DEFAULT_TRACK_DURATION_SECS
+        } else {
+            known_total / known.len() as u64
+        };
+        let total_duration_secs = known_total + average * unknown_duration_count as u64;
+
+        let estimated_size_bytes = total_duration_secs * u64::from(bitrate_kbps) * 1000 / 8;
+        let estimated_download_secs = estimated_size_bytes / ESTIMATED_THROUGHPUT_BPS
+            + ESTIMATED_OVERHEAD_PER_VIDEO_SECS * video_count as u64;
+
+        PlaylistDownloadEstimate {
+            playlist_id: self.id.clone(),
+            title: self.title.clone(),
+            video_count,
+            unknown_duration_count,
+            total_duration_secs,
+            bitrate_kbps,
+            estimated_size_bytes,
+            estimated_download_secs,
+        }
+    }
 }
 
 /// Progress callback for download operations.
@@ -88,6 +496,9 @@ pub struct DownloadProgress {
     pub current_bytes: u64,
     /// Total bytes for the current file (if known).
     pub current_total_bytes: Option<u64>,
+    /// Byte offset the current file's download resumed from (0 when it
+    /// started from the beginning).
+    pub resumed_from_bytes: u64,
     /// Total bytes downloaded across all files.
     pub total_bytes_downloaded: u64,
     /// Download speed in bytes per second.
@@ -102,6 +513,8 @@ pub struct DownloadProgress {
     pub videos_skipped: usize,
     /// Number of videos that failed.
     pub videos_failed: usize,
+    /// Number of videos being downloaded at the same time.
+    pub active_downloads: usize,
 }
 
 impl Default for DownloadProgress {
@@ -115,6 +528,7 @@ impl Default for DownloadProgress {
             status: DownloadStatus::Starting,
             current_bytes: 0,
             current_total_bytes: None,
+            resumed_from_bytes: 0,
             total_bytes_downloaded: 0,
             download_speed_bps: 0.0,
             estimated_remaining_secs: None,
@@ -122,6 +536,7 @@ impl Default for DownloadProgress {
             videos_completed: 0,
             videos_skipped: 0,
             videos_failed: 0,
+            active_downloads: 0,
         }
     }
 }
@@ -219,6 +634,13 @@ pub trait YouTubeDownloader: Send + Sync {
     /// Returns an error if the URL is invalid or not a playlist.
     fn parse_playlist_url(&self, url: &str) -> Result<PlaylistInfo>;
 
+    /// Parse a `YouTube` video URL and fetch the video's information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL has no video ID or the video is unavailable.
+    fn parse_video_url(&self, url: &str) -> Result<VideoInfo>;
+
     /// Download all videos from a playlist as MP3 files.
     ///
     /// # Errors
@@ -230,6 +652,42 @@ pub trait YouTubeDownloader: Send + Sync {
         output_dir: &Path,
         progress: Option<ProgressCallback>,
     ) -> Result<Vec<DownloadResult>>;
+
+    /// Download a single video as an MP3 file.
+    ///
+    /// Unlike [`download_playlist`](Self::download_playlist), a video that
+    /// can't be downloaded is an error rather than a failed result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the download fails.
+    fn download_single_video(
+        &self,
+        video: &VideoInfo,
+        output_dir: &Path,
+        progress: Option<ProgressCallback>,
+    ) -> Result<DownloadResult> {
+        let playlist = PlaylistInfo::from_video(video.clone());
+        let result = self
+            .download_playlist(&playlist, output_dir, progress)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::internal("Download returned no result"))?;
+        if result.success {
+            Ok(result)
+        } else {
+            Err(Error::Download(DownloadError::VideoUnavailable {
+                video_id: video.id.clone(),
+                reason: result.error.unwrap_or_else(|| "Unknown error".to_string()),
+            }))
+        }
+    }
+
+    /// Cancel the download in progress, if the downloader supports it.
+    ///
+    /// The download stops at its next chunk and returns
+    /// [`DownloadError::Cancelled`].
+    fn cancel_download(&self) {}
 }
 
 /// Result of downloading a single video.
@@ -243,6 +701,33 @@ pub struct DownloadResult {
     pub output_path: Option<std::path::PathBuf>,
     /// Error message (if failed).
     pub error: Option<String>,
+    /// Seconds of `SponsorBlock` segments removed from the audio.
+    pub sponsor_secs_removed: f64,
+    /// Audio bitrate of the downloaded stream in kbps, if known.
+    pub audio_bitrate_kbps: Option<u32>,
+    /// Audio codec of the downloaded stream, if known.
+    pub audio_codec: Option<String>,
+    /// Kind of stream the video was downloaded from, if it was downloaded.
+    pub stream_path: Option<StreamPath>,
+    /// Files of the video's chapters, when it was split by chapters. The
+    /// output path is then the first of them.
+    pub chapter_paths: Vec<PathBuf>,
+    /// Estimated size in bytes of the file a dry run would save. Set only
+    /// by dry runs, which leave the output path where the file would go.
+    pub estimated_size_bytes: Option<u64>,
+}
+
+impl DownloadResult {
+    /// Files the video was saved as: one per chapter when it was split,
+    /// otherwise the output file, if any.
+    #[must_use]
+    pub fn output_paths(&self) -> &[PathBuf] {
+        if self.chapter_paths.is_empty() {
+            self.output_path.as_slice()
+        } else {
+            &self.chapter_paths
+        }
+    }
 }
 
 /// Result of `YouTube` URL validation.
@@ -252,6 +737,9 @@ pub struct YouTubeUrlValidation {
     pub is_valid: bool,
     /// The extracted playlist ID (if valid).
     pub playlist_id: Option<String>,
+    /// The extracted video ID, for watch and short URLs.
+    #[serde(default)]
+    pub video_id: Option<String>,
     /// The normalized/canonical URL.
     pub normalized_url: Option<String>,
     /// Error message if validation failed.
@@ -271,6 +759,12 @@ pub enum YouTubeUrlType {
     SingleVideo,
     /// Short URL (youtu.be/...)
     ShortUrl,
+    /// Channel ID URL (youtube.com/channel/UC...)
+    Channel,
+    /// Channel handle URL (youtube.com/@handle)
+    ChannelHandle,
+    /// Channel custom URL (youtube.com/c/name)
+    ChannelCustomUrl,
     /// Invalid or unrecognized URL
     #[default]
     Invalid,
@@ -287,23 +781,76 @@ impl YouTubeUrlValidation {
         Self {
             is_valid: true,
             playlist_id: Some(playlist_id),
+            video_id: None,
             normalized_url: Some(normalized_url),
             error_message: None,
             url_type,
         }
     }
 
+    /// Create the result for a single video URL without a playlist.
+    ///
+    /// The URL is not a valid playlist, but the video can be downloaded on
+    /// its own.
+    #[must_use]
+    pub fn single_video(video_id: String, url_type: YouTubeUrlType, error_message: String) -> Self {
+        Self {
+            is_valid: false,
+            playlist_id: None,
+            normalized_url: Some(format!("https://www.youtube.com/watch?v={video_id}")),
+            video_id: Some(video_id),
+            error_message: Some(error_message),
+            url_type,
+        }
+    }
+
     /// Create a failed validation result.
     #[must_use]
     pub const fn invalid(error_message: String, url_type: YouTubeUrlType) -> Self {
         Self {
             is_valid: false,
             playlist_id: None,
+            video_id: None,
             normalized_url: None,
             error_message: Some(error_message),
             url_type,
         }
     }
+
+    /// Create the result for a channel URL whose uploads playlist is only
+    /// known after fetching the channel page.
+    #[must_use]
+    pub const fn channel(channel_url: String, url_type: YouTubeUrlType) -> Self {
+        Self {
+            is_valid: true,
+            playlist_id: None,
+            video_id: None,
+            normalized_url: Some(channel_url),
+            error_message: None,
+            url_type,
+        }
+    }
+
+    /// Whether the URL is a single video without a playlist that can be
+    /// downloaded on its own.
+    #[must_use]
+    pub const fn is_single_video(&self) -> bool {
+        !self.is_valid && self.video_id.is_some()
+    }
+
+    /// Whether the URL is a `YouTube` Mix, which never ends and is only
+    /// downloaded up to [`mix::MAX_MIX_VIDEOS`] videos.
+    #[must_use]
+    pub fn is_mix(&self) -> bool {
+        self.playlist_id.as_deref().is_some_and(mix::is_mix)
+    }
+
+    /// Whether the URL is a channel that must be resolved to its uploads
+    /// playlist before downloading; `normalized_url` is then the channel page.
+    #[must_use]
+    pub const fn needs_channel_resolution(&self) -> bool {
+        self.is_valid && self.playlist_id.is_none()
+    }
 }
 
 /// Validate a `YouTube` URL and extract playlist information.
@@ -318,8 +865,15 @@ impl YouTubeUrlValidation {
 /// - `https://youtube.com/playlist?list=PLxxxxxxxx` - Without www
 /// - `https://www.youtube.com/watch?v=xxxxx&list=PLxxxxxxxx` - Watch with playlist
 /// - `https://youtu.be/xxxxx?list=PLxxxxxxxx` - Short URL with playlist
+/// - `https://www.youtube.com/channel/UCxxxxxxxx` - Channel, as its uploads playlist
+/// - `https://www.youtube.com/@handle` and `https://www.youtube.com/c/name` -
+///   Channel, resolved to its uploads playlist when downloading
 /// - `http://` variants are also accepted
 ///
+/// Single video URLs (`watch?v=...` or `youtu.be/...` without a playlist)
+/// are not valid playlists, but their `video_id` is set so they can be
+/// downloaded on their own (see [`YouTubeUrlValidation::is_single_video`]).
+///
 /// # Examples
 ///
 /// ```rust
@@ -368,6 +922,13 @@ pub fn validate_youtube_url(url: &str) -> YouTubeUrlValidation {
     // Determine URL type and extract playlist ID
     let url_type = detect_url_type(url);
 
+    // Channel URLs download the channel's uploads playlist
+    if !url_lower.contains("list=")
+        && let Some(channel) = parse_channel_url(url)
+    {
+        return channel.validation();
+    }
+
     // Extract playlist ID based on URL type
     if let Some(playlist_id) = extract_playlist_id_internal(url) {
         // Validate playlist ID format
@@ -375,10 +936,19 @@ pub fn validate_youtube_url(url: &str) -> YouTubeUrlValidation {
             return YouTubeUrlValidation::invalid(validation_error, url_type);
         }
 
-        // Generate normalized URL
-        let normalized = format!("https://www.youtube.com/playlist?list={playlist_id}");
+        // Generate normalized URL; mixes keep the video they were started
+        // from, as they have no playlist page
+        let video_id = extract_video_id_internal(url);
+        let normalized = match &video_id {
+            Some(video_id) if mix::is_mix(&playlist_id) => {
+                format!("https://www.youtube.com/watch?v={video_id}&list={playlist_id}")
+            }
+            _ => format!("https://www.youtube.com/playlist?list={playlist_id}"),
+        };
 
-        YouTubeUrlValidation::valid(playlist_id, url_type, normalized)
+        let mut validation = YouTubeUrlValidation::valid(playlist_id, url_type, normalized);
+        validation.video_id = video_id;
+        validation
     } else {
         let error_msg = match url_type {
             YouTubeUrlType::SingleVideo => {
@@ -389,7 +959,17 @@ pub fn validate_youtube_url(url: &str) -> YouTubeUrlValidation {
             }
             _ => "URL does not contain a valid playlist ID".to_string(),
         };
-        YouTubeUrlValidation::invalid(error_msg, url_type)
+        match extract_video_id_internal(url) {
+            Some(video_id)
+                if matches!(
+                    url_type,
+                    YouTubeUrlType::SingleVideo | YouTubeUrlType::ShortUrl
+                ) =>
+            {
+                YouTubeUrlValidation::single_video(video_id, url_type, error_msg)
+            }
+            _ => YouTubeUrlValidation::invalid(error_msg, url_type),
+        }
     }
 }
 
@@ -403,6 +983,10 @@ fn detect_url_type(url: &str) -> YouTubeUrlType {
         } else {
             YouTubeUrlType::ShortUrl
         }
+    } else if !url_lower.contains("list=")
+        && let Some(channel) = parse_channel_url(url)
+    {
+        channel.url_type()
     } else if url_lower.contains("/playlist") {
         YouTubeUrlType::Playlist
     } else if url_lower.contains("/watch") {
@@ -416,14 +1000,112 @@ fn detect_url_type(url: &str) -> YouTubeUrlType {
     }
 }
 
+/// A channel referenced by a `YouTube` channel URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ChannelRef {
+    /// Channel ID (`UC...`).
+    Id(String),
+    /// Handle, without the leading `@`.
+    Handle(String),
+    /// Custom URL name.
+    Custom(String),
+}
+
+impl ChannelRef {
+    const fn url_type(&self) -> YouTubeUrlType {
+        match self {
+            Self::Id(_) => YouTubeUrlType::Channel,
+            Self::Handle(_) => YouTubeUrlType::ChannelHandle,
+            Self::Custom(_) => YouTubeUrlType::ChannelCustomUrl,
+        }
+    }
+
+    /// Canonical URL of the channel page.
+    fn page_url(&self) -> String {
+        match self {
+            Self::Id(id) => format!("https://www.youtube.com/channel/{id}"),
+            Self::Handle(handle) => format!("https://www.youtube.com/@{handle}"),
+            Self::Custom(name) => format!("https://www.youtube.com/c/{name}"),
+        }
+    }
+
+    fn validation(&self) -> YouTubeUrlValidation {
+        match self {
+            // Channel IDs map to their uploads playlist without a lookup
+            Self::Id(id) => match uploads_playlist_id(id) {
+                Some(playlist_id) => {
+                    let normalized = format!("https://www.youtube.com/playlist?list={playlist_id}");
+                    YouTubeUrlValidation::valid(playlist_id, self.url_type(), normalized)
+                }
+                None => YouTubeUrlValidation::invalid(
+                    "Channel ID has an invalid format".to_string(),
+                    self.url_type(),
+                ),
+            },
+            _ => YouTubeUrlValidation::channel(self.page_url(), self.url_type()),
+        }
+    }
+}
+
+/// Parse a channel URL (`/channel/UC...`, `/@handle` or `/c/name`, with any
+/// tab such as `/videos` after it).
+fn parse_channel_url(url: &str) -> Option<ChannelRef> {
+    let (_, rest) = url.split_once("://")?;
+    let (host, path) = rest.split_once('/')?;
+    let host = host.to_lowercase();
+    if host != "youtube.com" && !host.ends_with(".youtube.com") {
+        return None;
+    }
+
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let mut segments = path.split('/').filter(|segment| !segment.is_empty());
+    let first = segments.next()?;
+
+    if let Some(handle) = first.strip_prefix('@') {
+        let is_valid = (3..=30).contains(&handle.chars().count())
+            && handle
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'));
+        return is_valid.then(|| ChannelRef::Handle(handle.to_string()));
+    }
+
+    let name = segments.next()?;
+    if first.eq_ignore_ascii_case("channel") {
+        let is_valid = name.len() == 24
+            && name.starts_with("UC")
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        is_valid.then(|| ChannelRef::Id(name.to_string()))
+    } else if first.eq_ignore_ascii_case("c") {
+        let is_valid = name.len() <= 100
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '%'));
+        is_valid.then(|| ChannelRef::Custom(name.to_string()))
+    } else {
+        None
+    }
+}
+
+/// ID of the uploads playlist of the channel `channel_id` (`UC...` becomes
+/// `UU...`).
+fn uploads_playlist_id(channel_id: &str) -> Option<String> {
+    channel_id
+        .strip_prefix("UC")
+        .filter(|rest| !rest.is_empty())
+        .map(|rest| format!("UU{rest}"))
+}
+
 /// Extract playlist ID from URL (internal implementation).
 fn extract_playlist_id_internal(url: &str) -> Option<String> {
-    let url_lower = url.to_lowercase();
+    // ASCII lowercasing keeps byte offsets valid in `url`
+    let url_lower = url.to_ascii_lowercase();
 
     // Find list= parameter (case-insensitive search)
     if let Some(list_pos) = url_lower.find("list=") {
         let start = list_pos + 5;
-        let rest = &url[start..];
+        let rest = url.get(start..)?;
 
         // Extract until next & or # or end of string
         let end = rest.find(['&', '#']).unwrap_or(rest.len());
@@ -437,6 +1119,31 @@ fn extract_playlist_id_internal(url: &str) -> Option<String> {
     None
 }
 
+/// Extract the video ID from a watch or short URL (internal implementation).
+fn extract_video_id_internal(url: &str) -> Option<String> {
+    // ASCII lowercasing keeps byte offsets valid in `url`
+    let url_lower = url.to_ascii_lowercase();
+
+    let start = if let Some(pos) = url_lower.find("youtu.be/") {
+        pos + "youtu.be/".len()
+    } else {
+        // v= must be a parameter of its own, not the end of another one
+        url_lower.find("?v=").or_else(|| url_lower.find("&v="))? + 3
+    };
+    let rest = url.get(start..)?;
+
+    // Extract until the next parameter, fragment or path segment
+    let end = rest.find(['&', '#', '?', '/']).unwrap_or(rest.len());
+    let video_id = rest[..end].trim();
+
+    let is_valid = !video_id.is_empty()
+        && video_id.len() <= 64
+        && video_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    is_valid.then(|| video_id.to_string())
+}
+
 /// Validate playlist ID format.
 ///
 /// `YouTube` playlist IDs have specific formats:
@@ -492,22 +1199,23 @@ fn validate_playlist_id_format(playlist_id: &str) -> std::result::Result<(), Str
 /// - `https://youtube.com/playlist?list=PLxxxxxxxx`
 /// - `https://www.youtube.com/watch?v=xxxxx&list=PLxxxxxxxx`
 /// - `https://youtu.be/xxxxx?list=PLxxxxxxxx`
+/// - `https://www.youtube.com/channel/UCxxxxxxxx` (the uploads playlist)
 ///
 /// # Errors
 ///
-/// Returns an error if the URL is not a valid `YouTube` playlist URL.
-///
-/// # Panics
-///
-/// Panics if the URL validation reports valid but has no playlist ID (should never happen).
-#[allow(clippy::expect_used)]
+/// Returns an error if the URL is not a valid `YouTube` playlist URL, or is
+/// a channel handle or custom URL that must be resolved first (see
+/// [`YouTubeUrlValidation::needs_channel_resolution`]).
 pub fn extract_playlist_id(url: &str) -> Result<String> {
     let validation = validate_youtube_url(url);
 
     if validation.is_valid {
-        Ok(validation
-            .playlist_id
-            .expect("Valid URL should have playlist ID"))
+        validation.playlist_id.ok_or_else(|| {
+            Error::Download(DownloadError::InvalidUrl {
+                url: url.to_string(),
+                reason: "Channel URL must be resolved to its uploads playlist first".to_string(),
+            })
+        })
     } else {
         let error_message = validation
             .error_message
@@ -531,6 +1239,120 @@ pub fn extract_playlist_id(url: &str) -> Result<String> {
     }
 }
 
+/// Extract the video ID from a `YouTube` watch or short URL.
+///
+/// Supports the following URL formats:
+/// - `https://www.youtube.com/watch?v=xxxxx`
+/// - `https://www.youtube.com/watch?v=xxxxx&list=PLxxxxxxxx`
+/// - `https://youtu.be/xxxxx`
+///
+/// # Errors
+///
+/// Returns an error if the URL is invalid or has no video ID.
+pub fn extract_video_id(url: &str) -> Result<String> {
+    let validation = validate_youtube_url(url);
+    validation.video_id.ok_or_else(|| {
+        Error::Download(DownloadError::InvalidUrl {
+            url: url.to_string(),
+            reason: validation
+                .error_message
+                .filter(|_| validation.url_type == YouTubeUrlType::Invalid)
+                .unwrap_or_else(|| "URL does not contain a video ID".to_string()),
+        })
+    })
+}
+
+/// Kind of URL found in dropped or pasted text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UrlCandidateKind {
+    /// A `YouTube` playlist that can be downloaded.
+    Playlist,
+    /// A single `YouTube` video without a playlist.
+    Video,
+    /// Not a usable `YouTube` URL.
+    Invalid,
+}
+
+/// A URL found in dropped or pasted text, classified for ingestion.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UrlCandidate {
+    /// The URL as found in the text.
+    pub url: String,
+    /// What the URL points to.
+    pub kind: UrlCandidateKind,
+    /// Playlist ID, for playlists.
+    pub playlist_id: Option<String>,
+    /// Canonical playlist URL, for playlists.
+    pub normalized_url: Option<String>,
+    /// Why the URL cannot be added as a playlist, for videos and invalid URLs.
+    pub message: Option<String>,
+}
+
+impl UrlCandidate {
+    /// Classify a single URL.
+    #[must_use]
+    pub fn classify(url: &str) -> Self {
+        let validation = validate_youtube_url(url);
+        let kind = if validation.is_valid {
+            UrlCandidateKind::Playlist
+        } else if matches!(
+            validation.url_type,
+            YouTubeUrlType::SingleVideo | YouTubeUrlType::ShortUrl
+        ) {
+            UrlCandidateKind::Video
+        } else {
+            UrlCandidateKind::Invalid
+        };
+
+        Self {
+            url: url.to_string(),
+            kind,
+            playlist_id: validation.playlist_id,
+            normalized_url: validation.normalized_url,
+            message: validation.error_message,
+        }
+    }
+}
+
+/// Find and classify every URL in dropped or pasted text.
+///
+/// Accepts free text as well as `text/uri-list` data (one URI per line, `#`
+/// comment lines ignored). Bare `YouTube` hosts without a scheme are
+/// recognised, surrounding punctuation is stripped and duplicates are
+/// reported once, in order of appearance.
+#[must_use]
+pub fn find_url_candidates(text: &str) -> Vec<UrlCandidate> {
+    const BARE_HOSTS: [&str; 5] = [
+        "youtube.com/",
+        "www.youtube.com/",
+        "m.youtube.com/",
+        "music.youtube.com/",
+        "youtu.be/",
+    ];
+
+    let mut seen = std::collections::HashSet::new();
+    text.lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .flat_map(str::split_whitespace)
+        .filter_map(|token| {
+            let token = token
+                .trim_start_matches(['<', '(', '[', '"', '\''])
+                .trim_end_matches(['>', ')', ']', '"', '\'', ',', '.', ';', '!', '?']);
+            let lower = token.to_lowercase();
+            if lower.starts_with("http://") || lower.starts_with("https://") {
+                Some(token.to_string())
+            } else if BARE_HOSTS.iter().any(|host| lower.starts_with(host)) {
+                Some(format!("https://{token}"))
+            } else {
+                None
+            }
+        })
+        .filter(|url| seen.insert(url.clone()))
+        .map(|url| UrlCandidate::classify(&url))
+        .collect()
+}
+
 /// Sanitize a string for use as a filename.
 #[must_use]
 pub fn sanitize_filename(name: &str) -> String {
@@ -574,6 +1396,8 @@ pub struct DownloadProgressTracker {
     speed_samples: Vec<(std::time::Instant, u64)>,
     /// Maximum number of samples to keep for speed averaging.
     max_samples: usize,
+    /// Progress (0.0 - 1.0) of the videos being downloaded, by 1-based index.
+    active: std::collections::HashMap<usize, f64>,
 }
 
 impl Default for DownloadProgressTracker {
@@ -595,6 +1419,7 @@ impl DownloadProgressTracker {
             total_bytes_downloaded: 0,
             speed_samples: Vec::with_capacity(10),
             max_samples: 10,
+            active: std::collections::HashMap::new(),
         }
     }
 
@@ -625,13 +1450,31 @@ impl DownloadProgressTracker {
         self.videos_failed += 1;
     }
 
-    /// Get elapsed time in seconds.
-    #[must_use]
-    pub fn elapsed_secs(&self) -> f64 {
-        self.start_time.elapsed().as_secs_f64()
+    /// Record the progress of a video that is being downloaded, so overall
+    /// progress accounts for every video downloading at the same time.
+    pub fn set_video_progress(&mut self, index: usize, progress: f64) {
+        self.active.insert(index, progress.clamp(0.0, 1.0));
     }
 
-    /// Calculate current download speed in bytes per second.
+    /// Stop tracking the progress of a video that finished, failed or was
+    /// skipped.
+    pub fn video_finished(&mut self, index: usize) {
+        self.active.remove(&index);
+    }
+
+    /// Number of videos being downloaded.
+    #[must_use]
+    pub fn active_downloads(&self) -> usize {
+        self.active.len()
+    }
+
+    /// Get elapsed time in seconds.
+    #[must_use]
+    pub fn elapsed_secs(&self) -> f64 {
+        self.start_time.elapsed().as_secs_f64()
+    }
+
+    /// Calculate current download speed in bytes per second.
     ///
     /// Uses a sliding window average for smoother speed estimates.
     #[must_use]
@@ -698,10 +1541,14 @@ impl DownloadProgressTracker {
         current_bytes: u64,
         current_total_bytes: Option<u64>,
     ) -> DownloadProgress {
-        let overall_progress = if self.total_videos > 0 {
+        let overall_progress = if self.total_videos == 0 {
+            0.0
+        } else if self.active.is_empty() {
             (current_index.saturating_sub(1) as f64 + current_progress) / self.total_videos as f64
         } else {
-            0.0
+            let finished = self.videos_completed + self.videos_skipped + self.videos_failed;
+            let in_flight: f64 = self.active.values().sum();
+            ((finished as f64 + in_flight) / self.total_videos as f64).min(1.0)
         };
 
         DownloadProgress {
@@ -713,6 +1560,7 @@ impl DownloadProgressTracker {
             status,
             current_bytes,
             current_total_bytes,
+            resumed_from_bytes: 0,
             total_bytes_downloaded: self.total_bytes_downloaded,
             download_speed_bps: self.download_speed_bps(),
             estimated_remaining_secs: self.estimated_remaining_secs(overall_progress),
@@ -720,6 +1568,7 @@ impl DownloadProgressTracker {
             videos_completed: self.videos_completed,
             videos_skipped: self.videos_skipped,
             videos_failed: self.videos_failed,
+            active_downloads: self.active.len(),
         }
     }
 }
@@ -756,6 +1605,24 @@ impl YouTubeDownloader for DefaultYouTubeDownloader {
             video_count: 0,
             videos: vec![],
             thumbnail_url: None,
+            health: PlaylistHealth::default(),
+        })
+    }
+
+    fn parse_video_url(&self, url: &str) -> Result<VideoInfo> {
+        let video_id = extract_video_id(url)?;
+
+        // TODO: Fetch the actual video info, like parse_playlist_url
+        info!("Parsing video: {}", video_id);
+
+        Ok(VideoInfo {
+            id: video_id.clone(),
+            title: video_id,
+            duration_secs: None,
+            channel: None,
+            thumbnail_url: None,
+            availability: VideoAvailability::Available,
+            chapters: Vec::new(),
         })
     }
 
@@ -801,6 +1668,12 @@ impl YouTubeDownloader for DefaultYouTubeDownloader {
                 success: false,
                 output_path: Some(output_path),
                 error: Some("Download not yet implemented".to_string()),
+                sponsor_secs_removed: 0.0,
+                audio_bitrate_kbps: None,
+                audio_codec: None,
+                stream_path: None,
+                chapter_paths: Vec::new(),
+                estimated_size_bytes: None,
             });
         }
 
@@ -817,19 +1690,273 @@ impl YouTubeDownloader for DefaultYouTubeDownloader {
 pub struct RustyYtdlConfig {
     /// Download timeout in seconds per video.
     pub timeout_secs: u64,
-    /// Number of retries for failed downloads.
-    pub retries: u32,
+    /// When and how often failed videos are retried.
+    pub retry: RetryPolicy,
+    /// Whether offline mode is enabled (no network requests are made).
+    pub offline: bool,
+    /// Directory for partial downloads, usually the cache temp area.
+    ///
+    /// Falls back to the system temp directory when not set. Partial files
+    /// never live in the output directory.
+    pub temp_dir: Option<PathBuf>,
+    /// MP3 conversion of downloaded streams. If None, or if `ffmpeg` can't
+    /// be found, the downloaded stream is kept as is.
+    pub transcode: Option<TranscodeOptions>,
+    /// `SponsorBlock` segment removal. Only applies when streams are
+    /// converted to MP3.
+    pub sponsorblock: Option<SponsorBlockConfig>,
+    /// Number of videos of a playlist downloaded at the same time.
+    pub concurrency: usize,
+    /// Download speed limit in bytes per second, shared by all videos
+    /// downloaded at the same time. Unlimited if `None`.
+    pub max_speed_bps: Option<u64>,
+    /// Audio bitrate in kbps the downloaded stream is chosen closest to.
+    pub audio_bitrate_kbps: u32,
+    /// Write ID3 tags (title, artist, album, track number) into MP3 files.
+    pub write_tags: bool,
+    /// Embed the video thumbnail as cover art when writing tags.
+    pub embed_cover_art: bool,
+    /// Lyrics lookup after each video is downloaded. No lyrics are fetched
+    /// if `None`.
+    pub lyrics: Option<LyricsConfig>,
+    /// Split videos with chapters into one track per chapter. Only applies
+    /// when streams are converted to MP3.
+    pub split_by_chapters: bool,
+    /// Which extractor reads pages and downloads streams.
+    pub extraction: ExtractionConfig,
+    /// File names of the downloaded tracks.
+    pub filename_template: FilenameTemplate,
+    /// Only work out what a playlist download would do: the results carry
+    /// each video's estimated size and nothing is written.
+    pub dry_run: bool,
 }
 
 impl Default for RustyYtdlConfig {
     fn default() -> Self {
         Self {
             timeout_secs: 300,
-            retries: 3,
+            retry: RetryPolicy::default(),
+            offline: false,
+            temp_dir: None,
+            transcode: Some(TranscodeOptions::default()),
+            sponsorblock: None,
+            concurrency: 1,
+            max_speed_bps: None,
+            audio_bitrate_kbps: DownloadQuality::default().bitrate_kbps(),
+            write_tags: true,
+            embed_cover_art: true,
+            lyrics: None,
+            split_by_chapters: false,
+            extraction: ExtractionConfig::default(),
+            filename_template: FilenameTemplate::default(),
+            dry_run: false,
+        }
+    }
+}
+
+impl RustyYtdlConfig {
+    /// Apply the settings a playlist's download `profile` overrides.
+    pub fn apply_profile(&mut self, profile: &DownloadProfile) {
+        if let Some(kbps) = profile.bitrate_kbps {
+            self.audio_bitrate_kbps = kbps;
+            if let Some(transcode) = &mut self.transcode {
+                transcode.bitrate_kbps = kbps;
+            }
+        }
+        match profile.output_format {
+            Some(OutputFormat::Mp3) => {
+                let transcode = self.transcode.get_or_insert_with(TranscodeOptions::default);
+                transcode.bitrate_kbps = self.audio_bitrate_kbps;
+            }
+            Some(OutputFormat::Original) => self.transcode = None,
+            None => {}
+        }
+        match profile.tag_policy {
+            Some(TagPolicy::None) => self.write_tags = false,
+            Some(TagPolicy::Tags) => {
+                self.write_tags = true;
+                self.embed_cover_art = false;
+            }
+            Some(TagPolicy::TagsAndCoverArt) => {
+                self.write_tags = true;
+                self.embed_cover_art = true;
+            }
+            None => {}
+        }
+        if let Some(template) = &profile.filename_template {
+            match FilenameTemplate::parse(template) {
+                Ok(template) => self.filename_template = template,
+                Err(e) => warn!("Ignoring the profile's filename template: {}", e),
+            }
+        }
+    }
+}
+
+/// Handle for steering a running download from another thread.
+///
+/// Clones share the same flags. Cancelling stops the whole download;
+/// cancelling the video only abandons the video being downloaded and moves
+/// on to the next one (every video in progress, when a playlist downloads
+/// several videos at the same time). Pausing suspends the stream between chunks without
+/// discarding the bytes downloaded so far.
+#[derive(Debug, Clone, Default)]
+pub struct DownloadControl {
+    cancel: Arc<AtomicBool>,
+    cancel_video: Arc<AtomicBool>,
+    paused: Arc<AtomicBool>,
+    token: CancellationToken,
+}
+
+impl DownloadControl {
+    /// Create a new control with no flag set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the flag cancelling the whole download.
+    #[must_use]
+    pub fn cancel_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.cancel)
+    }
+
+    /// Get a token cancelled along with the whole download, for awaiting
+    /// it with an [`AsyncYouTubeDownloader`].
+    ///
+    /// Unlike the flag, the token stays cancelled when the downloader is
+    /// reused.
+    #[must_use]
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
+    /// Cancel the whole download.
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::SeqCst);
+        self.token.cancel();
+    }
+
+    /// Whether the whole download was cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.load(Ordering::SeqCst)
+    }
+
+    /// Abandon the video being downloaded and continue with the next one.
+    pub fn cancel_video(&self) {
+        self.cancel_video.store(true, Ordering::SeqCst);
+    }
+
+    /// Suspend the download at the next chunk.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Continue a paused download.
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+    }
+
+    /// Whether the download is paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Whether `other` controls the same download.
+    #[must_use]
+    pub fn same_download(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.cancel, &other.cancel)
+    }
+
+    /// Whether the current video or the whole download was cancelled.
+    fn should_stop(&self) -> bool {
+        self.is_cancelled() || self.cancel_video.load(Ordering::SeqCst)
+    }
+
+    /// Sleep for `duration`, waking up early once the video or the whole
+    /// download is cancelled.
+    fn sleep(&self, duration: std::time::Duration) {
+        let deadline = std::time::Instant::now() + duration;
+        while !self.should_stop() {
+            let left = deadline.saturating_duration_since(std::time::Instant::now());
+            if left.is_zero() {
+                return;
+            }
+            std::thread::sleep(left.min(PAUSE_POLL_INTERVAL));
+        }
+    }
+
+    /// Clear the video cancellation, returning whether it was set.
+    fn take_video_cancelled(&self) -> bool {
+        self.cancel_video.swap(false, Ordering::SeqCst)
+    }
+
+    /// Wait while paused, returning [`DownloadError::Cancelled`] once the
+    /// video or the whole download is cancelled.
+    async fn checkpoint(&self) -> Result<()> {
+        loop {
+            if self.should_stop() {
+                return Err(Error::Download(DownloadError::Cancelled));
+            }
+            if !self.is_paused() {
+                return Ok(());
+            }
+            tokio::time::sleep(PAUSE_POLL_INTERVAL).await;
         }
     }
 }
 
+/// A track written to the output directory.
+struct DownloadedFile {
+    path: PathBuf,
+    sponsor_secs_removed: f64,
+    format: Option<AudioFormat>,
+    stream_path: StreamPath,
+    /// Files of the chapters, when the video was split.
+    chapter_paths: Vec<PathBuf>,
+}
+
+/// Bytes of a video's stream downloaded so far, reported after every chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct StreamBytes {
+    /// Bytes in the partial file, including those of an interrupted attempt.
+    downloaded: u64,
+    /// Size of the stream, if known.
+    total: Option<u64>,
+    /// Byte offset the download resumed from (0 when it started from the
+    /// beginning).
+    resumed_from: u64,
+    /// Bytes received since the previous report.
+    received: u64,
+}
+
+impl StreamBytes {
+    /// Progress of the video (0.1 - 0.5) while its stream downloads; the
+    /// conversion is the second half.
+    fn video_progress(&self) -> f64 {
+        let fraction = self.total.filter(|&total| total > 0).map_or(0.0, |total| {
+            (self.downloaded as f64 / total as f64).min(1.0)
+        });
+        fraction.mul_add(0.4, 0.1)
+    }
+
+    /// Whether the whole stream was downloaded.
+    fn is_complete(&self) -> bool {
+        self.total == Some(self.downloaded)
+    }
+}
+
+/// A video's stream downloaded into the temp area.
+struct DownloadedStream {
+    file: TempFile,
+    /// Format of the stream, if known.
+    format: Option<AudioFormat>,
+    stream_path: StreamPath,
+    /// Chapter markers of the video.
+    chapters: Vec<Chapter>,
+}
+
 /// Pure Rust `YouTube` downloader using `rusty_ytdl`.
 ///
 /// This implementation uses the `rusty_ytdl` library which is a pure Rust
@@ -837,8 +1964,9 @@ impl Default for RustyYtdlConfig {
 ///
 /// # Features
 ///
-/// - Pure Rust - no yt-dlp or ffmpeg dependencies
+/// - Pure Rust - no yt-dlp dependency
 /// - Downloads audio streams directly
+/// - Converts them to MP3 when `ffmpeg` is installed
 /// - Playlist parsing via HTML scraping
 /// - Progress tracking
 ///
@@ -856,46 +1984,124 @@ impl Default for RustyYtdlConfig {
 /// ```
 pub struct RustyYtdlDownloader {
     config: RustyYtdlConfig,
-    cancel_flag: Arc<AtomicBool>,
+    control: DownloadControl,
+    limiter: Option<RateLimiter>,
+    /// `yt-dlp`, unless only the built-in scraper is used.
+    yt_dlp: Option<YtDlpBackend>,
 }
 
 impl RustyYtdlDownloader {
     /// Create a new downloader with default configuration.
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            config: RustyYtdlConfig::default(),
-            cancel_flag: Arc::new(AtomicBool::new(false)),
-        }
+        Self::with_config(RustyYtdlConfig::default())
     }
 
     /// Create a new downloader with custom configuration.
     #[must_use]
     pub fn with_config(config: RustyYtdlConfig) -> Self {
+        let limiter = config
+            .max_speed_bps
+            .filter(|&bytes_per_sec| bytes_per_sec > 0)
+            .map(RateLimiter::new);
+        let control = DownloadControl::new();
+        let yt_dlp = (config.extraction.backend != ExtractionBackend::PureRust).then(|| {
+            YtDlpBackend::from_config(
+                &config,
+                config.extraction.yt_dlp_path.clone(),
+                control.clone(),
+            )
+        });
         Self {
             config,
-            cancel_flag: Arc::new(AtomicBool::new(false)),
+            control,
+            limiter,
+            yt_dlp,
         }
     }
 
     /// Get the cancel flag for external cancellation control.
     #[must_use]
     pub fn cancel_flag(&self) -> Arc<AtomicBool> {
-        Arc::clone(&self.cancel_flag)
+        self.control.cancel_flag()
+    }
+
+    /// Get the control for cancelling, pausing and resuming downloads from
+    /// another thread.
+    #[must_use]
+    pub fn control(&self) -> DownloadControl {
+        self.control.clone()
     }
 
     /// Cancel any ongoing download operation.
     pub fn cancel(&self) {
-        self.cancel_flag.store(true, Ordering::SeqCst);
+        self.control.cancel();
     }
 
-    /// Reset the cancel flag.
+    /// Reset the cancel flags.
     pub fn reset_cancel(&self) {
-        self.cancel_flag.store(false, Ordering::SeqCst);
+        self.control.cancel.store(false, Ordering::SeqCst);
+        self.control.cancel_video.store(false, Ordering::SeqCst);
+    }
+
+    /// Check whether offline mode is enabled for this downloader.
+    #[must_use]
+    pub const fn is_offline(&self) -> bool {
+        self.config.offline
+    }
+
+    /// Get the directory partial downloads are written to.
+    fn partial_download_dir(&self) -> PathBuf {
+        self.config
+            .temp_dir
+            .clone()
+            .unwrap_or_else(|| std::env::temp_dir().join("youtun4"))
+    }
+
+    /// Extract with the configured backend: `yt-dlp` when it is selected,
+    /// otherwise the built-in `scrape`, retried with `yt-dlp` in auto mode
+    /// when it can't read `YouTube`'s pages.
+    fn extract<T>(
+        &self,
+        scrape: impl FnOnce() -> Result<T>,
+        fallback: impl FnOnce(&YtDlpBackend) -> Result<T>,
+    ) -> Result<T> {
+        // Offline mode is reported by the scraper
+        let Some(backend) = self.yt_dlp.as_ref().filter(|_| !self.config.offline) else {
+            return scrape();
+        };
+        if self.config.extraction.backend == ExtractionBackend::YtDlp {
+            return fallback(backend);
+        }
+        scrape().or_else(|error| {
+            if !yt_dlp::is_extraction_failure(&error) {
+                return Err(error);
+            }
+            warn!("Built-in extraction failed, trying yt-dlp: {}", error);
+            fallback(backend).map_err(|fallback_error| {
+                warn!("yt-dlp could not extract either: {}", fallback_error);
+                error
+            })
+        })
     }
 
-    /// Fetch playlist info by scraping the `YouTube` playlist page.
-    fn fetch_playlist_info(&self, playlist_id: &str) -> Result<(String, Vec<VideoInfo>)> {
+    /// Find `ffmpeg` for MP3 conversion, if conversion is enabled.
+    fn transcoder(&self) -> Option<Transcoder> {
+        let options = self.config.transcode.as_ref()?;
+        let transcoder = Transcoder::locate(options);
+        if transcoder.is_none() {
+            warn!("ffmpeg not found, keeping downloaded streams in their original format");
+        }
+        transcoder
+    }
+
+    /// Fetch playlist info by scraping the `YouTube` playlist page, then
+    /// following continuation tokens until every page of videos is read.
+    fn fetch_playlist_info(
+        &self,
+        playlist_id: &str,
+        on_page: &dyn Fn(&PlaylistFetchProgress),
+    ) -> Result<(String, Vec<VideoInfo>)> {
         let url = format!("https://www.youtube.com/playlist?list={playlist_id}");
 
         info!("Fetching playlist page: {}", url);
@@ -928,11 +2134,134 @@ impl RustyYtdlDownloader {
             Self::extract_playlist_title(&html).unwrap_or_else(|| "Unknown Playlist".to_string());
 
         // Extract video IDs and titles from the page
-        let videos = Self::extract_videos_from_html(&html)?;
+        let first_page = Self::extract_videos_from_html(&html)?;
+        let mut videos = first_page.videos;
+        let mut continuation = first_page.continuation;
+        let mut progress = PlaylistFetchProgress {
+            playlist_id: playlist_id.to_string(),
+            pages_fetched: 1,
+            videos_found: videos.len(),
+        };
+        on_page(&progress);
+
+        let innertube = InnertubeClient::from_html(&html);
+        while let Some(token) = continuation.take() {
+            if progress.pages_fetched >= MAX_PLAYLIST_PAGES {
+                warn!(
+                    "Stopped paging playlist {} after {} pages",
+                    playlist_id, progress.pages_fetched
+                );
+                break;
+            }
+            if self.control.is_cancelled() {
+                return Err(Error::Download(DownloadError::Cancelled));
+            }
+
+            let page = Self::fetch_continuation(&client, &innertube, playlist_id, &token)?;
+            videos.extend(page.videos);
+            continuation = page.continuation;
+            progress.pages_fetched += 1;
+            progress.videos_found = videos.len();
+            debug!(
+                "Fetched page {} of playlist {} ({} videos so far)",
+                progress.pages_fetched, playlist_id, progress.videos_found
+            );
+            on_page(&progress);
+        }
 
         Ok((title, videos))
     }
 
+    /// Fetch the page of a playlist that `token` continues to.
+    fn fetch_continuation(
+        client: &reqwest::blocking::Client,
+        innertube: &InnertubeClient,
+        playlist_id: &str,
+        token: &str,
+    ) -> Result<PlaylistPage> {
+        let parse_failed = |reason: String| {
+            Error::Download(DownloadError::PlaylistParseFailed {
+                playlist_id: playlist_id.to_string(),
+                reason,
+            })
+        };
+
+        let body = serde_json::to_vec(&innertube.continuation_body(token))?;
+        let response = client
+            .post(innertube.browse_url())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("Accept-Language", "en-US,en;q=0.9")
+            .body(body)
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .map_err(|e| parse_failed(format!("Failed to fetch playlist continuation: {e}")))?;
+        let text = response
+            .text()
+            .map_err(|e| parse_failed(format!("Failed to read continuation: {e}")))?;
+        let json: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|e| parse_failed(format!("Failed to parse continuation: {e}")))?;
+
+        Self::find_continuation_items(&json)
+            .map(|items| Self::parse_playlist_items(items))
+            .ok_or_else(|| parse_failed("Continuation response has no playlist items".to_string()))
+    }
+
+    /// Resolve a channel page URL to the ID of the channel's uploads playlist.
+    fn resolve_channel_uploads(&self, channel_url: &str) -> Result<String> {
+        info!("Fetching channel page: {}", channel_url);
+
+        let client = reqwest::blocking::Client::new();
+        let response = client
+            .get(channel_url)
+            .header(
+                "User-Agent",
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36",
+            )
+            .header("Accept-Language", "en-US,en;q=0.9")
+            .send()
+            .map_err(|e| {
+                Error::Download(DownloadError::PlaylistParseFailed {
+                    playlist_id: channel_url.to_string(),
+                    reason: format!("Failed to fetch channel page: {e}"),
+                })
+            })?;
+
+        let html = response.text().map_err(|e| {
+            Error::Download(DownloadError::PlaylistParseFailed {
+                playlist_id: channel_url.to_string(),
+                reason: format!("Failed to read response: {e}"),
+            })
+        })?;
+
+        let uploads = Self::extract_channel_id(&html)
+            .as_deref()
+            .and_then(uploads_playlist_id)
+            .ok_or_else(|| {
+                Error::Download(DownloadError::PlaylistParseFailed {
+                    playlist_id: channel_url.to_string(),
+                    reason: "No channel ID found on the channel page".to_string(),
+                })
+            })?;
+
+        debug!("Channel {} has uploads playlist {}", channel_url, uploads);
+        Ok(uploads)
+    }
+
+    /// Extract the channel ID from a channel page.
+    fn extract_channel_id(html: &str) -> Option<String> {
+        // Most specific first: "channelId" also appears for featured channels
+        let patterns = [
+            r#"<link rel="canonical" href="https://www\.youtube\.com/channel/(UC[\w-]{22})""#,
+            r#""externalId":"(UC[\w-]{22})""#,
+            r#"<meta itemprop="identifier" content="(UC[\w-]{22})""#,
+            r#""channelId":"(UC[\w-]{22})""#,
+        ];
+        patterns.iter().find_map(|pattern| {
+            let re = Regex::new(pattern).ok()?;
+            Some(re.captures(html)?.get(1)?.as_str().to_string())
+        })
+    }
+
     /// Extract playlist title from HTML.
     fn extract_playlist_title(html: &str) -> Option<String> {
         // Try to find the title in the meta tag or page content
@@ -951,10 +2280,8 @@ impl RustyYtdlDownloader {
         None
     }
 
-    /// Extract video information from playlist HTML.
-    fn extract_videos_from_html(html: &str) -> Result<Vec<VideoInfo>> {
-        let mut videos = Vec::new();
-
+    /// Extract the first page of video information from playlist HTML.
+    fn extract_videos_from_html(html: &str) -> Result<PlaylistPage> {
         // YouTube embeds playlist data as JSON in the page
         // Look for: "playlistVideoListRenderer":{"contents":[...]
         // Or in ytInitialData
@@ -963,21 +2290,63 @@ impl RustyYtdlDownloader {
         let json_data = Self::extract_yt_initial_data(html)?;
 
         // Parse the JSON to extract video info
-        if let Some(contents) = Self::find_playlist_contents(&json_data) {
-            for item in contents {
-                if let Some(video) = Self::parse_playlist_item(item) {
-                    videos.push(video);
-                }
-            }
-        }
+        let mut page = Self::find_playlist_contents(&json_data)
+            .map(|contents| Self::parse_playlist_items(contents))
+            .unwrap_or_default();
 
-        if videos.is_empty() {
+        if page.videos.is_empty() {
             warn!("No videos found in playlist HTML, trying alternative extraction");
             // Fallback: try regex-based extraction
-            videos = Self::extract_videos_regex(html);
+            page.videos = Self::extract_videos_regex(html);
+        }
+
+        Ok(page)
+    }
+
+    /// Parse the videos of a page of playlist items and the token of the
+    /// next page, which `YouTube` appends as a `continuationItemRenderer`.
+    fn parse_playlist_items(items: &[serde_json::Value]) -> PlaylistPage {
+        PlaylistPage {
+            videos: items.iter().filter_map(Self::parse_playlist_item).collect(),
+            continuation: items.iter().find_map(Self::continuation_token),
         }
+    }
 
-        Ok(videos)
+    /// Continuation token of a `continuationItemRenderer` item.
+    fn continuation_token(item: &serde_json::Value) -> Option<String> {
+        let endpoint = item
+            .get("continuationItemRenderer")?
+            .get("continuationEndpoint")?;
+        let token = |command: &serde_json::Value| {
+            command
+                .get("continuationCommand")?
+                .get("token")?
+                .as_str()
+                .map(String::from)
+        };
+
+        // The command is either direct or wrapped in a command executor
+        token(endpoint).or_else(|| {
+            endpoint
+                .get("commandExecutorCommand")?
+                .get("commands")?
+                .as_array()?
+                .iter()
+                .find_map(token)
+        })
+    }
+
+    /// Find the playlist items in a `youtubei` continuation response.
+    fn find_continuation_items(json: &serde_json::Value) -> Option<&Vec<serde_json::Value>> {
+        json.get("onResponseReceivedActions")?
+            .as_array()?
+            .iter()
+            .find_map(|action| {
+                action
+                    .get("appendContinuationItemsAction")?
+                    .get("continuationItems")?
+                    .as_array()
+            })
     }
 
     /// Extract ytInitialData JSON from HTML.
@@ -1100,14 +2469,14 @@ impl RustyYtdlDownloader {
 
         let id = renderer.get("videoId")?.as_str()?.to_string();
 
-        let title = renderer
-            .get("title")?
-            .get("runs")?
-            .as_array()?
-            .first()?
-            .get("text")?
-            .as_str()?
+        // Unavailable videos have their placeholder title as simple text
+        let title = renderer.get("title")?;
+        let title = title
+            .get("runs")
+            .and_then(|runs| runs.as_array()?.first()?.get("text")?.as_str())
+            .or_else(|| title.get("simpleText")?.as_str())?
             .to_string();
+        let availability = Self::playlist_item_availability(renderer, &title);
 
         // Duration in seconds - try lengthSeconds first, then parse lengthText
         let duration_secs = renderer
@@ -1147,9 +2516,31 @@ impl RustyYtdlDownloader {
             duration_secs,
             channel,
             thumbnail_url,
+            availability,
+            chapters: Vec::new(),
         })
     }
 
+    /// Availability of a `playlistVideoRenderer` titled `title`.
+    ///
+    /// Private and deleted videos stay in playlists under a placeholder
+    /// title; other videos that can't be played here are marked as not
+    /// playable.
+    fn playlist_item_availability(renderer: &serde_json::Value, title: &str) -> VideoAvailability {
+        match title {
+            "[Private video]" => VideoAvailability::Private,
+            "[Deleted video]" => VideoAvailability::Deleted,
+            _ if renderer
+                .get("isPlayable")
+                .and_then(serde_json::Value::as_bool)
+                == Some(false) =>
+            {
+                VideoAvailability::GeoBlocked
+            }
+            _ => VideoAvailability::Available,
+        }
+    }
+
     /// Fallback: extract videos using regex patterns.
     fn extract_videos_regex(html: &str) -> Vec<VideoInfo> {
         let mut videos = Vec::new();
@@ -1183,6 +2574,8 @@ impl RustyYtdlDownloader {
                         duration_secs: None,
                         channel: None,
                         thumbnail_url: None,
+                        availability: VideoAvailability::Available,
+                        chapters: Vec::new(),
                     });
                 }
             }
@@ -1193,85 +2586,400 @@ impl RustyYtdlDownloader {
         videos
     }
 
-    /// Download a single video's audio stream.
-    fn download_single_video(
+    /// Download a single video's audio stream as `file_stem` in
+    /// `output_dir`, converting it to MP3 when a transcoder is given and
+    /// writing `tags` into the MP3.
+    ///
+    /// `on_bytes` receives the bytes of the stream downloaded after every
+    /// chunk, and once when an earlier partial download is continued;
+    /// `on_convert` receives the conversion progress (0.0 - 1.0).
+    #[allow(clippy::too_many_arguments)]
+    fn download_video_file(
         &self,
-        video_id: &str,
-        video_title: &str,
+        video: &VideoInfo,
         output_dir: &Path,
-    ) -> Result<PathBuf> {
-        // Use tokio runtime to run async rusty_ytdl code
-        // The blocking feature of rusty_ytdl hangs, so we use async API
+        file_stem: &str,
+        transcoder: Option<&Transcoder>,
+        tags: Option<TrackTags>,
+        on_bytes: impl FnMut(StreamBytes),
+        on_convert: impl FnMut(f64),
+    ) -> Result<DownloadedFile> {
+        let temp_dir = self.partial_download_dir();
+        let DownloadedStream {
+            file: partial,
+            format,
+            stream_path,
+            chapters,
+        } = self.download_stream(&video.id, &video.title, &temp_dir, &self.control, on_bytes)?;
+
+        let Some(transcoder) = transcoder else {
+            if self.config.split_by_chapters && chapters.len() > 1 {
+                warn!(
+                    "Keeping '{}' whole: splitting by chapters needs ffmpeg",
+                    video.title
+                );
+            }
+            let extension = format.as_ref().map_or("mp4", AudioFormat::extension);
+            let path = partial.persist(&output_dir.join(format!("{file_stem}.{extension}")))?;
+            self.fetch_lyrics(&path, video);
+            return Ok(DownloadedFile {
+                path,
+                sponsor_secs_removed: 0.0,
+                format,
+                stream_path,
+                chapter_paths: Vec::new(),
+            });
+        };
 
-        let video_id_owned = video_id.to_string();
-        let video_title_owned = video_title.to_string();
-        let output_dir_owned = output_dir.to_path_buf();
-
-        // Try to use existing runtime handle if we're inside a runtime context (e.g., spawn_blocking)
-        // Otherwise create a new runtime
-        if let Ok(handle) = tokio::runtime::Handle::try_current() {
-            // We're inside an existing runtime - use block_in_place to run async code
-            tokio::task::block_in_place(|| {
-                handle.block_on(async move {
-                    Self::download_single_video_async(
-                        &video_id_owned,
-                        &video_title_owned,
-                        &output_dir_owned,
-                    )
-                    .await
-                })
-            })
+        let cuts = self.sponsor_cuts(&video.id);
+        let sponsor_secs_removed = sponsorblock::total_cut(&cuts).as_secs_f64();
+        let chapter_tracks = if self.config.split_by_chapters {
+            chapters::chapter_tracks(&chapters, video.duration_secs, &cuts)
         } else {
-            // No runtime exists - create a new one
-            let rt = tokio::runtime::Runtime::new().map_err(|e| {
-                Error::Download(DownloadError::AudioExtractionFailed {
-                    title: video_title.to_string(),
-                    reason: format!("Failed to create tokio runtime: {e}"),
-                })
-            })?;
+            Vec::new()
+        };
+        let transcoder = transcoder.with_cuts(cuts);
+
+        let converted = TempFile::create_in(&temp_dir, &video.id, "mp3")?;
+        transcoder.transcode(
+            partial.path(),
+            converted.path(),
+            &video.title,
+            video.duration_secs,
+            || self.control.should_stop(),
+            on_convert,
+        )?;
+        drop(partial);
+
+        if !chapter_tracks.is_empty() {
+            let chapter_paths = self.split_chapters(
+                converted.path(),
+                video,
+                output_dir,
+                file_stem,
+                &transcoder,
+                &chapter_tracks,
+                tags.is_some(),
+            )?;
+            return Ok(DownloadedFile {
+                path: chapter_paths[0].clone(),
+                sponsor_secs_removed,
+                format,
+                stream_path,
+                chapter_paths,
+            });
+        }
 
-            rt.block_on(async move {
-                Self::download_single_video_async(
-                    &video_id_owned,
-                    &video_title_owned,
-                    &output_dir_owned,
-                )
-                .await
-            })
+        if let Some(tags) = tags {
+            self.tag_track(converted.path(), video, tags);
         }
+        let path = converted.persist(&output_dir.join(format!("{file_stem}.mp3")))?;
+        self.fetch_lyrics(&path, video);
+        Ok(DownloadedFile {
+            path,
+            sponsor_secs_removed,
+            format,
+            stream_path,
+            chapter_paths: Vec::new(),
+        })
     }
 
-    /// Async implementation of video download
-    async fn download_single_video_async(
-        video_id: &str,
-        video_title: &str,
+    /// Copy each of `tracks` of the MP3 `converted` into its own file in
+    /// `output_dir`, named after `file_stem` and tagged (when `write_tags`)
+    /// as a track of an album named after `video`.
+    #[allow(clippy::too_many_arguments)]
+    fn split_chapters(
+        &self,
+        converted: &Path,
+        video: &VideoInfo,
         output_dir: &Path,
-    ) -> Result<PathBuf> {
-        let video_url = format!("https://www.youtube.com/watch?v={video_id}");
+        file_stem: &str,
+        transcoder: &Transcoder,
+        tracks: &[ChapterTrack],
+        write_tags: bool,
+    ) -> Result<Vec<PathBuf>> {
+        info!("Splitting '{}' into {} chapters", video.title, tracks.len());
+        let temp_dir = self.partial_download_dir();
+        let cover = if write_tags && self.config.embed_cover_art {
+            fetch_cover_art(video)
+        } else {
+            None
+        };
 
-        debug!(
-            "Downloading audio for {} using rusty_ytdl (async)",
-            video_id
-        );
+        let mut paths = Vec::with_capacity(tracks.len());
+        for (index, track) in tracks.iter().enumerate() {
+            if self.control.should_stop() {
+                return Err(Error::Download(DownloadError::Cancelled));
+            }
+            let part = TempFile::create_in(&temp_dir, &video.id, "mp3")?;
+            transcoder.extract(converted, part.path(), track.start, track.end, &track.title)?;
+            if write_tags {
+                let tags = TrackTags {
+                    title: track.title.clone(),
+                    artist: video.channel.clone(),
+                    album: Some(video.title.clone()),
+                    track_number: u32::try_from(index + 1).ok(),
+                    total_tracks: u32::try_from(tracks.len()).ok(),
+                    cover: cover.clone(),
+                };
+                if let Err(e) = metadata::write_tags(part.path(), &tags) {
+                    warn!("Failed to tag '{}': {}", track.title, e);
+                }
+            }
+            let name =
+                chapters::chapter_file_name(file_stem, index + 1, tracks.len(), &track.title);
+            paths.push(part.persist(&output_dir.join(format!("{name}.mp3")))?);
+        }
+        Ok(paths)
+    }
 
-        // Use VideoAudio (combined stream) with Lowest quality for smallest file size
-        // Audio-only streams (Audio filter) often get 403 Forbidden errors from YouTube
-        // VideoAudio combined streams are more reliable
-        let video_opts = VideoOptions {
-            quality: VideoQuality::Lowest,          // Smallest combined stream
-            filter: VideoSearchOptions::VideoAudio, // Combined video+audio (more reliable)
-            ..Default::default()
+    /// Write `tags` into the MP3 at `path`, adding the thumbnail of `video`
+    /// as cover art when enabled. Untagged tracks still play, so a failure
+    /// is only logged.
+    fn tag_track(&self, path: &Path, video: &VideoInfo, mut tags: TrackTags) {
+        if self.config.embed_cover_art {
+            tags.cover = fetch_cover_art(video);
+        }
+        if let Err(e) = metadata::write_tags(path, &tags) {
+            warn!("Failed to tag '{}': {}", video.title, e);
+        }
+    }
+
+    /// Look up the lyrics of `video` and save them with the track at
+    /// `path`, when enabled. Missing lyrics don't fail the download.
+    fn fetch_lyrics(&self, path: &Path, video: &VideoInfo) {
+        let Some(config) = self.config.lyrics.as_ref() else {
+            return;
         };
+        let found = LyricsClient::new(config.clone()).fetch(
+            &video.title,
+            video.channel.as_deref(),
+            video.duration_secs,
+        );
+        match found {
+            Ok(Some(found)) => match lyrics::save_lyrics(path, &found, config) {
+                Ok(_) => info!("Saved lyrics of '{}'", video.title),
+                Err(e) => warn!("Failed to save lyrics of '{}': {}", video.title, e),
+            },
+            Ok(None) => debug!("No lyrics found for '{}'", video.title),
+            Err(e) => warn!("Failed to fetch lyrics of '{}': {}", video.title, e),
+        }
+    }
 
-        let video = Video::new_with_options(&video_url, video_opts).map_err(|e| {
-            Error::Download(DownloadError::VideoUnavailable {
-                video_id: video_id.to_string(),
-                reason: format!("Failed to create video instance: {e}"),
-            })
-        })?;
+    /// Time ranges of `SponsorBlock` segments to cut from `video_id`, when
+    /// enabled. A failed lookup keeps the track untrimmed.
+    fn sponsor_cuts(&self, video_id: &str) -> Vec<std::ops::Range<std::time::Duration>> {
+        let Some(config) = self.config.sponsorblock.as_ref().filter(|c| c.enabled) else {
+            return Vec::new();
+        };
+        match SponsorBlockClient::new(config.clone()).fetch_segments(video_id) {
+            Ok(segments) => {
+                let cuts = sponsorblock::cut_ranges(&segments);
+                if !cuts.is_empty() {
+                    info!(
+                        "Removing {} SponsorBlock segments from {}",
+                        cuts.len(),
+                        video_id
+                    );
+                }
+                cuts
+            }
+            Err(e) => {
+                warn!("Keeping {} untrimmed: {}", video_id, e);
+                Vec::new()
+            }
+        }
+    }
 
-        // Get video info for logging
-        let video_info = video.get_info().await.map_err(|e| {
+    /// Download a video's stream into a temp file in `temp_dir`.
+    fn download_stream(
+        &self,
+        video_id: &str,
+        video_title: &str,
+        temp_dir: &Path,
+        control: &DownloadControl,
+        on_bytes: impl FnMut(StreamBytes),
+    ) -> Result<DownloadedStream> {
+        // The blocking feature of rusty_ytdl hangs, so we use async API
+        nonblocking::block_on(Self::download_stream_async(
+            video_id,
+            video_title,
+            temp_dir,
+            self.config.audio_bitrate_kbps,
+            control,
+            self.limiter.as_ref(),
+            on_bytes,
+        ))?
+    }
+
+    /// Async implementation of the stream download, trying each client of
+    /// [`PlayerClient::ROTATION`] until one can download the video.
+    async fn download_stream_async(
+        video_id: &str,
+        video_title: &str,
+        temp_dir: &Path,
+        target_kbps: u32,
+        control: &DownloadControl,
+        limiter: Option<&RateLimiter>,
+        mut on_bytes: impl FnMut(StreamBytes),
+    ) -> Result<DownloadedStream> {
+        let mut failures = Vec::new();
+        for client in PlayerClient::ROTATION {
+            debug!("Downloading {} as the {} client", video_id, client);
+            let attempt = match client {
+                PlayerClient::Web => {
+                    Self::download_web_stream(
+                        video_id,
+                        video_title,
+                        temp_dir,
+                        target_kbps,
+                        control,
+                        limiter,
+                        &mut on_bytes,
+                    )
+                    .await
+                }
+                PlayerClient::Android | PlayerClient::Ios => {
+                    Self::download_client_stream(
+                        client,
+                        video_id,
+                        video_title,
+                        temp_dir,
+                        target_kbps,
+                        control,
+                        limiter,
+                        &mut on_bytes,
+                    )
+                    .await
+                }
+            };
+            match attempt {
+                Ok(stream) => {
+                    if !failures.is_empty() {
+                        info!("Downloaded {} as the {} client", video_id, client);
+                    }
+                    return Ok(stream);
+                }
+                Err(e) if player::should_rotate(&e) => {
+                    warn!(
+                        "The {} client could not download {}: {}",
+                        client, video_id, e
+                    );
+                    failures.push((client, e));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(player::rotation_failed(video_id, &failures))
+    }
+
+    /// Download the audio stream `YouTube` serves `client` for `video_id`,
+    /// fetching the audio-only format closest to `target_kbps`.
+    #[allow(clippy::too_many_arguments)]
+    async fn download_client_stream(
+        client: PlayerClient,
+        video_id: &str,
+        video_title: &str,
+        temp_dir: &Path,
+        target_kbps: u32,
+        control: &DownloadControl,
+        limiter: Option<&RateLimiter>,
+        mut on_bytes: impl FnMut(StreamBytes),
+    ) -> Result<DownloadedStream> {
+        let formats = client
+            .fetch_formats(&reqwest::Client::new(), video_id)
+            .await?;
+        let ranked = quality::rank_formats(
+            formats
+                .into_iter()
+                .filter(|format| !format.has_video && format.content_length.is_some())
+                .collect(),
+            target_kbps,
+        );
+
+        let mut last_error = None;
+        for format in ranked.iter().take(quality::MAX_AUDIO_ONLY_ATTEMPTS) {
+            let Some(content_length) = format.content_length else {
+                continue;
+            };
+            info!(
+                "Downloading format {} of {} as the {} client",
+                format.itag, video_id, client
+            );
+            match Self::download_ranged(
+                &format.url,
+                content_length,
+                video_id,
+                video_title,
+                temp_dir,
+                control,
+                limiter,
+                &mut on_bytes,
+            )
+            .await
+            {
+                Ok(file) => {
+                    return Ok(DownloadedStream {
+                        file,
+                        format: Some(format.clone()),
+                        stream_path: StreamPath::new(false, false),
+                        chapters: Vec::new(),
+                    });
+                }
+                Err(e) if retry::classify(&e) == Some(RetryCategory::Forbidden) => {
+                    warn!(
+                        "Format {} of {} was refused to the {} client: {}",
+                        format.itag, video_id, client, e
+                    );
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            Error::Download(DownloadError::VideoUnavailable {
+                video_id: video_id.to_string(),
+                reason: format!("no audio stream of known size for the {client} client"),
+            })
+        }))
+    }
+
+    /// Download the stream `rusty_ytdl` finds as the web client, fetching
+    /// the format closest to `target_kbps`.
+    #[allow(clippy::too_many_lines)]
+    async fn download_web_stream(
+        video_id: &str,
+        video_title: &str,
+        temp_dir: &Path,
+        target_kbps: u32,
+        control: &DownloadControl,
+        limiter: Option<&RateLimiter>,
+        mut on_bytes: impl FnMut(StreamBytes),
+    ) -> Result<DownloadedStream> {
+        let video_url = format!("https://www.youtube.com/watch?v={video_id}");
+
+        debug!(
+            "Downloading audio for {} using rusty_ytdl (async)",
+            video_id
+        );
+
+        // When no format of known size works, fall back to the smallest
+        // combined stream, which rusty_ytdl streams most reliably
+        let video_opts = || VideoOptions {
+            quality: VideoQuality::Lowest,          // Smallest combined stream
+            filter: VideoSearchOptions::VideoAudio, // Combined video+audio (more reliable)
+            ..Default::default()
+        };
+
+        let video = Video::new_with_options(&video_url, video_opts()).map_err(|e| {
+            Error::Download(DownloadError::VideoUnavailable {
+                video_id: video_id.to_string(),
+                reason: format!("Failed to create video instance: {e}"),
+            })
+        })?;
+
+        // Get video info for logging
+        let video_info = video.get_info().await.map_err(|e| {
             Error::Download(DownloadError::VideoUnavailable {
                 video_id: video_id.to_string(),
                 reason: format!("Failed to get video info: {e}"),
@@ -1284,10 +2992,77 @@ impl RustyYtdlDownloader {
             video_id,
             video_info.formats.len()
         );
+        let chapters = Chapter::from_ytdl(&video_info.video_details.chapters);
 
-        // Use mp4 extension for the combined stream
-        let sanitized_title = sanitize_filename(video_title);
-        let output_path = output_dir.join(format!("{sanitized_title}.mp4"));
+        // Streams of known size are fetched with range requests so an
+        // interrupted download continues where it stopped
+        let ranked = quality::rank_formats(
+            video_info
+                .formats
+                .iter()
+                .filter_map(AudioFormat::from_ytdl)
+                .filter(|format| format.content_length.is_some())
+                .collect(),
+            target_kbps,
+        );
+        // Audio-only streams are sometimes refused, so the best combined
+        // stream is kept as a fallback
+        let audio_only = ranked
+            .iter()
+            .filter(|format| !format.has_video)
+            .take(quality::MAX_AUDIO_ONLY_ATTEMPTS);
+        let combined = ranked.iter().find(|format| format.has_video);
+        let mut refused = false;
+        for format in audio_only.chain(combined) {
+            let Some(content_length) = format.content_length else {
+                continue;
+            };
+            info!(
+                "Downloading format {} of {} ({} kbps {}, target {} kbps)",
+                format.itag,
+                video_id,
+                format
+                    .bitrate_kbps
+                    .map_or_else(|| "?".to_string(), |kbps| kbps.to_string()),
+                format.codec.as_deref().unwrap_or("unknown codec"),
+                target_kbps
+            );
+            match Self::download_ranged(
+                &format.url,
+                content_length,
+                video_id,
+                video_title,
+                temp_dir,
+                control,
+                limiter,
+                &mut on_bytes,
+            )
+            .await
+            {
+                Ok(file) => {
+                    return Ok(DownloadedStream {
+                        file,
+                        format: Some(format.clone()),
+                        stream_path: StreamPath::new(format.has_video, refused),
+                        chapters,
+                    });
+                }
+                Err(e)
+                    if !format.has_video
+                        && retry::classify(&e) == Some(RetryCategory::Forbidden) =>
+                {
+                    warn!(
+                        "Audio-only format {} of {} was refused: {}",
+                        format.itag, video_id, e
+                    );
+                    refused = true;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        let fallback_format = rusty_ytdl::choose_format(&video_info.formats, &video_opts())
+            .ok()
+            .and_then(|format| AudioFormat::from_ytdl(&format));
 
         // Download using stream API with chunks (async)
         let stream = video.stream().await.map_err(|e| {
@@ -1297,9 +3072,13 @@ impl RustyYtdlDownloader {
             })
         })?;
 
-        info!("Stream content length: {} bytes", stream.content_length());
+        let content_length = stream.content_length() as u64;
+        info!("Stream content length: {} bytes", content_length);
 
-        let mut file = std::fs::File::create(&output_path).map_err(|e| {
+        // Stream into the temp area; the guard removes the partial file if
+        // anything below fails
+        let partial = TempFile::create_in(temp_dir, video_id, "mp4")?;
+        let mut file = std::fs::File::create(partial.path()).map_err(|e| {
             Error::Download(DownloadError::AudioExtractionFailed {
                 title: video_title.to_string(),
                 reason: format!("Failed to create file: {e}"),
@@ -1314,6 +3093,10 @@ impl RustyYtdlDownloader {
                 reason: format!("Failed to download chunk: {e}"),
             })
         })? {
+            control.checkpoint().await?;
+            if let Some(limiter) = limiter {
+                limiter.acquire(chunk.len() as u64).await;
+            }
             total_bytes += chunk.len() as u64;
             file.write_all(&chunk).map_err(|e| {
                 Error::Download(DownloadError::AudioExtractionFailed {
@@ -1321,35 +3104,145 @@ impl RustyYtdlDownloader {
                     reason: format!("Failed to write chunk: {e}"),
                 })
             })?;
+            on_bytes(StreamBytes {
+                downloaded: total_bytes,
+                total: (content_length > 0).then_some(content_length),
+                resumed_from: 0,
+                received: chunk.len() as u64,
+            });
         }
+        drop(file);
 
         info!(
-            "Successfully downloaded {} bytes: {} -> {:?}",
-            total_bytes, video_title, output_path
+            "Successfully downloaded {} bytes: {}",
+            total_bytes, video_title
         );
-        Ok(output_path)
+        Ok(DownloadedStream {
+            file: partial,
+            format: fallback_format,
+            stream_path: StreamPath::new(true, refused),
+            chapters,
+        })
     }
 
-    /// Get info for a single video.
-    pub fn get_video_info(&self, video_id: &str) -> Result<VideoInfo> {
-        let video_id_owned = video_id.to_string();
+    /// Download `stream_url` with range requests into a resumable partial
+    /// file, continuing an earlier attempt when one was interrupted.
+    #[allow(clippy::too_many_arguments)]
+    async fn download_ranged(
+        stream_url: &str,
+        content_length: u64,
+        video_id: &str,
+        video_title: &str,
+        temp_dir: &Path,
+        control: &DownloadControl,
+        limiter: Option<&RateLimiter>,
+        mut on_bytes: impl FnMut(StreamBytes),
+    ) -> Result<TempFile> {
+        use std::io::Write;
 
-        // Try to use existing runtime handle if available
-        if let Ok(handle) = tokio::runtime::Handle::try_current() {
-            tokio::task::block_in_place(|| {
-                handle.block_on(Self::get_video_info_async(&video_id_owned))
+        let extraction_failed = |reason: String| {
+            Error::Download(DownloadError::AudioExtractionFailed {
+                title: video_title.to_string(),
+                reason,
             })
-        } else {
-            // No runtime exists - create a new one
-            let rt = tokio::runtime::Runtime::new().map_err(|e| {
-                Error::Download(DownloadError::VideoUnavailable {
-                    video_id: video_id.to_string(),
-                    reason: format!("Failed to create tokio runtime: {e}"),
-                })
+        };
+
+        let partial = PartialDownload::open(temp_dir, video_id, content_length)?;
+        let resumed_from = partial.resume_from();
+        let mut offset = resumed_from;
+        if offset > 0 {
+            on_bytes(StreamBytes {
+                downloaded: offset,
+                total: Some(content_length),
+                resumed_from,
+                received: 0,
+            });
+        }
+
+        let mut file = partial.append()?;
+        let client = reqwest::Client::new();
+        while offset < content_length {
+            control.checkpoint().await?;
+            let end = (offset + RANGE_CHUNK_SIZE).min(content_length) - 1;
+            let response = client
+                .get(stream_url)
+                .header(reqwest::header::RANGE, format!("bytes={offset}-{end}"))
+                .send()
+                .await
+                .map_err(|e| {
+                    Error::Download(DownloadError::Network {
+                        message: format!(
+                            "Failed to request bytes {offset}-{end} of '{video_title}': {e}"
+                        ),
+                        source: Some(Box::new(e)),
+                    })
+                })?;
+            if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                return Err(Error::Download(DownloadError::RateLimited {
+                    retry_after_secs: retry_after_secs(&response),
+                }));
+            }
+            let mut response = response.error_for_status().map_err(|e| {
+                extraction_failed(format!("Failed to request bytes {offset}-{end}: {e}"))
             })?;
 
-            rt.block_on(Self::get_video_info_async(&video_id_owned))
+            // A server ignoring the range resends the whole stream, which is
+            // only usable when starting from zero
+            if offset > 0 && response.status() != reqwest::StatusCode::PARTIAL_CONTENT {
+                drop(file);
+                partial.discard();
+                return Err(extraction_failed(
+                    "Server does not support resuming downloads".to_string(),
+                ));
+            }
+
+            let mut received = 0u64;
+            while let Some(chunk) = response
+                .chunk()
+                .await
+                .map_err(|e| extraction_failed(format!("Failed to download chunk: {e}")))?
+            {
+                // Bytes already written stay in the partial file, so a
+                // cancelled video resumes from here next time
+                control.checkpoint().await?;
+                if let Some(limiter) = limiter {
+                    limiter.acquire(chunk.len() as u64).await;
+                }
+                file.write_all(&chunk)
+                    .map_err(|e| extraction_failed(format!("Failed to write chunk: {e}")))?;
+                received += chunk.len() as u64;
+                on_bytes(StreamBytes {
+                    downloaded: offset + received,
+                    total: Some(content_length),
+                    resumed_from,
+                    received: chunk.len() as u64,
+                });
+            }
+            if received == 0 {
+                return Err(extraction_failed(format!(
+                    "Empty response at byte {offset}"
+                )));
+            }
+            offset += received;
+        }
+        drop(file);
+
+        info!(
+            "Successfully downloaded {} bytes: {}",
+            content_length, video_title
+        );
+        partial.finish(temp_dir)
+    }
+
+    /// Get info for a single video.
+    pub fn get_video_info(&self, video_id: &str) -> Result<VideoInfo> {
+        if self.config.offline {
+            return Err(Error::offline(format!(
+                "fetching info for video {video_id}"
+            )));
         }
+
+        nonblocking::block_on(Self::get_video_info_async(video_id))?
     }
 
     /// Async implementation of `get_video_info`
@@ -1378,30 +3271,80 @@ impl RustyYtdlDownloader {
             duration_secs: details.length_seconds.parse().ok(),
             channel: details.author.as_ref().map(|a| a.name.clone()),
             thumbnail_url: details.thumbnails.last().map(|t| t.url.clone()),
+            availability: VideoAvailability::Available,
+            chapters: Chapter::from_ytdl(&details.chapters),
         })
     }
-}
 
-impl Default for RustyYtdlDownloader {
-    fn default() -> Self {
-        Self::new()
+    /// Parse a `YouTube` URL and fetch the full video list of the playlist,
+    /// calling `on_page` after each page of videos is fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or not a playlist, or if a
+    /// page of the playlist can't be fetched.
+    pub fn parse_playlist_url_with_progress(
+        &self,
+        url: &str,
+        on_page: &dyn Fn(&PlaylistFetchProgress),
+    ) -> Result<PlaylistInfo> {
+        self.extract(
+            || self.scrape_playlist(url, on_page),
+            |backend| backend.parse_playlist_url(url),
+        )
     }
-}
 
-impl YouTubeDownloader for RustyYtdlDownloader {
-    fn parse_playlist_url(&self, url: &str) -> Result<PlaylistInfo> {
+    /// Fetch the playlist of `url` with the built-in scraper.
+    fn scrape_playlist(
+        &self,
+        url: &str,
+        on_page: &dyn Fn(&PlaylistFetchProgress),
+    ) -> Result<PlaylistInfo> {
         // First validate the URL
-        let playlist_id = extract_playlist_id(url)?;
+        let validation = validate_youtube_url(url);
+        let playlist_id = if validation.needs_channel_resolution() {
+            let channel_url = validation.normalized_url.unwrap_or_else(|| url.to_string());
+            if self.config.offline {
+                return Err(Error::offline(format!("resolving channel {channel_url}")));
+            }
+            self.resolve_channel_uploads(&channel_url)?
+        } else {
+            extract_playlist_id(url)?
+        };
+
+        if self.config.offline {
+            return Err(Error::offline(format!(
+                "fetching playlist info for {playlist_id}"
+            )));
+        }
 
         info!("Fetching playlist info for: {}", playlist_id);
 
         // Fetch playlist info by scraping the page
-        let (title, videos) = self.fetch_playlist_info(&playlist_id)?;
+        let start = std::time::Instant::now();
+        let fetched = if mix::is_mix(&playlist_id) {
+            self.fetch_mix_info(&playlist_id, validation.video_id.as_deref(), on_page)
+        } else {
+            self.fetch_playlist_info(&playlist_id, on_page)
+        };
+        metrics::record_scrape(start.elapsed());
+        let (title, videos) = fetched?;
 
         let video_count = videos.len();
         let thumbnail_url = videos.first().and_then(|v| v.thumbnail_url.clone());
+        let health = PlaylistHealth::from_videos(&videos);
 
         info!("Parsed playlist '{}' with {} videos", title, video_count);
+        if health.unavailable() > 0 {
+            info!(
+                "{} videos of '{}' are unavailable: {} private, {} deleted, {} geo-blocked",
+                health.unavailable(),
+                title,
+                health.private,
+                health.deleted,
+                health.geo_blocked
+            );
+        }
 
         Ok(PlaylistInfo {
             id: playlist_id,
@@ -1409,10 +3352,37 @@ impl YouTubeDownloader for RustyYtdlDownloader {
             video_count,
             videos,
             thumbnail_url,
+            health,
         })
     }
+}
+
+impl Default for RustyYtdlDownloader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl YouTubeDownloader for RustyYtdlDownloader {
+    fn parse_playlist_url(&self, url: &str) -> Result<PlaylistInfo> {
+        self.parse_playlist_url_with_progress(url, &|_| {})
+    }
+
+    fn cancel_download(&self) {
+        self.cancel();
+    }
+
+    fn parse_video_url(&self, url: &str) -> Result<VideoInfo> {
+        self.extract(
+            || {
+                let video_id = extract_video_id(url)?;
+                info!("Fetching video info for: {}", video_id);
+                self.get_video_info(&video_id)
+            },
+            |backend| backend.parse_video_url(url),
+        )
+    }
 
-    #[allow(clippy::too_many_lines)]
     fn download_playlist(
         &self,
         playlist_info: &PlaylistInfo,
@@ -1422,6 +3392,18 @@ impl YouTubeDownloader for RustyYtdlDownloader {
         // Reset cancel flag at start
         self.reset_cancel();
 
+        if self.config.dry_run {
+            let plan = self.plan_playlist(playlist_info, output_dir)?;
+            return Ok(plan.into_results(&playlist_info.videos));
+        }
+
+        if self.config.extraction.backend == ExtractionBackend::YtDlp
+            && !self.config.offline
+            && let Some(backend) = &self.yt_dlp
+        {
+            return backend.download_playlist(playlist_info, output_dir, progress);
+        }
+
         info!(
             "Starting download of playlist '{}' ({} videos) to {}",
             playlist_info.title,
@@ -1439,162 +3421,830 @@ impl YouTubeDownloader for RustyYtdlDownloader {
             })?;
         }
 
-        let mut results = Vec::with_capacity(playlist_info.videos.len());
         let total_videos = playlist_info.videos.len();
+        let transcoder = if self.config.offline {
+            None
+        } else {
+            self.transcoder()
+        };
+        let workers = self.config.concurrency.clamp(1, total_videos.max(1));
 
         // Create progress tracker for this download operation
-        let mut tracker = DownloadProgressTracker::new(total_videos);
+        let run = PlaylistRun {
+            output_dir,
+            transcoder: transcoder.as_ref(),
+            tracker: std::sync::Mutex::new(DownloadProgressTracker::new(total_videos)),
+            progress: progress.as_ref(),
+            concurrent: workers > 1,
+            archive: DownloadArchive::open(output_dir),
+            album: playlist_info.album(),
+            total_tracks: u32::try_from(total_videos).unwrap_or(u32::MAX),
+        };
 
-        for (index, video) in playlist_info.videos.iter().enumerate() {
-            // Check for cancellation
-            if self.cancel_flag.load(Ordering::SeqCst) {
-                info!("Download cancelled by user");
+        let results = if workers == 1 {
+            playlist_info
+                .videos
+                .iter()
+                .enumerate()
+                .map(|(index, video)| self.download_playlist_video(&run, index + 1, video))
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            info!("Downloading up to {} videos at the same time", workers);
+            self.download_concurrently(&run, &playlist_info.videos, workers)?
+        };
+
+        // Positions let the next update tell which videos were moved
+        if run.album.is_some()
+            && let Err(e) = run.archive.record_positions(&playlist_info.videos)
+        {
+            warn!("Failed to record playlist positions: {}", e);
+        }
+
+        // Log summary
+        let successful = results.iter().filter(|r| r.success).count();
+        let failed = results.len() - successful;
+        info!(
+            "Download complete: {} successful, {} failed, elapsed: {:.1}s",
+            successful,
+            failed,
+            run.tracker().elapsed_secs()
+        );
+
+        Ok(results)
+    }
+}
+
+impl RustyYtdlDownloader {
+    /// Work out what downloading `playlist_info` into `output_dir` would
+    /// do, without downloading or writing anything.
+    ///
+    /// Sizes come from the format metadata of the stream a download would
+    /// pick, or from the video's length at the configured bitrate when the
+    /// stream can't be looked up (offline, or refused).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the plan is cancelled.
+    pub fn plan_playlist(
+        &self,
+        playlist_info: &PlaylistInfo,
+        output_dir: &Path,
+    ) -> Result<DownloadPlan> {
+        let archive = DownloadArchive::open(output_dir);
+        let album = playlist_info.album();
+        let keep_original = self.config.transcode.is_none();
+
+        let mut videos = Vec::with_capacity(playlist_info.videos.len());
+        for (position, video) in playlist_info.videos.iter().enumerate() {
+            if self.control.is_cancelled() {
                 return Err(Error::Download(DownloadError::Cancelled));
             }
+            let file_stem = self.config.filename_template.render(&TrackFields {
+                title: &video.title,
+                artist: video.channel.as_deref(),
+                index: position + 1,
+                video_id: &video.id,
+                playlist: album,
+            });
 
-            let current_index = index + 1;
+            let archived = archive.get(&video.id);
+            let existing = match &archived {
+                Some(archived) => archived
+                    .file_name
+                    .as_ref()
+                    .map(|name| output_dir.join(name)),
+                None => find_existing_download(output_dir, &file_stem),
+            };
+            let already_downloaded = archived.is_some() || existing.is_some();
+            let unavailable_reason = video.availability.unavailable_reason().map(str::to_string);
+            let will_download = !already_downloaded && unavailable_reason.is_none();
+
+            let format = (will_download && !self.config.offline)
+                .then(|| self.planned_format(&video.id))
+                .flatten();
+            let duration_secs = video
+                .duration_secs
+                .or_else(|| format.as_ref().and_then(format_duration_secs));
+            let extension = if keep_original {
+                format.as_ref().map_or("mp4", AudioFormat::extension)
+            } else {
+                "mp3"
+            };
 
-            // Report progress: starting
-            if let Some(ref callback) = progress {
-                callback(tracker.create_progress(
-                    current_index,
-                    &video.title,
-                    0.0,
-                    DownloadStatus::Starting,
-                    0,
-                    None,
-                ));
+            videos.push(PlannedDownload {
+                video_id: video.id.clone(),
+                title: video.title.clone(),
+                output_path: existing
+                    .unwrap_or_else(|| output_dir.join(format!("{file_stem}.{extension}"))),
+                already_downloaded,
+                unavailable_reason,
+                duration_secs,
+                estimated_size_bytes: if will_download {
+                    estimate_file_size(
+                        format.as_ref(),
+                        duration_secs,
+                        self.config.audio_bitrate_kbps,
+                        keep_original,
+                    )
+                } else {
+                    0
+                },
+                from_format: format.is_some(),
+            });
+        }
+
+        Ok(DownloadPlan {
+            playlist_id: playlist_info.id.clone(),
+            title: playlist_info.title.clone(),
+            bitrate_kbps: self.config.audio_bitrate_kbps,
+            videos,
+        })
+    }
+
+    /// The audio-only stream a download of `video_id` would pick, or `None`
+    /// when its formats can't be looked up.
+    fn planned_format(&self, video_id: &str) -> Option<AudioFormat> {
+        let formats = match nonblocking::block_on(Self::fetch_web_formats(video_id)) {
+            Ok(Ok(formats)) => formats,
+            Ok(Err(e)) | Err(e) => {
+                debug!("No format metadata for {}: {}", video_id, e);
+                return None;
             }
+        };
+        quality::rank_formats(
+            formats
+                .into_iter()
+                .filter(|format| !format.has_video)
+                .collect(),
+            self.config.audio_bitrate_kbps,
+        )
+        .into_iter()
+        .next()
+    }
 
-            // Check if file already exists (check multiple extensions)
-            let sanitized_title = sanitize_filename(&video.title);
-            let extensions = ["m4a", "webm", "audio", "mp3"];
-            let existing_file = extensions
-                .iter()
-                .map(|ext| output_dir.join(format!("{sanitized_title}.{ext}")))
-                .find(|p| p.exists());
+    /// The formats the web client is offered for `video_id`.
+    async fn fetch_web_formats(video_id: &str) -> Result<Vec<AudioFormat>> {
+        let unavailable = |reason: String| {
+            Error::Download(DownloadError::VideoUnavailable {
+                video_id: video_id.to_string(),
+                reason,
+            })
+        };
+        let video_url = format!("https://www.youtube.com/watch?v={video_id}");
+        let video = Video::new(&video_url)
+            .map_err(|e| unavailable(format!("Failed to create video instance: {e}")))?;
+        let info = video
+            .get_info()
+            .await
+            .map_err(|e| unavailable(format!("Failed to get video info: {e}")))?;
+        Ok(info
+            .formats
+            .iter()
+            .filter_map(AudioFormat::from_ytdl)
+            .collect())
+    }
 
-            if let Some(existing_path) = existing_file {
-                info!("Skipping existing file: {}", video.title);
-                tracker.video_skipped();
-                if let Some(ref callback) = progress {
-                    callback(tracker.create_progress(
-                        current_index,
-                        &video.title,
-                        1.0,
-                        DownloadStatus::Skipped,
-                        0,
-                        None,
-                    ));
+    /// Estimate the size and length of the audio of `playlist_info`.
+    ///
+    /// The streams of a few videos spread over the playlist are looked up,
+    /// and their size per second of audio is applied to the whole playlist.
+    /// Offline, or when no stream can be looked up, sizes come from the
+    /// configured bitrate alone. Estimates are kept in `cache` until the
+    /// playlist changes length.
+    #[must_use]
+    pub fn estimate_playlist_size(
+        &self,
+        playlist_info: &PlaylistInfo,
+        mut cache: Option<&mut CacheManager>,
+    ) -> PlaylistSizeEstimate {
+        let bitrate_kbps = self.config.audio_bitrate_kbps;
+        let original_audio = self.config.transcode.is_none();
+        let available: Vec<&VideoInfo> = playlist_info
+            .videos
+            .iter()
+            .filter(|video| video.availability == VideoAvailability::Available)
+            .collect();
+        let unavailable = playlist_info.videos.len() - available.len();
+        let video_count = playlist_info
+            .video_count
+            .max(playlist_info.videos.len())
+            .saturating_sub(unavailable);
+
+        if let Some(cached) = cache.as_deref_mut().and_then(|cache| {
+            cache
+                .get_size_estimate(&playlist_info.id, bitrate_kbps, original_audio)
+                .inspect_err(|e| warn!("Failed to read the cached size estimate: {}", e))
+                .ok()
+                .flatten()
+        }) && cached.video_count == video_count
+        {
+            return cached;
+        }
+
+        // Sampled videos count with their stream's length and size
+        let step = available.len().div_ceil(SIZE_ESTIMATE_SAMPLES).max(1);
+        let mut durations: Vec<Option<u64>> =
+            available.iter().map(|video| video.duration_secs).collect();
+        let (mut sampled_count, mut sampled_secs, mut sampled_bytes) = (0, 0, 0);
+        if !self.config.offline {
+            for (index, video) in available.iter().enumerate().step_by(step) {
+                if self.control.is_cancelled() {
+                    break;
                 }
-                results.push(DownloadResult {
-                    video: video.clone(),
-                    success: true,
-                    output_path: Some(existing_path),
-                    error: None,
-                });
-                continue;
+                let Some(format) = self.planned_format(&video.id) else {
+                    continue;
+                };
+                let duration = video
+                    .duration_secs
+                    .or_else(|| format_duration_secs(&format));
+                durations[index] = duration;
+                let Some(duration) = duration.filter(|&secs| secs > 0) else {
+                    continue;
+                };
+                sampled_count += 1;
+                sampled_secs += duration;
+                sampled_bytes +=
+                    estimate_file_size(Some(&format), Some(duration), bitrate_kbps, original_audio);
             }
+        }
 
-            // Report progress: downloading
-            if let Some(ref callback) = progress {
-                callback(tracker.create_progress(
-                    current_index,
-                    &video.title,
-                    0.1,
-                    DownloadStatus::Downloading,
-                    0,
-                    None,
-                ));
-            }
+        // Videos of unknown length count as the average of the known ones,
+        // as do videos missing from a truncated listing
+        let known: Vec<u64> = durations.iter().flatten().copied().collect();
+        let known_total: u64 = known.iter().sum();
+        let average = if known.is_empty() {
+            DEFAULT_TRACK_DURATION_SECS
+        } else {
+            known_total / known.len() as u64
+        };
+        let total_duration_secs =
+            known_total + average * video_count.saturating_sub(known.len()) as u64;
+        let estimated_size_bytes = if sampled_secs > 0 {
+            total_duration_secs * sampled_bytes / sampled_secs
+        } else {
+            total_duration_secs * u64::from(bitrate_kbps) * 1000 / 8
+        };
 
-            // Download the video with retries
-            let mut last_error = None;
-            let mut success = false;
-            let mut output_path = None;
-
-            for attempt in 1..=self.config.retries {
-                match self.download_single_video(&video.id, &video.title, output_dir) {
-                    Ok(path) => {
-                        // Get file size for bytes tracking
-                        let file_size = path.metadata().map(|m| m.len()).unwrap_or(0);
-                        tracker.record_progress(tracker.total_bytes_downloaded + file_size);
-                        tracker.video_completed();
+        let estimate = PlaylistSizeEstimate {
+            playlist_id: playlist_info.id.clone(),
+            title: playlist_info.title.clone(),
+            video_count,
+            sampled_count,
+            total_duration_secs,
+            bitrate_kbps,
+            original_audio,
+            estimated_size_bytes,
+        };
+        // Estimates without any stream are cheap to redo once online
+        if sampled_count > 0
+            && let Some(cache) = cache
+            && let Err(e) = cache.put_size_estimate(&estimate)
+        {
+            warn!(
+                "Failed to cache the size estimate of {}: {}",
+                estimate.playlist_id, e
+            );
+        }
+        estimate
+    }
+}
 
-                        if let Some(ref callback) = progress {
-                            callback(tracker.create_progress(
-                                current_index,
-                                &video.title,
-                                1.0,
-                                DownloadStatus::Completed,
-                                file_size,
-                                Some(file_size),
-                            ));
-                        }
-                        output_path = Some(path);
-                        success = true;
-                        break;
-                    }
-                    Err(e) => {
-                        warn!(
-                            "Download attempt {}/{} failed for '{}': {}",
-                            attempt, self.config.retries, video.title, e
-                        );
-                        last_error = Some(e);
+impl DownloadPlan {
+    /// The plan as the results of a dry run of `videos`, the playlist's
+    /// videos in plan order.
+    fn into_results(self, videos: &[VideoInfo]) -> Vec<DownloadResult> {
+        self.videos
+            .into_iter()
+            .zip(videos)
+            .map(|(planned, video)| DownloadResult {
+                video: video.clone(),
+                success: planned.unavailable_reason.is_none(),
+                output_path: planned
+                    .unavailable_reason
+                    .is_none()
+                    .then_some(planned.output_path),
+                error: planned.unavailable_reason,
+                sponsor_secs_removed: 0.0,
+                audio_bitrate_kbps: None,
+                audio_codec: None,
+                stream_path: None,
+                chapter_paths: Vec::new(),
+                estimated_size_bytes: Some(planned.estimated_size_bytes),
+            })
+            .collect()
+    }
+}
+
+/// Length in seconds of an audio-only `format`, from its size and bitrate.
+fn format_duration_secs(format: &AudioFormat) -> Option<u64> {
+    if format.has_video {
+        return None;
+    }
+    let kbps = u64::from(format.bitrate_kbps.filter(|&kbps| kbps > 0)?);
+    Some(format.content_length? * 8 / (kbps * 1000))
+}
+
+/// Estimated size in bytes of the file saved from `format`, a stream
+/// `duration_secs` long: the stream's own size when it is kept as is,
+/// otherwise its length converted at `target_kbps`.
+fn estimate_file_size(
+    format: Option<&AudioFormat>,
+    duration_secs: Option<u64>,
+    target_kbps: u32,
+    keep_original: bool,
+) -> u64 {
+    if keep_original && let Some(length) = format.and_then(|format| format.content_length) {
+        return length;
+    }
+    let kbps = if keep_original {
+        format
+            .and_then(|format| format.bitrate_kbps)
+            .unwrap_or(target_kbps)
+    } else {
+        target_kbps
+    };
+    duration_secs.unwrap_or(DEFAULT_TRACK_DURATION_SECS) * u64::from(kbps) * 1000 / 8
+}
+
+/// State shared by the videos of a playlist download.
+struct PlaylistRun<'a> {
+    output_dir: &'a Path,
+    transcoder: Option<&'a Transcoder>,
+    tracker: std::sync::Mutex<DownloadProgressTracker>,
+    progress: Option<&'a ProgressCallback>,
+    /// Whether several videos are downloaded at the same time.
+    concurrent: bool,
+    /// Videos downloaded into the output directory before.
+    archive: DownloadArchive,
+    /// Album tag of the tracks; `None` for single videos.
+    album: Option<&'a str>,
+    /// Number of videos in the playlist.
+    total_tracks: u32,
+}
+
+impl PlaylistRun<'_> {
+    /// Lock the progress tracker, recovering from a poisoned lock.
+    fn tracker(&self) -> std::sync::MutexGuard<'_, DownloadProgressTracker> {
+        self.tracker
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Tags of `video` at `position` in the playlist.
+    fn track_tags(&self, video: &VideoInfo, position: usize) -> TrackTags {
+        let playlist = self.album.is_some();
+        TrackTags {
+            title: video.title.clone(),
+            artist: video.channel.clone(),
+            album: self.album.map(str::to_string),
+            track_number: u32::try_from(position).ok().filter(|_| playlist),
+            total_tracks: playlist.then_some(self.total_tracks),
+            cover: None,
+        }
+    }
 
-                        if attempt < self.config.retries {
-                            // Wait before retry
-                            std::thread::sleep(std::time::Duration::from_secs(2));
+    /// Add `video` to the archive. A failed write only means the video may
+    /// be downloaded again, so it doesn't fail the video.
+    fn archive_video(&self, video: &VideoInfo, path: Option<&Path>) {
+        if let Err(e) = self.archive.record(video, path) {
+            warn!("Failed to archive '{}': {}", video.title, e);
+        }
+    }
+
+    /// Update the tracker and report the progress it creates.
+    ///
+    /// The tracker stays locked while reporting so updates of concurrent
+    /// videos arrive in the order they were created.
+    fn report(&self, update: impl FnOnce(&mut DownloadProgressTracker) -> DownloadProgress) {
+        let mut tracker = self.tracker();
+        let progress = update(&mut tracker);
+        if let Some(callback) = self.progress {
+            callback(progress);
+        }
+    }
+}
+
+impl RustyYtdlDownloader {
+    /// Download `videos` on `workers` threads, each taking the next video
+    /// once its previous one is done, so at most `workers` videos download
+    /// at the same time. Results keep the playlist order.
+    fn download_concurrently(
+        &self,
+        run: &PlaylistRun<'_>,
+        videos: &[VideoInfo],
+        workers: usize,
+    ) -> Result<Vec<DownloadResult>> {
+        let next = std::sync::atomic::AtomicUsize::new(0);
+        let slots: Vec<std::sync::Mutex<Option<DownloadResult>>> =
+            videos.iter().map(|_| std::sync::Mutex::default()).collect();
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| -> Result<()> {
+                        loop {
+                            let position = next.fetch_add(1, Ordering::SeqCst);
+                            let Some(video) = videos.get(position) else {
+                                return Ok(());
+                            };
+                            let result = self.download_playlist_video(run, position + 1, video)?;
+                            *slots[position]
+                                .lock()
+                                .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(result);
                         }
-                    }
-                }
+                    })
+                })
+                .collect();
+
+            // Join every worker before reporting the first error
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|_| Err(Error::internal("Download worker panicked")))
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .collect::<Result<()>>()
+        })?;
+
+        slots
+            .into_iter()
+            .map(|slot| {
+                slot.into_inner()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .ok_or_else(|| Error::internal("Download worker skipped a video"))
+            })
+            .collect()
+    }
+
+    /// Download one video of a playlist, with retries.
+    ///
+    /// A video that can't be downloaded is a failed result; only cancelling
+    /// the whole download is an error.
+    #[allow(clippy::too_many_lines)]
+    fn download_playlist_video(
+        &self,
+        run: &PlaylistRun<'_>,
+        current_index: usize,
+        video: &VideoInfo,
+    ) -> Result<DownloadResult> {
+        // Check for cancellation
+        if self.control.is_cancelled() {
+            info!("Download cancelled by user");
+            return Err(Error::Download(DownloadError::Cancelled));
+        }
+        // A video cancellation only applies to the video it was made for.
+        // Concurrent downloads clear it once handled instead, as it applies
+        // to every video in progress.
+        if !run.concurrent {
+            self.control.cancel_video.store(false, Ordering::SeqCst);
+        }
+
+        // Report progress: starting
+        run.report(|tracker| {
+            tracker.set_video_progress(current_index, 0.0);
+            tracker.create_progress(
+                current_index,
+                &video.title,
+                0.0,
+                DownloadStatus::Starting,
+                0,
+                None,
+            )
+        });
+
+        let file_stem = self.config.filename_template.render(&TrackFields {
+            title: &video.title,
+            artist: video.channel.as_deref(),
+            index: current_index,
+            video_id: &video.id,
+            playlist: run.album,
+        });
+
+        // Videos in the archive were downloaded before, even if their file
+        // has since been renamed
+        let archived = run.archive.get(&video.id);
+        let existing_file = if let Some(archived) = &archived {
+            info!("Skipping archived video: {}", video.title);
+            archived
+                .file_name
+                .as_ref()
+                .map(|name| run.output_dir.join(name))
+                .filter(|p| p.exists())
+        } else {
+            let existing_file = find_existing_download(run.output_dir, &file_stem);
+            if let Some(existing_path) = &existing_file {
+                info!("Skipping existing file: {}", video.title);
+                run.archive_video(video, Some(existing_path));
             }
+            existing_file
+        };
+
+        if archived.is_some() || existing_file.is_some() {
+            run.report(|tracker| {
+                tracker.video_skipped();
+                tracker.video_finished(current_index);
+                tracker.create_progress(
+                    current_index,
+                    &video.title,
+                    1.0,
+                    DownloadStatus::Skipped,
+                    0,
+                    None,
+                )
+            });
+            return Ok(DownloadResult {
+                video: video.clone(),
+                success: true,
+                output_path: existing_file,
+                error: None,
+                sponsor_secs_removed: 0.0,
+                audio_bitrate_kbps: None,
+                audio_codec: None,
+                stream_path: None,
+                chapter_paths: Vec::new(),
+                estimated_size_bytes: None,
+            });
+        }
 
-            if success {
-                results.push(DownloadResult {
-                    video: video.clone(),
-                    success: true,
-                    output_path,
-                    error: None,
-                });
-            } else {
-                let error_msg =
-                    last_error.map_or_else(|| "Unknown error".to_string(), |e| e.to_string());
-                error!("Failed to download '{}': {}", video.title, error_msg);
+        // Private, deleted and blocked videos fail the same way every time
+        let unavailable = video.availability.unavailable_reason().map(|reason| {
+            Error::Download(DownloadError::VideoUnavailable {
+                video_id: video.id.clone(),
+                reason: reason.to_string(),
+            })
+        });
+        // Missing files cannot be fetched while offline
+        let offline = self
+            .config
+            .offline
+            .then(|| Error::offline(format!("downloading '{}'", video.title)));
+        if let Some(error) = unavailable.or(offline) {
+            let error_msg = error.to_string();
+            run.report(|tracker| {
                 tracker.video_failed();
-                if let Some(ref callback) = progress {
-                    callback(tracker.create_progress(
+                tracker.video_finished(current_index);
+                tracker.create_progress(
+                    current_index,
+                    &video.title,
+                    0.0,
+                    DownloadStatus::Failed(error_msg.clone()),
+                    0,
+                    None,
+                )
+            });
+            return Ok(DownloadResult {
+                video: video.clone(),
+                success: false,
+                output_path: None,
+                error: Some(error_msg),
+                sponsor_secs_removed: 0.0,
+                audio_bitrate_kbps: None,
+                audio_codec: None,
+                stream_path: None,
+                chapter_paths: Vec::new(),
+                estimated_size_bytes: None,
+            });
+        }
+
+        // Report progress: downloading
+        run.report(|tracker| {
+            tracker.set_video_progress(current_index, 0.1);
+            tracker.create_progress(
+                current_index,
+                &video.title,
+                0.1,
+                DownloadStatus::Downloading,
+                0,
+                None,
+            )
+        });
+
+        // Download the video with retries
+        let policy = &self.config.retry;
+        let max_attempts = policy.max_attempts.max(1);
+        let mut last_error = None;
+
+        for attempt in 1..=max_attempts {
+            // The download itself is the first half of the video's progress
+            let on_convert = |fraction: f64| {
+                let current_progress = fraction.mul_add(0.5, 0.5);
+                run.report(|tracker| {
+                    tracker.set_video_progress(current_index, current_progress);
+                    tracker.create_progress(
                         current_index,
                         &video.title,
-                        0.0,
-                        DownloadStatus::Failed(error_msg.clone()),
+                        current_progress,
+                        DownloadStatus::Converting,
                         0,
                         None,
-                    ));
+                    )
+                });
+            };
+            // Chunks arrive far more often than progress is worth reporting
+            let mut last_report: Option<std::time::Instant> = None;
+            let mut unreported = 0;
+            let on_bytes = |bytes: StreamBytes| {
+                unreported += bytes.received;
+                if !bytes.is_complete()
+                    && last_report.is_some_and(|at| at.elapsed() < BYTE_PROGRESS_INTERVAL)
+                {
+                    return;
                 }
-                results.push(DownloadResult {
-                    video: video.clone(),
-                    success: false,
-                    output_path: None,
-                    error: Some(error_msg),
+                last_report = Some(std::time::Instant::now());
+                let received = std::mem::take(&mut unreported);
+                let current_progress = bytes.video_progress();
+                run.report(|tracker| {
+                    tracker.record_progress(tracker.total_bytes_downloaded + received);
+                    tracker.set_video_progress(current_index, current_progress);
+                    let mut update = tracker.create_progress(
+                        current_index,
+                        &video.title,
+                        current_progress,
+                        DownloadStatus::Downloading,
+                        bytes.downloaded,
+                        bytes.total,
+                    );
+                    update.resumed_from_bytes = bytes.resumed_from;
+                    update
                 });
+            };
+            let result = if self.control.should_stop() {
+                // Cancelled while waiting to retry
+                Err(Error::Download(DownloadError::Cancelled))
+            } else {
+                self.download_video_file(
+                    video,
+                    run.output_dir,
+                    &file_stem,
+                    run.transcoder,
+                    self.config
+                        .write_tags
+                        .then(|| run.track_tags(video, current_index)),
+                    on_bytes,
+                    on_convert,
+                )
+            };
+            match result {
+                Ok(downloaded) => {
+                    let path = downloaded.path;
+                    // Size of the track, over every chapter of a split video
+                    let files = if downloaded.chapter_paths.is_empty() {
+                        std::slice::from_ref(&path)
+                    } else {
+                        downloaded.chapter_paths.as_slice()
+                    };
+                    let file_size = files
+                        .iter()
+                        .map(|file| file.metadata().map_or(0, |m| m.len()))
+                        .sum();
+                    run.report(|tracker| {
+                        tracker.video_completed();
+                        tracker.video_finished(current_index);
+                        tracker.create_progress(
+                            current_index,
+                            &video.title,
+                            1.0,
+                            DownloadStatus::Completed,
+                            file_size,
+                            Some(file_size),
+                        )
+                    });
+                    run.archive_video(video, Some(&path));
+                    return Ok(DownloadResult {
+                        video: video.clone(),
+                        success: true,
+                        output_path: Some(path),
+                        error: None,
+                        sponsor_secs_removed: downloaded.sponsor_secs_removed,
+                        audio_bitrate_kbps: downloaded
+                            .format
+                            .as_ref()
+                            .and_then(|format| format.bitrate_kbps),
+                        audio_codec: downloaded.format.and_then(|format| format.codec),
+                        stream_path: Some(downloaded.stream_path),
+                        chapter_paths: downloaded.chapter_paths,
+                        estimated_size_bytes: None,
+                    });
+                }
+                Err(Error::Download(DownloadError::Cancelled)) => {
+                    let video_cancelled = self.control.take_video_cancelled();
+                    if self.control.is_cancelled() || !(video_cancelled || run.concurrent) {
+                        info!("Download cancelled by user");
+                        return Err(Error::Download(DownloadError::Cancelled));
+                    }
+                    info!("Download of '{}' cancelled by user", video.title);
+                    last_error = Some(Error::Download(DownloadError::Cancelled));
+                    break;
+                }
+                Err(e) => {
+                    warn!(
+                        "Download attempt {}/{} failed for '{}': {}",
+                        attempt, max_attempts, video.title, e
+                    );
+                    // Private or deleted videos fail the same way every time
+                    if !policy.should_retry(&e, attempt) {
+                        last_error = Some(e);
+                        break;
+                    }
+                    let delay = policy.backoff(attempt, &e);
+                    info!("Retrying '{}' in {:.1}s", video.title, delay.as_secs_f64());
+                    last_error = Some(e);
+                    self.control.sleep(delay);
+                }
             }
         }
 
-        // Log summary
-        let successful = results.iter().filter(|r| r.success).count();
-        let failed = results.len() - successful;
-        info!(
-            "Download complete: {} successful, {} failed, elapsed: {:.1}s",
-            successful,
-            failed,
-            tracker.elapsed_secs()
-        );
+        let error_msg = last_error.map_or_else(|| "Unknown error".to_string(), |e| e.to_string());
+        error!("Failed to download '{}': {}", video.title, error_msg);
+        run.report(|tracker| {
+            tracker.video_failed();
+            tracker.video_finished(current_index);
+            tracker.create_progress(
+                current_index,
+                &video.title,
+                0.0,
+                DownloadStatus::Failed(error_msg.clone()),
+                0,
+                None,
+            )
+        });
+        Ok(DownloadResult {
+            video: video.clone(),
+            success: false,
+            output_path: None,
+            error: Some(error_msg),
+            sponsor_secs_removed: 0.0,
+            audio_bitrate_kbps: None,
+            audio_codec: None,
+            stream_path: None,
+            chapter_paths: Vec::new(),
+            estimated_size_bytes: None,
+        })
+    }
+}
 
-        Ok(results)
+/// File a video titled `title` was saved as in `output_dir` by an earlier
+/// download, matched by file name.
+#[must_use]
+pub fn find_existing_download(output_dir: &Path, title: &str) -> Option<PathBuf> {
+    let sanitized_title = sanitize_filename(title);
+    ["mp3", "m4a", "webm", "mp4", "audio"]
+        .iter()
+        .map(|ext| output_dir.join(format!("{sanitized_title}.{ext}")))
+        .find(|path| path.exists())
+}
+
+/// Thumbnail of `video` as cover art, or `None` when it can't be fetched.
+fn fetch_cover_art(video: &VideoInfo) -> Option<CoverArt> {
+    let url = video
+        .thumbnail_url
+        .clone()
+        .unwrap_or_else(|| format!("https://i.ytimg.com/vi/{}/hqdefault.jpg", video.id));
+    let response = reqwest::blocking::Client::new()
+        .get(&url)
+        .send()
+        .and_then(reqwest::blocking::Response::error_for_status);
+    let response = match response {
+        Ok(response) => response,
+        Err(e) => {
+            warn!("Failed to fetch cover art of '{}': {}", video.title, e);
+            return None;
+        }
+    };
+    let mime_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .filter(|mime_type| mime_type.starts_with("image/"))
+        .unwrap_or("image/jpeg")
+        .to_string();
+    match response.bytes() {
+        Ok(data) if !data.is_empty() => Some(CoverArt {
+            mime_type,
+            data: data.to_vec(),
+        }),
+        Ok(_) => None,
+        Err(e) => {
+            warn!("Failed to fetch cover art of '{}': {}", video.title, e);
+            None
+        }
     }
 }
 
+/// Delay in seconds asked for by a response's `Retry-After` header, or 0
+/// when it has none (or gives a date).
+fn retry_after_secs(response: &reqwest::Response) -> u64 {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(0)
+}
+
 /// Parse duration text like "3:45" or "1:23:45" into seconds.
 fn parse_duration_text(text: &str) -> Option<u64> {
     let parts: Vec<&str> = text.split(':').collect();
@@ -1687,9 +4337,21 @@ impl YtDlpDownloader {
     /// Create a new downloader with custom configuration.
     #[must_use]
     pub fn with_config(config: YtDlpConfig) -> Self {
+        let transcode = config.audio_quality.parse().map_or_else(
+            |_| TranscodeOptions::default(),
+            TranscodeOptions::with_bitrate,
+        );
         let rusty_config = RustyYtdlConfig {
             timeout_secs: config.timeout_secs,
-            retries: config.retries,
+            retry: RetryPolicy {
+                max_attempts: config.retries,
+                ..RetryPolicy::default()
+            },
+            audio_bitrate_kbps: transcode.bitrate_kbps,
+            transcode: Some(transcode),
+            write_tags: config.add_metadata,
+            embed_cover_art: config.add_metadata && config.embed_thumbnail,
+            ..RustyYtdlConfig::default()
         };
         Self {
             inner: RustyYtdlDownloader::with_config(rusty_config),
@@ -1702,6 +4364,13 @@ impl YtDlpDownloader {
         self.inner.cancel_flag()
     }
 
+    /// Get the control for cancelling, pausing and resuming downloads from
+    /// another thread.
+    #[must_use]
+    pub fn control(&self) -> DownloadControl {
+        self.inner.control()
+    }
+
     /// Cancel any ongoing download operation.
     pub fn cancel(&self) {
         self.inner.cancel();
@@ -1726,6 +4395,14 @@ impl YouTubeDownloader for YtDlpDownloader {
         self.inner.parse_playlist_url(url)
     }
 
+    fn parse_video_url(&self, url: &str) -> Result<VideoInfo> {
+        self.inner.parse_video_url(url)
+    }
+
+    fn cancel_download(&self) {
+        self.inner.cancel();
+    }
+
     fn download_playlist(
         &self,
         playlist_info: &PlaylistInfo,
@@ -1838,6 +4515,11 @@ mod tests {
 
             assert!(result.is_valid);
             assert_eq!(result.playlist_id, Some("RDxxxxxxxx".to_string()));
+            assert!(result.is_mix());
+            assert_eq!(
+                result.normalized_url.as_deref(),
+                Some("https://www.youtube.com/watch?v=abc&list=RDxxxxxxxx")
+            );
         }
 
         #[test]
@@ -1876,130 +4558,522 @@ mod tests {
             assert!(result.error_message.unwrap().contains("http"));
         }
 
-        #[test]
-        fn test_invalid_single_video_no_playlist() {
-            let url = "https://www.youtube.com/watch?v=dQw4w9WgXcQ";
-            let result = validate_youtube_url(url);
+        #[test]
+        fn test_invalid_single_video_no_playlist() {
+            let url = "https://www.youtube.com/watch?v=dQw4w9WgXcQ";
+            let result = validate_youtube_url(url);
+
+            assert!(!result.is_valid);
+            assert_eq!(result.url_type, YouTubeUrlType::SingleVideo);
+            assert!(result.is_single_video());
+            assert_eq!(result.video_id.as_deref(), Some("dQw4w9WgXcQ"));
+            assert_eq!(
+                result.normalized_url.as_deref(),
+                Some("https://www.youtube.com/watch?v=dQw4w9WgXcQ")
+            );
+            assert!(result.error_message.unwrap().contains("single video"));
+        }
+
+        #[test]
+        fn test_invalid_short_url_no_playlist() {
+            let url = "https://youtu.be/dQw4w9WgXcQ";
+            let result = validate_youtube_url(url);
+
+            assert!(!result.is_valid);
+            assert_eq!(result.url_type, YouTubeUrlType::ShortUrl);
+            assert!(result.is_single_video());
+            assert_eq!(result.video_id.as_deref(), Some("dQw4w9WgXcQ"));
+        }
+
+        #[test]
+        fn test_invalid_empty_list_parameter() {
+            let url = "https://www.youtube.com/playlist?list=";
+            let result = validate_youtube_url(url);
+
+            assert!(!result.is_valid);
+        }
+
+        #[test]
+        fn test_invalid_playlist_id_too_short() {
+            let url = "https://www.youtube.com/playlist?list=X";
+            let result = validate_youtube_url(url);
+
+            assert!(!result.is_valid);
+            assert!(result.error_message.unwrap().contains("too short"));
+        }
+
+        #[test]
+        fn test_invalid_playlist_id_special_chars() {
+            let url = "https://www.youtube.com/playlist?list=PL<script>alert(1)</script>";
+            let result = validate_youtube_url(url);
+
+            assert!(!result.is_valid);
+            assert!(result.error_message.unwrap().contains("invalid characters"));
+        }
+
+        #[test]
+        fn test_url_with_trailing_whitespace() {
+            let url = "  https://www.youtube.com/playlist?list=PLtest123  ";
+            let result = validate_youtube_url(url);
+
+            assert!(result.is_valid);
+            assert_eq!(result.playlist_id, Some("PLtest123".to_string()));
+        }
+
+        #[test]
+        fn test_url_with_hash_fragment() {
+            let url = "https://www.youtube.com/playlist?list=PLtest123#section";
+            let result = validate_youtube_url(url);
+
+            assert!(result.is_valid);
+            assert_eq!(result.playlist_id, Some("PLtest123".to_string()));
+        }
+
+        #[test]
+        fn test_normalized_url_format() {
+            let url = "https://youtube.com/watch?v=abc&list=PLtest123";
+            let result = validate_youtube_url(url);
+
+            assert!(result.is_valid);
+            assert_eq!(
+                result.normalized_url,
+                Some("https://www.youtube.com/playlist?list=PLtest123".to_string())
+            );
+        }
+    }
+
+    // =========================================================================
+    // Playlist ID Validation Tests
+    // =========================================================================
+
+    mod url_candidate_tests {
+        use super::*;
+
+        #[test]
+        fn test_finds_and_classifies_urls_in_text() {
+            let text = "Check this (https://www.youtube.com/playlist?list=PLrAXtmErZgOei), \
+                        this video youtu.be/abc123 and https://example.com/page.";
+            let candidates = find_url_candidates(text);
+
+            assert_eq!(candidates.len(), 3);
+            assert_eq!(
+                candidates[0].url,
+                "https://www.youtube.com/playlist?list=PLrAXtmErZgOei"
+            );
+            assert_eq!(candidates[0].kind, UrlCandidateKind::Playlist);
+            assert_eq!(candidates[0].playlist_id.as_deref(), Some("PLrAXtmErZgOei"));
+            assert_eq!(candidates[1].url, "https://youtu.be/abc123");
+            assert_eq!(candidates[1].kind, UrlCandidateKind::Video);
+            assert!(candidates[1].message.is_some());
+            assert_eq!(candidates[2].url, "https://example.com/page");
+            assert_eq!(candidates[2].kind, UrlCandidateKind::Invalid);
+        }
+
+        #[test]
+        fn test_uri_list_skips_comments_and_duplicates() {
+            let text = "# dragged from browser\r\n\
+                        https://www.youtube.com/watch?v=abc&list=PLtest123\r\n\
+                        https://www.youtube.com/watch?v=abc&list=PLtest123\r\n";
+            let candidates = find_url_candidates(text);
+
+            assert_eq!(candidates.len(), 1);
+            assert_eq!(candidates[0].kind, UrlCandidateKind::Playlist);
+            assert_eq!(
+                candidates[0].normalized_url.as_deref(),
+                Some("https://www.youtube.com/playlist?list=PLtest123")
+            );
+        }
+
+        #[test]
+        fn test_text_without_urls() {
+            assert!(find_url_candidates("").is_empty());
+            assert!(find_url_candidates("just some words about youtube").is_empty());
+        }
+    }
+
+    mod playlist_id_format_tests {
+        use super::*;
+
+        #[test]
+        fn test_valid_pl_prefix() {
+            assert!(validate_playlist_id_format("PLrAXtmErZgOeiKm4sgNOknGvNjby9efdf").is_ok());
+        }
+
+        #[test]
+        fn test_valid_uu_prefix() {
+            assert!(validate_playlist_id_format("UUxxxxxxxxxxxxxxxxxxxxxxxx").is_ok());
+        }
+
+        #[test]
+        fn test_valid_rd_prefix() {
+            assert!(validate_playlist_id_format("RDxxxxxxxx").is_ok());
+        }
+
+        #[test]
+        fn test_valid_olak_prefix() {
+            assert!(validate_playlist_id_format("OLAK5uy_xxxxxxxxxxxxxxxxx").is_ok());
+        }
+
+        #[test]
+        fn test_invalid_too_short() {
+            assert!(validate_playlist_id_format("X").is_err());
+        }
+
+        #[test]
+        fn test_invalid_too_long() {
+            let long_id = "PL".to_string() + &"x".repeat(100);
+            assert!(validate_playlist_id_format(&long_id).is_err());
+        }
+
+        #[test]
+        fn test_invalid_special_characters() {
+            assert!(validate_playlist_id_format("PL<>test").is_err());
+            assert!(validate_playlist_id_format("PL test").is_err());
+            assert!(validate_playlist_id_format("PL@test").is_err());
+        }
+
+        #[test]
+        fn test_valid_with_underscore_and_hyphen() {
+            assert!(validate_playlist_id_format("PLtest_123-abc").is_ok());
+        }
+    }
+
+    // =========================================================================
+    // Video ID Extraction Tests
+    // =========================================================================
+
+    mod video_id_tests {
+        use super::*;
+
+        #[test]
+        fn test_extract_video_id_formats() {
+            for url in [
+                "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+                "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42",
+                "https://www.youtube.com/watch?list=PLtest123&v=dQw4w9WgXcQ",
+                "https://youtu.be/dQw4w9WgXcQ",
+                "https://youtu.be/dQw4w9WgXcQ?list=PLtest123",
+            ] {
+                assert_eq!(extract_video_id(url).unwrap(), "dQw4w9WgXcQ", "{url}");
+            }
+        }
+
+        #[test]
+        fn test_playlist_with_video_keeps_both_ids() {
+            let result =
+                validate_youtube_url("https://www.youtube.com/watch?v=dQw4w9WgXcQ&list=PLtest123");
+            assert!(result.is_valid);
+            assert!(!result.is_single_video());
+            assert_eq!(result.playlist_id.as_deref(), Some("PLtest123"));
+            assert_eq!(result.video_id.as_deref(), Some("dQw4w9WgXcQ"));
+        }
+
+        #[test]
+        fn test_extract_video_id_rejects_urls_without_video() {
+            assert!(extract_video_id("https://www.youtube.com/playlist?list=PLtest123").is_err());
+            assert!(extract_video_id("https://www.youtube.com/watch?v=").is_err());
+            assert!(extract_video_id("https://www.youtube.com/watch?av=abc").is_err());
+            assert!(extract_video_id("https://example.com/watch?v=abc").is_err());
+        }
+
+        #[test]
+        fn test_extract_ids_after_non_ascii_query() {
+            // `İ` grows by a byte when lowercased with Unicode rules
+            let url = "https://www.youtube.com/watch?t=İ&v=dQw4w9WgXcQ&list=PLtest123";
+            assert_eq!(extract_video_id(url).unwrap(), "dQw4w9WgXcQ");
+            assert_eq!(
+                extract_playlist_id_internal(url).as_deref(),
+                Some("PLtest123")
+            );
+
+            let result = validate_youtube_url("https://www.youtube.com/watch?t=İ&v=édQw4w9WgXcQ");
+            assert_eq!(result.video_id, None);
+        }
+
+        #[test]
+        fn test_invalid_video_id_is_not_single_video() {
+            let result = validate_youtube_url("https://www.youtube.com/watch?v=bad<id>");
+            assert!(!result.is_single_video());
+            assert_eq!(result.video_id, None);
+        }
+
+        #[test]
+        fn test_playlist_from_video() {
+            let video = VideoInfo {
+                id: "dQw4w9WgXcQ".to_string(),
+                title: "Song".to_string(),
+                duration_secs: Some(212),
+                channel: None,
+                thumbnail_url: Some("https://i.ytimg.com/vi/dQw4w9WgXcQ/hq.jpg".to_string()),
+                availability: VideoAvailability::Available,
+                chapters: Vec::new(),
+            };
+            let playlist = PlaylistInfo::from_video(video);
+            assert_eq!(playlist.video_count, 1);
+            assert_eq!(playlist.title, "Song");
+            assert_eq!(playlist.videos[0].id, "dQw4w9WgXcQ");
+            assert!(playlist.thumbnail_url.is_some());
+        }
+    }
+
+    // =========================================================================
+    // Channel URL Tests
+    // =========================================================================
+
+    mod channel_url_tests {
+        use super::*;
 
-            assert!(!result.is_valid);
-            assert_eq!(result.url_type, YouTubeUrlType::SingleVideo);
-            assert!(result.error_message.unwrap().contains("single video"));
-        }
+        const CHANNEL_ID: &str = "UCuAXFkgsw1L7xaCfnd5JJOw";
 
         #[test]
-        fn test_invalid_short_url_no_playlist() {
-            let url = "https://youtu.be/dQw4w9WgXcQ";
-            let result = validate_youtube_url(url);
-
-            assert!(!result.is_valid);
-            assert_eq!(result.url_type, YouTubeUrlType::ShortUrl);
+        fn test_handle_formats() {
+            for (url, handle) in [
+                ("https://www.youtube.com/@LofiGirl", "LofiGirl"),
+                ("https://www.youtube.com/@LofiGirl/videos", "LofiGirl"),
+                ("https://m.youtube.com/@LofiGirl", "LofiGirl"),
+                ("https://youtube.com/@LofiGirl?si=abc123", "LofiGirl"),
+                (
+                    "http://www.youtube.com/@lofi.girl_music-1/",
+                    "lofi.girl_music-1",
+                ),
+            ] {
+                let result = validate_youtube_url(url);
+                assert!(result.is_valid, "{url}");
+                assert!(result.needs_channel_resolution(), "{url}");
+                assert_eq!(result.url_type, YouTubeUrlType::ChannelHandle, "{url}");
+                assert_eq!(
+                    result.normalized_url,
+                    Some(format!("https://www.youtube.com/@{handle}")),
+                    "{url}"
+                );
+            }
         }
 
         #[test]
-        fn test_invalid_empty_list_parameter() {
-            let url = "https://www.youtube.com/playlist?list=";
-            let result = validate_youtube_url(url);
-
-            assert!(!result.is_valid);
+        fn test_invalid_handles() {
+            for url in [
+                "https://www.youtube.com/@",
+                "https://www.youtube.com/@ab",
+                "https://www.youtube.com/@bad<handle>",
+                "https://example.com/@LofiGirl",
+            ] {
+                assert!(!validate_youtube_url(url).is_valid, "{url}");
+            }
         }
 
         #[test]
-        fn test_invalid_playlist_id_too_short() {
-            let url = "https://www.youtube.com/playlist?list=X";
-            let result = validate_youtube_url(url);
-
-            assert!(!result.is_valid);
-            assert!(result.error_message.unwrap().contains("too short"));
+        fn test_channel_id_resolves_to_uploads() {
+            let url = format!("https://www.youtube.com/channel/{CHANNEL_ID}/videos");
+            let result = validate_youtube_url(&url);
+            assert!(result.is_valid);
+            assert!(!result.needs_channel_resolution());
+            assert_eq!(result.url_type, YouTubeUrlType::Channel);
+            assert_eq!(
+                result.playlist_id.as_deref(),
+                Some("UUuAXFkgsw1L7xaCfnd5JJOw")
+            );
+            assert_eq!(
+                extract_playlist_id(&url).unwrap(),
+                "UUuAXFkgsw1L7xaCfnd5JJOw"
+            );
         }
 
         #[test]
-        fn test_invalid_playlist_id_special_chars() {
-            let url = "https://www.youtube.com/playlist?list=PL<script>alert(1)</script>";
-            let result = validate_youtube_url(url);
-
-            assert!(!result.is_valid);
-            assert!(result.error_message.unwrap().contains("invalid characters"));
+        fn test_invalid_channel_id() {
+            assert!(!validate_youtube_url("https://www.youtube.com/channel/UCshort").is_valid);
+            assert!(!validate_youtube_url("https://www.youtube.com/channel/").is_valid);
         }
 
         #[test]
-        fn test_url_with_trailing_whitespace() {
-            let url = "  https://www.youtube.com/playlist?list=PLtest123  ";
-            let result = validate_youtube_url(url);
-
+        fn test_custom_url() {
+            let result = validate_youtube_url("https://www.youtube.com/c/LofiGirl/featured");
             assert!(result.is_valid);
-            assert_eq!(result.playlist_id, Some("PLtest123".to_string()));
+            assert!(result.needs_channel_resolution());
+            assert_eq!(result.url_type, YouTubeUrlType::ChannelCustomUrl);
+            assert_eq!(
+                result.normalized_url.as_deref(),
+                Some("https://www.youtube.com/c/LofiGirl")
+            );
         }
 
         #[test]
-        fn test_url_with_hash_fragment() {
-            let url = "https://www.youtube.com/playlist?list=PLtest123#section";
-            let result = validate_youtube_url(url);
-
-            assert!(result.is_valid);
-            assert_eq!(result.playlist_id, Some("PLtest123".to_string()));
+        fn test_playlist_parameter_wins_over_channel() {
+            let result = validate_youtube_url("https://www.youtube.com/@LofiGirl?list=PLtest123");
+            assert_eq!(result.playlist_id.as_deref(), Some("PLtest123"));
+            assert!(!result.needs_channel_resolution());
         }
 
         #[test]
-        fn test_normalized_url_format() {
-            let url = "https://youtube.com/watch?v=abc&list=PLtest123";
-            let result = validate_youtube_url(url);
+        fn test_extract_playlist_id_requires_resolution() {
+            let err = extract_playlist_id("https://www.youtube.com/@LofiGirl").unwrap_err();
+            assert!(matches!(
+                err,
+                Error::Download(DownloadError::InvalidUrl { .. })
+            ));
+        }
 
-            assert!(result.is_valid);
+        #[test]
+        fn test_extract_channel_id_from_page() {
+            let canonical = format!(
+                r#"<link rel="canonical" href="https://www.youtube.com/channel/{CHANNEL_ID}">"#
+            );
+            let external_id = format!(
+                r#"{{"channelId":"UCxxxxxxxxxxxxxxxxxxxxxx","externalId":"{CHANNEL_ID}"}}"#
+            );
+            let meta = format!(r#"<meta itemprop="identifier" content="{CHANNEL_ID}">"#);
+            for html in [canonical, external_id, meta] {
+                assert_eq!(
+                    RustyYtdlDownloader::extract_channel_id(&html).as_deref(),
+                    Some(CHANNEL_ID),
+                    "{html}"
+                );
+            }
             assert_eq!(
-                result.normalized_url,
-                Some("https://www.youtube.com/playlist?list=PLtest123".to_string())
+                RustyYtdlDownloader::extract_channel_id("<html></html>"),
+                None
             );
         }
     }
 
-    // =========================================================================
-    // Playlist ID Validation Tests
-    // =========================================================================
-
-    mod playlist_id_format_tests {
+    mod playlist_paging_tests {
         use super::*;
 
-        #[test]
-        fn test_valid_pl_prefix() {
-            assert!(validate_playlist_id_format("PLrAXtmErZgOeiKm4sgNOknGvNjby9efdf").is_ok());
+        fn video_item(id: &str) -> serde_json::Value {
+            serde_json::json!({
+                "playlistVideoRenderer": {
+                    "videoId": id,
+                    "title": {"runs": [{"text": format!("Video {id}")}]},
+                    "lengthSeconds": "215"
+                }
+            })
         }
 
         #[test]
-        fn test_valid_uu_prefix() {
-            assert!(validate_playlist_id_format("UUxxxxxxxxxxxxxxxxxxxxxxxx").is_ok());
+        fn test_page_with_continuation_token() {
+            let items = vec![
+                video_item("aaaaaaaaaaa"),
+                video_item("bbbbbbbbbbb"),
+                serde_json::json!({
+                    "continuationItemRenderer": {
+                        "continuationEndpoint": {
+                            "continuationCommand": {"token": "4qmFsgKbARIkVkxQTA", "request": "CONTINUATION_REQUEST_TYPE_BROWSE"}
+                        }
+                    }
+                }),
+            ];
+            let page = RustyYtdlDownloader::parse_playlist_items(&items);
+            assert_eq!(page.videos.len(), 2);
+            assert_eq!(page.videos[1].id, "bbbbbbbbbbb");
+            assert_eq!(page.videos[0].duration_secs, Some(215));
+            assert_eq!(page.continuation.as_deref(), Some("4qmFsgKbARIkVkxQTA"));
         }
 
         #[test]
-        fn test_valid_rd_prefix() {
-            assert!(validate_playlist_id_format("RDxxxxxxxx").is_ok());
+        fn test_last_page_has_no_continuation() {
+            let page = RustyYtdlDownloader::parse_playlist_items(&[video_item("aaaaaaaaaaa")]);
+            assert_eq!(page.videos.len(), 1);
+            assert!(page.continuation.is_none());
         }
 
         #[test]
-        fn test_valid_olak_prefix() {
-            assert!(validate_playlist_id_format("OLAK5uy_xxxxxxxxxxxxxxxxx").is_ok());
-        }
+        fn test_unavailable_videos_are_classified() {
+            let items = vec![
+                video_item("aaaaaaaaaaa"),
+                serde_json::json!({
+                    "playlistVideoRenderer": {
+                        "videoId": "bbbbbbbbbbb",
+                        "title": {"simpleText": "[Private video]"},
+                        "isPlayable": false
+                    }
+                }),
+                serde_json::json!({
+                    "playlistVideoRenderer": {
+                        "videoId": "ccccccccccc",
+                        "title": {"runs": [{"text": "[Deleted video]"}]},
+                        "isPlayable": false
+                    }
+                }),
+                serde_json::json!({
+                    "playlistVideoRenderer": {
+                        "videoId": "ddddddddddd",
+                        "title": {"runs": [{"text": "Blocked Song"}]},
+                        "isPlayable": false
+                    }
+                }),
+            ];
+            let videos = RustyYtdlDownloader::parse_playlist_items(&items).videos;
+            let availability: Vec<_> = videos.iter().map(|v| v.availability).collect();
+            assert_eq!(
+                availability,
+                [
+                    VideoAvailability::Available,
+                    VideoAvailability::Private,
+                    VideoAvailability::Deleted,
+                    VideoAvailability::GeoBlocked,
+                ]
+            );
 
-        #[test]
-        fn test_invalid_too_short() {
-            assert!(validate_playlist_id_format("X").is_err());
+            let health = PlaylistHealth::from_videos(&videos);
+            assert_eq!(
+                health,
+                PlaylistHealth {
+                    available: 1,
+                    private: 1,
+                    deleted: 1,
+                    geo_blocked: 1,
+                }
+            );
+            assert_eq!(health.unavailable(), 3);
         }
 
         #[test]
-        fn test_invalid_too_long() {
-            let long_id = "PL".to_string() + &"x".repeat(100);
-            assert!(validate_playlist_id_format(&long_id).is_err());
+        fn test_continuation_wrapped_in_command_executor() {
+            let item = serde_json::json!({
+                "continuationItemRenderer": {
+                    "continuationEndpoint": {
+                        "commandExecutorCommand": {
+                            "commands": [
+                                {"playlistVotingRefreshPopupCommand": {}},
+                                {"continuationCommand": {"token": "next-page"}}
+                            ]
+                        }
+                    }
+                }
+            });
+            assert_eq!(
+                RustyYtdlDownloader::continuation_token(&item).as_deref(),
+                Some("next-page")
+            );
         }
 
         #[test]
-        fn test_invalid_special_characters() {
-            assert!(validate_playlist_id_format("PL<>test").is_err());
-            assert!(validate_playlist_id_format("PL test").is_err());
-            assert!(validate_playlist_id_format("PL@test").is_err());
+        fn test_find_continuation_items() {
+            let response = serde_json::json!({
+                "responseContext": {},
+                "onResponseReceivedActions": [{
+                    "appendContinuationItemsAction": {
+                        "continuationItems": [video_item("ccccccccccc")],
+                        "targetId": "VLPLtest"
+                    }
+                }]
+            });
+            let items = RustyYtdlDownloader::find_continuation_items(&response).unwrap();
+            assert_eq!(items.len(), 1);
+            assert!(RustyYtdlDownloader::find_continuation_items(&serde_json::json!({})).is_none());
         }
 
         #[test]
-        fn test_valid_with_underscore_and_hyphen() {
-            assert!(validate_playlist_id_format("PLtest_123-abc").is_ok());
+        fn test_innertube_client_from_html() {
+            let html = r#"ytcfg.set({"INNERTUBE_API_KEY":"AIzaTestKey","INNERTUBE_CLIENT_VERSION":"2.20250101.01.00"});"#;
+            let client = InnertubeClient::from_html(html);
+            assert_eq!(client.version, "2.20250101.01.00");
+            assert!(client.browse_url().ends_with("&key=AIzaTestKey"));
+            assert_eq!(client.continuation_body("tok")["continuation"], "tok");
+
+            let fallback = InnertubeClient::from_html("<html></html>");
+            assert_eq!(fallback.version, DEFAULT_INNERTUBE_CLIENT_VERSION);
+            assert_eq!(fallback.api_key, None);
+            assert!(!fallback.browse_url().contains("key="));
         }
     }
 
@@ -2297,6 +5371,422 @@ mod tests {
         }
     }
 
+    // =========================================================================
+    // Offline Mode Tests
+    // =========================================================================
+
+    mod offline_mode_tests {
+        use super::*;
+
+        fn offline_downloader() -> RustyYtdlDownloader {
+            RustyYtdlDownloader::with_config(RustyYtdlConfig {
+                offline: true,
+                ..RustyYtdlConfig::default()
+            })
+        }
+
+        fn test_playlist(titles: &[&str]) -> PlaylistInfo {
+            let videos: Vec<VideoInfo> = titles
+                .iter()
+                .enumerate()
+                .map(|(i, title)| VideoInfo {
+                    id: format!("video{i}"),
+                    title: (*title).to_string(),
+                    duration_secs: None,
+                    channel: None,
+                    thumbnail_url: None,
+                    availability: VideoAvailability::Available,
+                    chapters: Vec::new(),
+                })
+                .collect();
+            PlaylistInfo {
+                id: "PLtest123".to_string(),
+                title: "Test Playlist".to_string(),
+                video_count: videos.len(),
+                videos,
+                thumbnail_url: None,
+                health: PlaylistHealth::default(),
+            }
+        }
+
+        #[test]
+        fn test_offline_disabled_by_default() {
+            assert!(!RustyYtdlConfig::default().offline);
+            assert!(!RustyYtdlDownloader::new().is_offline());
+            assert!(offline_downloader().is_offline());
+        }
+
+        #[test]
+        fn test_offline_parse_playlist_url_returns_offline_error() {
+            let result = offline_downloader()
+                .parse_playlist_url("https://www.youtube.com/playlist?list=PLtest123");
+            let err = result.unwrap_err();
+            assert!(err.is_offline());
+            assert!(err.to_string().contains("PLtest123"));
+        }
+
+        #[test]
+        fn test_offline_parse_invalid_url_still_validates() {
+            let err = offline_downloader()
+                .parse_playlist_url("https://example.com")
+                .unwrap_err();
+            assert!(!err.is_offline());
+        }
+
+        #[test]
+        fn test_offline_channel_handle_returns_offline_error() {
+            let err = offline_downloader()
+                .parse_playlist_url("https://www.youtube.com/@LofiGirl")
+                .unwrap_err();
+            assert!(err.is_offline());
+        }
+
+        #[test]
+        fn test_offline_parse_video_url_returns_offline_error() {
+            let err = offline_downloader()
+                .parse_video_url("https://youtu.be/dQw4w9WgXcQ")
+                .unwrap_err();
+            assert!(err.is_offline());
+        }
+
+        #[test]
+        fn test_offline_get_video_info_returns_offline_error() {
+            let err = offline_downloader()
+                .get_video_info("dQw4w9WgXcQ")
+                .unwrap_err();
+            assert!(err.is_offline());
+        }
+
+        #[test]
+        fn test_offline_download_keeps_existing_and_fails_missing() {
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            std::fs::write(temp_dir.path().join("Existing Song.mp3"), b"data").unwrap();
+
+            let playlist = test_playlist(&["Existing Song", "Missing Song"]);
+            let results = offline_downloader()
+                .download_playlist(&playlist, temp_dir.path(), None)
+                .unwrap();
+
+            assert_eq!(results.len(), 2);
+            assert!(results[0].success);
+            assert!(results[0].output_path.is_some());
+            assert!(!results[1].success);
+            assert!(
+                results[1]
+                    .error
+                    .as_deref()
+                    .is_some_and(|e| e.contains("offline mode"))
+            );
+        }
+
+        #[test]
+        fn test_unavailable_video_fails_without_download() {
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            let mut playlist = test_playlist(&["[Private video]"]);
+            playlist.videos[0].availability = VideoAvailability::Private;
+
+            let results = offline_downloader()
+                .download_playlist(&playlist, temp_dir.path(), None)
+                .unwrap();
+            assert!(!results[0].success);
+            let error = results[0].error.as_deref().unwrap();
+            assert!(error.contains("video is private"), "{error}");
+        }
+
+        #[test]
+        fn test_archive_skips_renamed_tracks() {
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            std::fs::write(temp_dir.path().join("Song 1.mp3"), b"data").unwrap();
+            let playlist = test_playlist(&["Song 1"]);
+
+            // The existing file is archived by its video ID
+            let results = offline_downloader()
+                .download_playlist(&playlist, temp_dir.path(), None)
+                .unwrap();
+            assert!(results[0].success);
+            let archive = DownloadArchive::open(temp_dir.path());
+            assert_eq!(
+                archive.get("video0").unwrap().file_name.as_deref(),
+                Some("Song 1.mp3")
+            );
+
+            // Renamed, it is still skipped rather than downloaded again
+            std::fs::rename(
+                temp_dir.path().join("Song 1.mp3"),
+                temp_dir.path().join("Renamed.mp3"),
+            )
+            .unwrap();
+            let results = offline_downloader()
+                .download_playlist(&playlist, temp_dir.path(), None)
+                .unwrap();
+            assert!(results[0].success);
+            assert!(results[0].output_path.is_none());
+
+            // Once reset, the renamed track no longer counts as downloaded
+            archive.reset().unwrap();
+            let results = offline_downloader()
+                .download_playlist(&playlist, temp_dir.path(), None)
+                .unwrap();
+            assert!(!results[0].success);
+        }
+
+        #[test]
+        fn test_concurrent_download_keeps_playlist_order() {
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            std::fs::write(temp_dir.path().join("Song 2.mp3"), b"data").unwrap();
+
+            let downloader = RustyYtdlDownloader::with_config(RustyYtdlConfig {
+                offline: true,
+                concurrency: 3,
+                ..RustyYtdlConfig::default()
+            });
+            let playlist = test_playlist(&["Song 1", "Song 2", "Song 3", "Song 4", "Song 5"]);
+            let updates = Arc::new(std::sync::Mutex::new(Vec::new()));
+            let recorded = Arc::clone(&updates);
+            let results = downloader
+                .download_playlist(
+                    &playlist,
+                    temp_dir.path(),
+                    Some(Box::new(move |progress: DownloadProgress| {
+                        recorded.lock().unwrap().push(progress);
+                    })),
+                )
+                .unwrap();
+
+            let ids: Vec<&str> = results.iter().map(|r| r.video.id.as_str()).collect();
+            assert_eq!(ids, ["video0", "video1", "video2", "video3", "video4"]);
+            assert!(results[1].success);
+            assert_eq!(results.iter().filter(|r| !r.success).count(), 4);
+
+            let updates = updates.lock().unwrap();
+            let last = updates
+                .iter()
+                .max_by_key(|p| p.videos_failed + p.videos_skipped)
+                .unwrap();
+            assert_eq!(last.videos_failed, 4);
+            assert_eq!(last.videos_skipped, 1);
+        }
+
+        #[test]
+        fn test_dry_run_plans_without_writing() {
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            std::fs::write(temp_dir.path().join("Song 2.mp3"), b"data").unwrap();
+
+            let mut playlist = test_playlist(&["Song 1", "Song 2", "Song 3"]);
+            playlist.videos[0].duration_secs = Some(100);
+            playlist.videos[2].availability = VideoAvailability::Private;
+            let downloader = RustyYtdlDownloader::with_config(RustyYtdlConfig {
+                offline: true,
+                audio_bitrate_kbps: 128,
+                dry_run: true,
+                ..RustyYtdlConfig::default()
+            });
+
+            let plan = downloader
+                .plan_playlist(&playlist, temp_dir.path())
+                .unwrap();
+            assert_eq!(plan.download_count(), 1);
+            assert_eq!(plan.estimated_size_bytes(), 100 * 16_000);
+            assert_eq!(plan.total_duration_secs(), 100);
+            let (new, existing, private) = (&plan.videos[0], &plan.videos[1], &plan.videos[2]);
+            assert_eq!(new.output_path, temp_dir.path().join("Song 1.mp3"));
+            assert!(new.will_download() && !new.from_format);
+            assert!(existing.already_downloaded);
+            assert_eq!(existing.estimated_size_bytes, 0);
+            assert!(private.unavailable_reason.is_some());
+
+            let results = downloader
+                .download_playlist(&playlist, temp_dir.path(), None)
+                .unwrap();
+            assert_eq!(results[0].estimated_size_bytes, Some(100 * 16_000));
+            assert!(results[1].success);
+            assert!(!results[2].success);
+            // Nothing was written
+            assert!(!temp_dir.path().join("Song 1.mp3").exists());
+            assert!(
+                !temp_dir
+                    .path()
+                    .join(archive::DOWNLOAD_ARCHIVE_FILE)
+                    .exists()
+            );
+        }
+
+        #[test]
+        fn test_estimate_file_size_from_format() {
+            let format = AudioFormat {
+                itag: 140,
+                bitrate_kbps: Some(128),
+                codec: Some("mp4a.40.2".to_string()),
+                container: "mp4".to_string(),
+                has_video: false,
+                content_length: Some(3_200_000),
+                url: String::new(),
+            };
+            assert_eq!(format_duration_secs(&format), Some(200));
+            // Kept streams are as big as the stream; converted ones depend
+            // on the target bitrate
+            assert_eq!(
+                estimate_file_size(Some(&format), Some(200), 320, true),
+                3_200_000
+            );
+            assert_eq!(
+                estimate_file_size(Some(&format), Some(200), 320, false),
+                200 * 40_000
+            );
+            assert_eq!(
+                estimate_file_size(None, None, 192, false),
+                DEFAULT_TRACK_DURATION_SECS * 24_000
+            );
+        }
+
+        #[test]
+        fn test_estimate_playlist_size_offline_and_cached() {
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            let mut cache = CacheManager::new(
+                crate::cache::CacheConfig::new().with_cache_dir(temp_dir.path().to_path_buf()),
+            )
+            .unwrap();
+
+            let mut playlist = test_playlist(&["Song 1", "Song 2", "Song 3"]);
+            playlist.video_count = 4;
+            playlist.videos[0].duration_secs = Some(100);
+            playlist.videos[2].duration_secs = Some(900);
+            playlist.videos[2].availability = VideoAvailability::Private;
+            let downloader = RustyYtdlDownloader::with_config(RustyYtdlConfig {
+                offline: true,
+                audio_bitrate_kbps: 128,
+                ..RustyYtdlConfig::default()
+            });
+
+            // The private video is left out; the two videos of unknown
+            // length count as the known one
+            let estimate = downloader.estimate_playlist_size(&playlist, Some(&mut cache));
+            assert_eq!(estimate.video_count, 3);
+            assert_eq!(estimate.sampled_count, 0);
+            assert_eq!(estimate.total_duration_secs, 300);
+            assert_eq!(estimate.estimated_size_bytes, 300 * 16_000);
+            assert!(!estimate.original_audio);
+            // Estimates without samples are not cached
+            assert!(
+                cache
+                    .get_size_estimate(&playlist.id, 128, false)
+                    .unwrap()
+                    .is_none()
+            );
+
+            let sampled = PlaylistSizeEstimate {
+                sampled_count: 2,
+                estimated_size_bytes: 42,
+                ..estimate
+            };
+            cache.put_size_estimate(&sampled).unwrap();
+            assert_eq!(
+                downloader.estimate_playlist_size(&playlist, Some(&mut cache)),
+                sampled
+            );
+            // A playlist that changed length is estimated again
+            playlist.video_count = 5;
+            let estimate = downloader.estimate_playlist_size(&playlist, Some(&mut cache));
+            assert_eq!(estimate.video_count, 4);
+            assert_eq!(estimate.total_duration_secs, 400);
+        }
+
+        #[test]
+        fn test_concurrent_download_stops_when_cancelled() {
+            let temp_dir = tempfile::TempDir::new().unwrap();
+            let downloader = RustyYtdlDownloader::with_config(RustyYtdlConfig {
+                offline: true,
+                concurrency: 2,
+                ..RustyYtdlConfig::default()
+            });
+            let control = downloader.control();
+            let playlist = test_playlist(&["Song 1", "Song 2", "Song 3", "Song 4"]);
+            let result = downloader.download_playlist(
+                &playlist,
+                temp_dir.path(),
+                Some(Box::new(move |_| control.cancel())),
+            );
+            assert!(matches!(
+                result,
+                Err(Error::Download(DownloadError::Cancelled))
+            ));
+        }
+    }
+
+    // =========================================================================
+    // Download Control Tests
+    // =========================================================================
+
+    mod download_control_tests {
+        use super::*;
+
+        #[test]
+        fn test_download_control_flags() {
+            let downloader = RustyYtdlDownloader::new();
+            let control = downloader.control();
+            assert!(control.same_download(&downloader.control()));
+            assert!(!control.same_download(&DownloadControl::new()));
+
+            control.pause();
+            assert!(downloader.control().is_paused());
+            control.resume();
+            assert!(!control.is_paused());
+
+            control.cancel_video();
+            assert!(control.should_stop());
+            assert!(!control.is_cancelled());
+            assert!(control.take_video_cancelled());
+            assert!(!control.should_stop());
+
+            let token = control.cancellation_token();
+            assert!(!token.is_cancelled());
+            downloader.cancel();
+            assert!(control.is_cancelled());
+            assert!(downloader.cancel_flag().load(Ordering::SeqCst));
+            assert!(token.is_cancelled());
+            downloader.reset_cancel();
+            assert!(!control.should_stop());
+        }
+
+        #[tokio::test(start_paused = true)]
+        async fn test_checkpoint_waits_while_paused() {
+            let control = DownloadControl::new();
+            control.pause();
+
+            let waiting = control.clone();
+            let checkpoint = tokio::spawn(async move { waiting.checkpoint().await });
+            tokio::time::sleep(PAUSE_POLL_INTERVAL * 3).await;
+            assert!(!checkpoint.is_finished());
+
+            control.resume();
+            assert!(checkpoint.await.unwrap().is_ok());
+
+            control.cancel_video();
+            assert!(matches!(
+                control.checkpoint().await,
+                Err(Error::Download(DownloadError::Cancelled))
+            ));
+        }
+
+        #[test]
+        fn test_sleep_wakes_up_when_cancelled() {
+            let control = DownloadControl::new();
+            let start = std::time::Instant::now();
+            control.sleep(PAUSE_POLL_INTERVAL / 4);
+            assert!(start.elapsed() >= PAUSE_POLL_INTERVAL / 4);
+
+            let cancelling = control.clone();
+            let canceller = std::thread::spawn(move || {
+                std::thread::sleep(PAUSE_POLL_INTERVAL);
+                cancelling.cancel_video();
+            });
+            let start = std::time::Instant::now();
+            control.sleep(std::time::Duration::from_secs(60));
+            assert!(start.elapsed() < std::time::Duration::from_secs(30));
+            canceller.join().unwrap();
+        }
+    }
+
     // =========================================================================
     // Download Progress and Result Tests
     // =========================================================================
@@ -2315,6 +5805,7 @@ mod tests {
                 status: DownloadStatus::Downloading,
                 current_bytes: 1024,
                 current_total_bytes: Some(2048),
+                resumed_from_bytes: 512,
                 total_bytes_downloaded: 5000,
                 download_speed_bps: 102_400.0,
                 estimated_remaining_secs: Some(30.0),
@@ -2322,6 +5813,7 @@ mod tests {
                 videos_completed: 3,
                 videos_skipped: 1,
                 videos_failed: 0,
+                active_downloads: 1,
             };
 
             assert_eq!(progress.current_index, 5);
@@ -2332,6 +5824,7 @@ mod tests {
             assert_eq!(progress.status, DownloadStatus::Downloading);
             assert_eq!(progress.current_bytes, 1024);
             assert_eq!(progress.current_total_bytes, Some(2048));
+            assert_eq!(progress.resumed_from_bytes, 512);
             assert_eq!(progress.total_bytes_downloaded, 5000);
             assert!((progress.download_speed_bps - 102_400.0).abs() < f64::EPSILON);
             assert_eq!(progress.estimated_remaining_secs, Some(30.0));
@@ -2352,6 +5845,7 @@ mod tests {
                 status: DownloadStatus::Downloading,
                 current_bytes: 0,
                 current_total_bytes: None,
+                resumed_from_bytes: 0,
                 total_bytes_downloaded: 0,
                 download_speed_bps: 1_536_000.0,       // ~1.5 MB/s
                 estimated_remaining_secs: Some(150.0), // 2:30
@@ -2359,6 +5853,7 @@ mod tests {
                 videos_completed: 0,
                 videos_skipped: 0,
                 videos_failed: 0,
+                active_downloads: 1,
             };
 
             assert_eq!(progress.formatted_speed(), "1.5 MB/s");
@@ -2399,6 +5894,8 @@ mod tests {
                 duration_secs: Some(180),
                 channel: Some("Test Channel".to_string()),
                 thumbnail_url: Some("https://example.com/thumb.jpg".to_string()),
+                availability: VideoAvailability::Available,
+                chapters: Vec::new(),
             };
 
             let result = DownloadResult {
@@ -2406,6 +5903,12 @@ mod tests {
                 success: true,
                 output_path: Some(PathBuf::from("/output/test.mp3")),
                 error: None,
+                sponsor_secs_removed: 0.0,
+                audio_bitrate_kbps: None,
+                audio_codec: None,
+                stream_path: None,
+                chapter_paths: Vec::new(),
+                estimated_size_bytes: None,
             };
 
             assert!(result.success);
@@ -2421,6 +5924,8 @@ mod tests {
                 duration_secs: None,
                 channel: None,
                 thumbnail_url: None,
+                availability: VideoAvailability::Available,
+                chapters: Vec::new(),
             };
 
             let result = DownloadResult {
@@ -2428,6 +5933,12 @@ mod tests {
                 success: false,
                 output_path: None,
                 error: Some("Download failed".to_string()),
+                sponsor_secs_removed: 0.0,
+                audio_bitrate_kbps: None,
+                audio_codec: None,
+                stream_path: None,
+                chapter_paths: Vec::new(),
+                estimated_size_bytes: None,
             };
 
             assert!(!result.success);
@@ -2443,6 +5954,8 @@ mod tests {
                 duration_secs: Some(180),
                 channel: Some("Test Channel".to_string()),
                 thumbnail_url: Some("https://example.com/thumb.jpg".to_string()),
+                availability: VideoAvailability::Available,
+                chapters: Vec::new(),
             };
 
             let cloned = video.clone();
@@ -2462,6 +5975,8 @@ mod tests {
                     duration_secs: Some(120),
                     channel: None,
                     thumbnail_url: None,
+                    availability: VideoAvailability::Available,
+                    chapters: Vec::new(),
                 },
                 VideoInfo {
                     id: "vid2".to_string(),
@@ -2469,6 +5984,8 @@ mod tests {
                     duration_secs: Some(240),
                     channel: None,
                     thumbnail_url: None,
+                    availability: VideoAvailability::Available,
+                    chapters: Vec::new(),
                 },
             ];
 
@@ -2478,6 +5995,7 @@ mod tests {
                 video_count: 2,
                 videos,
                 thumbnail_url: Some("https://example.com/playlist-thumb.jpg".to_string()),
+                health: PlaylistHealth::default(),
             };
 
             assert_eq!(playlist.id, "PLtest123");
@@ -2486,6 +6004,55 @@ mod tests {
             assert_eq!(playlist.videos.len(), 2);
             assert!(playlist.thumbnail_url.is_some());
         }
+
+        #[test]
+        fn test_estimate_download() {
+            let video = |duration_secs| VideoInfo {
+                id: "vid".to_string(),
+                title: "Video".to_string(),
+                duration_secs,
+                channel: None,
+                thumbnail_url: None,
+                availability: VideoAvailability::Available,
+                chapters: Vec::new(),
+            };
+            let playlist = PlaylistInfo {
+                id: "PLtest123".to_string(),
+                title: "Test Playlist".to_string(),
+                video_count: 3,
+                videos: vec![video(Some(120)), video(Some(240)), video(None)],
+                thumbnail_url: None,
+                health: PlaylistHealth::default(),
+            };
+
+            let estimate = playlist.estimate_download(192);
+            assert_eq!(estimate.video_count, 3);
+            assert_eq!(estimate.unknown_duration_count, 1);
+            // The unknown video counts as the 180s average
+            assert_eq!(estimate.total_duration_secs, 540);
+            assert_eq!(estimate.estimated_size_bytes, 540 * 24_000);
+            assert_eq!(
+                estimate.estimated_download_secs,
+                540 * 24_000 / ESTIMATED_THROUGHPUT_BPS + 3 * ESTIMATED_OVERHEAD_PER_VIDEO_SECS
+            );
+
+            // Higher bitrates mean bigger files
+            assert!(
+                playlist.estimate_download(320).estimated_size_bytes
+                    > estimate.estimated_size_bytes
+            );
+
+            // No known durations at all
+            let playlist = PlaylistInfo {
+                videos: vec![video(None)],
+                video_count: 1,
+                ..playlist
+            };
+            assert_eq!(
+                playlist.estimate_download(128).total_duration_secs,
+                DEFAULT_TRACK_DURATION_SECS
+            );
+        }
     }
 
     // =========================================================================
@@ -2570,6 +6137,23 @@ mod tests {
             assert_eq!(progress.current_total_bytes, Some(1024));
         }
 
+        #[test]
+        fn test_stream_bytes_video_progress() {
+            let bytes = |downloaded, total| StreamBytes {
+                downloaded,
+                total,
+                resumed_from: 0,
+                received: 0,
+            };
+            assert!((bytes(0, Some(1000)).video_progress() - 0.1).abs() < f64::EPSILON);
+            assert!((bytes(500, Some(1000)).video_progress() - 0.3).abs() < 1e-9);
+            assert!((bytes(1000, Some(1000)).video_progress() - 0.5).abs() < 1e-9);
+            assert!(bytes(1000, Some(1000)).is_complete());
+            // Unknown sizes stay at the start of the download
+            assert!((bytes(500, None).video_progress() - 0.1).abs() < f64::EPSILON);
+            assert!(!bytes(500, None).is_complete());
+        }
+
         #[test]
         fn test_tracker_overall_progress_calculation() {
             let tracker = DownloadProgressTracker::new(4);
@@ -2603,6 +6187,23 @@ mod tests {
             let eta = tracker.estimated_remaining_secs(1.0);
             assert!(eta.is_none());
         }
+
+        #[test]
+        fn test_tracker_aggregates_concurrent_videos() {
+            let mut tracker = DownloadProgressTracker::new(4);
+            tracker.video_completed();
+            tracker.set_video_progress(2, 0.5);
+            tracker.set_video_progress(3, 0.25);
+
+            let progress =
+                tracker.create_progress(3, "Song 3", 0.25, DownloadStatus::Downloading, 0, None);
+            assert_eq!(progress.active_downloads, 2);
+            assert!((progress.overall_progress - 0.4375).abs() < f64::EPSILON);
+
+            tracker.video_finished(2);
+            tracker.video_finished(3);
+            assert_eq!(tracker.active_downloads(), 0);
+        }
     }
 
     // =========================================================================
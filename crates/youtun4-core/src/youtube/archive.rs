@@ -0,0 +1,324 @@
+//! Per-playlist archive of downloaded videos.
+//!
+//! Playlist downloads record every video they download or find already on
+//! disk in the [`DOWNLOAD_ARCHIVE_FILE`] of the output directory, keyed by
+//! video ID. Archived videos are skipped on later downloads, so a renamed
+//! track isn't downloaded again. Resetting the archive makes the next
+//! download fall back to matching file names. The archive also keeps each
+//! video's position in the playlist, so a playlist update can tell which
+//! videos were moved (see [`super::diff`]).
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use super::VideoInfo;
+use crate::error::Result;
+
+/// File name of the archive, stored in the playlist folder.
+pub const DOWNLOAD_ARCHIVE_FILE: &str = "download_archive.json";
+
+/// A video in the download archive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchivedVideo {
+    /// `YouTube` video ID.
+    pub video_id: String,
+    /// Video title when it was downloaded.
+    pub title: String,
+    /// File name the video was saved as, if known.
+    pub file_name: Option<String>,
+    /// When the video was archived (seconds since the Unix epoch).
+    pub archived_at: u64,
+    /// Position of the video in the playlist (starting at 1) at the end of
+    /// the last download, if known.
+    #[serde(default)]
+    pub position: Option<usize>,
+}
+
+/// Archive of the videos downloaded into a playlist folder.
+#[derive(Debug)]
+pub struct DownloadArchive {
+    path: PathBuf,
+    videos: Mutex<BTreeMap<String, ArchivedVideo>>,
+}
+
+impl DownloadArchive {
+    /// Open the archive of the playlist folder `dir`.
+    ///
+    /// A missing file yields an empty archive; an unreadable one is logged
+    /// and replaced on the next write.
+    #[must_use]
+    pub fn open(dir: &Path) -> Self {
+        let path = dir.join(DOWNLOAD_ARCHIVE_FILE);
+        let videos = Self::load(&path);
+        Self {
+            path,
+            videos: Mutex::new(videos),
+        }
+    }
+
+    fn load(path: &Path) -> BTreeMap<String, ArchivedVideo> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return BTreeMap::new(),
+            Err(e) => {
+                warn!("Failed to read download archive {}: {}", path.display(), e);
+                return BTreeMap::new();
+            }
+        };
+        serde_json::from_str(&contents).unwrap_or_else(|e| {
+            warn!(
+                "Discarding corrupt download archive {}: {}",
+                path.display(),
+                e
+            );
+            BTreeMap::new()
+        })
+    }
+
+    fn save(&self, videos: &BTreeMap<String, ArchivedVideo>) -> Result<()> {
+        let tmp = self.path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(videos)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<String, ArchivedVideo>> {
+        self.videos.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Path of the archive file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The archived video with ID `video_id`.
+    #[must_use]
+    pub fn get(&self, video_id: &str) -> Option<ArchivedVideo> {
+        self.lock().get(video_id).cloned()
+    }
+
+    /// Whether the video with ID `video_id` was downloaded before.
+    #[must_use]
+    pub fn contains(&self, video_id: &str) -> bool {
+        self.lock().contains_key(video_id)
+    }
+
+    /// Number of archived videos.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether no video was archived.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Archived videos, oldest first.
+    #[must_use]
+    pub fn videos(&self) -> Vec<ArchivedVideo> {
+        let mut videos: Vec<_> = self.lock().values().cloned().collect();
+        videos.sort_by_key(|video| video.archived_at);
+        videos
+    }
+
+    /// Record `video` as saved to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive can't be written.
+    pub fn record(&self, video: &VideoInfo, path: Option<&Path>) -> Result<()> {
+        let mut videos = self.lock();
+        let position = videos.get(&video.id).and_then(|archived| archived.position);
+        videos.insert(
+            video.id.clone(),
+            ArchivedVideo {
+                video_id: video.id.clone(),
+                title: video.title.clone(),
+                file_name: path
+                    .and_then(Path::file_name)
+                    .map(|name| name.to_string_lossy().into_owned()),
+                archived_at: unix_now(),
+                position,
+            },
+        );
+        self.save(&videos)?;
+        debug!("Archived video {}", video.id);
+        Ok(())
+    }
+
+    /// Record the position of every archived video in the playlist
+    /// `videos`, in playlist order. Archived videos no longer in the
+    /// playlist keep their last position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive can't be written.
+    pub fn record_positions(&self, videos: &[VideoInfo]) -> Result<()> {
+        let mut archived = self.lock();
+        let mut changed = false;
+        for (index, video) in videos.iter().enumerate() {
+            if let Some(entry) = archived.get_mut(&video.id)
+                && entry.position != Some(index + 1)
+            {
+                entry.position = Some(index + 1);
+                changed = true;
+            }
+        }
+        if changed {
+            self.save(&archived)?;
+        }
+        Ok(())
+    }
+
+    /// Remove the videos with IDs `video_ids` from the archive, so they're
+    /// downloaded again if they come back, returning how many were removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive can't be written.
+    pub fn forget(&self, video_ids: &[&str]) -> Result<usize> {
+        let mut videos = self.lock();
+        let removed = video_ids
+            .iter()
+            .filter(|id| videos.remove(**id).is_some())
+            .count();
+        if removed > 0 {
+            self.save(&videos)?;
+        }
+        Ok(removed)
+    }
+
+    /// Remove every video from the archive and delete its file, returning
+    /// how many videos were removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive file can't be deleted.
+    pub fn reset(&self) -> Result<usize> {
+        let mut videos = self.lock();
+        let removed = videos.len();
+        videos.clear();
+        match fs::remove_file(&self.path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(removed),
+        }
+    }
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::youtube::VideoAvailability;
+    use tempfile::TempDir;
+
+    fn video(id: &str, title: &str) -> VideoInfo {
+        VideoInfo {
+            id: id.to_string(),
+            title: title.to_string(),
+            duration_secs: None,
+            channel: None,
+            thumbnail_url: None,
+            availability: VideoAvailability::Available,
+            chapters: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_record_persists_across_opens() {
+        let dir = TempDir::new().unwrap();
+        let archive = DownloadArchive::open(dir.path());
+        assert!(archive.is_empty());
+
+        archive
+            .record(&video("abc", "Song"), Some(&dir.path().join("Song.mp3")))
+            .unwrap();
+        archive.record(&video("def", "Other"), None).unwrap();
+
+        let reopened = DownloadArchive::open(dir.path());
+        assert_eq!(reopened.len(), 2);
+        assert!(reopened.contains("abc"));
+        let entry = reopened.get("abc").unwrap();
+        assert_eq!(entry.title, "Song");
+        assert_eq!(entry.file_name.as_deref(), Some("Song.mp3"));
+        assert_eq!(reopened.get("def").unwrap().file_name, None);
+    }
+
+    #[test]
+    fn test_reset_removes_file() {
+        let dir = TempDir::new().unwrap();
+        let archive = DownloadArchive::open(dir.path());
+        archive.record(&video("abc", "Song"), None).unwrap();
+        assert!(archive.path().exists());
+
+        assert_eq!(archive.reset().unwrap(), 1);
+        assert!(!archive.path().exists());
+        assert!(!archive.contains("abc"));
+        // Resetting an archive without a file is fine
+        assert_eq!(archive.reset().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_forget() {
+        let dir = TempDir::new().unwrap();
+        let archive = DownloadArchive::open(dir.path());
+        archive.record(&video("a", "A"), None).unwrap();
+        archive.record(&video("b", "B"), None).unwrap();
+
+        assert_eq!(archive.forget(&["a", "unknown"]).unwrap(), 1);
+        let reopened = DownloadArchive::open(dir.path());
+        assert!(!reopened.contains("a"));
+        assert!(reopened.contains("b"));
+    }
+
+    #[test]
+    fn test_record_positions() {
+        let dir = TempDir::new().unwrap();
+        let archive = DownloadArchive::open(dir.path());
+        archive.record(&video("abc", "Song"), None).unwrap();
+        archive.record(&video("def", "Other"), None).unwrap();
+        assert_eq!(archive.get("abc").unwrap().position, None);
+
+        archive
+            .record_positions(&[
+                video("new", "New"),
+                video("def", "Other"),
+                video("abc", "Song"),
+            ])
+            .unwrap();
+        let reopened = DownloadArchive::open(dir.path());
+        assert!(!reopened.contains("new"));
+        assert_eq!(reopened.get("def").unwrap().position, Some(2));
+        assert_eq!(reopened.get("abc").unwrap().position, Some(3));
+
+        // Archiving again keeps the position
+        reopened.record(&video("abc", "Song"), None).unwrap();
+        assert_eq!(reopened.get("abc").unwrap().position, Some(3));
+    }
+
+    #[test]
+    fn test_corrupt_archive_is_discarded() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join(DOWNLOAD_ARCHIVE_FILE), "not json").unwrap();
+        let archive = DownloadArchive::open(dir.path());
+        assert!(archive.is_empty());
+
+        archive.record(&video("abc", "Song"), None).unwrap();
+        assert!(DownloadArchive::open(dir.path()).contains("abc"));
+    }
+}
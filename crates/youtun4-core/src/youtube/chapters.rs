@@ -0,0 +1,218 @@
+//! Splitting videos into one track per chapter.
+//!
+//! Uploaders can divide a video into chapters by listing timestamps in its
+//! description, which `YouTube` turns into chapter markers. "Full album"
+//! videos usually have one chapter per song, so when splitting is enabled
+//! the converted MP3 is cut at the chapter markers into separate tracks,
+//! each tagged with its chapter title and position.
+
+use std::ops::Range;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::sanitize_filename;
+
+/// A chapter marker of a video.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Chapter {
+    /// Chapter title.
+    pub title: String,
+    /// Start of the chapter in seconds.
+    pub start_secs: u64,
+}
+
+impl Chapter {
+    /// Chapters of a video as listed by `rusty_ytdl`, sorted by start.
+    #[must_use]
+    pub fn from_ytdl(chapters: &[rusty_ytdl::Chapter]) -> Vec<Self> {
+        let mut chapters: Vec<Self> = chapters
+            .iter()
+            .map(|chapter| Self {
+                title: chapter.title.trim().to_string(),
+                start_secs: u64::try_from(chapter.start_time).unwrap_or(0),
+            })
+            .collect();
+        chapters.sort_by_key(|chapter| chapter.start_secs);
+        chapters
+    }
+}
+
+/// A chapter of the converted audio, to be saved as its own track.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChapterTrack {
+    /// Track title.
+    pub title: String,
+    /// Start of the track in the converted audio.
+    pub start: Duration,
+    /// End of the track in the converted audio, or `None` for the end of
+    /// the file.
+    pub end: Option<Duration>,
+}
+
+/// Tracks to split a video lasting `duration_secs` into at `chapters`,
+/// once the time ranges `cuts` (sorted and non-overlapping) are removed.
+///
+/// A video needs at least two chapters to be split; otherwise there are no
+/// tracks. Chapters entirely removed by the cuts are dropped, and untitled
+/// chapters are named after their position.
+#[must_use]
+pub fn chapter_tracks(
+    chapters: &[Chapter],
+    duration_secs: Option<u64>,
+    cuts: &[Range<Duration>],
+) -> Vec<ChapterTrack> {
+    let mut starts: Vec<&Chapter> = chapters
+        .iter()
+        .filter(|chapter| duration_secs.is_none_or(|secs| chapter.start_secs < secs))
+        .collect();
+    starts.sort_by_key(|chapter| chapter.start_secs);
+    starts.dedup_by_key(|chapter| chapter.start_secs);
+    if starts.len() < 2 {
+        return Vec::new();
+    }
+
+    let end_of_video = duration_secs.map(Duration::from_secs);
+    let tracks: Vec<ChapterTrack> = starts
+        .iter()
+        .enumerate()
+        .map(|(index, chapter)| {
+            let start = Duration::from_secs(chapter.start_secs);
+            let end = starts
+                .get(index + 1)
+                .map(|next| Duration::from_secs(next.start_secs))
+                .or(end_of_video);
+            let title = if chapter.title.is_empty() {
+                format!("Chapter {}", index + 1)
+            } else {
+                chapter.title.clone()
+            };
+            ChapterTrack {
+                title,
+                start: shift_for_cuts(start, cuts),
+                end: end.map(|end| shift_for_cuts(end, cuts)),
+            }
+        })
+        .filter(|track| track.end.is_none_or(|end| end > track.start))
+        .collect();
+    if tracks.len() < 2 {
+        return Vec::new();
+    }
+    tracks
+}
+
+/// Position of `time` of the original video in audio without `cuts`.
+fn shift_for_cuts(time: Duration, cuts: &[Range<Duration>]) -> Duration {
+    let removed: Duration = cuts
+        .iter()
+        .filter(|cut| cut.start < time)
+        .map(|cut| cut.end.min(time).saturating_sub(cut.start))
+        .sum();
+    time.saturating_sub(removed)
+}
+
+/// File name (without extension) of chapter `position` (starting at 1) of
+/// `total`, titled `title`, of the video saved as `video_file_name`.
+///
+/// Positions are zero-padded so the tracks sort in order.
+#[must_use]
+pub fn chapter_file_name(
+    video_file_name: &str,
+    position: usize,
+    total: usize,
+    title: &str,
+) -> String {
+    let width = total.to_string().len().max(2);
+    format!(
+        "{video_file_name} - {position:0width$} - {}",
+        sanitize_filename(title)
+    )
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn chapter(title: &str, start_secs: u64) -> Chapter {
+        Chapter {
+            title: title.to_string(),
+            start_secs,
+        }
+    }
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn test_chapter_tracks_cover_the_video() {
+        let chapters = [chapter("Two", 120), chapter("One", 0), chapter("", 300)];
+        let tracks = chapter_tracks(&chapters, Some(400), &[]);
+        assert_eq!(
+            tracks,
+            vec![
+                ChapterTrack {
+                    title: "One".to_string(),
+                    start: secs(0),
+                    end: Some(secs(120)),
+                },
+                ChapterTrack {
+                    title: "Two".to_string(),
+                    start: secs(120),
+                    end: Some(secs(300)),
+                },
+                ChapterTrack {
+                    title: "Chapter 3".to_string(),
+                    start: secs(300),
+                    end: Some(secs(400)),
+                },
+            ]
+        );
+
+        // Without a known duration the last track runs to the end
+        let tracks = chapter_tracks(&chapters, None, &[]);
+        assert_eq!(tracks[2].end, None);
+    }
+
+    #[test]
+    fn test_single_chapter_is_not_split() {
+        assert!(chapter_tracks(&[], Some(400), &[]).is_empty());
+        assert!(chapter_tracks(&[chapter("All", 0)], Some(400), &[]).is_empty());
+        // Markers past the end don't count
+        assert!(
+            chapter_tracks(&[chapter("All", 0), chapter("Late", 500)], Some(400), &[]).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_chapter_tracks_follow_cuts() {
+        let chapters = [
+            chapter("Intro", 0),
+            chapter("Song", 30),
+            chapter("Outro", 200),
+        ];
+        // The intro is cut entirely, and 10s inside the song
+        let cuts = [secs(0)..secs(30), secs(100)..secs(110)];
+        let tracks = chapter_tracks(&chapters, Some(240), &cuts);
+        assert_eq!(tracks.len(), 2);
+        assert_eq!(tracks[0].title, "Song");
+        assert_eq!((tracks[0].start, tracks[0].end), (secs(0), Some(secs(160))));
+        assert_eq!(
+            (tracks[1].start, tracks[1].end),
+            (secs(160), Some(secs(200)))
+        );
+    }
+
+    #[test]
+    fn test_chapter_file_name() {
+        assert_eq!(
+            chapter_file_name("Full Album", 3, 12, "Song: Live"),
+            format!("Full Album - 03 - {}", sanitize_filename("Song: Live"))
+        );
+        assert_eq!(
+            chapter_file_name("Album", 7, 120, "Song"),
+            "Album - 007 - Song"
+        );
+    }
+}
@@ -0,0 +1,316 @@
+//! Differences between a playlist on `YouTube` and its local download.
+//!
+//! Before a playlist is downloaded again, its freshly fetched video list is
+//! compared with the [`DownloadArchive`] of its folder: videos not archived
+//! yet will be downloaded, archived videos missing from the list were
+//! removed from the playlist, and archived videos may have been renamed or
+//! moved since. Moves are found from the positions recorded in the archive,
+//! reporting the fewest videos that explain the new order, so a video
+//! inserted at the top doesn't count every other video as moved.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::archive::{ArchivedVideo, DownloadArchive};
+use super::{PlaylistInfo, VideoAvailability, VideoInfo, find_existing_download};
+
+/// A downloaded video whose title changed on `YouTube`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenamedVideo {
+    /// `YouTube` video ID.
+    pub video_id: String,
+    /// Title when the video was downloaded.
+    pub old_title: String,
+    /// Current title.
+    pub new_title: String,
+}
+
+/// A downloaded video that moved within the playlist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MovedVideo {
+    /// `YouTube` video ID.
+    pub video_id: String,
+    /// Current title.
+    pub title: String,
+    /// Position at the end of the last download, starting at 1.
+    pub old_position: usize,
+    /// Current position, starting at 1.
+    pub new_position: usize,
+}
+
+/// Changes of a playlist since it was last downloaded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlaylistDiff {
+    /// Playlist ID.
+    pub playlist_id: String,
+    /// Playlist title.
+    pub title: String,
+    /// Videos that will be downloaded, in playlist order.
+    pub new_videos: Vec<VideoInfo>,
+    /// Downloaded videos no longer in the playlist.
+    pub removed_videos: Vec<ArchivedVideo>,
+    /// Downloaded videos whose title changed.
+    pub renamed_videos: Vec<RenamedVideo>,
+    /// Downloaded videos that moved within the playlist.
+    pub moved_videos: Vec<MovedVideo>,
+    /// New videos that are private, deleted or blocked and won't be
+    /// downloaded.
+    pub unavailable_count: usize,
+    /// Videos of the playlist that were downloaded before.
+    pub downloaded_count: usize,
+}
+
+impl PlaylistDiff {
+    /// Whether the playlist changed since it was last downloaded.
+    #[must_use]
+    pub const fn has_changes(&self) -> bool {
+        !(self.new_videos.is_empty()
+            && self.removed_videos.is_empty()
+            && self.renamed_videos.is_empty()
+            && self.moved_videos.is_empty())
+    }
+
+    /// File names of the removed videos' tracks, where known.
+    #[must_use]
+    pub fn removed_file_names(&self) -> Vec<String> {
+        self.removed_videos
+            .iter()
+            .filter_map(|video| video.file_name.clone())
+            .collect()
+    }
+}
+
+/// Changes of the playlist `info` since it was downloaded into the folder
+/// of `archive`.
+///
+/// Videos that aren't archived but whose file is in the folder, from
+/// downloads made before the archive existed, count as downloaded.
+#[must_use]
+pub fn diff_playlist(info: &PlaylistInfo, archive: &DownloadArchive) -> PlaylistDiff {
+    let dir = archive.path().parent().unwrap_or_else(|| Path::new("."));
+    diff_videos(info, &archive.videos(), |video| {
+        find_existing_download(dir, &video.title).is_some()
+    })
+}
+
+/// Changes of the playlist `info` against the `archived` videos, counting
+/// the videos `is_on_disk` accepts as downloaded.
+fn diff_videos(
+    info: &PlaylistInfo,
+    archived: &[ArchivedVideo],
+    is_on_disk: impl Fn(&VideoInfo) -> bool,
+) -> PlaylistDiff {
+    let archived_by_id: HashMap<&str, &ArchivedVideo> = archived
+        .iter()
+        .map(|video| (video.video_id.as_str(), video))
+        .collect();
+    let mut diff = PlaylistDiff {
+        playlist_id: info.id.clone(),
+        title: info.title.clone(),
+        ..PlaylistDiff::default()
+    };
+
+    // (old position, new position, video) of archived videos still listed
+    let mut positioned = Vec::new();
+    for (index, video) in info.videos.iter().enumerate() {
+        match archived_by_id.get(video.id.as_str()) {
+            Some(archived) => {
+                diff.downloaded_count += 1;
+                // Private and deleted videos are listed under a placeholder title
+                if video.availability == VideoAvailability::Available
+                    && archived.title != video.title
+                {
+                    diff.renamed_videos.push(RenamedVideo {
+                        video_id: video.id.clone(),
+                        old_title: archived.title.clone(),
+                        new_title: video.title.clone(),
+                    });
+                }
+                if let Some(old_position) = archived.position {
+                    positioned.push((old_position, index + 1, video));
+                }
+            }
+            None if video.availability.unavailable_reason().is_some() => {
+                diff.unavailable_count += 1;
+            }
+            None if is_on_disk(video) => diff.downloaded_count += 1,
+            None => diff.new_videos.push(video.clone()),
+        }
+    }
+
+    let old_positions: Vec<usize> = positioned.iter().map(|(old, _, _)| *old).collect();
+    let in_order: HashSet<usize> = longest_increasing(&old_positions).into_iter().collect();
+    diff.moved_videos = positioned
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| !in_order.contains(index))
+        .map(|(_, (old_position, new_position, video))| MovedVideo {
+            video_id: video.id.clone(),
+            title: video.title.clone(),
+            old_position,
+            new_position,
+        })
+        .collect();
+
+    let listed: HashSet<&str> = info.videos.iter().map(|video| video.id.as_str()).collect();
+    diff.removed_videos = archived
+        .iter()
+        .filter(|video| !listed.contains(video.video_id.as_str()))
+        .cloned()
+        .collect();
+    diff.removed_videos
+        .sort_by_key(|video| (video.position.is_none(), video.position, video.archived_at));
+    diff
+}
+
+/// Indices of a longest strictly increasing subsequence of `values`.
+fn longest_increasing(values: &[usize]) -> Vec<usize> {
+    // tails[k] is the index of the smallest last value of an increasing
+    // subsequence of length k + 1
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous: Vec<Option<usize>> = vec![None; values.len()];
+    for (index, &value) in values.iter().enumerate() {
+        let length = tails.partition_point(|&tail| values[tail] < value);
+        if length > 0 {
+            previous[index] = Some(tails[length - 1]);
+        }
+        if length == tails.len() {
+            tails.push(index);
+        } else {
+            tails[length] = index;
+        }
+    }
+
+    let mut sequence = Vec::with_capacity(tails.len());
+    let mut next = tails.last().copied();
+    while let Some(index) = next {
+        sequence.push(index);
+        next = previous[index];
+    }
+    sequence.reverse();
+    sequence
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::youtube::PlaylistHealth;
+
+    fn video(id: &str, title: &str) -> VideoInfo {
+        VideoInfo {
+            id: id.to_string(),
+            title: title.to_string(),
+            duration_secs: None,
+            channel: None,
+            thumbnail_url: None,
+            availability: VideoAvailability::Available,
+            chapters: Vec::new(),
+        }
+    }
+
+    fn archived(id: &str, title: &str, position: Option<usize>) -> ArchivedVideo {
+        ArchivedVideo {
+            video_id: id.to_string(),
+            title: title.to_string(),
+            file_name: Some(format!("{title}.mp3")),
+            archived_at: 0,
+            position,
+        }
+    }
+
+    fn playlist(videos: Vec<VideoInfo>) -> PlaylistInfo {
+        PlaylistInfo {
+            id: "PL1".to_string(),
+            title: "Mix".to_string(),
+            video_count: videos.len(),
+            health: PlaylistHealth::from_videos(&videos),
+            videos,
+            thumbnail_url: None,
+        }
+    }
+
+    #[test]
+    fn test_new_removed_and_renamed_videos() {
+        let mut private = video("p", "[Private video]");
+        private.availability = VideoAvailability::Private;
+        let info = playlist(vec![
+            video("a", "Song A"),
+            video("n", "New Song"),
+            video("b", "Song B (Remastered)"),
+            private,
+            video("o", "On Disk"),
+        ]);
+        let archive = [
+            archived("a", "Song A", Some(1)),
+            archived("b", "Song B", Some(2)),
+            archived("gone", "Gone", Some(3)),
+        ];
+
+        let diff = diff_videos(&info, &archive, |video| video.id == "o");
+        let new: Vec<_> = diff.new_videos.iter().map(|v| v.id.as_str()).collect();
+        assert_eq!(new, ["n"]);
+        assert_eq!(diff.removed_videos, vec![archived("gone", "Gone", Some(3))]);
+        assert_eq!(
+            diff.renamed_videos,
+            vec![RenamedVideo {
+                video_id: "b".to_string(),
+                old_title: "Song B".to_string(),
+                new_title: "Song B (Remastered)".to_string(),
+            }]
+        );
+        assert!(diff.moved_videos.is_empty());
+        assert_eq!(diff.unavailable_count, 1);
+        assert_eq!(diff.downloaded_count, 3);
+        assert!(diff.has_changes());
+    }
+
+    #[test]
+    fn test_moves_report_fewest_videos() {
+        // "d" moved to the top, and a new video was inserted: only "d" moved
+        let info = playlist(vec![
+            video("d", "D"),
+            video("new", "New"),
+            video("a", "A"),
+            video("b", "B"),
+            video("c", "C"),
+        ]);
+        let archive = [
+            archived("a", "A", Some(1)),
+            archived("b", "B", Some(2)),
+            archived("c", "C", Some(3)),
+            archived("d", "D", Some(4)),
+        ];
+
+        let diff = diff_videos(&info, &archive, |_| false);
+        assert_eq!(
+            diff.moved_videos,
+            vec![MovedVideo {
+                video_id: "d".to_string(),
+                title: "D".to_string(),
+                old_position: 4,
+                new_position: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_unchanged_playlist() {
+        let info = playlist(vec![video("a", "A"), video("b", "B")]);
+        // Videos archived before positions were recorded can't have moved
+        let archive = [archived("a", "A", None), archived("b", "B", Some(1))];
+        let diff = diff_videos(&info, &archive, |_| false);
+        assert!(!diff.has_changes());
+        assert_eq!(diff.downloaded_count, 2);
+    }
+
+    #[test]
+    fn test_longest_increasing() {
+        assert_eq!(longest_increasing(&[]), Vec::<usize>::new());
+        assert_eq!(longest_increasing(&[4, 1, 2, 3]), vec![1, 2, 3]);
+        assert_eq!(longest_increasing(&[1, 2, 3]), vec![0, 1, 2]);
+        assert_eq!(longest_increasing(&[3, 2, 1]).len(), 1);
+    }
+}
@@ -0,0 +1,324 @@
+//! `YouTube` Mixes.
+//!
+//! Mixes are playlists whose ID starts with "RD", generated by `YouTube`
+//! from a seed video. They have no playlist page: their videos are listed
+//! in the playlist panel of the watch page, a couple dozen at a time, and
+//! the `next` endpoint lists the videos following the last one. Mixes never
+//! end, so only their first [`MAX_MIX_VIDEOS`] videos are read.
+
+use std::collections::HashSet;
+
+use serde_json::Value;
+use tracing::{debug, info, warn};
+
+use super::{
+    InnertubeClient, MAX_PLAYLIST_PAGES, PlaylistFetchProgress, RustyYtdlDownloader,
+    VideoAvailability, VideoInfo, parse_duration_text,
+};
+use crate::error::{DownloadError, Error, Result};
+
+/// Number of videos of a Mix that are downloaded.
+pub const MAX_MIX_VIDEOS: usize = 50;
+
+/// Prefixes of Mix IDs that are followed by their seed video's ID, most
+/// specific first.
+const SEEDED_MIX_PREFIXES: [&str; 3] = ["RDAMVM", "RDMM", "RD"];
+
+/// Whether `playlist_id` is a Mix.
+#[must_use]
+pub fn is_mix(playlist_id: &str) -> bool {
+    playlist_id.starts_with("RD")
+}
+
+/// ID of the video a Mix was generated from, when its ID names one.
+fn seed_video_id(playlist_id: &str) -> Option<&str> {
+    SEEDED_MIX_PREFIXES.iter().find_map(|prefix| {
+        playlist_id
+            .strip_prefix(prefix)
+            .filter(|video_id| video_id.len() == 11)
+    })
+}
+
+/// Title and videos of the playlist panel of a watch page or `next`
+/// response.
+#[derive(Debug, Default)]
+struct MixPanel {
+    title: Option<String>,
+    videos: Vec<VideoInfo>,
+}
+
+impl RustyYtdlDownloader {
+    /// Fetch the title and first videos of a Mix, from its watch page and
+    /// then the `next` endpoint until [`MAX_MIX_VIDEOS`] videos are found.
+    ///
+    /// `seed_video` is the video the Mix was opened from, if known.
+    pub(super) fn fetch_mix_info(
+        &self,
+        playlist_id: &str,
+        seed_video: Option<&str>,
+        on_page: &dyn Fn(&PlaylistFetchProgress),
+    ) -> Result<(String, Vec<VideoInfo>)> {
+        let parse_failed = |reason: String| {
+            Error::Download(DownloadError::PlaylistParseFailed {
+                playlist_id: playlist_id.to_string(),
+                reason,
+            })
+        };
+
+        let url = match seed_video.or_else(|| seed_video_id(playlist_id)) {
+            Some(video_id) => {
+                format!("https://www.youtube.com/watch?v={video_id}&list={playlist_id}")
+            }
+            None => format!("https://www.youtube.com/watch?list={playlist_id}"),
+        };
+        info!("Fetching mix watch page: {}", url);
+
+        let client = reqwest::blocking::Client::new();
+        let html = client
+            .get(&url)
+            .header(
+                "User-Agent",
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36",
+            )
+            .header("Accept-Language", "en-US,en;q=0.9")
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .and_then(reqwest::blocking::Response::text)
+            .map_err(|e| parse_failed(format!("Failed to fetch mix watch page: {e}")))?;
+
+        let panel = parse_mix_panel(&Self::extract_yt_initial_data(&html)?)
+            .ok_or_else(|| parse_failed("No mix in the watch page".to_string()))?;
+        let title = panel.title.unwrap_or_else(|| "Unknown Mix".to_string());
+        let mut seen = HashSet::new();
+        let mut videos = Vec::new();
+        add_new_videos(&mut videos, &mut seen, panel.videos);
+        let mut progress = PlaylistFetchProgress {
+            playlist_id: playlist_id.to_string(),
+            pages_fetched: 1,
+            videos_found: videos.len(),
+        };
+        on_page(&progress);
+
+        let innertube = InnertubeClient::from_html(&html);
+        while videos.len() < MAX_MIX_VIDEOS && progress.pages_fetched < MAX_PLAYLIST_PAGES {
+            if self.control.is_cancelled() {
+                return Err(Error::Download(DownloadError::Cancelled));
+            }
+            let Some(last) = videos.last() else {
+                break;
+            };
+
+            let panel = Self::fetch_mix_page(&client, &innertube, playlist_id, last, videos.len())?;
+            // Short mixes, like album mixes, start over once they end
+            if add_new_videos(&mut videos, &mut seen, panel.videos) == 0 {
+                break;
+            }
+            progress.pages_fetched += 1;
+            progress.videos_found = videos.len();
+            debug!(
+                "Fetched page {} of mix {} ({} videos so far)",
+                progress.pages_fetched, playlist_id, progress.videos_found
+            );
+            on_page(&progress);
+        }
+
+        if videos.len() >= MAX_MIX_VIDEOS {
+            warn!(
+                "Mix {} never ends, keeping its first {} videos",
+                playlist_id, MAX_MIX_VIDEOS
+            );
+            videos.truncate(MAX_MIX_VIDEOS);
+        }
+        Ok((title, videos))
+    }
+
+    /// Fetch the videos of a Mix following `last`, the `count`th video.
+    fn fetch_mix_page(
+        client: &reqwest::blocking::Client,
+        innertube: &InnertubeClient,
+        playlist_id: &str,
+        last: &VideoInfo,
+        count: usize,
+    ) -> Result<MixPanel> {
+        let parse_failed = |reason: String| {
+            Error::Download(DownloadError::PlaylistParseFailed {
+                playlist_id: playlist_id.to_string(),
+                reason,
+            })
+        };
+
+        let body = serde_json::to_vec(&serde_json::json!({
+            "context": innertube.context(),
+            "videoId": last.id,
+            "playlistId": playlist_id,
+            "playlistIndex": count - 1,
+        }))?;
+        let text = client
+            .post(innertube.next_url())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("Accept-Language", "en-US,en;q=0.9")
+            .body(body)
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .and_then(reqwest::blocking::Response::text)
+            .map_err(|e| parse_failed(format!("Failed to fetch more mix videos: {e}")))?;
+        let json: Value = serde_json::from_str(&text)
+            .map_err(|e| parse_failed(format!("Failed to parse mix videos: {e}")))?;
+
+        parse_mix_panel(&json).ok_or_else(|| parse_failed("Response has no mix videos".to_string()))
+    }
+}
+
+/// Append the `found` videos not `seen` yet, returning how many were added.
+fn add_new_videos(
+    videos: &mut Vec<VideoInfo>,
+    seen: &mut HashSet<String>,
+    found: Vec<VideoInfo>,
+) -> usize {
+    let before = videos.len();
+    videos.extend(
+        found
+            .into_iter()
+            .filter(|video| seen.insert(video.id.clone())),
+    );
+    videos.len() - before
+}
+
+/// The playlist panel of a watch page's `ytInitialData` or a `next`
+/// response, or `None` if it has none.
+fn parse_mix_panel(data: &Value) -> Option<MixPanel> {
+    let playlist = data
+        .get("contents")?
+        .get("twoColumnWatchNextResults")?
+        .get("playlist")?
+        .get("playlist")?;
+    let title = playlist.get("title").and_then(|title| {
+        title
+            .as_str()
+            .or_else(|| title.get("simpleText")?.as_str())
+            .map(String::from)
+    });
+    let videos = playlist
+        .get("contents")?
+        .as_array()?
+        .iter()
+        .filter_map(parse_panel_video)
+        .collect();
+    Some(MixPanel { title, videos })
+}
+
+/// A video of a playlist panel.
+fn parse_panel_video(item: &Value) -> Option<VideoInfo> {
+    let renderer = item.get("playlistPanelVideoRenderer")?;
+    let title = renderer.get("title")?;
+    let title = title
+        .get("simpleText")
+        .or_else(|| title.get("runs")?.as_array()?.first()?.get("text"))?
+        .as_str()?
+        .to_string();
+
+    Some(VideoInfo {
+        id: renderer.get("videoId")?.as_str()?.to_string(),
+        title,
+        duration_secs: renderer
+            .get("lengthText")
+            .and_then(|text| text.get("simpleText")?.as_str())
+            .and_then(parse_duration_text),
+        channel: renderer
+            .get("shortBylineText")
+            .or_else(|| renderer.get("longBylineText"))
+            .and_then(|byline| {
+                byline
+                    .get("runs")?
+                    .as_array()?
+                    .first()?
+                    .get("text")?
+                    .as_str()
+            })
+            .map(String::from),
+        thumbnail_url: renderer
+            .get("thumbnail")
+            .and_then(|thumbnail| thumbnail.get("thumbnails")?.as_array()?.last())
+            .and_then(|thumbnail| thumbnail.get("url")?.as_str())
+            .map(String::from),
+        availability: VideoAvailability::Available,
+        chapters: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn panel_video(id: &str, title: &str) -> Value {
+        json!({ "playlistPanelVideoRenderer": {
+            "videoId": id,
+            "title": { "simpleText": title },
+            "lengthText": { "simpleText": "4:05" },
+            "shortBylineText": { "runs": [{ "text": "Artist" }] },
+            "thumbnail": { "thumbnails": [{ "url": "small.jpg" }, { "url": "large.jpg" }] }
+        }})
+    }
+
+    #[test]
+    fn test_mix_ids() {
+        assert!(is_mix("RDdQw4w9WgXcQ"));
+        assert!(!is_mix("PLxxxxxxxx"));
+        assert_eq!(seed_video_id("RDdQw4w9WgXcQ"), Some("dQw4w9WgXcQ"));
+        assert_eq!(seed_video_id("RDAMVMdQw4w9WgXcQ"), Some("dQw4w9WgXcQ"));
+        assert_eq!(seed_video_id("RDMMdQw4w9WgXcQ"), Some("dQw4w9WgXcQ"));
+        assert_eq!(
+            seed_video_id("RDCLAK5uy_kmPRjHDECIcuVwnKsx2Ng7fyNgFKWNJFs"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_mix_panel() {
+        let data = json!({
+            "contents": { "twoColumnWatchNextResults": { "playlist": { "playlist": {
+                "title": "Mix - Never Gonna Give You Up",
+                "contents": [
+                    panel_video("dQw4w9WgXcQ", "Never Gonna Give You Up"),
+                    { "automixPreviewVideoRenderer": {} },
+                    panel_video("yPYZpwSpKmA", "Together Forever"),
+                ]
+            }}}}
+        });
+
+        let panel = parse_mix_panel(&data).unwrap();
+        assert_eq!(
+            panel.title.as_deref(),
+            Some("Mix - Never Gonna Give You Up")
+        );
+        assert_eq!(panel.videos.len(), 2);
+        let video = &panel.videos[1];
+        assert_eq!(video.id, "yPYZpwSpKmA");
+        assert_eq!(video.title, "Together Forever");
+        assert_eq!(video.duration_secs, Some(245));
+        assert_eq!(video.channel.as_deref(), Some("Artist"));
+        assert_eq!(video.thumbnail_url.as_deref(), Some("large.jpg"));
+
+        assert!(parse_mix_panel(&json!({ "contents": {} })).is_none());
+    }
+
+    #[test]
+    fn test_add_new_videos_skips_repeats() {
+        let video = |id: &str| parse_panel_video(&panel_video(id, "Song")).unwrap();
+        let mut seen = HashSet::new();
+        let mut videos = Vec::new();
+        assert_eq!(
+            add_new_videos(&mut videos, &mut seen, vec![video("a"), video("b")]),
+            2
+        );
+        assert_eq!(
+            add_new_videos(&mut videos, &mut seen, vec![video("b"), video("c")]),
+            1
+        );
+        assert_eq!(add_new_videos(&mut videos, &mut seen, vec![video("a")]), 0);
+        let ids: Vec<_> = videos.iter().map(|video| video.id.as_str()).collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+    }
+}
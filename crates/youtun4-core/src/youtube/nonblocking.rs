@@ -0,0 +1,286 @@
+//! Downloads awaited from async code.
+//!
+//! [`YouTubeDownloader`] blocks until its network and disk work is done, and
+//! runs the async `rusty_ytdl` requests by blocking on a runtime.
+//! [`AsyncYouTubeDownloader`] moves that work to the Tokio blocking pool, so
+//! async callers, like the app's commands and download queue, await
+//! downloads on their own runtime instead of spawning threads or building
+//! runtimes of their own. Awaited downloads stop when their
+//! [`CancellationToken`] is cancelled, after cleaning up their partial files.
+
+use std::future::Future;
+use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+use tokio::runtime::{Handle, Runtime};
+use tokio_util::sync::CancellationToken;
+
+use super::{DownloadResult, PlaylistInfo, ProgressCallback, VideoInfo, YouTubeDownloader};
+use crate::error::{DownloadError, Error, Result};
+
+/// A `YouTube` downloader that can be awaited.
+///
+/// Implemented for every shared [`YouTubeDownloader`], so an
+/// `Arc<RustyYtdlDownloader>` can be awaited directly.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::path::Path;
+/// use std::sync::Arc;
+/// use tokio_util::sync::CancellationToken;
+/// use youtun4_core::youtube::{AsyncYouTubeDownloader, RustyYtdlDownloader};
+///
+/// # async fn run() -> youtun4_core::Result<()> {
+/// let downloader = Arc::new(RustyYtdlDownloader::new());
+/// let cancel = CancellationToken::new();
+/// let playlist = downloader
+///     .parse_playlist_url("https://www.youtube.com/playlist?list=PLtest")
+///     .await?;
+/// let results = downloader
+///     .download_playlist(&playlist, Path::new("/tmp/music"), None, &cancel)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait AsyncYouTubeDownloader: Send + Sync {
+    /// Parse a `YouTube` URL and fetch the playlist's information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is invalid or not a playlist.
+    fn parse_playlist_url(&self, url: &str) -> impl Future<Output = Result<PlaylistInfo>> + Send;
+
+    /// Parse a `YouTube` video URL and fetch the video's information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL has no video ID or the video is unavailable.
+    fn parse_video_url(&self, url: &str) -> impl Future<Output = Result<VideoInfo>> + Send;
+
+    /// Download all videos from a playlist, until `cancel` is cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the download fails, or
+    /// [`DownloadError::Cancelled`] once `cancel` is cancelled.
+    fn download_playlist(
+        &self,
+        playlist_info: &PlaylistInfo,
+        output_dir: &Path,
+        progress: Option<ProgressCallback>,
+        cancel: &CancellationToken,
+    ) -> impl Future<Output = Result<Vec<DownloadResult>>> + Send;
+
+    /// Download a single video, until `cancel` is cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the video can't be downloaded, or
+    /// [`DownloadError::Cancelled`] once `cancel` is cancelled.
+    fn download_single_video(
+        &self,
+        video: &VideoInfo,
+        output_dir: &Path,
+        progress: Option<ProgressCallback>,
+        cancel: &CancellationToken,
+    ) -> impl Future<Output = Result<DownloadResult>> + Send;
+}
+
+impl<D> AsyncYouTubeDownloader for Arc<D>
+where
+    D: YouTubeDownloader + 'static,
+{
+    async fn parse_playlist_url(&self, url: &str) -> Result<PlaylistInfo> {
+        let url = url.to_string();
+        run_blocking(self, move |downloader| downloader.parse_playlist_url(&url)).await
+    }
+
+    async fn parse_video_url(&self, url: &str) -> Result<VideoInfo> {
+        let url = url.to_string();
+        run_blocking(self, move |downloader| downloader.parse_video_url(&url)).await
+    }
+
+    async fn download_playlist(
+        &self,
+        playlist_info: &PlaylistInfo,
+        output_dir: &Path,
+        progress: Option<ProgressCallback>,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<DownloadResult>> {
+        let playlist_info = playlist_info.clone();
+        let output_dir = output_dir.to_path_buf();
+        run_cancellable(self, cancel, move |downloader| {
+            downloader.download_playlist(&playlist_info, &output_dir, progress)
+        })
+        .await
+    }
+
+    async fn download_single_video(
+        &self,
+        video: &VideoInfo,
+        output_dir: &Path,
+        progress: Option<ProgressCallback>,
+        cancel: &CancellationToken,
+    ) -> Result<DownloadResult> {
+        let video = video.clone();
+        let output_dir = output_dir.to_path_buf();
+        run_cancellable(self, cancel, move |downloader| {
+            downloader.download_single_video(&video, &output_dir, progress)
+        })
+        .await
+    }
+}
+
+/// Run `work` on `downloader` in the blocking pool.
+async fn run_blocking<D, T>(
+    downloader: &Arc<D>,
+    work: impl FnOnce(&D) -> Result<T> + Send + 'static,
+) -> Result<T>
+where
+    D: YouTubeDownloader + 'static,
+    T: Send + 'static,
+{
+    let downloader = Arc::clone(downloader);
+    tokio::task::spawn_blocking(move || work(&downloader))
+        .await
+        .map_err(|e| Error::internal(format!("Download task failed: {e}")))?
+}
+
+/// Run `work` on `downloader` in the blocking pool, cancelling the download
+/// once `cancel` is cancelled.
+async fn run_cancellable<D, T>(
+    downloader: &Arc<D>,
+    cancel: &CancellationToken,
+    work: impl FnOnce(&D) -> Result<T> + Send + 'static,
+) -> Result<T>
+where
+    D: YouTubeDownloader + 'static,
+    T: Send + 'static,
+{
+    if cancel.is_cancelled() {
+        return Err(Error::Download(DownloadError::Cancelled));
+    }
+    let work = run_blocking(downloader, work);
+    tokio::pin!(work);
+    tokio::select! {
+        result = &mut work => return result,
+        () = cancel.cancelled() => {}
+    }
+    // The download stops at its next chunk and removes what it left behind,
+    // so it is awaited rather than dropped
+    downloader.cancel_download();
+    work.await
+}
+
+/// Run `future` to completion from synchronous code.
+///
+/// Inside a multi-threaded runtime, the worker thread is handed over to the
+/// runtime's other tasks while blocking. Elsewhere, the future runs on a
+/// runtime shared by every blocking download, created on first use.
+///
+/// # Errors
+///
+/// Returns an error if no runtime could be created.
+pub(super) fn block_on<F: Future>(future: F) -> Result<F::Output> {
+    if let Ok(handle) = Handle::try_current() {
+        return Ok(tokio::task::block_in_place(|| handle.block_on(future)));
+    }
+
+    static RUNTIME: OnceLock<std::result::Result<Runtime, String>> = OnceLock::new();
+    RUNTIME
+        .get_or_init(|| Runtime::new().map_err(|e| e.to_string()))
+        .as_ref()
+        .map(|runtime| runtime.block_on(future))
+        .map_err(|e| Error::internal(format!("Failed to create tokio runtime: {e}")))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::youtube::VideoAvailability;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    /// A downloader whose downloads run until cancelled.
+    #[derive(Default)]
+    struct StalledDownloader {
+        cancelled: AtomicBool,
+    }
+
+    impl YouTubeDownloader for StalledDownloader {
+        fn parse_playlist_url(&self, url: &str) -> Result<PlaylistInfo> {
+            Ok(PlaylistInfo::from_video(self.parse_video_url(url)?))
+        }
+
+        fn parse_video_url(&self, url: &str) -> Result<VideoInfo> {
+            Ok(VideoInfo {
+                id: url.to_string(),
+                title: "Song".to_string(),
+                duration_secs: None,
+                channel: None,
+                thumbnail_url: None,
+                availability: VideoAvailability::Available,
+                chapters: Vec::new(),
+            })
+        }
+
+        fn download_playlist(
+            &self,
+            _playlist_info: &PlaylistInfo,
+            _output_dir: &Path,
+            _progress: Option<ProgressCallback>,
+        ) -> Result<Vec<DownloadResult>> {
+            while !self.cancelled.load(Ordering::SeqCst) {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            Err(Error::Download(DownloadError::Cancelled))
+        }
+
+        fn cancel_download(&self) {
+            self.cancelled.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_cancel_stops_download() {
+        let downloader = Arc::new(StalledDownloader::default());
+        let playlist = downloader.parse_playlist_url("abc").await.unwrap();
+        assert_eq!(playlist.videos[0].id, "abc");
+
+        let cancel = CancellationToken::new();
+        let download = {
+            let downloader = Arc::clone(&downloader);
+            let cancel = cancel.clone();
+            tokio::spawn(async move {
+                downloader
+                    .download_playlist(&playlist, Path::new("."), None, &cancel)
+                    .await
+            })
+        };
+        cancel.cancel();
+        let result = download.await.unwrap();
+        assert!(matches!(
+            result,
+            Err(Error::Download(DownloadError::Cancelled))
+        ));
+        assert!(downloader.cancelled.load(Ordering::SeqCst));
+
+        // Already cancelled downloads don't start
+        let video = downloader.parse_video_url("def").await.unwrap();
+        let result = downloader
+            .download_single_video(&video, Path::new("."), None, &cancel)
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::Download(DownloadError::Cancelled))
+        ));
+    }
+
+    #[test]
+    fn test_block_on_outside_runtime() {
+        assert_eq!(block_on(async { 42 }).unwrap(), 42);
+        assert_eq!(block_on(async { 7 }).unwrap(), 7);
+    }
+}
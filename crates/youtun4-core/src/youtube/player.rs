@@ -0,0 +1,349 @@
+//! `youtubei` player clients.
+//!
+//! Streams refused to the web client (usually with HTTP 403) are often
+//! served to the mobile apps. When the web client can't download a video,
+//! the `player` endpoint is asked for its streams as the Android app and
+//! then as the iOS app, whose stream URLs need no signature deciphering.
+//! A video no client can download is reported as unavailable, or as not
+//! available in the user's country when a client said so.
+
+use serde_json::Value;
+use tracing::debug;
+
+use super::VideoAvailability;
+use super::quality::AudioFormat;
+use super::retry::{self, RetryCategory};
+use crate::error::{DownloadError, Error, Result};
+
+/// `youtubei` endpoint returning the streams of a video.
+const INNERTUBE_PLAYER_URL: &str = "https://www.youtube.com/youtubei/v1/player?prettyPrint=false";
+
+/// A `youtubei` client a video's streams are requested as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerClient {
+    /// The website, through `rusty_ytdl`.
+    Web,
+    /// The Android app.
+    Android,
+    /// The iOS app.
+    Ios,
+}
+
+impl PlayerClient {
+    /// Clients tried in turn until one can download a video.
+    pub const ROTATION: [Self; 3] = [Self::Web, Self::Android, Self::Ios];
+
+    /// `clientName` and `clientVersion` of the client.
+    const fn name_and_version(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Web => ("WEB", super::DEFAULT_INNERTUBE_CLIENT_VERSION),
+            Self::Android => ("ANDROID", "19.09.37"),
+            Self::Ios => ("IOS", "19.09.3"),
+        }
+    }
+
+    /// User agent of the client's requests.
+    const fn user_agent(self) -> &'static str {
+        match self {
+            Self::Web => "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36",
+            Self::Android => "com.google.android.youtube/19.09.37 (Linux; U; Android 11) gzip",
+            Self::Ios => {
+                "com.google.ios.youtube/19.09.3 (iPhone14,3; U; CPU iOS 15_6 like Mac OS X)"
+            }
+        }
+    }
+
+    /// Body of a `player` request for `video_id`.
+    fn player_body(self, video_id: &str) -> Value {
+        let (name, version) = self.name_and_version();
+        let mut client = serde_json::json!({
+            "clientName": name,
+            "clientVersion": version,
+            "hl": "en",
+            "gl": "US",
+        });
+        match self {
+            Self::Web => {}
+            Self::Android => {
+                client["androidSdkVersion"] = 30.into();
+                client["osName"] = "Android".into();
+                client["osVersion"] = "11".into();
+            }
+            Self::Ios => {
+                client["deviceMake"] = "Apple".into();
+                client["deviceModel"] = "iPhone14,3".into();
+                client["osName"] = "iPhone".into();
+                client["osVersion"] = "15.6.0.19G71".into();
+            }
+        }
+        serde_json::json!({
+            "context": { "client": client },
+            "videoId": video_id,
+            "contentCheckOk": true,
+            "racyCheckOk": true,
+        })
+    }
+
+    /// Fetch the audio streams `YouTube` serves this client for
+    /// `video_id`.
+    pub(super) async fn fetch_formats(
+        self,
+        client: &reqwest::Client,
+        video_id: &str,
+    ) -> Result<Vec<AudioFormat>> {
+        debug!("Requesting the streams of {} as {}", video_id, self);
+        let unavailable = |reason: String| {
+            Error::Download(DownloadError::VideoUnavailable {
+                video_id: video_id.to_string(),
+                reason,
+            })
+        };
+
+        let body = serde_json::to_vec(&self.player_body(video_id))?;
+        let text = client
+            .post(INNERTUBE_PLAYER_URL)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(reqwest::header::USER_AGENT, self.user_agent())
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| {
+                Error::Download(DownloadError::Network {
+                    message: format!("Failed to request the streams of {video_id}: {e}"),
+                    source: Some(Box::new(e)),
+                })
+            })?
+            .error_for_status()
+            .map_err(|e| unavailable(format!("The {self} player request failed: {e}")))?
+            .text()
+            .await
+            .map_err(|e| unavailable(format!("Failed to read the {self} player response: {e}")))?;
+        let json: Value = serde_json::from_str(&text)
+            .map_err(|e| unavailable(format!("Failed to parse the {self} player response: {e}")))?;
+
+        parse_player_response(&json).map_err(unavailable)
+    }
+}
+
+impl std::fmt::Display for PlayerClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Web => write!(f, "web"),
+            Self::Android => write!(f, "Android"),
+            Self::Ios => write!(f, "iOS"),
+        }
+    }
+}
+
+/// The audio streams of a `player` response, or why it has none.
+fn parse_player_response(json: &Value) -> std::result::Result<Vec<AudioFormat>, String> {
+    let status = json
+        .pointer("/playabilityStatus/status")
+        .and_then(Value::as_str)
+        .unwrap_or("ERROR");
+    if status != "OK" {
+        let reason = json
+            .pointer("/playabilityStatus/reason")
+            .and_then(Value::as_str)
+            .unwrap_or("no reason given");
+        return Err(format!("{status}: {reason}"));
+    }
+
+    let formats: Vec<_> = json
+        .pointer("/streamingData/adaptiveFormats")
+        .into_iter()
+        .chain(json.pointer("/streamingData/formats"))
+        .filter_map(Value::as_array)
+        .flatten()
+        .filter_map(parse_format)
+        .collect();
+    if formats.is_empty() {
+        return Err("no audio stream".to_string());
+    }
+    Ok(formats)
+}
+
+/// An audio stream of a `player` response. Streams without a plain URL
+/// (ciphered ones) are left out.
+fn parse_format(format: &Value) -> Option<AudioFormat> {
+    let mime_type = format.get("mimeType")?.as_str()?;
+    let (media, codecs) = mime_type.split_once(';').unwrap_or((mime_type, ""));
+    let (kind, container) = media.trim().split_once('/')?;
+    let has_video = kind == "video";
+    if has_video && format.get("audioQuality").is_none() {
+        return None;
+    }
+    let codecs = codecs
+        .trim()
+        .strip_prefix("codecs=")
+        .map(|codecs| codecs.trim_matches('"'));
+    let bitrate = |key: &str| format.get(key)?.as_u64();
+
+    Some(AudioFormat {
+        itag: format.get("itag")?.as_u64()?,
+        bitrate_kbps: (!has_video)
+            .then(|| bitrate("averageBitrate").or_else(|| bitrate("bitrate")))
+            .flatten()
+            .and_then(|bps| u32::try_from(bps / 1000).ok())
+            .filter(|&kbps| kbps > 0),
+        // Combined streams list the video codec first
+        codec: codecs
+            .and_then(|codecs| codecs.split(',').next_back().map(|c| c.trim().to_string())),
+        container: container.to_string(),
+        has_video,
+        content_length: format
+            .get("contentLength")
+            .and_then(Value::as_str)
+            .and_then(|length| length.parse().ok()),
+        url: format.get("url")?.as_str()?.to_string(),
+    })
+}
+
+/// Whether a download failing with `error` may work with another client.
+pub(super) fn should_rotate(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::Download(DownloadError::VideoUnavailable { .. })
+    ) || retry::classify(error) == Some(RetryCategory::Forbidden)
+}
+
+/// Whether `reason` says a video is blocked in the user's country.
+fn is_geo_blocked(reason: &str) -> bool {
+    let reason = reason.to_lowercase();
+    reason.contains("country") || reason.contains("region")
+}
+
+/// Error of a video no client could download, after each client failed
+/// with the listed error.
+pub(super) fn rotation_failed(video_id: &str, failures: &[(PlayerClient, Error)]) -> Error {
+    let geo_blocked = failures
+        .iter()
+        .any(|(_, error)| is_geo_blocked(&error.to_string()));
+    let reason = if geo_blocked {
+        VideoAvailability::GeoBlocked
+            .unavailable_reason()
+            .unwrap_or("video is geo-blocked")
+    } else {
+        "video is not available to any client"
+    };
+    let clients = failures
+        .iter()
+        .map(|(client, _)| client.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Error::Download(DownloadError::VideoUnavailable {
+        video_id: video_id.to_string(),
+        reason: format!("{reason} (tried {clients})"),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_player_response_formats() {
+        let json = json!({
+            "playabilityStatus": { "status": "OK" },
+            "streamingData": {
+                "formats": [{
+                    "itag": 18,
+                    "url": "https://example.com/18",
+                    "mimeType": "video/mp4; codecs=\"avc1.42001E, mp4a.40.2\"",
+                    "bitrate": 500_000,
+                    "audioQuality": "AUDIO_QUALITY_LOW"
+                }],
+                "adaptiveFormats": [
+                    {
+                        "itag": 251,
+                        "url": "https://example.com/251",
+                        "mimeType": "audio/webm; codecs=\"opus\"",
+                        "bitrate": 140_000,
+                        "averageBitrate": 129_000,
+                        "contentLength": "4000000"
+                    },
+                    {
+                        "itag": 140,
+                        "signatureCipher": "s=abc&url=https%3A%2F%2Fexample.com",
+                        "mimeType": "audio/mp4; codecs=\"mp4a.40.2\""
+                    },
+                    {
+                        "itag": 137,
+                        "url": "https://example.com/137",
+                        "mimeType": "video/mp4; codecs=\"avc1.640028\""
+                    }
+                ]
+            }
+        });
+
+        let formats = parse_player_response(&json).unwrap();
+        assert_eq!(formats.len(), 2);
+        let opus = &formats[0];
+        assert_eq!(opus.itag, 251);
+        assert_eq!(opus.bitrate_kbps, Some(129));
+        assert_eq!(opus.codec.as_deref(), Some("opus"));
+        assert_eq!(opus.container, "webm");
+        assert!(!opus.has_video);
+        assert_eq!(opus.content_length, Some(4_000_000));
+        let combined = &formats[1];
+        assert!(combined.has_video);
+        assert_eq!(combined.codec.as_deref(), Some("mp4a.40.2"));
+        assert_eq!(combined.bitrate_kbps, None);
+    }
+
+    #[test]
+    fn test_parse_player_response_unplayable() {
+        let json = json!({ "playabilityStatus": {
+            "status": "UNPLAYABLE",
+            "reason": "The uploader has not made this video available in your country"
+        }});
+        let reason = parse_player_response(&json).unwrap_err();
+        assert!(reason.starts_with("UNPLAYABLE: "));
+        assert!(is_geo_blocked(&reason));
+
+        let json = json!({ "playabilityStatus": { "status": "OK" }, "streamingData": {} });
+        assert_eq!(parse_player_response(&json).unwrap_err(), "no audio stream");
+    }
+
+    #[test]
+    fn test_rotation_failure_classification() {
+        let unavailable = |reason: &str| {
+            Error::Download(DownloadError::VideoUnavailable {
+                video_id: "abc".to_string(),
+                reason: reason.to_string(),
+            })
+        };
+        let forbidden = unavailable("Failed to get video info: 403 Forbidden");
+        assert!(should_rotate(&forbidden));
+        assert!(!should_rotate(&Error::Download(DownloadError::Cancelled)));
+
+        let geo = rotation_failed(
+            "abc",
+            &[
+                (PlayerClient::Web, forbidden),
+                (
+                    PlayerClient::Android,
+                    unavailable("UNPLAYABLE: This video is not available in your country"),
+                ),
+            ],
+        );
+        let reason = geo.to_string();
+        assert!(reason.contains("not available in your country"));
+        assert!(reason.contains("(tried web, Android)"));
+        // Persistent failures are not retried
+        assert_eq!(retry::classify(&geo), None);
+
+        let other = rotation_failed(
+            "abc",
+            &[(
+                PlayerClient::Ios,
+                unavailable("LOGIN_REQUIRED: Private video"),
+            )],
+        );
+        assert!(!is_geo_blocked(&other.to_string()));
+        assert_eq!(retry::classify(&other), None);
+    }
+}
@@ -0,0 +1,196 @@
+//! Stream selection for the configured download quality.
+//!
+//! `YouTube` offers every video in several formats: audio-only streams
+//! (AAC or Opus at roughly 50 to 160 kbps) and combined video and audio
+//! streams. The download picks the format whose audio bitrate is closest to
+//! the requested one, so a Low preset fetches a small stream and a High
+//! preset the best one available.
+//!
+//! Audio-only streams are tried first since they are much smaller. `YouTube`
+//! sometimes refuses them (HTTP 403), in which case the download falls back
+//! to a combined stream and records it in its [`StreamPath`].
+
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+
+/// Audio-only formats tried before falling back to a combined stream.
+pub const MAX_AUDIO_ONLY_ATTEMPTS: usize = 2;
+
+/// Which kind of stream a video was downloaded from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamPath {
+    /// An audio-only stream.
+    AudioOnly,
+    /// A combined video and audio stream, after the audio-only streams were
+    /// refused.
+    CombinedFallback,
+    /// A combined video and audio stream, the video having no usable
+    /// audio-only stream.
+    Combined,
+}
+
+impl StreamPath {
+    /// Path of a download from a stream with or without video, after
+    /// audio-only streams were `refused` or not.
+    #[must_use]
+    pub const fn new(has_video: bool, refused: bool) -> Self {
+        match (has_video, refused) {
+            (false, _) => Self::AudioOnly,
+            (true, true) => Self::CombinedFallback,
+            (true, false) => Self::Combined,
+        }
+    }
+}
+
+/// An audio-carrying format of a video that can be downloaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioFormat {
+    /// `YouTube` format identifier.
+    pub itag: u64,
+    /// Audio bitrate in kbps, if known.
+    pub bitrate_kbps: Option<u32>,
+    /// Audio codec (e.g. "opus" or "mp4a.40.2"), if known.
+    pub codec: Option<String>,
+    /// Container of the stream (e.g. "mp4" or "webm").
+    pub container: String,
+    /// Whether the stream also carries video.
+    pub has_video: bool,
+    /// Size of the stream in bytes, if known.
+    pub content_length: Option<u64>,
+    /// Stream URL.
+    pub url: String,
+}
+
+impl AudioFormat {
+    /// Summary of a `rusty_ytdl` format, or `None` when it carries no audio
+    /// or is a live stream.
+    #[must_use]
+    pub fn from_ytdl(format: &rusty_ytdl::VideoFormat) -> Option<Self> {
+        if !format.has_audio || format.is_live || format.is_hls || format.is_dash_mpd {
+            return None;
+        }
+        let bitrate_kbps = format
+            .audio_bitrate
+            .or_else(|| (!format.has_video).then_some(format.bitrate / 1000))
+            .and_then(|kbps| u32::try_from(kbps).ok())
+            .filter(|&kbps| kbps > 0);
+        Some(Self {
+            itag: format.itag,
+            bitrate_kbps,
+            codec: format.mime_type.audio_codec.clone(),
+            container: format.mime_type.container.clone(),
+            has_video: format.has_video,
+            content_length: format
+                .content_length
+                .as_deref()
+                .and_then(|length| length.parse().ok()),
+            url: format.url.clone(),
+        })
+    }
+
+    /// File extension for the stream when it is kept as is.
+    #[must_use]
+    pub fn extension(&self) -> &str {
+        match self.container.as_str() {
+            "mp4" if !self.has_video => "m4a",
+            "" => "mp4",
+            container => container,
+        }
+    }
+}
+
+/// `formats` ordered from best to worst match for `target_kbps`.
+///
+/// Audio-only streams come before combined ones. Within each kind, formats
+/// of known bitrate come first, closest to the target; on a tie the higher
+/// bitrate wins.
+#[must_use]
+pub fn rank_formats(mut formats: Vec<AudioFormat>, target_kbps: u32) -> Vec<AudioFormat> {
+    formats.sort_by_key(|format| {
+        (
+            format.has_video,
+            format.bitrate_kbps.is_none(),
+            format
+                .bitrate_kbps
+                .map_or(u32::MAX, |kbps| kbps.abs_diff(target_kbps)),
+            Reverse(format.bitrate_kbps),
+            format.itag,
+        )
+    });
+    formats
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn format(itag: u64, bitrate_kbps: Option<u32>, has_video: bool) -> AudioFormat {
+        AudioFormat {
+            itag,
+            bitrate_kbps,
+            codec: None,
+            container: if itag == 251 { "webm" } else { "mp4" }.to_string(),
+            has_video,
+            content_length: Some(1000),
+            url: format!("https://example.com/{itag}"),
+        }
+    }
+
+    fn formats() -> Vec<AudioFormat> {
+        vec![
+            format(18, Some(96), true),
+            format(139, Some(48), false),
+            format(140, Some(128), false),
+            format(251, Some(160), false),
+            format(22, None, true),
+        ]
+    }
+
+    fn best(target_kbps: u32) -> u64 {
+        rank_formats(formats(), target_kbps)[0].itag
+    }
+
+    #[test]
+    fn test_presets_pick_closest_bitrate() {
+        assert_eq!(best(128), 140);
+        assert_eq!(best(192), 251);
+        assert_eq!(best(320), 251);
+        assert_eq!(best(64), 139);
+    }
+
+    #[test]
+    fn test_ties_prefer_higher_bitrate() {
+        // 144 is 16 kbps from both 128 and 160
+        assert_eq!(best(144), 251);
+    }
+
+    #[test]
+    fn test_audio_only_before_combined() {
+        // The combined stream matches exactly but is much larger
+        assert_eq!(best(96), 140);
+
+        let ranked: Vec<_> = rank_formats(formats(), 1000)
+            .iter()
+            .map(|format| format.itag)
+            .collect();
+        assert_eq!(ranked, [251, 140, 139, 18, 22]);
+    }
+
+    #[test]
+    fn test_stream_path() {
+        assert_eq!(StreamPath::new(false, false), StreamPath::AudioOnly);
+        assert_eq!(StreamPath::new(false, true), StreamPath::AudioOnly);
+        assert_eq!(StreamPath::new(true, true), StreamPath::CombinedFallback);
+        assert_eq!(StreamPath::new(true, false), StreamPath::Combined);
+    }
+
+    #[test]
+    fn test_extension_follows_container() {
+        assert_eq!(format(140, Some(128), false).extension(), "m4a");
+        assert_eq!(format(18, Some(96), true).extension(), "mp4");
+        assert_eq!(format(251, Some(160), false).extension(), "webm");
+    }
+}
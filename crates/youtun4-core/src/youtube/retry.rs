@@ -0,0 +1,322 @@
+//! Retry policy for video downloads.
+//!
+//! A failed video is retried with exponential backoff: each retry waits
+//! `multiplier` times longer than the one before, up to a maximum, and part
+//! of the wait is random so videos failing together don't retry in
+//! lockstep. Only failures that may go away on their own are retried; a
+//! private or deleted video fails at once.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::error::{DownloadError, Error};
+
+/// Kind of download failure that may go away when retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RetryCategory {
+    /// Connection failures, resets and timeouts.
+    Network,
+    /// Too many requests (HTTP 429).
+    RateLimited,
+    /// Forbidden (HTTP 403), usually an expired stream URL.
+    Forbidden,
+    /// Server errors (HTTP 5xx).
+    ServerError,
+    /// Failures of unknown cause.
+    Other,
+}
+
+/// Failure reasons meaning the video can't be downloaded at all.
+const PERMANENT_MARKERS: &[&str] = &[
+    "private",
+    "deleted",
+    "removed",
+    "terminated",
+    "copyright",
+    "not available",
+    "unavailable",
+    "not found",
+    "does not exist",
+    "sign in",
+    "age-restricted",
+    "age restricted",
+    "confirm your age",
+];
+
+/// Failure reasons of network errors.
+const NETWORK_MARKERS: &[&str] = &[
+    "timed out",
+    "timeout",
+    "connection",
+    "network",
+    "dns",
+    "error sending request",
+    "error decoding response body",
+    "broken pipe",
+    "unexpected eof",
+];
+
+/// When and how often a failed video is retried.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Attempts per video, including the first one.
+    pub max_attempts: u32,
+    /// Wait before the first retry.
+    pub base_delay: Duration,
+    /// Factor applied to the wait after each retry.
+    pub multiplier: f64,
+    /// Longest wait before a retry.
+    pub max_delay: Duration,
+    /// Share of the wait that is random, from 0 (none) to 1 (anywhere
+    /// between no wait and the full wait).
+    pub jitter: f64,
+    /// Failures that are retried.
+    pub retry_on: Vec<RetryCategory>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(2),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(60),
+            jitter: 0.25,
+            retry_on: vec![
+                RetryCategory::Network,
+                RetryCategory::RateLimited,
+                RetryCategory::Forbidden,
+                RetryCategory::ServerError,
+                RetryCategory::Other,
+            ],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether a video failing with `error` on attempt `attempt` (starting
+    /// at 1) is tried again.
+    #[must_use]
+    pub fn should_retry(&self, error: &Error, attempt: u32) -> bool {
+        attempt < self.max_attempts
+            && classify(error).is_some_and(|category| self.retry_on.contains(&category))
+    }
+
+    /// Wait before retry number `retry` (starting at 1), without jitter.
+    #[must_use]
+    pub fn delay(&self, retry: u32) -> Duration {
+        let exponent = i32::try_from(retry.saturating_sub(1)).unwrap_or(i32::MAX);
+        let secs = self.base_delay.as_secs_f64() * self.multiplier.max(1.0).powi(exponent);
+        Duration::try_from_secs_f64(secs)
+            .unwrap_or(Duration::MAX)
+            .min(self.max_delay)
+    }
+
+    /// Wait before retry number `retry` after `error`.
+    ///
+    /// Rate limiting with a suggested delay waits at least that long.
+    #[must_use]
+    pub fn backoff(&self, retry: u32, error: &Error) -> Duration {
+        let delay = self.jittered(self.delay(retry), random_fraction());
+        let suggested = match error.retry_delay_secs() {
+            Some(secs) if classify(error) == Some(RetryCategory::RateLimited) => {
+                Duration::from_secs(secs)
+            }
+            _ => Duration::ZERO,
+        };
+        delay.max(suggested)
+    }
+
+    /// `delay` shortened by up to its jitter share, `sample` being a
+    /// random number in `0.0..1.0`.
+    fn jittered(&self, delay: Duration, sample: f64) -> Duration {
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        delay.mul_f64(jitter.mul_add(-sample, 1.0))
+    }
+}
+
+/// Kind of `error`, or `None` when retrying can't help.
+#[must_use]
+pub fn classify(error: &Error) -> Option<RetryCategory> {
+    match error {
+        Error::Download(DownloadError::Network { .. } | DownloadError::Timeout { .. }) => {
+            Some(RetryCategory::Network)
+        }
+        Error::Download(DownloadError::RateLimited { .. }) => Some(RetryCategory::RateLimited),
+        Error::Download(
+            DownloadError::VideoUnavailable { reason, .. }
+            | DownloadError::AudioExtractionFailed { reason, .. },
+        ) => classify_reason(reason),
+        Error::Download(DownloadError::ConversionFailed { .. }) => Some(RetryCategory::Other),
+        Error::Io(_) if error.is_retryable() => Some(RetryCategory::Network),
+        Error::WithContext { source, .. } => classify(source),
+        _ => None,
+    }
+}
+
+/// Kind of a failure described by `reason`, as reported by `rusty_ytdl`
+/// and `reqwest`.
+fn classify_reason(reason: &str) -> Option<RetryCategory> {
+    let reason = reason.to_lowercase();
+    let mentions = |markers: &[&str]| markers.iter().any(|marker| reason.contains(marker));
+
+    // Status codes first: "503 Service Unavailable" is not a missing video
+    if mentions(&["too many requests"]) {
+        Some(RetryCategory::RateLimited)
+    } else if mentions(&["forbidden"]) {
+        Some(RetryCategory::Forbidden)
+    } else if mentions(&[
+        "server error",
+        "internal server error",
+        "bad gateway",
+        "service unavailable",
+        "gateway timeout",
+    ]) {
+        Some(RetryCategory::ServerError)
+    } else if mentions(PERMANENT_MARKERS) {
+        None
+    } else if mentions(NETWORK_MARKERS) {
+        Some(RetryCategory::Network)
+    } else {
+        Some(RetryCategory::Other)
+    }
+}
+
+/// A random number in `0.0..1.0`.
+fn random_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish() >> 11;
+    bits as f64 / (1_u64 << 53) as f64
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn extraction_failed(reason: &str) -> Error {
+        Error::Download(DownloadError::AudioExtractionFailed {
+            title: "Song".to_string(),
+            reason: reason.to_string(),
+        })
+    }
+
+    fn unavailable(reason: &str) -> Error {
+        Error::Download(DownloadError::VideoUnavailable {
+            video_id: "abc".to_string(),
+            reason: reason.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_delay_grows_exponentially_up_to_max() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_secs(1),
+            multiplier: 3.0,
+            max_delay: Duration::from_secs(20),
+            ..RetryPolicy::default()
+        };
+        assert_eq!(policy.delay(1), Duration::from_secs(1));
+        assert_eq!(policy.delay(2), Duration::from_secs(3));
+        assert_eq!(policy.delay(3), Duration::from_secs(9));
+        assert_eq!(policy.delay(4), Duration::from_secs(20));
+        assert_eq!(policy.delay(u32::MAX), Duration::from_secs(20));
+    }
+
+    #[test]
+    fn test_jitter_shortens_by_up_to_its_share() {
+        let policy = RetryPolicy {
+            jitter: 0.5,
+            ..RetryPolicy::default()
+        };
+        let delay = Duration::from_secs(10);
+        assert_eq!(policy.jittered(delay, 0.0), delay);
+        assert_eq!(policy.jittered(delay, 0.5), Duration::from_millis(7500));
+
+        let no_jitter = RetryPolicy {
+            jitter: 0.0,
+            ..RetryPolicy::default()
+        };
+        assert_eq!(no_jitter.jittered(delay, 0.9), delay);
+
+        for _ in 0..100 {
+            let sample = random_fraction();
+            assert!((0.0..1.0).contains(&sample));
+            let backoff = policy.backoff(1, &Error::network_error("reset"));
+            assert!(backoff <= policy.base_delay && backoff >= policy.base_delay / 2);
+        }
+    }
+
+    #[test]
+    fn test_backoff_honors_rate_limit_delay() {
+        let policy = RetryPolicy::default();
+        let error = Error::Download(DownloadError::RateLimited {
+            retry_after_secs: 30,
+        });
+        assert!(policy.backoff(1, &error) >= Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_classify_retryable_errors() {
+        assert_eq!(
+            classify(&Error::network_error("connection reset")),
+            Some(RetryCategory::Network)
+        );
+        assert_eq!(
+            classify(&Error::Download(DownloadError::RateLimited {
+                retry_after_secs: 0
+            })),
+            Some(RetryCategory::RateLimited)
+        );
+        assert_eq!(
+            classify(&extraction_failed(
+                "Failed to request bytes 0-403: HTTP status client error (403 Forbidden) for url"
+            )),
+            Some(RetryCategory::Forbidden)
+        );
+        assert_eq!(
+            classify(&extraction_failed(
+                "HTTP status server error (503 Service Unavailable)"
+            )),
+            Some(RetryCategory::ServerError)
+        );
+        assert_eq!(
+            classify(&unavailable(
+                "Failed to get video info: operation timed out"
+            )),
+            Some(RetryCategory::Network)
+        );
+        assert_eq!(
+            classify(&extraction_failed("Empty response at byte 42")),
+            Some(RetryCategory::Other)
+        );
+    }
+
+    #[test]
+    fn test_classify_permanent_errors() {
+        for reason in [
+            "Failed to get video info: Video is private",
+            "This video has been removed by the uploader",
+            "Video unavailable",
+            "Sign in to confirm your age",
+        ] {
+            assert_eq!(classify(&unavailable(reason)), None, "{reason}");
+        }
+        assert_eq!(classify(&Error::Download(DownloadError::Cancelled)), None);
+        assert_eq!(classify(&Error::offline("downloading")), None);
+    }
+
+    #[test]
+    fn test_should_retry_respects_attempts_and_categories() {
+        let policy = RetryPolicy {
+            max_attempts: 2,
+            retry_on: vec![RetryCategory::Network],
+            ..RetryPolicy::default()
+        };
+        let network = Error::network_error("connection refused");
+        assert!(policy.should_retry(&network, 1));
+        assert!(!policy.should_retry(&network, 2));
+        assert!(!policy.should_retry(&extraction_failed("403 Forbidden"), 1));
+        assert!(!policy.should_retry(&unavailable("Video is private"), 1));
+    }
+}
@@ -0,0 +1,438 @@
+//! `YouTube` search.
+//!
+//! Searches scrape the results page the way playlists are scraped, reading
+//! the videos and playlists listed in its `ytInitialData`. Only the first
+//! page of results is read, usually around twenty results; channels,
+//! shelves and ads are left out.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{debug, info};
+
+use super::{RustyYtdlDownloader, parse_duration_text};
+use crate::error::{DownloadError, Error, Result};
+
+/// Results page of `YouTube` searches.
+const SEARCH_URL: &str = "https://www.youtube.com/results";
+
+/// Which results a search returns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchFilter {
+    /// Videos and playlists.
+    #[default]
+    All,
+    /// Videos only.
+    Videos,
+    /// Playlists only.
+    Playlists,
+}
+
+impl SearchFilter {
+    /// Value of the results page's `sp` parameter selecting the results.
+    const fn param(self) -> Option<&'static str> {
+        match self {
+            Self::All => None,
+            Self::Videos => Some("EgIQAQ=="),
+            Self::Playlists => Some("EgIQAw=="),
+        }
+    }
+}
+
+/// Kind of a search result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchResultKind {
+    /// A single video.
+    Video,
+    /// A playlist or mix.
+    Playlist,
+}
+
+/// A video or playlist found by a search.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchResult {
+    /// Whether the result is a video or a playlist.
+    pub kind: SearchResultKind,
+    /// Video or playlist ID.
+    pub id: String,
+    /// Title.
+    pub title: String,
+    /// Channel that uploaded the video or owns the playlist, if listed.
+    pub channel: Option<String>,
+    /// Duration of a video in seconds, if known (not for live streams).
+    pub duration_secs: Option<u64>,
+    /// Number of videos of a playlist, if listed.
+    pub video_count: Option<usize>,
+    /// Thumbnail URL.
+    pub thumbnail_url: Option<String>,
+}
+
+impl SearchResult {
+    /// URL of the video or playlist.
+    #[must_use]
+    pub fn url(&self) -> String {
+        match self.kind {
+            SearchResultKind::Video => format!("https://www.youtube.com/watch?v={}", self.id),
+            SearchResultKind::Playlist => {
+                format!("https://www.youtube.com/playlist?list={}", self.id)
+            }
+        }
+    }
+}
+
+/// Search `YouTube` for `query`, in the order `YouTube` ranks the results.
+///
+/// # Errors
+///
+/// Returns an error if the query is empty, or the results page can't be
+/// fetched or read.
+pub fn search(query: &str, filter: SearchFilter) -> Result<Vec<SearchResult>> {
+    let query = query.trim();
+    let failed = |reason: &str| {
+        Error::Download(DownloadError::SearchFailed {
+            query: query.to_string(),
+            reason: reason.to_string(),
+        })
+    };
+    if query.is_empty() {
+        return Err(failed("the query is empty"));
+    }
+
+    info!("Searching YouTube for '{}' ({:?})", query, filter);
+    let network = |e: reqwest::Error| {
+        Error::Download(DownloadError::Network {
+            message: format!("Failed to search YouTube for '{query}': {e}"),
+            source: Some(Box::new(e)),
+        })
+    };
+    let html = reqwest::blocking::Client::new()
+        .get(search_url(query, filter)?)
+        .header(
+            "User-Agent",
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36",
+        )
+        .header("Accept-Language", "en-US,en;q=0.9")
+        .send()
+        .and_then(reqwest::blocking::Response::error_for_status)
+        .and_then(reqwest::blocking::Response::text)
+        .map_err(network)?;
+
+    let data = RustyYtdlDownloader::extract_yt_initial_data(&html)
+        .map_err(|_| failed("no search data in the results page"))?;
+    let results =
+        parse_search_results(&data).ok_or_else(|| failed("no results in the results page"))?;
+    debug!("Search for '{}' found {} results", query, results.len());
+    Ok(results)
+}
+
+/// URL of the results page of `query`.
+fn search_url(query: &str, filter: SearchFilter) -> Result<reqwest::Url> {
+    let mut params = vec![("search_query", query)];
+    params.extend(filter.param().map(|sp| ("sp", sp)));
+    reqwest::Url::parse_with_params(SEARCH_URL, params)
+        .map_err(|e| Error::internal(format!("Invalid search URL: {e}")))
+}
+
+/// Videos and playlists of a results page's `ytInitialData`, or `None`
+/// if it has no results section.
+fn parse_search_results(data: &Value) -> Option<Vec<SearchResult>> {
+    let sections = data
+        .get("contents")?
+        .get("twoColumnSearchResultsRenderer")?
+        .get("primaryContents")?
+        .get("sectionListRenderer")?
+        .get("contents")?
+        .as_array()?;
+    Some(
+        sections
+            .iter()
+            .filter_map(|section| {
+                section
+                    .get("itemSectionRenderer")?
+                    .get("contents")?
+                    .as_array()
+            })
+            .flatten()
+            .filter_map(parse_search_item)
+            .collect(),
+    )
+}
+
+/// A video or playlist of the results, or `None` for other items.
+fn parse_search_item(item: &Value) -> Option<SearchResult> {
+    if let Some(renderer) = item.get("videoRenderer") {
+        return parse_video(renderer);
+    }
+    if let Some(renderer) = item.get("playlistRenderer") {
+        return parse_playlist(renderer);
+    }
+    parse_lockup(item.get("lockupViewModel")?)
+}
+
+fn parse_video(renderer: &Value) -> Option<SearchResult> {
+    Some(SearchResult {
+        kind: SearchResultKind::Video,
+        id: renderer.get("videoId")?.as_str()?.to_string(),
+        title: text(renderer.get("title")?)?,
+        channel: renderer
+            .get("ownerText")
+            .or_else(|| renderer.get("longBylineText"))
+            .and_then(text),
+        duration_secs: renderer
+            .get("lengthText")
+            .and_then(text)
+            .as_deref()
+            .and_then(parse_duration_text),
+        video_count: None,
+        thumbnail_url: renderer.get("thumbnail").and_then(last_thumbnail),
+    })
+}
+
+fn parse_playlist(renderer: &Value) -> Option<SearchResult> {
+    Some(SearchResult {
+        kind: SearchResultKind::Playlist,
+        id: renderer.get("playlistId")?.as_str()?.to_string(),
+        title: text(renderer.get("title")?)?,
+        channel: renderer
+            .get("shortBylineText")
+            .or_else(|| renderer.get("longBylineText"))
+            .and_then(text),
+        duration_secs: None,
+        video_count: renderer
+            .get("videoCount")
+            .and_then(Value::as_str)
+            .and_then(leading_number),
+        thumbnail_url: renderer
+            .get("thumbnails")
+            .and_then(Value::as_array)
+            .and_then(|thumbnails| thumbnails.first())
+            .and_then(last_thumbnail),
+    })
+}
+
+/// A playlist in the newer "lockup" layout of the results.
+fn parse_lockup(lockup: &Value) -> Option<SearchResult> {
+    if lockup.get("contentType")?.as_str()? != "LOCKUP_CONTENT_TYPE_PLAYLIST" {
+        return None;
+    }
+    let metadata = lockup.get("metadata")?.get("lockupMetadataViewModel")?;
+    let thumbnail = lockup
+        .get("contentImage")
+        .and_then(|image| image.get("collectionThumbnailViewModel"))
+        .and_then(|image| image.get("primaryThumbnail"))
+        .and_then(|image| image.get("thumbnailViewModel"));
+    // "25 videos" is shown as a badge on the thumbnail
+    let video_count = thumbnail
+        .and_then(|thumbnail| thumbnail.get("overlays"))
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|overlay| {
+            overlay
+                .get("thumbnailOverlayBadgeViewModel")?
+                .get("thumbnailBadges")?
+                .as_array()
+        })
+        .flatten()
+        .find_map(|badge| {
+            leading_number(
+                badge
+                    .get("thumbnailBadgeViewModel")?
+                    .get("text")?
+                    .as_str()?,
+            )
+        });
+
+    Some(SearchResult {
+        kind: SearchResultKind::Playlist,
+        id: lockup.get("contentId")?.as_str()?.to_string(),
+        title: metadata.get("title")?.get("content")?.as_str()?.to_string(),
+        channel: metadata
+            .get("metadata")
+            .and_then(|rows| rows.get("contentMetadataViewModel"))
+            .and_then(|rows| rows.get("metadataRows"))
+            .and_then(Value::as_array)
+            .and_then(|rows| rows.first())
+            .and_then(|row| row.get("metadataParts"))
+            .and_then(Value::as_array)
+            .and_then(|parts| parts.first())
+            .and_then(|part| part.get("text")?.get("content")?.as_str())
+            .map(String::from),
+        duration_secs: None,
+        video_count,
+        thumbnail_url: thumbnail
+            .and_then(|thumbnail| thumbnail.get("image")?.get("sources")?.as_array()?.last())
+            .and_then(|source| source.get("url")?.as_str())
+            .map(String::from),
+    })
+}
+
+/// Text of a `YouTube` text object, either simple or made of runs.
+fn text(value: &Value) -> Option<String> {
+    if let Some(text) = value.get("simpleText").and_then(Value::as_str) {
+        return Some(text.to_string());
+    }
+    let text: String = value
+        .get("runs")?
+        .as_array()?
+        .iter()
+        .filter_map(|run| run.get("text")?.as_str())
+        .collect();
+    (!text.is_empty()).then_some(text)
+}
+
+/// URL of the largest thumbnail of a `thumbnails` object.
+fn last_thumbnail(value: &Value) -> Option<String> {
+    value
+        .get("thumbnails")?
+        .as_array()?
+        .last()?
+        .get("url")?
+        .as_str()
+        .map(String::from)
+}
+
+/// The number a text like "1,234 videos" starts with.
+fn leading_number(text: &str) -> Option<usize> {
+    let digits: String = text
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',')
+        .filter(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn results_page(items: Vec<Value>) -> Value {
+        json!({
+            "contents": {
+                "twoColumnSearchResultsRenderer": {
+                    "primaryContents": {
+                        "sectionListRenderer": {
+                            "contents": [
+                                { "itemSectionRenderer": { "contents": items } },
+                                { "continuationItemRenderer": {} }
+                            ]
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_parse_search_results() {
+        let page = results_page(vec![
+            json!({ "videoRenderer": {
+                "videoId": "dQw4w9WgXcQ",
+                "title": { "runs": [{ "text": "Never Gonna " }, { "text": "Give You Up" }] },
+                "ownerText": { "runs": [{ "text": "Rick Astley" }] },
+                "lengthText": { "simpleText": "3:33" },
+                "thumbnail": { "thumbnails": [{ "url": "small.jpg" }, { "url": "large.jpg" }] }
+            }}),
+            json!({ "channelRenderer": { "channelId": "UC123" } }),
+            json!({ "playlistRenderer": {
+                "playlistId": "PLabc",
+                "title": { "simpleText": "80s Hits" },
+                "videoCount": "1,204",
+                "shortBylineText": { "runs": [{ "text": "Hits Channel" }] },
+                "thumbnails": [{ "thumbnails": [{ "url": "playlist.jpg" }] }]
+            }}),
+            json!({ "lockupViewModel": {
+                "contentId": "PLdef",
+                "contentType": "LOCKUP_CONTENT_TYPE_PLAYLIST",
+                "metadata": { "lockupMetadataViewModel": {
+                    "title": { "content": "Lofi Beats" },
+                    "metadata": { "contentMetadataViewModel": { "metadataRows": [
+                        { "metadataParts": [{ "text": { "content": "Lofi Girl" } }] }
+                    ]}}
+                }},
+                "contentImage": { "collectionThumbnailViewModel": { "primaryThumbnail": {
+                    "thumbnailViewModel": {
+                        "image": { "sources": [{ "url": "lockup.jpg" }] },
+                        "overlays": [{ "thumbnailOverlayBadgeViewModel": { "thumbnailBadges": [
+                            { "thumbnailBadgeViewModel": { "text": "42 videos" } }
+                        ]}}]
+                    }
+                }}}
+            }}),
+            json!({ "lockupViewModel": {
+                "contentId": "abc",
+                "contentType": "LOCKUP_CONTENT_TYPE_VIDEO"
+            }}),
+        ]);
+
+        let results = parse_search_results(&page).unwrap();
+        assert_eq!(
+            results,
+            vec![
+                SearchResult {
+                    kind: SearchResultKind::Video,
+                    id: "dQw4w9WgXcQ".to_string(),
+                    title: "Never Gonna Give You Up".to_string(),
+                    channel: Some("Rick Astley".to_string()),
+                    duration_secs: Some(213),
+                    video_count: None,
+                    thumbnail_url: Some("large.jpg".to_string()),
+                },
+                SearchResult {
+                    kind: SearchResultKind::Playlist,
+                    id: "PLabc".to_string(),
+                    title: "80s Hits".to_string(),
+                    channel: Some("Hits Channel".to_string()),
+                    duration_secs: None,
+                    video_count: Some(1204),
+                    thumbnail_url: Some("playlist.jpg".to_string()),
+                },
+                SearchResult {
+                    kind: SearchResultKind::Playlist,
+                    id: "PLdef".to_string(),
+                    title: "Lofi Beats".to_string(),
+                    channel: Some("Lofi Girl".to_string()),
+                    duration_secs: None,
+                    video_count: Some(42),
+                    thumbnail_url: Some("lockup.jpg".to_string()),
+                },
+            ]
+        );
+        assert_eq!(
+            results[0].url(),
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ"
+        );
+        assert_eq!(
+            results[1].url(),
+            "https://www.youtube.com/playlist?list=PLabc"
+        );
+
+        assert!(parse_search_results(&json!({ "contents": {} })).is_none());
+    }
+
+    #[test]
+    fn test_search_url() {
+        let url = search_url("lofi & chill", SearchFilter::All).unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://www.youtube.com/results?search_query=lofi+%26+chill"
+        );
+        let url = search_url("lofi", SearchFilter::Playlists).unwrap();
+        assert_eq!(
+            url.query_pairs().find(|(key, _)| key == "sp").unwrap().1,
+            "EgIQAw=="
+        );
+    }
+
+    #[test]
+    fn test_empty_query() {
+        assert!(matches!(
+            search("  ", SearchFilter::All),
+            Err(Error::Download(DownloadError::SearchFailed { .. }))
+        ));
+    }
+}
@@ -0,0 +1,324 @@
+//! `SponsorBlock` integration for trimming segments from downloaded audio.
+//!
+//! [`SponsorBlock`](https://sponsor.ajay.app) is a crowd-sourced database of
+//! sponsor reads, intros, outros and other segments of `YouTube` videos.
+//! When enabled, the segments of the configured categories are looked up per
+//! video ID and cut out while the stream is converted to MP3.
+
+use std::ops::Range;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::error::{DownloadError, Error, Result};
+
+/// Default `SponsorBlock` API server.
+pub const DEFAULT_SPONSORBLOCK_API_URL: &str = "https://sponsor.ajay.app";
+
+/// Category of a `SponsorBlock` segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentCategory {
+    /// Paid promotion.
+    Sponsor,
+    /// Unpaid self-promotion (merch, Patreon, other channels).
+    SelfPromo,
+    /// Reminders to like, subscribe or follow.
+    Interaction,
+    /// Intro animation or intermission without content.
+    Intro,
+    /// End cards and credits.
+    Outro,
+    /// Recap or preview of other content.
+    Preview,
+    /// Non-music part of a music video.
+    MusicOfftopic,
+    /// Tangents and filler.
+    Filler,
+}
+
+impl SegmentCategory {
+    /// Name of the category in the `SponsorBlock` API.
+    #[must_use]
+    pub const fn api_name(self) -> &'static str {
+        match self {
+            Self::Sponsor => "sponsor",
+            Self::SelfPromo => "selfpromo",
+            Self::Interaction => "interaction",
+            Self::Intro => "intro",
+            Self::Outro => "outro",
+            Self::Preview => "preview",
+            Self::MusicOfftopic => "music_offtopic",
+            Self::Filler => "filler",
+        }
+    }
+
+    /// Category with the given API name.
+    #[must_use]
+    pub fn from_api_name(name: &str) -> Option<Self> {
+        [
+            Self::Sponsor,
+            Self::SelfPromo,
+            Self::Interaction,
+            Self::Intro,
+            Self::Outro,
+            Self::Preview,
+            Self::MusicOfftopic,
+            Self::Filler,
+        ]
+        .into_iter()
+        .find(|category| category.api_name() == name)
+    }
+}
+
+/// `SponsorBlock` settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SponsorBlockConfig {
+    /// Whether segments are removed from downloaded audio.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Categories of segments to remove.
+    #[serde(default = "default_categories")]
+    pub categories: Vec<SegmentCategory>,
+    /// `SponsorBlock` API server.
+    #[serde(default = "default_api_url")]
+    pub api_url: String,
+}
+
+fn default_categories() -> Vec<SegmentCategory> {
+    vec![
+        SegmentCategory::Sponsor,
+        SegmentCategory::Intro,
+        SegmentCategory::Outro,
+    ]
+}
+
+fn default_api_url() -> String {
+    DEFAULT_SPONSORBLOCK_API_URL.to_string()
+}
+
+impl Default for SponsorBlockConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            categories: default_categories(),
+            api_url: default_api_url(),
+        }
+    }
+}
+
+/// A segment to remove from a video.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment {
+    /// Category of the segment.
+    pub category: SegmentCategory,
+    /// Start of the segment in seconds.
+    pub start_secs: f64,
+    /// End of the segment in seconds.
+    pub end_secs: f64,
+}
+
+/// A segment as returned by the `skipSegments` endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiSegment {
+    category: String,
+    #[serde(default)]
+    action_type: Option<String>,
+    segment: (f64, f64),
+}
+
+/// Client for the `SponsorBlock` API.
+#[derive(Debug, Clone)]
+pub struct SponsorBlockClient {
+    config: SponsorBlockConfig,
+}
+
+impl SponsorBlockClient {
+    /// Create a client for `config`.
+    #[must_use]
+    pub const fn new(config: SponsorBlockConfig) -> Self {
+        Self { config }
+    }
+
+    /// Fetch the segments of the configured categories for `video_id`.
+    ///
+    /// A video without submitted segments has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API can't be reached or answers with an
+    /// unexpected response.
+    pub fn fetch_segments(&self, video_id: &str) -> Result<Vec<Segment>> {
+        if self.config.categories.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let categories: String = self
+            .config
+            .categories
+            .iter()
+            .map(|category| format!("&category={}", category.api_name()))
+            .collect();
+        let url = format!(
+            "{}/api/skipSegments?videoID={video_id}{categories}",
+            self.config.api_url.trim_end_matches('/')
+        );
+        debug!("Fetching SponsorBlock segments: {}", url);
+
+        let request_failed = |reason: String| {
+            Error::Download(DownloadError::VideoUnavailable {
+                video_id: video_id.to_string(),
+                reason: format!("SponsorBlock lookup failed: {reason}"),
+            })
+        };
+        let response = reqwest::blocking::Client::new()
+            .get(&url)
+            .send()
+            .map_err(|e| request_failed(e.to_string()))?;
+
+        // The API answers 404 when no segments were submitted
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(Vec::new());
+        }
+        let body = response
+            .error_for_status()
+            .and_then(reqwest::blocking::Response::text)
+            .map_err(|e| request_failed(e.to_string()))?;
+        parse_segments(&body).map_err(request_failed)
+    }
+}
+
+/// Parse a `skipSegments` response, keeping segments that are skipped.
+fn parse_segments(body: &str) -> std::result::Result<Vec<Segment>, String> {
+    let segments: Vec<ApiSegment> =
+        serde_json::from_str(body).map_err(|e| format!("invalid response: {e}"))?;
+    Ok(segments
+        .into_iter()
+        .filter(|segment| segment.action_type.as_deref().is_none_or(|a| a == "skip"))
+        .filter_map(|segment| {
+            Some(Segment {
+                category: SegmentCategory::from_api_name(&segment.category)?,
+                start_secs: segment.segment.0,
+                end_secs: segment.segment.1,
+            })
+        })
+        .collect())
+}
+
+/// Time ranges to cut for `segments`, sorted and with overlaps merged.
+///
+/// Empty or inverted segments are ignored.
+#[must_use]
+pub fn cut_ranges(segments: &[Segment]) -> Vec<Range<Duration>> {
+    let mut ranges: Vec<Range<Duration>> = segments
+        .iter()
+        .filter(|segment| segment.start_secs.is_finite() && segment.end_secs.is_finite())
+        .map(|segment| {
+            Duration::from_secs_f64(segment.start_secs.max(0.0))
+                ..Duration::from_secs_f64(segment.end_secs.max(0.0))
+        })
+        .filter(|range| range.start < range.end)
+        .collect();
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<Duration>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Total length of `ranges`.
+#[must_use]
+pub fn total_cut(ranges: &[Range<Duration>]) -> Duration {
+    ranges
+        .iter()
+        .map(|range| range.end.saturating_sub(range.start))
+        .sum()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn segment(start_secs: f64, end_secs: f64) -> Segment {
+        Segment {
+            category: SegmentCategory::Sponsor,
+            start_secs,
+            end_secs,
+        }
+    }
+
+    #[test]
+    fn test_parse_segments() {
+        let body = r#"[
+            {"category":"sponsor","actionType":"skip","segment":[10.5,40.0],"UUID":"a","videoDuration":300},
+            {"category":"intro","actionType":"skip","segment":[0,5.25],"UUID":"b"},
+            {"category":"sponsor","actionType":"mute","segment":[50,60],"UUID":"c"},
+            {"category":"poi_highlight","actionType":"poi","segment":[90,90],"UUID":"d"}
+        ]"#;
+        let segments = parse_segments(body).unwrap();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0], segment(10.5, 40.0));
+        assert_eq!(segments[1].category, SegmentCategory::Intro);
+        assert!(parse_segments("not json").is_err());
+    }
+
+    #[test]
+    fn test_cut_ranges_merges_overlaps() {
+        let ranges = cut_ranges(&[
+            segment(30.0, 40.0),
+            segment(0.0, 5.0),
+            segment(35.0, 50.0),
+            segment(60.0, 60.0),
+            segment(-1.0, 2.0),
+        ]);
+        assert_eq!(
+            ranges,
+            vec![
+                Duration::ZERO..Duration::from_secs(5),
+                Duration::from_secs(30)..Duration::from_secs(50),
+            ]
+        );
+        assert_eq!(total_cut(&ranges), Duration::from_secs(25));
+    }
+
+    #[test]
+    fn test_category_api_names_roundtrip() {
+        for category in default_categories() {
+            assert_eq!(
+                SegmentCategory::from_api_name(category.api_name()),
+                Some(category)
+            );
+        }
+        assert_eq!(
+            SegmentCategory::from_api_name("music_offtopic"),
+            Some(SegmentCategory::MusicOfftopic)
+        );
+        assert_eq!(SegmentCategory::from_api_name("poi_highlight"), None);
+    }
+
+    #[test]
+    fn test_config_defaults() {
+        let config: SponsorBlockConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, SponsorBlockConfig::default());
+        assert!(!config.enabled);
+        assert_eq!(config.api_url, DEFAULT_SPONSORBLOCK_API_URL);
+    }
+
+    #[test]
+    fn test_fetch_without_categories_makes_no_request() {
+        let client = SponsorBlockClient::new(SponsorBlockConfig {
+            enabled: true,
+            categories: Vec::new(),
+            api_url: "http://127.0.0.1:1".to_string(),
+        });
+        assert!(client.fetch_segments("dQw4w9WgXcQ").unwrap().is_empty());
+    }
+}
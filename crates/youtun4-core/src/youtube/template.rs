@@ -0,0 +1,315 @@
+//! Filename templates of downloaded tracks.
+//!
+//! A template is text with placeholders in braces, such as
+//! `{index:02} - {artist} - {title}`. The placeholders are:
+//!
+//! - `{title}`: the video title
+//! - `{artist}`: the channel, or "Unknown Artist"
+//! - `{index}`: the position of the video in the playlist, from 1;
+//!   `{index:03}` pads it with zeros to 3 digits
+//! - `{id}`: the video ID
+//! - `{playlist}`: the playlist title, or the video title for single videos
+//!
+//! `{{` and `}}` stand for literal braces. The rendered name is sanitized
+//! like any other file name, and the extension is added after it.
+
+use std::fmt::{self, Write};
+
+use super::sanitize_filename;
+use crate::error::{Error, Result};
+
+/// Template of the default file names: the video title.
+pub const DEFAULT_FILENAME_TEMPLATE: &str = "{title}";
+
+/// Widest zero padding of `{index}`.
+const MAX_INDEX_WIDTH: usize = 6;
+
+/// Artist of tracks whose video has no channel.
+const UNKNOWN_ARTIST: &str = "Unknown Artist";
+
+/// A value a placeholder is replaced by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Title,
+    Artist,
+    Index,
+    Id,
+    Playlist,
+}
+
+impl Field {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "title" => Some(Self::Title),
+            "artist" => Some(Self::Artist),
+            "index" => Some(Self::Index),
+            "id" => Some(Self::Id),
+            "playlist" => Some(Self::Playlist),
+            _ => None,
+        }
+    }
+}
+
+/// A piece of a parsed template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    /// A placeholder, zero-padded to `width` digits.
+    Field {
+        field: Field,
+        width: usize,
+    },
+}
+
+/// Values of a track the placeholders of a template are replaced by.
+#[derive(Debug, Clone, Copy)]
+pub struct TrackFields<'a> {
+    /// Video title.
+    pub title: &'a str,
+    /// Channel of the video, if known.
+    pub artist: Option<&'a str>,
+    /// Position of the video in the playlist, from 1.
+    pub index: usize,
+    /// Video ID.
+    pub video_id: &'a str,
+    /// Playlist title, for videos of a playlist.
+    pub playlist: Option<&'a str>,
+}
+
+/// A parsed filename template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilenameTemplate {
+    source: String,
+    parts: Vec<Part>,
+}
+
+impl FilenameTemplate {
+    /// Parse `template`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a placeholder is unknown or not closed, a brace
+    /// is not escaped, or the template has no placeholder.
+    pub fn parse(template: &str) -> Result<Self> {
+        let invalid = |reason: String| {
+            Error::Configuration(format!("Invalid filename template '{template}': {reason}"))
+        };
+
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '}' => {
+                    return Err(invalid(
+                        "unmatched '}' (write '}}' for a brace)".to_string(),
+                    ));
+                }
+                '{' => {
+                    let mut placeholder = String::new();
+                    let mut closed = false;
+                    for c in chars.by_ref() {
+                        if c == '}' {
+                            closed = true;
+                            break;
+                        }
+                        placeholder.push(c);
+                    }
+                    if !closed {
+                        return Err(invalid("unclosed placeholder".to_string()));
+                    }
+                    let (name, format) = placeholder
+                        .split_once(':')
+                        .map_or((placeholder.as_str(), None), |(name, format)| {
+                            (name, Some(format))
+                        });
+                    let field = Field::from_name(name.trim()).ok_or_else(|| {
+                        invalid(format!(
+                            "unknown placeholder '{{{placeholder}}}' (use title, artist, index, id or playlist)"
+                        ))
+                    })?;
+                    let width = match format {
+                        None => 0,
+                        Some(format) if field == Field::Index => format
+                            .parse()
+                            .ok()
+                            .filter(|&width| width <= MAX_INDEX_WIDTH)
+                            .ok_or_else(|| {
+                                invalid(format!(
+                                    "index padding must be 0 to {MAX_INDEX_WIDTH} digits"
+                                ))
+                            })?,
+                        Some(_) => {
+                            return Err(invalid(format!("only index can be padded, not {name}")));
+                        }
+                    };
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(std::mem::take(&mut literal)));
+                    }
+                    parts.push(Part::Field { field, width });
+                }
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+        if !parts.iter().any(|part| matches!(part, Part::Field { .. })) {
+            return Err(invalid(
+                "it needs a placeholder, or every track gets the same name".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            source: template.to_string(),
+            parts,
+        })
+    }
+
+    /// The template as written.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// File name (without extension) of the track with `fields`. Falls
+    /// back to the title when the rendered name is empty.
+    #[must_use]
+    pub fn render(&self, fields: &TrackFields<'_>) -> String {
+        let mut name = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Literal(text) => name.push_str(text),
+                Part::Field { field, width } => match field {
+                    Field::Title => name.push_str(fields.title),
+                    Field::Artist => name.push_str(fields.artist.unwrap_or(UNKNOWN_ARTIST)),
+                    Field::Index => {
+                        let width = *width;
+                        let _ = write!(name, "{:0width$}", fields.index);
+                    }
+                    Field::Id => name.push_str(fields.video_id),
+                    Field::Playlist => name.push_str(fields.playlist.unwrap_or(fields.title)),
+                },
+            }
+        }
+        let name = sanitize_filename(&name);
+        if name.is_empty() {
+            sanitize_filename(fields.title)
+        } else {
+            name
+        }
+    }
+
+    /// File name `template` gives an example track, to show before the
+    /// template is saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `template` is invalid.
+    pub fn preview(template: &str) -> Result<String> {
+        Ok(Self::parse(template)?.render(&TrackFields {
+            title: "Never Gonna Give You Up",
+            artist: Some("Rick Astley"),
+            index: 7,
+            video_id: "dQw4w9WgXcQ",
+            playlist: Some("80s Hits"),
+        }))
+    }
+}
+
+impl Default for FilenameTemplate {
+    fn default() -> Self {
+        Self {
+            source: DEFAULT_FILENAME_TEMPLATE.to_string(),
+            parts: vec![Part::Field {
+                field: Field::Title,
+                width: 0,
+            }],
+        }
+    }
+}
+
+impl fmt::Display for FilenameTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn fields() -> TrackFields<'static> {
+        TrackFields {
+            title: "Song: Live",
+            artist: Some("Band"),
+            index: 3,
+            video_id: "abc123def45",
+            playlist: Some("Best Of"),
+        }
+    }
+
+    #[test]
+    fn test_render_placeholders() {
+        let template = FilenameTemplate::parse("{index:02} - {artist} - {title}").unwrap();
+        assert_eq!(template.render(&fields()), "03 - Band - Song_ Live");
+
+        let template = FilenameTemplate::parse("{playlist}/{index} [{id}] {{x}}").unwrap();
+        assert_eq!(template.render(&fields()), "Best Of_3 [abc123def45] {x}");
+
+        let single = TrackFields {
+            artist: None,
+            playlist: None,
+            ..fields()
+        };
+        let template = FilenameTemplate::parse("{artist} - {playlist}").unwrap();
+        assert_eq!(template.render(&single), "Unknown Artist - Song_ Live");
+    }
+
+    #[test]
+    fn test_default_template_is_the_title() {
+        let template = FilenameTemplate::default();
+        assert_eq!(
+            template,
+            FilenameTemplate::parse(DEFAULT_FILENAME_TEMPLATE).unwrap()
+        );
+        assert_eq!(template.render(&fields()), sanitize_filename("Song: Live"));
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_templates() {
+        for template in [
+            "{album}",
+            "{title",
+            "title}",
+            "{title:02}",
+            "{index:x}",
+            "{index:09}",
+            "no placeholder",
+            "",
+        ] {
+            assert!(
+                FilenameTemplate::parse(template).is_err(),
+                "{template} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_preview() {
+        assert_eq!(
+            FilenameTemplate::preview("{index:02} - {artist} - {title}").unwrap(),
+            "07 - Rick Astley - Never Gonna Give You Up"
+        );
+        assert!(FilenameTemplate::preview("{nope}").is_err());
+    }
+}
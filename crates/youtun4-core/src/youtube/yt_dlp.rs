@@ -0,0 +1,693 @@
+//! Extraction and downloads through an external `yt-dlp`.
+//!
+//! The built-in scraper breaks whenever `YouTube` changes its pages, while
+//! `yt-dlp` is updated within days. When the user points the app at a
+//! `yt-dlp` binary, it can either handle every extraction and download, or
+//! only take over when the built-in scraper fails to parse a page (see
+//! [`ExtractionBackend`]). `yt-dlp` needs `ffmpeg` to convert audio to MP3.
+
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use super::archive::DownloadArchive;
+use super::chapters::Chapter;
+use super::template::{FilenameTemplate, TrackFields};
+use super::{
+    DownloadControl, DownloadProgress, DownloadProgressTracker, DownloadResult, DownloadStatus,
+    PAUSE_POLL_INTERVAL, PlaylistHealth, PlaylistInfo, ProgressCallback, RustyYtdlConfig,
+    VideoAvailability, VideoInfo, YouTubeDownloader, find_existing_download,
+};
+use crate::error::{DownloadError, Error, Result};
+
+/// Binary run when no `yt-dlp` path is configured, looked up on `PATH`.
+const DEFAULT_YT_DLP_BINARY: &str = "yt-dlp";
+
+/// Prefix of the progress lines printed by `yt-dlp` downloads.
+const PROGRESS_PREFIX: &str = "youtun4-progress:";
+
+/// Which extractor reads `YouTube` pages and downloads streams.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractionBackend {
+    /// The built-in scraper, falling back to `yt-dlp` when it can't parse
+    /// a page and `yt-dlp` can be run.
+    #[default]
+    Auto,
+    /// The built-in scraper only.
+    PureRust,
+    /// `yt-dlp` for everything.
+    YtDlp,
+}
+
+/// Extractor settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtractionConfig {
+    /// Which extractor is used.
+    #[serde(default)]
+    pub backend: ExtractionBackend,
+    /// Path of the `yt-dlp` binary (looked up on `PATH` when `None`).
+    #[serde(default)]
+    pub yt_dlp_path: Option<PathBuf>,
+}
+
+/// `YouTube` downloader running an external `yt-dlp` binary.
+#[derive(Debug, Clone)]
+pub struct YtDlpBackend {
+    binary: PathBuf,
+    audio_bitrate_kbps: u32,
+    write_tags: bool,
+    embed_cover_art: bool,
+    filename_template: FilenameTemplate,
+    control: DownloadControl,
+}
+
+impl YtDlpBackend {
+    /// Create a backend running `binary`, or `yt-dlp` from `PATH`.
+    #[must_use]
+    pub fn new(binary: Option<PathBuf>) -> Self {
+        Self::from_config(&RustyYtdlConfig::default(), binary, DownloadControl::new())
+    }
+
+    /// Create a backend downloading like `config`, steered by `control`.
+    pub(super) fn from_config(
+        config: &RustyYtdlConfig,
+        binary: Option<PathBuf>,
+        control: DownloadControl,
+    ) -> Self {
+        Self {
+            binary: binary.unwrap_or_else(|| PathBuf::from(DEFAULT_YT_DLP_BINARY)),
+            audio_bitrate_kbps: config.audio_bitrate_kbps,
+            write_tags: config.write_tags,
+            embed_cover_art: config.embed_cover_art,
+            filename_template: config.filename_template.clone(),
+            control,
+        }
+    }
+
+    /// Get the control for cancelling downloads from another thread.
+    #[must_use]
+    pub fn control(&self) -> DownloadControl {
+        self.control.clone()
+    }
+
+    /// Version of the `yt-dlp` binary.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `yt-dlp` can't be run.
+    pub fn version(&self) -> Result<String> {
+        let output = self.run(&["--version"])?;
+        Ok(String::from_utf8_lossy(&output).trim().to_string())
+    }
+
+    /// Run `yt-dlp` with `args` and return its standard output.
+    fn run(&self, args: &[&str]) -> Result<Vec<u8>> {
+        debug!("Running {} {:?}", self.binary.display(), args);
+        let output = Command::new(&self.binary)
+            .args(args)
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| self.spawn_failed(&e))?;
+        if !output.status.success() {
+            return Err(failed(last_error_line(&output.stderr)));
+        }
+        Ok(output.stdout)
+    }
+
+    fn spawn_failed(&self, error: &std::io::Error) -> Error {
+        failed(format!(
+            "could not run '{}': {error}",
+            self.binary.display()
+        ))
+    }
+
+    /// Download `video` into `output_dir` as MP3 named `stem`, reporting
+    /// downloaded bytes to `on_bytes`, until the download is cancelled.
+    fn download_video(
+        &self,
+        video: &VideoInfo,
+        output_dir: &Path,
+        stem: &str,
+        on_bytes: &(dyn Fn(u64, Option<u64>) + Sync),
+    ) -> Result<PathBuf> {
+        let template = output_dir.join(format!("{stem}.%(ext)s"));
+        let quality = format!("{}K", self.audio_bitrate_kbps);
+        let url = format!("https://www.youtube.com/watch?v={}", video.id);
+        let mut command = Command::new(&self.binary);
+        command
+            .args(["--no-playlist", "--newline", "--no-warnings"])
+            .args(["--extract-audio", "--audio-format", "mp3"])
+            .args(["--audio-quality", &quality])
+            .arg("--progress-template")
+            .arg(format!(
+                "download:{PROGRESS_PREFIX}%(progress.downloaded_bytes)s \
+                 %(progress.total_bytes,progress.total_bytes_estimate)s"
+            ))
+            .arg("--output")
+            .arg(&template);
+        if self.write_tags {
+            command.arg("--embed-metadata");
+        }
+        if self.embed_cover_art {
+            command.arg("--embed-thumbnail");
+        }
+        let mut child = command
+            .arg(&url)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| self.spawn_failed(&e))?;
+
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
+        let finished = std::thread::scope(|scope| {
+            scope.spawn(|| {
+                let Some(stdout) = stdout else { return };
+                for line in BufReader::new(stdout)
+                    .lines()
+                    .map_while(std::io::Result::ok)
+                {
+                    if let Some((downloaded, total)) = parse_progress_line(&line) {
+                        on_bytes(downloaded, total);
+                    }
+                }
+            });
+            let errors = scope.spawn(|| {
+                let mut errors = Vec::new();
+                if let Some(mut stderr) = stderr {
+                    let _ = std::io::Read::read_to_end(&mut stderr, &mut errors);
+                }
+                errors
+            });
+
+            loop {
+                if self.control.should_stop() {
+                    // Killing the process closes its pipes, ending the readers
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(if self.control.is_cancelled() {
+                        Error::Download(DownloadError::Cancelled)
+                    } else {
+                        failed("the video was skipped".to_string())
+                    });
+                }
+                match child.try_wait() {
+                    Ok(Some(status)) => {
+                        let errors = errors.join().unwrap_or_default();
+                        return Ok((status, errors));
+                    }
+                    Ok(None) => std::thread::sleep(PAUSE_POLL_INTERVAL),
+                    Err(e) => return Err(failed(format!("lost track of yt-dlp: {e}"))),
+                }
+            }
+        });
+
+        let (status, errors) = match finished {
+            Ok(done) => done,
+            Err(e) => {
+                remove_partial_files(output_dir, stem);
+                return Err(e);
+            }
+        };
+        if !status.success() {
+            remove_partial_files(output_dir, stem);
+            return Err(failed(last_error_line(&errors)));
+        }
+        let path = output_dir.join(format!("{stem}.mp3"));
+        if path.exists() {
+            Ok(path)
+        } else {
+            Err(failed(format!(
+                "no MP3 file was written for '{}'",
+                video.title
+            )))
+        }
+    }
+}
+
+impl Default for YtDlpBackend {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl YouTubeDownloader for YtDlpBackend {
+    fn parse_playlist_url(&self, url: &str) -> Result<PlaylistInfo> {
+        info!("Fetching playlist info with yt-dlp: {}", url);
+        let output = self.run(&[
+            "--flat-playlist",
+            "--dump-single-json",
+            "--no-warnings",
+            url,
+        ])?;
+        let dump: YtDlpDump = serde_json::from_slice(&output)
+            .map_err(|e| failed(format!("unreadable playlist info: {e}")))?;
+
+        let Some(entries) = dump.entries else {
+            return Ok(PlaylistInfo::from_video(dump.video.into_video_info()));
+        };
+        let videos: Vec<VideoInfo> = entries
+            .into_iter()
+            .map(YtDlpVideo::into_video_info)
+            .collect();
+        Ok(PlaylistInfo {
+            id: dump.video.id,
+            title: dump
+                .video
+                .title
+                .unwrap_or_else(|| "Unknown Playlist".to_string()),
+            video_count: videos.len(),
+            thumbnail_url: videos.first().and_then(|v| v.thumbnail_url.clone()),
+            health: PlaylistHealth::from_videos(&videos),
+            videos,
+        })
+    }
+
+    fn parse_video_url(&self, url: &str) -> Result<VideoInfo> {
+        info!("Fetching video info with yt-dlp: {}", url);
+        let output = self.run(&["--no-playlist", "--dump-single-json", "--no-warnings", url])?;
+        let video: YtDlpVideo = serde_json::from_slice(&output)
+            .map_err(|e| failed(format!("unreadable video info: {e}")))?;
+        Ok(video.into_video_info())
+    }
+
+    fn cancel_download(&self) {
+        self.control.cancel();
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn download_playlist(
+        &self,
+        playlist_info: &PlaylistInfo,
+        output_dir: &Path,
+        progress: Option<ProgressCallback>,
+    ) -> Result<Vec<DownloadResult>> {
+        info!(
+            "Downloading playlist '{}' ({} videos) with yt-dlp to {}",
+            playlist_info.title,
+            playlist_info.videos.len(),
+            output_dir.display()
+        );
+        std::fs::create_dir_all(output_dir).map_err(|e| {
+            Error::FileSystem(crate::error::FileSystemError::CreateDirFailed {
+                path: output_dir.to_path_buf(),
+                reason: e.to_string(),
+            })
+        })?;
+
+        let archive = DownloadArchive::open(output_dir);
+        let tracker =
+            std::sync::Mutex::new(DownloadProgressTracker::new(playlist_info.videos.len()));
+        let report = |update: &dyn Fn(&mut DownloadProgressTracker) -> DownloadProgress| {
+            let mut tracker = tracker
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            let snapshot = update(&mut tracker);
+            if let Some(callback) = &progress {
+                callback(snapshot);
+            }
+        };
+
+        let mut results = Vec::with_capacity(playlist_info.videos.len());
+        for (position, video) in playlist_info.videos.iter().enumerate() {
+            let index = position + 1;
+            if self.control.is_cancelled() {
+                return Err(Error::Download(DownloadError::Cancelled));
+            }
+            // A video cancellation only applies to the video it was made for
+            self.control
+                .cancel_video
+                .store(false, std::sync::atomic::Ordering::SeqCst);
+            report(&|tracker| {
+                tracker.set_video_progress(index, 0.0);
+                tracker.create_progress(index, &video.title, 0.0, DownloadStatus::Starting, 0, None)
+            });
+            let stem = self.filename_template.render(&TrackFields {
+                title: &video.title,
+                artist: video.channel.as_deref(),
+                index,
+                video_id: &video.id,
+                playlist: Some(&playlist_info.title),
+            });
+
+            let existing = archive
+                .get(&video.id)
+                .and_then(|archived| archived.file_name)
+                .map(|name| output_dir.join(name))
+                .filter(|path| path.exists())
+                .or_else(|| find_existing_download(output_dir, &stem));
+            let outcome = if let Some(path) = existing {
+                info!("Skipping existing file: {}", video.title);
+                Ok((path, DownloadStatus::Skipped))
+            } else if let Some(reason) = video.availability.unavailable_reason() {
+                Err(Error::Download(DownloadError::VideoUnavailable {
+                    video_id: video.id.clone(),
+                    reason: reason.to_string(),
+                }))
+            } else {
+                let base = tracker
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .total_bytes_downloaded;
+                let on_bytes = |downloaded: u64, total: Option<u64>| {
+                    let fraction = total
+                        .filter(|&total| total > 0)
+                        .map_or(0.0, |total| downloaded as f64 / total as f64);
+                    report(&|tracker| {
+                        tracker.record_progress(base + downloaded);
+                        tracker.set_video_progress(index, fraction);
+                        tracker.create_progress(
+                            index,
+                            &video.title,
+                            fraction,
+                            DownloadStatus::Downloading,
+                            downloaded,
+                            total,
+                        )
+                    });
+                };
+                self.download_video(video, output_dir, &stem, &on_bytes)
+                    .map(|path| (path, DownloadStatus::Completed))
+            };
+
+            match outcome {
+                Ok((path, status)) => {
+                    if let Err(e) = archive.record(video, Some(&path)) {
+                        warn!("Failed to archive '{}': {}", video.title, e);
+                    }
+                    report(&|tracker| {
+                        match status {
+                            DownloadStatus::Skipped => tracker.video_skipped(),
+                            _ => tracker.video_completed(),
+                        }
+                        tracker.video_finished(index);
+                        tracker.create_progress(index, &video.title, 1.0, status.clone(), 0, None)
+                    });
+                    results.push(DownloadResult {
+                        video: video.clone(),
+                        success: true,
+                        output_path: Some(path),
+                        error: None,
+                        sponsor_secs_removed: 0.0,
+                        audio_bitrate_kbps: Some(self.audio_bitrate_kbps),
+                        audio_codec: Some("mp3".to_string()),
+                        stream_path: None,
+                        chapter_paths: Vec::new(),
+                        estimated_size_bytes: None,
+                    });
+                }
+                Err(Error::Download(DownloadError::Cancelled)) => {
+                    return Err(Error::Download(DownloadError::Cancelled));
+                }
+                Err(e) => {
+                    warn!("Failed to download '{}' with yt-dlp: {}", video.title, e);
+                    let message = e.to_string();
+                    report(&|tracker| {
+                        tracker.video_failed();
+                        tracker.video_finished(index);
+                        tracker.create_progress(
+                            index,
+                            &video.title,
+                            0.0,
+                            DownloadStatus::Failed(message.clone()),
+                            0,
+                            None,
+                        )
+                    });
+                    results.push(DownloadResult {
+                        video: video.clone(),
+                        success: false,
+                        output_path: None,
+                        error: Some(message),
+                        sponsor_secs_removed: 0.0,
+                        audio_bitrate_kbps: None,
+                        audio_codec: None,
+                        stream_path: None,
+                        chapter_paths: Vec::new(),
+                        estimated_size_bytes: None,
+                    });
+                }
+            }
+        }
+
+        if playlist_info
+            .videos
+            .first()
+            .is_none_or(|video| video.id != playlist_info.id)
+            && let Err(e) = archive.record_positions(&playlist_info.videos)
+        {
+            warn!("Failed to record playlist positions: {}", e);
+        }
+        Ok(results)
+    }
+}
+
+/// Whether the built-in scraper failing with `error` should be retried
+/// with `yt-dlp`: pages it couldn't read, but not offline mode,
+/// cancellations or network errors.
+#[must_use]
+pub fn is_extraction_failure(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::Download(
+            DownloadError::PlaylistParseFailed { .. } | DownloadError::VideoUnavailable { .. }
+        )
+    )
+}
+
+fn failed(reason: String) -> Error {
+    Error::Download(DownloadError::YtDlpFailed { reason })
+}
+
+/// The last line `yt-dlp` printed to standard error, usually the reason it
+/// failed.
+fn last_error_line(stderr: &[u8]) -> String {
+    String::from_utf8_lossy(stderr)
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map_or_else(
+            || "yt-dlp exited with an error".to_string(),
+            |line| line.trim_start_matches("ERROR: ").to_string(),
+        )
+}
+
+/// Downloaded and total bytes of a progress line, e.g.
+/// "youtun4-progress:1024 4096". The total is "NA" when unknown.
+fn parse_progress_line(line: &str) -> Option<(u64, Option<u64>)> {
+    let (downloaded, total) = line.strip_prefix(PROGRESS_PREFIX)?.split_once(' ')?;
+    // Estimated totals are printed as floats
+    let bytes = |value: &str| value.trim().parse::<f64>().ok().map(|bytes| bytes as u64);
+    Some((bytes(downloaded)?, bytes(total)))
+}
+
+/// Remove what a failed `yt-dlp` download of `stem` left behind.
+fn remove_partial_files(output_dir: &Path, stem: &str) {
+    let Ok(entries) = std::fs::read_dir(output_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let is_partial =
+            name.ends_with(".part") || name.ends_with(".ytdl") || name.contains(".temp.");
+        if name.starts_with(stem)
+            && is_partial
+            && let Err(e) = std::fs::remove_file(entry.path())
+        {
+            debug!("Failed to remove partial file {}: {}", name, e);
+        }
+    }
+}
+
+/// Output of `yt-dlp --dump-single-json`: a video, or a playlist and its
+/// entries.
+#[derive(Debug, Deserialize)]
+struct YtDlpDump {
+    #[serde(flatten)]
+    video: YtDlpVideo,
+    #[serde(default)]
+    entries: Option<Vec<YtDlpVideo>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct YtDlpVideo {
+    id: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    duration: Option<f64>,
+    #[serde(default)]
+    channel: Option<String>,
+    #[serde(default)]
+    uploader: Option<String>,
+    #[serde(default)]
+    thumbnail: Option<String>,
+    #[serde(default)]
+    thumbnails: Option<Vec<YtDlpThumbnail>>,
+    #[serde(default)]
+    chapters: Option<Vec<YtDlpChapter>>,
+    #[serde(default)]
+    availability: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct YtDlpThumbnail {
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct YtDlpChapter {
+    title: String,
+    start_time: f64,
+}
+
+impl YtDlpVideo {
+    fn into_video_info(self) -> VideoInfo {
+        let title = self.title.unwrap_or_else(|| self.id.clone());
+        // Flat playlists keep unavailable videos under a placeholder title
+        let availability = match (title.as_str(), self.availability.as_deref()) {
+            ("[Private video]", _) | (_, Some("private")) => VideoAvailability::Private,
+            ("[Deleted video]", _) => VideoAvailability::Deleted,
+            _ => VideoAvailability::Available,
+        };
+        VideoInfo {
+            id: self.id,
+            title,
+            duration_secs: self.duration.map(|secs| secs.round() as u64),
+            channel: self.channel.or(self.uploader),
+            thumbnail_url: self.thumbnail.or_else(|| {
+                self.thumbnails
+                    .and_then(|thumbnails| thumbnails.into_iter().last())
+                    .map(|thumbnail| thumbnail.url)
+            }),
+            availability,
+            chapters: self
+                .chapters
+                .unwrap_or_default()
+                .into_iter()
+                .map(|chapter| Chapter {
+                    title: chapter.title,
+                    start_secs: chapter.start_time as u64,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_flat_playlist_dump() {
+        let dump: YtDlpDump = serde_json::from_str(
+            r#"{
+                "_type": "playlist",
+                "id": "PLtest",
+                "title": "Road Trip",
+                "entries": [
+                    {
+                        "id": "dQw4w9WgXcQ",
+                        "title": "Never Gonna Give You Up",
+                        "duration": 212.6,
+                        "channel": "Rick Astley",
+                        "thumbnails": [{"url": "small.jpg"}, {"url": "large.jpg"}]
+                    },
+                    {"id": "abcdefghijk", "title": "[Private video]", "duration": null}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let entries = dump.entries.unwrap();
+        assert_eq!(dump.video.id, "PLtest");
+        let videos: Vec<VideoInfo> = entries
+            .into_iter()
+            .map(YtDlpVideo::into_video_info)
+            .collect();
+        assert_eq!(videos[0].duration_secs, Some(213));
+        assert_eq!(videos[0].channel.as_deref(), Some("Rick Astley"));
+        assert_eq!(videos[0].thumbnail_url.as_deref(), Some("large.jpg"));
+        assert_eq!(videos[1].availability, VideoAvailability::Private);
+    }
+
+    #[test]
+    fn test_parse_video_dump() {
+        let dump: YtDlpDump = serde_json::from_str(
+            r#"{
+                "id": "dQw4w9WgXcQ",
+                "title": "Mix",
+                "uploader": "DJ",
+                "thumbnail": "cover.jpg",
+                "chapters": [
+                    {"title": "Intro", "start_time": 0.0, "end_time": 60.0},
+                    {"title": "Outro", "start_time": 60.0, "end_time": 120.0}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(dump.entries.is_none());
+        let video = dump.video.into_video_info();
+        assert_eq!(video.channel.as_deref(), Some("DJ"));
+        assert_eq!(video.thumbnail_url.as_deref(), Some("cover.jpg"));
+        assert_eq!(video.chapters.len(), 2);
+        assert_eq!(video.chapters[1].start_secs, 60);
+    }
+
+    #[test]
+    fn test_parse_progress_line() {
+        assert_eq!(
+            parse_progress_line("youtun4-progress:1024 4096"),
+            Some((1024, Some(4096)))
+        );
+        assert_eq!(
+            parse_progress_line("youtun4-progress:1024 8192.5"),
+            Some((1024, Some(8192)))
+        );
+        assert_eq!(
+            parse_progress_line("youtun4-progress:1024 NA"),
+            Some((1024, None))
+        );
+        assert_eq!(parse_progress_line("[download] Destination: a.webm"), None);
+    }
+
+    #[test]
+    fn test_last_error_line() {
+        assert_eq!(
+            last_error_line(b"WARNING: slow\nERROR: Video unavailable\n\n"),
+            "Video unavailable"
+        );
+        assert_eq!(last_error_line(b""), "yt-dlp exited with an error");
+    }
+
+    #[test]
+    fn test_missing_binary() {
+        let backend = YtDlpBackend::new(Some(PathBuf::from("/nonexistent/yt-dlp")));
+        assert!(matches!(
+            backend.version(),
+            Err(Error::Download(DownloadError::YtDlpFailed { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_extraction_failures() {
+        let parse = Error::Download(DownloadError::PlaylistParseFailed {
+            playlist_id: "PLtest".to_string(),
+            reason: "no ytInitialData".to_string(),
+        });
+        assert!(is_extraction_failure(&parse));
+        assert!(!is_extraction_failure(&Error::offline("fetching")));
+        assert!(!is_extraction_failure(&Error::Download(
+            DownloadError::Cancelled
+        )));
+    }
+}
@@ -136,8 +136,11 @@ fn test_download_single_video() {
                 duration_secs: Some(60),
                 channel: Some("Test Channel".to_string()),
                 thumbnail_url: None,
+                availability: youtun4_core::VideoAvailability::Available,
+                chapters: Vec::new(),
             }],
             thumbnail_url: None,
+            health: youtun4_core::PlaylistHealth::default(),
         },
         &temp_dir,
         None,
@@ -0,0 +1,188 @@
+//! Background activity, followed from the unified task event bus.
+//!
+//! [`ActivityStore`] keeps track of the downloads and syncs that are queued
+//! or running, so navigation can show them from anywhere in the app.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::tauri_api;
+use crate::types::{TaskEvent, TaskEventCategory, TaskPhase};
+
+/// Kind of background activity shown in the navigation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ActivityKind {
+    /// Downloads, queued or running.
+    Downloads,
+    /// Syncs and transfers to a device.
+    Sync,
+}
+
+impl ActivityKind {
+    /// The kind of activity events of `category` belong to, if shown.
+    #[must_use]
+    pub const fn of(category: TaskEventCategory) -> Option<Self> {
+        match category {
+            TaskEventCategory::Download | TaskEventCategory::Queue => Some(Self::Downloads),
+            TaskEventCategory::Sync
+            | TaskEventCategory::OrchestratedSync
+            | TaskEventCategory::Transfer => Some(Self::Sync),
+            TaskEventCategory::Cache
+            | TaskEventCategory::Verification
+            | TaskEventCategory::Maintenance => None,
+        }
+    }
+}
+
+/// A queued or running task. Tasks whose events carry no ID are tracked
+/// without one.
+type ActiveTask = (ActivityKind, Option<u64>);
+
+/// Update `active` with `event`.
+///
+/// An event without a task ID stands for any task of its kind: it only adds
+/// a task when none of that kind is active, and when it ends one it ends them
+/// all.
+fn apply_event(active: &mut Vec<ActiveTask>, event: &TaskEvent) {
+    let Some(kind) = ActivityKind::of(event.category) else {
+        return;
+    };
+    match event.phase {
+        TaskPhase::Queued | TaskPhase::Started | TaskPhase::Progress => {
+            let known = match event.task_id {
+                Some(_) => active.contains(&(kind, event.task_id)),
+                None => active.iter().any(|(k, _)| *k == kind),
+            };
+            if !known {
+                active.push((kind, event.task_id));
+            }
+        }
+        TaskPhase::Completed | TaskPhase::Failed | TaskPhase::Cancelled | TaskPhase::Removed => {
+            active.retain(|(k, id)| {
+                *k != kind || (event.task_id.is_some() && id.is_some() && *id != event.task_id)
+            });
+        }
+        TaskPhase::Paused | TaskPhase::Resumed | TaskPhase::Updated => {}
+    }
+}
+
+/// Background activity, shared through context.
+#[derive(Clone, Copy)]
+pub struct ActivityStore {
+    active: RwSignal<Vec<ActiveTask>>,
+}
+
+impl ActivityStore {
+    /// Number of queued or running tasks of `kind`.
+    #[must_use]
+    pub fn count(&self, kind: ActivityKind) -> usize {
+        self.active
+            .with(|active| active.iter().filter(|(k, _)| *k == kind).count())
+    }
+
+    /// Whether any task of `kind` is queued or running.
+    #[must_use]
+    pub fn is_active(&self, kind: ActivityKind) -> bool {
+        self.count(kind) > 0
+    }
+}
+
+/// Provide the background activity to the component tree and start
+/// following task events.
+pub fn provide_activity() -> ActivityStore {
+    let store = ActivityStore {
+        active: RwSignal::new(Vec::new()),
+    };
+    provide_context(store);
+    spawn_local(async move {
+        if let Err(e) = tauri_api::listen_to_task_events(move |event| {
+            store.active.update(|active| apply_event(active, &event));
+        })
+        .await
+        {
+            leptos::logging::error!("Failed to listen for task events: {}", e);
+        }
+    });
+    store
+}
+
+/// Access the background activity.
+///
+/// # Panics
+/// Panics if called outside of the tree set up by [`provide_activity`].
+pub fn use_activity() -> ActivityStore {
+    expect_context::<ActivityStore>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(category: TaskEventCategory, phase: TaskPhase, task_id: Option<u64>) -> TaskEvent {
+        TaskEvent {
+            task_id,
+            category,
+            phase,
+            progress: None,
+            event: String::new(),
+            payload: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn test_apply_event_counts_tasks() {
+        let mut active = Vec::new();
+        apply_event(
+            &mut active,
+            &event(TaskEventCategory::Queue, TaskPhase::Queued, Some(1)),
+        );
+        apply_event(
+            &mut active,
+            &event(TaskEventCategory::Queue, TaskPhase::Started, Some(1)),
+        );
+        apply_event(
+            &mut active,
+            &event(TaskEventCategory::Download, TaskPhase::Started, Some(7)),
+        );
+        apply_event(
+            &mut active,
+            &event(TaskEventCategory::Cache, TaskPhase::Started, Some(9)),
+        );
+        assert_eq!(active.len(), 2);
+
+        apply_event(
+            &mut active,
+            &event(TaskEventCategory::Queue, TaskPhase::Completed, Some(1)),
+        );
+        assert_eq!(active, vec![(ActivityKind::Downloads, Some(7))]);
+    }
+
+    #[test]
+    fn test_apply_event_without_task_id() {
+        let mut active = Vec::new();
+        apply_event(
+            &mut active,
+            &event(TaskEventCategory::Sync, TaskPhase::Started, Some(3)),
+        );
+        // Progress without an ID belongs to the sync already running
+        apply_event(
+            &mut active,
+            &event(
+                TaskEventCategory::OrchestratedSync,
+                TaskPhase::Progress,
+                None,
+            ),
+        );
+        assert_eq!(active.len(), 1);
+
+        apply_event(
+            &mut active,
+            &event(
+                TaskEventCategory::OrchestratedSync,
+                TaskPhase::Completed,
+                None,
+            ),
+        );
+        assert!(active.is_empty());
+    }
+}
@@ -1,21 +1,33 @@
 //! Main application component.
 
+use leptos::ev;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use wasm_bindgen::prelude::*;
 
+use crate::activity::{ActivityKind, provide_activity, use_activity};
+use crate::components::shortcuts::{KeyPress, Shortcut, global_shortcut, is_modal_open, is_typing};
 use crate::components::{
-    ContentHeader, CreatePlaylistDialog, DeletePlaylistDialog, DeviceList, DeviceStatusIndicator,
-    DownloadErrorInfo, DownloadPanelState, DownloadProgressPanel, Layout, LayoutMain,
-    LayoutSidebar, LoadingState, NotificationProvider, PlaylistDetailView, PlaylistList,
-    PlaylistListState, PlaylistSelectionList, PlaylistSelectionState, PlaylistSelectionSummary,
-    SettingsPanel, SyncButton, TransferPanelState, TransferProgressPanel, use_notifications,
+    BatchAction, ContentHeader, CreatePlaylistDialog, DeletePlaylistDialog, DeviceBanner,
+    DeviceDetailView, DeviceList, DeviceStatusIndicator, DownloadErrorInfo, DownloadPanelState,
+    DownloadProgressPanel, FirstPlaylistDialog, HistoryView, Layout, LayoutMain, LayoutSidebar,
+    ListSelection, LoadingState, NavItem, NavSection, NotificationProvider, OnboardingWizard,
+    OperationProgressDialog, PlaylistAction, PlaylistDetailView, PlaylistList, PlaylistListState,
+    PlaylistSelectionList, PlaylistSelectionState, PlaylistSelectionSummary, QueuePanel,
+    RenamePlaylistDialog, RunningOperation, SearchBar, SettingsPanel, ShortcutCheatsheet,
+    StorageView, SyncWizard, TransferPanelState, TransferProgressPanel, UndoHistory, batch_summary,
+    nav_icons, use_notifications,
 };
+use crate::i18n::{provide_i18n, use_i18n};
 use crate::tauri_api;
-use crate::theme::generate_css_variables;
+use crate::theme::{apply_appearance, generate_css_variables, generate_theme_variants_css};
 use crate::types::{
-    DeviceInfo, DownloadProgress, PlaylistMetadata, TaskId, TransferProgress, TransferStatus,
+    DeviceInfo, DownloadProgress, DownloadResult, MaintenanceOperation, Notification,
+    OnboardingState, PlaylistMetadata, PlaylistViewPreferences, TaskEvent, TaskId, TaskPhase,
+    TransferProgress, TransferStatus, UiState, UiView,
 };
+use crate::ui_state::{provide_ui_state, use_ui_state};
+use crate::undo::{UndoAction, provide_undo};
 
 /// Main application component.
 #[component]
@@ -23,12 +35,76 @@ use crate::types::{
 pub fn App() -> impl IntoView {
     // CSS variables
     let css_vars = generate_css_variables();
+    let theme_variants = generate_theme_variants_css();
+
+    let i18n = provide_i18n();
+    let ui_state = provide_ui_state();
+    provide_activity();
+
+    // Apply the saved appearance, language and interface state, and follow
+    // later changes from the settings
+    spawn_local(async move {
+        match tauri_api::get_config().await {
+            Ok(config) => {
+                apply_appearance(&config);
+                i18n.load(config.language);
+                ui_state.restore(config.ui_state);
+            }
+            Err(e) => {
+                leptos::logging::error!("Failed to load appearance settings: {}", e);
+                i18n.load(None);
+                ui_state.restore(UiState::default());
+            }
+        }
+        if let Err(e) = tauri_api::listen_to_config_changed(move |changed| {
+            apply_appearance(&changed.config);
+            if changed.sections.iter().any(|section| section == "language") {
+                i18n.load(changed.config.language);
+            }
+        })
+        .await
+        {
+            leptos::logging::error!("Failed to listen for config-changed events: {}", e);
+        }
+    });
+
+    // First-run setup runs before the main layout mounts
+    let (onboarding, set_onboarding) = signal::<Option<OnboardingState>>(None);
+    let (ready, set_ready) = signal(false);
+    spawn_local(async move {
+        match tauri_api::get_onboarding_state().await {
+            Ok(state) if !state.completed => set_onboarding.set(Some(state)),
+            Ok(_) => set_ready.set(true),
+            Err(e) => {
+                // Don't lock the user out of the app over a failed check
+                leptos::logging::error!("Failed to load onboarding state: {}", e);
+                set_ready.set(true);
+            }
+        }
+    });
+
+    let on_onboarding_complete = Callback::new(move |()| {
+        set_onboarding.set(None);
+        set_ready.set(true);
+    });
 
     view! {
         <style>{css_vars}</style>
+        <style>{theme_variants}</style>
         <style>{include_str!("../styles/main.css")}</style>
         <NotificationProvider>
-            <AppContent />
+            {move || {
+                if ready.get() {
+                    view! { <AppContent /> }.into_any()
+                } else if let Some(state) = onboarding.get() {
+                    view! {
+                        <OnboardingWizard state=state on_complete=on_onboarding_complete />
+                    }
+                    .into_any()
+                } else {
+                    view! { <div class="onboarding"></div> }.into_any()
+                }
+            }}
         </NotificationProvider>
     }
 }
@@ -38,6 +114,10 @@ pub fn App() -> impl IntoView {
 fn AppContent() -> impl IntoView {
     // Get notification context
     let notifications = use_notifications();
+    let i18n = use_i18n();
+    let ui_state = use_ui_state();
+    let activity = use_activity();
+    let undo = provide_undo();
 
     // State signals
     let (devices, set_devices) = signal::<Vec<DeviceInfo>>(vec![]);
@@ -63,12 +143,41 @@ fn AppContent() -> impl IntoView {
 
     // Create playlist dialog state
     let (create_dialog_open, set_create_dialog_open) = signal(false);
+    // Guided version, opened from the empty library
+    let (first_playlist_open, set_first_playlist_open) = signal(false);
+    let (sync_wizard_open, set_sync_wizard_open) = signal(false);
 
     // View mode: true = selection mode (for syncing), false = management mode (list with actions)
     let (selection_mode, set_selection_mode) = signal(false);
+    // Multi-select in the management list, for batch actions
+    let playlist_selection = ListSelection::new();
+    // Library layout and sort order, persisted in config
+    let (playlist_view, set_playlist_view) = signal(PlaylistViewPreferences::default());
+    spawn_local(async move {
+        match tauri_api::get_config().await {
+            Ok(config) => set_playlist_view.set(config.playlist_view),
+            Err(e) => leptos::logging::error!("Failed to load playlist view: {}", e),
+        }
+    });
+    let on_playlist_view_change = Callback::new(move |view: PlaylistViewPreferences| {
+        set_playlist_view.set(view);
+        spawn_local(async move {
+            if let Err(e) = tauri_api::set_playlist_view(&view).await {
+                leptos::logging::error!("Failed to save playlist view: {}", e);
+            }
+        });
+    });
+    // Playlists preselected when the sync wizard is opened for a selection
+    let (wizard_playlists, set_wizard_playlists) = signal::<Vec<String>>(vec![]);
 
     // Detail view state: Some(playlist_name) = viewing playlist detail, None = viewing list
     let (detail_view_playlist, set_detail_view_playlist) = signal::<Option<String>>(None);
+    // Device dashboard: Some(mount_point) when viewing a device
+    let (detail_view_device, set_detail_view_device) = signal::<Option<String>>(None);
+    // History page, opened from the sidebar
+    let (history_open, set_history_open) = signal(false);
+    // Storage page, opened from the sidebar
+    let (storage_open, set_storage_open) = signal(false);
 
     // Syncing state for the sync button
     let (syncing, set_syncing) = signal(false);
@@ -81,6 +190,7 @@ fn AppContent() -> impl IntoView {
     // Download progress state
     let (download_progress, set_download_progress) = signal::<Option<DownloadProgress>>(None);
     let (download_panel_state, set_download_panel_state) = signal(DownloadPanelState::Idle);
+    let (download_result, set_download_result) = signal::<Option<DownloadResult>>(None);
     let (_current_download_task_id, set_current_download_task_id) = signal::<Option<TaskId>>(None);
 
     // Refresh trigger for detail view (incremented when download completes)
@@ -112,7 +222,7 @@ fn AppContent() -> impl IntoView {
                 Err(e) => {
                     leptos::logging::error!("=== LOAD DEVICES FAILED: {} ===", e);
                     set_device_list_state.set(LoadingState::Error);
-                    notifications.error(format!("Failed to load devices: {e}"));
+                    notifications.error(i18n.tf("toast.load_devices_failed", &[("error", &e)]));
                 }
             }
         });
@@ -134,7 +244,7 @@ fn AppContent() -> impl IntoView {
                     leptos::logging::error!("Failed to load playlists: {}", e);
                     set_playlist_error.set(Some(e.clone()));
                     set_playlist_list_state.set(PlaylistListState::Error);
-                    notifications.error(format!("Failed to load playlists: {e}"));
+                    notifications.error(i18n.tf("toast.load_playlists_failed", &[("error", &e)]));
                 }
             }
         });
@@ -149,6 +259,15 @@ fn AppContent() -> impl IntoView {
         // IMPORTANT: Set up event listeners BEFORE starting the watcher to avoid missing
         // the initial "devices-refreshed" event that is emitted immediately on watcher start.
         spawn_local(async move {
+            // Tell the user if the app crashed last time, once
+            if let Ok(Some(report)) = tauri_api::get_last_crash_report().await {
+                notifications
+                    .warning(i18n.tf("toast.crashed_last_time", &[("message", &report.message)]));
+                if let Err(e) = tauri_api::dismiss_crash_report().await {
+                    leptos::logging::error!("Failed to dismiss crash report: {}", e);
+                }
+            }
+
             leptos::logging::log!("Setting up device event listeners...");
 
             // Listen for device connected events
@@ -166,15 +285,14 @@ fn AppContent() -> impl IntoView {
                             device.name,
                             device.mount_point
                         );
-                        let device_name = device.name.clone();
-                        // Add the new device to the list
+                        // Add the new device to the list; the device banner
+                        // announces it
                         set_devices_connected.update(|devices| {
                             // Only add if not already present
                             if !devices.iter().any(|d| d.mount_point == device.mount_point) {
                                 devices.push(device);
                             }
                         });
-                        notifications.success(format!("Device \"{device_name}\" connected"));
                     }
                 },
             )
@@ -199,7 +317,6 @@ fn AppContent() -> impl IntoView {
                             device.name,
                             device.mount_point
                         );
-                        let device_name = device.name.clone();
                         // Remove the device from the list
                         set_devices_disconnected.update(|devices| {
                             devices.retain(|d| d.mount_point != device.mount_point);
@@ -212,7 +329,6 @@ fn AppContent() -> impl IntoView {
                                 *selected = None;
                             }
                         });
-                        notifications.info(format!("Device \"{device_name}\" disconnected"));
                     }
                 },
             )
@@ -318,7 +434,8 @@ fn AppContent() -> impl IntoView {
                     elapsed_secs: progress.elapsed_secs,
                 };
                 set_transfer_progress_listener.set(Some(transfer_progress));
-                set_transfer_panel_state_listener.set(TransferPanelState::from_status(status));
+                set_transfer_panel_state_listener
+                    .set(TransferPanelState::from_status(status, i18n.locale()));
             })
             .await
             {
@@ -337,10 +454,12 @@ fn AppContent() -> impl IntoView {
                 set_transfer_panel_state_completed.set(TransferPanelState::Completed);
                 set_syncing_completed.set(false);
                 set_current_sync_task_id_completed.set(None);
-                notifications.success(format!(
-                    "Transfer complete: {} files transferred to {}",
-                    result.files_transferred, result.device_mount_point
+                let files = i18n.count("count.files", result.files_transferred);
+                notifications.success(i18n.tf(
+                    "toast.transfer_complete",
+                    &[("files", &files), ("device", &result.device_mount_point)],
                 ));
+                notifications.announce(i18n.tf("announce.sync_finished", &[("files", &files)]));
             })
             .await
             {
@@ -354,12 +473,13 @@ fn AppContent() -> impl IntoView {
             if let Err(e) = tauri_api::listen_to_sync_failed(move |result| {
                 let error_msg = result
                     .error_message
-                    .unwrap_or_else(|| "Unknown error".to_string());
+                    .unwrap_or_else(|| i18n.t("common.unknown_error"));
                 leptos::logging::error!("Sync failed: {}", error_msg);
                 set_transfer_panel_state_failed.set(TransferPanelState::Failed(error_msg.clone()));
                 set_syncing_failed.set(false);
                 set_current_sync_task_id_failed.set(None);
-                notifications.error(format!("Transfer failed: {error_msg}"));
+                notifications.announce(i18n.tf("announce.sync_failed", &[("error", &error_msg)]));
+                notifications.error(i18n.tf("toast.transfer_failed", &[("error", &error_msg)]));
             })
             .await
             {
@@ -375,7 +495,7 @@ fn AppContent() -> impl IntoView {
                 set_transfer_panel_state_cancelled.set(TransferPanelState::Cancelled);
                 set_syncing_cancelled.set(false);
                 set_current_sync_task_id_cancelled.set(None);
-                notifications.info("Transfer cancelled");
+                notifications.info(i18n.t("toast.transfer_cancelled"));
             })
             .await
             {
@@ -391,6 +511,7 @@ fn AppContent() -> impl IntoView {
                 leptos::logging::log!("Download started: task_id={:?}", task_id);
                 set_download_panel_state_started.set(DownloadPanelState::Downloading);
                 set_current_download_task_id_started.set(Some(task_id));
+                set_download_result.set(None);
             })
             .await
             {
@@ -417,11 +538,11 @@ fn AppContent() -> impl IntoView {
                     let reason = if progress.status.starts_with("failed: ") {
                         progress.status.trim_start_matches("failed: ").to_string()
                     } else {
-                        "Unknown error".to_string()
+                        i18n.t("common.unknown_error")
                     };
-                    notifications.warning(format!(
-                        "Failed to download \"{}\": {}",
-                        progress.current_title, reason
+                    notifications.warning(i18n.tf(
+                        "toast.video_failed",
+                        &[("title", &progress.current_title), ("error", &reason)],
                     ));
                 }
                 prev_videos_failed.set(progress.videos_failed);
@@ -447,15 +568,16 @@ fn AppContent() -> impl IntoView {
                 );
                 set_download_panel_state_completed.set(DownloadPanelState::Completed);
                 set_current_download_task_id_completed.set(None);
-                notifications.success(format!(
-                    "Downloaded {} track{}",
-                    result.successful_count,
-                    if result.successful_count == 1 {
-                        ""
-                    } else {
-                        "s"
-                    }
+                set_download_result.set(Some(result.clone()));
+                notifications.announce(i18n.tf(
+                    "announce.download_finished",
+                    &[
+                        ("downloaded", &result.successful_count.to_string()),
+                        ("failed", &result.failed_count.to_string()),
+                        ("skipped", &result.skipped_count.to_string()),
+                    ],
                 ));
+                notifications.success(i18n.count("toast.downloaded", result.successful_count));
                 // Refresh playlists to show downloaded tracks
                 load_playlists_download();
                 // Trigger detail view refresh
@@ -470,15 +592,42 @@ fn AppContent() -> impl IntoView {
             let set_download_panel_state_failed = set_download_panel_state;
             let set_current_download_task_id_failed = set_current_download_task_id;
             if let Err(e) = tauri_api::listen_to_download_failed(move |result| {
-                let error_info = DownloadErrorInfo::from_result(&result);
+                let error_info = DownloadErrorInfo::from_result(&result, i18n.locale());
                 leptos::logging::error!(
                     "Download failed: {} - {}",
                     error_info.title,
                     error_info.description
                 );
-                set_download_panel_state_failed.set(DownloadPanelState::Failed(error_info.clone()));
+                let toast = Notification::error(error_info.description.clone())
+                    .with_title(i18n.tf("toast.download_error", &[("error", &error_info.title)]));
+                notifications
+                    .announce(i18n.tf("announce.download_failed", &[("error", &error_info.title)]));
+                set_download_panel_state_failed.set(DownloadPanelState::Failed(error_info));
                 set_current_download_task_id_failed.set(None);
-                notifications.error(format!("Download failed: {}", error_info.title));
+                set_download_result.set(Some(result.clone()));
+                match result.retry_target() {
+                    Some((url, playlist_name)) => {
+                        notifications.error_with_retry(
+                            toast,
+                            Callback::new(move |()| {
+                                let url = url.clone();
+                                let playlist_name = playlist_name.clone();
+                                spawn_local(async move {
+                                    if let Err(e) = tauri_api::download_youtube_to_playlist(
+                                        &url,
+                                        &playlist_name,
+                                    )
+                                    .await
+                                    {
+                                        notifications
+                                            .error(i18n.tf("toast.retry_failed", &[("error", &e)]));
+                                    }
+                                });
+                            }),
+                        );
+                    }
+                    None => notifications.push(toast),
+                }
             })
             .await
             {
@@ -492,17 +641,134 @@ fn AppContent() -> impl IntoView {
                 leptos::logging::log!("Download cancelled by user");
                 set_download_panel_state_cancelled.set(DownloadPanelState::Cancelled);
                 set_current_download_task_id_cancelled.set(None);
-                notifications.info("Download cancelled");
+                notifications.info(i18n.t("toast.download_cancelled"));
             })
             .await
             {
                 leptos::logging::error!("Failed to listen for download-cancelled events: {}", e);
             }
+
+            // Listen for deep links that need a target playlist
+            if let Err(e) = tauri_api::listen_to_deep_link_received(move |request| {
+                let Some(playlist) = selected_playlist.get_untracked() else {
+                    notifications.warning(i18n.t("toast.deep_link_needs_playlist"));
+                    return;
+                };
+                spawn_local(async move {
+                    if let Err(e) = tauri_api::queue_add_to_playlist(
+                        &request.url,
+                        &playlist.name,
+                        request.priority.as_deref(),
+                    )
+                    .await
+                    {
+                        notifications.error(i18n.tf("toast.deep_link_failed", &[("error", &e)]));
+                    }
+                });
+            })
+            .await
+            {
+                leptos::logging::error!("Failed to listen for deep-link-received events: {}", e);
+            }
+
+            // Listen for deep links added to the queue
+            let load_playlists_deep_link = load_playlists;
+            if let Err(e) = tauri_api::listen_to_deep_link_queued(move |queued| {
+                notifications
+                    .success(i18n.tf("toast.deep_link_queued", &[("name", &queued.playlist_name)]));
+                load_playlists_deep_link();
+            })
+            .await
+            {
+                leptos::logging::error!("Failed to listen for deep-link-queued events: {}", e);
+            }
+
+            // Listen for rejected deep links
+            if let Err(e) = tauri_api::listen_to_deep_link_rejected(move |rejected| {
+                notifications
+                    .error(i18n.tf("toast.deep_link_failed", &[("error", &rejected.reason)]));
+            })
+            .await
+            {
+                leptos::logging::error!("Failed to listen for deep-link-rejected events: {}", e);
+            }
+
+            // Listen for available updates
+            if let Err(e) = tauri_api::listen_to_update_available(move |update| {
+                notifications.info(i18n.tf(
+                    "toast.update_available",
+                    &[
+                        ("version", &update.version),
+                        ("channel", &i18n.t(update.channel.label_key())),
+                    ],
+                ));
+            })
+            .await
+            {
+                leptos::logging::error!("Failed to listen for update-available events: {}", e);
+            }
         });
     });
 
+    // Reopen the view and playlist left open by the previous session, once
+    // both the saved state and the playlists have loaded
+    let ui_restored = StoredValue::new(false);
+    Effect::new(move |_| {
+        if ui_restored.get_value()
+            || !ui_state.loaded()
+            || playlist_list_state.get() == PlaylistListState::Loading
+        {
+            return;
+        }
+        ui_restored.set_value(true);
+        let saved = ui_state.get_untracked();
+        match saved.view {
+            UiView::Library => {}
+            UiView::Sync => set_selection_mode.set(true),
+            UiView::History => set_history_open.set(true),
+            UiView::Storage => set_storage_open.set(true),
+        }
+        // A playlist deleted since then is simply not reopened
+        let playlist = saved.selected_playlist.and_then(|name| {
+            playlists.with_untracked(|list| list.iter().find(|p| p.name == name).cloned())
+        });
+        if let Some(playlist) = playlist {
+            set_detail_view_playlist.set(Some(playlist.name.clone()));
+            set_selected_playlist.set(Some(playlist));
+        }
+    });
+
+    // Save the open view and playlist as they change
+    Effect::new(move |_| {
+        let view = if history_open.get() {
+            UiView::History
+        } else if storage_open.get() {
+            UiView::Storage
+        } else if selection_mode.get() {
+            UiView::Sync
+        } else {
+            UiView::Library
+        };
+        let selected_playlist = detail_view_playlist.get();
+        if ui_restored.get_value() {
+            ui_state.update(|state| {
+                state.view = view;
+                state.selected_playlist = selected_playlist;
+            });
+        }
+    });
+
+    let sidebar_collapsed = Signal::derive(move || ui_state.get().sidebar_collapsed);
+    let on_sidebar_toggle = Callback::new(move |()| {
+        ui_state.update(|state| state.sidebar_collapsed = !state.sidebar_collapsed);
+    });
+
     // Callbacks
     let on_device_select = Callback::new(move |device: DeviceInfo| {
+        set_history_open.set(false);
+        set_storage_open.set(false);
+        set_detail_view_playlist.set(None);
+        set_detail_view_device.set(Some(device.mount_point.clone()));
         set_selected_device.set(Some(device));
     });
 
@@ -520,17 +786,23 @@ fn AppContent() -> impl IntoView {
         {
             set_selected_device.set(None);
         }
+        if detail_view_device.get_untracked().as_ref() == Some(&mount_point) {
+            set_detail_view_device.set(None);
+        }
         // Remove from device list
         set_devices.update(|devs| {
             devs.retain(|d| d.mount_point != mount_point);
         });
-        notifications.success("Device safely ejected. You can now remove it.");
+        notifications.success(i18n.t("toast.device_ejected"));
     });
 
     let on_playlist_select = Callback::new(move |playlist: PlaylistMetadata| {
         set_selected_playlist.set(Some(playlist.clone()));
         // Navigate to detail view when clicking a playlist in management mode
         if !selection_mode.get() {
+            set_history_open.set(false);
+            set_storage_open.set(false);
+            set_detail_view_device.set(None);
             set_detail_view_playlist.set(Some(playlist.name));
         }
     });
@@ -540,6 +812,76 @@ fn AppContent() -> impl IntoView {
         set_detail_view_playlist.set(None);
     });
 
+    let on_device_detail_back = Callback::new(move |()| {
+        set_detail_view_device.set(None);
+    });
+
+    // Open a playlist picked in the search palette
+    let on_search_select = Callback::new(move |name: String| {
+        set_selection_mode.set(false);
+        set_history_open.set(false);
+        set_storage_open.set(false);
+        set_detail_view_device.set(None);
+        if let Some(playlist) = playlists.with(|list| list.iter().find(|p| p.name == name).cloned())
+        {
+            set_selected_playlist.set(Some(playlist));
+        }
+        set_detail_view_playlist.set(Some(name));
+    });
+
+    // The download queue sits at the top of the content area
+    let on_downloads_open = Callback::new(move |()| {
+        if let Some(panel) = document().query_selector(".queue-panel").ok().flatten() {
+            panel.scroll_into_view();
+        }
+    });
+
+    let on_history_open = Callback::new(move |()| {
+        set_detail_view_playlist.set(None);
+        set_detail_view_device.set(None);
+        set_storage_open.set(false);
+        set_history_open.set(true);
+    });
+
+    let on_history_back = Callback::new(move |()| {
+        set_history_open.set(false);
+    });
+
+    let on_storage_open = Callback::new(move |()| {
+        set_detail_view_playlist.set(None);
+        set_detail_view_device.set(None);
+        set_history_open.set(false);
+        set_storage_open.set(true);
+    });
+
+    let on_storage_back = Callback::new(move |()| {
+        set_storage_open.set(false);
+    });
+
+    let on_storage_changed = Callback::new(move |()| {
+        load_playlists();
+    });
+
+    let on_device_detail_sync = Callback::new(move |()| {
+        set_sync_wizard_open.set(true);
+    });
+
+    // Actions from the banner shown when a device is plugged in
+    let clean_requested = StoredValue::new(false);
+    let on_banner_sync = Callback::new(move |device: DeviceInfo| {
+        set_selected_device.set(Some(device));
+        set_wizard_playlists.set(vec![]);
+        set_sync_wizard_open.set(true);
+    });
+    let on_banner_clean = Callback::new(move |device: DeviceInfo| {
+        clean_requested.set_value(true);
+        set_history_open.set(false);
+        set_storage_open.set(false);
+        set_detail_view_playlist.set(None);
+        set_detail_view_device.set(Some(device.mount_point.clone()));
+        set_selected_device.set(Some(device));
+    });
+
     // Callback when sync is requested from detail view
     let on_detail_sync = {
         Callback::new(move |name: String| {
@@ -547,9 +889,9 @@ fn AppContent() -> impl IntoView {
             let name_for_notification = name.clone();
             if let Some(device) = selected {
                 leptos::logging::log!("Syncing playlist {} to {}", name, device.mount_point);
-                notifications.info(format!(
-                    "Syncing \"{}\" to {}...",
-                    name_for_notification, device.name
+                notifications.info(i18n.tf(
+                    "toast.syncing",
+                    &[("name", &name_for_notification), ("device", &device.name)],
                 ));
                 let name_clone = name;
                 let device_mount = device.mount_point;
@@ -557,18 +899,18 @@ fn AppContent() -> impl IntoView {
                     match tauri_api::sync_playlist(&name_clone, &device_mount).await {
                         Ok(()) => {
                             leptos::logging::log!("Playlist synced successfully");
-                            notifications.success(format!(
-                                "\"{name_for_notification}\" synced successfully"
-                            ));
+                            notifications.success(
+                                i18n.tf("toast.synced", &[("name", &name_for_notification)]),
+                            );
                         }
                         Err(e) => {
                             leptos::logging::error!("Failed to sync playlist: {}", e);
-                            notifications.error(format!("Failed to sync playlist: {e}"));
+                            notifications.error(i18n.tf("toast.sync_failed", &[("error", &e)]));
                         }
                     }
                 });
             } else {
-                notifications.warning("Please select a device first");
+                notifications.warning(i18n.t("toast.select_device"));
             }
         })
     };
@@ -614,27 +956,24 @@ fn AppContent() -> impl IntoView {
     // Handler when delete is confirmed in the dialog
     let on_delete_confirm = Callback::new(move |()| {
         if let Some(name) = delete_playlist_name.get() {
-            let name_clone = name.clone();
-            let name_for_notification = name.clone();
             // If we're in detail view of this playlist, navigate back to list
             if detail_view_playlist.get().as_ref() == Some(&name) {
                 set_detail_view_playlist.set(None);
             }
+            // Move it to the trash so it can be undone for the rest of the session
+            set_playlists.update(|list| list.retain(|p| p.name != name));
             spawn_local(async move {
-                leptos::logging::log!("Deleting playlist: {}", name_clone);
-                match tauri_api::delete_playlist(&name_clone).await {
-                    Ok(()) => {
-                        leptos::logging::log!("Playlist deleted successfully");
-                        notifications
-                            .success(format!("Playlist \"{name_for_notification}\" deleted"));
-                        // Reload playlists
-                        if let Ok(playlist_list) = tauri_api::list_playlists().await {
-                            set_playlists.set(playlist_list);
-                        }
-                    }
+                leptos::logging::log!("Deleting playlist: {}", name);
+                match tauri_api::trash_playlist(&name, None).await {
+                    Ok(entry) => undo.record(
+                        i18n.tf("toast.playlist_deleted", &[("name", &name)]),
+                        UndoAction::Restore(entry.id),
+                    ),
                     Err(e) => {
                         leptos::logging::error!("Failed to delete playlist: {}", e);
-                        notifications.error(format!("Failed to delete playlist: {e}"));
+                        notifications
+                            .error(i18n.tf("toast.delete_playlist_failed", &[("error", &e)]));
+                        load_playlists();
                     }
                 }
             });
@@ -662,23 +1001,23 @@ fn AppContent() -> impl IntoView {
         spawn_local(async move {
             if let Some(device) = selected {
                 leptos::logging::log!("Syncing playlist {} to {}", name, device.mount_point);
-                notifications.info(format!(
-                    "Syncing \"{}\" to {}...",
-                    name_for_notification, device.name
+                notifications.info(i18n.tf(
+                    "toast.syncing",
+                    &[("name", &name_for_notification), ("device", &device.name)],
                 ));
                 match tauri_api::sync_playlist(&name, &device.mount_point).await {
                     Ok(()) => {
                         leptos::logging::log!("Playlist synced successfully");
                         notifications
-                            .success(format!("\"{name_for_notification}\" synced successfully"));
+                            .success(i18n.tf("toast.synced", &[("name", &name_for_notification)]));
                     }
                     Err(e) => {
                         leptos::logging::error!("Failed to sync playlist: {}", e);
-                        notifications.error(format!("Failed to sync playlist: {e}"));
+                        notifications.error(i18n.tf("toast.sync_failed", &[("error", &e)]));
                     }
                 }
             } else {
-                notifications.warning("Please select a device first");
+                notifications.warning(i18n.t("toast.select_device"));
             }
         });
     });
@@ -696,7 +1035,7 @@ fn AppContent() -> impl IntoView {
     // Create playlist dialog callbacks
     let on_create_playlist = Callback::new(move |name: String| {
         leptos::logging::log!("Playlist created: {}", name);
-        notifications.success(format!("Playlist \"{name}\" created"));
+        notifications.success(i18n.tf("toast.playlist_created", &[("name", &name)]));
         // Reload playlists to include the new one
         load_playlists();
     });
@@ -705,42 +1044,52 @@ fn AppContent() -> impl IntoView {
         set_create_dialog_open.set(false);
     });
 
-    // Sync button callback (handles sync with loading state and progress tracking)
-    let on_sync_button = Callback::new(move |name: String| {
-        let selected = selected_device.get();
-        let name_for_notification = name.clone();
-        set_syncing.set(true);
-        set_transfer_panel_state.set(TransferPanelState::Preparing);
-        set_transfer_progress.set(None);
+    let on_sync_wizard_close = Callback::new(move |()| {
+        set_sync_wizard_open.set(false);
+        set_wizard_playlists.set(vec![]);
+    });
+
+    // Batch actions on the playlists selected in the management list
+    let on_playlist_batch = Callback::new(move |(action, names): (BatchAction, Vec<String>)| {
+        let (done, tag) = match action {
+            BatchAction::Sync => {
+                set_wizard_playlists.set(names);
+                set_sync_wizard_open.set(true);
+                return;
+            }
+            BatchAction::Delete => ("batch.deleted", None),
+            BatchAction::Tag(tag) => ("batch.tagged", Some(tag)),
+            BatchAction::Move(_) => return,
+        };
         spawn_local(async move {
-            if let Some(device) = selected {
-                leptos::logging::log!(
-                    "Starting sync for playlist {} to {}",
-                    name,
-                    device.mount_point
-                );
-                notifications.info(format!(
-                    "Starting transfer of \"{}\" to {}...",
-                    name_for_notification, device.name
-                ));
-                // Use start_sync for async progress tracking
-                match tauri_api::start_sync(&name, &device.mount_point, false, true).await {
-                    Ok(task_id) => {
-                        leptos::logging::log!("Sync started with task ID: {:?}", task_id);
-                        set_current_sync_task_id.set(Some(task_id));
-                        // Progress updates will be handled by event listeners
+            let result = match &tag {
+                Some(tag) => tauri_api::tag_playlists(&names, tag).await,
+                None => tauri_api::delete_playlists(&names, None).await,
+            };
+            match result {
+                Ok(result) => {
+                    let message = batch_summary(i18n.locale(), done, "count.playlists", &result);
+                    if result.failed.is_empty() {
+                        notifications.success(message);
+                    } else {
+                        notifications.warning(message);
                     }
-                    Err(e) => {
-                        leptos::logging::error!("Failed to start sync: {}", e);
-                        notifications.error(format!("Failed to start transfer: {e}"));
-                        set_syncing.set(false);
-                        set_transfer_panel_state.set(TransferPanelState::Failed(e));
+                    if let Ok(playlist_list) = tauri_api::list_playlists().await {
+                        set_playlists.set(playlist_list);
                     }
                 }
-            } else {
-                notifications.warning("Please select a device first");
-                set_syncing.set(false);
-                set_transfer_panel_state.set(TransferPanelState::Idle);
+                Err(e) => {
+                    notifications.error(i18n.tf("toast.update_playlists_failed", &[("error", &e)]))
+                }
+            }
+        });
+    });
+
+    // Keep track counts current after tracks are changed in the detail view
+    let on_tracks_changed = Callback::new(move |()| {
+        spawn_local(async move {
+            if let Ok(playlist_list) = tauri_api::list_playlists().await {
+                set_playlists.set(playlist_list);
             }
         });
     });
@@ -756,8 +1105,9 @@ fn AppContent() -> impl IntoView {
                 let name_for_notification = playlist.name;
                 let device_name = device.name.clone();
                 let mount_point = device.mount_point;
-                notifications.info(format!(
-                    "Starting transfer of \"{name_for_notification}\" to {device_name}..."
+                notifications.info(i18n.tf(
+                    "toast.transfer_starting",
+                    &[("name", &name_for_notification), ("device", &device_name)],
                 ));
                 set_syncing.set(true);
                 set_transfer_panel_state.set(TransferPanelState::Preparing);
@@ -772,7 +1122,8 @@ fn AppContent() -> impl IntoView {
                         }
                         Err(e) => {
                             leptos::logging::error!("Failed to start sync: {}", e);
-                            notifications.error(format!("Failed to start transfer: {e}"));
+                            notifications
+                                .error(i18n.tf("toast.transfer_start_failed", &[("error", &e)]));
                             set_syncing.set(false);
                             set_transfer_panel_state.set(TransferPanelState::Failed(e));
                         }
@@ -782,10 +1133,10 @@ fn AppContent() -> impl IntoView {
                 set_selection_mode.set(false);
             }
             (None, _) => {
-                notifications.warning("Please select a playlist first");
+                notifications.warning(i18n.t("toast.select_playlist"));
             }
             (_, None) => {
-                notifications.warning("Please select a device first");
+                notifications.warning(i18n.t("toast.select_device"));
             }
         }
     };
@@ -794,6 +1145,7 @@ fn AppContent() -> impl IntoView {
     let enter_selection_mode = move |_: web_sys::MouseEvent| {
         // Clear playlist selection when entering selection mode for a fresh start
         set_selected_playlist.set(None);
+        playlist_selection.set_active(false);
         set_selection_mode.set(true);
     };
 
@@ -819,7 +1171,7 @@ fn AppContent() -> impl IntoView {
                 }
                 Err(e) => {
                     leptos::logging::error!("Failed to cancel transfer: {}", e);
-                    notifications.error(format!("Failed to cancel transfer: {e}"));
+                    notifications.error(i18n.tf("toast.cancel_transfer_failed", &[("error", &e)]));
                 }
             }
         });
@@ -847,7 +1199,7 @@ fn AppContent() -> impl IntoView {
                 }
                 Err(e) => {
                     leptos::logging::error!("Failed to cancel download: {}", e);
-                    notifications.error(format!("Failed to cancel download: {e}"));
+                    notifications.error(i18n.tf("toast.cancel_download_failed", &[("error", &e)]));
                 }
             }
         });
@@ -857,11 +1209,262 @@ fn AppContent() -> impl IntoView {
         set_download_panel_state.set(DownloadPanelState::Idle);
         set_download_progress.set(None);
         set_current_download_task_id.set(None);
+        set_download_result.set(None);
+    });
+
+    let on_download_retry_failed = Callback::new(move |(): ()| {
+        let Some((url, playlist_name)) = download_result
+            .get_untracked()
+            .and_then(|result| result.failed_videos_retry_target())
+        else {
+            return;
+        };
+        spawn_local(async move {
+            if let Err(e) = tauri_api::download_youtube_to_playlist(&url, &playlist_name).await {
+                notifications.error(i18n.tf("toast.retry_failed", &[("error", &e)]));
+            }
+        });
+    });
+
+    // Rename dialog state, opened from a playlist's context menu
+    let (rename_dialog_open, set_rename_dialog_open) = signal(false);
+    let (rename_playlist_name, set_rename_playlist_name) = signal::<Option<String>>(None);
+
+    let on_rename_confirm = Callback::new(move |new_name: String| {
+        let Some(old_name) = rename_playlist_name.get_untracked() else {
+            return;
+        };
+        set_rename_dialog_open.set(false);
+        spawn_local(async move {
+            match tauri_api::rename_playlist(&old_name, &new_name).await {
+                Ok(()) => {
+                    if detail_view_playlist.get_untracked().as_ref() == Some(&old_name) {
+                        set_detail_view_playlist.set(Some(new_name.clone()));
+                    }
+                    load_playlists();
+                    undo.record(
+                        i18n.tf("undo.renamed", &[("from", &old_name), ("to", &new_name)]),
+                        UndoAction::Rename {
+                            from: old_name,
+                            to: new_name,
+                        },
+                    );
+                }
+                Err(e) => notifications.error(i18n.tf("toast.rename_failed", &[("error", &e)])),
+            }
+        });
+    });
+
+    let on_rename_cancel = Callback::new(move |()| {
+        set_rename_dialog_open.set(false);
+    });
+
+    // Catch up with changes undone from the undo history
+    Effect::new(move || {
+        let undone = undo.last_undone();
+        if undone.is_empty() {
+            return;
+        }
+        for action in &undone {
+            if let UndoAction::Rename { from, to } = action
+                && detail_view_playlist.get_untracked().as_ref() == Some(to)
+            {
+                set_detail_view_playlist.set(Some(from.clone()));
+            }
+        }
+        load_playlists();
+        set_detail_refresh_trigger.update(|v| *v += 1);
+    });
+
+    // Maintenance operations on playlists, followed in a progress dialog
+    let (running_operation, set_running_operation) = signal::<Option<RunningOperation>>(None);
+    let on_maintenance = Callback::new(move |operation: MaintenanceOperation| {
+        spawn_local(async move {
+            match tauri_api::start_maintenance(&operation).await {
+                Ok(task_id) => set_running_operation.set(Some(RunningOperation::maintenance(
+                    &operation,
+                    task_id,
+                    i18n.locale(),
+                ))),
+                Err(e) => notifications.error(i18n.tf("toast.start_failed", &[("error", &e)])),
+            }
+        });
+    });
+    let on_operation_finished = Callback::new(move |event: TaskEvent| {
+        if event.phase == TaskPhase::Completed {
+            load_playlists();
+            set_detail_refresh_trigger.update(|v| *v += 1);
+        }
+    });
+
+    // Context menu actions on playlist cards
+    let on_playlist_action = Callback::new(
+        move |(action, playlist): (PlaylistAction, PlaylistMetadata)| {
+            let name = playlist.name;
+            match action {
+                PlaylistAction::Rename => {
+                    set_rename_playlist_name.set(Some(name));
+                    set_rename_dialog_open.set(true);
+                }
+                PlaylistAction::RefreshFromSource => {
+                    let Some(url) = playlist.source_url else {
+                        return;
+                    };
+                    spawn_local(async move {
+                        if let Err(e) = tauri_api::download_youtube_to_playlist(&url, &name).await {
+                            notifications.error(i18n.tf("toast.refresh_failed", &[("error", &e)]));
+                        }
+                    });
+                }
+                PlaylistAction::ResetDownloadArchive => spawn_local(async move {
+                    match tauri_api::reset_download_archive(&name).await {
+                        Ok(removed) => notifications.success(i18n.tf(
+                            "toast.archive_reset",
+                            &[
+                                ("videos", &i18n.count("count.videos", removed)),
+                                ("name", &name),
+                            ],
+                        )),
+                        Err(e) => {
+                            notifications
+                                .error(i18n.tf("toast.archive_reset_failed", &[("error", &e)]));
+                        }
+                    }
+                }),
+                PlaylistAction::Export => spawn_local(async move {
+                    match tauri_api::export_playlist_m3u(&name).await {
+                        Ok(path) => {
+                            notifications.success(i18n.tf("toast.exported", &[("path", &path)]))
+                        }
+                        Err(e) => {
+                            notifications.error(i18n.tf("toast.export_failed", &[("error", &e)]))
+                        }
+                    }
+                }),
+                PlaylistAction::Verify => spawn_local(async move {
+                    match tauri_api::validate_playlist_folder(&name).await {
+                        Ok(result) if result.is_valid() => notifications.success(i18n.tf(
+                            "toast.verify_ok",
+                            &[
+                                ("name", &name),
+                                (
+                                    "tracks",
+                                    &i18n.count("count.tracks", result.audio_file_count),
+                                ),
+                            ],
+                        )),
+                        // Report the issues, and fix what can be fixed
+                        Ok(result) => {
+                            notifications.warning(i18n.tf(
+                                "toast.verify_issues",
+                                &[("name", &name), ("issues", &result.issues.join(", "))],
+                            ));
+                            on_maintenance
+                                .run(MaintenanceOperation::RepairPlaylistFolder { playlist: name });
+                        }
+                        Err(e) => {
+                            notifications.error(i18n.tf("toast.verify_failed", &[("error", &e)]))
+                        }
+                    }
+                }),
+                PlaylistAction::CreateChecksums => {
+                    on_maintenance
+                        .run(MaintenanceOperation::CreateChecksumManifest { playlist: name });
+                }
+                PlaylistAction::OpenFolder => spawn_local(async move {
+                    if let Err(e) = tauri_api::open_playlist_folder(&name).await {
+                        notifications.error(i18n.tf("toast.open_folder_failed", &[("error", &e)]));
+                    }
+                }),
+                PlaylistAction::Delete => on_playlist_delete_request.run(name),
+            }
+        },
+    );
+
+    let on_stop_all = Callback::new(move |(): ()| {
+        spawn_local(async move {
+            match tauri_api::cancel_all_tasks().await {
+                Ok(cancelled) if cancelled.total() == 0 => {
+                    notifications.info(i18n.t("toast.nothing_running"));
+                }
+                Ok(cancelled) => {
+                    notifications.success(i18n.count("toast.tasks_stopped", cancelled.total()));
+                }
+                Err(e) => {
+                    leptos::logging::error!("Failed to stop tasks: {}", e);
+                    notifications.error(i18n.tf("toast.stop_tasks_failed", &[("error", &e)]));
+                }
+            }
+        });
+    });
+
+    // Keyboard shortcuts
+    let (shortcuts_open, set_shortcuts_open) = signal(false);
+    let on_shortcuts_close = Callback::new(move |()| set_shortcuts_open.set(false));
+
+    let handle = window_event_listener(ev::keydown, move |e| {
+        if e.repeat() || is_typing(&e) {
+            return;
+        }
+        let Some(shortcut) = global_shortcut(&KeyPress::from_event(&e)) else {
+            return;
+        };
+        // "?" also closes the cheatsheet; other shortcuts wait until dialogs are closed
+        if shortcut == Shortcut::ShowShortcuts && shortcuts_open.get_untracked() {
+            e.prevent_default();
+            set_shortcuts_open.set(false);
+            return;
+        }
+        if is_modal_open() {
+            return;
+        }
+        e.prevent_default();
+        match shortcut {
+            Shortcut::ShowShortcuts => set_shortcuts_open.set(true),
+            Shortcut::OpenSettings => set_settings_open.set(true),
+            Shortcut::Undo => undo.undo_last(),
+            Shortcut::GoToPlaylists => {
+                set_selection_mode.set(false);
+                set_history_open.set(false);
+                set_storage_open.set(false);
+                set_detail_view_device.set(None);
+                set_detail_view_playlist.set(None);
+            }
+            Shortcut::GoToHistory => on_history_open.run(()),
+            Shortcut::GoToDevice => match selected_device.get_untracked() {
+                Some(device) => on_device_select.run(device),
+                None => notifications.info(i18n.t("toast.select_device_first")),
+            },
+            Shortcut::DeletePlaylist => {
+                // The playlist being viewed, or the one selected in the list
+                let on_list = !history_open.get_untracked()
+                    && !storage_open.get_untracked()
+                    && !selection_mode.get_untracked()
+                    && detail_view_device.get_untracked().is_none();
+                let name = detail_view_playlist.get_untracked().or_else(|| {
+                    selected_playlist
+                        .get_untracked()
+                        .filter(|_| on_list)
+                        .map(|p| p.name)
+                });
+                match name {
+                    Some(name) => on_playlist_delete_request.run(name),
+                    None => notifications.info(i18n.t("toast.select_playlist_to_delete")),
+                }
+            }
+        }
     });
+    on_cleanup(move || handle.remove());
 
     view! {
-        <Layout on_settings_click=Callback::new(move |()| set_settings_open.set(true))>
+        <Layout
+            on_settings_click=Callback::new(move |()| set_settings_open.set(true))
+            on_stop_all_click=on_stop_all
+            sidebar_collapsed=sidebar_collapsed
+            on_sidebar_toggle=on_sidebar_toggle
+        >
             <LayoutSidebar>
+                <SearchBar on_select=on_search_select />
                 <DeviceStatusIndicator device=selected_device />
                 <DeviceList
                     devices=devices
@@ -871,26 +1474,110 @@ fn AppContent() -> impl IntoView {
                     on_eject=on_device_eject
                     state=device_list_state
                 />
-                <SyncButton
-                    selected_device=selected_device
-                    selected_playlist=selected_playlist
-                    on_sync=on_sync_button
-                    syncing=syncing
-                />
+                <div class="sync-button-container">
+                    <button
+                        class="btn btn-primary sync-button"
+                        disabled=move || syncing.get()
+                        on:click=move |_| set_sync_wizard_open.set(true)
+                        data-testid="sync-button"
+                    >
+                        <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor" class="sync-icon">
+                            <path d="M19 8l-4 4h3c0 3.31-2.69 6-6 6-1.01 0-1.97-.25-2.8-.7l-1.46 1.46C8.97 19.54 10.43 20 12 20c4.42 0 8-3.58 8-8h3l-4-4zM6 12c0-3.31 2.69-6 6-6 1.01 0 1.97.25 2.8.7l1.46-1.46C15.03 4.46 13.57 4 12 4c-4.42 0-8 3.58-8 8H1l4 4 4-4H6z"/>
+                        </svg>
+                        <span>{move || i18n.t("app.sync_to_device")}</span>
+                    </button>
+                </div>
+                {move || view! {
+                    <NavSection title=i18n.t("nav.library")>
+                        <NavItem
+                            label=i18n.t("nav.history")
+                            icon=nav_icons::HISTORY
+                            active=history_open.get()
+                            on_click=on_history_open
+                        />
+                        <NavItem
+                            label=i18n.t("nav.storage")
+                            icon=nav_icons::STORAGE
+                            active=storage_open.get()
+                            on_click=on_storage_open
+                        />
+                    </NavSection>
+                    <NavSection title=i18n.t("nav.activity")>
+                        <NavItem
+                            label=i18n.t("nav.downloads")
+                            icon=nav_icons::DOWNLOAD
+                            on_click=on_downloads_open
+                            badge=Signal::derive(move || activity.count(ActivityKind::Downloads))
+                            status=Signal::derive(move || {
+                                let count = activity.count(ActivityKind::Downloads);
+                                if count == 0 {
+                                    String::new()
+                                } else {
+                                    i18n.tf("nav.downloads_status", &[("count", &count.to_string())])
+                                }
+                            })
+                        />
+                        <NavItem
+                            label=i18n.t("nav.sync")
+                            icon=nav_icons::SYNC
+                            on_click=Callback::new(move |()| set_sync_wizard_open.set(true))
+                            busy=Signal::derive(move || activity.is_active(ActivityKind::Sync))
+                            status=Signal::derive(move || {
+                                if activity.is_active(ActivityKind::Sync) {
+                                    i18n.t("nav.sync_status")
+                                } else {
+                                    String::new()
+                                }
+                            })
+                        />
+                    </NavSection>
+                }}
+                <UndoHistory />
+                <button class="shortcut-hint" on:click=move |_| set_shortcuts_open.set(true)>
+                    <kbd>"?"</kbd>
+                    <span>{move || i18n.t("nav.shortcuts")}</span>
+                </button>
             </LayoutSidebar>
             <LayoutMain>
+                <DeviceBanner on_sync=on_banner_sync on_clean=on_banner_clean />
+
                 // Download Progress Panel (inline in content area)
                 <DownloadProgressPanel
                     progress=download_progress
                     state=download_panel_state
                     on_cancel=on_download_cancel
                     on_dismiss=on_download_dismiss
+                    result=download_result
+                    on_retry_failed=on_download_retry_failed
                 />
 
+                // Download queue (hidden while empty)
+                <QueuePanel />
+
                 // Content switches between management mode, selection mode, and detail view
                 {move || {
-                    // Check if we're viewing a playlist detail
-                    if let Some(playlist_name) = detail_view_playlist.get() {
+                    // Check if we're viewing the history, storage, a device or a playlist detail
+                    if history_open.get() {
+                        view! { <HistoryView on_back=on_history_back /> }.into_any()
+                    } else if storage_open.get() {
+                        view! {
+                            <StorageView on_back=on_storage_back on_library_changed=on_storage_changed />
+                        }.into_any()
+                    } else if let Some(mount_point) = detail_view_device.get() {
+                        // Opened from a banner's "Clean device" action
+                        let confirm_clean = clean_requested.get_value();
+                        clean_requested.set_value(false);
+                        view! {
+                            <DeviceDetailView
+                                mount_point=mount_point
+                                devices=devices
+                                confirm_clean=confirm_clean
+                                on_back=on_device_detail_back
+                                on_sync=on_device_detail_sync
+                                on_eject=on_device_eject
+                            />
+                        }.into_any()
+                    } else if let Some(playlist_name) = detail_view_playlist.get() {
                         // Detail view: show individual playlist
                         view! {
                             <PlaylistDetailView
@@ -899,6 +1586,8 @@ fn AppContent() -> impl IntoView {
                                 on_sync=on_detail_sync
                                 on_delete=on_detail_delete
                                 refresh_trigger=detail_refresh_trigger.into()
+                                on_tracks_changed=on_tracks_changed
+                                on_maintenance=on_maintenance
                             />
                         }.into_any()
                     } else if selection_mode.get() {
@@ -909,12 +1598,12 @@ fn AppContent() -> impl IntoView {
                             PlaylistListState::Error => PlaylistSelectionState::Error,
                         };
                         view! {
-                            <ContentHeader title="Select Playlist to Sync">
+                            <ContentHeader title=i18n.t("playlists.select_to_sync")>
                                 <button
                                     class="btn btn-secondary"
                                     on:click=cancel_selection
                                 >
-                                    "Cancel"
+                                    {i18n.t("common.cancel")}
                                 </button>
                                 <button
                                     class="btn btn-primary"
@@ -924,7 +1613,7 @@ fn AppContent() -> impl IntoView {
                                     <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
                                         <path d="M19 8l-4 4h3c0 3.31-2.69 6-6 6-1.01 0-1.97-.25-2.8-.7l-1.46 1.46C8.97 19.54 10.43 20 12 20c4.42 0 8-3.58 8-8h3l-4-4zM6 12c0-3.31 2.69-6 6-6 1.01 0 1.97.25 2.8.7l1.46-1.46C15.03 4.46 13.57 4 12 4c-4.42 0-8 3.58-8 8H1l4 4 4-4H6z"/>
                                     </svg>
-                                    "Sync to Device"
+                                    {i18n.t("app.sync_to_device")}
                                 </button>
                             </ContentHeader>
                             <PlaylistSelectionSummary selected=selected_playlist />
@@ -934,15 +1623,21 @@ fn AppContent() -> impl IntoView {
                                     selected_playlist=selected_playlist
                                     on_select=on_playlist_select
                                     state=selection_state
-                                    title="Choose a playlist".to_string()
-                                    description="Select one playlist to sync to your connected device".to_string()
+                                    title=i18n.t("playlists.choose")
+                                    description=i18n.t("playlists.choose_description")
                                 />
                             </div>
                         }.into_any()
                     } else {
                         // Management mode: standard list view with actions
                         view! {
-                            <ContentHeader title="Playlists">
+                            <ContentHeader title=i18n.t("playlists.title")>
+                                <button
+                                    class="btn btn-ghost"
+                                    on:click=move |_| playlist_selection.set_active(!playlist_selection.is_active())
+                                >
+                                    {move || i18n.t(if playlist_selection.is_active() { "playlists.done" } else { "playlists.select" })}
+                                </button>
                                 <button
                                     class="btn btn-secondary"
                                     on:click=enter_selection_mode
@@ -950,7 +1645,7 @@ fn AppContent() -> impl IntoView {
                                     <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
                                         <path d="M19 8l-4 4h3c0 3.31-2.69 6-6 6-1.01 0-1.97-.25-2.8-.7l-1.46 1.46C8.97 19.54 10.43 20 12 20c4.42 0 8-3.58 8-8h3l-4-4zM6 12c0-3.31 2.69-6 6-6 1.01 0 1.97.25 2.8.7l1.46-1.46C15.03 4.46 13.57 4 12 4c-4.42 0-8 3.58-8 8H1l4 4 4-4H6z"/>
                                     </svg>
-                                    "Select for Sync"
+                                    {i18n.t("playlists.select_for_sync")}
                                 </button>
                                 <button
                                     class="btn btn-primary"
@@ -959,7 +1654,7 @@ fn AppContent() -> impl IntoView {
                                     <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
                                         <path d="M19 13h-6v6h-2v-6H5v-2h6V5h2v6h6v2z"/>
                                     </svg>
-                                    "New Playlist"
+                                    {i18n.t("playlists.new")}
                                 </button>
                             </ContentHeader>
                             <PlaylistList
@@ -972,9 +1667,14 @@ fn AppContent() -> impl IntoView {
                                 on_delete=on_playlist_delete_request
                                 on_sync=on_playlist_sync
                                 on_retry=on_playlist_retry
-                                on_create=Callback::new(move |()| set_create_dialog_open.set(true))
+                                on_create=Callback::new(move |()| set_first_playlist_open.set(true))
                                 min_item_width="300px".to_string()
                                 show_summary=true
+                                selection=playlist_selection
+                                on_batch=on_playlist_batch
+                                view=playlist_view
+                                on_view_change=on_playlist_view_change
+                                on_action=on_playlist_action
                             />
                         }.into_any()
                     }
@@ -999,6 +1699,14 @@ fn AppContent() -> impl IntoView {
             on_cancel=on_delete_cancel
         />
 
+        // Rename Playlist Dialog
+        <RenamePlaylistDialog
+            is_open=rename_dialog_open
+            playlist_name=rename_playlist_name
+            on_confirm=on_rename_confirm
+            on_cancel=on_rename_cancel
+        />
+
         // Create Playlist Dialog
         <CreatePlaylistDialog
             is_open=create_dialog_open
@@ -1006,6 +1714,38 @@ fn AppContent() -> impl IntoView {
             on_close=on_create_dialog_close
         />
 
+        // Guided first playlist dialog
+        <FirstPlaylistDialog
+            is_open=first_playlist_open
+            playlists=playlists
+            on_create=on_create_playlist
+            on_close=Callback::new(move |()| set_first_playlist_open.set(false))
+        />
+
+        // Progress of maintenance operations on playlists
+        <OperationProgressDialog
+            operation=running_operation
+            on_close=Callback::new(move |()| set_running_operation.set(None))
+            on_finished=on_operation_finished
+        />
+
+        // Sync Wizard
+        <SyncWizard
+            is_open=sync_wizard_open
+            devices=devices
+            playlists=playlists
+            initial_device=selected_device
+            initial_playlist=selected_playlist
+            initial_selection=wizard_playlists
+            on_close=on_sync_wizard_close
+        />
+
+        // Keyboard Shortcuts
+        <ShortcutCheatsheet
+            is_open=shortcuts_open
+            on_close=on_shortcuts_close
+        />
+
         // Transfer Progress Panel
         <TransferProgressPanel
             progress=transfer_progress
@@ -0,0 +1,442 @@
+//! Multi-select for playlist and track lists, and the action bar shown while
+//! selecting.
+
+use std::collections::BTreeSet;
+
+use leptos::prelude::*;
+
+use crate::i18n::{translate, translate_count, use_i18n};
+use crate::types::{BatchResult, Locale};
+
+/// An action to run on every selected item.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BatchAction {
+    /// Delete the items.
+    Delete,
+    /// Sync the items to a device.
+    Sync,
+    /// Move the items to the named playlist.
+    Move(String),
+    /// Add the tag to the items.
+    Tag(String),
+}
+
+/// Selected items of a list, by key, with the anchor for shift-click ranges.
+#[derive(Clone, Copy)]
+pub struct ListSelection {
+    active: RwSignal<bool>,
+    selected: RwSignal<BTreeSet<String>>,
+    anchor: RwSignal<Option<usize>>,
+}
+
+impl Default for ListSelection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ListSelection {
+    /// Create an inactive, empty selection.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            active: RwSignal::new(false),
+            selected: RwSignal::new(BTreeSet::new()),
+            anchor: RwSignal::new(None),
+        }
+    }
+
+    /// Whether the list is in selection mode.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.active.get()
+    }
+
+    /// Enter or leave selection mode. Leaving clears the selection.
+    pub fn set_active(&self, active: bool) {
+        if !active {
+            self.clear();
+        }
+        self.active.set(active);
+    }
+
+    /// Whether the item with `key` is selected.
+    #[must_use]
+    pub fn is_selected(&self, key: &str) -> bool {
+        self.selected.with(|selected| selected.contains(key))
+    }
+
+    /// Number of selected items.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.selected.with(BTreeSet::len)
+    }
+
+    /// Keys of the selected items.
+    #[must_use]
+    pub fn keys(&self) -> Vec<String> {
+        self.selected
+            .with_untracked(|selected| selected.iter().cloned().collect())
+    }
+
+    /// Select every item in `keys`.
+    pub fn select_all(&self, keys: &[String]) {
+        self.selected
+            .update(|selected| selected.extend(keys.iter().cloned()));
+    }
+
+    /// Deselect everything.
+    pub fn clear(&self) {
+        self.selected.update(BTreeSet::clear);
+        self.anchor.set(None);
+    }
+
+    /// Handle a click on the item at `index` of `keys`.
+    pub fn click(&self, keys: &[String], index: usize, shift: bool) {
+        let anchor = self.anchor.get_untracked();
+        let mut new_anchor = anchor;
+        self.selected.update(|selected| {
+            new_anchor = apply_click(selected, anchor, keys, index, shift);
+        });
+        self.anchor.set(new_anchor);
+    }
+}
+
+/// Apply a click on the item at `index` to `selected`, returning the new
+/// range anchor.
+///
+/// A plain click toggles the item and makes it the anchor. A shift-click
+/// selects every item between the anchor and the clicked one.
+pub fn apply_click(
+    selected: &mut BTreeSet<String>,
+    anchor: Option<usize>,
+    keys: &[String],
+    index: usize,
+    shift: bool,
+) -> Option<usize> {
+    let Some(key) = keys.get(index) else {
+        return anchor;
+    };
+    match anchor.filter(|&a| shift && a < keys.len()) {
+        Some(anchor) => {
+            let (start, end) = (anchor.min(index), anchor.max(index));
+            selected.extend(keys[start..=end].iter().cloned());
+            Some(anchor)
+        }
+        None => {
+            if !selected.remove(key) {
+                selected.insert(key.clone());
+            }
+            Some(index)
+        }
+    }
+}
+
+/// Toast message for a finished batch action, e.g. "Deleted 2 of 3
+/// playlists. Chill: not found".
+///
+/// `done` is the message key of the action, e.g. `batch.deleted`, and `noun`
+/// the count key of the items, e.g. `count.playlists`.
+#[must_use]
+pub fn batch_summary(locale: Locale, done: &str, noun: &str, result: &BatchResult) -> String {
+    let succeeded = result.succeeded.len();
+    let total = succeeded + result.failed.len();
+    let items = if result.failed.is_empty() {
+        translate_count(locale, noun, succeeded)
+    } else {
+        translate(
+            locale,
+            "batch.partial",
+            &[
+                ("succeeded", &succeeded.to_string()),
+                ("items", &translate_count(locale, noun, total)),
+            ],
+        )
+    };
+    let mut message = translate(locale, done, &[("items", &items)]);
+    if let Some(failure) = result.failed.first() {
+        message.push_str(&translate(
+            locale,
+            "batch.first_failure",
+            &[("item", &failure.item), ("error", &failure.error)],
+        ));
+        if result.failed.len() > 1 {
+            message.push_str(&translate_count(
+                locale,
+                "batch.more_failures",
+                result.failed.len() - 1,
+            ));
+        }
+    }
+    message
+}
+
+/// What the action bar is asking for.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum BarMode {
+    /// Showing the actions.
+    Actions,
+    /// Asking for a tag.
+    Tag,
+    /// Asking for the destination playlist.
+    Move,
+    /// Asking to confirm a delete.
+    ConfirmDelete,
+}
+
+/// Contextual action bar shown while a list is in selection mode.
+///
+/// Actions apply to every selected item; the selection is cleared once an
+/// action is picked.
+#[component]
+
+pub fn BatchActionBar(
+    /// The list's selection.
+    selection: ListSelection,
+    /// Keys of every item in the list, for "Select all".
+    #[prop(into)]
+    all_keys: Signal<Vec<String>>,
+    /// Count key of the items, e.g. `count.playlists`.
+    noun: &'static str,
+    /// Callback with the action and the selected keys.
+    on_action: Callback<(BatchAction, Vec<String>)>,
+    /// Whether to offer syncing the selection.
+    #[prop(default = false)]
+    allow_sync: bool,
+    /// Playlists the selection can be moved to; moving is offered when set.
+    #[prop(optional, into)]
+    move_targets: Option<Signal<Vec<String>>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let (mode, set_mode) = signal(BarMode::Actions);
+    let (tag, set_tag) = signal(String::new());
+    let (move_target, set_move_target) = signal(String::new());
+
+    let count = move || selection.count();
+    let run = move |action: BatchAction| {
+        let keys = selection.keys();
+        if keys.is_empty() {
+            return;
+        }
+        selection.clear();
+        set_mode.set(BarMode::Actions);
+        on_action.run((action, keys));
+    };
+
+    let apply_tag = move || {
+        let value = tag.get_untracked().trim().to_string();
+        if !value.is_empty() {
+            set_tag.set(String::new());
+            run(BatchAction::Tag(value));
+        }
+    };
+
+    let apply_move = move || {
+        let target = move_target.get_untracked();
+        if !target.is_empty() {
+            run(BatchAction::Move(target));
+        }
+    };
+
+    let start_move = move || {
+        if let Some(targets) = move_targets {
+            set_move_target.set(targets.get_untracked().first().cloned().unwrap_or_default());
+        }
+        set_mode.set(BarMode::Move);
+    };
+
+    view! {
+        <div class="batch-action-bar" role="toolbar" aria-label=move || i18n.t("batch.toolbar")>
+            <span class="batch-action-count">
+                {move || i18n.tf("batch.selected", &[("items", &i18n.count(noun, count()))])}
+            </span>
+            {move || match mode.get() {
+                BarMode::Actions => view! {
+                    <div class="batch-action-buttons">
+                        <button
+                            class="btn btn-ghost btn-sm"
+                            on:click=move |_| selection.select_all(&all_keys.get_untracked())
+                        >
+                            {i18n.t("batch.select_all")}
+                        </button>
+                        {allow_sync.then(|| view! {
+                            <button
+                                class="btn btn-secondary btn-sm"
+                                disabled=move || count() == 0
+                                on:click=move |_| run(BatchAction::Sync)
+                            >
+                                {i18n.t("batch.sync_selected")}
+                            </button>
+                        })}
+                        {move_targets.map(|_| view! {
+                            <button
+                                class="btn btn-secondary btn-sm"
+                                disabled=move || count() == 0
+                                on:click=move |_| start_move()
+                            >
+                                {i18n.t("batch.move")}
+                            </button>
+                        })}
+                        <button
+                            class="btn btn-secondary btn-sm"
+                            disabled=move || count() == 0
+                            on:click=move |_| set_mode.set(BarMode::Tag)
+                        >
+                            {i18n.t("batch.tag")}
+                        </button>
+                        <button
+                            class="btn btn-danger btn-sm"
+                            disabled=move || count() == 0
+                            on:click=move |_| set_mode.set(BarMode::ConfirmDelete)
+                        >
+                            {i18n.t("common.delete")}
+                        </button>
+                        <button
+                            class="btn btn-ghost btn-sm"
+                            on:click=move |_| selection.set_active(false)
+                        >
+                            {i18n.t("common.done")}
+                        </button>
+                    </div>
+                }.into_any(),
+                BarMode::Tag => view! {
+                    <div class="batch-action-buttons">
+                        <input
+                            class="batch-action-input"
+                            type="text"
+                            placeholder=i18n.t("batch.tag_name")
+                            prop:value=move || tag.get()
+                            on:input=move |ev| set_tag.set(event_target_value(&ev))
+                            on:keydown=move |e: web_sys::KeyboardEvent| {
+                                if e.key() == "Enter" {
+                                    apply_tag();
+                                }
+                            }
+                        />
+                        <button
+                            class="btn btn-primary btn-sm"
+                            disabled=move || tag.with(|t| t.trim().is_empty())
+                            on:click=move |_| apply_tag()
+                        >
+                            {i18n.t("batch.add_tag")}
+                        </button>
+                        <button class="btn btn-ghost btn-sm" on:click=move |_| set_mode.set(BarMode::Actions)>
+                            {i18n.t("common.cancel")}
+                        </button>
+                    </div>
+                }.into_any(),
+                BarMode::Move => view! {
+                    <div class="batch-action-buttons">
+                        <select
+                            class="history-filter-select"
+                            aria-label=i18n.t("batch.move_target")
+                            on:change=move |ev| set_move_target.set(event_target_value(&ev))
+                        >
+                            {move || move_targets.map(|targets| targets.get()).unwrap_or_default()
+                                .into_iter()
+                                .map(|name| {
+                                    let selected = move_target.get_untracked() == name;
+                                    view! { <option value=name.clone() selected=selected>{name}</option> }
+                                })
+                                .collect_view()}
+                        </select>
+                        <button
+                            class="btn btn-primary btn-sm"
+                            disabled=move || move_target.with(String::is_empty)
+                            on:click=move |_| apply_move()
+                        >
+                            {i18n.t("batch.move_here")}
+                        </button>
+                        <button class="btn btn-ghost btn-sm" on:click=move |_| set_mode.set(BarMode::Actions)>
+                            {i18n.t("common.cancel")}
+                        </button>
+                    </div>
+                }.into_any(),
+                BarMode::ConfirmDelete => view! {
+                    <div class="batch-action-buttons device-clean-confirm">
+                        <p>{move || i18n.tf("batch.confirm_delete", &[("items", &i18n.count(noun, count()))])}</p>
+                        <button class="btn btn-danger btn-sm" on:click=move |_| run(BatchAction::Delete)>
+                            {i18n.t("common.delete")}
+                        </button>
+                        <button class="btn btn-ghost btn-sm" on:click=move |_| set_mode.set(BarMode::Actions)>
+                            {i18n.t("common.cancel")}
+                        </button>
+                    </div>
+                }.into_any(),
+            }}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BatchFailure;
+
+    fn keys() -> Vec<String> {
+        ["a", "b", "c", "d", "e"].map(String::from).to_vec()
+    }
+
+    #[test]
+    fn test_apply_click_toggles() {
+        let keys = keys();
+        let mut selected = BTreeSet::new();
+        let anchor = apply_click(&mut selected, None, &keys, 1, false);
+        assert_eq!(anchor, Some(1));
+        assert!(selected.contains("b"));
+
+        let anchor = apply_click(&mut selected, anchor, &keys, 1, false);
+        assert_eq!(anchor, Some(1));
+        assert!(selected.is_empty());
+
+        // Out of range clicks are ignored
+        assert_eq!(apply_click(&mut selected, anchor, &keys, 9, false), anchor);
+        assert!(selected.is_empty());
+    }
+
+    #[test]
+    fn test_apply_click_shift_selects_range() {
+        let keys = keys();
+        let mut selected = BTreeSet::new();
+        let anchor = apply_click(&mut selected, None, &keys, 3, false);
+        let anchor = apply_click(&mut selected, anchor, &keys, 1, true);
+        assert_eq!(anchor, Some(3));
+        assert_eq!(selected.into_iter().collect::<Vec<_>>(), ["b", "c", "d"]);
+
+        // Without an anchor, shift-click acts like a plain click
+        let mut selected = BTreeSet::new();
+        assert_eq!(apply_click(&mut selected, None, &keys, 2, true), Some(2));
+        assert_eq!(selected.len(), 1);
+    }
+
+    #[test]
+    fn test_batch_summary() {
+        let mut result = BatchResult {
+            succeeded: vec!["a".to_string(), "b".to_string()],
+            failed: vec![],
+        };
+        assert_eq!(
+            batch_summary(Locale::En, "batch.deleted", "count.playlists", &result),
+            "Deleted 2 playlists"
+        );
+
+        result.failed = vec![
+            BatchFailure {
+                item: "c".to_string(),
+                error: "not found".to_string(),
+            },
+            BatchFailure {
+                item: "d".to_string(),
+                error: "busy".to_string(),
+            },
+        ];
+        assert_eq!(
+            batch_summary(Locale::En, "batch.moved", "count.tracks", &result),
+            "Moved 2 of 4 tracks. c: not found (and 1 more)"
+        );
+        assert_eq!(
+            batch_summary(Locale::Fr, "batch.moved", "count.tracks", &result),
+            "Déplacé : 2 sur 4 pistes. c : not found (et 1 de plus)"
+        );
+    }
+}
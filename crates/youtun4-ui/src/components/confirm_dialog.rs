@@ -1,7 +1,11 @@
 //! Confirmation dialog component for destructive actions.
 
+use leptos::html;
 use leptos::prelude::*;
 
+use crate::components::use_dialog_keyboard;
+use crate::i18n::use_i18n;
+
 /// Confirmation dialog component for confirming destructive actions like deletion.
 #[component]
 
@@ -12,12 +16,12 @@ pub fn ConfirmDialog(
     title: String,
     /// Message to display in the dialog.
     message: String,
-    /// Text for the confirm button.
-    #[prop(default = "Delete".to_string())]
-    confirm_text: String,
-    /// Text for the cancel button.
-    #[prop(default = "Cancel".to_string())]
-    cancel_text: String,
+    /// Text for the confirm button, "Delete" when not set.
+    #[prop(optional)]
+    confirm_text: Option<String>,
+    /// Text for the cancel button, "Cancel" when not set.
+    #[prop(optional)]
+    cancel_text: Option<String>,
     /// Whether the confirm button should be styled as dangerous.
     #[prop(default = true)]
     is_dangerous: bool,
@@ -26,11 +30,15 @@ pub fn ConfirmDialog(
     /// Callback when the user cancels or closes the dialog.
     on_cancel: Callback<()>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
+    let dialog_ref = NodeRef::<html::Div>::new();
+    use_dialog_keyboard(is_open.into(), dialog_ref, on_cancel);
+
     // Clone values for the closures
     let title_clone = title;
     let message_clone = message;
-    let confirm_text_clone = confirm_text;
-    let cancel_text_clone = cancel_text;
+    let confirm_text_clone = confirm_text.unwrap_or_else(|| i18n.t("common.delete"));
+    let cancel_text_clone = cancel_text.unwrap_or_else(|| i18n.t("common.cancel"));
 
     view! {
         <div
@@ -40,6 +48,7 @@ pub fn ConfirmDialog(
         >
             <div
                 class="confirm-dialog"
+                node_ref=dialog_ref
                 on:click=move |e| e.stop_propagation()
                 role="alertdialog"
                 aria-modal="true"
@@ -109,6 +118,10 @@ pub fn DeletePlaylistDialog(
     /// Callback when the user cancels.
     on_cancel: Callback<()>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
+    let dialog_ref = NodeRef::<html::Div>::new();
+    use_dialog_keyboard(is_open.into(), dialog_ref, on_cancel);
+
     view! {
         <div
             class="confirm-dialog-overlay"
@@ -117,6 +130,7 @@ pub fn DeletePlaylistDialog(
         >
             <div
                 class="confirm-dialog delete-playlist-dialog"
+                node_ref=dialog_ref
                 on:click=move |e| e.stop_propagation()
                 role="alertdialog"
                 aria-modal="true"
@@ -129,33 +143,28 @@ pub fn DeletePlaylistDialog(
                     </svg>
                 </div>
                 <h3 id="delete-dialog-title" class="confirm-dialog-title">
-                    "Delete Playlist"
+                    {move || i18n.t("delete_playlist.title")}
                 </h3>
                 <p id="delete-dialog-message" class="confirm-dialog-message">
                     {move || {
                         let name = playlist_name.get().unwrap_or_default();
-                        format!(
-                            "Are you sure you want to delete \"{name}\"?"
-                        )
+                        i18n.tf("delete_playlist.confirm", &[("name", &name)])
                     }}
                 </p>
 
                 // Playlist details section
                 <div class="delete-dialog-details">
                     <div class="delete-dialog-detail-row">
-                        <span class="detail-label">"Tracks:"</span>
+                        <span class="detail-label">{move || i18n.t("delete_playlist.tracks")}</span>
                         <span class="detail-value">
-                            {move || {
-                                let tracks = track_count.get().unwrap_or(0);
-                                format!("{} track{}", tracks, if tracks == 1 { "" } else { "s" })
-                            }}
+                            {move || i18n.count("count.tracks", track_count.get().unwrap_or(0))}
                         </span>
                     </div>
                     {move || {
                         total_bytes.map(|tb| {
                             view! {
                                 <div class="delete-dialog-detail-row">
-                                    <span class="detail-label">"Size:"</span>
+                                    <span class="detail-label">{move || i18n.t("delete_playlist.size")}</span>
                                     <span class="detail-value">
                                         {move || format_bytes(tb.get().unwrap_or(0))}
                                     </span>
@@ -168,12 +177,12 @@ pub fn DeletePlaylistDialog(
                             su.get().map(|url| {
                                 view! {
                                     <div class="delete-dialog-detail-row source-row">
-                                        <span class="detail-label">"Source:"</span>
+                                        <span class="detail-label">{i18n.t("delete_playlist.source")}</span>
                                         <span class="detail-value source-url" title=url>
                                             <svg viewBox="0 0 24 24" width="14" height="14" fill="currentColor" class="youtube-icon">
                                                 <path d="M21.582 7.186c-.23-.869-.908-1.553-1.775-1.784C18.254 5 12 5 12 5s-6.254 0-7.807.402c-.867.23-1.545.915-1.775 1.784C2 8.746 2 12 2 12s0 3.254.418 4.814c.23.869.908 1.553 1.775 1.784C5.746 19 12 19 12 19s6.254 0 7.807-.402c.867-.23 1.545-.915 1.775-1.784C22 15.254 22 12 22 12s0-3.254-.418-4.814zM10 15V9l5.196 3L10 15z"/>
                                             </svg>
-                                            "YouTube Playlist"
+                                            {i18n.t("delete_playlist.youtube")}
                                         </span>
                                     </div>
                                 }
@@ -186,14 +195,14 @@ pub fn DeletePlaylistDialog(
                     <svg viewBox="0 0 24 24" width="16" height="16" fill="currentColor">
                         <path d="M1 21h22L12 2 1 21zm12-3h-2v-2h2v2zm0-4h-2v-4h2v4z"/>
                     </svg>
-                    <span>"This action cannot be undone. All tracks and files will be permanently deleted from your local storage."</span>
+                    <span>{move || i18n.t("delete_playlist.warning")}</span>
                 </div>
                 <div class="confirm-dialog-actions">
                     <button
                         class="btn btn-secondary"
                         on:click=move |_| on_cancel.run(())
                     >
-                        "Cancel"
+                        {move || i18n.t("common.cancel")}
                     </button>
                     <button
                         class="btn btn-danger-solid"
@@ -202,7 +211,96 @@ pub fn DeletePlaylistDialog(
                         <svg viewBox="0 0 24 24" width="18" height="18" fill="currentColor">
                             <path d="M6 19c0 1.1.9 2 2 2h8c1.1 0 2-.9 2-2V7H6v12zM19 4h-3.5l-1-1h-5l-1 1H5v2h14V4z"/>
                         </svg>
-                        "Delete Playlist"
+                        {move || i18n.t("delete_playlist.title")}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Dialog asking for a new name for a playlist.
+#[component]
+
+pub fn RenamePlaylistDialog(
+    /// Whether the dialog is open.
+    is_open: ReadSignal<bool>,
+    /// Current name of the playlist being renamed.
+    playlist_name: ReadSignal<Option<String>>,
+    /// Callback with the new name when the user confirms.
+    on_confirm: Callback<String>,
+    /// Callback when the user cancels.
+    on_cancel: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let dialog_ref = NodeRef::<html::Div>::new();
+    use_dialog_keyboard(is_open.into(), dialog_ref, on_cancel);
+
+    let (new_name, set_new_name) = signal(String::new());
+
+    // Start from the current name each time the dialog opens
+    Effect::new(move || {
+        if is_open.get() {
+            set_new_name.set(playlist_name.get_untracked().unwrap_or_default());
+        }
+    });
+
+    let can_confirm = move || {
+        let name = new_name.get();
+        let name = name.trim();
+        !name.is_empty() && playlist_name.get().as_deref() != Some(name)
+    };
+    let confirm = move || {
+        if can_confirm() {
+            on_confirm.run(new_name.get_untracked().trim().to_string());
+        }
+    };
+
+    view! {
+        <div
+            class="confirm-dialog-overlay"
+            class:visible=move || is_open.get()
+            on:click=move |_| on_cancel.run(())
+        >
+            <div
+                class="confirm-dialog rename-playlist-dialog"
+                node_ref=dialog_ref
+                on:click=move |e| e.stop_propagation()
+                role="dialog"
+                aria-modal="true"
+                aria-labelledby="rename-dialog-title"
+            >
+                <h3 id="rename-dialog-title" class="confirm-dialog-title">
+                    {move || i18n.t("rename_playlist.title")}
+                </h3>
+                <label class="rename-dialog-field">
+                    <span>{move || i18n.t("rename_playlist.label")}</span>
+                    <input
+                        type="text"
+                        class="settings-input"
+                        prop:value=new_name
+                        on:input=move |ev| set_new_name.set(event_target_value(&ev))
+                        on:keydown=move |ev: web_sys::KeyboardEvent| {
+                            if ev.key() == "Enter" {
+                                ev.prevent_default();
+                                confirm();
+                            }
+                        }
+                    />
+                </label>
+                <div class="confirm-dialog-actions">
+                    <button
+                        class="btn btn-secondary"
+                        on:click=move |_| on_cancel.run(())
+                    >
+                        {move || i18n.t("common.cancel")}
+                    </button>
+                    <button
+                        class="btn btn-primary"
+                        disabled=move || !can_confirm()
+                        on:click=move |_| confirm()
+                    >
+                        {move || i18n.t("rename_playlist.confirm")}
                     </button>
                 </div>
             </div>
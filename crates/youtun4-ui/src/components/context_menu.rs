@@ -0,0 +1,226 @@
+//! Right-click and long-press context menus.
+
+use std::time::Duration;
+
+use leptos::ev;
+use leptos::html;
+use leptos::portal::Portal;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::i18n::use_i18n;
+
+/// How long a touch must be held to open the menu.
+const LONG_PRESS: Duration = Duration::from_millis(500);
+/// Approximate menu size, used to keep it inside the window.
+const MENU_WIDTH: i32 = 220;
+const ITEM_HEIGHT: i32 = 36;
+
+/// One entry of a [`ContextMenu`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ContextMenuItem<A> {
+    /// Action passed to `on_action` when the entry is chosen.
+    pub action: A,
+    /// Message key of the text shown for the entry.
+    pub label: &'static str,
+    /// Whether the entry is destructive and shown in the danger color.
+    pub danger: bool,
+}
+
+impl<A> ContextMenuItem<A> {
+    /// Create an entry.
+    #[must_use]
+    pub const fn new(action: A, label: &'static str) -> Self {
+        Self {
+            action,
+            label,
+            danger: false,
+        }
+    }
+
+    /// Mark the entry as destructive.
+    #[must_use]
+    pub const fn danger(mut self) -> Self {
+        self.danger = true;
+        self
+    }
+}
+
+/// Shift a menu opened at `(x, y)` so it fits in a `viewport` of the given
+/// size.
+fn clamp_position(x: i32, y: i32, items: usize, viewport: (i32, i32)) -> (i32, i32) {
+    let height = ITEM_HEIGHT.saturating_mul(i32::try_from(items).unwrap_or(i32::MAX));
+    let x = x.min(viewport.0 - MENU_WIDTH).max(0);
+    let y = y.min(viewport.1 - height).max(0);
+    (x, y)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn viewport_size() -> (i32, i32) {
+    let window = window();
+    let dimension = |value: Result<wasm_bindgen::JsValue, _>| {
+        value
+            .ok()
+            .and_then(|v| v.as_f64())
+            .map_or(i32::MAX, |v| v as i32)
+    };
+    (
+        dimension(window.inner_width()),
+        dimension(window.inner_height()),
+    )
+}
+
+/// Wraps content and opens a menu of actions for it on right-click, or on a
+/// long press on touch screens.
+///
+/// The menu closes when an entry is chosen, on Escape, or when clicking
+/// anywhere else.
+#[component]
+pub fn ContextMenu<A>(
+    /// Entries of the menu, in order.
+    items: Vec<ContextMenuItem<A>>,
+    /// Callback with the chosen entry's action.
+    on_action: Callback<A>,
+    /// Message key of the accessible name of the menu.
+    #[prop(default = "context_menu.actions")]
+    label: &'static str,
+    /// Content the menu is attached to.
+    children: Children,
+) -> impl IntoView
+where
+    A: Copy + Send + Sync + 'static,
+{
+    let i18n = use_i18n();
+    let position = RwSignal::new(None::<(i32, i32)>);
+    let long_press = StoredValue::new(None::<TimeoutHandle>);
+    let item_count = items.len();
+    let items = StoredValue::new(items);
+    let menu_ref = NodeRef::<html::Ul>::new();
+
+    let open_at = move |x: i32, y: i32| {
+        position.set(Some(clamp_position(x, y, item_count, viewport_size())));
+    };
+    let close = move || position.set(None);
+    let cancel_long_press = move || {
+        if let Some(handle) = long_press.get_value() {
+            handle.clear();
+        }
+        long_press.set_value(None);
+    };
+
+    // Focus the first entry so the menu can be used from the keyboard
+    Effect::new(move |_| {
+        if position.get().is_some() {
+            request_animation_frame(move || {
+                let first = menu_ref
+                    .get_untracked()
+                    .and_then(|menu| menu.query_selector("button").ok().flatten())
+                    .and_then(|button| button.dyn_into::<web_sys::HtmlElement>().ok());
+                if let Some(button) = first {
+                    let _ = button.focus();
+                }
+            });
+        }
+    });
+
+    let handle = window_event_listener(ev::keydown, move |e| {
+        if position.get_untracked().is_some() && e.key() == "Escape" {
+            e.prevent_default();
+            close();
+        }
+    });
+    on_cleanup(move || {
+        handle.remove();
+        cancel_long_press();
+    });
+
+    view! {
+        <div
+            class="context-menu-area"
+            on:contextmenu=move |e: web_sys::MouseEvent| {
+                e.prevent_default();
+                e.stop_propagation();
+                open_at(e.client_x(), e.client_y());
+            }
+            on:touchstart=move |e: web_sys::TouchEvent| {
+                cancel_long_press();
+                let Some(touch) = e.touches().get(0) else {
+                    return;
+                };
+                let (x, y) = (touch.client_x(), touch.client_y());
+                let handle = set_timeout_with_handle(
+                    move || {
+                        long_press.set_value(None);
+                        open_at(x, y);
+                    },
+                    LONG_PRESS,
+                )
+                .ok();
+                long_press.set_value(handle);
+            }
+            on:touchmove=move |_| cancel_long_press()
+            on:touchend=move |_| cancel_long_press()
+            on:touchcancel=move |_| cancel_long_press()
+        >
+            {children()}
+        </div>
+        <Show when=move || position.get().is_some()>
+            <Portal>
+                <div
+                    class="context-menu-backdrop"
+                    on:click=move |_| close()
+                    on:contextmenu=move |e: web_sys::MouseEvent| {
+                        e.prevent_default();
+                        close();
+                    }
+                ></div>
+                <ul
+                    class="context-menu"
+                    role="menu"
+                    aria-label=move || i18n.t(label)
+                    node_ref=menu_ref
+                    style=move || {
+                        let (x, y) = position.get().unwrap_or_default();
+                        format!("left: {x}px; top: {y}px")
+                    }
+                >
+                    {move || items.with_value(|items| items.iter().map(|item| {
+                        let action = item.action;
+                        view! {
+                            <li role="none">
+                                <button
+                                    class="context-menu-item"
+                                    class:danger=item.danger
+                                    role="menuitem"
+                                    on:click=move |e: web_sys::MouseEvent| {
+                                        e.stop_propagation();
+                                        close();
+                                        on_action.run(action);
+                                    }
+                                >
+                                    {i18n.t(item.label)}
+                                </button>
+                            </li>
+                        }
+                    }).collect_view())}
+                </ul>
+            </Portal>
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_position_inside_viewport() {
+        assert_eq!(clamp_position(10, 20, 3, (1000, 800)), (10, 20));
+    }
+
+    #[test]
+    fn test_clamp_position_near_edges() {
+        assert_eq!(clamp_position(950, 790, 2, (1000, 800)), (780, 728));
+        assert_eq!(clamp_position(5, 5, 2, (100, 50)), (0, 0));
+    }
+}
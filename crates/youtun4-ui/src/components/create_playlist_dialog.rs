@@ -1,10 +1,16 @@
 //! Dialog component for creating new playlists from `YouTube` URLs.
 
+use leptos::html;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
+use crate::components::use_dialog_keyboard;
+use crate::i18n::{translate, use_i18n};
 use crate::tauri_api;
-use crate::types::{YouTubeUrlType, YouTubeUrlValidation};
+use crate::types::{
+    Locale, MAX_MIX_VIDEOS, SearchFilter, SearchResult, SearchResultKind, YouTubeUrlType,
+    YouTubeUrlValidation,
+};
 
 /// State of the URL validation process.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -20,6 +26,39 @@ pub enum UrlValidationState {
     Invalid,
 }
 
+/// Why `name` can't be used as a playlist name, in `locale`, or `None` if
+/// it can.
+#[must_use]
+pub fn validate_playlist_name(locale: Locale, name: &str) -> Option<String> {
+    if name.trim().is_empty() {
+        return Some(translate(locale, "create_playlist.name_required", &[]));
+    }
+    if name.len() > 255 {
+        return Some(translate(locale, "create_playlist.name_too_long", &[]));
+    }
+    // Check for invalid filesystem characters
+    let invalid_chars = ['/', '\\', ':', '*', '?', '"', '<', '>', '|', '\0'];
+    for c in invalid_chars {
+        if name.contains(c) {
+            return Some(translate(
+                locale,
+                "create_playlist.name_invalid_char",
+                &[("char", &c.to_string())],
+            ));
+        }
+    }
+    // Check for reserved Windows names
+    let reserved_names = [
+        "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+        "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+    ];
+    let upper_name = name.trim().to_uppercase();
+    if reserved_names.contains(&upper_name.as_str()) {
+        return Some(translate(locale, "create_playlist.name_reserved", &[]));
+    }
+    None
+}
+
 /// Dialog component for creating new playlists from YouTube URLs.
 #[component]
 
@@ -31,6 +70,8 @@ pub fn CreatePlaylistDialog(
     /// Callback when the dialog is closed (cancelled or after creation).
     on_close: Callback<()>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
+
     // Form state
     let (url_input, set_url_input) = signal(String::new());
     let (name_input, set_name_input) = signal(String::new());
@@ -45,6 +86,13 @@ pub fn CreatePlaylistDialog(
     let (is_creating, set_is_creating) = signal(false);
     let (create_error, set_create_error) = signal::<Option<String>>(None);
 
+    // YouTube search state
+    let (search_query, set_search_query) = signal(String::new());
+    let (search_filter, set_search_filter) = signal(SearchFilter::All);
+    let (search_results, set_search_results) = signal::<Option<Vec<SearchResult>>>(None);
+    let (is_searching, set_is_searching) = signal(false);
+    let (search_error, set_search_error) = signal::<Option<String>>(None);
+
     // Reset form when dialog opens
     Effect::new(move || {
         if is_open.get() {
@@ -56,6 +104,9 @@ pub fn CreatePlaylistDialog(
             set_name_error.set(None);
             set_is_creating.set(false);
             set_create_error.set(None);
+            set_search_query.set(String::new());
+            set_search_results.set(None);
+            set_search_error.set(None);
         }
     });
 
@@ -85,8 +136,11 @@ pub fn CreatePlaylistDialog(
                     set_url_validation.set(YouTubeUrlValidation {
                         is_valid: false,
                         playlist_id: None,
+                        video_id: None,
                         normalized_url: None,
-                        error_message: Some(format!("Validation error: {e}")),
+                        error_message: Some(
+                            i18n.tf("create_playlist.validation_error", &[("error", &e)]),
+                        ),
                         url_type: YouTubeUrlType::Invalid,
                     });
                 }
@@ -94,33 +148,6 @@ pub fn CreatePlaylistDialog(
         });
     };
 
-    // Name validation
-    let validate_name = move |name: &str| -> Option<String> {
-        if name.trim().is_empty() {
-            return Some("Playlist name is required".to_string());
-        }
-        if name.len() > 255 {
-            return Some("Name must be 255 characters or less".to_string());
-        }
-        // Check for invalid filesystem characters
-        let invalid_chars = ['/', '\\', ':', '*', '?', '"', '<', '>', '|', '\0'];
-        for c in invalid_chars {
-            if name.contains(c) {
-                return Some(format!("Name cannot contain '{c}'"));
-            }
-        }
-        // Check for reserved Windows names
-        let reserved_names = [
-            "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7",
-            "COM8", "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
-        ];
-        let upper_name = name.trim().to_uppercase();
-        if reserved_names.contains(&upper_name.as_str()) {
-            return Some("This name is reserved by the system".to_string());
-        }
-        None
-    };
-
     // Handle URL input change
     let on_url_change = move |ev: web_sys::Event| {
         let value = event_target_value(&ev);
@@ -129,6 +156,57 @@ pub fn CreatePlaylistDialog(
         validate_url(value);
     };
 
+    // Search YouTube for the query
+    let run_search = move || {
+        let query = search_query.get().trim().to_string();
+        if query.is_empty() || is_searching.get() {
+            return;
+        }
+        set_is_searching.set(true);
+        set_search_error.set(None);
+        let filter = search_filter.get();
+        spawn_local(async move {
+            match tauri_api::search_youtube(&query, filter).await {
+                Ok(results) => set_search_results.set(Some(results)),
+                Err(e) => {
+                    leptos::logging::error!("YouTube search error: {}", e);
+                    set_search_results.set(None);
+                    set_search_error.set(Some(e));
+                }
+            }
+            set_is_searching.set(false);
+        });
+    };
+
+    let on_search_keydown = move |ev: web_sys::KeyboardEvent| {
+        if ev.key() == "Enter" {
+            ev.prevent_default();
+            run_search();
+        }
+    };
+
+    let on_filter_change = move |ev: web_sys::Event| {
+        let filter = match event_target_value(&ev).as_str() {
+            "videos" => SearchFilter::Videos,
+            "playlists" => SearchFilter::Playlists,
+            _ => SearchFilter::All,
+        };
+        set_search_filter.set(filter);
+    };
+
+    // Use a search result as the playlist's source, named after it unless
+    // a name was already entered
+    let select_result = move |result: SearchResult| {
+        let url = result.url();
+        set_url_input.set(url.clone());
+        set_create_error.set(None);
+        validate_url(url);
+        if name_input.get_untracked().trim().is_empty() {
+            set_name_error.set(validate_playlist_name(i18n.locale(), &result.title));
+            set_name_input.set(result.title);
+        }
+    };
+
     // Handle name input change
     let on_name_change = move |ev: web_sys::Event| {
         let value = event_target_value(&ev);
@@ -136,20 +214,20 @@ pub fn CreatePlaylistDialog(
         set_create_error.set(None);
 
         if name_touched.get() {
-            set_name_error.set(validate_name(&value));
+            set_name_error.set(validate_playlist_name(i18n.locale(), &value));
         }
     };
 
     // Handle name input blur (for validation)
     let on_name_blur = move |_| {
         set_name_touched.set(true);
-        set_name_error.set(validate_name(&name_input.get()));
+        set_name_error.set(validate_playlist_name(i18n.locale(), &name_input.get()));
     };
 
     // Check if form is valid for submission
     let is_form_valid = move || {
         let url_valid = url_validation_state.get() == UrlValidationState::Valid;
-        let name_valid = validate_name(&name_input.get()).is_none();
+        let name_valid = validate_playlist_name(i18n.locale(), &name_input.get()).is_none();
         url_valid && name_valid && !is_creating.get()
     };
 
@@ -227,6 +305,18 @@ pub fn CreatePlaylistDialog(
         }
     };
 
+    // Escape is ignored while the playlist is being created, like the close button
+    let dialog_ref = NodeRef::<html::Div>::new();
+    use_dialog_keyboard(
+        is_open.into(),
+        dialog_ref,
+        Callback::new(move |()| {
+            if !is_creating.get_untracked() {
+                on_close.run(());
+            }
+        }),
+    );
+
     view! {
         <div
             class="create-playlist-dialog-overlay"
@@ -235,6 +325,7 @@ pub fn CreatePlaylistDialog(
         >
             <div
                 class="create-playlist-dialog"
+                node_ref=dialog_ref
                 on:click=move |e| e.stop_propagation()
                 role="dialog"
                 aria-modal="true"
@@ -242,12 +333,12 @@ pub fn CreatePlaylistDialog(
             >
                 // Header
                 <div class="create-playlist-dialog-header">
-                    <h2 id="create-playlist-dialog-title">"New Playlist"</h2>
+                    <h2 id="create-playlist-dialog-title">{i18n.t("create_playlist.title")}</h2>
                     <button
                         class="btn btn-ghost btn-icon"
                         on:click=on_cancel
                         disabled=move || is_creating.get()
-                        aria-label="Close dialog"
+                        aria-label=i18n.t("common.close_dialog")
                     >
                         <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
                             <path d="M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 17.59 19 19 17.59 13.41 12z"/>
@@ -267,9 +358,104 @@ pub fn CreatePlaylistDialog(
                         </div>
                     })}
 
+                    // YouTube Search
+                    <div class="create-playlist-field">
+                        <label for="playlist-search">{i18n.t("create_playlist.search")}</label>
+                        <div class="create-playlist-search">
+                            <input
+                                id="playlist-search"
+                                type="search"
+                                class="create-playlist-input"
+                                prop:value=move || search_query.get()
+                                on:input=move |ev| set_search_query.set(event_target_value(&ev))
+                                on:keydown=on_search_keydown
+                                placeholder=i18n.t("create_playlist.search.placeholder")
+                                disabled=move || is_creating.get()
+                                autocomplete="off"
+                            />
+                            <select
+                                class="create-playlist-search-filter"
+                                on:change=on_filter_change
+                                disabled=move || is_creating.get()
+                                aria-label=i18n.t("create_playlist.search.filter")
+                            >
+                                <option value="all">{i18n.t("create_playlist.search.all")}</option>
+                                <option value="videos">{i18n.t("create_playlist.search.videos")}</option>
+                                <option value="playlists">{i18n.t("playlists.title")}</option>
+                            </select>
+                            <button
+                                class="btn btn-secondary"
+                                on:click=move |_| run_search()
+                                disabled=move || {
+                                    is_creating.get()
+                                        || is_searching.get()
+                                        || search_query.get().trim().is_empty()
+                                }
+                            >
+                                {move || i18n.t(if is_searching.get() { "create_playlist.searching" } else { "create_playlist.search.button" })}
+                            </button>
+                        </div>
+                        {move || search_error.get().map(|err| view! {
+                            <p class="create-playlist-error-text">{err}</p>
+                        })}
+                        {move || search_results.get().map(|results| {
+                            if results.is_empty() {
+                                return view! {
+                                    <p class="create-playlist-hint">{i18n.t("create_playlist.no_results")}</p>
+                                }.into_any();
+                            }
+                            view! {
+                                <ul class="create-playlist-search-results">
+                                    {results.into_iter().map(|result| {
+                                        let selected_url = result.url();
+                                        let is_selected = move || url_input.get() == selected_url;
+                                        let kind = match result.kind {
+                                            SearchResultKind::Video => "create_playlist.result.video",
+                                            SearchResultKind::Playlist => "create_playlist.result.playlist",
+                                        };
+                                        let details = [
+                                            Some(i18n.t(kind)),
+                                            result.channel.clone(),
+                                            result.formatted_length(i18n.locale()),
+                                        ]
+                                        .into_iter()
+                                        .flatten()
+                                        .collect::<Vec<_>>()
+                                        .join(" · ");
+                                        let thumbnail = result.thumbnail_url.clone();
+                                        let title = result.title.clone();
+                                        view! {
+                                            <li>
+                                                <button
+                                                    class="create-playlist-search-result"
+                                                    class:selected=is_selected
+                                                    on:click=move |_| select_result(result.clone())
+                                                    disabled=move || is_creating.get()
+                                                >
+                                                    {thumbnail.map(|src| view! {
+                                                        <img
+                                                            class="create-playlist-search-thumbnail"
+                                                            src=src
+                                                            alt=""
+                                                            loading="lazy"
+                                                        />
+                                                    })}
+                                                    <span class="create-playlist-search-info">
+                                                        <span class="create-playlist-search-title">{title}</span>
+                                                        <span class="create-playlist-search-details">{details}</span>
+                                                    </span>
+                                                </button>
+                                            </li>
+                                        }
+                                    }).collect_view()}
+                                </ul>
+                            }.into_any()
+                        })}
+                    </div>
+
                     // YouTube URL Field
                     <div class="create-playlist-field">
-                        <label for="playlist-url">"YouTube Playlist URL"</label>
+                        <label for="playlist-url">{i18n.t("create_playlist.url")}</label>
                         <div class="create-playlist-input-wrapper">
                             <input
                                 id="playlist-url"
@@ -315,37 +501,46 @@ pub fn CreatePlaylistDialog(
                                     UrlValidationState::Idle => {
                                         view! {
                                             <p class="create-playlist-hint">
-                                                "Paste a YouTube playlist URL to get started"
+                                                {i18n.t("create_playlist.url.hint")}
                                             </p>
                                         }.into_any()
                                     }
                                     UrlValidationState::Validating => {
                                         view! {
                                             <p class="create-playlist-hint validating">
-                                                "Validating URL..."
+                                                {i18n.t("create_playlist.validating")}
                                             </p>
                                         }.into_any()
                                     }
                                     UrlValidationState::Valid => {
+                                        let is_mix = validation.is_mix();
                                         view! {
                                             <div class="create-playlist-validation-success">
                                                 <div class="validation-row">
-                                                    <span class="validation-label">"Type:"</span>
-                                                    <span class="validation-value">{url_type_label(validation.url_type)}</span>
+                                                    <span class="validation-label">{i18n.t("create_playlist.url_type")}</span>
+                                                    <span class="validation-value">{i18n.t(url_type_label_key(validation.url_type))}</span>
                                                 </div>
                                                 {validation.playlist_id.map(|id| view! {
                                                     <div class="validation-row">
-                                                        <span class="validation-label">"Playlist ID:"</span>
+                                                        <span class="validation-label">{i18n.t("create_playlist.playlist_id")}</span>
                                                         <span class="validation-value playlist-id">{id}</span>
                                                     </div>
                                                 })}
+                                                {is_mix.then(|| view! {
+                                                    <p class="create-playlist-warning">
+                                                        {i18n.tf(
+                                                            "create_playlist.mix_warning",
+                                                            &[("count", &MAX_MIX_VIDEOS.to_string())],
+                                                        )}
+                                                    </p>
+                                                })}
                                             </div>
                                         }.into_any()
                                     }
                                     UrlValidationState::Invalid => {
                                         view! {
                                             <p class="create-playlist-error-text">
-                                                {validation.error_message.unwrap_or_else(|| "Invalid URL".to_string())}
+                                                {validation.error_message.unwrap_or_else(|| i18n.t("create_playlist.url_type.invalid"))}
                                             </p>
                                         }.into_any()
                                     }
@@ -356,7 +551,7 @@ pub fn CreatePlaylistDialog(
 
                     // Playlist Name Field
                     <div class="create-playlist-field">
-                        <label for="playlist-name">"Playlist Name"</label>
+                        <label for="playlist-name">{i18n.t("create_playlist.name")}</label>
                         <input
                             id="playlist-name"
                             type="text"
@@ -365,7 +560,7 @@ pub fn CreatePlaylistDialog(
                             prop:value=move || name_input.get()
                             on:input=on_name_change
                             on:blur=on_name_blur
-                            placeholder="Enter a name for your playlist"
+                            placeholder=i18n.t("create_playlist.name.placeholder")
                             disabled=move || is_creating.get()
                             maxlength="255"
                         />
@@ -383,7 +578,7 @@ pub fn CreatePlaylistDialog(
                         on:click=on_cancel
                         disabled=move || is_creating.get()
                     >
-                        "Cancel"
+                        {i18n.t("common.cancel")}
                     </button>
                     <button
                         class="btn btn-primary"
@@ -393,14 +588,16 @@ pub fn CreatePlaylistDialog(
                         {move || if is_creating.get() {
                             view! {
                                 <span class="spinner"></span>
-                                " Creating..."
+                                " "
+                                {i18n.t("create_playlist.creating")}
                             }.into_any()
                         } else {
                             view! {
                                 <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
                                     <path d="M19 13h-6v6h-2v-6H5v-2h6V5h2v6h6v2z"/>
                                 </svg>
-                                " Create Playlist"
+                                " "
+                                {i18n.t("create_playlist.create")}
                             }.into_any()
                         }}
                     </button>
@@ -410,13 +607,17 @@ pub fn CreatePlaylistDialog(
     }
 }
 
-/// Helper function to get a user-friendly label for the URL type.
-const fn url_type_label(url_type: YouTubeUrlType) -> &'static str {
+/// Helper function to get the message key of a user-friendly label for the
+/// URL type.
+const fn url_type_label_key(url_type: YouTubeUrlType) -> &'static str {
     match url_type {
-        YouTubeUrlType::Playlist => "YouTube Playlist",
-        YouTubeUrlType::WatchWithPlaylist => "Video with Playlist",
-        YouTubeUrlType::SingleVideo => "Single Video (no playlist)",
-        YouTubeUrlType::ShortUrl => "Short URL",
-        YouTubeUrlType::Invalid => "Invalid URL",
+        YouTubeUrlType::Playlist => "create_playlist.url_type.playlist",
+        YouTubeUrlType::WatchWithPlaylist => "create_playlist.url_type.watch_with_playlist",
+        YouTubeUrlType::SingleVideo => "create_playlist.url_type.single_video",
+        YouTubeUrlType::ShortUrl => "create_playlist.url_type.short_url",
+        YouTubeUrlType::Channel
+        | YouTubeUrlType::ChannelHandle
+        | YouTubeUrlType::ChannelCustomUrl => "create_playlist.url_type.channel",
+        YouTubeUrlType::Invalid => "create_playlist.url_type.invalid",
     }
 }
@@ -0,0 +1,268 @@
+//! Banners announcing connected and disconnected devices, with quick actions
+//! for a device that was just plugged in.
+
+use std::time::Duration;
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::use_notifications;
+use crate::i18n::use_i18n;
+use crate::tauri_api;
+use crate::types::{DeviceInfo, DeviceProfile};
+
+/// How long a disconnection stays announced.
+const DISCONNECTED_BANNER_DURATION: Duration = Duration::from_secs(6);
+
+/// What a banner announces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeviceBannerKind {
+    /// The device was plugged in.
+    Connected,
+    /// The device was removed.
+    Disconnected,
+}
+
+/// A banner about one device.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeviceBannerEntry {
+    /// Identifies the banner, so a timeout only hides the banner it was
+    /// started for.
+    pub id: u64,
+    /// What the banner announces.
+    pub kind: DeviceBannerKind,
+    /// The device.
+    pub device: DeviceInfo,
+    /// Name shown for the device: its nickname, or the volume label.
+    pub name: String,
+    /// Whether the device's last playlists are being synced again
+    /// automatically.
+    pub auto_sync: bool,
+}
+
+/// Show `entry`, replacing any banner about the same device.
+fn show_banner(banners: &mut Vec<DeviceBannerEntry>, entry: DeviceBannerEntry) {
+    banners.retain(|banner| banner.device.mount_point != entry.device.mount_point);
+    banners.push(entry);
+}
+
+/// Banner for `device`, or `None` if the device is ignored.
+async fn banner_for(
+    device: DeviceInfo,
+    kind: DeviceBannerKind,
+    id: u64,
+) -> Option<DeviceBannerEntry> {
+    let profile = match tauri_api::get_device_profile(&device.mount_point).await {
+        Ok(profile) => profile,
+        Err(e) => {
+            leptos::logging::warn!("Failed to load device profile: {}", e);
+            DeviceProfile::default()
+        }
+    };
+    if profile.ignored {
+        return None;
+    }
+    Some(DeviceBannerEntry {
+        id,
+        kind,
+        name: profile.nickname.unwrap_or_else(|| device.name.clone()),
+        auto_sync: profile.auto_sync,
+        device,
+    })
+}
+
+/// Banners shown when devices are connected or disconnected.
+///
+/// A connected device gets actions to sync it, clean it, or ignore it from
+/// now on. When its profile re-syncs it automatically the banner says so
+/// instead of offering to sync. Ignored devices get no banner.
+#[component]
+pub fn DeviceBanner(
+    /// Callback to sync to the device.
+    on_sync: Callback<DeviceInfo>,
+    /// Callback to clean the device.
+    on_clean: Callback<DeviceInfo>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let notifications = use_notifications();
+
+    let banners = RwSignal::new(Vec::<DeviceBannerEntry>::new());
+    let next_id = StoredValue::new(0_u64);
+    let new_id = move || {
+        next_id.update_value(|id| *id += 1);
+        next_id.get_value()
+    };
+    let dismiss = move |id: u64| banners.update(|list| list.retain(|banner| banner.id != id));
+
+    spawn_local(async move {
+        if let Err(e) = tauri_api::listen_to_device_connected(move |device| {
+            let id = new_id();
+            spawn_local(async move {
+                if let Some(entry) = banner_for(device, DeviceBannerKind::Connected, id).await {
+                    banners.update(|list| show_banner(list, entry));
+                }
+            });
+        })
+        .await
+        {
+            leptos::logging::error!("Failed to listen for device-connected events: {}", e);
+        }
+
+        if let Err(e) = tauri_api::listen_to_device_disconnected(move |device| {
+            let id = new_id();
+            spawn_local(async move {
+                if let Some(entry) = banner_for(device, DeviceBannerKind::Disconnected, id).await {
+                    banners.update(|list| show_banner(list, entry));
+                    set_timeout(move || dismiss(id), DISCONNECTED_BANNER_DURATION);
+                }
+            });
+        })
+        .await
+        {
+            leptos::logging::error!("Failed to listen for device-disconnected events: {}", e);
+        }
+    });
+
+    let ignore = move |entry: DeviceBannerEntry| {
+        dismiss(entry.id);
+        spawn_local(async move {
+            let mount_point = entry.device.mount_point;
+            let result = match tauri_api::get_device_profile(&mount_point).await {
+                Ok(profile) => {
+                    let profile = DeviceProfile {
+                        ignored: true,
+                        ..profile
+                    };
+                    tauri_api::set_device_profile(&mount_point, &profile).await
+                }
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(()) => notifications.info(i18n.tf("banner.ignored", &[("device", &entry.name)])),
+                Err(e) => notifications.error(i18n.tf("banner.ignore_failed", &[("error", &e)])),
+            }
+        });
+    };
+
+    view! {
+        <div class="device-banners" aria-live="polite">
+            <For
+                each=move || banners.get()
+                key=|banner| banner.id
+                children=move |banner| {
+                    let id = banner.id;
+                    let connected = banner.kind == DeviceBannerKind::Connected;
+                    let auto_sync = banner.auto_sync;
+                    let name = banner.name.clone();
+                    let title = move || {
+                        let key = if connected {
+                            "banner.device_connected"
+                        } else {
+                            "banner.device_disconnected"
+                        };
+                        i18n.tf(key, &[("device", &name)])
+                    };
+                    let actions = connected.then(|| {
+                        let sync_device = banner.device.clone();
+                        let clean_device = banner.device.clone();
+                        view! {
+                            {(!auto_sync).then(|| view! {
+                                <button
+                                    class="btn btn-primary btn-sm"
+                                    on:click=move |_| {
+                                        dismiss(id);
+                                        on_sync.run(sync_device.clone());
+                                    }
+                                >
+                                    {move || i18n.t("banner.sync_now")}
+                                </button>
+                            })}
+                            <button
+                                class="btn btn-secondary btn-sm"
+                                on:click=move |_| {
+                                    dismiss(id);
+                                    on_clean.run(clean_device.clone());
+                                }
+                            >
+                                {move || i18n.t("banner.clean")}
+                            </button>
+                            <button
+                                class="btn btn-ghost btn-sm"
+                                on:click=move |_| ignore(banner.clone())
+                            >
+                                {move || i18n.t("banner.ignore")}
+                            </button>
+                        }
+                    });
+                    view! {
+                        <div class="device-banner" class:disconnected=!connected role="status">
+                            <svg class="device-banner-icon" viewBox="0 0 24 24" width="20" height="20" fill="currentColor" aria-hidden="true">
+                                <path d="M15 7v4h1v2h-3V5h2l-3-4-3 4h2v8H8v-2.07c.7-.37 1.2-1.08 1.2-1.93 0-1.21-.99-2.2-2.2-2.2-1.21 0-2.2.99-2.2 2.2 0 .85.5 1.56 1.2 1.93V13c0 1.11.89 2 2 2h3v3.05c-.71.37-1.2 1.1-1.2 1.95 0 1.22.99 2.2 2.2 2.2 1.21 0 2.2-.98 2.2-2.2 0-.85-.49-1.58-1.2-1.95V15h3c1.11 0 2-.89 2-2v-2h1V7h-4z"/>
+                            </svg>
+                            <div class="device-banner-text">
+                                <strong>{title}</strong>
+                                {(connected && auto_sync).then(|| view! {
+                                    <span class="device-banner-detail">
+                                        {move || i18n.t("banner.auto_syncing")}
+                                    </span>
+                                })}
+                            </div>
+                            <div class="device-banner-actions">
+                                {actions}
+                                <button
+                                    class="btn btn-ghost btn-icon"
+                                    title=move || i18n.t("banner.dismiss")
+                                    aria-label=move || i18n.t("banner.dismiss")
+                                    on:click=move |_| dismiss(id)
+                                >
+                                    <svg viewBox="0 0 24 24" width="18" height="18" fill="currentColor">
+                                        <path d="M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 17.59 19 19 17.59 13.41 12z"/>
+                                    </svg>
+                                </button>
+                            </div>
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: u64, mount_point: &str, kind: DeviceBannerKind) -> DeviceBannerEntry {
+        DeviceBannerEntry {
+            id,
+            kind,
+            device: DeviceInfo {
+                name: "PLAYER".to_string(),
+                mount_point: mount_point.to_string(),
+                total_bytes: 0,
+                available_bytes: 0,
+                file_system: "vfat".to_string(),
+                is_removable: true,
+            },
+            name: "PLAYER".to_string(),
+            auto_sync: false,
+        }
+    }
+
+    #[test]
+    fn test_show_banner_replaces_same_device() {
+        let mut banners = vec![entry(1, "/media/a", DeviceBannerKind::Connected)];
+        show_banner(
+            &mut banners,
+            entry(2, "/media/b", DeviceBannerKind::Connected),
+        );
+        show_banner(
+            &mut banners,
+            entry(3, "/media/a", DeviceBannerKind::Disconnected),
+        );
+
+        let ids: Vec<_> = banners.iter().map(|b| b.id).collect();
+        assert_eq!(ids, vec![2, 3]);
+        assert_eq!(banners[1].kind, DeviceBannerKind::Disconnected);
+    }
+}
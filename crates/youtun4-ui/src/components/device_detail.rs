@@ -0,0 +1,617 @@
+//! Device dashboard showing how a device's space is used, its last sync and
+//! its settings, with device actions.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::use_notifications;
+use crate::i18n::{translate, translate_count, use_i18n};
+use crate::tauri_api;
+use crate::types::{DeviceInfo, DeviceProfile, DeviceUsage, Locale, SyncChange, SyncPreview};
+
+/// Usage above which a device is reported as almost full.
+const ALMOST_FULL_PERCENT: f64 = 95.0;
+
+/// Format bytes to human-readable string.
+fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+/// Format Unix timestamp as a local date and time.
+fn format_date_time(timestamp: u64) -> String {
+    let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(timestamp as f64 * 1000.0));
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02}",
+        date.get_full_year(),
+        date.get_month() + 1,
+        date.get_date(),
+        date.get_hours(),
+        date.get_minutes()
+    )
+}
+
+/// Shares of a device's capacity (0.0 - 100.0) for the usage bar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UsageSegments {
+    /// Audio files.
+    pub music: f64,
+    /// Other visible files.
+    pub other: f64,
+    /// Hidden and system files and file system overhead.
+    pub system: f64,
+}
+
+impl UsageSegments {
+    /// Split the capacity of a device by usage.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(usage: &DeviceUsage) -> Self {
+        if usage.total_bytes == 0 {
+            return Self {
+                music: 0.0,
+                other: 0.0,
+                system: 0.0,
+            };
+        }
+        let percent = |bytes: u64| bytes as f64 / usage.total_bytes as f64 * 100.0;
+        Self {
+            music: percent(usage.music_bytes),
+            other: percent(usage.other_bytes),
+            system: percent(usage.system_bytes),
+        }
+    }
+}
+
+/// Problems worth pointing out on the dashboard, most important first.
+#[must_use]
+pub fn health_warnings(locale: Locale, usage: &DeviceUsage) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if usage.usage_percentage() >= ALMOST_FULL_PERCENT {
+        warnings.push(translate(locale, "device_detail.almost_full", &[]));
+    }
+    if let Some(record) = &usage.last_sync
+        && record.files_failed > 0
+    {
+        warnings.push(translate_count(
+            locale,
+            "device_detail.files_failed",
+            record.files_failed,
+        ));
+    }
+    if usage.unreadable_entries > 0 {
+        warnings.push(translate_count(
+            locale,
+            "device_detail.unreadable",
+            usage.unreadable_entries,
+        ));
+    }
+    warnings
+}
+
+/// How the tracks on a device compare with the library.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VerifyOutcome {
+    /// Tracks on the device matching the library.
+    pub matching: usize,
+    /// Tracks missing from the device.
+    pub missing: usize,
+    /// Tracks on the device that differ from the library.
+    pub different: usize,
+}
+
+impl VerifyOutcome {
+    /// Read the outcome from a preview of re-syncing the device's playlists
+    /// without cleanup.
+    #[must_use]
+    pub fn from_preview(preview: &SyncPreview) -> Self {
+        Self {
+            matching: preview.count(SyncChange::Unchanged),
+            missing: preview.count(SyncChange::Add),
+            different: preview.count(SyncChange::Replace),
+        }
+    }
+
+    /// Whether every track is on the device and matches.
+    #[must_use]
+    pub const fn is_ok(&self) -> bool {
+        self.missing == 0 && self.different == 0
+    }
+}
+
+/// Dashboard for a connected device.
+///
+/// Verifying compares the device with the playlists of its last sync; no
+/// files are changed.
+#[component]
+
+pub fn DeviceDetailView(
+    /// Mount point of the device.
+    mount_point: String,
+    /// Connected devices, to follow the device's details and disconnection.
+    devices: ReadSignal<Vec<DeviceInfo>>,
+    /// Callback when the back button is clicked.
+    on_back: Callback<()>,
+    /// Callback to start syncing to the device.
+    on_sync: Callback<()>,
+    /// Callback when the device has been ejected, with its mount point.
+    on_eject: Callback<String>,
+    /// Open with the clean confirmation already shown.
+    #[prop(optional)]
+    confirm_clean: bool,
+) -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+
+    let device = {
+        let mount_point = mount_point.clone();
+        Memo::new(move |_| {
+            devices.with(|list| list.iter().find(|d| d.mount_point == mount_point).cloned())
+        })
+    };
+
+    let (usage, set_usage) = signal::<Option<DeviceUsage>>(None);
+    let (usage_error, set_usage_error) = signal::<Option<String>>(None);
+    let (profile, set_profile) = signal(DeviceProfile::default());
+    let (nickname, set_nickname) = signal(String::new());
+    let (busy, set_busy) = signal::<Option<&'static str>>(None);
+    let (confirm_clean, set_confirm_clean) = signal(confirm_clean);
+    let (verify_outcome, set_verify_outcome) = signal::<Option<VerifyOutcome>>(None);
+
+    let load_usage = {
+        let mount_point = mount_point.clone();
+        move || {
+            let mount_point = mount_point.clone();
+            set_usage_error.set(None);
+            spawn_local(async move {
+                match tauri_api::analyze_device_usage(&mount_point).await {
+                    Ok(u) => set_usage.set(Some(u)),
+                    Err(e) => set_usage_error.set(Some(e)),
+                }
+            });
+        }
+    };
+    load_usage();
+
+    {
+        let mount_point = mount_point.clone();
+        spawn_local(async move {
+            match tauri_api::get_device_profile(&mount_point).await {
+                Ok(p) => {
+                    set_nickname.set(p.nickname.clone().unwrap_or_default());
+                    set_profile.set(p);
+                }
+                Err(e) => leptos::logging::error!("Failed to load device profile: {}", e),
+            }
+        });
+    }
+
+    let save_profile = {
+        let mount_point = mount_point.clone();
+        move |_| {
+            let mount_point = mount_point.clone();
+            let new_profile = DeviceProfile {
+                nickname: Some(nickname.get_untracked().trim().to_string())
+                    .filter(|n| !n.is_empty()),
+                ..profile.get_untracked()
+            };
+            spawn_local(async move {
+                match tauri_api::set_device_profile(&mount_point, &new_profile).await {
+                    Ok(()) => {
+                        set_profile.set(new_profile);
+                        notifications.success(i18n.t("device_detail.settings_saved"));
+                    }
+                    Err(e) => notifications
+                        .error(i18n.tf("device_detail.settings_save_failed", &[("error", &e)])),
+                }
+            });
+        }
+    };
+
+    let verify = {
+        let mount_point = mount_point.clone();
+        move |_| {
+            let Some(record) =
+                usage.with_untracked(|u| u.as_ref().and_then(|u| u.last_sync.clone()))
+            else {
+                return;
+            };
+            let mount_point = mount_point.clone();
+            set_busy.set(Some("device_detail.verifying"));
+            set_verify_outcome.set(None);
+            spawn_local(async move {
+                match tauri_api::preview_sync(record.playlists, &mount_point, false, true).await {
+                    Ok(preview) => {
+                        set_verify_outcome.set(Some(VerifyOutcome::from_preview(&preview)))
+                    }
+                    Err(e) => notifications
+                        .error(i18n.tf("device_detail.verify_failed", &[("error", &e)])),
+                }
+                set_busy.set(None);
+            });
+        }
+    };
+
+    let clean = {
+        let mount_point = mount_point.clone();
+        let load_usage = load_usage.clone();
+        move |_| {
+            let mount_point = mount_point.clone();
+            let load_usage = load_usage.clone();
+            set_confirm_clean.set(false);
+            set_busy.set(Some("device_detail.cleaning"));
+            spawn_local(async move {
+                match tauri_api::cleanup_device(&mount_point, None).await {
+                    Ok(result) => notifications.success(i18n.tf(
+                        "device_detail.cleaned",
+                        &[
+                            ("files", &i18n.count("count.files", result.files_deleted)),
+                            ("size", &format_bytes(result.bytes_freed)),
+                        ],
+                    )),
+                    Err(e) => {
+                        notifications.error(i18n.tf("device_detail.clean_failed", &[("error", &e)]))
+                    }
+                }
+                set_busy.set(None);
+                set_verify_outcome.set(None);
+                load_usage();
+            });
+        }
+    };
+
+    let eject = {
+        let mount_point = mount_point.clone();
+        move |_| {
+            let mount_point = mount_point.clone();
+            set_busy.set(Some("device_detail.ejecting"));
+            spawn_local(async move {
+                match tauri_api::eject_device(&mount_point).await {
+                    Ok(result) if result.success => on_eject.run(mount_point),
+                    Ok(_) => notifications.error(i18n.t("device_detail.eject_refused")),
+                    Err(e) => {
+                        notifications.error(i18n.tf("device_detail.eject_failed", &[("error", &e)]))
+                    }
+                }
+                set_busy.set(None);
+            });
+        }
+    };
+
+    let title = move || {
+        profile
+            .with(|p| p.nickname.clone())
+            .or_else(|| device.with(|d| d.as_ref().map(|d| d.name.clone())))
+            .unwrap_or_default()
+    };
+
+    let usage_section = move || {
+        if let Some(e) = usage_error.get() {
+            return view! { <p class="device-detail-error">{e}</p> }.into_any();
+        }
+        let Some(u) = usage.get() else {
+            return view! { <p class="device-detail-muted">{i18n.t("device_detail.scanning")}</p> }
+                .into_any();
+        };
+        let segments = UsageSegments::new(&u);
+        let warnings = health_warnings(i18n.locale(), &u);
+        view! {
+            <div
+                class="device-usage-bar"
+                role="img"
+                aria-label=i18n.tf(
+                    "device_detail.usage_label",
+                    &[
+                        ("music", &format!("{:.0}", segments.music)),
+                        ("other", &format!("{:.0}", segments.other)),
+                        ("system", &format!("{:.0}", segments.system)),
+                    ],
+                )
+            >
+                <div class="device-usage-music" style:width=format!("{:.1}%", segments.music)></div>
+                <div class="device-usage-other" style:width=format!("{:.1}%", segments.other)></div>
+                <div class="device-usage-system" style:width=format!("{:.1}%", segments.system)></div>
+            </div>
+            <ul class="device-usage-legend">
+                <li class="music">
+                    {format!(
+                        "{} · {} · {}",
+                        i18n.t("device_detail.music"),
+                        i18n.count("count.files", u.music_files),
+                        format_bytes(u.music_bytes)
+                    )}
+                </li>
+                <li class="other">
+                    {format!(
+                        "{} · {} · {}",
+                        i18n.t("device_detail.other"),
+                        i18n.count("count.files", u.other_files),
+                        format_bytes(u.other_bytes)
+                    )}
+                </li>
+                <li class="system">
+                    {format!("{} · {}", i18n.t("device_detail.system"), format_bytes(u.system_bytes))}
+                </li>
+                <li class="free">
+                    {format!(
+                        "{} · {}",
+                        i18n.t("device_detail.free"),
+                        i18n.tf(
+                            "device_detail.free_of",
+                            &[
+                                ("free", &format_bytes(u.available_bytes)),
+                                ("total", &format_bytes(u.total_bytes)),
+                            ],
+                        )
+                    )}
+                </li>
+            </ul>
+            <div class="device-health" class:ok=warnings.is_empty()>
+                {if warnings.is_empty() {
+                    view! { <p>{i18n.t("device_detail.no_problems")}</p> }.into_any()
+                } else {
+                    warnings.into_iter().map(|w| view! { <p>{w}</p> }).collect_view().into_any()
+                }}
+            </div>
+        }
+        .into_any()
+    };
+
+    let last_sync_section = move || {
+        let Some(record) = usage.with(|u| u.as_ref().and_then(|u| u.last_sync.clone())) else {
+            return view! { <p class="device-detail-muted">{i18n.t("device_detail.not_synced")}</p> }
+                .into_any();
+        };
+        view! {
+            <p>{format_date_time(record.synced_at)}</p>
+            <p class="device-detail-muted">
+                {format!(
+                    "{} · {}",
+                    i18n.count("device_detail.files_copied", record.files_transferred),
+                    format_bytes(record.bytes_transferred)
+                )}
+            </p>
+            <ul class="device-last-sync-playlists">
+                {record.playlists.into_iter().map(|p| view! { <li>{p}</li> }).collect_view()}
+            </ul>
+            {move || verify_outcome.get().map(|outcome| view! {
+                <p class="device-verify-result" class:ok=outcome.is_ok()>
+                    {if outcome.is_ok() {
+                        i18n.count("device_detail.verify_ok", outcome.matching)
+                    } else {
+                        i18n.tf(
+                            "device_detail.verify_counts",
+                            &[
+                                ("matching", &outcome.matching.to_string()),
+                                ("missing", &outcome.missing.to_string()),
+                                ("different", &outcome.different.to_string()),
+                            ],
+                        )
+                    }}
+                </p>
+            })}
+        }
+        .into_any()
+    };
+
+    let mount_point_display = mount_point.clone();
+
+    view! {
+        <div class="device-detail-view">
+            <div class="playlist-detail-header-top">
+                <button
+                    class="btn btn-ghost playlist-back-btn"
+                    on:click=move |_| on_back.run(())
+                    aria-label=move || i18n.t("common.go_back")
+                >
+                    <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
+                        <path d="M20 11H7.83l5.59-5.59L12 4l-8 8 8 8 1.41-1.41L7.83 13H20v-2z"/>
+                    </svg>
+                    {move || i18n.t("common.back")}
+                </button>
+                <div class="playlist-detail-actions">
+                    {move || busy.get().map(|label| view! {
+                        <span class="device-detail-busy"><span class="spinner"></span>{i18n.t(label)}</span>
+                    })}
+                    <button
+                        class="btn btn-primary"
+                        on:click=move |_| on_sync.run(())
+                        disabled=move || device.get().is_none() || busy.get().is_some()
+                    >
+                        {move || i18n.t("device_detail.sync")}
+                    </button>
+                    <button
+                        class="btn btn-secondary"
+                        on:click=verify
+                        disabled=move || {
+                            busy.get().is_some()
+                                || usage.with(|u| u.as_ref().is_none_or(|u| u.last_sync.is_none()))
+                        }
+                        title=move || i18n.t("device_detail.verify_hint")
+                    >
+                        {move || i18n.t("device_detail.verify")}
+                    </button>
+                    <button
+                        class="btn btn-secondary"
+                        on:click=move |_| set_confirm_clean.set(true)
+                        disabled=move || device.get().is_none() || busy.get().is_some()
+                    >
+                        {move || i18n.t("device_detail.clean")}
+                    </button>
+                    <button
+                        class="btn btn-eject"
+                        on:click=eject
+                        disabled=move || device.get().is_none() || busy.get().is_some()
+                    >
+                        {move || i18n.t("device_detail.eject")}
+                    </button>
+                </div>
+            </div>
+
+            <h1 class="device-detail-name">{title}</h1>
+            <p class="device-detail-muted">
+                {mount_point_display}
+                {move || device.get().map(|d| format!(" · {}", d.file_system))}
+            </p>
+
+            {move || device.get().is_none().then(|| view! {
+                <p class="device-detail-error">{i18n.t("device_detail.disconnected")}</p>
+            })}
+
+            {move || confirm_clean.get().then(|| view! {
+                <div class="device-clean-confirm">
+                    <p>{i18n.t("device_detail.clean_confirm")}</p>
+                    <button class="btn btn-secondary" on:click=move |_| set_confirm_clean.set(false)>
+                        {i18n.t("common.cancel")}
+                    </button>
+                    <button class="btn btn-danger" on:click=clean.clone()>
+                        {i18n.t("device_detail.delete_files")}
+                    </button>
+                </div>
+            })}
+
+            <section class="device-detail-section">
+                <h2>{move || i18n.t("nav.storage")}</h2>
+                {usage_section}
+            </section>
+
+            <section class="device-detail-section">
+                <h2>{move || i18n.t("device_detail.last_sync")}</h2>
+                {last_sync_section}
+            </section>
+
+            <section class="device-detail-section">
+                <h2>{move || i18n.t("settings.title")}</h2>
+                <label class="device-profile-field">
+                    <span>{move || i18n.t("device_detail.nickname")}</span>
+                    <input
+                        type="text"
+                        class="device-profile-input"
+                        placeholder=move || device.with(|d| d.as_ref().map(|d| d.name.clone()).unwrap_or_default())
+                        prop:value=move || nickname.get()
+                        on:input=move |ev| set_nickname.set(event_target_value(&ev))
+                    />
+                </label>
+                <label class="device-profile-toggle">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || profile.with(|p| p.auto_sync)
+                        on:change=move |ev| {
+                            let checked = event_target_checked(&ev);
+                            set_profile.update(|p| p.auto_sync = checked);
+                        }
+                    />
+                    {move || i18n.t("device_detail.auto_sync")}
+                </label>
+                <label class="device-profile-toggle">
+                    <input
+                        type="checkbox"
+                        prop:checked=move || profile.with(|p| p.ignored)
+                        on:change=move |ev| {
+                            let checked = event_target_checked(&ev);
+                            set_profile.update(|p| p.ignored = checked);
+                        }
+                    />
+                    {move || i18n.t("device_detail.ignore")}
+                </label>
+                <button class="btn btn-secondary" on:click=save_profile>
+                    {move || i18n.t("settings.save")}
+                </button>
+            </section>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DeviceSyncRecord, SyncPreviewEntry};
+
+    fn usage(total: u64, available: u64) -> DeviceUsage {
+        DeviceUsage {
+            total_bytes: total,
+            available_bytes: available,
+            music_bytes: 500,
+            music_files: 5,
+            other_bytes: 100,
+            other_files: 1,
+            system_bytes: 150,
+            unreadable_entries: 0,
+            last_sync: None,
+        }
+    }
+
+    #[test]
+    fn test_usage_segments() {
+        let segments = UsageSegments::new(&usage(1000, 250));
+        assert!((segments.music - 50.0).abs() < f64::EPSILON);
+        assert!((segments.other - 10.0).abs() < f64::EPSILON);
+        assert!((segments.system - 15.0).abs() < f64::EPSILON);
+        assert!(UsageSegments::new(&usage(0, 0)).music.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_health_warnings() {
+        assert!(health_warnings(Locale::En, &usage(1000, 250)).is_empty());
+
+        let mut full = usage(1000, 10);
+        full.unreadable_entries = 2;
+        full.last_sync = Some(DeviceSyncRecord {
+            synced_at: 0,
+            playlists: vec![],
+            files_transferred: 3,
+            files_failed: 1,
+            bytes_transferred: 0,
+        });
+        let warnings = health_warnings(Locale::En, &full);
+        assert_eq!(
+            warnings,
+            [
+                "The device is almost full",
+                "1 file failed to copy during the last sync",
+                "2 items on the device couldn't be read",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_outcome() {
+        let entry = |change| SyncPreviewEntry {
+            playlist: None,
+            file_name: "a.mp3".to_string(),
+            size_bytes: 1,
+            change,
+        };
+        let preview = SyncPreview {
+            entries: vec![
+                entry(SyncChange::Unchanged),
+                entry(SyncChange::Unchanged),
+                entry(SyncChange::Add),
+            ],
+            bytes_to_write: 1,
+            bytes_to_remove: 0,
+            bytes_replaced: 0,
+        };
+        let outcome = VerifyOutcome::from_preview(&preview);
+        assert_eq!(
+            outcome,
+            VerifyOutcome {
+                matching: 2,
+                missing: 1,
+                different: 0
+            }
+        );
+        assert!(!outcome.is_ok());
+    }
+}
@@ -3,9 +3,11 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
+use crate::components::context_menu::{ContextMenu, ContextMenuItem};
 use crate::components::empty_state::{EmptyStateSize, ErrorEmptyState, NoDeviceEmptyState};
 use crate::components::layout::MobileMenuContext;
 use crate::components::loading::{LoadingState, Skeleton, SkeletonText};
+use crate::i18n::use_i18n;
 use crate::tauri_api;
 use crate::types::DeviceInfo;
 
@@ -26,6 +28,24 @@ fn format_bytes(bytes: u64) -> String {
     }
 }
 
+/// Actions offered in a device's context menu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DeviceAction {
+    /// Open the device in the file manager.
+    OpenFolder,
+    /// Safely eject the device.
+    Eject,
+}
+
+/// Context menu entries for a device.
+const DEVICE_MENU_ITEMS: [ContextMenuItem<DeviceAction>; 2] = [
+    ContextMenuItem::new(
+        DeviceAction::OpenFolder,
+        "playlist_detail.open_folder.title",
+    ),
+    ContextMenuItem::new(DeviceAction::Eject, "device_detail.eject"),
+];
+
 /// Loading skeleton for a single device item.
 #[component]
 fn DeviceItemSkeleton() -> impl IntoView {
@@ -77,8 +97,10 @@ fn DeviceItem(
     #[prop(default = false)]
     selected: bool,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let device_clone = device.clone();
     let device_for_eject = device.clone();
+    let mount_point_for_menu = device.mount_point.clone();
     let usage = device.usage_percentage();
 
     // Try to get mobile menu context to close menu on selection
@@ -87,8 +109,7 @@ fn DeviceItem(
     // Eject state
     let (ejecting, set_ejecting) = signal(false);
 
-    let handle_eject = move |e: web_sys::MouseEvent| {
-        e.stop_propagation();
+    let eject = move || {
         if ejecting.get() {
             return;
         }
@@ -114,64 +135,96 @@ fn DeviceItem(
             set_ejecting.set(false);
         });
     };
+    let eject_from_menu = eject.clone();
+    let handle_eject = move |e: web_sys::MouseEvent| {
+        e.stop_propagation();
+        eject();
+    };
+
+    let on_menu_action = Callback::new(move |action| match action {
+        DeviceAction::OpenFolder => {
+            let mount_point = mount_point_for_menu.clone();
+            spawn_local(async move {
+                if let Err(e) = tauri_api::open_device_folder(&mount_point).await {
+                    leptos::logging::error!("Failed to open device folder: {}", e);
+                }
+            });
+        }
+        DeviceAction::Eject => eject_from_menu(),
+    });
 
     view! {
-        <div
-            class=move || if selected { "device-item selected" } else { "device-item" }
-            on:click=move |_| {
-                on_select.run(device_clone.clone());
-                // Close mobile menu when device is selected
-                if let Some(ctx) = menu_ctx {
-                    ctx.close();
+        <ContextMenu items=DEVICE_MENU_ITEMS.to_vec() on_action=on_menu_action label="device_menu.label">
+            <div
+                class=move || if selected { "device-item selected" } else { "device-item" }
+                on:click=move |_| {
+                    on_select.run(device_clone.clone());
+                    // Close mobile menu when device is selected
+                    if let Some(ctx) = menu_ctx {
+                        ctx.close();
+                    }
                 }
-            }
-        >
-            <div class="device-icon">
-                <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
-                    <path d="M15 7v4h1v2h-3V5h2l-3-4-3 4h2v8H8v-2.07c.7-.37 1.2-1.08 1.2-1.93 0-1.21-.99-2.2-2.2-2.2-1.21 0-2.2.99-2.2 2.2 0 .85.5 1.56 1.2 1.93V13c0 1.1.9 2 2 2h3v3.05c-.71.37-1.2 1.1-1.2 1.95 0 1.22.99 2.2 2.2 2.2 1.21 0 2.2-.98 2.2-2.2 0-.85-.49-1.58-1.2-1.95V15h3c1.1 0 2-.9 2-2v-2h1V7h-4z"/>
-                </svg>
-            </div>
-            <div class="device-info">
-                <div class="device-name">{device.name.clone()}</div>
-                <div class="device-path">{device.mount_point.clone()}</div>
-                <div class="device-storage">
-                    <div class="storage-bar">
+            >
+                <div class="device-icon">
+                    <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
+                        <path d="M15 7v4h1v2h-3V5h2l-3-4-3 4h2v8H8v-2.07c.7-.37 1.2-1.08 1.2-1.93 0-1.21-.99-2.2-2.2-2.2-1.21 0-2.2.99-2.2 2.2 0 .85.5 1.56 1.2 1.93V13c0 1.1.9 2 2 2h3v3.05c-.71.37-1.2 1.1-1.2 1.95 0 1.22.99 2.2 2.2 2.2 1.21 0 2.2-.98 2.2-2.2 0-.85-.49-1.58-1.2-1.95V15h3c1.1 0 2-.9 2-2v-2h1V7h-4z"/>
+                    </svg>
+                </div>
+                <div class="device-info">
+                    <div class="device-name">{device.name.clone()}</div>
+                    <div class="device-path">{device.mount_point.clone()}</div>
+                    <div class="device-storage">
                         <div
-                            class="storage-used"
-                            style=format!("width: {}%", usage)
-                        ></div>
+                            class="storage-bar"
+                            role="meter"
+                            aria-label=i18n.t("device_list.storage_used")
+                            aria-valuemin="0"
+                            aria-valuemax="100"
+                            aria-valuenow=format!("{usage:.0}")
+                        >
+                            <div
+                                class="storage-used"
+                                style=format!("width: {}%", usage)
+                            ></div>
+                        </div>
+                        <div class="storage-text">
+                            {i18n.tf(
+                                "sync_wizard.device_space",
+                                &[
+                                    ("free", &format_bytes(device.available_bytes)),
+                                    ("total", &format_bytes(device.total_bytes)),
+                                ],
+                            )}
+                        </div>
                     </div>
-                    <div class="storage-text">
-                        {format_bytes(device.available_bytes)} " free of " {format_bytes(device.total_bytes)}
+                    // Eject button
+                    <div class="device-actions">
+                        <button
+                            class="btn btn-eject"
+                            title=i18n.t("device_list.eject.title")
+                            disabled=move || ejecting.get()
+                            on:click=handle_eject
+                        >
+                            {move || {
+                                if ejecting.get() {
+                                    view! {
+                                        <span class="spinner"></span>
+                                        <span>{i18n.t("device_detail.ejecting")}</span>
+                                    }.into_any()
+                                } else {
+                                    view! {
+                                        <svg viewBox="0 0 24 24" width="16" height="16" fill="currentColor">
+                                            <path d="M5 17h14v2H5zm7-12L5.33 15h13.34z"/>
+                                        </svg>
+                                        <span>{i18n.t("device_detail.eject")}</span>
+                                    }.into_any()
+                                }
+                            }}
+                        </button>
                     </div>
                 </div>
-                // Eject button
-                <div class="device-actions">
-                    <button
-                        class="btn btn-eject"
-                        title="Safely eject device"
-                        disabled=move || ejecting.get()
-                        on:click=handle_eject
-                    >
-                        {move || {
-                            if ejecting.get() {
-                                view! {
-                                    <span class="spinner"></span>
-                                    <span>"Ejecting..."</span>
-                                }.into_any()
-                            } else {
-                                view! {
-                                    <svg viewBox="0 0 24 24" width="16" height="16" fill="currentColor">
-                                        <path d="M5 17h14v2H5zm7-12L5.33 15h13.34z"/>
-                                    </svg>
-                                    <span>"Eject"</span>
-                                }.into_any()
-                            }
-                        }}
-                    </button>
-                </div>
             </div>
-        </div>
+        </ContextMenu>
     }
 }
 
@@ -199,14 +252,16 @@ pub fn DeviceList(
     /// Loading state of the device list (as a signal for reactivity).
     state: ReadSignal<LoadingState>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <div class="device-list">
             <div class="device-list-header">
-                <h3>"Connected Devices"</h3>
+                <h3>{move || i18n.t("device_list.title")}</h3>
                 <button
                     class=move || if state.get() == LoadingState::Loading { "btn btn-ghost btn-icon refreshing" } else { "btn btn-ghost btn-icon" }
                     on:click=move |_| on_refresh.run(())
                     disabled=move || state.get() == LoadingState::Loading
+                    aria-label=move || i18n.t("device_list.refresh")
                 >
                     <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor" class="refresh-icon">
                         <path d="M17.65 6.35C16.2 4.9 14.21 4 12 4c-4.42 0-7.99 3.58-7.99 8s3.57 8 7.99 8c3.73 0 6.84-2.55 7.73-6h-2.08c-.82 2.33-3.04 4-5.65 4-3.31 0-6-2.69-6-6s2.69-6 6-6c1.66 0 3.14.69 4.22 1.78L13 11h7V4l-2.35 2.35z"/>
@@ -225,7 +280,7 @@ pub fn DeviceList(
                             view! {
                                 <div class="device-list-error">
                                     <ErrorEmptyState
-                                        message="Failed to detect devices".to_string()
+                                        message=i18n.t("device_list.detect_failed")
                                         on_retry=on_refresh
                                         size=EmptyStateSize::Small
                                     />
@@ -2,6 +2,7 @@
 
 use leptos::prelude::*;
 
+use crate::i18n::use_i18n;
 use crate::types::DeviceInfo;
 
 /// Connection status for display purposes.
@@ -85,6 +86,7 @@ pub fn DeviceStatusIndicator(
 /// Connected device status display.
 #[component]
 fn DeviceStatusConnected(device: DeviceInfo) -> impl IntoView {
+    let i18n = use_i18n();
     let usage_percent = device.usage_percentage();
     let usage_class = if usage_percent > 90.0 {
         "critical"
@@ -106,7 +108,7 @@ fn DeviceStatusConnected(device: DeviceInfo) -> impl IntoView {
                 // Device name and connection status
                 <div class="device-status-header">
                     <span class="device-status-name" data-testid="device-name">{device.name.clone()}</span>
-                    <span class="device-status-badge connected">"Connected"</span>
+                    <span class="device-status-badge connected">{i18n.t("device_status.connected")}</span>
                 </div>
 
                 // Mount point
@@ -119,7 +121,14 @@ fn DeviceStatusConnected(device: DeviceInfo) -> impl IntoView {
 
                 // Storage capacity bar
                 <div class="device-status-storage">
-                    <div class="storage-capacity-bar">
+                    <div
+                        class="storage-capacity-bar"
+                        role="meter"
+                        aria-label=i18n.t("device_list.storage_used")
+                        aria-valuemin="0"
+                        aria-valuemax="100"
+                        aria-valuenow=format!("{usage_percent:.0}")
+                    >
                         <div
                             class=format!("storage-capacity-fill {usage_class}")
                             style=format!("width: {}%", usage_percent)
@@ -127,7 +136,7 @@ fn DeviceStatusConnected(device: DeviceInfo) -> impl IntoView {
                         ></div>
                     </div>
                     <div class="storage-capacity-text" data-testid="storage-text">
-                        <span class="available">{format_bytes(device.available_bytes)}" free"</span>
+                        <span class="available">{i18n.tf("device_status.free", &[("size", &format_bytes(device.available_bytes))])}</span>
                         <span class="separator">" / "</span>
                         <span class="total">{format_bytes(device.total_bytes)}</span>
                     </div>
@@ -146,7 +155,7 @@ fn DeviceStatusConnected(device: DeviceInfo) -> impl IntoView {
                             <svg viewBox="0 0 24 24" width="12" height="12" fill="currentColor">
                                 <path d="M15 7v4h1v2h-3V5h2l-3-4-3 4h2v8H8v-2.07c.7-.37 1.2-1.08 1.2-1.93 0-1.21-.99-2.2-2.2-2.2-1.21 0-2.2.99-2.2 2.2 0 .85.5 1.56 1.2 1.93V13c0 1.1.9 2 2 2h3v3.05c-.71.37-1.2 1.1-1.2 1.95 0 1.22.99 2.2 2.2 2.2 1.21 0 2.2-.98 2.2-2.2 0-.85-.49-1.58-1.2-1.95V15h3c1.1 0 2-.9 2-2v-2h1V7h-4z"/>
                             </svg>
-                            "Removable"
+                            {i18n.t("device_status.removable")}
                         </span>
                     })}
                 </div>
@@ -158,6 +167,7 @@ fn DeviceStatusConnected(device: DeviceInfo) -> impl IntoView {
 /// Disconnected status display.
 #[component]
 fn DeviceStatusDisconnected() -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <div class="device-status-content disconnected" data-testid="device-status-disconnected">
             // Disconnected indicator dot
@@ -167,11 +177,11 @@ fn DeviceStatusDisconnected() -> impl IntoView {
             // Disconnected message
             <div class="device-status-info">
                 <div class="device-status-header">
-                    <span class="device-status-name muted">"No Device"</span>
-                    <span class="device-status-badge disconnected">"Disconnected"</span>
+                    <span class="device-status-name muted">{i18n.t("device_status.no_device")}</span>
+                    <span class="device-status-badge disconnected">{i18n.t("device_status.disconnected")}</span>
                 </div>
                 <p class="device-status-hint">
-                    "Plug in your MP3 player via USB"
+                    {i18n.t("device_status.plug_in")}
                 </p>
             </div>
         </div>
@@ -181,6 +191,7 @@ fn DeviceStatusDisconnected() -> impl IntoView {
 /// Checking status display (loading state).
 #[component]
 fn DeviceStatusChecking() -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <div class="device-status-content checking" data-testid="device-status-checking">
             // Checking indicator (spinner)
@@ -191,11 +202,11 @@ fn DeviceStatusChecking() -> impl IntoView {
             // Checking message
             <div class="device-status-info">
                 <div class="device-status-header">
-                    <span class="device-status-name muted">"Detecting..."</span>
-                    <span class="device-status-badge checking">"Scanning"</span>
+                    <span class="device-status-name muted">{i18n.t("device_status.detecting")}</span>
+                    <span class="device-status-badge checking">{i18n.t("device_status.scanning")}</span>
                 </div>
                 <p class="device-status-hint">
-                    "Looking for connected devices..."
+                    {i18n.t("device_status.looking")}
                 </p>
             </div>
         </div>
@@ -209,6 +220,7 @@ pub fn DeviceStatusIndicatorCompact(
     /// The currently selected/connected device, if any.
     device: ReadSignal<Option<DeviceInfo>>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <div class="device-status-indicator-compact" data-testid="device-status-indicator-compact">
             {move || {
@@ -217,14 +229,14 @@ pub fn DeviceStatusIndicatorCompact(
                         <div class="device-status-compact connected">
                             <div class="status-dot connected"></div>
                             <span class="device-name">{dev.name}</span>
-                            <span class="device-space">{format_bytes(dev.available_bytes)}" free"</span>
+                            <span class="device-space">{i18n.tf("device_status.free", &[("size", &format_bytes(dev.available_bytes))])}</span>
                         </div>
                     }.into_any()
                 } else {
                     view! {
                         <div class="device-status-compact disconnected">
                             <div class="status-dot disconnected"></div>
-                            <span class="device-name muted">"No device connected"</span>
+                            <span class="device-name muted">{i18n.t("device_status.none_connected")}</span>
                         </div>
                     }.into_any()
                 }
@@ -2,7 +2,28 @@
 
 use leptos::prelude::*;
 
-use crate::types::{DownloadProgress, DownloadResult, TaskId, YouTubeErrorCategory};
+use crate::i18n::{translate, use_i18n};
+use crate::types::{
+    DownloadProgress, DownloadResult, Locale, TaskId, VideoDownloadResult, VideoOutcome,
+    YouTubeErrorCategory,
+};
+
+/// Format bytes to human-readable string.
+fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
 
 /// Detailed error information for display.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -20,9 +41,9 @@ pub struct DownloadErrorInfo {
 }
 
 impl DownloadErrorInfo {
-    /// Create from a `DownloadResult`.
+    /// Create from a `DownloadResult`, with fallback text in `locale`.
     #[must_use]
-    pub fn from_result(result: &DownloadResult) -> Self {
+    pub fn from_result(result: &DownloadResult, locale: Locale) -> Self {
         let category = result
             .error_category
             .unwrap_or(YouTubeErrorCategory::Unknown);
@@ -31,22 +52,22 @@ impl DownloadErrorInfo {
             title: result
                 .error_title
                 .clone()
-                .unwrap_or_else(|| category.title().to_string()),
+                .unwrap_or_else(|| translate(locale, category.title_key(), &[])),
             description: result
                 .error_description
                 .clone()
-                .unwrap_or_else(|| category.description().to_string()),
+                .unwrap_or_else(|| translate(locale, category.description_key(), &[])),
             technical_message: result.error_message.clone(),
             is_retryable: category.is_retryable(),
         }
     }
 
-    /// Create from an error message string (fallback).
+    /// Create from an error message string (fallback), titled in `locale`.
     #[must_use]
-    pub fn from_message(message: String) -> Self {
+    pub fn from_message(message: String, locale: Locale) -> Self {
         Self {
             category: YouTubeErrorCategory::Unknown,
-            title: "Download Failed".to_string(),
+            title: translate(locale, "youtube_error.download_failed", &[]),
             description: message.clone(),
             technical_message: Some(message),
             is_retryable: false,
@@ -56,10 +77,11 @@ impl DownloadErrorInfo {
 
 impl Default for DownloadErrorInfo {
     fn default() -> Self {
+        let category = YouTubeErrorCategory::Unknown;
         Self {
-            category: YouTubeErrorCategory::Unknown,
-            title: "Error".to_string(),
-            description: "An unexpected error occurred.".to_string(),
+            category,
+            title: translate(Locale::default(), category.title_key(), &[]),
+            description: translate(Locale::default(), category.description_key(), &[]),
             technical_message: None,
             is_retryable: false,
         }
@@ -102,6 +124,86 @@ impl DownloadPanelState {
     }
 }
 
+/// One video of a finished download in the expandable details list.
+#[component]
+fn VideoResultRow(
+    /// The video's result.
+    video: VideoDownloadResult,
+    /// Callback to download the failed videos again, if possible.
+    on_retry: Option<Callback<()>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let outcome = video.outcome();
+    let stream_label = video
+        .stream_label()
+        .filter(|_| outcome == VideoOutcome::Completed);
+    let (class, label, icon) = match outcome {
+        VideoOutcome::Completed => (
+            "completed",
+            "download_panel.video.downloaded",
+            "M9 16.17L4.83 12l-1.42 1.41L9 19 21 7l-1.41-1.41z",
+        ),
+        VideoOutcome::Skipped => (
+            "skipped",
+            "download_panel.video.skipped",
+            "M6 18l8.5-6L6 6v12zM16 6v12h2V6h-2z",
+        ),
+        VideoOutcome::Failed => (
+            "failed",
+            "download_panel.video.failed",
+            "M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 17.59 19 19 17.59 13.41 12z",
+        ),
+    };
+    let label = i18n.t(label);
+
+    view! {
+        <li class=format!("download-video-row {class}") data-testid="download-video-row">
+            <span class=format!("download-video-status {class}") title=label.clone() aria-label=label>
+                <svg viewBox="0 0 24 24" width="14" height="14" fill="currentColor">
+                    <path d=icon/>
+                </svg>
+            </span>
+            <div class="download-video-info">
+                <div class="download-video-title" title=video.title.clone()>{video.title}</div>
+                {video.error.map(|error| view! {
+                    <div class="download-video-error" data-testid="download-video-error">{error}</div>
+                })}
+                {(video.sponsor_secs_removed > 0.0).then(|| view! {
+                    <div class="download-video-sponsor" data-testid="download-video-sponsor">
+                        {i18n.tf(
+                            "download_panel.sponsor_removed",
+                            &[("seconds", &format!("{:.0}", video.sponsor_secs_removed))],
+                        )}
+                    </div>
+                })}
+                {(video.chapter_count > 0).then(|| view! {
+                    <div class="download-video-chapters" data-testid="download-video-chapters">
+                        {i18n.tf(
+                            "download_panel.chapters",
+                            &[("tracks", &i18n.count("count.tracks", video.chapter_count))],
+                        )}
+                    </div>
+                })}
+                {stream_label.map(|label| view! {
+                    <div class="download-video-stream" data-testid="download-video-stream">
+                        {i18n.tf("download_panel.source", &[("source", &label)])}
+                    </div>
+                })}
+            </div>
+            {on_retry.filter(|_| outcome == VideoOutcome::Failed).map(|retry| view! {
+                <button
+                    class="btn btn-ghost btn-sm download-video-retry"
+                    title=i18n.t("download_panel.retry_failed.title")
+                    on:click=move |_| retry.run(())
+                    data-testid="download-video-retry"
+                >
+                    {i18n.t("empty.error.retry")}
+                </button>
+            })}
+        </li>
+    }
+}
+
 /// Download progress panel component.
 ///
 /// Displays a progress indicator for YouTube downloads including:
@@ -110,6 +212,7 @@ impl DownloadPanelState {
 /// - Download speed
 /// - Time remaining
 /// - Cancel button
+/// - Once finished, an expandable list of every video with its outcome
 #[component]
 
 pub fn DownloadProgressPanel(
@@ -121,7 +224,14 @@ pub fn DownloadProgressPanel(
     on_cancel: Callback<TaskId>,
     /// Callback when dismiss is clicked (for completed/failed states).
     on_dismiss: Callback<()>,
+    /// The finished download's per-video results.
+    result: ReadSignal<Option<DownloadResult>>,
+    /// Callback to download the failed videos again.
+    #[prop(optional)]
+    on_retry_failed: Option<Callback<()>>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
+    let (details_open, set_details_open) = signal(false);
     let is_visible = move || state.get().is_visible();
     let is_active = move || state.get().is_active();
     let is_ended = move || state.get().is_ended();
@@ -181,11 +291,11 @@ pub fn DownloadProgressPanel(
     };
 
     let status_title = move || match state.get() {
-        DownloadPanelState::Idle => "Ready".to_string(),
-        DownloadPanelState::Downloading => "Downloading...".to_string(),
-        DownloadPanelState::Completed => "Download Complete".to_string(),
+        DownloadPanelState::Idle => i18n.t("download_panel.ready"),
+        DownloadPanelState::Downloading => i18n.t("download_panel.downloading"),
+        DownloadPanelState::Completed => i18n.t("download_panel.completed"),
         DownloadPanelState::Failed(ref err) => err.title.clone(),
-        DownloadPanelState::Cancelled => "Download Cancelled".to_string(),
+        DownloadPanelState::Cancelled => i18n.t("download_panel.cancelled"),
     };
 
     // Get error description for failed state
@@ -239,7 +349,13 @@ pub fn DownloadProgressPanel(
                             if is_active() {
                                 view! {
                                     <div class="download-status-subtitle" data-testid="download-status-subtitle">
-                                        {format!("{} of {} videos", p.current_index, p.total_videos)}
+                                        {i18n.tf(
+                                            "download_panel.progress",
+                                            &[
+                                                ("current", &p.current_index.to_string()),
+                                                ("total", &i18n.count("count.videos", p.total_videos)),
+                                            ],
+                                        )}
                                     </div>
                                 }.into_any()
                             } else {
@@ -257,7 +373,7 @@ pub fn DownloadProgressPanel(
                                     <svg viewBox="0 0 24 24" width="14" height="14" fill="currentColor">
                                         <path d="M17.65 6.35C16.2 4.9 14.21 4 12 4c-4.42 0-7.99 3.58-7.99 8s3.57 8 7.99 8c3.73 0 6.84-2.55 7.73-6h-2.08c-.82 2.33-3.04 4-5.65 4-3.31 0-6-2.69-6-6s2.69-6 6-6c1.66 0 3.14.69 4.22 1.78L13 11h7V4l-2.35 2.35z"/>
                                     </svg>
-                                    "You can try again"
+                                    {i18n.t("download_panel.retry_hint")}
                                 </div>
                             }.into_any()
                         } else {
@@ -272,7 +388,8 @@ pub fn DownloadProgressPanel(
                             view! {
                                 <button
                                     class="btn btn-ghost btn-icon download-cancel-btn"
-                                    title="Cancel download"
+                                    title=i18n.t("download_panel.cancel")
+                                    aria-label=i18n.t("download_panel.cancel")
                                     on:click=handle_cancel
                                     data-testid="download-cancel-btn"
                                 >
@@ -285,7 +402,8 @@ pub fn DownloadProgressPanel(
                             view! {
                                 <button
                                     class="btn btn-ghost btn-icon download-dismiss-btn"
-                                    title="Dismiss"
+                                    title=i18n.t("download_panel.dismiss")
+                                    aria-label=i18n.t("download_panel.dismiss_download")
                                     on:click=handle_dismiss
                                     data-testid="download-dismiss-btn"
                                 >
@@ -318,9 +436,39 @@ pub fn DownloadProgressPanel(
                                 </div>
                             </div>
 
+                            // Current track's stream
+                            {p.current_bytes_percent().map(|percent| {
+                                let total = p.current_total_bytes.unwrap_or_default();
+                                view! {
+                                    <div class="download-track-bytes" data-testid="download-track-bytes">
+                                        <div
+                                            class="download-track-bar"
+                                            role="progressbar"
+                                            aria-label=i18n.t("download_panel.track_progress")
+                                            aria-valuemin="0"
+                                            aria-valuemax="100"
+                                            aria-valuenow=format!("{percent:.0}")
+                                        >
+                                            <div class="download-track-fill" style=format!("width: {percent}%")></div>
+                                        </div>
+                                        <span class="download-track-size">
+                                            {format!("{} / {}", format_bytes(p.current_bytes), format_bytes(total))}
+                                        </span>
+                                    </div>
+                                }
+                            })}
+
                             // Overall progress bar
                             <div class="download-progress-bar-container">
-                                <div class="download-progress-bar" data-testid="download-progress-bar">
+                                <div
+                                    class="download-progress-bar"
+                                    role="progressbar"
+                                    aria-label=i18n.t("download_panel.progress_label")
+                                    aria-valuemin="0"
+                                    aria-valuemax="100"
+                                    aria-valuenow=move || format!("{:.0}", progress.get().map_or(0.0, |p| p.overall_progress_percent()))
+                                    data-testid="download-progress-bar"
+                                >
                                     <div
                                         class="download-progress-fill"
                                         style=move || format!("width: {}%", progress.get().map_or(0.0, |p| p.overall_progress_percent()))
@@ -351,7 +499,7 @@ pub fn DownloadProgressPanel(
                                                     <path d="M11.99 2C6.47 2 2 6.48 2 12s4.47 10 9.99 10C17.52 22 22 17.52 22 12S17.52 2 11.99 2zM12 20c-4.42 0-8-3.58-8-8s3.58-8 8-8 8 3.58 8 8-3.58 8-8 8zm.5-13H11v6l5.25 3.15.75-1.23-4.5-2.67z"/>
                                                 </svg>
                                                 <span class="stat-value" data-testid="download-eta">{eta}</span>
-                                                <span class="stat-label">"remaining"</span>
+                                                <span class="stat-label">{i18n.t("download_panel.remaining")}</span>
                                             </div>
                                         }
                                     })
@@ -363,7 +511,7 @@ pub fn DownloadProgressPanel(
                                         <path d="M13 3c-4.97 0-9 4.03-9 9H1l3.89 3.89.07.14L9 12H6c0-3.87 3.13-7 7-7s7 3.13 7 7-3.13 7-7 7c-1.93 0-3.68-.79-4.94-2.06l-1.42 1.42C8.27 19.99 10.51 21 13 21c4.97 0 9-4.03 9-9s-4.03-9-9-9zm-1 5v5l4.28 2.54.72-1.21-3.5-2.08V8H12z"/>
                                     </svg>
                                     <span class="stat-value" data-testid="download-elapsed">{p.formatted_elapsed}</span>
-                                    <span class="stat-label">"elapsed"</span>
+                                    <span class="stat-label">{i18n.t("download_panel.elapsed")}</span>
                                 </div>
                             </div>
 
@@ -407,6 +555,60 @@ pub fn DownloadProgressPanel(
                     None
                 }
             }}
+
+            // Per-video details (only shown once the download has results)
+            {move || {
+                let result = result.get().filter(|r| !r.results.is_empty())?;
+                let can_retry = result.failed_videos_retry_target().is_some();
+                let on_retry = on_retry_failed.filter(|_| can_retry);
+                let count = result.results.len();
+                Some(view! {
+                    <div class="download-details" data-testid="download-details">
+                        <div class="download-details-header">
+                            <button
+                                class="btn btn-ghost btn-sm download-details-toggle"
+                                aria-expanded=move || details_open.get().to_string()
+                                aria-controls="download-video-list"
+                                on:click=move |_| set_details_open.update(|open| *open = !*open)
+                                data-testid="download-details-toggle"
+                            >
+                                <svg
+                                    viewBox="0 0 24 24"
+                                    width="16"
+                                    height="16"
+                                    fill="currentColor"
+                                    class="download-details-chevron"
+                                    class:open=move || details_open.get()
+                                >
+                                    <path d="M10 6L8.59 7.41 13.17 12l-4.58 4.59L10 18l6-6z"/>
+                                </svg>
+                                {i18n.count("count.videos", count)}
+                            </button>
+                            {on_retry.map(|retry| view! {
+                                <button
+                                    class="btn btn-secondary btn-sm"
+                                    on:click=move |_| retry.run(())
+                                    data-testid="download-retry-failed"
+                                >
+                                    {i18n.tf(
+                                        "download_panel.retry_count",
+                                        &[("count", &result.failed_count.to_string())],
+                                    )}
+                                </button>
+                            })}
+                        </div>
+                        <ul
+                            id="download-video-list"
+                            class="download-video-list"
+                            class:hidden=move || !details_open.get()
+                        >
+                            {result.results.into_iter().map(|video| view! {
+                                <VideoResultRow video=video on_retry=on_retry />
+                            }).collect_view()}
+                        </ul>
+                    </div>
+                })
+            }}
         </div>
     }
 }
@@ -425,6 +627,7 @@ pub fn DownloadProgressIndicator(
     #[prop(optional)]
     on_click: Option<Callback<()>>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let handle_click = move |_: web_sys::MouseEvent| {
         if let Some(callback) = on_click {
             callback.run(());
@@ -448,14 +651,14 @@ pub fn DownloadProgressIndicator(
                                 <span class="indicator-progress" data-testid="indicator-progress">
                                     {format!("{:.0}%", p.overall_progress_percent())}
                                 </span>
-                                <span class="indicator-label">"Downloading"</span>
+                                <span class="indicator-label">{i18n.t("download_panel.indicator.downloading")}</span>
                             </div>
                         }.into_any()
                     } else {
                         view! {
                             <div class="indicator-content">
                                 <div class="indicator-spinner"></div>
-                                <span class="indicator-label">"Starting..."</span>
+                                <span class="indicator-label">{i18n.t("download_panel.indicator.starting")}</span>
                             </div>
                         }.into_any()
                     }
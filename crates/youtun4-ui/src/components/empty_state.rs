@@ -5,6 +5,8 @@
 
 use leptos::prelude::*;
 
+use crate::i18n::use_i18n;
+
 /// Icon types for empty states.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum EmptyStateIcon {
@@ -110,12 +112,12 @@ pub fn EmptyState(
     #[prop(default = EmptyStateIcon::Music)]
     icon: EmptyStateIcon,
     /// The main title/heading.
-    title: &'static str,
+    title: String,
     /// The descriptive message.
-    message: &'static str,
+    message: String,
     /// Optional hint text (displayed in italics).
     #[prop(optional)]
-    hint: Option<&'static str>,
+    hint: Option<String>,
     /// Size variant.
     #[prop(default = EmptyStateSize::Medium)]
     size: EmptyStateSize,
@@ -164,12 +166,13 @@ pub fn NoPlaylistsEmptyState(
     #[prop(default = EmptyStateSize::Large)]
     size: EmptyStateSize,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <EmptyState
             icon=EmptyStateIcon::Music
-            title="Start building your music library"
-            message="Paste a YouTube playlist URL to download tracks as MP3 files."
-            hint="Downloads are stored locally and can be synced to any MP3 player."
+            title=i18n.t("empty.playlists.title")
+            message=i18n.t("empty.playlists.message")
+            hint=i18n.t("empty.playlists.hint")
             size=size
             class="no-playlists-empty"
         >
@@ -181,7 +184,7 @@ pub fn NoPlaylistsEmptyState(
                     <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
                         <path d="M19 13h-6v6h-2v-6H5v-2h6V5h2v6h6v2z"/>
                     </svg>
-                    "Add YouTube Playlist"
+                    {i18n.t("empty.playlists.add")}
                 </button>
             })}
         </EmptyState>
@@ -201,12 +204,13 @@ pub fn NoDeviceEmptyState(
     #[prop(default = EmptyStateSize::Medium)]
     size: EmptyStateSize,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <EmptyState
             icon=EmptyStateIcon::Device
-            title="Connect your MP3 player"
-            message="Plug in your MP3 player or USB drive via USB to sync music."
-            hint="Most USB storage devices are detected automatically."
+            title=i18n.t("empty.device.title")
+            message=i18n.t("empty.device.message")
+            hint=i18n.t("empty.device.hint")
             size=size
             class="no-device-empty"
         >
@@ -218,7 +222,7 @@ pub fn NoDeviceEmptyState(
                     <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
                         <path d="M17.65 6.35C16.2 4.9 14.21 4 12 4c-4.42 0-7.99 3.58-7.99 8s3.57 8 7.99 8c3.73 0 6.84-2.55 7.73-6h-2.08c-.82 2.33-3.04 4-5.65 4-3.31 0-6-2.69-6-6s2.69-6 6-6c1.66 0 3.14.69 4.22 1.78L13 11h7V4l-2.35 2.35z"/>
                     </svg>
-                    "Scan for Devices"
+                    {i18n.t("empty.device.scan")}
                 </button>
             })}
         </EmptyState>
@@ -241,17 +245,18 @@ pub fn NoSearchResultsEmptyState(
     #[prop(default = EmptyStateSize::Medium)]
     size: EmptyStateSize,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let message = query.map_or_else(
-        || "No results found".to_string(),
-        |q| format!("No results found for \"{q}\""),
+        || i18n.t("create_playlist.no_results"),
+        |q| i18n.tf("empty.search.message", &[("query", &q)]),
     );
 
     view! {
         <EmptyState
             icon=EmptyStateIcon::Search
-            title="No results found"
-            message=Box::leak(message.into_boxed_str())
-            hint="Try adjusting your search terms or check for typos."
+            title=i18n.t("create_playlist.no_results")
+            message=message
+            hint=i18n.t("empty.search.hint")
             size=size
             class="no-search-results-empty"
         >
@@ -260,7 +265,7 @@ pub fn NoSearchResultsEmptyState(
                     class="btn btn-secondary"
                     on:click=move |_| callback.run(())
                 >
-                    "Clear Search"
+                    {i18n.t("empty.search.clear")}
                 </button>
             })}
         </EmptyState>
@@ -275,12 +280,13 @@ pub fn NoTracksEmptyState(
     #[prop(default = EmptyStateSize::Medium)]
     size: EmptyStateSize,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <EmptyState
             icon=EmptyStateIcon::Music
-            title="No tracks in this playlist"
-            message="This playlist doesn't have any tracks yet."
-            hint="Tracks are downloaded when you create a playlist from a YouTube URL."
+            title=i18n.t("empty.tracks.title")
+            message=i18n.t("empty.tracks.message")
+            hint=i18n.t("empty.tracks.hint")
             size=size
             class="no-tracks-empty"
         />
@@ -295,12 +301,13 @@ pub fn NothingToSyncEmptyState(
     #[prop(default = EmptyStateSize::Medium)]
     size: EmptyStateSize,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <EmptyState
             icon=EmptyStateIcon::Download
-            title="Nothing to sync"
-            message="All tracks are already on your device."
-            hint="Add new playlists or update existing ones to sync new content."
+            title=i18n.t("empty.sync.title")
+            message=i18n.t("empty.sync.message")
+            hint=i18n.t("empty.sync.hint")
             size=size
             class="nothing-to-sync-empty"
         />
@@ -320,11 +327,12 @@ pub fn ErrorEmptyState(
     #[prop(default = EmptyStateSize::Medium)]
     size: EmptyStateSize,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <EmptyState
             icon=EmptyStateIcon::Error
-            title="Something went wrong"
-            message=Box::leak(message.into_boxed_str())
+            title=i18n.t("empty.error.title")
+            message=message
             size=size
             class="error-empty-state"
         >
@@ -336,7 +344,7 @@ pub fn ErrorEmptyState(
                     <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
                         <path d="M17.65 6.35C16.2 4.9 14.21 4 12 4c-4.42 0-7.99 3.58-7.99 8s3.57 8 7.99 8c3.73 0 6.84-2.55 7.73-6h-2.08c-.82 2.33-3.04 4-5.65 4-3.31 0-6-2.69-6-6s2.69-6 6-6c1.66 0 3.14.69 4.22 1.78L13 11h7V4l-2.35 2.35z"/>
                     </svg>
-                    "Retry"
+                    {i18n.t("empty.error.retry")}
                 </button>
             })}
         </EmptyState>
@@ -354,16 +362,17 @@ pub fn EmptyFolderState(
     #[prop(default = EmptyStateSize::Medium)]
     size: EmptyStateSize,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let title = folder_name.as_ref().map_or_else(
-        || "Folder is empty".to_string(),
-        |n| format!("\"{n}\" is empty"),
+        || i18n.t("empty.folder.title"),
+        |n| i18n.tf("empty.folder.named_title", &[("name", n)]),
     );
 
     view! {
         <EmptyState
             icon=EmptyStateIcon::Folder
-            title=Box::leak(title.into_boxed_str())
-            message="This folder doesn't contain any files."
+            title=title
+            message=i18n.t("empty.folder.message")
             size=size
             class="empty-folder-state"
         />
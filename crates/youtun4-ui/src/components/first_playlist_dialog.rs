@@ -0,0 +1,486 @@
+//! Guided dialog for adding the first playlist from the empty library.
+//!
+//! The user pastes a link, which is checked as they type. Once it points to
+//! a playlist its title, cover and video count are fetched, a name is
+//! suggested from the title, and the download is queued.
+
+use leptos::html;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::create_playlist_dialog::{UrlValidationState, validate_playlist_name};
+use crate::components::use_dialog_keyboard;
+use crate::i18n::use_i18n;
+use crate::tauri_api;
+use crate::types::{Locale, PlaylistInfo, PlaylistMetadata, PlaylistSizeEstimate};
+
+/// Name used when the playlist title gives nothing usable.
+const FALLBACK_NAME: &str = "My Playlist";
+/// Longest suggested name, in characters.
+const MAX_SUGGESTED_LEN: usize = 100;
+
+/// Suggest a playlist name from a `YouTube` playlist title.
+///
+/// Characters that can't appear in folder names are dropped, and a number is
+/// added when a playlist in `existing` already has the name.
+#[must_use]
+pub fn suggest_playlist_name(title: &str, existing: &[String]) -> String {
+    let cleaned: String = title
+        .chars()
+        .map(|c| {
+            if c.is_control() || matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect();
+    let mut base: String = cleaned
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(MAX_SUGGESTED_LEN)
+        .collect();
+    base = base.trim_end_matches(['.', ' ']).to_string();
+    if validate_playlist_name(Locale::default(), &base).is_some() {
+        base = FALLBACK_NAME.to_string();
+    }
+
+    let taken = |name: &str| existing.iter().any(|e| e.eq_ignore_ascii_case(name));
+    if !taken(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{base} ({n})"))
+        .find(|name| !taken(name))
+        .unwrap_or(base)
+}
+
+/// Message keys of the steps shown at the top of the dialog.
+const STEPS: [&str; 3] = [
+    "first_playlist.step.paste",
+    "first_playlist.step.check",
+    "first_playlist.step.name",
+];
+
+/// Guided modal for adding a `YouTube` playlist to an empty library.
+///
+/// Validates the link while it is typed, previews the playlist, suggests a
+/// name and queues the download into a new playlist.
+#[component]
+pub fn FirstPlaylistDialog(
+    /// Whether the dialog is open.
+    is_open: ReadSignal<bool>,
+    /// Playlists already in the library, to keep the suggested name unique.
+    playlists: ReadSignal<Vec<PlaylistMetadata>>,
+    /// Callback with the playlist name once its download is queued.
+    on_create: Callback<String>,
+    /// Callback when the dialog is closed.
+    on_close: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let (url_input, set_url_input) = signal(String::new());
+    let (url_state, set_url_state) = signal(UrlValidationState::Idle);
+    let (url_error, set_url_error) = signal::<Option<String>>(None);
+    let (source_url, set_source_url) = signal::<Option<String>>(None);
+    let (info, set_info) = signal::<Option<PlaylistInfo>>(None);
+    let (info_loading, set_info_loading) = signal(false);
+    let (info_error, set_info_error) = signal::<Option<String>>(None);
+    let (size_estimate, set_size_estimate) = signal::<Option<PlaylistSizeEstimate>>(None);
+    let (estimating, set_estimating) = signal(false);
+    let (name_input, set_name_input) = signal(String::new());
+    let (name_edited, set_name_edited) = signal(false);
+    let (queueing, set_queueing) = signal(false);
+    let (queue_error, set_queue_error) = signal::<Option<String>>(None);
+
+    let existing_names = move || {
+        playlists
+            .get_untracked()
+            .into_iter()
+            .map(|p| p.name)
+            .collect::<Vec<_>>()
+    };
+
+    Effect::new(move || {
+        if is_open.get() {
+            set_url_input.set(String::new());
+            set_url_state.set(UrlValidationState::Idle);
+            set_url_error.set(None);
+            set_source_url.set(None);
+            set_info.set(None);
+            set_info_loading.set(false);
+            set_info_error.set(None);
+            set_size_estimate.set(None);
+            set_estimating.set(false);
+            set_name_input.set(String::new());
+            set_name_edited.set(false);
+            set_queueing.set(false);
+            set_queue_error.set(None);
+        }
+    });
+
+    // Results for a link the user has since changed are dropped
+    let is_current = move |url: &str| url_input.get_untracked().trim() == url;
+
+    // The estimate samples streams, so it comes after the playlist details
+    let fetch_estimate = move |url: String, playlist_url: String| {
+        set_estimating.set(true);
+        spawn_local(async move {
+            let result = tauri_api::estimate_playlist_size(&playlist_url, None).await;
+            if !is_current(&url) {
+                return;
+            }
+            match result {
+                Ok(estimate) => set_size_estimate.set(Some(estimate)),
+                Err(e) => leptos::logging::warn!("Failed to estimate playlist size: {}", e),
+            }
+            set_estimating.set(false);
+        });
+    };
+
+    let fetch_info = move |url: String, playlist_url: String| {
+        set_info_loading.set(true);
+        spawn_local(async move {
+            let result = tauri_api::fetch_youtube_playlist_info(&playlist_url).await;
+            if !is_current(&url) {
+                return;
+            }
+            match result {
+                Ok(fetched) => {
+                    if !name_edited.get_untracked() {
+                        set_name_input
+                            .set(suggest_playlist_name(&fetched.title, &existing_names()));
+                    }
+                    set_info.set(Some(fetched));
+                    fetch_estimate(url, playlist_url);
+                }
+                Err(e) => set_info_error.set(Some(e)),
+            }
+            set_info_loading.set(false);
+        });
+    };
+
+    let on_url_input = move |ev: web_sys::Event| {
+        let value = event_target_value(&ev);
+        set_url_input.set(value.clone());
+        set_url_error.set(None);
+        set_source_url.set(None);
+        set_info.set(None);
+        set_info_loading.set(false);
+        set_info_error.set(None);
+        set_size_estimate.set(None);
+        set_estimating.set(false);
+        set_queue_error.set(None);
+
+        let url = value.trim().to_string();
+        if url.is_empty() {
+            set_url_state.set(UrlValidationState::Idle);
+            return;
+        }
+        set_url_state.set(UrlValidationState::Validating);
+        spawn_local(async move {
+            let result = tauri_api::validate_youtube_playlist_url(&url).await;
+            if !is_current(&url) {
+                return;
+            }
+            match result {
+                Ok(validation) if validation.is_valid => {
+                    let playlist_url = validation.normalized_url.unwrap_or_else(|| url.clone());
+                    set_url_state.set(UrlValidationState::Valid);
+                    set_source_url.set(Some(playlist_url.clone()));
+                    fetch_info(url, playlist_url);
+                }
+                Ok(validation) => {
+                    set_url_state.set(UrlValidationState::Invalid);
+                    set_url_error.set(validation.error_message);
+                }
+                Err(e) => {
+                    set_url_state.set(UrlValidationState::Invalid);
+                    set_url_error.set(Some(
+                        i18n.tf("create_playlist.validation_error", &[("error", &e)]),
+                    ));
+                }
+            }
+        });
+    };
+
+    let name_error = move || {
+        let name = name_input.get();
+        validate_playlist_name(i18n.locale(), &name).or_else(|| {
+            playlists
+                .with(|list| {
+                    list.iter()
+                        .any(|p| p.name.eq_ignore_ascii_case(name.trim()))
+                })
+                .then(|| i18n.t("first_playlist.name_taken"))
+        })
+    };
+
+    // 0: waiting for a link, 1: checking it, 2: ready to name and download
+    let current_step = move || {
+        if info.with(Option::is_some) {
+            2
+        } else if url_state.get() == UrlValidationState::Idle {
+            0
+        } else {
+            1
+        }
+    };
+
+    let can_queue = move || {
+        info.with(Option::is_some)
+            && source_url.with(Option::is_some)
+            && name_error().is_none()
+            && !queueing.get()
+    };
+
+    let on_queue = move |_| {
+        let (Some(url), true) = (source_url.get_untracked(), can_queue()) else {
+            return;
+        };
+        let name = name_input.get_untracked().trim().to_string();
+        set_queueing.set(true);
+        set_queue_error.set(None);
+        spawn_local(async move {
+            match tauri_api::queue_add_to_playlist(&url, &name, None).await {
+                Ok(_) => {
+                    on_create.run(name);
+                    on_close.run(());
+                }
+                Err(e) => set_queue_error.set(Some(e)),
+            }
+            set_queueing.set(false);
+        });
+    };
+
+    let close = move || {
+        if !queueing.get_untracked() {
+            on_close.run(());
+        }
+    };
+    let dialog_ref = NodeRef::<html::Div>::new();
+    use_dialog_keyboard(is_open.into(), dialog_ref, Callback::new(move |()| close()));
+
+    let preview = move || {
+        if info_loading.get() {
+            return Some(
+                view! {
+                    <div class="first-playlist-preview loading">
+                        <span class="spinner"></span>
+                        " "
+                        {i18n.t("first_playlist.fetching")}
+                    </div>
+                }
+                .into_any(),
+            );
+        }
+        if let Some(e) = info_error.get() {
+            return Some(
+                view! {
+                    <p class="create-playlist-error-text">
+                        {i18n.tf("first_playlist.load_failed", &[("error", &e)])}
+                    </p>
+                }
+                .into_any(),
+            );
+        }
+        info.get().map(|info| {
+            let count = info.video_count;
+            let unavailable = info.health.unavailable();
+            view! {
+                <div class="first-playlist-preview">
+                    {match info.thumbnail_url {
+                        Some(url) => view! {
+                            <img class="first-playlist-cover" src=url alt="" />
+                        }.into_any(),
+                        None => view! {
+                            <div class="first-playlist-cover placeholder">
+                                <svg viewBox="0 0 24 24" width="32" height="32" fill="currentColor" aria-hidden="true">
+                                    <path d="M15 6H3v2h12V6zm0 4H3v2h12v-2zM3 16h8v-2H3v2zM17 6v8.18c-.31-.11-.65-.18-1-.18-1.66 0-3 1.34-3 3s1.34 3 3 3 3-1.34 3-3V8h3V6h-5z"/>
+                                </svg>
+                            </div>
+                        }.into_any(),
+                    }}
+                    <div class="first-playlist-details">
+                        <span class="first-playlist-title">{info.title}</span>
+                        <span class="first-playlist-count">
+                            {i18n.count("count.videos", count)}
+                        </span>
+                        {move || match size_estimate.get() {
+                            Some(estimate) => Some(estimate.summary(i18n.locale())),
+                            None => estimating.get().then(|| i18n.t("first_playlist.estimating")),
+                        }
+                        .map(|text| view! { <span class="first-playlist-size">{text}</span> })}
+                        {(unavailable > 0).then(|| view! {
+                            <span class="first-playlist-unavailable">
+                                {i18n.tf(
+                                    "first_playlist.unavailable",
+                                    &[("count", &unavailable.to_string())],
+                                )}
+                            </span>
+                        })}
+                    </div>
+                </div>
+            }
+            .into_any()
+        })
+    };
+
+    view! {
+        <div
+            class="create-playlist-dialog-overlay"
+            class:visible=move || is_open.get()
+            on:click=move |_| close()
+        >
+            <div
+                class="create-playlist-dialog first-playlist-dialog"
+                node_ref=dialog_ref
+                on:click=move |e| e.stop_propagation()
+                role="dialog"
+                aria-modal="true"
+                aria-labelledby="first-playlist-dialog-title"
+            >
+                <div class="create-playlist-dialog-header">
+                    <h2 id="first-playlist-dialog-title">{i18n.t("first_playlist.title")}</h2>
+                    <button
+                        class="btn btn-ghost btn-icon"
+                        on:click=move |_| close()
+                        disabled=move || queueing.get()
+                        aria-label=i18n.t("common.close_dialog")
+                    >
+                        <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
+                            <path d="M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 17.59 19 19 17.59 13.41 12z"/>
+                        </svg>
+                    </button>
+                </div>
+
+                <ol class="first-playlist-steps">
+                    {STEPS.into_iter().enumerate().map(|(index, label)| view! {
+                        <li
+                            class:active=move || current_step() == index
+                            class:done=move || current_step() > index
+                            aria-current=move || (current_step() == index).then_some("step")
+                        >
+                            {i18n.t(label)}
+                        </li>
+                    }).collect_view()}
+                </ol>
+
+                <div class="create-playlist-dialog-body">
+                    <div class="create-playlist-field">
+                        <label for="first-playlist-url">{i18n.t("create_playlist.url")}</label>
+                        <input
+                            id="first-playlist-url"
+                            type="url"
+                            class="create-playlist-input"
+                            class:error=move || url_state.get() == UrlValidationState::Invalid
+                            class:valid=move || url_state.get() == UrlValidationState::Valid
+                            prop:value=move || url_input.get()
+                            on:input=on_url_input
+                            placeholder="https://www.youtube.com/playlist?list=..."
+                            disabled=move || queueing.get()
+                            autocomplete="off"
+                            spellcheck="false"
+                        />
+                        <div class="create-playlist-feedback">
+                            {move || match url_state.get() {
+                                UrlValidationState::Idle => view! {
+                                    <p class="create-playlist-hint">
+                                        {i18n.t("first_playlist.url.hint")}
+                                    </p>
+                                }.into_any(),
+                                UrlValidationState::Validating => view! {
+                                    <p class="create-playlist-hint validating">{i18n.t("first_playlist.checking")}</p>
+                                }.into_any(),
+                                UrlValidationState::Valid => view! {
+                                    <p class="create-playlist-hint">{i18n.t("first_playlist.valid")}</p>
+                                }.into_any(),
+                                UrlValidationState::Invalid => view! {
+                                    <p class="create-playlist-error-text">
+                                        {url_error.get().unwrap_or_else(|| i18n.t("create_playlist.url_type.invalid"))}
+                                    </p>
+                                }.into_any(),
+                            }}
+                        </div>
+                    </div>
+
+                    {preview}
+
+                    <Show when=move || info.with(Option::is_some)>
+                        <div class="create-playlist-field">
+                            <label for="first-playlist-name">{i18n.t("create_playlist.name")}</label>
+                            <input
+                                id="first-playlist-name"
+                                type="text"
+                                class="create-playlist-input"
+                                class:error=move || name_error().is_some()
+                                prop:value=move || name_input.get()
+                                on:input=move |ev| {
+                                    set_name_edited.set(true);
+                                    set_name_input.set(event_target_value(&ev));
+                                }
+                                disabled=move || queueing.get()
+                                maxlength="255"
+                            />
+                            {move || name_error().map(|err| view! {
+                                <p class="create-playlist-error-text">{err}</p>
+                            })}
+                        </div>
+                    </Show>
+
+                    {move || queue_error.get().map(|msg| view! {
+                        <div class="create-playlist-error">
+                            <span>{msg}</span>
+                        </div>
+                    })}
+                </div>
+
+                <div class="create-playlist-dialog-footer">
+                    <button
+                        class="btn btn-secondary"
+                        on:click=move |_| close()
+                        disabled=move || queueing.get()
+                    >
+                        {i18n.t("common.cancel")}
+                    </button>
+                    <button
+                        class="btn btn-primary"
+                        on:click=on_queue
+                        disabled=move || !can_queue()
+                    >
+                        {move || i18n.t(if queueing.get() { "first_playlist.queueing" } else { "first_playlist.download" })}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggest_playlist_name_cleans_title() {
+        assert_eq!(
+            suggest_playlist_name("Road Trip: 2024", &[]),
+            "Road Trip 2024"
+        );
+        assert_eq!(
+            suggest_playlist_name("  AC/DC   best of... ", &[]),
+            "AC DC best of"
+        );
+        assert_eq!(suggest_playlist_name("???", &[]), FALLBACK_NAME);
+        assert_eq!(suggest_playlist_name("con", &[]), FALLBACK_NAME);
+        let long = "a".repeat(300);
+        assert_eq!(suggest_playlist_name(&long, &[]).len(), MAX_SUGGESTED_LEN);
+    }
+
+    #[test]
+    fn test_suggest_playlist_name_is_unique() {
+        let existing = vec!["Chill".to_string(), "chill (2)".to_string()];
+        assert_eq!(suggest_playlist_name("Chill", &existing), "Chill (3)");
+        assert_eq!(suggest_playlist_name("Focus", &existing), "Focus");
+    }
+}
@@ -0,0 +1,706 @@
+//! History page listing past downloads and syncs, with filters and a chart
+//! of the bytes downloaded per week.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::use_notifications;
+use crate::i18n::{translate, translate_count, use_i18n};
+use crate::tauri_api;
+use crate::types::{
+    HistoryEntry, HistoryFilter, HistoryFilterState, HistoryKind, HistoryReport, HistoryStats,
+    HistoryStatus, Locale, WeeklyBytes,
+};
+use crate::ui_state::use_ui_state;
+
+/// Statuses offered by the status filter, in display order.
+const STATUSES: [HistoryStatus; 4] = [
+    HistoryStatus::Completed,
+    HistoryStatus::CompletedWithErrors,
+    HistoryStatus::Failed,
+    HistoryStatus::Cancelled,
+];
+
+/// Entries older than this many days are removed by "Remove Old Entries".
+const PRUNE_AFTER_DAYS: u32 = 90;
+
+/// Format bytes to human-readable string.
+fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+/// Format Unix timestamp as a local date and time.
+fn format_date_time(timestamp: u64) -> String {
+    let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(timestamp as f64 * 1000.0));
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02}",
+        date.get_full_year(),
+        date.get_month() + 1,
+        date.get_date(),
+        date.get_hours(),
+        date.get_minutes()
+    )
+}
+
+/// Format the start of a week as a short local date.
+fn format_week(timestamp: u64) -> String {
+    let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(timestamp as f64 * 1000.0));
+    format!("{:02}-{:02}", date.get_month() + 1, date.get_date())
+}
+
+/// Current time in seconds since the Unix epoch.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn now_secs() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
+
+/// Time span covered by the date filter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateRange {
+    /// The last 7 days.
+    Week,
+    /// The last 30 days.
+    Month,
+    /// The last 90 days.
+    Quarter,
+    /// Everything in the history.
+    All,
+}
+
+impl DateRange {
+    /// Every range, in display order.
+    pub const ALL: [Self; 4] = [Self::Week, Self::Month, Self::Quarter, Self::All];
+
+    /// Message key of the label shown in the date filter.
+    #[must_use]
+    pub const fn label_key(self) -> &'static str {
+        match self {
+            Self::Week => "history.range.week",
+            Self::Month => "history.range.month",
+            Self::Quarter => "history.range.quarter",
+            Self::All => "history.range.all",
+        }
+    }
+
+    /// Number of days covered, or `None` for everything.
+    #[must_use]
+    pub const fn days(self) -> Option<u32> {
+        match self {
+            Self::Week => Some(7),
+            Self::Month => Some(30),
+            Self::Quarter => Some(90),
+            Self::All => None,
+        }
+    }
+
+    /// The range covering `days` days, or the last 30 days if no range
+    /// matches.
+    #[must_use]
+    pub fn from_days(days: Option<u32>) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|range| range.days() == days)
+            .unwrap_or(Self::Month)
+    }
+
+    /// Earliest time covered by the range, given the current time.
+    #[must_use]
+    pub fn since(self, now: u64) -> Option<u64> {
+        const DAY_SECS: u64 = 24 * 60 * 60;
+        self.days()
+            .map(|days| now.saturating_sub(u64::from(days) * DAY_SECS))
+    }
+}
+
+/// Height of each week's bar as a share (0.0 - 100.0) of the busiest week.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn bar_heights(weeks: &[WeeklyBytes]) -> Vec<f64> {
+    let max = weeks.iter().map(|w| w.bytes).max().unwrap_or(0);
+    weeks
+        .iter()
+        .map(|w| {
+            if max == 0 {
+                0.0
+            } else {
+                w.bytes as f64 / max as f64 * 100.0
+            }
+        })
+        .collect()
+}
+
+/// Totals over the listed history entries.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HistoryTotals {
+    /// Number of downloads.
+    pub downloads: usize,
+    /// Bytes downloaded.
+    pub downloaded_bytes: u64,
+    /// Number of syncs.
+    pub syncs: usize,
+    /// Bytes copied to devices.
+    pub synced_bytes: u64,
+}
+
+impl HistoryTotals {
+    /// Add up `entries`.
+    #[must_use]
+    pub fn new(entries: &[HistoryEntry]) -> Self {
+        entries.iter().fold(Self::default(), |mut totals, entry| {
+            match entry.kind {
+                HistoryKind::Download => {
+                    totals.downloads += 1;
+                    totals.downloaded_bytes += entry.bytes;
+                }
+                HistoryKind::Sync => {
+                    totals.syncs += 1;
+                    totals.synced_bytes += entry.bytes;
+                }
+            }
+            totals
+        })
+    }
+}
+
+/// One-line description of what an entry did.
+fn entry_summary(locale: Locale, entry: &HistoryEntry) -> String {
+    let mut parts = vec![match (entry.kind, entry.device_mount_point.as_deref()) {
+        (HistoryKind::Download, _) => translate(locale, "history.entry.download", &[]),
+        (HistoryKind::Sync, Some(device)) => {
+            translate(locale, "history.entry.sync_to", &[("device", device)])
+        }
+        (HistoryKind::Sync, None) => translate(locale, "history.entry.sync", &[]),
+    }];
+    if entry.items_succeeded > 0 || entry.items_failed > 0 {
+        let noun = match entry.kind {
+            HistoryKind::Download => "count.tracks",
+            HistoryKind::Sync => "count.files",
+        };
+        parts.push(translate_count(locale, noun, entry.items_succeeded));
+        if entry.items_failed > 0 {
+            parts.push(translate_count(locale, "count.failed", entry.items_failed));
+        }
+        parts.push(format_bytes(entry.bytes));
+        if let Some(ms) = entry.elapsed_ms.filter(|&ms| ms > 0) {
+            parts.push(format!(
+                "{}/s",
+                format_bytes(entry.bytes.saturating_mul(1000) / ms)
+            ));
+        }
+    }
+    parts.join(" · ")
+}
+
+/// One-line description of the totals over the whole history.
+fn stats_summary(locale: Locale, stats: &HistoryStats) -> String {
+    let mut parts = vec![
+        translate(
+            locale,
+            "history.stats.this_month",
+            &[("size", &format_bytes(stats.bytes_this_month))],
+        ),
+        translate(
+            locale,
+            "history.stats.total",
+            &[("size", &format_bytes(stats.total_bytes))],
+        ),
+        translate_count(locale, "count.tracks", stats.videos_downloaded),
+    ];
+    if stats.videos_failed > 0 {
+        parts.push(translate_count(locale, "count.failed", stats.videos_failed));
+    }
+    if let Some(bps) = stats.average_speed_bps {
+        parts.push(translate(
+            locale,
+            "history.stats.average_speed",
+            &[("speed", &format!("{}/s", format_bytes(bps)))],
+        ));
+    }
+    parts.join(" · ")
+}
+
+/// CSS modifier for an entry's status badge.
+const fn status_class(status: HistoryStatus) -> &'static str {
+    match status {
+        HistoryStatus::Completed => "history-status completed",
+        HistoryStatus::CompletedWithErrors => "history-status partial",
+        HistoryStatus::Failed => "history-status failed",
+        HistoryStatus::Cancelled => "history-status cancelled",
+    }
+}
+
+/// History of past downloads and syncs.
+///
+/// Filters apply to both the list and the chart.
+#[component]
+
+pub fn HistoryView(
+    /// Callback when the back button is clicked.
+    on_back: Callback<()>,
+) -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+    let ui_state = use_ui_state();
+
+    // Start from the filters used last time
+    let saved = ui_state.get_untracked().history_filter;
+    let (range, set_range) = signal(DateRange::from_days(saved.range_days));
+    let (kind, set_kind) = signal(saved.kind);
+    let (status, set_status) = signal(saved.status);
+    let (playlist, set_playlist) = signal::<Option<String>>(None);
+    let (report, set_report) = signal::<Option<HistoryReport>>(None);
+    let (stats, set_stats) = signal::<Option<HistoryStats>>(None);
+    let (error, set_error) = signal::<Option<String>>(None);
+    let (refresh, set_refresh) = signal(0_u32);
+    let (confirm_clear, set_confirm_clear) = signal(false);
+
+    Effect::new(move || {
+        let filter = HistoryFilterState {
+            range_days: range.get().days(),
+            kind: kind.get(),
+            status: status.get(),
+        };
+        ui_state.update(|state| state.history_filter = filter);
+    });
+
+    Effect::new(move || {
+        let _refresh = refresh.get();
+        let filter = HistoryFilter {
+            kind: kind.get(),
+            status: status.get(),
+            playlist: playlist.get(),
+            since: range.get().since(now_secs()),
+            until: None,
+            video_id: None,
+        };
+        spawn_local(async move {
+            match tauri_api::get_history(&filter).await {
+                Ok(r) => {
+                    set_error.set(None);
+                    set_report.set(Some(r));
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    });
+
+    Effect::new(move || {
+        let _refresh = refresh.get();
+        spawn_local(async move {
+            match tauri_api::get_history_stats().await {
+                Ok(s) => set_stats.set(Some(s)),
+                Err(e) => leptos::logging::error!("Failed to load history stats: {}", e),
+            }
+        });
+    });
+
+    let prune = move |_| {
+        spawn_local(async move {
+            match tauri_api::prune_history(PRUNE_AFTER_DAYS).await {
+                Ok(removed) => {
+                    notifications.success(i18n.tf(
+                        "history.pruned",
+                        &[
+                            ("count", &removed.to_string()),
+                            ("days", &PRUNE_AFTER_DAYS.to_string()),
+                        ],
+                    ));
+                    set_refresh.update(|n| *n += 1);
+                }
+                Err(e) => notifications.error(i18n.tf("history.prune_failed", &[("error", &e)])),
+            }
+        });
+    };
+
+    let clear = move |_| {
+        set_confirm_clear.set(false);
+        spawn_local(async move {
+            match tauri_api::clear_history().await {
+                Ok(removed) => {
+                    notifications.success(i18n.count("history.cleared", removed));
+                    set_playlist.set(None);
+                    set_refresh.update(|n| *n += 1);
+                }
+                Err(e) => notifications.error(i18n.tf("history.clear_failed", &[("error", &e)])),
+            }
+        });
+    };
+
+    let totals = move || {
+        report.with(|r| {
+            r.as_ref()
+                .map_or_else(HistoryTotals::default, |r| HistoryTotals::new(&r.entries))
+        })
+    };
+
+    let chart = move || {
+        let weeks = report.with(|r| {
+            r.as_ref()
+                .map(|r| r.weekly_download_bytes.clone())
+                .unwrap_or_default()
+        });
+        let heights = bar_heights(&weeks);
+        weeks
+            .into_iter()
+            .zip(heights)
+            .map(|(week, height)| {
+                view! {
+                    <div
+                        class="history-chart-column"
+                        title=i18n.tf(
+                            "history.chart.week",
+                            &[
+                                ("week", &format_week(week.week_start)),
+                                ("size", &format_bytes(week.bytes)),
+                            ],
+                        )
+                    >
+                        <div class="history-chart-track">
+                            <div class="history-chart-bar" style:height=format!("{height:.1}%")></div>
+                        </div>
+                        <span class="history-chart-label">{format_week(week.week_start)}</span>
+                    </div>
+                }
+            })
+            .collect_view()
+    };
+
+    let entries = move || {
+        if let Some(e) = error.get() {
+            return view! { <p class="device-detail-error">{e}</p> }.into_any();
+        }
+        let Some(r) = report.get() else {
+            return view! { <p class="device-detail-muted">{i18n.t("history.loading")}</p> }
+                .into_any();
+        };
+        if r.entries.is_empty() {
+            return view! {
+                <p class="device-detail-muted">{i18n.t("history.no_matches")}</p>
+            }
+            .into_any();
+        }
+        view! {
+            <ul class="history-list">
+                {r.entries.into_iter().map(|entry| {
+                    let summary = entry_summary(i18n.locale(), &entry);
+                    view! {
+                        <li class="history-item">
+                            <div class="history-item-main">
+                                <span class="history-item-title">{entry.playlists.join(", ")}</span>
+                                <span class="device-detail-muted">{summary}</span>
+                                {entry.error.map(|e| view! {
+                                    <span class="history-item-error">{e}</span>
+                                })}
+                            </div>
+                            <div class="history-item-meta">
+                                <span class=status_class(entry.status)>
+                                    {i18n.t(entry.status.label_key())}
+                                </span>
+                                <span class="device-detail-muted">{format_date_time(entry.finished_at)}</span>
+                            </div>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+        }
+        .into_any()
+    };
+
+    view! {
+        <div class="history-view">
+            <div class="playlist-detail-header-top">
+                <button
+                    class="btn btn-ghost playlist-back-btn"
+                    on:click=move |_| on_back.run(())
+                    aria-label=move || i18n.t("common.go_back")
+                >
+                    <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
+                        <path d="M20 11H7.83l5.59-5.59L12 4l-8 8 8 8 1.41-1.41L7.83 13H20v-2z"/>
+                    </svg>
+                    {move || i18n.t("common.back")}
+                </button>
+                <div class="playlist-detail-actions">
+                    <button
+                        class="btn btn-secondary"
+                        on:click=move |_| set_refresh.update(|n| *n += 1)
+                    >
+                        {move || i18n.t("common.refresh")}
+                    </button>
+                    <button
+                        class="btn btn-secondary"
+                        on:click=prune
+                        disabled=move || report.with(|r| r.as_ref().is_none_or(|r| r.playlists.is_empty()))
+                        title=move || {
+                            i18n.tf("history.prune_hint", &[("days", &PRUNE_AFTER_DAYS.to_string())])
+                        }
+                    >
+                        {move || i18n.t("history.prune")}
+                    </button>
+                    <button
+                        class="btn btn-secondary"
+                        on:click=move |_| set_confirm_clear.set(true)
+                        disabled=move || report.with(|r| r.as_ref().is_none_or(|r| r.playlists.is_empty()))
+                    >
+                        {move || i18n.t("history.clear")}
+                    </button>
+                </div>
+            </div>
+
+            <h1 class="device-detail-name">{move || i18n.t("nav.history")}</h1>
+            {move || stats.get().filter(|s| s.downloads > 0).map(|s| view! {
+                <p class="device-detail-muted history-stats">{stats_summary(i18n.locale(), &s)}</p>
+            })}
+
+            {move || confirm_clear.get().then(|| view! {
+                <div class="device-clean-confirm">
+                    <p>{i18n.t("history.clear_confirm")}</p>
+                    <div class="device-clean-confirm-actions">
+                        <button class="btn btn-secondary" on:click=move |_| set_confirm_clear.set(false)>
+                            {i18n.t("common.cancel")}
+                        </button>
+                        <button class="btn btn-danger" on:click=clear>{i18n.t("common.clear")}</button>
+                    </div>
+                </div>
+            })}
+
+            <div class="history-filters">
+                <label class="history-filter">
+                    <span>{move || i18n.t("history.filter.date")}</span>
+                    <select
+                        class="history-filter-select"
+                        on:change=move |ev| {
+                            if let Some(r) = event_target_value(&ev)
+                                .parse::<usize>()
+                                .ok()
+                                .and_then(|i| DateRange::ALL.get(i).copied())
+                            {
+                                set_range.set(r);
+                            }
+                        }
+                    >
+                        {DateRange::ALL.into_iter().enumerate().map(|(i, r)| view! {
+                            <option value=i.to_string() selected=move || range.get() == r>
+                                {move || i18n.t(r.label_key())}
+                            </option>
+                        }).collect_view()}
+                    </select>
+                </label>
+                <label class="history-filter">
+                    <span>{move || i18n.t("history.filter.type")}</span>
+                    <select
+                        class="history-filter-select"
+                        on:change=move |ev| {
+                            set_kind.set(match event_target_value(&ev).as_str() {
+                                "download" => Some(HistoryKind::Download),
+                                "sync" => Some(HistoryKind::Sync),
+                                _ => None,
+                            });
+                        }
+                    >
+                        <option value="">{move || i18n.t("history.filter.all_kinds")}</option>
+                        <option value="download">{move || i18n.t("history.filter.downloads")}</option>
+                        <option value="sync">{move || i18n.t("history.filter.syncs")}</option>
+                    </select>
+                </label>
+                <label class="history-filter">
+                    <span>{move || i18n.t("history.filter.status")}</span>
+                    <select
+                        class="history-filter-select"
+                        on:change=move |ev| {
+                            set_status.set(
+                                event_target_value(&ev)
+                                    .parse::<usize>()
+                                    .ok()
+                                    .and_then(|i| STATUSES.get(i).copied()),
+                            );
+                        }
+                    >
+                        <option value="">{move || i18n.t("history.filter.any_status")}</option>
+                        {STATUSES.into_iter().enumerate().map(|(i, s)| view! {
+                            <option value=i.to_string()>{move || i18n.t(s.label_key())}</option>
+                        }).collect_view()}
+                    </select>
+                </label>
+                <label class="history-filter">
+                    <span>{move || i18n.t("history.filter.playlist")}</span>
+                    <select
+                        class="history-filter-select"
+                        on:change=move |ev| {
+                            let value = event_target_value(&ev);
+                            set_playlist.set(Some(value).filter(|v| !v.is_empty()));
+                        }
+                    >
+                        <option value="" selected=move || playlist.get().is_none()>
+                            {move || i18n.t("history.filter.all_playlists")}
+                        </option>
+                        {move || {
+                            let names = report.with(|r| {
+                                r.as_ref().map(|r| r.playlists.clone()).unwrap_or_default()
+                            });
+                            names.into_iter().map(|name| {
+                                let selected_name = name.clone();
+                                view! {
+                                    <option
+                                        value=name.clone()
+                                        selected=move || playlist.get().as_ref() == Some(&selected_name)
+                                    >
+                                        {name}
+                                    </option>
+                                }
+                            }).collect_view()
+                        }}
+                    </select>
+                </label>
+            </div>
+
+            <section class="device-detail-section">
+                <h2>{move || i18n.t("history.chart.title")}</h2>
+                <p class="device-detail-muted">
+                    {move || {
+                        let t = totals();
+                        [
+                            i18n.count("count.downloads", t.downloads),
+                            format_bytes(t.downloaded_bytes),
+                            i18n.count("count.syncs", t.syncs),
+                            i18n.tf("history.copied", &[("size", &format_bytes(t.synced_bytes))]),
+                        ]
+                        .join(" · ")
+                    }}
+                </p>
+                <div class="history-chart">{chart}</div>
+            </section>
+
+            <section class="device-detail-section">
+                <h2>{move || i18n.t("nav.activity")}</h2>
+                {entries}
+            </section>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(kind: HistoryKind, bytes: u64) -> HistoryEntry {
+        HistoryEntry {
+            id: 0,
+            kind,
+            status: HistoryStatus::Completed,
+            finished_at: 0,
+            playlists: vec!["Mix".to_string()],
+            source_url: None,
+            device_mount_point: None,
+            items_succeeded: 1,
+            items_failed: 0,
+            bytes,
+            error: None,
+            elapsed_ms: None,
+            videos: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_date_range_since() {
+        let now = 100 * 24 * 60 * 60;
+        assert_eq!(DateRange::Week.since(now), Some(93 * 24 * 60 * 60));
+        assert_eq!(DateRange::Quarter.since(now), Some(10 * 24 * 60 * 60));
+        assert_eq!(DateRange::Quarter.since(60), Some(0));
+        assert_eq!(DateRange::All.since(now), None);
+    }
+
+    #[test]
+    fn test_date_range_days() {
+        for range in DateRange::ALL {
+            assert_eq!(DateRange::from_days(range.days()), range);
+        }
+        assert_eq!(DateRange::from_days(Some(12)), DateRange::Month);
+    }
+
+    #[test]
+    fn test_bar_heights() {
+        let weeks = [
+            WeeklyBytes {
+                week_start: 0,
+                bytes: 0,
+            },
+            WeeklyBytes {
+                week_start: 1,
+                bytes: 50,
+            },
+            WeeklyBytes {
+                week_start: 2,
+                bytes: 200,
+            },
+        ];
+        let heights = bar_heights(&weeks);
+        assert!(heights[0].abs() < f64::EPSILON);
+        assert!((heights[1] - 25.0).abs() < f64::EPSILON);
+        assert!((heights[2] - 100.0).abs() < f64::EPSILON);
+
+        let empty = bar_heights(&weeks[..1]);
+        assert!(empty[0].abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_history_totals() {
+        let totals = HistoryTotals::new(&[
+            entry(HistoryKind::Download, 10),
+            entry(HistoryKind::Download, 5),
+            entry(HistoryKind::Sync, 7),
+        ]);
+        assert_eq!(
+            totals,
+            HistoryTotals {
+                downloads: 2,
+                downloaded_bytes: 15,
+                syncs: 1,
+                synced_bytes: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_summary() {
+        let stats = HistoryStats {
+            downloads: 2,
+            videos_downloaded: 12,
+            videos_failed: 1,
+            total_bytes: 3 * 1024 * 1024,
+            bytes_this_month: 2048,
+            average_speed_bps: Some(1024),
+            ..HistoryStats::default()
+        };
+        assert_eq!(
+            stats_summary(Locale::En, &stats),
+            "2.0 KB this month · 3.0 MB in total · 12 tracks · 1 failed · 1.0 KB/s on average"
+        );
+    }
+
+    #[test]
+    fn test_entry_summary() {
+        let mut sync = entry(HistoryKind::Sync, 2048);
+        sync.device_mount_point = Some("/media/player".to_string());
+        sync.items_failed = 2;
+        assert_eq!(
+            entry_summary(Locale::En, &sync),
+            "Sync to /media/player · 1 file · 2 failed · 2.0 KB"
+        );
+        assert_eq!(
+            entry_summary(Locale::Fr, &entry(HistoryKind::Download, 10)),
+            "Téléchargement · 1 piste · 10 B"
+        );
+    }
+}
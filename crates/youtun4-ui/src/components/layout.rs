@@ -4,6 +4,8 @@
 
 use leptos::prelude::*;
 
+use crate::i18n::use_i18n;
+
 /// Provides access to the mobile menu state for child components.
 #[derive(Clone, Copy)]
 pub struct MobileMenuContext {
@@ -54,7 +56,20 @@ pub fn Layout(
     /// Callback when settings button is clicked.
     #[prop(optional)]
     on_settings_click: Option<Callback<()>>,
+    /// Callback when the "stop everything" button is clicked.
+    #[prop(optional)]
+    on_stop_all_click: Option<Callback<()>>,
+    /// Whether the sidebar is collapsed on desktop.
+    #[prop(optional, into)]
+    sidebar_collapsed: Option<Signal<bool>>,
+    /// Callback when the sidebar toggle is clicked. The toggle is only shown
+    /// when this is set.
+    #[prop(optional)]
+    on_sidebar_toggle: Option<Callback<()>>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
+    let is_collapsed = move || sidebar_collapsed.is_some_and(|collapsed| collapsed.get());
+
     // Mobile menu state
     let (mobile_menu_open, set_mobile_menu_open) = signal(false);
 
@@ -81,14 +96,21 @@ pub fn Layout(
         }
     };
 
+    // Handle stop everything click
+    let handle_stop_all_click = move |_| {
+        if let Some(cb) = on_stop_all_click {
+            cb.run(());
+        }
+    };
+
     view! {
-        <div class="layout">
+        <div class="layout" class:sidebar-collapsed=is_collapsed>
             // Header with mobile menu toggle and window drag region
             <header class="layout-header" data-tauri-drag-region="true">
                 <button
                     class="layout-menu-toggle btn btn-ghost btn-icon"
                     on:click=toggle_menu
-                    aria-label="Toggle menu"
+                    aria-label=move || i18n.t("header.toggle_menu")
                     aria-expanded=move || mobile_menu_open.get().to_string()
                 >
                     <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
@@ -105,15 +127,41 @@ pub fn Layout(
                         }}
                     </svg>
                 </button>
+                {on_sidebar_toggle.map(|toggle| view! {
+                    <button
+                        class="layout-sidebar-toggle btn btn-ghost btn-icon"
+                        title=move || i18n.t("header.toggle_sidebar")
+                        aria-label=move || i18n.t("header.toggle_sidebar")
+                        aria-expanded=move || (!is_collapsed()).to_string()
+                        on:click=move |_| toggle.run(())
+                    >
+                        <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
+                            <path d="M3 5v14h18V5H3zm2 2h4v10H5V7zm6 10V7h8v10h-8z"/>
+                        </svg>
+                    </button>
+                })}
                 <div class="logo">
                     <span class="logo-text">"Youtun4"</span>
                 </div>
-                // Header actions (settings button)
+                // Header actions (stop everything and settings buttons)
                 <div class="layout-header-actions">
+                    {on_stop_all_click.is_some().then(|| view! {
+                        <button
+                            class="btn btn-ghost btn-icon"
+                            title=move || i18n.t("header.stop_all")
+                            aria-label=move || i18n.t("header.stop_all")
+                            on:click=handle_stop_all_click
+                        >
+                            <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
+                                <path d="M6 6h12v12H6z"/>
+                            </svg>
+                        </button>
+                    })}
                     {on_settings_click.is_some().then(|| view! {
                         <button
                             class="btn btn-ghost btn-icon"
-                            title="Settings"
+                            title=move || i18n.t("header.settings")
+                            aria-label=move || i18n.t("header.settings")
                             on:click=handle_settings_click
                         >
                             <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
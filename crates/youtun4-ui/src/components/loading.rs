@@ -10,6 +10,8 @@
 
 use leptos::prelude::*;
 
+use crate::i18n::use_i18n;
+
 /// Loading state enum for components that need loading/loaded/error states.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum LoadingState {
@@ -237,6 +239,7 @@ where
     F: Fn() -> IV + 'static,
     IV: IntoView + 'static,
 {
+    let i18n = use_i18n();
     let is_loading = state == LoadingState::Loading;
     let is_error = state == LoadingState::Error;
     let is_loaded = state == LoadingState::Loaded;
@@ -277,8 +280,8 @@ where
                         <path d="M12 2C6.48 2 2 6.48 2 12s4.48 10 10 10 10-4.48 10-10S17.52 2 12 2zm1 15h-2v-2h2v2zm0-4h-2V7h2v6z"/>
                     </svg>
                 </div>
-                <h3 class="content-loader-error-title">"Something went wrong"</h3>
-                <p class="content-loader-error-message">"Please try again"</p>
+                <h3 class="content-loader-error-title">{move || i18n.t("loading.error.title")}</h3>
+                <p class="content-loader-error-message">{move || i18n.t("playlist_selection.error.message")}</p>
             </div>
 
             // Loaded content
@@ -0,0 +1,255 @@
+//! Live view of the application log, for diagnosing failed downloads without
+//! opening the log files.
+
+use leptos::html;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::use_notifications;
+use crate::i18n::use_i18n;
+use crate::tauri_api;
+use crate::types::LogEntry;
+
+/// Number of log lines fetched on each refresh.
+const LOG_LINES: usize = 500;
+
+/// Delay between log refreshes, in milliseconds.
+const REFRESH_INTERVAL_MS: u32 = 2000;
+
+/// Distance from the bottom, in pixels, still counted as scrolled to the end.
+const BOTTOM_THRESHOLD_PX: i32 = 24;
+
+/// Minimum levels offered in the filter, as (value, label key).
+const LEVEL_FILTERS: &[(&str, &str)] = &[
+    ("", "logs.level.all"),
+    ("debug", "logs.level.debug"),
+    ("info", "logs.level.info"),
+    ("warn", "logs.level.warn"),
+    ("error", "logs.level.error"),
+];
+
+/// Format entries as plain text, one line per entry, for the clipboard.
+#[must_use]
+pub fn entries_to_text(entries: &[LogEntry]) -> String {
+    entries
+        .iter()
+        .map(|e| format!("{} {} {}: {}", e.timestamp, e.level, e.target, e.message))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Whether a scroll container is scrolled to (or within a few pixels of) its
+/// end.
+#[must_use]
+pub const fn is_at_bottom(scroll_top: i32, client_height: i32, scroll_height: i32) -> bool {
+    scroll_height - (scroll_top + client_height) <= BOTTOM_THRESHOLD_PX
+}
+
+/// CSS class for an entry's level, e.g. "log-level-warn".
+fn level_class(level: &str) -> String {
+    format!("log-entry log-level-{}", level.to_ascii_lowercase())
+}
+
+/// Log viewer that refreshes the most recent entries while it is shown.
+///
+/// Features:
+/// - Minimum level filter
+/// - Auto-scroll to new entries, paused while scrolled up
+/// - Copy the shown entries to the clipboard
+/// - Open the log directory
+#[component]
+
+pub fn LogViewer() -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+    let list_ref = NodeRef::<html::Div>::new();
+    let (entries, set_entries) = signal::<Vec<LogEntry>>(vec![]);
+    let (level, set_level) = signal(String::new());
+    let (auto_scroll, set_auto_scroll) = signal(true);
+    let (error, set_error) = signal::<Option<String>>(None);
+
+    let refresh = move || {
+        spawn_local(async move {
+            let Some(filter) = level.try_get_untracked() else {
+                return;
+            };
+            let filter = (!filter.is_empty()).then_some(filter);
+            match tauri_api::get_recent_logs(LOG_LINES, filter.as_deref()).await {
+                Ok(latest) => {
+                    set_error.try_set(None);
+                    // Skip the update when nothing changed, to keep the scroll position
+                    if entries.try_with_untracked(|current| *current != latest) == Some(true) {
+                        set_entries.try_set(latest);
+                    }
+                }
+                Err(e) => {
+                    set_error.try_set(Some(e));
+                }
+            }
+        });
+    };
+
+    // Poll for new entries until the viewer is removed
+    refresh();
+    spawn_local(async move {
+        loop {
+            gloo_timers::future::TimeoutFuture::new(REFRESH_INTERVAL_MS).await;
+            if level.try_get_untracked().is_none() {
+                break;
+            }
+            refresh();
+        }
+    });
+
+    // Keep the newest entry in view
+    Effect::new(move || {
+        entries.track();
+        if !auto_scroll.get() {
+            return;
+        }
+        request_animation_frame(move || {
+            if let Some(list) = list_ref.get_untracked() {
+                list.set_scroll_top(list.scroll_height());
+            }
+        });
+    });
+
+    let on_scroll = move |_| {
+        if let Some(list) = list_ref.get_untracked() {
+            let at_bottom = is_at_bottom(
+                list.scroll_top(),
+                list.client_height(),
+                list.scroll_height(),
+            );
+            if at_bottom != auto_scroll.get_untracked() {
+                set_auto_scroll.set(at_bottom);
+            }
+        }
+    };
+
+    let on_copy = move |_| {
+        let text = entries.with_untracked(|entries| entries_to_text(entries));
+        spawn_local(async move {
+            match tauri_api::copy_logs_to_clipboard(&text).await {
+                Ok(()) => notifications.success(i18n.t("logs.copied")),
+                Err(e) => notifications.error(i18n.tf("logs.copy_failed", &[("error", &e)])),
+            }
+        });
+    };
+
+    let on_open_directory = move |_| {
+        spawn_local(async move {
+            if let Err(e) = tauri_api::open_log_directory().await {
+                notifications.error(i18n.tf("logs.open_folder_failed", &[("error", &e)]));
+            }
+        });
+    };
+
+    view! {
+        <div class="log-viewer">
+            <div class="log-viewer-toolbar">
+                <select
+                    class="history-filter-select"
+                    aria-label=i18n.t("logs.level")
+                    on:change=move |ev| {
+                        set_level.set(event_target_value(&ev));
+                        refresh();
+                    }
+                >
+                    {LEVEL_FILTERS.iter().map(|(value, label)| view! {
+                        <option value=*value selected=move || level.get() == *value>{i18n.t(label)}</option>
+                    }).collect_view()}
+                </select>
+                <label class="log-viewer-autoscroll">
+                    <input
+                        type="checkbox"
+                        checked=move || auto_scroll.get()
+                        on:change=move |ev| set_auto_scroll.set(event_target_checked(&ev))
+                    />
+                    {i18n.t("logs.auto_scroll")}
+                </label>
+                <div class="log-viewer-actions">
+                    <button
+                        class="btn btn-secondary btn-sm"
+                        on:click=on_copy
+                        disabled=move || entries.with(Vec::is_empty)
+                    >
+                        {i18n.t("logs.copy")}
+                    </button>
+                    <button class="btn btn-ghost btn-sm" on:click=on_open_directory>
+                        {i18n.t("logs.open_folder")}
+                    </button>
+                </div>
+            </div>
+
+            {move || error.get().map(|e| view! {
+                <p class="log-viewer-message">{i18n.tf("logs.read_failed", &[("error", &e)])}</p>
+            })}
+
+            <div
+                class="log-viewer-entries"
+                node_ref=list_ref
+                on:scroll=on_scroll
+                role="log"
+                aria-live="polite"
+            >
+                {move || {
+                    let list = entries.get();
+                    if list.is_empty() {
+                        return view! { <p class="log-viewer-message">{i18n.t("logs.empty")}</p> }.into_any();
+                    }
+                    list.into_iter().map(|entry| {
+                        let class = level_class(&entry.level);
+                        view! {
+                            <div class=class>
+                                <span class="log-entry-time">{entry.timestamp}</span>
+                                <span class="log-entry-level">{entry.level}</span>
+                                <span class="log-entry-target">{entry.target}</span>
+                                <span class="log-entry-message">{entry.message}</span>
+                            </div>
+                        }
+                    }).collect_view().into_any()
+                }}
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(level: &str, message: &str) -> LogEntry {
+        LogEntry {
+            timestamp: "2026-10-14T09:30:00Z".to_string(),
+            level: level.to_string(),
+            target: "youtun4::download".to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_entries_to_text() {
+        let entries = [entry("INFO", "Started"), entry("ERROR", "Failed: timeout")];
+        assert_eq!(
+            entries_to_text(&entries),
+            "2026-10-14T09:30:00Z INFO youtun4::download: Started\n\
+             2026-10-14T09:30:00Z ERROR youtun4::download: Failed: timeout"
+        );
+        assert_eq!(entries_to_text(&[]), "");
+    }
+
+    #[test]
+    fn test_is_at_bottom() {
+        assert!(is_at_bottom(600, 400, 1000));
+        assert!(is_at_bottom(590, 400, 1000));
+        assert!(!is_at_bottom(100, 400, 1000));
+        // Content shorter than the container
+        assert!(is_at_bottom(0, 400, 200));
+    }
+
+    #[test]
+    fn test_level_class() {
+        assert_eq!(level_class("WARN"), "log-entry log-level-warn");
+    }
+}
@@ -1,29 +1,52 @@
 //! UI components for `Youtun4`.
 
+pub mod batch_actions;
 pub mod button;
 pub mod confirm_dialog;
+pub mod context_menu;
 pub mod create_playlist_dialog;
+pub mod device_banner;
+pub mod device_detail;
 pub mod device_list;
 pub mod device_status_indicator;
 pub mod download_progress_panel;
 pub mod empty_state;
+pub mod first_playlist_dialog;
 pub mod header;
+pub mod history_view;
 pub mod layout;
 pub mod loading;
+pub mod log_viewer;
 pub mod navigation;
+pub mod onboarding_wizard;
+pub mod operation_progress_dialog;
 pub mod playlist_card;
 pub mod playlist_detail;
+pub mod playlist_grid;
 pub mod playlist_list;
 pub mod playlist_selection;
+pub mod playlist_settings;
+pub mod queue_panel;
+pub mod search_bar;
 pub mod settings_panel;
+pub mod shortcuts;
+pub mod storage_view;
 pub mod sync_button;
+pub mod sync_conflict_dialog;
+pub mod sync_wizard;
 pub mod toast;
 pub mod track_list;
+pub mod track_player;
 pub mod transfer_progress_panel;
+pub mod undo_history;
 
+pub use batch_actions::{BatchAction, BatchActionBar, ListSelection, batch_summary};
 pub use button::Button;
-pub use confirm_dialog::{ConfirmDialog, DeletePlaylistDialog};
+pub use confirm_dialog::{ConfirmDialog, DeletePlaylistDialog, RenamePlaylistDialog};
+pub use context_menu::{ContextMenu, ContextMenuItem};
 pub use create_playlist_dialog::CreatePlaylistDialog;
+pub use device_banner::DeviceBanner;
+pub use device_detail::DeviceDetailView;
 pub use device_list::DeviceList;
 pub use device_status_indicator::{
     ConnectionStatus, DeviceStatusIndicator, DeviceStatusIndicatorCompact,
@@ -36,7 +59,9 @@ pub use empty_state::{
     NoDeviceEmptyState, NoPlaylistsEmptyState, NoSearchResultsEmptyState, NoTracksEmptyState,
     NothingToSyncEmptyState,
 };
+pub use first_playlist_dialog::FirstPlaylistDialog;
 pub use header::Header;
+pub use history_view::HistoryView;
 pub use layout::{
     ContentHeader, ContentSection, Layout, LayoutHeaderActions, LayoutMain, LayoutSidebar,
     MobileMenuContext, ResponsiveGrid,
@@ -45,17 +70,30 @@ pub use loading::{
     ButtonLoader, ContentLoader, InlineLoader, LoadingIndicator, LoadingOverlay, LoadingState,
     Skeleton, SkeletonBlock, SkeletonListItem, SkeletonText, Spinner,
 };
+pub use log_viewer::LogViewer;
 pub use navigation::{NavItem, NavSection, icons as nav_icons};
-pub use playlist_card::PlaylistCard;
+pub use onboarding_wizard::OnboardingWizard;
+pub use operation_progress_dialog::{OperationProgressDialog, RunningOperation};
+pub use playlist_card::{PlaylistAction, PlaylistCard};
 pub use playlist_detail::{PlaylistDetailState, PlaylistDetailView};
+pub use playlist_grid::{PlaylistGridTile, PlaylistViewControls};
 pub use playlist_list::{PlaylistList, PlaylistListState};
 pub use playlist_selection::{
     PlaylistSelectionCard, PlaylistSelectionList, PlaylistSelectionState, PlaylistSelectionSummary,
 };
+pub use playlist_settings::PlaylistSettings;
+pub use queue_panel::QueuePanel;
+pub use search_bar::SearchBar;
 pub use settings_panel::SettingsPanel;
+pub use shortcuts::{ShortcutCheatsheet, use_dialog_keyboard};
+pub use storage_view::StorageView;
 pub use sync_button::SyncButton;
+pub use sync_conflict_dialog::{SyncConflictDialog, sync_conflicts};
+pub use sync_wizard::SyncWizard;
 pub use toast::{NotificationContext, NotificationProvider, ToastContainer, use_notifications};
-pub use track_list::{TrackItemCompact, TrackList, TrackListState};
+pub use track_list::{TrackAction, TrackItemCompact, TrackList, TrackListState};
+pub use track_player::TrackPlayer;
 pub use transfer_progress_panel::{
     TransferPanelState, TransferProgressIndicator, TransferProgressPanel,
 };
+pub use undo_history::UndoHistory;
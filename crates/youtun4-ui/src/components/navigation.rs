@@ -17,7 +17,7 @@ pub fn NavSection(
     view! {
         <div class="nav-section">
             <h3 class="nav-section-title">{title}</h3>
-            <nav class="nav-section-items">
+            <nav class="nav-section-items" aria-label=title.clone()>
                 {children()}
             </nav>
         </div>
@@ -40,6 +40,15 @@ pub fn NavItem(
     /// Click handler.
     #[prop(optional)]
     on_click: Option<Callback<()>>,
+    /// Number of background tasks shown next to the label, hidden at zero.
+    #[prop(optional, into)]
+    badge: Option<Signal<usize>>,
+    /// Whether to show a spinner for work in progress.
+    #[prop(optional, into)]
+    busy: Option<Signal<bool>>,
+    /// Accessible description of the badge and spinner.
+    #[prop(optional, into)]
+    status: Option<Signal<String>>,
 ) -> impl IntoView {
     let handle_click = move |_| {
         if let Some(callback) = on_click {
@@ -51,14 +60,28 @@ pub fn NavItem(
         <button
             class="nav-item"
             class:active=active
+            aria-current=active.then_some("page")
             on:click=handle_click
         >
             {icon.map(|path| view! {
-                <svg class="nav-item-icon" viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
+                <svg class="nav-item-icon" aria-hidden="true" viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
                     <path d=path />
                 </svg>
             })}
             <span class="nav-item-label">{label}</span>
+            {busy.map(|busy| view! {
+                <Show when=move || busy.get()>
+                    <span class="nav-item-spinner spinner" aria-hidden="true"></span>
+                </Show>
+            })}
+            {badge.map(|badge| view! {
+                <Show when=move || { badge.get() > 0 }>
+                    <span class="nav-item-badge" aria-hidden="true">{move || badge.get()}</span>
+                </Show>
+            })}
+            {status.map(|status| view! {
+                <span class="sr-only">{move || status.get()}</span>
+            })}
         </button>
     }
 }
@@ -75,6 +98,11 @@ pub mod icons {
     pub const SETTINGS: &str = "M19.14 12.94c.04-.31.06-.63.06-.94 0-.31-.02-.63-.06-.94l2.03-1.58c.18-.14.23-.41.12-.61l-1.92-3.32c-.12-.22-.37-.29-.59-.22l-2.39.96c-.5-.38-1.03-.7-1.62-.94l-.36-2.54c-.04-.24-.24-.41-.48-.41h-3.84c-.24 0-.43.17-.47.41l-.36 2.54c-.59.24-1.13.57-1.62.94l-2.39-.96c-.22-.08-.47 0-.59.22L2.74 8.87c-.12.21-.08.47.12.61l2.03 1.58c-.04.31-.06.63-.06.94s.02.63.06.94l-2.03 1.58c-.18.14-.23.41-.12.61l1.92 3.32c.12.22.37.29.59.22l2.39-.96c.5.38 1.03.7 1.62.94l.36 2.54c.05.24.24.41.48.41h3.84c.24 0 .44-.17.47-.41l.36-2.54c.59-.24 1.13-.56 1.62-.94l2.39.96c.22.08.47 0 .59-.22l1.92-3.32c.12-.22.07-.47-.12-.61l-2.01-1.58zM12 15.6c-1.98 0-3.6-1.62-3.6-3.6s1.62-3.6 3.6-3.6 3.6 1.62 3.6 3.6-1.62 3.6-3.6 3.6z";
     /// Download icon.
     pub const DOWNLOAD: &str = "M19 9h-4V3H9v6H5l7 7 7-7zM5 18v2h14v-2H5z";
+    /// History icon.
+    pub const HISTORY: &str = "M13 3c-4.97 0-9 4.03-9 9H1l3.89 3.89.07.14L9 12H6c0-3.87 3.13-7 7-7s7 3.13 7 7-3.13 7-7 7c-1.93 0-3.68-.79-4.94-2.06l-1.42 1.42C8.27 19.99 10.51 21 13 21c4.97 0 9-4.03 9-9s-4.03-9-9-9zm-1 5v5l4.28 2.54.72-1.21-3.5-2.08V8H12z";
+    /// Storage icon.
+    pub const STORAGE: &str =
+        "M2 20h20v-4H2v4zm2-3h2v2H4v-2zM2 4v4h20V4H2zm4 3H4V5h2v2zm-4 7h20v-4H2v4zm2-3h2v2H4v-2z";
     /// Sync icon.
     pub const SYNC: &str = "M12 4V1L8 5l4 4V6c3.31 0 6 2.69 6 6 0 1.01-.25 1.97-.7 2.8l1.46 1.46C19.54 15.03 20 13.57 20 12c0-4.42-3.58-8-8-8zm0 14c-3.31 0-6-2.69-6-6 0-1.01.25-1.97.7-2.8L5.24 7.74C4.46 8.97 4 10.43 4 12c0 4.42 3.58 8 8 8v3l4-4-4-4v3z";
 }
@@ -0,0 +1,557 @@
+//! First-run wizard for choosing where playlists live, the download quality,
+//! an optional first playlist and checking that a device is detected.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::use_notifications;
+use crate::i18n::use_i18n;
+use crate::tauri_api;
+use crate::types::{DeviceInfo, DownloadQuality, OnboardingState};
+
+/// Steps of the onboarding wizard, in order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OnboardingStep {
+    /// Choose the playlists directory.
+    Storage,
+    /// Choose the download quality.
+    Quality,
+    /// Optionally add a first playlist.
+    Playlist,
+    /// Plug in a device and check it is detected.
+    Device,
+}
+
+impl OnboardingStep {
+    /// All steps, in order.
+    pub const ALL: [Self; 4] = [Self::Storage, Self::Quality, Self::Playlist, Self::Device];
+
+    /// Message key of the short title for the step indicator.
+    #[must_use]
+    pub const fn title_key(self) -> &'static str {
+        match self {
+            Self::Storage => "onboarding.step.storage",
+            Self::Quality => "onboarding.step.quality",
+            Self::Playlist => "onboarding.step.playlist",
+            Self::Device => "onboarding.step.device",
+        }
+    }
+
+    /// Position of the step, starting at 1.
+    #[must_use]
+    pub const fn number(self) -> usize {
+        match self {
+            Self::Storage => 1,
+            Self::Quality => 2,
+            Self::Playlist => 3,
+            Self::Device => 4,
+        }
+    }
+
+    /// The step Back returns to.
+    #[must_use]
+    pub const fn previous(self) -> Option<Self> {
+        match self {
+            Self::Storage => None,
+            Self::Quality => Some(Self::Storage),
+            Self::Playlist => Some(Self::Quality),
+            Self::Device => Some(Self::Playlist),
+        }
+    }
+
+    /// The step Next goes to; `None` on the last step, which finishes setup.
+    #[must_use]
+    pub const fn next(self) -> Option<Self> {
+        match self {
+            Self::Storage => Some(Self::Quality),
+            Self::Quality => Some(Self::Playlist),
+            Self::Playlist => Some(Self::Device),
+            Self::Device => None,
+        }
+    }
+}
+
+/// The directory the storage step starts with: the configured one, or the
+/// suggested default when none is set.
+#[must_use]
+pub fn initial_directory(state: &OnboardingState) -> String {
+    if state.playlists_directory.is_empty() {
+        state.suggested_playlists_directory.clone()
+    } else {
+        state.playlists_directory.clone()
+    }
+}
+
+/// Check the first playlist fields, returning the (url, name) to create, or
+/// `None` when the step was skipped.
+///
+/// # Errors
+///
+/// Returns the message key of an error for the user when a URL is given
+/// without a name.
+pub fn first_playlist(url: &str, name: &str) -> Result<Option<(String, String)>, &'static str> {
+    let (url, name) = (url.trim(), name.trim());
+    if url.is_empty() {
+        return Ok(None);
+    }
+    if name.is_empty() {
+        return Err("onboarding.name_required");
+    }
+    Ok(Some((url.to_string(), name.to_string())))
+}
+
+/// Format bytes to human-readable string.
+fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+/// First-run setup wizard, shown instead of the main layout until setup is
+/// completed.
+///
+/// Every step except the storage directory can be skipped; finishing saves
+/// the choices through `complete_onboarding` and starts downloading the first
+/// playlist, if one was given.
+#[component]
+
+pub fn OnboardingWizard(
+    /// Onboarding state with detected defaults.
+    state: OnboardingState,
+    /// Callback once setup is saved.
+    on_complete: Callback<()>,
+) -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+    let (step, set_step) = signal(OnboardingStep::Storage);
+    let (directory, set_directory) = signal(initial_directory(&state));
+    let (quality, set_quality) = signal(DownloadQuality::default());
+    let (playlist_url, set_playlist_url) = signal(String::new());
+    let (playlist_name, set_playlist_name) = signal(String::new());
+    let (devices, set_devices) = signal::<Vec<DeviceInfo>>(vec![]);
+    let (checking_devices, set_checking_devices) = signal(false);
+    let (preferred_device, set_preferred_device) = signal::<Option<String>>(None);
+    let (error, set_error) = signal::<Option<String>>(None);
+    let (is_busy, set_is_busy) = signal(false);
+    let suggested_directory = state.suggested_playlists_directory.clone();
+    let music_folders = state.music_folders;
+    let known_devices = state.known_devices;
+
+    let check_devices = move || {
+        set_checking_devices.set(true);
+        spawn_local(async move {
+            match tauri_api::list_devices().await {
+                Ok(list) => {
+                    set_devices.try_set(list);
+                }
+                Err(e) => {
+                    set_error.try_set(Some(i18n.tf("onboarding.devices_failed", &[("error", &e)])));
+                }
+            }
+            set_checking_devices.try_set(false);
+        });
+    };
+
+    let finish = move || {
+        let dir = directory.get_untracked().trim().to_string();
+        if dir.is_empty() {
+            set_error.set(Some(i18n.t("onboarding.directory_required")));
+            set_step.set(OnboardingStep::Storage);
+            return;
+        }
+        let first = first_playlist(
+            &playlist_url.get_untracked(),
+            &playlist_name.get_untracked(),
+        )
+        .ok()
+        .flatten();
+        let device = preferred_device.get_untracked();
+        let chosen_quality = quality.get_untracked();
+        set_is_busy.set(true);
+        spawn_local(async move {
+            if let Err(e) =
+                tauri_api::complete_onboarding(Some(&dir), device.as_deref(), Some(chosen_quality))
+                    .await
+            {
+                set_error.set(Some(i18n.tf("onboarding.save_failed", &[("error", &e)])));
+                set_is_busy.set(false);
+                return;
+            }
+            if let Some((url, name)) = first {
+                match tauri_api::create_playlist(&name, Some(&url)).await {
+                    Ok(created) => {
+                        if let Err(e) =
+                            tauri_api::download_youtube_to_playlist(&url, &created).await
+                        {
+                            notifications.error(i18n.tf("toast.download_failed", &[("error", &e)]));
+                        } else {
+                            notifications.info(i18n.tf("toast.downloading", &[("name", &created)]));
+                        }
+                    }
+                    Err(e) => notifications
+                        .error(i18n.tf("toast.create_playlist_failed", &[("error", &e)])),
+                }
+            }
+            on_complete.run(());
+        });
+    };
+
+    let on_next = move |_| {
+        set_error.set(None);
+        match step.get_untracked() {
+            OnboardingStep::Playlist => {
+                let url = playlist_url.get_untracked();
+                match first_playlist(&url, &playlist_name.get_untracked()) {
+                    Err(key) => set_error.set(Some(i18n.t(key))),
+                    Ok(None) => {
+                        set_step.set(OnboardingStep::Device);
+                        check_devices();
+                    }
+                    Ok(Some((url, _))) => {
+                        set_is_busy.set(true);
+                        spawn_local(async move {
+                            match tauri_api::validate_youtube_playlist_url(&url).await {
+                                Ok(validation) if validation.is_valid => {
+                                    if let Some(normalized) = validation.normalized_url {
+                                        set_playlist_url.set(normalized);
+                                    }
+                                    set_step.set(OnboardingStep::Device);
+                                    check_devices();
+                                }
+                                Ok(validation) => set_error.set(Some(
+                                    validation
+                                        .error_message
+                                        .unwrap_or_else(|| i18n.t("onboarding.invalid_url")),
+                                )),
+                                Err(e) => set_error.set(Some(e)),
+                            }
+                            set_is_busy.set(false);
+                        });
+                    }
+                }
+            }
+            current => match current.next() {
+                Some(next) => set_step.set(next),
+                None => finish(),
+            },
+        }
+    };
+
+    let on_back = move |_| {
+        set_error.set(None);
+        if let Some(previous) = step.get_untracked().previous() {
+            set_step.set(previous);
+        }
+    };
+
+    let storage_step = move || {
+        let suggested = suggested_directory.clone();
+        view! {
+            <p class="onboarding-description">
+                {i18n.t("onboarding.storage.description")}
+            </p>
+            <div class="settings-field">
+                <label for="onboarding-directory">{i18n.t("onboarding.storage.folder")}</label>
+                <input
+                    id="onboarding-directory"
+                    type="text"
+                    class="settings-input"
+                    prop:value=move || directory.get()
+                    on:input=move |ev| set_directory.set(event_target_value(&ev))
+                />
+                <p class="settings-hint">
+                    {i18n.tf("onboarding.storage.suggested", &[("path", &suggested)])}
+                </p>
+            </div>
+            {(!music_folders.is_empty()).then(|| view! {
+                <p class="onboarding-description">{i18n.t("onboarding.storage.music_found")}</p>
+                <div class="sync-wizard-options">
+                    {music_folders.iter().cloned().map(|folder| {
+                        let path = folder.path.clone();
+                        let is_selected = move || directory.with(|d| *d == path);
+                        let detail = i18n.count("count.audio_files", folder.audio_file_count);
+                        let title = folder.path.clone();
+                        view! {
+                            <button
+                                class="sync-wizard-option"
+                                class:selected=is_selected
+                                on:click=move |_| set_directory.set(folder.path.clone())
+                            >
+                                <span class="sync-wizard-option-title">{title}</span>
+                                <span class="sync-wizard-option-detail">{detail}</span>
+                            </button>
+                        }
+                    }).collect_view()}
+                </div>
+            })}
+        }
+        .into_any()
+    };
+
+    let quality_step = move || {
+        let option = move |value: DownloadQuality, title: String, description: String| {
+            view! {
+                <label class="settings-radio-option">
+                    <input
+                        type="radio"
+                        name="onboarding-quality"
+                        checked=move || quality.get() == value
+                        on:change=move |_| set_quality.set(value)
+                    />
+                    <span class="settings-radio-label">
+                        <span class="settings-radio-title">{title}</span>
+                        <span class="settings-radio-description">{description}</span>
+                    </span>
+                </label>
+            }
+        };
+        view! {
+            <p class="onboarding-description">
+                {i18n.t("onboarding.quality.description")}
+            </p>
+            <div class="settings-radio-group">
+                {option(
+                    DownloadQuality::Low,
+                    i18n.t("quality.low"),
+                    i18n.t("onboarding.quality.low_description"),
+                )}
+                {option(
+                    DownloadQuality::Medium,
+                    i18n.t("quality.medium"),
+                    i18n.t("quality.medium.description"),
+                )}
+                {option(
+                    DownloadQuality::High,
+                    i18n.t("quality.high"),
+                    i18n.t("quality.high.description"),
+                )}
+            </div>
+        }
+        .into_any()
+    };
+
+    let playlist_step = move || {
+        view! {
+            <p class="onboarding-description">
+                {i18n.t("onboarding.playlist.description")}
+            </p>
+            <div class="settings-field">
+                <label for="onboarding-url">{i18n.t("onboarding.playlist.url")}</label>
+                <input
+                    id="onboarding-url"
+                    type="url"
+                    class="settings-input"
+                    placeholder="https://www.youtube.com/playlist?list=..."
+                    prop:value=move || playlist_url.get()
+                    on:input=move |ev| set_playlist_url.set(event_target_value(&ev))
+                />
+            </div>
+            <div class="settings-field">
+                <label for="onboarding-name">{i18n.t("onboarding.playlist.name")}</label>
+                <input
+                    id="onboarding-name"
+                    type="text"
+                    class="settings-input"
+                    placeholder=i18n.t("onboarding.playlist.name_placeholder")
+                    prop:value=move || playlist_name.get()
+                    on:input=move |ev| set_playlist_name.set(event_target_value(&ev))
+                    disabled=move || playlist_url.with(|u| u.trim().is_empty())
+                />
+            </div>
+        }
+        .into_any()
+    };
+
+    let device_step = move || {
+        let list = devices.get();
+        let known = known_devices.clone();
+        view! {
+            <p class="onboarding-description">
+                {i18n.t("onboarding.device.description")}
+            </p>
+            {if list.is_empty() {
+                view! {
+                    <p class="sync-wizard-empty">
+                        {move || {
+                            i18n.t(if checking_devices.get() {
+                                "onboarding.device.looking"
+                            } else {
+                                "onboarding.device.none"
+                            })
+                        }}
+                    </p>
+                }.into_any()
+            } else {
+                view! {
+                    <div class="sync-wizard-options">
+                        {list.into_iter().map(|d| {
+                            let mount_point = d.mount_point.clone();
+                            let is_selected = move || {
+                                preferred_device.with(|p| p.as_deref() == Some(mount_point.as_str()))
+                            };
+                            let space = i18n.tf(
+                                "sync_wizard.device_space",
+                                &[
+                                    ("free", &format_bytes(d.available_bytes)),
+                                    ("total", &format_bytes(d.total_bytes)),
+                                ],
+                            );
+                            let mount_point = d.mount_point.clone();
+                            view! {
+                                <button
+                                    class="sync-wizard-option"
+                                    class:selected=is_selected
+                                    on:click=move |_| set_preferred_device.set(Some(mount_point.clone()))
+                                >
+                                    <span class="sync-wizard-option-title">{d.name}</span>
+                                    <span class="sync-wizard-option-detail">{space}</span>
+                                </button>
+                            }
+                        }).collect_view()}
+                    </div>
+                }.into_any()
+            }}
+            {(!known.is_empty()).then(|| view! {
+                <p class="settings-hint">
+                    {i18n.tf(
+                        "onboarding.device.known",
+                        &[(
+                            "devices",
+                            &known.iter().map(|k| {
+                                if k.connected {
+                                    i18n.tf("onboarding.device.connected", &[("device", &k.mount_point)])
+                                } else {
+                                    k.mount_point.clone()
+                                }
+                            }).collect::<Vec<_>>().join(", "),
+                        )],
+                    )}
+                </p>
+            })}
+            <button
+                class="btn btn-secondary btn-sm onboarding-check-devices"
+                on:click=move |_| check_devices()
+                disabled=move || checking_devices.get()
+            >
+                {i18n.t("onboarding.device.check_again")}
+            </button>
+        }
+        .into_any()
+    };
+
+    view! {
+        <div class="onboarding">
+            <div class="onboarding-card" role="dialog" aria-labelledby="onboarding-title">
+                <div class="onboarding-header">
+                    <h1 id="onboarding-title">{move || i18n.t("onboarding.title")}</h1>
+                    <p class="onboarding-description">{move || i18n.t("onboarding.subtitle")}</p>
+                </div>
+
+                <ol class="sync-wizard-steps">
+                    {OnboardingStep::ALL.into_iter().map(|s| view! {
+                        <li
+                            class:active=move || step.get() == s
+                            class:done=move || step.get().number() > s.number()
+                            aria-current=move || (step.get() == s).then_some("step")
+                        >
+                            <span class="sync-wizard-step-number">{s.number()}</span>
+                            {move || i18n.t(s.title_key())}
+                        </li>
+                    }).collect_view()}
+                </ol>
+
+                <div class="onboarding-body">
+                    {move || error.get().map(|e| view! { <p class="sync-wizard-error" role="alert">{e}</p> })}
+                    {move || match step.get() {
+                        OnboardingStep::Storage => storage_step(),
+                        OnboardingStep::Quality => quality_step(),
+                        OnboardingStep::Playlist => playlist_step(),
+                        OnboardingStep::Device => device_step(),
+                    }}
+                </div>
+
+                <div class="onboarding-footer">
+                    {move || step.get().previous().map(|_| view! {
+                        <button class="btn btn-secondary" on:click=on_back disabled=move || is_busy.get()>
+                            {i18n.t("common.back")}
+                        </button>
+                    })}
+                    <button
+                        class="btn btn-primary"
+                        on:click=on_next
+                        disabled=move || {
+                            is_busy.get()
+                                || (step.get() == OnboardingStep::Storage
+                                    && directory.with(|d| d.trim().is_empty()))
+                        }
+                    >
+                        {move || i18n.t(match step.get() {
+                            OnboardingStep::Device if is_busy.get() => "onboarding.setting_up",
+                            OnboardingStep::Device => "onboarding.finish",
+                            OnboardingStep::Playlist if playlist_url.with(|u| u.trim().is_empty()) => {
+                                "onboarding.skip"
+                            }
+                            _ => "common.next",
+                        })}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(configured: &str) -> OnboardingState {
+        OnboardingState {
+            completed: false,
+            playlists_directory: configured.to_string(),
+            suggested_playlists_directory: "/home/user/Music/Youtun4".to_string(),
+            music_folders: vec![],
+            known_devices: vec![],
+        }
+    }
+
+    #[test]
+    fn test_onboarding_steps() {
+        assert_eq!(OnboardingStep::Storage.previous(), None);
+        assert_eq!(OnboardingStep::Device.next(), None);
+        for pair in OnboardingStep::ALL.windows(2) {
+            assert_eq!(pair[0].next(), Some(pair[1]));
+            assert_eq!(pair[1].previous(), Some(pair[0]));
+        }
+        let numbers: Vec<_> = OnboardingStep::ALL.iter().map(|s| s.number()).collect();
+        assert_eq!(numbers, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_initial_directory() {
+        assert_eq!(initial_directory(&state("")), "/home/user/Music/Youtun4");
+        assert_eq!(initial_directory(&state("/data/music")), "/data/music");
+    }
+
+    #[test]
+    fn test_first_playlist() {
+        assert_eq!(first_playlist("  ", "Ignored"), Ok(None));
+        assert_eq!(
+            first_playlist(" https://youtube.com/playlist?list=PL1 ", " Chill "),
+            Ok(Some((
+                "https://youtube.com/playlist?list=PL1".to_string(),
+                "Chill".to_string()
+            )))
+        );
+        assert!(first_playlist("https://youtube.com/playlist?list=PL1", "").is_err());
+    }
+}
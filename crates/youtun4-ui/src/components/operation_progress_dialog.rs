@@ -0,0 +1,303 @@
+//! Modal progress dialog for long-running operations, with a cancel button.
+
+use leptos::html;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::use_dialog_keyboard;
+use crate::i18n::use_i18n;
+use crate::tauri_api;
+use crate::types::{Locale, MaintenanceOperation, TaskEvent, TaskEventCategory, TaskId, TaskPhase};
+
+/// Number of tasks whose latest event is kept, so events that arrive before
+/// the dialog learns its task ID are not lost.
+const RECENT_TASK_LIMIT: usize = 32;
+
+/// Width of the sliding bar shown while progress is unknown.
+const INDETERMINATE_PERCENT: f64 = 30.0;
+
+/// A background task followed by an [`OperationProgressDialog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunningOperation {
+    /// Title shown while the task runs.
+    pub title: String,
+    /// Category of the task's events.
+    pub category: TaskEventCategory,
+    /// The task's ID.
+    pub task_id: TaskId,
+}
+
+impl RunningOperation {
+    /// A maintenance operation started as `task_id`.
+    #[must_use]
+    pub fn maintenance(operation: &MaintenanceOperation, task_id: TaskId, locale: Locale) -> Self {
+        Self {
+            title: operation.title(locale),
+            category: TaskEventCategory::Maintenance,
+            task_id,
+        }
+    }
+}
+
+/// Whether `phase` ends a task.
+const fn is_finished(phase: TaskPhase) -> bool {
+    matches!(
+        phase,
+        TaskPhase::Completed | TaskPhase::Failed | TaskPhase::Cancelled | TaskPhase::Removed
+    )
+}
+
+/// Keep `event` as the latest event of its task, if it belongs to one.
+fn remember(recent: &mut Vec<TaskEvent>, event: TaskEvent) {
+    if event.task_id.is_none() {
+        return;
+    }
+    recent.retain(|e| e.category != event.category || e.task_id != event.task_id);
+    if recent.len() == RECENT_TASK_LIMIT {
+        recent.remove(0);
+    }
+    recent.push(event);
+}
+
+/// The latest event of `operation`'s task.
+fn latest_event<'a>(
+    recent: &'a [TaskEvent],
+    operation: &RunningOperation,
+) -> Option<&'a TaskEvent> {
+    recent
+        .iter()
+        .find(|e| e.category == operation.category && e.task_id == Some(operation.task_id))
+}
+
+/// A string field of an event's payload.
+fn payload_text(event: &TaskEvent, key: &str) -> Option<String> {
+    event
+        .payload
+        .get(key)
+        .and_then(serde_json::Value::as_str)
+        .map(str::to_string)
+}
+
+/// Shows the progress of a background task and lets the user cancel it.
+///
+/// The dialog follows the task through the unified task event bus: the
+/// event's progress fills the bar, and its payload's `message` (or `error`)
+/// is shown below. Once the task ends the cancel button becomes a close
+/// button.
+#[component]
+pub fn OperationProgressDialog(
+    /// The task to follow; the dialog is open while this is set.
+    operation: ReadSignal<Option<RunningOperation>>,
+    /// Callback when the dialog is closed after the task ended.
+    on_close: Callback<()>,
+    /// Callback with the task's last event when it ends.
+    #[prop(optional)]
+    on_finished: Option<Callback<TaskEvent>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let recent = RwSignal::new(Vec::<TaskEvent>::new());
+    let cancelling = RwSignal::new(false);
+    let reported = StoredValue::new(None::<TaskId>);
+    let dialog_ref = NodeRef::<html::Div>::new();
+
+    Effect::new(move || {
+        spawn_local(async move {
+            if let Err(e) = tauri_api::listen_to_task_events(move |event| {
+                recent.update(|recent| remember(recent, event));
+            })
+            .await
+            {
+                leptos::logging::error!("Failed to listen for task events: {}", e);
+            }
+        });
+    });
+
+    let current = Memo::new(move |_| {
+        operation.with(|operation| {
+            operation.as_ref().and_then(|operation| {
+                recent.with(|recent| latest_event(recent, operation).cloned())
+            })
+        })
+    });
+    let finished =
+        Memo::new(move |_| current.with(|e| e.as_ref().is_some_and(|e| is_finished(e.phase))));
+
+    // Start fresh for each task, and report its end once
+    Effect::new(move |previous: Option<Option<TaskId>>| {
+        let task_id = operation.with(|o| o.as_ref().map(|o| o.task_id));
+        if previous.is_some_and(|previous| previous != task_id) {
+            cancelling.set(false);
+        }
+        task_id
+    });
+    Effect::new(move || {
+        let Some(event) = current.get().filter(|e| is_finished(e.phase)) else {
+            return;
+        };
+        if reported.get_value() == event.task_id {
+            return;
+        }
+        reported.set_value(event.task_id);
+        if let Some(callback) = on_finished {
+            callback.run(event);
+        }
+    });
+
+    let cancel = move || {
+        let Some(task_id) = operation.with_untracked(|o| o.as_ref().map(|o| o.task_id)) else {
+            return;
+        };
+        cancelling.set(true);
+        spawn_local(async move {
+            if let Err(e) = tauri_api::cancel_task(task_id).await {
+                leptos::logging::error!("Failed to cancel task {}: {}", task_id, e);
+                cancelling.set(false);
+            }
+        });
+    };
+
+    // Escape and clicks outside only close the dialog once the task ended
+    let dismiss = Callback::new(move |()| {
+        if finished.get_untracked() {
+            on_close.run(());
+        }
+    });
+    let is_open = Signal::derive(move || operation.with(Option::is_some));
+    use_dialog_keyboard(is_open, dialog_ref, dismiss);
+
+    let title = move || operation.with(|o| o.as_ref().map(|o| o.title.clone()).unwrap_or_default());
+    let percent = move || {
+        current
+            .with(|e| e.as_ref().and_then(|e| e.progress))
+            .map(|p| p * 100.0)
+    };
+    let status = move || {
+        current.with(|event| match event {
+            None => i18n.t("operation.starting"),
+            Some(event) => match event.phase {
+                TaskPhase::Failed => payload_text(event, "error").map_or_else(
+                    || i18n.t("operation.failed"),
+                    |e| i18n.tf("operation.failed_with", &[("error", &e)]),
+                ),
+                TaskPhase::Cancelled | TaskPhase::Removed => i18n.t("operation.cancelled"),
+                TaskPhase::Completed => {
+                    payload_text(event, "message").unwrap_or_else(|| i18n.t("operation.done"))
+                }
+                _ if cancelling.get() => i18n.t("operation.cancelling"),
+                _ => payload_text(event, "message").unwrap_or_else(|| i18n.t("operation.working")),
+            },
+        })
+    };
+
+    view! {
+        <div
+            class="confirm-dialog-overlay operation-progress-overlay"
+            class:visible=move || is_open.get()
+            on:click=move |_| dismiss.run(())
+        >
+            <div
+                class="confirm-dialog operation-progress-dialog"
+                node_ref=dialog_ref
+                on:click=move |e| e.stop_propagation()
+                role="dialog"
+                aria-modal="true"
+                aria-labelledby="operation-progress-title"
+                aria-describedby="operation-progress-status"
+            >
+                <h3 id="operation-progress-title" class="confirm-dialog-title">{title}</h3>
+                <div
+                    class="operation-progress-bar"
+                    class:indeterminate=move || percent().is_none()
+                    role="progressbar"
+                    aria-labelledby="operation-progress-title"
+                    aria-valuemin="0"
+                    aria-valuemax="100"
+                    aria-valuenow=move || percent().map(|p| format!("{p:.0}"))
+                >
+                    <div
+                        class="operation-progress-fill"
+                        style:width=move || {
+                            format!("{:.0}%", percent().unwrap_or(INDETERMINATE_PERCENT))
+                        }
+                    ></div>
+                </div>
+                <p
+                    id="operation-progress-status"
+                    class="operation-progress-status"
+                    aria-live="polite"
+                >
+                    {status}
+                </p>
+                <div class="confirm-dialog-actions">
+                    <Show
+                        when=move || finished.get()
+                        fallback=move || view! {
+                            <button
+                                class="btn btn-secondary"
+                                disabled=move || cancelling.get()
+                                on:click=move |_| cancel()
+                            >
+                                {i18n.t("common.cancel")}
+                            </button>
+                        }
+                    >
+                        <button class="btn btn-primary" on:click=move |_| on_close.run(())>
+                            {i18n.t("common.close")}
+                        </button>
+                    </Show>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(task_id: Option<u64>, phase: TaskPhase) -> TaskEvent {
+        TaskEvent {
+            task_id,
+            category: TaskEventCategory::Maintenance,
+            phase,
+            progress: None,
+            event: String::new(),
+            payload: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn test_latest_event_for_operation() {
+        let mut recent = Vec::new();
+        remember(&mut recent, event(Some(1), TaskPhase::Started));
+        remember(&mut recent, event(None, TaskPhase::Progress));
+        remember(&mut recent, event(Some(2), TaskPhase::Started));
+        remember(&mut recent, event(Some(1), TaskPhase::Completed));
+        assert_eq!(recent.len(), 2);
+
+        let operation = RunningOperation {
+            title: String::new(),
+            category: TaskEventCategory::Maintenance,
+            task_id: 1,
+        };
+        let latest = latest_event(&recent, &operation).map(|e| e.phase);
+        assert_eq!(latest, Some(TaskPhase::Completed));
+
+        // Same ID, other feature
+        let queue_item = RunningOperation {
+            category: TaskEventCategory::Queue,
+            ..operation
+        };
+        assert!(latest_event(&recent, &queue_item).is_none());
+    }
+
+    #[test]
+    fn test_remember_keeps_recent_tasks() {
+        let mut recent = Vec::new();
+        for id in 0..40 {
+            remember(&mut recent, event(Some(id), TaskPhase::Progress));
+        }
+        assert_eq!(recent.len(), RECENT_TASK_LIMIT);
+        assert_eq!(recent.first().and_then(|e| e.task_id), Some(8));
+    }
+}
@@ -1,7 +1,11 @@
 //! Playlist card component.
 
 use leptos::prelude::*;
+use leptos::task::spawn_local;
 
+use crate::components::context_menu::{ContextMenu, ContextMenuItem};
+use crate::i18n::use_i18n;
+use crate::tauri_api;
 use crate::types::{DeviceInfo, PlaylistMetadata};
 
 /// Format bytes to human-readable string.
@@ -21,6 +25,57 @@ fn format_bytes(bytes: u64) -> String {
     }
 }
 
+/// Actions offered in a playlist's context menu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlaylistAction {
+    /// Rename the playlist.
+    Rename,
+    /// Download new videos from the playlist's YouTube source.
+    RefreshFromSource,
+    /// Forget which videos were downloaded, so renamed or removed tracks are
+    /// downloaded again on the next refresh.
+    ResetDownloadArchive,
+    /// Export the playlist as an M3U file.
+    Export,
+    /// Check the playlist folder and repair what can be fixed.
+    Verify,
+    /// Save checksums of the playlist's files, to verify them later.
+    CreateChecksums,
+    /// Open the playlist folder in the file manager.
+    OpenFolder,
+    /// Delete the playlist.
+    Delete,
+}
+
+impl PlaylistAction {
+    /// Context menu entries for `playlist`; refreshing needs a source URL.
+    #[must_use]
+    pub fn menu_items(playlist: &PlaylistMetadata) -> Vec<ContextMenuItem<Self>> {
+        let mut items = vec![ContextMenuItem::new(
+            Self::Rename,
+            "rename_playlist.confirm",
+        )];
+        if playlist.source_url.is_some() {
+            items.push(ContextMenuItem::new(
+                Self::RefreshFromSource,
+                "playlist_settings.refresh",
+            ));
+            items.push(ContextMenuItem::new(
+                Self::ResetDownloadArchive,
+                "playlist_menu.reset_archive",
+            ));
+        }
+        items.extend([
+            ContextMenuItem::new(Self::Export, "playlist_menu.export"),
+            ContextMenuItem::new(Self::Verify, "device_detail.verify"),
+            ContextMenuItem::new(Self::CreateChecksums, "playlist_menu.checksums"),
+            ContextMenuItem::new(Self::OpenFolder, "playlist_detail.open_folder.title"),
+            ContextMenuItem::new(Self::Delete, "common.delete").danger(),
+        ]);
+        items
+    }
+}
+
 /// Playlist card component.
 #[component]
 
@@ -39,8 +94,14 @@ pub fn PlaylistCard(
     /// Whether this playlist is selected.
     #[prop(default = false)]
     selected: bool,
+    /// Callback with an action chosen from the card's context menu; the
+    /// card has no context menu without it.
+    #[prop(optional_no_strip)]
+    on_action: Option<Callback<(PlaylistAction, PlaylistMetadata)>>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let playlist_clone = playlist.clone();
+    let playlist_for_menu = playlist.clone();
     let playlist_name = playlist.name.clone();
     let playlist_name_delete = playlist.name.clone();
     let playlist_name_sync = playlist.name.clone();
@@ -65,9 +126,9 @@ pub fn PlaylistCard(
                 }
                 title=move || {
                     if let Some(dev_name) = device_name() {
-                        format!("Sync to {dev_name}")
+                        i18n.tf("playlist_card.sync_to", &[("device", &dev_name)])
                     } else {
-                        "Connect a device to sync".to_string()
+                        i18n.t("playlist_card.connect_device.title")
                     }
                 }
                 disabled=move || !has_device()
@@ -83,9 +144,9 @@ pub fn PlaylistCard(
                 </svg>
                 {move || {
                     if let Some(dev_name) = device_name() {
-                        format!("Sync to {dev_name}")
+                        i18n.tf("playlist_card.sync_to", &[("device", &dev_name)])
                     } else {
-                        "Connect device to sync".to_string()
+                        i18n.t("playlist_card.connect_device")
                     }
                 }}
             </button>
@@ -99,7 +160,7 @@ pub fn PlaylistCard(
         view! {
             <button
                 class="btn btn-secondary"
-                title=format!("View {} details", name)
+                title=i18n.tf("playlist_card.details.title", &[("name", &name)])
                 on:click=move |e| {
                     e.stop_propagation();
                     on_select.run(playlist_for_view.clone());
@@ -108,7 +169,7 @@ pub fn PlaylistCard(
                 <svg viewBox="0 0 24 24" width="16" height="16" fill="currentColor">
                     <path d="M12 4.5C7 4.5 2.73 7.61 1 12c1.73 4.39 6 7.5 11 7.5s9.27-3.11 11-7.5c-1.73-4.39-6-7.5-11-7.5zM12 17c-2.76 0-5-2.24-5-5s2.24-5 5-5 5 2.24 5 5-2.24 5-5 5zm0-8c-1.66 0-3 1.34-3 3s1.34 3 3 3 3-1.34 3-3-1.34-3-3-3z"/>
                 </svg>
-                "Details"
+                {i18n.t("playlist_card.details")}
             </button>
         }
     };
@@ -118,7 +179,8 @@ pub fn PlaylistCard(
         view! {
             <button
                 class="btn btn-icon btn-danger"
-                title="Delete playlist"
+                title=i18n.t("playlist_detail.delete.title")
+                aria-label=i18n.t("playlist_detail.delete.title")
                 on:click=move |e| {
                     e.stop_propagation();
                     on_delete.run(name.clone());
@@ -142,15 +204,33 @@ pub fn PlaylistCard(
         }
     });
 
-    // Thumbnail view - show image if URL available, otherwise show icon
-    let thumbnail_url = playlist.thumbnail_url.clone();
+    let tags_view = (!playlist.tags.is_empty()).then(|| {
+        view! {
+            <div class="playlist-tags">
+                {playlist.tags.iter().map(|tag| view! {
+                    <span class="playlist-tag">{tag.clone()}</span>
+                }).collect_view()}
+            </div>
+        }
+    });
+
+    // Thumbnail view - show the cached image once available, otherwise show icon
+    let (thumbnail_url, set_thumbnail_url) = signal::<Option<String>>(None);
+    if let Some(url) = playlist.thumbnail_url.clone() {
+        spawn_local(async move {
+            match tauri_api::get_cached_thumbnail_url(&url).await {
+                Ok(src) => set_thumbnail_url.set(Some(src)),
+                Err(e) => leptos::logging::warn!("Failed to load thumbnail {}: {}", url, e),
+            }
+        });
+    }
     let thumbnail_view = move || {
-        if let Some(ref url) = thumbnail_url {
+        if let Some(url) = thumbnail_url.get() {
             view! {
                 <div class="playlist-thumbnail">
                     <img
-                        src=url.clone()
-                        alt="Playlist thumbnail"
+                        src=url
+                        alt=i18n.t("playlist_card.thumbnail")
                         class="playlist-thumbnail-img"
                         loading="lazy"
                     />
@@ -187,7 +267,7 @@ pub fn PlaylistCard(
         }
     };
 
-    view! {
+    let card = view! {
         <div
             class=move || if selected { "playlist-card selected" } else { "playlist-card" }
         >
@@ -198,11 +278,12 @@ pub fn PlaylistCard(
                 <div class="playlist-info">
                     <h4 class="playlist-name">{playlist_name}</h4>
                     <div class="playlist-meta">
-                        <span class="track-count">{playlist.track_count} " tracks"</span>
+                        <span class="track-count">{i18n.count("count.tracks", playlist.track_count)}</span>
                         <span class="separator">"•"</span>
                         <span class="size">{format_bytes(playlist.total_bytes)}</span>
                     </div>
                     {source_info}
+                    {tags_view}
                 </div>
             </div>
             <div class="playlist-card-actions">
@@ -213,6 +294,22 @@ pub fn PlaylistCard(
                 </div>
             </div>
         </div>
+    };
+
+    match on_action {
+        Some(callback) => view! {
+            <ContextMenu
+                items=PlaylistAction::menu_items(&playlist_for_menu)
+                on_action=Callback::new(move |action| {
+                    callback.run((action, playlist_for_menu.clone()));
+                })
+                label="playlist_menu.label"
+            >
+                {card}
+            </ContextMenu>
+        }
+        .into_any(),
+        None => card.into_any(),
     }
 }
 
@@ -220,6 +317,41 @@ pub fn PlaylistCard(
 mod tests {
     use super::*;
 
+    fn playlist(source_url: Option<&str>) -> PlaylistMetadata {
+        PlaylistMetadata {
+            name: "Mix".to_string(),
+            source_url: source_url.map(str::to_string),
+            created_at: 0,
+            modified_at: 0,
+            track_count: 3,
+            total_bytes: 0,
+            thumbnail_url: None,
+            tags: vec![],
+        }
+    }
+
+    #[test]
+    fn test_menu_items_refresh_needs_source() {
+        let actions = |playlist: &PlaylistMetadata| -> Vec<PlaylistAction> {
+            PlaylistAction::menu_items(playlist)
+                .iter()
+                .map(|item| item.action)
+                .collect()
+        };
+        assert!(!actions(&playlist(None)).contains(&PlaylistAction::RefreshFromSource));
+        assert!(!actions(&playlist(None)).contains(&PlaylistAction::ResetDownloadArchive));
+        let with_source = actions(&playlist(Some("https://youtube.com/playlist?list=PL1")));
+        assert!(with_source.contains(&PlaylistAction::RefreshFromSource));
+        assert!(with_source.contains(&PlaylistAction::ResetDownloadArchive));
+
+        let items = PlaylistAction::menu_items(&playlist(None));
+        assert!(
+            items
+                .iter()
+                .all(|item| item.danger == (item.action == PlaylistAction::Delete))
+        );
+    }
+
     #[test]
     fn test_format_bytes() {
         assert_eq!(format_bytes(500), "500 B");
@@ -1,11 +1,20 @@
 //! Playlist detail view component for displaying individual playlist information.
 
+use leptos::html;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
-use crate::components::{TrackList, TrackListState};
+use crate::components::{
+    BatchAction, ListSelection, PlaylistSettings, TrackAction, TrackList, TrackListState,
+    TrackPlayer, batch_summary, use_dialog_keyboard, use_notifications,
+};
+use crate::i18n::use_i18n;
 use crate::tauri_api;
-use crate::types::{PlaylistMetadata, TrackInfo};
+use crate::types::{MaintenanceOperation, PlaylistDiff, PlaylistMetadata, TrackInfo};
+use crate::undo::{UndoAction, use_undo};
+
+/// Number of tracks fetched per page.
+const TRACK_PAGE_SIZE: usize = 100;
 
 /// Format bytes to human-readable string.
 fn format_bytes(bytes: u64) -> String {
@@ -53,23 +62,31 @@ fn PlaylistDetailHeader(
     /// The playlist metadata.
     playlist: PlaylistMetadata,
     /// Total duration in seconds (calculated from tracks).
-    total_duration_secs: ReadSignal<u64>,
+    total_duration_secs: Signal<u64>,
     /// Callback when back button is clicked.
     on_back: Callback<()>,
     /// Callback when sync button is clicked.
     on_sync: Callback<String>,
     /// Callback when delete button is clicked.
     on_delete: Callback<String>,
+    /// Callback when the settings button is clicked.
+    on_settings: Callback<()>,
+    /// Callback to open the playlist folder in the file manager.
+    on_open_folder: Callback<()>,
+    /// Callback to run a maintenance operation on the playlist, if offered.
+    on_maintenance: Option<Callback<MaintenanceOperation>>,
 ) -> impl IntoView {
     let playlist_name = playlist.name.clone();
     let playlist_name_for_sync = playlist.name.clone();
     let playlist_name_for_delete = playlist.name.clone();
+    let playlist_name_for_update = playlist.name.clone();
     let has_source_url = playlist.source_url.is_some();
     let source_url = playlist.source_url.clone();
     let created_at = format_date(playlist.created_at);
     let modified_at = format_date(playlist.modified_at);
     let total_bytes = format_bytes(playlist.total_bytes);
     let track_count = playlist.track_count;
+    let i18n = use_i18n();
 
     view! {
         <div class="playlist-detail-header">
@@ -77,33 +94,83 @@ fn PlaylistDetailHeader(
                 <button
                     class="btn btn-ghost playlist-back-btn"
                     on:click=move |_| on_back.run(())
-                    aria-label="Go back to playlist list"
+                    aria-label=i18n.t("playlist_detail.back_label")
                 >
                     <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
                         <path d="M20 11H7.83l5.59-5.59L12 4l-8 8 8 8 1.41-1.41L7.83 13H20v-2z"/>
                     </svg>
-                    "Back"
+                    {i18n.t("common.back")}
                 </button>
                 <div class="playlist-detail-actions">
+                    <button
+                        class="btn btn-ghost"
+                        on:click=move |_| on_settings.run(())
+                        title=i18n.t("playlist_detail.settings")
+                    >
+                        <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
+                            <path d="M3 17v2h6v-2H3zM3 5v2h10V5H3zm10 16v-2h8v-2h-8v-2h-2v6h2zM7 9v2H3v2h4v2h2V9H7zm14 4v-2H11v2h10zm-6-4h2V7h4V5h-4V3h-2v6z"/>
+                        </svg>
+                        {i18n.t("settings.title")}
+                    </button>
+                    <button
+                        class="btn btn-ghost"
+                        on:click=move |_| on_open_folder.run(())
+                        title=i18n.t("playlist_detail.open_folder.title")
+                    >
+                        <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
+                            <path d="M20 6h-8l-2-2H4c-1.1 0-1.99.9-1.99 2L2 18c0 1.1.9 2 2 2h16c1.1 0 2-.9 2-2V8c0-1.1-.9-2-2-2zm0 12H4V8h16v10z"/>
+                        </svg>
+                        {i18n.t("playlist_detail.open_folder")}
+                    </button>
+                    {on_maintenance.map(|on_maintenance| {
+                        let name_for_repair = playlist_name.clone();
+                        let name_for_checksums = playlist_name.clone();
+                        view! {
+                            <button
+                                class="btn btn-ghost"
+                                on:click=move |_| on_maintenance.run(MaintenanceOperation::RepairPlaylistFolder {
+                                    playlist: name_for_repair.clone(),
+                                })
+                                title=i18n.t("playlist_detail.repair.title")
+                            >
+                                <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
+                                    <path d="M22.7 19l-9.1-9.1c.9-2.3.4-5-1.5-6.9-2-2-5-2.4-7.4-1.3L9 6 6 9 1.6 4.7C.4 7.1.9 10.1 2.9 12.1c1.9 1.9 4.6 2.4 6.9 1.5l9.1 9.1c.4.4 1 .4 1.4 0l2.3-2.3c.5-.4.5-1.1.1-1.4z"/>
+                                </svg>
+                                {i18n.t("playlist_detail.repair")}
+                            </button>
+                            <button
+                                class="btn btn-ghost"
+                                on:click=move |_| on_maintenance.run(MaintenanceOperation::CreateChecksumManifest {
+                                    playlist: name_for_checksums.clone(),
+                                })
+                                title=i18n.t("playlist_detail.checksums.title")
+                            >
+                                <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
+                                    <path d="M12 1L3 5v6c0 5.55 3.84 10.74 9 12 5.16-1.26 9-6.45 9-12V5l-9-4zm-2 16l-4-4 1.41-1.41L10 14.17l6.59-6.59L18 9l-8 8z"/>
+                                </svg>
+                                {i18n.t("playlist_detail.checksums")}
+                            </button>
+                        }
+                    })}
                     <button
                         class="btn btn-secondary"
                         on:click=move |_| on_sync.run(playlist_name_for_sync.clone())
-                        title="Sync to device"
+                        title=i18n.t("app.sync_to_device")
                     >
                         <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
                             <path d="M19 8l-4 4h3c0 3.31-2.69 6-6 6-1.01 0-1.97-.25-2.8-.7l-1.46 1.46C8.97 19.54 10.43 20 12 20c4.42 0 8-3.58 8-8h3l-4-4zM6 12c0-3.31 2.69-6 6-6 1.01 0 1.97.25 2.8.7l1.46-1.46C15.03 4.46 13.57 4 12 4c-4.42 0-8 3.58-8 8H1l4 4 4-4H6z"/>
                         </svg>
-                        "Sync"
+                        {i18n.t("playlist_detail.sync")}
                     </button>
                     <button
                         class="btn btn-danger"
                         on:click=move |_| on_delete.run(playlist_name_for_delete.clone())
-                        title="Delete playlist"
+                        title=i18n.t("playlist_detail.delete.title")
                     >
                         <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
                             <path d="M6 19c0 1.1.9 2 2 2h8c1.1 0 2-.9 2-2V7H6v12zM19 4h-3.5l-1-1h-5l-1 1H5v2h14V4z"/>
                         </svg>
-                        "Delete"
+                        {i18n.t("common.delete")}
                     </button>
                 </div>
             </div>
@@ -121,7 +188,7 @@ fn PlaylistDetailHeader(
                             <svg viewBox="0 0 24 24" width="16" height="16" fill="currentColor">
                                 <path d="M12 3v10.55c-.59-.34-1.27-.55-2-.55-2.21 0-4 1.79-4 4s1.79 4 4 4 4-1.79 4-4V7h4V3h-6z"/>
                             </svg>
-                            {track_count} " track" {if track_count == 1 { "" } else { "s" }}
+                            {i18n.count("count.tracks", track_count)}
                         </span>
                         <span class="stat-separator">"•"</span>
                         <span class="stat-item">
@@ -162,7 +229,7 @@ fn PlaylistDetailHeader(
                                 <svg viewBox="0 0 24 24" width="16" height="16" fill="currentColor" class="youtube-icon">
                                     <path d="M21.543 6.498C22 8.28 22 12 22 12s0 3.72-.457 5.502c-.254.985-.997 1.76-1.938 2.022C17.896 20 12 20 12 20s-5.893 0-7.605-.476c-.945-.266-1.687-1.04-1.938-2.022C2 15.72 2 12 2 12s0-3.72.457-5.502c.254-.985.997-1.76 1.938-2.022C6.107 4 12 4 12 4s5.896 0 7.605.476c.945.266 1.687 1.04 1.938 2.022zM10 15.5l6-3.5-6-3.5v7z"/>
                                 </svg>
-                                "Source"
+                                {i18n.t("playlist_detail.source")}
                             </span>
                             <a
                                 href=url_display.clone()
@@ -177,6 +244,7 @@ fn PlaylistDetailHeader(
                                 </svg>
                             </a>
                         </div>
+                        <PlaylistUpdatePreview playlist_name=playlist_name_for_update />
                     })
                 } else {
                     None
@@ -187,7 +255,7 @@ fn PlaylistDetailHeader(
                             <path d="M11.99 2C6.47 2 2 6.48 2 12s4.47 10 9.99 10C17.52 22 22 17.52 22 12S17.52 2 11.99 2zM12 20c-4.42 0-8-3.58-8-8s3.58-8 8-8 8 3.58 8 8-3.58 8-8 8z"/>
                             <path d="M12.5 7H11v6l5.25 3.15.75-1.23-4.5-2.67z"/>
                         </svg>
-                        "Created"
+                        {i18n.t("playlist_detail.created")}
                     </span>
                     <span class="metadata-value">{created_at}</span>
                 </div>
@@ -196,7 +264,7 @@ fn PlaylistDetailHeader(
                         <svg viewBox="0 0 24 24" width="16" height="16" fill="currentColor">
                             <path d="M3 17.25V21h3.75L17.81 9.94l-3.75-3.75L3 17.25zM20.71 7.04c.39-.39.39-1.02 0-1.41l-2.34-2.34a.9959.9959 0 0 0-1.41 0l-1.83 1.83 3.75 3.75 1.83-1.83z"/>
                         </svg>
-                        "Modified"
+                        {i18n.t("playlist_detail.modified")}
                     </span>
                     <span class="metadata-value">{modified_at}</span>
                 </div>
@@ -205,6 +273,102 @@ fn PlaylistDetailHeader(
     }
 }
 
+/// Buttons comparing the playlist with its `YouTube` source, showing what
+/// downloading it again would change, and downloading the changes.
+#[component]
+fn PlaylistUpdatePreview(
+    /// Name of the playlist.
+    playlist_name: String,
+) -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+    let (checking, set_checking) = signal(false);
+    let (diff, set_diff) = signal(None::<Result<PlaylistDiff, String>>);
+    let (remove_missing, set_remove_missing) = signal(false);
+    let playlist_name_for_update = playlist_name.clone();
+
+    let on_check = move |_| {
+        let name = playlist_name.clone();
+        set_checking.set(true);
+        spawn_local(async move {
+            let result = tauri_api::preview_playlist_update(&name).await;
+            set_diff.set(Some(result));
+            set_checking.set(false);
+        });
+    };
+
+    let on_update = move |_| {
+        let name = playlist_name_for_update.clone();
+        let remove = remove_missing.get_untracked();
+        spawn_local(async move {
+            match tauri_api::update_playlist_from_source(&name, remove, None).await {
+                Ok(_) => {
+                    set_diff.set(None);
+                    notifications.info(i18n.tf("playlist_detail.updating", &[("name", &name)]));
+                }
+                Err(e) => notifications.error(i18n.tf("toast.refresh_failed", &[("error", &e)])),
+            }
+        });
+    };
+
+    // Number of removed videos, when the last check found something to update
+    let pending_update = move || {
+        diff.with(|diff| match diff {
+            Some(Ok(diff)) if !diff.new_videos.is_empty() || !diff.removed_videos.is_empty() => {
+                Some(diff.removed_videos.len())
+            }
+            _ => None,
+        })
+    };
+
+    view! {
+        <div class="metadata-row update-preview">
+            <button
+                class="btn btn-secondary"
+                disabled=move || checking.get()
+                on:click=on_check
+            >
+                {move || i18n.t(if checking.get() { "playlist_detail.checking" } else { "playlist_detail.check_updates" })}
+            </button>
+            {move || {
+                diff.get()
+                    .map(|result| {
+                        let (class, text) = match result {
+                            Ok(diff) => ("update-preview-summary", diff.summary(i18n.locale())),
+                            Err(e) => ("update-preview-summary error", e),
+                        };
+                        view! { <span class=class>{text}</span> }
+                    })
+            }}
+            {move || {
+                pending_update()
+                    .map(|removed_count| {
+                        view! {
+                            {(removed_count > 0)
+                                .then(|| {
+                                    view! {
+                                        <label class="update-preview-remove">
+                                            <input
+                                                type="checkbox"
+                                                prop:checked=move || remove_missing.get()
+                                                on:change=move |ev| {
+                                                    set_remove_missing.set(event_target_checked(&ev));
+                                                }
+                                            />
+                                            {i18n.count("playlist_detail.remove_missing", removed_count)}
+                                        </label>
+                                    }
+                                })}
+                            <button class="btn btn-primary" on:click=on_update.clone()>
+                                {i18n.t("playlist_detail.update")}
+                            </button>
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
 /// Loading skeleton for the detail header.
 #[component]
 fn PlaylistDetailHeaderSkeleton() -> impl IntoView {
@@ -247,9 +411,16 @@ fn TrackPreviewModal(
     is_open: ReadSignal<bool>,
     /// The track to preview.
     track: ReadSignal<Option<TrackInfo>>,
+    /// Playlist containing the track, used to play it.
+    playlist_name: String,
     /// Callback when the modal is closed.
     on_close: Callback<()>,
 ) -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+    let dialog_ref = NodeRef::<html::Div>::new();
+    use_dialog_keyboard(is_open.into(), dialog_ref, on_close);
+
     view! {
         <div
             class="track-preview-overlay"
@@ -258,10 +429,17 @@ fn TrackPreviewModal(
         >
             <div
                 class="track-preview-modal"
+                node_ref=dialog_ref
                 on:click=move |e: web_sys::MouseEvent| e.stop_propagation()
+                role="dialog"
+                aria-modal="true"
+                aria-label=move || i18n.t("track_preview.title")
             >
                 {move || {
                     if let Some(t) = track.get() {
+                        let playlist_name = playlist_name.clone();
+                        let file_name = t.file_name.clone();
+                        let duration_secs = t.metadata.as_ref().and_then(|m| m.duration_secs);
                         let has_metadata = t.metadata.as_ref().is_some_and(super::super::types::Mp3Metadata::has_content);
                         let title = t.metadata.as_ref()
                             .and_then(|m| m.title.clone())
@@ -281,14 +459,32 @@ fn TrackPreviewModal(
                         let track_num = t.metadata.as_ref().and_then(|m| m.track_number);
                         let total_tracks = t.metadata.as_ref().and_then(|m| m.total_tracks);
                         let size = format_bytes(t.size_bytes);
+                        let path = t.path.clone();
 
                         view! {
                             <div class="track-preview-header">
                                 <h2 class="track-preview-title">{title}</h2>
+                                <button
+                                    class="btn btn-ghost btn-icon"
+                                    on:click=move |_| {
+                                        let path = path.clone();
+                                        spawn_local(async move {
+                                            if let Err(e) = tauri_api::reveal_in_file_manager(&path).await {
+                                                notifications.error(i18n.tf("toast.show_track_failed", &[("error", &e)]));
+                                            }
+                                        });
+                                    }
+                                    title=i18n.t("track_preview.reveal")
+                                    aria-label=i18n.t("track_preview.reveal")
+                                >
+                                    <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor">
+                                        <path d="M20 6h-8l-2-2H4c-1.1 0-1.99.9-1.99 2L2 18c0 1.1.9 2 2 2h16c1.1 0 2-.9 2-2V8c0-1.1-.9-2-2-2zm0 12H4V8h16v10z"/>
+                                    </svg>
+                                </button>
                                 <button
                                     class="btn btn-ghost btn-icon"
                                     on:click=move |_| on_close.run(())
-                                    aria-label="Close preview"
+                                    aria-label=i18n.t("track_preview.close")
                                 >
                                     <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
                                         <path d="M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 17.59 19 19 17.59 13.41 12z"/>
@@ -304,19 +500,19 @@ fn TrackPreviewModal(
                                 <div class="track-preview-details">
                                     {artist.map(|a| view! {
                                         <div class="detail-row">
-                                            <span class="detail-label">"Artist"</span>
+                                            <span class="detail-label">{i18n.t("track.artist")}</span>
                                             <span class="detail-value">{a}</span>
                                         </div>
                                     })}
                                     {album.map(|a| view! {
                                         <div class="detail-row">
-                                            <span class="detail-label">"Album"</span>
+                                            <span class="detail-label">{i18n.t("track.album")}</span>
                                             <span class="detail-value">{a}</span>
                                         </div>
                                     })}
                                     {track_num.map(|tn| view! {
                                         <div class="detail-row">
-                                            <span class="detail-label">"Track"</span>
+                                            <span class="detail-label">{i18n.t("track.number")}</span>
                                             <span class="detail-value">
                                                 {if let Some(total) = total_tracks {
                                                     format!("{tn} / {total}")
@@ -328,34 +524,34 @@ fn TrackPreviewModal(
                                     })}
                                     {duration.map(|d| view! {
                                         <div class="detail-row">
-                                            <span class="detail-label">"Duration"</span>
+                                            <span class="detail-label">{i18n.t("track.duration")}</span>
                                             <span class="detail-value">{d}</span>
                                         </div>
                                     })}
                                     {year.map(|y| view! {
                                         <div class="detail-row">
-                                            <span class="detail-label">"Year"</span>
+                                            <span class="detail-label">{i18n.t("track.year")}</span>
                                             <span class="detail-value">{y}</span>
                                         </div>
                                     })}
                                     {genre.map(|g| view! {
                                         <div class="detail-row">
-                                            <span class="detail-label">"Genre"</span>
+                                            <span class="detail-label">{i18n.t("track.genre")}</span>
                                             <span class="detail-value">{g}</span>
                                         </div>
                                     })}
                                     {bitrate.map(|b| view! {
                                         <div class="detail-row">
-                                            <span class="detail-label">"Bitrate"</span>
+                                            <span class="detail-label">{i18n.t("track.bitrate")}</span>
                                             <span class="detail-value">{b} " kbps"</span>
                                         </div>
                                     })}
                                     <div class="detail-row">
-                                        <span class="detail-label">"File Size"</span>
+                                        <span class="detail-label">{i18n.t("track.file_size")}</span>
                                         <span class="detail-value">{size}</span>
                                     </div>
                                     <div class="detail-row">
-                                        <span class="detail-label">"Filename"</span>
+                                        <span class="detail-label">{i18n.t("track.file_name")}</span>
                                         <span class="detail-value filename">{t.file_name}</span>
                                     </div>
                                     {if has_metadata {
@@ -366,17 +562,24 @@ fn TrackPreviewModal(
                                                 <svg viewBox="0 0 24 24" width="16" height="16" fill="currentColor">
                                                     <path d="M12 2C6.48 2 2 6.48 2 12s4.48 10 10 10 10-4.48 10-10S17.52 2 12 2zm1 15h-2v-2h2v2zm0-4h-2V7h2v6z"/>
                                                 </svg>
-                                                "No ID3 metadata found in this file"
+                                                {i18n.t("track_preview.no_metadata")}
                                             </div>
                                         })
                                     }}
                                 </div>
                             </div>
+                            {move || is_open.get().then(|| view! {
+                                <TrackPlayer
+                                    playlist_name=playlist_name.clone()
+                                    file_name=file_name.clone()
+                                    duration_hint=duration_secs
+                                />
+                            })}
                         }.into_any()
                     } else {
                         view! {
                             <div class="track-preview-empty">
-                                "No track selected"
+                                {i18n.t("track_preview.empty")}
                             </div>
                         }.into_any()
                     }
@@ -412,24 +615,50 @@ pub fn PlaylistDetailView(
     /// Refresh trigger - increment to reload tracks.
     #[prop(optional, default = 0u32.into())]
     refresh_trigger: Signal<u32>,
+    /// Callback when tracks were deleted, moved or tagged.
+    #[prop(optional)]
+    on_tracks_changed: Option<Callback<()>>,
+    /// Callback to run a maintenance operation on the playlist.
+    #[prop(optional)]
+    on_maintenance: Option<Callback<MaintenanceOperation>>,
 ) -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+    let undo = use_undo();
     let (state, set_state) = signal(PlaylistDetailState::Loading);
     let (playlist, set_playlist) = signal::<Option<PlaylistMetadata>>(None);
     let (tracks, set_tracks) = signal::<Vec<TrackInfo>>(vec![]);
     let (track_list_state, set_track_list_state) = signal(TrackListState::Loading);
     let (error_message, set_error_message) = signal::<Option<String>>(None);
-    let (total_duration_secs, set_total_duration_secs) = signal(0u64);
+    let (total_tracks, set_total_tracks) = signal(0usize);
+    let (recorded_duration_secs, set_recorded_duration_secs) = signal::<Option<u64>>(None);
+    let (loading_page, set_loading_page) = signal(false);
 
     // Track preview modal state
     let (preview_open, set_preview_open) = signal(false);
     let (preview_track, set_preview_track) = signal::<Option<TrackInfo>>(None);
 
+    // Multi-select state for batch actions on tracks
+    let selection = ListSelection::new();
+    let (move_targets, set_move_targets) = signal::<Vec<String>>(vec![]);
+    let (reload, set_reload) = signal(0u32);
+
+    // Per-playlist settings panel
+    let (settings_open, set_settings_open) = signal(false);
+
+    let playlist_name_for_settings = playlist_name.clone();
+    let playlist_name_for_preview = playlist_name.clone();
+    let playlist_name_for_pages = playlist_name.clone();
+    let playlist_name_for_batch = playlist_name.clone();
+    let playlist_name_for_targets = playlist_name.clone();
+    let playlist_name_for_folder = playlist_name.clone();
     let playlist_name_clone = playlist_name;
 
     // Load playlist data on mount and when refresh_trigger changes
     Effect::new(move || {
         // Subscribe to refresh_trigger to re-run when it changes
         let _trigger = refresh_trigger.get();
+        let _reload = reload.get();
         let name = playlist_name_clone.clone();
         spawn_local(async move {
             leptos::logging::log!("Loading playlist details for: {}", name);
@@ -449,20 +678,15 @@ pub fn PlaylistDetailView(
                 }
             }
 
-            // Load tracks with metadata
-            match tauri_api::get_playlist_tracks(&name).await {
-                Ok(track_list) => {
-                    leptos::logging::log!("Loaded {} tracks with metadata", track_list.len());
-
-                    // Calculate total duration
-                    let total: u64 = track_list
-                        .iter()
-                        .filter_map(|t| t.metadata.as_ref())
-                        .filter_map(|m| m.duration_secs)
-                        .sum();
-                    set_total_duration_secs.set(total);
-
-                    set_tracks.set(track_list);
+            // Load the first page of tracks; TrackList asks for the rest as
+            // the user scrolls
+            set_tracks.set(vec![]);
+            match tauri_api::list_tracks_paged(&name, 0, TRACK_PAGE_SIZE).await {
+                Ok(page) => {
+                    leptos::logging::log!("Loaded {} of {} tracks", page.tracks.len(), page.total);
+                    set_total_tracks.set(page.total);
+                    set_recorded_duration_secs.set(page.total_duration_secs);
+                    set_tracks.set(page.tracks);
                     set_track_list_state.set(TrackListState::Loaded);
                 }
                 Err(e) => {
@@ -473,6 +697,40 @@ pub fn PlaylistDetailView(
         });
     });
 
+    let on_load_more = Callback::new(move |loaded: usize| {
+        if loading_page.get_untracked() || loaded >= total_tracks.get_untracked() {
+            return;
+        }
+        set_loading_page.set(true);
+        let name = playlist_name_for_pages.clone();
+        spawn_local(async move {
+            match tauri_api::list_tracks_paged(&name, loaded, TRACK_PAGE_SIZE).await {
+                // Drop pages that no longer line up, e.g. after a refresh
+                Ok(page) if page.offset == tracks.with_untracked(Vec::len) => {
+                    set_total_tracks.set(page.total);
+                    set_tracks.update(|tracks| tracks.extend(page.tracks));
+                }
+                Ok(_) => {}
+                Err(e) => leptos::logging::error!("Failed to load more tracks: {}", e),
+            }
+            set_loading_page.set(false);
+        });
+    });
+
+    // Prefer durations recorded at download time, which cover every track;
+    // otherwise add up the metadata of the tracks loaded so far
+    let total_duration_secs = Signal::derive(move || {
+        recorded_duration_secs.get().unwrap_or_else(|| {
+            tracks.with(|tracks| {
+                tracks
+                    .iter()
+                    .filter_map(|t| t.metadata.as_ref())
+                    .filter_map(|m| m.duration_secs)
+                    .sum()
+            })
+        })
+    });
+
     // Handle track click to show preview
     let on_track_click = Callback::new(move |track: TrackInfo| {
         set_preview_track.set(Some(track));
@@ -483,6 +741,119 @@ pub fn PlaylistDetailView(
         set_preview_open.set(false);
     });
 
+    // Other playlists are the destinations for moving tracks
+    Effect::new(move || {
+        if !selection.is_active() {
+            return;
+        }
+        let current = playlist_name_for_targets.clone();
+        spawn_local(async move {
+            match tauri_api::list_playlists().await {
+                Ok(playlists) => set_move_targets.set(
+                    playlists
+                        .into_iter()
+                        .map(|p| p.name)
+                        .filter(|name| *name != current)
+                        .collect(),
+                ),
+                Err(e) => leptos::logging::error!("Failed to load playlists: {}", e),
+            }
+        });
+    });
+
+    let on_batch = Callback::new(move |(action, file_names): (BatchAction, Vec<String>)| {
+        let name = playlist_name_for_batch.clone();
+        spawn_local(async move {
+            // Deleted tracks go to the trash so they can be undone
+            let mut trashed = None;
+            let (done, result) = match action {
+                BatchAction::Delete => (
+                    "batch.deleted",
+                    tauri_api::trash_tracks(&name, &file_names, None)
+                        .await
+                        .map(|t| {
+                            trashed = t.entry.map(|entry| entry.id);
+                            t.result
+                        }),
+                ),
+                BatchAction::Move(to) => (
+                    "batch.moved",
+                    tauri_api::move_tracks(&name, &to, &file_names).await,
+                ),
+                BatchAction::Tag(tag) => (
+                    "batch.tagged",
+                    tauri_api::tag_tracks(&name, &file_names, &tag).await,
+                ),
+                BatchAction::Sync => return,
+            };
+            match result {
+                Ok(result) => {
+                    let message = batch_summary(i18n.locale(), done, "count.tracks", &result);
+                    if !result.failed.is_empty() {
+                        notifications.warning(message);
+                    } else if trashed.is_none() {
+                        notifications.success(message);
+                    }
+                    if let Some(id) = trashed {
+                        undo.record(
+                            i18n.tf(
+                                "undo.tracks_removed",
+                                &[
+                                    (
+                                        "tracks",
+                                        &i18n.count("count.tracks", result.succeeded.len()),
+                                    ),
+                                    ("name", &name),
+                                ],
+                            ),
+                            UndoAction::Restore(id),
+                        );
+                    }
+                    set_reload.update(|n| *n = n.wrapping_add(1));
+                    if let Some(callback) = on_tracks_changed {
+                        callback.run(());
+                    }
+                }
+                Err(e) => {
+                    notifications.error(i18n.tf("toast.update_tracks_failed", &[("error", &e)]))
+                }
+            }
+        });
+    });
+
+    let on_open_folder = Callback::new(move |()| {
+        let name = playlist_name_for_folder.clone();
+        spawn_local(async move {
+            if let Err(e) = tauri_api::open_playlist_folder(&name).await {
+                notifications.error(i18n.tf("toast.open_folder_failed", &[("error", &e)]));
+            }
+        });
+    });
+
+    let on_track_action =
+        Callback::new(
+            move |(action, track): (TrackAction, TrackInfo)| match action {
+                TrackAction::Preview => on_track_click.run(track),
+                TrackAction::Reveal => spawn_local(async move {
+                    if let Err(e) = tauri_api::reveal_in_file_manager(&track.path).await {
+                        notifications.error(i18n.tf("toast.show_track_failed", &[("error", &e)]));
+                    }
+                }),
+                TrackAction::FetchLyrics => spawn_local(async move {
+                    match tauri_api::fetch_track_lyrics(&track.path).await {
+                        Ok(Some(_)) => notifications
+                            .success(i18n.tf("toast.lyrics_saved", &[("name", &track.file_name)])),
+                        Ok(None) => notifications
+                            .info(i18n.tf("toast.lyrics_not_found", &[("name", &track.file_name)])),
+                        Err(e) => {
+                            notifications.error(i18n.tf("toast.lyrics_failed", &[("error", &e)]))
+                        }
+                    }
+                }),
+                TrackAction::Delete => on_batch.run((BatchAction::Delete, vec![track.file_name])),
+            },
+        );
+
     view! {
         <div class="playlist-detail-view">
             {move || match state.get() {
@@ -490,7 +861,7 @@ pub fn PlaylistDetailView(
                     view! {
                         <PlaylistDetailHeaderSkeleton />
                         <div class="playlist-detail-content">
-                            <h3 class="tracks-section-title">"Tracks"</h3>
+                            <h3 class="tracks-section-title">{i18n.t("playlist_detail.tracks")}</h3>
                             <TrackList
                                 tracks=tracks
                                 state=TrackListState::Loading
@@ -499,7 +870,7 @@ pub fn PlaylistDetailView(
                     }.into_any()
                 }
                 PlaylistDetailState::Error => {
-                    let msg = error_message.get().unwrap_or_else(|| "Failed to load playlist".to_string());
+                    let msg = error_message.get().unwrap_or_else(|| i18n.t("playlist_detail.load_failed"));
                     view! {
                         <div class="playlist-detail-error">
                             <button
@@ -509,13 +880,13 @@ pub fn PlaylistDetailView(
                                 <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
                                     <path d="M20 11H7.83l5.59-5.59L12 4l-8 8 8 8 1.41-1.41L7.83 13H20v-2z"/>
                                 </svg>
-                                "Back"
+                                {i18n.t("common.back")}
                             </button>
                             <div class="error-content">
                                 <svg viewBox="0 0 24 24" width="64" height="64" fill="var(--accent-error)">
                                     <path d="M12 2C6.48 2 2 6.48 2 12s4.48 10 10 10 10-4.48 10-10S17.52 2 12 2zm1 15h-2v-2h2v2zm0-4h-2V7h2v6z"/>
                                 </svg>
-                                <h3>"Failed to load playlist"</h3>
+                                <h3>{i18n.t("playlist_detail.load_failed")}</h3>
                                 <p class="error-message">{msg}</p>
                             </div>
                         </div>
@@ -523,6 +894,7 @@ pub fn PlaylistDetailView(
                 }
                 PlaylistDetailState::Loaded => {
                     if let Some(pl) = playlist.get() {
+                        let has_source = pl.source_url.is_some();
                         view! {
                             <PlaylistDetailHeader
                                 playlist=pl
@@ -530,14 +902,46 @@ pub fn PlaylistDetailView(
                                 on_back=on_back
                                 on_sync=on_sync
                                 on_delete=on_delete
+                                on_settings=Callback::new(move |()| set_settings_open.update(|open| *open = !*open))
+                                on_open_folder=on_open_folder
+                                on_maintenance=on_maintenance
                             />
+                            {
+                                let name = playlist_name_for_settings.clone();
+                                move || settings_open.get().then(|| view! {
+                                    <div class="playlist-detail-settings">
+                                        <h3 class="tracks-section-title">{i18n.t("playlist_detail.settings")}</h3>
+                                        <PlaylistSettings playlist_name=name.clone() has_source=has_source />
+                                    </div>
+                                })
+                            }
                             <div class="playlist-detail-content">
-                                <h3 class="tracks-section-title">"Tracks"</h3>
-                                <p class="tracks-section-hint">"Click on a track to view detailed information"</p>
+                                <div class="tracks-section-header">
+                                    <h3 class="tracks-section-title">{i18n.t("playlist_detail.tracks")}</h3>
+                                    <button
+                                        class="btn btn-ghost btn-sm"
+                                        on:click=move |_| selection.set_active(!selection.is_active())
+                                    >
+                                        {move || i18n.t(if selection.is_active() { "common.done" } else { "playlists.select" })}
+                                    </button>
+                                </div>
+                                <p class="tracks-section-hint">
+                                    {move || i18n.t(if selection.is_active() {
+                                        "playlist_detail.select_hint"
+                                    } else {
+                                        "playlist_detail.track_hint"
+                                    })}
+                                </p>
                                 <TrackList
                                     tracks=tracks
                                     state=track_list_state.get()
                                     on_track_click=on_track_click
+                                    total=total_tracks
+                                    on_load_more=on_load_more
+                                    selection=selection
+                                    on_batch=on_batch
+                                    move_targets=move_targets
+                                    on_track_action=on_track_action
                                 />
                             </div>
                         }.into_any()
@@ -551,10 +955,10 @@ pub fn PlaylistDetailView(
                                     <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
                                         <path d="M20 11H7.83l5.59-5.59L12 4l-8 8 8 8 1.41-1.41L7.83 13H20v-2z"/>
                                     </svg>
-                                    "Back"
+                                    {i18n.t("common.back")}
                                 </button>
                                 <div class="error-content">
-                                    <h3>"Playlist not found"</h3>
+                                    <h3>{i18n.t("playlist_detail.not_found")}</h3>
                                 </div>
                             </div>
                         }.into_any()
@@ -565,6 +969,7 @@ pub fn PlaylistDetailView(
             <TrackPreviewModal
                 is_open=preview_open
                 track=preview_track
+                playlist_name=playlist_name_for_preview
                 on_close=on_preview_close
             />
         </div>
@@ -0,0 +1,193 @@
+//! Grid layout pieces for the playlist library: cover tiles and view controls.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::context_menu::ContextMenu;
+use crate::components::playlist_card::PlaylistAction;
+use crate::i18n::use_i18n;
+use crate::tauri_api;
+use crate::types::{PlaylistLayout, PlaylistMetadata, PlaylistSort, PlaylistViewPreferences};
+
+/// Format bytes to a short human-readable badge.
+fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.0} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.0} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+/// A compact playlist tile showing the cached cover, track count and size.
+///
+/// The cover is fetched through the thumbnail cache once the tile mounts;
+/// until then, or when the playlist has no cover, a placeholder icon is shown.
+#[component]
+pub fn PlaylistGridTile(
+    /// The playlist to display.
+    playlist: PlaylistMetadata,
+    /// Callback when the tile is clicked.
+    on_select: Callback<PlaylistMetadata>,
+    /// Whether this playlist is currently selected.
+    #[prop(default = false)]
+    selected: bool,
+    /// Callback with an action chosen from the tile's context menu.
+    #[prop(optional_no_strip)]
+    on_action: Option<Callback<(PlaylistAction, PlaylistMetadata)>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let (cover_url, set_cover_url) = signal::<Option<String>>(None);
+
+    if playlist.thumbnail_url.is_some() {
+        let name = playlist.name.clone();
+        spawn_local(async move {
+            match tauri_api::get_playlist_cover_url(&name).await {
+                Ok(url) => set_cover_url.set(url),
+                Err(e) => leptos::logging::warn!("Failed to load cover for '{}': {}", name, e),
+            }
+        });
+    }
+
+    let name = playlist.name.clone();
+    let track_count = playlist.track_count;
+    let size = format_bytes(playlist.total_bytes);
+    let title = i18n.tf("playlist_grid.open", &[("name", &name)]);
+    let playlist_for_menu = playlist.clone();
+
+    let tile = view! {
+        <button
+            class="playlist-tile"
+            class:selected=selected
+            title=title
+            on:click=move |_| on_select.run(playlist.clone())
+        >
+            <div class="playlist-tile-cover">
+                {move || match cover_url.get() {
+                    Some(url) => view! {
+                        <img src=url alt="" class="playlist-tile-img" loading="lazy" />
+                    }.into_any(),
+                    None => view! {
+                        <svg class="playlist-tile-placeholder" viewBox="0 0 24 24" width="40" height="40" fill="currentColor" aria-hidden="true">
+                            <path d="M15 6H3v2h12V6zm0 4H3v2h12v-2zM3 16h8v-2H3v2zM17 6v8.18c-.31-.11-.65-.18-1-.18-1.66 0-3 1.34-3 3s1.34 3 3 3 3-1.34 3-3V8h3V6h-5z"/>
+                        </svg>
+                    }.into_any(),
+                }}
+                <div class="playlist-tile-badges">
+                    <span class="playlist-tile-badge">{i18n.count("count.tracks", track_count)}</span>
+                    <span class="playlist-tile-badge">{size}</span>
+                </div>
+            </div>
+            <span class="playlist-tile-name">{name}</span>
+        </button>
+    };
+
+    match on_action {
+        Some(callback) => view! {
+            <ContextMenu
+                items=PlaylistAction::menu_items(&playlist_for_menu)
+                on_action=Callback::new(move |action| {
+                    callback.run((action, playlist_for_menu.clone()));
+                })
+                label="playlist_menu.label"
+            >
+                {tile}
+            </ContextMenu>
+        }
+        .into_any(),
+        None => tile.into_any(),
+    }
+}
+
+/// Layout toggle and sort selector for the playlist library.
+#[component]
+pub fn PlaylistViewControls(
+    /// Current layout and sort order.
+    view: ReadSignal<PlaylistViewPreferences>,
+    /// Callback with the updated preferences.
+    on_change: Callback<PlaylistViewPreferences>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let set_layout = move |layout: PlaylistLayout| {
+        let mut prefs = view.get_untracked();
+        if prefs.layout != layout {
+            prefs.layout = layout;
+            on_change.run(prefs);
+        }
+    };
+
+    view! {
+        <div class="playlist-view-controls">
+            <label class="playlist-sort">
+                <span>{move || i18n.t("playlist_grid.sort_by")}</span>
+                <select
+                    class="settings-input"
+                    on:change=move |ev| {
+                        if let Some(sort) = PlaylistSort::from_id(&event_target_value(&ev)) {
+                            let mut prefs = view.get_untracked();
+                            prefs.sort = sort;
+                            on_change.run(prefs);
+                        }
+                    }
+                >
+                    {PlaylistSort::ALL.into_iter().map(|sort| {
+                        view! {
+                            <option
+                                value=sort.id()
+                                selected=move || view.get().sort == sort
+                            >
+                                {move || i18n.t(sort.label_key())}
+                            </option>
+                        }
+                    }).collect_view()}
+                </select>
+            </label>
+            <div class="playlist-layout-toggle" role="group" aria-label=move || i18n.t("playlist_grid.layout")>
+                <button
+                    class="btn btn-icon"
+                    class:active=move || view.get().layout == PlaylistLayout::List
+                    title=move || i18n.t("playlist_grid.list_view")
+                    aria-label=move || i18n.t("playlist_grid.list_view")
+                    aria-pressed=move || (view.get().layout == PlaylistLayout::List).to_string()
+                    on:click=move |_| set_layout(PlaylistLayout::List)
+                >
+                    <svg viewBox="0 0 24 24" width="18" height="18" fill="currentColor">
+                        <path d="M3 13h2v-2H3v2zm0 4h2v-2H3v2zm0-8h2V7H3v2zm4 4h14v-2H7v2zm0 4h14v-2H7v2zM7 7v2h14V7H7z"/>
+                    </svg>
+                </button>
+                <button
+                    class="btn btn-icon"
+                    class:active=move || view.get().layout == PlaylistLayout::Grid
+                    title=move || i18n.t("playlist_grid.grid_view")
+                    aria-label=move || i18n.t("playlist_grid.grid_view")
+                    aria-pressed=move || (view.get().layout == PlaylistLayout::Grid).to_string()
+                    on:click=move |_| set_layout(PlaylistLayout::Grid)
+                >
+                    <svg viewBox="0 0 24 24" width="18" height="18" fill="currentColor">
+                        <path d="M4 11h5V5H4v6zm0 7h5v-6H4v6zm6 0h5v-6h-5v6zm6 0h5v-6h-5v6zm-6-7h5V5h-5v6zm6-6v6h5V5h-5z"/>
+                    </svg>
+                </button>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(500), "500 B");
+        assert_eq!(format_bytes(2048), "2 KB");
+        assert_eq!(format_bytes(5 * 1_048_576), "5 MB");
+        assert_eq!(format_bytes(1_073_741_824), "1.0 GB");
+    }
+}
@@ -3,8 +3,12 @@
 use leptos::prelude::*;
 
 use crate::components::PlaylistCard;
+use crate::components::batch_actions::{BatchAction, BatchActionBar, ListSelection};
 use crate::components::empty_state::{EmptyStateSize, ErrorEmptyState, NoPlaylistsEmptyState};
-use crate::types::{DeviceInfo, PlaylistMetadata};
+use crate::components::playlist_card::PlaylistAction;
+use crate::components::playlist_grid::{PlaylistGridTile, PlaylistViewControls};
+use crate::i18n::use_i18n;
+use crate::types::{DeviceInfo, PlaylistLayout, PlaylistMetadata, PlaylistViewPreferences};
 
 /// Loading skeleton for a single playlist card.
 #[component]
@@ -113,6 +117,11 @@ pub enum PlaylistListState {
 /// - Error state with retry capability
 /// - Smooth scrolling
 /// - Playlist count summary
+/// - Multi-select with shift-click ranges and batch actions, when
+///   `selection` and `on_batch` are given
+/// - Right-click and long-press context menus, when `on_action` is given
+/// - Card or cover-grid layout and sort order, with controls when
+///   `on_view_change` is given
 #[component]
 
 pub fn PlaylistList(
@@ -146,9 +155,57 @@ pub fn PlaylistList(
     /// Whether to show the playlist summary.
     #[prop(default = true)]
     show_summary: bool,
+    /// Multi-select state; while active, clicking a card selects it.
+    #[prop(optional)]
+    selection: Option<ListSelection>,
+    /// Callback with a batch action and the selected playlist names.
+    #[prop(optional)]
+    on_batch: Option<Callback<(BatchAction, Vec<String>)>>,
+    /// Layout and sort order; defaults to the card list sorted by name.
+    #[prop(optional)]
+    view: Option<ReadSignal<PlaylistViewPreferences>>,
+    /// Callback when the layout or sort order is changed from the controls.
+    #[prop(optional)]
+    on_view_change: Option<Callback<PlaylistViewPreferences>>,
+    /// Callback with an action chosen from a playlist's context menu.
+    #[prop(optional)]
+    on_action: Option<Callback<(PlaylistAction, PlaylistMetadata)>>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let min_width = min_item_width;
     let min_width_style = format!("--grid-min-width: {min_width}");
+    let view_prefs = view.unwrap_or_else(|| signal(PlaylistViewPreferences::default()).0);
+    let sorted = move || {
+        let mut list = playlists.get();
+        view_prefs.get().sort.apply(&mut list);
+        list
+    };
+
+    let names = move || {
+        let mut list = playlists.get_untracked();
+        view_prefs.get_untracked().sort.apply(&mut list);
+        list.into_iter().map(|p| p.name).collect::<Vec<_>>()
+    };
+    let controls = move || {
+        on_view_change.map(|on_change| {
+            view! { <PlaylistViewControls view=view_prefs on_change=on_change /> }
+        })
+    };
+    let action_bar = move || {
+        let selection = selection.filter(ListSelection::is_active)?;
+        let on_action = on_batch?;
+        Some(view! {
+            <BatchActionBar
+                selection=selection
+                all_keys=Signal::derive(move || {
+                    playlists.with(|list| list.iter().map(|p| p.name.clone()).collect())
+                })
+                noun="count.playlists"
+                on_action=on_action
+                allow_sync=true
+            />
+        })
+    };
 
     view! {
         <div class="playlist-list">
@@ -157,12 +214,12 @@ pub fn PlaylistList(
                     view! { <LoadingState count=6 /> }.into_any()
                 }
                 PlaylistListState::Error => {
-                    let msg = error_message.clone().unwrap_or_else(|| "An error occurred".to_string());
+                    let msg = error_message.clone().unwrap_or_else(|| i18n.t("playlist_list.load_failed"));
                     let retry_cb = on_retry.unwrap_or_else(|| Callback::new(|()| {}));
                     view! { <PlaylistListErrorState message=msg on_retry=retry_cb /> }.into_any()
                 }
                 PlaylistListState::Loaded => {
-                    let playlist_list = playlists.get();
+                    let playlist_list = sorted();
 
                     if playlist_list.is_empty() {
                         let create_callback = on_create;
@@ -187,29 +244,74 @@ pub fn PlaylistList(
                     } else {
                         let total_bytes: u64 = playlist_list.iter().map(|p| p.total_bytes).sum();
                         let count = playlist_list.len();
-                        let style = min_width_style.clone();
+                        let grid = view_prefs.get().layout == PlaylistLayout::Grid;
+                        let (container_class, style) = if grid {
+                            ("responsive-grid playlist-tile-grid", "--grid-min-width: 160px".to_string())
+                        } else {
+                            ("responsive-grid playlist-grid-container", min_width_style.clone())
+                        };
 
                         view! {
                             <div class="playlist-list-content">
-                                {if show_summary {
-                                    Some(view! { <PlaylistSummary count=count total_bytes=total_bytes /> })
-                                } else {
-                                    None
-                                }}
-                                <div class="responsive-grid playlist-grid-container" style=style>
-                                    {playlist_list.into_iter().map(|playlist| {
+                                <div class="playlist-list-toolbar">
+                                    {if show_summary {
+                                        Some(view! { <PlaylistSummary count=count total_bytes=total_bytes /> })
+                                    } else {
+                                        None
+                                    }}
+                                    {controls}
+                                </div>
+                                {action_bar}
+                                <div class=container_class style=style>
+                                    {playlist_list.into_iter().enumerate().map(|(index, playlist)| {
                                         let is_selected = selected_playlist.get()
                                             .as_ref()
                                             .is_some_and(|s| s.name == playlist.name);
+                                        let name = playlist.name.clone();
+                                        let checked = Signal::derive(move || {
+                                            selection.is_some_and(|s| s.is_selected(&name))
+                                        });
+                                        let label = i18n.tf("playlist_list.select", &[("name", &playlist.name)]);
                                         view! {
-                                            <PlaylistCard
-                                                playlist=playlist
-                                                on_select=on_select
-                                                on_delete=on_delete
-                                                on_sync=on_sync
-                                                selected_device=selected_device
-                                                selected=is_selected
-                                            />
+                                            <div class="batch-select-item" class:checked=checked>
+                                                {if grid {
+                                                    view! {
+                                                        <PlaylistGridTile
+                                                            playlist=playlist
+                                                            on_select=on_select
+                                                            selected=is_selected
+                                                            on_action=on_action
+                                                        />
+                                                    }.into_any()
+                                                } else {
+                                                    view! {
+                                                        <PlaylistCard
+                                                            playlist=playlist
+                                                            on_select=on_select
+                                                            on_delete=on_delete
+                                                            on_sync=on_sync
+                                                            selected_device=selected_device
+                                                            selected=is_selected
+                                                            on_action=on_action
+                                                        />
+                                                    }.into_any()
+                                                }}
+                                                {move || selection.filter(ListSelection::is_active).map(|selection| {
+                                                    view! {
+                                                        <button
+                                                            class="batch-select-overlay"
+                                                            role="checkbox"
+                                                            aria-checked=move || checked.get().to_string()
+                                                            aria-label=label.clone()
+                                                            on:click=move |e: web_sys::MouseEvent| {
+                                                                selection.click(&names(), index, e.shift_key());
+                                                            }
+                                                        >
+                                                            <span class="batch-select-check"></span>
+                                                        </button>
+                                                    }
+                                                })}
+                                            </div>
                                         }
                                     }).collect_view()}
                                 </div>
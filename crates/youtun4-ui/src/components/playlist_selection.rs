@@ -5,6 +5,7 @@
 
 use leptos::prelude::*;
 
+use crate::i18n::use_i18n;
 use crate::types::PlaylistMetadata;
 
 /// Format bytes to human-readable string.
@@ -58,6 +59,8 @@ pub fn PlaylistSelectionCard(
     #[prop(default = false)]
     selected: bool,
 ) -> impl IntoView {
+    let i18n = use_i18n();
+    let track_count = playlist.track_count;
     let playlist_clone = playlist.clone();
     let playlist_name = playlist.name.clone();
 
@@ -117,7 +120,7 @@ pub fn PlaylistSelectionCard(
             <div class="playlist-info">
                 <h4 class="playlist-name">{playlist_name}</h4>
                 <div class="playlist-meta">
-                    <span class="track-count">{playlist.track_count} " tracks"</span>
+                    <span class="track-count">{move || i18n.count("count.tracks", track_count)}</span>
                     <span class="separator">"•"</span>
                     <span class="size">{format_bytes(playlist.total_bytes)}</span>
                 </div>
@@ -161,13 +164,14 @@ fn SelectionLoadingState(
 /// Empty state when no playlists are available for selection.
 #[component]
 fn SelectionEmptyState() -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <div class="empty-state playlist-selection-empty">
             <svg viewBox="0 0 24 24" width="48" height="48" fill="var(--text-disabled)">
                 <path d="M15 6H3v2h12V6zm0 4H3v2h12v-2zM3 16h8v-2H3v2zM17 6v8.18c-.31-.11-.65-.18-1-.18-1.66 0-3 1.34-3 3s1.34 3 3 3 3-1.34 3-3V8h3V6h-5z"/>
             </svg>
-            <h3>"No playlists available"</h3>
-            <p>"Create a playlist first to select it for syncing"</p>
+            <h3>{i18n.t("playlist_selection.empty.title")}</h3>
+            <p>{i18n.t("playlist_selection.empty.message")}</p>
         </div>
     }
 }
@@ -211,8 +215,13 @@ pub fn PlaylistSelectionList(
     #[prop(optional, into)]
     description: Option<String>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     view! {
-        <div class="playlist-selection-list" role="radiogroup" aria-label="Select a playlist">
+        <div
+            class="playlist-selection-list"
+            role="radiogroup"
+            aria-label=move || i18n.t("playlist_selection.label")
+        >
             {title.map(|t| {
                 view! {
                     <div class="playlist-selection-header">
@@ -236,8 +245,8 @@ pub fn PlaylistSelectionList(
                                 <svg viewBox="0 0 24 24" width="48" height="48" fill="var(--accent-error)">
                                     <path d="M12 2C6.48 2 2 6.48 2 12s4.48 10 10 10 10-4.48 10-10S17.52 2 12 2zm1 15h-2v-2h2v2zm0-4h-2V7h2v6z"/>
                                 </svg>
-                                <h3>"Failed to load playlists"</h3>
-                                <p>"Please try again"</p>
+                                <h3>{i18n.t("playlist_selection.error.title")}</h3>
+                                <p>{i18n.t("playlist_selection.error.message")}</p>
                             </div>
                         }.into_any()
                     }
@@ -278,6 +287,7 @@ pub fn PlaylistSelectionSummary(
     /// Currently selected playlist.
     selected: ReadSignal<Option<PlaylistMetadata>>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <div class="playlist-selection-summary">
             {move || {
@@ -285,10 +295,12 @@ pub fn PlaylistSelectionSummary(
                     Some(playlist) => {
                         view! {
                             <div class="playlist-selection-summary-content">
-                                <span class="label">"Selected:"</span>
+                                <span class="label">{i18n.t("playlist_selection.selected")}</span>
                                 <span class="playlist-name">{playlist.name}</span>
                                 <span class="separator">"•"</span>
-                                <span class="track-count">{playlist.track_count} " tracks"</span>
+                                <span class="track-count">
+                                    {i18n.count("count.tracks", playlist.track_count)}
+                                </span>
                                 <span class="separator">"•"</span>
                                 <span class="size">{format_bytes(playlist.total_bytes)}</span>
                             </div>
@@ -297,7 +309,7 @@ pub fn PlaylistSelectionSummary(
                     None => {
                         view! {
                             <div class="playlist-selection-summary-content empty">
-                                <span class="hint">"Select a playlist to sync"</span>
+                                <span class="hint">{i18n.t("playlist_selection.hint")}</span>
                             </div>
                         }.into_any()
                     }
@@ -0,0 +1,542 @@
+//! Per-playlist settings that override the global ones.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::use_notifications;
+use crate::i18n::use_i18n;
+use crate::tauri_api;
+use crate::types::{
+    DownloadProfile, DownloadQuality, OutputFormat, PlaylistOverrides, RefreshSchedule, TagPolicy,
+};
+
+/// Download quality choices, as (value, label key); the empty value keeps
+/// the global setting.
+const QUALITY_CHOICES: &[(&str, &str)] = &[
+    ("", "playlist_settings.use_global"),
+    ("low", "playlist_settings.quality.low"),
+    ("medium", "playlist_settings.quality.medium"),
+    ("high", "playlist_settings.quality.high"),
+];
+
+/// Lyrics lookup choices, as (value, label key); the empty value keeps the
+/// global setting.
+const LYRICS_CHOICES: &[(&str, &str)] = &[
+    ("", "playlist_settings.use_global"),
+    ("on", "playlist_settings.lyrics.on"),
+    ("off", "playlist_settings.lyrics.off"),
+];
+
+/// Bitrate choices of the download profile, in kbps; the empty value keeps
+/// the download quality.
+const BITRATE_CHOICES: &[&str] = &["", "96", "128", "160", "192", "256", "320"];
+
+/// Output format choices of the download profile, as (value, label key);
+/// the empty value keeps the global setting.
+const FORMAT_CHOICES: &[(&str, &str)] = &[
+    ("", "playlist_settings.use_global"),
+    ("mp3", "playlist_settings.format.mp3"),
+    ("original", "playlist_settings.format.original"),
+];
+
+/// Tag choices of the download profile, as (value, label key); the empty
+/// value keeps the global setting.
+const TAG_CHOICES: &[(&str, &str)] = &[
+    ("", "playlist_settings.use_global"),
+    ("none", "playlist_settings.tags.none"),
+    ("tags", "playlist_settings.tags.tags"),
+    ("tags_and_cover_art", "playlist_settings.tags.cover_art"),
+];
+
+/// Refresh schedule choices, as (value, label key); the empty value turns
+/// scheduled refreshes off.
+const REFRESH_CHOICES: &[(&str, &str)] = &[
+    ("", "playlist_settings.refresh.never"),
+    ("6h", "playlist_settings.refresh.6h"),
+    ("12h", "playlist_settings.refresh.12h"),
+    ("24h", "playlist_settings.refresh.24h"),
+    ("nightly", "playlist_settings.refresh.nightly"),
+    ("weekly", "playlist_settings.refresh.weekly"),
+];
+
+/// Minutes past midnight of the nightly refresh.
+const NIGHTLY_MINUTE_OF_DAY: u32 = 3 * 60;
+
+/// Select value for a refresh schedule.
+#[must_use]
+pub const fn refresh_value(schedule: Option<RefreshSchedule>) -> &'static str {
+    match schedule {
+        Some(RefreshSchedule::Interval { hours: 6 }) => "6h",
+        Some(RefreshSchedule::Interval { hours: 12 }) => "12h",
+        Some(RefreshSchedule::Interval { hours: 24 }) => "24h",
+        Some(RefreshSchedule::Interval { hours: 168 }) => "weekly",
+        Some(RefreshSchedule::Daily { .. }) => "nightly",
+        Some(RefreshSchedule::Interval { .. }) | None => "",
+    }
+}
+
+/// Refresh schedule for a select value, with nightly refreshes in the time
+/// zone `utc_offset_minutes` ahead of UTC.
+#[must_use]
+pub fn parse_refresh(value: &str, utc_offset_minutes: i32) -> Option<RefreshSchedule> {
+    let hours = match value {
+        "6h" => 6,
+        "12h" => 12,
+        "24h" => 24,
+        "weekly" => 168,
+        "nightly" => {
+            return Some(RefreshSchedule::Daily {
+                minute_of_day: NIGHTLY_MINUTE_OF_DAY,
+                utc_offset_minutes,
+            });
+        }
+        _ => return None,
+    };
+    Some(RefreshSchedule::Interval { hours })
+}
+
+/// Offset of the local time zone from UTC, in minutes.
+fn local_utc_offset_minutes() -> i32 {
+    // getTimezoneOffset is UTC minus local time
+    -(js_sys::Date::new_0().get_timezone_offset() as i32)
+}
+
+/// Select value for an overridden lyrics lookup.
+#[must_use]
+pub const fn lyrics_value(fetch_lyrics: Option<bool>) -> &'static str {
+    match fetch_lyrics {
+        None => "",
+        Some(true) => "on",
+        Some(false) => "off",
+    }
+}
+
+/// Lyrics lookup override for a select value.
+#[must_use]
+pub fn parse_lyrics(value: &str) -> Option<bool> {
+    match value {
+        "on" => Some(true),
+        "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// Select value for a profile bitrate.
+#[must_use]
+pub fn bitrate_value(bitrate_kbps: Option<u32>) -> String {
+    bitrate_kbps
+        .map(|kbps| kbps.to_string())
+        .unwrap_or_default()
+}
+
+/// Profile bitrate for a select value.
+#[must_use]
+pub fn parse_bitrate(value: &str) -> Option<u32> {
+    value.parse().ok()
+}
+
+/// Select value for a profile output format.
+#[must_use]
+pub const fn format_value(format: Option<OutputFormat>) -> &'static str {
+    match format {
+        None => "",
+        Some(OutputFormat::Mp3) => "mp3",
+        Some(OutputFormat::Original) => "original",
+    }
+}
+
+/// Profile output format for a select value.
+#[must_use]
+pub fn parse_format(value: &str) -> Option<OutputFormat> {
+    match value {
+        "mp3" => Some(OutputFormat::Mp3),
+        "original" => Some(OutputFormat::Original),
+        _ => None,
+    }
+}
+
+/// Select value for a profile tag policy.
+#[must_use]
+pub const fn tag_value(policy: Option<TagPolicy>) -> &'static str {
+    match policy {
+        None => "",
+        Some(TagPolicy::None) => "none",
+        Some(TagPolicy::Tags) => "tags",
+        Some(TagPolicy::TagsAndCoverArt) => "tags_and_cover_art",
+    }
+}
+
+/// Profile tag policy for a select value.
+#[must_use]
+pub fn parse_tag(value: &str) -> Option<TagPolicy> {
+    match value {
+        "none" => Some(TagPolicy::None),
+        "tags" => Some(TagPolicy::Tags),
+        "tags_and_cover_art" => Some(TagPolicy::TagsAndCoverArt),
+        _ => None,
+    }
+}
+
+/// Select value for an overridden download quality.
+#[must_use]
+pub const fn quality_value(quality: Option<DownloadQuality>) -> &'static str {
+    match quality {
+        None => "",
+        Some(DownloadQuality::Low) => "low",
+        Some(DownloadQuality::Medium) => "medium",
+        Some(DownloadQuality::High) => "high",
+    }
+}
+
+/// Download quality override for a select value.
+#[must_use]
+pub fn parse_quality(value: &str) -> Option<DownloadQuality> {
+    match value {
+        "low" => Some(DownloadQuality::Low),
+        "medium" => Some(DownloadQuality::Medium),
+        "high" => Some(DownloadQuality::High),
+        _ => None,
+    }
+}
+
+/// Settings for a single playlist, saved as overrides of the global settings.
+///
+/// Settings left on "Use global setting" follow the settings panel. The
+/// download profile is saved with the playlist itself and wins over the
+/// overrides. Playlists with a `YouTube` source can also be refreshed on a
+/// schedule.
+#[component]
+pub fn PlaylistSettings(
+    /// Name of the playlist.
+    playlist_name: String,
+    /// Whether the playlist has a `YouTube` source to refresh from.
+    #[prop(optional)]
+    has_source: bool,
+) -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+    let (overrides, set_overrides) = signal(PlaylistOverrides::default());
+    let (saved, set_saved) = signal(PlaylistOverrides::default());
+    let (profile, set_profile) = signal(DownloadProfile::default());
+    let (saved_profile, set_saved_profile) = signal(DownloadProfile::default());
+    // (select value, remove missing tracks) of the refresh schedule
+    let (refresh, set_refresh) = signal((String::new(), false));
+    let (saved_refresh, set_saved_refresh) = signal((String::new(), false));
+    let (is_saving, set_is_saving) = signal(false);
+
+    let name_for_load = playlist_name.clone();
+    spawn_local(async move {
+        match tauri_api::get_playlist_overrides(&name_for_load).await {
+            Ok(loaded) => {
+                set_overrides.try_set(loaded);
+                set_saved.try_set(loaded);
+            }
+            Err(e) => leptos::logging::error!("Failed to load playlist settings: {}", e),
+        }
+        match tauri_api::get_playlist_download_profile(&name_for_load).await {
+            Ok(loaded) => {
+                let loaded = loaded.unwrap_or_default();
+                set_profile.try_set(loaded.clone());
+                set_saved_profile.try_set(loaded);
+            }
+            Err(e) => leptos::logging::error!("Failed to load download profile: {}", e),
+        }
+        if has_source {
+            match tauri_api::get_refresh_schedules().await {
+                Ok(schedules) => {
+                    let loaded = schedules
+                        .into_iter()
+                        .find(|s| s.playlist == name_for_load)
+                        .map(|s| {
+                            (
+                                refresh_value(Some(s.schedule)).to_string(),
+                                s.remove_missing,
+                            )
+                        })
+                        .unwrap_or_default();
+                    set_refresh.try_set(loaded.clone());
+                    set_saved_refresh.try_set(loaded);
+                }
+                Err(e) => leptos::logging::error!("Failed to load refresh schedule: {}", e),
+            }
+        }
+    });
+
+    let on_save = move |_| {
+        let name = playlist_name.clone();
+        let current = overrides.get_untracked();
+        let current_refresh = refresh.get_untracked();
+        let refresh_changed = current_refresh != saved_refresh.get_untracked();
+        let current_profile = profile.get_untracked();
+        let profile_changed = current_profile != saved_profile.get_untracked();
+        spawn_local(async move {
+            set_is_saving.set(true);
+            let mut result = tauri_api::set_playlist_overrides(&name, &current).await;
+            if result.is_ok() {
+                set_saved.set(current);
+            }
+            if result.is_ok() && profile_changed {
+                result = if current_profile.is_empty() {
+                    tauri_api::delete_playlist_download_profile(&name).await
+                } else {
+                    tauri_api::set_playlist_download_profile(&name, &current_profile).await
+                }
+                .map(|_| ());
+                if result.is_ok() {
+                    set_saved_profile.set(current_profile);
+                }
+            }
+            if result.is_ok() && refresh_changed {
+                let (value, remove_missing) = &current_refresh;
+                let schedule = parse_refresh(value, local_utc_offset_minutes());
+                result =
+                    tauri_api::set_refresh_schedule(&name, schedule, *remove_missing, None).await;
+                if result.is_ok() {
+                    set_saved_refresh.set(current_refresh);
+                }
+            }
+            match result {
+                Ok(()) => {
+                    notifications.success(i18n.tf("playlist_settings.saved", &[("name", &name)]))
+                }
+                Err(e) => {
+                    notifications.error(i18n.tf("playlist_settings.save_failed", &[("error", &e)]))
+                }
+            }
+            set_is_saving.set(false);
+        });
+    };
+
+    view! {
+        <div class="playlist-settings">
+            <div class="settings-field">
+                <label for="playlist-download-quality">{i18n.t("playlist_settings.quality")}</label>
+                <select
+                    id="playlist-download-quality"
+                    class="history-filter-select"
+                    on:change=move |ev| {
+                        let quality = parse_quality(&event_target_value(&ev));
+                        set_overrides.update(|o| o.download_quality = quality);
+                    }
+                    disabled=move || is_saving.get()
+                >
+                    {QUALITY_CHOICES.iter().map(|(value, label)| view! {
+                        <option
+                            value=*value
+                            selected=move || quality_value(overrides.get().download_quality) == *value
+                        >
+                            {i18n.t(label)}
+                        </option>
+                    }).collect_view()}
+                </select>
+                <p class="settings-hint">{i18n.t("playlist_settings.quality.hint")}</p>
+            </div>
+            <div class="settings-field">
+                <label for="playlist-fetch-lyrics">{i18n.t("settings.lyrics")}</label>
+                <select
+                    id="playlist-fetch-lyrics"
+                    class="history-filter-select"
+                    on:change=move |ev| {
+                        let fetch_lyrics = parse_lyrics(&event_target_value(&ev));
+                        set_overrides.update(|o| o.fetch_lyrics = fetch_lyrics);
+                    }
+                    disabled=move || is_saving.get()
+                >
+                    {LYRICS_CHOICES.iter().map(|(value, label)| view! {
+                        <option
+                            value=*value
+                            selected=move || lyrics_value(overrides.get().fetch_lyrics) == *value
+                        >
+                            {i18n.t(label)}
+                        </option>
+                    }).collect_view()}
+                </select>
+            </div>
+            <div class="settings-field">
+                <label for="playlist-profile-bitrate">{i18n.t("track.bitrate")}</label>
+                <select
+                    id="playlist-profile-bitrate"
+                    class="history-filter-select"
+                    on:change=move |ev| {
+                        let bitrate_kbps = parse_bitrate(&event_target_value(&ev));
+                        set_profile.update(|p| p.bitrate_kbps = bitrate_kbps);
+                    }
+                    disabled=move || is_saving.get()
+                >
+                    {BITRATE_CHOICES.iter().map(|value| view! {
+                        <option
+                            value=*value
+                            selected=move || bitrate_value(profile.get().bitrate_kbps) == *value
+                        >
+                            {if value.is_empty() {
+                                i18n.t("playlist_settings.bitrate.default")
+                            } else {
+                                format!("{value} kbps")
+                            }}
+                        </option>
+                    }).collect_view()}
+                </select>
+                <p class="settings-hint">{i18n.t("playlist_settings.bitrate.hint")}</p>
+            </div>
+            <div class="settings-field">
+                <label for="playlist-profile-format">{i18n.t("playlist_settings.format")}</label>
+                <select
+                    id="playlist-profile-format"
+                    class="history-filter-select"
+                    on:change=move |ev| {
+                        let format = parse_format(&event_target_value(&ev));
+                        set_profile.update(|p| p.output_format = format);
+                    }
+                    disabled=move || is_saving.get()
+                >
+                    {FORMAT_CHOICES.iter().map(|(value, label)| view! {
+                        <option
+                            value=*value
+                            selected=move || format_value(profile.get().output_format) == *value
+                        >
+                            {i18n.t(label)}
+                        </option>
+                    }).collect_view()}
+                </select>
+            </div>
+            <div class="settings-field">
+                <label for="playlist-profile-tags">{i18n.t("playlist_settings.tags")}</label>
+                <select
+                    id="playlist-profile-tags"
+                    class="history-filter-select"
+                    on:change=move |ev| {
+                        let policy = parse_tag(&event_target_value(&ev));
+                        set_profile.update(|p| p.tag_policy = policy);
+                    }
+                    disabled=move || is_saving.get()
+                >
+                    {TAG_CHOICES.iter().map(|(value, label)| view! {
+                        <option
+                            value=*value
+                            selected=move || tag_value(profile.get().tag_policy) == *value
+                        >
+                            {i18n.t(label)}
+                        </option>
+                    }).collect_view()}
+                </select>
+            </div>
+            <div class="settings-field">
+                <label for="playlist-profile-filename">{i18n.t("playlist_settings.file_names")}</label>
+                <input
+                    id="playlist-profile-filename"
+                    type="text"
+                    class="settings-input"
+                    placeholder=i18n.t("playlist_settings.file_names.default")
+                    prop:value=move || profile.get().filename_template.unwrap_or_default()
+                    on:change=move |ev| {
+                        let template = event_target_value(&ev).trim().to_string();
+                        set_profile.update(|p| {
+                            p.filename_template = (!template.is_empty()).then_some(template);
+                        });
+                    }
+                    disabled=move || is_saving.get()
+                />
+                <p class="settings-hint">
+                    {i18n.tf("playlist_settings.file_names.hint", &[("example", "{index:02} - {title}")])}
+                </p>
+            </div>
+            {has_source.then(|| view! {
+                <div class="settings-field">
+                    <label for="playlist-refresh-schedule">{i18n.t("playlist_settings.refresh")}</label>
+                    <select
+                        id="playlist-refresh-schedule"
+                        class="history-filter-select"
+                        on:change=move |ev| {
+                            let value = event_target_value(&ev);
+                            set_refresh.update(|r| r.0 = value);
+                        }
+                        disabled=move || is_saving.get()
+                    >
+                        {REFRESH_CHOICES.iter().map(|(value, label)| view! {
+                            <option
+                                value=*value
+                                selected=move || refresh.get().0 == *value
+                            >
+                                {i18n.t(label)}
+                            </option>
+                        }).collect_view()}
+                    </select>
+                    <label class="playlist-settings-checkbox">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || refresh.get().1
+                            on:change=move |ev| {
+                                let checked = event_target_checked(&ev);
+                                set_refresh.update(|r| r.1 = checked);
+                            }
+                            disabled=move || is_saving.get() || refresh.get().0.is_empty()
+                        />
+                        {i18n.t("playlist_settings.refresh.remove_missing")}
+                    </label>
+                    <p class="settings-hint">{i18n.t("playlist_settings.refresh.hint")}</p>
+                </div>
+            })}
+            <div class="playlist-settings-actions">
+                <button
+                    class="btn btn-primary btn-sm"
+                    on:click=on_save
+                    disabled=move || {
+                        is_saving.get()
+                            || (overrides.get() == saved.get()
+                                && profile.get() == saved_profile.get()
+                                && refresh.get() == saved_refresh.get())
+                    }
+                >
+                    {i18n.t("common.save")}
+                </button>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quality_value_round_trip() {
+        for (value, _) in QUALITY_CHOICES {
+            assert_eq!(quality_value(parse_quality(value)), *value);
+        }
+        assert_eq!(parse_quality("lossless"), None);
+    }
+
+    #[test]
+    fn test_refresh_value_round_trip() {
+        for (value, _) in REFRESH_CHOICES {
+            assert_eq!(refresh_value(parse_refresh(value, 60)), *value);
+        }
+        assert_eq!(
+            parse_refresh("nightly", -300),
+            Some(RefreshSchedule::Daily {
+                minute_of_day: NIGHTLY_MINUTE_OF_DAY,
+                utc_offset_minutes: -300,
+            })
+        );
+    }
+
+    #[test]
+    fn test_profile_values_round_trip() {
+        for value in BITRATE_CHOICES {
+            assert_eq!(bitrate_value(parse_bitrate(value)), *value);
+        }
+        for (value, _) in FORMAT_CHOICES {
+            assert_eq!(format_value(parse_format(value)), *value);
+        }
+        for (value, _) in TAG_CHOICES {
+            assert_eq!(tag_value(parse_tag(value)), *value);
+        }
+    }
+
+    #[test]
+    fn test_lyrics_value_round_trip() {
+        for (value, _) in LYRICS_CHOICES {
+            assert_eq!(lyrics_value(parse_lyrics(value)), *value);
+        }
+    }
+}
@@ -0,0 +1,633 @@
+//! Download queue panel for managing queued, running and finished downloads.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::use_notifications;
+use crate::i18n::use_i18n;
+use crate::tauri_api::{self, queue_events};
+use crate::types::{QueueItem, QueueItemId, QueueItemStatus, TaskEvent, TaskEventCategory};
+
+/// An action on a single queue item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueueAction {
+    /// Move a pending item to the front of the queue.
+    MoveToFront,
+    /// Raise a pending item's priority.
+    RaisePriority,
+    /// Lower a pending item's priority.
+    LowerPriority,
+    /// Split the videos of a pending item into one track per chapter.
+    SplitByChapters,
+    /// Keep the videos of a pending item whole.
+    KeepWhole,
+    /// Cancel a pending or downloading item.
+    Cancel,
+    /// Retry a failed or cancelled item.
+    Retry,
+    /// Resume an item interrupted when the application stopped.
+    Resume,
+    /// Remove the item from the queue.
+    Remove,
+}
+
+impl QueueAction {
+    /// Message key of the button label.
+    #[must_use]
+    pub const fn label_key(self) -> &'static str {
+        match self {
+            Self::MoveToFront => "queue.action.move_to_front",
+            Self::RaisePriority => "queue.action.move_up",
+            Self::LowerPriority => "queue.action.move_down",
+            Self::SplitByChapters => "queue.action.split_chapters",
+            Self::KeepWhole => "queue.action.keep_whole",
+            Self::Cancel => "common.cancel",
+            Self::Retry => "toast.retry",
+            Self::Resume => "queue.resume",
+            Self::Remove => "queue.action.remove",
+        }
+    }
+
+    /// Actions available for an item in its current state.
+    #[must_use]
+    pub fn available_for(item: &QueueItem) -> Vec<Self> {
+        match item.status {
+            QueueItemStatus::Pending => {
+                let priority = item.request.priority;
+                let mut actions = vec![Self::MoveToFront];
+                if priority.raised().is_some() {
+                    actions.push(Self::RaisePriority);
+                }
+                if priority.lowered().is_some() {
+                    actions.push(Self::LowerPriority);
+                }
+                actions.push(if item.request.split_by_chapters {
+                    Self::KeepWhole
+                } else {
+                    Self::SplitByChapters
+                });
+                actions.extend([Self::Cancel, Self::Remove]);
+                actions
+            }
+            QueueItemStatus::Downloading => vec![Self::Cancel],
+            QueueItemStatus::Failed(_) | QueueItemStatus::Cancelled => {
+                vec![Self::Retry, Self::Remove]
+            }
+            QueueItemStatus::Interrupted => vec![Self::Resume, Self::Remove],
+            QueueItemStatus::Completed => vec![Self::Remove],
+        }
+    }
+}
+
+/// Queue items grouped for display, each group in queue order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueueSections {
+    /// Items currently downloading.
+    pub downloading: Vec<QueueItem>,
+    /// Items waiting to start, in the order they start: highest priority
+    /// first, then queue order.
+    pub pending: Vec<QueueItem>,
+    /// Completed, failed and cancelled items.
+    pub finished: Vec<QueueItem>,
+}
+
+impl QueueSections {
+    /// Group items by status.
+    #[must_use]
+    pub fn from_items(items: Vec<QueueItem>) -> Self {
+        let mut sections = Self::default();
+        for item in items {
+            match item.status {
+                QueueItemStatus::Downloading => sections.downloading.push(item),
+                QueueItemStatus::Pending => sections.pending.push(item),
+                _ => sections.finished.push(item),
+            }
+        }
+        // Stable, so queue order is kept within each priority
+        sections
+            .pending
+            .sort_by_key(|item| std::cmp::Reverse(item.request.priority));
+        sections
+    }
+}
+
+/// Position a pending item dragged onto `target` moves to among the pending
+/// items of its priority: the target's. `None` if either isn't pending or
+/// their priorities differ, since the order only applies within a priority.
+#[must_use]
+pub fn drop_position(
+    pending: &[QueueItem],
+    dragged: QueueItemId,
+    target: QueueItemId,
+) -> Option<usize> {
+    let priority = pending
+        .iter()
+        .find(|item| item.id == dragged)?
+        .request
+        .priority;
+    pending
+        .iter()
+        .filter(|item| item.request.priority == priority)
+        .position(|item| item.id == target)
+}
+
+/// Drag-and-drop state shared by the rows of reorderable items.
+#[derive(Debug, Clone, Copy)]
+struct QueueDrag {
+    /// Item being dragged.
+    dragged: RwSignal<Option<QueueItemId>>,
+    /// Callback with the dragged item and the item it was dropped on.
+    on_drop: Callback<(QueueItemId, QueueItemId)>,
+}
+
+/// Apply a `queue-item-progress` payload to the matching item.
+///
+/// Returns `false` if no item matched.
+fn apply_progress(items: &mut [QueueItem], payload: &serde_json::Value) -> bool {
+    let Some(item_id) = payload.get("item_id").and_then(serde_json::Value::as_u64) else {
+        return false;
+    };
+    let Some(item) = items.iter_mut().find(|item| item.id == item_id) else {
+        return false;
+    };
+    if let Some(progress) = payload.get("progress").and_then(serde_json::Value::as_f64) {
+        item.progress = progress;
+    }
+    if let Some(video) = payload
+        .get("current_video")
+        .and_then(serde_json::Value::as_str)
+    {
+        item.current_video = Some(video.to_string());
+    }
+    let count = |key: &str| {
+        payload
+            .get(key)
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|n| usize::try_from(n).ok())
+    };
+    if let Some(total) = count("total_videos") {
+        item.total_videos = Some(total);
+    }
+    if let Some(completed) = count("videos_completed") {
+        item.videos_completed = Some(completed);
+    }
+    true
+}
+
+/// A single queue item row.
+#[component]
+fn QueueItemRow(
+    /// The queue item to display.
+    item: QueueItem,
+    /// Callback when an action is chosen.
+    on_action: Callback<(QueueItemId, QueueAction)>,
+    /// Drag-and-drop state, for items that can be reordered.
+    drag: Option<QueueDrag>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let item_id = item.id;
+    let drag = drag.filter(|_| item.status == QueueItemStatus::Pending);
+    let draggable = if drag.is_some() { "true" } else { "false" };
+    let actions = QueueAction::available_for(&item);
+    let downloading = item.status == QueueItemStatus::Downloading;
+    let status_class = match &item.status {
+        QueueItemStatus::Pending => "pending",
+        QueueItemStatus::Downloading => "downloading",
+        QueueItemStatus::Completed => "completed",
+        QueueItemStatus::Failed(_) => "failed",
+        QueueItemStatus::Cancelled => "cancelled",
+        QueueItemStatus::Interrupted => "interrupted",
+    };
+    let progress_value = format!("{:.0}", item.progress_percent());
+    let progress_width = format!("width: {progress_value}%");
+    let progress_text = format!("{progress_value}%");
+    let progress_label = i18n.tf("queue.progress_label", &[("name", item.display_name())]);
+    let status_text = match &item.status {
+        QueueItemStatus::Failed(error) => i18n.tf("queue.status.failed_with", &[("error", error)]),
+        status => i18n.t(status.label_key()),
+    };
+    let detail = match (
+        &item.current_video,
+        item.videos_completed,
+        item.total_videos,
+    ) {
+        (Some(video), Some(done), Some(total)) if downloading => {
+            Some(format!("{video} ({done}/{total})"))
+        }
+        (Some(video), _, _) if downloading => Some(video.clone()),
+        _ => None,
+    };
+
+    view! {
+        <li
+            class=format!("queue-item {status_class}")
+            class:draggable=drag.is_some()
+            class:dragging=move || drag.is_some_and(|d| d.dragged.get() == Some(item_id))
+            draggable=draggable
+            on:dragstart=move |_| {
+                if let Some(d) = drag {
+                    d.dragged.set(Some(item_id));
+                }
+            }
+            on:dragend=move |_| {
+                if let Some(d) = drag {
+                    d.dragged.set(None);
+                }
+            }
+            on:dragover=move |ev| {
+                // Preventing the default marks the row as a drop target.
+                if drag.is_some_and(|d| d.dragged.with_untracked(Option::is_some)) {
+                    ev.prevent_default();
+                }
+            }
+            on:drop=move |ev| {
+                let Some(d) = drag else {
+                    return;
+                };
+                ev.prevent_default();
+                if let Some(dragged) = d.dragged.get_untracked() {
+                    d.dragged.set(None);
+                    if dragged != item_id {
+                        d.on_drop.run((dragged, item_id));
+                    }
+                }
+            }
+        >
+            <div class="queue-item-header">
+                <span class="queue-item-name" title=item.request.url.clone()>
+                    {item.display_name().to_string()}
+                </span>
+                {item.request.split_by_chapters.then(|| view! {
+                    <span class="queue-item-chapters" title=i18n.t("queue.chapters.title")>
+                        {i18n.t("queue.chapters")}
+                    </span>
+                })}
+                {item.request.dry_run.then(|| view! {
+                    <span class="queue-item-dry-run" title=i18n.t("queue.dry_run.title")>
+                        {i18n.t("queue.dry_run")}
+                    </span>
+                })}
+                <span class="queue-item-priority">{i18n.t(item.request.priority.label_key())}</span>
+                <span class="queue-item-status">{status_text}</span>
+            </div>
+            {detail.map(|detail| view! { <div class="queue-item-detail">{detail}</div> })}
+            {downloading.then(|| view! {
+                <div class="download-progress-bar-container">
+                    <div
+                        class="download-progress-bar"
+                        role="progressbar"
+                        aria-label=progress_label
+                        aria-valuemin="0"
+                        aria-valuemax="100"
+                        aria-valuenow=progress_value
+                    >
+                        <div class="download-progress-fill" style=progress_width></div>
+                    </div>
+                    <span class="download-progress-percent">{progress_text}</span>
+                </div>
+            })}
+            <div class="queue-item-actions">
+                {actions.into_iter().map(|action| view! {
+                    <button
+                        class="btn btn-ghost btn-sm"
+                        on:click=move |_| on_action.run((item_id, action))
+                    >
+                        {i18n.t(action.label_key())}
+                    </button>
+                }).collect_view()}
+            </div>
+        </li>
+    }
+}
+
+/// A titled group of queue items, hidden when empty.
+#[component]
+fn QueueSection(
+    /// Message key of the section title.
+    title: &'static str,
+    /// Items in the section.
+    items: Vec<QueueItem>,
+    /// Callback when an action is chosen.
+    on_action: Callback<(QueueItemId, QueueAction)>,
+    /// Drag-and-drop state, if the items can be reordered.
+    #[prop(optional)]
+    drag: Option<QueueDrag>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    (!items.is_empty()).then(|| {
+        view! {
+            <section class="queue-section">
+                <h4 class="queue-section-title">
+                    {i18n.tf("queue.section", &[("title", &i18n.t(title)), ("count", &items.len().to_string())])}
+                </h4>
+                <ul class="queue-item-list">
+                    {items.into_iter().map(|item| view! {
+                        <QueueItemRow item=item on_action=on_action drag=drag />
+                    }).collect_view()}
+                </ul>
+            </section>
+        }
+    })
+}
+
+/// Download queue panel.
+///
+/// Lists downloading, pending and finished queue items and offers:
+/// - Pause/resume for the whole queue
+/// - Reordering pending items (drag and drop within a priority, move to
+///   front, move up/down by priority)
+/// - Splitting the videos of pending items by chapters
+/// - Cancel, retry and remove actions per item
+/// - Clearing finished items
+///
+/// Updates live from queue events. Hidden while the queue is empty.
+#[component]
+
+pub fn QueuePanel() -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+
+    let (items, set_items) = signal::<Vec<QueueItem>>(vec![]);
+    let (paused, set_paused) = signal(false);
+
+    let load = move || {
+        spawn_local(async move {
+            match tauri_api::queue_get_all_items().await {
+                Ok(list) => set_items.set(list),
+                Err(e) => leptos::logging::error!("Failed to load download queue: {}", e),
+            }
+            if let Ok(is_paused) = tauri_api::queue_is_paused().await {
+                set_paused.set(is_paused);
+            }
+        });
+    };
+
+    Effect::new(move || {
+        load();
+        spawn_local(async move {
+            if let Err(e) = tauri_api::listen_to_task_events(move |event: TaskEvent| {
+                if event.category != TaskEventCategory::Queue {
+                    return;
+                }
+                match event.event.as_str() {
+                    queue_events::QUEUE_ITEM_PROGRESS => {
+                        let mut updated = false;
+                        set_items.update(|list| updated = apply_progress(list, &event.payload));
+                        if !updated {
+                            load();
+                        }
+                    }
+                    queue_events::QUEUE_PAUSED => set_paused.set(true),
+                    queue_events::QUEUE_RESUMED => set_paused.set(false),
+                    _ => load(),
+                }
+            })
+            .await
+            {
+                leptos::logging::error!("Failed to listen for queue events: {}", e);
+            }
+        });
+    });
+
+    let on_action = Callback::new(move |(item_id, action): (QueueItemId, QueueAction)| {
+        let priority = items
+            .get_untracked()
+            .iter()
+            .find(|item| item.id == item_id)
+            .map(|item| item.request.priority);
+        spawn_local(async move {
+            let result = match action {
+                QueueAction::MoveToFront => tauri_api::queue_move_to_front(item_id).await,
+                QueueAction::RaisePriority => match priority.and_then(|p| p.raised()) {
+                    Some(p) => tauri_api::queue_set_priority(item_id, p).await,
+                    None => Ok(false),
+                },
+                QueueAction::LowerPriority => match priority.and_then(|p| p.lowered()) {
+                    Some(p) => tauri_api::queue_set_priority(item_id, p).await,
+                    None => Ok(false),
+                },
+                QueueAction::SplitByChapters => {
+                    tauri_api::queue_set_split_by_chapters(item_id, true).await
+                }
+                QueueAction::KeepWhole => {
+                    tauri_api::queue_set_split_by_chapters(item_id, false).await
+                }
+                QueueAction::Cancel => tauri_api::queue_cancel_item(item_id).await,
+                QueueAction::Retry | QueueAction::Resume => {
+                    tauri_api::queue_retry_item(item_id).await
+                }
+                QueueAction::Remove => tauri_api::queue_remove_item(item_id).await,
+            };
+            if let Err(e) = result {
+                leptos::logging::error!("Queue action {:?} failed: {}", action, e);
+                notifications.error(i18n.tf("queue.update_failed", &[("error", &e)]));
+            }
+            // Priority changes and reordering emit no event
+            load();
+        });
+    });
+
+    // Dropping a pending item on another of the same priority takes its place
+    let dragged = RwSignal::new(None::<QueueItemId>);
+    let on_drop = Callback::new(move |(item_id, target): (QueueItemId, QueueItemId)| {
+        let pending = QueueSections::from_items(items.get_untracked()).pending;
+        let Some(position) = drop_position(&pending, item_id, target) else {
+            notifications.warning(i18n.t("queue.reorder_within_priority"));
+            return;
+        };
+        spawn_local(async move {
+            if let Err(e) = tauri_api::queue_move_item(item_id, position).await {
+                leptos::logging::error!("Failed to move queue item {}: {}", item_id, e);
+                notifications.error(i18n.tf("queue.update_failed", &[("error", &e)]));
+            }
+            load();
+        });
+    });
+    let drag = QueueDrag { dragged, on_drop };
+
+    let toggle_pause = move |_| {
+        spawn_local(async move {
+            let result = if paused.get_untracked() {
+                tauri_api::queue_resume().await
+            } else {
+                tauri_api::queue_pause().await
+            };
+            if let Err(e) = result {
+                notifications.error(i18n.tf("queue.update_failed", &[("error", &e)]));
+            }
+            load();
+        });
+    };
+
+    let clear_finished = move |_| {
+        spawn_local(async move {
+            match tauri_api::queue_clear_finished().await {
+                Ok(_) => load(),
+                Err(e) => notifications.error(i18n.tf("queue.clear_failed", &[("error", &e)])),
+            }
+        });
+    };
+
+    move || {
+        let list = items.get();
+        if list.is_empty() {
+            return None;
+        }
+        let sections = QueueSections::from_items(list);
+        let has_finished = !sections.finished.is_empty();
+        Some(view! {
+            <div class="queue-panel" class:paused=move || paused.get()>
+                <div class="queue-panel-header">
+                    <h3>{move || i18n.t("queue.title")}</h3>
+                    {move || paused.get().then(|| view! {
+                        <span class="queue-paused-badge">{i18n.t("queue.paused")}</span>
+                    })}
+                    <div class="queue-panel-actions">
+                        <button class="btn btn-secondary btn-sm" on:click=toggle_pause>
+                            {move || i18n.t(if paused.get() { "queue.resume" } else { "queue.pause" })}
+                        </button>
+                        <button
+                            class="btn btn-ghost btn-sm"
+                            on:click=clear_finished
+                            disabled=!has_finished
+                        >
+                            {move || i18n.t("queue.clear_finished")}
+                        </button>
+                    </div>
+                </div>
+                <QueueSection title="queue.section.downloading" items=sections.downloading on_action=on_action />
+                <QueueSection title="queue.section.up_next" items=sections.pending on_action=on_action drag=drag />
+                <QueueSection title="queue.section.finished" items=sections.finished on_action=on_action />
+            </div>
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DownloadPriority, DownloadRequest};
+
+    fn item(id: QueueItemId, status: QueueItemStatus, priority: DownloadPriority) -> QueueItem {
+        QueueItem {
+            id,
+            request: DownloadRequest {
+                url: format!("https://www.youtube.com/playlist?list=PL{id}"),
+                output_dir: "/music".to_string(),
+                playlist_name: None,
+                audio_quality: None,
+                embed_thumbnail: None,
+                priority,
+                max_speed_bps: None,
+                split_by_chapters: false,
+                dry_run: false,
+            },
+            status,
+            task_id: None,
+            retry_count: 0,
+            added_at: 0,
+            started_at: None,
+            finished_at: None,
+            progress: 0.0,
+            current_video: None,
+            total_videos: None,
+            videos_completed: None,
+        }
+    }
+
+    #[test]
+    fn test_available_actions() {
+        use QueueAction::{
+            Cancel, KeepWhole, LowerPriority, MoveToFront, RaisePriority, Remove, Resume, Retry,
+            SplitByChapters,
+        };
+
+        let mut pending = item(1, QueueItemStatus::Pending, DownloadPriority::Normal);
+        assert_eq!(
+            QueueAction::available_for(&pending),
+            vec![
+                MoveToFront,
+                RaisePriority,
+                LowerPriority,
+                SplitByChapters,
+                Cancel,
+                Remove
+            ]
+        );
+        pending.request.split_by_chapters = true;
+        assert!(QueueAction::available_for(&pending).contains(&KeepWhole));
+        let high = item(1, QueueItemStatus::Pending, DownloadPriority::High);
+        assert!(!QueueAction::available_for(&high).contains(&RaisePriority));
+
+        let downloading = item(2, QueueItemStatus::Downloading, DownloadPriority::Normal);
+        assert_eq!(QueueAction::available_for(&downloading), vec![Cancel]);
+
+        let failed = item(
+            3,
+            QueueItemStatus::Failed("boom".to_string()),
+            DownloadPriority::Low,
+        );
+        assert_eq!(QueueAction::available_for(&failed), vec![Retry, Remove]);
+
+        let interrupted = item(4, QueueItemStatus::Interrupted, DownloadPriority::Normal);
+        assert_eq!(
+            QueueAction::available_for(&interrupted),
+            vec![Resume, Remove]
+        );
+    }
+
+    #[test]
+    fn test_sections_keep_queue_order() {
+        let sections = QueueSections::from_items(vec![
+            item(1, QueueItemStatus::Completed, DownloadPriority::Normal),
+            item(2, QueueItemStatus::Pending, DownloadPriority::High),
+            item(3, QueueItemStatus::Downloading, DownloadPriority::Normal),
+            item(4, QueueItemStatus::Pending, DownloadPriority::Normal),
+            item(5, QueueItemStatus::Cancelled, DownloadPriority::Normal),
+            item(6, QueueItemStatus::Pending, DownloadPriority::High),
+        ]);
+        let ids = |items: &[QueueItem]| items.iter().map(|i| i.id).collect::<Vec<_>>();
+        assert_eq!(ids(&sections.downloading), vec![3]);
+        assert_eq!(ids(&sections.pending), vec![2, 6, 4]);
+        assert_eq!(ids(&sections.finished), vec![1, 5]);
+    }
+
+    #[test]
+    fn test_drop_position() {
+        let pending = vec![
+            item(1, QueueItemStatus::Pending, DownloadPriority::High),
+            item(2, QueueItemStatus::Pending, DownloadPriority::Normal),
+            item(3, QueueItemStatus::Pending, DownloadPriority::Normal),
+            item(4, QueueItemStatus::Pending, DownloadPriority::Normal),
+        ];
+        assert_eq!(drop_position(&pending, 4, 2), Some(0));
+        assert_eq!(drop_position(&pending, 2, 4), Some(2));
+        assert_eq!(drop_position(&pending, 2, 1), None);
+        assert_eq!(drop_position(&pending, 9, 2), None);
+    }
+
+    #[test]
+    fn test_apply_progress() {
+        let mut items = vec![item(
+            7,
+            QueueItemStatus::Downloading,
+            DownloadPriority::Normal,
+        )];
+        let payload = serde_json::json!({
+            "item_id": 7,
+            "progress": 0.5,
+            "current_video": "Song",
+            "total_videos": 10,
+            "videos_completed": 5,
+        });
+        assert!(apply_progress(&mut items, &payload));
+        assert!((items[0].progress - 0.5).abs() < f64::EPSILON);
+        assert_eq!(items[0].current_video.as_deref(), Some("Song"));
+        assert_eq!(items[0].videos_completed, Some(5));
+
+        assert!(!apply_progress(
+            &mut items,
+            &serde_json::json!({ "item_id": 8 })
+        ));
+    }
+}
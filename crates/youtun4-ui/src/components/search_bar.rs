@@ -0,0 +1,343 @@
+//! Library search palette, opened from the sidebar or with Ctrl/Cmd+K or
+//! Ctrl/Cmd+F.
+
+use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::use_dialog_keyboard;
+use crate::i18n::use_i18n;
+use crate::tauri_api;
+use crate::types::LibrarySearchResults;
+
+/// Maximum number of results shown per group.
+const RESULT_LIMIT: usize = 8;
+
+/// A result that can be picked in the palette.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SearchItem {
+    /// A matching playlist.
+    Playlist {
+        /// Playlist name.
+        name: String,
+        /// Number of tracks in the playlist.
+        track_count: usize,
+    },
+    /// A matching track, which opens its playlist.
+    Track {
+        /// Playlist containing the track.
+        playlist: String,
+        /// Track title, or its file name if no title was recorded.
+        label: String,
+        /// Channel recorded at download time, if any.
+        channel: Option<String>,
+    },
+}
+
+impl SearchItem {
+    /// Flatten search results into the palette's order: playlists first,
+    /// then tracks.
+    #[must_use]
+    pub fn from_results(results: LibrarySearchResults) -> Vec<Self> {
+        let playlists = results.playlists.into_iter().map(|p| Self::Playlist {
+            name: p.name,
+            track_count: p.track_count,
+        });
+        let tracks = results.tracks.into_iter().map(|t| Self::Track {
+            playlist: t.playlist,
+            label: t.title.unwrap_or(t.file_name),
+            channel: t.channel,
+        });
+        playlists.chain(tracks).collect()
+    }
+
+    /// Playlist opened when this result is picked.
+    #[must_use]
+    pub fn playlist(&self) -> &str {
+        match self {
+            Self::Playlist { name, .. } => name,
+            Self::Track { playlist, .. } => playlist,
+        }
+    }
+}
+
+/// Whether a key press is a shortcut that opens the palette.
+#[must_use]
+pub fn is_palette_shortcut(key: &str, ctrl: bool, meta: bool) -> bool {
+    (ctrl || meta) && (key.eq_ignore_ascii_case("k") || key.eq_ignore_ascii_case("f"))
+}
+
+/// Index of the next (or previous) result, wrapping around the ends.
+#[must_use]
+pub const fn step_selection(current: usize, len: usize, forward: bool) -> usize {
+    if len == 0 {
+        0
+    } else if forward {
+        (current + 1) % len
+    } else if current == 0 {
+        len - 1
+    } else {
+        current - 1
+    }
+}
+
+/// Search field in the sidebar that opens a palette of matching playlists
+/// and tracks.
+///
+/// Arrow keys move through the results, Enter opens the highlighted one and
+/// Escape closes the palette.
+#[component]
+
+pub fn SearchBar(
+    /// Callback with the name of the playlist to open.
+    on_select: Callback<String>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let input_ref = NodeRef::<html::Input>::new();
+    let dialog_ref = NodeRef::<html::Div>::new();
+    let (is_open, set_is_open) = signal(false);
+    let (query, set_query) = signal(String::new());
+    let (items, set_items) = signal::<Vec<SearchItem>>(vec![]);
+    let (active, set_active) = signal(0_usize);
+    let (error, set_error) = signal::<Option<String>>(None);
+
+    let open = move || {
+        set_is_open.set(true);
+        request_animation_frame(move || {
+            if let Some(input) = input_ref.get_untracked() {
+                let _ = input.focus();
+                input.select();
+            }
+        });
+    };
+
+    let close = move || set_is_open.set(false);
+    use_dialog_keyboard(is_open.into(), dialog_ref, Callback::new(move |()| close()));
+
+    let handle = window_event_listener(ev::keydown, move |e| {
+        if is_palette_shortcut(&e.key(), e.ctrl_key(), e.meta_key()) {
+            e.prevent_default();
+            if is_open.get_untracked() {
+                close();
+            } else {
+                open();
+            }
+        }
+    });
+    on_cleanup(move || handle.remove());
+
+    let search = move |text: String| {
+        set_query.set(text.clone());
+        set_active.set(0);
+        if text.trim().is_empty() {
+            set_items.set(vec![]);
+            set_error.set(None);
+            return;
+        }
+        spawn_local(async move {
+            let result = tauri_api::search_library(&text, RESULT_LIMIT).await;
+            // Drop results for a query that has since changed
+            if query.get_untracked() != text {
+                return;
+            }
+            match result {
+                Ok(results) => {
+                    set_error.set(None);
+                    set_items.set(SearchItem::from_results(results));
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    };
+
+    let choose = move |index: usize| {
+        let Some(item) = items.with_untracked(|items| items.get(index).cloned()) else {
+            return;
+        };
+        close();
+        on_select.run(item.playlist().to_string());
+    };
+
+    let on_keydown = move |e: web_sys::KeyboardEvent| match e.key().as_str() {
+        "ArrowDown" | "ArrowUp" => {
+            e.prevent_default();
+            let len = items.with_untracked(Vec::len);
+            set_active.update(|i| *i = step_selection(*i, len, e.key() == "ArrowDown"));
+        }
+        "Enter" => {
+            e.prevent_default();
+            choose(active.get_untracked());
+        }
+        _ => {}
+    };
+
+    let result_view = move |index: usize, item: SearchItem| {
+        let (label, detail) = match item {
+            SearchItem::Playlist { name, track_count } => {
+                (name, i18n.count("count.tracks", track_count))
+            }
+            SearchItem::Track {
+                playlist,
+                label,
+                channel,
+            } => (
+                label,
+                channel.map_or_else(|| playlist.clone(), |c| format!("{c} · {playlist}")),
+            ),
+        };
+        view! {
+            <li
+                class="search-result"
+                class:active=move || active.get() == index
+                role="option"
+                aria-selected=move || (active.get() == index).to_string()
+                on:mousemove=move |_| set_active.set(index)
+                on:click=move |_| choose(index)
+            >
+                <span class="search-result-label">{label}</span>
+                <span class="search-result-detail">{detail}</span>
+            </li>
+        }
+    };
+
+    let results = move || {
+        if let Some(e) = error.get() {
+            return view! { <p class="search-palette-message">{e}</p> }.into_any();
+        }
+        if query.with(|q| q.trim().is_empty()) {
+            return view! {
+                <p class="search-palette-message">{i18n.t("search.hint")}</p>
+            }
+            .into_any();
+        }
+        let all = items.get();
+        if all.is_empty() {
+            return view! { <p class="search-palette-message">{i18n.t("search.no_matches")}</p> }
+                .into_any();
+        }
+        let (playlists, tracks): (Vec<_>, Vec<_>) = all
+            .into_iter()
+            .enumerate()
+            .partition(|(_, item)| matches!(item, SearchItem::Playlist { .. }));
+        view! {
+            {(!playlists.is_empty()).then(|| view! {
+                <h3 class="search-group-title">{i18n.t("playlists.title")}</h3>
+                <ul class="search-results" role="listbox">
+                    {playlists.into_iter().map(|(i, item)| result_view(i, item)).collect_view()}
+                </ul>
+            })}
+            {(!tracks.is_empty()).then(|| view! {
+                <h3 class="search-group-title">{i18n.t("playlist_detail.tracks")}</h3>
+                <ul class="search-results" role="listbox">
+                    {tracks.into_iter().map(|(i, item)| result_view(i, item)).collect_view()}
+                </ul>
+            })}
+        }
+        .into_any()
+    };
+
+    view! {
+        <button class="search-bar-trigger" on:click=move |_| open() aria-label=move || i18n.t("search.label")>
+            <svg viewBox="0 0 24 24" width="18" height="18" fill="currentColor">
+                <path d="M15.5 14h-.79l-.28-.27C15.41 12.59 16 11.11 16 9.5 16 5.91 13.09 3 9.5 3S3 5.91 3 9.5 5.91 16 9.5 16c1.61 0 3.09-.59 4.23-1.57l.27.28v.79l5 4.99L20.49 19l-4.99-5zm-6 0C7.01 14 5 11.99 5 9.5S7.01 5 9.5 5 14 7.01 14 9.5 11.99 14 9.5 14z"/>
+            </svg>
+            <span>{move || i18n.t("create_playlist.search.button")}</span>
+            <kbd>"Ctrl K"</kbd>
+        </button>
+
+        <div
+            class="search-palette-overlay"
+            class:visible=move || is_open.get()
+            on:click=move |_| close()
+        >
+            <div
+                class="search-palette"
+                node_ref=dialog_ref
+                on:click=move |e| e.stop_propagation()
+                role="dialog"
+                aria-modal="true"
+                aria-label=move || i18n.t("search.label")
+            >
+                <input
+                    node_ref=input_ref
+                    class="search-palette-input"
+                    type="search"
+                    placeholder=move || i18n.t("search.placeholder")
+                    prop:value=move || query.get()
+                    on:input=move |ev| search(event_target_value(&ev))
+                    on:keydown=on_keydown
+                />
+                <div class="search-palette-results">{results}</div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PlaylistMetadata, TrackSearchMatch};
+
+    #[test]
+    fn test_search_items_from_results() {
+        let results = LibrarySearchResults {
+            playlists: vec![PlaylistMetadata {
+                name: "Road Trip".to_string(),
+                source_url: None,
+                created_at: 0,
+                modified_at: 0,
+                track_count: 12,
+                total_bytes: 0,
+                thumbnail_url: None,
+                tags: vec![],
+            }],
+            tracks: vec![
+                TrackSearchMatch {
+                    playlist: "Chill".to_string(),
+                    file_name: "track01.mp3".to_string(),
+                    title: Some("Ocean Waves".to_string()),
+                    channel: None,
+                },
+                TrackSearchMatch {
+                    playlist: "Road Trip".to_string(),
+                    file_name: "drive.mp3".to_string(),
+                    title: None,
+                    channel: Some("Band".to_string()),
+                },
+            ],
+        };
+
+        let items = SearchItem::from_results(results);
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0].playlist(), "Road Trip");
+        assert_eq!(
+            items[1],
+            SearchItem::Track {
+                playlist: "Chill".to_string(),
+                label: "Ocean Waves".to_string(),
+                channel: None,
+            }
+        );
+        // Tracks without a title show their file name
+        assert!(matches!(&items[2], SearchItem::Track { label, .. } if label == "drive.mp3"));
+    }
+
+    #[test]
+    fn test_is_palette_shortcut() {
+        assert!(is_palette_shortcut("k", true, false));
+        assert!(is_palette_shortcut("K", false, true));
+        assert!(is_palette_shortcut("f", true, false));
+        assert!(!is_palette_shortcut("k", false, false));
+        assert!(!is_palette_shortcut("j", true, false));
+    }
+
+    #[test]
+    fn test_step_selection_wraps() {
+        assert_eq!(step_selection(0, 3, true), 1);
+        assert_eq!(step_selection(2, 3, true), 0);
+        assert_eq!(step_selection(0, 3, false), 2);
+        assert_eq!(step_selection(2, 3, false), 1);
+        assert_eq!(step_selection(0, 0, true), 0);
+    }
+}
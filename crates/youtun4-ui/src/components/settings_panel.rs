@@ -1,12 +1,206 @@
 //! Settings panel component for configuring application settings.
 
+use leptos::html;
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
+use crate::components::{
+    LogViewer, OperationProgressDialog, RunningOperation, use_dialog_keyboard,
+};
+use crate::i18n::{I18n, use_i18n};
 use crate::tauri_api;
-use crate::types::{AppConfig, DownloadQuality, NotificationPreferences, Theme};
+use crate::theme::colors;
+use crate::types::{
+    AppConfig, CacheConfig, DownloadQuality, ExtractionBackend, Locale, MaintenanceOperation,
+    Theme, UpdateChannel, WebhookEvent,
+};
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+const BYTES_PER_GB: u64 = 1024 * BYTES_PER_MB;
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Secrets store key of the webhook URL (mirrors the core's
+/// `secret_keys::WEBHOOK_URL`).
+const WEBHOOK_URL_SECRET: &str = "webhook_url";
+
+/// Most downloads the queue runs at once.
+const MAX_CONCURRENT_DOWNLOADS: usize = 4;
+
+/// Highest number of videos of a playlist downloaded at once (mirrors the
+/// core's `MAX_DOWNLOAD_CONCURRENCY`).
+const MAX_DOWNLOAD_CONCURRENCY: usize = 8;
+
+/// Download speed limits offered in the settings, in bytes per second.
+const DOWNLOAD_SPEED_LIMITS: [(u64, &str); 6] = [
+    (256 * 1024, "256 KB/s"),
+    (512 * 1024, "512 KB/s"),
+    (BYTES_PER_MB, "1 MB/s"),
+    (2 * BYTES_PER_MB, "2 MB/s"),
+    (5 * BYTES_PER_MB, "5 MB/s"),
+    (10 * BYTES_PER_MB, "10 MB/s"),
+];
+
+/// A section of the settings panel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SettingsSection {
+    /// Download quality, queue and offline mode.
+    Downloads,
+    /// Preferred device and device detection.
+    Devices,
+    /// Playlists directory and library limits.
+    Storage,
+    /// Metadata and thumbnail cache.
+    Cache,
+    /// Which notifications are shown.
+    Notifications,
+    /// Theme and language.
+    Appearance,
+    /// Background operation, power, updates and logs.
+    Advanced,
+}
+
+impl SettingsSection {
+    /// Every section, in navigation order.
+    pub const ALL: [Self; 7] = [
+        Self::Downloads,
+        Self::Devices,
+        Self::Storage,
+        Self::Cache,
+        Self::Notifications,
+        Self::Appearance,
+        Self::Advanced,
+    ];
+
+    /// Message key of the label shown in the section navigation.
+    #[must_use]
+    pub const fn title_key(self) -> &'static str {
+        match self {
+            Self::Downloads => "settings.section.downloads",
+            Self::Devices => "settings.section.devices",
+            Self::Storage => "settings.section.storage",
+            Self::Cache => "settings.section.cache",
+            Self::Notifications => "settings.section.notifications",
+            Self::Appearance => "settings.section.appearance",
+            Self::Advanced => "settings.section.advanced",
+        }
+    }
+
+    /// SVG path of the section icon.
+    const fn icon(self) -> &'static str {
+        match self {
+            Self::Downloads => "M19 9h-4V3H9v6H5l7 7 7-7zM5 18v2h14v-2H5z",
+            Self::Devices => {
+                "M15 7v4h1v2h-3V5h2l-3-4-3 4h2v8H8v-2.07c.7-.37 1.2-1.08 1.2-1.93 0-1.21-.99-2.2-2.2-2.2-1.21 0-2.2.99-2.2 2.2 0 .85.5 1.56 1.2 1.93V13c0 1.11.89 2 2 2h3v3.05c-.71.37-1.2 1.1-1.2 1.95 0 1.22.99 2.2 2.2 2.2 1.21 0 2.2-.98 2.2-2.2 0-.85-.49-1.58-1.2-1.95V15h3c1.11 0 2-.89 2-2v-2h1V7h-4z"
+            }
+            Self::Storage => {
+                "M20 6h-8l-2-2H4c-1.1 0-1.99.9-1.99 2L2 18c0 1.1.9 2 2 2h16c1.1 0 2-.9 2-2V8c0-1.1-.9-2-2-2z"
+            }
+            Self::Cache => {
+                "M15 9H9v6h6V9zm-2 4h-2v-2h2v2zm8-2V9h-2V7c0-1.1-.9-2-2-2h-2V3h-2v2h-2V3H9v2H7c-1.1 0-2 .9-2 2v2H3v2h2v2H3v2h2v2c0 1.1.9 2 2 2h2v2h2v-2h2v2h2v-2h2c1.1 0 2-.9 2-2v-2h2v-2h-2v-2h2zm-4 6H7V7h10v10z"
+            }
+            Self::Notifications => {
+                "M12 22c1.1 0 2-.9 2-2h-4c0 1.1.89 2 2 2zm6-6v-5c0-3.07-1.64-5.64-4.5-6.32V4c0-.83-.67-1.5-1.5-1.5s-1.5.67-1.5 1.5v.68C7.63 5.36 6 7.92 6 11v5l-2 2v1h16v-1l-2-2z"
+            }
+            Self::Appearance => {
+                "M12 3c-4.97 0-9 4.03-9 9s4.03 9 9 9c.83 0 1.5-.67 1.5-1.5 0-.39-.15-.74-.39-1.01-.23-.26-.38-.61-.38-.99 0-.83.67-1.5 1.5-1.5H16c2.76 0 5-2.24 5-5 0-4.42-4.03-8-9-8zm-5.5 9c-.83 0-1.5-.67-1.5-1.5S5.67 9 6.5 9 8 9.67 8 10.5 7.33 12 6.5 12zm3-4C8.67 8 8 7.33 8 6.5S8.67 5 9.5 5s1.5.67 1.5 1.5S10.33 8 9.5 8zm5 0c-.83 0-1.5-.67-1.5-1.5S13.67 5 14.5 5s1.5.67 1.5 1.5S15.33 8 14.5 8zm3 4c-.83 0-1.5-.67-1.5-1.5S16.67 9 17.5 9s1.5.67 1.5 1.5-.67 1.5-1.5 1.5z"
+            }
+            Self::Advanced => {
+                "M3 17v2h6v-2H3zM3 5v2h10V5H3zm10 16v-2h8v-2h-8v-2h-2v6h2zM7 9v2H3v2h4v2h2V9H7zm14 4v-2H11v2h10zm-6-4h2V7h4V5h-4V3h-2v6z"
+            }
+        }
+    }
+}
+
+/// Format a byte count as a number of gigabytes for an input field.
+#[must_use]
+pub fn bytes_to_gigabytes(bytes: u64) -> String {
+    let gb = bytes as f64 / BYTES_PER_GB as f64;
+    let text = format!("{gb:.2}");
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Parse a number of gigabytes typed by the user into bytes.
+///
+/// Returns `None` for blank, negative or malformed input.
+#[must_use]
+pub fn parse_gigabytes(input: &str) -> Option<u64> {
+    let gb: f64 = input.trim().parse().ok()?;
+    (gb.is_finite() && gb >= 0.0).then(|| (gb * BYTES_PER_GB as f64).round() as u64)
+}
+
+/// Parse a whole number within `min..=max` typed by the user.
+#[must_use]
+pub fn parse_bounded(input: &str, min: u64, max: u64) -> Option<u64> {
+    input
+        .trim()
+        .parse()
+        .ok()
+        .filter(|value| (min..=max).contains(value))
+}
+
+/// The settings this panel edits reset to their defaults, with everything
+/// else (profiles, device settings, playlist overrides) kept from `current`.
+#[must_use]
+pub fn reset_settings(current: &AppConfig, default_dir: &str) -> AppConfig {
+    AppConfig {
+        playlists_directory: default_dir.to_string(),
+        language: current.language,
+        playlist_view: current.playlist_view,
+        ui_state: current.ui_state.clone(),
+        crash_report_endpoint: current.crash_report_endpoint.clone(),
+        simple_mode: current.simple_mode,
+        device_profiles: current.device_profiles.clone(),
+        playlist_overrides: current.playlist_overrides.clone(),
+        profiles: current.profiles.clone(),
+        active_profile: current.active_profile.clone(),
+        onboarding_completed: current.onboarding_completed,
+        cache: CacheConfig {
+            custom_cache_dir: current.cache.custom_cache_dir.clone(),
+            ..CacheConfig::default()
+        },
+        ..AppConfig::default()
+    }
+}
+
+/// A labelled on/off switch.
+#[component]
+fn SettingsToggle(
+    /// Message key of the setting name.
+    title: &'static str,
+    /// Message key of what the setting does.
+    description: &'static str,
+    /// Whether the setting is on.
+    #[prop(into)]
+    checked: Signal<bool>,
+    /// Callback with the new value.
+    on_change: Callback<bool>,
+    /// Whether the switch is disabled.
+    #[prop(into)]
+    disabled: Signal<bool>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    view! {
+        <label class="settings-toggle-option">
+            <span class="settings-toggle-label">
+                <span class="settings-toggle-title">{move || i18n.t(title)}</span>
+                <span class="settings-toggle-description">{move || i18n.t(description)}</span>
+            </span>
+            <input
+                type="checkbox"
+                class="settings-toggle"
+                checked=move || checked.get()
+                on:change=move |ev| on_change.run(event_target_checked(&ev))
+                disabled=move || disabled.get()
+            />
+        </label>
+    }
+}
 
 /// Settings panel component for configuring application preferences.
+///
+/// Settings are grouped into sections (Downloads, Devices, Storage, Cache,
+/// Notifications, Appearance, Advanced) picked from the navigation bar, and
+/// are saved together.
 #[component]
 
 pub fn SettingsPanel(
@@ -15,22 +209,28 @@ pub fn SettingsPanel(
     /// Callback to close the settings panel.
     on_close: Callback<()>,
 ) -> impl IntoView {
-    // Local state for settings
-    let (storage_dir, set_storage_dir) = signal::<String>(String::new());
+    let i18n = use_i18n();
+    let dialog_ref = NodeRef::<html::Div>::new();
+
+    // Maintenance operation in progress; its dialog handles the keyboard
+    let (running_operation, set_running_operation) = signal::<Option<RunningOperation>>(None);
+    use_dialog_keyboard(
+        Signal::derive(move || is_open.get() && running_operation.with(Option::is_none)),
+        dialog_ref,
+        on_close,
+    );
+
+    // The configuration being edited; settings not shown here are carried
+    // through on save
+    let draft = RwSignal::new(AppConfig::default());
     let (default_dir, set_default_dir) = signal::<String>(String::new());
-    let (download_quality, set_download_quality) =
-        signal::<DownloadQuality>(DownloadQuality::Medium);
-    let (theme, set_theme) = signal::<Theme>(Theme::Dark);
-    let (notif_download, set_notif_download) = signal(true);
-    let (notif_sync, set_notif_sync) = signal(true);
-    let (notif_errors, set_notif_errors) = signal(true);
-    let (notif_device, set_notif_device) = signal(true);
 
     // UI state
     let (is_loading, set_is_loading) = signal(false);
     let (error_message, set_error_message) = signal::<Option<String>>(None);
     let (success_message, set_success_message) = signal::<Option<String>>(None);
-    let (active_tab, set_active_tab) = signal::<&'static str>("storage");
+    let (active_section, set_active_section) = signal(SettingsSection::Downloads);
+    let disabled = Signal::derive(move || is_loading.get());
 
     // Load current settings when panel opens
     Effect::new(move || {
@@ -42,18 +242,11 @@ pub fn SettingsPanel(
 
                 // Load current configuration
                 match tauri_api::get_config().await {
-                    Ok(config) => {
-                        set_storage_dir.set(config.playlists_directory);
-                        set_download_quality.set(config.download_quality);
-                        set_theme.set(config.theme);
-                        set_notif_download.set(config.notification_preferences.download_complete);
-                        set_notif_sync.set(config.notification_preferences.sync_complete);
-                        set_notif_errors.set(config.notification_preferences.errors);
-                        set_notif_device.set(config.notification_preferences.device_connected);
-                    }
+                    Ok(config) => draft.set(config),
                     Err(e) => {
                         leptos::logging::error!("Failed to load config: {}", e);
-                        set_error_message.set(Some(format!("Failed to load settings: {e}")));
+                        set_error_message
+                            .set(Some(i18n.tf("settings.load_failed", &[("error", &e)])));
                     }
                 }
 
@@ -74,36 +267,21 @@ pub fn SettingsPanel(
 
     // Save settings handler
     let on_save = move |_| {
-        let new_dir = storage_dir.get();
-        let new_quality = download_quality.get();
-        let new_theme = theme.get();
-        let notif_prefs = NotificationPreferences {
-            download_complete: notif_download.get(),
-            sync_complete: notif_sync.get(),
-            errors: notif_errors.get(),
-            device_connected: notif_device.get(),
-        };
+        let config = draft.get_untracked();
 
         spawn_local(async move {
             set_is_loading.set(true);
             set_error_message.set(None);
             set_success_message.set(None);
 
-            let config = AppConfig {
-                playlists_directory: new_dir,
-                download_quality: new_quality,
-                theme: new_theme,
-                notification_preferences: notif_prefs,
-            };
-
             match tauri_api::update_config(&config).await {
                 Ok(()) => {
                     leptos::logging::log!("Configuration updated successfully");
-                    set_success_message.set(Some("Settings saved successfully!".to_string()));
+                    set_success_message.set(Some(i18n.t("settings.saved")));
                 }
                 Err(e) => {
                     leptos::logging::error!("Failed to save config: {}", e);
-                    set_error_message.set(Some(format!("Failed to save settings: {e}")));
+                    set_error_message.set(Some(i18n.tf("settings.save_failed", &[("error", &e)])));
                 }
             }
 
@@ -113,14 +291,48 @@ pub fn SettingsPanel(
 
     // Reset to default handler
     let on_reset = move |_| {
-        let default = default_dir.get();
-        set_storage_dir.set(default);
-        set_download_quality.set(DownloadQuality::Medium);
-        set_theme.set(Theme::Dark);
-        set_notif_download.set(true);
-        set_notif_sync.set(true);
-        set_notif_errors.set(true);
-        set_notif_device.set(true);
+        let default = default_dir.get_untracked();
+        draft.update(|config| *config = reset_settings(config, &default));
+    };
+
+    let on_maintenance = Callback::new(move |operation: MaintenanceOperation| {
+        set_error_message.set(None);
+        spawn_local(async move {
+            match tauri_api::start_maintenance(&operation).await {
+                Ok(task_id) => set_running_operation.set(Some(RunningOperation::maintenance(
+                    &operation,
+                    task_id,
+                    i18n.locale(),
+                ))),
+                Err(e) => set_error_message.set(Some(e)),
+            }
+        });
+    });
+
+    let section_content = move |section: SettingsSection| {
+        view! {
+            <div class="settings-tab-content" class:hidden=move || active_section.get() != section>
+                {match section {
+                    SettingsSection::Downloads => downloads_section(draft, disabled, i18n).into_any(),
+                    SettingsSection::Devices => devices_section(draft, disabled, i18n).into_any(),
+                    SettingsSection::Storage => {
+                        storage_section(draft, default_dir, disabled, i18n).into_any()
+                    }
+                    SettingsSection::Cache => {
+                        cache_section(draft, disabled, on_maintenance, i18n).into_any()
+                    }
+                    SettingsSection::Notifications => {
+                        notifications_section(draft, disabled, i18n).into_any()
+                    }
+                    SettingsSection::Appearance => {
+                        appearance_section(draft, disabled, i18n).into_any()
+                    }
+                    SettingsSection::Advanced => {
+                        advanced_section(draft, disabled, active_section, i18n).into_any()
+                    }
+                }}
+            </div>
+        }
     };
 
     view! {
@@ -131,13 +343,18 @@ pub fn SettingsPanel(
         >
             <div
                 class="settings-panel"
+                node_ref=dialog_ref
                 on:click=move |e| e.stop_propagation()
+                role="dialog"
+                aria-modal="true"
+                aria-label=move || i18n.t("settings.title")
             >
                 <div class="settings-header">
-                    <h2>"Settings"</h2>
+                    <h2>{move || i18n.t("settings.title")}</h2>
                     <button
                         class="btn btn-ghost btn-icon"
                         on:click=move |_| on_close.run(())
+                        aria-label=move || i18n.t("settings.close")
                     >
                         <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
                             <path d="M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 17.59 19 19 17.59 13.41 12z"/>
@@ -145,48 +362,22 @@ pub fn SettingsPanel(
                     </button>
                 </div>
 
-                // Tab navigation
-                <div class="settings-tabs">
-                    <button
-                        class="settings-tab"
-                        class:active=move || active_tab.get() == "storage"
-                        on:click=move |_| set_active_tab.set("storage")
-                    >
-                        <svg viewBox="0 0 24 24" width="18" height="18" fill="currentColor">
-                            <path d="M20 6h-8l-2-2H4c-1.1 0-1.99.9-1.99 2L2 18c0 1.1.9 2 2 2h16c1.1 0 2-.9 2-2V8c0-1.1-.9-2-2-2z"/>
-                        </svg>
-                        "Storage"
-                    </button>
-                    <button
-                        class="settings-tab"
-                        class:active=move || active_tab.get() == "downloads"
-                        on:click=move |_| set_active_tab.set("downloads")
-                    >
-                        <svg viewBox="0 0 24 24" width="18" height="18" fill="currentColor">
-                            <path d="M19 9h-4V3H9v6H5l7 7 7-7zM5 18v2h14v-2H5z"/>
-                        </svg>
-                        "Downloads"
-                    </button>
-                    <button
-                        class="settings-tab"
-                        class:active=move || active_tab.get() == "appearance"
-                        on:click=move |_| set_active_tab.set("appearance")
-                    >
-                        <svg viewBox="0 0 24 24" width="18" height="18" fill="currentColor">
-                            <path d="M12 3c-4.97 0-9 4.03-9 9s4.03 9 9 9c.83 0 1.5-.67 1.5-1.5 0-.39-.15-.74-.39-1.01-.23-.26-.38-.61-.38-.99 0-.83.67-1.5 1.5-1.5H16c2.76 0 5-2.24 5-5 0-4.42-4.03-8-9-8zm-5.5 9c-.83 0-1.5-.67-1.5-1.5S5.67 9 6.5 9 8 9.67 8 10.5 7.33 12 6.5 12zm3-4C8.67 8 8 7.33 8 6.5S8.67 5 9.5 5s1.5.67 1.5 1.5S10.33 8 9.5 8zm5 0c-.83 0-1.5-.67-1.5-1.5S13.67 5 14.5 5s1.5.67 1.5 1.5S15.33 8 14.5 8zm3 4c-.83 0-1.5-.67-1.5-1.5S16.67 9 17.5 9s1.5.67 1.5 1.5-.67 1.5-1.5 1.5z"/>
-                        </svg>
-                        "Appearance"
-                    </button>
-                    <button
-                        class="settings-tab"
-                        class:active=move || active_tab.get() == "notifications"
-                        on:click=move |_| set_active_tab.set("notifications")
-                    >
-                        <svg viewBox="0 0 24 24" width="18" height="18" fill="currentColor">
-                            <path d="M12 22c1.1 0 2-.9 2-2h-4c0 1.1.89 2 2 2zm6-6v-5c0-3.07-1.64-5.64-4.5-6.32V4c0-.83-.67-1.5-1.5-1.5s-1.5.67-1.5 1.5v.68C7.63 5.36 6 7.92 6 11v5l-2 2v1h16v-1l-2-2z"/>
-                        </svg>
-                        "Notifications"
-                    </button>
+                // Section navigation
+                <div class="settings-tabs" role="tablist">
+                    {SettingsSection::ALL.into_iter().map(|section| view! {
+                        <button
+                            class="settings-tab"
+                            role="tab"
+                            class:active=move || active_section.get() == section
+                            aria-selected=move || (active_section.get() == section).to_string()
+                            on:click=move |_| set_active_section.set(section)
+                        >
+                            <svg viewBox="0 0 24 24" width="18" height="18" fill="currentColor">
+                                <path d=section.icon()/>
+                            </svg>
+                            {move || i18n.t(section.title_key())}
+                        </button>
+                    }).collect_view()}
                 </div>
 
                 <div class="settings-body">
@@ -210,216 +401,7 @@ pub fn SettingsPanel(
                         </div>
                     })}
 
-                    // Storage Tab
-                    <div class="settings-tab-content" class:hidden=move || active_tab.get() != "storage">
-                        <div class="settings-section">
-                            <h3>"Storage Location"</h3>
-                            <p class="settings-description">
-                                "Choose where your playlists are stored on your computer."
-                            </p>
-
-                            <div class="settings-field">
-                                <label for="storage-dir">"Playlists Directory"</label>
-                                <div class="settings-input-group">
-                                    <input
-                                        id="storage-dir"
-                                        type="text"
-                                        class="settings-input"
-                                        prop:value=move || storage_dir.get()
-                                        on:input=move |ev| {
-                                            set_storage_dir.set(event_target_value(&ev));
-                                        }
-                                        placeholder="Enter directory path..."
-                                        disabled=move || is_loading.get()
-                                    />
-                                </div>
-                                <p class="settings-hint">
-                                    "Default: " {move || default_dir.get()}
-                                </p>
-                            </div>
-                        </div>
-                    </div>
-
-                    // Downloads Tab
-                    <div class="settings-tab-content" class:hidden=move || active_tab.get() != "downloads">
-                        <div class="settings-section">
-                            <h3>"Download Quality"</h3>
-                            <p class="settings-description">
-                                "Select the audio quality for YouTube downloads."
-                            </p>
-
-                            <div class="settings-field">
-                                <div class="settings-radio-group">
-                                    <label class="settings-radio-option">
-                                        <input
-                                            type="radio"
-                                            name="download-quality"
-                                            checked=move || download_quality.get() == DownloadQuality::Low
-                                            on:change=move |_| set_download_quality.set(DownloadQuality::Low)
-                                            disabled=move || is_loading.get()
-                                        />
-                                        <span class="settings-radio-label">
-                                            <span class="settings-radio-title">"Low"</span>
-                                            <span class="settings-radio-description">"128 kbps - Smaller files, lower quality"</span>
-                                        </span>
-                                    </label>
-                                    <label class="settings-radio-option">
-                                        <input
-                                            type="radio"
-                                            name="download-quality"
-                                            checked=move || download_quality.get() == DownloadQuality::Medium
-                                            on:change=move |_| set_download_quality.set(DownloadQuality::Medium)
-                                            disabled=move || is_loading.get()
-                                        />
-                                        <span class="settings-radio-label">
-                                            <span class="settings-radio-title">"Medium"</span>
-                                            <span class="settings-radio-description">"192 kbps - Balanced quality and size"</span>
-                                        </span>
-                                    </label>
-                                    <label class="settings-radio-option">
-                                        <input
-                                            type="radio"
-                                            name="download-quality"
-                                            checked=move || download_quality.get() == DownloadQuality::High
-                                            on:change=move |_| set_download_quality.set(DownloadQuality::High)
-                                            disabled=move || is_loading.get()
-                                        />
-                                        <span class="settings-radio-label">
-                                            <span class="settings-radio-title">"High"</span>
-                                            <span class="settings-radio-description">"320 kbps - Best quality, larger files"</span>
-                                        </span>
-                                    </label>
-                                </div>
-                            </div>
-                        </div>
-                    </div>
-
-                    // Appearance Tab
-                    <div class="settings-tab-content" class:hidden=move || active_tab.get() != "appearance">
-                        <div class="settings-section">
-                            <h3>"Theme"</h3>
-                            <p class="settings-description">
-                                "Choose the appearance of the application."
-                            </p>
-
-                            <div class="settings-field">
-                                <div class="settings-theme-options">
-                                    <button
-                                        class="settings-theme-option"
-                                        class:selected=move || theme.get() == Theme::Dark
-                                        on:click=move |_| set_theme.set(Theme::Dark)
-                                        disabled=move || is_loading.get()
-                                    >
-                                        <div class="theme-preview theme-preview-dark">
-                                            <div class="theme-preview-header"></div>
-                                            <div class="theme-preview-content">
-                                                <div class="theme-preview-sidebar"></div>
-                                                <div class="theme-preview-main"></div>
-                                            </div>
-                                        </div>
-                                        <span class="theme-label">"Dark"</span>
-                                    </button>
-                                    <button
-                                        class="settings-theme-option"
-                                        class:selected=move || theme.get() == Theme::Light
-                                        on:click=move |_| set_theme.set(Theme::Light)
-                                        disabled=move || is_loading.get()
-                                    >
-                                        <div class="theme-preview theme-preview-light">
-                                            <div class="theme-preview-header"></div>
-                                            <div class="theme-preview-content">
-                                                <div class="theme-preview-sidebar"></div>
-                                                <div class="theme-preview-main"></div>
-                                            </div>
-                                        </div>
-                                        <span class="theme-label">"Light"</span>
-                                    </button>
-                                    <button
-                                        class="settings-theme-option"
-                                        class:selected=move || theme.get() == Theme::System
-                                        on:click=move |_| set_theme.set(Theme::System)
-                                        disabled=move || is_loading.get()
-                                    >
-                                        <div class="theme-preview theme-preview-system">
-                                            <div class="theme-preview-header"></div>
-                                            <div class="theme-preview-content">
-                                                <div class="theme-preview-sidebar"></div>
-                                                <div class="theme-preview-main"></div>
-                                            </div>
-                                        </div>
-                                        <span class="theme-label">"System"</span>
-                                    </button>
-                                </div>
-                            </div>
-                        </div>
-                    </div>
-
-                    // Notifications Tab
-                    <div class="settings-tab-content" class:hidden=move || active_tab.get() != "notifications">
-                        <div class="settings-section">
-                            <h3>"Notification Preferences"</h3>
-                            <p class="settings-description">
-                                "Choose which notifications you want to receive."
-                            </p>
-
-                            <div class="settings-field">
-                                <div class="settings-toggle-group">
-                                    <label class="settings-toggle-option">
-                                        <span class="settings-toggle-label">
-                                            <span class="settings-toggle-title">"Download Complete"</span>
-                                            <span class="settings-toggle-description">"Notify when a playlist download finishes"</span>
-                                        </span>
-                                        <input
-                                            type="checkbox"
-                                            class="settings-toggle"
-                                            checked=move || notif_download.get()
-                                            on:change=move |ev| set_notif_download.set(event_target_checked(&ev))
-                                            disabled=move || is_loading.get()
-                                        />
-                                    </label>
-                                    <label class="settings-toggle-option">
-                                        <span class="settings-toggle-label">
-                                            <span class="settings-toggle-title">"Sync Complete"</span>
-                                            <span class="settings-toggle-description">"Notify when syncing to a device finishes"</span>
-                                        </span>
-                                        <input
-                                            type="checkbox"
-                                            class="settings-toggle"
-                                            checked=move || notif_sync.get()
-                                            on:change=move |ev| set_notif_sync.set(event_target_checked(&ev))
-                                            disabled=move || is_loading.get()
-                                        />
-                                    </label>
-                                    <label class="settings-toggle-option">
-                                        <span class="settings-toggle-label">
-                                            <span class="settings-toggle-title">"Errors"</span>
-                                            <span class="settings-toggle-description">"Show notifications for errors and warnings"</span>
-                                        </span>
-                                        <input
-                                            type="checkbox"
-                                            class="settings-toggle"
-                                            checked=move || notif_errors.get()
-                                            on:change=move |ev| set_notif_errors.set(event_target_checked(&ev))
-                                            disabled=move || is_loading.get()
-                                        />
-                                    </label>
-                                    <label class="settings-toggle-option">
-                                        <span class="settings-toggle-label">
-                                            <span class="settings-toggle-title">"Device Connected"</span>
-                                            <span class="settings-toggle-description">"Notify when a USB device is connected"</span>
-                                        </span>
-                                        <input
-                                            type="checkbox"
-                                            class="settings-toggle"
-                                            checked=move || notif_device.get()
-                                            on:change=move |ev| set_notif_device.set(event_target_checked(&ev))
-                                            disabled=move || is_loading.get()
-                                        />
-                                    </label>
-                                </div>
-                            </div>
-                        </div>
-                    </div>
+                    {SettingsSection::ALL.into_iter().map(section_content).collect_view()}
                 </div>
 
                 <div class="settings-footer">
@@ -428,7 +410,7 @@ pub fn SettingsPanel(
                         on:click=on_reset
                         disabled=move || is_loading.get()
                     >
-                        "Reset to Default"
+                        {move || i18n.t("settings.reset")}
                     </button>
                     <div class="settings-footer-right">
                         <button
@@ -436,7 +418,7 @@ pub fn SettingsPanel(
                             on:click=move |_| on_close.run(())
                             disabled=move || is_loading.get()
                         >
-                            "Cancel"
+                            {move || i18n.t("common.cancel")}
                         </button>
                         <button
                             class="btn btn-primary"
@@ -444,14 +426,1101 @@ pub fn SettingsPanel(
                             disabled=move || is_loading.get()
                         >
                             {move || if is_loading.get() {
-                                view! { <span class="spinner"></span> " Saving..." }.into_any()
+                                view! { <span class="spinner"></span> " " {i18n.t("settings.saving")} }
+                                    .into_any()
                             } else {
-                                view! { "Save Settings" }.into_any()
+                                i18n.t("settings.save").into_any()
                             }}
                         </button>
                     </div>
                 </div>
             </div>
         </div>
+
+        <OperationProgressDialog
+            operation=running_operation
+            on_close=Callback::new(move |()| set_running_operation.set(None))
+        />
+    }
+}
+
+/// Download quality, queue and offline mode settings.
+fn downloads_section(
+    draft: RwSignal<AppConfig>,
+    disabled: Signal<bool>,
+    i18n: I18n,
+) -> impl IntoView {
+    let quality_option = move |quality: DownloadQuality,
+                               title: &'static str,
+                               description: &'static str| {
+        view! {
+            <label class="settings-radio-option">
+                <input
+                    type="radio"
+                    name="download-quality"
+                    checked=move || draft.with(|c| c.download_quality == quality)
+                    on:change=move |_| draft.update(|c| c.download_quality = quality)
+                    disabled=move || disabled.get()
+                />
+                <span class="settings-radio-label">
+                    <span class="settings-radio-title">{move || i18n.t(title)}</span>
+                    <span class="settings-radio-description">{move || i18n.t(description)}</span>
+                </span>
+            </label>
+        }
+    };
+
+    // Example file name of the template being edited
+    let (template_preview, set_template_preview) =
+        signal::<Result<String, String>>(Ok(String::new()));
+    Effect::new(move || {
+        let template = draft.with(|c| c.filename_template.clone());
+        spawn_local(async move {
+            let preview = tauri_api::preview_filename_template(&template).await;
+            set_template_preview.set(preview.map(|name| format!("{name}.mp3")));
+        });
+    });
+
+    view! {
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.quality")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.quality.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-radio-group">
+                    {quality_option(DownloadQuality::Low, "quality.low", "quality.low.description")}
+                    {quality_option(DownloadQuality::Medium, "quality.medium", "quality.medium.description")}
+                    {quality_option(DownloadQuality::High, "quality.high", "quality.high.description")}
+                </div>
+            </div>
+
+            <div class="settings-field">
+                <div class="settings-toggle-group">
+                    <SettingsToggle
+                        title="settings.write_tags"
+                        description="settings.write_tags.description"
+                        checked=Signal::derive(move || draft.with(|c| c.write_tags))
+                        on_change=Callback::new(move |on| draft.update(|c| c.write_tags = on))
+                        disabled=disabled
+                    />
+                    <SettingsToggle
+                        title="settings.embed_cover_art"
+                        description="settings.embed_cover_art.description"
+                        checked=Signal::derive(move || draft.with(|c| c.write_tags && c.embed_cover_art))
+                        on_change=Callback::new(move |on| draft.update(|c| c.embed_cover_art = on))
+                        disabled=Signal::derive(move || disabled.get() || draft.with(|c| !c.write_tags))
+                    />
+                </div>
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.lyrics")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.lyrics.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-toggle-group">
+                    <SettingsToggle
+                        title="settings.fetch_lyrics"
+                        description="settings.fetch_lyrics.description"
+                        checked=Signal::derive(move || draft.with(|c| c.lyrics.enabled))
+                        on_change=Callback::new(move |on| draft.update(|c| c.lyrics.enabled = on))
+                        disabled=disabled
+                    />
+                    <SettingsToggle
+                        title="settings.save_lrc"
+                        description="settings.save_lrc.description"
+                        checked=Signal::derive(move || draft.with(|c| c.lyrics.save_sidecar))
+                        on_change=Callback::new(move |on| draft.update(|c| c.lyrics.save_sidecar = on))
+                        disabled=Signal::derive(move || disabled.get() || draft.with(|c| !c.lyrics.enabled))
+                    />
+                    <SettingsToggle
+                        title="settings.embed_lyrics"
+                        description="settings.embed_lyrics.description"
+                        checked=Signal::derive(move || draft.with(|c| c.lyrics.embed))
+                        on_change=Callback::new(move |on| draft.update(|c| c.lyrics.embed = on))
+                        disabled=Signal::derive(move || disabled.get() || draft.with(|c| !c.lyrics.enabled))
+                    />
+                </div>
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.file_names")}</h3>
+            <p class="settings-description">
+                {move || i18n.tf(
+                    "settings.file_names.description",
+                    &[
+                        ("fields", "{title}, {artist}, {index}, {id}, {playlist}"),
+                        ("padded", "{index:02}"),
+                    ],
+                )}
+            </p>
+
+            <div class="settings-field">
+                <label for="filename-template">{move || i18n.t("settings.file_names.template")}</label>
+                <input
+                    id="filename-template"
+                    type="text"
+                    class="settings-input"
+                    placeholder="{title}"
+                    prop:value=move || draft.with(|c| c.filename_template.clone())
+                    on:input=move |ev| {
+                        let template = event_target_value(&ev);
+                        draft.update(|c| c.filename_template = template);
+                    }
+                    disabled=move || disabled.get()
+                />
+                <p class="settings-hint">
+                    {move || match template_preview.get() {
+                        Ok(name) => i18n.tf("settings.file_names.example", &[("name", &name)]),
+                        Err(e) => e,
+                    }}
+                </p>
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.queue")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.queue.description")}
+            </p>
+
+            <div class="settings-field">
+                <label for="max-concurrent">{move || i18n.t("settings.queue.max_concurrent")}</label>
+                <select
+                    id="max-concurrent"
+                    class="settings-input"
+                    on:change=move |ev| {
+                        if let Some(value) = parse_bounded(&event_target_value(&ev), 1, MAX_CONCURRENT_DOWNLOADS as u64) {
+                            draft.update(|c| c.queue.max_concurrent_downloads = value as usize);
+                        }
+                    }
+                    disabled=move || disabled.get()
+                >
+                    {(1..=MAX_CONCURRENT_DOWNLOADS).map(|n| view! {
+                        <option
+                            value=n.to_string()
+                            selected=move || draft.with(|c| c.queue.max_concurrent_downloads == n)
+                        >
+                            {n}
+                        </option>
+                    }).collect_view()}
+                </select>
+            </div>
+
+            <div class="settings-field">
+                <label for="download-concurrency">{move || i18n.t("settings.queue.concurrency")}</label>
+                <select
+                    id="download-concurrency"
+                    class="settings-input"
+                    on:change=move |ev| {
+                        if let Some(value) = parse_bounded(&event_target_value(&ev), 1, MAX_DOWNLOAD_CONCURRENCY as u64) {
+                            draft.update(|c| c.download_concurrency = value as usize);
+                        }
+                    }
+                    disabled=move || disabled.get()
+                >
+                    {(1..=MAX_DOWNLOAD_CONCURRENCY).map(|n| view! {
+                        <option
+                            value=n.to_string()
+                            selected=move || draft.with(|c| c.download_concurrency == n)
+                        >
+                            {n}
+                        </option>
+                    }).collect_view()}
+                </select>
+            </div>
+
+            <div class="settings-field">
+                <label for="max-download-speed">{move || i18n.t("settings.queue.speed_limit")}</label>
+                <select
+                    id="max-download-speed"
+                    class="settings-input"
+                    on:change=move |ev| {
+                        let limit = event_target_value(&ev).parse().ok();
+                        draft.update(|c| c.max_download_speed_bps = limit);
+                    }
+                    disabled=move || disabled.get()
+                >
+                    <option
+                        value=""
+                        selected=move || draft.with(|c| c.max_download_speed_bps.is_none())
+                    >
+                        {move || i18n.t("settings.unlimited")}
+                    </option>
+                    {DOWNLOAD_SPEED_LIMITS.into_iter().map(|(bytes_per_sec, label)| view! {
+                        <option
+                            value=bytes_per_sec.to_string()
+                            selected=move || draft.with(|c| c.max_download_speed_bps == Some(bytes_per_sec))
+                        >
+                            {label}
+                        </option>
+                    }).collect_view()}
+                </select>
+            </div>
+
+            <div class="settings-field">
+                <div class="settings-toggle-group">
+                    <SettingsToggle
+                        title="settings.auto_start"
+                        description="settings.auto_start.description"
+                        checked=Signal::derive(move || draft.with(|c| c.queue.auto_start))
+                        on_change=Callback::new(move |on| draft.update(|c| c.queue.auto_start = on))
+                        disabled=disabled
+                    />
+                    <SettingsToggle
+                        title="settings.auto_retry"
+                        description="settings.auto_retry.description"
+                        checked=Signal::derive(move || draft.with(|c| c.queue.auto_retry))
+                        on_change=Callback::new(move |on| draft.update(|c| c.queue.auto_retry = on))
+                        disabled=disabled
+                    />
+                </div>
+            </div>
+
+            {move || draft.with(|c| c.queue.auto_retry).then(|| view! {
+                <div class="settings-field">
+                    <label for="max-retries">{move || i18n.t("settings.queue.max_retries")}</label>
+                    <input
+                        id="max-retries"
+                        type="number"
+                        min="1"
+                        max="10"
+                        class="settings-input"
+                        prop:value=move || draft.with(|c| c.queue.max_retries.to_string())
+                        on:change=move |ev| {
+                            if let Some(value) = parse_bounded(&event_target_value(&ev), 1, 10) {
+                                draft.update(|c| c.queue.max_retries = value as u32);
+                            }
+                        }
+                        disabled=move || disabled.get()
+                    />
+                </div>
+            })}
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.offline")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.offline.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-toggle-group">
+                    <SettingsToggle
+                        title="settings.work_offline"
+                        description="settings.work_offline.description"
+                        checked=Signal::derive(move || draft.with(|c| c.offline_mode))
+                        on_change=Callback::new(move |on| draft.update(|c| c.offline_mode = on))
+                        disabled=disabled
+                    />
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Preferred device and device detection settings.
+fn devices_section(
+    draft: RwSignal<AppConfig>,
+    disabled: Signal<bool>,
+    i18n: I18n,
+) -> impl IntoView {
+    view! {
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.preferred_device")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.preferred_device.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-input-group">
+                    <input
+                        type="text"
+                        class="settings-input"
+                        prop:value=move || draft.with(|c| c.preferred_device.clone().unwrap_or_default())
+                        placeholder=move || i18n.t("settings.preferred_device.none")
+                        readonly=true
+                    />
+                    <button
+                        class="btn btn-secondary"
+                        on:click=move |_| draft.update(|c| c.preferred_device = None)
+                        disabled=move || disabled.get() || draft.with(|c| c.preferred_device.is_none())
+                    >
+                        {move || i18n.t("common.clear")}
+                    </button>
+                </div>
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.device_detection")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.device_detection.description")}
+            </p>
+
+            <div class="settings-field">
+                <label for="device-poll-interval">{move || i18n.t("settings.device_detection.interval")}</label>
+                <input
+                    id="device-poll-interval"
+                    type="number"
+                    min="1"
+                    max="60"
+                    class="settings-input"
+                    prop:value=move || draft.with(|c| (c.device_poll_interval_ms / 1000).max(1).to_string())
+                    on:change=move |ev| {
+                        if let Some(secs) = parse_bounded(&event_target_value(&ev), 1, 60) {
+                            draft.update(|c| c.device_poll_interval_ms = secs * 1000);
+                        }
+                    }
+                    disabled=move || disabled.get()
+                />
+            </div>
+        </div>
+    }
+}
+
+/// Playlists directory and library limit settings.
+fn storage_section(
+    draft: RwSignal<AppConfig>,
+    default_dir: ReadSignal<String>,
+    disabled: Signal<bool>,
+    i18n: I18n,
+) -> impl IntoView {
+    view! {
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.storage_location")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.storage_location.description")}
+            </p>
+
+            <div class="settings-field">
+                <label for="storage-dir">{move || i18n.t("settings.storage_location.directory")}</label>
+                <div class="settings-input-group">
+                    <input
+                        id="storage-dir"
+                        type="text"
+                        class="settings-input"
+                        prop:value=move || draft.with(|c| c.playlists_directory.clone())
+                        on:input=move |ev| {
+                            let dir = event_target_value(&ev);
+                            draft.update(|c| c.playlists_directory = dir);
+                        }
+                        placeholder=move || i18n.t("settings.storage_location.placeholder")
+                        disabled=move || disabled.get()
+                    />
+                </div>
+                <p class="settings-hint">
+                    {move || i18n.tf("settings.storage_location.default", &[("path", &default_dir.get())])}
+                </p>
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.library_limits")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.library_limits.description")}
+            </p>
+
+            <div class="settings-field">
+                <label for="max-library-size">{move || i18n.t("settings.library_limits.max_size")}</label>
+                <input
+                    id="max-library-size"
+                    type="number"
+                    min="0"
+                    step="0.5"
+                    class="settings-input"
+                    prop:value=move || draft.with(|c| {
+                        c.library_limits.max_library_size_bytes.map(bytes_to_gigabytes).unwrap_or_default()
+                    })
+                    on:change=move |ev| {
+                        let limit = parse_gigabytes(&event_target_value(&ev)).filter(|&b| b > 0);
+                        draft.update(|c| c.library_limits.max_library_size_bytes = limit);
+                    }
+                    placeholder=move || i18n.t("settings.unlimited")
+                    disabled=move || disabled.get()
+                />
+                <p class="settings-hint">{move || i18n.t("settings.library_limits.no_limit")}</p>
+            </div>
+
+            <div class="settings-field">
+                <label for="min-free-space">{move || i18n.t("settings.library_limits.min_free")}</label>
+                <input
+                    id="min-free-space"
+                    type="number"
+                    min="0"
+                    step="0.5"
+                    class="settings-input"
+                    prop:value=move || draft.with(|c| bytes_to_gigabytes(c.library_limits.min_free_space_bytes))
+                    on:change=move |ev| {
+                        if let Some(bytes) = parse_gigabytes(&event_target_value(&ev)) {
+                            draft.update(|c| c.library_limits.min_free_space_bytes = bytes);
+                        }
+                    }
+                    disabled=move || disabled.get()
+                />
+            </div>
+        </div>
+    }
+}
+
+/// Metadata and thumbnail cache settings.
+fn cache_section(
+    draft: RwSignal<AppConfig>,
+    disabled: Signal<bool>,
+    on_maintenance: Callback<MaintenanceOperation>,
+    i18n: I18n,
+) -> impl IntoView {
+    let cache_disabled = Signal::derive(move || disabled.get() || !draft.with(|c| c.cache.enabled));
+
+    view! {
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.cache")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.cache.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-toggle-group">
+                    <SettingsToggle
+                        title="settings.cache_enabled"
+                        description="settings.cache_enabled.description"
+                        checked=Signal::derive(move || draft.with(|c| c.cache.enabled))
+                        on_change=Callback::new(move |on| draft.update(|c| c.cache.enabled = on))
+                        disabled=disabled
+                    />
+                    <SettingsToggle
+                        title="settings.cache_metadata"
+                        description="settings.cache_metadata.description"
+                        checked=Signal::derive(move || draft.with(|c| c.cache.cache_metadata))
+                        on_change=Callback::new(move |on| draft.update(|c| c.cache.cache_metadata = on))
+                        disabled=cache_disabled
+                    />
+                    <SettingsToggle
+                        title="settings.cache_thumbnails"
+                        description="settings.cache_thumbnails.description"
+                        checked=Signal::derive(move || draft.with(|c| c.cache.cache_thumbnails))
+                        on_change=Callback::new(move |on| draft.update(|c| c.cache.cache_thumbnails = on))
+                        disabled=cache_disabled
+                    />
+                </div>
+            </div>
+
+            <div class="settings-field">
+                <label for="cache-max-size">{move || i18n.t("settings.cache.max_size")}</label>
+                <input
+                    id="cache-max-size"
+                    type="number"
+                    min="10"
+                    max="10240"
+                    class="settings-input"
+                    prop:value=move || draft.with(|c| (c.cache.max_size_bytes / BYTES_PER_MB).to_string())
+                    on:change=move |ev| {
+                        if let Some(mb) = parse_bounded(&event_target_value(&ev), 10, 10_240) {
+                            draft.update(|c| c.cache.max_size_bytes = mb * BYTES_PER_MB);
+                        }
+                    }
+                    disabled=move || cache_disabled.get()
+                />
+            </div>
+
+            <div class="settings-field">
+                <label for="cache-ttl">{move || i18n.t("settings.cache.ttl")}</label>
+                <input
+                    id="cache-ttl"
+                    type="number"
+                    min="1"
+                    max="365"
+                    class="settings-input"
+                    prop:value=move || draft.with(|c| (c.cache.ttl_secs / SECS_PER_DAY).max(1).to_string())
+                    on:change=move |ev| {
+                        if let Some(days) = parse_bounded(&event_target_value(&ev), 1, 365) {
+                            draft.update(|c| c.cache.ttl_secs = days * SECS_PER_DAY);
+                        }
+                    }
+                    disabled=move || cache_disabled.get()
+                />
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.clear_cache")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.clear_cache.description")}
+            </p>
+
+            <div class="settings-field">
+                <button
+                    class="btn btn-secondary"
+                    on:click=move |_| on_maintenance.run(MaintenanceOperation::ClearCache)
+                    disabled=move || disabled.get()
+                >
+                    {move || i18n.t("settings.clear_cache")}
+                </button>
+            </div>
+        </div>
+    }
+}
+
+/// Notification preference and webhook settings.
+fn notifications_section(
+    draft: RwSignal<AppConfig>,
+    disabled: Signal<bool>,
+    i18n: I18n,
+) -> impl IntoView {
+    // The URL is write-only: only whether one is saved can be read back
+    let (webhook_url, set_webhook_url) = signal(String::new());
+    let (url_saved, set_url_saved) = signal(false);
+    let (webhook_status, set_webhook_status) = signal::<Option<String>>(None);
+    spawn_local(async move {
+        match tauri_api::has_secret(WEBHOOK_URL_SECRET).await {
+            Ok(saved) => set_url_saved.set(saved),
+            Err(e) => leptos::logging::warn!("Failed to check webhook URL: {}", e),
+        }
+    });
+
+    let on_save_url = move |_| {
+        let url = webhook_url.get_untracked().trim().to_string();
+        spawn_local(async move {
+            match tauri_api::set_secret(WEBHOOK_URL_SECRET, &url).await {
+                Ok(()) => {
+                    set_url_saved.set(true);
+                    set_webhook_url.set(String::new());
+                    set_webhook_status.set(Some(i18n.t("settings.webhook.url_saved")));
+                }
+                Err(e) => set_webhook_status.set(Some(e)),
+            }
+        });
+    };
+    let on_remove_url = move |_| {
+        spawn_local(async move {
+            match tauri_api::delete_secret(WEBHOOK_URL_SECRET).await {
+                Ok(_) => {
+                    set_url_saved.set(false);
+                    set_webhook_status.set(Some(i18n.t("settings.webhook.url_removed")));
+                }
+                Err(e) => set_webhook_status.set(Some(e)),
+            }
+        });
+    };
+    let on_test = move |_| {
+        let webhook = draft.with_untracked(|c| c.webhook.clone());
+        spawn_local(async move {
+            set_webhook_status.set(Some(i18n.t("settings.webhook.sending")));
+            let status = match tauri_api::send_test_webhook(&webhook).await {
+                Ok(()) => i18n.t("settings.webhook.sent"),
+                Err(e) => e,
+            };
+            set_webhook_status.set(Some(status));
+        });
+    };
+
+    let event_toggle = move |event: WebhookEvent, description: &'static str| {
+        view! {
+            <SettingsToggle
+                title=event.label_key()
+                description=description
+                checked=Signal::derive(move || draft.with(|c| c.webhook.events.contains(&event)))
+                on_change=Callback::new(move |on| {
+                    draft.update(|c| {
+                        c.webhook.events.retain(|e| *e != event);
+                        if on {
+                            c.webhook.events.push(event);
+                        }
+                    });
+                })
+                disabled=Signal::derive(move || disabled.get() || draft.with(|c| !c.webhook.enabled))
+            />
+        }
+    };
+
+    view! {
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.notifications")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.notifications.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-toggle-group">
+                    <SettingsToggle
+                        title="settings.notify_download"
+                        description="settings.notify_download.description"
+                        checked=Signal::derive(move || draft.with(|c| c.notification_preferences.download_complete))
+                        on_change=Callback::new(move |on| draft.update(|c| c.notification_preferences.download_complete = on))
+                        disabled=disabled
+                    />
+                    <SettingsToggle
+                        title="settings.notify_sync"
+                        description="settings.notify_sync.description"
+                        checked=Signal::derive(move || draft.with(|c| c.notification_preferences.sync_complete))
+                        on_change=Callback::new(move |on| draft.update(|c| c.notification_preferences.sync_complete = on))
+                        disabled=disabled
+                    />
+                    <SettingsToggle
+                        title="settings.notify_errors"
+                        description="settings.notify_errors.description"
+                        checked=Signal::derive(move || draft.with(|c| c.notification_preferences.errors))
+                        on_change=Callback::new(move |on| draft.update(|c| c.notification_preferences.errors = on))
+                        disabled=disabled
+                    />
+                    <SettingsToggle
+                        title="settings.notify_device"
+                        description="settings.notify_device.description"
+                        checked=Signal::derive(move || draft.with(|c| c.notification_preferences.device_connected))
+                        on_change=Callback::new(move |on| draft.update(|c| c.notification_preferences.device_connected = on))
+                        disabled=disabled
+                    />
+                </div>
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.webhook")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.webhook.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-toggle-group">
+                    <SettingsToggle
+                        title="settings.webhook_enabled"
+                        description="settings.webhook_enabled.description"
+                        checked=Signal::derive(move || draft.with(|c| c.webhook.enabled))
+                        on_change=Callback::new(move |on| draft.update(|c| c.webhook.enabled = on))
+                        disabled=disabled
+                    />
+                </div>
+            </div>
+
+            <div class="settings-field">
+                <label for="webhook-url">{move || i18n.t("settings.webhook.url")}</label>
+                <div class="settings-input-group">
+                    <input
+                        id="webhook-url"
+                        type="url"
+                        class="settings-input"
+                        placeholder=move || {
+                            if url_saved.get() {
+                                i18n.t("settings.webhook.url_replace")
+                            } else {
+                                "https://ntfy.sh/my-topic".to_string()
+                            }
+                        }
+                        prop:value=move || webhook_url.get()
+                        on:input=move |ev| set_webhook_url.set(event_target_value(&ev))
+                        disabled=move || disabled.get()
+                    />
+                    <button
+                        class="btn btn-secondary"
+                        on:click=on_save_url
+                        disabled=move || disabled.get() || webhook_url.with(|url| url.trim().is_empty())
+                    >
+                        {move || i18n.t("settings.webhook.save_url")}
+                    </button>
+                    <button
+                        class="btn btn-secondary"
+                        on:click=on_remove_url
+                        disabled=move || disabled.get() || !url_saved.get()
+                    >
+                        {move || i18n.t("common.remove")}
+                    </button>
+                </div>
+                <p class="settings-hint">
+                    {move || i18n.t("settings.webhook.url_hint")}
+                </p>
+            </div>
+
+            <div class="settings-field">
+                <label for="webhook-payload">{move || i18n.t("settings.webhook.payload")}</label>
+                <input
+                    id="webhook-payload"
+                    type="text"
+                    class="settings-input"
+                    placeholder="{{message}}"
+                    prop:value=move || draft.with(|c| c.webhook.payload_template.clone())
+                    on:input=move |ev| {
+                        let template = event_target_value(&ev);
+                        draft.update(|c| c.webhook.payload_template = template);
+                    }
+                    disabled=move || disabled.get() || draft.with(|c| !c.webhook.enabled)
+                />
+                <p class="settings-hint">
+                    {move || i18n.tf(
+                        "settings.webhook.payload_hint",
+                        &[
+                            ("fields", "{{event}}, {{message}}, {{playlist}}, {{succeeded}}, {{failed}}, {{error}}"),
+                            ("brace", "{"),
+                            ("example", "{\"content\": \"{{message}}\"}"),
+                        ],
+                    )}
+                </p>
+            </div>
+
+            <div class="settings-field">
+                <div class="settings-toggle-group">
+                    {event_toggle(WebhookEvent::DownloadCompleted, "settings.webhook.download_completed")}
+                    {event_toggle(WebhookEvent::DownloadFailed, "settings.webhook.download_failed")}
+                    {event_toggle(WebhookEvent::SyncCompleted, "settings.webhook.sync_completed")}
+                    {event_toggle(WebhookEvent::SyncFailed, "settings.webhook.sync_failed")}
+                </div>
+            </div>
+
+            <div class="settings-field">
+                <button
+                    class="btn btn-secondary"
+                    on:click=on_test
+                    disabled=move || disabled.get() || !url_saved.get()
+                >
+                    {move || i18n.t("settings.webhook.test")}
+                </button>
+                {move || webhook_status.get().map(|status| view! { <p class="settings-hint">{status}</p> })}
+            </div>
+        </div>
+    }
+}
+
+/// Theme settings.
+fn appearance_section(
+    draft: RwSignal<AppConfig>,
+    disabled: Signal<bool>,
+    i18n: I18n,
+) -> impl IntoView {
+    let theme_option = move |theme: Theme, preview_class: &'static str, label: &'static str| {
+        view! {
+            <button
+                class="settings-theme-option"
+                class:selected=move || draft.with(|c| c.theme == theme)
+                on:click=move |_| draft.update(|c| c.theme = theme)
+                disabled=move || disabled.get()
+            >
+                <div class=format!("theme-preview {preview_class}")>
+                    <div class="theme-preview-header"></div>
+                    <div class="theme-preview-content">
+                        <div class="theme-preview-sidebar"></div>
+                        <div class="theme-preview-main"></div>
+                    </div>
+                </div>
+                <span class="theme-label">{move || i18n.t(label)}</span>
+            </button>
+        }
+    };
+
+    view! {
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.theme")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.theme.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-theme-options">
+                    {theme_option(Theme::Dark, "theme-preview-dark", "settings.theme.dark")}
+                    {theme_option(Theme::Light, "theme-preview-light", "settings.theme.light")}
+                    {theme_option(Theme::System, "theme-preview-system", "settings.theme.system")}
+                </div>
+                <p class="settings-hint">{move || i18n.t("settings.theme.system_hint")}</p>
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.accent_color")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.accent_color.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-input-group">
+                    <input
+                        type="color"
+                        class="settings-color-input"
+                        aria-label=move || i18n.t("settings.accent_color")
+                        prop:value=move || draft.with(|c| {
+                            c.accent_color
+                                .clone()
+                                .unwrap_or_else(|| colors::accent::PRIMARY.to_string())
+                                .to_ascii_lowercase()
+                        })
+                        on:input=move |ev| {
+                            let color = event_target_value(&ev);
+                            draft.update(|c| c.accent_color = Some(color));
+                        }
+                        disabled=move || disabled.get() || draft.with(|c| c.high_contrast)
+                    />
+                    <button
+                        class="btn btn-secondary"
+                        on:click=move |_| draft.update(|c| c.accent_color = None)
+                        disabled=move || disabled.get() || draft.with(|c| c.accent_color.is_none())
+                    >
+                        {move || i18n.t("settings.accent_color.default")}
+                    </button>
+                </div>
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.accessibility")}</h3>
+
+            <div class="settings-field">
+                <div class="settings-toggle-group">
+                    <SettingsToggle
+                        title="settings.high_contrast"
+                        description="settings.high_contrast.description"
+                        checked=Signal::derive(move || draft.with(|c| c.high_contrast))
+                        on_change=Callback::new(move |on| draft.update(|c| c.high_contrast = on))
+                        disabled=disabled
+                    />
+                </div>
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.language")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.language.description")}
+            </p>
+
+            <div class="settings-field">
+                <select
+                    class="settings-input"
+                    aria-label=move || i18n.t("settings.language")
+                    prop:value=move || draft.with(|c| c.language.map_or("", Locale::code))
+                    on:change=move |ev| {
+                        let language = Locale::from_code(&event_target_value(&ev));
+                        draft.update(|c| c.language = language);
+                    }
+                    disabled=move || disabled.get()
+                >
+                    <option value="">{move || i18n.t("settings.language.system")}</option>
+                    {Locale::ALL.into_iter().map(|locale| view! {
+                        <option value=locale.code()>{locale.to_string()}</option>
+                    }).collect_view()}
+                </select>
+            </div>
+        </div>
+    }
+}
+
+/// Extraction, background operation, power, update and log settings.
+fn advanced_section(
+    draft: RwSignal<AppConfig>,
+    disabled: Signal<bool>,
+    active_section: ReadSignal<SettingsSection>,
+    i18n: I18n,
+) -> impl IntoView {
+    let channel_option = move |channel: UpdateChannel, description: &'static str| {
+        view! {
+            <label class="settings-radio-option">
+                <input
+                    type="radio"
+                    name="update-channel"
+                    checked=move || draft.with(|c| c.update_channel == channel)
+                    on:change=move |_| draft.update(|c| c.update_channel = channel)
+                    disabled=move || disabled.get()
+                />
+                <span class="settings-radio-label">
+                    <span class="settings-radio-title">{move || i18n.t(channel.label_key())}</span>
+                    <span class="settings-radio-description">{move || i18n.t(description)}</span>
+                </span>
+            </label>
+        }
+    };
+
+    let backend_option = move |backend: ExtractionBackend,
+                               title: &'static str,
+                               description: &'static str| {
+        view! {
+            <label class="settings-radio-option">
+                <input
+                    type="radio"
+                    name="extraction-backend"
+                    checked=move || draft.with(|c| c.extraction.backend == backend)
+                    on:change=move |_| draft.update(|c| c.extraction.backend = backend)
+                    disabled=move || disabled.get()
+                />
+                <span class="settings-radio-label">
+                    <span class="settings-radio-title">{move || i18n.t(title)}</span>
+                    <span class="settings-radio-description">{move || i18n.t(description)}</span>
+                </span>
+            </label>
+        }
+    };
+
+    view! {
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.extraction")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.extraction.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-radio-group">
+                    {backend_option(ExtractionBackend::Auto, "settings.extraction.auto", "settings.extraction.auto.description")}
+                    {backend_option(ExtractionBackend::PureRust, "settings.extraction.built_in", "settings.extraction.built_in.description")}
+                    {backend_option(ExtractionBackend::YtDlp, "settings.extraction.yt_dlp", "settings.extraction.yt_dlp.description")}
+                </div>
+            </div>
+
+            <div class="settings-field">
+                <label for="yt-dlp-path">{move || i18n.t("settings.extraction.yt_dlp_path")}</label>
+                <input
+                    id="yt-dlp-path"
+                    type="text"
+                    class="settings-input"
+                    placeholder=move || i18n.t("settings.extraction.yt_dlp_path.placeholder")
+                    prop:value=move || draft.with(|c| c.extraction.yt_dlp_path.clone().unwrap_or_default())
+                    on:change=move |ev| {
+                        let path = event_target_value(&ev).trim().to_string();
+                        draft.update(|c| c.extraction.yt_dlp_path = (!path.is_empty()).then_some(path));
+                    }
+                    disabled=move || disabled.get()
+                        || draft.with(|c| c.extraction.backend == ExtractionBackend::PureRust)
+                />
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.background")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.background.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-toggle-group">
+                    <SettingsToggle
+                        title="settings.minimize_to_tray"
+                        description="settings.minimize_to_tray.description"
+                        checked=Signal::derive(move || draft.with(|c| c.minimize_to_tray))
+                        on_change=Callback::new(move |on| draft.update(|c| c.minimize_to_tray = on))
+                        disabled=disabled
+                    />
+                </div>
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.power")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.power.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-toggle-group">
+                    <SettingsToggle
+                        title="settings.pause_on_battery"
+                        description="settings.pause_on_battery.description"
+                        checked=Signal::derive(move || draft.with(|c| c.power.pause_on_battery))
+                        on_change=Callback::new(move |on| draft.update(|c| c.power.pause_on_battery = on))
+                        disabled=disabled
+                    />
+                    <SettingsToggle
+                        title="settings.pause_on_metered"
+                        description="settings.pause_on_metered.description"
+                        checked=Signal::derive(move || draft.with(|c| c.power.pause_on_metered))
+                        on_change=Callback::new(move |on| draft.update(|c| c.power.pause_on_metered = on))
+                        disabled=disabled
+                    />
+                    <SettingsToggle
+                        title="settings.pause_on_guessed_metered"
+                        description="settings.pause_on_guessed_metered.description"
+                        checked=Signal::derive(move || draft.with(|c| c.power.pause_on_guessed_metered))
+                        on_change=Callback::new(move |on| draft.update(|c| c.power.pause_on_guessed_metered = on))
+                        disabled=Signal::derive(move || disabled.get() || !draft.with(|c| c.power.pause_on_metered))
+                    />
+                </div>
+            </div>
+        </div>
+
+        <div class="settings-section">
+            <h3>{move || i18n.t("settings.updates")}</h3>
+            <p class="settings-description">
+                {move || i18n.t("settings.updates.description")}
+            </p>
+
+            <div class="settings-field">
+                <div class="settings-radio-group">
+                    {channel_option(UpdateChannel::Stable, "settings.updates.stable.description")}
+                    {channel_option(UpdateChannel::Beta, "settings.updates.beta.description")}
+                </div>
+            </div>
+        </div>
+
+        // Mounted only while shown so it stops refreshing
+        {move || (active_section.get() == SettingsSection::Advanced).then(|| view! {
+            <div class="settings-section">
+                <h3>{move || i18n.t("settings.log")}</h3>
+                <p class="settings-description">
+                    {move || i18n.t("settings.log.description")}
+                </p>
+                <LogViewer />
+            </div>
+        })}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DeviceProfile, PlaylistOverrides};
+
+    #[test]
+    fn test_gigabytes_round_trip() {
+        assert_eq!(bytes_to_gigabytes(BYTES_PER_GB), "1");
+        assert_eq!(bytes_to_gigabytes(BYTES_PER_GB * 3 / 2), "1.5");
+        assert_eq!(bytes_to_gigabytes(0), "0");
+        assert_eq!(parse_gigabytes(" 1.5 "), Some(BYTES_PER_GB * 3 / 2));
+        assert_eq!(parse_gigabytes(""), None);
+        assert_eq!(parse_gigabytes("-1"), None);
+        assert_eq!(parse_gigabytes("lots"), None);
+    }
+
+    #[test]
+    fn test_parse_bounded() {
+        assert_eq!(parse_bounded("3", 1, 4), Some(3));
+        assert_eq!(parse_bounded("5", 1, 4), None);
+        assert_eq!(parse_bounded("0", 1, 4), None);
+        assert_eq!(parse_bounded("two", 1, 4), None);
+    }
+
+    #[test]
+    fn test_reset_settings_keeps_unrelated_settings() {
+        let mut current = AppConfig {
+            playlists_directory: "/music".to_string(),
+            download_quality: DownloadQuality::High,
+            offline_mode: true,
+            onboarding_completed: true,
+            ..AppConfig::default()
+        };
+        current.queue.max_concurrent_downloads = 4;
+        current
+            .device_profiles
+            .insert("/media/player".to_string(), DeviceProfile::default());
+        current
+            .playlist_overrides
+            .insert("Chill".to_string(), PlaylistOverrides::default());
+        current.cache.custom_cache_dir = Some("/cache".to_string());
+
+        let reset = reset_settings(&current, "/default");
+        assert_eq!(reset.playlists_directory, "/default");
+        assert_eq!(reset.download_quality, DownloadQuality::Medium);
+        assert!(!reset.offline_mode);
+        assert_eq!(reset.queue.max_concurrent_downloads, 2);
+        assert!(reset.onboarding_completed);
+        assert_eq!(reset.device_profiles.len(), 1);
+        assert_eq!(reset.playlist_overrides.len(), 1);
+        assert_eq!(reset.cache.custom_cache_dir.as_deref(), Some("/cache"));
+    }
+
+    #[test]
+    fn test_sections_have_distinct_titles() {
+        let mut titles: Vec<_> = SettingsSection::ALL.iter().map(|s| s.title_key()).collect();
+        titles.sort_unstable();
+        titles.dedup();
+        assert_eq!(titles.len(), SettingsSection::ALL.len());
     }
 }
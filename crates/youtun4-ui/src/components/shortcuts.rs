@@ -0,0 +1,404 @@
+//! Keyboard shortcuts: the global shortcut map, its cheatsheet overlay and
+//! keyboard handling shared by dialogs.
+
+use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::i18n::use_i18n;
+
+/// Elements that can take focus inside a dialog.
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+     select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex=\"-1\"])";
+
+/// Shortcuts listed in the cheatsheet, as (group key, keys, description
+/// key).
+pub const SHORTCUT_HELP: &[(&str, &str, &str)] = &[
+    ("shortcuts.group.general", "?", "shortcuts.show"),
+    (
+        "shortcuts.group.general",
+        "Ctrl K / Ctrl F",
+        "shortcuts.search",
+    ),
+    ("shortcuts.group.general", "Ctrl ,", "shortcuts.settings"),
+    ("shortcuts.group.general", "Ctrl Z", "shortcuts.undo"),
+    ("shortcuts.group.navigation", "Alt 1", "shortcuts.playlists"),
+    ("shortcuts.group.navigation", "Alt 2", "shortcuts.history"),
+    ("shortcuts.group.navigation", "Alt 3", "shortcuts.device"),
+    ("shortcuts.group.playlists", "Delete", "shortcuts.delete"),
+    ("shortcuts.group.playlists", "Space", "shortcuts.preview"),
+    (
+        "shortcuts.group.dialogs",
+        "Tab / Shift Tab",
+        "shortcuts.focus",
+    ),
+    (
+        "shortcuts.group.dialogs",
+        "Escape",
+        "shortcuts.close_dialog",
+    ),
+];
+
+/// An action triggered by a global shortcut.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shortcut {
+    /// Toggle the shortcut cheatsheet.
+    ShowShortcuts,
+    /// Open the settings panel.
+    OpenSettings,
+    /// Show the playlist list.
+    GoToPlaylists,
+    /// Show the download history.
+    GoToHistory,
+    /// Show the selected device's dashboard.
+    GoToDevice,
+    /// Ask to delete the open or selected playlist.
+    DeletePlaylist,
+    /// Undo the last change.
+    Undo,
+}
+
+/// The parts of a key press that shortcuts match on.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyPress {
+    /// Key value, e.g. "?" or "Delete".
+    pub key: String,
+    /// Physical key, e.g. "Digit1"; used where the key value depends on the
+    /// layout or on Alt.
+    pub code: String,
+    /// Whether Ctrl was held.
+    pub ctrl: bool,
+    /// Whether Cmd (or the Windows key) was held.
+    pub meta: bool,
+    /// Whether Alt (Option) was held.
+    pub alt: bool,
+}
+
+impl KeyPress {
+    /// Read a key press from a keyboard event.
+    #[must_use]
+    pub fn from_event(e: &web_sys::KeyboardEvent) -> Self {
+        Self {
+            key: e.key(),
+            code: e.code(),
+            ctrl: e.ctrl_key(),
+            meta: e.meta_key(),
+            alt: e.alt_key(),
+        }
+    }
+}
+
+/// The global shortcut for a key press, if any.
+#[must_use]
+pub fn global_shortcut(press: &KeyPress) -> Option<Shortcut> {
+    let command = press.ctrl || press.meta;
+    if press.alt {
+        if command {
+            return None;
+        }
+        return match press.code.as_str() {
+            "Digit1" => Some(Shortcut::GoToPlaylists),
+            "Digit2" => Some(Shortcut::GoToHistory),
+            "Digit3" => Some(Shortcut::GoToDevice),
+            _ => None,
+        };
+    }
+    if command {
+        // Shift+Z is usually redo, and arrives as "Z"
+        return match press.key.as_str() {
+            "," => Some(Shortcut::OpenSettings),
+            "z" => Some(Shortcut::Undo),
+            _ => None,
+        };
+    }
+    match press.key.as_str() {
+        "?" => Some(Shortcut::ShowShortcuts),
+        "Delete" => Some(Shortcut::DeletePlaylist),
+        _ => None,
+    }
+}
+
+/// Whether an element with this tag name takes typed text, so single-key
+/// shortcuts must leave its key presses alone.
+#[must_use]
+pub fn is_text_entry(tag_name: &str) -> bool {
+    ["INPUT", "TEXTAREA", "SELECT"]
+        .iter()
+        .any(|tag| tag_name.eq_ignore_ascii_case(tag))
+}
+
+/// Tag name of the element a keyboard event was sent to.
+#[must_use]
+pub fn event_target_tag(e: &web_sys::KeyboardEvent) -> Option<String> {
+    e.target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .map(|element| element.tag_name())
+}
+
+/// Whether a keyboard event was sent to a text field or editable content.
+#[must_use]
+pub fn is_typing(e: &web_sys::KeyboardEvent) -> bool {
+    let Some(target) = e.target() else {
+        return false;
+    };
+    target
+        .dyn_ref::<web_sys::HtmlElement>()
+        .is_some_and(|element| is_text_entry(&element.tag_name()) || element.is_content_editable())
+}
+
+/// Whether a modal dialog is currently shown.
+#[must_use]
+pub fn is_modal_open() -> bool {
+    document()
+        .query_selector(".visible > [aria-modal=\"true\"]")
+        .ok()
+        .flatten()
+        .is_some()
+}
+
+/// Visible focusable elements inside a dialog, in document order.
+fn focusable_elements(dialog: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let Ok(nodes) = dialog.query_selector_all(FOCUSABLE) else {
+        return vec![];
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        // Hidden elements have no offset parent
+        .filter(|element| element.offset_parent().is_some())
+        .collect()
+}
+
+/// Whether an element is the dialog or inside it.
+fn contains(dialog: &web_sys::Element, element: &web_sys::Element) -> bool {
+    let node: &web_sys::Node = element;
+    dialog.contains(Some(node))
+}
+
+/// Keep Tab and Shift+Tab cycling within a dialog.
+fn trap_focus(dialog: &web_sys::Element, e: &web_sys::KeyboardEvent) {
+    let focusable = focusable_elements(dialog);
+    let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+        e.prevent_default();
+        return;
+    };
+    let (first_element, last_element): (&web_sys::Element, &web_sys::Element) = (first, last);
+    let active = document().active_element();
+    // Focus on the dialog itself counts as outside its controls
+    let inside = active
+        .as_ref()
+        .is_some_and(|a| a != dialog && contains(dialog, a));
+    let wrap_to = if e.shift_key() {
+        (!inside || active.as_ref() == Some(first_element)).then_some(last)
+    } else {
+        (!inside || active.as_ref() == Some(last_element)).then_some(first)
+    };
+    if let Some(element) = wrap_to {
+        e.prevent_default();
+        let _ = element.focus();
+    }
+}
+
+/// Keyboard handling for a modal dialog.
+///
+/// While the dialog is open, Escape runs `on_close` and Tab cycles through
+/// the dialog's controls. Focus moves to the dialog when it opens, so no
+/// control is triggered by a stray Enter or Space, and back to the
+/// previously focused element when it closes.
+pub fn use_dialog_keyboard(
+    is_open: Signal<bool>,
+    dialog_ref: NodeRef<html::Div>,
+    on_close: Callback<()>,
+) {
+    let previous_focus = StoredValue::new_local(None::<web_sys::Element>);
+
+    Effect::new(move |was_open: Option<bool>| {
+        let open = is_open.get();
+        if open && was_open != Some(true) {
+            previous_focus.set_value(document().active_element());
+            request_animation_frame(move || {
+                let Some(dialog) = dialog_ref.get_untracked() else {
+                    return;
+                };
+                // Leave focus alone if the dialog already moved it inside
+                let active = document().active_element();
+                if !active.is_some_and(|a| contains(&dialog, &a)) {
+                    let _ = dialog.set_attribute("tabindex", "-1");
+                    let _ = dialog.focus();
+                }
+            });
+        } else if !open && was_open == Some(true) {
+            let previous = previous_focus.get_value();
+            if let Some(element) = previous.and_then(|p| p.dyn_into::<web_sys::HtmlElement>().ok())
+            {
+                let _ = element.focus();
+            }
+        }
+        open
+    });
+
+    let handle = window_event_listener(ev::keydown, move |e| {
+        if !is_open.get_untracked() {
+            return;
+        }
+        match e.key().as_str() {
+            "Escape" => {
+                e.prevent_default();
+                on_close.run(());
+            }
+            "Tab" => {
+                if let Some(dialog) = dialog_ref.get_untracked() {
+                    trap_focus(&dialog, &e);
+                }
+            }
+            _ => {}
+        }
+    });
+    on_cleanup(move || handle.remove());
+}
+
+/// Overlay listing the keyboard shortcuts, opened with "?".
+#[component]
+
+pub fn ShortcutCheatsheet(
+    /// Whether the cheatsheet is open.
+    is_open: ReadSignal<bool>,
+    /// Callback when the cheatsheet is closed.
+    on_close: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let dialog_ref = NodeRef::<html::Div>::new();
+    use_dialog_keyboard(is_open.into(), dialog_ref, on_close);
+
+    let mut groups: Vec<&str> = SHORTCUT_HELP.iter().map(|(group, _, _)| *group).collect();
+    groups.dedup();
+
+    view! {
+        <div
+            class="shortcut-cheatsheet-overlay"
+            class:visible=move || is_open.get()
+            on:click=move |_| on_close.run(())
+        >
+            <div
+                class="shortcut-cheatsheet"
+                node_ref=dialog_ref
+                on:click=move |e| e.stop_propagation()
+                role="dialog"
+                aria-modal="true"
+                aria-labelledby="shortcut-cheatsheet-title"
+            >
+                <div class="shortcut-cheatsheet-header">
+                    <h2 id="shortcut-cheatsheet-title">{move || i18n.t("shortcuts.title")}</h2>
+                    <button
+                        class="btn btn-ghost btn-icon"
+                        on:click=move |_| on_close.run(())
+                        aria-label=move || i18n.t("shortcuts.close")
+                    >
+                        <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
+                            <path d="M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 17.59 19 19 17.59 13.41 12z"/>
+                        </svg>
+                    </button>
+                </div>
+                <div class="shortcut-cheatsheet-body">
+                    {groups.into_iter().map(|group| view! {
+                        <section class="shortcut-group">
+                            <h3 class="shortcut-group-title">{move || i18n.t(group)}</h3>
+                            <dl class="shortcut-list">
+                                {SHORTCUT_HELP
+                                    .iter()
+                                    .filter(|(g, _, _)| *g == group)
+                                    .map(|(_, keys, description)| view! {
+                                        <div class="shortcut-row">
+                                            <dt><kbd>{*keys}</kbd></dt>
+                                            <dd>{move || i18n.t(description)}</dd>
+                                        </div>
+                                    })
+                                    .collect_view()}
+                            </dl>
+                        </section>
+                    }).collect_view()}
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(key: &str, code: &str) -> KeyPress {
+        KeyPress {
+            key: key.to_string(),
+            code: code.to_string(),
+            ..KeyPress::default()
+        }
+    }
+
+    #[test]
+    fn test_global_shortcut_single_keys() {
+        assert_eq!(
+            global_shortcut(&press("?", "Slash")),
+            Some(Shortcut::ShowShortcuts)
+        );
+        assert_eq!(
+            global_shortcut(&press("Delete", "Delete")),
+            Some(Shortcut::DeletePlaylist)
+        );
+        assert_eq!(global_shortcut(&press("d", "KeyD")), None);
+    }
+
+    #[test]
+    fn test_global_shortcut_modifiers() {
+        let settings = KeyPress {
+            meta: true,
+            ..press(",", "Comma")
+        };
+        assert_eq!(global_shortcut(&settings), Some(Shortcut::OpenSettings));
+
+        let undo = KeyPress {
+            ctrl: true,
+            ..press("z", "KeyZ")
+        };
+        assert_eq!(global_shortcut(&undo), Some(Shortcut::Undo));
+        let redo = KeyPress {
+            ctrl: true,
+            ..press("Z", "KeyZ")
+        };
+        assert_eq!(global_shortcut(&redo), None);
+
+        // Alt changes the key value on some layouts, so sections match the code
+        let history = KeyPress {
+            alt: true,
+            ..press("™", "Digit2")
+        };
+        assert_eq!(global_shortcut(&history), Some(Shortcut::GoToHistory));
+
+        // Ctrl+Delete is left to the focused control
+        let ctrl_delete = KeyPress {
+            ctrl: true,
+            ..press("Delete", "Delete")
+        };
+        assert_eq!(global_shortcut(&ctrl_delete), None);
+    }
+
+    #[test]
+    fn test_is_text_entry() {
+        assert!(is_text_entry("INPUT"));
+        assert!(is_text_entry("textarea"));
+        assert!(!is_text_entry("BUTTON"));
+    }
+
+    #[test]
+    fn test_shortcut_help_groups_are_contiguous() {
+        // The cheatsheet renders one section per run of the same group
+        let mut seen: Vec<&str> = vec![];
+        for (group, _, _) in SHORTCUT_HELP {
+            if seen.last() != Some(group) {
+                assert!(!seen.contains(group), "{group} is split");
+                seen.push(group);
+            }
+        }
+    }
+}
@@ -0,0 +1,517 @@
+//! Storage page showing how much space the library, each playlist and the
+//! cache take, with actions to free or move that space.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::{OperationProgressDialog, RunningOperation, use_notifications};
+use crate::i18n::use_i18n;
+use crate::tauri_api;
+use crate::types::{MaintenanceOperation, PlaylistUsage, StorageOverview, TaskEvent, TaskPhase};
+
+/// Format bytes to human-readable string.
+fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+/// Share (0.0 - 100.0) of the library, the cache and the free space in the
+/// disk bar.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DiskSegments {
+    /// Playlists.
+    pub library: f64,
+    /// Cached metadata, thumbnails and temp files.
+    pub cache: f64,
+    /// Free disk space.
+    pub free: f64,
+}
+
+impl DiskSegments {
+    /// Split the bar between the library, the cache and the free space.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(overview: &StorageOverview) -> Self {
+        let library = overview.library.library_bytes;
+        let cache = overview.cache.total_size_bytes;
+        let free = overview.library.available_bytes.unwrap_or(0);
+        let total = library + cache + free;
+        if total == 0 {
+            return Self::default();
+        }
+        let share = |bytes: u64| bytes as f64 / total as f64 * 100.0;
+        Self {
+            library: share(library),
+            cache: share(cache),
+            free: share(free),
+        }
+    }
+}
+
+/// Width of each playlist's bar as a share (0.0 - 100.0) of the largest
+/// playlist.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn bar_widths(playlists: &[PlaylistUsage]) -> Vec<f64> {
+    let max = playlists.iter().map(|p| p.bytes).max().unwrap_or(0);
+    playlists
+        .iter()
+        .map(|p| {
+            if max == 0 {
+                0.0
+            } else {
+                p.bytes as f64 / max as f64 * 100.0
+            }
+        })
+        .collect()
+}
+
+/// Overview of the space used by the library and the cache.
+///
+/// Playlists can be archived out of the library, the cache cleared and the
+/// library moved to another directory.
+#[component]
+pub fn StorageView(
+    /// Callback when the back button is clicked.
+    on_back: Callback<()>,
+    /// Callback when playlists were archived or moved.
+    #[prop(optional)]
+    on_library_changed: Option<Callback<()>>,
+) -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+
+    let (overview, set_overview) = signal::<Option<StorageOverview>>(None);
+    let (error, set_error) = signal::<Option<String>>(None);
+    let (refresh, set_refresh) = signal(0_u32);
+    let (confirm_archive, set_confirm_archive) = signal::<Option<String>>(None);
+    let (running_operation, set_running_operation) = signal::<Option<RunningOperation>>(None);
+    let (directory, set_directory) = signal(String::new());
+    let (move_data, set_move_data) = signal(true);
+    let (moving, set_moving) = signal(false);
+
+    let library_changed = move || {
+        set_refresh.update(|n| *n += 1);
+        if let Some(callback) = on_library_changed {
+            callback.run(());
+        }
+    };
+
+    Effect::new(move || {
+        let _refresh = refresh.get();
+        spawn_local(async move {
+            match tauri_api::get_storage_overview().await {
+                Ok(o) => {
+                    // Keep whatever the user is typing
+                    if directory.with_untracked(String::is_empty) {
+                        set_directory.set(o.playlists_directory.clone());
+                    }
+                    set_error.set(None);
+                    set_overview.set(Some(o));
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    });
+
+    let clear_cache = move |_| {
+        let operation = MaintenanceOperation::ClearCache;
+        spawn_local(async move {
+            match tauri_api::start_maintenance(&operation).await {
+                Ok(task_id) => set_running_operation.set(Some(RunningOperation::maintenance(
+                    &operation,
+                    task_id,
+                    i18n.locale(),
+                ))),
+                Err(e) => {
+                    notifications.error(i18n.tf("storage.clear_cache_failed", &[("error", &e)]))
+                }
+            }
+        });
+    };
+
+    let archive = move |name: String| {
+        set_confirm_archive.set(None);
+        spawn_local(async move {
+            match tauri_api::archive_playlist(&name).await {
+                Ok(path) => {
+                    notifications
+                        .success(i18n.tf("storage.archived", &[("name", &name), ("path", &path)]));
+                    library_changed();
+                }
+                Err(e) => notifications
+                    .error(i18n.tf("storage.archive_failed", &[("name", &name), ("error", &e)])),
+            }
+        });
+    };
+
+    let change_directory = move |_| {
+        let path = directory.get_untracked().trim().to_string();
+        let move_data = move_data.get_untracked();
+        set_moving.set(true);
+        spawn_local(async move {
+            match tauri_api::set_storage_directory(&path, move_data).await {
+                Ok(Some(result)) => {
+                    notifications.success(i18n.tf(
+                        "storage.moved",
+                        &[
+                            ("files", &i18n.count("count.files", result.files_copied)),
+                            ("size", &format_bytes(result.bytes_copied)),
+                            ("path", &result.destination),
+                        ],
+                    ));
+                    library_changed();
+                }
+                Ok(None) => {
+                    notifications.success(i18n.tf("storage.directory_changed", &[("path", &path)]));
+                    library_changed();
+                }
+                Err(e) => notifications
+                    .error(i18n.tf("storage.directory_change_failed", &[("error", &e)])),
+            }
+            set_moving.set(false);
+        });
+    };
+
+    let directory_unchanged = move || {
+        let path = directory.with(|d| d.trim().to_string());
+        path.is_empty()
+            || overview.with(|o| o.as_ref().is_none_or(|o| o.playlists_directory == path))
+    };
+
+    let disk_section = move || {
+        let Some(o) = overview.get() else {
+            return view! { <p class="device-detail-muted">{i18n.t("storage.measuring")}</p> }
+                .into_any();
+        };
+        let segments = DiskSegments::new(&o);
+        let limit = o.library.limits.max_library_size_bytes.map(|limit| {
+            view! {
+                <p class="device-detail-muted">
+                    {i18n.tf("storage.library_limit", &[("size", &format_bytes(limit))])}
+                </p>
+            }
+        });
+        view! {
+            <div
+                class="device-usage-bar"
+                role="img"
+                aria-label=i18n.tf(
+                    "storage.disk_usage",
+                    &[
+                        ("playlists", &format!("{:.0}", segments.library)),
+                        ("cache", &format!("{:.0}", segments.cache)),
+                        ("free", &format!("{:.0}", segments.free)),
+                    ],
+                )
+            >
+                <div class="device-usage-music" style:width=format!("{:.1}%", segments.library)></div>
+                <div class="device-usage-other" style:width=format!("{:.1}%", segments.cache)></div>
+            </div>
+            <ul class="device-usage-legend">
+                <li class="music">
+                    {i18n.tf("storage.legend.playlists", &[("size", &format_bytes(o.library.library_bytes))])}
+                </li>
+                <li class="other">{i18n.tf("storage.legend.cache", &[("size", &format_bytes(o.cache.total_size_bytes))])}</li>
+                <li class="free">
+                    {o.library.available_bytes.map_or_else(
+                        || i18n.t("storage.legend.free_unknown"),
+                        |free| i18n.tf("storage.legend.free", &[("size", &format_bytes(free))]),
+                    )}
+                </li>
+            </ul>
+            {limit}
+            <p class="device-detail-muted">
+                {i18n.tf(
+                    "storage.min_free_space",
+                    &[("size", &format_bytes(o.library.limits.min_free_space_bytes))],
+                )}
+            </p>
+        }
+        .into_any()
+    };
+
+    let playlists_section = move || {
+        let Some(o) = overview.get() else {
+            return ().into_any();
+        };
+        if o.playlists.is_empty() {
+            return view! { <p class="device-detail-muted">{i18n.t("storage.no_playlists")}</p> }
+                .into_any();
+        }
+        let widths = bar_widths(&o.playlists);
+        view! {
+            <ul class="storage-playlist-list">
+                {o.playlists.into_iter().zip(widths).map(|(playlist, width)| {
+                    let name = playlist.name.clone();
+                    view! {
+                        <li class="storage-playlist">
+                            <div class="storage-playlist-main">
+                                <span class="storage-playlist-name">{playlist.name.clone()}</span>
+                                <span class="device-detail-muted">
+                                    {format!(
+                                        "{} · {}",
+                                        i18n.count("count.tracks", playlist.track_count),
+                                        format_bytes(playlist.bytes)
+                                    )}
+                                </span>
+                                <div class="storage-playlist-track">
+                                    <div
+                                        class="storage-playlist-bar"
+                                        style:width=format!("{width:.1}%")
+                                    ></div>
+                                </div>
+                            </div>
+                            <button
+                                class="btn btn-ghost btn-sm"
+                                on:click=move |_| set_confirm_archive.set(Some(name.clone()))
+                                aria-label=i18n.tf("storage.archive_playlist", &[("name", &playlist.name)])
+                            >
+                                {i18n.t("storage.archive")}
+                            </button>
+                        </li>
+                    }
+                }).collect_view()}
+            </ul>
+        }
+        .into_any()
+    };
+
+    let cache_section = move || {
+        let Some(o) = overview.get() else {
+            return ().into_any();
+        };
+        let cache = o.cache;
+        if !cache.enabled {
+            return view! { <p class="device-detail-muted">{i18n.t("storage.cache_off")}</p> }
+                .into_any();
+        }
+        view! {
+            <div
+                class="device-usage-bar"
+                role="img"
+                aria-label=i18n.tf("storage.cache_usage", &[("percent", &format!("{:.0}", cache.usage_percentage * 100.0))])
+            >
+                <div
+                    class="device-usage-other"
+                    style:width=format!("{:.1}%", cache.usage_percentage * 100.0)
+                ></div>
+            </div>
+            <p class="device-detail-muted">
+                {i18n.tf(
+                    "storage.cache_summary",
+                    &[
+                        ("used", &format_bytes(cache.total_size_bytes)),
+                        ("max", &format_bytes(cache.max_size_bytes)),
+                        ("metadata", &cache.metadata_entries.to_string()),
+                        ("thumbnails", &i18n.count("count.thumbnails", cache.thumbnail_entries)),
+                        ("temp", &i18n.count("count.temp_files", cache.temp_entries)),
+                    ],
+                )}
+            </p>
+        }
+        .into_any()
+    };
+
+    view! {
+        <div class="storage-view">
+            <div class="playlist-detail-header-top">
+                <button
+                    class="btn btn-ghost playlist-back-btn"
+                    on:click=move |_| on_back.run(())
+                    aria-label=i18n.t("common.go_back")
+                >
+                    <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
+                        <path d="M20 11H7.83l5.59-5.59L12 4l-8 8 8 8 1.41-1.41L7.83 13H20v-2z"/>
+                    </svg>
+                    {i18n.t("common.back")}
+                </button>
+                <div class="playlist-detail-actions">
+                    <button
+                        class="btn btn-secondary"
+                        on:click=move |_| set_refresh.update(|n| *n += 1)
+                    >
+                        {i18n.t("common.refresh")}
+                    </button>
+                </div>
+            </div>
+
+            <h1 class="device-detail-name">{i18n.t("nav.storage")}</h1>
+
+            {move || error.get().map(|e| view! { <p class="device-detail-error">{e}</p> })}
+
+            <section class="device-detail-section">
+                <h2>{i18n.t("storage.disk")}</h2>
+                {disk_section}
+            </section>
+
+            <section class="device-detail-section">
+                <h2>{i18n.t("playlists.title")}</h2>
+                {move || confirm_archive.get().map(|name| {
+                    let archive_directory = overview.with(|o| {
+                        o.as_ref().map(|o| o.archive_directory.clone()).unwrap_or_default()
+                    });
+                    let archived = name.clone();
+                    view! {
+                        <div class="device-clean-confirm">
+                            <p>
+                                {i18n.tf(
+                                    "storage.archive_confirm",
+                                    &[("name", &name), ("path", &archive_directory)],
+                                )}
+                            </p>
+                            <div class="device-clean-confirm-actions">
+                                <button
+                                    class="btn btn-secondary"
+                                    on:click=move |_| set_confirm_archive.set(None)
+                                >
+                                    {i18n.t("common.cancel")}
+                                </button>
+                                <button
+                                    class="btn btn-danger"
+                                    on:click=move |_| archive(archived.clone())
+                                >
+                                    {i18n.t("storage.archive")}
+                                </button>
+                            </div>
+                        </div>
+                    }
+                })}
+                {playlists_section}
+            </section>
+
+            <section class="device-detail-section">
+                <h2>{i18n.t("settings.cache")}</h2>
+                {cache_section}
+                <div>
+                    <button
+                        class="btn btn-secondary"
+                        on:click=clear_cache
+                        disabled=move || {
+                            overview.with(|o| o.as_ref().is_none_or(|o| o.cache.total_entries == 0))
+                        }
+                    >
+                        {i18n.t("settings.clear_cache")}
+                    </button>
+                </div>
+            </section>
+
+            <section class="device-detail-section">
+                <h2>{i18n.t("storage.directory")}</h2>
+                <p class="device-detail-muted">
+                    {i18n.t("storage.directory.description")}
+                </p>
+                <div class="settings-input-group">
+                    <input
+                        type="text"
+                        class="settings-input"
+                        aria-label=i18n.t("storage.directory")
+                        prop:value=move || directory.get()
+                        on:input=move |ev| set_directory.set(event_target_value(&ev))
+                        placeholder=i18n.t("storage.directory.placeholder")
+                        disabled=move || moving.get()
+                    />
+                    <button
+                        class="btn btn-primary"
+                        on:click=change_directory
+                        disabled=move || moving.get() || directory_unchanged()
+                    >
+                        {move || i18n.t(if moving.get() { "storage.moving" } else { "storage.change" })}
+                    </button>
+                </div>
+                <label class="storage-move-option">
+                    <input
+                        type="checkbox"
+                        checked=move || move_data.get()
+                        on:change=move |ev| set_move_data.set(event_target_checked(&ev))
+                        disabled=move || moving.get()
+                    />
+                    {i18n.t("storage.move_playlists")}
+                </label>
+            </section>
+        </div>
+
+        <OperationProgressDialog
+            operation=running_operation
+            on_close=Callback::new(move |()| set_running_operation.set(None))
+            on_finished=Callback::new(move |event: TaskEvent| {
+                if event.phase == TaskPhase::Completed {
+                    set_refresh.update(|n| *n += 1);
+                }
+            })
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CacheStats, LibraryLimits, LibrarySpace};
+
+    fn usage(name: &str, bytes: u64) -> PlaylistUsage {
+        PlaylistUsage {
+            name: name.to_string(),
+            bytes,
+            track_count: 1,
+        }
+    }
+
+    fn overview(library: u64, cache: u64, free: Option<u64>) -> StorageOverview {
+        StorageOverview {
+            library: LibrarySpace {
+                library_bytes: library,
+                available_bytes: free,
+                limits: LibraryLimits::default(),
+            },
+            playlists: Vec::new(),
+            cache: CacheStats {
+                total_entries: 1,
+                metadata_entries: 1,
+                thumbnail_entries: 0,
+                temp_entries: 0,
+                total_size_bytes: cache,
+                max_size_bytes: 1000,
+                usage_percentage: 0.0,
+                enabled: true,
+            },
+            playlists_directory: String::new(),
+            archive_directory: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_disk_segments() {
+        let segments = DiskSegments::new(&overview(300, 100, Some(600)));
+        assert!((segments.library - 30.0).abs() < f64::EPSILON);
+        assert!((segments.cache - 10.0).abs() < f64::EPSILON);
+        assert!((segments.free - 60.0).abs() < f64::EPSILON);
+
+        // Free space unknown
+        let segments = DiskSegments::new(&overview(300, 100, None));
+        assert!((segments.library - 75.0).abs() < f64::EPSILON);
+        assert_eq!(
+            DiskSegments::new(&overview(0, 0, None)),
+            DiskSegments::default()
+        );
+    }
+
+    #[test]
+    fn test_bar_widths() {
+        let widths = bar_widths(&[usage("a", 200), usage("b", 50), usage("c", 0)]);
+        assert!((widths[0] - 100.0).abs() < f64::EPSILON);
+        assert!((widths[1] - 25.0).abs() < f64::EPSILON);
+        assert!(widths[2].abs() < f64::EPSILON);
+        assert!(bar_widths(&[usage("a", 0)])[0].abs() < f64::EPSILON);
+    }
+}
@@ -3,6 +3,7 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
+use crate::i18n::use_i18n;
 use crate::tauri_api;
 use crate::types::{CapacityCheckResult, CapacityWarningLevel, DeviceInfo, PlaylistMetadata};
 
@@ -25,6 +26,7 @@ pub fn SyncButton(
     /// Whether a sync operation is currently in progress (as a signal for reactivity).
     syncing: ReadSignal<bool>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     // Signal to store capacity check result
     let (capacity_result, set_capacity_result) = signal::<Option<CapacityCheckResult>>(None);
     let (checking_capacity, set_checking_capacity) = signal(false);
@@ -64,13 +66,13 @@ pub fn SyncButton(
 
         match (device.is_some(), playlist.is_some()) {
             (false, false) => SyncButtonState::Disabled {
-                reason: "Connect a device and select a playlist to sync".to_string(),
+                reason: i18n.t("sync_button.need_both"),
             },
             (false, true) => SyncButtonState::Disabled {
-                reason: "Connect a USB device to sync".to_string(),
+                reason: i18n.t("sync_button.need_device"),
             },
             (true, false) => SyncButtonState::Disabled {
-                reason: "Select a playlist to sync".to_string(),
+                reason: i18n.t("sync_button.need_playlist"),
             },
             (true, true) => {
                 // Check capacity result
@@ -97,9 +99,9 @@ pub fn SyncButton(
 
     let tooltip = move || {
         if syncing.get() {
-            "Syncing in progress...".to_string()
+            i18n.t("sync_button.syncing.title")
         } else if checking_capacity.get() {
-            "Checking available space...".to_string()
+            i18n.t("sync_button.checking.title")
         } else {
             match button_state() {
                 SyncButtonState::Disabled { reason } => reason,
@@ -108,9 +110,14 @@ pub fn SyncButton(
                     let playlist = selected_playlist.get();
                     let device = selected_device.get();
                     match (playlist, device) {
-                        (Some(p), Some(d)) => {
-                            format!("Sync \"{}\" to {} ({})", p.name, d.name, capacity.message)
-                        }
+                        (Some(p), Some(d)) => i18n.tf(
+                            "sync_button.sync_with_warning",
+                            &[
+                                ("name", &p.name),
+                                ("device", &d.name),
+                                ("warning", &capacity.message),
+                            ],
+                        ),
                         _ => capacity.message,
                     }
                 }
@@ -118,8 +125,11 @@ pub fn SyncButton(
                     let playlist = selected_playlist.get();
                     let device = selected_device.get();
                     match (playlist, device) {
-                        (Some(p), Some(d)) => format!("Sync \"{}\" to {}", p.name, d.name),
-                        _ => "Sync playlist to device".to_string(),
+                        (Some(p), Some(d)) => i18n.tf(
+                            "sync_button.sync",
+                            &[("name", &p.name), ("device", &d.name)],
+                        ),
+                        _ => i18n.t("sync_button.sync_default"),
                     }
                 }
             }
@@ -158,19 +168,19 @@ pub fn SyncButton(
                     if syncing.get() {
                         view! {
                             <span class="spinner"></span>
-                            <span>"Syncing..."</span>
+                            <span>{i18n.t("sync_button.syncing")}</span>
                         }.into_any()
                     } else if checking_capacity.get() {
                         view! {
                             <span class="spinner"></span>
-                            <span>"Checking space..."</span>
+                            <span>{i18n.t("sync_button.checking")}</span>
                         }.into_any()
                     } else {
                         view! {
                             <svg viewBox="0 0 24 24" width="20" height="20" fill="currentColor" class="sync-icon">
                                 <path d="M19 8l-4 4h3c0 3.31-2.69 6-6 6-1.01 0-1.97-.25-2.8-.7l-1.46 1.46C8.97 19.54 10.43 20 12 20c4.42 0 8-3.58 8-8h3l-4-4zM6 12c0-3.31 2.69-6 6-6 1.01 0 1.97.25 2.8.7l1.46-1.46C15.03 4.46 13.57 4 12 4c-4.42 0-8 3.58-8 8H1l4 4 4-4H6z"/>
                             </svg>
-                            <span>"Sync to Device"</span>
+                            <span>{i18n.t("app.sync_to_device")}</span>
                         }.into_any()
                     }
                 }}
@@ -186,7 +196,16 @@ pub fn SyncButton(
                                 </svg>
                                 <span>{capacity.message.clone()}</span>
                                 <span class="capacity-details">
-                                    {format!(" (need {}, have {})", capacity.formatted_required(), capacity.formatted_available())}
+                                    {format!(
+                                        " {}",
+                                        i18n.tf(
+                                            "sync_button.need_have",
+                                            &[
+                                                ("required", &capacity.formatted_required()),
+                                                ("available", &capacity.formatted_available()),
+                                            ],
+                                        )
+                                    )}
                                 </span>
                             </div>
                         }.into_any())
@@ -197,7 +216,12 @@ pub fn SyncButton(
                                 <svg viewBox="0 0 24 24" width="14" height="14" fill="currentColor">
                                     <path d="M1 21h22L12 2 1 21zm12-3h-2v-2h2v2zm0-4h-2v-4h2v4z"/>
                                 </svg>
-                                <span>{format!("Low space: {:.0}% full after sync", capacity.usage_after_sync_percent)}</span>
+                                <span>
+                                    {i18n.tf(
+                                        "sync_button.low_space",
+                                        &[("percent", &format!("{:.0}", capacity.usage_after_sync_percent))],
+                                    )}
+                                </span>
                             </div>
                         }.into_any())
                     }
@@ -0,0 +1,201 @@
+//! Dialog for choosing which device files a sync may overwrite or delete.
+
+use std::collections::BTreeSet;
+
+use leptos::html;
+use leptos::prelude::*;
+
+use crate::components::use_dialog_keyboard;
+use crate::i18n::use_i18n;
+use crate::types::{SyncChange, SyncPreview, SyncPreviewEntry};
+
+/// Entries of `preview` that replace or delete a file already on the device.
+#[must_use]
+pub fn sync_conflicts(preview: &SyncPreview) -> Vec<SyncPreviewEntry> {
+    preview
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry.change, SyncChange::Replace | SyncChange::Remove))
+        .cloned()
+        .collect()
+}
+
+/// Lists the device files a sync would overwrite or delete, with a keep or
+/// replace choice for each.
+///
+/// Every file is replaced unless the user chooses to keep it. Confirming
+/// passes the names of the kept files back, so they can be excluded from the
+/// sync.
+#[component]
+pub fn SyncConflictDialog(
+    /// Whether the dialog is open.
+    is_open: ReadSignal<bool>,
+    /// Files the sync would overwrite or delete.
+    #[prop(into)]
+    conflicts: Signal<Vec<SyncPreviewEntry>>,
+    /// Callback with the files to keep, relative to the mount point.
+    on_confirm: Callback<Vec<String>>,
+    /// Callback when the dialog is closed without syncing.
+    on_cancel: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let kept = RwSignal::new(BTreeSet::<String>::new());
+    let dialog_ref = NodeRef::<html::Div>::new();
+    use_dialog_keyboard(is_open.into(), dialog_ref, on_cancel);
+
+    // Start from the planned changes each time the dialog opens
+    Effect::new(move || {
+        if is_open.get() {
+            kept.set(BTreeSet::new());
+        }
+    });
+
+    let set_kept = move |file_name: String, keep: bool| {
+        kept.update(|kept| {
+            if keep {
+                kept.insert(file_name);
+            } else {
+                kept.remove(&file_name);
+            }
+        });
+    };
+    let keep_all = move |_| {
+        kept.set(
+            conflicts
+                .get_untracked()
+                .into_iter()
+                .map(|entry| entry.file_name)
+                .collect(),
+        );
+    };
+    let replace_all = move |_| kept.set(BTreeSet::new());
+    let confirm = move |_| on_confirm.run(kept.get_untracked().into_iter().collect());
+
+    let summary = move || {
+        let total = conflicts.with(Vec::len);
+        let keeping = kept.with(BTreeSet::len);
+        i18n.tf(
+            "sync_conflict.summary",
+            &[
+                ("keeping", &keeping.to_string()),
+                ("total", &total.to_string()),
+            ],
+        )
+    };
+
+    view! {
+        <div
+            class="confirm-dialog-overlay sync-conflict-overlay"
+            class:visible=move || is_open.get()
+            on:click=move |_| on_cancel.run(())
+        >
+            <div
+                class="confirm-dialog sync-conflict-dialog"
+                node_ref=dialog_ref
+                on:click=move |e| e.stop_propagation()
+                role="dialog"
+                aria-modal="true"
+                aria-labelledby="sync-conflict-title"
+                aria-describedby="sync-conflict-message"
+            >
+                <h3 id="sync-conflict-title" class="confirm-dialog-title">
+                    {i18n.t("sync_conflict.title")}
+                </h3>
+                <p id="sync-conflict-message" class="confirm-dialog-message">
+                    {i18n.t("sync_conflict.message")}
+                </p>
+                <div class="sync-conflict-bulk">
+                    <span class="sync-conflict-summary">{summary}</span>
+                    <button class="btn btn-ghost btn-sm" on:click=keep_all>{i18n.t("sync_conflict.keep_all")}</button>
+                    <button class="btn btn-ghost btn-sm" on:click=replace_all>{i18n.t("sync_conflict.replace_all")}</button>
+                </div>
+                <ul class="sync-conflict-list">
+                    <For
+                        each=move || conflicts.get()
+                        key=|entry| entry.file_name.clone()
+                        children=move |entry| {
+                            let removal = entry.change == SyncChange::Remove;
+                            let file_name = entry.file_name.clone();
+                            let is_kept = Memo::new(move |_| {
+                                kept.with(|kept| kept.contains(&file_name))
+                            });
+                            let keep_name = entry.file_name.clone();
+                            let replace_name = entry.file_name.clone();
+                            let detail = entry.playlist.map_or_else(
+                                || i18n.t("sync_conflict.not_selected"),
+                                |p| i18n.tf("sync_conflict.from", &[("playlist", &p)]),
+                            );
+                            view! {
+                                <li class="sync-conflict-item" class:removal=removal>
+                                    <div class="sync-conflict-file">
+                                        <span class="sync-conflict-name">{entry.file_name}</span>
+                                        <span class="sync-conflict-detail">{detail}</span>
+                                    </div>
+                                    <div class="sync-conflict-choice" role="group" aria-label=i18n.t("sync_conflict.choice")>
+                                        <button
+                                            class="btn btn-sm"
+                                            class:active=move || is_kept.get()
+                                            aria-pressed=move || is_kept.get().to_string()
+                                            on:click=move |_| set_kept(keep_name.clone(), true)
+                                        >
+                                            {i18n.t("sync_wizard.change.keep")}
+                                        </button>
+                                        <button
+                                            class="btn btn-sm"
+                                            class:active=move || !is_kept.get()
+                                            aria-pressed=move || (!is_kept.get()).to_string()
+                                            on:click=move |_| set_kept(replace_name.clone(), false)
+                                        >
+                                            {i18n.t(if removal { "common.delete" } else { "sync_wizard.change.replace" })}
+                                        </button>
+                                    </div>
+                                </li>
+                            }
+                        }
+                    />
+                </ul>
+                <div class="confirm-dialog-actions">
+                    <button class="btn btn-secondary" on:click=move |_| on_cancel.run(())>
+                        {i18n.t("common.cancel")}
+                    </button>
+                    <button class="btn btn-primary" on:click=confirm>
+                        {i18n.t("sync_wizard.start")}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(file_name: &str, change: SyncChange) -> SyncPreviewEntry {
+        SyncPreviewEntry {
+            playlist: None,
+            file_name: file_name.to_string(),
+            size_bytes: 1,
+            change,
+        }
+    }
+
+    #[test]
+    fn test_sync_conflicts() {
+        let preview = SyncPreview {
+            entries: vec![
+                entry("new.mp3", SyncChange::Add),
+                entry("changed.mp3", SyncChange::Replace),
+                entry("same.mp3", SyncChange::Unchanged),
+                entry("stale.mp3", SyncChange::Remove),
+            ],
+            ..SyncPreview::default()
+        };
+
+        let names: Vec<_> = sync_conflicts(&preview)
+            .into_iter()
+            .map(|entry| entry.file_name)
+            .collect();
+        assert_eq!(names, ["changed.mp3", "stale.mp3"]);
+    }
+}
@@ -0,0 +1,812 @@
+//! Sync wizard for choosing a device and playlists, reviewing the changes and
+//! running the sync.
+
+use std::collections::BTreeSet;
+
+use leptos::html;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::{
+    SyncConflictDialog, sync_conflicts, use_dialog_keyboard, use_notifications,
+};
+use crate::i18n::use_i18n;
+use crate::tauri_api::{self, SyncOrchestratorProgress, SyncOrchestratorResult};
+use crate::types::{
+    CapacityCheckResult, DeviceInfo, PlaylistMetadata, SyncChange, SyncPreview, TaskId,
+};
+
+/// Format bytes to human-readable string.
+fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+/// Steps of the sync wizard, in order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WizardStep {
+    /// Choose the device to sync to.
+    Device,
+    /// Choose the playlists to put on it.
+    Playlists,
+    /// Review the changes and the space they need.
+    Preview,
+    /// Sync in progress or finished.
+    Running,
+}
+
+impl WizardStep {
+    /// All steps, in order.
+    pub const ALL: [Self; 4] = [Self::Device, Self::Playlists, Self::Preview, Self::Running];
+
+    /// Message key of the short title for the step indicator.
+    #[must_use]
+    pub const fn title_key(self) -> &'static str {
+        match self {
+            Self::Device => "sync_wizard.step.device",
+            Self::Playlists => "sync_wizard.step.playlists",
+            Self::Preview => "sync_wizard.step.review",
+            Self::Running => "sync_wizard.step.sync",
+        }
+    }
+
+    /// Position of the step, starting at 1.
+    #[must_use]
+    pub const fn number(self) -> usize {
+        match self {
+            Self::Device => 1,
+            Self::Playlists => 2,
+            Self::Preview => 3,
+            Self::Running => 4,
+        }
+    }
+
+    /// The step Back returns to, if going back is allowed.
+    #[must_use]
+    pub const fn previous(self) -> Option<Self> {
+        match self {
+            Self::Device | Self::Running => None,
+            Self::Playlists => Some(Self::Device),
+            Self::Preview => Some(Self::Playlists),
+        }
+    }
+}
+
+/// How a device's capacity splits up after a sync, for the capacity bar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CapacitySegments {
+    /// Share of the device taken by files the sync keeps (0.0 - 100.0).
+    pub kept_percent: f64,
+    /// Share of the device taken by the files the sync writes (0.0 - 100.0).
+    pub incoming_percent: f64,
+    /// Whether everything the sync writes fits.
+    pub fits: bool,
+}
+
+impl CapacitySegments {
+    /// Split a device with `total_bytes` capacity and `available_bytes` free
+    /// after applying `preview`.
+    ///
+    /// Space taken by removed files and by the old copies of replaced tracks
+    /// counts as free.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(total_bytes: u64, available_bytes: u64, preview: &SyncPreview) -> Self {
+        if total_bytes == 0 {
+            return Self {
+                kept_percent: 0.0,
+                incoming_percent: 0.0,
+                fits: preview.bytes_to_write == 0,
+            };
+        }
+        let kept = total_bytes
+            .saturating_sub(available_bytes)
+            .saturating_sub(preview.bytes_to_remove)
+            .saturating_sub(preview.bytes_replaced);
+        let free = total_bytes - kept;
+        let incoming = preview.bytes_to_write.min(free);
+        let percent = |bytes: u64| bytes as f64 / total_bytes as f64 * 100.0;
+        Self {
+            kept_percent: percent(kept),
+            incoming_percent: percent(incoming),
+            fits: preview.bytes_to_write <= free,
+        }
+    }
+}
+
+/// Message key of the label for a group of changes.
+const fn change_key(change: SyncChange) -> &'static str {
+    match change {
+        SyncChange::Add => "sync_wizard.change.add",
+        SyncChange::Replace => "sync_wizard.change.replace",
+        SyncChange::Unchanged => "sync_wizard.change.keep",
+        SyncChange::Remove => "sync_wizard.change.remove",
+    }
+}
+
+/// A group of preview entries with the same change, shown collapsed.
+#[component]
+fn PreviewGroup(
+    /// The change shared by the entries.
+    change: SyncChange,
+    /// The preview to take entries from.
+    preview: SyncPreview,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let entries: Vec<_> = preview
+        .entries
+        .into_iter()
+        .filter(|entry| entry.change == change)
+        .collect();
+    if entries.is_empty() {
+        return None;
+    }
+    let class = match change {
+        SyncChange::Add => "sync-wizard-group add",
+        SyncChange::Replace => "sync-wizard-group replace",
+        SyncChange::Unchanged => "sync-wizard-group unchanged",
+        SyncChange::Remove => "sync-wizard-group remove",
+    };
+    let count = entries.len();
+    let bytes: u64 = entries.iter().map(|entry| entry.size_bytes).sum();
+
+    Some(view! {
+        <details class=class>
+            <summary>
+                <span class="sync-wizard-group-label">{move || i18n.t(change_key(change))}</span>
+                <span class="sync-wizard-group-count">
+                    {move || format!("{} · {}", i18n.count("count.files", count), format_bytes(bytes))}
+                </span>
+            </summary>
+            <ul class="sync-wizard-group-files">
+                {entries.into_iter().map(|entry| view! {
+                    <li>
+                        <span class="sync-wizard-file-name">{entry.file_name}</span>
+                        {entry.playlist.map(|p| view! {
+                            <span class="sync-wizard-file-playlist">{p}</span>
+                        })}
+                    </li>
+                }).collect_view()}
+            </ul>
+        </details>
+    })
+}
+
+/// Multi-step sync flow: pick a device, pick playlists, review the diff and
+/// capacity, then run the sync with live progress.
+///
+/// Runs the sync orchestrator, so several playlists go to the device in one
+/// task and the device can be cleaned of other files first.
+#[component]
+
+pub fn SyncWizard(
+    /// Whether the wizard is open.
+    is_open: ReadSignal<bool>,
+    /// Connected devices.
+    devices: ReadSignal<Vec<DeviceInfo>>,
+    /// All playlists in the library.
+    playlists: ReadSignal<Vec<PlaylistMetadata>>,
+    /// Device to preselect when the wizard opens.
+    initial_device: ReadSignal<Option<DeviceInfo>>,
+    /// Playlist to preselect when the wizard opens.
+    initial_playlist: ReadSignal<Option<PlaylistMetadata>>,
+    /// Playlists to preselect instead of `initial_playlist`, when not empty.
+    #[prop(optional, into)]
+    initial_selection: Option<Signal<Vec<String>>>,
+    /// Callback when the wizard is closed.
+    on_close: Callback<()>,
+) -> impl IntoView {
+    let notifications = use_notifications();
+    let i18n = use_i18n();
+
+    let (step, set_step) = signal(WizardStep::Device);
+    let (device, set_device) = signal::<Option<DeviceInfo>>(None);
+    let (selected, set_selected) = signal(BTreeSet::<String>::new());
+    let (cleanup_enabled, set_cleanup_enabled) = signal(true);
+    let (preview, set_preview) = signal::<Option<SyncPreview>>(None);
+    let (capacity, set_capacity) = signal::<Option<CapacityCheckResult>>(None);
+    let (loading, set_loading) = signal(false);
+    let (error, set_error) = signal::<Option<String>>(None);
+    let (task_id, set_task_id) = signal::<Option<TaskId>>(None);
+    let (progress, set_progress) = signal::<Option<SyncOrchestratorProgress>>(None);
+    let (outcome, set_outcome) = signal::<Option<SyncOrchestratorResult>>(None);
+    let (conflicts_open, set_conflicts_open) = signal(false);
+
+    // Start over with the current selection each time the wizard opens,
+    // unless a sync is still running
+    Effect::new(move || {
+        if !is_open.get() || task_id.get_untracked().is_some() {
+            return;
+        }
+        let initial = initial_device.get_untracked();
+        set_step.set(if initial.is_some() {
+            WizardStep::Playlists
+        } else {
+            WizardStep::Device
+        });
+        set_device.set(initial);
+        let preselected = initial_selection
+            .map(|names| names.get_untracked())
+            .filter(|names| !names.is_empty());
+        set_selected.set(preselected.map_or_else(
+            || {
+                initial_playlist
+                    .get_untracked()
+                    .map(|p| p.name)
+                    .into_iter()
+                    .collect()
+            },
+            |names| names.into_iter().collect(),
+        ));
+        set_preview.set(None);
+        set_capacity.set(None);
+        set_error.set(None);
+        set_progress.set(None);
+        set_outcome.set(None);
+        set_conflicts_open.set(false);
+    });
+
+    // Follow the running sync
+    spawn_local(async move {
+        let finish = move |result: SyncOrchestratorResult| {
+            if task_id.get_untracked().is_none() {
+                return;
+            }
+            set_task_id.set(None);
+            set_outcome.set(Some(result));
+        };
+        if let Err(e) = tauri_api::listen_to_sync_orchestrator_progress(move |p| {
+            if task_id.get_untracked().is_some() {
+                set_progress.set(Some(p));
+            }
+        })
+        .await
+        {
+            leptos::logging::error!("Failed to listen for sync progress: {}", e);
+        }
+        if let Err(e) = tauri_api::listen_to_sync_orchestrator_completed(move |result| {
+            if task_id.get_untracked().is_some() {
+                let transferred = result.total_files_transferred;
+                notifications.success(i18n.count("sync_wizard.synced", transferred));
+                notifications
+                    .announce(i18n.count("sync_wizard.finished_announcement", transferred));
+            }
+            finish(result);
+        })
+        .await
+        {
+            leptos::logging::error!("Failed to listen for sync completion: {}", e);
+        }
+        if let Err(e) = tauri_api::listen_to_sync_orchestrator_failed(move |result| {
+            if task_id.get_untracked().is_some() {
+                let reason = result
+                    .error_message
+                    .clone()
+                    .unwrap_or_else(|| i18n.t("common.unknown_error"));
+                notifications.error(i18n.tf("sync_wizard.failed", &[("error", &reason)]));
+                notifications
+                    .announce(i18n.tf("sync_wizard.failed_announcement", &[("error", &reason)]));
+            }
+            finish(result);
+        })
+        .await
+        {
+            leptos::logging::error!("Failed to listen for sync failures: {}", e);
+        }
+        if let Err(e) = tauri_api::listen_to_sync_orchestrator_cancelled(finish).await {
+            leptos::logging::error!("Failed to listen for sync cancellation: {}", e);
+        }
+    });
+
+    let load_preview = move || {
+        let (Some(device), playlists) = (device.get_untracked(), selected.get_untracked()) else {
+            return;
+        };
+        let playlists: Vec<String> = playlists.into_iter().collect();
+        let cleanup = cleanup_enabled.get_untracked();
+        set_loading.set(true);
+        set_error.set(None);
+        set_preview.set(None);
+        set_capacity.set(None);
+        spawn_local(async move {
+            let preview =
+                tauri_api::preview_sync(playlists.clone(), &device.mount_point, cleanup, true)
+                    .await;
+            let capacity = tauri_api::check_sync_capacity(playlists, &device.mount_point).await;
+            match (preview, capacity) {
+                (Ok(preview), Ok(capacity)) => {
+                    set_preview.set(Some(preview));
+                    set_capacity.set(Some(capacity));
+                }
+                (Err(e), _) | (_, Err(e)) => set_error.set(Some(e)),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let start_sync = move |exclusions: Vec<String>| {
+        let Some(device) = device.get_untracked() else {
+            return;
+        };
+        let playlists: Vec<String> = selected.get_untracked().into_iter().collect();
+        let cleanup = cleanup_enabled.get_untracked();
+        set_loading.set(true);
+        set_error.set(None);
+        spawn_local(async move {
+            match tauri_api::start_orchestrated_sync(
+                playlists,
+                &device.mount_point,
+                cleanup,
+                true,
+                true,
+                exclusions,
+            )
+            .await
+            {
+                Ok(id) => {
+                    set_progress.set(None);
+                    set_outcome.set(None);
+                    set_task_id.set(Some(id));
+                    set_step.set(WizardStep::Running);
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let can_continue = move || {
+        !loading.get()
+            && match step.get() {
+                WizardStep::Device => device.with(Option::is_some),
+                WizardStep::Playlists => selected.with(|s| !s.is_empty()),
+                WizardStep::Preview => preview.with(|p| {
+                    p.as_ref().is_some_and(|p| {
+                        capacity.get().is_some_and(|c| {
+                            CapacitySegments::new(c.total_bytes, c.available_bytes, p).fits
+                        })
+                    })
+                }),
+                WizardStep::Running => false,
+            }
+    };
+
+    let on_next = move |_| match step.get_untracked() {
+        WizardStep::Device => set_step.set(WizardStep::Playlists),
+        WizardStep::Playlists => {
+            set_step.set(WizardStep::Preview);
+            load_preview();
+        }
+        // Let the user pick files to keep before anything is overwritten
+        WizardStep::Preview => {
+            let has_conflicts = preview
+                .with_untracked(|p| p.as_ref().is_some_and(|p| !sync_conflicts(p).is_empty()));
+            if has_conflicts {
+                set_conflicts_open.set(true);
+            } else {
+                start_sync(Vec::new());
+            }
+        }
+        WizardStep::Running => {}
+    };
+
+    let on_back = move |_| {
+        if let Some(previous) = step.get_untracked().previous() {
+            set_error.set(None);
+            set_step.set(previous);
+        }
+    };
+
+    let on_cancel_sync = move |_| {
+        if let Some(id) = task_id.get_untracked() {
+            spawn_local(async move {
+                if let Err(e) = tauri_api::cancel_sync(id).await {
+                    notifications.error(i18n.tf("sync_wizard.cancel_failed", &[("error", &e)]));
+                }
+            });
+        }
+    };
+
+    // The wizard can be closed while syncing; the sync keeps going and the
+    // progress is there again when it reopens
+    let close = move || on_close.run(());
+    let dialog_ref = NodeRef::<html::Div>::new();
+    // The conflict dialog handles the keyboard while it is open on top
+    use_dialog_keyboard(
+        Signal::derive(move || is_open.get() && !conflicts_open.get()),
+        dialog_ref,
+        on_close,
+    );
+    let conflicts = Signal::derive(move || {
+        preview.with(|p| p.as_ref().map(sync_conflicts).unwrap_or_default())
+    });
+    let on_conflicts_confirm = Callback::new(move |kept: Vec<String>| {
+        set_conflicts_open.set(false);
+        start_sync(kept);
+    });
+    let on_conflicts_cancel = Callback::new(move |()| set_conflicts_open.set(false));
+
+    let device_step = move || {
+        let list = devices.get();
+        if list.is_empty() {
+            return view! {
+                <p class="sync-wizard-empty">{i18n.t("sync_wizard.no_devices")}</p>
+            }
+            .into_any();
+        }
+        view! {
+            <div class="sync-wizard-options">
+                {list.into_iter().map(|d| {
+                    let mount_point = d.mount_point.clone();
+                    let is_selected = move || {
+                        device.with(|sel| sel.as_ref().is_some_and(|s| s.mount_point == mount_point))
+                    };
+                    let space = i18n.tf(
+                        "sync_wizard.device_space",
+                        &[
+                            ("free", &format_bytes(d.available_bytes)),
+                            ("total", &format_bytes(d.total_bytes)),
+                        ],
+                    );
+                    let name = d.name.clone();
+                    view! {
+                        <button
+                            class="sync-wizard-option"
+                            class:selected=is_selected
+                            on:click=move |_| set_device.set(Some(d.clone()))
+                        >
+                            <span class="sync-wizard-option-title">{name}</span>
+                            <span class="sync-wizard-option-detail">{space}</span>
+                        </button>
+                    }
+                }).collect_view()}
+            </div>
+        }
+        .into_any()
+    };
+
+    let playlists_step = move || {
+        let list = playlists.get();
+        let summary = move || {
+            let (count, bytes) = playlists.with(|list| {
+                selected.with(|selected| {
+                    let bytes: u64 = list
+                        .iter()
+                        .filter(|p| selected.contains(&p.name))
+                        .map(|p| p.total_bytes)
+                        .sum();
+                    (selected.len(), bytes)
+                })
+            });
+            i18n.tf(
+                "sync_wizard.selection",
+                &[
+                    ("count", &count.to_string()),
+                    ("size", &format_bytes(bytes)),
+                ],
+            )
+        };
+        view! {
+            <div class="sync-wizard-options">
+                {list.into_iter().map(|p| {
+                    let name = p.name.clone();
+                    let name_for_toggle = p.name.clone();
+                    let detail = format!(
+                        "{} · {}",
+                        i18n.count("count.tracks", p.track_count),
+                        format_bytes(p.total_bytes)
+                    );
+                    view! {
+                        <label class="sync-wizard-option checkbox">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || selected.with(|s| s.contains(&name))
+                                on:change=move |_| {
+                                    let name = name_for_toggle.clone();
+                                    set_selected.update(|s| {
+                                        if !s.remove(&name) {
+                                            s.insert(name);
+                                        }
+                                    });
+                                }
+                            />
+                            <span class="sync-wizard-option-title">{p.name}</span>
+                            <span class="sync-wizard-option-detail">{detail}</span>
+                        </label>
+                    }
+                }).collect_view()}
+            </div>
+            <div class="sync-wizard-summary">
+                {summary}
+            </div>
+            <label class="sync-wizard-toggle">
+                <input
+                    type="checkbox"
+                    prop:checked=move || cleanup_enabled.get()
+                    on:change=move |_| set_cleanup_enabled.update(|c| *c = !*c)
+                />
+                {i18n.t("sync_wizard.cleanup")}
+            </label>
+        }
+        .into_any()
+    };
+
+    let preview_step = move || {
+        if loading.get() {
+            return view! { <p class="sync-wizard-empty">{i18n.t("sync_wizard.comparing")}</p> }
+                .into_any();
+        }
+        let (Some(preview), Some(capacity)) = (preview.get(), capacity.get()) else {
+            return view! { <p class="sync-wizard-empty">{i18n.t("sync_wizard.no_preview")}</p> }
+                .into_any();
+        };
+        let segments =
+            CapacitySegments::new(capacity.total_bytes, capacity.available_bytes, &preview);
+        let summary = i18n.tf(
+            "sync_wizard.preview_summary",
+            &[
+                ("write", &format_bytes(preview.bytes_to_write)),
+                ("remove", &format_bytes(preview.bytes_to_remove)),
+            ],
+        );
+        let capacity_note = if !segments.fits {
+            Some(capacity.message.clone())
+        } else if !capacity.can_fit {
+            Some(i18n.t("sync_wizard.fits_after_cleanup"))
+        } else {
+            None
+        };
+        view! {
+            <div class="sync-wizard-capacity" class:over=!segments.fits>
+                <div
+                    class="sync-wizard-capacity-bar"
+                    role="img"
+                    aria-label=i18n.tf(
+                        "sync_wizard.capacity_label",
+                        &[
+                            ("kept", &format!("{:.0}", segments.kept_percent)),
+                            ("incoming", &format!("{:.0}", segments.incoming_percent)),
+                        ],
+                    )
+                >
+                    <div
+                        class="sync-wizard-capacity-kept"
+                        style:width=format!("{:.1}%", segments.kept_percent)
+                    ></div>
+                    <div
+                        class="sync-wizard-capacity-incoming"
+                        style:width=format!("{:.1}%", segments.incoming_percent)
+                    ></div>
+                </div>
+                <div class="sync-wizard-capacity-legend">
+                    <span class="kept">{i18n.t("sync_wizard.kept")}</span>
+                    <span class="incoming">{i18n.t("sync_wizard.incoming")}</span>
+                    <span>{summary}</span>
+                </div>
+                {capacity_note.map(|note| view! {
+                    <p class="sync-wizard-capacity-note">{note}</p>
+                })}
+            </div>
+            <div class="sync-wizard-groups">
+                <PreviewGroup change=SyncChange::Add preview=preview.clone() />
+                <PreviewGroup change=SyncChange::Replace preview=preview.clone() />
+                <PreviewGroup change=SyncChange::Unchanged preview=preview.clone() />
+                <PreviewGroup change=SyncChange::Remove preview=preview />
+            </div>
+        }
+        .into_any()
+    };
+
+    let running_step = move || {
+        if let Some(result) = outcome.get() {
+            let (class, title) = if result.success {
+                ("sync-wizard-outcome success", "sync_wizard.outcome.success")
+            } else if result.was_cancelled {
+                ("sync-wizard-outcome", "sync_wizard.outcome.cancelled")
+            } else {
+                ("sync-wizard-outcome failed", "sync_wizard.outcome.failed")
+            };
+            return view! {
+                <div class=class>
+                    <h3>{i18n.t(title)}</h3>
+                    <p>
+                        {i18n.tf(
+                            "sync_wizard.outcome.counts",
+                            &[
+                                ("copied", &result.total_files_transferred.to_string()),
+                                ("skipped", &result.total_files_skipped.to_string()),
+                                ("failed", &result.total_files_failed.to_string()),
+                            ],
+                        )}
+                    </p>
+                    {result.error_message.map(|e| view! { <p class="sync-wizard-error">{e}</p> })}
+                </div>
+            }
+            .into_any();
+        }
+        let current = progress.get();
+        let percent = current.as_ref().map_or(0.0, |p| p.overall_progress_percent);
+        let message = current
+            .as_ref()
+            .map_or_else(|| i18n.t("sync_wizard.starting"), |p| p.message.clone());
+        let detail = current.map(|p| {
+            let playlist = p.current_playlist.as_ref().map(|name| {
+                format!(
+                    "{name} ({}/{})",
+                    p.current_playlist_index, p.total_playlists
+                )
+            });
+            let eta = p
+                .formatted_eta()
+                .map(|eta| i18n.tf("common.time_left", &[("time", &eta)]));
+            [playlist, Some(p.formatted_speed()), eta]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" · ")
+        });
+        view! {
+            <div class="sync-wizard-progress">
+                <div
+                    class="sync-wizard-progress-bar"
+                    role="progressbar"
+                    aria-label=i18n.t("sync_wizard.progress")
+                    aria-valuemin="0"
+                    aria-valuemax="100"
+                    aria-valuenow=format!("{percent:.0}")
+                >
+                    <div class="sync-wizard-progress-fill" style:width=format!("{percent:.0}%")></div>
+                </div>
+                <p class="sync-wizard-progress-message">{message}</p>
+                {detail.map(|d| view! { <p class="sync-wizard-progress-detail">{d}</p> })}
+            </div>
+        }
+        .into_any()
+    };
+
+    view! {
+        <div
+            class="sync-wizard-overlay"
+            class:visible=move || is_open.get()
+            on:click=move |_| close()
+        >
+            <div
+                class="sync-wizard"
+                node_ref=dialog_ref
+                on:click=move |e| e.stop_propagation()
+                role="dialog"
+                aria-modal="true"
+                aria-labelledby="sync-wizard-title"
+            >
+                <div class="sync-wizard-header">
+                    <h2 id="sync-wizard-title">{move || i18n.t("app.sync_to_device")}</h2>
+                    <button
+                        class="btn btn-ghost btn-icon"
+                        on:click=move |_| close()
+                        aria-label=move || i18n.t("sync_wizard.close")
+                    >
+                        <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
+                            <path d="M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 17.59 19 19 17.59 13.41 12z"/>
+                        </svg>
+                    </button>
+                </div>
+
+                <ol class="sync-wizard-steps">
+                    {WizardStep::ALL.into_iter().map(|s| view! {
+                        <li
+                            class:active=move || step.get() == s
+                            class:done=move || step.get().number() > s.number()
+                            aria-current=move || (step.get() == s).then_some("step")
+                        >
+                            <span class="sync-wizard-step-number">{s.number()}</span>
+                            {move || i18n.t(s.title_key())}
+                        </li>
+                    }).collect_view()}
+                </ol>
+
+                <div class="sync-wizard-body">
+                    {move || error.get().map(|e| view! { <p class="sync-wizard-error" role="alert">{e}</p> })}
+                    {move || match step.get() {
+                        WizardStep::Device => device_step(),
+                        WizardStep::Playlists => playlists_step(),
+                        WizardStep::Preview => preview_step(),
+                        WizardStep::Running => running_step(),
+                    }}
+                </div>
+
+                <div class="sync-wizard-footer">
+                    {move || step.get().previous().map(|_| view! {
+                        <button class="btn btn-secondary" on:click=on_back>{i18n.t("common.back")}</button>
+                    })}
+                    {move || match step.get() {
+                        WizardStep::Running if task_id.get().is_some() => view! {
+                            <button class="btn btn-secondary" on:click=on_cancel_sync>
+                                {i18n.t("sync_wizard.cancel")}
+                            </button>
+                        }.into_any(),
+                        WizardStep::Running => view! {
+                            <button class="btn btn-primary" on:click=move |_| close()>
+                                {i18n.t("common.done")}
+                            </button>
+                        }.into_any(),
+                        current => view! {
+                            <button
+                                class="btn btn-primary"
+                                on:click=on_next
+                                disabled=move || !can_continue()
+                            >
+                                {i18n.t(if current == WizardStep::Preview {
+                                    "sync_wizard.start"
+                                } else {
+                                    "common.next"
+                                })}
+                            </button>
+                        }.into_any(),
+                    }}
+                </div>
+            </div>
+        </div>
+        <SyncConflictDialog
+            is_open=conflicts_open
+            conflicts=conflicts
+            on_confirm=on_conflicts_confirm
+            on_cancel=on_conflicts_cancel
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preview(write: u64, remove: u64, replaced: u64) -> SyncPreview {
+        SyncPreview {
+            entries: vec![],
+            bytes_to_write: write,
+            bytes_to_remove: remove,
+            bytes_replaced: replaced,
+        }
+    }
+
+    #[test]
+    fn test_wizard_steps() {
+        assert_eq!(WizardStep::Device.previous(), None);
+        assert_eq!(WizardStep::Preview.previous(), Some(WizardStep::Playlists));
+        // A started sync can't be undone by going back
+        assert_eq!(WizardStep::Running.previous(), None);
+        let numbers: Vec<_> = WizardStep::ALL.iter().map(|s| s.number()).collect();
+        assert_eq!(numbers, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_capacity_segments() {
+        // 1000-byte device with 400 used, writing 300
+        let segments = CapacitySegments::new(1000, 600, &preview(300, 0, 0));
+        assert!((segments.kept_percent - 40.0).abs() < f64::EPSILON);
+        assert!((segments.incoming_percent - 30.0).abs() < f64::EPSILON);
+        assert!(segments.fits);
+
+        // Too big until removals and replaced copies are counted as free
+        assert!(!CapacitySegments::new(1000, 100, &preview(500, 0, 0)).fits);
+        let segments = CapacitySegments::new(1000, 100, &preview(500, 300, 200));
+        assert!(segments.fits);
+        assert!((segments.kept_percent - 40.0).abs() < f64::EPSILON);
+
+        // Incoming is clamped to the free space
+        let segments = CapacitySegments::new(1000, 100, &preview(500, 0, 0));
+        assert!((segments.incoming_percent - 10.0).abs() < f64::EPSILON);
+
+        assert!(!CapacitySegments::new(0, 0, &preview(1, 0, 0)).fits);
+    }
+}
@@ -1,12 +1,28 @@
 //! Toast notification component for displaying feedback messages.
 //!
 //! Provides a toast notification system with support for different notification
-//! types (info, success, warning, error) and automatic dismissal.
+//! types (info, success, warning, error), automatic dismissal and action
+//! buttons such as "Retry" and "Undo".
+
+use std::collections::HashMap;
 
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
-use crate::types::{Notification, NotificationType};
+use crate::i18n::use_i18n;
+use crate::types::{Notification, NotificationAction, NotificationType};
+
+/// How long an undo toast stays up before the change goes ahead.
+pub const UNDO_WINDOW_MS: u64 = 6000;
+
+/// Callbacks for a toast with an action button.
+#[derive(Clone, Copy)]
+struct ToastCallbacks {
+    /// Run when the action button is clicked.
+    on_action: Callback<()>,
+    /// Run when the toast closes without the action being taken.
+    on_close: Option<Callback<()>>,
+}
 
 /// Context for managing notifications across the application.
 #[derive(Clone, Copy)]
@@ -15,6 +31,10 @@ pub struct NotificationContext {
     pub notifications: ReadSignal<Vec<Notification>>,
     /// Signal to update the notifications list.
     set_notifications: WriteSignal<Vec<Notification>>,
+    /// Callbacks of the notifications that have an action, by ID.
+    callbacks: StoredValue<HashMap<u64, ToastCallbacks>>,
+    /// Message in the screen-reader live region.
+    announcement: RwSignal<String>,
 }
 
 impl NotificationContext {
@@ -25,6 +45,8 @@ impl NotificationContext {
         Self {
             notifications,
             set_notifications,
+            callbacks: StoredValue::new(HashMap::new()),
+            announcement: RwSignal::new(String::new()),
         }
     }
 
@@ -32,7 +54,7 @@ impl NotificationContext {
     pub fn push(&self, notification: Notification) {
         let id = notification.id;
         let duration_ms = notification.duration_ms;
-        let set_notifications = self.set_notifications;
+        let ctx = *self;
 
         // Add the notification
         self.set_notifications.update(|notifications| {
@@ -43,18 +65,58 @@ impl NotificationContext {
         if let Some(duration) = duration_ms {
             spawn_local(async move {
                 gloo_timers::future::TimeoutFuture::new(duration as u32).await;
-                set_notifications.update(|notifications| {
-                    notifications.retain(|n| n.id != id);
-                });
+                ctx.dismiss(id);
             });
         }
     }
 
+    /// Add a notification with an action button.
+    ///
+    /// `on_action` runs when the button is clicked. `on_close` runs instead
+    /// when the toast times out or is dismissed.
+    pub fn push_with_action(
+        &self,
+        notification: Notification,
+        on_action: Callback<()>,
+        on_close: Option<Callback<()>>,
+    ) {
+        self.callbacks.update_value(|callbacks| {
+            callbacks.insert(
+                notification.id,
+                ToastCallbacks {
+                    on_action,
+                    on_close,
+                },
+            );
+        });
+        self.push(notification);
+    }
+
     /// Remove a notification by ID.
+    ///
+    /// If the notification has an action that was not taken, its close
+    /// callback runs.
     pub fn dismiss(&self, id: u64) {
+        if let Some(on_close) = self.remove(id).and_then(|callbacks| callbacks.on_close) {
+            on_close.run(());
+        }
+    }
+
+    /// Run the action of a notification and remove it.
+    pub fn run_action(&self, id: u64) {
+        if let Some(callbacks) = self.remove(id) {
+            callbacks.on_action.run(());
+        }
+    }
+
+    /// Remove a notification and take its callbacks.
+    fn remove(&self, id: u64) -> Option<ToastCallbacks> {
         self.set_notifications.update(|notifications| {
             notifications.retain(|n| n.id != id);
         });
+        self.callbacks
+            .try_update_value(|callbacks| callbacks.remove(&id))
+            .flatten()
     }
 
     /// Show an info notification.
@@ -77,9 +139,68 @@ impl NotificationContext {
         self.push(Notification::error(message));
     }
 
+    /// Show an error notification with a "Retry" button.
+    pub fn error_with_retry(&self, notification: Notification, on_retry: Callback<()>) {
+        self.push_with_action(
+            notification.with_action(NotificationAction::new("toast.retry")),
+            on_retry,
+            None,
+        );
+    }
+
+    /// Show a notification with an "Undo" button and a countdown.
+    ///
+    /// `on_commit` runs once the countdown ends or the toast is dismissed,
+    /// unless "Undo" was clicked first, in which case `on_undo` runs.
+    pub fn undo(&self, message: impl Into<String>, on_undo: Callback<()>, on_commit: Callback<()>) {
+        self.push_with_action(
+            Notification::info(message)
+                .with_duration(UNDO_WINDOW_MS)
+                .with_action(NotificationAction::new("nav.undo").with_countdown()),
+            on_undo,
+            Some(on_commit),
+        );
+    }
+
+    /// Read a message out to screen readers, without a toast.
+    ///
+    /// The live region is emptied first so a repeated message is read again.
+    pub fn announce(&self, message: impl Into<String>) {
+        let message = message.into();
+        let announcement = self.announcement;
+        announcement.set(String::new());
+        request_animation_frame(move || announcement.set(message));
+    }
+
     /// Clear all notifications.
+    ///
+    /// Pending close callbacks run, so undoable changes go ahead.
     pub fn clear_all(&self) {
         self.set_notifications.set(vec![]);
+        let callbacks = self
+            .callbacks
+            .try_update_value(std::mem::take)
+            .unwrap_or_default();
+        for on_close in callbacks.into_values().filter_map(|c| c.on_close) {
+            on_close.run(());
+        }
+    }
+}
+
+/// Whole seconds left, rounded up, before a toast lasting `duration_ms`
+/// closes.
+#[must_use]
+pub const fn seconds_left(duration_ms: u64, elapsed_ms: u64) -> u64 {
+    duration_ms.saturating_sub(elapsed_ms).div_ceil(1000)
+}
+
+/// ARIA role for a toast: errors and warnings interrupt, the rest wait for
+/// the screen reader to finish.
+#[must_use]
+pub const fn toast_role(notification_type: NotificationType) -> &'static str {
+    match notification_type {
+        NotificationType::Warning | NotificationType::Error => "alert",
+        NotificationType::Info | NotificationType::Success => "status",
     }
 }
 
@@ -97,9 +218,24 @@ impl Default for NotificationContext {
 
 pub fn ToastContainer() -> impl IntoView {
     let ctx = expect_context::<NotificationContext>();
+    let i18n = use_i18n();
 
     view! {
-        <div class="toast-container" data-testid="toast-container">
+        <div
+            class="sr-only"
+            role="status"
+            aria-live="polite"
+            aria-atomic="true"
+            data-testid="announcer"
+        >
+            {move || ctx.announcement.get()}
+        </div>
+        <div
+            class="toast-container"
+            role="region"
+            aria-label=move || i18n.t("settings.section.notifications")
+            data-testid="toast-container"
+        >
             <For
                 each=move || ctx.notifications.get()
                 key=|notification| notification.id
@@ -111,6 +247,9 @@ pub fn ToastContainer() -> impl IntoView {
                             on_dismiss=Callback::new(move |()| {
                                 ctx.dismiss(id);
                             })
+                            on_action=Callback::new(move |()| {
+                                ctx.run_action(id);
+                            })
                         />
                     }
                 }
@@ -126,16 +265,66 @@ fn Toast(
     notification: Notification,
     /// Callback when the notification is dismissed.
     on_dismiss: Callback<()>,
+    /// Callback when the action button is clicked.
+    on_action: Callback<()>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let notification_type = notification.notification_type;
     let type_class = format!("toast-{notification_type}");
     let has_title = notification.title.is_some();
 
+    let countdown_ms = notification
+        .action
+        .as_ref()
+        .filter(|action| action.countdown)
+        .and(notification.duration_ms);
+    let (seconds, set_seconds) = signal(countdown_ms.map_or(0, |ms| seconds_left(ms, 0)));
+    if let Some(duration) = countdown_ms {
+        spawn_local(async move {
+            let mut elapsed = 0;
+            while elapsed < duration {
+                gloo_timers::future::TimeoutFuture::new(1000).await;
+                elapsed += 1000;
+                // Stop once the toast has been removed
+                if set_seconds
+                    .try_set(seconds_left(duration, elapsed))
+                    .is_some()
+                {
+                    break;
+                }
+            }
+        });
+    }
+
+    let action = notification.action.clone().map(|action| {
+        let label = i18n.t(&action.label);
+        view! {
+            <button
+                class="toast-action btn btn-sm btn-secondary"
+                on:click=move |_| on_action.run(())
+                data-testid="toast-action"
+            >
+                {move || if action.countdown {
+                    i18n.tf(
+                        "toast.action_countdown",
+                        &[("label", &label), ("seconds", &seconds.get().to_string())],
+                    )
+                } else {
+                    label.clone()
+                }}
+            </button>
+        }
+    });
+    let countdown_bar = countdown_ms.map(|ms| {
+        view! {
+            <div class="toast-countdown" style=format!("animation-duration: {ms}ms")></div>
+        }
+    });
+
     view! {
         <div
             class=format!("toast {}", type_class)
-            role="alert"
-            aria-live="polite"
+            role=toast_role(notification_type)
             data-testid="toast"
             data-toast-type=notification_type.to_string()
         >
@@ -171,16 +360,18 @@ fn Toast(
                     {notification.message}
                 </div>
             </div>
+            {action}
             <button
                 class="toast-dismiss btn btn-ghost btn-icon"
                 on:click=move |_| on_dismiss.run(())
-                aria-label="Dismiss notification"
+                aria-label=move || i18n.t("toast.dismiss")
                 data-testid="toast-dismiss"
             >
                 <svg viewBox="0 0 24 24" width="16" height="16" fill="currentColor">
                     <path d="M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 17.59 19 19 17.59 13.41 12z"/>
                 </svg>
             </button>
+            {countdown_bar}
         </div>
     }
 }
@@ -210,3 +401,24 @@ pub fn NotificationProvider(
 pub fn use_notifications() -> NotificationContext {
     expect_context::<NotificationContext>()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seconds_left_rounds_up() {
+        assert_eq!(seconds_left(6000, 0), 6);
+        assert_eq!(seconds_left(6000, 1500), 5);
+        assert_eq!(seconds_left(6000, 5999), 1);
+        assert_eq!(seconds_left(6000, 6000), 0);
+        assert_eq!(seconds_left(6000, 9000), 0);
+    }
+
+    #[test]
+    fn test_toast_role() {
+        assert_eq!(toast_role(NotificationType::Error), "alert");
+        assert_eq!(toast_role(NotificationType::Warning), "alert");
+        assert_eq!(toast_role(NotificationType::Success), "status");
+    }
+}
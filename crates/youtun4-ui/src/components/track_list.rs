@@ -2,7 +2,10 @@
 
 use leptos::prelude::*;
 
+use crate::components::batch_actions::{BatchAction, BatchActionBar, ListSelection};
+use crate::components::context_menu::{ContextMenu, ContextMenuItem};
 use crate::components::empty_state::{EmptyStateSize, ErrorEmptyState, NoTracksEmptyState};
+use crate::i18n::use_i18n;
 use crate::types::TrackInfo;
 
 /// Format bytes to human-readable string.
@@ -29,6 +32,29 @@ fn format_duration(secs: u64) -> String {
     format!("{mins}:{remaining_secs:02}")
 }
 
+/// Actions offered in a track's context menu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrackAction {
+    /// Open the track preview.
+    Preview,
+    /// Show the track file in the file manager.
+    Reveal,
+    /// Look up the track's lyrics and save them with it.
+    FetchLyrics,
+    /// Delete the track.
+    Delete,
+}
+
+impl TrackAction {
+    /// Context menu entries, in order.
+    pub const MENU_ITEMS: [ContextMenuItem<Self>; 4] = [
+        ContextMenuItem::new(Self::Preview, "track_menu.preview"),
+        ContextMenuItem::new(Self::Reveal, "track_menu.reveal"),
+        ContextMenuItem::new(Self::FetchLyrics, "track_menu.fetch_lyrics"),
+        ContextMenuItem::new(Self::Delete, "common.delete").danger(),
+    ];
+}
+
 /// Single track row component.
 #[component]
 fn TrackRow(
@@ -39,8 +65,19 @@ fn TrackRow(
     /// Callback when track is clicked.
     #[prop(optional)]
     on_click: Option<Callback<TrackInfo>>,
+    /// Whether the row is checked; shows a checkbox while selecting.
+    #[prop(optional)]
+    checked: Option<Signal<bool>>,
+    /// Callback when the row is clicked while selecting, with whether shift
+    /// was held. Replaces `on_click`.
+    #[prop(optional)]
+    on_select_click: Option<Callback<bool>>,
+    /// Callback with an action chosen from the row's context menu.
+    #[prop(optional_no_strip)]
+    on_action: Option<Callback<(TrackAction, TrackInfo)>>,
 ) -> impl IntoView {
     let track_clone = track.clone();
+    let track_for_menu = track.clone();
     let has_metadata = track
         .metadata
         .as_ref()
@@ -79,17 +116,31 @@ fn TrackRow(
         .and_then(|m| m.track_number)
         .map_or_else(|| index.to_string(), |n| n.to_string());
 
-    view! {
+    let row = view! {
         <div
             class="track-row"
             class:has-metadata=has_metadata
-            on:click=move |_| {
-                if let Some(callback) = on_click {
+            class:checked=move || checked.is_some_and(|c| c.get())
+            on:click=move |e: web_sys::MouseEvent| {
+                if let Some(callback) = on_select_click {
+                    callback.run(e.shift_key());
+                } else if let Some(callback) = on_click {
                     callback.run(track_clone.clone());
                 }
             }
         >
-            <div class="track-number">{track_num}</div>
+            <div class="track-number">
+                {match checked {
+                    Some(checked) => view! {
+                        <span
+                            class="batch-select-check"
+                            role="checkbox"
+                            aria-checked=move || checked.get().to_string()
+                        ></span>
+                    }.into_any(),
+                    None => track_num.into_any(),
+                }}
+            </div>
             <div class="track-info">
                 <div class="track-title">{display_title}</div>
                 {artist.map(|a| view! {
@@ -102,6 +153,22 @@ fn TrackRow(
             <div class="track-duration">{duration.unwrap_or_default()}</div>
             <div class="track-size">{format_bytes(track.size_bytes)}</div>
         </div>
+    };
+
+    match on_action {
+        Some(callback) => view! {
+            <ContextMenu
+                items=TrackAction::MENU_ITEMS.to_vec()
+                on_action=Callback::new(move |action| {
+                    callback.run((action, track_for_menu.clone()));
+                })
+                label="track_menu.label"
+            >
+                {row}
+            </ContextMenu>
+        }
+        .into_any(),
+        None => row.into_any(),
     }
 }
 
@@ -125,13 +192,14 @@ fn TrackRowSkeleton() -> impl IntoView {
 /// Track list header row.
 #[component]
 fn TrackListHeader() -> impl IntoView {
+    let i18n = use_i18n();
     view! {
         <div class="track-list-header">
             <div class="track-number">"#"</div>
-            <div class="track-info">"Title"</div>
-            <div class="track-album">"Album"</div>
-            <div class="track-duration">"Duration"</div>
-            <div class="track-size">"Size"</div>
+            <div class="track-info">{move || i18n.t("track_list.title")}</div>
+            <div class="track-album">{move || i18n.t("track_list.album")}</div>
+            <div class="track-duration">{move || i18n.t("track_list.duration")}</div>
+            <div class="track-size">{move || i18n.t("track_list.size")}</div>
         </div>
     }
 }
@@ -173,6 +241,34 @@ pub enum TrackListState {
     Error,
 }
 
+/// Height of a track row in pixels; must match `.track-list-viewport .track-row`.
+const ROW_HEIGHT_PX: f64 = 56.0;
+
+/// Rows rendered above and below the visible window to hide scroll gaps.
+const OVERSCAN_ROWS: usize = 8;
+
+/// Viewport height assumed until the list has been scrolled.
+const DEFAULT_VIEWPORT_PX: f64 = 640.0;
+
+/// Rows to render for a viewport scrolled to `scroll_top`, as a half-open
+/// index range clamped to `total`.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn visible_range(scroll_top: f64, viewport_height: f64, total: usize) -> (usize, usize) {
+    // Truncation to whole rows is intended
+    let first = (scroll_top.max(0.0) / ROW_HEIGHT_PX) as usize;
+    let visible = (viewport_height.max(0.0) / ROW_HEIGHT_PX).ceil() as usize;
+    let start = first.saturating_sub(OVERSCAN_ROWS).min(total);
+    let end = first
+        .saturating_add(visible)
+        .saturating_add(OVERSCAN_ROWS)
+        .min(total);
+    (start, end)
+}
+
 /// Track list component that displays tracks with metadata.
 ///
 /// Features:
@@ -180,7 +276,15 @@ pub enum TrackListState {
 /// - Falls back to filename when no metadata available
 /// - Loading state with skeleton placeholders
 /// - Empty state for playlists with no tracks
-/// - Column headers for organization
+/// - Sticky column headers
+/// - Virtual scrolling: only the rows in view are rendered, so playlists
+///   with thousands of tracks stay responsive
+/// - Incremental loading: with `total` and `on_load_more`, rows past the
+///   loaded tracks show as placeholders until the next page arrives
+/// - Multi-select with shift-click ranges and batch actions, when
+///   `selection` and `on_batch` are given
+/// - Right-click and long-press context menus, when `on_track_action` is
+///   given
 #[component]
 
 pub fn TrackList(
@@ -198,7 +302,97 @@ pub fn TrackList(
     /// Whether to show column headers.
     #[prop(default = true)]
     show_header: bool,
+    /// Total number of tracks when `tracks` holds only the loaded pages.
+    #[prop(optional, into)]
+    total: Option<Signal<usize>>,
+    /// Callback to load more tracks, called with the number already loaded.
+    ///
+    /// Called again on every scroll until the page arrives, so callers should
+    /// ignore requests while one is in flight.
+    #[prop(optional)]
+    on_load_more: Option<Callback<usize>>,
+    /// Multi-select state; while active, clicking a row selects it.
+    #[prop(optional)]
+    selection: Option<ListSelection>,
+    /// Callback with a batch action and the selected track file names.
+    #[prop(optional)]
+    on_batch: Option<Callback<(BatchAction, Vec<String>)>>,
+    /// Playlists the selected tracks can be moved to.
+    #[prop(optional, into)]
+    move_targets: Option<Signal<Vec<String>>>,
+    /// Callback with an action chosen from a track's context menu.
+    #[prop(optional)]
+    on_track_action: Option<Callback<(TrackAction, TrackInfo)>>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
+    let (scroll_top, set_scroll_top) = signal(0.0_f64);
+    let (viewport_height, set_viewport_height) = signal(DEFAULT_VIEWPORT_PX);
+
+    let total_rows = move || {
+        let loaded = tracks.with(Vec::len);
+        total.map_or(loaded, |total| total.get().max(loaded))
+    };
+    let range =
+        Memo::new(move |_| visible_range(scroll_top.get(), viewport_height.get(), total_rows()));
+    // Only re-render the viewport (and lose its scroll position) when the
+    // list becomes empty or non-empty, not whenever a page arrives
+    let is_empty = Memo::new(move |_| total_rows() == 0);
+
+    // Ask for the next page once placeholder rows come into view
+    Effect::new(move || {
+        let (_, end) = range.get();
+        let loaded = tracks.with(Vec::len);
+        if end > loaded
+            && let Some(callback) = on_load_more
+        {
+            callback.run(loaded);
+        }
+    });
+
+    // Ranges only cover the tracks loaded so far
+    let keys = move || {
+        tracks.with_untracked(|tracks| {
+            tracks
+                .iter()
+                .map(|t| t.file_name.clone())
+                .collect::<Vec<_>>()
+        })
+    };
+    let action_bar = move || {
+        let selection = selection.filter(ListSelection::is_active)?;
+        let on_action = on_batch?;
+        let all_keys = Signal::derive(move || {
+            tracks.with(|tracks| tracks.iter().map(|t| t.file_name.clone()).collect())
+        });
+        Some(match move_targets {
+            Some(targets) => view! {
+                <BatchActionBar
+                    selection=selection
+                    all_keys=all_keys
+                    noun="count.tracks"
+                    on_action=on_action
+                    move_targets=targets
+                />
+            }
+            .into_any(),
+            None => view! {
+                <BatchActionBar
+                    selection=selection
+                    all_keys=all_keys
+                    noun="count.tracks"
+                    on_action=on_action
+                />
+            }
+            .into_any(),
+        })
+    };
+
+    let on_scroll = move |ev: web_sys::Event| {
+        let viewport = event_target::<web_sys::Element>(&ev);
+        set_scroll_top.set(f64::from(viewport.scroll_top()));
+        set_viewport_height.set(f64::from(viewport.client_height()));
+    };
+
     view! {
         <div class="track-list">
             {move || match state {
@@ -206,7 +400,7 @@ pub fn TrackList(
                     view! { <LoadingState count=5 /> }.into_any()
                 }
                 TrackListState::Error => {
-                    let msg = error_message.clone().unwrap_or_else(|| "Failed to load tracks".to_string());
+                    let msg = error_message.clone().unwrap_or_else(|| i18n.t("track_list.load_failed"));
                     view! {
                         <div class="track-list-error">
                             <ErrorEmptyState
@@ -217,38 +411,65 @@ pub fn TrackList(
                     }.into_any()
                 }
                 TrackListState::Loaded => {
-                    let track_list = tracks.get();
-
-                    if track_list.is_empty() {
+                    if is_empty.get() {
                         view! { <TrackListEmptyState /> }.into_any()
                     } else {
                         view! {
-                            <div class="track-list-content">
+                            {action_bar}
+                            <div class="track-list-content track-list-viewport" on:scroll=on_scroll>
                                 {if show_header {
                                     Some(view! { <TrackListHeader /> })
                                 } else {
                                     None
                                 }}
-                                <div class="track-list-rows">
-                                    {track_list.into_iter().enumerate().map(|(i, track)| {
-                                        let callback = on_track_click;
-                                        if let Some(cb) = callback {
-                                            view! {
-                                                <TrackRow
-                                                    index=i + 1
-                                                    track=track
-                                                    on_click=cb
-                                                />
-                                            }.into_any()
-                                        } else {
-                                            view! {
-                                                <TrackRow
-                                                    index=i + 1
-                                                    track=track
-                                                />
-                                            }.into_any()
+                                <div
+                                    class="track-list-rows"
+                                    style:height=move || {
+                                        #[allow(clippy::cast_precision_loss)]
+                                        let rows = total_rows() as f64;
+                                        format!("{}px", rows * ROW_HEIGHT_PX)
+                                    }
+                                >
+                                    {move || {
+                                        let (start, end) = range.get();
+                                        #[allow(clippy::cast_precision_loss)]
+                                        let offset = start as f64 * ROW_HEIGHT_PX;
+                                        let selecting = selection.filter(ListSelection::is_active);
+                                        let rows = tracks.with(|tracks| {
+                                            (start..end)
+                                                .map(|i| match (tracks.get(i).cloned(), selecting, on_track_click) {
+                                                    (Some(track), Some(selection), _) => {
+                                                        let key = track.file_name.clone();
+                                                        view! {
+                                                            <TrackRow
+                                                                index=i + 1
+                                                                track=track
+                                                                checked=Signal::derive(move || selection.is_selected(&key))
+                                                                on_select_click=Callback::new(move |shift| {
+                                                                    selection.click(&keys(), i, shift);
+                                                                })
+                                                            />
+                                                        }.into_any()
+                                                    }
+                                                    (Some(track), None, Some(cb)) => view! {
+                                                        <TrackRow index=i + 1 track=track on_click=cb on_action=on_track_action />
+                                                    }.into_any(),
+                                                    (Some(track), None, None) => view! {
+                                                        <TrackRow index=i + 1 track=track on_action=on_track_action />
+                                                    }.into_any(),
+                                                    (None, ..) => view! { <TrackRowSkeleton /> }.into_any(),
+                                                })
+                                                .collect_view()
+                                        });
+                                        view! {
+                                            <div
+                                                class="track-list-window"
+                                                style:transform=format!("translateY({offset}px)")
+                                            >
+                                                {rows}
+                                            </div>
                                         }
-                                    }).collect_view()}
+                                    }}
                                 </div>
                             </div>
                         }.into_any()
@@ -332,6 +553,19 @@ mod tests {
         assert_eq!(format_bytes(1_073_741_824), "1.0 GB");
     }
 
+    #[test]
+    fn test_visible_range() {
+        // Top of the list: no rows above, overscan below
+        assert_eq!(visible_range(0.0, 560.0, 1000), (0, 18));
+        // Scrolled to row 100
+        assert_eq!(visible_range(5600.0, 560.0, 1000), (92, 118));
+        // Clamped to the end of the list
+        assert_eq!(visible_range(55_440.0, 560.0, 1000), (982, 1000));
+        // Short lists render every row
+        assert_eq!(visible_range(0.0, 560.0, 3), (0, 3));
+        assert_eq!(visible_range(0.0, 560.0, 0), (0, 0));
+    }
+
     #[test]
     fn test_format_duration() {
         assert_eq!(format_duration(0), "0:00");
@@ -0,0 +1,182 @@
+//! Track player component for previewing downloaded tracks before syncing.
+
+use leptos::ev;
+use leptos::html;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::shortcuts::{event_target_tag, is_text_entry};
+use crate::i18n::use_i18n;
+use crate::tauri_api;
+
+/// Format a playback position in seconds as M:SS.
+///
+/// Positions the audio element can't report yet (NaN or infinite) read as
+/// zero.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_position(secs: f64) -> String {
+    // Truncation to whole seconds is intended
+    let total = if secs.is_finite() && secs > 0.0 {
+        secs as u64
+    } else {
+        0
+    };
+    format!("{}:{:02}", total / 60, total % 60)
+}
+
+/// Audio player with play/pause and seeking for a track in a playlist.
+///
+/// The track is streamed from disk through the asset protocol, so seeking
+/// doesn't need the whole file in memory. Playback stops when the player is
+/// removed. Space plays or pauses while the player is shown.
+#[component]
+
+pub fn TrackPlayer(
+    /// Playlist containing the track.
+    playlist_name: String,
+    /// File name of the track within the playlist.
+    file_name: String,
+    /// Duration from the track's metadata, shown until the audio loads.
+    duration_hint: Option<u64>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let audio_ref = NodeRef::<html::Audio>::new();
+    let (src, set_src) = signal::<Option<String>>(None);
+    let (error, set_error) = signal::<Option<String>>(None);
+    let (playing, set_playing) = signal(false);
+    let (position, set_position) = signal(0.0_f64);
+    #[allow(clippy::cast_precision_loss)]
+    let (duration, set_duration) = signal(duration_hint.map_or(0.0, |secs| secs as f64));
+
+    spawn_local(async move {
+        match tauri_api::get_track_preview_url(&playlist_name, &file_name).await {
+            Ok(url) => set_src.set(Some(url)),
+            Err(e) => set_error.set(Some(e)),
+        }
+    });
+
+    on_cleanup(move || {
+        if let Some(audio) = audio_ref.get_untracked() {
+            let _ = audio.pause();
+        }
+    });
+
+    let toggle = move || {
+        let Some(audio) = audio_ref.get_untracked() else {
+            return;
+        };
+        if audio.paused() {
+            let _ = audio.play();
+        } else {
+            let _ = audio.pause();
+        }
+    };
+
+    // Buttons and fields handle Space themselves
+    let handle = window_event_listener(ev::keydown, move |e| {
+        if e.key() != " " || e.ctrl_key() || e.meta_key() || e.alt_key() {
+            return;
+        }
+        if event_target_tag(&e)
+            .is_some_and(|tag| is_text_entry(&tag) || tag == "BUTTON" || tag == "A")
+        {
+            return;
+        }
+        if src.get_untracked().is_some() && error.get_untracked().is_none() {
+            e.prevent_default();
+            toggle();
+        }
+    });
+    on_cleanup(move || handle.remove());
+
+    let seek = move |ev| {
+        if let (Some(audio), Ok(secs)) = (audio_ref.get(), event_target_value(&ev).parse::<f64>()) {
+            audio.set_current_time(secs);
+            set_position.set(secs);
+        }
+    };
+
+    view! {
+        <div class="track-player" class:has-error=move || error.get().is_some()>
+            <audio
+                node_ref=audio_ref
+                preload="metadata"
+                src=move || src.get()
+                on:play=move |_| set_playing.set(true)
+                on:pause=move |_| set_playing.set(false)
+                on:ended=move |_| set_playing.set(false)
+                on:timeupdate=move |_| {
+                    if let Some(audio) = audio_ref.get() {
+                        set_position.set(audio.current_time());
+                    }
+                }
+                on:loadedmetadata=move |_| {
+                    if let Some(audio) = audio_ref.get() {
+                        let secs = audio.duration();
+                        if secs.is_finite() {
+                            set_duration.set(secs);
+                        }
+                    }
+                }
+                on:error=move |_| set_error.set(Some(i18n.t("track_player.unplayable")))
+            ></audio>
+            <button
+                class="btn btn-ghost btn-icon track-player-toggle"
+                on:click=move |_| toggle()
+                disabled=move || src.get().is_none() || error.get().is_some()
+                aria-label=move || i18n.t(if playing.get() { "track_player.pause" } else { "track_player.play" })
+            >
+                {move || if playing.get() {
+                    view! {
+                        <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
+                            <path d="M6 19h4V5H6v14zm8-14v14h4V5h-4z"/>
+                        </svg>
+                    }.into_any()
+                } else {
+                    view! {
+                        <svg viewBox="0 0 24 24" width="24" height="24" fill="currentColor">
+                            <path d="M8 5v14l11-7z"/>
+                        </svg>
+                    }.into_any()
+                }}
+            </button>
+            <input
+                class="track-player-seek"
+                type="range"
+                min="0"
+                step="0.1"
+                max=move || duration.get().to_string()
+                prop:value=move || position.get().to_string()
+                on:input=seek
+                disabled=move || src.get().is_none() || error.get().is_some()
+                aria-label=move || i18n.t("track_player.seek")
+            />
+            <span class="track-player-time">
+                {move || format!("{} / {}", format_position(position.get()), format_position(duration.get()))}
+            </span>
+            {move || error.get().map(|e| view! {
+                <div class="track-player-error">{e}</div>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_position() {
+        assert_eq!(format_position(0.0), "0:00");
+        assert_eq!(format_position(7.9), "0:07");
+        assert_eq!(format_position(225.0), "3:45");
+        assert_eq!(format_position(3725.0), "62:05");
+    }
+
+    #[test]
+    fn test_format_position_unknown() {
+        assert_eq!(format_position(f64::NAN), "0:00");
+        assert_eq!(format_position(f64::INFINITY), "0:00");
+        assert_eq!(format_position(-3.0), "0:00");
+    }
+}
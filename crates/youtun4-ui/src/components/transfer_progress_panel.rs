@@ -2,7 +2,8 @@
 
 use leptos::prelude::*;
 
-use crate::types::{TaskId, TransferProgress, TransferStatus};
+use crate::i18n::{translate, use_i18n};
+use crate::types::{Locale, TaskId, TransferProgress, TransferStatus};
 
 /// State of the transfer panel.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -43,15 +44,15 @@ impl TransferPanelState {
         matches!(self, Self::Completed | Self::Failed(_) | Self::Cancelled)
     }
 
-    /// Create from `TransferStatus`.
+    /// Create from `TransferStatus`, with a failure message in `locale`.
     #[must_use]
-    pub fn from_status(status: TransferStatus) -> Self {
+    pub fn from_status(status: TransferStatus, locale: Locale) -> Self {
         match status {
             TransferStatus::Preparing => Self::Preparing,
             TransferStatus::Transferring => Self::Transferring,
             TransferStatus::Verifying => Self::Verifying,
             TransferStatus::Completed => Self::Completed,
-            TransferStatus::Failed => Self::Failed("Transfer failed".to_string()),
+            TransferStatus::Failed => Self::Failed(translate(locale, "common.unknown_error", &[])),
             TransferStatus::Cancelled => Self::Cancelled,
         }
     }
@@ -81,6 +82,7 @@ pub fn TransferProgressPanel(
     /// Reactive task ID for cancellation.
     task_id: ReadSignal<Option<TaskId>>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let is_visible = move || state.get().is_visible();
     let is_active = move || state.get().is_active();
     let is_ended = move || state.get().is_ended();
@@ -137,13 +139,13 @@ pub fn TransferProgressPanel(
     };
 
     let status_title = move || match state.get() {
-        TransferPanelState::Idle => "Ready".to_string(),
-        TransferPanelState::Preparing => "Preparing transfer...".to_string(),
-        TransferPanelState::Transferring => "Transferring files...".to_string(),
-        TransferPanelState::Verifying => "Verifying integrity...".to_string(),
-        TransferPanelState::Completed => "Transfer Complete".to_string(),
-        TransferPanelState::Failed(ref msg) => format!("Transfer Failed: {msg}"),
-        TransferPanelState::Cancelled => "Transfer Cancelled".to_string(),
+        TransferPanelState::Idle => i18n.t("download_panel.ready"),
+        TransferPanelState::Preparing => i18n.t("transfer_panel.preparing"),
+        TransferPanelState::Transferring => i18n.t("transfer_panel.transferring"),
+        TransferPanelState::Verifying => i18n.t("transfer_panel.verifying"),
+        TransferPanelState::Completed => i18n.t("transfer_panel.completed"),
+        TransferPanelState::Failed(ref msg) => i18n.tf("transfer_panel.failed", &[("error", msg)]),
+        TransferPanelState::Cancelled => i18n.t("transfer_panel.cancelled"),
     };
 
     let handle_cancel = move |_: web_sys::MouseEvent| {
@@ -173,7 +175,13 @@ pub fn TransferProgressPanel(
                                 Some(view! {
                                     <div class="transfer-status-subtitle" data-testid="transfer-status-subtitle">
                                         <span class="file-counter">
-                                            {format!("{} of {} files", p.current_file_index, p.total_files)}
+                                            {i18n.tf(
+                                                "download_panel.progress",
+                                                &[
+                                                    ("current", &p.current_file_index.to_string()),
+                                                    ("total", &i18n.count("count.files", p.total_files)),
+                                                ],
+                                            )}
                                         </span>
                                     </div>
                                 })
@@ -192,7 +200,8 @@ pub fn TransferProgressPanel(
                             view! {
                                 <button
                                     class="btn btn-ghost btn-icon transfer-cancel-btn"
-                                    title="Cancel transfer"
+                                    title=i18n.t("transfer_panel.cancel")
+                                    aria-label=i18n.t("transfer_panel.cancel")
                                     on:click=handle_cancel
                                     data-testid="transfer-cancel-btn"
                                 >
@@ -205,7 +214,8 @@ pub fn TransferProgressPanel(
                             view! {
                                 <button
                                     class="btn btn-ghost btn-icon transfer-dismiss-btn"
-                                    title="Dismiss"
+                                    title=i18n.t("download_panel.dismiss")
+                                    aria-label=i18n.t("transfer_panel.dismiss")
                                     on:click=handle_dismiss
                                     data-testid="transfer-dismiss-btn"
                                 >
@@ -244,7 +254,15 @@ pub fn TransferProgressPanel(
                                 if p.current_file_total > 0 {
                                     Some(view! {
                                         <div class="transfer-file-progress-container">
-                                            <div class="transfer-file-progress-bar" data-testid="transfer-file-progress-bar">
+                                            <div
+                                                class="transfer-file-progress-bar"
+                                                role="progressbar"
+                                                aria-label=i18n.t("transfer_panel.file_progress")
+                                                aria-valuemin="0"
+                                                aria-valuemax="100"
+                                                aria-valuenow=move || format!("{:.0}", progress.get().map_or(0.0, |p| p.current_file_progress_percent()))
+                                                data-testid="transfer-file-progress-bar"
+                                            >
                                                 <div
                                                     class="transfer-file-progress-fill"
                                                     style=move || format!("width: {}%", progress.get().map_or(0.0, |p| p.current_file_progress_percent()))
@@ -266,12 +284,20 @@ pub fn TransferProgressPanel(
                             // Overall progress bar
                             <div class="transfer-progress-bar-container">
                                 <div class="transfer-progress-label-row">
-                                    <span class="progress-label">"Overall Progress"</span>
+                                    <span class="progress-label">{i18n.t("transfer_panel.overall")}</span>
                                     <span class="transfer-progress-percent" data-testid="transfer-progress-percent">
                                         {move || format!("{:.0}%", progress.get().map_or(0.0, |p| p.overall_progress_percent()))}
                                     </span>
                                 </div>
-                                <div class="transfer-progress-bar" data-testid="transfer-progress-bar">
+                                <div
+                                    class="transfer-progress-bar"
+                                    role="progressbar"
+                                    aria-label=i18n.t("transfer_panel.overall_label")
+                                    aria-valuemin="0"
+                                    aria-valuemax="100"
+                                    aria-valuenow=move || format!("{:.0}", progress.get().map_or(0.0, |p| p.overall_progress_percent()))
+                                    data-testid="transfer-progress-bar"
+                                >
                                     <div
                                         class="transfer-progress-fill"
                                         style=move || format!("width: {}%", progress.get().map_or(0.0, |p| p.overall_progress_percent()))
@@ -319,7 +345,7 @@ pub fn TransferProgressPanel(
                                         <path d="M13 3c-4.97 0-9 4.03-9 9H1l3.89 3.89.07.14L9 12H6c0-3.87 3.13-7 7-7s7 3.13 7 7-3.13 7-7 7c-1.93 0-3.68-.79-4.94-2.06l-1.42 1.42C8.27 19.99 10.51 21 13 21c4.97 0 9-4.03 9-9s-4.03-9-9-9zm-1 5v5l4.28 2.54.72-1.21-3.5-2.08V8H12z"/>
                                     </svg>
                                     <span class="stat-value" data-testid="transfer-elapsed">{format_elapsed(p.elapsed_secs)}</span>
-                                    <span class="stat-label">"elapsed"</span>
+                                    <span class="stat-label">{i18n.t("download_panel.elapsed")}</span>
                                 </div>
                             </div>
 
@@ -334,7 +360,7 @@ pub fn TransferProgressPanel(
                                                     <svg viewBox="0 0 24 24" width="12" height="12" fill="currentColor">
                                                         <path d="M9 16.17L4.83 12l-1.42 1.41L9 19 21 7l-1.41-1.41z"/>
                                                     </svg>
-                                                    {p.files_completed} " completed"
+                                                    {i18n.tf("transfer_panel.files_completed", &[("count", &p.files_completed.to_string())])}
                                                 </span>
                                             })}
                                             {(p.files_skipped > 0).then(|| view! {
@@ -342,7 +368,7 @@ pub fn TransferProgressPanel(
                                                     <svg viewBox="0 0 24 24" width="12" height="12" fill="currentColor">
                                                         <path d="M6 18l8.5-6L6 6v12zM16 6v12h2V6h-2z"/>
                                                     </svg>
-                                                    {p.files_skipped} " skipped"
+                                                    {i18n.tf("transfer_panel.files_skipped", &[("count", &p.files_skipped.to_string())])}
                                                 </span>
                                             })}
                                             {(p.files_failed > 0).then(|| view! {
@@ -350,7 +376,7 @@ pub fn TransferProgressPanel(
                                                     <svg viewBox="0 0 24 24" width="12" height="12" fill="currentColor">
                                                         <path d="M19 6.41L17.59 5 12 10.59 6.41 5 5 6.41 10.59 12 5 17.59 6.41 19 12 13.41 17.59 19 19 17.59 13.41 12z"/>
                                                     </svg>
-                                                    {p.files_failed} " failed"
+                                                    {i18n.count("count.failed", p.files_failed)}
                                                 </span>
                                             })}
                                         </>
@@ -381,6 +407,7 @@ pub fn TransferProgressIndicator(
     #[prop(optional)]
     on_click: Option<Callback<()>>,
 ) -> impl IntoView {
+    let i18n = use_i18n();
     let handle_click = move |_: web_sys::MouseEvent| {
         if let Some(callback) = on_click {
             callback.run(());
@@ -404,14 +431,14 @@ pub fn TransferProgressIndicator(
                                 <span class="indicator-progress" data-testid="indicator-progress">
                                     {format!("{:.0}%", p.overall_progress_percent())}
                                 </span>
-                                <span class="indicator-label">"Transferring"</span>
+                                <span class="indicator-label">{i18n.t("transfer_panel.indicator.transferring")}</span>
                             </div>
                         }.into_any()
                     } else {
                         view! {
                             <div class="indicator-content">
                                 <div class="indicator-spinner"></div>
-                                <span class="indicator-label">"Preparing..."</span>
+                                <span class="indicator-label">{i18n.t("transfer_panel.indicator.preparing")}</span>
                             </div>
                         }.into_any()
                     }
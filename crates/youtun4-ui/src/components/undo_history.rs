@@ -0,0 +1,107 @@
+//! Sidebar button listing the session's undoable changes.
+
+use leptos::prelude::*;
+
+use crate::i18n::{translate, translate_count, use_i18n};
+use crate::types::Locale;
+use crate::undo::use_undo;
+
+/// Tooltip for undoing an entry, counting the later changes undone with it.
+fn undo_title(locale: Locale, later: usize) -> String {
+    match later {
+        0 => translate(locale, "undo_history.item", &[]),
+        n => translate_count(locale, "undo_history.item_and_later", n),
+    }
+}
+
+/// Undo history popover.
+///
+/// Hidden while there is nothing to undo. Undoing an older change also
+/// undoes every change made after it.
+#[component]
+pub fn UndoHistory() -> impl IntoView {
+    let undo = use_undo();
+    let i18n = use_i18n();
+    let (open, set_open) = signal(false);
+
+    // Nothing left to show
+    Effect::new(move || {
+        if !undo.can_undo() {
+            set_open.set(false);
+        }
+    });
+
+    let entries = move || {
+        undo.entries()
+            .into_iter()
+            .enumerate()
+            .map(|(later, entry)| {
+                let id = entry.id;
+                view! {
+                    <li>
+                        <button
+                            class="undo-history-item"
+                            title=undo_title(i18n.locale(), later)
+                            disabled=move || undo.is_undoing()
+                            on:click=move |_| {
+                                set_open.set(false);
+                                undo.undo_through(id);
+                            }
+                        >
+                            <span class="undo-history-label">{entry.label}</span>
+                            {(later == 0).then(|| view! { <kbd>"Ctrl Z"</kbd> })}
+                        </button>
+                    </li>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <Show when=move || undo.can_undo()>
+            <div
+                class="undo-history"
+                on:keydown=move |e| {
+                    if e.key() == "Escape" && open.get_untracked() {
+                        e.stop_propagation();
+                        set_open.set(false);
+                    }
+                }
+            >
+                <button
+                    class="undo-history-toggle"
+                    aria-expanded=move || open.get().to_string()
+                    aria-controls="undo-history-list"
+                    on:click=move |_| set_open.update(|o| *o = !*o)
+                >
+                    <svg viewBox="0 0 24 24" width="16" height="16" fill="currentColor" aria-hidden="true">
+                        <path d="M12.5 8c-2.65 0-5.05.99-6.9 2.6L2 7v9h9l-3.62-3.62c1.39-1.16 3.16-1.88 5.12-1.88 3.54 0 6.55 2.31 7.6 5.5l2.37-.78C21.08 11.03 17.15 8 12.5 8z"/>
+                    </svg>
+                    <span>{move || i18n.t("nav.undo")}</span>
+                    <span class="undo-history-count">{move || undo.entries().len()}</span>
+                </button>
+                <Show when=move || open.get()>
+                    <ul id="undo-history-list" class="undo-history-popover" aria-label=move || i18n.t("undo_history.label")>
+                        {entries}
+                    </ul>
+                </Show>
+            </div>
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undo_title() {
+        let locale = Locale::En;
+        assert_eq!(undo_title(locale, 0), "Undo this change");
+        assert_eq!(
+            undo_title(locale, 1),
+            "Undo this change and the one after it"
+        );
+        assert_eq!(undo_title(locale, 3), "Undo this change and the 3 after it");
+    }
+}
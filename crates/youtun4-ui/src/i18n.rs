@@ -0,0 +1,257 @@
+//! Interface translations and runtime language switching.
+//!
+//! Component text is looked up by key in the per-language catalogs, in the
+//! language picked in the settings or, by default, the system language.
+//! Messages the backend builds itself, such as error descriptions, carry their
+//! own key and are rendered with the backend catalog fetched for the same
+//! language.
+//!
+//! As in the backend catalogs, a key missing from a catalog falls back to
+//! English, and a key missing from English renders as the key itself.
+
+use std::cell::RefCell;
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::tauri_api;
+use crate::types::{Locale, Message, TranslationCatalog};
+
+mod de;
+mod en;
+mod es;
+mod fr;
+
+/// Interface text by key, for one language.
+type Catalog = &'static [(&'static str, &'static str)];
+
+const fn catalog(locale: Locale) -> Catalog {
+    match locale {
+        Locale::En => en::CATALOG,
+        Locale::Fr => fr::CATALOG,
+        Locale::Es => es::CATALOG,
+        Locale::De => de::CATALOG,
+    }
+}
+
+fn lookup(catalog: Catalog, key: &str) -> Option<&'static str> {
+    catalog
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, template)| *template)
+}
+
+/// The template for `key` in `locale`, falling back to English.
+#[must_use]
+pub fn template(locale: Locale, key: &str) -> Option<&'static str> {
+    lookup(catalog(locale), key).or_else(|| lookup(en::CATALOG, key))
+}
+
+/// Render `key` in `locale`, filling `{name}` placeholders from `params`.
+#[must_use]
+pub fn translate(locale: Locale, key: &str, params: &[(&str, &str)]) -> String {
+    let Some(template) = template(locale, key) else {
+        return key.to_string();
+    };
+    params
+        .iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+/// Render a count in `locale`, e.g. `count.tracks` gives "1 track" or
+/// "3 tracks".
+#[must_use]
+pub fn translate_count(locale: Locale, key: &str, count: usize) -> String {
+    let key = format!("{key}.{}", plural_form(locale, count));
+    translate(locale, &key, &[("count", &count.to_string())])
+}
+
+/// Plural form of `count` in `locale`: `one` or `other`.
+#[must_use]
+pub const fn plural_form(locale: Locale, count: usize) -> &'static str {
+    // French counts zero as singular
+    let singular = match locale {
+        Locale::Fr => count <= 1,
+        Locale::En | Locale::Es | Locale::De => count == 1,
+    };
+    if singular { "one" } else { "other" }
+}
+
+thread_local! {
+    /// Backend catalog for the current language, for rendering command errors
+    /// outside of components.
+    static BACKEND_CATALOG: RefCell<Option<TranslationCatalog>> = const { RefCell::new(None) };
+}
+
+/// Render a backend message in the current language, or `None` before the
+/// backend catalog is loaded or if it lacks the key.
+#[must_use]
+pub fn render_backend_message(message: &Message) -> Option<String> {
+    BACKEND_CATALOG.with_borrow(|catalog| catalog.as_ref().and_then(|c| message.render(c)))
+}
+
+/// Language of the loaded backend catalog, which follows the interface
+/// language, or English before it is loaded.
+#[must_use]
+pub fn backend_locale() -> Locale {
+    BACKEND_CATALOG
+        .with_borrow(|catalog| catalog.as_ref().map_or_else(Locale::default, |c| c.locale))
+}
+
+/// Current interface language, shared through context.
+#[derive(Clone, Copy)]
+pub struct I18n {
+    locale: RwSignal<Locale>,
+}
+
+impl I18n {
+    /// The current language.
+    #[must_use]
+    pub fn locale(&self) -> Locale {
+        self.locale.get()
+    }
+
+    /// Text for `key` in the current language.
+    ///
+    /// Reading it in a reactive closure updates the text when the language
+    /// changes.
+    #[must_use]
+    pub fn t(&self, key: &str) -> String {
+        translate(self.locale.get(), key, &[])
+    }
+
+    /// Text for `key` in the current language, with `{name}` placeholders
+    /// filled from `params`.
+    #[must_use]
+    pub fn tf(&self, key: &str, params: &[(&str, &str)]) -> String {
+        translate(self.locale.get(), key, params)
+    }
+
+    /// Text for a count, e.g. `count.tracks` gives "1 track" or "3 tracks".
+    #[must_use]
+    pub fn count(&self, key: &str, count: usize) -> String {
+        translate_count(self.locale.get(), key, count)
+    }
+
+    /// Switch to `language`, or to the system language when `None`.
+    ///
+    /// The backend resolves the system language and sends its catalog for
+    /// backend messages; the interface follows once it arrives.
+    pub fn load(&self, language: Option<Locale>) {
+        let locale = self.locale;
+        spawn_local(async move {
+            match tauri_api::get_translation_catalog(language.map(Locale::code)).await {
+                Ok(catalog) => {
+                    let resolved = catalog.locale;
+                    BACKEND_CATALOG.set(Some(catalog));
+                    set_document_language(resolved);
+                    locale.set(resolved);
+                }
+                Err(e) => {
+                    leptos::logging::error!("Failed to load translations: {}", e);
+                    if let Some(language) = language {
+                        set_document_language(language);
+                        locale.set(language);
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// Set the page's `lang` attribute, so screen readers use the right voice.
+fn set_document_language(locale: Locale) {
+    if let Some(root) = document().document_element() {
+        let _ = root.set_attribute("lang", locale.code());
+    }
+}
+
+/// Provide the interface language to the component tree, starting in
+/// English until [`I18n::load`] resolves the configured language.
+pub fn provide_i18n() -> I18n {
+    let i18n = I18n {
+        locale: RwSignal::new(Locale::En),
+    };
+    provide_context(i18n);
+    i18n
+}
+
+/// Access the interface language.
+///
+/// # Panics
+/// Panics if called outside of the tree set up by [`provide_i18n`].
+pub fn use_i18n() -> I18n {
+    expect_context::<I18n>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Placeholder names in a template, sorted.
+    fn placeholders(template: &str) -> Vec<&str> {
+        let mut names: Vec<_> = template
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn test_catalogs_match_english() {
+        for locale in Locale::ALL {
+            for (key, template) in catalog(locale) {
+                let english = lookup(en::CATALOG, key);
+                assert!(english.is_some(), "{locale:?} has unknown key {key}");
+                assert_eq!(
+                    placeholders(template),
+                    placeholders(english.unwrap_or_default()),
+                    "{locale:?} {key}"
+                );
+            }
+            for (key, _) in en::CATALOG {
+                assert!(
+                    lookup(catalog(locale), key).is_some(),
+                    "{locale:?} is missing {key}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_translate_fills_placeholders() {
+        assert_eq!(
+            translate(
+                Locale::En,
+                "toast.playlist_created",
+                &[("name", "Road Trip")]
+            ),
+            "Playlist \"Road Trip\" created"
+        );
+        assert_eq!(
+            translate(
+                Locale::De,
+                "toast.playlist_created",
+                &[("name", "Road Trip")]
+            ),
+            "Playlist „Road Trip“ erstellt"
+        );
+    }
+
+    #[test]
+    fn test_translate_unknown_key() {
+        assert_eq!(translate(Locale::Fr, "missing.key", &[]), "missing.key");
+    }
+
+    #[test]
+    fn test_plural_form() {
+        assert_eq!(plural_form(Locale::En, 1), "one");
+        assert_eq!(plural_form(Locale::En, 0), "other");
+        assert_eq!(plural_form(Locale::Fr, 0), "one");
+        assert_eq!(plural_form(Locale::Fr, 2), "other");
+    }
+}
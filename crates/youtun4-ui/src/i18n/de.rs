@@ -0,0 +1,1868 @@
+//! German interface text.
+
+use super::Catalog;
+
+pub(super) const CATALOG: Catalog = &[
+    ("common.cancel", "Abbrechen"),
+    ("common.back", "Zurück"),
+    ("common.next", "Weiter"),
+    ("common.done", "Fertig"),
+    ("common.unknown_error", "unbekannter Fehler"),
+    ("common.time_left", "noch {time}"),
+    ("common.go_back", "Zurück"),
+    ("common.refresh", "Aktualisieren"),
+    ("common.clear", "Leeren"),
+    ("common.remove", "Entfernen"),
+    ("common.delete", "Löschen"),
+    ("common.save", "Speichern"),
+    ("common.close_dialog", "Dialog schließen"),
+    ("common.close", "Schließen"),
+    (
+        "common.unknown_command_error",
+        "Unbekannter Fehler des Tauri-Befehls",
+    ),
+    ("app.sync_to_device", "Mit Gerät synchronisieren"),
+    ("nav.library", "Bibliothek"),
+    ("nav.history", "Verlauf"),
+    ("nav.storage", "Speicher"),
+    ("nav.undo", "Rückgängig"),
+    ("nav.shortcuts", "Tastenkürzel"),
+    ("nav.activity", "Aktivität"),
+    ("nav.downloads", "Downloads"),
+    ("nav.sync", "Synchronisierung"),
+    (
+        "nav.downloads_status",
+        "{count} Downloads in der Warteschlange oder aktiv",
+    ),
+    ("nav.sync_status", "Synchronisierung läuft"),
+    ("header.stop_all", "Alles stoppen"),
+    ("header.settings", "Einstellungen"),
+    ("header.toggle_sidebar", "Seitenleiste ein- oder ausblenden"),
+    ("header.toggle_menu", "Menü ein-/ausblenden"),
+    ("playlists.title", "Playlists"),
+    ("playlists.select", "Auswählen"),
+    ("playlists.done", "Fertig"),
+    ("playlists.select_for_sync", "Zum Synchronisieren wählen"),
+    ("playlists.new", "Neue Playlist"),
+    (
+        "playlists.select_to_sync",
+        "Playlist zum Synchronisieren wählen",
+    ),
+    ("playlists.choose", "Playlist wählen"),
+    (
+        "playlists.choose_description",
+        "Wähle eine Playlist für das verbundene Gerät",
+    ),
+    ("count.tracks.one", "{count} Titel"),
+    ("count.tracks.other", "{count} Titel"),
+    ("count.files.one", "{count} Datei"),
+    ("count.files.other", "{count} Dateien"),
+    ("count.failed.one", "{count} fehlgeschlagen"),
+    ("count.failed.other", "{count} fehlgeschlagen"),
+    ("count.downloads.one", "{count} Download"),
+    ("count.downloads.other", "{count} Downloads"),
+    ("count.syncs.one", "{count} Synchronisierung"),
+    ("count.syncs.other", "{count} Synchronisierungen"),
+    ("count.audio_files.one", "{count} Audiodatei"),
+    ("count.audio_files.other", "{count} Audiodateien"),
+    ("count.videos.one", "{count} Video"),
+    ("count.videos.other", "{count} Videos"),
+    ("count.playlists.one", "{count} Playlist"),
+    ("count.playlists.other", "{count} Playlists"),
+    ("count.thumbnails.one", "{count} Vorschaubild"),
+    ("count.thumbnails.other", "{count} Vorschaubilder"),
+    ("count.temp_files.one", "{count} temporäre Datei"),
+    ("count.temp_files.other", "{count} temporäre Dateien"),
+    ("banner.device_connected", "Gerät „{device}“ verbunden"),
+    ("banner.device_disconnected", "Gerät „{device}“ getrennt"),
+    ("banner.sync_now", "Jetzt synchronisieren"),
+    ("banner.clean", "Gerät bereinigen"),
+    ("banner.ignore", "Dieses Gerät ignorieren"),
+    (
+        "banner.auto_syncing",
+        "Die letzten Playlists werden automatisch synchronisiert",
+    ),
+    ("banner.dismiss", "Schließen"),
+    (
+        "banner.ignored",
+        "„{device}“ wird ignoriert. Du kannst das in seinen Einstellungen ändern.",
+    ),
+    (
+        "banner.ignore_failed",
+        "Gerät konnte nicht ignoriert werden: {error}",
+    ),
+    (
+        "toast.device_ejected",
+        "Gerät sicher ausgeworfen. Du kannst es jetzt entfernen.",
+    ),
+    ("toast.select_device", "Bitte zuerst ein Gerät auswählen"),
+    (
+        "toast.select_playlist",
+        "Bitte zuerst eine Playlist auswählen",
+    ),
+    ("toast.playlist_created", "Playlist „{name}“ erstellt"),
+    ("toast.playlist_deleted", "Playlist „{name}“ gelöscht"),
+    ("toast.dismiss", "Benachrichtigung schließen"),
+    (
+        "toast.download_failed",
+        "Download konnte nicht gestartet werden: {error}",
+    ),
+    ("toast.downloading", "„{name}“ wird heruntergeladen"),
+    (
+        "toast.create_playlist_failed",
+        "Playlist konnte nicht erstellt werden: {error}",
+    ),
+    (
+        "toast.load_devices_failed",
+        "Geräte konnten nicht geladen werden: {error}",
+    ),
+    (
+        "toast.load_playlists_failed",
+        "Playlists konnten nicht geladen werden: {error}",
+    ),
+    (
+        "toast.crashed_last_time",
+        "Youtun4 wurde beim letzten Mal unerwartet beendet: {message}",
+    ),
+    (
+        "toast.transfer_complete",
+        "Übertragung abgeschlossen: {files} auf {device} übertragen",
+    ),
+    (
+        "toast.transfer_failed",
+        "Übertragung fehlgeschlagen: {error}",
+    ),
+    ("toast.transfer_cancelled", "Übertragung abgebrochen"),
+    (
+        "toast.video_failed",
+        "„{title}“ konnte nicht heruntergeladen werden: {error}",
+    ),
+    ("toast.downloaded.one", "{count} Titel heruntergeladen"),
+    ("toast.downloaded.other", "{count} Titel heruntergeladen"),
+    ("toast.download_error", "Download fehlgeschlagen: {error}"),
+    (
+        "toast.retry_failed",
+        "Download konnte nicht wiederholt werden: {error}",
+    ),
+    ("toast.download_cancelled", "Download abgebrochen"),
+    (
+        "toast.deep_link_needs_playlist",
+        "Wähle eine Playlist und öffne den Link erneut",
+    ),
+    (
+        "toast.deep_link_failed",
+        "Link konnte nicht hinzugefügt werden: {error}",
+    ),
+    ("toast.deep_link_queued", "Download für „{name}“ eingereiht"),
+    (
+        "toast.update_available",
+        "Youtun4 {version} ist verfügbar (Kanal {channel})",
+    ),
+    (
+        "toast.syncing",
+        "„{name}“ wird mit {device} synchronisiert...",
+    ),
+    ("toast.synced", "„{name}“ erfolgreich synchronisiert"),
+    (
+        "toast.sync_failed",
+        "Playlist konnte nicht synchronisiert werden: {error}",
+    ),
+    (
+        "toast.delete_playlist_failed",
+        "Playlist konnte nicht gelöscht werden: {error}",
+    ),
+    (
+        "toast.update_playlists_failed",
+        "Playlists konnten nicht aktualisiert werden: {error}",
+    ),
+    (
+        "toast.transfer_starting",
+        "Übertragung von „{name}“ auf {device} wird gestartet...",
+    ),
+    (
+        "toast.transfer_start_failed",
+        "Übertragung konnte nicht gestartet werden: {error}",
+    ),
+    (
+        "toast.cancel_transfer_failed",
+        "Übertragung konnte nicht abgebrochen werden: {error}",
+    ),
+    (
+        "toast.cancel_download_failed",
+        "Download konnte nicht abgebrochen werden: {error}",
+    ),
+    (
+        "toast.rename_failed",
+        "Playlist konnte nicht umbenannt werden: {error}",
+    ),
+    ("toast.start_failed", "Start fehlgeschlagen: {error}"),
+    (
+        "toast.refresh_failed",
+        "Playlist konnte nicht aktualisiert werden: {error}",
+    ),
+    ("toast.archive_reset", "{videos} für „{name}“ vergessen"),
+    (
+        "toast.archive_reset_failed",
+        "Download-Archiv konnte nicht zurückgesetzt werden: {error}",
+    ),
+    ("toast.exported", "Exportiert nach {path}"),
+    (
+        "toast.export_failed",
+        "Playlist konnte nicht exportiert werden: {error}",
+    ),
+    ("toast.verify_ok", "„{name}“ ist in Ordnung ({tracks})"),
+    ("toast.verify_issues", "„{name}“ hat Probleme: {issues}"),
+    (
+        "toast.verify_failed",
+        "Playlist konnte nicht überprüft werden: {error}",
+    ),
+    (
+        "toast.open_folder_failed",
+        "Ordner konnte nicht geöffnet werden: {error}",
+    ),
+    ("toast.nothing_running", "Es läuft nichts"),
+    ("toast.tasks_stopped.one", "{count} Aufgabe gestoppt"),
+    ("toast.tasks_stopped.other", "{count} Aufgaben gestoppt"),
+    (
+        "toast.stop_tasks_failed",
+        "Aufgaben konnten nicht gestoppt werden: {error}",
+    ),
+    ("toast.select_device_first", "Wähle zuerst ein Gerät"),
+    (
+        "toast.select_playlist_to_delete",
+        "Wähle eine Playlist zum Löschen",
+    ),
+    (
+        "toast.show_track_failed",
+        "Titel konnte nicht angezeigt werden: {error}",
+    ),
+    (
+        "toast.update_tracks_failed",
+        "Titel konnten nicht aktualisiert werden: {error}",
+    ),
+    ("toast.lyrics_saved", "Liedtext von „{name}“ gespeichert"),
+    (
+        "toast.lyrics_not_found",
+        "Kein Liedtext für „{name}“ gefunden",
+    ),
+    (
+        "toast.lyrics_failed",
+        "Liedtext konnte nicht abgerufen werden: {error}",
+    ),
+    ("toast.retry", "Erneut versuchen"),
+    ("toast.action_countdown", "{label} ({seconds} s)"),
+    ("settings.title", "Einstellungen"),
+    ("settings.close", "Einstellungen schließen"),
+    ("settings.section.downloads", "Downloads"),
+    ("settings.section.devices", "Geräte"),
+    ("settings.section.storage", "Speicher"),
+    ("settings.section.cache", "Cache"),
+    ("settings.section.notifications", "Benachrichtigungen"),
+    ("settings.section.appearance", "Darstellung"),
+    ("settings.section.advanced", "Erweitert"),
+    ("settings.reset", "Auf Standard zurücksetzen"),
+    ("settings.save", "Einstellungen speichern"),
+    ("settings.saving", "Speichern..."),
+    ("settings.saved", "Einstellungen gespeichert!"),
+    (
+        "settings.load_failed",
+        "Einstellungen konnten nicht geladen werden: {error}",
+    ),
+    (
+        "settings.save_failed",
+        "Einstellungen konnten nicht gespeichert werden: {error}",
+    ),
+    ("settings.language", "Sprache"),
+    (
+        "settings.language.description",
+        "Sprache der Oberfläche und der Benachrichtigungen.",
+    ),
+    ("settings.language.system", "Systemsprache"),
+    ("settings.write_tags", "Tags schreiben"),
+    (
+        "settings.write_tags.description",
+        "Titel, Interpret, Album und Titelnummer zu heruntergeladenen Songs hinzufügen",
+    ),
+    ("settings.embed_cover_art", "Cover einbetten"),
+    (
+        "settings.embed_cover_art.description",
+        "Das Vorschaubild des Videos als Cover verwenden",
+    ),
+    ("settings.fetch_lyrics", "Liedtexte abrufen"),
+    (
+        "settings.fetch_lyrics.description",
+        "Synchronisierte oder einfache Liedtexte für heruntergeladene Songs suchen",
+    ),
+    ("settings.save_lrc", ".lrc-Dateien speichern"),
+    (
+        "settings.save_lrc.description",
+        "Liedtexte neben dem Song speichern, für Player mit synchronisierten Liedtexten",
+    ),
+    ("settings.embed_lyrics", "Liedtexte einbetten"),
+    (
+        "settings.embed_lyrics.description",
+        "Liedtexte in den Tags des Songs speichern",
+    ),
+    ("settings.auto_start", "Automatisch starten"),
+    (
+        "settings.auto_start.description",
+        "Downloads starten, sobald sie eingereiht sind",
+    ),
+    (
+        "settings.auto_retry",
+        "Fehlgeschlagene Downloads wiederholen",
+    ),
+    (
+        "settings.auto_retry.description",
+        "Fehlgeschlagene Downloads automatisch erneut einreihen",
+    ),
+    ("settings.work_offline", "Offline arbeiten"),
+    (
+        "settings.work_offline.description",
+        "Eingereihte Downloads warten, bis der Offline-Modus ausgeschaltet ist",
+    ),
+    ("settings.cache_enabled", "Cache aktivieren"),
+    (
+        "settings.cache_enabled.description",
+        "Abgerufene Daten zur Wiederverwendung auf der Festplatte behalten",
+    ),
+    (
+        "settings.cache_metadata",
+        "Titel-Metadaten zwischenspeichern",
+    ),
+    (
+        "settings.cache_metadata.description",
+        "Tags nicht aus jeder Datei neu lesen",
+    ),
+    (
+        "settings.cache_thumbnails",
+        "Vorschaubilder zwischenspeichern",
+    ),
+    (
+        "settings.cache_thumbnails.description",
+        "Playlist- und Titelbilder auf der Festplatte behalten",
+    ),
+    ("settings.notify_download", "Download abgeschlossen"),
+    (
+        "settings.notify_download.description",
+        "Benachrichtigen, wenn der Download einer Playlist endet",
+    ),
+    ("settings.notify_sync", "Synchronisierung abgeschlossen"),
+    (
+        "settings.notify_sync.description",
+        "Benachrichtigen, wenn die Synchronisierung mit einem Gerät endet",
+    ),
+    ("settings.notify_errors", "Fehler"),
+    (
+        "settings.notify_errors.description",
+        "Benachrichtigungen für Fehler und Warnungen anzeigen",
+    ),
+    ("settings.notify_device", "Gerät verbunden"),
+    (
+        "settings.notify_device.description",
+        "Benachrichtigen, wenn ein USB-Gerät verbunden wird",
+    ),
+    ("settings.webhook_enabled", "Webhook aufrufen"),
+    (
+        "settings.webhook_enabled.description",
+        "Eine Nachricht senden, wenn ein Download oder eine Synchronisierung endet",
+    ),
+    ("settings.high_contrast", "Hoher Kontrast"),
+    (
+        "settings.high_contrast.description",
+        "Kräftigere Text-, Rahmen- und Statusfarben; ersetzt die eigene Akzentfarbe",
+    ),
+    ("settings.minimize_to_tray", "In den Infobereich minimieren"),
+    (
+        "settings.minimize_to_tray.description",
+        "Beim Schließen des Fensters wird Youtun4 im Infobereich ausgeblendet",
+    ),
+    ("settings.pause_on_battery", "Im Akkubetrieb pausieren"),
+    (
+        "settings.pause_on_battery.description",
+        "Warten, bis der Computer angeschlossen ist",
+    ),
+    (
+        "settings.pause_on_metered",
+        "In getakteten Netzen pausieren",
+    ),
+    (
+        "settings.pause_on_metered.description",
+        "Auf eine nicht getaktete Verbindung warten",
+    ),
+    (
+        "settings.pause_on_guessed_metered",
+        "Vermutlich getaktete Netze einbeziehen",
+    ),
+    (
+        "settings.pause_on_guessed_metered.description",
+        "Auch bei Verbindungen wie Handy-Hotspots pausieren, die nur vermutlich getaktet sind",
+    ),
+    ("settings.quality", "Download-Qualität"),
+    (
+        "settings.quality.description",
+        "Wähle die Audioqualität für YouTube-Downloads. Playlists können sie in ihren Einstellungen überschreiben.",
+    ),
+    ("settings.lyrics", "Liedtexte"),
+    (
+        "settings.lyrics.description",
+        "Nach jedem Download Liedtexte auf LRCLIB suchen. Playlists können das in ihren Einstellungen überschreiben.",
+    ),
+    ("settings.file_names", "Dateinamen"),
+    ("settings.file_names.template", "Dateinamensvorlage"),
+    ("settings.queue", "Download-Warteschlange"),
+    (
+        "settings.queue.description",
+        "Lege fest, wie eingereihte Downloads ausgeführt werden.",
+    ),
+    ("settings.queue.max_concurrent", "Gleichzeitige Downloads"),
+    (
+        "settings.queue.concurrency",
+        "Gleichzeitige Videos pro Playlist",
+    ),
+    ("settings.queue.speed_limit", "Geschwindigkeitsbegrenzung"),
+    ("settings.queue.max_retries", "Wiederholungen pro Download"),
+    ("settings.offline", "Offline-Modus"),
+    (
+        "settings.offline.description",
+        "Keinen Netzwerkzugriff nutzen und nur zwischengespeicherte Daten verwenden.",
+    ),
+    ("settings.preferred_device", "Bevorzugtes Gerät"),
+    (
+        "settings.preferred_device.description",
+        "Das Gerät, das beim Verbinden automatisch ausgewählt wird. Wähle eines aus der Geräteliste.",
+    ),
+    ("settings.device_detection", "Geräteerkennung"),
+    (
+        "settings.device_detection.description",
+        "Wie oft nach verbundenen oder entfernten Geräten gesucht wird.",
+    ),
+    (
+        "settings.device_detection.interval",
+        "Prüfen alle (Sekunden)",
+    ),
+    ("settings.storage_location", "Speicherort"),
+    (
+        "settings.storage_location.description",
+        "Wähle, wo deine Playlists auf deinem Computer gespeichert werden.",
+    ),
+    (
+        "settings.storage_location.directory",
+        "Playlist-Verzeichnis",
+    ),
+    ("settings.library_limits", "Bibliotheksgrenzen"),
+    (
+        "settings.library_limits.description",
+        "Downloads stoppen, wenn die Bibliothek ihre Größengrenze erreicht oder die Festplatte zu voll wird.",
+    ),
+    (
+        "settings.library_limits.max_size",
+        "Maximale Bibliotheksgröße (GB)",
+    ),
+    (
+        "settings.library_limits.no_limit",
+        "Leer lassen für keine Begrenzung.",
+    ),
+    (
+        "settings.library_limits.min_free",
+        "Auf der Festplatte frei halten (GB)",
+    ),
+    ("settings.cache", "Cache"),
+    (
+        "settings.cache.description",
+        "Zwischengespeicherte Playlist-Details, Metadaten und Vorschaubilder machen die App schneller und offline nutzbar.",
+    ),
+    ("settings.cache.max_size", "Maximale Cache-Größe (MB)"),
+    ("settings.cache.ttl", "Einträge behalten für (Tage)"),
+    (
+        "settings.clear_cache.description",
+        "Alle zwischengespeicherten Playlist-Details, Metadaten und Vorschaubilder entfernen. Sie werden bei Bedarf neu abgerufen.",
+    ),
+    ("settings.notifications", "Benachrichtigungseinstellungen"),
+    (
+        "settings.notifications.description",
+        "Wähle, welche Benachrichtigungen du erhalten möchtest.",
+    ),
+    ("settings.webhook", "Webhook"),
+    (
+        "settings.webhook.description",
+        "Eine Nachricht an eine URL senden, etwa ein ntfy-Thema oder einen Discord-Webhook, wenn Downloads und Synchronisierungen enden. Im Offline-Modus werden Aufrufe übersprungen.",
+    ),
+    ("settings.webhook.url", "Webhook-URL"),
+    ("settings.webhook.save_url", "URL speichern"),
+    (
+        "settings.webhook.url_hint",
+        "Die URL wird im sicheren Speicher abgelegt, nicht in der Einstellungsdatei.",
+    ),
+    ("settings.webhook.payload", "Inhaltsvorlage"),
+    ("settings.webhook.test", "Testnachricht senden"),
+    ("settings.theme", "Design"),
+    (
+        "settings.theme.description",
+        "Wähle das Aussehen der Anwendung.",
+    ),
+    (
+        "settings.theme.system_hint",
+        "System folgt der hellen oder dunklen Einstellung deines Betriebssystems.",
+    ),
+    ("settings.accent_color", "Akzentfarbe"),
+    (
+        "settings.accent_color.description",
+        "Farbe von Schaltflächen, Hervorhebungen und Fokusrahmen.",
+    ),
+    ("settings.accent_color.default", "Standard verwenden"),
+    ("settings.accessibility", "Barrierefreiheit"),
+    ("settings.extraction", "Extraktion"),
+    (
+        "settings.extraction.description",
+        "Wähle, wie Playlists von YouTube gelesen werden. yt-dlp muss separat installiert werden.",
+    ),
+    ("settings.extraction.yt_dlp_path", "Speicherort von yt-dlp"),
+    ("settings.background", "Hintergrund"),
+    (
+        "settings.background.description",
+        "Downloads und Synchronisierungen bei geschlossenem Fenster weiterlaufen lassen.",
+    ),
+    ("settings.power", "Energie und Netzwerk"),
+    (
+        "settings.power.description",
+        "Downloads und andere aufwendige Arbeit pausieren, wenn Ressourcen knapp sind.",
+    ),
+    ("settings.updates", "Updates"),
+    (
+        "settings.updates.description",
+        "Wähle, nach welchen Versionen Youtun4 beim Start sucht.",
+    ),
+    ("settings.log", "Anwendungsprotokoll"),
+    (
+        "settings.log.description",
+        "Aktuelle Protokolleinträge, aktualisiert solange dieser Bereich geöffnet ist. Kopiere sie in einen Fehlerbericht, wenn ein Download fehlschlägt.",
+    ),
+    (
+        "settings.file_names.description",
+        "Heruntergeladene Songs nach {fields} benennen. {padded} füllt den Index mit Nullen auf. Playlists können das in ihren Einstellungen überschreiben.",
+    ),
+    ("settings.file_names.example", "Beispiel: {name}"),
+    ("settings.unlimited", "Unbegrenzt"),
+    ("settings.preferred_device.none", "Kein bevorzugtes Gerät"),
+    (
+        "settings.storage_location.placeholder",
+        "Verzeichnispfad eingeben...",
+    ),
+    ("settings.storage_location.default", "Standard: {path}"),
+    ("settings.clear_cache", "Cache leeren"),
+    ("settings.webhook.url_saved", "Webhook-URL gespeichert"),
+    ("settings.webhook.url_removed", "Webhook-URL entfernt"),
+    ("settings.webhook.sending", "Testnachricht wird gesendet..."),
+    ("settings.webhook.sent", "Testnachricht gesendet"),
+    (
+        "settings.webhook.url_replace",
+        "Eine URL ist gespeichert; gib eine neue ein, um sie zu ersetzen",
+    ),
+    (
+        "settings.webhook.payload_hint",
+        "Verwende {fields}. Vorlagen, die mit {brace} beginnen, werden als JSON gesendet, z. B. {example} für Discord.",
+    ),
+    (
+        "settings.webhook.download_completed",
+        "Wenn der Download einer Playlist endet",
+    ),
+    (
+        "settings.webhook.download_failed",
+        "Wenn der Download einer Playlist fehlschlägt",
+    ),
+    (
+        "settings.webhook.sync_completed",
+        "Wenn die Synchronisierung mit einem Gerät endet",
+    ),
+    (
+        "settings.webhook.sync_failed",
+        "Wenn die Synchronisierung mit einem Gerät fehlschlägt",
+    ),
+    ("settings.theme.dark", "Dunkel"),
+    ("settings.theme.light", "Hell"),
+    ("settings.theme.system", "System"),
+    ("settings.extraction.auto", "Automatisch"),
+    (
+        "settings.extraction.auto.description",
+        "Integriert, mit yt-dlp, wenn YouTube-Seiten nicht gelesen werden können",
+    ),
+    ("settings.extraction.built_in", "Integriert"),
+    (
+        "settings.extraction.built_in.description",
+        "yt-dlp nie ausführen",
+    ),
+    ("settings.extraction.yt_dlp", "yt-dlp"),
+    (
+        "settings.extraction.yt_dlp.description",
+        "Alles mit yt-dlp lesen und herunterladen",
+    ),
+    (
+        "settings.extraction.yt_dlp_path.placeholder",
+        "yt-dlp (im PATH gefunden)",
+    ),
+    ("settings.updates.stable", "Stabil"),
+    (
+        "settings.updates.stable.description",
+        "Nur getestete Versionen",
+    ),
+    ("settings.updates.beta", "Beta"),
+    (
+        "settings.updates.beta.description",
+        "Vorabversionen mit den neuesten Funktionen",
+    ),
+    ("delete_playlist.title", "Playlist löschen"),
+    (
+        "delete_playlist.confirm",
+        "Möchtest du „{name}“ wirklich löschen?",
+    ),
+    ("delete_playlist.tracks", "Titel:"),
+    ("delete_playlist.size", "Größe:"),
+    ("delete_playlist.source", "Quelle:"),
+    ("delete_playlist.youtube", "YouTube-Playlist"),
+    (
+        "delete_playlist.warning",
+        "Dies kann nicht rückgängig gemacht werden. Alle Titel und Dateien werden dauerhaft aus dem lokalen Speicher gelöscht.",
+    ),
+    ("shortcuts.title", "Tastenkürzel"),
+    ("shortcuts.close", "Tastenkürzel schließen"),
+    ("shortcuts.group.general", "Allgemein"),
+    ("shortcuts.group.navigation", "Navigation"),
+    ("shortcuts.group.playlists", "Playlists"),
+    ("shortcuts.group.dialogs", "Dialoge"),
+    ("shortcuts.show", "Tastenkürzel anzeigen"),
+    ("shortcuts.search", "Bibliothek durchsuchen"),
+    ("shortcuts.settings", "Einstellungen öffnen"),
+    ("shortcuts.undo", "Letzte Änderung rückgängig machen"),
+    ("shortcuts.playlists", "Zu den Playlists"),
+    ("shortcuts.history", "Zum Verlauf"),
+    ("shortcuts.device", "Zum ausgewählten Gerät"),
+    (
+        "shortcuts.delete",
+        "Geöffnete oder ausgewählte Playlist löschen",
+    ),
+    (
+        "shortcuts.preview",
+        "Titelvorschau abspielen oder pausieren",
+    ),
+    ("shortcuts.focus", "Zwischen Bedienelementen wechseln"),
+    ("shortcuts.close_dialog", "Dialog schließen"),
+    ("rename_playlist.title", "Playlist umbenennen"),
+    ("rename_playlist.label", "Neuer Name"),
+    ("rename_playlist.confirm", "Umbenennen"),
+    ("sync_wizard.step.device", "Gerät"),
+    ("sync_wizard.step.playlists", "Playlists"),
+    ("sync_wizard.step.review", "Überprüfen"),
+    ("sync_wizard.step.sync", "Synchronisierung"),
+    ("sync_wizard.change.add", "Hinzufügen"),
+    ("sync_wizard.change.replace", "Ersetzen"),
+    ("sync_wizard.change.keep", "Behalten"),
+    ("sync_wizard.change.remove", "Entfernen"),
+    (
+        "sync_wizard.synced.one",
+        "{count} Datei mit dem Gerät synchronisiert",
+    ),
+    (
+        "sync_wizard.synced.other",
+        "{count} Dateien mit dem Gerät synchronisiert",
+    ),
+    (
+        "sync_wizard.finished_announcement.one",
+        "Synchronisierung abgeschlossen. {count} Datei übertragen.",
+    ),
+    (
+        "sync_wizard.finished_announcement.other",
+        "Synchronisierung abgeschlossen. {count} Dateien übertragen.",
+    ),
+    (
+        "sync_wizard.failed",
+        "Synchronisierung fehlgeschlagen: {error}",
+    ),
+    (
+        "sync_wizard.failed_announcement",
+        "Synchronisierung fehlgeschlagen. {error}",
+    ),
+    (
+        "sync_wizard.cancel_failed",
+        "Synchronisierung konnte nicht abgebrochen werden: {error}",
+    ),
+    (
+        "sync_wizard.no_devices",
+        "Schließe ein Gerät zum Synchronisieren an.",
+    ),
+    ("sync_wizard.device_space", "{free} von {total} frei"),
+    ("sync_wizard.selection", "{count} ausgewählt · {size}"),
+    (
+        "sync_wizard.cleanup",
+        "Zuerst andere Dateien vom Gerät entfernen",
+    ),
+    ("sync_wizard.comparing", "Abgleich mit dem Gerät..."),
+    ("sync_wizard.no_preview", "Keine Vorschau verfügbar."),
+    (
+        "sync_wizard.preview_summary",
+        "{write} zu schreiben, {remove} zu entfernen",
+    ),
+    (
+        "sync_wizard.fits_after_cleanup",
+        "Passt, sobald die alten Dateien vom Gerät entfernt sind",
+    ),
+    (
+        "sync_wizard.capacity_label",
+        "{kept} % behalten, {incoming} % neu",
+    ),
+    ("sync_wizard.kept", "Behalten"),
+    ("sync_wizard.incoming", "Aus dieser Synchronisierung"),
+    (
+        "sync_wizard.outcome.success",
+        "Synchronisierung abgeschlossen",
+    ),
+    (
+        "sync_wizard.outcome.cancelled",
+        "Synchronisierung abgebrochen",
+    ),
+    (
+        "sync_wizard.outcome.failed",
+        "Synchronisierung fehlgeschlagen",
+    ),
+    (
+        "sync_wizard.outcome.counts",
+        "{copied} kopiert, {skipped} übersprungen, {failed} fehlgeschlagen",
+    ),
+    ("sync_wizard.starting", "Synchronisierung wird gestartet..."),
+    ("sync_wizard.progress", "Fortschritt der Synchronisierung"),
+    ("sync_wizard.close", "Synchronisierungsassistent schließen"),
+    ("sync_wizard.cancel", "Synchronisierung abbrechen"),
+    ("sync_wizard.start", "Synchronisierung starten"),
+    ("history.range.week", "Letzte 7 Tage"),
+    ("history.range.month", "Letzte 30 Tage"),
+    ("history.range.quarter", "Letzte 90 Tage"),
+    ("history.range.all", "Gesamter Zeitraum"),
+    ("history.status.completed", "Abgeschlossen"),
+    (
+        "history.status.completed_with_errors",
+        "Mit Fehlern abgeschlossen",
+    ),
+    ("history.status.failed", "Fehlgeschlagen"),
+    ("history.status.cancelled", "Abgebrochen"),
+    ("history.entry.download", "Download"),
+    ("history.entry.sync", "Synchronisierung mit dem Gerät"),
+    ("history.entry.sync_to", "Synchronisierung mit {device}"),
+    ("history.stats.this_month", "{size} diesen Monat"),
+    ("history.stats.total", "{size} insgesamt"),
+    ("history.stats.average_speed", "{speed} im Durchschnitt"),
+    (
+        "history.pruned",
+        "{count} Verlaufseinträge älter als {days} Tage entfernt",
+    ),
+    (
+        "history.prune_failed",
+        "Alte Einträge konnten nicht entfernt werden: {error}",
+    ),
+    ("history.cleared.one", "{count} Verlaufseintrag entfernt"),
+    ("history.cleared.other", "{count} Verlaufseinträge entfernt"),
+    (
+        "history.clear_failed",
+        "Verlauf konnte nicht geleert werden: {error}",
+    ),
+    ("history.chart.week", "Woche vom {week}: {size}"),
+    ("history.chart.title", "Pro Woche heruntergeladen"),
+    ("history.copied", "{size} kopiert"),
+    ("history.loading", "Verlauf wird geladen..."),
+    (
+        "history.no_matches",
+        "Keine Downloads oder Synchronisierungen entsprechen diesen Filtern",
+    ),
+    ("history.prune", "Alte Einträge entfernen"),
+    (
+        "history.prune_hint",
+        "Einträge älter als {days} Tage entfernen",
+    ),
+    ("history.clear", "Verlauf leeren"),
+    (
+        "history.clear_confirm",
+        "Alle Downloads und Synchronisierungen aus dem Verlauf entfernen?",
+    ),
+    ("history.filter.date", "Datum"),
+    ("history.filter.type", "Art"),
+    (
+        "history.filter.all_kinds",
+        "Downloads und Synchronisierungen",
+    ),
+    ("history.filter.downloads", "Downloads"),
+    ("history.filter.syncs", "Synchronisierungen"),
+    ("history.filter.status", "Status"),
+    ("history.filter.any_status", "Beliebiger Status"),
+    ("history.filter.playlist", "Playlist"),
+    ("history.filter.all_playlists", "Alle Playlists"),
+    ("device_detail.almost_full", "Das Gerät ist fast voll"),
+    (
+        "device_detail.files_failed.one",
+        "{count} Datei konnte bei der letzten Synchronisierung nicht kopiert werden",
+    ),
+    (
+        "device_detail.files_failed.other",
+        "{count} Dateien konnten bei der letzten Synchronisierung nicht kopiert werden",
+    ),
+    (
+        "device_detail.unreadable.one",
+        "{count} Element auf dem Gerät konnte nicht gelesen werden",
+    ),
+    (
+        "device_detail.unreadable.other",
+        "{count} Elemente auf dem Gerät konnten nicht gelesen werden",
+    ),
+    (
+        "device_detail.settings_saved",
+        "Geräteeinstellungen gespeichert",
+    ),
+    (
+        "device_detail.settings_save_failed",
+        "Geräteeinstellungen konnten nicht gespeichert werden: {error}",
+    ),
+    ("device_detail.verifying", "Wird überprüft..."),
+    (
+        "device_detail.verify_failed",
+        "Gerät konnte nicht überprüft werden: {error}",
+    ),
+    ("device_detail.cleaning", "Wird bereinigt..."),
+    (
+        "device_detail.cleaned",
+        "{files} entfernt, {size} freigegeben",
+    ),
+    (
+        "device_detail.clean_failed",
+        "Gerät konnte nicht bereinigt werden: {error}",
+    ),
+    ("device_detail.ejecting", "Wird ausgeworfen..."),
+    (
+        "device_detail.eject_refused",
+        "Das Gerät konnte nicht ausgeworfen werden",
+    ),
+    (
+        "device_detail.eject_failed",
+        "Gerät konnte nicht ausgeworfen werden: {error}",
+    ),
+    ("device_detail.scanning", "Gerät wird durchsucht..."),
+    (
+        "device_detail.usage_label",
+        "{music} % Musik, {other} % andere Dateien, {system} % System",
+    ),
+    ("device_detail.music", "Musik"),
+    ("device_detail.other", "Andere"),
+    ("device_detail.system", "System"),
+    ("device_detail.free", "Frei"),
+    ("device_detail.free_of", "{free} von {total}"),
+    ("device_detail.no_problems", "Keine Probleme gefunden"),
+    ("device_detail.not_synced", "Noch nicht synchronisiert"),
+    ("device_detail.files_copied.one", "{count} Datei kopiert"),
+    (
+        "device_detail.files_copied.other",
+        "{count} Dateien kopiert",
+    ),
+    (
+        "device_detail.verify_ok.one",
+        "{count} Titel ist auf dem Gerät",
+    ),
+    (
+        "device_detail.verify_ok.other",
+        "Alle {count} Titel sind auf dem Gerät",
+    ),
+    (
+        "device_detail.verify_counts",
+        "{matching} übereinstimmend, {missing} fehlend, {different} abweichend",
+    ),
+    ("device_detail.sync", "Synchronisieren"),
+    ("device_detail.verify", "Überprüfen"),
+    (
+        "device_detail.verify_hint",
+        "Gerät mit den Playlists der letzten Synchronisierung vergleichen",
+    ),
+    ("device_detail.clean", "Bereinigen"),
+    ("device_detail.eject", "Auswerfen"),
+    (
+        "device_detail.disconnected",
+        "Dieses Gerät ist nicht mehr verbunden.",
+    ),
+    (
+        "device_detail.clean_confirm",
+        "Alles auf dem Gerät außer versteckten und Systemdateien löschen?",
+    ),
+    ("device_detail.delete_files", "Dateien löschen"),
+    ("device_detail.last_sync", "Letzte Synchronisierung"),
+    ("device_detail.nickname", "Spitzname"),
+    (
+        "device_detail.auto_sync",
+        "Die letzten Playlists erneut synchronisieren, wenn dieses Gerät angeschlossen wird",
+    ),
+    (
+        "device_detail.ignore",
+        "Dieses Gerät beim Anschließen ignorieren",
+    ),
+    ("onboarding.step.storage", "Speicher"),
+    ("onboarding.step.quality", "Qualität"),
+    ("onboarding.step.playlist", "Erste Playlist"),
+    ("onboarding.step.device", "Gerät"),
+    ("onboarding.name_required", "Gib der Playlist einen Namen"),
+    (
+        "onboarding.devices_failed",
+        "Geräte konnten nicht gesucht werden: {error}",
+    ),
+    (
+        "onboarding.directory_required",
+        "Wähle einen Ordner für deine Playlists",
+    ),
+    (
+        "onboarding.save_failed",
+        "Einrichtung konnte nicht gespeichert werden: {error}",
+    ),
+    ("onboarding.invalid_url", "Keine YouTube-Playlist-URL"),
+    (
+        "onboarding.storage.description",
+        "Wähle, wo Youtun4 deine Playlists speichert. Jede Playlist ist ein Ordner mit MP3-Dateien.",
+    ),
+    ("onboarding.storage.folder", "Playlist-Ordner"),
+    ("onboarding.storage.suggested", "Vorschlag: {path}"),
+    (
+        "onboarding.storage.music_found",
+        "Auf diesem Computer gefundene Musik:",
+    ),
+    (
+        "onboarding.quality.description",
+        "Höhere Qualität klingt besser, füllt deinen Player aber schneller. Du kannst das später in den Einstellungen ändern.",
+    ),
+    (
+        "onboarding.quality.low_description",
+        "128 kbps - Passt die meisten Songs",
+    ),
+    (
+        "onboarding.playlist.description",
+        "Füge einen YouTube-Playlist-Link ein, um deine Bibliothek zu beginnen, oder überspringe diesen Schritt.",
+    ),
+    ("onboarding.playlist.url", "YouTube-Playlist-URL"),
+    ("onboarding.playlist.name", "Name der Playlist"),
+    (
+        "onboarding.playlist.name_placeholder",
+        "Meine erste Playlist",
+    ),
+    (
+        "onboarding.device.description",
+        "Schließe deinen MP3-Player an, um zu prüfen, ob Youtun4 ihn erkennt. Wähle ihn aus, damit er beim nächsten Mal automatisch ausgewählt wird.",
+    ),
+    ("onboarding.device.looking", "Suche nach Geräten..."),
+    ("onboarding.device.none", "Noch kein Gerät erkannt."),
+    ("onboarding.device.known", "Bereits verwendet: {devices}"),
+    ("onboarding.device.connected", "{device} (verbunden)"),
+    ("onboarding.device.check_again", "Erneut prüfen"),
+    ("onboarding.title", "Willkommen bei Youtun4"),
+    (
+        "onboarding.subtitle",
+        "Ein paar Entscheidungen, und du kannst Musik auf deinen Player bringen.",
+    ),
+    ("onboarding.setting_up", "Wird eingerichtet..."),
+    ("onboarding.finish", "Fertig"),
+    ("onboarding.skip", "Überspringen"),
+    ("quality.low", "Niedrig"),
+    ("quality.medium", "Mittel"),
+    (
+        "quality.medium.description",
+        "192 kbps - Ausgewogene Qualität und Größe",
+    ),
+    ("quality.high", "Hoch"),
+    (
+        "quality.high.description",
+        "320 kbps - Beste Qualität, größere Dateien",
+    ),
+    (
+        "quality.low.description",
+        "128 kbps - Kleinere Dateien, geringere Qualität",
+    ),
+    ("webhook_event.download_completed", "Download abgeschlossen"),
+    ("webhook_event.download_failed", "Download fehlgeschlagen"),
+    (
+        "webhook_event.sync_completed",
+        "Synchronisierung abgeschlossen",
+    ),
+    (
+        "webhook_event.sync_failed",
+        "Synchronisierung fehlgeschlagen",
+    ),
+    (
+        "announce.sync_finished",
+        "Synchronisierung beendet. {files} übertragen.",
+    ),
+    (
+        "announce.sync_failed",
+        "Synchronisierung fehlgeschlagen. {error}",
+    ),
+    (
+        "announce.download_finished",
+        "Download beendet. {downloaded} heruntergeladen, {failed} fehlgeschlagen, {skipped} übersprungen.",
+    ),
+    (
+        "announce.download_failed",
+        "Download fehlgeschlagen. {error}",
+    ),
+    ("undo.renamed", "„{from}“ in „{to}“ umbenannt"),
+    ("undo.tracks_removed", "{tracks} aus „{name}“ entfernt"),
+    ("batch.partial", "{succeeded} von {items}"),
+    ("batch.deleted", "Gelöscht: {items}"),
+    ("batch.moved", "Verschoben: {items}"),
+    ("batch.tagged", "Getaggt: {items}"),
+    ("batch.first_failure", ". {item}: {error}"),
+    ("batch.more_failures.one", " (und {count} weiterer)"),
+    ("batch.more_failures.other", " (und {count} weitere)"),
+    ("batch.toolbar", "Auswahlaktionen"),
+    ("batch.selected", "Ausgewählt: {items}"),
+    ("batch.select_all", "Alle auswählen"),
+    ("batch.sync_selected", "Auswahl synchronisieren"),
+    ("batch.move", "Verschieben"),
+    ("batch.tag", "Taggen"),
+    ("batch.tag_name", "Tag-Name"),
+    ("batch.add_tag", "Tag hinzufügen"),
+    ("batch.move_target", "Ziel-Playlist"),
+    ("batch.move_here", "Hierher verschieben"),
+    (
+        "batch.confirm_delete",
+        "{items} löschen? Das kann nicht rückgängig gemacht werden.",
+    ),
+    ("playlist_detail.back_label", "Zurück zur Playlist-Liste"),
+    ("playlist_detail.settings", "Playlist-Einstellungen"),
+    ("playlist_detail.open_folder", "Im Ordner anzeigen"),
+    (
+        "playlist_detail.open_folder.title",
+        "Im Dateimanager öffnen",
+    ),
+    ("playlist_detail.repair", "Reparieren"),
+    (
+        "playlist_detail.repair.title",
+        "Metadaten des Playlist-Ordners korrigieren",
+    ),
+    ("playlist_detail.checksums", "Prüfsummen"),
+    (
+        "playlist_detail.checksums.title",
+        "Prüfsummen der Titel speichern, um sie später zu überprüfen",
+    ),
+    ("playlist_detail.sync", "Synchronisieren"),
+    ("playlist_detail.delete.title", "Playlist löschen"),
+    ("playlist_detail.source", "Quelle"),
+    ("playlist_detail.created", "Erstellt"),
+    ("playlist_detail.modified", "Geändert"),
+    (
+        "playlist_detail.updating",
+        "„{name}“ wird aus der Quelle aktualisiert",
+    ),
+    ("playlist_detail.checking", "Wird geprüft..."),
+    (
+        "playlist_detail.check_updates",
+        "Nach Aktualisierungen suchen",
+    ),
+    (
+        "playlist_detail.remove_missing.one",
+        "{count} Titel entfernen, der nicht mehr in der Quelle ist",
+    ),
+    (
+        "playlist_detail.remove_missing.other",
+        "{count} Titel entfernen, die nicht mehr in der Quelle sind",
+    ),
+    ("playlist_detail.update", "Aktualisieren"),
+    (
+        "playlist_detail.load_failed",
+        "Playlist konnte nicht geladen werden",
+    ),
+    ("playlist_detail.tracks", "Titel"),
+    (
+        "playlist_detail.select_hint",
+        "Klicke auf Titel, um sie auszuwählen; Umschalt+Klick wählt einen Bereich",
+    ),
+    (
+        "playlist_detail.track_hint",
+        "Klicke auf einen Titel, um Details anzuzeigen",
+    ),
+    ("playlist_detail.not_found", "Playlist nicht gefunden"),
+    ("track_preview.title", "Titelvorschau"),
+    ("track_preview.reveal", "Im Dateimanager anzeigen"),
+    ("track_preview.close", "Vorschau schließen"),
+    (
+        "track_preview.no_metadata",
+        "Keine ID3-Metadaten in dieser Datei gefunden",
+    ),
+    ("track_preview.empty", "Kein Titel ausgewählt"),
+    ("track.artist", "Interpret"),
+    ("track.album", "Album"),
+    ("track.number", "Titelnummer"),
+    ("track.duration", "Dauer"),
+    ("track.year", "Jahr"),
+    ("track.genre", "Genre"),
+    ("track.bitrate", "Bitrate"),
+    ("track.file_size", "Dateigröße"),
+    ("track.file_name", "Dateiname"),
+    (
+        "playlist_diff.new.one",
+        "{count} neuer Titel wird heruntergeladen",
+    ),
+    (
+        "playlist_diff.new.other",
+        "{count} neue Titel werden heruntergeladen",
+    ),
+    ("playlist_diff.removed.one", "{count} entfernt"),
+    ("playlist_diff.removed.other", "{count} entfernt"),
+    ("playlist_diff.renamed.one", "{count} umbenannt"),
+    ("playlist_diff.renamed.other", "{count} umbenannt"),
+    ("playlist_diff.moved.one", "{count} verschoben"),
+    ("playlist_diff.moved.other", "{count} verschoben"),
+    ("playlist_diff.unavailable.one", "{count} nicht verfügbar"),
+    ("playlist_diff.unavailable.other", "{count} nicht verfügbar"),
+    ("playlist_diff.up_to_date", "Auf dem neuesten Stand"),
+    ("size_estimate.summary", "~{size}, {length} Audio"),
+    (
+        "playlist_settings.use_global",
+        "Globale Einstellung verwenden",
+    ),
+    ("playlist_settings.quality.low", "Niedrig (128 kbps)"),
+    ("playlist_settings.quality.medium", "Mittel (192 kbps)"),
+    ("playlist_settings.quality.high", "Hoch (320 kbps)"),
+    ("playlist_settings.lyrics.on", "Liedtexte abrufen"),
+    ("playlist_settings.lyrics.off", "Keine Liedtexte abrufen"),
+    (
+        "playlist_settings.bitrate.default",
+        "Download-Qualität verwenden",
+    ),
+    ("playlist_settings.format.mp3", "MP3"),
+    (
+        "playlist_settings.format.original",
+        "Original (keine Konvertierung)",
+    ),
+    ("playlist_settings.tags.none", "Keine Tags"),
+    ("playlist_settings.tags.tags", "Nur Tags"),
+    ("playlist_settings.tags.cover_art", "Tags und Cover"),
+    ("playlist_settings.refresh.never", "Nie"),
+    ("playlist_settings.refresh.6h", "Alle 6 Stunden"),
+    ("playlist_settings.refresh.12h", "Alle 12 Stunden"),
+    ("playlist_settings.refresh.24h", "Täglich"),
+    ("playlist_settings.refresh.nightly", "Jede Nacht um 3:00"),
+    ("playlist_settings.refresh.weekly", "Wöchentlich"),
+    (
+        "playlist_settings.saved",
+        "Einstellungen für „{name}“ gespeichert",
+    ),
+    (
+        "playlist_settings.save_failed",
+        "Playlist-Einstellungen konnten nicht gespeichert werden: {error}",
+    ),
+    ("playlist_settings.quality", "Download-Qualität"),
+    (
+        "playlist_settings.quality.hint",
+        "Wird für neue Downloads in diese Playlist verwendet.",
+    ),
+    (
+        "playlist_settings.bitrate.hint",
+        "Ersetzt die Download-Qualität für diese Playlist.",
+    ),
+    ("playlist_settings.format", "Dateiformat"),
+    ("playlist_settings.tags", "Tags"),
+    ("playlist_settings.file_names", "Dateinamen"),
+    ("playlist_settings.file_names.default", "Standard"),
+    (
+        "playlist_settings.file_names.hint",
+        "Ersetzt die Dateinamenvorlage, z. B. {example}.",
+    ),
+    ("playlist_settings.refresh", "Aus der Quelle aktualisieren"),
+    (
+        "playlist_settings.refresh.remove_missing",
+        "Titel entfernen, die nicht mehr in der Quelle sind",
+    ),
+    (
+        "playlist_settings.refresh.hint",
+        "Neue Videos werden heruntergeladen, während die App läuft.",
+    ),
+    (
+        "storage.clear_cache_failed",
+        "Cache konnte nicht geleert werden: {error}",
+    ),
+    ("storage.archived", "„{name}“ nach {path} archiviert"),
+    (
+        "storage.archive_failed",
+        "„{name}“ konnte nicht archiviert werden: {error}",
+    ),
+    ("storage.moved", "{files} ({size}) nach {path} verschoben"),
+    (
+        "storage.directory_changed",
+        "Playlists werden jetzt in {path} gespeichert",
+    ),
+    (
+        "storage.directory_change_failed",
+        "Speicherordner konnte nicht geändert werden: {error}",
+    ),
+    ("storage.measuring", "Speicher wird gemessen..."),
+    ("storage.library_limit", "Bibliotheksgrenze: {size}"),
+    (
+        "storage.disk_usage",
+        "{playlists} % Playlists, {cache} % Cache, {free} % frei",
+    ),
+    ("storage.legend.playlists", "Playlists · {size}"),
+    ("storage.legend.cache", "Cache · {size}"),
+    ("storage.legend.free", "Frei · {size}"),
+    ("storage.legend.free_unknown", "Frei · unbekannt"),
+    (
+        "storage.min_free_space",
+        "Downloads stoppen, wenn weniger als {size} frei sind",
+    ),
+    ("storage.no_playlists", "Noch keine Playlists"),
+    ("storage.archive_playlist", "{name} archivieren"),
+    ("storage.archive", "Archivieren"),
+    ("storage.cache_off", "Cache ist ausgeschaltet"),
+    ("storage.cache_usage", "Cache zu {percent} % voll"),
+    (
+        "storage.cache_summary",
+        "{used} von {max} · {metadata} Metadaten, {thumbnails}, {temp}",
+    ),
+    ("storage.disk", "Datenträger"),
+    (
+        "storage.archive_confirm",
+        "„{name}“ nach {path} verschieben? Sie erscheint dann nicht mehr in deiner Bibliothek.",
+    ),
+    ("storage.directory", "Speicherordner"),
+    (
+        "storage.directory.description",
+        "Playlists werden in diesem Ordner gespeichert. Beim Verschieben wird jede Datei kopiert und geprüft, bevor gewechselt wird.",
+    ),
+    ("storage.directory.placeholder", "Ordnerpfad eingeben..."),
+    ("storage.moving", "Wird verschoben..."),
+    ("storage.change", "Ändern"),
+    (
+        "storage.move_playlists",
+        "Meine Playlists in den neuen Ordner verschieben",
+    ),
+    (
+        "create_playlist.name_required",
+        "Playlist-Name ist erforderlich",
+    ),
+    (
+        "create_playlist.name_too_long",
+        "Der Name darf höchstens 255 Zeichen lang sein",
+    ),
+    (
+        "create_playlist.name_invalid_char",
+        "Der Name darf „{char}“ nicht enthalten",
+    ),
+    (
+        "create_playlist.name_reserved",
+        "Dieser Name ist vom System reserviert",
+    ),
+    (
+        "create_playlist.validation_error",
+        "Prüfungsfehler: {error}",
+    ),
+    ("create_playlist.title", "Neue Playlist"),
+    ("create_playlist.search", "YouTube durchsuchen"),
+    (
+        "create_playlist.search.placeholder",
+        "Nach Videos und Playlists suchen",
+    ),
+    ("create_playlist.search.filter", "Suchfilter"),
+    ("create_playlist.search.all", "Alle"),
+    ("create_playlist.search.videos", "Videos"),
+    ("create_playlist.searching", "Suche läuft..."),
+    ("create_playlist.search.button", "Suchen"),
+    ("create_playlist.no_results", "Keine Ergebnisse gefunden"),
+    ("create_playlist.result.video", "Video"),
+    ("create_playlist.result.playlist", "Playlist"),
+    ("create_playlist.url", "URL der YouTube-Playlist"),
+    (
+        "create_playlist.url.hint",
+        "Füge eine YouTube-Playlist-URL ein oder wähle ein Suchergebnis",
+    ),
+    ("create_playlist.validating", "URL wird geprüft..."),
+    ("create_playlist.url_type", "Typ:"),
+    ("create_playlist.playlist_id", "Playlist-ID:"),
+    (
+        "create_playlist.mix_warning",
+        "Mixe enden nie: Nur die ersten {count} Videos werden heruntergeladen",
+    ),
+    ("create_playlist.name", "Playlist-Name"),
+    (
+        "create_playlist.name.placeholder",
+        "Gib einen Namen für deine Playlist ein",
+    ),
+    ("create_playlist.creating", "Wird erstellt..."),
+    ("create_playlist.create", "Playlist erstellen"),
+    ("create_playlist.url_type.playlist", "YouTube-Playlist"),
+    (
+        "create_playlist.url_type.watch_with_playlist",
+        "Video mit Playlist",
+    ),
+    (
+        "create_playlist.url_type.single_video",
+        "Einzelnes Video (keine Playlist)",
+    ),
+    ("create_playlist.url_type.short_url", "Kurz-URL"),
+    ("create_playlist.url_type.channel", "Kanal-Uploads"),
+    ("create_playlist.url_type.invalid", "Ungültige URL"),
+    ("first_playlist.step.paste", "Link einfügen"),
+    ("first_playlist.step.check", "Playlist prüfen"),
+    ("first_playlist.step.name", "Benennen und herunterladen"),
+    (
+        "first_playlist.name_taken",
+        "Eine Playlist mit diesem Namen existiert bereits",
+    ),
+    (
+        "first_playlist.fetching",
+        "Playlist-Details werden abgerufen...",
+    ),
+    (
+        "first_playlist.load_failed",
+        "Playlist konnte nicht geladen werden: {error}",
+    ),
+    ("first_playlist.estimating", "Größe wird geschätzt..."),
+    (
+        "first_playlist.unavailable",
+        "{count} privat, gelöscht oder gesperrt (werden übersprungen)",
+    ),
+    ("first_playlist.title", "Füge deine erste Playlist hinzu"),
+    (
+        "first_playlist.url.hint",
+        "Kopiere den Link einer YouTube-Playlist und füge ihn hier ein",
+    ),
+    ("first_playlist.checking", "Link wird geprüft..."),
+    ("first_playlist.valid", "Playlist-Link"),
+    ("first_playlist.queueing", "Wird eingereiht..."),
+    ("first_playlist.download", "Herunterladen"),
+    ("empty.playlists.title", "Baue deine Musikbibliothek auf"),
+    (
+        "empty.playlists.message",
+        "Füge eine YouTube-Playlist-URL ein, um Titel als MP3 herunterzuladen.",
+    ),
+    (
+        "empty.playlists.hint",
+        "Downloads werden lokal gespeichert und können auf jeden MP3-Player synchronisiert werden.",
+    ),
+    ("empty.playlists.add", "YouTube-Playlist hinzufügen"),
+    ("empty.device.title", "Verbinde deinen MP3-Player"),
+    (
+        "empty.device.message",
+        "Schließe deinen MP3-Player oder USB-Stick an, um Musik zu synchronisieren.",
+    ),
+    (
+        "empty.device.hint",
+        "Die meisten USB-Speichergeräte werden automatisch erkannt.",
+    ),
+    ("empty.device.scan", "Nach Geräten suchen"),
+    ("empty.search.message", "Keine Ergebnisse für „{query}“"),
+    (
+        "empty.search.hint",
+        "Passe deine Suchbegriffe an oder prüfe sie auf Tippfehler.",
+    ),
+    ("empty.search.clear", "Suche löschen"),
+    ("empty.tracks.title", "Keine Titel in dieser Playlist"),
+    (
+        "empty.tracks.message",
+        "Diese Playlist hat noch keine Titel.",
+    ),
+    (
+        "empty.tracks.hint",
+        "Titel werden heruntergeladen, wenn du eine Playlist aus einer YouTube-URL erstellst.",
+    ),
+    ("empty.sync.title", "Nichts zu synchronisieren"),
+    (
+        "empty.sync.message",
+        "Alle Titel sind bereits auf deinem Gerät.",
+    ),
+    (
+        "empty.sync.hint",
+        "Füge neue Playlists hinzu oder aktualisiere bestehende, um neue Inhalte zu synchronisieren.",
+    ),
+    ("empty.error.title", "Etwas ist schiefgelaufen"),
+    ("empty.error.retry", "Erneut versuchen"),
+    ("empty.folder.title", "Der Ordner ist leer"),
+    ("empty.folder.named_title", "„{name}“ ist leer"),
+    (
+        "empty.folder.message",
+        "Dieser Ordner enthält keine Dateien.",
+    ),
+    ("youtube_error.network.title", "Netzwerkfehler"),
+    (
+        "youtube_error.network.description",
+        "Keine Verbindung zu YouTube. Bitte prüfe deine Internetverbindung und versuche es erneut.",
+    ),
+    (
+        "youtube_error.youtube_service.title",
+        "YouTube-Dienstfehler",
+    ),
+    (
+        "youtube_error.youtube_service.description",
+        "YouTube ist vorübergehend nicht verfügbar oder hat Anfragen begrenzt. Bitte warte kurz und versuche es erneut.",
+    ),
+    ("youtube_error.invalid_url.title", "Ungültige URL"),
+    (
+        "youtube_error.invalid_url.description",
+        "Die angegebene URL ist keine gültige YouTube-Playlist-URL.",
+    ),
+    (
+        "youtube_error.playlist_not_found.title",
+        "Playlist nicht gefunden",
+    ),
+    (
+        "youtube_error.playlist_not_found.description",
+        "Die Playlist wurde nicht gefunden. Sie ist vielleicht privat oder gelöscht, oder die URL ist falsch.",
+    ),
+    (
+        "youtube_error.video_unavailable.title",
+        "Video nicht verfügbar",
+    ),
+    (
+        "youtube_error.video_unavailable.description",
+        "Ein oder mehrere Videos der Playlist sind nicht verfügbar (privat, gelöscht oder eingeschränkt).",
+    ),
+    (
+        "youtube_error.age_restricted.title",
+        "Altersbeschränkter Inhalt",
+    ),
+    (
+        "youtube_error.age_restricted.description",
+        "Dieser Inhalt ist altersbeschränkt und erfordert eine Anmeldung.",
+    ),
+    ("youtube_error.geo_restricted.title", "Regionale Sperre"),
+    (
+        "youtube_error.geo_restricted.description",
+        "Dieser Inhalt ist in deiner Region nicht verfügbar.",
+    ),
+    (
+        "youtube_error.audio_extraction.title",
+        "Audio-Extraktion fehlgeschlagen",
+    ),
+    (
+        "youtube_error.audio_extraction.description",
+        "Audio konnte nicht aus dem Video extrahiert werden. Das Format wird möglicherweise nicht unterstützt.",
+    ),
+    ("youtube_error.file_system.title", "Dateisystemfehler"),
+    (
+        "youtube_error.file_system.description",
+        "Die Datei konnte nicht gespeichert werden. Bitte prüfe Speicherplatz und Berechtigungen.",
+    ),
+    ("youtube_error.cancelled.title", "Abgebrochen"),
+    (
+        "youtube_error.cancelled.description",
+        "Der Download wurde abgebrochen.",
+    ),
+    (
+        "youtube_error.library_space.title",
+        "Nicht genug Speicherplatz",
+    ),
+    (
+        "youtube_error.library_space.description",
+        "Die Bibliothek hat ihre Größengrenze erreicht oder der Datenträger ist fast voll. Gib Speicher frei oder passe die Speichergrenzen in den Einstellungen an.",
+    ),
+    ("youtube_error.unknown.title", "Fehler"),
+    (
+        "youtube_error.unknown.description",
+        "Ein unerwarteter Fehler ist aufgetreten.",
+    ),
+    ("youtube_error.download_failed", "Download fehlgeschlagen"),
+    ("download_panel.video.downloaded", "Heruntergeladen"),
+    ("download_panel.video.skipped", "Übersprungen"),
+    ("download_panel.video.failed", "Fehlgeschlagen"),
+    (
+        "download_panel.sponsor_removed",
+        "{seconds} s Sponsorsegmente entfernt",
+    ),
+    (
+        "download_panel.chapters",
+        "Nach Kapiteln in {tracks} aufgeteilt",
+    ),
+    ("download_panel.source", "Quelle: {source}"),
+    (
+        "download_panel.retry_failed.title",
+        "Fehlgeschlagene Videos erneut herunterladen",
+    ),
+    ("download_panel.ready", "Bereit"),
+    ("download_panel.downloading", "Wird heruntergeladen..."),
+    ("download_panel.completed", "Download abgeschlossen"),
+    ("download_panel.cancelled", "Download abgebrochen"),
+    ("download_panel.progress", "{current} von {total}"),
+    ("download_panel.retry_hint", "Du kannst es erneut versuchen"),
+    ("download_panel.cancel", "Download abbrechen"),
+    ("download_panel.dismiss", "Schließen"),
+    ("download_panel.dismiss_download", "Download ausblenden"),
+    (
+        "download_panel.track_progress",
+        "Download-Fortschritt des Titels",
+    ),
+    ("download_panel.progress_label", "Download-Fortschritt"),
+    ("download_panel.remaining", "verbleibend"),
+    ("download_panel.elapsed", "vergangen"),
+    (
+        "download_panel.retry_count",
+        "{count} fehlgeschlagene erneut versuchen",
+    ),
+    ("download_panel.indicator.downloading", "Download läuft"),
+    ("download_panel.indicator.starting", "Wird gestartet..."),
+    ("context_menu.actions", "Aktionen"),
+    ("track_menu.preview", "Vorschau"),
+    ("track_menu.reveal", "Im Dateimanager anzeigen"),
+    ("track_menu.fetch_lyrics", "Liedtext abrufen"),
+    ("track_menu.label", "Titelaktionen"),
+    ("device_menu.label", "Geräteaktionen"),
+    ("playlist_menu.label", "Playlist-Aktionen"),
+    (
+        "playlist_menu.reset_archive",
+        "Download-Archiv zurücksetzen",
+    ),
+    ("playlist_menu.export", "Als M3U exportieren"),
+    ("playlist_menu.checksums", "Prüfsummen erstellen"),
+    ("playlist_card.sync_to", "Mit {device} synchronisieren"),
+    (
+        "playlist_card.connect_device.title",
+        "Verbinde ein Gerät zum Synchronisieren",
+    ),
+    ("playlist_card.connect_device", "Gerät verbinden"),
+    ("playlist_card.details.title", "Details zu {name} anzeigen"),
+    ("playlist_card.details", "Details"),
+    ("playlist_card.thumbnail", "Playlist-Vorschaubild"),
+    ("search.hint", "Playlists, Titel und Kanäle durchsuchen"),
+    ("search.no_matches", "Keine Treffer"),
+    ("search.label", "Bibliothek durchsuchen"),
+    ("search.placeholder", "Bibliothek durchsuchen..."),
+    (
+        "transfer_panel.preparing",
+        "Übertragung wird vorbereitet...",
+    ),
+    (
+        "transfer_panel.transferring",
+        "Dateien werden übertragen...",
+    ),
+    ("transfer_panel.verifying", "Integrität wird geprüft..."),
+    ("transfer_panel.completed", "Übertragung abgeschlossen"),
+    (
+        "transfer_panel.failed",
+        "Übertragung fehlgeschlagen: {error}",
+    ),
+    ("transfer_panel.cancelled", "Übertragung abgebrochen"),
+    ("transfer_panel.cancel", "Übertragung abbrechen"),
+    ("transfer_panel.dismiss", "Übertragung ausblenden"),
+    (
+        "transfer_panel.file_progress",
+        "Fortschritt der aktuellen Datei",
+    ),
+    ("transfer_panel.overall", "Gesamtfortschritt"),
+    (
+        "transfer_panel.overall_label",
+        "Gesamtfortschritt der Übertragung",
+    ),
+    ("transfer_panel.files_completed", "{count} abgeschlossen"),
+    ("transfer_panel.files_skipped", "{count} übersprungen"),
+    ("transfer_panel.indicator.transferring", "Übertragung läuft"),
+    ("transfer_panel.indicator.preparing", "Wird vorbereitet..."),
+    ("device_list.storage_used", "Belegter Speicher"),
+    ("device_list.eject.title", "Gerät sicher auswerfen"),
+    ("device_list.title", "Verbundene Geräte"),
+    ("device_list.refresh", "Geräte aktualisieren"),
+    (
+        "device_list.detect_failed",
+        "Geräte konnten nicht erkannt werden",
+    ),
+    ("logs.level.all", "Alle Stufen"),
+    ("logs.level.debug", "Debug und höher"),
+    ("logs.level.info", "Info und höher"),
+    ("logs.level.warn", "Warnungen und Fehler"),
+    ("logs.level.error", "Nur Fehler"),
+    ("logs.copied", "Protokolle in die Zwischenablage kopiert"),
+    (
+        "logs.copy_failed",
+        "Protokolle konnten nicht kopiert werden: {error}",
+    ),
+    (
+        "logs.open_folder_failed",
+        "Protokollordner konnte nicht geöffnet werden: {error}",
+    ),
+    ("logs.level", "Minimale Protokollstufe"),
+    ("logs.auto_scroll", "Automatisch scrollen"),
+    ("logs.copy", "Kopieren"),
+    ("logs.open_folder", "Protokollordner öffnen"),
+    (
+        "logs.read_failed",
+        "Protokolle konnten nicht gelesen werden: {error}",
+    ),
+    ("logs.empty", "Keine Protokolleinträge"),
+    (
+        "sync_button.need_both",
+        "Verbinde ein Gerät und wähle eine Playlist zum Synchronisieren",
+    ),
+    (
+        "sync_button.need_device",
+        "Verbinde ein USB-Gerät zum Synchronisieren",
+    ),
+    (
+        "sync_button.need_playlist",
+        "Wähle eine Playlist zum Synchronisieren",
+    ),
+    ("sync_button.syncing.title", "Synchronisierung läuft..."),
+    (
+        "sync_button.checking.title",
+        "Verfügbarer Speicher wird geprüft...",
+    ),
+    (
+        "sync_button.sync_with_warning",
+        "„{name}“ mit {device} synchronisieren ({warning})",
+    ),
+    ("sync_button.sync", "„{name}“ mit {device} synchronisieren"),
+    (
+        "sync_button.sync_default",
+        "Playlist mit dem Gerät synchronisieren",
+    ),
+    ("sync_button.syncing", "Wird synchronisiert..."),
+    ("sync_button.checking", "Speicher wird geprüft..."),
+    (
+        "sync_button.need_have",
+        "(benötigt {required}, verfügbar {available})",
+    ),
+    (
+        "sync_button.low_space",
+        "Wenig Speicher: nach der Synchronisierung zu {percent} % voll",
+    ),
+    (
+        "sync_conflict.summary",
+        "{keeping} von {total} bleiben unverändert",
+    ),
+    (
+        "sync_conflict.title",
+        "Dateien auf dem Gerät werden geändert",
+    ),
+    (
+        "sync_conflict.message",
+        "Diese Synchronisierung überschreibt oder löscht die folgenden Dateien. Wähle die aus, die unverändert bleiben sollen.",
+    ),
+    ("sync_conflict.keep_all", "Alle behalten"),
+    ("sync_conflict.replace_all", "Alle ersetzen"),
+    (
+        "sync_conflict.not_selected",
+        "Nicht in den ausgewählten Playlists",
+    ),
+    ("sync_conflict.from", "Aus {playlist}"),
+    ("sync_conflict.choice", "Behalten oder ersetzen"),
+    ("device_status.connected", "Verbunden"),
+    ("device_status.free", "{size} frei"),
+    ("device_status.removable", "Wechselmedium"),
+    ("device_status.no_device", "Kein Gerät"),
+    ("device_status.disconnected", "Getrennt"),
+    (
+        "device_status.plug_in",
+        "Schließe deinen MP3-Player per USB an",
+    ),
+    ("device_status.detecting", "Erkennung..."),
+    ("device_status.scanning", "Suche"),
+    ("device_status.looking", "Suche nach verbundenen Geräten..."),
+    ("device_status.none_connected", "Kein Gerät verbunden"),
+    (
+        "maintenance.checksums",
+        "Prüfsummen für „{playlist}“ werden erstellt",
+    ),
+    ("maintenance.repair", "„{playlist}“ wird repariert"),
+    ("maintenance.clear_cache", "Cache wird geleert"),
+    ("operation.starting", "Wird gestartet…"),
+    ("operation.failed", "Fehlgeschlagen"),
+    ("operation.failed_with", "Fehlgeschlagen: {error}"),
+    ("operation.cancelled", "Abgebrochen"),
+    ("operation.done", "Fertig"),
+    ("operation.cancelling", "Wird abgebrochen…"),
+    ("operation.working", "Wird ausgeführt…"),
+    ("undo_history.item", "Diese Änderung rückgängig machen"),
+    (
+        "undo_history.item_and_later.one",
+        "Diese und die nächste Änderung rückgängig machen",
+    ),
+    (
+        "undo_history.item_and_later.other",
+        "Diese und die {count} folgenden Änderungen rückgängig machen",
+    ),
+    ("undo_history.label", "Rückgängig-Verlauf"),
+    (
+        "playlist_selection.empty.title",
+        "Keine Playlists verfügbar",
+    ),
+    (
+        "playlist_selection.empty.message",
+        "Erstelle zuerst eine Playlist, um sie zum Synchronisieren auszuwählen",
+    ),
+    ("playlist_selection.label", "Playlist auswählen"),
+    (
+        "playlist_selection.error.title",
+        "Playlists konnten nicht geladen werden",
+    ),
+    (
+        "playlist_selection.error.message",
+        "Bitte versuche es erneut",
+    ),
+    ("playlist_selection.selected", "Ausgewählt:"),
+    (
+        "playlist_selection.hint",
+        "Wähle eine Playlist zum Synchronisieren",
+    ),
+    ("playlist_sort.name", "Name"),
+    ("playlist_sort.recently_updated", "Zuletzt aktualisiert"),
+    ("playlist_sort.size", "Größe"),
+    ("playlist_grid.open", "{name} öffnen"),
+    ("playlist_grid.sort_by", "Sortieren nach"),
+    ("playlist_grid.layout", "Ansicht"),
+    ("playlist_grid.list_view", "Listenansicht"),
+    ("playlist_grid.grid_view", "Rasteransicht"),
+    ("track_list.title", "Titel"),
+    ("track_list.album", "Album"),
+    ("track_list.duration", "Dauer"),
+    ("track_list.size", "Größe"),
+    (
+        "track_list.load_failed",
+        "Titel konnten nicht geladen werden",
+    ),
+    ("playlist_list.load_failed", "Ein Fehler ist aufgetreten"),
+    ("playlist_list.select", "{name} auswählen"),
+    ("loading.error.title", "Etwas ist schiefgelaufen"),
+    (
+        "track_player.unplayable",
+        "Dieser Titel kann nicht abgespielt werden",
+    ),
+    ("track_player.play", "Abspielen"),
+    ("track_player.pause", "Pause"),
+    ("track_player.seek", "Wiedergabeposition"),
+    (
+        "command_error.device_not_found",
+        "Das Gerät wurde nicht gefunden. Stelle sicher, dass es angeschlossen ist.",
+    ),
+    (
+        "command_error.device_not_mounted",
+        "Das Gerät ist nicht eingebunden.",
+    ),
+    (
+        "command_error.device_disconnected",
+        "Das Gerät wurde getrennt. Schließe es erneut an und versuche es noch einmal.",
+    ),
+    (
+        "command_error.device_read_only",
+        "Das Gerät ist schreibgeschützt.",
+    ),
+    (
+        "command_error.device_busy",
+        "Das Gerät ist belegt. Schließe alle Apps, die es verwenden, und versuche es erneut.",
+    ),
+    (
+        "command_error.insufficient_space",
+        "Auf dem Gerät ist nicht genug Speicherplatz.",
+    ),
+    ("command_error.permission_denied", "Zugriff verweigert."),
+    (
+        "command_error.unsupported_file_system",
+        "Das Dateisystem des Geräts wird nicht unterstützt.",
+    ),
+    (
+        "command_error.platform_not_supported",
+        "Dies wird auf deiner Plattform nicht unterstützt.",
+    ),
+    (
+        "command_error.not_a_playlist",
+        "Diese URL ist keine YouTube-Playlist.",
+    ),
+    (
+        "command_error.network",
+        "Netzwerkfehler. Prüfe deine Verbindung und versuche es erneut.",
+    ),
+    (
+        "command_error.timeout",
+        "Zeitüberschreitung der Anfrage. Versuche es erneut.",
+    ),
+    (
+        "command_error.rate_limited",
+        "YouTube begrenzt die Anfragen. Versuche es später erneut.",
+    ),
+    (
+        "command_error.rate_limited_for",
+        "YouTube begrenzt die Anfragen. Versuche es in {seconds} Sekunden erneut.",
+    ),
+    (
+        "command_error.video_unavailable",
+        "Das Video ist nicht verfügbar.",
+    ),
+    (
+        "command_error.offline",
+        "Der Offlinemodus ist aktiv. Deaktiviere ihn in den Einstellungen, um online zu gehen.",
+    ),
+    (
+        "command_error.library_limit_reached",
+        "Deine Bibliothek hat ihre Größenbegrenzung erreicht. Gib Speicher frei oder erhöhe das Limit in den Einstellungen.",
+    ),
+    (
+        "command_error.low_disk_space",
+        "Dein Datenträger ist fast voll. Gib vor dem Herunterladen Speicher frei.",
+    ),
+    (
+        "command_error.transfer_interrupted",
+        "Die Übertragung wurde unterbrochen. Versuche es erneut.",
+    ),
+    (
+        "command_error.integrity_check_failed",
+        "Eine Datei hat die Integritätsprüfung nach der Übertragung nicht bestanden.",
+    ),
+    (
+        "command_error.playlist_not_found",
+        "Die Playlist wurde nicht gefunden.",
+    ),
+    (
+        "command_error.playlist_exists",
+        "Eine Playlist mit diesem Namen existiert bereits.",
+    ),
+    (
+        "command_error.playlist_empty",
+        "Die Playlist enthält keine Titel.",
+    ),
+    ("command_error.cancelled", "Der Vorgang wurde abgebrochen."),
+    (
+        "command_error.task_busy",
+        "Eine andere Aufgabe verwendet dies bereits. Warte, bis sie fertig ist, oder brich sie ab.",
+    ),
+    ("queue.action.move_to_front", "Nach vorne"),
+    ("queue.action.move_up", "Nach oben"),
+    ("queue.action.move_down", "Nach unten"),
+    ("queue.action.split_chapters", "In Kapitel teilen"),
+    ("queue.action.keep_whole", "Ganz behalten"),
+    ("queue.action.remove", "Entfernen"),
+    ("queue.resume", "Fortsetzen"),
+    ("queue.pause", "Pausieren"),
+    ("queue.paused", "Pausiert"),
+    ("queue.title", "Download-Warteschlange"),
+    ("queue.clear_finished", "Abgeschlossene entfernen"),
+    ("queue.section", "{title} ({count})"),
+    ("queue.section.downloading", "Wird heruntergeladen"),
+    ("queue.section.up_next", "Als Nächstes"),
+    ("queue.section.finished", "Abgeschlossen"),
+    ("queue.progress_label", "Downloadfortschritt für {name}"),
+    ("queue.chapters", "Kapitel"),
+    (
+        "queue.chapters.title",
+        "Videos mit Kapiteln werden in einen Titel pro Kapitel geteilt",
+    ),
+    ("queue.dry_run", "Probelauf"),
+    (
+        "queue.dry_run.title",
+        "Nur geplant: Es wird nichts heruntergeladen oder geschrieben",
+    ),
+    ("queue.priority.low", "Niedrig"),
+    ("queue.priority.normal", "Normal"),
+    ("queue.priority.high", "Hoch"),
+    ("queue.status.pending", "Ausstehend"),
+    ("queue.status.downloading", "Wird heruntergeladen"),
+    ("queue.status.completed", "Abgeschlossen"),
+    ("queue.status.failed", "Fehlgeschlagen"),
+    ("queue.status.failed_with", "Fehlgeschlagen: {error}"),
+    ("queue.status.cancelled", "Abgebrochen"),
+    ("queue.status.interrupted", "Unterbrochen"),
+    (
+        "queue.reorder_within_priority",
+        "Einträge können nur innerhalb derselben Priorität umsortiert werden",
+    ),
+    (
+        "queue.update_failed",
+        "Warteschlange konnte nicht aktualisiert werden: {error}",
+    ),
+    (
+        "queue.clear_failed",
+        "Abgeschlossene Downloads konnten nicht entfernt werden: {error}",
+    ),
+];
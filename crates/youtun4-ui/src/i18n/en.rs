@@ -0,0 +1,1634 @@
+//! English interface text.
+
+use super::Catalog;
+
+pub(super) const CATALOG: Catalog = &[
+    ("common.cancel", "Cancel"),
+    ("common.back", "Back"),
+    ("common.next", "Next"),
+    ("common.done", "Done"),
+    ("common.unknown_error", "unknown error"),
+    ("common.time_left", "{time} left"),
+    ("common.go_back", "Go back"),
+    ("common.refresh", "Refresh"),
+    ("common.clear", "Clear"),
+    ("common.remove", "Remove"),
+    ("common.delete", "Delete"),
+    ("common.save", "Save"),
+    ("common.close_dialog", "Close dialog"),
+    ("common.close", "Close"),
+    (
+        "common.unknown_command_error",
+        "Unknown error from Tauri command",
+    ),
+    ("app.sync_to_device", "Sync to Device"),
+    ("nav.library", "Library"),
+    ("nav.history", "History"),
+    ("nav.storage", "Storage"),
+    ("nav.undo", "Undo"),
+    ("nav.shortcuts", "Keyboard shortcuts"),
+    ("nav.activity", "Activity"),
+    ("nav.downloads", "Downloads"),
+    ("nav.sync", "Sync"),
+    (
+        "nav.downloads_status",
+        "{count} downloads queued or running",
+    ),
+    ("nav.sync_status", "Sync in progress"),
+    ("header.stop_all", "Stop everything"),
+    ("header.settings", "Settings"),
+    ("header.toggle_sidebar", "Show or hide the sidebar"),
+    ("header.toggle_menu", "Toggle menu"),
+    ("playlists.title", "Playlists"),
+    ("playlists.select", "Select"),
+    ("playlists.done", "Done"),
+    ("playlists.select_for_sync", "Select for Sync"),
+    ("playlists.new", "New Playlist"),
+    ("playlists.select_to_sync", "Select Playlist to Sync"),
+    ("playlists.choose", "Choose a playlist"),
+    (
+        "playlists.choose_description",
+        "Select one playlist to sync to your connected device",
+    ),
+    ("count.tracks.one", "{count} track"),
+    ("count.tracks.other", "{count} tracks"),
+    ("count.files.one", "{count} file"),
+    ("count.files.other", "{count} files"),
+    ("count.failed.one", "{count} failed"),
+    ("count.failed.other", "{count} failed"),
+    ("count.downloads.one", "{count} download"),
+    ("count.downloads.other", "{count} downloads"),
+    ("count.syncs.one", "{count} sync"),
+    ("count.syncs.other", "{count} syncs"),
+    ("count.audio_files.one", "{count} audio file"),
+    ("count.audio_files.other", "{count} audio files"),
+    ("count.videos.one", "{count} video"),
+    ("count.videos.other", "{count} videos"),
+    ("count.playlists.one", "{count} playlist"),
+    ("count.playlists.other", "{count} playlists"),
+    ("count.thumbnails.one", "{count} thumbnail"),
+    ("count.thumbnails.other", "{count} thumbnails"),
+    ("count.temp_files.one", "{count} temp file"),
+    ("count.temp_files.other", "{count} temp files"),
+    ("banner.device_connected", "Device \"{device}\" connected"),
+    (
+        "banner.device_disconnected",
+        "Device \"{device}\" disconnected",
+    ),
+    ("banner.sync_now", "Sync now"),
+    ("banner.clean", "Clean device"),
+    ("banner.ignore", "Ignore this device"),
+    (
+        "banner.auto_syncing",
+        "Syncing its last playlists automatically",
+    ),
+    ("banner.dismiss", "Dismiss"),
+    (
+        "banner.ignored",
+        "\"{device}\" will be ignored. You can change this in its settings.",
+    ),
+    (
+        "banner.ignore_failed",
+        "Failed to ignore the device: {error}",
+    ),
+    (
+        "toast.device_ejected",
+        "Device safely ejected. You can now remove it.",
+    ),
+    ("toast.select_device", "Please select a device first"),
+    ("toast.select_playlist", "Please select a playlist first"),
+    ("toast.playlist_created", "Playlist \"{name}\" created"),
+    ("toast.playlist_deleted", "Playlist \"{name}\" deleted"),
+    ("toast.dismiss", "Dismiss notification"),
+    ("toast.download_failed", "Failed to start download: {error}"),
+    ("toast.downloading", "Downloading \"{name}\""),
+    (
+        "toast.create_playlist_failed",
+        "Failed to create playlist: {error}",
+    ),
+    (
+        "toast.load_devices_failed",
+        "Failed to load devices: {error}",
+    ),
+    (
+        "toast.load_playlists_failed",
+        "Failed to load playlists: {error}",
+    ),
+    (
+        "toast.crashed_last_time",
+        "Youtun4 closed unexpectedly last time: {message}",
+    ),
+    (
+        "toast.transfer_complete",
+        "Transfer complete: {files} transferred to {device}",
+    ),
+    ("toast.transfer_failed", "Transfer failed: {error}"),
+    ("toast.transfer_cancelled", "Transfer cancelled"),
+    (
+        "toast.video_failed",
+        "Failed to download \"{title}\": {error}",
+    ),
+    ("toast.downloaded.one", "Downloaded {count} track"),
+    ("toast.downloaded.other", "Downloaded {count} tracks"),
+    ("toast.download_error", "Download failed: {error}"),
+    ("toast.retry_failed", "Failed to retry download: {error}"),
+    ("toast.download_cancelled", "Download cancelled"),
+    (
+        "toast.deep_link_needs_playlist",
+        "Select a playlist, then open the link again",
+    ),
+    ("toast.deep_link_failed", "Could not add link: {error}"),
+    ("toast.deep_link_queued", "Queued download into \"{name}\""),
+    (
+        "toast.update_available",
+        "Youtun4 {version} is available ({channel} channel)",
+    ),
+    ("toast.syncing", "Syncing \"{name}\" to {device}..."),
+    ("toast.synced", "\"{name}\" synced successfully"),
+    ("toast.sync_failed", "Failed to sync playlist: {error}"),
+    (
+        "toast.delete_playlist_failed",
+        "Failed to delete playlist: {error}",
+    ),
+    (
+        "toast.update_playlists_failed",
+        "Failed to update playlists: {error}",
+    ),
+    (
+        "toast.transfer_starting",
+        "Starting transfer of \"{name}\" to {device}...",
+    ),
+    (
+        "toast.transfer_start_failed",
+        "Failed to start transfer: {error}",
+    ),
+    (
+        "toast.cancel_transfer_failed",
+        "Failed to cancel transfer: {error}",
+    ),
+    (
+        "toast.cancel_download_failed",
+        "Failed to cancel download: {error}",
+    ),
+    ("toast.rename_failed", "Failed to rename playlist: {error}"),
+    ("toast.start_failed", "Failed to start: {error}"),
+    (
+        "toast.refresh_failed",
+        "Failed to refresh playlist: {error}",
+    ),
+    (
+        "toast.archive_reset",
+        "Forgot {videos} downloaded for \"{name}\"",
+    ),
+    (
+        "toast.archive_reset_failed",
+        "Failed to reset download archive: {error}",
+    ),
+    ("toast.exported", "Exported to {path}"),
+    ("toast.export_failed", "Failed to export playlist: {error}"),
+    ("toast.verify_ok", "\"{name}\" is OK ({tracks})"),
+    ("toast.verify_issues", "\"{name}\" has issues: {issues}"),
+    ("toast.verify_failed", "Failed to verify playlist: {error}"),
+    ("toast.open_folder_failed", "Failed to open folder: {error}"),
+    ("toast.nothing_running", "Nothing is running"),
+    ("toast.tasks_stopped.one", "Stopped {count} task"),
+    ("toast.tasks_stopped.other", "Stopped {count} tasks"),
+    ("toast.stop_tasks_failed", "Failed to stop tasks: {error}"),
+    ("toast.select_device_first", "Select a device first"),
+    (
+        "toast.select_playlist_to_delete",
+        "Select a playlist to delete",
+    ),
+    ("toast.show_track_failed", "Failed to show track: {error}"),
+    (
+        "toast.update_tracks_failed",
+        "Failed to update tracks: {error}",
+    ),
+    ("toast.lyrics_saved", "Saved lyrics of \"{name}\""),
+    ("toast.lyrics_not_found", "No lyrics found for \"{name}\""),
+    ("toast.lyrics_failed", "Failed to fetch lyrics: {error}"),
+    ("toast.retry", "Retry"),
+    ("toast.action_countdown", "{label} ({seconds}s)"),
+    ("settings.title", "Settings"),
+    ("settings.close", "Close settings"),
+    ("settings.section.downloads", "Downloads"),
+    ("settings.section.devices", "Devices"),
+    ("settings.section.storage", "Storage"),
+    ("settings.section.cache", "Cache"),
+    ("settings.section.notifications", "Notifications"),
+    ("settings.section.appearance", "Appearance"),
+    ("settings.section.advanced", "Advanced"),
+    ("settings.reset", "Reset to Default"),
+    ("settings.save", "Save Settings"),
+    ("settings.saving", "Saving..."),
+    ("settings.saved", "Settings saved successfully!"),
+    ("settings.load_failed", "Failed to load settings: {error}"),
+    ("settings.save_failed", "Failed to save settings: {error}"),
+    ("settings.language", "Language"),
+    (
+        "settings.language.description",
+        "Language of the interface and notifications.",
+    ),
+    ("settings.language.system", "System default"),
+    ("settings.write_tags", "Write Tags"),
+    (
+        "settings.write_tags.description",
+        "Add title, artist, album and track number to downloaded songs",
+    ),
+    ("settings.embed_cover_art", "Embed Cover Art"),
+    (
+        "settings.embed_cover_art.description",
+        "Use the video thumbnail as the song's cover",
+    ),
+    ("settings.fetch_lyrics", "Fetch Lyrics"),
+    (
+        "settings.fetch_lyrics.description",
+        "Find synced or plain lyrics for downloaded songs",
+    ),
+    ("settings.save_lrc", "Save .lrc Files"),
+    (
+        "settings.save_lrc.description",
+        "Save lyrics next to the song, for players showing synced lyrics",
+    ),
+    ("settings.embed_lyrics", "Embed Lyrics"),
+    (
+        "settings.embed_lyrics.description",
+        "Store lyrics in the song's tags",
+    ),
+    ("settings.auto_start", "Start Automatically"),
+    (
+        "settings.auto_start.description",
+        "Start downloads as soon as they are queued",
+    ),
+    ("settings.auto_retry", "Retry Failed Downloads"),
+    (
+        "settings.auto_retry.description",
+        "Queue failed downloads again automatically",
+    ),
+    ("settings.work_offline", "Work Offline"),
+    (
+        "settings.work_offline.description",
+        "Queued downloads wait until offline mode is turned off",
+    ),
+    ("settings.cache_enabled", "Enable Cache"),
+    (
+        "settings.cache_enabled.description",
+        "Keep fetched data on disk for reuse",
+    ),
+    ("settings.cache_metadata", "Cache Track Metadata"),
+    (
+        "settings.cache_metadata.description",
+        "Avoid re-reading tags from every file",
+    ),
+    ("settings.cache_thumbnails", "Cache Thumbnails"),
+    (
+        "settings.cache_thumbnails.description",
+        "Keep playlist and track artwork on disk",
+    ),
+    ("settings.notify_download", "Download Complete"),
+    (
+        "settings.notify_download.description",
+        "Notify when a playlist download finishes",
+    ),
+    ("settings.notify_sync", "Sync Complete"),
+    (
+        "settings.notify_sync.description",
+        "Notify when syncing to a device finishes",
+    ),
+    ("settings.notify_errors", "Errors"),
+    (
+        "settings.notify_errors.description",
+        "Show notifications for errors and warnings",
+    ),
+    ("settings.notify_device", "Device Connected"),
+    (
+        "settings.notify_device.description",
+        "Notify when a USB device is connected",
+    ),
+    ("settings.webhook_enabled", "Call Webhook"),
+    (
+        "settings.webhook_enabled.description",
+        "Post a message when a download or sync ends",
+    ),
+    ("settings.high_contrast", "High Contrast"),
+    (
+        "settings.high_contrast.description",
+        "Stronger text, border and status colors; replaces the custom accent color",
+    ),
+    ("settings.minimize_to_tray", "Minimize to Tray"),
+    (
+        "settings.minimize_to_tray.description",
+        "Closing the window hides Youtun4 in the system tray",
+    ),
+    ("settings.pause_on_battery", "Pause on Battery"),
+    (
+        "settings.pause_on_battery.description",
+        "Wait until the computer is plugged in",
+    ),
+    ("settings.pause_on_metered", "Pause on Metered Networks"),
+    (
+        "settings.pause_on_metered.description",
+        "Wait for an unmetered connection",
+    ),
+    (
+        "settings.pause_on_guessed_metered",
+        "Include Guessed Metered Networks",
+    ),
+    (
+        "settings.pause_on_guessed_metered.description",
+        "Also pause on connections such as phone hotspots that are only guessed to be metered",
+    ),
+    ("settings.quality", "Download Quality"),
+    (
+        "settings.quality.description",
+        "Select the audio quality for YouTube downloads. Playlists can override it from their settings.",
+    ),
+    ("settings.lyrics", "Lyrics"),
+    (
+        "settings.lyrics.description",
+        "Look up lyrics on LRCLIB after each download. Playlists can override it from their settings.",
+    ),
+    ("settings.file_names", "File Names"),
+    ("settings.file_names.template", "File name template"),
+    ("settings.queue", "Download Queue"),
+    (
+        "settings.queue.description",
+        "Control how queued downloads are run.",
+    ),
+    ("settings.queue.max_concurrent", "Downloads at once"),
+    ("settings.queue.concurrency", "Videos at once per playlist"),
+    ("settings.queue.speed_limit", "Speed limit"),
+    ("settings.queue.max_retries", "Retries per download"),
+    ("settings.offline", "Offline Mode"),
+    (
+        "settings.offline.description",
+        "Skip all network access and use cached data only.",
+    ),
+    ("settings.preferred_device", "Preferred Device"),
+    (
+        "settings.preferred_device.description",
+        "The device selected automatically when it is connected. Pick one from the device list.",
+    ),
+    ("settings.device_detection", "Device Detection"),
+    (
+        "settings.device_detection.description",
+        "How often to check for connected or removed devices.",
+    ),
+    (
+        "settings.device_detection.interval",
+        "Check every (seconds)",
+    ),
+    ("settings.storage_location", "Storage Location"),
+    (
+        "settings.storage_location.description",
+        "Choose where your playlists are stored on your computer.",
+    ),
+    ("settings.storage_location.directory", "Playlists Directory"),
+    ("settings.library_limits", "Library Limits"),
+    (
+        "settings.library_limits.description",
+        "Downloads stop when the library reaches its size limit or the disk gets too full.",
+    ),
+    (
+        "settings.library_limits.max_size",
+        "Maximum library size (GB)",
+    ),
+    (
+        "settings.library_limits.no_limit",
+        "Leave empty for no limit.",
+    ),
+    ("settings.library_limits.min_free", "Keep free on disk (GB)"),
+    ("settings.cache", "Cache"),
+    (
+        "settings.cache.description",
+        "Cached playlist details, track metadata and thumbnails make the app faster and work offline.",
+    ),
+    ("settings.cache.max_size", "Maximum cache size (MB)"),
+    ("settings.cache.ttl", "Keep entries for (days)"),
+    (
+        "settings.clear_cache.description",
+        "Remove all cached playlist details, metadata and thumbnails. They are fetched again when needed.",
+    ),
+    ("settings.notifications", "Notification Preferences"),
+    (
+        "settings.notifications.description",
+        "Choose which notifications you want to receive.",
+    ),
+    ("settings.webhook", "Webhook"),
+    (
+        "settings.webhook.description",
+        "Post a message to a URL, such as an ntfy topic or a Discord webhook, when downloads and syncs end. Calls are skipped in offline mode.",
+    ),
+    ("settings.webhook.url", "Webhook URL"),
+    ("settings.webhook.save_url", "Save URL"),
+    (
+        "settings.webhook.url_hint",
+        "The URL is kept in secure storage, not in the settings file.",
+    ),
+    ("settings.webhook.payload", "Payload template"),
+    ("settings.webhook.test", "Send Test Message"),
+    ("settings.theme", "Theme"),
+    (
+        "settings.theme.description",
+        "Choose the appearance of the application.",
+    ),
+    (
+        "settings.theme.system_hint",
+        "System follows your operating system's light or dark setting.",
+    ),
+    ("settings.accent_color", "Accent Color"),
+    (
+        "settings.accent_color.description",
+        "Color of buttons, highlights and focus rings.",
+    ),
+    ("settings.accent_color.default", "Use Default"),
+    ("settings.accessibility", "Accessibility"),
+    ("settings.extraction", "Extraction"),
+    (
+        "settings.extraction.description",
+        "Choose how playlists are read from YouTube. yt-dlp must be installed separately.",
+    ),
+    ("settings.extraction.yt_dlp_path", "yt-dlp location"),
+    ("settings.background", "Background"),
+    (
+        "settings.background.description",
+        "Keep downloads and syncs running when the window is closed.",
+    ),
+    ("settings.power", "Power and Network"),
+    (
+        "settings.power.description",
+        "Pause downloads and other heavy work when resources are limited.",
+    ),
+    ("settings.updates", "Updates"),
+    (
+        "settings.updates.description",
+        "Choose which releases Youtun4 checks for on startup.",
+    ),
+    ("settings.log", "Application Log"),
+    (
+        "settings.log.description",
+        "Recent log entries, refreshed while this section is open. Copy them into a bug report when a download fails.",
+    ),
+    (
+        "settings.file_names.description",
+        "Name downloaded songs from {fields}. {padded} pads the index with zeros. Playlists can override it from their settings.",
+    ),
+    ("settings.file_names.example", "Example: {name}"),
+    ("settings.unlimited", "Unlimited"),
+    ("settings.preferred_device.none", "No preferred device"),
+    (
+        "settings.storage_location.placeholder",
+        "Enter directory path...",
+    ),
+    ("settings.storage_location.default", "Default: {path}"),
+    ("settings.clear_cache", "Clear Cache"),
+    ("settings.webhook.url_saved", "Webhook URL saved"),
+    ("settings.webhook.url_removed", "Webhook URL removed"),
+    ("settings.webhook.sending", "Sending test message..."),
+    ("settings.webhook.sent", "Test message sent"),
+    (
+        "settings.webhook.url_replace",
+        "A URL is saved; enter one to replace it",
+    ),
+    (
+        "settings.webhook.payload_hint",
+        "Use {fields}. Templates starting with {brace} are sent as JSON, e.g. {example} for Discord.",
+    ),
+    (
+        "settings.webhook.download_completed",
+        "When a playlist download finishes",
+    ),
+    (
+        "settings.webhook.download_failed",
+        "When a playlist download fails",
+    ),
+    (
+        "settings.webhook.sync_completed",
+        "When syncing to a device finishes",
+    ),
+    (
+        "settings.webhook.sync_failed",
+        "When syncing to a device fails",
+    ),
+    ("settings.theme.dark", "Dark"),
+    ("settings.theme.light", "Light"),
+    ("settings.theme.system", "System"),
+    ("settings.extraction.auto", "Automatic"),
+    (
+        "settings.extraction.auto.description",
+        "Built in, with yt-dlp when YouTube pages can't be read",
+    ),
+    ("settings.extraction.built_in", "Built In"),
+    (
+        "settings.extraction.built_in.description",
+        "Never run yt-dlp",
+    ),
+    ("settings.extraction.yt_dlp", "yt-dlp"),
+    (
+        "settings.extraction.yt_dlp.description",
+        "Read and download everything with yt-dlp",
+    ),
+    (
+        "settings.extraction.yt_dlp_path.placeholder",
+        "yt-dlp (found on PATH)",
+    ),
+    ("settings.updates.stable", "Stable"),
+    (
+        "settings.updates.stable.description",
+        "Tested releases only",
+    ),
+    ("settings.updates.beta", "Beta"),
+    (
+        "settings.updates.beta.description",
+        "Pre-releases with the newest features",
+    ),
+    ("delete_playlist.title", "Delete Playlist"),
+    (
+        "delete_playlist.confirm",
+        "Are you sure you want to delete \"{name}\"?",
+    ),
+    ("delete_playlist.tracks", "Tracks:"),
+    ("delete_playlist.size", "Size:"),
+    ("delete_playlist.source", "Source:"),
+    ("delete_playlist.youtube", "YouTube Playlist"),
+    (
+        "delete_playlist.warning",
+        "This action cannot be undone. All tracks and files will be permanently deleted from your local storage.",
+    ),
+    ("shortcuts.title", "Keyboard Shortcuts"),
+    ("shortcuts.close", "Close keyboard shortcuts"),
+    ("shortcuts.group.general", "General"),
+    ("shortcuts.group.navigation", "Navigation"),
+    ("shortcuts.group.playlists", "Playlists"),
+    ("shortcuts.group.dialogs", "Dialogs"),
+    ("shortcuts.show", "Show keyboard shortcuts"),
+    ("shortcuts.search", "Search the library"),
+    ("shortcuts.settings", "Open settings"),
+    ("shortcuts.undo", "Undo the last change"),
+    ("shortcuts.playlists", "Go to playlists"),
+    ("shortcuts.history", "Go to history"),
+    ("shortcuts.device", "Go to the selected device"),
+    ("shortcuts.delete", "Delete the open or selected playlist"),
+    ("shortcuts.preview", "Play or pause the track preview"),
+    ("shortcuts.focus", "Move between controls"),
+    ("shortcuts.close_dialog", "Close the dialog"),
+    ("rename_playlist.title", "Rename Playlist"),
+    ("rename_playlist.label", "New name"),
+    ("rename_playlist.confirm", "Rename"),
+    ("sync_wizard.step.device", "Device"),
+    ("sync_wizard.step.playlists", "Playlists"),
+    ("sync_wizard.step.review", "Review"),
+    ("sync_wizard.step.sync", "Sync"),
+    ("sync_wizard.change.add", "Add"),
+    ("sync_wizard.change.replace", "Replace"),
+    ("sync_wizard.change.keep", "Keep"),
+    ("sync_wizard.change.remove", "Remove"),
+    (
+        "sync_wizard.synced.one",
+        "Synced {count} file to the device",
+    ),
+    (
+        "sync_wizard.synced.other",
+        "Synced {count} files to the device",
+    ),
+    (
+        "sync_wizard.finished_announcement.one",
+        "Sync finished. {count} file transferred.",
+    ),
+    (
+        "sync_wizard.finished_announcement.other",
+        "Sync finished. {count} files transferred.",
+    ),
+    ("sync_wizard.failed", "Sync failed: {error}"),
+    ("sync_wizard.failed_announcement", "Sync failed. {error}"),
+    (
+        "sync_wizard.cancel_failed",
+        "Failed to cancel sync: {error}",
+    ),
+    ("sync_wizard.no_devices", "Connect a device to sync to."),
+    ("sync_wizard.device_space", "{free} free of {total}"),
+    ("sync_wizard.selection", "{count} selected · {size}"),
+    (
+        "sync_wizard.cleanup",
+        "Remove other files from the device first",
+    ),
+    ("sync_wizard.comparing", "Comparing with the device..."),
+    ("sync_wizard.no_preview", "No preview available."),
+    (
+        "sync_wizard.preview_summary",
+        "{write} to write, {remove} to remove",
+    ),
+    (
+        "sync_wizard.fits_after_cleanup",
+        "Fits once the old files are removed from the device",
+    ),
+    (
+        "sync_wizard.capacity_label",
+        "{kept}% kept, {incoming}% incoming",
+    ),
+    ("sync_wizard.kept", "Kept"),
+    ("sync_wizard.incoming", "From this sync"),
+    ("sync_wizard.outcome.success", "Sync complete"),
+    ("sync_wizard.outcome.cancelled", "Sync cancelled"),
+    ("sync_wizard.outcome.failed", "Sync failed"),
+    (
+        "sync_wizard.outcome.counts",
+        "{copied} copied, {skipped} skipped, {failed} failed",
+    ),
+    ("sync_wizard.starting", "Starting sync..."),
+    ("sync_wizard.progress", "Sync progress"),
+    ("sync_wizard.close", "Close sync wizard"),
+    ("sync_wizard.cancel", "Cancel Sync"),
+    ("sync_wizard.start", "Start Sync"),
+    ("history.range.week", "Last 7 days"),
+    ("history.range.month", "Last 30 days"),
+    ("history.range.quarter", "Last 90 days"),
+    ("history.range.all", "All time"),
+    ("history.status.completed", "Completed"),
+    (
+        "history.status.completed_with_errors",
+        "Completed with errors",
+    ),
+    ("history.status.failed", "Failed"),
+    ("history.status.cancelled", "Cancelled"),
+    ("history.entry.download", "Download"),
+    ("history.entry.sync", "Sync to device"),
+    ("history.entry.sync_to", "Sync to {device}"),
+    ("history.stats.this_month", "{size} this month"),
+    ("history.stats.total", "{size} in total"),
+    ("history.stats.average_speed", "{speed} on average"),
+    (
+        "history.pruned",
+        "Removed {count} history entries older than {days} days",
+    ),
+    (
+        "history.prune_failed",
+        "Failed to remove old entries: {error}",
+    ),
+    ("history.cleared.one", "Removed {count} history entry"),
+    ("history.cleared.other", "Removed {count} history entries"),
+    ("history.clear_failed", "Failed to clear history: {error}"),
+    ("history.chart.week", "Week of {week}: {size}"),
+    ("history.chart.title", "Downloaded per week"),
+    ("history.copied", "{size} copied"),
+    ("history.loading", "Loading history..."),
+    (
+        "history.no_matches",
+        "No downloads or syncs match these filters",
+    ),
+    ("history.prune", "Remove Old Entries"),
+    (
+        "history.prune_hint",
+        "Remove entries older than {days} days",
+    ),
+    ("history.clear", "Clear History"),
+    (
+        "history.clear_confirm",
+        "Remove every download and sync from the history?",
+    ),
+    ("history.filter.date", "Date"),
+    ("history.filter.type", "Type"),
+    ("history.filter.all_kinds", "Downloads and syncs"),
+    ("history.filter.downloads", "Downloads"),
+    ("history.filter.syncs", "Syncs"),
+    ("history.filter.status", "Status"),
+    ("history.filter.any_status", "Any status"),
+    ("history.filter.playlist", "Playlist"),
+    ("history.filter.all_playlists", "All playlists"),
+    ("device_detail.almost_full", "The device is almost full"),
+    (
+        "device_detail.files_failed.one",
+        "{count} file failed to copy during the last sync",
+    ),
+    (
+        "device_detail.files_failed.other",
+        "{count} files failed to copy during the last sync",
+    ),
+    (
+        "device_detail.unreadable.one",
+        "{count} item on the device couldn't be read",
+    ),
+    (
+        "device_detail.unreadable.other",
+        "{count} items on the device couldn't be read",
+    ),
+    ("device_detail.settings_saved", "Device settings saved"),
+    (
+        "device_detail.settings_save_failed",
+        "Failed to save device settings: {error}",
+    ),
+    ("device_detail.verifying", "Verifying..."),
+    (
+        "device_detail.verify_failed",
+        "Failed to verify device: {error}",
+    ),
+    ("device_detail.cleaning", "Cleaning..."),
+    ("device_detail.cleaned", "Removed {files}, freeing {size}"),
+    (
+        "device_detail.clean_failed",
+        "Failed to clean device: {error}",
+    ),
+    ("device_detail.ejecting", "Ejecting..."),
+    (
+        "device_detail.eject_refused",
+        "The device couldn't be ejected",
+    ),
+    (
+        "device_detail.eject_failed",
+        "Failed to eject device: {error}",
+    ),
+    ("device_detail.scanning", "Scanning device..."),
+    (
+        "device_detail.usage_label",
+        "{music}% music, {other}% other files, {system}% system",
+    ),
+    ("device_detail.music", "Music"),
+    ("device_detail.other", "Other"),
+    ("device_detail.system", "System"),
+    ("device_detail.free", "Free"),
+    ("device_detail.free_of", "{free} of {total}"),
+    ("device_detail.no_problems", "No problems found"),
+    ("device_detail.not_synced", "Not synced yet"),
+    ("device_detail.files_copied.one", "{count} file copied"),
+    ("device_detail.files_copied.other", "{count} files copied"),
+    (
+        "device_detail.verify_ok.one",
+        "{count} track is on the device",
+    ),
+    (
+        "device_detail.verify_ok.other",
+        "All {count} tracks are on the device",
+    ),
+    (
+        "device_detail.verify_counts",
+        "{matching} matching, {missing} missing, {different} different",
+    ),
+    ("device_detail.sync", "Sync"),
+    ("device_detail.verify", "Verify"),
+    (
+        "device_detail.verify_hint",
+        "Compare the device with the playlists of its last sync",
+    ),
+    ("device_detail.clean", "Clean"),
+    ("device_detail.eject", "Eject"),
+    (
+        "device_detail.disconnected",
+        "This device is no longer connected.",
+    ),
+    (
+        "device_detail.clean_confirm",
+        "Delete everything on the device except hidden and system files?",
+    ),
+    ("device_detail.delete_files", "Delete Files"),
+    ("device_detail.last_sync", "Last Sync"),
+    ("device_detail.nickname", "Nickname"),
+    (
+        "device_detail.auto_sync",
+        "Sync the last playlists again when this device is connected",
+    ),
+    (
+        "device_detail.ignore",
+        "Ignore this device when it is connected",
+    ),
+    ("onboarding.step.storage", "Storage"),
+    ("onboarding.step.quality", "Quality"),
+    ("onboarding.step.playlist", "First Playlist"),
+    ("onboarding.step.device", "Device"),
+    ("onboarding.name_required", "Give the playlist a name"),
+    (
+        "onboarding.devices_failed",
+        "Could not look for devices: {error}",
+    ),
+    (
+        "onboarding.directory_required",
+        "Choose a folder for your playlists",
+    ),
+    ("onboarding.save_failed", "Failed to save setup: {error}"),
+    ("onboarding.invalid_url", "Not a YouTube playlist URL"),
+    (
+        "onboarding.storage.description",
+        "Choose where Youtun4 keeps your playlists. Each playlist is a folder of MP3 files.",
+    ),
+    ("onboarding.storage.folder", "Playlists folder"),
+    ("onboarding.storage.suggested", "Suggested: {path}"),
+    (
+        "onboarding.storage.music_found",
+        "Music found on this computer:",
+    ),
+    (
+        "onboarding.quality.description",
+        "Higher quality sounds better but fills your player faster. You can change this later in Settings.",
+    ),
+    (
+        "onboarding.quality.low_description",
+        "128 kbps - Fits the most songs",
+    ),
+    (
+        "onboarding.playlist.description",
+        "Paste a YouTube playlist link to start your library, or skip this step.",
+    ),
+    ("onboarding.playlist.url", "YouTube playlist URL"),
+    ("onboarding.playlist.name", "Playlist name"),
+    ("onboarding.playlist.name_placeholder", "My first playlist"),
+    (
+        "onboarding.device.description",
+        "Plug in your MP3 player to check that Youtun4 can see it. Pick it to select it automatically next time.",
+    ),
+    ("onboarding.device.looking", "Looking for devices..."),
+    ("onboarding.device.none", "No device detected yet."),
+    ("onboarding.device.known", "Used before: {devices}"),
+    ("onboarding.device.connected", "{device} (connected)"),
+    ("onboarding.device.check_again", "Check again"),
+    ("onboarding.title", "Welcome to Youtun4"),
+    (
+        "onboarding.subtitle",
+        "A few choices and you're ready to put music on your player.",
+    ),
+    ("onboarding.setting_up", "Setting up..."),
+    ("onboarding.finish", "Finish"),
+    ("onboarding.skip", "Skip"),
+    ("quality.low", "Low"),
+    ("quality.medium", "Medium"),
+    (
+        "quality.medium.description",
+        "192 kbps - Balanced quality and size",
+    ),
+    ("quality.high", "High"),
+    (
+        "quality.high.description",
+        "320 kbps - Best quality, larger files",
+    ),
+    (
+        "quality.low.description",
+        "128 kbps - Smaller files, lower quality",
+    ),
+    ("webhook_event.download_completed", "Download completed"),
+    ("webhook_event.download_failed", "Download failed"),
+    ("webhook_event.sync_completed", "Sync completed"),
+    ("webhook_event.sync_failed", "Sync failed"),
+    (
+        "announce.sync_finished",
+        "Sync finished. {files} transferred.",
+    ),
+    ("announce.sync_failed", "Sync failed. {error}"),
+    (
+        "announce.download_finished",
+        "Download finished. {downloaded} downloaded, {failed} failed, {skipped} skipped.",
+    ),
+    ("announce.download_failed", "Download failed. {error}"),
+    ("undo.renamed", "Renamed \"{from}\" to \"{to}\""),
+    ("undo.tracks_removed", "Removed {tracks} from \"{name}\""),
+    ("batch.partial", "{succeeded} of {items}"),
+    ("batch.deleted", "Deleted {items}"),
+    ("batch.moved", "Moved {items}"),
+    ("batch.tagged", "Tagged {items}"),
+    ("batch.first_failure", ". {item}: {error}"),
+    ("batch.more_failures.one", " (and {count} more)"),
+    ("batch.more_failures.other", " (and {count} more)"),
+    ("batch.toolbar", "Selection actions"),
+    ("batch.selected", "{items} selected"),
+    ("batch.select_all", "Select all"),
+    ("batch.sync_selected", "Sync selected"),
+    ("batch.move", "Move"),
+    ("batch.tag", "Tag"),
+    ("batch.tag_name", "Tag name"),
+    ("batch.add_tag", "Add tag"),
+    ("batch.move_target", "Destination playlist"),
+    ("batch.move_here", "Move here"),
+    (
+        "batch.confirm_delete",
+        "Delete {items}? This cannot be undone.",
+    ),
+    ("playlist_detail.back_label", "Go back to playlist list"),
+    ("playlist_detail.settings", "Playlist Settings"),
+    ("playlist_detail.open_folder", "Show in folder"),
+    ("playlist_detail.open_folder.title", "Open in file manager"),
+    ("playlist_detail.repair", "Repair"),
+    (
+        "playlist_detail.repair.title",
+        "Fix the playlist folder's metadata",
+    ),
+    ("playlist_detail.checksums", "Checksums"),
+    (
+        "playlist_detail.checksums.title",
+        "Save checksums of the tracks, to verify them later",
+    ),
+    ("playlist_detail.sync", "Sync"),
+    ("playlist_detail.delete.title", "Delete playlist"),
+    ("playlist_detail.source", "Source"),
+    ("playlist_detail.created", "Created"),
+    ("playlist_detail.modified", "Modified"),
+    (
+        "playlist_detail.updating",
+        "Updating \"{name}\" from its source",
+    ),
+    ("playlist_detail.checking", "Checking..."),
+    ("playlist_detail.check_updates", "Check for updates"),
+    (
+        "playlist_detail.remove_missing.one",
+        "Remove {count} track no longer in the source",
+    ),
+    (
+        "playlist_detail.remove_missing.other",
+        "Remove {count} tracks no longer in the source",
+    ),
+    ("playlist_detail.update", "Update"),
+    ("playlist_detail.load_failed", "Failed to load playlist"),
+    ("playlist_detail.tracks", "Tracks"),
+    (
+        "playlist_detail.select_hint",
+        "Click tracks to select them; shift-click selects a range",
+    ),
+    (
+        "playlist_detail.track_hint",
+        "Click on a track to view detailed information",
+    ),
+    ("playlist_detail.not_found", "Playlist not found"),
+    ("track_preview.title", "Track preview"),
+    ("track_preview.reveal", "Reveal in file manager"),
+    ("track_preview.close", "Close preview"),
+    (
+        "track_preview.no_metadata",
+        "No ID3 metadata found in this file",
+    ),
+    ("track_preview.empty", "No track selected"),
+    ("track.artist", "Artist"),
+    ("track.album", "Album"),
+    ("track.number", "Track"),
+    ("track.duration", "Duration"),
+    ("track.year", "Year"),
+    ("track.genre", "Genre"),
+    ("track.bitrate", "Bitrate"),
+    ("track.file_size", "File Size"),
+    ("track.file_name", "Filename"),
+    (
+        "playlist_diff.new.one",
+        "{count} new track will be downloaded",
+    ),
+    (
+        "playlist_diff.new.other",
+        "{count} new tracks will be downloaded",
+    ),
+    ("playlist_diff.removed.one", "{count} removed"),
+    ("playlist_diff.removed.other", "{count} removed"),
+    ("playlist_diff.renamed.one", "{count} renamed"),
+    ("playlist_diff.renamed.other", "{count} renamed"),
+    ("playlist_diff.moved.one", "{count} moved"),
+    ("playlist_diff.moved.other", "{count} moved"),
+    ("playlist_diff.unavailable.one", "{count} unavailable"),
+    ("playlist_diff.unavailable.other", "{count} unavailable"),
+    ("playlist_diff.up_to_date", "Up to date"),
+    ("size_estimate.summary", "~{size}, {length} of audio"),
+    ("playlist_settings.use_global", "Use global setting"),
+    ("playlist_settings.quality.low", "Low (128 kbps)"),
+    ("playlist_settings.quality.medium", "Medium (192 kbps)"),
+    ("playlist_settings.quality.high", "High (320 kbps)"),
+    ("playlist_settings.lyrics.on", "Fetch lyrics"),
+    ("playlist_settings.lyrics.off", "Don't fetch lyrics"),
+    ("playlist_settings.bitrate.default", "Use download quality"),
+    ("playlist_settings.format.mp3", "MP3"),
+    (
+        "playlist_settings.format.original",
+        "Original (no conversion)",
+    ),
+    ("playlist_settings.tags.none", "No tags"),
+    ("playlist_settings.tags.tags", "Tags only"),
+    ("playlist_settings.tags.cover_art", "Tags and cover art"),
+    ("playlist_settings.refresh.never", "Never"),
+    ("playlist_settings.refresh.6h", "Every 6 hours"),
+    ("playlist_settings.refresh.12h", "Every 12 hours"),
+    ("playlist_settings.refresh.24h", "Every day"),
+    ("playlist_settings.refresh.nightly", "Every night at 3:00"),
+    ("playlist_settings.refresh.weekly", "Every week"),
+    ("playlist_settings.saved", "Settings saved for \"{name}\""),
+    (
+        "playlist_settings.save_failed",
+        "Failed to save playlist settings: {error}",
+    ),
+    ("playlist_settings.quality", "Download quality"),
+    (
+        "playlist_settings.quality.hint",
+        "Used for new downloads into this playlist.",
+    ),
+    (
+        "playlist_settings.bitrate.hint",
+        "Replaces the download quality for this playlist.",
+    ),
+    ("playlist_settings.format", "File format"),
+    ("playlist_settings.tags", "Tags"),
+    ("playlist_settings.file_names", "File names"),
+    ("playlist_settings.file_names.default", "Default"),
+    (
+        "playlist_settings.file_names.hint",
+        "Replaces the file name template, such as {example}.",
+    ),
+    ("playlist_settings.refresh", "Refresh from source"),
+    (
+        "playlist_settings.refresh.remove_missing",
+        "Remove tracks no longer in the source",
+    ),
+    (
+        "playlist_settings.refresh.hint",
+        "New videos are downloaded while the app is running.",
+    ),
+    (
+        "storage.clear_cache_failed",
+        "Failed to clear the cache: {error}",
+    ),
+    ("storage.archived", "Archived \"{name}\" to {path}"),
+    (
+        "storage.archive_failed",
+        "Failed to archive \"{name}\": {error}",
+    ),
+    ("storage.moved", "Moved {files} ({size}) to {path}"),
+    (
+        "storage.directory_changed",
+        "Playlists are now stored in {path}",
+    ),
+    (
+        "storage.directory_change_failed",
+        "Failed to change the storage directory: {error}",
+    ),
+    ("storage.measuring", "Measuring storage..."),
+    ("storage.library_limit", "Library limit: {size}"),
+    (
+        "storage.disk_usage",
+        "{playlists}% playlists, {cache}% cache, {free}% free",
+    ),
+    ("storage.legend.playlists", "Playlists · {size}"),
+    ("storage.legend.cache", "Cache · {size}"),
+    ("storage.legend.free", "Free · {size}"),
+    ("storage.legend.free_unknown", "Free · unknown"),
+    (
+        "storage.min_free_space",
+        "Downloads stop when less than {size} is free",
+    ),
+    ("storage.no_playlists", "No playlists yet"),
+    ("storage.archive_playlist", "Archive {name}"),
+    ("storage.archive", "Archive"),
+    ("storage.cache_off", "Caching is turned off"),
+    ("storage.cache_usage", "Cache {percent}% full"),
+    (
+        "storage.cache_summary",
+        "{used} of {max} · {metadata} metadata, {thumbnails}, {temp}",
+    ),
+    ("storage.disk", "Disk"),
+    (
+        "storage.archive_confirm",
+        "Move \"{name}\" to {path}? It will no longer appear in your library.",
+    ),
+    ("storage.directory", "Storage directory"),
+    (
+        "storage.directory.description",
+        "Playlists are stored in this folder. Moving them copies and verifies every file before switching.",
+    ),
+    ("storage.directory.placeholder", "Enter directory path..."),
+    ("storage.moving", "Moving..."),
+    ("storage.change", "Change"),
+    (
+        "storage.move_playlists",
+        "Move my playlists to the new directory",
+    ),
+    ("create_playlist.name_required", "Playlist name is required"),
+    (
+        "create_playlist.name_too_long",
+        "Name must be 255 characters or less",
+    ),
+    (
+        "create_playlist.name_invalid_char",
+        "Name cannot contain '{char}'",
+    ),
+    (
+        "create_playlist.name_reserved",
+        "This name is reserved by the system",
+    ),
+    (
+        "create_playlist.validation_error",
+        "Validation error: {error}",
+    ),
+    ("create_playlist.title", "New Playlist"),
+    ("create_playlist.search", "Search YouTube"),
+    (
+        "create_playlist.search.placeholder",
+        "Search for videos and playlists",
+    ),
+    ("create_playlist.search.filter", "Search filter"),
+    ("create_playlist.search.all", "All"),
+    ("create_playlist.search.videos", "Videos"),
+    ("create_playlist.searching", "Searching..."),
+    ("create_playlist.search.button", "Search"),
+    ("create_playlist.no_results", "No results found"),
+    ("create_playlist.result.video", "Video"),
+    ("create_playlist.result.playlist", "Playlist"),
+    ("create_playlist.url", "YouTube Playlist URL"),
+    (
+        "create_playlist.url.hint",
+        "Paste a YouTube playlist URL or pick a search result",
+    ),
+    ("create_playlist.validating", "Validating URL..."),
+    ("create_playlist.url_type", "Type:"),
+    ("create_playlist.playlist_id", "Playlist ID:"),
+    (
+        "create_playlist.mix_warning",
+        "Mixes never end: only the first {count} videos will be downloaded",
+    ),
+    ("create_playlist.name", "Playlist Name"),
+    (
+        "create_playlist.name.placeholder",
+        "Enter a name for your playlist",
+    ),
+    ("create_playlist.creating", "Creating..."),
+    ("create_playlist.create", "Create Playlist"),
+    ("create_playlist.url_type.playlist", "YouTube Playlist"),
+    (
+        "create_playlist.url_type.watch_with_playlist",
+        "Video with Playlist",
+    ),
+    (
+        "create_playlist.url_type.single_video",
+        "Single Video (no playlist)",
+    ),
+    ("create_playlist.url_type.short_url", "Short URL"),
+    ("create_playlist.url_type.channel", "Channel Uploads"),
+    ("create_playlist.url_type.invalid", "Invalid URL"),
+    ("first_playlist.step.paste", "Paste a link"),
+    ("first_playlist.step.check", "Check the playlist"),
+    ("first_playlist.step.name", "Name and download"),
+    (
+        "first_playlist.name_taken",
+        "A playlist with this name already exists",
+    ),
+    ("first_playlist.fetching", "Fetching playlist details..."),
+    (
+        "first_playlist.load_failed",
+        "Could not load the playlist: {error}",
+    ),
+    ("first_playlist.estimating", "Estimating size..."),
+    (
+        "first_playlist.unavailable",
+        "{count} private, deleted or blocked (will be skipped)",
+    ),
+    ("first_playlist.title", "Add your first playlist"),
+    (
+        "first_playlist.url.hint",
+        "Copy the link of a playlist from YouTube and paste it here",
+    ),
+    ("first_playlist.checking", "Checking the link..."),
+    ("first_playlist.valid", "Playlist link"),
+    ("first_playlist.queueing", "Queueing..."),
+    ("first_playlist.download", "Download"),
+    ("empty.playlists.title", "Start building your music library"),
+    (
+        "empty.playlists.message",
+        "Paste a YouTube playlist URL to download tracks as MP3 files.",
+    ),
+    (
+        "empty.playlists.hint",
+        "Downloads are stored locally and can be synced to any MP3 player.",
+    ),
+    ("empty.playlists.add", "Add YouTube Playlist"),
+    ("empty.device.title", "Connect your MP3 player"),
+    (
+        "empty.device.message",
+        "Plug in your MP3 player or USB drive via USB to sync music.",
+    ),
+    (
+        "empty.device.hint",
+        "Most USB storage devices are detected automatically.",
+    ),
+    ("empty.device.scan", "Scan for Devices"),
+    ("empty.search.message", "No results found for \"{query}\""),
+    (
+        "empty.search.hint",
+        "Try adjusting your search terms or check for typos.",
+    ),
+    ("empty.search.clear", "Clear Search"),
+    ("empty.tracks.title", "No tracks in this playlist"),
+    (
+        "empty.tracks.message",
+        "This playlist doesn't have any tracks yet.",
+    ),
+    (
+        "empty.tracks.hint",
+        "Tracks are downloaded when you create a playlist from a YouTube URL.",
+    ),
+    ("empty.sync.title", "Nothing to sync"),
+    (
+        "empty.sync.message",
+        "All tracks are already on your device.",
+    ),
+    (
+        "empty.sync.hint",
+        "Add new playlists or update existing ones to sync new content.",
+    ),
+    ("empty.error.title", "Something went wrong"),
+    ("empty.error.retry", "Retry"),
+    ("empty.folder.title", "Folder is empty"),
+    ("empty.folder.named_title", "\"{name}\" is empty"),
+    (
+        "empty.folder.message",
+        "This folder doesn't contain any files.",
+    ),
+    ("youtube_error.network.title", "Network Error"),
+    (
+        "youtube_error.network.description",
+        "Could not connect to YouTube. Please check your internet connection and try again.",
+    ),
+    (
+        "youtube_error.youtube_service.title",
+        "YouTube Service Error",
+    ),
+    (
+        "youtube_error.youtube_service.description",
+        "YouTube is temporarily unavailable or has rate-limited requests. Please wait a moment and try again.",
+    ),
+    ("youtube_error.invalid_url.title", "Invalid URL"),
+    (
+        "youtube_error.invalid_url.description",
+        "The provided URL is not a valid YouTube playlist URL.",
+    ),
+    (
+        "youtube_error.playlist_not_found.title",
+        "Playlist Not Found",
+    ),
+    (
+        "youtube_error.playlist_not_found.description",
+        "The playlist could not be found. It may be private, deleted, or the URL is incorrect.",
+    ),
+    ("youtube_error.video_unavailable.title", "Video Unavailable"),
+    (
+        "youtube_error.video_unavailable.description",
+        "One or more videos in the playlist are unavailable (private, deleted, or restricted).",
+    ),
+    (
+        "youtube_error.age_restricted.title",
+        "Age-Restricted Content",
+    ),
+    (
+        "youtube_error.age_restricted.description",
+        "This content is age-restricted and requires authentication to access.",
+    ),
+    (
+        "youtube_error.geo_restricted.title",
+        "Geographic Restriction",
+    ),
+    (
+        "youtube_error.geo_restricted.description",
+        "This content is not available in your geographic region.",
+    ),
+    (
+        "youtube_error.audio_extraction.title",
+        "Audio Extraction Failed",
+    ),
+    (
+        "youtube_error.audio_extraction.description",
+        "Failed to extract audio from the video. The format may not be supported.",
+    ),
+    ("youtube_error.file_system.title", "File System Error"),
+    (
+        "youtube_error.file_system.description",
+        "Could not save the file. Please check disk space and permissions.",
+    ),
+    ("youtube_error.cancelled.title", "Cancelled"),
+    (
+        "youtube_error.cancelled.description",
+        "The download was cancelled.",
+    ),
+    ("youtube_error.library_space.title", "Not Enough Space"),
+    (
+        "youtube_error.library_space.description",
+        "The library has reached its size limit or the disk is almost full. Free up space or adjust the storage limits in settings.",
+    ),
+    ("youtube_error.unknown.title", "Error"),
+    (
+        "youtube_error.unknown.description",
+        "An unexpected error occurred.",
+    ),
+    ("youtube_error.download_failed", "Download Failed"),
+    ("download_panel.video.downloaded", "Downloaded"),
+    ("download_panel.video.skipped", "Skipped"),
+    ("download_panel.video.failed", "Failed"),
+    (
+        "download_panel.sponsor_removed",
+        "{seconds}s of sponsor segments removed",
+    ),
+    ("download_panel.chapters", "Split into {tracks} by chapters"),
+    ("download_panel.source", "Source: {source}"),
+    (
+        "download_panel.retry_failed.title",
+        "Download the failed videos again",
+    ),
+    ("download_panel.ready", "Ready"),
+    ("download_panel.downloading", "Downloading..."),
+    ("download_panel.completed", "Download Complete"),
+    ("download_panel.cancelled", "Download Cancelled"),
+    ("download_panel.progress", "{current} of {total}"),
+    ("download_panel.retry_hint", "You can try again"),
+    ("download_panel.cancel", "Cancel download"),
+    ("download_panel.dismiss", "Dismiss"),
+    ("download_panel.dismiss_download", "Dismiss download"),
+    ("download_panel.track_progress", "Track download progress"),
+    ("download_panel.progress_label", "Download progress"),
+    ("download_panel.remaining", "remaining"),
+    ("download_panel.elapsed", "elapsed"),
+    ("download_panel.retry_count", "Retry {count} failed"),
+    ("download_panel.indicator.downloading", "Downloading"),
+    ("download_panel.indicator.starting", "Starting..."),
+    ("context_menu.actions", "Actions"),
+    ("track_menu.preview", "Preview"),
+    ("track_menu.reveal", "Reveal in file manager"),
+    ("track_menu.fetch_lyrics", "Fetch lyrics"),
+    ("track_menu.label", "Track actions"),
+    ("device_menu.label", "Device actions"),
+    ("playlist_menu.label", "Playlist actions"),
+    ("playlist_menu.reset_archive", "Reset download archive"),
+    ("playlist_menu.export", "Export as M3U"),
+    ("playlist_menu.checksums", "Create checksums"),
+    ("playlist_card.sync_to", "Sync to {device}"),
+    (
+        "playlist_card.connect_device.title",
+        "Connect a device to sync",
+    ),
+    ("playlist_card.connect_device", "Connect device to sync"),
+    ("playlist_card.details.title", "View {name} details"),
+    ("playlist_card.details", "Details"),
+    ("playlist_card.thumbnail", "Playlist thumbnail"),
+    ("search.hint", "Search playlists, titles and channels"),
+    ("search.no_matches", "No matches"),
+    ("search.label", "Search library"),
+    ("search.placeholder", "Search the library..."),
+    ("transfer_panel.preparing", "Preparing transfer..."),
+    ("transfer_panel.transferring", "Transferring files..."),
+    ("transfer_panel.verifying", "Verifying integrity..."),
+    ("transfer_panel.completed", "Transfer Complete"),
+    ("transfer_panel.failed", "Transfer Failed: {error}"),
+    ("transfer_panel.cancelled", "Transfer Cancelled"),
+    ("transfer_panel.cancel", "Cancel transfer"),
+    ("transfer_panel.dismiss", "Dismiss transfer"),
+    ("transfer_panel.file_progress", "Current file progress"),
+    ("transfer_panel.overall", "Overall Progress"),
+    ("transfer_panel.overall_label", "Overall transfer progress"),
+    ("transfer_panel.files_completed", "{count} completed"),
+    ("transfer_panel.files_skipped", "{count} skipped"),
+    ("transfer_panel.indicator.transferring", "Transferring"),
+    ("transfer_panel.indicator.preparing", "Preparing..."),
+    ("device_list.storage_used", "Storage used"),
+    ("device_list.eject.title", "Safely eject device"),
+    ("device_list.title", "Connected Devices"),
+    ("device_list.refresh", "Refresh devices"),
+    ("device_list.detect_failed", "Failed to detect devices"),
+    ("logs.level.all", "All levels"),
+    ("logs.level.debug", "Debug and above"),
+    ("logs.level.info", "Info and above"),
+    ("logs.level.warn", "Warnings and errors"),
+    ("logs.level.error", "Errors only"),
+    ("logs.copied", "Logs copied to clipboard"),
+    ("logs.copy_failed", "Failed to copy logs: {error}"),
+    (
+        "logs.open_folder_failed",
+        "Failed to open log folder: {error}",
+    ),
+    ("logs.level", "Minimum log level"),
+    ("logs.auto_scroll", "Auto-scroll"),
+    ("logs.copy", "Copy"),
+    ("logs.open_folder", "Open log folder"),
+    ("logs.read_failed", "Could not read logs: {error}"),
+    ("logs.empty", "No log entries"),
+    (
+        "sync_button.need_both",
+        "Connect a device and select a playlist to sync",
+    ),
+    ("sync_button.need_device", "Connect a USB device to sync"),
+    ("sync_button.need_playlist", "Select a playlist to sync"),
+    ("sync_button.syncing.title", "Syncing in progress..."),
+    ("sync_button.checking.title", "Checking available space..."),
+    (
+        "sync_button.sync_with_warning",
+        "Sync \"{name}\" to {device} ({warning})",
+    ),
+    ("sync_button.sync", "Sync \"{name}\" to {device}"),
+    ("sync_button.sync_default", "Sync playlist to device"),
+    ("sync_button.syncing", "Syncing..."),
+    ("sync_button.checking", "Checking space..."),
+    (
+        "sync_button.need_have",
+        "(need {required}, have {available})",
+    ),
+    (
+        "sync_button.low_space",
+        "Low space: {percent}% full after sync",
+    ),
+    (
+        "sync_conflict.summary",
+        "{keeping} of {total} kept as they are",
+    ),
+    ("sync_conflict.title", "Files on the device will change"),
+    (
+        "sync_conflict.message",
+        "This sync overwrites or deletes the files below. Choose the ones to keep as they are.",
+    ),
+    ("sync_conflict.keep_all", "Keep all"),
+    ("sync_conflict.replace_all", "Replace all"),
+    (
+        "sync_conflict.not_selected",
+        "Not in the selected playlists",
+    ),
+    ("sync_conflict.from", "From {playlist}"),
+    ("sync_conflict.choice", "Keep or replace"),
+    ("device_status.connected", "Connected"),
+    ("device_status.free", "{size} free"),
+    ("device_status.removable", "Removable"),
+    ("device_status.no_device", "No Device"),
+    ("device_status.disconnected", "Disconnected"),
+    ("device_status.plug_in", "Plug in your MP3 player via USB"),
+    ("device_status.detecting", "Detecting..."),
+    ("device_status.scanning", "Scanning"),
+    ("device_status.looking", "Looking for connected devices..."),
+    ("device_status.none_connected", "No device connected"),
+    (
+        "maintenance.checksums",
+        "Creating checksums for \"{playlist}\"",
+    ),
+    ("maintenance.repair", "Repairing \"{playlist}\""),
+    ("maintenance.clear_cache", "Clearing the cache"),
+    ("operation.starting", "Starting…"),
+    ("operation.failed", "Failed"),
+    ("operation.failed_with", "Failed: {error}"),
+    ("operation.cancelled", "Cancelled"),
+    ("operation.done", "Done"),
+    ("operation.cancelling", "Cancelling…"),
+    ("operation.working", "Working…"),
+    ("undo_history.item", "Undo this change"),
+    (
+        "undo_history.item_and_later.one",
+        "Undo this change and the one after it",
+    ),
+    (
+        "undo_history.item_and_later.other",
+        "Undo this change and the {count} after it",
+    ),
+    ("undo_history.label", "Undo history"),
+    ("playlist_selection.empty.title", "No playlists available"),
+    (
+        "playlist_selection.empty.message",
+        "Create a playlist first to select it for syncing",
+    ),
+    ("playlist_selection.label", "Select a playlist"),
+    ("playlist_selection.error.title", "Failed to load playlists"),
+    ("playlist_selection.error.message", "Please try again"),
+    ("playlist_selection.selected", "Selected:"),
+    ("playlist_selection.hint", "Select a playlist to sync"),
+    ("playlist_sort.name", "Name"),
+    ("playlist_sort.recently_updated", "Recently updated"),
+    ("playlist_sort.size", "Size"),
+    ("playlist_grid.open", "Open {name}"),
+    ("playlist_grid.sort_by", "Sort by"),
+    ("playlist_grid.layout", "Layout"),
+    ("playlist_grid.list_view", "List view"),
+    ("playlist_grid.grid_view", "Grid view"),
+    ("track_list.title", "Title"),
+    ("track_list.album", "Album"),
+    ("track_list.duration", "Duration"),
+    ("track_list.size", "Size"),
+    ("track_list.load_failed", "Failed to load tracks"),
+    ("playlist_list.load_failed", "An error occurred"),
+    ("playlist_list.select", "Select {name}"),
+    ("loading.error.title", "Something went wrong"),
+    ("track_player.unplayable", "This track can't be played"),
+    ("track_player.play", "Play"),
+    ("track_player.pause", "Pause"),
+    ("track_player.seek", "Seek"),
+    (
+        "command_error.device_not_found",
+        "The device could not be found. Make sure it is connected.",
+    ),
+    (
+        "command_error.device_not_mounted",
+        "The device is not mounted.",
+    ),
+    (
+        "command_error.device_disconnected",
+        "The device was disconnected. Reconnect it and try again.",
+    ),
+    ("command_error.device_read_only", "The device is read-only."),
+    (
+        "command_error.device_busy",
+        "The device is busy. Close any apps using it and try again.",
+    ),
+    (
+        "command_error.insufficient_space",
+        "There isn't enough space on the device.",
+    ),
+    ("command_error.permission_denied", "Permission denied."),
+    (
+        "command_error.unsupported_file_system",
+        "The device's file system is not supported.",
+    ),
+    (
+        "command_error.platform_not_supported",
+        "This isn't supported on your platform.",
+    ),
+    (
+        "command_error.not_a_playlist",
+        "That URL is not a YouTube playlist.",
+    ),
+    (
+        "command_error.network",
+        "Network error. Check your connection and try again.",
+    ),
+    ("command_error.timeout", "The request timed out. Try again."),
+    (
+        "command_error.rate_limited",
+        "YouTube is limiting requests. Try again later.",
+    ),
+    (
+        "command_error.rate_limited_for",
+        "YouTube is limiting requests. Try again in {seconds} seconds.",
+    ),
+    (
+        "command_error.video_unavailable",
+        "The video is unavailable.",
+    ),
+    (
+        "command_error.offline",
+        "Offline mode is enabled. Turn it off in Settings to go online.",
+    ),
+    (
+        "command_error.library_limit_reached",
+        "Your library has reached its size limit. Free up space or raise the limit in Settings.",
+    ),
+    (
+        "command_error.low_disk_space",
+        "Your disk is almost full. Free up space before downloading.",
+    ),
+    (
+        "command_error.transfer_interrupted",
+        "The transfer was interrupted. Try again.",
+    ),
+    (
+        "command_error.integrity_check_failed",
+        "A file failed its integrity check after transfer.",
+    ),
+    (
+        "command_error.playlist_not_found",
+        "The playlist could not be found.",
+    ),
+    (
+        "command_error.playlist_exists",
+        "A playlist with that name already exists.",
+    ),
+    (
+        "command_error.playlist_empty",
+        "The playlist has no tracks.",
+    ),
+    ("command_error.cancelled", "The operation was cancelled."),
+    (
+        "command_error.task_busy",
+        "Another task is already using this. Wait for it to finish or cancel it.",
+    ),
+    ("queue.action.move_to_front", "Move to front"),
+    ("queue.action.move_up", "Move up"),
+    ("queue.action.move_down", "Move down"),
+    ("queue.action.split_chapters", "Split chapters"),
+    ("queue.action.keep_whole", "Keep whole"),
+    ("queue.action.remove", "Remove"),
+    ("queue.resume", "Resume"),
+    ("queue.pause", "Pause"),
+    ("queue.paused", "Paused"),
+    ("queue.title", "Download Queue"),
+    ("queue.clear_finished", "Clear finished"),
+    ("queue.section", "{title} ({count})"),
+    ("queue.section.downloading", "Downloading"),
+    ("queue.section.up_next", "Up next"),
+    ("queue.section.finished", "Finished"),
+    ("queue.progress_label", "Download progress for {name}"),
+    ("queue.chapters", "Chapters"),
+    (
+        "queue.chapters.title",
+        "Videos with chapters are split into one track per chapter",
+    ),
+    ("queue.dry_run", "Dry run"),
+    (
+        "queue.dry_run.title",
+        "Planned only: nothing is downloaded or written",
+    ),
+    ("queue.priority.low", "Low"),
+    ("queue.priority.normal", "Normal"),
+    ("queue.priority.high", "High"),
+    ("queue.status.pending", "Pending"),
+    ("queue.status.downloading", "Downloading"),
+    ("queue.status.completed", "Completed"),
+    ("queue.status.failed", "Failed"),
+    ("queue.status.failed_with", "Failed: {error}"),
+    ("queue.status.cancelled", "Cancelled"),
+    ("queue.status.interrupted", "Interrupted"),
+    (
+        "queue.reorder_within_priority",
+        "Items can only be reordered within the same priority",
+    ),
+    ("queue.update_failed", "Failed to update the queue: {error}"),
+    (
+        "queue.clear_failed",
+        "Failed to clear finished downloads: {error}",
+    ),
+];
@@ -0,0 +1,1844 @@
+//! Spanish interface text.
+
+use super::Catalog;
+
+pub(super) const CATALOG: Catalog = &[
+    ("common.cancel", "Cancelar"),
+    ("common.back", "Atrás"),
+    ("common.next", "Siguiente"),
+    ("common.done", "Listo"),
+    ("common.unknown_error", "error desconocido"),
+    ("common.time_left", "quedan {time}"),
+    ("common.go_back", "Volver"),
+    ("common.refresh", "Actualizar"),
+    ("common.clear", "Borrar"),
+    ("common.remove", "Quitar"),
+    ("common.delete", "Eliminar"),
+    ("common.save", "Guardar"),
+    ("common.close_dialog", "Cerrar el diálogo"),
+    ("common.close", "Cerrar"),
+    (
+        "common.unknown_command_error",
+        "Error desconocido del comando de Tauri",
+    ),
+    ("app.sync_to_device", "Sincronizar con el dispositivo"),
+    ("nav.library", "Biblioteca"),
+    ("nav.history", "Historial"),
+    ("nav.storage", "Almacenamiento"),
+    ("nav.undo", "Deshacer"),
+    ("nav.shortcuts", "Atajos de teclado"),
+    ("nav.activity", "Actividad"),
+    ("nav.downloads", "Descargas"),
+    ("nav.sync", "Sincronización"),
+    (
+        "nav.downloads_status",
+        "{count} descargas en cola o en curso",
+    ),
+    ("nav.sync_status", "Sincronización en curso"),
+    ("header.stop_all", "Detener todo"),
+    ("header.settings", "Ajustes"),
+    (
+        "header.toggle_sidebar",
+        "Mostrar u ocultar la barra lateral",
+    ),
+    ("header.toggle_menu", "Mostrar u ocultar el menú"),
+    ("playlists.title", "Listas de reproducción"),
+    ("playlists.select", "Seleccionar"),
+    ("playlists.done", "Listo"),
+    ("playlists.select_for_sync", "Elegir para sincronizar"),
+    ("playlists.new", "Nueva lista"),
+    ("playlists.select_to_sync", "Elige la lista a sincronizar"),
+    ("playlists.choose", "Elige una lista"),
+    (
+        "playlists.choose_description",
+        "Selecciona una lista para sincronizar con el dispositivo conectado",
+    ),
+    ("count.tracks.one", "{count} pista"),
+    ("count.tracks.other", "{count} pistas"),
+    ("count.files.one", "{count} archivo"),
+    ("count.files.other", "{count} archivos"),
+    ("count.failed.one", "{count} con error"),
+    ("count.failed.other", "{count} con error"),
+    ("count.downloads.one", "{count} descarga"),
+    ("count.downloads.other", "{count} descargas"),
+    ("count.syncs.one", "{count} sincronización"),
+    ("count.syncs.other", "{count} sincronizaciones"),
+    ("count.audio_files.one", "{count} archivo de audio"),
+    ("count.audio_files.other", "{count} archivos de audio"),
+    ("count.videos.one", "{count} vídeo"),
+    ("count.videos.other", "{count} vídeos"),
+    ("count.playlists.one", "{count} lista"),
+    ("count.playlists.other", "{count} listas"),
+    ("count.thumbnails.one", "{count} miniatura"),
+    ("count.thumbnails.other", "{count} miniaturas"),
+    ("count.temp_files.one", "{count} archivo temporal"),
+    ("count.temp_files.other", "{count} archivos temporales"),
+    (
+        "banner.device_connected",
+        "Dispositivo «{device}» conectado",
+    ),
+    (
+        "banner.device_disconnected",
+        "Dispositivo «{device}» desconectado",
+    ),
+    ("banner.sync_now", "Sincronizar ahora"),
+    ("banner.clean", "Limpiar dispositivo"),
+    ("banner.ignore", "Ignorar este dispositivo"),
+    (
+        "banner.auto_syncing",
+        "Sincronizando sus últimas listas automáticamente",
+    ),
+    ("banner.dismiss", "Cerrar"),
+    (
+        "banner.ignored",
+        "«{device}» se ignorará. Puedes cambiarlo en sus ajustes.",
+    ),
+    (
+        "banner.ignore_failed",
+        "No se pudo ignorar el dispositivo: {error}",
+    ),
+    (
+        "toast.device_ejected",
+        "Dispositivo expulsado. Ya puedes retirarlo.",
+    ),
+    ("toast.select_device", "Selecciona primero un dispositivo"),
+    ("toast.select_playlist", "Selecciona primero una lista"),
+    ("toast.playlist_created", "Lista «{name}» creada"),
+    ("toast.playlist_deleted", "Lista «{name}» eliminada"),
+    ("toast.dismiss", "Cerrar notificación"),
+    (
+        "toast.download_failed",
+        "No se pudo iniciar la descarga: {error}",
+    ),
+    ("toast.downloading", "Descargando «{name}»"),
+    (
+        "toast.create_playlist_failed",
+        "No se pudo crear la lista: {error}",
+    ),
+    (
+        "toast.load_devices_failed",
+        "No se pudieron cargar los dispositivos: {error}",
+    ),
+    (
+        "toast.load_playlists_failed",
+        "No se pudieron cargar las listas: {error}",
+    ),
+    (
+        "toast.crashed_last_time",
+        "Youtun4 se cerró inesperadamente la última vez: {message}",
+    ),
+    (
+        "toast.transfer_complete",
+        "Transferencia completada: {files} transferidos a {device}",
+    ),
+    ("toast.transfer_failed", "La transferencia falló: {error}"),
+    ("toast.transfer_cancelled", "Transferencia cancelada"),
+    (
+        "toast.video_failed",
+        "No se pudo descargar «{title}»: {error}",
+    ),
+    ("toast.downloaded.one", "{count} canción descargada"),
+    ("toast.downloaded.other", "{count} canciones descargadas"),
+    ("toast.download_error", "La descarga falló: {error}"),
+    (
+        "toast.retry_failed",
+        "No se pudo reintentar la descarga: {error}",
+    ),
+    ("toast.download_cancelled", "Descarga cancelada"),
+    (
+        "toast.deep_link_needs_playlist",
+        "Selecciona una lista y vuelve a abrir el enlace",
+    ),
+    (
+        "toast.deep_link_failed",
+        "No se pudo añadir el enlace: {error}",
+    ),
+    ("toast.deep_link_queued", "Descarga en cola para «{name}»"),
+    (
+        "toast.update_available",
+        "Youtun4 {version} está disponible (canal {channel})",
+    ),
+    ("toast.syncing", "Sincronizando «{name}» con {device}..."),
+    ("toast.synced", "«{name}» sincronizada correctamente"),
+    (
+        "toast.sync_failed",
+        "No se pudo sincronizar la lista: {error}",
+    ),
+    (
+        "toast.delete_playlist_failed",
+        "No se pudo eliminar la lista: {error}",
+    ),
+    (
+        "toast.update_playlists_failed",
+        "No se pudieron actualizar las listas: {error}",
+    ),
+    (
+        "toast.transfer_starting",
+        "Iniciando la transferencia de «{name}» a {device}...",
+    ),
+    (
+        "toast.transfer_start_failed",
+        "No se pudo iniciar la transferencia: {error}",
+    ),
+    (
+        "toast.cancel_transfer_failed",
+        "No se pudo cancelar la transferencia: {error}",
+    ),
+    (
+        "toast.cancel_download_failed",
+        "No se pudo cancelar la descarga: {error}",
+    ),
+    (
+        "toast.rename_failed",
+        "No se pudo renombrar la lista: {error}",
+    ),
+    ("toast.start_failed", "No se pudo iniciar: {error}"),
+    (
+        "toast.refresh_failed",
+        "No se pudo actualizar la lista: {error}",
+    ),
+    (
+        "toast.archive_reset",
+        "Se olvidaron {videos} descargados de «{name}»",
+    ),
+    (
+        "toast.archive_reset_failed",
+        "No se pudo restablecer el archivo de descargas: {error}",
+    ),
+    ("toast.exported", "Exportada a {path}"),
+    (
+        "toast.export_failed",
+        "No se pudo exportar la lista: {error}",
+    ),
+    ("toast.verify_ok", "«{name}» está bien ({tracks})"),
+    ("toast.verify_issues", "«{name}» tiene problemas: {issues}"),
+    (
+        "toast.verify_failed",
+        "No se pudo verificar la lista: {error}",
+    ),
+    (
+        "toast.open_folder_failed",
+        "No se pudo abrir la carpeta: {error}",
+    ),
+    ("toast.nothing_running", "No hay nada en curso"),
+    ("toast.tasks_stopped.one", "Se detuvo {count} tarea"),
+    ("toast.tasks_stopped.other", "Se detuvieron {count} tareas"),
+    (
+        "toast.stop_tasks_failed",
+        "No se pudieron detener las tareas: {error}",
+    ),
+    (
+        "toast.select_device_first",
+        "Selecciona primero un dispositivo",
+    ),
+    (
+        "toast.select_playlist_to_delete",
+        "Selecciona una lista para eliminar",
+    ),
+    (
+        "toast.show_track_failed",
+        "No se pudo mostrar la canción: {error}",
+    ),
+    (
+        "toast.update_tracks_failed",
+        "No se pudieron actualizar las canciones: {error}",
+    ),
+    ("toast.lyrics_saved", "Letra de «{name}» guardada"),
+    (
+        "toast.lyrics_not_found",
+        "No se encontró letra para «{name}»",
+    ),
+    (
+        "toast.lyrics_failed",
+        "No se pudo obtener la letra: {error}",
+    ),
+    ("toast.retry", "Reintentar"),
+    ("toast.action_countdown", "{label} ({seconds} s)"),
+    ("settings.title", "Ajustes"),
+    ("settings.close", "Cerrar ajustes"),
+    ("settings.section.downloads", "Descargas"),
+    ("settings.section.devices", "Dispositivos"),
+    ("settings.section.storage", "Almacenamiento"),
+    ("settings.section.cache", "Caché"),
+    ("settings.section.notifications", "Notificaciones"),
+    ("settings.section.appearance", "Apariencia"),
+    ("settings.section.advanced", "Avanzado"),
+    ("settings.reset", "Restablecer valores"),
+    ("settings.save", "Guardar ajustes"),
+    ("settings.saving", "Guardando..."),
+    ("settings.saved", "¡Ajustes guardados!"),
+    (
+        "settings.load_failed",
+        "No se pudieron cargar los ajustes: {error}",
+    ),
+    (
+        "settings.save_failed",
+        "No se pudieron guardar los ajustes: {error}",
+    ),
+    ("settings.language", "Idioma"),
+    (
+        "settings.language.description",
+        "Idioma de la interfaz y las notificaciones.",
+    ),
+    ("settings.language.system", "Idioma del sistema"),
+    ("settings.write_tags", "Escribir etiquetas"),
+    (
+        "settings.write_tags.description",
+        "Añadir título, artista, álbum y número de pista a las canciones descargadas",
+    ),
+    ("settings.embed_cover_art", "Incrustar carátula"),
+    (
+        "settings.embed_cover_art.description",
+        "Usar la miniatura del vídeo como carátula",
+    ),
+    ("settings.fetch_lyrics", "Obtener letras"),
+    (
+        "settings.fetch_lyrics.description",
+        "Buscar letras sincronizadas o simples para las canciones descargadas",
+    ),
+    ("settings.save_lrc", "Guardar archivos .lrc"),
+    (
+        "settings.save_lrc.description",
+        "Guardar la letra junto a la canción, para reproductores que muestran letras sincronizadas",
+    ),
+    ("settings.embed_lyrics", "Incrustar letras"),
+    (
+        "settings.embed_lyrics.description",
+        "Guardar la letra en las etiquetas de la canción",
+    ),
+    ("settings.auto_start", "Iniciar automáticamente"),
+    (
+        "settings.auto_start.description",
+        "Iniciar las descargas en cuanto se ponen en cola",
+    ),
+    ("settings.auto_retry", "Reintentar descargas fallidas"),
+    (
+        "settings.auto_retry.description",
+        "Volver a poner en cola las descargas fallidas automáticamente",
+    ),
+    ("settings.work_offline", "Trabajar sin conexión"),
+    (
+        "settings.work_offline.description",
+        "Las descargas en cola esperan hasta que se desactive el modo sin conexión",
+    ),
+    ("settings.cache_enabled", "Activar caché"),
+    (
+        "settings.cache_enabled.description",
+        "Guardar en disco los datos obtenidos para reutilizarlos",
+    ),
+    ("settings.cache_metadata", "Guardar metadatos en caché"),
+    (
+        "settings.cache_metadata.description",
+        "Evitar volver a leer las etiquetas de cada archivo",
+    ),
+    ("settings.cache_thumbnails", "Guardar miniaturas en caché"),
+    (
+        "settings.cache_thumbnails.description",
+        "Guardar en disco las imágenes de listas y canciones",
+    ),
+    ("settings.notify_download", "Descarga completada"),
+    (
+        "settings.notify_download.description",
+        "Avisar cuando termine la descarga de una lista",
+    ),
+    ("settings.notify_sync", "Sincronización completada"),
+    (
+        "settings.notify_sync.description",
+        "Avisar cuando termine la sincronización con un dispositivo",
+    ),
+    ("settings.notify_errors", "Errores"),
+    (
+        "settings.notify_errors.description",
+        "Mostrar notificaciones de errores y advertencias",
+    ),
+    ("settings.notify_device", "Dispositivo conectado"),
+    (
+        "settings.notify_device.description",
+        "Avisar cuando se conecte un dispositivo USB",
+    ),
+    ("settings.webhook_enabled", "Llamar al webhook"),
+    (
+        "settings.webhook_enabled.description",
+        "Publicar un mensaje cuando termine una descarga o sincronización",
+    ),
+    ("settings.high_contrast", "Alto contraste"),
+    (
+        "settings.high_contrast.description",
+        "Colores de texto, borde y estado más intensos; sustituye el color de acento personalizado",
+    ),
+    ("settings.minimize_to_tray", "Minimizar a la bandeja"),
+    (
+        "settings.minimize_to_tray.description",
+        "Al cerrar la ventana, Youtun4 se oculta en la bandeja del sistema",
+    ),
+    ("settings.pause_on_battery", "Pausar con batería"),
+    (
+        "settings.pause_on_battery.description",
+        "Esperar a que el equipo esté enchufado",
+    ),
+    ("settings.pause_on_metered", "Pausar en redes de uso medido"),
+    (
+        "settings.pause_on_metered.description",
+        "Esperar a una conexión sin límite de datos",
+    ),
+    (
+        "settings.pause_on_guessed_metered",
+        "Incluir redes supuestamente medidas",
+    ),
+    (
+        "settings.pause_on_guessed_metered.description",
+        "Pausar también en conexiones, como los puntos de acceso del móvil, que solo se suponen medidas",
+    ),
+    ("settings.quality", "Calidad de descarga"),
+    (
+        "settings.quality.description",
+        "Selecciona la calidad de audio de las descargas de YouTube. Las listas pueden cambiarla en sus ajustes.",
+    ),
+    ("settings.lyrics", "Letras"),
+    (
+        "settings.lyrics.description",
+        "Buscar letras en LRCLIB después de cada descarga. Las listas pueden cambiarlo en sus ajustes.",
+    ),
+    ("settings.file_names", "Nombres de archivo"),
+    (
+        "settings.file_names.template",
+        "Plantilla de nombre de archivo",
+    ),
+    ("settings.queue", "Cola de descargas"),
+    (
+        "settings.queue.description",
+        "Controla cómo se ejecutan las descargas en cola.",
+    ),
+    ("settings.queue.max_concurrent", "Descargas simultáneas"),
+    ("settings.queue.concurrency", "Vídeos simultáneos por lista"),
+    ("settings.queue.speed_limit", "Límite de velocidad"),
+    ("settings.queue.max_retries", "Reintentos por descarga"),
+    ("settings.offline", "Modo sin conexión"),
+    (
+        "settings.offline.description",
+        "Omitir todo acceso a la red y usar solo datos en caché.",
+    ),
+    ("settings.preferred_device", "Dispositivo preferido"),
+    (
+        "settings.preferred_device.description",
+        "El dispositivo que se selecciona automáticamente al conectarse. Elige uno de la lista de dispositivos.",
+    ),
+    ("settings.device_detection", "Detección de dispositivos"),
+    (
+        "settings.device_detection.description",
+        "Con qué frecuencia buscar dispositivos conectados o retirados.",
+    ),
+    (
+        "settings.device_detection.interval",
+        "Comprobar cada (segundos)",
+    ),
+    ("settings.storage_location", "Ubicación de almacenamiento"),
+    (
+        "settings.storage_location.description",
+        "Elige dónde se guardan tus listas en tu equipo.",
+    ),
+    ("settings.storage_location.directory", "Carpeta de listas"),
+    ("settings.library_limits", "Límites de la biblioteca"),
+    (
+        "settings.library_limits.description",
+        "Las descargas se detienen cuando la biblioteca alcanza su límite de tamaño o el disco se llena demasiado.",
+    ),
+    (
+        "settings.library_limits.max_size",
+        "Tamaño máximo de la biblioteca (GB)",
+    ),
+    (
+        "settings.library_limits.no_limit",
+        "Déjalo vacío para no tener límite.",
+    ),
+    (
+        "settings.library_limits.min_free",
+        "Mantener libre en disco (GB)",
+    ),
+    ("settings.cache", "Caché"),
+    (
+        "settings.cache.description",
+        "Los detalles de listas, metadatos y miniaturas en caché hacen la aplicación más rápida y permiten trabajar sin conexión.",
+    ),
+    ("settings.cache.max_size", "Tamaño máximo de la caché (MB)"),
+    ("settings.cache.ttl", "Conservar entradas durante (días)"),
+    (
+        "settings.clear_cache.description",
+        "Eliminar todos los detalles de listas, metadatos y miniaturas en caché. Se volverán a obtener cuando haga falta.",
+    ),
+    ("settings.notifications", "Preferencias de notificación"),
+    (
+        "settings.notifications.description",
+        "Elige qué notificaciones quieres recibir.",
+    ),
+    ("settings.webhook", "Webhook"),
+    (
+        "settings.webhook.description",
+        "Publicar un mensaje en una URL, como un tema de ntfy o un webhook de Discord, al terminar descargas y sincronizaciones. Las llamadas se omiten en modo sin conexión.",
+    ),
+    ("settings.webhook.url", "URL del webhook"),
+    ("settings.webhook.save_url", "Guardar URL"),
+    (
+        "settings.webhook.url_hint",
+        "La URL se guarda en un almacenamiento seguro, no en el archivo de ajustes.",
+    ),
+    ("settings.webhook.payload", "Plantilla del contenido"),
+    ("settings.webhook.test", "Enviar mensaje de prueba"),
+    ("settings.theme", "Tema"),
+    (
+        "settings.theme.description",
+        "Elige la apariencia de la aplicación.",
+    ),
+    (
+        "settings.theme.system_hint",
+        "Sistema sigue el ajuste claro u oscuro de tu sistema operativo.",
+    ),
+    ("settings.accent_color", "Color de acento"),
+    (
+        "settings.accent_color.description",
+        "Color de los botones, resaltados y anillos de foco.",
+    ),
+    ("settings.accent_color.default", "Usar predeterminado"),
+    ("settings.accessibility", "Accesibilidad"),
+    ("settings.extraction", "Extracción"),
+    (
+        "settings.extraction.description",
+        "Elige cómo se leen las listas de YouTube. yt-dlp debe instalarse por separado.",
+    ),
+    ("settings.extraction.yt_dlp_path", "Ubicación de yt-dlp"),
+    ("settings.background", "Segundo plano"),
+    (
+        "settings.background.description",
+        "Mantener las descargas y sincronizaciones cuando la ventana está cerrada.",
+    ),
+    ("settings.power", "Energía y red"),
+    (
+        "settings.power.description",
+        "Pausar las descargas y otras tareas pesadas cuando los recursos son limitados.",
+    ),
+    ("settings.updates", "Actualizaciones"),
+    (
+        "settings.updates.description",
+        "Elige qué versiones busca Youtun4 al iniciarse.",
+    ),
+    ("settings.log", "Registro de la aplicación"),
+    (
+        "settings.log.description",
+        "Entradas recientes del registro, actualizadas mientras esta sección está abierta. Cópialas en un informe de error si falla una descarga.",
+    ),
+    (
+        "settings.file_names.description",
+        "Nombrar las canciones descargadas a partir de {fields}. {padded} rellena el índice con ceros. Las listas pueden cambiarlo en sus ajustes.",
+    ),
+    ("settings.file_names.example", "Ejemplo: {name}"),
+    ("settings.unlimited", "Sin límite"),
+    (
+        "settings.preferred_device.none",
+        "Ningún dispositivo preferido",
+    ),
+    (
+        "settings.storage_location.placeholder",
+        "Introduce la ruta de la carpeta...",
+    ),
+    (
+        "settings.storage_location.default",
+        "Predeterminado: {path}",
+    ),
+    ("settings.clear_cache", "Vaciar caché"),
+    ("settings.webhook.url_saved", "URL del webhook guardada"),
+    ("settings.webhook.url_removed", "URL del webhook eliminada"),
+    ("settings.webhook.sending", "Enviando mensaje de prueba..."),
+    ("settings.webhook.sent", "Mensaje de prueba enviado"),
+    (
+        "settings.webhook.url_replace",
+        "Hay una URL guardada; introduce otra para reemplazarla",
+    ),
+    (
+        "settings.webhook.payload_hint",
+        "Usa {fields}. Las plantillas que empiezan por {brace} se envían como JSON, p. ej. {example} para Discord.",
+    ),
+    (
+        "settings.webhook.download_completed",
+        "Cuando termina la descarga de una lista",
+    ),
+    (
+        "settings.webhook.download_failed",
+        "Cuando falla la descarga de una lista",
+    ),
+    (
+        "settings.webhook.sync_completed",
+        "Cuando termina la sincronización con un dispositivo",
+    ),
+    (
+        "settings.webhook.sync_failed",
+        "Cuando falla la sincronización con un dispositivo",
+    ),
+    ("settings.theme.dark", "Oscuro"),
+    ("settings.theme.light", "Claro"),
+    ("settings.theme.system", "Sistema"),
+    ("settings.extraction.auto", "Automático"),
+    (
+        "settings.extraction.auto.description",
+        "Integrado, con yt-dlp cuando no se pueden leer las páginas de YouTube",
+    ),
+    ("settings.extraction.built_in", "Integrado"),
+    (
+        "settings.extraction.built_in.description",
+        "No ejecutar nunca yt-dlp",
+    ),
+    ("settings.extraction.yt_dlp", "yt-dlp"),
+    (
+        "settings.extraction.yt_dlp.description",
+        "Leer y descargar todo con yt-dlp",
+    ),
+    (
+        "settings.extraction.yt_dlp_path.placeholder",
+        "yt-dlp (encontrado en el PATH)",
+    ),
+    ("settings.updates.stable", "Estable"),
+    (
+        "settings.updates.stable.description",
+        "Solo versiones probadas",
+    ),
+    ("settings.updates.beta", "Beta"),
+    (
+        "settings.updates.beta.description",
+        "Versiones preliminares con las últimas funciones",
+    ),
+    ("delete_playlist.title", "Eliminar lista"),
+    (
+        "delete_playlist.confirm",
+        "¿Seguro que quieres eliminar «{name}»?",
+    ),
+    ("delete_playlist.tracks", "Pistas:"),
+    ("delete_playlist.size", "Tamaño:"),
+    ("delete_playlist.source", "Origen:"),
+    ("delete_playlist.youtube", "Lista de YouTube"),
+    (
+        "delete_playlist.warning",
+        "Esta acción no se puede deshacer. Todas las pistas y archivos se eliminarán de forma permanente del almacenamiento local.",
+    ),
+    ("shortcuts.title", "Atajos de teclado"),
+    ("shortcuts.close", "Cerrar atajos de teclado"),
+    ("shortcuts.group.general", "General"),
+    ("shortcuts.group.navigation", "Navegación"),
+    ("shortcuts.group.playlists", "Listas"),
+    ("shortcuts.group.dialogs", "Diálogos"),
+    ("shortcuts.show", "Mostrar los atajos de teclado"),
+    ("shortcuts.search", "Buscar en la biblioteca"),
+    ("shortcuts.settings", "Abrir los ajustes"),
+    ("shortcuts.undo", "Deshacer el último cambio"),
+    ("shortcuts.playlists", "Ir a las listas"),
+    ("shortcuts.history", "Ir al historial"),
+    ("shortcuts.device", "Ir al dispositivo seleccionado"),
+    (
+        "shortcuts.delete",
+        "Eliminar la lista abierta o seleccionada",
+    ),
+    (
+        "shortcuts.preview",
+        "Reproducir o pausar la vista previa de la pista",
+    ),
+    ("shortcuts.focus", "Moverse entre los controles"),
+    ("shortcuts.close_dialog", "Cerrar el diálogo"),
+    ("rename_playlist.title", "Renombrar lista"),
+    ("rename_playlist.label", "Nuevo nombre"),
+    ("rename_playlist.confirm", "Renombrar"),
+    ("sync_wizard.step.device", "Dispositivo"),
+    ("sync_wizard.step.playlists", "Listas"),
+    ("sync_wizard.step.review", "Revisión"),
+    ("sync_wizard.step.sync", "Sincronización"),
+    ("sync_wizard.change.add", "Añadir"),
+    ("sync_wizard.change.replace", "Reemplazar"),
+    ("sync_wizard.change.keep", "Conservar"),
+    ("sync_wizard.change.remove", "Eliminar"),
+    (
+        "sync_wizard.synced.one",
+        "{count} archivo sincronizado con el dispositivo",
+    ),
+    (
+        "sync_wizard.synced.other",
+        "{count} archivos sincronizados con el dispositivo",
+    ),
+    (
+        "sync_wizard.finished_announcement.one",
+        "Sincronización terminada. {count} archivo transferido.",
+    ),
+    (
+        "sync_wizard.finished_announcement.other",
+        "Sincronización terminada. {count} archivos transferidos.",
+    ),
+    ("sync_wizard.failed", "La sincronización falló: {error}"),
+    (
+        "sync_wizard.failed_announcement",
+        "La sincronización falló. {error}",
+    ),
+    (
+        "sync_wizard.cancel_failed",
+        "No se pudo cancelar la sincronización: {error}",
+    ),
+    (
+        "sync_wizard.no_devices",
+        "Conecta un dispositivo para sincronizar.",
+    ),
+    ("sync_wizard.device_space", "{free} libres de {total}"),
+    ("sync_wizard.selection", "{count} seleccionadas · {size}"),
+    (
+        "sync_wizard.cleanup",
+        "Eliminar primero los demás archivos del dispositivo",
+    ),
+    ("sync_wizard.comparing", "Comparando con el dispositivo..."),
+    ("sync_wizard.no_preview", "No hay vista previa disponible."),
+    (
+        "sync_wizard.preview_summary",
+        "{write} por escribir, {remove} por eliminar",
+    ),
+    (
+        "sync_wizard.fits_after_cleanup",
+        "Cabe una vez eliminados los archivos antiguos del dispositivo",
+    ),
+    (
+        "sync_wizard.capacity_label",
+        "{kept} % conservado, {incoming} % entrante",
+    ),
+    ("sync_wizard.kept", "Conservado"),
+    ("sync_wizard.incoming", "De esta sincronización"),
+    ("sync_wizard.outcome.success", "Sincronización completada"),
+    ("sync_wizard.outcome.cancelled", "Sincronización cancelada"),
+    ("sync_wizard.outcome.failed", "La sincronización falló"),
+    (
+        "sync_wizard.outcome.counts",
+        "{copied} copiados, {skipped} omitidos, {failed} con error",
+    ),
+    ("sync_wizard.starting", "Iniciando sincronización..."),
+    ("sync_wizard.progress", "Progreso de la sincronización"),
+    ("sync_wizard.close", "Cerrar el asistente de sincronización"),
+    ("sync_wizard.cancel", "Cancelar sincronización"),
+    ("sync_wizard.start", "Iniciar sincronización"),
+    ("history.range.week", "Últimos 7 días"),
+    ("history.range.month", "Últimos 30 días"),
+    ("history.range.quarter", "Últimos 90 días"),
+    ("history.range.all", "Todo el historial"),
+    ("history.status.completed", "Completado"),
+    (
+        "history.status.completed_with_errors",
+        "Completado con errores",
+    ),
+    ("history.status.failed", "Error"),
+    ("history.status.cancelled", "Cancelado"),
+    ("history.entry.download", "Descarga"),
+    ("history.entry.sync", "Sincronización con el dispositivo"),
+    ("history.entry.sync_to", "Sincronización con {device}"),
+    ("history.stats.this_month", "{size} este mes"),
+    ("history.stats.total", "{size} en total"),
+    ("history.stats.average_speed", "{speed} de media"),
+    (
+        "history.pruned",
+        "Se eliminaron {count} entradas del historial de más de {days} días",
+    ),
+    (
+        "history.prune_failed",
+        "No se pudieron eliminar las entradas antiguas: {error}",
+    ),
+    (
+        "history.cleared.one",
+        "Se eliminó {count} entrada del historial",
+    ),
+    (
+        "history.cleared.other",
+        "Se eliminaron {count} entradas del historial",
+    ),
+    (
+        "history.clear_failed",
+        "No se pudo borrar el historial: {error}",
+    ),
+    ("history.chart.week", "Semana del {week}: {size}"),
+    ("history.chart.title", "Descargado por semana"),
+    ("history.copied", "{size} copiados"),
+    ("history.loading", "Cargando historial..."),
+    (
+        "history.no_matches",
+        "Ninguna descarga o sincronización coincide con estos filtros",
+    ),
+    ("history.prune", "Eliminar entradas antiguas"),
+    (
+        "history.prune_hint",
+        "Eliminar las entradas de más de {days} días",
+    ),
+    ("history.clear", "Borrar historial"),
+    (
+        "history.clear_confirm",
+        "¿Eliminar todas las descargas y sincronizaciones del historial?",
+    ),
+    ("history.filter.date", "Fecha"),
+    ("history.filter.type", "Tipo"),
+    ("history.filter.all_kinds", "Descargas y sincronizaciones"),
+    ("history.filter.downloads", "Descargas"),
+    ("history.filter.syncs", "Sincronizaciones"),
+    ("history.filter.status", "Estado"),
+    ("history.filter.any_status", "Cualquier estado"),
+    ("history.filter.playlist", "Lista"),
+    ("history.filter.all_playlists", "Todas las listas"),
+    (
+        "device_detail.almost_full",
+        "El dispositivo está casi lleno",
+    ),
+    (
+        "device_detail.files_failed.one",
+        "{count} archivo no se pudo copiar en la última sincronización",
+    ),
+    (
+        "device_detail.files_failed.other",
+        "{count} archivos no se pudieron copiar en la última sincronización",
+    ),
+    (
+        "device_detail.unreadable.one",
+        "{count} elemento del dispositivo no se pudo leer",
+    ),
+    (
+        "device_detail.unreadable.other",
+        "{count} elementos del dispositivo no se pudieron leer",
+    ),
+    (
+        "device_detail.settings_saved",
+        "Ajustes del dispositivo guardados",
+    ),
+    (
+        "device_detail.settings_save_failed",
+        "No se pudieron guardar los ajustes del dispositivo: {error}",
+    ),
+    ("device_detail.verifying", "Verificando..."),
+    (
+        "device_detail.verify_failed",
+        "No se pudo verificar el dispositivo: {error}",
+    ),
+    ("device_detail.cleaning", "Limpiando..."),
+    (
+        "device_detail.cleaned",
+        "Se eliminaron {files} y se liberaron {size}",
+    ),
+    (
+        "device_detail.clean_failed",
+        "No se pudo limpiar el dispositivo: {error}",
+    ),
+    ("device_detail.ejecting", "Expulsando..."),
+    (
+        "device_detail.eject_refused",
+        "No se pudo expulsar el dispositivo",
+    ),
+    (
+        "device_detail.eject_failed",
+        "No se pudo expulsar el dispositivo: {error}",
+    ),
+    ("device_detail.scanning", "Analizando el dispositivo..."),
+    (
+        "device_detail.usage_label",
+        "{music} % música, {other} % otros archivos, {system} % sistema",
+    ),
+    ("device_detail.music", "Música"),
+    ("device_detail.other", "Otros"),
+    ("device_detail.system", "Sistema"),
+    ("device_detail.free", "Libre"),
+    ("device_detail.free_of", "{free} de {total}"),
+    ("device_detail.no_problems", "No se encontraron problemas"),
+    ("device_detail.not_synced", "Aún no sincronizado"),
+    ("device_detail.files_copied.one", "{count} archivo copiado"),
+    (
+        "device_detail.files_copied.other",
+        "{count} archivos copiados",
+    ),
+    (
+        "device_detail.verify_ok.one",
+        "{count} pista está en el dispositivo",
+    ),
+    (
+        "device_detail.verify_ok.other",
+        "Las {count} pistas están en el dispositivo",
+    ),
+    (
+        "device_detail.verify_counts",
+        "{matching} iguales, {missing} faltan, {different} diferentes",
+    ),
+    ("device_detail.sync", "Sincronizar"),
+    ("device_detail.verify", "Verificar"),
+    (
+        "device_detail.verify_hint",
+        "Comparar el dispositivo con las listas de su última sincronización",
+    ),
+    ("device_detail.clean", "Limpiar"),
+    ("device_detail.eject", "Expulsar"),
+    (
+        "device_detail.disconnected",
+        "Este dispositivo ya no está conectado.",
+    ),
+    (
+        "device_detail.clean_confirm",
+        "¿Eliminar todo el contenido del dispositivo excepto los archivos ocultos y del sistema?",
+    ),
+    ("device_detail.delete_files", "Eliminar archivos"),
+    ("device_detail.last_sync", "Última sincronización"),
+    ("device_detail.nickname", "Apodo"),
+    (
+        "device_detail.auto_sync",
+        "Volver a sincronizar las últimas listas al conectar este dispositivo",
+    ),
+    (
+        "device_detail.ignore",
+        "Ignorar este dispositivo cuando se conecte",
+    ),
+    ("onboarding.step.storage", "Almacenamiento"),
+    ("onboarding.step.quality", "Calidad"),
+    ("onboarding.step.playlist", "Primera lista"),
+    ("onboarding.step.device", "Dispositivo"),
+    ("onboarding.name_required", "Ponle un nombre a la lista"),
+    (
+        "onboarding.devices_failed",
+        "No se pudieron buscar dispositivos: {error}",
+    ),
+    (
+        "onboarding.directory_required",
+        "Elige una carpeta para tus listas",
+    ),
+    (
+        "onboarding.save_failed",
+        "No se pudo guardar la configuración: {error}",
+    ),
+    (
+        "onboarding.invalid_url",
+        "No es una URL de lista de YouTube",
+    ),
+    (
+        "onboarding.storage.description",
+        "Elige dónde guarda Youtun4 tus listas. Cada lista es una carpeta de archivos MP3.",
+    ),
+    ("onboarding.storage.folder", "Carpeta de listas"),
+    ("onboarding.storage.suggested", "Sugerencia: {path}"),
+    (
+        "onboarding.storage.music_found",
+        "Música encontrada en este equipo:",
+    ),
+    (
+        "onboarding.quality.description",
+        "Una calidad mayor suena mejor pero llena tu reproductor más rápido. Puedes cambiarla después en Ajustes.",
+    ),
+    (
+        "onboarding.quality.low_description",
+        "128 kbps - Caben más canciones",
+    ),
+    (
+        "onboarding.playlist.description",
+        "Pega un enlace de lista de YouTube para empezar tu biblioteca, u omite este paso.",
+    ),
+    ("onboarding.playlist.url", "URL de la lista de YouTube"),
+    ("onboarding.playlist.name", "Nombre de la lista"),
+    ("onboarding.playlist.name_placeholder", "Mi primera lista"),
+    (
+        "onboarding.device.description",
+        "Conecta tu reproductor MP3 para comprobar que Youtun4 lo ve. Elígelo para seleccionarlo automáticamente la próxima vez.",
+    ),
+    ("onboarding.device.looking", "Buscando dispositivos..."),
+    (
+        "onboarding.device.none",
+        "Aún no se ha detectado ningún dispositivo.",
+    ),
+    ("onboarding.device.known", "Usados antes: {devices}"),
+    ("onboarding.device.connected", "{device} (conectado)"),
+    ("onboarding.device.check_again", "Volver a comprobar"),
+    ("onboarding.title", "Te damos la bienvenida a Youtun4"),
+    (
+        "onboarding.subtitle",
+        "Unas pocas decisiones y estarás listo para poner música en tu reproductor.",
+    ),
+    ("onboarding.setting_up", "Configurando..."),
+    ("onboarding.finish", "Finalizar"),
+    ("onboarding.skip", "Omitir"),
+    ("quality.low", "Baja"),
+    ("quality.medium", "Media"),
+    (
+        "quality.medium.description",
+        "192 kbps - Equilibrio entre calidad y tamaño",
+    ),
+    ("quality.high", "Alta"),
+    (
+        "quality.high.description",
+        "320 kbps - Mejor calidad, archivos más grandes",
+    ),
+    (
+        "quality.low.description",
+        "128 kbps - Archivos más pequeños, menor calidad",
+    ),
+    ("webhook_event.download_completed", "Descarga completada"),
+    ("webhook_event.download_failed", "Descarga fallida"),
+    ("webhook_event.sync_completed", "Sincronización completada"),
+    ("webhook_event.sync_failed", "Sincronización fallida"),
+    (
+        "announce.sync_finished",
+        "Sincronización terminada. {files} transferidos.",
+    ),
+    ("announce.sync_failed", "La sincronización falló. {error}"),
+    (
+        "announce.download_finished",
+        "Descarga terminada. {downloaded} descargados, {failed} fallidos, {skipped} omitidos.",
+    ),
+    ("announce.download_failed", "La descarga falló. {error}"),
+    ("undo.renamed", "«{from}» renombrada a «{to}»"),
+    ("undo.tracks_removed", "Se quitaron {tracks} de «{name}»"),
+    ("batch.partial", "{succeeded} de {items}"),
+    ("batch.deleted", "Eliminado: {items}"),
+    ("batch.moved", "Movido: {items}"),
+    ("batch.tagged", "Etiquetado: {items}"),
+    ("batch.first_failure", ". {item}: {error}"),
+    ("batch.more_failures.one", " (y {count} más)"),
+    ("batch.more_failures.other", " (y {count} más)"),
+    ("batch.toolbar", "Acciones de selección"),
+    ("batch.selected", "Seleccionado: {items}"),
+    ("batch.select_all", "Seleccionar todo"),
+    ("batch.sync_selected", "Sincronizar selección"),
+    ("batch.move", "Mover"),
+    ("batch.tag", "Etiquetar"),
+    ("batch.tag_name", "Nombre de la etiqueta"),
+    ("batch.add_tag", "Añadir etiqueta"),
+    ("batch.move_target", "Lista de destino"),
+    ("batch.move_here", "Mover aquí"),
+    (
+        "batch.confirm_delete",
+        "¿Eliminar {items}? No se puede deshacer.",
+    ),
+    ("playlist_detail.back_label", "Volver a la lista de listas"),
+    ("playlist_detail.settings", "Ajustes de la lista"),
+    ("playlist_detail.open_folder", "Mostrar en la carpeta"),
+    (
+        "playlist_detail.open_folder.title",
+        "Abrir en el gestor de archivos",
+    ),
+    ("playlist_detail.repair", "Reparar"),
+    (
+        "playlist_detail.repair.title",
+        "Corregir los metadatos de la carpeta de la lista",
+    ),
+    ("playlist_detail.checksums", "Sumas de comprobación"),
+    (
+        "playlist_detail.checksums.title",
+        "Guardar las sumas de comprobación de las canciones para verificarlas después",
+    ),
+    ("playlist_detail.sync", "Sincronizar"),
+    ("playlist_detail.delete.title", "Eliminar lista"),
+    ("playlist_detail.source", "Origen"),
+    ("playlist_detail.created", "Creada"),
+    ("playlist_detail.modified", "Modificada"),
+    (
+        "playlist_detail.updating",
+        "Actualizando «{name}» desde su origen",
+    ),
+    ("playlist_detail.checking", "Comprobando..."),
+    ("playlist_detail.check_updates", "Buscar actualizaciones"),
+    (
+        "playlist_detail.remove_missing.one",
+        "Quitar {count} canción que ya no está en el origen",
+    ),
+    (
+        "playlist_detail.remove_missing.other",
+        "Quitar {count} canciones que ya no están en el origen",
+    ),
+    ("playlist_detail.update", "Actualizar"),
+    ("playlist_detail.load_failed", "No se pudo cargar la lista"),
+    ("playlist_detail.tracks", "Canciones"),
+    (
+        "playlist_detail.select_hint",
+        "Haz clic en las canciones para seleccionarlas; Mayús+clic selecciona un rango",
+    ),
+    (
+        "playlist_detail.track_hint",
+        "Haz clic en una canción para ver su información",
+    ),
+    ("playlist_detail.not_found", "Lista no encontrada"),
+    ("track_preview.title", "Vista previa de la canción"),
+    ("track_preview.reveal", "Mostrar en el gestor de archivos"),
+    ("track_preview.close", "Cerrar vista previa"),
+    (
+        "track_preview.no_metadata",
+        "No se encontraron metadatos ID3 en este archivo",
+    ),
+    ("track_preview.empty", "Ninguna canción seleccionada"),
+    ("track.artist", "Artista"),
+    ("track.album", "Álbum"),
+    ("track.number", "Pista"),
+    ("track.duration", "Duración"),
+    ("track.year", "Año"),
+    ("track.genre", "Género"),
+    ("track.bitrate", "Tasa de bits"),
+    ("track.file_size", "Tamaño del archivo"),
+    ("track.file_name", "Nombre del archivo"),
+    (
+        "playlist_diff.new.one",
+        "Se descargará {count} canción nueva",
+    ),
+    (
+        "playlist_diff.new.other",
+        "Se descargarán {count} canciones nuevas",
+    ),
+    ("playlist_diff.removed.one", "{count} eliminada"),
+    ("playlist_diff.removed.other", "{count} eliminadas"),
+    ("playlist_diff.renamed.one", "{count} renombrada"),
+    ("playlist_diff.renamed.other", "{count} renombradas"),
+    ("playlist_diff.moved.one", "{count} movida"),
+    ("playlist_diff.moved.other", "{count} movidas"),
+    ("playlist_diff.unavailable.one", "{count} no disponible"),
+    ("playlist_diff.unavailable.other", "{count} no disponibles"),
+    ("playlist_diff.up_to_date", "Al día"),
+    ("size_estimate.summary", "~{size}, {length} de audio"),
+    ("playlist_settings.use_global", "Usar el ajuste global"),
+    ("playlist_settings.quality.low", "Baja (128 kbps)"),
+    ("playlist_settings.quality.medium", "Media (192 kbps)"),
+    ("playlist_settings.quality.high", "Alta (320 kbps)"),
+    ("playlist_settings.lyrics.on", "Obtener letras"),
+    ("playlist_settings.lyrics.off", "No obtener letras"),
+    (
+        "playlist_settings.bitrate.default",
+        "Usar la calidad de descarga",
+    ),
+    ("playlist_settings.format.mp3", "MP3"),
+    (
+        "playlist_settings.format.original",
+        "Original (sin conversión)",
+    ),
+    ("playlist_settings.tags.none", "Sin etiquetas"),
+    ("playlist_settings.tags.tags", "Solo etiquetas"),
+    ("playlist_settings.tags.cover_art", "Etiquetas y carátula"),
+    ("playlist_settings.refresh.never", "Nunca"),
+    ("playlist_settings.refresh.6h", "Cada 6 horas"),
+    ("playlist_settings.refresh.12h", "Cada 12 horas"),
+    ("playlist_settings.refresh.24h", "Cada día"),
+    ("playlist_settings.refresh.nightly", "Cada noche a las 3:00"),
+    ("playlist_settings.refresh.weekly", "Cada semana"),
+    (
+        "playlist_settings.saved",
+        "Ajustes guardados para \"{name}\"",
+    ),
+    (
+        "playlist_settings.save_failed",
+        "No se pudieron guardar los ajustes de la lista: {error}",
+    ),
+    ("playlist_settings.quality", "Calidad de descarga"),
+    (
+        "playlist_settings.quality.hint",
+        "Se usa para las nuevas descargas de esta lista.",
+    ),
+    (
+        "playlist_settings.bitrate.hint",
+        "Sustituye la calidad de descarga para esta lista.",
+    ),
+    ("playlist_settings.format", "Formato de archivo"),
+    ("playlist_settings.tags", "Etiquetas"),
+    ("playlist_settings.file_names", "Nombres de archivo"),
+    ("playlist_settings.file_names.default", "Predeterminado"),
+    (
+        "playlist_settings.file_names.hint",
+        "Sustituye la plantilla de nombre de archivo, por ejemplo {example}.",
+    ),
+    ("playlist_settings.refresh", "Actualizar desde el origen"),
+    (
+        "playlist_settings.refresh.remove_missing",
+        "Quitar las pistas que ya no están en el origen",
+    ),
+    (
+        "playlist_settings.refresh.hint",
+        "Los vídeos nuevos se descargan mientras la aplicación está abierta.",
+    ),
+    (
+        "storage.clear_cache_failed",
+        "No se pudo vaciar la caché: {error}",
+    ),
+    ("storage.archived", "\"{name}\" archivada en {path}"),
+    (
+        "storage.archive_failed",
+        "No se pudo archivar \"{name}\": {error}",
+    ),
+    ("storage.moved", "{files} movidos ({size}) a {path}"),
+    (
+        "storage.directory_changed",
+        "Las listas se guardan ahora en {path}",
+    ),
+    (
+        "storage.directory_change_failed",
+        "No se pudo cambiar la carpeta de almacenamiento: {error}",
+    ),
+    ("storage.measuring", "Midiendo el almacenamiento..."),
+    ("storage.library_limit", "Límite de la biblioteca: {size}"),
+    (
+        "storage.disk_usage",
+        "{playlists} % listas, {cache} % caché, {free} % libre",
+    ),
+    ("storage.legend.playlists", "Listas · {size}"),
+    ("storage.legend.cache", "Caché · {size}"),
+    ("storage.legend.free", "Libre · {size}"),
+    ("storage.legend.free_unknown", "Libre · desconocido"),
+    (
+        "storage.min_free_space",
+        "Las descargas se detienen cuando quedan menos de {size} libres",
+    ),
+    ("storage.no_playlists", "Todavía no hay listas"),
+    ("storage.archive_playlist", "Archivar {name}"),
+    ("storage.archive", "Archivar"),
+    ("storage.cache_off", "La caché está desactivada"),
+    ("storage.cache_usage", "Caché llena al {percent} %"),
+    (
+        "storage.cache_summary",
+        "{used} de {max} · {metadata} metadatos, {thumbnails}, {temp}",
+    ),
+    ("storage.disk", "Disco"),
+    (
+        "storage.archive_confirm",
+        "¿Mover \"{name}\" a {path}? Dejará de aparecer en tu biblioteca.",
+    ),
+    ("storage.directory", "Carpeta de almacenamiento"),
+    (
+        "storage.directory.description",
+        "Las listas se guardan en esta carpeta. Al moverlas se copia y verifica cada archivo antes del cambio.",
+    ),
+    (
+        "storage.directory.placeholder",
+        "Introduce la ruta de la carpeta...",
+    ),
+    ("storage.moving", "Moviendo..."),
+    ("storage.change", "Cambiar"),
+    (
+        "storage.move_playlists",
+        "Mover mis listas a la nueva carpeta",
+    ),
+    (
+        "create_playlist.name_required",
+        "El nombre de la lista es obligatorio",
+    ),
+    (
+        "create_playlist.name_too_long",
+        "El nombre debe tener 255 caracteres como máximo",
+    ),
+    (
+        "create_playlist.name_invalid_char",
+        "El nombre no puede contener '{char}'",
+    ),
+    (
+        "create_playlist.name_reserved",
+        "Este nombre está reservado por el sistema",
+    ),
+    (
+        "create_playlist.validation_error",
+        "Error de validación: {error}",
+    ),
+    ("create_playlist.title", "Nueva lista"),
+    ("create_playlist.search", "Buscar en YouTube"),
+    (
+        "create_playlist.search.placeholder",
+        "Buscar vídeos y listas",
+    ),
+    ("create_playlist.search.filter", "Filtro de búsqueda"),
+    ("create_playlist.search.all", "Todo"),
+    ("create_playlist.search.videos", "Vídeos"),
+    ("create_playlist.searching", "Buscando..."),
+    ("create_playlist.search.button", "Buscar"),
+    ("create_playlist.no_results", "No se encontraron resultados"),
+    ("create_playlist.result.video", "Vídeo"),
+    ("create_playlist.result.playlist", "Lista"),
+    ("create_playlist.url", "URL de la lista de YouTube"),
+    (
+        "create_playlist.url.hint",
+        "Pega la URL de una lista de YouTube o elige un resultado de búsqueda",
+    ),
+    ("create_playlist.validating", "Validando la URL..."),
+    ("create_playlist.url_type", "Tipo:"),
+    ("create_playlist.playlist_id", "ID de la lista:"),
+    (
+        "create_playlist.mix_warning",
+        "Los mixes no terminan nunca: solo se descargarán los primeros {count} vídeos",
+    ),
+    ("create_playlist.name", "Nombre de la lista"),
+    (
+        "create_playlist.name.placeholder",
+        "Introduce un nombre para tu lista",
+    ),
+    ("create_playlist.creating", "Creando..."),
+    ("create_playlist.create", "Crear lista"),
+    ("create_playlist.url_type.playlist", "Lista de YouTube"),
+    (
+        "create_playlist.url_type.watch_with_playlist",
+        "Vídeo con lista",
+    ),
+    (
+        "create_playlist.url_type.single_video",
+        "Vídeo suelto (sin lista)",
+    ),
+    ("create_playlist.url_type.short_url", "URL corta"),
+    ("create_playlist.url_type.channel", "Subidas del canal"),
+    ("create_playlist.url_type.invalid", "URL no válida"),
+    ("first_playlist.step.paste", "Pegar un enlace"),
+    ("first_playlist.step.check", "Comprobar la lista"),
+    ("first_playlist.step.name", "Nombrar y descargar"),
+    (
+        "first_playlist.name_taken",
+        "Ya existe una lista con este nombre",
+    ),
+    (
+        "first_playlist.fetching",
+        "Obteniendo los detalles de la lista...",
+    ),
+    (
+        "first_playlist.load_failed",
+        "No se pudo cargar la lista: {error}",
+    ),
+    ("first_playlist.estimating", "Estimando el tamaño..."),
+    (
+        "first_playlist.unavailable",
+        "{count} privados, eliminados o bloqueados (se omitirán)",
+    ),
+    ("first_playlist.title", "Añade tu primera lista"),
+    (
+        "first_playlist.url.hint",
+        "Copia el enlace de una lista de YouTube y pégalo aquí",
+    ),
+    ("first_playlist.checking", "Comprobando el enlace..."),
+    ("first_playlist.valid", "Enlace de lista"),
+    ("first_playlist.queueing", "Poniendo en cola..."),
+    ("first_playlist.download", "Descargar"),
+    (
+        "empty.playlists.title",
+        "Empieza a crear tu biblioteca musical",
+    ),
+    (
+        "empty.playlists.message",
+        "Pega la URL de una lista de YouTube para descargar las pistas como MP3.",
+    ),
+    (
+        "empty.playlists.hint",
+        "Las descargas se guardan localmente y pueden sincronizarse con cualquier reproductor MP3.",
+    ),
+    ("empty.playlists.add", "Añadir lista de YouTube"),
+    ("empty.device.title", "Conecta tu reproductor MP3"),
+    (
+        "empty.device.message",
+        "Conecta tu reproductor MP3 o memoria USB para sincronizar música.",
+    ),
+    (
+        "empty.device.hint",
+        "La mayoría de los dispositivos de almacenamiento USB se detectan automáticamente.",
+    ),
+    ("empty.device.scan", "Buscar dispositivos"),
+    (
+        "empty.search.message",
+        "No se encontraron resultados para \"{query}\"",
+    ),
+    (
+        "empty.search.hint",
+        "Prueba a ajustar los términos de búsqueda o revisa si hay erratas.",
+    ),
+    ("empty.search.clear", "Borrar la búsqueda"),
+    ("empty.tracks.title", "No hay pistas en esta lista"),
+    (
+        "empty.tracks.message",
+        "Esta lista todavía no tiene pistas.",
+    ),
+    (
+        "empty.tracks.hint",
+        "Las pistas se descargan al crear una lista a partir de una URL de YouTube.",
+    ),
+    ("empty.sync.title", "Nada que sincronizar"),
+    (
+        "empty.sync.message",
+        "Todas las pistas ya están en tu dispositivo.",
+    ),
+    (
+        "empty.sync.hint",
+        "Añade listas nuevas o actualiza las existentes para sincronizar contenido nuevo.",
+    ),
+    ("empty.error.title", "Algo salió mal"),
+    ("empty.error.retry", "Reintentar"),
+    ("empty.folder.title", "La carpeta está vacía"),
+    ("empty.folder.named_title", "\"{name}\" está vacía"),
+    ("empty.folder.message", "Esta carpeta no contiene archivos."),
+    ("youtube_error.network.title", "Error de red"),
+    (
+        "youtube_error.network.description",
+        "No se pudo conectar con YouTube. Comprueba tu conexión a Internet e inténtalo de nuevo.",
+    ),
+    (
+        "youtube_error.youtube_service.title",
+        "Error del servicio de YouTube",
+    ),
+    (
+        "youtube_error.youtube_service.description",
+        "YouTube no está disponible temporalmente o ha limitado las solicitudes. Espera un momento e inténtalo de nuevo.",
+    ),
+    ("youtube_error.invalid_url.title", "URL no válida"),
+    (
+        "youtube_error.invalid_url.description",
+        "La URL indicada no es una URL válida de lista de YouTube.",
+    ),
+    (
+        "youtube_error.playlist_not_found.title",
+        "Lista no encontrada",
+    ),
+    (
+        "youtube_error.playlist_not_found.description",
+        "No se encontró la lista. Puede ser privada, haberse eliminado o la URL es incorrecta.",
+    ),
+    (
+        "youtube_error.video_unavailable.title",
+        "Vídeo no disponible",
+    ),
+    (
+        "youtube_error.video_unavailable.description",
+        "Uno o más vídeos de la lista no están disponibles (privados, eliminados o restringidos).",
+    ),
+    (
+        "youtube_error.age_restricted.title",
+        "Contenido con restricción de edad",
+    ),
+    (
+        "youtube_error.age_restricted.description",
+        "Este contenido tiene restricción de edad y requiere autenticación.",
+    ),
+    (
+        "youtube_error.geo_restricted.title",
+        "Restricción geográfica",
+    ),
+    (
+        "youtube_error.geo_restricted.description",
+        "Este contenido no está disponible en tu región.",
+    ),
+    (
+        "youtube_error.audio_extraction.title",
+        "Error al extraer el audio",
+    ),
+    (
+        "youtube_error.audio_extraction.description",
+        "No se pudo extraer el audio del vídeo. Puede que el formato no sea compatible.",
+    ),
+    (
+        "youtube_error.file_system.title",
+        "Error del sistema de archivos",
+    ),
+    (
+        "youtube_error.file_system.description",
+        "No se pudo guardar el archivo. Comprueba el espacio en disco y los permisos.",
+    ),
+    ("youtube_error.cancelled.title", "Cancelado"),
+    (
+        "youtube_error.cancelled.description",
+        "La descarga se canceló.",
+    ),
+    ("youtube_error.library_space.title", "Espacio insuficiente"),
+    (
+        "youtube_error.library_space.description",
+        "La biblioteca ha alcanzado su tamaño máximo o el disco está casi lleno. Libera espacio o ajusta los límites de almacenamiento en los ajustes.",
+    ),
+    ("youtube_error.unknown.title", "Error"),
+    (
+        "youtube_error.unknown.description",
+        "Se produjo un error inesperado.",
+    ),
+    ("youtube_error.download_failed", "Error en la descarga"),
+    ("download_panel.video.downloaded", "Descargado"),
+    ("download_panel.video.skipped", "Omitido"),
+    ("download_panel.video.failed", "Error"),
+    (
+        "download_panel.sponsor_removed",
+        "{seconds} s de segmentos patrocinados eliminados",
+    ),
+    (
+        "download_panel.chapters",
+        "Dividido en {tracks} por capítulos",
+    ),
+    ("download_panel.source", "Origen: {source}"),
+    (
+        "download_panel.retry_failed.title",
+        "Volver a descargar los vídeos fallidos",
+    ),
+    ("download_panel.ready", "Listo"),
+    ("download_panel.downloading", "Descargando..."),
+    ("download_panel.completed", "Descarga completada"),
+    ("download_panel.cancelled", "Descarga cancelada"),
+    ("download_panel.progress", "{current} de {total}"),
+    ("download_panel.retry_hint", "Puedes volver a intentarlo"),
+    ("download_panel.cancel", "Cancelar la descarga"),
+    ("download_panel.dismiss", "Descartar"),
+    ("download_panel.dismiss_download", "Descartar la descarga"),
+    (
+        "download_panel.track_progress",
+        "Progreso de descarga de la pista",
+    ),
+    ("download_panel.progress_label", "Progreso de la descarga"),
+    ("download_panel.remaining", "restante"),
+    ("download_panel.elapsed", "transcurrido"),
+    ("download_panel.retry_count", "Reintentar {count} fallidos"),
+    ("download_panel.indicator.downloading", "Descargando"),
+    ("download_panel.indicator.starting", "Iniciando..."),
+    ("context_menu.actions", "Acciones"),
+    ("track_menu.preview", "Vista previa"),
+    (
+        "track_menu.reveal",
+        "Mostrar en el administrador de archivos",
+    ),
+    ("track_menu.fetch_lyrics", "Obtener la letra"),
+    ("track_menu.label", "Acciones de la pista"),
+    ("device_menu.label", "Acciones del dispositivo"),
+    ("playlist_menu.label", "Acciones de la lista"),
+    (
+        "playlist_menu.reset_archive",
+        "Restablecer el archivo de descargas",
+    ),
+    ("playlist_menu.export", "Exportar como M3U"),
+    ("playlist_menu.checksums", "Crear sumas de verificación"),
+    ("playlist_card.sync_to", "Sincronizar con {device}"),
+    (
+        "playlist_card.connect_device.title",
+        "Conecta un dispositivo para sincronizar",
+    ),
+    ("playlist_card.connect_device", "Conectar un dispositivo"),
+    ("playlist_card.details.title", "Ver los detalles de {name}"),
+    ("playlist_card.details", "Detalles"),
+    ("playlist_card.thumbnail", "Miniatura de la lista"),
+    ("search.hint", "Buscar listas, títulos y canales"),
+    ("search.no_matches", "Sin coincidencias"),
+    ("search.label", "Buscar en la biblioteca"),
+    ("search.placeholder", "Buscar en la biblioteca..."),
+    ("transfer_panel.preparing", "Preparando la transferencia..."),
+    ("transfer_panel.transferring", "Transfiriendo archivos..."),
+    ("transfer_panel.verifying", "Verificando la integridad..."),
+    ("transfer_panel.completed", "Transferencia completada"),
+    (
+        "transfer_panel.failed",
+        "Error en la transferencia: {error}",
+    ),
+    ("transfer_panel.cancelled", "Transferencia cancelada"),
+    ("transfer_panel.cancel", "Cancelar la transferencia"),
+    ("transfer_panel.dismiss", "Descartar la transferencia"),
+    (
+        "transfer_panel.file_progress",
+        "Progreso del archivo actual",
+    ),
+    ("transfer_panel.overall", "Progreso total"),
+    (
+        "transfer_panel.overall_label",
+        "Progreso total de la transferencia",
+    ),
+    ("transfer_panel.files_completed", "{count} completados"),
+    ("transfer_panel.files_skipped", "{count} omitidos"),
+    ("transfer_panel.indicator.transferring", "Transfiriendo"),
+    ("transfer_panel.indicator.preparing", "Preparando..."),
+    ("device_list.storage_used", "Almacenamiento usado"),
+    (
+        "device_list.eject.title",
+        "Expulsar el dispositivo de forma segura",
+    ),
+    ("device_list.title", "Dispositivos conectados"),
+    ("device_list.refresh", "Actualizar dispositivos"),
+    (
+        "device_list.detect_failed",
+        "No se pudieron detectar los dispositivos",
+    ),
+    ("logs.level.all", "Todos los niveles"),
+    ("logs.level.debug", "Depuración y superiores"),
+    ("logs.level.info", "Información y superiores"),
+    ("logs.level.warn", "Advertencias y errores"),
+    ("logs.level.error", "Solo errores"),
+    ("logs.copied", "Registros copiados al portapapeles"),
+    (
+        "logs.copy_failed",
+        "No se pudieron copiar los registros: {error}",
+    ),
+    (
+        "logs.open_folder_failed",
+        "No se pudo abrir la carpeta de registros: {error}",
+    ),
+    ("logs.level", "Nivel mínimo de registro"),
+    ("logs.auto_scroll", "Desplazamiento automático"),
+    ("logs.copy", "Copiar"),
+    ("logs.open_folder", "Abrir la carpeta de registros"),
+    (
+        "logs.read_failed",
+        "No se pudieron leer los registros: {error}",
+    ),
+    ("logs.empty", "No hay entradas de registro"),
+    (
+        "sync_button.need_both",
+        "Conecta un dispositivo y selecciona una lista para sincronizar",
+    ),
+    (
+        "sync_button.need_device",
+        "Conecta un dispositivo USB para sincronizar",
+    ),
+    (
+        "sync_button.need_playlist",
+        "Selecciona una lista para sincronizar",
+    ),
+    ("sync_button.syncing.title", "Sincronización en curso..."),
+    (
+        "sync_button.checking.title",
+        "Comprobando el espacio disponible...",
+    ),
+    (
+        "sync_button.sync_with_warning",
+        "Sincronizar \"{name}\" con {device} ({warning})",
+    ),
+    ("sync_button.sync", "Sincronizar \"{name}\" con {device}"),
+    (
+        "sync_button.sync_default",
+        "Sincronizar la lista con el dispositivo",
+    ),
+    ("sync_button.syncing", "Sincronizando..."),
+    ("sync_button.checking", "Comprobando el espacio..."),
+    (
+        "sync_button.need_have",
+        "(se necesitan {required}, hay {available})",
+    ),
+    (
+        "sync_button.low_space",
+        "Poco espacio: {percent} % lleno tras sincronizar",
+    ),
+    (
+        "sync_conflict.summary",
+        "{keeping} de {total} se conservan tal cual",
+    ),
+    (
+        "sync_conflict.title",
+        "Algunos archivos del dispositivo cambiarán",
+    ),
+    (
+        "sync_conflict.message",
+        "Esta sincronización sobrescribe o elimina los archivos siguientes. Elige los que quieres conservar tal cual.",
+    ),
+    ("sync_conflict.keep_all", "Conservar todo"),
+    ("sync_conflict.replace_all", "Reemplazar todo"),
+    (
+        "sync_conflict.not_selected",
+        "No está en las listas seleccionadas",
+    ),
+    ("sync_conflict.from", "De {playlist}"),
+    ("sync_conflict.choice", "Conservar o reemplazar"),
+    ("device_status.connected", "Conectado"),
+    ("device_status.free", "{size} libres"),
+    ("device_status.removable", "Extraíble"),
+    ("device_status.no_device", "Sin dispositivo"),
+    ("device_status.disconnected", "Desconectado"),
+    (
+        "device_status.plug_in",
+        "Conecta tu reproductor MP3 por USB",
+    ),
+    ("device_status.detecting", "Detectando..."),
+    ("device_status.scanning", "Buscando"),
+    (
+        "device_status.looking",
+        "Buscando dispositivos conectados...",
+    ),
+    (
+        "device_status.none_connected",
+        "Ningún dispositivo conectado",
+    ),
+    (
+        "maintenance.checksums",
+        "Creando sumas de verificación de «{playlist}»",
+    ),
+    ("maintenance.repair", "Reparando «{playlist}»"),
+    ("maintenance.clear_cache", "Vaciando la caché"),
+    ("operation.starting", "Iniciando…"),
+    ("operation.failed", "Error"),
+    ("operation.failed_with", "Error: {error}"),
+    ("operation.cancelled", "Cancelado"),
+    ("operation.done", "Hecho"),
+    ("operation.cancelling", "Cancelando…"),
+    ("operation.working", "Trabajando…"),
+    ("undo_history.item", "Deshacer este cambio"),
+    (
+        "undo_history.item_and_later.one",
+        "Deshacer este cambio y el siguiente",
+    ),
+    (
+        "undo_history.item_and_later.other",
+        "Deshacer este cambio y los {count} siguientes",
+    ),
+    ("undo_history.label", "Historial de deshacer"),
+    (
+        "playlist_selection.empty.title",
+        "No hay listas disponibles",
+    ),
+    (
+        "playlist_selection.empty.message",
+        "Crea primero una lista para seleccionarla y sincronizarla",
+    ),
+    ("playlist_selection.label", "Seleccionar una lista"),
+    (
+        "playlist_selection.error.title",
+        "No se pudieron cargar las listas",
+    ),
+    ("playlist_selection.error.message", "Inténtalo de nuevo"),
+    ("playlist_selection.selected", "Seleccionada:"),
+    (
+        "playlist_selection.hint",
+        "Selecciona una lista para sincronizar",
+    ),
+    ("playlist_sort.name", "Nombre"),
+    (
+        "playlist_sort.recently_updated",
+        "Actualizadas recientemente",
+    ),
+    ("playlist_sort.size", "Tamaño"),
+    ("playlist_grid.open", "Abrir {name}"),
+    ("playlist_grid.sort_by", "Ordenar por"),
+    ("playlist_grid.layout", "Diseño"),
+    ("playlist_grid.list_view", "Vista de lista"),
+    ("playlist_grid.grid_view", "Vista de cuadrícula"),
+    ("track_list.title", "Título"),
+    ("track_list.album", "Álbum"),
+    ("track_list.duration", "Duración"),
+    ("track_list.size", "Tamaño"),
+    ("track_list.load_failed", "No se pudieron cargar las pistas"),
+    ("playlist_list.load_failed", "Se ha producido un error"),
+    ("playlist_list.select", "Seleccionar {name}"),
+    ("loading.error.title", "Algo ha salido mal"),
+    (
+        "track_player.unplayable",
+        "Esta pista no se puede reproducir",
+    ),
+    ("track_player.play", "Reproducir"),
+    ("track_player.pause", "Pausa"),
+    ("track_player.seek", "Posición de reproducción"),
+    (
+        "command_error.device_not_found",
+        "No se encuentra el dispositivo. Comprueba que está conectado.",
+    ),
+    (
+        "command_error.device_not_mounted",
+        "El dispositivo no está montado.",
+    ),
+    (
+        "command_error.device_disconnected",
+        "El dispositivo se ha desconectado. Vuelve a conectarlo e inténtalo de nuevo.",
+    ),
+    (
+        "command_error.device_read_only",
+        "El dispositivo es de solo lectura.",
+    ),
+    (
+        "command_error.device_busy",
+        "El dispositivo está ocupado. Cierra las aplicaciones que lo usan e inténtalo de nuevo.",
+    ),
+    (
+        "command_error.insufficient_space",
+        "No hay suficiente espacio en el dispositivo.",
+    ),
+    ("command_error.permission_denied", "Permiso denegado."),
+    (
+        "command_error.unsupported_file_system",
+        "El sistema de archivos del dispositivo no es compatible.",
+    ),
+    (
+        "command_error.platform_not_supported",
+        "Esto no es compatible con tu plataforma.",
+    ),
+    (
+        "command_error.not_a_playlist",
+        "Esa URL no es una lista de YouTube.",
+    ),
+    (
+        "command_error.network",
+        "Error de red. Comprueba la conexión e inténtalo de nuevo.",
+    ),
+    (
+        "command_error.timeout",
+        "La solicitud ha caducado. Inténtalo de nuevo.",
+    ),
+    (
+        "command_error.rate_limited",
+        "YouTube está limitando las solicitudes. Inténtalo más tarde.",
+    ),
+    (
+        "command_error.rate_limited_for",
+        "YouTube está limitando las solicitudes. Inténtalo de nuevo en {seconds} segundos.",
+    ),
+    (
+        "command_error.video_unavailable",
+        "El vídeo no está disponible.",
+    ),
+    (
+        "command_error.offline",
+        "El modo sin conexión está activado. Desactívalo en Ajustes para conectarte.",
+    ),
+    (
+        "command_error.library_limit_reached",
+        "Tu biblioteca ha alcanzado su tamaño máximo. Libera espacio o aumenta el límite en Ajustes.",
+    ),
+    (
+        "command_error.low_disk_space",
+        "Tu disco está casi lleno. Libera espacio antes de descargar.",
+    ),
+    (
+        "command_error.transfer_interrupted",
+        "La transferencia se ha interrumpido. Inténtalo de nuevo.",
+    ),
+    (
+        "command_error.integrity_check_failed",
+        "Un archivo no superó la comprobación de integridad tras la transferencia.",
+    ),
+    (
+        "command_error.playlist_not_found",
+        "No se encuentra la lista.",
+    ),
+    (
+        "command_error.playlist_exists",
+        "Ya existe una lista con ese nombre.",
+    ),
+    ("command_error.playlist_empty", "La lista no tiene pistas."),
+    ("command_error.cancelled", "La operación se ha cancelado."),
+    (
+        "command_error.task_busy",
+        "Otra tarea ya lo está usando. Espera a que termine o cancélala.",
+    ),
+    ("queue.action.move_to_front", "Mover al principio"),
+    ("queue.action.move_up", "Subir"),
+    ("queue.action.move_down", "Bajar"),
+    ("queue.action.split_chapters", "Dividir en capítulos"),
+    ("queue.action.keep_whole", "Mantener entero"),
+    ("queue.action.remove", "Quitar"),
+    ("queue.resume", "Reanudar"),
+    ("queue.pause", "Pausar"),
+    ("queue.paused", "En pausa"),
+    ("queue.title", "Cola de descargas"),
+    ("queue.clear_finished", "Borrar finalizadas"),
+    ("queue.section", "{title} ({count})"),
+    ("queue.section.downloading", "Descargando"),
+    ("queue.section.up_next", "A continuación"),
+    ("queue.section.finished", "Finalizadas"),
+    ("queue.progress_label", "Progreso de la descarga de {name}"),
+    ("queue.chapters", "Capítulos"),
+    (
+        "queue.chapters.title",
+        "Los vídeos con capítulos se dividen en una pista por capítulo",
+    ),
+    ("queue.dry_run", "Simulación"),
+    (
+        "queue.dry_run.title",
+        "Solo planificado: no se descarga ni se escribe nada",
+    ),
+    ("queue.priority.low", "Baja"),
+    ("queue.priority.normal", "Normal"),
+    ("queue.priority.high", "Alta"),
+    ("queue.status.pending", "Pendiente"),
+    ("queue.status.downloading", "Descargando"),
+    ("queue.status.completed", "Completada"),
+    ("queue.status.failed", "Error"),
+    ("queue.status.failed_with", "Error: {error}"),
+    ("queue.status.cancelled", "Cancelada"),
+    ("queue.status.interrupted", "Interrumpida"),
+    (
+        "queue.reorder_within_priority",
+        "Los elementos solo se pueden reordenar dentro de la misma prioridad",
+    ),
+    (
+        "queue.update_failed",
+        "No se pudo actualizar la cola: {error}",
+    ),
+    (
+        "queue.clear_failed",
+        "No se pudieron borrar las descargas finalizadas: {error}",
+    ),
+];
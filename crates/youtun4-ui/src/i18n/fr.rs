@@ -0,0 +1,1895 @@
+//! French interface text.
+
+use super::Catalog;
+
+pub(super) const CATALOG: Catalog = &[
+    ("common.cancel", "Annuler"),
+    ("common.back", "Retour"),
+    ("common.next", "Suivant"),
+    ("common.done", "Terminé"),
+    ("common.unknown_error", "erreur inconnue"),
+    ("common.time_left", "{time} restant"),
+    ("common.go_back", "Retour"),
+    ("common.refresh", "Actualiser"),
+    ("common.clear", "Effacer"),
+    ("common.remove", "Supprimer"),
+    ("common.delete", "Supprimer"),
+    ("common.save", "Enregistrer"),
+    ("common.close_dialog", "Fermer la boîte de dialogue"),
+    ("common.close", "Fermer"),
+    (
+        "common.unknown_command_error",
+        "Erreur inconnue renvoyée par la commande Tauri",
+    ),
+    ("app.sync_to_device", "Synchroniser vers l'appareil"),
+    ("nav.library", "Bibliothèque"),
+    ("nav.history", "Historique"),
+    ("nav.storage", "Stockage"),
+    ("nav.undo", "Annuler"),
+    ("nav.shortcuts", "Raccourcis clavier"),
+    ("nav.activity", "Activité"),
+    ("nav.downloads", "Téléchargements"),
+    ("nav.sync", "Synchronisation"),
+    (
+        "nav.downloads_status",
+        "{count} téléchargements en attente ou en cours",
+    ),
+    ("nav.sync_status", "Synchronisation en cours"),
+    ("header.stop_all", "Tout arrêter"),
+    ("header.settings", "Paramètres"),
+    (
+        "header.toggle_sidebar",
+        "Afficher ou masquer la barre latérale",
+    ),
+    ("header.toggle_menu", "Afficher ou masquer le menu"),
+    ("playlists.title", "Playlists"),
+    ("playlists.select", "Sélectionner"),
+    ("playlists.done", "Terminé"),
+    ("playlists.select_for_sync", "Choisir pour synchroniser"),
+    ("playlists.new", "Nouvelle playlist"),
+    (
+        "playlists.select_to_sync",
+        "Choisir la playlist à synchroniser",
+    ),
+    ("playlists.choose", "Choisissez une playlist"),
+    (
+        "playlists.choose_description",
+        "Sélectionnez une playlist à synchroniser vers l'appareil connecté",
+    ),
+    ("count.tracks.one", "{count} piste"),
+    ("count.tracks.other", "{count} pistes"),
+    ("count.files.one", "{count} fichier"),
+    ("count.files.other", "{count} fichiers"),
+    ("count.failed.one", "{count} en échec"),
+    ("count.failed.other", "{count} en échec"),
+    ("count.downloads.one", "{count} téléchargement"),
+    ("count.downloads.other", "{count} téléchargements"),
+    ("count.syncs.one", "{count} synchronisation"),
+    ("count.syncs.other", "{count} synchronisations"),
+    ("count.audio_files.one", "{count} fichier audio"),
+    ("count.audio_files.other", "{count} fichiers audio"),
+    ("count.videos.one", "{count} vidéo"),
+    ("count.videos.other", "{count} vidéos"),
+    ("count.playlists.one", "{count} playlist"),
+    ("count.playlists.other", "{count} playlists"),
+    ("count.thumbnails.one", "{count} miniature"),
+    ("count.thumbnails.other", "{count} miniatures"),
+    ("count.temp_files.one", "{count} fichier temporaire"),
+    ("count.temp_files.other", "{count} fichiers temporaires"),
+    ("banner.device_connected", "Appareil « {device} » connecté"),
+    (
+        "banner.device_disconnected",
+        "Appareil « {device} » déconnecté",
+    ),
+    ("banner.sync_now", "Synchroniser"),
+    ("banner.clean", "Nettoyer l'appareil"),
+    ("banner.ignore", "Ignorer cet appareil"),
+    (
+        "banner.auto_syncing",
+        "Synchronisation automatique de ses dernières playlists",
+    ),
+    ("banner.dismiss", "Fermer"),
+    (
+        "banner.ignored",
+        "« {device} » sera ignoré. Vous pouvez changer cela dans ses paramètres.",
+    ),
+    (
+        "banner.ignore_failed",
+        "Impossible d'ignorer l'appareil : {error}",
+    ),
+    (
+        "toast.device_ejected",
+        "L'appareil a été éjecté. Vous pouvez le débrancher.",
+    ),
+    ("toast.select_device", "Sélectionnez d'abord un appareil"),
+    ("toast.select_playlist", "Sélectionnez d'abord une playlist"),
+    ("toast.playlist_created", "Playlist « {name} » créée"),
+    ("toast.playlist_deleted", "Playlist « {name} » supprimée"),
+    ("toast.dismiss", "Fermer la notification"),
+    (
+        "toast.download_failed",
+        "Échec du lancement du téléchargement : {error}",
+    ),
+    ("toast.downloading", "Téléchargement de « {name} »"),
+    (
+        "toast.create_playlist_failed",
+        "Échec de la création de la playlist : {error}",
+    ),
+    (
+        "toast.load_devices_failed",
+        "Échec du chargement des appareils : {error}",
+    ),
+    (
+        "toast.load_playlists_failed",
+        "Échec du chargement des playlists : {error}",
+    ),
+    (
+        "toast.crashed_last_time",
+        "Youtun4 s'est fermé de façon inattendue la dernière fois : {message}",
+    ),
+    (
+        "toast.transfer_complete",
+        "Transfert terminé : {files} transférés vers {device}",
+    ),
+    ("toast.transfer_failed", "Échec du transfert : {error}"),
+    ("toast.transfer_cancelled", "Transfert annulé"),
+    (
+        "toast.video_failed",
+        "Échec du téléchargement de « {title} » : {error}",
+    ),
+    ("toast.downloaded.one", "{count} piste téléchargée"),
+    ("toast.downloaded.other", "{count} pistes téléchargées"),
+    ("toast.download_error", "Échec du téléchargement : {error}"),
+    (
+        "toast.retry_failed",
+        "Échec de la relance du téléchargement : {error}",
+    ),
+    ("toast.download_cancelled", "Téléchargement annulé"),
+    (
+        "toast.deep_link_needs_playlist",
+        "Sélectionnez une playlist, puis rouvrez le lien",
+    ),
+    (
+        "toast.deep_link_failed",
+        "Impossible d'ajouter le lien : {error}",
+    ),
+    (
+        "toast.deep_link_queued",
+        "Téléchargement ajouté à la file pour « {name} »",
+    ),
+    (
+        "toast.update_available",
+        "Youtun4 {version} est disponible (canal {channel})",
+    ),
+    (
+        "toast.syncing",
+        "Synchronisation de « {name} » vers {device}...",
+    ),
+    ("toast.synced", "« {name} » synchronisée"),
+    (
+        "toast.sync_failed",
+        "Échec de la synchronisation de la playlist : {error}",
+    ),
+    (
+        "toast.delete_playlist_failed",
+        "Échec de la suppression de la playlist : {error}",
+    ),
+    (
+        "toast.update_playlists_failed",
+        "Échec de la mise à jour des playlists : {error}",
+    ),
+    (
+        "toast.transfer_starting",
+        "Début du transfert de « {name} » vers {device}...",
+    ),
+    (
+        "toast.transfer_start_failed",
+        "Échec du lancement du transfert : {error}",
+    ),
+    (
+        "toast.cancel_transfer_failed",
+        "Échec de l'annulation du transfert : {error}",
+    ),
+    (
+        "toast.cancel_download_failed",
+        "Échec de l'annulation du téléchargement : {error}",
+    ),
+    (
+        "toast.rename_failed",
+        "Échec du renommage de la playlist : {error}",
+    ),
+    ("toast.start_failed", "Échec du démarrage : {error}"),
+    (
+        "toast.refresh_failed",
+        "Échec de l'actualisation de la playlist : {error}",
+    ),
+    (
+        "toast.archive_reset",
+        "{videos} téléchargées oubliées pour « {name} »",
+    ),
+    (
+        "toast.archive_reset_failed",
+        "Échec de la réinitialisation de l'archive de téléchargement : {error}",
+    ),
+    ("toast.exported", "Exportée vers {path}"),
+    (
+        "toast.export_failed",
+        "Échec de l'export de la playlist : {error}",
+    ),
+    ("toast.verify_ok", "« {name} » est correcte ({tracks})"),
+    (
+        "toast.verify_issues",
+        "« {name} » présente des problèmes : {issues}",
+    ),
+    (
+        "toast.verify_failed",
+        "Échec de la vérification de la playlist : {error}",
+    ),
+    (
+        "toast.open_folder_failed",
+        "Échec de l'ouverture du dossier : {error}",
+    ),
+    ("toast.nothing_running", "Rien n'est en cours"),
+    ("toast.tasks_stopped.one", "{count} tâche arrêtée"),
+    ("toast.tasks_stopped.other", "{count} tâches arrêtées"),
+    (
+        "toast.stop_tasks_failed",
+        "Échec de l'arrêt des tâches : {error}",
+    ),
+    (
+        "toast.select_device_first",
+        "Sélectionnez d'abord un appareil",
+    ),
+    (
+        "toast.select_playlist_to_delete",
+        "Sélectionnez une playlist à supprimer",
+    ),
+    (
+        "toast.show_track_failed",
+        "Impossible d'afficher la piste : {error}",
+    ),
+    (
+        "toast.update_tracks_failed",
+        "Échec de la mise à jour des pistes : {error}",
+    ),
+    ("toast.lyrics_saved", "Paroles de « {name} » enregistrées"),
+    (
+        "toast.lyrics_not_found",
+        "Aucune parole trouvée pour « {name} »",
+    ),
+    (
+        "toast.lyrics_failed",
+        "Échec de la récupération des paroles : {error}",
+    ),
+    ("toast.retry", "Réessayer"),
+    ("toast.action_countdown", "{label} ({seconds} s)"),
+    ("settings.title", "Paramètres"),
+    ("settings.close", "Fermer les paramètres"),
+    ("settings.section.downloads", "Téléchargements"),
+    ("settings.section.devices", "Appareils"),
+    ("settings.section.storage", "Stockage"),
+    ("settings.section.cache", "Cache"),
+    ("settings.section.notifications", "Notifications"),
+    ("settings.section.appearance", "Apparence"),
+    ("settings.section.advanced", "Avancé"),
+    ("settings.reset", "Réinitialiser"),
+    ("settings.save", "Enregistrer"),
+    ("settings.saving", "Enregistrement..."),
+    ("settings.saved", "Paramètres enregistrés !"),
+    (
+        "settings.load_failed",
+        "Impossible de charger les paramètres : {error}",
+    ),
+    (
+        "settings.save_failed",
+        "Impossible d'enregistrer les paramètres : {error}",
+    ),
+    ("settings.language", "Langue"),
+    (
+        "settings.language.description",
+        "Langue de l'interface et des notifications.",
+    ),
+    ("settings.language.system", "Langue du système"),
+    ("settings.write_tags", "Écrire les tags"),
+    (
+        "settings.write_tags.description",
+        "Ajouter titre, artiste, album et numéro de piste aux chansons téléchargées",
+    ),
+    ("settings.embed_cover_art", "Intégrer la pochette"),
+    (
+        "settings.embed_cover_art.description",
+        "Utiliser la miniature de la vidéo comme pochette",
+    ),
+    ("settings.fetch_lyrics", "Récupérer les paroles"),
+    (
+        "settings.fetch_lyrics.description",
+        "Trouver des paroles synchronisées ou simples pour les chansons téléchargées",
+    ),
+    ("settings.save_lrc", "Enregistrer les fichiers .lrc"),
+    (
+        "settings.save_lrc.description",
+        "Enregistrer les paroles à côté de la chanson, pour les lecteurs affichant les paroles synchronisées",
+    ),
+    ("settings.embed_lyrics", "Intégrer les paroles"),
+    (
+        "settings.embed_lyrics.description",
+        "Stocker les paroles dans les tags de la chanson",
+    ),
+    ("settings.auto_start", "Démarrer automatiquement"),
+    (
+        "settings.auto_start.description",
+        "Lancer les téléchargements dès leur mise en file",
+    ),
+    (
+        "settings.auto_retry",
+        "Relancer les téléchargements échoués",
+    ),
+    (
+        "settings.auto_retry.description",
+        "Remettre automatiquement en file les téléchargements échoués",
+    ),
+    ("settings.work_offline", "Travailler hors ligne"),
+    (
+        "settings.work_offline.description",
+        "Les téléchargements en file attendent la désactivation du mode hors ligne",
+    ),
+    ("settings.cache_enabled", "Activer le cache"),
+    (
+        "settings.cache_enabled.description",
+        "Conserver les données récupérées sur le disque pour les réutiliser",
+    ),
+    ("settings.cache_metadata", "Mettre en cache les métadonnées"),
+    (
+        "settings.cache_metadata.description",
+        "Éviter de relire les tags de chaque fichier",
+    ),
+    (
+        "settings.cache_thumbnails",
+        "Mettre en cache les miniatures",
+    ),
+    (
+        "settings.cache_thumbnails.description",
+        "Conserver les illustrations des playlists et des pistes sur le disque",
+    ),
+    ("settings.notify_download", "Téléchargement terminé"),
+    (
+        "settings.notify_download.description",
+        "Prévenir quand le téléchargement d'une playlist se termine",
+    ),
+    ("settings.notify_sync", "Synchronisation terminée"),
+    (
+        "settings.notify_sync.description",
+        "Prévenir quand la synchronisation vers un appareil se termine",
+    ),
+    ("settings.notify_errors", "Erreurs"),
+    (
+        "settings.notify_errors.description",
+        "Afficher des notifications pour les erreurs et avertissements",
+    ),
+    ("settings.notify_device", "Appareil connecté"),
+    (
+        "settings.notify_device.description",
+        "Prévenir quand un appareil USB est connecté",
+    ),
+    ("settings.webhook_enabled", "Appeler le webhook"),
+    (
+        "settings.webhook_enabled.description",
+        "Publier un message à la fin d'un téléchargement ou d'une synchronisation",
+    ),
+    ("settings.high_contrast", "Contraste élevé"),
+    (
+        "settings.high_contrast.description",
+        "Couleurs de texte, de bordure et d'état plus marquées ; remplace la couleur d'accent personnalisée",
+    ),
+    (
+        "settings.minimize_to_tray",
+        "Réduire dans la zone de notification",
+    ),
+    (
+        "settings.minimize_to_tray.description",
+        "Fermer la fenêtre masque Youtun4 dans la zone de notification",
+    ),
+    ("settings.pause_on_battery", "Pause sur batterie"),
+    (
+        "settings.pause_on_battery.description",
+        "Attendre que l'ordinateur soit branché",
+    ),
+    ("settings.pause_on_metered", "Pause sur les réseaux limités"),
+    (
+        "settings.pause_on_metered.description",
+        "Attendre une connexion non limitée",
+    ),
+    (
+        "settings.pause_on_guessed_metered",
+        "Inclure les réseaux supposés limités",
+    ),
+    (
+        "settings.pause_on_guessed_metered.description",
+        "Mettre aussi en pause sur les connexions, comme les partages de connexion, seulement supposées limitées",
+    ),
+    ("settings.quality", "Qualité de téléchargement"),
+    (
+        "settings.quality.description",
+        "Choisissez la qualité audio des téléchargements YouTube. Les playlists peuvent la remplacer dans leurs paramètres.",
+    ),
+    ("settings.lyrics", "Paroles"),
+    (
+        "settings.lyrics.description",
+        "Rechercher les paroles sur LRCLIB après chaque téléchargement. Les playlists peuvent le remplacer dans leurs paramètres.",
+    ),
+    ("settings.file_names", "Noms de fichiers"),
+    ("settings.file_names.template", "Modèle de nom de fichier"),
+    ("settings.queue", "File de téléchargement"),
+    (
+        "settings.queue.description",
+        "Réglez l'exécution des téléchargements en file.",
+    ),
+    (
+        "settings.queue.max_concurrent",
+        "Téléchargements simultanés",
+    ),
+    (
+        "settings.queue.concurrency",
+        "Vidéos simultanées par playlist",
+    ),
+    ("settings.queue.speed_limit", "Limite de vitesse"),
+    (
+        "settings.queue.max_retries",
+        "Nouvelles tentatives par téléchargement",
+    ),
+    ("settings.offline", "Mode hors ligne"),
+    (
+        "settings.offline.description",
+        "Éviter tout accès réseau et n'utiliser que les données en cache.",
+    ),
+    ("settings.preferred_device", "Appareil préféré"),
+    (
+        "settings.preferred_device.description",
+        "L'appareil sélectionné automatiquement lorsqu'il est connecté. Choisissez-en un dans la liste des appareils.",
+    ),
+    ("settings.device_detection", "Détection des appareils"),
+    (
+        "settings.device_detection.description",
+        "Fréquence de recherche des appareils connectés ou retirés.",
+    ),
+    (
+        "settings.device_detection.interval",
+        "Vérifier toutes les (secondes)",
+    ),
+    ("settings.storage_location", "Emplacement de stockage"),
+    (
+        "settings.storage_location.description",
+        "Choisissez où vos playlists sont stockées sur votre ordinateur.",
+    ),
+    (
+        "settings.storage_location.directory",
+        "Dossier des playlists",
+    ),
+    ("settings.library_limits", "Limites de la bibliothèque"),
+    (
+        "settings.library_limits.description",
+        "Les téléchargements s'arrêtent quand la bibliothèque atteint sa taille limite ou que le disque est trop plein.",
+    ),
+    (
+        "settings.library_limits.max_size",
+        "Taille maximale de la bibliothèque (Go)",
+    ),
+    (
+        "settings.library_limits.no_limit",
+        "Laissez vide pour ne pas limiter.",
+    ),
+    (
+        "settings.library_limits.min_free",
+        "Garder libre sur le disque (Go)",
+    ),
+    ("settings.cache", "Cache"),
+    (
+        "settings.cache.description",
+        "Les détails des playlists, métadonnées et miniatures en cache accélèrent l'application et permettent de travailler hors ligne.",
+    ),
+    ("settings.cache.max_size", "Taille maximale du cache (Mo)"),
+    (
+        "settings.cache.ttl",
+        "Conserver les entrées pendant (jours)",
+    ),
+    (
+        "settings.clear_cache.description",
+        "Supprimer tous les détails de playlists, métadonnées et miniatures en cache. Ils seront récupérés à nouveau si besoin.",
+    ),
+    ("settings.notifications", "Préférences de notification"),
+    (
+        "settings.notifications.description",
+        "Choisissez les notifications que vous souhaitez recevoir.",
+    ),
+    ("settings.webhook", "Webhook"),
+    (
+        "settings.webhook.description",
+        "Publier un message sur une URL, comme un sujet ntfy ou un webhook Discord, à la fin des téléchargements et synchronisations. Les appels sont ignorés en mode hors ligne.",
+    ),
+    ("settings.webhook.url", "URL du webhook"),
+    ("settings.webhook.save_url", "Enregistrer l'URL"),
+    (
+        "settings.webhook.url_hint",
+        "L'URL est conservée dans un stockage sécurisé, pas dans le fichier de paramètres.",
+    ),
+    ("settings.webhook.payload", "Modèle de contenu"),
+    ("settings.webhook.test", "Envoyer un message de test"),
+    ("settings.theme", "Thème"),
+    (
+        "settings.theme.description",
+        "Choisissez l'apparence de l'application.",
+    ),
+    (
+        "settings.theme.system_hint",
+        "Système suit le réglage clair ou sombre de votre système d'exploitation.",
+    ),
+    ("settings.accent_color", "Couleur d'accent"),
+    (
+        "settings.accent_color.description",
+        "Couleur des boutons, des surlignages et des contours de focus.",
+    ),
+    ("settings.accent_color.default", "Par défaut"),
+    ("settings.accessibility", "Accessibilité"),
+    ("settings.extraction", "Extraction"),
+    (
+        "settings.extraction.description",
+        "Choisissez comment les playlists sont lues depuis YouTube. yt-dlp doit être installé séparément.",
+    ),
+    ("settings.extraction.yt_dlp_path", "Emplacement de yt-dlp"),
+    ("settings.background", "Arrière-plan"),
+    (
+        "settings.background.description",
+        "Poursuivre les téléchargements et synchronisations quand la fenêtre est fermée.",
+    ),
+    ("settings.power", "Alimentation et réseau"),
+    (
+        "settings.power.description",
+        "Mettre en pause les téléchargements et autres tâches lourdes quand les ressources sont limitées.",
+    ),
+    ("settings.updates", "Mises à jour"),
+    (
+        "settings.updates.description",
+        "Choisissez les versions que Youtun4 recherche au démarrage.",
+    ),
+    ("settings.log", "Journal de l'application"),
+    (
+        "settings.log.description",
+        "Entrées récentes du journal, actualisées tant que cette section est ouverte. Copiez-les dans un rapport de bogue si un téléchargement échoue.",
+    ),
+    (
+        "settings.file_names.description",
+        "Nommer les chansons téléchargées à partir de {fields}. {padded} complète l'index avec des zéros. Les playlists peuvent le remplacer dans leurs paramètres.",
+    ),
+    ("settings.file_names.example", "Exemple : {name}"),
+    ("settings.unlimited", "Illimité"),
+    ("settings.preferred_device.none", "Aucun appareil préféré"),
+    (
+        "settings.storage_location.placeholder",
+        "Saisissez le chemin du dossier...",
+    ),
+    ("settings.storage_location.default", "Par défaut : {path}"),
+    ("settings.clear_cache", "Vider le cache"),
+    ("settings.webhook.url_saved", "URL du webhook enregistrée"),
+    ("settings.webhook.url_removed", "URL du webhook supprimée"),
+    ("settings.webhook.sending", "Envoi du message de test..."),
+    ("settings.webhook.sent", "Message de test envoyé"),
+    (
+        "settings.webhook.url_replace",
+        "Une URL est enregistrée ; saisissez-en une pour la remplacer",
+    ),
+    (
+        "settings.webhook.payload_hint",
+        "Utilisez {fields}. Les modèles commençant par {brace} sont envoyés en JSON, p. ex. {example} pour Discord.",
+    ),
+    (
+        "settings.webhook.download_completed",
+        "Quand le téléchargement d'une playlist se termine",
+    ),
+    (
+        "settings.webhook.download_failed",
+        "Quand le téléchargement d'une playlist échoue",
+    ),
+    (
+        "settings.webhook.sync_completed",
+        "Quand la synchronisation vers un appareil se termine",
+    ),
+    (
+        "settings.webhook.sync_failed",
+        "Quand la synchronisation vers un appareil échoue",
+    ),
+    ("settings.theme.dark", "Sombre"),
+    ("settings.theme.light", "Clair"),
+    ("settings.theme.system", "Système"),
+    ("settings.extraction.auto", "Automatique"),
+    (
+        "settings.extraction.auto.description",
+        "Intégré, avec yt-dlp quand les pages YouTube ne peuvent pas être lues",
+    ),
+    ("settings.extraction.built_in", "Intégré"),
+    (
+        "settings.extraction.built_in.description",
+        "Ne jamais lancer yt-dlp",
+    ),
+    ("settings.extraction.yt_dlp", "yt-dlp"),
+    (
+        "settings.extraction.yt_dlp.description",
+        "Tout lire et télécharger avec yt-dlp",
+    ),
+    (
+        "settings.extraction.yt_dlp_path.placeholder",
+        "yt-dlp (trouvé dans le PATH)",
+    ),
+    ("settings.updates.stable", "Stable"),
+    (
+        "settings.updates.stable.description",
+        "Versions testées uniquement",
+    ),
+    ("settings.updates.beta", "Bêta"),
+    (
+        "settings.updates.beta.description",
+        "Préversions avec les dernières fonctionnalités",
+    ),
+    ("delete_playlist.title", "Supprimer la playlist"),
+    (
+        "delete_playlist.confirm",
+        "Voulez-vous vraiment supprimer « {name} » ?",
+    ),
+    ("delete_playlist.tracks", "Pistes :"),
+    ("delete_playlist.size", "Taille :"),
+    ("delete_playlist.source", "Source :"),
+    ("delete_playlist.youtube", "Playlist YouTube"),
+    (
+        "delete_playlist.warning",
+        "Cette action est irréversible. Toutes les pistes et tous les fichiers seront supprimés définitivement du stockage local.",
+    ),
+    ("shortcuts.title", "Raccourcis clavier"),
+    ("shortcuts.close", "Fermer les raccourcis clavier"),
+    ("shortcuts.group.general", "Général"),
+    ("shortcuts.group.navigation", "Navigation"),
+    ("shortcuts.group.playlists", "Playlists"),
+    ("shortcuts.group.dialogs", "Boîtes de dialogue"),
+    ("shortcuts.show", "Afficher les raccourcis clavier"),
+    ("shortcuts.search", "Rechercher dans la bibliothèque"),
+    ("shortcuts.settings", "Ouvrir les réglages"),
+    ("shortcuts.undo", "Annuler la dernière modification"),
+    ("shortcuts.playlists", "Aller aux playlists"),
+    ("shortcuts.history", "Aller à l'historique"),
+    ("shortcuts.device", "Aller à l'appareil sélectionné"),
+    (
+        "shortcuts.delete",
+        "Supprimer la playlist ouverte ou sélectionnée",
+    ),
+    (
+        "shortcuts.preview",
+        "Lire ou mettre en pause l'aperçu de la piste",
+    ),
+    ("shortcuts.focus", "Passer d'un contrôle à l'autre"),
+    ("shortcuts.close_dialog", "Fermer la boîte de dialogue"),
+    ("rename_playlist.title", "Renommer la playlist"),
+    ("rename_playlist.label", "Nouveau nom"),
+    ("rename_playlist.confirm", "Renommer"),
+    ("sync_wizard.step.device", "Appareil"),
+    ("sync_wizard.step.playlists", "Playlists"),
+    ("sync_wizard.step.review", "Vérification"),
+    ("sync_wizard.step.sync", "Synchronisation"),
+    ("sync_wizard.change.add", "Ajouter"),
+    ("sync_wizard.change.replace", "Remplacer"),
+    ("sync_wizard.change.keep", "Conserver"),
+    ("sync_wizard.change.remove", "Supprimer"),
+    (
+        "sync_wizard.synced.one",
+        "{count} fichier synchronisé vers l'appareil",
+    ),
+    (
+        "sync_wizard.synced.other",
+        "{count} fichiers synchronisés vers l'appareil",
+    ),
+    (
+        "sync_wizard.finished_announcement.one",
+        "Synchronisation terminée. {count} fichier transféré.",
+    ),
+    (
+        "sync_wizard.finished_announcement.other",
+        "Synchronisation terminée. {count} fichiers transférés.",
+    ),
+    (
+        "sync_wizard.failed",
+        "Échec de la synchronisation : {error}",
+    ),
+    (
+        "sync_wizard.failed_announcement",
+        "Échec de la synchronisation. {error}",
+    ),
+    (
+        "sync_wizard.cancel_failed",
+        "Impossible d'annuler la synchronisation : {error}",
+    ),
+    (
+        "sync_wizard.no_devices",
+        "Connectez un appareil à synchroniser.",
+    ),
+    ("sync_wizard.device_space", "{free} libres sur {total}"),
+    ("sync_wizard.selection", "{count} sélectionnées · {size}"),
+    (
+        "sync_wizard.cleanup",
+        "Supprimer d'abord les autres fichiers de l'appareil",
+    ),
+    ("sync_wizard.comparing", "Comparaison avec l'appareil..."),
+    ("sync_wizard.no_preview", "Aucun aperçu disponible."),
+    (
+        "sync_wizard.preview_summary",
+        "{write} à écrire, {remove} à supprimer",
+    ),
+    (
+        "sync_wizard.fits_after_cleanup",
+        "Tient une fois les anciens fichiers supprimés de l'appareil",
+    ),
+    (
+        "sync_wizard.capacity_label",
+        "{kept} % conservés, {incoming} % entrants",
+    ),
+    ("sync_wizard.kept", "Conservé"),
+    ("sync_wizard.incoming", "De cette synchronisation"),
+    ("sync_wizard.outcome.success", "Synchronisation terminée"),
+    ("sync_wizard.outcome.cancelled", "Synchronisation annulée"),
+    ("sync_wizard.outcome.failed", "Échec de la synchronisation"),
+    (
+        "sync_wizard.outcome.counts",
+        "{copied} copiés, {skipped} ignorés, {failed} en échec",
+    ),
+    ("sync_wizard.starting", "Démarrage de la synchronisation..."),
+    ("sync_wizard.progress", "Progression de la synchronisation"),
+    ("sync_wizard.close", "Fermer l'assistant de synchronisation"),
+    ("sync_wizard.cancel", "Annuler la synchronisation"),
+    ("sync_wizard.start", "Lancer la synchronisation"),
+    ("history.range.week", "7 derniers jours"),
+    ("history.range.month", "30 derniers jours"),
+    ("history.range.quarter", "90 derniers jours"),
+    ("history.range.all", "Depuis le début"),
+    ("history.status.completed", "Terminé"),
+    (
+        "history.status.completed_with_errors",
+        "Terminé avec des erreurs",
+    ),
+    ("history.status.failed", "Échec"),
+    ("history.status.cancelled", "Annulé"),
+    ("history.entry.download", "Téléchargement"),
+    ("history.entry.sync", "Synchronisation vers l'appareil"),
+    ("history.entry.sync_to", "Synchronisation vers {device}"),
+    ("history.stats.this_month", "{size} ce mois-ci"),
+    ("history.stats.total", "{size} au total"),
+    ("history.stats.average_speed", "{speed} en moyenne"),
+    (
+        "history.pruned",
+        "{count} entrées de plus de {days} jours supprimées de l'historique",
+    ),
+    (
+        "history.prune_failed",
+        "Impossible de supprimer les anciennes entrées : {error}",
+    ),
+    (
+        "history.cleared.one",
+        "{count} entrée supprimée de l'historique",
+    ),
+    (
+        "history.cleared.other",
+        "{count} entrées supprimées de l'historique",
+    ),
+    (
+        "history.clear_failed",
+        "Impossible d'effacer l'historique : {error}",
+    ),
+    ("history.chart.week", "Semaine du {week} : {size}"),
+    ("history.chart.title", "Téléchargé par semaine"),
+    ("history.copied", "{size} copiés"),
+    ("history.loading", "Chargement de l'historique..."),
+    (
+        "history.no_matches",
+        "Aucun téléchargement ni synchronisation ne correspond à ces filtres",
+    ),
+    ("history.prune", "Supprimer les anciennes entrées"),
+    (
+        "history.prune_hint",
+        "Supprimer les entrées de plus de {days} jours",
+    ),
+    ("history.clear", "Effacer l'historique"),
+    (
+        "history.clear_confirm",
+        "Supprimer tous les téléchargements et synchronisations de l'historique ?",
+    ),
+    ("history.filter.date", "Date"),
+    ("history.filter.type", "Type"),
+    (
+        "history.filter.all_kinds",
+        "Téléchargements et synchronisations",
+    ),
+    ("history.filter.downloads", "Téléchargements"),
+    ("history.filter.syncs", "Synchronisations"),
+    ("history.filter.status", "Statut"),
+    ("history.filter.any_status", "Tous les statuts"),
+    ("history.filter.playlist", "Playlist"),
+    ("history.filter.all_playlists", "Toutes les playlists"),
+    ("device_detail.almost_full", "L'appareil est presque plein"),
+    (
+        "device_detail.files_failed.one",
+        "{count} fichier n'a pas pu être copié lors de la dernière synchronisation",
+    ),
+    (
+        "device_detail.files_failed.other",
+        "{count} fichiers n'ont pas pu être copiés lors de la dernière synchronisation",
+    ),
+    (
+        "device_detail.unreadable.one",
+        "{count} élément de l'appareil n'a pas pu être lu",
+    ),
+    (
+        "device_detail.unreadable.other",
+        "{count} éléments de l'appareil n'ont pas pu être lus",
+    ),
+    (
+        "device_detail.settings_saved",
+        "Paramètres de l'appareil enregistrés",
+    ),
+    (
+        "device_detail.settings_save_failed",
+        "Impossible d'enregistrer les paramètres de l'appareil : {error}",
+    ),
+    ("device_detail.verifying", "Vérification..."),
+    (
+        "device_detail.verify_failed",
+        "Impossible de vérifier l'appareil : {error}",
+    ),
+    ("device_detail.cleaning", "Nettoyage..."),
+    ("device_detail.cleaned", "{files} supprimés, {size} libérés"),
+    (
+        "device_detail.clean_failed",
+        "Impossible de nettoyer l'appareil : {error}",
+    ),
+    ("device_detail.ejecting", "Éjection..."),
+    (
+        "device_detail.eject_refused",
+        "L'appareil n'a pas pu être éjecté",
+    ),
+    (
+        "device_detail.eject_failed",
+        "Impossible d'éjecter l'appareil : {error}",
+    ),
+    ("device_detail.scanning", "Analyse de l'appareil..."),
+    (
+        "device_detail.usage_label",
+        "{music} % musique, {other} % autres fichiers, {system} % système",
+    ),
+    ("device_detail.music", "Musique"),
+    ("device_detail.other", "Autres"),
+    ("device_detail.system", "Système"),
+    ("device_detail.free", "Libre"),
+    ("device_detail.free_of", "{free} sur {total}"),
+    ("device_detail.no_problems", "Aucun problème détecté"),
+    ("device_detail.not_synced", "Pas encore synchronisé"),
+    ("device_detail.files_copied.one", "{count} fichier copié"),
+    (
+        "device_detail.files_copied.other",
+        "{count} fichiers copiés",
+    ),
+    (
+        "device_detail.verify_ok.one",
+        "{count} piste est sur l'appareil",
+    ),
+    (
+        "device_detail.verify_ok.other",
+        "Les {count} pistes sont sur l'appareil",
+    ),
+    (
+        "device_detail.verify_counts",
+        "{matching} identiques, {missing} manquantes, {different} différentes",
+    ),
+    ("device_detail.sync", "Synchroniser"),
+    ("device_detail.verify", "Vérifier"),
+    (
+        "device_detail.verify_hint",
+        "Comparer l'appareil avec les playlists de sa dernière synchronisation",
+    ),
+    ("device_detail.clean", "Nettoyer"),
+    ("device_detail.eject", "Éjecter"),
+    (
+        "device_detail.disconnected",
+        "Cet appareil n'est plus connecté.",
+    ),
+    (
+        "device_detail.clean_confirm",
+        "Supprimer tout le contenu de l'appareil, sauf les fichiers cachés et système ?",
+    ),
+    ("device_detail.delete_files", "Supprimer les fichiers"),
+    ("device_detail.last_sync", "Dernière synchronisation"),
+    ("device_detail.nickname", "Surnom"),
+    (
+        "device_detail.auto_sync",
+        "Resynchroniser les dernières playlists à la connexion de cet appareil",
+    ),
+    (
+        "device_detail.ignore",
+        "Ignorer cet appareil lorsqu'il est connecté",
+    ),
+    ("onboarding.step.storage", "Stockage"),
+    ("onboarding.step.quality", "Qualité"),
+    ("onboarding.step.playlist", "Première playlist"),
+    ("onboarding.step.device", "Appareil"),
+    ("onboarding.name_required", "Donnez un nom à la playlist"),
+    (
+        "onboarding.devices_failed",
+        "Impossible de rechercher les appareils : {error}",
+    ),
+    (
+        "onboarding.directory_required",
+        "Choisissez un dossier pour vos playlists",
+    ),
+    (
+        "onboarding.save_failed",
+        "Échec de l'enregistrement de la configuration : {error}",
+    ),
+    (
+        "onboarding.invalid_url",
+        "Ce n'est pas une URL de playlist YouTube",
+    ),
+    (
+        "onboarding.storage.description",
+        "Choisissez où Youtun4 conserve vos playlists. Chaque playlist est un dossier de fichiers MP3.",
+    ),
+    ("onboarding.storage.folder", "Dossier des playlists"),
+    ("onboarding.storage.suggested", "Suggestion : {path}"),
+    (
+        "onboarding.storage.music_found",
+        "Musique trouvée sur cet ordinateur :",
+    ),
+    (
+        "onboarding.quality.description",
+        "Une meilleure qualité sonne mieux mais remplit votre lecteur plus vite. Vous pourrez la modifier plus tard dans les Paramètres.",
+    ),
+    (
+        "onboarding.quality.low_description",
+        "128 kbps - Le plus de chansons",
+    ),
+    (
+        "onboarding.playlist.description",
+        "Collez un lien de playlist YouTube pour démarrer votre bibliothèque, ou passez cette étape.",
+    ),
+    ("onboarding.playlist.url", "URL de la playlist YouTube"),
+    ("onboarding.playlist.name", "Nom de la playlist"),
+    (
+        "onboarding.playlist.name_placeholder",
+        "Ma première playlist",
+    ),
+    (
+        "onboarding.device.description",
+        "Branchez votre lecteur MP3 pour vérifier que Youtun4 le voit. Choisissez-le pour le sélectionner automatiquement la prochaine fois.",
+    ),
+    ("onboarding.device.looking", "Recherche d'appareils..."),
+    (
+        "onboarding.device.none",
+        "Aucun appareil détecté pour l'instant.",
+    ),
+    ("onboarding.device.known", "Déjà utilisés : {devices}"),
+    ("onboarding.device.connected", "{device} (connecté)"),
+    ("onboarding.device.check_again", "Vérifier à nouveau"),
+    ("onboarding.title", "Bienvenue dans Youtun4"),
+    (
+        "onboarding.subtitle",
+        "Quelques choix et vous êtes prêt à mettre de la musique sur votre lecteur.",
+    ),
+    ("onboarding.setting_up", "Configuration..."),
+    ("onboarding.finish", "Terminer"),
+    ("onboarding.skip", "Passer"),
+    ("quality.low", "Basse"),
+    ("quality.medium", "Moyenne"),
+    (
+        "quality.medium.description",
+        "192 kbps - Équilibre entre qualité et taille",
+    ),
+    ("quality.high", "Haute"),
+    (
+        "quality.high.description",
+        "320 kbps - Meilleure qualité, fichiers plus gros",
+    ),
+    (
+        "quality.low.description",
+        "128 kbps - Fichiers plus petits, qualité moindre",
+    ),
+    ("webhook_event.download_completed", "Téléchargement terminé"),
+    ("webhook_event.download_failed", "Échec du téléchargement"),
+    ("webhook_event.sync_completed", "Synchronisation terminée"),
+    ("webhook_event.sync_failed", "Échec de la synchronisation"),
+    (
+        "announce.sync_finished",
+        "Synchronisation terminée. {files} transférés.",
+    ),
+    (
+        "announce.sync_failed",
+        "Échec de la synchronisation. {error}",
+    ),
+    (
+        "announce.download_finished",
+        "Téléchargement terminé. {downloaded} téléchargés, {failed} en échec, {skipped} ignorés.",
+    ),
+    (
+        "announce.download_failed",
+        "Échec du téléchargement. {error}",
+    ),
+    ("undo.renamed", "« {from} » renommée en « {to} »"),
+    ("undo.tracks_removed", "{tracks} retirée(s) de « {name} »"),
+    ("batch.partial", "{succeeded} sur {items}"),
+    ("batch.deleted", "Supprimé : {items}"),
+    ("batch.moved", "Déplacé : {items}"),
+    ("batch.tagged", "Étiqueté : {items}"),
+    ("batch.first_failure", ". {item} : {error}"),
+    ("batch.more_failures.one", " (et {count} de plus)"),
+    ("batch.more_failures.other", " (et {count} de plus)"),
+    ("batch.toolbar", "Actions sur la sélection"),
+    ("batch.selected", "Sélection : {items}"),
+    ("batch.select_all", "Tout sélectionner"),
+    ("batch.sync_selected", "Synchroniser la sélection"),
+    ("batch.move", "Déplacer"),
+    ("batch.tag", "Étiqueter"),
+    ("batch.tag_name", "Nom de l'étiquette"),
+    ("batch.add_tag", "Ajouter l'étiquette"),
+    ("batch.move_target", "Playlist de destination"),
+    ("batch.move_here", "Déplacer ici"),
+    (
+        "batch.confirm_delete",
+        "Supprimer {items} ? Cette action est irréversible.",
+    ),
+    (
+        "playlist_detail.back_label",
+        "Revenir à la liste des playlists",
+    ),
+    ("playlist_detail.settings", "Paramètres de la playlist"),
+    ("playlist_detail.open_folder", "Afficher dans le dossier"),
+    (
+        "playlist_detail.open_folder.title",
+        "Ouvrir dans le gestionnaire de fichiers",
+    ),
+    ("playlist_detail.repair", "Réparer"),
+    (
+        "playlist_detail.repair.title",
+        "Corriger les métadonnées du dossier de la playlist",
+    ),
+    ("playlist_detail.checksums", "Sommes de contrôle"),
+    (
+        "playlist_detail.checksums.title",
+        "Enregistrer les sommes de contrôle des pistes pour les vérifier plus tard",
+    ),
+    ("playlist_detail.sync", "Synchroniser"),
+    ("playlist_detail.delete.title", "Supprimer la playlist"),
+    ("playlist_detail.source", "Source"),
+    ("playlist_detail.created", "Créée"),
+    ("playlist_detail.modified", "Modifiée"),
+    (
+        "playlist_detail.updating",
+        "Mise à jour de « {name} » depuis sa source",
+    ),
+    ("playlist_detail.checking", "Vérification..."),
+    (
+        "playlist_detail.check_updates",
+        "Rechercher des mises à jour",
+    ),
+    (
+        "playlist_detail.remove_missing.one",
+        "Supprimer {count} piste qui n'est plus dans la source",
+    ),
+    (
+        "playlist_detail.remove_missing.other",
+        "Supprimer {count} pistes qui ne sont plus dans la source",
+    ),
+    ("playlist_detail.update", "Mettre à jour"),
+    (
+        "playlist_detail.load_failed",
+        "Échec du chargement de la playlist",
+    ),
+    ("playlist_detail.tracks", "Pistes"),
+    (
+        "playlist_detail.select_hint",
+        "Cliquez sur les pistes pour les sélectionner ; Maj+clic sélectionne une plage",
+    ),
+    (
+        "playlist_detail.track_hint",
+        "Cliquez sur une piste pour voir ses détails",
+    ),
+    ("playlist_detail.not_found", "Playlist introuvable"),
+    ("track_preview.title", "Aperçu de la piste"),
+    (
+        "track_preview.reveal",
+        "Afficher dans le gestionnaire de fichiers",
+    ),
+    ("track_preview.close", "Fermer l'aperçu"),
+    (
+        "track_preview.no_metadata",
+        "Aucune métadonnée ID3 dans ce fichier",
+    ),
+    ("track_preview.empty", "Aucune piste sélectionnée"),
+    ("track.artist", "Artiste"),
+    ("track.album", "Album"),
+    ("track.number", "Piste"),
+    ("track.duration", "Durée"),
+    ("track.year", "Année"),
+    ("track.genre", "Genre"),
+    ("track.bitrate", "Débit"),
+    ("track.file_size", "Taille du fichier"),
+    ("track.file_name", "Nom du fichier"),
+    (
+        "playlist_diff.new.one",
+        "{count} nouvelle piste sera téléchargée",
+    ),
+    (
+        "playlist_diff.new.other",
+        "{count} nouvelles pistes seront téléchargées",
+    ),
+    ("playlist_diff.removed.one", "{count} retirée"),
+    ("playlist_diff.removed.other", "{count} retirées"),
+    ("playlist_diff.renamed.one", "{count} renommée"),
+    ("playlist_diff.renamed.other", "{count} renommées"),
+    ("playlist_diff.moved.one", "{count} déplacée"),
+    ("playlist_diff.moved.other", "{count} déplacées"),
+    ("playlist_diff.unavailable.one", "{count} indisponible"),
+    ("playlist_diff.unavailable.other", "{count} indisponibles"),
+    ("playlist_diff.up_to_date", "À jour"),
+    ("size_estimate.summary", "~{size}, {length} d'audio"),
+    ("playlist_settings.use_global", "Utiliser le réglage global"),
+    ("playlist_settings.quality.low", "Basse (128 kbps)"),
+    ("playlist_settings.quality.medium", "Moyenne (192 kbps)"),
+    ("playlist_settings.quality.high", "Haute (320 kbps)"),
+    ("playlist_settings.lyrics.on", "Récupérer les paroles"),
+    (
+        "playlist_settings.lyrics.off",
+        "Ne pas récupérer les paroles",
+    ),
+    (
+        "playlist_settings.bitrate.default",
+        "Utiliser la qualité de téléchargement",
+    ),
+    ("playlist_settings.format.mp3", "MP3"),
+    (
+        "playlist_settings.format.original",
+        "Original (sans conversion)",
+    ),
+    ("playlist_settings.tags.none", "Aucun tag"),
+    ("playlist_settings.tags.tags", "Tags uniquement"),
+    ("playlist_settings.tags.cover_art", "Tags et pochette"),
+    ("playlist_settings.refresh.never", "Jamais"),
+    ("playlist_settings.refresh.6h", "Toutes les 6 heures"),
+    ("playlist_settings.refresh.12h", "Toutes les 12 heures"),
+    ("playlist_settings.refresh.24h", "Tous les jours"),
+    ("playlist_settings.refresh.nightly", "Chaque nuit à 3:00"),
+    ("playlist_settings.refresh.weekly", "Toutes les semaines"),
+    (
+        "playlist_settings.saved",
+        "Réglages enregistrés pour « {name} »",
+    ),
+    (
+        "playlist_settings.save_failed",
+        "Échec de l'enregistrement des réglages de la playlist : {error}",
+    ),
+    ("playlist_settings.quality", "Qualité de téléchargement"),
+    (
+        "playlist_settings.quality.hint",
+        "Utilisée pour les nouveaux téléchargements dans cette playlist.",
+    ),
+    (
+        "playlist_settings.bitrate.hint",
+        "Remplace la qualité de téléchargement pour cette playlist.",
+    ),
+    ("playlist_settings.format", "Format de fichier"),
+    ("playlist_settings.tags", "Tags"),
+    ("playlist_settings.file_names", "Noms de fichiers"),
+    ("playlist_settings.file_names.default", "Par défaut"),
+    (
+        "playlist_settings.file_names.hint",
+        "Remplace le modèle de nom de fichier, par exemple {example}.",
+    ),
+    ("playlist_settings.refresh", "Actualiser depuis la source"),
+    (
+        "playlist_settings.refresh.remove_missing",
+        "Supprimer les pistes absentes de la source",
+    ),
+    (
+        "playlist_settings.refresh.hint",
+        "Les nouvelles vidéos sont téléchargées tant que l'application est ouverte.",
+    ),
+    (
+        "storage.clear_cache_failed",
+        "Échec du vidage du cache : {error}",
+    ),
+    ("storage.archived", "« {name} » archivée dans {path}"),
+    (
+        "storage.archive_failed",
+        "Échec de l'archivage de « {name} » : {error}",
+    ),
+    ("storage.moved", "{files} déplacés ({size}) vers {path}"),
+    (
+        "storage.directory_changed",
+        "Les playlists sont maintenant stockées dans {path}",
+    ),
+    (
+        "storage.directory_change_failed",
+        "Échec du changement de dossier de stockage : {error}",
+    ),
+    ("storage.measuring", "Mesure de l'espace..."),
+    (
+        "storage.library_limit",
+        "Limite de la bibliothèque : {size}",
+    ),
+    (
+        "storage.disk_usage",
+        "{playlists} % playlists, {cache} % cache, {free} % libre",
+    ),
+    ("storage.legend.playlists", "Playlists · {size}"),
+    ("storage.legend.cache", "Cache · {size}"),
+    ("storage.legend.free", "Libre · {size}"),
+    ("storage.legend.free_unknown", "Libre · inconnu"),
+    (
+        "storage.min_free_space",
+        "Les téléchargements s'arrêtent quand il reste moins de {size}",
+    ),
+    ("storage.no_playlists", "Aucune playlist pour l'instant"),
+    ("storage.archive_playlist", "Archiver {name}"),
+    ("storage.archive", "Archiver"),
+    ("storage.cache_off", "Le cache est désactivé"),
+    ("storage.cache_usage", "Cache plein à {percent} %"),
+    (
+        "storage.cache_summary",
+        "{used} sur {max} · {metadata} métadonnées, {thumbnails}, {temp}",
+    ),
+    ("storage.disk", "Disque"),
+    (
+        "storage.archive_confirm",
+        "Déplacer « {name} » vers {path} ? Elle n'apparaîtra plus dans votre bibliothèque.",
+    ),
+    ("storage.directory", "Dossier de stockage"),
+    (
+        "storage.directory.description",
+        "Les playlists sont stockées dans ce dossier. Les déplacer copie et vérifie chaque fichier avant de basculer.",
+    ),
+    (
+        "storage.directory.placeholder",
+        "Saisissez le chemin du dossier...",
+    ),
+    ("storage.moving", "Déplacement..."),
+    ("storage.change", "Changer"),
+    (
+        "storage.move_playlists",
+        "Déplacer mes playlists vers le nouveau dossier",
+    ),
+    (
+        "create_playlist.name_required",
+        "Le nom de la playlist est obligatoire",
+    ),
+    (
+        "create_playlist.name_too_long",
+        "Le nom doit faire 255 caractères au maximum",
+    ),
+    (
+        "create_playlist.name_invalid_char",
+        "Le nom ne peut pas contenir « {char} »",
+    ),
+    (
+        "create_playlist.name_reserved",
+        "Ce nom est réservé par le système",
+    ),
+    (
+        "create_playlist.validation_error",
+        "Erreur de validation : {error}",
+    ),
+    ("create_playlist.title", "Nouvelle playlist"),
+    ("create_playlist.search", "Rechercher sur YouTube"),
+    (
+        "create_playlist.search.placeholder",
+        "Rechercher des vidéos et des playlists",
+    ),
+    ("create_playlist.search.filter", "Filtre de recherche"),
+    ("create_playlist.search.all", "Tout"),
+    ("create_playlist.search.videos", "Vidéos"),
+    ("create_playlist.searching", "Recherche..."),
+    ("create_playlist.search.button", "Rechercher"),
+    ("create_playlist.no_results", "Aucun résultat"),
+    ("create_playlist.result.video", "Vidéo"),
+    ("create_playlist.result.playlist", "Playlist"),
+    ("create_playlist.url", "URL de la playlist YouTube"),
+    (
+        "create_playlist.url.hint",
+        "Collez l'URL d'une playlist YouTube ou choisissez un résultat de recherche",
+    ),
+    ("create_playlist.validating", "Validation de l'URL..."),
+    ("create_playlist.url_type", "Type :"),
+    ("create_playlist.playlist_id", "ID de playlist :"),
+    (
+        "create_playlist.mix_warning",
+        "Les mix ne s'arrêtent jamais : seules les {count} premières vidéos seront téléchargées",
+    ),
+    ("create_playlist.name", "Nom de la playlist"),
+    (
+        "create_playlist.name.placeholder",
+        "Saisissez un nom pour votre playlist",
+    ),
+    ("create_playlist.creating", "Création..."),
+    ("create_playlist.create", "Créer la playlist"),
+    ("create_playlist.url_type.playlist", "Playlist YouTube"),
+    (
+        "create_playlist.url_type.watch_with_playlist",
+        "Vidéo avec playlist",
+    ),
+    (
+        "create_playlist.url_type.single_video",
+        "Vidéo seule (sans playlist)",
+    ),
+    ("create_playlist.url_type.short_url", "URL courte"),
+    ("create_playlist.url_type.channel", "Vidéos de la chaîne"),
+    ("create_playlist.url_type.invalid", "URL invalide"),
+    ("first_playlist.step.paste", "Coller un lien"),
+    ("first_playlist.step.check", "Vérifier la playlist"),
+    ("first_playlist.step.name", "Nommer et télécharger"),
+    (
+        "first_playlist.name_taken",
+        "Une playlist porte déjà ce nom",
+    ),
+    (
+        "first_playlist.fetching",
+        "Récupération des détails de la playlist...",
+    ),
+    (
+        "first_playlist.load_failed",
+        "Impossible de charger la playlist : {error}",
+    ),
+    ("first_playlist.estimating", "Estimation de la taille..."),
+    (
+        "first_playlist.unavailable",
+        "{count} privées, supprimées ou bloquées (seront ignorées)",
+    ),
+    ("first_playlist.title", "Ajoutez votre première playlist"),
+    (
+        "first_playlist.url.hint",
+        "Copiez le lien d'une playlist YouTube et collez-le ici",
+    ),
+    ("first_playlist.checking", "Vérification du lien..."),
+    ("first_playlist.valid", "Lien de playlist"),
+    ("first_playlist.queueing", "Mise en file..."),
+    ("first_playlist.download", "Télécharger"),
+    (
+        "empty.playlists.title",
+        "Commencez votre bibliothèque musicale",
+    ),
+    (
+        "empty.playlists.message",
+        "Collez l'URL d'une playlist YouTube pour télécharger les pistes en MP3.",
+    ),
+    (
+        "empty.playlists.hint",
+        "Les téléchargements sont stockés localement et peuvent être synchronisés sur n'importe quel lecteur MP3.",
+    ),
+    ("empty.playlists.add", "Ajouter une playlist YouTube"),
+    ("empty.device.title", "Connectez votre lecteur MP3"),
+    (
+        "empty.device.message",
+        "Branchez votre lecteur MP3 ou votre clé USB pour synchroniser la musique.",
+    ),
+    (
+        "empty.device.hint",
+        "La plupart des périphériques de stockage USB sont détectés automatiquement.",
+    ),
+    ("empty.device.scan", "Rechercher des appareils"),
+    ("empty.search.message", "Aucun résultat pour « {query} »"),
+    (
+        "empty.search.hint",
+        "Essayez d'autres termes de recherche ou vérifiez l'orthographe.",
+    ),
+    ("empty.search.clear", "Effacer la recherche"),
+    ("empty.tracks.title", "Aucune piste dans cette playlist"),
+    (
+        "empty.tracks.message",
+        "Cette playlist n'a pas encore de pistes.",
+    ),
+    (
+        "empty.tracks.hint",
+        "Les pistes sont téléchargées quand vous créez une playlist à partir d'une URL YouTube.",
+    ),
+    ("empty.sync.title", "Rien à synchroniser"),
+    (
+        "empty.sync.message",
+        "Toutes les pistes sont déjà sur votre appareil.",
+    ),
+    (
+        "empty.sync.hint",
+        "Ajoutez de nouvelles playlists ou mettez à jour les existantes pour synchroniser du nouveau contenu.",
+    ),
+    ("empty.error.title", "Un problème est survenu"),
+    ("empty.error.retry", "Réessayer"),
+    ("empty.folder.title", "Le dossier est vide"),
+    ("empty.folder.named_title", "« {name} » est vide"),
+    (
+        "empty.folder.message",
+        "Ce dossier ne contient aucun fichier.",
+    ),
+    ("youtube_error.network.title", "Erreur réseau"),
+    (
+        "youtube_error.network.description",
+        "Impossible de se connecter à YouTube. Vérifiez votre connexion Internet et réessayez.",
+    ),
+    (
+        "youtube_error.youtube_service.title",
+        "Erreur du service YouTube",
+    ),
+    (
+        "youtube_error.youtube_service.description",
+        "YouTube est temporairement indisponible ou limite les requêtes. Patientez un instant et réessayez.",
+    ),
+    ("youtube_error.invalid_url.title", "URL invalide"),
+    (
+        "youtube_error.invalid_url.description",
+        "L'URL fournie n'est pas une URL de playlist YouTube valide.",
+    ),
+    (
+        "youtube_error.playlist_not_found.title",
+        "Playlist introuvable",
+    ),
+    (
+        "youtube_error.playlist_not_found.description",
+        "La playlist est introuvable. Elle est peut-être privée ou supprimée, ou l'URL est incorrecte.",
+    ),
+    (
+        "youtube_error.video_unavailable.title",
+        "Vidéo indisponible",
+    ),
+    (
+        "youtube_error.video_unavailable.description",
+        "Une ou plusieurs vidéos de la playlist sont indisponibles (privées, supprimées ou restreintes).",
+    ),
+    (
+        "youtube_error.age_restricted.title",
+        "Contenu soumis à une limite d'âge",
+    ),
+    (
+        "youtube_error.age_restricted.description",
+        "Ce contenu est soumis à une limite d'âge et nécessite une authentification.",
+    ),
+    (
+        "youtube_error.geo_restricted.title",
+        "Restriction géographique",
+    ),
+    (
+        "youtube_error.geo_restricted.description",
+        "Ce contenu n'est pas disponible dans votre région.",
+    ),
+    (
+        "youtube_error.audio_extraction.title",
+        "Échec de l'extraction audio",
+    ),
+    (
+        "youtube_error.audio_extraction.description",
+        "Impossible d'extraire l'audio de la vidéo. Le format n'est peut-être pas pris en charge.",
+    ),
+    (
+        "youtube_error.file_system.title",
+        "Erreur du système de fichiers",
+    ),
+    (
+        "youtube_error.file_system.description",
+        "Impossible d'enregistrer le fichier. Vérifiez l'espace disque et les autorisations.",
+    ),
+    ("youtube_error.cancelled.title", "Annulé"),
+    (
+        "youtube_error.cancelled.description",
+        "Le téléchargement a été annulé.",
+    ),
+    ("youtube_error.library_space.title", "Espace insuffisant"),
+    (
+        "youtube_error.library_space.description",
+        "La bibliothèque a atteint sa taille maximale ou le disque est presque plein. Libérez de l'espace ou ajustez les limites de stockage dans les réglages.",
+    ),
+    ("youtube_error.unknown.title", "Erreur"),
+    (
+        "youtube_error.unknown.description",
+        "Une erreur inattendue s'est produite.",
+    ),
+    ("youtube_error.download_failed", "Échec du téléchargement"),
+    ("download_panel.video.downloaded", "Téléchargée"),
+    ("download_panel.video.skipped", "Ignorée"),
+    ("download_panel.video.failed", "Échec"),
+    (
+        "download_panel.sponsor_removed",
+        "{seconds} s de segments sponsorisés supprimées",
+    ),
+    (
+        "download_panel.chapters",
+        "Découpée en {tracks} selon les chapitres",
+    ),
+    ("download_panel.source", "Source : {source}"),
+    (
+        "download_panel.retry_failed.title",
+        "Télécharger à nouveau les vidéos en échec",
+    ),
+    ("download_panel.ready", "Prêt"),
+    ("download_panel.downloading", "Téléchargement..."),
+    ("download_panel.completed", "Téléchargement terminé"),
+    ("download_panel.cancelled", "Téléchargement annulé"),
+    ("download_panel.progress", "{current} sur {total}"),
+    ("download_panel.retry_hint", "Vous pouvez réessayer"),
+    ("download_panel.cancel", "Annuler le téléchargement"),
+    ("download_panel.dismiss", "Fermer"),
+    (
+        "download_panel.dismiss_download",
+        "Fermer le téléchargement",
+    ),
+    (
+        "download_panel.track_progress",
+        "Progression du téléchargement de la piste",
+    ),
+    (
+        "download_panel.progress_label",
+        "Progression du téléchargement",
+    ),
+    ("download_panel.remaining", "restant"),
+    ("download_panel.elapsed", "écoulé"),
+    (
+        "download_panel.retry_count",
+        "Réessayer les {count} en échec",
+    ),
+    ("download_panel.indicator.downloading", "Téléchargement"),
+    ("download_panel.indicator.starting", "Démarrage..."),
+    ("context_menu.actions", "Actions"),
+    ("track_menu.preview", "Aperçu"),
+    (
+        "track_menu.reveal",
+        "Afficher dans le gestionnaire de fichiers",
+    ),
+    ("track_menu.fetch_lyrics", "Récupérer les paroles"),
+    ("track_menu.label", "Actions sur la piste"),
+    ("device_menu.label", "Actions sur l'appareil"),
+    ("playlist_menu.label", "Actions sur la playlist"),
+    (
+        "playlist_menu.reset_archive",
+        "Réinitialiser l'archive de téléchargement",
+    ),
+    ("playlist_menu.export", "Exporter en M3U"),
+    ("playlist_menu.checksums", "Créer des sommes de contrôle"),
+    ("playlist_card.sync_to", "Synchroniser sur {device}"),
+    (
+        "playlist_card.connect_device.title",
+        "Connectez un appareil pour synchroniser",
+    ),
+    ("playlist_card.connect_device", "Connecter un appareil"),
+    ("playlist_card.details.title", "Voir les détails de {name}"),
+    ("playlist_card.details", "Détails"),
+    ("playlist_card.thumbnail", "Miniature de la playlist"),
+    ("search.hint", "Rechercher des playlists, titres et chaînes"),
+    ("search.no_matches", "Aucune correspondance"),
+    ("search.label", "Rechercher dans la bibliothèque"),
+    ("search.placeholder", "Rechercher dans la bibliothèque..."),
+    ("transfer_panel.preparing", "Préparation du transfert..."),
+    ("transfer_panel.transferring", "Transfert des fichiers..."),
+    ("transfer_panel.verifying", "Vérification de l'intégrité..."),
+    ("transfer_panel.completed", "Transfert terminé"),
+    ("transfer_panel.failed", "Échec du transfert : {error}"),
+    ("transfer_panel.cancelled", "Transfert annulé"),
+    ("transfer_panel.cancel", "Annuler le transfert"),
+    ("transfer_panel.dismiss", "Fermer le transfert"),
+    (
+        "transfer_panel.file_progress",
+        "Progression du fichier en cours",
+    ),
+    ("transfer_panel.overall", "Progression globale"),
+    (
+        "transfer_panel.overall_label",
+        "Progression globale du transfert",
+    ),
+    ("transfer_panel.files_completed", "{count} terminés"),
+    ("transfer_panel.files_skipped", "{count} ignorés"),
+    ("transfer_panel.indicator.transferring", "Transfert"),
+    ("transfer_panel.indicator.preparing", "Préparation..."),
+    ("device_list.storage_used", "Stockage utilisé"),
+    (
+        "device_list.eject.title",
+        "Éjecter l'appareil en toute sécurité",
+    ),
+    ("device_list.title", "Appareils connectés"),
+    ("device_list.refresh", "Actualiser les appareils"),
+    (
+        "device_list.detect_failed",
+        "Échec de la détection des appareils",
+    ),
+    ("logs.level.all", "Tous les niveaux"),
+    ("logs.level.debug", "Debug et plus"),
+    ("logs.level.info", "Info et plus"),
+    ("logs.level.warn", "Avertissements et erreurs"),
+    ("logs.level.error", "Erreurs uniquement"),
+    ("logs.copied", "Journaux copiés dans le presse-papiers"),
+    (
+        "logs.copy_failed",
+        "Échec de la copie des journaux : {error}",
+    ),
+    (
+        "logs.open_folder_failed",
+        "Échec de l'ouverture du dossier des journaux : {error}",
+    ),
+    ("logs.level", "Niveau de journal minimal"),
+    ("logs.auto_scroll", "Défilement automatique"),
+    ("logs.copy", "Copier"),
+    ("logs.open_folder", "Ouvrir le dossier des journaux"),
+    (
+        "logs.read_failed",
+        "Impossible de lire les journaux : {error}",
+    ),
+    ("logs.empty", "Aucune entrée de journal"),
+    (
+        "sync_button.need_both",
+        "Connectez un appareil et sélectionnez une playlist à synchroniser",
+    ),
+    (
+        "sync_button.need_device",
+        "Connectez un appareil USB pour synchroniser",
+    ),
+    (
+        "sync_button.need_playlist",
+        "Sélectionnez une playlist à synchroniser",
+    ),
+    ("sync_button.syncing.title", "Synchronisation en cours..."),
+    (
+        "sync_button.checking.title",
+        "Vérification de l'espace disponible...",
+    ),
+    (
+        "sync_button.sync_with_warning",
+        "Synchroniser « {name} » sur {device} ({warning})",
+    ),
+    ("sync_button.sync", "Synchroniser « {name} » sur {device}"),
+    (
+        "sync_button.sync_default",
+        "Synchroniser la playlist sur l'appareil",
+    ),
+    ("sync_button.syncing", "Synchronisation..."),
+    ("sync_button.checking", "Vérification de l'espace..."),
+    (
+        "sync_button.need_have",
+        "(requis : {required}, disponible : {available})",
+    ),
+    (
+        "sync_button.low_space",
+        "Espace faible : plein à {percent} % après la synchronisation",
+    ),
+    (
+        "sync_conflict.summary",
+        "{keeping} sur {total} conservés tels quels",
+    ),
+    (
+        "sync_conflict.title",
+        "Des fichiers de l'appareil vont changer",
+    ),
+    (
+        "sync_conflict.message",
+        "Cette synchronisation écrase ou supprime les fichiers ci-dessous. Choisissez ceux à conserver tels quels.",
+    ),
+    ("sync_conflict.keep_all", "Tout conserver"),
+    ("sync_conflict.replace_all", "Tout remplacer"),
+    (
+        "sync_conflict.not_selected",
+        "Absent des playlists sélectionnées",
+    ),
+    ("sync_conflict.from", "De {playlist}"),
+    ("sync_conflict.choice", "Conserver ou remplacer"),
+    ("device_status.connected", "Connecté"),
+    ("device_status.free", "{size} libres"),
+    ("device_status.removable", "Amovible"),
+    ("device_status.no_device", "Aucun appareil"),
+    ("device_status.disconnected", "Déconnecté"),
+    ("device_status.plug_in", "Branchez votre lecteur MP3 en USB"),
+    ("device_status.detecting", "Détection..."),
+    ("device_status.scanning", "Recherche"),
+    (
+        "device_status.looking",
+        "Recherche des appareils connectés...",
+    ),
+    ("device_status.none_connected", "Aucun appareil connecté"),
+    (
+        "maintenance.checksums",
+        "Création des sommes de contrôle de « {playlist} »",
+    ),
+    ("maintenance.repair", "Réparation de « {playlist} »"),
+    ("maintenance.clear_cache", "Vidage du cache"),
+    ("operation.starting", "Démarrage…"),
+    ("operation.failed", "Échec"),
+    ("operation.failed_with", "Échec : {error}"),
+    ("operation.cancelled", "Annulé"),
+    ("operation.done", "Terminé"),
+    ("operation.cancelling", "Annulation…"),
+    ("operation.working", "En cours…"),
+    ("undo_history.item", "Annuler cette modification"),
+    (
+        "undo_history.item_and_later.one",
+        "Annuler cette modification et la suivante",
+    ),
+    (
+        "undo_history.item_and_later.other",
+        "Annuler cette modification et les {count} suivantes",
+    ),
+    ("undo_history.label", "Historique d'annulation"),
+    (
+        "playlist_selection.empty.title",
+        "Aucune playlist disponible",
+    ),
+    (
+        "playlist_selection.empty.message",
+        "Créez d'abord une playlist pour la sélectionner à synchroniser",
+    ),
+    ("playlist_selection.label", "Sélectionner une playlist"),
+    (
+        "playlist_selection.error.title",
+        "Impossible de charger les playlists",
+    ),
+    ("playlist_selection.error.message", "Veuillez réessayer"),
+    ("playlist_selection.selected", "Sélection :"),
+    (
+        "playlist_selection.hint",
+        "Sélectionnez une playlist à synchroniser",
+    ),
+    ("playlist_sort.name", "Nom"),
+    ("playlist_sort.recently_updated", "Modifiées récemment"),
+    ("playlist_sort.size", "Taille"),
+    ("playlist_grid.open", "Ouvrir {name}"),
+    ("playlist_grid.sort_by", "Trier par"),
+    ("playlist_grid.layout", "Disposition"),
+    ("playlist_grid.list_view", "Vue en liste"),
+    ("playlist_grid.grid_view", "Vue en grille"),
+    ("track_list.title", "Titre"),
+    ("track_list.album", "Album"),
+    ("track_list.duration", "Durée"),
+    ("track_list.size", "Taille"),
+    ("track_list.load_failed", "Impossible de charger les pistes"),
+    ("playlist_list.load_failed", "Une erreur s'est produite"),
+    ("playlist_list.select", "Sélectionner {name}"),
+    ("loading.error.title", "Un problème est survenu"),
+    (
+        "track_player.unplayable",
+        "Cette piste ne peut pas être lue",
+    ),
+    ("track_player.play", "Lire"),
+    ("track_player.pause", "Pause"),
+    ("track_player.seek", "Position de lecture"),
+    (
+        "command_error.device_not_found",
+        "L'appareil est introuvable. Vérifiez qu'il est branché.",
+    ),
+    (
+        "command_error.device_not_mounted",
+        "L'appareil n'est pas monté.",
+    ),
+    (
+        "command_error.device_disconnected",
+        "L'appareil a été débranché. Rebranchez-le et réessayez.",
+    ),
+    (
+        "command_error.device_read_only",
+        "L'appareil est en lecture seule.",
+    ),
+    (
+        "command_error.device_busy",
+        "L'appareil est occupé. Fermez les applications qui l'utilisent et réessayez.",
+    ),
+    (
+        "command_error.insufficient_space",
+        "Il n'y a pas assez d'espace sur l'appareil.",
+    ),
+    ("command_error.permission_denied", "Autorisation refusée."),
+    (
+        "command_error.unsupported_file_system",
+        "Le système de fichiers de l'appareil n'est pas pris en charge.",
+    ),
+    (
+        "command_error.platform_not_supported",
+        "Cette fonction n'est pas prise en charge sur votre plateforme.",
+    ),
+    (
+        "command_error.not_a_playlist",
+        "Cette URL n'est pas une playlist YouTube.",
+    ),
+    (
+        "command_error.network",
+        "Erreur réseau. Vérifiez votre connexion et réessayez.",
+    ),
+    ("command_error.timeout", "La requête a expiré. Réessayez."),
+    (
+        "command_error.rate_limited",
+        "YouTube limite les requêtes. Réessayez plus tard.",
+    ),
+    (
+        "command_error.rate_limited_for",
+        "YouTube limite les requêtes. Réessayez dans {seconds} secondes.",
+    ),
+    (
+        "command_error.video_unavailable",
+        "La vidéo n'est pas disponible.",
+    ),
+    (
+        "command_error.offline",
+        "Le mode hors ligne est activé. Désactivez-le dans les paramètres pour vous reconnecter.",
+    ),
+    (
+        "command_error.library_limit_reached",
+        "Votre bibliothèque a atteint sa taille maximale. Libérez de l'espace ou augmentez la limite dans les paramètres.",
+    ),
+    (
+        "command_error.low_disk_space",
+        "Votre disque est presque plein. Libérez de l'espace avant de télécharger.",
+    ),
+    (
+        "command_error.transfer_interrupted",
+        "Le transfert a été interrompu. Réessayez.",
+    ),
+    (
+        "command_error.integrity_check_failed",
+        "Un fichier a échoué à la vérification d'intégrité après le transfert.",
+    ),
+    (
+        "command_error.playlist_not_found",
+        "La playlist est introuvable.",
+    ),
+    (
+        "command_error.playlist_exists",
+        "Une playlist porte déjà ce nom.",
+    ),
+    (
+        "command_error.playlist_empty",
+        "La playlist ne contient aucune piste.",
+    ),
+    ("command_error.cancelled", "L'opération a été annulée."),
+    (
+        "command_error.task_busy",
+        "Une autre tâche l'utilise déjà. Attendez qu'elle se termine ou annulez-la.",
+    ),
+    ("queue.action.move_to_front", "Placer en tête"),
+    ("queue.action.move_up", "Monter"),
+    ("queue.action.move_down", "Descendre"),
+    ("queue.action.split_chapters", "Découper en chapitres"),
+    ("queue.action.keep_whole", "Garder entier"),
+    ("queue.action.remove", "Retirer"),
+    ("queue.resume", "Reprendre"),
+    ("queue.pause", "Pause"),
+    ("queue.paused", "En pause"),
+    ("queue.title", "File de téléchargement"),
+    ("queue.clear_finished", "Effacer les terminés"),
+    ("queue.section", "{title} ({count})"),
+    ("queue.section.downloading", "En cours"),
+    ("queue.section.up_next", "À suivre"),
+    ("queue.section.finished", "Terminés"),
+    (
+        "queue.progress_label",
+        "Progression du téléchargement de {name}",
+    ),
+    ("queue.chapters", "Chapitres"),
+    (
+        "queue.chapters.title",
+        "Les vidéos avec chapitres sont découpées en une piste par chapitre",
+    ),
+    ("queue.dry_run", "Simulation"),
+    (
+        "queue.dry_run.title",
+        "Planification seule : rien n'est téléchargé ni écrit",
+    ),
+    ("queue.priority.low", "Basse"),
+    ("queue.priority.normal", "Normale"),
+    ("queue.priority.high", "Haute"),
+    ("queue.status.pending", "En attente"),
+    ("queue.status.downloading", "Téléchargement"),
+    ("queue.status.completed", "Terminé"),
+    ("queue.status.failed", "Échec"),
+    ("queue.status.failed_with", "Échec : {error}"),
+    ("queue.status.cancelled", "Annulé"),
+    ("queue.status.interrupted", "Interrompu"),
+    (
+        "queue.reorder_within_priority",
+        "Les éléments ne peuvent être réordonnés qu'au sein d'une même priorité",
+    ),
+    (
+        "queue.update_failed",
+        "Impossible de mettre à jour la file : {error}",
+    ),
+    (
+        "queue.clear_failed",
+        "Impossible d'effacer les téléchargements terminés : {error}",
+    ),
+];
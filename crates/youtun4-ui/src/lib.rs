@@ -13,11 +13,15 @@
 // Pass by value suggestions for small types like bool - not always clearer
 #![allow(clippy::trivially_copy_pass_by_ref)]
 
+pub mod activity;
 pub mod app;
 pub mod components;
+pub mod i18n;
 pub mod tauri_api;
 pub mod theme;
 pub mod types;
+pub mod ui_state;
+pub mod undo;
 
 pub use app::App;
 pub use types::{DeviceInfo, PlaylistMetadata, TrackInfo};
@@ -3,6 +3,7 @@
 #![no_main]
 
 use leptos::prelude::*;
+use wasm_bindgen::JsValue;
 use wasm_bindgen::prelude::wasm_bindgen;
 use youtun4_ui::App;
 
@@ -10,8 +11,25 @@ use youtun4_ui::App;
 /// This function is called automatically when the WASM module is loaded.
 #[wasm_bindgen(start)]
 pub fn start() {
-    // Set up better panic messages in the browser console
-    console_error_panic_hook::set_once();
+    // Log panics to the browser console and persist a crash report
+    std::panic::set_hook(Box::new(|info| {
+        console_error_panic_hook::hook(info);
+        let message = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Unknown panic".to_string());
+        let location = info.location().map(ToString::to_string);
+        let stack = js_sys::Reflect::get(&js_sys::Error::new(""), &JsValue::from_str("stack"))
+            .ok()
+            .and_then(|stack| stack.as_string());
+        youtun4_ui::tauri_api::report_frontend_crash(
+            &message,
+            location.as_deref(),
+            stack.as_deref(),
+        );
+    }));
 
     // Remove the loading spinner
     if let Some(window) = web_sys::window()
@@ -6,9 +6,21 @@ use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 
 use crate::types::{
-    AppConfig, CapacityCheckResult, DeviceInfo, DownloadProgress, DownloadResult, FolderStatistics,
-    FolderValidationResult, Mp3Metadata, PlaylistMetadata, SavedPlaylistMetadata, TaskCount,
-    TaskId, TrackInfo, TransferOptions, TransferProgress, TransferResult, YouTubeUrlValidation,
+    AppConfig, ArchivedVideo, BackupManifest, BatchResult, CachedThumbnail, CancelledTasks,
+    CapacityCheckResult, CleanupResult, CommandError, ConfigChangedPayload, ConfigImportPreview,
+    CrashReport, DeepLinkQueued, DeepLinkRejected, DeepLinkRequest, DeviceInfo, DeviceProfile,
+    DeviceUsage, DownloadPlan, DownloadPriority, DownloadProfile, DownloadProgress,
+    DownloadQuality, DownloadResult, FolderStatistics, FolderValidationResult, HistoryFilter,
+    HistoryReport, HistoryStats, LibrarySearchResults, LibrarySpace, LogEntry, Lyrics,
+    MaintenanceOperation, Mp3Metadata, OnboardingState, ParentalStatus, PerformanceMetrics,
+    PersistedTask, PlaylistDiff, PlaylistDownloadEstimate, PlaylistFetchProgress, PlaylistInfo,
+    PlaylistMetadata, PlaylistOverrides, PlaylistSizeEstimate, PlaylistViewPreferences,
+    PowerStatus, QueueItem, QueueItemId, QueueStats, RefreshSchedule, SavedPlaylistMetadata,
+    ScheduledRefresh, SearchFilter, SearchResult, StorageMigrationResult, StorageOverview,
+    SyncPreview, TaskCount, TaskEvent, TaskId, TrackInfo, TrackPage, TransferOptions,
+    TransferProgress, TransferResult, TranslationCatalog, TrashEntry, TrashId, TrashedItem,
+    TrashedTracks, UiState, UpdateDownloadProgress, UpdateInfo, UrlCandidate, WebhookConfig,
+    YouTubeUrlValidation,
 };
 
 #[wasm_bindgen]
@@ -23,6 +35,10 @@ extern "C" {
         event: &str,
         handler: &Closure<dyn Fn(JsValue)>,
     ) -> Result<js_sys::Promise, JsValue>;
+
+    /// Convert a file path to an asset protocol URL (Tauri 2.x API).
+    #[wasm_bindgen(js_namespace = ["window", "__TAURI__", "core"], js_name = convertFileSrc)]
+    fn tauri_convert_file_src(path: &str) -> String;
 }
 
 /// Event names for device-related events.
@@ -86,6 +102,24 @@ fn is_tauri_available() -> bool {
     tauri.is_ok() && !tauri.expect("tauri ok").is_undefined()
 }
 
+/// Turn a rejected command promise into a user-facing message, in the
+/// interface language when the backend sent a message key.
+fn command_error_message(error: JsValue) -> String {
+    if let Some(message) = error.as_string() {
+        return message;
+    }
+    let locale = crate::i18n::backend_locale();
+    serde_wasm_bindgen::from_value::<CommandError>(error).map_or_else(
+        |_| crate::i18n::translate(locale, "common.unknown_command_error", &[]),
+        |e| {
+            e.message_i18n
+                .as_ref()
+                .and_then(crate::i18n::render_backend_message)
+                .unwrap_or_else(|| e.user_message(locale))
+        },
+    )
+}
+
 /// Call a Tauri command with the given arguments.
 async fn invoke<T: serde::de::DeserializeOwned>(
     cmd: &str,
@@ -114,9 +148,7 @@ async fn invoke<T: serde::de::DeserializeOwned>(
     })?;
 
     let result = JsFuture::from(promise).await.map_err(|e| {
-        let msg = e
-            .as_string()
-            .unwrap_or_else(|| "Unknown error from Tauri command".to_string());
+        let msg = command_error_message(e);
         leptos::logging::error!("=== INVOKE {} FAILED (promise): {} ===", cmd, msg);
         msg
     })?;
@@ -240,6 +272,76 @@ pub async fn check_sync_capacity(
     .await
 }
 
+/// Break down how the space on a device is used, with its last sync.
+pub async fn analyze_device_usage(mount_point: &str) -> Result<DeviceUsage, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        mount_point: &'a str,
+    }
+
+    invoke("analyze_device_usage", Args { mount_point }).await
+}
+
+/// Get the saved settings (nickname, auto-sync) for a device.
+pub async fn get_device_profile(mount_point: &str) -> Result<DeviceProfile, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        mount_point: &'a str,
+    }
+
+    invoke("get_device_profile", Args { mount_point }).await
+}
+
+/// Save the settings (nickname, auto-sync) for a device.
+pub async fn set_device_profile(mount_point: &str, profile: &DeviceProfile) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        mount_point: &'a str,
+        profile: &'a DeviceProfile,
+    }
+
+    invoke(
+        "set_device_profile",
+        Args {
+            mount_point,
+            profile,
+        },
+    )
+    .await
+}
+
+/// Delete everything on a device except hidden and system files.
+///
+/// In simple mode, `pin` must be the parent PIN.
+pub async fn cleanup_device(mount_point: &str, pin: Option<&str>) -> Result<CleanupResult, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        mount_point: &'a str,
+        skip_hidden: bool,
+        skip_system_files: bool,
+        protected_patterns: Vec<String>,
+        verify_deletions: bool,
+        pin: Option<&'a str>,
+    }
+
+    invoke(
+        "cleanup_device",
+        Args {
+            mount_point,
+            skip_hidden: true,
+            skip_system_files: true,
+            protected_patterns: vec![],
+            verify_deletions: true,
+            pin,
+        },
+    )
+    .await
+}
+
 /// Start watching for USB device connections/disconnections.
 ///
 /// This starts a background task that polls for device changes and emits
@@ -280,6 +382,17 @@ pub struct EjectResult {
     pub success: bool,
 }
 
+/// Open a connected device's folder in the system file manager.
+pub async fn open_device_folder(mount_point: &str) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        mount_point: &'a str,
+    }
+
+    invoke("open_device_folder", Args { mount_point }).await
+}
+
 /// Safely eject a device.
 ///
 /// This unmounts the device and prepares it for safe removal.
@@ -406,13 +519,53 @@ pub async fn create_playlist(name: &str, source_url: Option<&str>) -> Result<Str
 }
 
 /// Delete a playlist.
-pub async fn delete_playlist(name: &str) -> Result<(), String> {
+///
+/// In simple mode, `pin` must be the parent PIN.
+pub async fn delete_playlist(name: &str, pin: Option<&str>) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+        pin: Option<&'a str>,
+    }
+
+    invoke("delete_playlist", Args { name, pin }).await
+}
+
+/// Move a playlist to the trash, so the deletion can be undone this session.
+///
+/// In simple mode, `pin` must be the parent PIN.
+pub async fn trash_playlist(name: &str, pin: Option<&str>) -> Result<TrashEntry, String> {
     #[derive(serde::Serialize)]
     struct Args<'a> {
         name: &'a str,
+        pin: Option<&'a str>,
+    }
+
+    invoke("trash_playlist", Args { name, pin }).await
+}
+
+/// Delete several playlists.
+///
+/// In simple mode, `pin` must be the parent PIN.
+pub async fn delete_playlists(names: &[String], pin: Option<&str>) -> Result<BatchResult, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        names: &'a [String],
+        pin: Option<&'a str>,
+    }
+
+    invoke("delete_playlists", Args { names, pin }).await
+}
+
+/// Add a tag to several playlists.
+pub async fn tag_playlists(names: &[String], tag: &str) -> Result<BatchResult, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        names: &'a [String],
+        tag: &'a str,
     }
 
-    invoke("delete_playlist", Args { name }).await
+    invoke("tag_playlists", Args { names, tag }).await
 }
 
 /// Sync a playlist to a device.
@@ -454,6 +607,319 @@ pub async fn get_playlist_tracks_fast(name: &str) -> Result<Vec<TrackInfo>, Stri
     invoke("get_playlist_tracks_fast", Args { name }).await
 }
 
+/// Get one page of tracks for a playlist, with metadata for that page only.
+pub async fn list_tracks_paged(
+    name: &str,
+    offset: usize,
+    limit: usize,
+) -> Result<TrackPage, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+        offset: usize,
+        limit: usize,
+    }
+
+    invoke(
+        "list_tracks_paged",
+        Args {
+            name,
+            offset,
+            limit,
+        },
+    )
+    .await
+}
+
+/// Search the library's playlists and tracks, with at most `limit` results
+/// per group.
+pub async fn search_library(query: &str, limit: usize) -> Result<LibrarySearchResults, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        query: &'a str,
+        limit: usize,
+    }
+
+    invoke("search_library", Args { query, limit }).await
+}
+
+/// Delete several tracks from a playlist.
+///
+/// In simple mode, `pin` must be the parent PIN.
+pub async fn delete_tracks(
+    playlist: &str,
+    file_names: &[String],
+    pin: Option<&str>,
+) -> Result<BatchResult, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        playlist: &'a str,
+        file_names: &'a [String],
+        pin: Option<&'a str>,
+    }
+
+    invoke(
+        "delete_tracks",
+        Args {
+            playlist,
+            file_names,
+            pin,
+        },
+    )
+    .await
+}
+
+/// Move several tracks of a playlist to the trash, so the deletion can be
+/// undone this session.
+///
+/// In simple mode, `pin` must be the parent PIN.
+pub async fn trash_tracks(
+    playlist: &str,
+    file_names: &[String],
+    pin: Option<&str>,
+) -> Result<TrashedTracks, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        playlist: &'a str,
+        file_names: &'a [String],
+        pin: Option<&'a str>,
+    }
+
+    invoke(
+        "trash_tracks",
+        Args {
+            playlist,
+            file_names,
+            pin,
+        },
+    )
+    .await
+}
+
+/// Put a trashed playlist or tracks back where they were.
+pub async fn restore_from_trash(id: TrashId) -> Result<TrashedItem, String> {
+    #[derive(serde::Serialize)]
+    struct Args {
+        id: TrashId,
+    }
+
+    invoke("restore_from_trash", Args { id }).await
+}
+
+/// Move several tracks to another playlist.
+pub async fn move_tracks(
+    from: &str,
+    to: &str,
+    file_names: &[String],
+) -> Result<BatchResult, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        from: &'a str,
+        to: &'a str,
+        file_names: &'a [String],
+    }
+
+    invoke(
+        "move_tracks",
+        Args {
+            from,
+            to,
+            file_names,
+        },
+    )
+    .await
+}
+
+/// Add a tag to several tracks in a playlist.
+pub async fn tag_tracks(
+    playlist: &str,
+    file_names: &[String],
+    tag: &str,
+) -> Result<BatchResult, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        playlist: &'a str,
+        file_names: &'a [String],
+        tag: &'a str,
+    }
+
+    invoke(
+        "tag_tracks",
+        Args {
+            playlist,
+            file_names,
+            tag,
+        },
+    )
+    .await
+}
+
+/// Save the playlist layout and sort order.
+pub async fn set_playlist_view(view: &PlaylistViewPreferences) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        view: &'a PlaylistViewPreferences,
+    }
+
+    invoke("set_playlist_view", Args { view }).await
+}
+
+/// Get the setting overrides for a playlist.
+pub async fn get_playlist_overrides(name: &str) -> Result<PlaylistOverrides, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("get_playlist_overrides", Args { name }).await
+}
+
+/// Save the setting overrides for a playlist.
+pub async fn set_playlist_overrides(
+    name: &str,
+    overrides: &PlaylistOverrides,
+) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+        overrides: &'a PlaylistOverrides,
+    }
+
+    invoke("set_playlist_overrides", Args { name, overrides }).await
+}
+
+/// Get the download profile of a playlist, if it has one.
+pub async fn get_playlist_download_profile(name: &str) -> Result<Option<DownloadProfile>, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("get_playlist_download_profile", Args { name }).await
+}
+
+/// Save the download profile of a playlist.
+pub async fn set_playlist_download_profile(
+    name: &str,
+    profile: &DownloadProfile,
+) -> Result<SavedPlaylistMetadata, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+        profile: &'a DownloadProfile,
+    }
+
+    invoke("set_playlist_download_profile", Args { name, profile }).await
+}
+
+/// Remove the download profile of a playlist.
+pub async fn delete_playlist_download_profile(name: &str) -> Result<SavedPlaylistMetadata, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("delete_playlist_download_profile", Args { name }).await
+}
+
+/// Get the refresh schedules of every playlist, next to run first.
+pub async fn get_refresh_schedules() -> Result<Vec<ScheduledRefresh>, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_refresh_schedules", Args {}).await
+}
+
+/// Set the refresh schedule of a playlist, or remove it when `schedule` is
+/// `None`.
+///
+/// In simple mode, `pin` must be the parent PIN for refreshes to remove
+/// tracks.
+pub async fn set_refresh_schedule(
+    playlist: &str,
+    schedule: Option<RefreshSchedule>,
+    remove_missing: bool,
+    pin: Option<&str>,
+) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        playlist: &'a str,
+        schedule: Option<RefreshSchedule>,
+        remove_missing: bool,
+        pin: Option<&'a str>,
+    }
+
+    invoke(
+        "set_refresh_schedule",
+        Args {
+            playlist,
+            schedule,
+            remove_missing,
+            pin,
+        },
+    )
+    .await
+}
+
+/// Get a URL the webview can stream a downloaded track from.
+///
+/// The backend only allows tracks listed in the playlist to be read.
+pub async fn get_track_preview_url(playlist_name: &str, file_name: &str) -> Result<String, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        playlist_name: &'a str,
+        file_name: &'a str,
+    }
+
+    let path: String = invoke(
+        "prepare_track_preview",
+        Args {
+            playlist_name,
+            file_name,
+        },
+    )
+    .await?;
+    Ok(tauri_convert_file_src(&path))
+}
+
+/// Get a displayable URL for a playlist's cached cover thumbnail.
+///
+/// Returns `None` when the playlist has no cover or it could not be fetched.
+pub async fn get_playlist_cover_url(playlist_name: &str) -> Result<Option<String>, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        playlist_name: &'a str,
+    }
+
+    let path: Option<String> = invoke("prepare_playlist_cover", Args { playlist_name }).await?;
+    Ok(path.map(|path| tauri_convert_file_src(&path)))
+}
+
+/// Get a displayable URL for a thumbnail URL or local image.
+///
+/// Remote thumbnails are cached on first use, so they load without the
+/// network afterwards.
+pub async fn get_cached_thumbnail_url(path_or_url: &str) -> Result<String, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        path_or_url: &'a str,
+    }
+
+    let thumbnail: CachedThumbnail = invoke("get_cached_thumbnail", Args { path_or_url }).await?;
+    Ok(match thumbnail {
+        CachedThumbnail::File(path) => tauri_convert_file_src(&path),
+        CachedThumbnail::DataUrl(url) => url,
+    })
+}
+
 /// Extract MP3 metadata (ID3 tags) from a single file.
 ///
 /// Returns metadata including title, artist, album, duration, track number, etc.
@@ -557,17 +1023,73 @@ pub async fn rename_playlist(old_name: &str, new_name: &str) -> Result<(), Strin
     invoke("rename_playlist", Args { old_name, new_name }).await
 }
 
-/// Check if a playlist exists.
-pub async fn playlist_exists(name: &str) -> Result<bool, String> {
+/// Open a playlist's folder in the system file manager.
+pub async fn open_playlist_folder(name: &str) -> Result<(), String> {
     #[derive(serde::Serialize)]
     struct Args<'a> {
         name: &'a str,
     }
 
-    invoke("playlist_exists", Args { name }).await
+    invoke("open_playlist_folder", Args { name }).await
 }
 
-/// Ensure a playlist folder has proper structure.
+/// Show a library file or folder in the system file manager, selected.
+///
+/// Only paths inside the playlists directory can be revealed.
+pub async fn reveal_in_file_manager(path: &str) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        path: &'a str,
+    }
+
+    invoke("reveal_in_file_manager", Args { path }).await
+}
+
+/// Export a playlist as an M3U file in the downloads folder.
+///
+/// Returns the path of the written file.
+pub async fn export_playlist_m3u(name: &str) -> Result<String, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("export_playlist_m3u", Args { name }).await
+}
+
+/// Get the videos in a playlist's download archive, oldest first.
+pub async fn get_download_archive(name: &str) -> Result<Vec<ArchivedVideo>, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("get_download_archive", Args { name }).await
+}
+
+/// Clear a playlist's download archive.
+///
+/// Returns how many videos were removed from the archive.
+pub async fn reset_download_archive(name: &str) -> Result<usize, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("reset_download_archive", Args { name }).await
+}
+
+/// Check if a playlist exists.
+pub async fn playlist_exists(name: &str) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("playlist_exists", Args { name }).await
+}
+
+/// Ensure a playlist folder has proper structure.
 ///
 /// Creates the metadata file if it doesn't exist.
 pub async fn ensure_playlist_structure(name: &str) -> Result<(), String> {
@@ -634,107 +1156,1142 @@ pub async fn update_playlist_metadata(
     .await
 }
 
-/// Refresh the cached track count and total size for a playlist.
-///
-/// Scans the playlist folder and updates the `track_count` and `total_size_bytes`
-/// fields in the metadata file.
-pub async fn refresh_playlist_stats(name: &str) -> Result<SavedPlaylistMetadata, String> {
+/// Refresh the cached track count and total size for a playlist.
+///
+/// Scans the playlist folder and updates the `track_count` and `total_size_bytes`
+/// fields in the metadata file.
+pub async fn refresh_playlist_stats(name: &str) -> Result<SavedPlaylistMetadata, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("refresh_playlist_stats", Args { name }).await
+}
+
+// =============================================================================
+// Task Management API
+// =============================================================================
+
+/// Get the status of a running task.
+///
+/// Returns the status as a string (e.g., "Running", "Completed", "Failed(error)", "Cancelled").
+pub async fn get_task_status(task_id: TaskId) -> Result<Option<String>, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        task_id: TaskId,
+    }
+
+    invoke("get_task_status", Args { task_id }).await
+}
+
+/// Get all running tasks count by category.
+pub async fn get_running_tasks() -> Result<Vec<TaskCount>, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    let result: Vec<(String, usize)> = invoke("get_running_tasks", Args {}).await?;
+    Ok(result
+        .into_iter()
+        .map(|(category, count)| TaskCount { category, count })
+        .collect())
+}
+
+/// Cancel a running task.
+///
+/// Returns `true` if the task was successfully cancelled, `false` otherwise.
+pub async fn cancel_task(task_id: TaskId) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        task_id: TaskId,
+    }
+
+    invoke("cancel_task", Args { task_id }).await
+}
+
+/// Start a maintenance operation in the background.
+///
+/// Returns the task ID; progress is reported as `maintenance` task events and
+/// the operation can be stopped with [`cancel_task`].
+pub async fn start_maintenance(operation: &MaintenanceOperation) -> Result<TaskId, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        operation: &'a MaintenanceOperation,
+    }
+
+    invoke("start_maintenance", Args { operation }).await
+}
+
+/// Cancel every running task: downloads, syncs, queued downloads and device cleanups.
+pub async fn cancel_all_tasks() -> Result<CancelledTasks, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("cancel_all_tasks", Args {}).await
+}
+
+/// Get tasks interrupted by a crash or forced quit in a previous session.
+pub async fn get_interrupted_tasks() -> Result<Vec<PersistedTask>, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_interrupted_tasks", Args {}).await
+}
+
+/// Resume an interrupted task.
+///
+/// Returns the new task ID, or the new queue item ID for queue downloads.
+pub async fn resume_interrupted_task(id: u64) -> Result<u64, String> {
+    #[derive(serde::Serialize)]
+    struct Args {
+        id: u64,
+    }
+
+    invoke("resume_interrupted_task", Args { id }).await
+}
+
+/// Mark an interrupted task as failed.
+pub async fn dismiss_interrupted_task(id: u64) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args {
+        id: u64,
+    }
+
+    invoke("dismiss_interrupted_task", Args { id }).await
+}
+
+// =============================================================================
+// Download Queue API
+// =============================================================================
+
+/// Event names for download queue events.
+pub mod queue_events {
+    /// Emitted when an item is added to the queue.
+    pub const QUEUE_ITEM_ADDED: &str = "queue-item-added";
+    /// Emitted when an item starts downloading.
+    pub const QUEUE_ITEM_STARTED: &str = "queue-item-started";
+    /// Emitted for item progress updates.
+    pub const QUEUE_ITEM_PROGRESS: &str = "queue-item-progress";
+    /// Emitted when an item finishes downloading.
+    pub const QUEUE_ITEM_COMPLETED: &str = "queue-item-completed";
+    /// Emitted when a dry run item finishes, with what it would download.
+    pub const QUEUE_ITEM_PLANNED: &str = "queue-item-planned";
+    /// Emitted when an item fails.
+    pub const QUEUE_ITEM_FAILED: &str = "queue-item-failed";
+    /// Emitted when an item is cancelled.
+    pub const QUEUE_ITEM_CANCELLED: &str = "queue-item-cancelled";
+    /// Emitted when an item is removed from the queue.
+    pub const QUEUE_ITEM_REMOVED: &str = "queue-item-removed";
+    /// Emitted when pending items are reordered, with their new order.
+    pub const QUEUE_REORDERED: &str = "queue-reordered";
+    /// Emitted when the queue is paused.
+    pub const QUEUE_PAUSED: &str = "queue-paused";
+    /// Emitted when the queue is resumed.
+    pub const QUEUE_RESUMED: &str = "queue-resumed";
+}
+
+/// Get every item in the download queue.
+pub async fn queue_get_all_items() -> Result<Vec<QueueItem>, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("queue_get_all_items", Args {}).await
+}
+
+/// Get download queue statistics.
+pub async fn queue_get_stats() -> Result<QueueStats, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("queue_get_stats", Args {}).await
+}
+
+/// Check whether the download queue is paused.
+pub async fn queue_is_paused() -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("queue_is_paused", Args {}).await
+}
+
+/// Pause the download queue. Running downloads finish.
+pub async fn queue_pause() -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("queue_pause", Args {}).await
+}
+
+/// Resume the download queue.
+pub async fn queue_resume() -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("queue_resume", Args {}).await
+}
+
+/// Remove an item from the queue.
+///
+/// Returns `true` if the item was removed.
+pub async fn queue_remove_item(item_id: QueueItemId) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        item_id: QueueItemId,
+    }
+
+    invoke("queue_remove_item", Args { item_id }).await
+}
+
+/// Cancel a pending or downloading item.
+///
+/// Returns `true` if the item was cancelled.
+pub async fn queue_cancel_item(item_id: QueueItemId) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        item_id: QueueItemId,
+    }
+
+    invoke("queue_cancel_item", Args { item_id }).await
+}
+
+/// Retry a failed or cancelled item.
+///
+/// Returns `true` if the item was queued again.
+pub async fn queue_retry_item(item_id: QueueItemId) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        item_id: QueueItemId,
+    }
+
+    invoke("queue_retry_item", Args { item_id }).await
+}
+
+/// Move an item to the front of the queue.
+///
+/// Returns `true` if the item was moved.
+pub async fn queue_move_to_front(item_id: QueueItemId) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        item_id: QueueItemId,
+    }
+
+    invoke("queue_move_to_front", Args { item_id }).await
+}
+
+/// Put pending queue items in the order of `ordered_ids`, within their
+/// priorities.
+///
+/// Returns `true` if the items were reordered.
+pub async fn queue_reorder_items(ordered_ids: &[QueueItemId]) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        ordered_ids: &'a [QueueItemId],
+    }
+
+    invoke("queue_reorder_items", Args { ordered_ids }).await
+}
+
+/// Move a pending queue item to `new_position` among the pending items of
+/// its priority, 0 being the first to start.
+///
+/// Returns `true` if the item was moved.
+pub async fn queue_move_item(item_id: QueueItemId, new_position: usize) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        item_id: QueueItemId,
+        new_position: usize,
+    }
+
+    invoke(
+        "queue_move_item",
+        Args {
+            item_id,
+            new_position,
+        },
+    )
+    .await
+}
+
+/// Set the priority of a queue item.
+///
+/// Returns `true` if the priority was changed.
+pub async fn queue_set_priority(
+    item_id: QueueItemId,
+    priority: DownloadPriority,
+) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        item_id: QueueItemId,
+        priority: &'a str,
+    }
+
+    invoke(
+        "queue_set_priority",
+        Args {
+            item_id,
+            priority: priority.as_str(),
+        },
+    )
+    .await
+}
+
+/// Set whether a pending queue item splits videos with chapters into one
+/// track per chapter.
+///
+/// Returns `true` if the item was changed.
+pub async fn queue_set_split_by_chapters(
+    item_id: QueueItemId,
+    split: bool,
+) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        item_id: QueueItemId,
+        split: bool,
+    }
+
+    invoke("queue_set_split_by_chapters", Args { item_id, split }).await
+}
+
+/// Remove completed, failed and cancelled items from the queue.
+///
+/// Returns the number of items removed.
+pub async fn queue_clear_finished() -> Result<usize, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("queue_clear_finished", Args {}).await
+}
+
+// =============================================================================
+// Configuration API
+// =============================================================================
+
+/// Event names for configuration events.
+pub mod config_events {
+    /// Emitted after any configuration change, with the new configuration.
+    pub const CONFIG_CHANGED: &str = "config-changed";
+}
+
+/// Listen to config-changed events.
+///
+/// Returns a function to stop listening.
+pub async fn listen_to_config_changed<F>(handler: F) -> Result<js_sys::Function, String>
+where
+    F: Fn(ConfigChangedPayload) + 'static,
+{
+    listen_to_event(config_events::CONFIG_CHANGED, move |value| {
+        if let Ok(payload) =
+            js_sys::Reflect::get(&value, &wasm_bindgen::JsValue::from_str("payload"))
+            && let Ok(changed) = serde_wasm_bindgen::from_value::<ConfigChangedPayload>(payload)
+        {
+            handler(changed);
+        }
+    })
+    .await
+}
+
+/// Get the current application configuration.
+pub async fn get_config() -> Result<AppConfig, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_config", Args {}).await
+}
+
+/// Update the application configuration.
+pub async fn update_config(config: &AppConfig) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        config: &'a AppConfig,
+    }
+
+    invoke("update_config", Args { config }).await
+}
+
+/// Save the interface state to restore on the next start.
+pub async fn set_ui_state(ui_state: &UiState) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        ui_state: &'a UiState,
+    }
+
+    invoke("set_ui_state", Args { ui_state }).await
+}
+
+/// Get the current playlists storage directory.
+pub async fn get_storage_directory() -> Result<String, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_storage_directory", Args {}).await
+}
+
+/// Set the playlists storage directory.
+///
+/// With `move_data`, existing playlists are copied to the new location and
+/// verified before switching; the migration result is returned.
+pub async fn set_storage_directory(
+    path: &str,
+    move_data: bool,
+) -> Result<Option<StorageMigrationResult>, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        path: &'a str,
+        move_data: bool,
+    }
+
+    invoke("set_storage_directory", Args { path, move_data }).await
+}
+
+/// Get the default storage directory.
+pub async fn get_default_storage_directory() -> Result<String, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_default_storage_directory", Args {}).await
+}
+
+/// List the names of saved configuration profiles.
+pub async fn list_config_profiles() -> Result<Vec<String>, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("list_config_profiles", Args {}).await
+}
+
+/// Save the current settings as a named configuration profile.
+pub async fn save_config_profile(name: &str) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("save_config_profile", Args { name }).await
+}
+
+/// Delete a saved configuration profile.
+pub async fn delete_config_profile(name: &str) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("delete_config_profile", Args { name }).await
+}
+
+/// Switch to a saved configuration profile, returning the new configuration.
+pub async fn switch_profile(name: &str) -> Result<AppConfig, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("switch_profile", Args { name }).await
+}
+
+/// Export the current configuration to a file.
+pub async fn export_config(path: &str, include_machine_paths: bool) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        path: &'a str,
+        include_machine_paths: bool,
+    }
+
+    invoke(
+        "export_config",
+        Args {
+            path,
+            include_machine_paths,
+        },
+    )
+    .await
+}
+
+/// Import a configuration file, or only preview the changes with `dry_run`.
+pub async fn import_config(path: &str, dry_run: bool) -> Result<ConfigImportPreview, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        path: &'a str,
+        dry_run: bool,
+    }
+
+    invoke("import_config", Args { path, dry_run }).await
+}
+
+// =============================================================================
+// Storage API
+// =============================================================================
+
+/// Get the library's disk usage and configured limits.
+pub async fn get_library_space() -> Result<LibrarySpace, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_library_space", Args {}).await
+}
+
+/// Get the library size per playlist, cache usage and free disk space.
+pub async fn get_storage_overview() -> Result<StorageOverview, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_storage_overview", Args {}).await
+}
+
+/// Move a playlist out of the library into the archive directory.
+///
+/// Returns the archived folder.
+pub async fn archive_playlist(name: &str) -> Result<String, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("archive_playlist", Args { name }).await
+}
+
+// =============================================================================
+// Onboarding API
+// =============================================================================
+
+/// Get the first-run setup state, including detected defaults.
+pub async fn get_onboarding_state() -> Result<OnboardingState, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_onboarding_state", Args {}).await
+}
+
+/// Finish first-run setup, returning the updated configuration.
+pub async fn complete_onboarding(
+    playlists_directory: Option<&str>,
+    preferred_device: Option<&str>,
+    download_quality: Option<DownloadQuality>,
+) -> Result<AppConfig, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        playlists_directory: Option<&'a str>,
+        preferred_device: Option<&'a str>,
+        download_quality: Option<DownloadQuality>,
+    }
+
+    invoke(
+        "complete_onboarding",
+        Args {
+            playlists_directory,
+            preferred_device,
+            download_quality,
+        },
+    )
+    .await
+}
+
+// =============================================================================
+// Notifications API
+// =============================================================================
+
+/// Show a test OS notification, regardless of notification preferences.
+pub async fn send_test_notification() -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("send_test_notification", Args {}).await
+}
+
+/// Post a test message to the webhook with `webhook` settings, regardless of
+/// whether it is enabled.
+pub async fn send_test_webhook(webhook: &WebhookConfig) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        webhook: &'a WebhookConfig,
+    }
+
+    invoke("send_test_webhook", Args { webhook }).await
+}
+
+// =============================================================================
+// Secrets API
+// =============================================================================
+
+/// Store a sensitive setting in the secrets store.
+pub async fn set_secret(key: &str, value: &str) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        key: &'a str,
+        value: &'a str,
+    }
+
+    invoke("set_secret", Args { key, value }).await
+}
+
+/// Delete a sensitive setting from the secrets store.
+pub async fn delete_secret(key: &str) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        key: &'a str,
+    }
+
+    invoke("delete_secret", Args { key }).await
+}
+
+/// Check whether a sensitive setting is set.
+pub async fn has_secret(key: &str) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        key: &'a str,
+    }
+
+    invoke("has_secret", Args { key }).await
+}
+
+/// Get the name of the active secrets backend.
+pub async fn get_secrets_backend() -> Result<String, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_secrets_backend", Args {}).await
+}
+
+// =============================================================================
+// Simple Mode API
+// =============================================================================
+
+/// Get whether simple mode is on and a parent PIN is set.
+pub async fn get_parental_status() -> Result<ParentalStatus, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_parental_status", Args {}).await
+}
+
+/// Set or change the parent PIN. Changing an existing PIN needs `current_pin`.
+pub async fn set_parent_pin(current_pin: Option<&str>, new_pin: &str) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        current_pin: Option<&'a str>,
+        new_pin: &'a str,
+    }
+
+    invoke(
+        "set_parent_pin",
+        Args {
+            current_pin,
+            new_pin,
+        },
+    )
+    .await
+}
+
+/// Remove the parent PIN, turning simple mode off.
+pub async fn clear_parent_pin(pin: &str) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        pin: &'a str,
+    }
+
+    invoke("clear_parent_pin", Args { pin }).await
+}
+
+/// Turn simple mode on or off. Needs the parent PIN either way.
+pub async fn set_simple_mode(enabled: bool, pin: &str) -> Result<AppConfig, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        enabled: bool,
+        pin: &'a str,
+    }
+
+    invoke("set_simple_mode", Args { enabled, pin }).await
+}
+
+// =============================================================================
+// Power API
+// =============================================================================
+
+/// Get the power state and whether heavy background work is paused.
+pub async fn get_power_status() -> Result<PowerStatus, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_power_status", Args {}).await
+}
+
+// =============================================================================
+// Localization API
+// =============================================================================
+
+/// Get the translation catalog for backend messages in `locale` (e.g. `fr`),
+/// or in the configured language when `None`.
+pub async fn get_translation_catalog(locale: Option<&str>) -> Result<TranslationCatalog, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        locale: Option<&'a str>,
+    }
+
+    invoke("get_translation_catalog", Args { locale }).await
+}
+
+// =============================================================================
+// Tray API
+// =============================================================================
+
+/// Event names for tray and background operation events.
+pub mod tray_events {
+    /// Emitted while a quit waits for active tasks, with the tasks still running.
+    pub const QUIT_PENDING: &str = "quit-pending";
+}
+
+/// Quit once active tasks have finished.
+pub async fn quit_when_tasks_finish() -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("quit_when_tasks_finish", Args {}).await
+}
+
+/// Check whether the app is running in the background (main window hidden).
+pub async fn is_in_background() -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("is_in_background", Args {}).await
+}
+
+/// Listen to pending quit events, with the tasks still being waited for.
+///
+/// Returns a function to stop listening.
+pub async fn listen_to_quit_pending<F>(handler: F) -> Result<js_sys::Function, String>
+where
+    F: Fn(Vec<PersistedTask>) + 'static,
+{
+    listen_to_event(tray_events::QUIT_PENDING, move |value| {
+        if let Ok(payload) =
+            js_sys::Reflect::get(&value, &wasm_bindgen::JsValue::from_str("payload"))
+            && let Ok(tasks) = serde_wasm_bindgen::from_value::<Vec<PersistedTask>>(payload)
+        {
+            handler(tasks);
+        }
+    })
+    .await
+}
+
+// =============================================================================
+// Deep Link API
+// =============================================================================
+
+/// Event names for `youtun4://` deep link events.
+pub mod deep_link_events {
+    /// A link was received but needs a playlist name from the user.
+    pub const DEEP_LINK_RECEIVED: &str = "deep-link-received";
+    /// A link was added to the download queue.
+    pub const DEEP_LINK_QUEUED: &str = "deep-link-queued";
+    /// A link could not be handled.
+    pub const DEEP_LINK_REJECTED: &str = "deep-link-rejected";
+}
+
+/// Queue a playlist download into a library playlist, creating it if needed.
+///
+/// Returns the queue item ID.
+pub async fn queue_add_to_playlist(
+    url: &str,
+    playlist_name: &str,
+    priority: Option<&str>,
+) -> Result<u64, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        url: &'a str,
+        playlist_name: &'a str,
+        priority: Option<&'a str>,
+    }
+
+    invoke(
+        "queue_add_to_playlist",
+        Args {
+            url,
+            playlist_name,
+            priority,
+        },
+    )
+    .await
+}
+
+/// Queue a single video download into a library playlist, creating it if
+/// needed.
+///
+/// Returns the queue item ID.
+pub async fn queue_add_video_to_playlist(
+    url: &str,
+    playlist_name: &str,
+    priority: Option<&str>,
+) -> Result<u64, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        url: &'a str,
+        playlist_name: &'a str,
+        priority: Option<&'a str>,
+    }
+
+    invoke(
+        "queue_add_video_to_playlist",
+        Args {
+            url,
+            playlist_name,
+            priority,
+        },
+    )
+    .await
+}
+
+/// Listen to deep links that need a target playlist.
+///
+/// Returns a function to stop listening.
+pub async fn listen_to_deep_link_received<F>(handler: F) -> Result<js_sys::Function, String>
+where
+    F: Fn(DeepLinkRequest) + 'static,
+{
+    listen_to_event(deep_link_events::DEEP_LINK_RECEIVED, move |value| {
+        if let Ok(payload) =
+            js_sys::Reflect::get(&value, &wasm_bindgen::JsValue::from_str("payload"))
+            && let Ok(request) = serde_wasm_bindgen::from_value::<DeepLinkRequest>(payload)
+        {
+            handler(request);
+        }
+    })
+    .await
+}
+
+/// Listen to deep links added to the download queue.
+///
+/// Returns a function to stop listening.
+pub async fn listen_to_deep_link_queued<F>(handler: F) -> Result<js_sys::Function, String>
+where
+    F: Fn(DeepLinkQueued) + 'static,
+{
+    listen_to_event(deep_link_events::DEEP_LINK_QUEUED, move |value| {
+        if let Ok(payload) =
+            js_sys::Reflect::get(&value, &wasm_bindgen::JsValue::from_str("payload"))
+            && let Ok(queued) = serde_wasm_bindgen::from_value::<DeepLinkQueued>(payload)
+        {
+            handler(queued);
+        }
+    })
+    .await
+}
+
+/// Listen to deep links that could not be handled.
+///
+/// Returns a function to stop listening.
+pub async fn listen_to_deep_link_rejected<F>(handler: F) -> Result<js_sys::Function, String>
+where
+    F: Fn(DeepLinkRejected) + 'static,
+{
+    listen_to_event(deep_link_events::DEEP_LINK_REJECTED, move |value| {
+        if let Ok(payload) =
+            js_sys::Reflect::get(&value, &wasm_bindgen::JsValue::from_str("payload"))
+            && let Ok(rejected) = serde_wasm_bindgen::from_value::<DeepLinkRejected>(payload)
+        {
+            handler(rejected);
+        }
+    })
+    .await
+}
+
+// =============================================================================
+// Updates API
+// =============================================================================
+
+/// Event names for update events.
+pub mod update_events {
+    /// Emitted when a newer version is available on the configured channel.
+    pub const UPDATE_AVAILABLE: &str = "update-available";
+    /// Emitted while an update downloads.
+    pub const UPDATE_DOWNLOAD_PROGRESS: &str = "update-download-progress";
+}
+
+/// Check for an update on the configured release channel.
+pub async fn check_for_updates() -> Result<Option<UpdateInfo>, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("check_for_updates", Args {}).await
+}
+
+/// Download and install the available update, then restart the app.
+///
+/// Returns `false` if no update is available.
+pub async fn install_update() -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("install_update", Args {}).await
+}
+
+/// Listen to update-available events.
+///
+/// Returns a function to stop listening.
+pub async fn listen_to_update_available<F>(handler: F) -> Result<js_sys::Function, String>
+where
+    F: Fn(UpdateInfo) + 'static,
+{
+    listen_to_event(update_events::UPDATE_AVAILABLE, move |value| {
+        if let Ok(payload) =
+            js_sys::Reflect::get(&value, &wasm_bindgen::JsValue::from_str("payload"))
+            && let Ok(info) = serde_wasm_bindgen::from_value::<UpdateInfo>(payload)
+        {
+            handler(info);
+        }
+    })
+    .await
+}
+
+/// Listen to update download progress events.
+///
+/// Returns a function to stop listening.
+pub async fn listen_to_update_download_progress<F>(handler: F) -> Result<js_sys::Function, String>
+where
+    F: Fn(UpdateDownloadProgress) + 'static,
+{
+    listen_to_event(update_events::UPDATE_DOWNLOAD_PROGRESS, move |value| {
+        if let Ok(payload) =
+            js_sys::Reflect::get(&value, &wasm_bindgen::JsValue::from_str("payload"))
+            && let Ok(progress) = serde_wasm_bindgen::from_value::<UpdateDownloadProgress>(payload)
+        {
+            handler(progress);
+        }
+    })
+    .await
+}
+
+// =============================================================================
+// Logs API
+// =============================================================================
+
+/// Get the most recent log entries, oldest first.
+///
+/// `level_filter` keeps only entries at that level or more severe.
+pub async fn get_recent_logs(
+    lines: usize,
+    level_filter: Option<&str>,
+) -> Result<Vec<LogEntry>, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        lines: usize,
+        level_filter: Option<&'a str>,
+    }
+
+    invoke(
+        "get_recent_logs",
+        Args {
+            lines,
+            level_filter,
+        },
+    )
+    .await
+}
+
+/// Change the application log level until it restarts.
+pub async fn set_log_level(level: &str) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        level: &'a str,
+    }
+
+    invoke("set_log_level", Args { level }).await
+}
+
+/// Get the directory log files are written to.
+pub async fn get_log_directory() -> Result<String, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_log_directory", Args {}).await
+}
+
+/// Open the log directory in the system file manager.
+pub async fn open_log_directory() -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("open_log_directory", Args {}).await
+}
+
+/// Copy log text to the clipboard.
+pub async fn copy_logs_to_clipboard(text: &str) -> Result<(), String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        text: &'a str,
+    }
+
+    invoke("copy_logs_to_clipboard", Args { text }).await
+}
+
+/// Export a diagnostics zip (logs, redacted config, cache, queue and device
+/// state) to `path` for attaching to a bug report.
+///
+/// Returns the path of the written archive.
+pub async fn export_diagnostics(path: &str) -> Result<String, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        path: &'a str,
+    }
+
+    invoke("export_diagnostics", Args { path }).await
+}
+
+/// Get the performance counters for hashing, transfers and playlist scraping.
+pub async fn get_performance_metrics() -> Result<PerformanceMetrics, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("get_performance_metrics", Args {}).await
+}
+
+/// Reset the performance counters.
+pub async fn reset_performance_metrics() -> Result<(), String> {
     #[derive(serde::Serialize)]
-    struct Args<'a> {
-        name: &'a str,
-    }
+    struct Args {}
 
-    invoke("refresh_playlist_stats", Args { name }).await
+    invoke("reset_performance_metrics", Args {}).await
 }
 
 // =============================================================================
-// Task Management API
+// Crash Report API
 // =============================================================================
 
-/// Get the status of a running task.
-///
-/// Returns the status as a string (e.g., "Running", "Completed", "Failed(error)", "Cancelled").
-pub async fn get_task_status(task_id: TaskId) -> Result<Option<String>, String> {
+/// Get the report of the last crash, if it has not been dismissed.
+pub async fn get_last_crash_report() -> Result<Option<CrashReport>, String> {
     #[derive(serde::Serialize)]
-    #[serde(rename_all = "camelCase")]
-    struct Args {
-        task_id: TaskId,
-    }
+    struct Args {}
 
-    invoke("get_task_status", Args { task_id }).await
+    invoke("get_last_crash_report", Args {}).await
 }
 
-/// Get all running tasks count by category.
-pub async fn get_running_tasks() -> Result<Vec<TaskCount>, String> {
+/// Dismiss the last crash report.
+pub async fn dismiss_crash_report() -> Result<bool, String> {
     #[derive(serde::Serialize)]
     struct Args {}
 
-    let result: Vec<(String, usize)> = invoke("get_running_tasks", Args {}).await?;
-    Ok(result
-        .into_iter()
-        .map(|(category, count)| TaskCount { category, count })
-        .collect())
+    invoke("dismiss_crash_report", Args {}).await
 }
 
-/// Cancel a running task.
+/// Record a frontend panic.
 ///
-/// Returns `true` if the task was successfully cancelled, `false` otherwise.
-pub async fn cancel_task(task_id: TaskId) -> Result<bool, String> {
+/// Fire-and-forget: this runs from the panic hook, so it doesn't wait for
+/// the result and never runs more Rust code after the panic.
+pub fn report_frontend_crash(message: &str, location: Option<&str>, stack: Option<&str>) {
     #[derive(serde::Serialize)]
-    #[serde(rename_all = "camelCase")]
-    struct Args {
-        task_id: TaskId,
+    struct Args<'a> {
+        message: &'a str,
+        location: Option<&'a str>,
+        stack: Option<&'a str>,
     }
 
-    invoke("cancel_task", Args { task_id }).await
+    if !is_tauri_available() {
+        return;
+    }
+    if let Ok(args) = serde_wasm_bindgen::to_value(&Args {
+        message,
+        location,
+        stack,
+    }) {
+        let _ = tauri_invoke("report_frontend_crash", args);
+    }
 }
 
 // =============================================================================
-// Configuration API
+// History API
 // =============================================================================
 
-/// Get the current application configuration.
-pub async fn get_config() -> Result<AppConfig, String> {
+/// Get the download and sync history matching `filter`, newest first, with
+/// the bytes downloaded per week.
+pub async fn get_history(filter: &HistoryFilter) -> Result<HistoryReport, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        filter: &'a HistoryFilter,
+    }
+
+    invoke("get_history", Args { filter }).await
+}
+
+/// Get totals over every download in the history: bytes downloaded overall
+/// and this month, videos downloaded and failed, and the average speed.
+pub async fn get_history_stats() -> Result<HistoryStats, String> {
     #[derive(serde::Serialize)]
     struct Args {}
 
-    invoke("get_config", Args {}).await
+    invoke("get_history_stats", Args {}).await
 }
 
-/// Update the application configuration.
-pub async fn update_config(config: &AppConfig) -> Result<(), String> {
+/// Remove the history entries older than `older_than_days` days, returning
+/// how many were removed.
+pub async fn prune_history(older_than_days: u32) -> Result<usize, String> {
     #[derive(serde::Serialize)]
-    struct Args<'a> {
-        config: &'a AppConfig,
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        older_than_days: u32,
     }
 
-    invoke("update_config", Args { config }).await
+    invoke("prune_history", Args { older_than_days }).await
 }
 
-/// Get the current playlists storage directory.
-pub async fn get_storage_directory() -> Result<String, String> {
+/// Remove every history entry, returning how many were removed.
+pub async fn clear_history() -> Result<usize, String> {
     #[derive(serde::Serialize)]
     struct Args {}
 
-    invoke("get_storage_directory", Args {}).await
+    invoke("clear_history", Args {}).await
 }
 
-/// Set the playlists storage directory.
-pub async fn set_storage_directory(path: &str) -> Result<(), String> {
+// =============================================================================
+// Backup API
+// =============================================================================
+
+/// Back up the configuration and playlist metadata to a zip archive at
+/// `path`, with the audio files too if `include_audio` is set.
+pub async fn backup_library(path: &str, include_audio: bool) -> Result<BackupManifest, String> {
     #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
     struct Args<'a> {
         path: &'a str,
+        include_audio: bool,
     }
 
-    invoke("set_storage_directory", Args { path }).await
+    invoke(
+        "backup_library",
+        Args {
+            path,
+            include_audio,
+        },
+    )
+    .await
 }
 
-/// Get the default storage directory.
-pub async fn get_default_storage_directory() -> Result<String, String> {
+/// Verify and restore a library backup, applying its configuration.
+pub async fn restore_library(path: &str) -> Result<BackupManifest, String> {
     #[derive(serde::Serialize)]
-    struct Args {}
+    struct Args<'a> {
+        path: &'a str,
+    }
 
-    invoke("get_default_storage_directory", Args {}).await
+    invoke("restore_library", Args { path }).await
 }
 
 // =============================================================================
@@ -1273,6 +2830,9 @@ pub struct SyncOrchestratorResult {
 /// - Device cleanup before transfer
 /// - Detailed progress tracking per phase
 ///
+/// Files in `exclusions`, relative to the mount point, are kept on the
+/// device as they are.
+///
 /// Subscribe to sync orchestrator events to receive progress updates.
 pub async fn start_orchestrated_sync(
     playlists: Vec<String>,
@@ -1280,6 +2840,7 @@ pub async fn start_orchestrated_sync(
     cleanup_enabled: bool,
     verify_integrity: bool,
     skip_existing: bool,
+    exclusions: Vec<String>,
 ) -> Result<TaskId, String> {
     #[derive(serde::Serialize)]
     #[serde(rename_all = "camelCase")]
@@ -1289,6 +2850,7 @@ pub async fn start_orchestrated_sync(
         cleanup_enabled: bool,
         verify_integrity: bool,
         skip_existing: bool,
+        exclusions: Vec<String>,
     }
 
     invoke(
@@ -1299,6 +2861,35 @@ pub async fn start_orchestrated_sync(
             cleanup_enabled,
             verify_integrity,
             skip_existing,
+            exclusions,
+        },
+    )
+    .await
+}
+
+/// Preview what an orchestrated sync would change on the device.
+pub async fn preview_sync(
+    playlists: Vec<String>,
+    device_mount_point: &str,
+    cleanup_enabled: bool,
+    skip_existing: bool,
+) -> Result<SyncPreview, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        playlists: Vec<String>,
+        device_mount_point: &'a str,
+        cleanup_enabled: bool,
+        skip_existing: bool,
+    }
+
+    invoke(
+        "preview_sync",
+        Args {
+            playlists,
+            device_mount_point,
+            cleanup_enabled,
+            skip_existing,
         },
     )
     .await
@@ -1443,6 +3034,24 @@ pub async fn extract_youtube_playlist_id(url: &str) -> Result<String, String> {
     invoke("extract_youtube_playlist_id", Args { url }).await
 }
 
+/// Find and classify the URLs in dropped text or a `text/uri-list`.
+pub async fn parse_dropped_urls(text: &str) -> Result<Vec<UrlCandidate>, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        text: &'a str,
+    }
+
+    invoke("parse_dropped_urls", Args { text }).await
+}
+
+/// Find and classify the URLs in the clipboard.
+pub async fn read_clipboard_urls() -> Result<Vec<UrlCandidate>, String> {
+    #[derive(serde::Serialize)]
+    struct Args {}
+
+    invoke("read_clipboard_urls", Args {}).await
+}
+
 // =============================================================================
 // YouTube Download API
 // =============================================================================
@@ -1459,6 +3068,12 @@ pub mod youtube_events {
     pub const DOWNLOAD_FAILED: &str = "youtube-download-failed";
     /// Event emitted when a download is cancelled.
     pub const DOWNLOAD_CANCELLED: &str = "youtube-download-cancelled";
+    /// Event emitted when a download is paused.
+    pub const DOWNLOAD_PAUSED: &str = "youtube-download-paused";
+    /// Event emitted when a paused download is resumed.
+    pub const DOWNLOAD_RESUMED: &str = "youtube-download-resumed";
+    /// Event emitted after each page of a playlist's video list is fetched.
+    pub const PLAYLIST_FETCH_PROGRESS: &str = "youtube-playlist-fetch-progress";
 }
 
 /// Check if yt-dlp is available on the system.
@@ -1471,6 +3086,123 @@ pub async fn check_yt_dlp_available() -> Result<String, String> {
     invoke("check_yt_dlp_available", Args {}).await
 }
 
+/// File name `template` gives an example track, or why the template is
+/// invalid.
+pub async fn preview_filename_template(template: &str) -> Result<String, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        template: &'a str,
+    }
+
+    invoke("preview_filename_template", Args { template }).await
+}
+
+/// Fetch the title, cover and video count of a `YouTube` playlist.
+///
+/// In offline mode the cached info is returned, if the playlist was fetched
+/// before.
+pub async fn fetch_youtube_playlist_info(url: &str) -> Result<PlaylistInfo, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        url: &'a str,
+    }
+
+    invoke("fetch_youtube_playlist_info", Args { url }).await
+}
+
+/// Estimate the size and download time of a `YouTube` playlist at the
+/// configured download quality, so the user can confirm before queueing it.
+pub async fn estimate_playlist_download(url: &str) -> Result<PlaylistDownloadEstimate, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        url: &'a str,
+    }
+
+    invoke("estimate_playlist_download", Args { url }).await
+}
+
+/// Estimate the size and audio length of a `YouTube` playlist from a sample
+/// of its videos' streams, at the quality `playlist_name` (or the global
+/// settings) would download it.
+pub async fn estimate_playlist_size(
+    url: &str,
+    playlist_name: Option<&str>,
+) -> Result<PlaylistSizeEstimate, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        url: &'a str,
+        playlist_name: Option<&'a str>,
+    }
+
+    invoke("estimate_playlist_size", Args { url, playlist_name }).await
+}
+
+/// Plan the download of a `YouTube` playlist without downloading or writing
+/// anything: which videos would be saved where, which are skipped and why,
+/// and how large the new files would be.
+pub async fn plan_playlist_download(
+    url: &str,
+    playlist_name: &str,
+) -> Result<DownloadPlan, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        url: &'a str,
+        playlist_name: &'a str,
+    }
+
+    invoke("plan_playlist_download", Args { url, playlist_name }).await
+}
+
+/// Check which videos of a `YouTube` playlist are private, deleted or
+/// blocked in this country.
+pub async fn check_playlist_health(url: &str) -> Result<PlaylistHealthReport, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        url: &'a str,
+    }
+
+    invoke("check_playlist_health", Args { url }).await
+}
+
+/// Search `YouTube` for videos and playlists matching `query`.
+pub async fn search_youtube(
+    query: &str,
+    filter: SearchFilter,
+) -> Result<Vec<SearchResult>, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        query: &'a str,
+        filter: SearchFilter,
+    }
+
+    invoke("search_youtube", Args { query, filter }).await
+}
+
+/// Compare a library playlist's `YouTube` source with what was downloaded,
+/// before downloading it again.
+pub async fn preview_playlist_update(name: &str) -> Result<PlaylistDiff, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        name: &'a str,
+    }
+
+    invoke("preview_playlist_update", Args { name }).await
+}
+
+/// Look up the lyrics of the track at `path` and save them as configured.
+///
+/// Returns `None` when no lyrics were found.
+pub async fn fetch_track_lyrics(path: &str) -> Result<Option<Lyrics>, String> {
+    #[derive(serde::Serialize)]
+    struct Args<'a> {
+        path: &'a str,
+    }
+
+    invoke("fetch_track_lyrics", Args { path }).await
+}
+
 /// Download a `YouTube` playlist to a local directory.
 ///
 /// Returns the task ID that can be used to track the download.
@@ -1518,6 +3250,35 @@ pub async fn download_youtube_to_playlist(
     invoke("download_youtube_to_playlist", Args { url, playlist_name }).await
 }
 
+/// Download the new videos of a library playlist's `YouTube` source into
+/// it, optionally moving the tracks of videos removed from the source to the
+/// trash.
+///
+/// In simple mode, `pin` must be the parent PIN to remove tracks.
+pub async fn update_playlist_from_source(
+    name: &str,
+    remove_missing: bool,
+    pin: Option<&str>,
+) -> Result<TaskId, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args<'a> {
+        name: &'a str,
+        remove_missing: bool,
+        pin: Option<&'a str>,
+    }
+
+    invoke(
+        "update_playlist_from_source",
+        Args {
+            name,
+            remove_missing,
+            pin,
+        },
+    )
+    .await
+}
+
 /// Cancel a running download task.
 ///
 /// Returns `true` if the task was successfully cancelled.
@@ -1525,6 +3286,45 @@ pub async fn cancel_download(task_id: TaskId) -> Result<bool, String> {
     cancel_task(task_id).await
 }
 
+/// Cancel the video a download is working on and continue with the next one.
+///
+/// Returns `true` if the download was found.
+pub async fn cancel_download_video(task_id: TaskId) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        task_id: TaskId,
+    }
+
+    invoke("cancel_download_video", Args { task_id }).await
+}
+
+/// Pause a running download, keeping the bytes downloaded so far.
+///
+/// Returns `true` if the download was found.
+pub async fn pause_download(task_id: TaskId) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        task_id: TaskId,
+    }
+
+    invoke("pause_download", Args { task_id }).await
+}
+
+/// Resume a paused download.
+///
+/// Returns `true` if the download was found.
+pub async fn resume_download(task_id: TaskId) -> Result<bool, String> {
+    #[derive(serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Args {
+        task_id: TaskId,
+    }
+
+    invoke("resume_download", Args { task_id }).await
+}
+
 /// Listen to `YouTube` download started events.
 ///
 /// Returns a function to stop listening.
@@ -1562,6 +3362,25 @@ where
     .await
 }
 
+/// Listen to playlist paging progress events, emitted while the video list
+/// of a long playlist is fetched before its download starts.
+///
+/// Returns a function to stop listening.
+pub async fn listen_to_playlist_fetch_progress<F>(handler: F) -> Result<js_sys::Function, String>
+where
+    F: Fn(PlaylistFetchProgress) + 'static,
+{
+    listen_to_event(youtube_events::PLAYLIST_FETCH_PROGRESS, move |value| {
+        if let Ok(payload) =
+            js_sys::Reflect::get(&value, &wasm_bindgen::JsValue::from_str("payload"))
+            && let Ok(progress) = serde_wasm_bindgen::from_value::<PlaylistFetchProgress>(payload)
+        {
+            handler(progress);
+        }
+    })
+    .await
+}
+
 /// Listen to `YouTube` download completed events.
 ///
 /// Returns a function to stop listening.
@@ -1615,3 +3434,36 @@ where
     })
     .await
 }
+
+// =============================================================================
+// Task Events API
+// =============================================================================
+
+/// Event names for the unified task event bus.
+pub mod task_events {
+    /// Emitted for every sync, download, queue, cache, verification,
+    /// maintenance and transfer lifecycle or progress update.
+    pub const TASK_EVENT: &str = "task-event";
+}
+
+/// Listen to unified task events.
+///
+/// This single channel carries every sync, orchestrated sync, download, queue,
+/// cache warming, verification, maintenance and transfer event, so one
+/// listener replaces the per-feature ones.
+///
+/// Returns a function to stop listening.
+pub async fn listen_to_task_events<F>(handler: F) -> Result<js_sys::Function, String>
+where
+    F: Fn(TaskEvent) + 'static,
+{
+    listen_to_event(task_events::TASK_EVENT, move |value| {
+        if let Ok(payload) =
+            js_sys::Reflect::get(&value, &wasm_bindgen::JsValue::from_str("payload"))
+            && let Ok(event) = serde_wasm_bindgen::from_value::<TaskEvent>(payload)
+        {
+            handler(event);
+        }
+    })
+    .await
+}
@@ -2,6 +2,14 @@
 //!
 //! Modern dark mode with vibrant accent colors and glassmorphic elements.
 //! Designed for performance: system fonts, subtle depth, GPU-accelerated animations.
+//!
+//! The dark palette is the default; light, system-following and high-contrast
+//! variants override its colors through `data-theme` and `data-contrast`
+//! attributes on the document element (see [`apply_appearance`]).
+
+use std::fmt::Write as _;
+
+use crate::types::{AppConfig, Theme};
 
 /// Color palette for the application.
 pub mod colors {
@@ -333,6 +341,270 @@ pub fn generate_css_variables() -> String {
     )
 }
 
+/// Colors that change between theme variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    /// Primary background.
+    pub bg_primary: &'static str,
+    /// Secondary/elevated background.
+    pub bg_secondary: &'static str,
+    /// Tertiary/card background.
+    pub bg_tertiary: &'static str,
+    /// Hover state background.
+    pub bg_hover: &'static str,
+    /// Glass effect background.
+    pub bg_glass: &'static str,
+    /// Elevated surface.
+    pub bg_elevated: &'static str,
+    /// Primary text color.
+    pub text_primary: &'static str,
+    /// Secondary/muted text.
+    pub text_secondary: &'static str,
+    /// Disabled text.
+    pub text_disabled: &'static str,
+    /// Primary accent.
+    pub accent_primary: &'static str,
+    /// Primary accent hover variant.
+    pub accent_primary_dim: &'static str,
+    /// Success color.
+    pub accent_success: &'static str,
+    /// Warning color.
+    pub accent_warning: &'static str,
+    /// Error color.
+    pub accent_error: &'static str,
+    /// Info color.
+    pub accent_info: &'static str,
+    /// Default border.
+    pub border_default: &'static str,
+    /// Focused border.
+    pub border_focused: &'static str,
+    /// Subtle border.
+    pub border_subtle: &'static str,
+    /// Strong border.
+    pub border_strong: &'static str,
+    /// Overlay background.
+    pub overlay: &'static str,
+}
+
+/// Light palette; status colors are darkened to stay readable on white.
+pub const LIGHT: Palette = Palette {
+    bg_primary: "#ffffff",
+    bg_secondary: "#f4f4f5",
+    bg_tertiary: "#e4e4e7",
+    bg_hover: "#d4d4d8",
+    bg_glass: "rgba(255, 255, 255, 0.8)",
+    bg_elevated: "#ffffff",
+    text_primary: "#18181b",
+    text_secondary: "#52525b",
+    text_disabled: "#71717a",
+    accent_primary: "#6d28d9",
+    accent_primary_dim: "#5b21b6",
+    accent_success: "#047857",
+    accent_warning: "#b45309",
+    accent_error: "#dc2626",
+    accent_info: "#2563eb",
+    border_default: "rgba(0, 0, 0, 0.1)",
+    border_focused: "#7c3aed",
+    border_subtle: "rgba(0, 0, 0, 0.05)",
+    border_strong: "rgba(0, 0, 0, 0.16)",
+    overlay: "rgba(0, 0, 0, 0.5)",
+};
+
+/// High-contrast dark palette (WCAG AAA for text).
+pub const HIGH_CONTRAST_DARK: Palette = Palette {
+    bg_primary: "#000000",
+    bg_secondary: "#0a0a0a",
+    bg_tertiary: "#141414",
+    bg_hover: "#262626",
+    bg_glass: "rgba(0, 0, 0, 0.95)",
+    bg_elevated: "#0a0a0a",
+    text_primary: "#ffffff",
+    text_secondary: "#e4e4e7",
+    text_disabled: "#a1a1aa",
+    accent_primary: "#c4b5fd",
+    accent_primary_dim: "#a78bfa",
+    accent_success: "#4ade80",
+    accent_warning: "#facc15",
+    accent_error: "#fca5a5",
+    accent_info: "#93c5fd",
+    border_default: "rgba(255, 255, 255, 0.6)",
+    border_focused: "#facc15",
+    border_subtle: "rgba(255, 255, 255, 0.4)",
+    border_strong: "#ffffff",
+    overlay: "rgba(0, 0, 0, 0.92)",
+};
+
+/// High-contrast light palette (WCAG AAA for text).
+pub const HIGH_CONTRAST_LIGHT: Palette = Palette {
+    bg_primary: "#ffffff",
+    bg_secondary: "#ffffff",
+    bg_tertiary: "#f4f4f5",
+    bg_hover: "#e4e4e7",
+    bg_glass: "rgba(255, 255, 255, 0.97)",
+    bg_elevated: "#ffffff",
+    text_primary: "#000000",
+    text_secondary: "#27272a",
+    text_disabled: "#52525b",
+    accent_primary: "#5b21b6",
+    accent_primary_dim: "#4c1d95",
+    accent_success: "#065f46",
+    accent_warning: "#78350f",
+    accent_error: "#991b1b",
+    accent_info: "#1e3a8a",
+    border_default: "rgba(0, 0, 0, 0.6)",
+    border_focused: "#1d4ed8",
+    border_subtle: "rgba(0, 0, 0, 0.4)",
+    border_strong: "#000000",
+    overlay: "rgba(0, 0, 0, 0.7)",
+};
+
+impl Palette {
+    /// CSS custom property declarations for the palette.
+    #[must_use]
+    pub fn declarations(&self) -> String {
+        let mut css = String::new();
+        for (name, value) in [
+            ("--bg-primary", self.bg_primary),
+            ("--bg-secondary", self.bg_secondary),
+            ("--bg-tertiary", self.bg_tertiary),
+            ("--bg-hover", self.bg_hover),
+            ("--bg-glass", self.bg_glass),
+            ("--bg-elevated", self.bg_elevated),
+            ("--text-primary", self.text_primary),
+            ("--text-secondary", self.text_secondary),
+            ("--text-disabled", self.text_disabled),
+            ("--accent-primary", self.accent_primary),
+            ("--accent-primary-dim", self.accent_primary_dim),
+            ("--accent-success", self.accent_success),
+            ("--accent-warning", self.accent_warning),
+            ("--accent-error", self.accent_error),
+            ("--accent-info", self.accent_info),
+            ("--border-default", self.border_default),
+            ("--border-focused", self.border_focused),
+            ("--border-subtle", self.border_subtle),
+            ("--border-strong", self.border_strong),
+            ("--overlay-bg", self.overlay),
+        ] {
+            let _ = writeln!(css, "  {name}: {value};");
+        }
+        css
+    }
+}
+
+/// Generate the CSS overriding the default dark colors for the light,
+/// system-following and high-contrast variants.
+pub fn generate_theme_variants_css() -> String {
+    let light = LIGHT.declarations();
+    let hc_dark = HIGH_CONTRAST_DARK.declarations();
+    let hc_light = HIGH_CONTRAST_LIGHT.declarations();
+    format!(
+        r#":root[data-theme="light"] {{
+{light}}}
+:root[data-contrast="high"] {{
+{hc_dark}}}
+:root[data-theme="light"][data-contrast="high"] {{
+{hc_light}}}
+@media (prefers-color-scheme: light) {{
+  :root[data-theme="system"] {{
+{light}  }}
+  :root[data-theme="system"][data-contrast="high"] {{
+{hc_light}  }}
+}}"#
+    )
+}
+
+/// Parse a `#rrggbb` color into its red, green and blue components.
+#[must_use]
+pub fn parse_hex_color(value: &str) -> Option<(u8, u8, u8)> {
+    let hex = value.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
+/// WCAG relative luminance of a color (0.0 for black, 1.0 for white).
+#[must_use]
+pub fn relative_luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    let linear = |channel: u8| {
+        let c = f64::from(channel) / 255.0;
+        if c <= 0.039_28 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.0722_f64.mul_add(linear(b), 0.2126_f64.mul_add(linear(r), 0.7152 * linear(g)))
+}
+
+/// WCAG contrast ratio between two colors, from 1.0 to 21.0.
+#[must_use]
+pub fn contrast_ratio(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// Inline CSS custom properties for a custom accent color, or `None` for an
+/// invalid color.
+///
+/// Besides the accent itself this sets the darker hover variant, the focus
+/// ring and the accent glow.
+#[must_use]
+pub fn accent_declarations(accent: &str) -> Option<String> {
+    let (r, g, b) = parse_hex_color(accent)?;
+    let darken = |c: u8| u8::try_from(u16::from(c) * 85 / 100).unwrap_or(c);
+    Some(format!(
+        "--accent-primary: #{r:02x}{g:02x}{b:02x}; \
+         --accent-primary-dim: #{:02x}{:02x}{:02x}; \
+         --border-focused: #{r:02x}{g:02x}{b:02x}; \
+         --shadow-primary: rgba({r}, {g}, {b}, 0.3);",
+        darken(r),
+        darken(g),
+        darken(b)
+    ))
+}
+
+/// `data-theme` value for a theme setting.
+#[must_use]
+pub const fn theme_attribute(theme: Theme) -> &'static str {
+    match theme {
+        Theme::Dark => "dark",
+        Theme::Light => "light",
+        Theme::System => "system",
+    }
+}
+
+/// Apply the theme, contrast and accent color from `config` to the document.
+///
+/// The custom accent is skipped in high-contrast mode, whose accent colors are
+/// chosen to keep their contrast.
+pub fn apply_appearance(config: &AppConfig) {
+    let Some(root) = leptos::prelude::document().document_element() else {
+        return;
+    };
+    let contrast = if config.high_contrast {
+        "high"
+    } else {
+        "normal"
+    };
+    let accent = config
+        .accent_color
+        .as_deref()
+        .filter(|_| !config.high_contrast)
+        .and_then(accent_declarations)
+        .unwrap_or_default();
+    for (name, value) in [
+        ("data-theme", theme_attribute(config.theme)),
+        ("data-contrast", contrast),
+        ("style", accent.as_str()),
+    ] {
+        if let Err(e) = root.set_attribute(name, value) {
+            leptos::logging::error!("Failed to set {} on the document: {:?}", name, e);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -351,4 +623,70 @@ mod tests {
         assert!(colors::background::PRIMARY.starts_with('#'));
         assert!(colors::accent::PRIMARY.starts_with('#'));
     }
+
+    fn rgb(color: &str) -> (u8, u8, u8) {
+        parse_hex_color(color).expect("palette colors are hex")
+    }
+
+    /// Check every text and status color of `palette` against its backgrounds.
+    fn assert_contrast(palette: &Palette, text_min: f64, other_min: f64) {
+        for bg in [
+            palette.bg_primary,
+            palette.bg_secondary,
+            palette.bg_tertiary,
+        ] {
+            for text in [palette.text_primary, palette.text_secondary] {
+                let ratio = contrast_ratio(rgb(text), rgb(bg));
+                assert!(ratio >= text_min, "{text} on {bg} is {ratio:.2}:1");
+            }
+        }
+        for color in [
+            palette.text_disabled,
+            palette.accent_primary,
+            palette.accent_success,
+            palette.accent_warning,
+            palette.accent_error,
+            palette.accent_info,
+        ] {
+            let ratio = contrast_ratio(rgb(color), rgb(palette.bg_primary));
+            assert!(ratio >= other_min, "{color} is {ratio:.2}:1");
+        }
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let ratio = contrast_ratio((0, 0, 0), (255, 255, 255));
+        assert!((ratio - 21.0).abs() < 0.01);
+        assert!((contrast_ratio((1, 2, 3), (1, 2, 3)) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_palettes_meet_wcag() {
+        // AA for the light theme, AAA text for high contrast
+        assert_contrast(&LIGHT, 4.5, 4.5);
+        assert_contrast(&HIGH_CONTRAST_DARK, 7.0, 4.5);
+        assert_contrast(&HIGH_CONTRAST_LIGHT, 7.0, 4.5);
+    }
+
+    #[test]
+    fn test_theme_variants_css() {
+        let css = generate_theme_variants_css();
+        assert!(css.contains(r#":root[data-theme="light"]"#));
+        assert!(css.contains("prefers-color-scheme: light"));
+        assert!(css.contains(r#"[data-contrast="high"]"#));
+        assert!(css.contains("--text-primary: #000000;"));
+    }
+
+    #[test]
+    fn test_accent_declarations() {
+        assert_eq!(parse_hex_color("#22D3ee"), Some((0x22, 0xd3, 0xee)));
+        assert_eq!(parse_hex_color("22d3ee"), None);
+        assert_eq!(parse_hex_color("#22d3e"), None);
+
+        let css = accent_declarations("#C8641E").expect("valid color");
+        assert!(css.contains("--accent-primary: #c8641e;"));
+        assert!(css.contains("--accent-primary-dim: #aa5519;"));
+        assert!(css.contains("rgba(200, 100, 30, 0.3)"));
+        assert_eq!(accent_declarations("violet"), None);
+    }
 }
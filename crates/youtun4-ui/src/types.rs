@@ -2,8 +2,12 @@
 //!
 //! These types mirror the core types but are WASM-compatible.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::i18n::{translate, translate_count};
+
 /// Information about a detected device.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct DeviceInfo {
@@ -38,6 +42,72 @@ impl DeviceInfo {
     }
 }
 
+/// What the last sync to a device did.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeviceSyncRecord {
+    /// When the sync finished (seconds since the Unix epoch).
+    pub synced_at: u64,
+    /// Playlists that were synced, in order.
+    pub playlists: Vec<String>,
+    /// Files copied to the device.
+    pub files_transferred: usize,
+    /// Files that failed to copy.
+    pub files_failed: usize,
+    /// Bytes copied to the device.
+    pub bytes_transferred: u64,
+}
+
+/// How the space on a device is used.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeviceUsage {
+    /// Total capacity in bytes.
+    pub total_bytes: u64,
+    /// Free space in bytes.
+    pub available_bytes: u64,
+    /// Bytes taken by audio files.
+    pub music_bytes: u64,
+    /// Number of audio files.
+    pub music_files: usize,
+    /// Bytes taken by other files the user can see.
+    pub other_bytes: u64,
+    /// Number of other files.
+    pub other_files: usize,
+    /// Bytes taken by hidden and system files and file system overhead.
+    pub system_bytes: u64,
+    /// Files and folders that couldn't be read while scanning.
+    pub unreadable_entries: usize,
+    /// The last sync to this device, if any.
+    pub last_sync: Option<DeviceSyncRecord>,
+}
+
+impl DeviceUsage {
+    /// Returns the usage percentage (0.0 - 100.0).
+    #[must_use]
+    pub fn usage_percentage(&self) -> f64 {
+        if self.total_bytes == 0 {
+            return 0.0;
+        }
+        let used = self.total_bytes.saturating_sub(self.available_bytes);
+        (used as f64 / self.total_bytes as f64) * 100.0
+    }
+}
+
+/// Result of cleaning up a device.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CleanupResult {
+    /// Number of files deleted.
+    pub files_deleted: usize,
+    /// Number of directories deleted.
+    pub directories_deleted: usize,
+    /// Bytes freed.
+    pub bytes_freed: u64,
+    /// Number of files that could not be deleted.
+    pub files_failed: usize,
+    /// Whether the cleanup was cancelled before finishing.
+    #[serde(default)]
+    pub cancelled: bool,
+}
+
 /// Metadata for a playlist (computed view).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PlaylistMetadata {
@@ -55,6 +125,9 @@ pub struct PlaylistMetadata {
     pub total_bytes: u64,
     /// Thumbnail URL for the playlist (from `YouTube`).
     pub thumbnail_url: Option<String>,
+    /// Tags given to the playlist.
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 /// Saved playlist metadata stored in playlist.json.
@@ -86,6 +159,12 @@ pub struct SavedPlaylistMetadata {
     /// Thumbnail URL from `YouTube` (if available).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumbnail_url: Option<String>,
+    /// Tags given to the playlist.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Download settings overriding the global ones for this playlist.
+    #[serde(default)]
+    pub download_profile: Option<DownloadProfile>,
 }
 
 impl SavedPlaylistMetadata {
@@ -137,6 +216,110 @@ pub struct TrackInfo {
     pub metadata: Option<Mp3Metadata>,
 }
 
+/// A page of a playlist's tracks, ordered by file name.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrackPage {
+    /// Index of the first track in the page.
+    pub offset: usize,
+    /// Total number of tracks in the playlist.
+    pub total: usize,
+    /// Tracks in the page, with ID3 metadata.
+    pub tracks: Vec<TrackInfo>,
+    /// Sum of the track durations recorded at download time, if any were.
+    pub total_duration_secs: Option<u64>,
+}
+
+/// An item a batch operation couldn't process.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BatchFailure {
+    /// Playlist name or track file name.
+    pub item: String,
+    /// Why the item failed.
+    pub error: String,
+}
+
+/// Outcome of an operation on several playlists or tracks.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BatchResult {
+    /// Items processed successfully, in order.
+    pub succeeded: Vec<String>,
+    /// Items that failed, in order.
+    pub failed: Vec<BatchFailure>,
+}
+
+/// A thumbnail ready to be displayed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum CachedThumbnail {
+    /// Path of a local image file.
+    File(String),
+    /// The image as a base64 `data:` URL.
+    DataUrl(String),
+}
+
+/// Identifier of a trash entry, unique within a session.
+pub type TrashId = u64;
+
+/// What a trash entry holds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TrashedItem {
+    /// A whole playlist folder.
+    Playlist {
+        /// Playlist name.
+        name: String,
+    },
+    /// Tracks removed from a playlist.
+    Tracks {
+        /// Playlist the tracks belong to.
+        playlist: String,
+        /// Track file names.
+        file_names: Vec<String>,
+    },
+}
+
+/// A deletion that can be undone.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashEntry {
+    /// Entry ID.
+    pub id: TrashId,
+    /// What was deleted.
+    pub item: TrashedItem,
+    /// When it was deleted (Unix epoch seconds).
+    pub trashed_at: u64,
+}
+
+/// Result of moving tracks to the trash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashedTracks {
+    /// The trash entry, if any track was moved.
+    pub entry: Option<TrashEntry>,
+    /// Which tracks were moved.
+    pub result: BatchResult,
+}
+
+/// A track matching a library search.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TrackSearchMatch {
+    /// Playlist containing the track.
+    pub playlist: String,
+    /// Track file name.
+    pub file_name: String,
+    /// Title recorded at download time, if any.
+    pub title: Option<String>,
+    /// Channel recorded at download time, if any.
+    pub channel: Option<String>,
+}
+
+/// Results of a library search, best matches first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LibrarySearchResults {
+    /// Playlists whose name or title matches.
+    pub playlists: Vec<PlaylistMetadata>,
+    /// Tracks whose file name, title or channel matches.
+    pub tracks: Vec<TrackSearchMatch>,
+}
+
 /// Metadata extracted from an MP3 file.
 ///
 /// Contains ID3 tag information commonly found in MP3 files.
@@ -296,6 +479,64 @@ impl std::fmt::Display for DownloadQuality {
     }
 }
 
+/// Category of a `SponsorBlock` segment.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum SegmentCategory {
+    /// Paid promotion.
+    Sponsor,
+    /// Unpaid self-promotion.
+    SelfPromo,
+    /// Reminders to like, subscribe or follow.
+    Interaction,
+    /// Intro animation or intermission.
+    Intro,
+    /// End cards and credits.
+    Outro,
+    /// Recap or preview of other content.
+    Preview,
+    /// Non-music part of a music video.
+    MusicOfftopic,
+    /// Tangents and filler.
+    Filler,
+}
+
+/// `SponsorBlock` settings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SponsorBlockConfig {
+    /// Whether segments are removed from downloaded audio.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Categories of segments to remove.
+    #[serde(default = "default_sponsorblock_categories")]
+    pub categories: Vec<SegmentCategory>,
+    /// `SponsorBlock` API server.
+    #[serde(default = "default_sponsorblock_api_url")]
+    pub api_url: String,
+}
+
+fn default_sponsorblock_categories() -> Vec<SegmentCategory> {
+    vec![
+        SegmentCategory::Sponsor,
+        SegmentCategory::Intro,
+        SegmentCategory::Outro,
+    ]
+}
+
+fn default_sponsorblock_api_url() -> String {
+    "https://sponsor.ajay.app".to_string()
+}
+
+impl Default for SponsorBlockConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            categories: default_sponsorblock_categories(),
+            api_url: default_sponsorblock_api_url(),
+        }
+    }
+}
+
 /// Theme setting for the application.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
@@ -319,6 +560,258 @@ impl std::fmt::Display for Theme {
     }
 }
 
+/// A language the app ships translations for.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    /// English (default).
+    #[default]
+    En,
+    /// French.
+    Fr,
+    /// Spanish.
+    Es,
+    /// German.
+    De,
+}
+
+impl Locale {
+    /// Every supported locale.
+    pub const ALL: [Self; 4] = [Self::En, Self::Fr, Self::Es, Self::De];
+
+    /// The two-letter language code, as sent to the backend.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Fr => "fr",
+            Self::Es => "es",
+            Self::De => "de",
+        }
+    }
+
+    /// Parse a two-letter language code.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|locale| locale.code() == code)
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::En => write!(f, "English"),
+            Self::Fr => write!(f, "Français"),
+            Self::Es => write!(f, "Español"),
+            Self::De => write!(f, "Deutsch"),
+        }
+    }
+}
+
+/// A translatable backend message: a catalog key and its parameters.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Message {
+    /// Catalog key, e.g. `capacity.ready`.
+    pub key: String,
+    /// Values for the template's `{name}` placeholders.
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+}
+
+impl Message {
+    /// Render the message with a catalog, or `None` if the key is missing.
+    #[must_use]
+    pub fn render(&self, catalog: &TranslationCatalog) -> Option<String> {
+        let template = catalog.messages.get(&self.key)?;
+        Some(
+            self.params
+                .iter()
+                .fold(template.clone(), |text, (name, value)| {
+                    text.replace(&format!("{{{name}}}"), value)
+                }),
+        )
+    }
+}
+
+/// Message templates for one language.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TranslationCatalog {
+    /// Language of the templates.
+    pub locale: Locale,
+    /// Templates by message key, with `{name}` placeholders.
+    pub messages: BTreeMap<String, String>,
+}
+
+/// How the library shows playlists.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PlaylistLayout {
+    /// Cards with details and actions (default).
+    #[default]
+    List,
+    /// Cover art tiles.
+    Grid,
+}
+
+/// Order of the playlists in the library.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaylistSort {
+    /// Alphabetical by name (default).
+    #[default]
+    Name,
+    /// Most recently modified first.
+    RecentlyUpdated,
+    /// Largest first.
+    Size,
+}
+
+impl PlaylistSort {
+    /// Every sort order, in the order they are offered.
+    pub const ALL: [Self; 3] = [Self::Name, Self::RecentlyUpdated, Self::Size];
+
+    /// Stable identifier, as serialized.
+    #[must_use]
+    pub const fn id(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::RecentlyUpdated => "recently_updated",
+            Self::Size => "size",
+        }
+    }
+
+    /// Message key of the label shown in the sort control.
+    #[must_use]
+    pub const fn label_key(self) -> &'static str {
+        match self {
+            Self::Name => "playlist_sort.name",
+            Self::RecentlyUpdated => "playlist_sort.recently_updated",
+            Self::Size => "playlist_sort.size",
+        }
+    }
+
+    /// Parse an identifier from [`Self::id`].
+    #[must_use]
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|sort| sort.id() == id)
+    }
+
+    /// Sort `playlists` in this order.
+    pub fn apply(self, playlists: &mut [PlaylistMetadata]) {
+        match self {
+            Self::Name => {
+                playlists.sort_by_cached_key(|p| p.name.to_lowercase());
+            }
+            Self::RecentlyUpdated => {
+                playlists.sort_by(|a, b| b.modified_at.cmp(&a.modified_at));
+            }
+            Self::Size => playlists.sort_by(|a, b| b.total_bytes.cmp(&a.total_bytes)),
+        }
+    }
+}
+
+/// Layout and order of the playlists in the library.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PlaylistViewPreferences {
+    /// List or grid layout.
+    #[serde(default)]
+    pub layout: PlaylistLayout,
+    /// Sort order.
+    #[serde(default)]
+    pub sort: PlaylistSort,
+}
+
+/// Main view of the app.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UiView {
+    /// Playlist library (default).
+    #[default]
+    Library,
+    /// Choosing playlists to sync.
+    Sync,
+    /// Download and sync history.
+    History,
+    /// Storage overview.
+    Storage,
+}
+
+const fn default_history_range_days() -> Option<u32> {
+    Some(30)
+}
+
+/// History view filters.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HistoryFilterState {
+    /// How many days back to show (everything when `None`).
+    #[serde(default = "default_history_range_days")]
+    pub range_days: Option<u32>,
+    /// Only downloads or only syncs.
+    #[serde(default)]
+    pub kind: Option<HistoryKind>,
+    /// Only entries with this status.
+    #[serde(default)]
+    pub status: Option<HistoryStatus>,
+}
+
+impl Default for HistoryFilterState {
+    fn default() -> Self {
+        Self {
+            range_days: default_history_range_days(),
+            kind: None,
+            status: None,
+        }
+    }
+}
+
+/// Interface state restored on the next start.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UiState {
+    /// View shown at startup.
+    #[serde(default)]
+    pub view: UiView,
+    /// Playlist whose details were open.
+    #[serde(default)]
+    pub selected_playlist: Option<String>,
+    /// Whether the sidebar is collapsed.
+    #[serde(default)]
+    pub sidebar_collapsed: bool,
+    /// History view filters.
+    #[serde(default)]
+    pub history_filter: HistoryFilterState,
+}
+
+/// Release channel used for update checks.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    /// Stable releases only (default).
+    #[default]
+    Stable,
+    /// Pre-releases for early testing.
+    Beta,
+}
+
+impl UpdateChannel {
+    /// Message key of the channel name.
+    #[must_use]
+    pub const fn label_key(self) -> &'static str {
+        match self {
+            Self::Stable => "settings.updates.stable",
+            Self::Beta => "settings.updates.beta",
+        }
+    }
+}
+
+impl std::fmt::Display for UpdateChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stable => write!(f, "Stable"),
+            Self::Beta => write!(f, "Beta"),
+        }
+    }
+}
+
 /// Notification preferences for the application.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct NotificationPreferences {
@@ -351,56 +844,1120 @@ impl Default for NotificationPreferences {
     }
 }
 
-/// Application configuration.
+/// An event the webhook can be called for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// A playlist download finished.
+    DownloadCompleted,
+    /// A playlist download failed.
+    DownloadFailed,
+    /// A sync to a device finished.
+    SyncCompleted,
+    /// A sync to a device failed.
+    SyncFailed,
+}
+
+impl WebhookEvent {
+    /// Every event, in display order.
+    pub const ALL: [Self; 4] = [
+        Self::DownloadCompleted,
+        Self::DownloadFailed,
+        Self::SyncCompleted,
+        Self::SyncFailed,
+    ];
+
+    /// Message key of the name of the event in settings.
+    #[must_use]
+    pub const fn label_key(self) -> &'static str {
+        match self {
+            Self::DownloadCompleted => "webhook_event.download_completed",
+            Self::DownloadFailed => "webhook_event.download_failed",
+            Self::SyncCompleted => "webhook_event.sync_completed",
+            Self::SyncFailed => "webhook_event.sync_failed",
+        }
+    }
+}
+
+fn default_webhook_payload_template() -> String {
+    "{{message}}".to_string()
+}
+
+fn default_webhook_events() -> Vec<WebhookEvent> {
+    WebhookEvent::ALL.to_vec()
+}
+
+/// What to post to the webhook, and when. The URL is kept in the secrets
+/// store.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct AppConfig {
+pub struct WebhookConfig {
+    /// Whether the webhook is called.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Template of the payload.
+    #[serde(default = "default_webhook_payload_template")]
+    pub payload_template: String,
+    /// Events the webhook is called for.
+    #[serde(default = "default_webhook_events")]
+    pub events: Vec<WebhookEvent>,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            payload_template: default_webhook_payload_template(),
+            events: default_webhook_events(),
+        }
+    }
+}
+
+/// A named set of machine- or user-specific settings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConfigProfile {
     /// Directory where playlists are stored.
     pub playlists_directory: String,
+    /// Custom cache directory (platform default when `None`).
+    #[serde(default)]
+    pub cache_directory: Option<String>,
     /// Download quality for `YouTube` downloads.
     #[serde(default)]
     pub download_quality: DownloadQuality,
-    /// Theme preference.
-    #[serde(default)]
-    pub theme: Theme,
-    /// Notification preferences.
+    /// Mount point of the preferred sync device.
     #[serde(default)]
-    pub notification_preferences: NotificationPreferences,
+    pub preferred_device: Option<String>,
 }
 
-/// Unique identifier for a spawned task.
-pub type TaskId = u64;
+/// Settings remembered for a single sync device.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeviceProfile {
+    /// Name shown instead of the volume label.
+    #[serde(default)]
+    pub nickname: Option<String>,
+    /// Sync the device's last playlists again whenever it is connected.
+    #[serde(default)]
+    pub auto_sync: bool,
+    /// Do nothing when the device is connected: no banner and no auto-sync.
+    #[serde(default)]
+    pub ignored: bool,
+}
 
-/// Running task count by category.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub struct TaskCount {
-    /// Task category name.
-    pub category: String,
-    /// Number of running tasks in this category.
-    pub count: usize,
+/// Settings that replace the global ones for a single playlist.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PlaylistOverrides {
+    /// Download quality for this playlist (the global setting when `None`).
+    #[serde(default)]
+    pub download_quality: Option<DownloadQuality>,
+    /// Whether lyrics are fetched for this playlist (the global setting
+    /// when `None`).
+    #[serde(default)]
+    pub fetch_lyrics: Option<bool>,
 }
 
-// =============================================================================
-// Transfer Types
-// =============================================================================
+/// File format of downloaded tracks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    /// Converted to MP3 (default).
+    #[default]
+    Mp3,
+    /// The downloaded stream as is (usually M4A or WebM).
+    Original,
+}
 
-/// Status of a transfer operation.
+/// Which tags are written into downloaded tracks.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum TransferStatus {
-    /// Transfer is preparing (calculating sizes, etc.).
-    Preparing,
-    /// Transfer is actively copying files.
-    Transferring,
-    /// Transfer is verifying file integrity.
-    Verifying,
-    /// Transfer completed successfully.
-    Completed,
-    /// Transfer failed.
-    Failed,
-    /// Transfer was cancelled.
-    Cancelled,
+#[serde(rename_all = "snake_case")]
+pub enum TagPolicy {
+    /// No tags.
+    None,
+    /// Title, artist, album and track number.
+    Tags,
+    /// Tags and the video thumbnail as cover art.
+    TagsAndCoverArt,
 }
 
-impl std::fmt::Display for TransferStatus {
+/// Download settings of a playlist, saved in its playlist.json. Unset
+/// fields follow the global settings and the playlist's overrides.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DownloadProfile {
+    /// Audio bitrate in kbps.
+    #[serde(default)]
+    pub bitrate_kbps: Option<u32>,
+    /// File format of the tracks.
+    #[serde(default)]
+    pub output_format: Option<OutputFormat>,
+    /// Tags written into the tracks.
+    #[serde(default)]
+    pub tag_policy: Option<TagPolicy>,
+    /// Template the tracks are named from.
+    #[serde(default)]
+    pub filename_template: Option<String>,
+}
+
+impl DownloadProfile {
+    /// Whether the profile overrides nothing.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bitrate_kbps.is_none()
+            && self.output_format.is_none()
+            && self.tag_policy.is_none()
+            && self.filename_template.is_none()
+    }
+}
+
+/// When a playlist is refreshed from its `YouTube` source.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RefreshSchedule {
+    /// Every `hours` hours.
+    Interval {
+        /// Hours between two refreshes.
+        hours: u32,
+    },
+    /// Once a day at `minute_of_day` minutes past midnight, in the time zone
+    /// `utc_offset_minutes` ahead of UTC.
+    Daily {
+        /// Minutes past midnight.
+        minute_of_day: u32,
+        /// Offset of the time zone from UTC, in minutes.
+        #[serde(default)]
+        utc_offset_minutes: i32,
+    },
+}
+
+/// A playlist's refresh schedule along with when it next runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledRefresh {
+    /// Playlist name.
+    pub playlist: String,
+    /// When the playlist is refreshed.
+    pub schedule: RefreshSchedule,
+    /// Whether tracks of videos removed from the source are moved to the
+    /// trash on refresh.
+    #[serde(default)]
+    pub remove_missing: bool,
+    /// When the playlist was last refreshed by the schedule, if ever.
+    #[serde(default)]
+    pub last_run_at: Option<u64>,
+    /// When the playlist is next refreshed (seconds since the Unix epoch).
+    pub next_run_at: u64,
+}
+
+/// Lyrics lookup settings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LyricsConfig {
+    /// Whether lyrics are fetched after downloads.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Save lyrics as an `.lrc` file next to the track.
+    #[serde(default = "default_true")]
+    pub save_sidecar: bool,
+    /// Embed lyrics in the MP3's ID3 tag.
+    #[serde(default = "default_true")]
+    pub embed: bool,
+    /// LRCLIB API server.
+    #[serde(default = "default_lyrics_api_url")]
+    pub api_url: String,
+}
+
+fn default_lyrics_api_url() -> String {
+    "https://lrclib.net".to_string()
+}
+
+impl Default for LyricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            save_sidecar: true,
+            embed: true,
+            api_url: default_lyrics_api_url(),
+        }
+    }
+}
+
+/// Which extractor reads `YouTube` pages and downloads streams.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractionBackend {
+    /// The built-in scraper, falling back to `yt-dlp` (default).
+    #[default]
+    Auto,
+    /// The built-in scraper only.
+    PureRust,
+    /// `yt-dlp` for everything.
+    YtDlp,
+}
+
+/// Extractor settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtractionConfig {
+    /// Which extractor is used.
+    #[serde(default)]
+    pub backend: ExtractionBackend,
+    /// Path of the `yt-dlp` binary (looked up on `PATH` when `None`).
+    #[serde(default)]
+    pub yt_dlp_path: Option<String>,
+}
+
+/// Lyrics of a track.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lyrics {
+    /// Plain lyrics.
+    pub plain: Option<String>,
+    /// Synced lyrics in LRC format.
+    pub synced: Option<String>,
+    /// Whether the track is instrumental.
+    #[serde(default)]
+    pub instrumental: bool,
+}
+
+/// Cache settings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// Whether caching is enabled.
+    pub enabled: bool,
+    /// Maximum total cache size in bytes.
+    pub max_size_bytes: u64,
+    /// Time-to-live for cache entries in seconds.
+    pub ttl_secs: u64,
+    /// Usage fraction (0.0 - 1.0) above which cleanup starts.
+    pub cleanup_threshold: f64,
+    /// Usage fraction (0.0 - 1.0) cleanup reduces the cache to.
+    pub cleanup_target: f64,
+    /// Whether to cache MP3 metadata.
+    pub cache_metadata: bool,
+    /// Whether to cache thumbnails.
+    pub cache_thumbnails: bool,
+    /// Custom cache directory (platform default when `None`).
+    pub custom_cache_dir: Option<String>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_size_bytes: 100 * 1024 * 1024,
+            ttl_secs: 7 * 24 * 60 * 60,
+            cleanup_threshold: 0.80,
+            cleanup_target: 0.60,
+            cache_metadata: true,
+            cache_thumbnails: true,
+            custom_cache_dir: None,
+        }
+    }
+}
+
+/// Download queue settings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct QueueConfig {
+    /// Maximum number of concurrent downloads.
+    pub max_concurrent_downloads: usize,
+    /// Whether to auto-start downloads when items are added.
+    pub auto_start: bool,
+    /// Whether to retry failed downloads automatically.
+    pub auto_retry: bool,
+    /// Maximum number of retries for failed downloads.
+    pub max_retries: u32,
+}
+
+impl Default for QueueConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_downloads: 2,
+            auto_start: true,
+            auto_retry: false,
+            max_retries: 3,
+        }
+    }
+}
+
+/// Application configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AppConfig {
+    /// Directory where playlists are stored.
+    pub playlists_directory: String,
+    /// Disk usage limits for the playlists library.
+    #[serde(default)]
+    pub library_limits: LibraryLimits,
+    /// Download quality for `YouTube` downloads.
+    #[serde(default)]
+    pub download_quality: DownloadQuality,
+    /// Number of videos of a playlist downloaded at the same time.
+    #[serde(default = "default_download_concurrency")]
+    pub download_concurrency: usize,
+    /// Download speed limit in bytes per second (unlimited when `None`).
+    #[serde(default)]
+    pub max_download_speed_bps: Option<u64>,
+    /// `SponsorBlock` segment removal from downloaded audio.
+    #[serde(default)]
+    pub sponsorblock: SponsorBlockConfig,
+    /// Write ID3 tags (title, artist, album, track number) into downloads.
+    #[serde(default = "default_true")]
+    pub write_tags: bool,
+    /// Embed the video thumbnail as cover art of downloads.
+    #[serde(default = "default_true")]
+    pub embed_cover_art: bool,
+    /// Lyrics lookup after downloads.
+    #[serde(default)]
+    pub lyrics: LyricsConfig,
+    /// Template of downloaded file names, such as
+    /// `{index:02} - {artist} - {title}`.
+    #[serde(default = "default_filename_template")]
+    pub filename_template: String,
+    /// How playlists and videos are read from `YouTube`.
+    #[serde(default)]
+    pub extraction: ExtractionConfig,
+    /// Theme preference.
+    #[serde(default)]
+    pub theme: Theme,
+    /// Custom accent color as `#rrggbb` (the brand violet when `None`).
+    #[serde(default)]
+    pub accent_color: Option<String>,
+    /// High-contrast colors for the chosen theme.
+    #[serde(default)]
+    pub high_contrast: bool,
+    /// Display language (the system language when `None`).
+    #[serde(default)]
+    pub language: Option<Locale>,
+    /// Layout and order of the playlists in the library.
+    #[serde(default)]
+    pub playlist_view: PlaylistViewPreferences,
+    /// Interface state restored on the next start.
+    #[serde(default)]
+    pub ui_state: UiState,
+    /// Notification preferences.
+    #[serde(default)]
+    pub notification_preferences: NotificationPreferences,
+    /// Webhook called when downloads and syncs end.
+    #[serde(default)]
+    pub webhook: WebhookConfig,
+    /// Cache settings.
+    #[serde(default)]
+    pub cache: CacheConfig,
+    /// Download queue settings.
+    #[serde(default)]
+    pub queue: QueueConfig,
+    /// When heavy background work pauses for battery power or metered networks.
+    #[serde(default)]
+    pub power: PowerPreferences,
+    /// Offline mode: skip all network access and serve cached data only.
+    #[serde(default)]
+    pub offline_mode: bool,
+    /// Keep running in the system tray when the main window is closed.
+    #[serde(default)]
+    pub minimize_to_tray: bool,
+    /// Release channel used for update checks.
+    #[serde(default)]
+    pub update_channel: UpdateChannel,
+    /// Endpoint crash reports are submitted to (opt-in).
+    #[serde(default)]
+    pub crash_report_endpoint: Option<String>,
+    /// Simple mode: destructive operations need the parent PIN.
+    #[serde(default)]
+    pub simple_mode: bool,
+    /// Mount point of the preferred sync device.
+    #[serde(default)]
+    pub preferred_device: Option<String>,
+    /// Polling interval of the device watcher in milliseconds.
+    #[serde(default = "default_device_poll_interval_ms")]
+    pub device_poll_interval_ms: u64,
+    /// Device settings by mount point.
+    #[serde(default)]
+    pub device_profiles: BTreeMap<String, DeviceProfile>,
+    /// Setting overrides by playlist name.
+    #[serde(default)]
+    pub playlist_overrides: BTreeMap<String, PlaylistOverrides>,
+    /// Saved configuration profiles by name.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, ConfigProfile>,
+    /// Name of the active profile, if any.
+    #[serde(default)]
+    pub active_profile: Option<String>,
+    /// Whether the first-run setup has been completed.
+    #[serde(default)]
+    pub onboarding_completed: bool,
+}
+
+const fn default_device_poll_interval_ms() -> u64 {
+    2000
+}
+
+const fn default_download_concurrency() -> usize {
+    1
+}
+
+fn default_filename_template() -> String {
+    "{title}".to_string()
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            playlists_directory: String::new(),
+            library_limits: LibraryLimits::default(),
+            download_quality: DownloadQuality::default(),
+            download_concurrency: default_download_concurrency(),
+            max_download_speed_bps: None,
+            sponsorblock: SponsorBlockConfig::default(),
+            write_tags: true,
+            embed_cover_art: true,
+            lyrics: LyricsConfig::default(),
+            filename_template: default_filename_template(),
+            extraction: ExtractionConfig::default(),
+            theme: Theme::default(),
+            accent_color: None,
+            high_contrast: false,
+            language: None,
+            playlist_view: PlaylistViewPreferences::default(),
+            ui_state: UiState::default(),
+            notification_preferences: NotificationPreferences::default(),
+            webhook: WebhookConfig::default(),
+            cache: CacheConfig::default(),
+            queue: QueueConfig::default(),
+            power: PowerPreferences::default(),
+            offline_mode: false,
+            minimize_to_tray: false,
+            update_channel: UpdateChannel::default(),
+            crash_report_endpoint: None,
+            simple_mode: false,
+            preferred_device: None,
+            device_poll_interval_ms: default_device_poll_interval_ms(),
+            device_profiles: BTreeMap::new(),
+            playlist_overrides: BTreeMap::new(),
+            profiles: BTreeMap::new(),
+            active_profile: None,
+            onboarding_completed: false,
+        }
+    }
+}
+
+/// Which power conditions pause heavy background work.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PowerPreferences {
+    /// Pause heavy work while running on battery power.
+    #[serde(default)]
+    pub pause_on_battery: bool,
+    /// Pause heavy work while on a metered network.
+    #[serde(default)]
+    pub pause_on_metered: bool,
+    /// Also treat connections guessed to be metered as metered.
+    #[serde(default)]
+    pub pause_on_guessed_metered: bool,
+}
+
+/// Why heavy background work is paused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PauseReason {
+    /// The machine is running on battery power.
+    Battery,
+    /// The network connection is metered.
+    MeteredNetwork,
+}
+
+/// The power state and whether heavy work is paused because of it.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PowerStatus {
+    /// Whether the machine is running on battery power.
+    pub on_battery: bool,
+    /// Whether the active network connection is metered.
+    pub metered_network: bool,
+    /// Whether the active connection was only guessed to be metered.
+    #[serde(default)]
+    pub metered_guessed: bool,
+    /// Why heavy background work is paused, if it is.
+    pub pause_reason: Option<PauseReason>,
+}
+
+/// Disk usage limits for the playlists library.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LibraryLimits {
+    /// Maximum total size of the library in bytes (unlimited when `None`).
+    #[serde(default)]
+    pub max_library_size_bytes: Option<u64>,
+    /// Free space to keep on the library's disk in bytes.
+    #[serde(default = "default_min_free_space_bytes")]
+    pub min_free_space_bytes: u64,
+}
+
+const fn default_min_free_space_bytes() -> u64 {
+    1024 * 1024 * 1024
+}
+
+impl Default for LibraryLimits {
+    fn default() -> Self {
+        Self {
+            max_library_size_bytes: None,
+            min_free_space_bytes: default_min_free_space_bytes(),
+        }
+    }
+}
+
+/// Current disk usage of the library, measured against its limits.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LibrarySpace {
+    /// Total size of the library in bytes.
+    pub library_bytes: u64,
+    /// Free space on the library's disk in bytes, if known.
+    pub available_bytes: Option<u64>,
+    /// Limits the usage was checked against.
+    pub limits: LibraryLimits,
+}
+
+/// Statistics about the current cache state.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CacheStats {
+    /// Total number of entries in cache.
+    pub total_entries: usize,
+    /// Number of metadata entries.
+    pub metadata_entries: usize,
+    /// Number of thumbnail entries.
+    pub thumbnail_entries: usize,
+    /// Number of temp entries.
+    pub temp_entries: usize,
+    /// Total size in bytes.
+    pub total_size_bytes: u64,
+    /// Maximum allowed size in bytes.
+    pub max_size_bytes: u64,
+    /// Usage percentage (0.0 - 1.0).
+    pub usage_percentage: f64,
+    /// Whether caching is enabled.
+    pub enabled: bool,
+}
+
+/// Disk usage of one playlist.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PlaylistUsage {
+    /// Playlist name.
+    pub name: String,
+    /// Size of the playlist folder in bytes.
+    pub bytes: u64,
+    /// Number of tracks in the playlist.
+    pub track_count: usize,
+}
+
+/// Everything the storage overview shows.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StorageOverview {
+    /// Library usage, free disk space and limits.
+    pub library: LibrarySpace,
+    /// Per-playlist usage, largest first.
+    pub playlists: Vec<PlaylistUsage>,
+    /// Cache usage.
+    pub cache: CacheStats,
+    /// Directory playlists are stored in.
+    pub playlists_directory: String,
+    /// Directory archived playlists are moved to.
+    pub archive_directory: String,
+}
+
+/// An existing folder containing music.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DetectedMusicFolder {
+    /// Folder path.
+    pub path: String,
+    /// Number of audio files found.
+    pub audio_file_count: usize,
+}
+
+/// A device referenced by the configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KnownDevice {
+    /// Device mount point.
+    pub mount_point: String,
+    /// Whether the device is currently mounted.
+    pub connected: bool,
+}
+
+/// Everything the first-run wizard needs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OnboardingState {
+    /// Whether first-run setup has been completed.
+    pub completed: bool,
+    /// Currently configured playlists directory.
+    pub playlists_directory: String,
+    /// Suggested playlists directory for a fresh setup.
+    pub suggested_playlists_directory: String,
+    /// Existing music folders found on this machine.
+    pub music_folders: Vec<DetectedMusicFolder>,
+    /// Devices used before.
+    pub known_devices: Vec<KnownDevice>,
+}
+
+/// Payload of the `config-changed` event.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConfigChangedPayload {
+    /// Names of the sections that changed (e.g. `storage`, `cache`).
+    pub sections: Vec<String>,
+    /// The configuration after the change.
+    pub config: AppConfig,
+}
+
+/// A single setting that would change when importing a configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConfigChange {
+    /// Dotted path of the setting (e.g. `cache.max_size_bytes`).
+    pub key: String,
+    /// Current value (`null` if not set).
+    pub old_value: serde_json::Value,
+    /// Imported value (`null` if removed).
+    pub new_value: serde_json::Value,
+}
+
+/// Result of importing (or previewing the import of) a configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConfigImportPreview {
+    /// The configuration that results from the import.
+    pub config: AppConfig,
+    /// Settings that differ from the current configuration.
+    pub changes: Vec<ConfigChange>,
+}
+
+/// Phase of a storage directory migration.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageMigrationPhase {
+    /// Enumerating files in the source directory.
+    Scanning,
+    /// Copying files to the destination.
+    Copying,
+    /// Verifying copied files against source checksums.
+    Verifying,
+    /// Migration finished successfully.
+    Completed,
+}
+
+/// Progress of a storage directory migration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StorageMigrationProgress {
+    /// Current phase.
+    pub phase: StorageMigrationPhase,
+    /// Files processed in the current phase.
+    pub files_done: usize,
+    /// Total files to migrate.
+    pub files_total: usize,
+    /// Bytes processed in the current phase.
+    pub bytes_done: u64,
+    /// Total bytes to migrate.
+    pub bytes_total: u64,
+    /// File currently being processed.
+    pub current_file: Option<String>,
+}
+
+/// Result of a completed storage directory migration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StorageMigrationResult {
+    /// Directory the data was copied from.
+    pub source: String,
+    /// Directory the data was copied to.
+    pub destination: String,
+    /// Number of files copied and verified.
+    pub files_copied: usize,
+    /// Number of bytes copied.
+    pub bytes_copied: u64,
+}
+
+/// Stable error code returned by backend commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// Device not found or not connected.
+    DeviceNotFound,
+    /// Device is not mounted.
+    DeviceNotMounted,
+    /// Device was disconnected during an operation.
+    DeviceDisconnected,
+    /// Device is read-only.
+    DeviceReadOnly,
+    /// Device is busy.
+    DeviceBusy,
+    /// Not enough space on the device.
+    InsufficientSpace,
+    /// Access to a device or file was denied.
+    PermissionDenied,
+    /// The operation is locked by simple mode and needs the parent PIN.
+    PinRequired,
+    /// Device file system is not supported.
+    UnsupportedFileSystem,
+    /// Mounting or unmounting a device failed.
+    MountFailed,
+    /// Operation is not supported on this platform.
+    PlatformNotSupported,
+    /// Other device error.
+    DeviceError,
+    /// URL is not a valid `YouTube` URL.
+    InvalidUrl,
+    /// URL is not a playlist.
+    NotAPlaylist,
+    /// Network request failed.
+    Network,
+    /// Operation timed out.
+    Timeout,
+    /// Rate limited by the remote service.
+    RateLimited,
+    /// Video is unavailable.
+    VideoUnavailable,
+    /// Audio extraction or conversion failed.
+    ConversionFailed,
+    /// Playlist could not be parsed.
+    PlaylistParseFailed,
+    /// Network access is disabled by offline mode.
+    Offline,
+    /// Library size limit reached.
+    LibraryLimitReached,
+    /// Not enough free disk space for the library.
+    LowDiskSpace,
+    /// Other download error.
+    DownloadFailed,
+    /// Transfer was interrupted.
+    TransferInterrupted,
+    /// Integrity check failed.
+    IntegrityCheckFailed,
+    /// Other transfer or sync error.
+    TransferFailed,
+    /// Playlist not found.
+    PlaylistNotFound,
+    /// Playlist already exists.
+    PlaylistExists,
+    /// Playlist name is invalid.
+    InvalidPlaylistName,
+    /// Playlist metadata is corrupted.
+    PlaylistCorrupted,
+    /// Playlist has no tracks.
+    PlaylistEmpty,
+    /// Track not found.
+    TrackNotFound,
+    /// Cache error.
+    CacheError,
+    /// File or directory not found.
+    NotFound,
+    /// File or directory already exists.
+    AlreadyExists,
+    /// Path is invalid.
+    InvalidPath,
+    /// Other file system error.
+    FileSystemError,
+    /// Configuration is invalid.
+    Configuration,
+    /// I/O error.
+    Io,
+    /// Serialization error.
+    Serialization,
+    /// Secrets store error.
+    Secrets,
+    /// Operation was cancelled.
+    Cancelled,
+    /// A conflicting task is already running.
+    TaskBusy,
+    /// Invalid input to a command.
+    InvalidInput,
+    /// Internal/unexpected error.
+    Internal,
+    /// A code added by a newer backend.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Structured error returned by backend commands.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CommandError {
+    /// Stable machine-readable error code.
+    pub code: ErrorCode,
+    /// Backend error message.
+    pub message: String,
+    /// Translatable description of the error.
+    #[serde(default)]
+    pub message_i18n: Option<Message>,
+    /// Whether the error can be retried.
+    pub retryable: bool,
+    /// Suggested retry delay in seconds, if applicable.
+    pub retry_delay_secs: Option<u64>,
+    /// The running task that blocked this one, for `task_busy` errors.
+    #[serde(default)]
+    pub conflicting_task_id: Option<TaskId>,
+}
+
+impl CommandError {
+    /// Message to show the user for this error.
+    ///
+    /// This is the single place where error codes are mapped to user-facing
+    /// text; codes without a dedicated message fall back to the backend message.
+    #[must_use]
+    pub fn user_message(&self, locale: Locale) -> String {
+        let key = match self.code {
+            ErrorCode::DeviceNotFound => "command_error.device_not_found",
+            ErrorCode::DeviceNotMounted => "command_error.device_not_mounted",
+            ErrorCode::DeviceDisconnected => "command_error.device_disconnected",
+            ErrorCode::DeviceReadOnly => "command_error.device_read_only",
+            ErrorCode::DeviceBusy => "command_error.device_busy",
+            ErrorCode::InsufficientSpace => "command_error.insufficient_space",
+            ErrorCode::PermissionDenied => "command_error.permission_denied",
+            ErrorCode::UnsupportedFileSystem => "command_error.unsupported_file_system",
+            ErrorCode::PlatformNotSupported => "command_error.platform_not_supported",
+            ErrorCode::NotAPlaylist => "command_error.not_a_playlist",
+            ErrorCode::Network => "command_error.network",
+            ErrorCode::Timeout => "command_error.timeout",
+            ErrorCode::RateLimited => {
+                return self.retry_delay_secs.map_or_else(
+                    || translate(locale, "command_error.rate_limited", &[]),
+                    |secs| {
+                        translate(
+                            locale,
+                            "command_error.rate_limited_for",
+                            &[("seconds", &secs.to_string())],
+                        )
+                    },
+                );
+            }
+            ErrorCode::VideoUnavailable => "command_error.video_unavailable",
+            ErrorCode::Offline => "command_error.offline",
+            ErrorCode::LibraryLimitReached => "command_error.library_limit_reached",
+            ErrorCode::LowDiskSpace => "command_error.low_disk_space",
+            ErrorCode::TransferInterrupted => "command_error.transfer_interrupted",
+            ErrorCode::IntegrityCheckFailed => "command_error.integrity_check_failed",
+            ErrorCode::PlaylistNotFound => "command_error.playlist_not_found",
+            ErrorCode::PlaylistExists => "command_error.playlist_exists",
+            ErrorCode::PlaylistEmpty => "command_error.playlist_empty",
+            ErrorCode::Cancelled => "command_error.cancelled",
+            ErrorCode::TaskBusy => "command_error.task_busy",
+            _ => return self.message.clone(),
+        };
+        translate(locale, key, &[])
+    }
+}
+
+/// Whether simple mode is on and a parent PIN is set.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ParentalStatus {
+    /// Whether destructive operations need the parent PIN.
+    pub simple_mode: bool,
+    /// Whether a parent PIN has been set.
+    pub pin_set: bool,
+}
+
+/// Unique identifier for a spawned task.
+pub type TaskId = u64;
+
+/// Running task count by category.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TaskCount {
+    /// Task category name.
+    pub category: String,
+    /// Number of running tasks in this category.
+    pub count: usize,
+}
+
+/// What a "stop everything" request cancelled.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CancelledTasks {
+    /// Cancellable runtime tasks signalled.
+    pub runtime_tasks: usize,
+    /// Downloads whose cancel flag was set.
+    pub downloads: usize,
+    /// Syncs whose cancel flag was set.
+    pub syncs: usize,
+    /// Queue items that were cancelled.
+    pub queue_items: Vec<u64>,
+    /// Device cleanups whose cancel flag was set.
+    pub cleanups: usize,
+}
+
+impl CancelledTasks {
+    /// Total number of cancelled tasks.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.runtime_tasks + self.downloads + self.syncs + self.queue_items.len() + self.cleanups
+    }
+}
+
+/// What an interrupted task was doing.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PersistedTaskKind {
+    /// Download of a `YouTube` playlist into a directory.
+    PlaylistDownload {
+        /// Playlist URL.
+        url: String,
+        /// Output directory.
+        output_dir: String,
+    },
+    /// Download of a `YouTube` playlist into a library playlist.
+    LibraryDownload {
+        /// Playlist URL.
+        url: String,
+        /// Name of the library playlist.
+        playlist_name: String,
+    },
+    /// Download started from the download queue.
+    QueueDownload {
+        /// The queued request.
+        request: DownloadRequest,
+    },
+    /// Sync of a playlist to a device.
+    Sync {
+        /// Playlist being synced.
+        playlist_name: String,
+        /// Device mount point.
+        device_mount_point: String,
+        /// Whether the sync uses integrity verification.
+        verify_integrity: bool,
+        /// Whether to skip existing files.
+        skip_existing: bool,
+    },
+}
+
+/// Status of a journaled task.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PersistedTaskStatus {
+    /// Running in this session.
+    Running,
+    /// Was running when the application last stopped.
+    Interrupted,
+}
+
+/// A task interrupted by a crash or forced quit, which can be resumed or
+/// marked failed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PersistedTask {
+    /// Journal identifier.
+    pub id: u64,
+    /// What the task was doing.
+    pub kind: PersistedTaskKind,
+    /// Human-readable description.
+    pub description: String,
+    /// Current status.
+    pub status: PersistedTaskStatus,
+    /// When the task started (Unix seconds).
+    pub started_at: u64,
+}
+
+/// Feature area a unified task event belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskEventCategory {
+    /// Single playlist sync to a device.
+    Sync,
+    /// Multi-playlist orchestrated sync.
+    OrchestratedSync,
+    /// `YouTube` playlist download.
+    Download,
+    /// Download queue item or queue state.
+    Queue,
+    /// Cache warming.
+    Cache,
+    /// Integrity verification.
+    Verification,
+    /// Maintenance operation (checksum manifests, folder repair, cache clearing).
+    Maintenance,
+    /// Raw file transfer.
+    Transfer,
+}
+
+/// Lifecycle phase of a unified task event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskPhase {
+    /// A task was added without being started (queue items).
+    Queued,
+    /// The task started.
+    Started,
+    /// The task reported progress.
+    Progress,
+    /// The task finished successfully.
+    Completed,
+    /// The task failed.
+    Failed,
+    /// The task was cancelled.
+    Cancelled,
+    /// The task was removed without running (queue items).
+    Removed,
+    /// The task's container was paused (queue).
+    Paused,
+    /// The task's container was resumed (queue).
+    Resumed,
+    /// The task's container settings changed (queue).
+    Updated,
+}
+
+/// A single event on the unified `task-event` channel.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TaskEvent {
+    /// Task identifier (queue item ID for queue events), if known.
+    pub task_id: Option<u64>,
+    /// Feature area the event belongs to.
+    pub category: TaskEventCategory,
+    /// Lifecycle phase.
+    pub phase: TaskPhase,
+    /// Overall progress between 0.0 and 1.0, if known.
+    pub progress: Option<f64>,
+    /// Name of the deprecated per-feature event this mirrors.
+    pub event: String,
+    /// The per-feature payload.
+    pub payload: serde_json::Value,
+}
+
+/// A maintenance operation to run in the background.
+///
+/// This mirrors the backend's `MaintenanceOperation` enum.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MaintenanceOperation {
+    /// Create a checksum manifest for a playlist folder.
+    CreateChecksumManifest {
+        /// Playlist name.
+        playlist: String,
+    },
+    /// Repair a playlist folder.
+    RepairPlaylistFolder {
+        /// Playlist name.
+        playlist: String,
+    },
+    /// Clear all cached data.
+    ClearCache,
+}
+
+impl MaintenanceOperation {
+    /// Title shown while the operation runs.
+    #[must_use]
+    pub fn title(&self, locale: Locale) -> String {
+        match self {
+            Self::CreateChecksumManifest { playlist } => {
+                translate(locale, "maintenance.checksums", &[("playlist", playlist)])
+            }
+            Self::RepairPlaylistFolder { playlist } => {
+                translate(locale, "maintenance.repair", &[("playlist", playlist)])
+            }
+            Self::ClearCache => translate(locale, "maintenance.clear_cache", &[]),
+        }
+    }
+}
+
+// =============================================================================
+// Transfer Types
+// =============================================================================
+
+/// Status of a transfer operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransferStatus {
+    /// Transfer is preparing (calculating sizes, etc.).
+    Preparing,
+    /// Transfer is actively copying files.
+    Transferring,
+    /// Transfer is verifying file integrity.
+    Verifying,
+    /// Transfer completed successfully.
+    Completed,
+    /// Transfer failed.
+    Failed,
+    /// Transfer was cancelled.
+    Cancelled,
+}
+
+impl std::fmt::Display for TransferStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Preparing => write!(f, "Preparing"),
@@ -766,6 +2323,68 @@ pub struct SyncResult {
     pub error_message: Option<String>,
 }
 
+/// What a sync would do to one file on the device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncChange {
+    /// The track isn't on the device and will be copied.
+    Add,
+    /// The track is on the device and will be copied again.
+    Replace,
+    /// The track is already on the device and will be kept.
+    Unchanged,
+    /// The file is in no selected playlist and will be deleted.
+    Remove,
+}
+
+impl std::fmt::Display for SyncChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Add => write!(f, "Add"),
+            Self::Replace => write!(f, "Replace"),
+            Self::Unchanged => write!(f, "Keep"),
+            Self::Remove => write!(f, "Remove"),
+        }
+    }
+}
+
+/// One file in a [`SyncPreview`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncPreviewEntry {
+    /// Playlist the track comes from, or `None` for files being removed.
+    pub playlist: Option<String>,
+    /// File path relative to the device mount point.
+    pub file_name: String,
+    /// File size in bytes.
+    pub size_bytes: u64,
+    /// What the sync will do with the file.
+    pub change: SyncChange,
+}
+
+/// Preview of the changes a sync would make to a device.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncPreview {
+    /// Every file the sync touches or keeps.
+    pub entries: Vec<SyncPreviewEntry>,
+    /// Bytes that will be written to the device.
+    pub bytes_to_write: u64,
+    /// Bytes that will be deleted from the device.
+    pub bytes_to_remove: u64,
+    /// Bytes of existing device copies that replaced tracks overwrite.
+    pub bytes_replaced: u64,
+}
+
+impl SyncPreview {
+    /// Number of entries with the given change.
+    #[must_use]
+    pub fn count(&self, change: SyncChange) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.change == change)
+            .count()
+    }
+}
+
 // =============================================================================
 // YouTube URL Validation Types
 // =============================================================================
@@ -781,57 +2400,502 @@ pub enum YouTubeUrlType {
     SingleVideo,
     /// Short URL (youtu.be/...)
     ShortUrl,
+    /// Channel ID URL (youtube.com/channel/UC...)
+    Channel,
+    /// Channel handle URL (youtube.com/@handle)
+    ChannelHandle,
+    /// Channel custom URL (youtube.com/c/name)
+    ChannelCustomUrl,
     /// Invalid or unrecognized URL
     #[default]
     Invalid,
 }
 
-impl std::fmt::Display for YouTubeUrlType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Playlist => write!(f, "Playlist"),
-            Self::WatchWithPlaylist => write!(f, "Watch with Playlist"),
-            Self::SingleVideo => write!(f, "Single Video"),
-            Self::ShortUrl => write!(f, "Short URL"),
-            Self::Invalid => write!(f, "Invalid"),
-        }
+impl std::fmt::Display for YouTubeUrlType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Playlist => write!(f, "Playlist"),
+            Self::WatchWithPlaylist => write!(f, "Watch with Playlist"),
+            Self::SingleVideo => write!(f, "Single Video"),
+            Self::ShortUrl => write!(f, "Short URL"),
+            Self::Channel | Self::ChannelHandle | Self::ChannelCustomUrl => write!(f, "Channel"),
+            Self::Invalid => write!(f, "Invalid"),
+        }
+    }
+}
+
+/// Number of videos of a `YouTube` Mix that are downloaded.
+pub const MAX_MIX_VIDEOS: usize = 50;
+
+/// Result of `YouTube` URL validation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct YouTubeUrlValidation {
+    /// Whether the URL is valid.
+    pub is_valid: bool,
+    /// The extracted playlist ID (if valid).
+    pub playlist_id: Option<String>,
+    /// The extracted video ID, for watch and short URLs.
+    #[serde(default)]
+    pub video_id: Option<String>,
+    /// The normalized/canonical URL.
+    pub normalized_url: Option<String>,
+    /// Error message if validation failed.
+    pub error_message: Option<String>,
+    /// The URL type detected.
+    pub url_type: YouTubeUrlType,
+}
+
+impl YouTubeUrlValidation {
+    /// Create a placeholder for pending validation.
+    #[must_use]
+    pub const fn pending() -> Self {
+        Self {
+            is_valid: false,
+            playlist_id: None,
+            video_id: None,
+            normalized_url: None,
+            error_message: None,
+            url_type: YouTubeUrlType::Invalid,
+        }
+    }
+
+    /// Check if this is a single video without a playlist that can be
+    /// downloaded on its own.
+    #[must_use]
+    pub const fn is_single_video(&self) -> bool {
+        !self.is_valid && self.video_id.is_some()
+    }
+
+    /// Check if this is a `YouTube` Mix, which never ends: only its first
+    /// [`MAX_MIX_VIDEOS`] videos are downloaded.
+    #[must_use]
+    pub fn is_mix(&self) -> bool {
+        self.playlist_id
+            .as_deref()
+            .is_some_and(|id| id.starts_with("RD"))
+    }
+
+    /// Check if this is a playlist URL (either standard or watch with playlist).
+    #[must_use]
+    pub const fn is_playlist_url(&self) -> bool {
+        matches!(
+            self.url_type,
+            YouTubeUrlType::Playlist | YouTubeUrlType::WatchWithPlaylist
+        )
+    }
+}
+
+/// Kind of URL found in dropped or pasted text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UrlCandidateKind {
+    /// A `YouTube` playlist that can be downloaded.
+    Playlist,
+    /// A single `YouTube` video without a playlist.
+    Video,
+    /// Not a usable `YouTube` URL.
+    Invalid,
+}
+
+/// A URL found in dropped or pasted text, classified for ingestion.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UrlCandidate {
+    /// The URL as found in the text.
+    pub url: String,
+    /// What the URL points to.
+    pub kind: UrlCandidateKind,
+    /// Playlist ID, for playlists.
+    pub playlist_id: Option<String>,
+    /// Canonical playlist URL, for playlists.
+    pub normalized_url: Option<String>,
+    /// Why the URL cannot be added as a playlist, for videos and invalid URLs.
+    pub message: Option<String>,
+}
+
+/// Information about a `YouTube` playlist, without its videos.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistInfo {
+    /// Playlist ID.
+    pub id: String,
+    /// Playlist title.
+    pub title: String,
+    /// Number of videos in the playlist.
+    pub video_count: usize,
+    /// Thumbnail URL for the playlist (or first video's thumbnail).
+    pub thumbnail_url: Option<String>,
+    /// Availability of the videos when the playlist was fetched.
+    #[serde(default)]
+    pub health: PlaylistHealth,
+}
+
+/// Whether a video of a playlist can be downloaded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VideoAvailability {
+    /// The video can be downloaded.
+    #[default]
+    Available,
+    /// The uploader made the video private.
+    Private,
+    /// The video was deleted.
+    Deleted,
+    /// The video can't be played in this country.
+    GeoBlocked,
+}
+
+/// How many videos of a playlist can be downloaded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistHealth {
+    /// Videos that can be downloaded.
+    pub available: usize,
+    /// Private videos.
+    pub private: usize,
+    /// Deleted videos.
+    pub deleted: usize,
+    /// Videos blocked in this country.
+    pub geo_blocked: usize,
+}
+
+impl PlaylistHealth {
+    /// Number of videos that can't be downloaded.
+    #[must_use]
+    pub const fn unavailable(&self) -> usize {
+        self.private + self.deleted + self.geo_blocked
+    }
+}
+
+/// A video of a playlist that can't be downloaded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnavailableVideo {
+    /// `YouTube` video ID.
+    pub video_id: String,
+    /// Video title, a placeholder for private and deleted videos.
+    pub title: String,
+    /// Why the video can't be downloaded.
+    pub availability: VideoAvailability,
+}
+
+/// Availability of the videos of a playlist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistHealthReport {
+    /// Playlist ID.
+    pub playlist_id: String,
+    /// Playlist title.
+    pub title: String,
+    /// Video counts by availability.
+    pub health: PlaylistHealth,
+    /// Videos that can't be downloaded.
+    pub unavailable: Vec<UnavailableVideo>,
+}
+
+/// A video that a playlist update will download.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NewVideo {
+    /// `YouTube` video ID.
+    pub id: String,
+    /// Video title.
+    pub title: String,
+    /// Video duration in seconds.
+    #[serde(default)]
+    pub duration_secs: Option<u64>,
+}
+
+/// A downloaded video whose title changed on `YouTube`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenamedVideo {
+    /// `YouTube` video ID.
+    pub video_id: String,
+    /// Title when the video was downloaded.
+    pub old_title: String,
+    /// Current title.
+    pub new_title: String,
+}
+
+/// A downloaded video that moved within the playlist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MovedVideo {
+    /// `YouTube` video ID.
+    pub video_id: String,
+    /// Current title.
+    pub title: String,
+    /// Position at the end of the last download, starting at 1.
+    pub old_position: usize,
+    /// Current position, starting at 1.
+    pub new_position: usize,
+}
+
+/// Changes of a playlist on `YouTube` since it was last downloaded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistDiff {
+    /// Playlist ID.
+    pub playlist_id: String,
+    /// Playlist title.
+    pub title: String,
+    /// Videos that will be downloaded, in playlist order.
+    pub new_videos: Vec<NewVideo>,
+    /// Downloaded videos no longer in the playlist.
+    pub removed_videos: Vec<ArchivedVideo>,
+    /// Downloaded videos whose title changed.
+    pub renamed_videos: Vec<RenamedVideo>,
+    /// Downloaded videos that moved within the playlist.
+    pub moved_videos: Vec<MovedVideo>,
+    /// New videos that can't be downloaded.
+    pub unavailable_count: usize,
+    /// Videos of the playlist that were downloaded before.
+    pub downloaded_count: usize,
+}
+
+impl PlaylistDiff {
+    /// One-line summary in `locale`, e.g. "12 new tracks will be downloaded,
+    /// 1 removed".
+    #[must_use]
+    pub fn summary(&self, locale: Locale) -> String {
+        let mut parts = Vec::new();
+        for (count, key) in [
+            (self.new_videos.len(), "playlist_diff.new"),
+            (self.removed_videos.len(), "playlist_diff.removed"),
+            (self.renamed_videos.len(), "playlist_diff.renamed"),
+            (self.moved_videos.len(), "playlist_diff.moved"),
+            (self.unavailable_count, "playlist_diff.unavailable"),
+        ] {
+            if count > 0 {
+                parts.push(translate_count(locale, key, count));
+            }
+        }
+        if parts.is_empty() {
+            translate(locale, "playlist_diff.up_to_date", &[])
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+/// Which results a `YouTube` search returns.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchFilter {
+    /// Videos and playlists.
+    #[default]
+    All,
+    /// Videos only.
+    Videos,
+    /// Playlists only.
+    Playlists,
+}
+
+/// Kind of a `YouTube` search result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchResultKind {
+    /// A single video.
+    Video,
+    /// A playlist or mix.
+    Playlist,
+}
+
+/// A video or playlist found by a `YouTube` search.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchResult {
+    /// Whether the result is a video or a playlist.
+    pub kind: SearchResultKind,
+    /// Video or playlist ID.
+    pub id: String,
+    /// Title.
+    pub title: String,
+    /// Channel of the video or playlist.
+    #[serde(default)]
+    pub channel: Option<String>,
+    /// Duration of a video in seconds.
+    #[serde(default)]
+    pub duration_secs: Option<u64>,
+    /// Number of videos of a playlist.
+    #[serde(default)]
+    pub video_count: Option<usize>,
+    /// Thumbnail URL.
+    #[serde(default)]
+    pub thumbnail_url: Option<String>,
+}
+
+impl SearchResult {
+    /// URL of the video or playlist.
+    #[must_use]
+    pub fn url(&self) -> String {
+        match self.kind {
+            SearchResultKind::Video => format!("https://www.youtube.com/watch?v={}", self.id),
+            SearchResultKind::Playlist => {
+                format!("https://www.youtube.com/playlist?list={}", self.id)
+            }
+        }
+    }
+
+    /// Duration of a video or size of a playlist in `locale`, e.g. "3:45" or
+    /// "12 videos".
+    #[must_use]
+    pub fn formatted_length(&self, locale: Locale) -> Option<String> {
+        match self.kind {
+            SearchResultKind::Video => self.duration_secs.map(|secs| {
+                let (hours, mins, secs) = (secs / 3600, secs / 60 % 60, secs % 60);
+                if hours > 0 {
+                    format!("{hours}:{mins:02}:{secs:02}")
+                } else {
+                    format!("{mins}:{secs:02}")
+                }
+            }),
+            SearchResultKind::Playlist => self
+                .video_count
+                .map(|count| translate_count(locale, "count.videos", count)),
+        }
+    }
+}
+
+/// Estimated size and time of downloading a playlist.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistDownloadEstimate {
+    /// Playlist ID.
+    pub playlist_id: String,
+    /// Playlist title.
+    pub title: String,
+    /// Number of videos in the playlist.
+    pub video_count: usize,
+    /// Number of videos whose duration was unknown and had to be guessed.
+    pub unknown_duration_count: usize,
+    /// Total audio duration in seconds.
+    pub total_duration_secs: u64,
+    /// Bitrate the estimate assumes, in kbps.
+    pub bitrate_kbps: u32,
+    /// Estimated total size of the MP3 files in bytes.
+    pub estimated_size_bytes: u64,
+    /// Estimated download time in seconds.
+    pub estimated_download_secs: u64,
+}
+
+impl PlaylistDownloadEstimate {
+    /// Format the estimated size as a human-readable string (e.g. "3.20 GB").
+    #[must_use]
+    pub fn formatted_size(&self) -> String {
+        format_bytes(self.estimated_size_bytes)
+    }
+
+    /// Format the estimated download time (e.g. "~1h 05m" or "~4m").
+    #[must_use]
+    pub fn formatted_time(&self) -> String {
+        let mins = self.estimated_download_secs.div_ceil(60);
+        if mins >= 60 {
+            format!("~{}h {:02}m", mins / 60, mins % 60)
+        } else {
+            format!("~{mins}m")
+        }
+    }
+}
+
+/// Size and length of a playlist's audio, estimated from the format
+/// metadata of a sample of its videos.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlaylistSizeEstimate {
+    /// Playlist ID.
+    pub playlist_id: String,
+    /// Playlist title.
+    pub title: String,
+    /// Number of videos that can be downloaded.
+    pub video_count: usize,
+    /// Number of videos whose format metadata was looked up.
+    pub sampled_count: usize,
+    /// Total audio length in seconds.
+    pub total_duration_secs: u64,
+    /// Bitrate of the saved files, in kbps.
+    pub bitrate_kbps: u32,
+    /// Whether the streams are saved as is rather than converted to MP3.
+    pub original_audio: bool,
+    /// Estimated total size of the saved files in bytes.
+    pub estimated_size_bytes: u64,
+}
+
+impl PlaylistSizeEstimate {
+    /// Summary of the estimate in `locale` (e.g. "~620 MB, 4h12m of audio").
+    #[must_use]
+    pub fn summary(&self, locale: Locale) -> String {
+        let size = if self.estimated_size_bytes >= 1_000_000_000 {
+            format!(
+                "{:.1} GB",
+                self.estimated_size_bytes as f64 / 1_000_000_000.0
+            )
+        } else {
+            format!("{} MB", self.estimated_size_bytes.div_ceil(1_000_000))
+        };
+        let mins = self.total_duration_secs.div_ceil(60);
+        let length = if mins >= 60 {
+            format!("{}h{:02}m", mins / 60, mins % 60)
+        } else {
+            format!("{mins}m")
+        };
+        translate(
+            locale,
+            "size_estimate.summary",
+            &[("size", &size), ("length", &length)],
+        )
+    }
+}
+
+/// What downloading a video would do, found by a dry run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedDownload {
+    /// Video ID.
+    pub video_id: String,
+    /// Video title.
+    pub title: String,
+    /// File the video would be saved as, or already is.
+    pub output_path: String,
+    /// Whether the video is already in the playlist folder.
+    pub already_downloaded: bool,
+    /// Why the video can't be downloaded, if it can't.
+    pub unavailable_reason: Option<String>,
+    /// Length of the video in seconds, if known.
+    pub duration_secs: Option<u64>,
+    /// Estimated size of the saved file in bytes.
+    pub estimated_size_bytes: u64,
+    /// Whether the estimate comes from the stream's format metadata.
+    pub from_format: bool,
+}
+
+impl PlannedDownload {
+    /// Whether the video would be downloaded.
+    #[must_use]
+    pub const fn will_download(&self) -> bool {
+        !self.already_downloaded && self.unavailable_reason.is_none()
     }
 }
 
-/// Result of `YouTube` URL validation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct YouTubeUrlValidation {
-    /// Whether the URL is valid.
-    pub is_valid: bool,
-    /// The extracted playlist ID (if valid).
-    pub playlist_id: Option<String>,
-    /// The normalized/canonical URL.
-    pub normalized_url: Option<String>,
-    /// Error message if validation failed.
-    pub error_message: Option<String>,
-    /// The URL type detected.
-    pub url_type: YouTubeUrlType,
+/// What downloading a playlist would do, worked out without downloading.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownloadPlan {
+    /// Playlist ID.
+    pub playlist_id: String,
+    /// Playlist title.
+    pub title: String,
+    /// Bitrate the estimates assume, in kbps.
+    pub bitrate_kbps: u32,
+    /// Each video of the playlist, in order.
+    pub videos: Vec<PlannedDownload>,
 }
 
-impl YouTubeUrlValidation {
-    /// Create a placeholder for pending validation.
+impl DownloadPlan {
+    /// Number of videos that would be downloaded.
     #[must_use]
-    pub const fn pending() -> Self {
-        Self {
-            is_valid: false,
-            playlist_id: None,
-            normalized_url: None,
-            error_message: None,
-            url_type: YouTubeUrlType::Invalid,
-        }
+    pub fn download_count(&self) -> usize {
+        self.videos
+            .iter()
+            .filter(|video| video.will_download())
+            .count()
     }
 
-    /// Check if this is a playlist URL (either standard or watch with playlist).
+    /// Format the estimated size of the new files (e.g. "3.20 GB").
     #[must_use]
-    pub const fn is_playlist_url(&self) -> bool {
-        matches!(
-            self.url_type,
-            YouTubeUrlType::Playlist | YouTubeUrlType::WatchWithPlaylist
+    pub fn formatted_size(&self) -> String {
+        format_bytes(
+            self.videos
+                .iter()
+                .map(|video| video.estimated_size_bytes)
+                .sum(),
         )
     }
 }
@@ -871,6 +2935,17 @@ impl std::fmt::Display for DownloadStatus {
     }
 }
 
+/// Progress of fetching the video list of a long playlist, page by page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlaylistFetchProgress {
+    /// Task ID of the download fetching the playlist.
+    pub task_id: TaskId,
+    /// Number of pages fetched so far.
+    pub pages_fetched: usize,
+    /// Number of videos found so far.
+    pub videos_found: usize,
+}
+
 /// Progress information for a `YouTube` download operation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DownloadProgress {
@@ -892,6 +2967,10 @@ pub struct DownloadProgress {
     pub current_bytes: u64,
     /// Total bytes for the current file (if known).
     pub current_total_bytes: Option<u64>,
+    /// Byte offset the current file's download resumed from (0 when it
+    /// started from the beginning).
+    #[serde(default)]
+    pub resumed_from_bytes: u64,
     /// Total bytes downloaded across all files.
     pub total_bytes_downloaded: u64,
     /// Download speed in bytes per second.
@@ -912,199 +2991,886 @@ pub struct DownloadProgress {
     pub videos_skipped: usize,
     /// Number of videos that failed.
     pub videos_failed: usize,
+    /// Number of videos being downloaded at the same time.
+    #[serde(default)]
+    pub active_downloads: usize,
+}
+
+impl DownloadProgress {
+    /// Calculate the overall progress as a percentage (0.0 - 100.0).
+    #[must_use]
+    pub fn overall_progress_percent(&self) -> f64 {
+        self.overall_progress * 100.0
+    }
+
+    /// Calculate the current video progress as a percentage (0.0 - 100.0).
+    #[must_use]
+    pub fn current_progress_percent(&self) -> f64 {
+        self.current_progress * 100.0
+    }
+
+    /// Percentage (0.0 - 100.0) of the current video's stream downloaded,
+    /// while it downloads and its size is known.
+    #[must_use]
+    pub fn current_bytes_percent(&self) -> Option<f64> {
+        let total = self.current_total_bytes.filter(|&total| total > 0)?;
+        (self.status == "downloading")
+            .then(|| (self.current_bytes as f64 / total as f64 * 100.0).min(100.0))
+    }
+
+    /// Check if the download is actively in progress.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self.status.as_str(),
+            "downloading" | "converting" | "starting"
+        )
+    }
+
+    /// Check if the download has completed (successfully or with errors).
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status.as_str(), "completed" | "cancelled")
+            || self.status.starts_with("failed")
+    }
+}
+
+/// Category of YouTube-related errors for UI display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum YouTubeErrorCategory {
+    /// Network connection issues (no internet, DNS failure, timeout).
+    Network,
+    /// `YouTube` service issues (rate limiting, service unavailable).
+    YouTubeService,
+    /// Invalid or malformed URL.
+    InvalidUrl,
+    /// Playlist not found or is private.
+    PlaylistNotFound,
+    /// Video is unavailable (private, deleted, region-locked).
+    VideoUnavailable,
+    /// Age-restricted content requiring authentication.
+    AgeRestricted,
+    /// Geographic restriction on content.
+    GeoRestricted,
+    /// Failed to extract or download audio stream.
+    AudioExtraction,
+    /// File system error (disk full, permission denied).
+    FileSystem,
+    /// Operation was cancelled by user.
+    Cancelled,
+    /// Library size limit or minimum free disk space reached.
+    LibrarySpace,
+    /// Unknown or unclassified error.
+    #[default]
+    Unknown,
+}
+
+impl YouTubeErrorCategory {
+    /// Message key of a user-friendly title for this error category.
+    #[must_use]
+    pub const fn title_key(&self) -> &'static str {
+        match self {
+            Self::Network => "youtube_error.network.title",
+            Self::YouTubeService => "youtube_error.youtube_service.title",
+            Self::InvalidUrl => "youtube_error.invalid_url.title",
+            Self::PlaylistNotFound => "youtube_error.playlist_not_found.title",
+            Self::VideoUnavailable => "youtube_error.video_unavailable.title",
+            Self::AgeRestricted => "youtube_error.age_restricted.title",
+            Self::GeoRestricted => "youtube_error.geo_restricted.title",
+            Self::AudioExtraction => "youtube_error.audio_extraction.title",
+            Self::FileSystem => "youtube_error.file_system.title",
+            Self::Cancelled => "youtube_error.cancelled.title",
+            Self::LibrarySpace => "youtube_error.library_space.title",
+            Self::Unknown => "youtube_error.unknown.title",
+        }
+    }
+
+    /// Message key of a user-friendly description for this error category.
+    #[must_use]
+    pub const fn description_key(&self) -> &'static str {
+        match self {
+            Self::Network => "youtube_error.network.description",
+            Self::YouTubeService => "youtube_error.youtube_service.description",
+            Self::InvalidUrl => "youtube_error.invalid_url.description",
+            Self::PlaylistNotFound => "youtube_error.playlist_not_found.description",
+            Self::VideoUnavailable => "youtube_error.video_unavailable.description",
+            Self::AgeRestricted => "youtube_error.age_restricted.description",
+            Self::GeoRestricted => "youtube_error.geo_restricted.description",
+            Self::AudioExtraction => "youtube_error.audio_extraction.description",
+            Self::FileSystem => "youtube_error.file_system.description",
+            Self::Cancelled => "youtube_error.cancelled.description",
+            Self::LibrarySpace => "youtube_error.library_space.description",
+            Self::Unknown => "youtube_error.unknown.description",
+        }
+    }
+
+    /// Check if this error category is retryable.
+    #[must_use]
+    pub const fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::Network | Self::YouTubeService | Self::AudioExtraction
+        )
+    }
+}
+
+impl std::fmt::Display for YouTubeErrorCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", translate(Locale::default(), self.title_key(), &[]))
+    }
+}
+
+/// Result of a download operation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DownloadResult {
+    /// Task ID for this download operation.
+    pub task_id: TaskId,
+    /// Whether the overall download was successful.
+    pub success: bool,
+    /// Number of videos successfully downloaded.
+    pub successful_count: usize,
+    /// Number of videos that failed.
+    pub failed_count: usize,
+    /// Number of videos skipped (already exist).
+    pub skipped_count: usize,
+    /// Total number of videos in the playlist.
+    pub total_count: usize,
+    /// Individual video results.
+    pub results: Vec<VideoDownloadResult>,
+    /// Error message if the overall operation failed.
+    pub error_message: Option<String>,
+    /// Category of error for UI display (if failed).
+    pub error_category: Option<YouTubeErrorCategory>,
+    /// User-friendly error title.
+    pub error_title: Option<String>,
+    /// User-friendly error description with suggested action.
+    pub error_description: Option<String>,
+    /// URL that was downloaded.
+    #[serde(default)]
+    pub source_url: Option<String>,
+    /// Library playlist the download was saved into, if any.
+    #[serde(default)]
+    pub playlist_name: Option<String>,
+}
+
+impl DownloadResult {
+    /// Get the display error message, preferring the user-friendly description.
+    #[must_use]
+    pub fn display_error(&self) -> Option<String> {
+        if let Some(ref desc) = self.error_description {
+            Some(desc.clone())
+        } else {
+            self.error_message.clone()
+        }
+    }
+
+    /// Get the error title for display in `locale`.
+    #[must_use]
+    pub fn display_error_title(&self, locale: Locale) -> String {
+        self.error_title
+            .clone()
+            .or_else(|| {
+                self.error_category
+                    .map(|c| translate(locale, c.title_key(), &[]))
+            })
+            .unwrap_or_else(|| translate(locale, "youtube_error.download_failed", &[]))
+    }
+
+    /// Check if the error is retryable.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        self.error_category.is_some_and(|c| c.is_retryable())
+    }
+    /// URL and playlist to download again when retrying, if the download went
+    /// into a library playlist and its error is retryable.
+    #[must_use]
+    pub fn retry_target(&self) -> Option<(String, String)> {
+        if !self.is_retryable() {
+            return None;
+        }
+        Some((self.source_url.clone()?, self.playlist_name.clone()?))
+    }
+
+    /// URL and playlist to download again to fetch the videos that failed.
+    ///
+    /// Downloads skip tracks already in the playlist, so downloading the
+    /// source again only fetches the missing videos.
+    #[must_use]
+    pub fn failed_videos_retry_target(&self) -> Option<(String, String)> {
+        if self.failed_count == 0 {
+            return None;
+        }
+        Some((self.source_url.clone()?, self.playlist_name.clone()?))
+    }
+}
+
+/// What happened to a single video of a playlist download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoOutcome {
+    /// Downloaded.
+    Completed,
+    /// Failed with an error.
+    Failed,
+    /// Skipped because the track already exists.
+    Skipped,
+}
+
+/// Result of downloading a single video.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoDownloadResult {
+    /// Video ID.
+    pub video_id: String,
+    /// Video title.
+    pub title: String,
+    /// Whether the download was successful.
+    pub success: bool,
+    /// Output file path (if successful).
+    pub output_path: Option<String>,
+    /// Error message (if failed).
+    pub error: Option<String>,
+    /// Seconds of `SponsorBlock` segments removed from the track.
+    #[serde(default)]
+    pub sponsor_secs_removed: f64,
+    /// Audio bitrate of the downloaded stream in kbps, if known.
+    #[serde(default)]
+    pub audio_bitrate_kbps: Option<u32>,
+    /// Audio codec of the downloaded stream, if known.
+    #[serde(default)]
+    pub audio_codec: Option<String>,
+    /// Kind of stream the video was downloaded from.
+    #[serde(default)]
+    pub stream_path: Option<StreamPath>,
+    /// Number of tracks the video was split into by chapters, 0 when it was
+    /// kept whole.
+    #[serde(default)]
+    pub chapter_count: usize,
+}
+
+/// Which kind of stream a video was downloaded from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamPath {
+    /// An audio-only stream.
+    AudioOnly,
+    /// A combined video and audio stream, after the audio-only streams were
+    /// refused.
+    CombinedFallback,
+    /// A combined video and audio stream, the video having no usable
+    /// audio-only stream.
+    Combined,
+}
+
+impl VideoDownloadResult {
+    /// Whether the video was downloaded, failed or skipped.
+    #[must_use]
+    pub const fn outcome(&self) -> VideoOutcome {
+        if self.success {
+            VideoOutcome::Completed
+        } else if self.error.is_some() {
+            VideoOutcome::Failed
+        } else {
+            VideoOutcome::Skipped
+        }
+    }
+
+    /// Bitrate and codec of the downloaded stream, e.g. "160 kbps opus",
+    /// noting combined video streams.
+    #[must_use]
+    pub fn stream_label(&self) -> Option<String> {
+        let label = match (self.audio_bitrate_kbps, self.audio_codec.as_deref()) {
+            (Some(kbps), Some(codec)) => Some(format!("{kbps} kbps {codec}")),
+            (Some(kbps), None) => Some(format!("{kbps} kbps")),
+            (None, Some(codec)) => Some(codec.to_string()),
+            (None, None) => None,
+        };
+        let kind = match self.stream_path {
+            None | Some(StreamPath::AudioOnly) => return label,
+            Some(StreamPath::CombinedFallback) => "video stream, audio-only refused",
+            Some(StreamPath::Combined) => "video stream",
+        };
+        Some(label.map_or_else(|| kind.to_string(), |label| format!("{label} ({kind})")))
+    }
+}
+
+/// A video in a playlist's download archive.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchivedVideo {
+    /// `YouTube` video ID.
+    pub video_id: String,
+    /// Video title when it was downloaded.
+    pub title: String,
+    /// File name the video was saved as, if known.
+    pub file_name: Option<String>,
+    /// When the video was archived (seconds since the Unix epoch).
+    pub archived_at: u64,
+    /// Position of the video in the playlist at the end of the last
+    /// download, if known.
+    #[serde(default)]
+    pub position: Option<usize>,
+}
+
+// =============================================================================
+// Download Queue Types
+// =============================================================================
+
+/// Queue item identifier.
+pub type QueueItemId = u64;
+
+/// Priority level for download queue items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum DownloadPriority {
+    /// Processed after normal and high priority items.
+    Low,
+    /// Normal priority (default).
+    #[default]
+    Normal,
+    /// Processed before normal and low priority items.
+    High,
 }
 
-impl DownloadProgress {
-    /// Calculate the overall progress as a percentage (0.0 - 100.0).
+impl DownloadPriority {
+    /// Name accepted by `queue_set_priority`.
     #[must_use]
-    pub fn overall_progress_percent(&self) -> f64 {
-        self.overall_progress * 100.0
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Normal => "normal",
+            Self::High => "high",
+        }
     }
 
-    /// Calculate the current video progress as a percentage (0.0 - 100.0).
+    /// The next priority up, if any.
     #[must_use]
-    pub fn current_progress_percent(&self) -> f64 {
-        self.current_progress * 100.0
+    pub const fn raised(self) -> Option<Self> {
+        match self {
+            Self::Low => Some(Self::Normal),
+            Self::Normal => Some(Self::High),
+            Self::High => None,
+        }
     }
 
-    /// Check if the download is actively in progress.
+    /// The next priority down, if any.
     #[must_use]
-    pub fn is_active(&self) -> bool {
-        matches!(
-            self.status.as_str(),
-            "downloading" | "converting" | "starting"
-        )
+    pub const fn lowered(self) -> Option<Self> {
+        match self {
+            Self::Low => None,
+            Self::Normal => Some(Self::Low),
+            Self::High => Some(Self::Normal),
+        }
     }
 
-    /// Check if the download has completed (successfully or with errors).
+    /// Message key of the label.
     #[must_use]
-    pub fn is_finished(&self) -> bool {
-        matches!(self.status.as_str(), "completed" | "cancelled")
-            || self.status.starts_with("failed")
+    pub const fn label_key(self) -> &'static str {
+        match self {
+            Self::Low => "queue.priority.low",
+            Self::Normal => "queue.priority.normal",
+            Self::High => "queue.priority.high",
+        }
     }
 }
 
-/// Category of YouTube-related errors for UI display.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+impl std::fmt::Display for DownloadPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Low => write!(f, "Low"),
+            Self::Normal => write!(f, "Normal"),
+            Self::High => write!(f, "High"),
+        }
+    }
+}
+
+/// Status of a queue item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
-pub enum YouTubeErrorCategory {
-    /// Network connection issues (no internet, DNS failure, timeout).
-    Network,
-    /// `YouTube` service issues (rate limiting, service unavailable).
-    YouTubeService,
-    /// Invalid or malformed URL.
-    InvalidUrl,
-    /// Playlist not found or is private.
-    PlaylistNotFound,
-    /// Video is unavailable (private, deleted, region-locked).
-    VideoUnavailable,
-    /// Age-restricted content requiring authentication.
-    AgeRestricted,
-    /// Geographic restriction on content.
-    GeoRestricted,
-    /// Failed to extract or download audio stream.
-    AudioExtraction,
-    /// File system error (disk full, permission denied).
-    FileSystem,
-    /// Operation was cancelled by user.
+pub enum QueueItemStatus {
+    /// Waiting to be processed.
+    Pending,
+    /// Currently downloading.
+    Downloading,
+    /// Downloaded successfully.
+    Completed,
+    /// Failed with an error.
+    Failed(String),
+    /// Cancelled.
     Cancelled,
-    /// Unknown or unclassified error.
-    #[default]
-    Unknown,
+    /// Was downloading when the application last stopped.
+    Interrupted,
 }
 
-impl YouTubeErrorCategory {
-    /// Get a user-friendly title for this error category.
-    #[must_use]
-    pub const fn title(&self) -> &'static str {
+impl std::fmt::Display for QueueItemStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Network => "Network Error",
-            Self::YouTubeService => "YouTube Service Error",
-            Self::InvalidUrl => "Invalid URL",
-            Self::PlaylistNotFound => "Playlist Not Found",
-            Self::VideoUnavailable => "Video Unavailable",
-            Self::AgeRestricted => "Age-Restricted Content",
-            Self::GeoRestricted => "Geographic Restriction",
-            Self::AudioExtraction => "Audio Extraction Failed",
-            Self::FileSystem => "File System Error",
-            Self::Cancelled => "Cancelled",
-            Self::Unknown => "Error",
+            Self::Pending => write!(f, "Pending"),
+            Self::Downloading => write!(f, "Downloading"),
+            Self::Completed => write!(f, "Completed"),
+            Self::Failed(msg) => write!(f, "Failed: {msg}"),
+            Self::Cancelled => write!(f, "Cancelled"),
+            Self::Interrupted => write!(f, "Interrupted"),
         }
     }
+}
 
-    /// Get a user-friendly description for this error category.
+impl QueueItemStatus {
+    /// Message key of the label, without the error of a failed item.
     #[must_use]
-    pub const fn description(&self) -> &'static str {
+    pub const fn label_key(&self) -> &'static str {
         match self {
-            Self::Network => {
-                "Could not connect to YouTube. Please check your internet connection and try again."
-            }
-            Self::YouTubeService => {
-                "YouTube is temporarily unavailable or has rate-limited requests. Please wait a moment and try again."
-            }
-            Self::InvalidUrl => "The provided URL is not a valid YouTube playlist URL.",
-            Self::PlaylistNotFound => {
-                "The playlist could not be found. It may be private, deleted, or the URL is incorrect."
-            }
-            Self::VideoUnavailable => {
-                "One or more videos in the playlist are unavailable (private, deleted, or restricted)."
-            }
-            Self::AgeRestricted => {
-                "This content is age-restricted and requires authentication to access."
-            }
-            Self::GeoRestricted => "This content is not available in your geographic region.",
-            Self::AudioExtraction => {
-                "Failed to extract audio from the video. The format may not be supported."
-            }
-            Self::FileSystem => "Could not save the file. Please check disk space and permissions.",
-            Self::Cancelled => "The download was cancelled.",
-            Self::Unknown => "An unexpected error occurred.",
+            Self::Pending => "queue.status.pending",
+            Self::Downloading => "queue.status.downloading",
+            Self::Completed => "queue.status.completed",
+            Self::Failed(_) => "queue.status.failed",
+            Self::Cancelled => "queue.status.cancelled",
+            Self::Interrupted => "queue.status.interrupted",
         }
     }
 
-    /// Check if this error category is retryable.
+    /// Check if the item finished, successfully or not. Interrupted items
+    /// count as finished until resumed.
     #[must_use]
-    pub const fn is_retryable(&self) -> bool {
+    pub const fn is_finished(&self) -> bool {
         matches!(
             self,
-            Self::Network | Self::YouTubeService | Self::AudioExtraction
+            Self::Completed | Self::Failed(_) | Self::Cancelled | Self::Interrupted
         )
     }
-}
 
-impl std::fmt::Display for YouTubeErrorCategory {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.title())
+    /// Check if the item can be retried.
+    #[must_use]
+    pub const fn can_retry(&self) -> bool {
+        matches!(self, Self::Failed(_) | Self::Cancelled | Self::Interrupted)
     }
 }
 
-/// Result of a download operation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DownloadResult {
-    /// Task ID for this download operation.
-    pub task_id: TaskId,
-    /// Whether the overall download was successful.
-    pub success: bool,
-    /// Number of videos successfully downloaded.
-    pub successful_count: usize,
-    /// Number of videos that failed.
-    pub failed_count: usize,
-    /// Number of videos skipped (already exist).
-    pub skipped_count: usize,
-    /// Total number of videos in the playlist.
-    pub total_count: usize,
-    /// Individual video results.
-    pub results: Vec<VideoDownloadResult>,
-    /// Error message if the overall operation failed.
-    pub error_message: Option<String>,
-    /// Category of error for UI display (if failed).
-    pub error_category: Option<YouTubeErrorCategory>,
-    /// User-friendly error title.
-    pub error_title: Option<String>,
-    /// User-friendly error description with suggested action.
-    pub error_description: Option<String>,
+/// A download request in the queue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DownloadRequest {
+    /// `YouTube` playlist URL.
+    pub url: String,
+    /// Output directory for downloaded files.
+    pub output_dir: String,
+    /// Playlist name for display.
+    pub playlist_name: Option<String>,
+    /// Audio quality setting (e.g., "192", "320").
+    pub audio_quality: Option<String>,
+    /// Whether to embed thumbnails in MP3 files.
+    pub embed_thumbnail: Option<bool>,
+    /// Priority level.
+    #[serde(default)]
+    pub priority: DownloadPriority,
+    /// Download speed limit in bytes per second, overriding the global one.
+    #[serde(default)]
+    pub max_speed_bps: Option<u64>,
+    /// Split videos with chapters into one track per chapter.
+    #[serde(default)]
+    pub split_by_chapters: bool,
+    /// Only plan the download, without downloading or writing anything.
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
-impl DownloadResult {
-    /// Get the display error message, preferring the user-friendly description.
+/// A queued download with tracking information.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueItem {
+    /// Queue item identifier.
+    pub id: QueueItemId,
+    /// The download request.
+    pub request: DownloadRequest,
+    /// Current status.
+    pub status: QueueItemStatus,
+    /// Associated task ID while downloading.
+    pub task_id: Option<u64>,
+    /// Number of retry attempts.
+    pub retry_count: u32,
+    /// When the item was added (Unix millis).
+    pub added_at: u64,
+    /// When the download started (Unix millis).
+    pub started_at: Option<u64>,
+    /// When the download finished (Unix millis).
+    pub finished_at: Option<u64>,
+    /// Download progress (0.0 - 1.0).
+    pub progress: f64,
+    /// Video currently being downloaded.
+    pub current_video: Option<String>,
+    /// Total videos in the playlist.
+    pub total_videos: Option<usize>,
+    /// Videos completed so far.
+    pub videos_completed: Option<usize>,
+}
+
+impl QueueItem {
+    /// Name to show for the item: the playlist name, or the URL.
     #[must_use]
-    pub fn display_error(&self) -> Option<String> {
-        if let Some(ref desc) = self.error_description {
-            Some(desc.clone())
-        } else {
-            self.error_message.clone()
-        }
+    pub fn display_name(&self) -> &str {
+        self.request
+            .playlist_name
+            .as_deref()
+            .unwrap_or(&self.request.url)
     }
 
-    /// Get the error title for display.
+    /// Get progress as a percentage (0-100).
     #[must_use]
-    pub fn display_error_title(&self) -> String {
-        self.error_title
-            .clone()
-            .or_else(|| self.error_category.map(|c| c.title().to_string()))
-            .unwrap_or_else(|| "Download Failed".to_string())
+    pub fn progress_percent(&self) -> f64 {
+        self.progress * 100.0
     }
+}
 
-    /// Check if the error is retryable.
+/// Statistics about the download queue.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueueStats {
+    /// Total number of items.
+    pub total_items: usize,
+    /// Pending items.
+    pub pending_count: usize,
+    /// Downloading items.
+    pub downloading_count: usize,
+    /// Completed items.
+    pub completed_count: usize,
+    /// Failed items.
+    pub failed_count: usize,
+    /// Cancelled items.
+    pub cancelled_count: usize,
+    /// Items interrupted when the application last stopped.
+    #[serde(default)]
+    pub interrupted_count: usize,
+}
+
+// =============================================================================
+// Deep Link Types
+// =============================================================================
+
+/// A download requested through a `youtun4://` deep link that still needs a
+/// target playlist.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeepLinkRequest {
+    /// `YouTube` playlist URL.
+    pub url: String,
+    /// Target library playlist, if given in the link.
+    pub playlist_name: Option<String>,
+    /// Queue priority, if given in the link.
+    pub priority: Option<String>,
+}
+
+/// Confirmation that a deep link was added to the download queue.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeepLinkQueued {
+    /// ID of the queue item created for the link.
+    pub item_id: u64,
+    /// `YouTube` playlist URL.
+    pub url: String,
+    /// Library playlist the download goes to.
+    pub playlist_name: String,
+}
+
+/// A deep link that could not be handled.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeepLinkRejected {
+    /// The link as received.
+    pub link: String,
+    /// Why the link was rejected.
+    pub reason: String,
+}
+
+// =============================================================================
+// Update Types
+// =============================================================================
+
+/// An available application update.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UpdateInfo {
+    /// Version of the update.
+    pub version: String,
+    /// Currently installed version.
+    pub current_version: String,
+    /// Release date, if published.
+    pub date: Option<String>,
+    /// Release notes, if published.
+    pub notes: Option<String>,
+    /// Channel the update was found on.
+    pub channel: UpdateChannel,
+}
+
+/// Progress of an update download.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UpdateDownloadProgress {
+    /// Bytes downloaded so far.
+    pub downloaded_bytes: u64,
+    /// Total size in bytes, if known.
+    pub total_bytes: Option<u64>,
+}
+
+// =============================================================================
+// Log Types
+// =============================================================================
+
+/// A log entry read back from the application log files.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LogEntry {
+    /// Timestamp as written by the logger.
+    pub timestamp: String,
+    /// Level name (e.g. "INFO").
+    pub level: String,
+    /// Module that emitted the entry.
+    pub target: String,
+    /// Log message, followed by any structured fields.
+    pub message: String,
+}
+
+// =============================================================================
+// Performance Metrics Types
+// =============================================================================
+
+/// Totals for one kind of operation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct OperationStats {
+    /// Number of operations.
+    pub count: u64,
+    /// Bytes processed.
+    pub bytes: u64,
+    /// Time spent in the operations, in seconds.
+    pub total_secs: f64,
+    /// Longest single operation, in seconds.
+    pub max_secs: f64,
+}
+
+/// Performance of hashing, transfers and playlist scraping.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PerformanceMetrics {
+    /// Files checksummed by the integrity verifier.
+    pub hashing: OperationStats,
+    /// Files copied to devices.
+    pub transfer: OperationStats,
+    /// Playlist pages fetched and parsed.
+    pub scraping: OperationStats,
+    /// Files hashed per second.
+    pub files_hashed_per_sec: f64,
+    /// Hashing throughput in MB/s.
+    pub hash_mb_per_sec: f64,
+    /// Transfer throughput in MB/s.
+    pub transfer_mb_per_sec: f64,
+    /// Average playlist scrape latency in milliseconds.
+    pub average_scrape_latency_ms: f64,
+    /// Slowest playlist scrape in milliseconds.
+    pub max_scrape_latency_ms: f64,
+}
+
+// =============================================================================
+// Crash Report Types
+// =============================================================================
+
+/// Where a crash happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrashSource {
+    /// The native application process.
+    Native,
+    /// The web frontend.
+    Frontend,
+}
+
+/// Report of the last crash.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CrashReport {
+    /// Where the panic happened.
+    pub source: CrashSource,
+    /// Panic message.
+    pub message: String,
+    /// Source location of the panic, if known.
+    pub location: Option<String>,
+    /// Name of the panicking thread, if known.
+    pub thread: Option<String>,
+    /// Backtrace or stack trace, if captured.
+    pub backtrace: Option<String>,
+    /// Application version that crashed.
+    pub app_version: String,
+    /// Operating system.
+    pub os: String,
+    /// When the crash happened (Unix timestamp in seconds).
+    pub timestamp: u64,
+    /// Whether the report was sent to the crash report endpoint.
+    #[serde(default)]
+    pub submitted: bool,
+}
+
+// =============================================================================
+// History Types
+// =============================================================================
+
+/// What a history entry records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryKind {
+    /// A `YouTube` playlist download.
+    Download,
+    /// A sync to a device.
+    Sync,
+}
+
+/// How a download or sync ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryStatus {
+    /// Every item succeeded.
+    Completed,
+    /// Some items succeeded and some failed.
+    CompletedWithErrors,
+    /// The operation failed.
+    Failed,
+    /// The operation was cancelled.
+    Cancelled,
+}
+
+impl HistoryStatus {
+    /// Message key of the label.
     #[must_use]
-    pub fn is_retryable(&self) -> bool {
-        self.error_category.is_some_and(|c| c.is_retryable())
+    pub const fn label_key(self) -> &'static str {
+        match self {
+            Self::Completed => "history.status.completed",
+            Self::CompletedWithErrors => "history.status.completed_with_errors",
+            Self::Failed => "history.status.failed",
+            Self::Cancelled => "history.status.cancelled",
+        }
     }
 }
 
-/// Result of downloading a single video.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct VideoDownloadResult {
+/// A video of a finished download.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HistoryVideo {
     /// Video ID.
     pub video_id: String,
     /// Video title.
     pub title: String,
-    /// Whether the download was successful.
+    /// Whether the video was downloaded.
     pub success: bool,
-    /// Output file path (if successful).
-    pub output_path: Option<String>,
-    /// Error message (if failed).
+    /// Size of the saved files in bytes.
+    pub bytes: u64,
+    /// Length of the audio in seconds, if known.
+    pub duration_secs: Option<u64>,
+    /// Why the video failed, if it did.
+    pub error: Option<String>,
+}
+
+/// A finished download or sync.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HistoryEntry {
+    /// Entry identifier.
+    pub id: u64,
+    /// Whether this was a download or a sync.
+    pub kind: HistoryKind,
+    /// How it ended.
+    pub status: HistoryStatus,
+    /// When it ended (seconds since the Unix epoch).
+    pub finished_at: u64,
+    /// Playlists involved.
+    pub playlists: Vec<String>,
+    /// Source URL of a download.
+    pub source_url: Option<String>,
+    /// Device mount point of a sync.
+    pub device_mount_point: Option<String>,
+    /// Tracks downloaded or files copied.
+    pub items_succeeded: usize,
+    /// Tracks or files that failed.
+    pub items_failed: usize,
+    /// Bytes downloaded or copied.
+    pub bytes: u64,
+    /// Error message, if the operation failed.
     pub error: Option<String>,
+    /// How long the operation took, in milliseconds, if measured.
+    #[serde(default)]
+    pub elapsed_ms: Option<u64>,
+    /// Videos downloaded or failed by a download.
+    #[serde(default)]
+    pub videos: Vec<HistoryVideo>,
+}
+
+/// Totals over the downloads in the history.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HistoryStats {
+    /// Number of downloads.
+    pub downloads: usize,
+    /// Videos downloaded.
+    pub videos_downloaded: usize,
+    /// Videos that failed.
+    pub videos_failed: usize,
+    /// Bytes downloaded.
+    pub total_bytes: u64,
+    /// Start of the current month (Unix seconds).
+    pub month_start: u64,
+    /// Bytes downloaded since the start of the month.
+    pub bytes_this_month: u64,
+    /// Length of the downloaded audio in seconds, where known.
+    pub total_audio_secs: u64,
+    /// Average download speed in bytes per second.
+    pub average_speed_bps: Option<u64>,
+}
+
+/// Which history entries to return. Unset fields match every entry.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HistoryFilter {
+    /// Only downloads or only syncs.
+    pub kind: Option<HistoryKind>,
+    /// Only entries with this status.
+    pub status: Option<HistoryStatus>,
+    /// Only entries involving this playlist.
+    pub playlist: Option<String>,
+    /// Only entries that ended at or after this time (Unix seconds).
+    pub since: Option<u64>,
+    /// Only entries that ended before this time (Unix seconds).
+    pub until: Option<u64>,
+    /// Only downloads of this video.
+    pub video_id: Option<String>,
+}
+
+/// Bytes downloaded in one week.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WeeklyBytes {
+    /// Start of the week: Monday 00:00 UTC, in Unix seconds.
+    pub week_start: u64,
+    /// Bytes downloaded that week.
+    pub bytes: u64,
+}
+
+/// Filtered history with its aggregates.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HistoryReport {
+    /// Matching entries, newest first.
+    pub entries: Vec<HistoryEntry>,
+    /// Every playlist that appears in the history, sorted.
+    pub playlists: Vec<String>,
+    /// Bytes downloaded per week by the matching entries, oldest first.
+    pub weekly_download_bytes: Vec<WeeklyBytes>,
+}
+
+// =============================================================================
+// Backup Types
+// =============================================================================
+
+/// A file in a library backup.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BackupFile {
+    /// Path inside the archive.
+    pub path: String,
+    /// Size in bytes.
+    pub size: u64,
+    /// SHA-256 checksum, hex encoded.
+    pub sha256: String,
+}
+
+/// Contents of a library backup.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BackupManifest {
+    /// Backup format version.
+    pub format_version: u32,
+    /// When the backup was made (seconds since the Unix epoch).
+    pub created_at: u64,
+    /// Whether audio files are included.
+    pub includes_audio: bool,
+    /// Backed up playlists, sorted.
+    pub playlists: Vec<String>,
+    /// Every file in the backup.
+    pub files: Vec<BackupFile>,
 }
 
 // =============================================================================
@@ -1148,8 +3914,12 @@ pub struct CapacityCheckResult {
     pub usage_after_sync_percent: f64,
     /// Warning level based on available space.
     pub warning_level: CapacityWarningLevel,
-    /// Human-readable message about the capacity status.
+    /// Human-readable message about the capacity status, in the configured
+    /// language.
     pub message: String,
+    /// Translatable form of `message`.
+    #[serde(default)]
+    pub message_i18n: Option<Message>,
 }
 
 impl CapacityCheckResult {
@@ -1238,6 +4008,37 @@ pub struct Notification {
     pub title: Option<String>,
     /// Duration in milliseconds before auto-dismiss (None = manual dismiss only).
     pub duration_ms: Option<u64>,
+    /// Optional action button, such as "Retry" or "Undo".
+    pub action: Option<NotificationAction>,
+}
+
+/// An action button shown on a toast notification.
+///
+/// The callback run by the button is held by the notification context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationAction {
+    /// Message key of the button label.
+    pub label: String,
+    /// Whether to count down the seconds left before the toast closes.
+    pub countdown: bool,
+}
+
+impl NotificationAction {
+    /// Create an action button labelled with the message `label`.
+    #[must_use]
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            countdown: false,
+        }
+    }
+
+    /// Show the seconds left before the toast closes next to the label.
+    #[must_use]
+    pub const fn with_countdown(mut self) -> Self {
+        self.countdown = true;
+        self
+    }
 }
 
 impl Notification {
@@ -1253,6 +4054,7 @@ impl Notification {
             message: message.into(),
             title: None,
             duration_ms: Some(5000), // Default 5 seconds
+            action: None,
         }
     }
 
@@ -1282,6 +4084,17 @@ impl Notification {
         notification
     }
 
+    /// Create an error notification titled and described for an error
+    /// category, in `locale`.
+    #[must_use]
+    pub fn for_error_category(category: YouTubeErrorCategory, locale: Locale) -> Self {
+        Self::error(translate(locale, category.description_key(), &[])).with_title(translate(
+            locale,
+            category.title_key(),
+            &[],
+        ))
+    }
+
     /// Set the title for this notification.
     #[must_use]
     pub fn with_title(mut self, title: impl Into<String>) -> Self {
@@ -1302,4 +4115,11 @@ impl Notification {
         self.duration_ms = None;
         self
     }
+
+    /// Set the action button for this notification.
+    #[must_use]
+    pub fn with_action(mut self, action: NotificationAction) -> Self {
+        self.action = Some(action);
+        self
+    }
 }
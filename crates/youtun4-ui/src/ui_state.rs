@@ -0,0 +1,84 @@
+//! Interface state kept across restarts.
+//!
+//! The open view, selected playlist, sidebar and filter choices are saved in
+//! the `ui_state` config section. Components read them through
+//! [`UiStateStore`] and every change is saved in the background.
+//!
+//! Changes made before the saved state has loaded are not saved, so they
+//! can't overwrite what the previous session left.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::tauri_api;
+use crate::types::UiState;
+
+/// Interface state, shared through context.
+#[derive(Clone, Copy)]
+pub struct UiStateStore {
+    state: RwSignal<UiState>,
+    loaded: RwSignal<bool>,
+}
+
+impl UiStateStore {
+    /// Whether the saved state has been restored.
+    #[must_use]
+    pub fn loaded(&self) -> bool {
+        self.loaded.get()
+    }
+
+    /// The current state.
+    #[must_use]
+    pub fn get(&self) -> UiState {
+        self.state.get()
+    }
+
+    /// The current state, without tracking it.
+    #[must_use]
+    pub fn get_untracked(&self) -> UiState {
+        self.state.get_untracked()
+    }
+
+    /// Restore the state saved by a previous session.
+    pub fn restore(&self, state: UiState) {
+        self.state.set(state);
+        self.loaded.set(true);
+    }
+
+    /// Change the state and save it if anything changed.
+    pub fn update(&self, change: impl FnOnce(&mut UiState)) {
+        let mut next = self.state.get_untracked();
+        change(&mut next);
+        if next == self.state.get_untracked() {
+            return;
+        }
+        self.state.set(next.clone());
+        if !self.loaded.get_untracked() {
+            return;
+        }
+        spawn_local(async move {
+            if let Err(e) = tauri_api::set_ui_state(&next).await {
+                leptos::logging::error!("Failed to save UI state: {}", e);
+            }
+        });
+    }
+}
+
+/// Provide the interface state to the component tree, starting from the
+/// defaults until [`UiStateStore::restore`] is called.
+pub fn provide_ui_state() -> UiStateStore {
+    let store = UiStateStore {
+        state: RwSignal::new(UiState::default()),
+        loaded: RwSignal::new(false),
+    };
+    provide_context(store);
+    store
+}
+
+/// Access the interface state.
+///
+/// # Panics
+/// Panics if called outside of the tree set up by [`provide_ui_state`].
+pub fn use_ui_state() -> UiStateStore {
+    expect_context::<UiStateStore>()
+}
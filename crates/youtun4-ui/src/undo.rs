@@ -0,0 +1,275 @@
+//! Session undo history.
+//!
+//! [`UndoStore`] records playlist deletions, track removals and renames as
+//! they happen, so they can be undone with Ctrl+Z or from the undo history.
+//! Deletions are backed by the backend's session trash, so the history only
+//! lasts until the app restarts.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::components::{NotificationContext, use_notifications};
+use crate::tauri_api;
+use crate::types::{Notification, NotificationAction, TrashId};
+
+/// Maximum number of changes kept in the history; older ones can no longer be
+/// undone.
+pub const MAX_UNDO_ENTRIES: usize = 20;
+
+/// How to undo a change.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UndoAction {
+    /// Put a trashed playlist or tracks back.
+    Restore(TrashId),
+    /// Rename a playlist from `to` back to `from`.
+    Rename {
+        /// Name before the rename.
+        from: String,
+        /// Name after the rename.
+        to: String,
+    },
+}
+
+impl UndoAction {
+    async fn run(&self) -> Result<(), String> {
+        match self {
+            Self::Restore(id) => tauri_api::restore_from_trash(*id).await.map(|_| ()),
+            Self::Rename { from, to } => tauri_api::rename_playlist(to, from).await,
+        }
+    }
+}
+
+/// A change that can be undone.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UndoEntry {
+    /// Entry ID, unique within the session.
+    pub id: u64,
+    /// What the change did, e.g. "Deleted \"Road Trip\"".
+    pub label: String,
+    /// How to undo it.
+    pub action: UndoAction,
+}
+
+/// Undoable changes, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct UndoStack {
+    entries: Vec<UndoEntry>,
+    next_id: u64,
+}
+
+impl UndoStack {
+    /// Record a change, dropping the oldest one if the history is full.
+    /// Returns the new entry's ID.
+    pub fn push(&mut self, label: impl Into<String>, action: UndoAction) -> u64 {
+        self.next_id += 1;
+        if self.entries.len() == MAX_UNDO_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.entries.push(UndoEntry {
+            id: self.next_id,
+            label: label.into(),
+            action,
+        });
+        self.next_id
+    }
+
+    /// Changes in the history, oldest first.
+    #[must_use]
+    pub fn entries(&self) -> &[UndoEntry] {
+        &self.entries
+    }
+
+    /// Take the entry `id` and every later change out of the history, newest
+    /// first, which is the order they must be undone in.
+    pub fn take_through(&mut self, id: u64) -> Vec<UndoEntry> {
+        let Some(index) = self.entries.iter().position(|e| e.id == id) else {
+            return Vec::new();
+        };
+        let mut taken = self.entries.split_off(index);
+        taken.reverse();
+        taken
+    }
+
+    /// Put back entries taken with [`Self::take_through`] that were not
+    /// undone.
+    pub fn put_back(&mut self, mut entries: Vec<UndoEntry>) {
+        entries.reverse();
+        self.entries.extend(entries);
+    }
+}
+
+/// The undo history, shared through context.
+#[derive(Clone, Copy)]
+pub struct UndoStore {
+    stack: RwSignal<UndoStack>,
+    notifications: NotificationContext,
+    undoing: RwSignal<bool>,
+    last_undone: RwSignal<Vec<UndoAction>>,
+}
+
+impl UndoStore {
+    /// Record a change and show it in a toast with an "Undo" button.
+    pub fn record(&self, label: impl Into<String>, action: UndoAction) {
+        let label = label.into();
+        let Some(id) = self
+            .stack
+            .try_update(|stack| stack.push(label.clone(), action))
+        else {
+            return;
+        };
+        let store = *self;
+        self.notifications.push_with_action(
+            Notification::success(label).with_action(NotificationAction::new("nav.undo")),
+            Callback::new(move |()| store.undo_through(id)),
+            None,
+        );
+    }
+
+    /// Changes that can be undone, newest first.
+    #[must_use]
+    pub fn entries(&self) -> Vec<UndoEntry> {
+        self.stack
+            .with(|stack| stack.entries().iter().rev().cloned().collect())
+    }
+
+    /// Whether there is anything to undo.
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        self.stack.with(|stack| !stack.entries().is_empty())
+    }
+
+    /// Whether an undo is in progress.
+    #[must_use]
+    pub fn is_undoing(&self) -> bool {
+        self.undoing.get()
+    }
+
+    /// Changes undone by the latest undo, newest first, so views showing
+    /// the library can catch up.
+    #[must_use]
+    pub fn last_undone(&self) -> Vec<UndoAction> {
+        self.last_undone.get()
+    }
+
+    /// Undo the latest change.
+    pub fn undo_last(&self) {
+        if let Some(id) = self
+            .stack
+            .with_untracked(|stack| stack.entries().last().map(|e| e.id))
+        {
+            self.undo_through(id);
+        }
+    }
+
+    /// Undo the change `id` and every later one, newest first.
+    ///
+    /// Stops at the first change that can't be undone; it and the older
+    /// changes stay in the history.
+    pub fn undo_through(&self, id: u64) {
+        if self.undoing.get_untracked() {
+            return;
+        }
+        let mut entries = self
+            .stack
+            .try_update(|stack| stack.take_through(id))
+            .unwrap_or_default();
+        if entries.is_empty() {
+            return;
+        }
+
+        let notifications = self.notifications;
+        let store = *self;
+        store.undoing.set(true);
+        spawn_local(async move {
+            let mut undone = 0;
+            let mut failure = None;
+            for entry in &entries {
+                match entry.action.run().await {
+                    Ok(()) => undone += 1,
+                    Err(e) => {
+                        failure = Some(format!("Couldn't undo {}: {e}", entry.label));
+                        break;
+                    }
+                }
+            }
+            let remaining = entries.split_off(undone);
+            store.stack.update(|stack| stack.put_back(remaining));
+            store.undoing.set(false);
+            if undone > 0 {
+                store
+                    .last_undone
+                    .set(entries.iter().map(|e| e.action.clone()).collect());
+            }
+            match (failure, entries.as_slice()) {
+                (Some(message), _) => notifications.error(message),
+                (None, [entry]) => notifications.info(format!("Undid: {}", entry.label)),
+                (None, _) => notifications.info(format!("Undid {undone} changes")),
+            }
+        });
+    }
+}
+
+/// Provide an empty undo history to the component tree.
+///
+/// # Panics
+/// Panics if called outside of a `NotificationProvider`.
+pub fn provide_undo() -> UndoStore {
+    let store = UndoStore {
+        stack: RwSignal::new(UndoStack::default()),
+        notifications: use_notifications(),
+        undoing: RwSignal::new(false),
+        last_undone: RwSignal::new(Vec::new()),
+    };
+    provide_context(store);
+    store
+}
+
+/// Access the undo history.
+///
+/// # Panics
+/// Panics if called outside of the tree set up by [`provide_undo`].
+pub fn use_undo() -> UndoStore {
+    expect_context::<UndoStore>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename(n: u32) -> UndoAction {
+        UndoAction::Rename {
+            from: format!("a{n}"),
+            to: format!("b{n}"),
+        }
+    }
+
+    #[test]
+    fn test_push_drops_oldest() {
+        let mut stack = UndoStack::default();
+        for n in 0..25 {
+            stack.push(format!("change {n}"), rename(n));
+        }
+        assert_eq!(stack.entries().len(), MAX_UNDO_ENTRIES);
+        assert_eq!(stack.entries()[0].label, "change 5");
+    }
+
+    #[test]
+    fn test_take_through_and_put_back() {
+        let mut stack = UndoStack::default();
+        let first = stack.push("first", UndoAction::Restore(1));
+        let second = stack.push("second", rename(2));
+        let third = stack.push("third", UndoAction::Restore(3));
+
+        let taken = stack.take_through(second);
+        let ids: Vec<u64> = taken.iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![third, second]);
+        assert_eq!(stack.entries().len(), 1);
+
+        // Only the newest one was undone
+        stack.put_back(taken.into_iter().skip(1).collect());
+        let ids: Vec<u64> = stack.entries().iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![first, second]);
+
+        assert!(stack.take_through(third).is_empty());
+    }
+}
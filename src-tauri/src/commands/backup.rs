@@ -0,0 +1,106 @@
+//! Library backup and restore commands.
+
+use std::path::PathBuf;
+
+use tauri::{AppHandle, State};
+use tracing::info;
+use youtun4_core::Error;
+use youtun4_core::backup::{BackupManifest, BackupOptions};
+use youtun4_core::change_log::LibraryChange;
+
+use super::change_log::record_change;
+use super::config::apply_config_change;
+use super::error::{CommandResult, map_err};
+use super::state::AppState;
+
+/// Back up the configuration and every playlist's metadata to a zip archive
+/// at `path`, with the audio files too if `include_audio` is set.
+#[tauri::command]
+pub async fn backup_library(
+    state: State<'_, AppState>,
+    path: String,
+    include_audio: Option<bool>,
+) -> CommandResult<BackupManifest> {
+    let options = BackupOptions {
+        include_audio: include_audio.unwrap_or(false),
+    };
+    info!(
+        "Backing up library to {} (audio: {})",
+        path, options.include_audio
+    );
+
+    let config = state.config_manager.read().await.config().clone();
+    let destination = PathBuf::from(path);
+    tokio::task::spawn_blocking(move || {
+        youtun4_core::backup::backup_library(&config, &destination, &options)
+    })
+    .await
+    .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))
+    .map_err(map_err)
+}
+
+/// Restore a backup made with `backup_library` into the current playlists
+/// directory and apply its configuration.
+///
+/// The archive is verified before anything is written. The playlists
+/// directory and simple mode of this installation are kept.
+#[tauri::command]
+pub async fn restore_library(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    path: String,
+) -> CommandResult<BackupManifest> {
+    info!("Restoring library from {}", path);
+
+    let playlists_dir = state
+        .config_manager
+        .read()
+        .await
+        .playlists_directory()
+        .to_path_buf();
+    let existing: Vec<String> = state
+        .playlist_manager
+        .read()
+        .await
+        .list_playlists()
+        .map_err(map_err)?
+        .into_iter()
+        .map(|playlist| playlist.name)
+        .collect();
+
+    let archive = PathBuf::from(path);
+    let restored = tokio::task::spawn_blocking(move || {
+        youtun4_core::backup::restore_library(&archive, &playlists_dir)
+    })
+    .await
+    .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))
+    .map_err(map_err)?;
+
+    let mut config = restored.config;
+    apply_config_change(&app, &state, |manager| {
+        config
+            .playlists_directory
+            .clone_from(&manager.config().playlists_directory);
+        config.simple_mode = manager.config().simple_mode;
+        manager.update(config)
+    })
+    .await?;
+
+    for playlist in &restored.manifest.playlists {
+        if !existing.contains(playlist) {
+            record_change(
+                &state,
+                LibraryChange::PlaylistCreated {
+                    playlist: playlist.clone(),
+                },
+            );
+        }
+    }
+
+    info!(
+        "Restored {} files from a backup of {} playlist(s)",
+        restored.files_restored,
+        restored.manifest.playlists.len()
+    );
+    Ok(restored.manifest)
+}
@@ -1,19 +1,44 @@
 //! Cache management commands.
 
-use tauri::State;
-use tracing::{debug, info};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tauri::{AppHandle, State};
+use tracing::{debug, error, info};
 use youtun4_core::cache::{
-    CacheCleanupStats, CacheConfig, CacheManager, CacheStats, default_cache_directory,
+    CacheCleanupStats, CacheConfig, CacheManager, CacheStats, CacheVerifyReport, CacheWarmStats,
+    default_cache_directory,
 };
+use youtun4_core::youtube::{RustyYtdlDownloader, YouTubeDownloader, extract_playlist_id};
+use youtun4_core::{Error, Result};
+
+use crate::runtime::{TaskCategory, TaskId};
 
-use super::error::map_err;
+use super::config::apply_config_change;
+use super::error::{CommandError, CommandResult, map_err};
+use super::events::emit_task_event;
 use super::state::AppState;
 
+/// Event names for cache events emitted to the frontend.
+///
+/// Deprecated aliases: every event here is also emitted as a unified `task-event`,
+/// which new frontend code should listen to instead.
+pub mod cache_events {
+    pub const CACHE_WARM_COMPLETED: &str = "cache-warm-completed";
+    pub const CACHE_WARM_FAILED: &str = "cache-warm-failed";
+}
+
+/// Result payload for cache warming events.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CacheWarmPayload {
+    pub playlist_name: String,
+    pub stats: Option<CacheWarmStats>,
+    pub error: Option<String>,
+}
+
 /// Get cache statistics.
 #[tauri::command]
-pub async fn get_cache_stats(
-    state: State<'_, AppState>,
-) -> std::result::Result<CacheStats, String> {
+pub async fn get_cache_stats(state: State<'_, AppState>) -> CommandResult<CacheStats> {
     debug!("Getting cache statistics");
 
     let config_manager = state.config_manager.read().await;
@@ -26,9 +51,7 @@ pub async fn get_cache_stats(
 
 /// Get the cache configuration.
 #[tauri::command]
-pub async fn get_cache_config(
-    state: State<'_, AppState>,
-) -> std::result::Result<CacheConfig, String> {
+pub async fn get_cache_config(state: State<'_, AppState>) -> CommandResult<CacheConfig> {
     debug!("Getting cache configuration");
 
     let config_manager = state.config_manager.read().await;
@@ -38,22 +61,24 @@ pub async fn get_cache_config(
 /// Update the cache configuration.
 #[tauri::command]
 pub async fn update_cache_config(
+    app: AppHandle,
     state: State<'_, AppState>,
     config: CacheConfig,
-) -> std::result::Result<(), String> {
+) -> CommandResult<()> {
     info!("Updating cache configuration");
 
-    let mut config_manager = state.config_manager.write().await;
-    let mut app_config = config_manager.config().clone();
-    app_config.cache = config;
-    config_manager.update(app_config).map_err(map_err)
+    apply_config_change(&app, &state, |manager| {
+        let mut app_config = manager.config().clone();
+        app_config.cache = config;
+        manager.update(app_config)
+    })
+    .await?;
+    Ok(())
 }
 
 /// Clean up the cache.
 #[tauri::command]
-pub async fn cleanup_cache(
-    state: State<'_, AppState>,
-) -> std::result::Result<CacheCleanupStats, String> {
+pub async fn cleanup_cache(state: State<'_, AppState>) -> CommandResult<CacheCleanupStats> {
     info!("Running cache cleanup");
 
     let config_manager = state.config_manager.read().await;
@@ -66,9 +91,7 @@ pub async fn cleanup_cache(
 
 /// Clear all cached data.
 #[tauri::command]
-pub async fn clear_cache(
-    state: State<'_, AppState>,
-) -> std::result::Result<CacheCleanupStats, String> {
+pub async fn clear_cache(state: State<'_, AppState>) -> CommandResult<CacheCleanupStats> {
     info!("Clearing all cache data");
 
     let config_manager = state.config_manager.read().await;
@@ -81,9 +104,7 @@ pub async fn clear_cache(
 
 /// Clean up temporary files.
 #[tauri::command]
-pub async fn cleanup_cache_temp(
-    state: State<'_, AppState>,
-) -> std::result::Result<CacheCleanupStats, String> {
+pub async fn cleanup_cache_temp(state: State<'_, AppState>) -> CommandResult<CacheCleanupStats> {
     info!("Cleaning up cache temp files");
 
     let config_manager = state.config_manager.read().await;
@@ -94,6 +115,26 @@ pub async fn cleanup_cache_temp(
     cache.cleanup_temp().map_err(map_err)
 }
 
+/// Verify cache integrity and repair the manifest.
+///
+/// Cross-checks manifest entries against the files on disk. Repairs are
+/// applied unless `repair` is explicitly `false`.
+#[tauri::command]
+pub async fn verify_cache(
+    state: State<'_, AppState>,
+    repair: Option<bool>,
+) -> CommandResult<CacheVerifyReport> {
+    let repair = repair.unwrap_or(true);
+    info!("Verifying cache integrity (repair: {})", repair);
+
+    let config_manager = state.config_manager.read().await;
+    let cache_config = config_manager.config().cache.clone();
+    drop(config_manager);
+
+    let mut cache = CacheManager::new(cache_config).map_err(map_err)?;
+    cache.verify(repair).map_err(map_err)
+}
+
 /// Get the default cache directory path.
 #[tauri::command]
 pub fn get_default_cache_directory() -> String {
@@ -102,7 +143,7 @@ pub fn get_default_cache_directory() -> String {
 
 /// Check if caching is enabled.
 #[tauri::command]
-pub async fn is_cache_enabled(state: State<'_, AppState>) -> std::result::Result<bool, String> {
+pub async fn is_cache_enabled(state: State<'_, AppState>) -> CommandResult<bool> {
     let config_manager = state.config_manager.read().await;
     Ok(config_manager.config().cache.enabled)
 }
@@ -110,27 +151,150 @@ pub async fn is_cache_enabled(state: State<'_, AppState>) -> std::result::Result
 /// Enable or disable caching.
 #[tauri::command]
 pub async fn set_cache_enabled(
+    app: AppHandle,
     state: State<'_, AppState>,
     enabled: bool,
-) -> std::result::Result<(), String> {
+) -> CommandResult<()> {
     info!("Setting cache enabled: {}", enabled);
 
-    let mut config_manager = state.config_manager.write().await;
-    let mut app_config = config_manager.config().clone();
-    app_config.cache.enabled = enabled;
-    config_manager.update(app_config).map_err(map_err)
+    apply_config_change(&app, &state, |manager| {
+        let mut app_config = manager.config().clone();
+        app_config.cache.enabled = enabled;
+        manager.update(app_config)
+    })
+    .await?;
+    Ok(())
 }
 
 /// Set the maximum cache size in bytes.
 #[tauri::command]
 pub async fn set_cache_max_size(
+    app: AppHandle,
     state: State<'_, AppState>,
     max_size_bytes: u64,
-) -> std::result::Result<(), String> {
+) -> CommandResult<()> {
     info!("Setting cache max size: {} bytes", max_size_bytes);
 
-    let mut config_manager = state.config_manager.write().await;
-    let mut app_config = config_manager.config().clone();
-    app_config.cache.max_size_bytes = max_size_bytes;
-    config_manager.update(app_config).map_err(map_err)
+    apply_config_change(&app, &state, |manager| {
+        let mut app_config = manager.config().clone();
+        app_config.cache.max_size_bytes = max_size_bytes;
+        manager.update(app_config)
+    })
+    .await?;
+    Ok(())
+}
+
+/// Warm the cache for a playlist in a low-priority background task.
+///
+/// Fetches and caches video info and thumbnails for every item of the
+/// playlist's YouTube source, so the detail view opens fully populated.
+#[tauri::command]
+pub async fn warm_cache_for_playlist(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    playlist_name: String,
+) -> CommandResult<TaskId> {
+    info!("Warming cache for playlist: {}", playlist_name);
+
+    let playlist_manager = state.playlist_manager.read().await;
+    let saved = playlist_manager
+        .get_saved_metadata(&playlist_name)
+        .map_err(map_err)?;
+    drop(playlist_manager);
+
+    let source_url = saved.source_url.ok_or_else(|| {
+        CommandError::invalid_input(format!(
+            "Playlist '{playlist_name}' has no YouTube source to warm the cache from"
+        ))
+    })?;
+
+    spawn_cache_warming(app, &state, playlist_name, source_url).await
+}
+
+/// Spawn a background task that warms the cache for a YouTube playlist.
+pub(crate) async fn spawn_cache_warming(
+    app: AppHandle,
+    state: &AppState,
+    playlist_name: String,
+    source_url: String,
+) -> CommandResult<TaskId> {
+    // Validate up front so bad URLs fail the command instead of the task
+    extract_playlist_id(&source_url).map_err(map_err)?;
+
+    let config_manager = state.config_manager.read().await;
+    let cache_config = config_manager.config().cache.clone();
+    let offline = config_manager.config().offline_mode;
+    drop(config_manager);
+
+    let task_id = state.runtime().spawn_cancellable(
+        TaskCategory::Background,
+        Some(format!("Warm cache: {playlist_name}")),
+        move |cancel_rx| async move {
+            let cancel_flag = Arc::new(AtomicBool::new(false));
+            let flag = Arc::clone(&cancel_flag);
+            tokio::spawn(async move {
+                if cancel_rx.await.is_ok() {
+                    flag.store(true, Ordering::SeqCst);
+                }
+            });
+
+            let result = tokio::task::spawn_blocking(move || {
+                warm_playlist_cache(cache_config, &source_url, offline, &cancel_flag)
+            })
+            .await
+            .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))));
+
+            let (event, payload) = match result {
+                Ok(stats) => (
+                    cache_events::CACHE_WARM_COMPLETED,
+                    CacheWarmPayload {
+                        playlist_name,
+                        stats: Some(stats),
+                        error: None,
+                    },
+                ),
+                Err(e) => {
+                    error!("Cache warming failed for '{}': {}", playlist_name, e);
+                    (
+                        cache_events::CACHE_WARM_FAILED,
+                        CacheWarmPayload {
+                            playlist_name,
+                            stats: None,
+                            error: Some(e.to_string()),
+                        },
+                    )
+                }
+            };
+
+            if let Err(e) = emit_task_event(&app, event, &payload) {
+                error!("Failed to emit {} event: {}", event, e);
+            }
+        },
+    );
+
+    debug!("Cache warming task {} spawned", task_id);
+    Ok(task_id)
+}
+
+/// Resolve playlist info (cached or fetched) and warm the cache with it.
+fn warm_playlist_cache(
+    cache_config: CacheConfig,
+    source_url: &str,
+    offline: bool,
+    cancel_flag: &AtomicBool,
+) -> Result<CacheWarmStats> {
+    let playlist_id = extract_playlist_id(source_url)?;
+    let mut cache = CacheManager::new(cache_config)?;
+
+    let playlist = match cache.get_playlist_info(&playlist_id)? {
+        Some(info) => info,
+        None if offline => {
+            return Err(Error::offline(format!(
+                "fetching playlist info for {playlist_id}"
+            )));
+        }
+        None => RustyYtdlDownloader::new().parse_playlist_url(source_url)?,
+    };
+
+    cache.warm_playlist(&playlist, offline, cancel_flag)
 }
@@ -0,0 +1,45 @@
+//! Library change log commands.
+//!
+//! Commands that mutate the library add their change through
+//! [`record_change`]; the frontend reads the log with [`get_change_log`].
+
+use tauri::{AppHandle, Manager, State};
+use tracing::warn;
+use youtun4_core::change_log::{ChangeRecord, ChangeSeq, LibraryChange};
+use youtun4_core::youtube::DownloadResult;
+
+use super::state::AppState;
+
+/// Add a library change to the change log. Failures are logged and never
+/// affect the change itself.
+pub fn record_change(state: &AppState, change: LibraryChange) {
+    if let Err(e) = state.change_log.record(change) {
+        warn!("Failed to record library change: {}", e);
+    }
+}
+
+/// Add the tracks a finished download put into `playlist` to the change log.
+pub fn record_downloaded_tracks(app: &AppHandle, playlist: &str, results: &[DownloadResult]) {
+    if let Some(change) = LibraryChange::tracks_downloaded(playlist, results) {
+        record_change(&app.state::<AppState>(), change);
+    }
+}
+
+/// Add a sync of `playlists` to the device at `device_mount_point` that ran
+/// to the end to the change log.
+pub fn record_sync_performed(app: &AppHandle, playlists: Vec<String>, device_mount_point: &str) {
+    record_change(
+        &app.state::<AppState>(),
+        LibraryChange::SyncPerformed {
+            playlists,
+            device_mount_point: device_mount_point.to_string(),
+        },
+    );
+}
+
+/// Get the library changes recorded after sequence number `since`, oldest
+/// first, or every change in the log if `since` is not given.
+#[tauri::command]
+pub fn get_change_log(state: State<'_, AppState>, since: Option<ChangeSeq>) -> Vec<ChangeRecord> {
+    state.change_log.since(since)
+}
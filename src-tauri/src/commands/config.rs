@@ -1,17 +1,126 @@
 //! Configuration commands.
 
 use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
 
-use tauri::State;
-use tracing::{debug, info};
-use youtun4_core::AppConfig;
+use tauri::{AppHandle, Emitter, State};
+use tracing::{debug, error, info, warn};
+use youtun4_core::cache::CacheManager;
+use youtun4_core::storage::{StorageMigrationResult, migrate_storage_directory};
+use youtun4_core::{AppConfig, ConfigImportPreview, ConfigManager, ConfigSection, Error, UiState};
 
-use super::error::map_err;
+use super::device_watcher::restart_device_watcher;
+use super::error::{CommandResult, map_err};
+use super::queue::process_queue;
 use super::state::AppState;
 
+/// Event names for configuration events emitted to the frontend.
+pub mod config_events {
+    /// Event emitted when the configuration changes.
+    pub const CONFIG_CHANGED: &str = "config-changed";
+    /// Event emitted with progress while moving the storage directory.
+    pub const STORAGE_MIGRATION_PROGRESS: &str = "storage-migration-progress";
+}
+
+/// Payload for the config-changed event.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConfigChangedPayload {
+    /// Sections that changed.
+    pub sections: Vec<ConfigSection>,
+    /// The configuration after the change.
+    pub config: AppConfig,
+}
+
+/// Apply a change through the config manager, let subsystems react to it and
+/// emit a `config-changed` event.
+///
+/// Every command that modifies the configuration goes through here so the
+/// running app never drifts from the saved settings.
+pub(crate) async fn apply_config_change<F>(
+    app: &AppHandle,
+    state: &State<'_, AppState>,
+    change: F,
+) -> CommandResult<AppConfig>
+where
+    F: FnOnce(&mut ConfigManager) -> youtun4_core::Result<()>,
+{
+    let (old_config, new_config) = {
+        let mut config_manager = state.config_manager.write().await;
+        let old_config = config_manager.config().clone();
+        change(&mut config_manager).map_err(map_err)?;
+        (old_config, config_manager.config().clone())
+    };
+
+    let sections = old_config.changed_sections(&new_config);
+    if sections.is_empty() {
+        return Ok(new_config);
+    }
+    debug!("Config sections changed: {:?}", sections);
+
+    react_to_config_change(app, state, &new_config, &sections).await?;
+
+    let payload = ConfigChangedPayload {
+        sections,
+        config: new_config.clone(),
+    };
+    if let Err(e) = app.emit(config_events::CONFIG_CHANGED, &payload) {
+        error!("Failed to emit config-changed event: {}", e);
+    }
+
+    Ok(new_config)
+}
+
+/// Bring running subsystems in line with the changed config sections.
+async fn react_to_config_change(
+    app: &AppHandle,
+    state: &State<'_, AppState>,
+    config: &AppConfig,
+    sections: &[ConfigSection],
+) -> CommandResult<()> {
+    if sections.contains(&ConfigSection::Storage) {
+        state
+            .reinitialize_playlist_manager(config.playlists_directory.clone())
+            .await
+            .map_err(map_err)?;
+    }
+
+    if sections.contains(&ConfigSection::Cache) {
+        // Enforce new size limits now rather than on the next cache write
+        let cache_config = config.cache.clone();
+        let cleanup =
+            tokio::task::spawn_blocking(move || CacheManager::new(cache_config)?.cleanup()).await;
+        match cleanup {
+            Ok(Ok(stats)) => debug!(
+                "Applied cache config: {} entries removed",
+                stats.entries_removed
+            ),
+            Ok(Err(e)) => warn!("Failed to apply cache config: {}", e),
+            Err(e) => warn!("Cache cleanup task failed: {}", e),
+        }
+    }
+
+    if sections.contains(&ConfigSection::Devices) {
+        restart_device_watcher(app.clone(), state).await;
+    }
+
+    if sections.contains(&ConfigSection::Queue) {
+        state
+            .download_queue_arc()
+            .set_config(config.queue.clone())
+            .await;
+    }
+
+    // New concurrency limits or leaving offline mode may unblock queued items
+    if sections.contains(&ConfigSection::Queue) || sections.contains(&ConfigSection::Network) {
+        process_queue(app.clone(), state.clone()).await;
+    }
+
+    Ok(())
+}
+
 /// Get the current application configuration.
 #[tauri::command]
-pub async fn get_config(state: State<'_, AppState>) -> std::result::Result<AppConfig, String> {
+pub async fn get_config(state: State<'_, AppState>) -> CommandResult<AppConfig> {
     debug!("Getting config");
     let config_manager = state.config_manager.read().await;
     Ok(config_manager.config().clone())
@@ -20,64 +129,108 @@ pub async fn get_config(state: State<'_, AppState>) -> std::result::Result<AppCo
 /// Update the application configuration.
 #[tauri::command]
 pub async fn update_config(
+    app: AppHandle,
     state: State<'_, AppState>,
-    config: AppConfig,
-) -> std::result::Result<(), String> {
+    mut config: AppConfig,
+) -> CommandResult<()> {
     info!("Updating config");
     debug!(
         "New playlists directory: {}",
         config.playlists_directory.display()
     );
 
-    let new_playlists_dir = config.playlists_directory.clone();
-
-    {
-        let mut config_manager = state.config_manager.write().await;
-        config_manager.update(config).map_err(map_err)?;
-    }
-
-    state
-        .reinitialize_playlist_manager(new_playlists_dir)
-        .await
-        .map_err(map_err)?;
+    apply_config_change(&app, &state, |manager| {
+        // Simple mode only changes through the PIN-checked parental commands
+        config.simple_mode = manager.config().simple_mode;
+        // The settings panel may hold a stale copy of the interface state
+        // and of the refresh schedules, which record their last run
+        config.ui_state = manager.config().ui_state.clone();
+        config
+            .refresh_schedules
+            .clone_from(&manager.config().refresh_schedules);
+        manager.update(config)
+    })
+    .await?;
 
     info!("Config updated successfully");
     Ok(())
 }
 
-/// Get the current playlists storage directory.
+/// Save the interface state to restore on the next start.
 #[tauri::command]
-pub async fn get_storage_directory(
+pub async fn set_ui_state(
+    app: AppHandle,
     state: State<'_, AppState>,
-) -> std::result::Result<String, String> {
+    ui_state: UiState,
+) -> CommandResult<()> {
+    debug!("Saving UI state: {:?}", ui_state);
+    apply_config_change(&app, &state, |manager| manager.set_ui_state(ui_state)).await?;
+    Ok(())
+}
+
+/// Get the current playlists storage directory.
+#[tauri::command]
+pub async fn get_storage_directory(state: State<'_, AppState>) -> CommandResult<String> {
     debug!("Getting storage directory");
     let config_manager = state.config_manager.read().await;
     Ok(config_manager.playlists_directory().display().to_string())
 }
 
 /// Set the playlists storage directory.
+///
+/// With `move_data`, existing playlists are copied to the new location and
+/// verified by checksum before the configuration is switched. Progress is
+/// reported through `storage-migration-progress` events. The old directory is
+/// left in place.
 #[tauri::command]
 pub async fn set_storage_directory(
+    app: AppHandle,
     state: State<'_, AppState>,
     path: String,
-) -> std::result::Result<(), String> {
+    move_data: Option<bool>,
+) -> CommandResult<Option<StorageMigrationResult>> {
     let new_path = PathBuf::from(&path);
     info!("Setting storage directory to: {}", new_path.display());
 
-    {
-        let mut config_manager = state.config_manager.write().await;
-        config_manager
-            .set_playlists_directory(new_path.clone())
-            .map_err(map_err)?;
-    }
+    let migration = if move_data.unwrap_or(false) {
+        let current = state
+            .config_manager
+            .read()
+            .await
+            .playlists_directory()
+            .to_path_buf();
+        let destination = new_path.clone();
+        let app_handle = app.clone();
 
-    state
-        .reinitialize_playlist_manager(new_path)
+        let result = tokio::task::spawn_blocking(move || {
+            migrate_storage_directory(
+                &current,
+                &destination,
+                &AtomicBool::new(false),
+                |progress| {
+                    if let Err(e) =
+                        app_handle.emit(config_events::STORAGE_MIGRATION_PROGRESS, progress)
+                    {
+                        error!("Failed to emit storage-migration-progress event: {}", e);
+                    }
+                },
+            )
+        })
         .await
+        .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))
         .map_err(map_err)?;
+        Some(result)
+    } else {
+        None
+    };
+
+    apply_config_change(&app, &state, |manager| {
+        manager.set_playlists_directory(new_path)
+    })
+    .await?;
 
     info!("Storage directory updated successfully");
-    Ok(())
+    Ok(migration)
 }
 
 /// Get the default storage directory.
@@ -87,3 +240,115 @@ pub fn get_default_storage_directory() -> String {
         .display()
         .to_string()
 }
+
+/// List the names of saved configuration profiles.
+#[tauri::command]
+pub async fn list_config_profiles(state: State<'_, AppState>) -> CommandResult<Vec<String>> {
+    debug!("Listing config profiles");
+    let config_manager = state.config_manager.read().await;
+    Ok(config_manager.profile_names())
+}
+
+/// Save the current settings as a named configuration profile.
+#[tauri::command]
+pub async fn save_config_profile(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+) -> CommandResult<()> {
+    info!("Saving config profile: {}", name);
+    apply_config_change(&app, &state, |manager| manager.save_profile(&name)).await?;
+    Ok(())
+}
+
+/// Delete a saved configuration profile.
+#[tauri::command]
+pub async fn delete_config_profile(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+) -> CommandResult<()> {
+    info!("Deleting config profile: {}", name);
+    apply_config_change(&app, &state, |manager| manager.delete_profile(&name)).await?;
+    Ok(())
+}
+
+/// Switch to a saved configuration profile.
+///
+/// Swaps the playlists directory, cache directory, download quality and
+/// preferred device in one step and reinitializes the affected managers.
+/// Returns the resulting configuration.
+#[tauri::command]
+pub async fn switch_profile(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+) -> CommandResult<AppConfig> {
+    info!("Switching to config profile: {}", name);
+
+    let config = apply_config_change(&app, &state, |manager| manager.switch_profile(&name)).await?;
+
+    info!("Switched to config profile '{}'", name);
+    Ok(config)
+}
+
+/// Export the current configuration to a file.
+///
+/// Machine-specific paths (playlists/cache directories, preferred device and
+/// profiles) are included unless `include_machine_paths` is `false`.
+#[tauri::command]
+pub async fn export_config(
+    state: State<'_, AppState>,
+    path: String,
+    include_machine_paths: Option<bool>,
+) -> CommandResult<()> {
+    let include_machine_paths = include_machine_paths.unwrap_or(true);
+    info!(
+        "Exporting config to {} (machine paths: {})",
+        path, include_machine_paths
+    );
+
+    let config_manager = state.config_manager.read().await;
+    config_manager
+        .config()
+        .export_to(&PathBuf::from(path), include_machine_paths)
+        .map_err(map_err)
+}
+
+/// Import a configuration exported with `export_config`.
+///
+/// The file is validated and diffed against the current configuration. With
+/// `dry_run` set, nothing is applied and only the preview is returned.
+#[tauri::command]
+pub async fn import_config(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    path: String,
+    dry_run: Option<bool>,
+) -> CommandResult<ConfigImportPreview> {
+    let dry_run = dry_run.unwrap_or(false);
+    info!("Importing config from {} (dry run: {})", path, dry_run);
+
+    let preview = {
+        let config_manager = state.config_manager.read().await;
+        config_manager
+            .config()
+            .preview_import(&PathBuf::from(path))
+            .map_err(map_err)?
+    };
+
+    if dry_run || preview.changes.is_empty() {
+        debug!("Config import preview: {} changes", preview.changes.len());
+        return Ok(preview);
+    }
+
+    let mut imported = preview.config.clone();
+    apply_config_change(&app, &state, |manager| {
+        imported.simple_mode = manager.config().simple_mode;
+        manager.update(imported)
+    })
+    .await?;
+
+    info!("Imported config with {} changes", preview.changes.len());
+    Ok(preview)
+}
@@ -0,0 +1,69 @@
+//! Crash report commands.
+//!
+//! Native panics are recorded by the hook installed in `main`; the frontend
+//! reports its own panics through [`report_frontend_crash`].
+
+use tauri::{AppHandle, Manager, State};
+use tracing::{debug, error, warn};
+use youtun4_core::{CrashReport, CrashSource};
+
+use super::error::{CommandResult, map_err};
+use super::state::AppState;
+
+/// Get the report of the last crash, if it has not been dismissed.
+#[tauri::command]
+pub fn get_last_crash_report(state: State<'_, AppState>) -> Option<CrashReport> {
+    state.crash_reporter.last_report()
+}
+
+/// Dismiss the last crash report. Returns `false` if there was none.
+#[tauri::command]
+pub fn dismiss_crash_report(state: State<'_, AppState>) -> CommandResult<bool> {
+    state.crash_reporter.dismiss().map_err(map_err)
+}
+
+/// Record a panic in the frontend.
+#[tauri::command]
+pub fn report_frontend_crash(
+    state: State<'_, AppState>,
+    message: String,
+    location: Option<String>,
+    stack: Option<String>,
+) -> CommandResult<()> {
+    error!(
+        "Frontend panic at {}: {}",
+        location.as_deref().unwrap_or("unknown location"),
+        message
+    );
+    let mut report = CrashReport::new(CrashSource::Frontend, message, location);
+    report.backtrace = stack;
+    state.crash_reporter.save(&report).map_err(map_err)
+}
+
+/// Submit the last crash report in the background if the user opted in by
+/// configuring a crash report endpoint.
+pub fn submit_pending_crash_report(app: &AppHandle) {
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = handle.state::<AppState>();
+        let (endpoint, offline) = {
+            let config_manager = state.config_manager.read().await;
+            let config = config_manager.config();
+            (config.crash_report_endpoint.clone(), config.offline_mode)
+        };
+        let Some(endpoint) = endpoint.filter(|endpoint| !endpoint.trim().is_empty()) else {
+            return;
+        };
+        if offline {
+            debug!("Offline mode, not submitting crash report");
+            return;
+        }
+
+        let reporter = state.crash_reporter.clone();
+        match tokio::task::spawn_blocking(move || reporter.submit_pending(&endpoint)).await {
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => warn!("Failed to submit crash report: {}", e),
+            Err(e) => warn!("Crash report submission task failed: {}", e),
+        }
+    });
+}
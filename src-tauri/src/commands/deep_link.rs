@@ -0,0 +1,195 @@
+//! `youtun4://` deep links for adding playlists from a browser or share sheet.
+//!
+//! `youtun4://add?url=<playlist url>[&playlist=<name>][&priority=<high|normal|low>]`
+//! queues a download into the named library playlist. Plain `YouTube` links
+//! handed to the app (e.g. through "Open with") are accepted too. Links
+//! without a playlist name are passed to the UI so the user can pick one.
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, Url};
+use tauri_plugin_deep_link::DeepLinkExt;
+use tracing::{error, info, warn};
+use youtun4_core::queue::QueueItemId;
+
+use super::queue::queue_add_to_playlist;
+use super::state::AppState;
+use super::tray::show_main_window;
+
+/// Event names for deep link events.
+pub mod deep_link_events {
+    /// A link was received but needs a playlist name from the user.
+    pub const DEEP_LINK_RECEIVED: &str = "deep-link-received";
+    /// A link was added to the download queue.
+    pub const DEEP_LINK_QUEUED: &str = "deep-link-queued";
+    /// A link could not be handled.
+    pub const DEEP_LINK_REJECTED: &str = "deep-link-rejected";
+}
+
+/// URL scheme handled by the app.
+const SCHEME: &str = "youtun4";
+
+/// Hosts of `YouTube` links accepted directly.
+const YOUTUBE_HOSTS: &[&str] = &[
+    "youtube.com",
+    "www.youtube.com",
+    "m.youtube.com",
+    "music.youtube.com",
+    "youtu.be",
+];
+
+/// A download requested through a deep link.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeepLinkRequest {
+    /// `YouTube` playlist URL.
+    pub url: String,
+    /// Target library playlist, if given in the link.
+    pub playlist_name: Option<String>,
+    /// Queue priority, if given in the link.
+    pub priority: Option<String>,
+}
+
+impl DeepLinkRequest {
+    /// Parse a `youtun4://add` link or a plain `YouTube` link.
+    fn parse(link: &Url) -> Result<Self, String> {
+        if link.scheme() != SCHEME {
+            return match link.host_str() {
+                Some(host) if YOUTUBE_HOSTS.contains(&host) => Ok(Self {
+                    url: link.to_string(),
+                    playlist_name: None,
+                    priority: None,
+                }),
+                _ => Err(format!("Unsupported link: {link}")),
+            };
+        }
+
+        if link.host_str() != Some("add") {
+            return Err(format!("Unknown {SCHEME} action: {link}"));
+        }
+
+        let mut url = None;
+        let mut playlist_name = None;
+        let mut priority = None;
+        for (key, value) in link.query_pairs() {
+            let value = value.trim().to_string();
+            if value.is_empty() {
+                continue;
+            }
+            match key.as_ref() {
+                "url" => url = Some(value),
+                "playlist" => playlist_name = Some(value),
+                "priority" => priority = Some(value),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            url: url.ok_or_else(|| "Link is missing the 'url' parameter".to_string())?,
+            playlist_name,
+            priority,
+        })
+    }
+}
+
+/// Payload of [`deep_link_events::DEEP_LINK_QUEUED`].
+#[derive(Debug, Clone, Serialize)]
+pub struct DeepLinkQueuedPayload {
+    /// ID of the queue item created for the link.
+    pub item_id: QueueItemId,
+    /// `YouTube` playlist URL.
+    pub url: String,
+    /// Library playlist the download goes to.
+    pub playlist_name: String,
+}
+
+/// Payload of [`deep_link_events::DEEP_LINK_REJECTED`].
+#[derive(Debug, Clone, Serialize)]
+pub struct DeepLinkRejectedPayload {
+    /// The link as received.
+    pub link: String,
+    /// Why the link was rejected.
+    pub reason: String,
+}
+
+/// Handle deep links opened while running and the one the app was launched with.
+pub fn install_deep_link_handler(app: &AppHandle) {
+    // Linux and Windows dev builds have no installer to register the scheme
+    #[cfg(any(target_os = "linux", all(debug_assertions, windows)))]
+    if let Err(e) = app.deep_link().register_all() {
+        warn!("Failed to register deep link schemes: {}", e);
+    }
+
+    let handle = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        for link in event.urls() {
+            let handle = handle.clone();
+            tauri::async_runtime::spawn(async move { handle_link(&handle, &link).await });
+        }
+    });
+
+    match app.deep_link().get_current() {
+        Ok(Some(links)) => {
+            for link in links {
+                let handle = app.clone();
+                tauri::async_runtime::spawn(async move { handle_link(&handle, &link).await });
+            }
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Failed to read launch deep link: {}", e),
+    }
+
+    info!("Deep link handler installed");
+}
+
+async fn handle_link(app: &AppHandle, link: &Url) {
+    info!("Received deep link: {}", link);
+    show_main_window(app);
+
+    let request = match DeepLinkRequest::parse(link) {
+        Ok(request) => request,
+        Err(reason) => {
+            reject(app, link, reason);
+            return;
+        }
+    };
+
+    let Some(playlist_name) = request.playlist_name.clone() else {
+        if let Err(e) = app.emit(deep_link_events::DEEP_LINK_RECEIVED, &request) {
+            error!("Failed to emit deep-link-received event: {}", e);
+        }
+        return;
+    };
+
+    let state = app.state::<AppState>();
+    match queue_add_to_playlist(
+        app.clone(),
+        state,
+        request.url.clone(),
+        playlist_name.clone(),
+        request.priority,
+    )
+    .await
+    {
+        Ok(item_id) => {
+            let payload = DeepLinkQueuedPayload {
+                item_id,
+                url: request.url,
+                playlist_name,
+            };
+            if let Err(e) = app.emit(deep_link_events::DEEP_LINK_QUEUED, &payload) {
+                error!("Failed to emit deep-link-queued event: {}", e);
+            }
+        }
+        Err(e) => reject(app, link, e.message),
+    }
+}
+
+fn reject(app: &AppHandle, link: &Url, reason: String) {
+    warn!("Rejected deep link {}: {}", link, reason);
+    let payload = DeepLinkRejectedPayload {
+        link: link.to_string(),
+        reason,
+    };
+    if let Err(e) = app.emit(deep_link_events::DEEP_LINK_REJECTED, &payload) {
+        error!("Failed to emit deep-link-rejected event: {}", e);
+    }
+}
@@ -4,11 +4,16 @@
 
 use std::path::PathBuf;
 
-use tauri::State;
+use tauri::{AppHandle, State};
+use tauri_plugin_opener::OpenerExt;
 use tracing::{debug, error, info};
 use youtun4_core::device::{DeviceDetector, DeviceInfo};
+use youtun4_core::device_usage::DeviceUsage;
+use youtun4_core::i18n::{Message, keys};
+use youtun4_core::{DeviceProfile, Error};
 
-use super::error::map_err;
+use super::config::apply_config_change;
+use super::error::{CommandError, CommandResult, map_err};
 use super::state::AppState;
 
 /// Warning level for capacity checks.
@@ -38,15 +43,16 @@ pub struct CapacityCheckResult {
     pub usage_after_sync_percent: f64,
     /// Warning level based on available space.
     pub warning_level: CapacityWarningLevel,
-    /// Human-readable message about the capacity status.
+    /// Human-readable message about the capacity status, in the configured
+    /// language.
     pub message: String,
+    /// Translatable form of `message`.
+    pub message_i18n: Message,
 }
 
 /// List all detected devices.
 #[tauri::command]
-pub async fn list_devices(
-    state: State<'_, AppState>,
-) -> std::result::Result<Vec<DeviceInfo>, String> {
+pub async fn list_devices(state: State<'_, AppState>) -> CommandResult<Vec<DeviceInfo>> {
     info!("=== LIST_DEVICES command called ===");
 
     let mut manager = state.device_manager.write().await;
@@ -71,7 +77,7 @@ pub async fn list_devices(
 pub async fn get_device_info(
     state: State<'_, AppState>,
     mount_point: String,
-) -> std::result::Result<DeviceInfo, String> {
+) -> CommandResult<DeviceInfo> {
     debug!("Getting device info for: {}", mount_point);
 
     let mut manager = state.device_manager.write().await;
@@ -90,12 +96,35 @@ pub async fn get_device_info(
     Ok(device)
 }
 
+/// Open a connected device's root folder in the system file manager.
+///
+/// Only mount points of detected devices are opened.
+#[tauri::command]
+pub async fn open_device_folder(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    mount_point: String,
+) -> CommandResult<()> {
+    debug!("Opening device folder: {}", mount_point);
+
+    let mut manager = state.device_manager.write().await;
+    manager.refresh();
+    let device =
+        youtun4_core::device::get_device_by_mount_point(&*manager, &PathBuf::from(&mount_point))
+            .map_err(map_err)?;
+    drop(manager);
+
+    app.opener()
+        .open_path(device.mount_point.display().to_string(), None::<&str>)
+        .map_err(|e| CommandError::from(format!("Failed to open device folder: {e}")))
+}
+
 /// Check if a device is currently connected and available.
 #[tauri::command]
 pub async fn check_device_available(
     state: State<'_, AppState>,
     mount_point: String,
-) -> std::result::Result<bool, String> {
+) -> CommandResult<bool> {
     debug!("Checking device availability: {}", mount_point);
 
     let mut manager = state.device_manager.write().await;
@@ -120,7 +149,7 @@ pub async fn verify_device_space(
     state: State<'_, AppState>,
     mount_point: String,
     required_bytes: u64,
-) -> std::result::Result<bool, String> {
+) -> CommandResult<bool> {
     debug!(
         "Verifying space for device: {} (required: {} bytes)",
         mount_point, required_bytes
@@ -148,7 +177,7 @@ pub async fn check_sync_capacity(
     state: State<'_, AppState>,
     playlist_names: Vec<String>,
     device_mount_point: String,
-) -> std::result::Result<CapacityCheckResult, String> {
+) -> CommandResult<CapacityCheckResult> {
     debug!(
         "Checking sync capacity for {} playlists to device: {}",
         playlist_names.len(),
@@ -206,25 +235,24 @@ pub async fn check_sync_capacity(
         CapacityWarningLevel::Ok
     };
 
-    let message = if !can_fit {
+    let message_i18n = if !can_fit {
         let deficit = total_required - device.available_bytes;
-        format!(
-            "Insufficient space: need {} more on {}",
-            format_bytes(deficit),
-            device.name
-        )
+        Message::new(keys::CAPACITY_INSUFFICIENT)
+            .with("deficit", format_bytes(deficit))
+            .with("device", &device.name)
     } else if warning_level == CapacityWarningLevel::Warning {
-        format!(
-            "Limited space: {} will be {:.0}% full after sync",
-            device.name, usage_after_sync_percent
-        )
+        Message::new(keys::CAPACITY_LIMITED)
+            .with("device", &device.name)
+            .with("percent", format!("{usage_after_sync_percent:.0}"))
     } else {
-        format!(
-            "Ready to sync: {} available on {}",
-            format_bytes(device.available_bytes - total_required),
-            device.name
-        )
+        Message::new(keys::CAPACITY_READY)
+            .with(
+                "available",
+                format_bytes(device.available_bytes - total_required),
+            )
+            .with("device", &device.name)
     };
+    let message = message_i18n.render(state.locale().await);
 
     let result = CapacityCheckResult {
         can_fit,
@@ -234,6 +262,7 @@ pub async fn check_sync_capacity(
         usage_after_sync_percent,
         warning_level,
         message,
+        message_i18n,
     };
 
     info!(
@@ -248,6 +277,64 @@ pub async fn check_sync_capacity(
     Ok(result)
 }
 
+/// Break down how the space on a device is used, with its last sync.
+#[tauri::command]
+pub async fn analyze_device_usage(
+    state: State<'_, AppState>,
+    mount_point: String,
+) -> CommandResult<DeviceUsage> {
+    debug!("Analyzing device usage: {}", mount_point);
+
+    let device = {
+        let mut manager = state.device_manager.write().await;
+        manager.refresh();
+        youtun4_core::device::get_device_by_mount_point(&*manager, &PathBuf::from(&mount_point))
+            .map_err(map_err)?
+    };
+
+    let usage = tokio::task::spawn_blocking(move || {
+        youtun4_core::device_usage::analyze_device_usage(&device)
+    })
+    .await
+    .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))
+    .map_err(map_err)?;
+
+    info!(
+        "Device usage for {}: {} audio files ({} bytes), {} other files ({} bytes)",
+        mount_point, usage.music_files, usage.music_bytes, usage.other_files, usage.other_bytes
+    );
+    Ok(usage)
+}
+
+/// Get the saved settings (nickname, auto-sync, ignore) for a device.
+#[tauri::command]
+pub async fn get_device_profile(
+    state: State<'_, AppState>,
+    mount_point: String,
+) -> CommandResult<DeviceProfile> {
+    let config_manager = state.config_manager.read().await;
+    Ok(config_manager
+        .config()
+        .device_profile(&PathBuf::from(mount_point)))
+}
+
+/// Save the settings (nickname, auto-sync, ignore) for a device.
+#[tauri::command]
+pub async fn set_device_profile(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    mount_point: String,
+    profile: DeviceProfile,
+) -> CommandResult<()> {
+    info!("Saving device profile for {}", mount_point);
+    let mount_point = PathBuf::from(mount_point);
+    apply_config_change(&app, &state, |manager| {
+        manager.set_device_profile(&mount_point, profile)
+    })
+    .await?;
+    Ok(())
+}
+
 /// Format bytes as a human-readable string.
 pub fn format_bytes(bytes: u64) -> String {
     if bytes >= 1_000_000_000 {
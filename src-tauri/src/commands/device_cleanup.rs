@@ -1,15 +1,33 @@
 //! Device cleanup commands.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use tauri::State;
 use tracing::info;
+use youtun4_core::ProtectedOperation;
 use youtun4_core::cleanup::{CleanupOptions, CleanupResult, DeviceCleanupHandler};
 use youtun4_core::device::DeviceDetector;
 
-use super::error::map_err;
+use crate::runtime::{TaskCategory, TaskResource, TaskSlot};
+
+use super::error::{CommandError, CommandResult, map_err};
+use super::parental::require_parent_pin;
 use super::state::AppState;
 
+/// Admit a cleanup of the device at `mount_point`, so it can't overlap a
+/// sync or another cleanup of the same device.
+fn admit_cleanup(state: &AppState, mount_point: &Path) -> CommandResult<TaskSlot> {
+    let runtime = state.runtime();
+    runtime
+        .admit(
+            runtime.generate_task_id(),
+            TaskCategory::Cleanup,
+            Some(TaskResource::device(mount_point)),
+        )
+        .map_err(CommandError::from)
+}
+
 /// Preview what would be deleted from a device.
 #[tauri::command]
 pub async fn preview_device_cleanup(
@@ -17,7 +35,7 @@ pub async fn preview_device_cleanup(
     skip_hidden: bool,
     skip_system_files: bool,
     protected_patterns: Vec<String>,
-) -> std::result::Result<CleanupResult, String> {
+) -> CommandResult<CleanupResult> {
     info!("Previewing cleanup for device: {}", mount_point);
 
     let handler = DeviceCleanupHandler::new();
@@ -41,17 +59,26 @@ pub async fn preview_device_cleanup(
 }
 
 /// Clean up (delete) all non-protected files from a device.
+///
+/// In simple mode, `pin` must be the parent PIN.
 #[tauri::command]
 pub async fn cleanup_device(
+    state: State<'_, AppState>,
     mount_point: String,
     skip_hidden: bool,
     skip_system_files: bool,
     protected_patterns: Vec<String>,
     verify_deletions: bool,
-) -> std::result::Result<CleanupResult, String> {
+    pin: Option<String>,
+) -> CommandResult<CleanupResult> {
+    require_parent_pin(&state, ProtectedOperation::CleanupDevice, pin).await?;
     info!("Starting cleanup for device: {}", mount_point);
 
-    let handler = DeviceCleanupHandler::new();
+    let path = PathBuf::from(&mount_point);
+    let _slot = admit_cleanup(&state, &path)?;
+
+    let cancel_flag = state.register_cleanup().await;
+    let handler = DeviceCleanupHandler::new().with_cancel_flag(Arc::clone(&cancel_flag));
     let options = CleanupOptions {
         skip_hidden,
         skip_system_files,
@@ -61,8 +88,9 @@ pub async fn cleanup_device(
         ..Default::default()
     };
 
-    let path = PathBuf::from(&mount_point);
-    let result = handler.cleanup_device(&path, &options).map_err(map_err)?;
+    let result = handler.cleanup_device(&path, &options);
+    state.unregister_cleanup(&cancel_flag).await;
+    let result = result.map_err(map_err)?;
 
     info!(
         "Cleanup complete: {} files, {} directories deleted ({} bytes freed, {} failed)",
@@ -73,15 +101,24 @@ pub async fn cleanup_device(
 }
 
 /// Clean up only audio files from a device.
+///
+/// In simple mode, `pin` must be the parent PIN.
 #[tauri::command]
 pub async fn cleanup_device_audio_only(
+    state: State<'_, AppState>,
     mount_point: String,
     skip_hidden: bool,
     verify_deletions: bool,
-) -> std::result::Result<CleanupResult, String> {
+    pin: Option<String>,
+) -> CommandResult<CleanupResult> {
+    require_parent_pin(&state, ProtectedOperation::CleanupDevice, pin).await?;
     info!("Starting audio-only cleanup for device: {}", mount_point);
 
-    let handler = DeviceCleanupHandler::new();
+    let path = PathBuf::from(&mount_point);
+    let _slot = admit_cleanup(&state, &path)?;
+
+    let cancel_flag = state.register_cleanup().await;
+    let handler = DeviceCleanupHandler::new().with_cancel_flag(Arc::clone(&cancel_flag));
     let options = CleanupOptions {
         skip_hidden,
         skip_system_files: true,
@@ -90,10 +127,9 @@ pub async fn cleanup_device_audio_only(
         ..Default::default()
     };
 
-    let path = PathBuf::from(&mount_point);
-    let result = handler
-        .cleanup_audio_files_only(&path, &options)
-        .map_err(map_err)?;
+    let result = handler.cleanup_audio_files_only(&path, &options);
+    state.unregister_cleanup(&cancel_flag).await;
+    let result = result.map_err(map_err)?;
 
     info!(
         "Audio cleanup complete: {} files deleted ({} bytes freed)",
@@ -104,6 +140,8 @@ pub async fn cleanup_device_audio_only(
 }
 
 /// Perform a verified cleanup with device connection check.
+///
+/// In simple mode, `pin` must be the parent PIN.
 #[tauri::command]
 pub async fn cleanup_device_verified(
     state: State<'_, AppState>,
@@ -112,19 +150,23 @@ pub async fn cleanup_device_verified(
     skip_system_files: bool,
     protected_patterns: Vec<String>,
     verify_deletions: bool,
-) -> std::result::Result<CleanupResult, String> {
+    pin: Option<String>,
+) -> CommandResult<CleanupResult> {
+    require_parent_pin(&state, ProtectedOperation::CleanupDevice, pin).await?;
     info!("Starting verified cleanup for device: {}", mount_point);
 
     let mut manager = state.device_manager.write().await;
     manager.refresh();
 
     let path = PathBuf::from(&mount_point);
+    let _slot = admit_cleanup(&state, &path)?;
     let device =
         youtun4_core::device::get_device_by_mount_point(&*manager, &path).map_err(map_err)?;
 
     youtun4_core::device::verify_device_accessible(&*manager, &device).map_err(map_err)?;
 
-    let handler = DeviceCleanupHandler::new();
+    let cancel_flag = state.register_cleanup().await;
+    let handler = DeviceCleanupHandler::new().with_cancel_flag(Arc::clone(&cancel_flag));
     let options = CleanupOptions {
         skip_hidden,
         skip_system_files,
@@ -134,9 +176,9 @@ pub async fn cleanup_device_verified(
         ..Default::default()
     };
 
-    let result = handler
-        .cleanup_device_verified(&*manager, &device, &options)
-        .map_err(map_err)?;
+    let result = handler.cleanup_device_verified(&*manager, &device, &options);
+    state.unregister_cleanup(&cancel_flag).await;
+    let result = result.map_err(map_err)?;
 
     info!(
         "Verified cleanup complete: {} files, {} directories deleted ({} bytes freed)",
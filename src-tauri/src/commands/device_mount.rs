@@ -6,7 +6,7 @@ use tauri::State;
 use tracing::{debug, info};
 use youtun4_core::device::{DeviceMountHandler, MountResult, MountStatus, UnmountResult};
 
-use super::error::map_err;
+use super::error::{CommandResult, map_err};
 use super::state::AppState;
 
 /// Get the mount status of a device.
@@ -14,7 +14,7 @@ use super::state::AppState;
 pub async fn get_mount_status(
     state: State<'_, AppState>,
     device_path: String,
-) -> std::result::Result<MountStatus, String> {
+) -> CommandResult<MountStatus> {
     debug!("Getting mount status for: {}", device_path);
 
     let path = PathBuf::from(&device_path);
@@ -36,7 +36,7 @@ pub async fn mount_device(
     state: State<'_, AppState>,
     device_path: String,
     mount_point: Option<String>,
-) -> std::result::Result<MountResult, String> {
+) -> CommandResult<MountResult> {
     info!("Mounting device: {}", device_path);
 
     let path = PathBuf::from(&device_path);
@@ -67,7 +67,7 @@ pub async fn unmount_device(
     state: State<'_, AppState>,
     mount_point: String,
     force: bool,
-) -> std::result::Result<UnmountResult, String> {
+) -> CommandResult<UnmountResult> {
     info!("Unmounting device at: {} (force={})", mount_point, force);
 
     let path = PathBuf::from(&mount_point);
@@ -85,7 +85,7 @@ pub async fn unmount_device(
 pub async fn eject_device(
     state: State<'_, AppState>,
     mount_point: String,
-) -> std::result::Result<UnmountResult, String> {
+) -> CommandResult<UnmountResult> {
     info!("Ejecting device at: {}", mount_point);
 
     let path = PathBuf::from(&mount_point);
@@ -100,7 +100,7 @@ pub async fn eject_device(
 pub async fn is_mount_point_accessible(
     state: State<'_, AppState>,
     mount_point: String,
-) -> std::result::Result<bool, String> {
+) -> CommandResult<bool> {
     debug!("Checking accessibility of mount point: {}", mount_point);
 
     let path = PathBuf::from(&mount_point);
@@ -112,8 +112,6 @@ pub async fn is_mount_point_accessible(
 
 /// Get the current platform identifier.
 #[tauri::command]
-pub async fn get_mount_handler_platform(
-    state: State<'_, AppState>,
-) -> std::result::Result<String, String> {
+pub async fn get_mount_handler_platform(state: State<'_, AppState>) -> CommandResult<String> {
     Ok(state.mount_handler.platform().to_string())
 }
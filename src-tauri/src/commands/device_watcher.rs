@@ -1,10 +1,15 @@
 //! Device watching commands for monitoring USB device connections.
 
-use tauri::{AppHandle, Emitter, State};
-use tracing::{debug, error, info};
-use youtun4_core::device::{DeviceEvent, DeviceWatcher};
+use std::time::Duration;
 
+use tauri::{AppHandle, Emitter, Manager, State};
+use tracing::{debug, error, info, warn};
+use youtun4_core::device::{DeviceEvent, DeviceInfo, DeviceWatcher};
+use youtun4_core::device_usage::DeviceSyncRecord;
+
+use super::error::CommandResult;
 use super::state::AppState;
+use super::sync_orchestrator::start_orchestrated_sync;
 
 /// Event names for device events emitted to the frontend.
 pub mod device_events {
@@ -21,7 +26,7 @@ pub mod device_events {
 pub async fn start_device_watcher(
     app: AppHandle,
     state: State<'_, AppState>,
-) -> std::result::Result<bool, String> {
+) -> CommandResult<bool> {
     info!("Starting device watcher");
 
     // Check if watcher is already running
@@ -34,9 +39,23 @@ pub async fn start_device_watcher(
         }
     }
 
-    // Create and start the device watcher
+    spawn_device_watcher(app, &state).await;
+
+    info!("Device watcher started successfully");
+    Ok(true)
+}
+
+/// Create and start the device watcher, forwarding its events to the frontend.
+///
+/// Uses the polling interval from the current configuration.
+async fn spawn_device_watcher(app: AppHandle, state: &AppState) {
+    let poll_interval = {
+        let config_manager = state.config_manager.read().await;
+        Duration::from_millis(config_manager.config().device_poll_interval_ms)
+    };
+
     let device_manager = state.device_manager_arc();
-    let watcher = DeviceWatcher::new(device_manager);
+    let watcher = DeviceWatcher::with_interval(device_manager, poll_interval);
     let (mut event_rx, watcher_handle) = watcher.start();
 
     // Store the handle
@@ -47,19 +66,19 @@ pub async fn start_device_watcher(
     }
 
     // Spawn a task to forward events to the frontend
-    let app_handle = app.clone();
     tokio::spawn(async move {
         while let Some(event) = event_rx.recv().await {
             match &event {
                 DeviceEvent::Connected(device) => {
                     info!("Emitting device-connected event: {}", device.name);
-                    if let Err(e) = app_handle.emit(device_events::DEVICE_CONNECTED, device) {
+                    if let Err(e) = app.emit(device_events::DEVICE_CONNECTED, device) {
                         error!("Failed to emit device-connected event: {}", e);
                     }
+                    auto_sync_device(&app, device).await;
                 }
                 DeviceEvent::Disconnected(device) => {
                     info!("Emitting device-disconnected event: {}", device.name);
-                    if let Err(e) = app_handle.emit(device_events::DEVICE_DISCONNECTED, device) {
+                    if let Err(e) = app.emit(device_events::DEVICE_DISCONNECTED, device) {
                         error!("Failed to emit device-disconnected event: {}", e);
                     }
                 }
@@ -68,7 +87,7 @@ pub async fn start_device_watcher(
                         "Emitting devices-refreshed event: {} devices",
                         devices.len()
                     );
-                    if let Err(e) = app_handle.emit(device_events::DEVICES_REFRESHED, devices) {
+                    if let Err(e) = app.emit(device_events::DEVICES_REFRESHED, devices) {
                         error!("Failed to emit devices-refreshed event: {}", e);
                     }
                 }
@@ -76,14 +95,76 @@ pub async fn start_device_watcher(
         }
         debug!("Device event forwarding task ended");
     });
+}
 
-    info!("Device watcher started successfully");
-    Ok(true)
+/// Sync a newly connected device again if its profile asks for it and the
+/// device isn't ignored.
+///
+/// The playlists of the device's last sync are copied again, skipping files
+/// already there. Nothing is removed from the device without the user there
+/// to confirm it, so auto-sync never cleans up.
+async fn auto_sync_device(app: &AppHandle, device: &DeviceInfo) {
+    let state = app.state::<AppState>();
+    let profile = {
+        let config_manager = state.config_manager.read().await;
+        config_manager.config().device_profile(&device.mount_point)
+    };
+    if profile.ignored || !profile.auto_sync {
+        return;
+    }
+    let Some(record) = DeviceSyncRecord::read(&device.mount_point) else {
+        debug!(
+            "Auto-sync enabled for {} but it was never synced",
+            device.mount_point.display()
+        );
+        return;
+    };
+
+    info!(
+        "Auto-syncing {} playlist(s) to {}",
+        record.playlists.len(),
+        device.mount_point.display()
+    );
+    if let Err(e) = start_orchestrated_sync(
+        app.clone(),
+        state,
+        record.playlists,
+        device.mount_point.to_string_lossy().into_owned(),
+        false,
+        true,
+        true,
+        None,
+    )
+    .await
+    {
+        warn!(
+            "Failed to auto-sync {}: {}",
+            device.mount_point.display(),
+            e
+        );
+    }
+}
+
+/// Restart the device watcher so it picks up configuration changes.
+///
+/// Does nothing if the watcher is not running.
+pub(crate) async fn restart_device_watcher(app: AppHandle, state: &AppState) {
+    {
+        let handle_arc = state.device_watcher_handle_arc();
+        let mut handle = handle_arc.write().await;
+        let Some(watcher_handle) = handle.take() else {
+            return;
+        };
+        watcher_handle.stop().await;
+    }
+
+    info!("Restarting device watcher with updated configuration");
+    spawn_device_watcher(app, state).await;
 }
 
 /// Stop watching for USB device connections/disconnections.
 #[tauri::command]
-pub async fn stop_device_watcher(state: State<'_, AppState>) -> std::result::Result<bool, String> {
+pub async fn stop_device_watcher(state: State<'_, AppState>) -> CommandResult<bool> {
     info!("Stopping device watcher");
 
     let handle_arc = state.device_watcher_handle_arc();
@@ -101,9 +182,7 @@ pub async fn stop_device_watcher(state: State<'_, AppState>) -> std::result::Res
 
 /// Check if the device watcher is currently running.
 #[tauri::command]
-pub async fn is_device_watcher_running(
-    state: State<'_, AppState>,
-) -> std::result::Result<bool, String> {
+pub async fn is_device_watcher_running(state: State<'_, AppState>) -> CommandResult<bool> {
     let handle_arc = state.device_watcher_handle_arc();
     let handle = handle_arc.read().await;
     Ok(handle.is_some())
@@ -0,0 +1,151 @@
+//! Diagnostics bundle export for bug reports.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use tauri::State;
+use tracing::{info, warn};
+use youtun4_core::cache::CacheManager;
+use youtun4_core::metrics::{self, PerformanceMetrics};
+use youtun4_core::secrets::secret_keys;
+
+use crate::diagnostics::DiagnosticsBundle;
+use crate::logging;
+
+use super::error::{CommandError, CommandResult};
+use super::state::AppState;
+
+/// Number of recent log entries included in a bundle.
+const DIAGNOSTICS_LOG_LINES: usize = 2000;
+
+/// Overview of the app and platform, the first file in a bundle.
+#[derive(Debug, Serialize)]
+struct DiagnosticsSummary {
+    app_version: &'static str,
+    os: &'static str,
+    arch: &'static str,
+    generated_at_unix: u64,
+    offline_mode: bool,
+    in_background: bool,
+    secrets_backend: &'static str,
+    // Which secrets are set; values are never included
+    secrets_set: BTreeMap<&'static str, bool>,
+    log_directory: String,
+}
+
+fn add_json(
+    bundle: &mut DiagnosticsBundle,
+    name: &str,
+    value: &impl Serialize,
+) -> CommandResult<()> {
+    bundle
+        .add_json(name, value)
+        .map_err(|e| CommandError::from(format!("Failed to serialize {name}: {e}")))
+}
+
+/// Export a zip of recent logs, config (secrets redacted), cache stats, queue
+/// state, performance metrics and connected devices for attaching to a bug report.
+///
+/// Returns the path of the written archive.
+#[tauri::command]
+pub async fn export_diagnostics(state: State<'_, AppState>, path: String) -> CommandResult<String> {
+    info!("Exporting diagnostics to '{}'", path);
+    let path = PathBuf::from(path);
+    let config = state.config_manager.read().await.config().clone();
+    let mut bundle = DiagnosticsBundle::new();
+
+    let summary = DiagnosticsSummary {
+        app_version: env!("CARGO_PKG_VERSION"),
+        os: std::env::consts::OS,
+        arch: std::env::consts::ARCH,
+        generated_at_unix: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs()),
+        offline_mode: config.offline_mode,
+        in_background: state.is_in_background(),
+        secrets_backend: state.secrets.backend_name(),
+        secrets_set: secret_keys::ALL
+            .iter()
+            .map(|key| (*key, state.secrets.contains(key).unwrap_or(false)))
+            .collect(),
+        log_directory: logging::log_directory().display().to_string(),
+    };
+    add_json(&mut bundle, "summary.json", &summary)?;
+    add_json(&mut bundle, "config.json", &config)?;
+
+    // Sections that can't be gathered are noted in the bundle instead of failing it
+    match CacheManager::new(config.cache.clone()) {
+        Ok(cache) => add_json(&mut bundle, "cache.json", &cache.stats())?,
+        Err(e) => bundle.add_text("cache.txt", &format!("Cache unavailable: {e}")),
+    }
+
+    let queue = state.download_queue_arc();
+    add_json(
+        &mut bundle,
+        "queue.json",
+        &serde_json::json!({
+            "paused": queue.is_paused().await,
+            "stats": queue.stats().await,
+            "items": queue.get_all_items().await,
+        }),
+    )?;
+    add_json(&mut bundle, "tasks.json", &state.active_tasks())?;
+    add_json(&mut bundle, "metrics.json", &metrics::snapshot())?;
+
+    let devices = {
+        let mut manager = state.device_manager.write().await;
+        manager.refresh();
+        manager.list_devices()
+    };
+    match devices {
+        Ok(devices) => add_json(&mut bundle, "devices.json", &devices)?,
+        Err(e) => bundle.add_text("devices.txt", &format!("Device detection failed: {e}")),
+    }
+
+    let logs = tokio::task::spawn_blocking(|| logging::recent_entries(DIAGNOSTICS_LOG_LINES, None))
+        .await
+        .map_err(|e| CommandError::from(format!("Log reading task failed: {e}")))?;
+    match logs {
+        Ok(entries) => {
+            let mut text = String::new();
+            for entry in &entries {
+                let _ = writeln!(
+                    text,
+                    "{} {:>5} {}: {}",
+                    entry.timestamp, entry.level, entry.target, entry.message
+                );
+            }
+            bundle.add_text("logs.txt", &text);
+        }
+        Err(e) => {
+            warn!("Failed to read logs for diagnostics: {}", e);
+            bundle.add_text("logs.txt", &format!("Logs unavailable: {e}"));
+        }
+    }
+
+    let written = path.clone();
+    tokio::task::spawn_blocking(move || bundle.write_zip(&written))
+        .await
+        .map_err(|e| CommandError::from(format!("Diagnostics export task failed: {e}")))?
+        .map_err(|e| CommandError::from(format!("Failed to write diagnostics bundle: {e}")))?;
+
+    info!("Diagnostics exported to '{}'", path.display());
+    Ok(path.display().to_string())
+}
+
+/// Get the performance counters for hashing, transfers and playlist scraping
+/// since the app started or the counters were last reset.
+#[tauri::command]
+pub fn get_performance_metrics() -> PerformanceMetrics {
+    metrics::snapshot()
+}
+
+/// Reset the performance counters, e.g. before measuring a sync.
+#[tauri::command]
+pub fn reset_performance_metrics() {
+    info!("Resetting performance metrics");
+    metrics::reset();
+}
@@ -1,50 +1,113 @@
 //! Error handling utilities for Tauri commands.
 
 use tracing::error;
-use youtun4_core::{Error, ErrorKind};
+use youtun4_core::i18n::{self, Message, keys};
+use youtun4_core::{Error, ErrorCode, ErrorKind};
 
-/// Structured error response for Tauri IPC.
+use crate::runtime::{TaskBusy, TaskId};
+
+/// Result type returned by all Tauri commands.
+pub type CommandResult<T> = std::result::Result<T, CommandError>;
+
+/// Structured error returned by Tauri commands.
 ///
-/// Includes both the error message and the error kind for frontend handling.
+/// Serialized as an object so the frontend can branch on the stable `code`
+/// instead of parsing messages.
 #[derive(Debug, Clone, serde::Serialize)]
-pub struct ErrorResponse {
-    /// Human-readable error message.
+pub struct CommandError {
+    /// Stable machine-readable error code.
+    pub code: ErrorCode,
+    /// Human-readable error message (for logs and fallback display).
     pub message: String,
-    /// Error category for programmatic handling.
-    pub kind: String,
+    /// Translatable description of the error for display.
+    pub message_i18n: Message,
     /// Whether the error can be retried.
     pub retryable: bool,
     /// Suggested retry delay in seconds, if applicable.
     pub retry_delay_secs: Option<u64>,
+    /// The running task that blocked this one, for `task_busy` errors.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflicting_task_id: Option<TaskId>,
+}
+
+impl CommandError {
+    /// Create an error with a specific code and message.
+    ///
+    /// The message is shown as is in every language.
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self {
+            code,
+            message_i18n: Message::new(keys::ERROR_GENERIC).with("details", &message),
+            message,
+            retryable: false,
+            retry_delay_secs: None,
+            conflicting_task_id: None,
+        }
+    }
+
+    /// Create an invalid input error.
+    pub fn invalid_input(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::InvalidInput, message)
+    }
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
 }
 
-impl From<&Error> for ErrorResponse {
+impl From<&Error> for CommandError {
     fn from(e: &Error) -> Self {
         Self {
+            code: e.code(),
             message: e.to_string(),
-            kind: format!("{:?}", e.kind()),
+            message_i18n: Message::for_error(e),
             retryable: e.is_retryable(),
             retry_delay_secs: e.retry_delay_secs(),
+            conflicting_task_id: None,
         }
     }
 }
 
-/// Convert our error type to a string for Tauri.
-///
-/// The returned string is JSON-encoded `ErrorResponse` for structured error handling
-/// in the frontend. Falls back to plain error message if serialization fails.
-pub fn map_err(e: Error) -> String {
+impl From<Error> for CommandError {
+    fn from(e: Error) -> Self {
+        Self::from(&e)
+    }
+}
+
+impl From<TaskBusy> for CommandError {
+    fn from(busy: TaskBusy) -> Self {
+        Self {
+            conflicting_task_id: Some(busy.conflicting_task()),
+            message_i18n: Message::new(i18n::error_key(ErrorCode::TaskBusy)),
+            ..Self::new(ErrorCode::TaskBusy, busy.to_string())
+        }
+    }
+}
+
+/// Ad-hoc failures without a more specific core error are internal errors.
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        Self::new(ErrorCode::Internal, message)
+    }
+}
+
+/// Convert our error type to a [`CommandError`] for Tauri, logging it.
+pub fn map_err(e: Error) -> CommandError {
     let kind = e.kind();
     let is_retryable = e.is_retryable();
 
     error!(
-        "Command error [kind={:?}, retryable={}]: {}",
-        kind, is_retryable, e
+        "Command error [kind={:?}, code={:?}, retryable={}]: {}",
+        kind,
+        e.code(),
+        is_retryable,
+        e
     );
 
-    // Try to return structured JSON for the frontend
-    let response = ErrorResponse::from(&e);
-    serde_json::to_string(&response).unwrap_or_else(|_| e.to_string())
+    CommandError::from(&e)
 }
 
 /// Get the error kind from an error, useful for testing.
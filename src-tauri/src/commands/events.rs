@@ -0,0 +1,203 @@
+//! Unified task event bus.
+//!
+//! Sync, orchestrated sync, downloads, the download queue, cache warming,
+//! integrity verification, maintenance operations and file transfers all
+//! report through a single [`TaskEvent`] emitted on
+//! [`task_events::TASK_EVENT`], so the frontend only needs one listener. Each feature's own event names are still emitted
+//! alongside as deprecated aliases.
+
+use serde::Serialize;
+use serde_json::Value;
+use tauri::{AppHandle, Emitter};
+
+use super::cache::cache_events;
+use super::integrity::integrity_events;
+use super::maintenance::maintenance_events;
+use super::queue::queue_events;
+use super::sync::sync_events;
+use super::sync_orchestrator::sync_orchestrator_events;
+use super::transfer::transfer_events;
+use super::youtube::youtube_events;
+
+/// Event names for the unified task event bus.
+pub mod task_events {
+    /// Emitted for every task lifecycle and progress update.
+    pub const TASK_EVENT: &str = "task-event";
+}
+
+/// Feature area a task event belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskEventCategory {
+    /// Single playlist sync to a device.
+    Sync,
+    /// Multi-playlist orchestrated sync.
+    OrchestratedSync,
+    /// YouTube playlist download.
+    Download,
+    /// Download queue item or queue state.
+    Queue,
+    /// Cache warming.
+    Cache,
+    /// Integrity verification.
+    Verification,
+    /// Maintenance operation (checksum manifests, folder repair, cache clearing).
+    Maintenance,
+    /// Raw file transfer.
+    Transfer,
+}
+
+/// Lifecycle phase of a task event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskPhase {
+    /// A task was added without being started (queue items).
+    Queued,
+    /// The task started.
+    Started,
+    /// The task reported progress.
+    Progress,
+    /// The task finished successfully.
+    Completed,
+    /// The task failed.
+    Failed,
+    /// The task was cancelled.
+    Cancelled,
+    /// The task was removed without running (queue items).
+    Removed,
+    /// The task or its container (queue) was paused.
+    Paused,
+    /// The task or its container (queue) was resumed.
+    Resumed,
+    /// The task's container settings changed (queue).
+    Updated,
+}
+
+/// A single event on the unified task event bus.
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskEvent<'a> {
+    /// Task identifier (queue item ID for queue events), if known.
+    pub task_id: Option<u64>,
+    /// Feature area the event belongs to.
+    pub category: TaskEventCategory,
+    /// Lifecycle phase.
+    pub phase: TaskPhase,
+    /// Overall progress between 0.0 and 1.0, if known.
+    pub progress: Option<f64>,
+    /// Name of the deprecated per-feature event this mirrors.
+    pub event: &'a str,
+    /// The per-feature payload, unchanged.
+    pub payload: Value,
+}
+
+/// Map a per-feature event name to its category and phase.
+fn classify(event: &str) -> Option<(TaskEventCategory, TaskPhase)> {
+    use TaskEventCategory as C;
+    use TaskPhase as P;
+
+    let classified = match event {
+        sync_events::SYNC_STARTED => (C::Sync, P::Started),
+        sync_events::SYNC_PROGRESS => (C::Sync, P::Progress),
+        sync_events::SYNC_COMPLETED => (C::Sync, P::Completed),
+        sync_events::SYNC_FAILED => (C::Sync, P::Failed),
+        sync_events::SYNC_CANCELLED => (C::Sync, P::Cancelled),
+        sync_orchestrator_events::SYNC_ORCHESTRATOR_PROGRESS => (C::OrchestratedSync, P::Progress),
+        sync_orchestrator_events::SYNC_ORCHESTRATOR_COMPLETED => {
+            (C::OrchestratedSync, P::Completed)
+        }
+        sync_orchestrator_events::SYNC_ORCHESTRATOR_FAILED => (C::OrchestratedSync, P::Failed),
+        sync_orchestrator_events::SYNC_ORCHESTRATOR_CANCELLED => {
+            (C::OrchestratedSync, P::Cancelled)
+        }
+        youtube_events::DOWNLOAD_STARTED => (C::Download, P::Started),
+        youtube_events::DOWNLOAD_PROGRESS => (C::Download, P::Progress),
+        youtube_events::DOWNLOAD_COMPLETED => (C::Download, P::Completed),
+        youtube_events::DOWNLOAD_FAILED => (C::Download, P::Failed),
+        youtube_events::DOWNLOAD_CANCELLED => (C::Download, P::Cancelled),
+        youtube_events::DOWNLOAD_PAUSED => (C::Download, P::Paused),
+        youtube_events::DOWNLOAD_RESUMED => (C::Download, P::Resumed),
+        youtube_events::PLAYLIST_FETCH_PROGRESS => (C::Download, P::Progress),
+        queue_events::QUEUE_ITEM_ADDED => (C::Queue, P::Queued),
+        queue_events::QUEUE_ITEM_STARTED => (C::Queue, P::Started),
+        queue_events::QUEUE_ITEM_PROGRESS => (C::Queue, P::Progress),
+        queue_events::QUEUE_ITEM_COMPLETED => (C::Queue, P::Completed),
+        queue_events::QUEUE_ITEM_PLANNED => (C::Queue, P::Completed),
+        queue_events::QUEUE_ITEM_FAILED => (C::Queue, P::Failed),
+        queue_events::QUEUE_ITEM_CANCELLED => (C::Queue, P::Cancelled),
+        queue_events::QUEUE_ITEM_REMOVED => (C::Queue, P::Removed),
+        queue_events::QUEUE_PAUSED => (C::Queue, P::Paused),
+        queue_events::QUEUE_RESUMED => (C::Queue, P::Resumed),
+        queue_events::QUEUE_CONFIG_UPDATED => (C::Queue, P::Updated),
+        queue_events::QUEUE_REORDERED => (C::Queue, P::Updated),
+        cache_events::CACHE_WARM_COMPLETED => (C::Cache, P::Completed),
+        cache_events::CACHE_WARM_FAILED => (C::Cache, P::Failed),
+        integrity_events::VERIFICATION_PROGRESS => (C::Verification, P::Progress),
+        integrity_events::VERIFICATION_COMPLETED => (C::Verification, P::Completed),
+        maintenance_events::MAINTENANCE_STARTED => (C::Maintenance, P::Started),
+        maintenance_events::MAINTENANCE_PROGRESS => (C::Maintenance, P::Progress),
+        maintenance_events::MAINTENANCE_COMPLETED => (C::Maintenance, P::Completed),
+        maintenance_events::MAINTENANCE_FAILED => (C::Maintenance, P::Failed),
+        maintenance_events::MAINTENANCE_CANCELLED => (C::Maintenance, P::Cancelled),
+        transfer_events::TRANSFER_PROGRESS => (C::Transfer, P::Progress),
+        _ => return None,
+    };
+    Some(classified)
+}
+
+/// Extract the task identifier from a per-feature payload.
+fn extract_task_id(category: TaskEventCategory, payload: &Value) -> Option<u64> {
+    let keys: &[&str] = match category {
+        TaskEventCategory::Queue => &["item_id", "id"],
+        _ => &["task_id"],
+    };
+    payload.as_u64().or_else(|| {
+        keys.iter()
+            .find_map(|key| payload.get(key).and_then(Value::as_u64))
+    })
+}
+
+/// Extract overall progress (0.0 to 1.0) from a per-feature payload.
+fn extract_progress(phase: TaskPhase, payload: &Value) -> Option<f64> {
+    let number = |key: &str| payload.get(key).and_then(Value::as_f64);
+    let ratio = |done: &str, total: &str| match (number(done), number(total)) {
+        (Some(done), Some(total)) if total > 0.0 => Some(done / total),
+        _ => None,
+    };
+
+    let progress = number("overall_progress_percent")
+        .map(|percent| percent / 100.0)
+        .or_else(|| number("overall_progress"))
+        .or_else(|| number("progress"))
+        .or_else(|| ratio("total_bytes_transferred", "total_bytes"))
+        .or_else(|| ratio("verified", "total_files"));
+
+    match (phase, progress) {
+        (TaskPhase::Completed, _) => Some(1.0),
+        (_, Some(progress)) => Some(progress.clamp(0.0, 1.0)),
+        (_, None) => None,
+    }
+}
+
+/// Emit a task event on the unified bus, along with its deprecated
+/// per-feature alias `event`.
+pub(crate) fn emit_task_event<S: Serialize>(
+    app: &AppHandle,
+    event: &str,
+    payload: &S,
+) -> tauri::Result<()> {
+    app.emit(event, payload)?;
+
+    let Some((category, phase)) = classify(event) else {
+        return Ok(());
+    };
+    let payload = serde_json::to_value(payload)?;
+    let task_event = TaskEvent {
+        task_id: extract_task_id(category, &payload),
+        category,
+        phase,
+        progress: extract_progress(phase, &payload),
+        event,
+        payload,
+    };
+    app.emit(task_events::TASK_EVENT, &task_event)
+}
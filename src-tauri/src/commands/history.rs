@@ -0,0 +1,57 @@
+//! Download and sync history commands.
+//!
+//! Downloads and syncs add their entry through [`record_history`] when they
+//! end; the frontend reads the history for the history page.
+
+use tauri::{AppHandle, Manager, State};
+use tracing::warn;
+use youtun4_core::history::{HistoryEntry, HistoryFilter, HistoryReport, HistoryStats};
+
+use super::error::{CommandResult, map_err};
+use super::state::AppState;
+
+/// Number of weeks covered by the downloaded bytes chart.
+const HISTORY_CHART_WEEKS: usize = 12;
+
+/// Add a finished download or sync to the history. Failures are logged and
+/// never affect the operation itself.
+pub fn record_history(app: &AppHandle, entry: HistoryEntry) {
+    let state = app.state::<AppState>();
+    if let Err(e) = state.history.record(entry) {
+        warn!("Failed to record history entry: {}", e);
+    }
+}
+
+/// Get the history entries matching `filter`, newest first, with the bytes
+/// downloaded per week.
+#[tauri::command]
+pub fn get_history(state: State<'_, AppState>, filter: Option<HistoryFilter>) -> HistoryReport {
+    state
+        .history
+        .report(&filter.unwrap_or_default(), HISTORY_CHART_WEEKS)
+}
+
+/// Get totals over every download in the history: bytes downloaded overall
+/// and this month, videos downloaded and failed, and the average speed.
+#[tauri::command]
+pub fn get_history_stats(state: State<'_, AppState>) -> HistoryStats {
+    state.history.stats()
+}
+
+/// Remove the history entries older than `older_than_days` days, returning
+/// how many were removed.
+#[tauri::command]
+pub fn prune_history(state: State<'_, AppState>, older_than_days: u32) -> CommandResult<usize> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let before = now.saturating_sub(u64::from(older_than_days) * 24 * 60 * 60);
+    state.history.prune(before).map_err(map_err)
+}
+
+/// Remove every history entry, returning how many were removed.
+#[tauri::command]
+pub fn clear_history(state: State<'_, AppState>) -> CommandResult<usize> {
+    state.history.clear().map_err(map_err)
+}
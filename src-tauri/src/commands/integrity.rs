@@ -2,7 +2,7 @@
 
 use std::path::PathBuf;
 
-use tauri::{AppHandle, Emitter};
+use tauri::AppHandle;
 use tracing::{debug, error, info};
 use youtun4_core::Error;
 use youtun4_core::integrity::{
@@ -10,9 +10,13 @@ use youtun4_core::integrity::{
     VerificationResult,
 };
 
-use super::error::map_err;
+use super::error::{CommandResult, map_err};
+use super::events::emit_task_event;
 
 /// Event names for integrity verification events.
+///
+/// Deprecated aliases: every event here is also emitted as a unified `task-event`,
+/// which new frontend code should listen to instead.
 pub mod integrity_events {
     /// Event emitted for verification progress updates.
     pub const VERIFICATION_PROGRESS: &str = "integrity-verification-progress";
@@ -22,7 +26,7 @@ pub mod integrity_events {
 
 /// Create a checksum manifest for a directory.
 #[tauri::command]
-pub async fn create_checksum_manifest(directory: String) -> std::result::Result<usize, String> {
+pub async fn create_checksum_manifest(directory: String) -> CommandResult<usize> {
     info!("Creating checksum manifest for directory: {}", directory);
 
     let path = PathBuf::from(&directory);
@@ -41,9 +45,7 @@ pub async fn create_checksum_manifest(directory: String) -> std::result::Result<
 
 /// Load a checksum manifest from a directory.
 #[tauri::command]
-pub async fn load_checksum_manifest(
-    directory: String,
-) -> std::result::Result<ChecksumManifest, String> {
+pub async fn load_checksum_manifest(directory: String) -> CommandResult<ChecksumManifest> {
     debug!("Loading checksum manifest from: {}", directory);
 
     let path = PathBuf::from(&directory);
@@ -52,7 +54,7 @@ pub async fn load_checksum_manifest(
 
 /// Check if a checksum manifest exists in a directory.
 #[tauri::command]
-pub async fn has_checksum_manifest(directory: String) -> std::result::Result<bool, String> {
+pub async fn has_checksum_manifest(directory: String) -> CommandResult<bool> {
     let path = PathBuf::from(&directory).join(youtun4_core::integrity::DEFAULT_MANIFEST_FILE);
     Ok(path.exists())
 }
@@ -63,7 +65,7 @@ pub async fn verify_directory_integrity(
     app: AppHandle,
     directory: String,
     check_extra_files: bool,
-) -> std::result::Result<VerificationResult, String> {
+) -> CommandResult<VerificationResult> {
     info!("Verifying integrity of directory: {}", directory);
 
     let path = PathBuf::from(&directory);
@@ -78,7 +80,11 @@ pub async fn verify_directory_integrity(
 
     let app_handle = app.clone();
     let progress_callback = move |progress: &VerificationProgress| {
-        if let Err(e) = app_handle.emit(integrity_events::VERIFICATION_PROGRESS, progress) {
+        if let Err(e) = emit_task_event(
+            &app_handle,
+            integrity_events::VERIFICATION_PROGRESS,
+            progress,
+        ) {
             error!("Failed to emit verification-progress event: {}", e);
         }
     };
@@ -87,7 +93,7 @@ pub async fn verify_directory_integrity(
         .verify_directory(&path, &manifest, Some(progress_callback))
         .map_err(map_err)?;
 
-    if let Err(e) = app.emit(integrity_events::VERIFICATION_COMPLETED, &result) {
+    if let Err(e) = emit_task_event(&app, integrity_events::VERIFICATION_COMPLETED, &result) {
         error!("Failed to emit verification-completed event: {}", e);
     }
 
@@ -105,7 +111,7 @@ pub async fn verify_file_checksum(
     file_path: String,
     expected_checksum: String,
     expected_size: u64,
-) -> std::result::Result<bool, String> {
+) -> CommandResult<bool> {
     debug!("Verifying file checksum: {}", file_path);
 
     let path = PathBuf::from(&file_path);
@@ -126,10 +132,7 @@ pub async fn verify_file_checksum(
 
 /// Add or update a file in a checksum manifest.
 #[tauri::command]
-pub async fn update_manifest_file(
-    file_path: String,
-    manifest_dir: String,
-) -> std::result::Result<(), String> {
+pub async fn update_manifest_file(file_path: String, manifest_dir: String) -> CommandResult<()> {
     info!("Updating manifest for file: {}", file_path);
 
     let path = PathBuf::from(&file_path);
@@ -171,10 +174,7 @@ pub async fn update_manifest_file(
 
 /// Remove a file from a checksum manifest.
 #[tauri::command]
-pub async fn remove_from_manifest(
-    file_name: String,
-    manifest_dir: String,
-) -> std::result::Result<bool, String> {
+pub async fn remove_from_manifest(file_name: String, manifest_dir: String) -> CommandResult<bool> {
     debug!("Removing file from manifest: {}", file_name);
 
     let manifest_path = PathBuf::from(&manifest_dir);
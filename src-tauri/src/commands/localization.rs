@@ -0,0 +1,42 @@
+//! Translation catalogs for backend messages.
+//!
+//! Errors and capacity checks carry a translatable [`Message`] next to their
+//! English text. The frontend fetches the catalog for its language once and
+//! renders those messages itself.
+//!
+//! [`Message`]: youtun4_core::Message
+
+use std::collections::BTreeMap;
+
+use tauri::State;
+use youtun4_core::i18n::{self, Locale};
+
+use super::error::{CommandError, CommandResult};
+use super::state::AppState;
+
+/// Message templates for one language.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TranslationCatalog {
+    /// Language of the templates.
+    pub locale: Locale,
+    /// Templates by message key, with `{name}` placeholders.
+    pub messages: BTreeMap<String, String>,
+}
+
+/// Get the translation catalog for `locale` (e.g. `fr` or `de-DE`), or for
+/// the configured language when `None`.
+#[tauri::command]
+pub async fn get_translation_catalog(
+    state: State<'_, AppState>,
+    locale: Option<String>,
+) -> CommandResult<TranslationCatalog> {
+    let locale = match locale {
+        Some(tag) => Locale::from_tag(&tag)
+            .ok_or_else(|| CommandError::invalid_input(format!("Unsupported language: {tag}")))?,
+        None => state.locale().await,
+    };
+    Ok(TranslationCatalog {
+        locale,
+        messages: i18n::translations(locale),
+    })
+}
@@ -0,0 +1,71 @@
+//! Log access commands for the in-app log viewer and support requests.
+
+use tauri::AppHandle;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_opener::OpenerExt;
+use tracing::{Level, info};
+
+use crate::logging::{self, LogEntry};
+
+use super::error::{CommandError, CommandResult};
+
+/// Most log entries returned by a single [`get_recent_logs`] call.
+const MAX_LOG_LINES: usize = 5000;
+
+fn parse_level(level: &str) -> CommandResult<Level> {
+    level
+        .parse::<Level>()
+        .map_err(|_| CommandError::invalid_input(format!("Invalid log level: {level}")))
+}
+
+/// Get the most recent log entries, oldest first.
+///
+/// `level_filter` keeps only entries at that level or more severe
+/// (e.g. "warn" returns warnings and errors).
+#[tauri::command]
+pub async fn get_recent_logs(
+    lines: usize,
+    level_filter: Option<String>,
+) -> CommandResult<Vec<LogEntry>> {
+    let min_level = level_filter.as_deref().map(parse_level).transpose()?;
+    let lines = lines.min(MAX_LOG_LINES);
+
+    tokio::task::spawn_blocking(move || logging::recent_entries(lines, min_level))
+        .await
+        .map_err(|e| CommandError::from(format!("Log reading task failed: {e}")))?
+        .map_err(|e| CommandError::from(format!("Failed to read logs: {e}")))
+}
+
+/// Change the log level of the application until it restarts.
+#[tauri::command]
+pub fn set_log_level(level: String) -> CommandResult<()> {
+    let level = parse_level(&level)?;
+    logging::set_level(level).map_err(|e| CommandError::from(e.to_string()))?;
+    info!("Log level set to {}", level);
+    Ok(())
+}
+
+/// Get the directory log files are written to.
+#[tauri::command]
+pub fn get_log_directory() -> String {
+    logging::log_directory().display().to_string()
+}
+
+/// Open the log directory in the system file manager.
+#[tauri::command]
+pub fn open_log_directory(app: AppHandle) -> CommandResult<()> {
+    let directory = logging::log_directory();
+    std::fs::create_dir_all(&directory)
+        .map_err(|e| CommandError::from(format!("Failed to create log directory: {e}")))?;
+    app.opener()
+        .open_path(directory.display().to_string(), None::<&str>)
+        .map_err(|e| CommandError::from(format!("Failed to open log directory: {e}")))
+}
+
+/// Copy log text to the clipboard, for pasting into a bug report.
+#[tauri::command]
+pub fn copy_logs_to_clipboard(app: AppHandle, text: String) -> CommandResult<()> {
+    app.clipboard()
+        .write_text(text)
+        .map_err(|e| CommandError::from(format!("Failed to copy logs: {e}")))
+}
@@ -0,0 +1,253 @@
+//! Long-running maintenance operations with progress and cancellation.
+//!
+//! [`start_maintenance`] runs checksum manifest creation, playlist folder
+//! repair or cache clearing as a cancellable background task, and reports it
+//! through the unified task event bus so the frontend can show its progress.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, State};
+use tracing::{debug, error, info};
+use youtun4_core::cache::{CacheConfig, CacheManager};
+use youtun4_core::integrity::{IntegrityVerifier, VerificationProgress};
+use youtun4_core::playlist::PlaylistManager;
+use youtun4_core::{Error, Result};
+
+use crate::runtime::{TaskCategory, TaskId};
+
+use super::error::CommandResult;
+use super::events::emit_task_event;
+use super::state::AppState;
+
+/// Event names for maintenance events.
+///
+/// Deprecated aliases: every event here is also emitted as a unified `task-event`,
+/// which new frontend code should listen to instead.
+pub mod maintenance_events {
+    /// Event emitted when a maintenance operation starts.
+    pub const MAINTENANCE_STARTED: &str = "maintenance-started";
+    /// Event emitted for maintenance progress updates.
+    pub const MAINTENANCE_PROGRESS: &str = "maintenance-progress";
+    /// Event emitted when a maintenance operation completes.
+    pub const MAINTENANCE_COMPLETED: &str = "maintenance-completed";
+    /// Event emitted when a maintenance operation fails.
+    pub const MAINTENANCE_FAILED: &str = "maintenance-failed";
+    /// Event emitted when a maintenance operation is cancelled.
+    pub const MAINTENANCE_CANCELLED: &str = "maintenance-cancelled";
+}
+
+/// A maintenance operation to run in the background.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MaintenanceOperation {
+    /// Create a checksum manifest for a playlist folder.
+    CreateChecksumManifest {
+        /// Playlist name.
+        playlist: String,
+    },
+    /// Repair a playlist folder.
+    RepairPlaylistFolder {
+        /// Playlist name.
+        playlist: String,
+    },
+    /// Clear all cached data.
+    ClearCache,
+}
+
+impl MaintenanceOperation {
+    /// Short description, used for the task and in logs.
+    fn describe(&self) -> String {
+        match self {
+            Self::CreateChecksumManifest { playlist } => {
+                format!("Create checksum manifest: {playlist}")
+            }
+            Self::RepairPlaylistFolder { playlist } => format!("Repair playlist: {playlist}"),
+            Self::ClearCache => "Clear cache".to_string(),
+        }
+    }
+}
+
+/// Payload for maintenance events.
+#[derive(Debug, Clone, Serialize)]
+pub struct MaintenancePayload {
+    /// Task ID of the operation.
+    pub task_id: TaskId,
+    /// The operation being run.
+    pub operation: MaintenanceOperation,
+    /// Overall progress between 0.0 and 1.0, if known.
+    pub progress: Option<f64>,
+    /// What the operation is working on, or its outcome once finished.
+    pub message: Option<String>,
+    /// Error message, if the operation failed.
+    pub error: Option<String>,
+}
+
+/// Everything a maintenance task needs from the app state.
+struct MaintenanceContext {
+    app: AppHandle,
+    task_id: TaskId,
+    operation: MaintenanceOperation,
+    cancel_flag: Arc<AtomicBool>,
+    playlist_manager: Arc<tokio::sync::RwLock<PlaylistManager>>,
+    cache_config: CacheConfig,
+}
+
+impl MaintenanceContext {
+    fn emit(&self, event: &str, progress: Option<f64>, message: Option<String>) {
+        let payload = MaintenancePayload {
+            task_id: self.task_id,
+            operation: self.operation.clone(),
+            progress,
+            message,
+            error: None,
+        };
+        if let Err(e) = emit_task_event(&self.app, event, &payload) {
+            error!("Failed to emit {} event: {}", event, e);
+        }
+    }
+
+    fn emit_failed(&self, error: &Error) {
+        let payload = MaintenancePayload {
+            task_id: self.task_id,
+            operation: self.operation.clone(),
+            progress: None,
+            message: None,
+            error: Some(error.to_string()),
+        };
+        if let Err(e) = emit_task_event(&self.app, maintenance_events::MAINTENANCE_FAILED, &payload)
+        {
+            error!("Failed to emit maintenance-failed event: {}", e);
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancel_flag.load(Ordering::SeqCst)
+    }
+}
+
+/// Start a maintenance operation in the background.
+///
+/// Returns the task ID; progress and the outcome are reported as `maintenance`
+/// task events, and the task can be stopped with `cancel_task`. Checksum
+/// manifests stop between files; the other operations are short and can only
+/// be cancelled before they start.
+#[tauri::command]
+pub async fn start_maintenance(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    operation: MaintenanceOperation,
+) -> CommandResult<TaskId> {
+    info!("Starting maintenance: {}", operation.describe());
+
+    let cache_config = state.config_manager.read().await.config().cache.clone();
+    let playlist_manager = state.playlist_manager_arc();
+
+    let task_id = state.runtime().spawn_cancellable_with_id(
+        TaskCategory::Background,
+        Some(operation.describe()),
+        move |task_id, cancel_rx| async move {
+            let cancel_flag = Arc::new(AtomicBool::new(false));
+            let flag = Arc::clone(&cancel_flag);
+            tokio::spawn(async move {
+                if cancel_rx.await.is_ok() {
+                    flag.store(true, Ordering::SeqCst);
+                }
+            });
+
+            let context = Arc::new(MaintenanceContext {
+                app,
+                task_id,
+                operation,
+                cancel_flag,
+                playlist_manager,
+                cache_config,
+            });
+            context.emit(maintenance_events::MAINTENANCE_STARTED, Some(0.0), None);
+
+            match run_maintenance(Arc::clone(&context)).await {
+                Ok(summary) => {
+                    info!("{} finished: {}", context.operation.describe(), summary);
+                    context.emit(
+                        maintenance_events::MAINTENANCE_COMPLETED,
+                        Some(1.0),
+                        Some(summary),
+                    );
+                }
+                Err(Error::Cancelled) => {
+                    info!("{} cancelled", context.operation.describe());
+                    context.emit(maintenance_events::MAINTENANCE_CANCELLED, None, None);
+                }
+                Err(e) => {
+                    error!("{} failed: {}", context.operation.describe(), e);
+                    context.emit_failed(&e);
+                }
+            }
+        },
+    );
+
+    debug!("Maintenance task {} spawned", task_id);
+    Ok(task_id)
+}
+
+/// Run the operation and describe its outcome.
+async fn run_maintenance(context: Arc<MaintenanceContext>) -> Result<String> {
+    if context.is_cancelled() {
+        return Err(Error::Cancelled);
+    }
+
+    match context.operation.clone() {
+        MaintenanceOperation::CreateChecksumManifest { playlist } => {
+            let directory = context
+                .playlist_manager
+                .read()
+                .await
+                .get_playlist_path(&playlist)?;
+            tokio::task::spawn_blocking(move || {
+                let verifier =
+                    IntegrityVerifier::new().with_cancel_flag(Arc::clone(&context.cancel_flag));
+                let progress_callback = |progress: &VerificationProgress| {
+                    context.emit(
+                        maintenance_events::MAINTENANCE_PROGRESS,
+                        Some(progress.percentage() / 100.0),
+                        Some(progress.current_file_name.clone()),
+                    );
+                };
+                let manifest =
+                    verifier.create_manifest_from_directory(&directory, Some(progress_callback))?;
+                if context.is_cancelled() {
+                    return Err(Error::Cancelled);
+                }
+                manifest.save_to_directory(&directory)?;
+                Ok(format!("Checksums saved for {} files", manifest.len()))
+            })
+            .await
+            .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))
+        }
+        MaintenanceOperation::RepairPlaylistFolder { playlist } => {
+            let repairs = context
+                .playlist_manager
+                .read()
+                .await
+                .repair_folder(&playlist)?;
+            Ok(if repairs.is_empty() {
+                "No repairs needed".to_string()
+            } else {
+                repairs.join(", ")
+            })
+        }
+        MaintenanceOperation::ClearCache => {
+            let cache_config = context.cache_config.clone();
+            let stats = tokio::task::spawn_blocking(move || {
+                CacheManager::new(cache_config).and_then(|mut cache| cache.clear())
+            })
+            .await
+            .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))?;
+            Ok(format!(
+                "Removed {} entries ({} bytes)",
+                stats.entries_removed, stats.bytes_freed
+            ))
+        }
+    }
+}
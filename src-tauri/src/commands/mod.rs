@@ -5,6 +5,7 @@
 //! This module is organized into submodules by feature area:
 //! - `state`: Application state management
 //! - `error`: Error handling utilities
+//! - `events`: Unified task event bus
 //! - `device`: Device detection and management
 //! - `device_watcher`: Device connection monitoring
 //! - `device_mount`: Mount/unmount operations
@@ -14,44 +15,106 @@
 //! - `config`: Application configuration
 //! - `transfer`: File transfer operations
 //! - `integrity`: File integrity verification
+//! - `maintenance`: Cancellable maintenance operations with progress
 //! - `sync`: Playlist sync operations
 //! - `sync_orchestrator`: Multi-playlist orchestrated sync
 //! - `youtube`: YouTube URL validation and downloads
 //! - `cache`: Cache management
 //! - `queue`: Download queue management
+//! - `storage`: Library disk usage, guardrails and archiving
+//! - `onboarding`: First-run setup state
+//! - `notifications`: OS notifications and the webhook for sync, download and device events
+//! - `secrets`: Secure storage for sensitive settings
+//! - `tray`: System tray and background operation
+//! - `deep_link`: `youtun4://` links for adding playlists
+//! - `updater`: Application update checks and installation
+//! - `logs`: Log access for the in-app log viewer
+//! - `diagnostics`: Diagnostics bundle export and performance metrics
+//! - `crash`: Crash reports from native and frontend panics
+//! - `shutdown`: Cancelling all running work and exiting safely
+//! - `parental`: Simple mode and the parent PIN for destructive operations
+//! - `power`: Pausing heavy work on battery power or metered networks
+//! - `localization`: Translation catalogs for backend messages
+//! - `preview`: Track previews and thumbnails served through the asset protocol
+//! - `history`: History of finished downloads and syncs
+//! - `change_log`: Journal of library changes
+//! - `backup`: Backup and restore of the library
+//! - `scheduler`: Scheduled playlist refreshes
 
+mod backup;
 mod cache;
+mod change_log;
 mod config;
+mod crash;
+mod deep_link;
 mod device;
 mod device_cleanup;
 mod device_mount;
 mod device_watcher;
+mod diagnostics;
 mod error;
+mod events;
+mod history;
 mod integrity;
+mod localization;
+mod logs;
+mod maintenance;
+mod notifications;
+mod onboarding;
+mod parental;
 mod playlist;
+mod power;
+mod preview;
 mod queue;
+mod scheduler;
+mod secrets;
+mod shutdown;
 mod state;
+mod storage;
 mod sync;
 mod sync_orchestrator;
 mod task;
 mod transfer;
+mod tray;
+mod updater;
 mod youtube;
 
 // Re-export AppState for main.rs
 pub use state::AppState;
 
 // Re-export all commands
+pub use backup::*;
 pub use cache::*;
+pub use change_log::*;
 pub use config::*;
+pub use crash::*;
+pub use deep_link::*;
 pub use device::*;
 pub use device_cleanup::*;
 pub use device_mount::*;
 pub use device_watcher::*;
+pub use diagnostics::*;
+pub use events::*;
+pub use history::*;
 pub use integrity::*;
+pub use localization::*;
+pub use logs::*;
+pub use maintenance::*;
+pub use notifications::*;
+pub use onboarding::*;
+pub use parental::*;
 pub use playlist::*;
+pub use power::*;
+pub use preview::*;
 pub use queue::*;
+pub use scheduler::*;
+pub use secrets::*;
+pub use shutdown::*;
+pub use storage::*;
 pub use sync::*;
 pub use sync_orchestrator::*;
 pub use task::*;
 pub use transfer::*;
+pub use tray::*;
+pub use updater::*;
 pub use youtube::*;
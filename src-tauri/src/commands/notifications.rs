@@ -0,0 +1,286 @@
+//! OS notifications and the webhook for sync, download and device events.
+//!
+//! Notifications are driven by the same events the frontend listens to, and
+//! filtered through the user's `NotificationPreferences`. Their text is built
+//! from catalog [`Message`]s and rendered in the configured language.
+//!
+//! The webhook is called by the queue worker and the sync orchestrator through
+//! [`notify_webhook`] when a download or sync ends.
+
+use serde_json::Value;
+use tauri::{AppHandle, Listener, Manager, State};
+use tauri_plugin_notification::NotificationExt;
+use tracing::{debug, info, warn};
+use youtun4_core::history::HistoryEntry;
+use youtun4_core::i18n::{Message, keys};
+use youtun4_core::notifications::webhook::{self, WebhookConfig, WebhookMessage};
+use youtun4_core::secrets::secret_keys;
+use youtun4_core::{Error, NotificationKind};
+
+use super::device_watcher::device_events;
+use super::error::{CommandError, CommandResult, map_err};
+use super::queue::queue_events;
+use super::state::AppState;
+use super::sync::sync_events;
+use super::sync_orchestrator::sync_orchestrator_events;
+use super::youtube::youtube_events;
+
+/// A notification to show, derived from an event payload.
+struct Notification {
+    kind: NotificationKind,
+    title: Message,
+    body: Message,
+}
+
+impl Notification {
+    const fn new(kind: NotificationKind, title: Message, body: Message) -> Self {
+        Self { kind, title, body }
+    }
+
+    fn error(title: Message, payload: &Value) -> Self {
+        let body = str_field(payload, "error_message")
+            .or_else(|| str_field(payload, "error"))
+            .map_or_else(
+                || Message::new(keys::NOTIFY_UNKNOWN_ERROR),
+                |error| Message::new(keys::ERROR_GENERIC).with("details", error),
+            );
+        Self::new(NotificationKind::Error, title, body)
+    }
+}
+
+fn str_field<'a>(payload: &'a Value, key: &str) -> Option<&'a str> {
+    payload.get(key).and_then(Value::as_str)
+}
+
+fn u64_field(payload: &Value, key: &str) -> u64 {
+    payload.get(key).and_then(Value::as_u64).unwrap_or(0)
+}
+
+fn bool_field(payload: &Value, key: &str) -> bool {
+    payload.get(key).and_then(Value::as_bool).unwrap_or(false)
+}
+
+/// Show an OS notification without consulting preferences.
+pub(crate) fn show(app: &AppHandle, title: &str, body: &str) -> CommandResult<()> {
+    app.notification()
+        .builder()
+        .title(title)
+        .body(body)
+        .show()
+        .map_err(|e| CommandError::from(format!("Failed to show notification: {e}")))
+}
+
+/// Show an OS notification if the user's preferences allow its kind.
+async fn notify(app: &AppHandle, notification: Notification) {
+    let (allowed, locale) = {
+        let state = app.state::<AppState>();
+        let config_manager = state.config_manager.read().await;
+        let config = config_manager.config();
+        (
+            config.notification_preferences.allows(notification.kind),
+            config.locale(),
+        )
+    };
+
+    if !allowed {
+        debug!(
+            "Notification '{}' suppressed by preferences",
+            notification.title.key
+        );
+        return;
+    }
+
+    let title = notification.title.render(locale);
+    let body = notification.body.render(locale);
+    if let Err(e) = show(app, &title, &body) {
+        warn!("{}", e);
+    }
+}
+
+/// Listen to `event` and turn its payload into a notification with `describe`.
+fn listen<F>(app: &AppHandle, event: &'static str, describe: F)
+where
+    F: Fn(&Value) -> Option<Notification> + Send + Sync + 'static,
+{
+    let handle = app.clone();
+    app.listen(event, move |e| {
+        let Ok(payload) = serde_json::from_str::<Value>(e.payload()) else {
+            debug!("Ignoring unparseable {} payload", event);
+            return;
+        };
+        let Some(notification) = describe(&payload) else {
+            return;
+        };
+        let handle = handle.clone();
+        tauri::async_runtime::spawn(async move {
+            notify(&handle, notification).await;
+        });
+    });
+}
+
+/// Register listeners that turn application events into OS notifications.
+pub fn install_notification_listeners(app: &AppHandle) {
+    listen(app, sync_events::SYNC_COMPLETED, |p| {
+        Some(Notification::new(
+            NotificationKind::SyncComplete,
+            Message::new(keys::NOTIFY_SYNC_COMPLETE),
+            Message::new(keys::NOTIFY_SYNC_COMPLETE_BODY)
+                .with(
+                    "playlist",
+                    str_field(p, "playlist_name").unwrap_or("Playlist"),
+                )
+                .with("files", u64_field(p, "files_transferred")),
+        ))
+    });
+    listen(app, sync_events::SYNC_FAILED, |p| {
+        Some(Notification::error(
+            Message::new(keys::NOTIFY_SYNC_FAILED),
+            p,
+        ))
+    });
+    listen(
+        app,
+        sync_orchestrator_events::SYNC_ORCHESTRATOR_COMPLETED,
+        |p| {
+            let playlists = p
+                .get("transfer_results")
+                .and_then(Value::as_array)
+                .map_or(0, Vec::len);
+            Some(Notification::new(
+                NotificationKind::SyncComplete,
+                Message::new(keys::NOTIFY_SYNC_COMPLETE),
+                Message::new(keys::NOTIFY_SYNC_ALL_COMPLETE_BODY)
+                    .with("playlists", playlists)
+                    .with("files", u64_field(p, "total_files_transferred")),
+            ))
+        },
+    );
+    listen(
+        app,
+        sync_orchestrator_events::SYNC_ORCHESTRATOR_FAILED,
+        |p| {
+            Some(Notification::error(
+                Message::new(keys::NOTIFY_SYNC_FAILED),
+                p,
+            ))
+        },
+    );
+    listen(app, youtube_events::DOWNLOAD_COMPLETED, |p| {
+        if !bool_field(p, "success") {
+            return Some(Notification::error(
+                Message::new(keys::NOTIFY_DOWNLOAD_ERRORS),
+                p,
+            ));
+        }
+        Some(Notification::new(
+            NotificationKind::DownloadComplete,
+            Message::new(keys::NOTIFY_DOWNLOAD_COMPLETE),
+            Message::new(keys::NOTIFY_DOWNLOAD_COMPLETE_BODY)
+                .with("downloaded", u64_field(p, "successful_count"))
+                .with("total", u64_field(p, "total_count")),
+        ))
+    });
+    listen(app, youtube_events::DOWNLOAD_FAILED, |p| {
+        let title = str_field(p, "error_title").map_or_else(
+            || Message::new(keys::NOTIFY_DOWNLOAD_FAILED),
+            |title| Message::new(keys::ERROR_GENERIC).with("details", title),
+        );
+        Some(Notification::error(title, p))
+    });
+    listen(app, queue_events::QUEUE_ITEM_COMPLETED, |_| {
+        Some(Notification::new(
+            NotificationKind::DownloadComplete,
+            Message::new(keys::NOTIFY_DOWNLOAD_COMPLETE),
+            Message::new(keys::NOTIFY_QUEUE_ITEM_COMPLETE_BODY),
+        ))
+    });
+    listen(app, queue_events::QUEUE_ITEM_FAILED, |p| {
+        Some(Notification::error(
+            Message::new(keys::NOTIFY_QUEUE_ITEM_FAILED),
+            p,
+        ))
+    });
+    listen(app, device_events::DEVICE_CONNECTED, |p| {
+        Some(Notification::new(
+            NotificationKind::DeviceConnected,
+            Message::new(keys::NOTIFY_DEVICE_CONNECTED),
+            Message::new(keys::NOTIFY_DEVICE_CONNECTED_BODY)
+                .with("device", str_field(p, "name").unwrap_or("A device")),
+        ))
+    });
+
+    info!("Notification listeners installed");
+}
+
+/// Show a test notification, regardless of notification preferences.
+#[tauri::command]
+pub async fn send_test_notification(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> CommandResult<()> {
+    info!("Sending test notification");
+    let body = Message::new(keys::NOTIFY_TEST_BODY).render(state.locale().await);
+    show(&app, "Youtun4", &body)
+}
+
+/// Post `message` to the webhook URL in the secrets store.
+async fn send_webhook(
+    state: &AppState,
+    config: WebhookConfig,
+    message: WebhookMessage,
+) -> youtun4_core::Result<()> {
+    let secrets = state.secrets.clone();
+    tokio::task::spawn_blocking(move || {
+        let url = secrets
+            .get(secret_keys::WEBHOOK_URL)?
+            .ok_or_else(|| Error::Configuration("No webhook URL is set".to_string()))?;
+        webhook::send(&url, &config, &message)
+    })
+    .await
+    .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))
+}
+
+/// Call the webhook in the background for a download or sync that ended with
+/// `entry`, if the webhook is enabled for its event. Failures are logged and
+/// never affect the operation itself.
+pub(crate) fn notify_webhook(app: &AppHandle, entry: &HistoryEntry) {
+    let Some(message) = WebhookMessage::from_history(entry) else {
+        return;
+    };
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = handle.state::<AppState>();
+        let (config, offline) = {
+            let config_manager = state.config_manager.read().await;
+            let config = config_manager.config();
+            (config.webhook.clone(), config.offline_mode)
+        };
+        if !config.allows(message.event) {
+            return;
+        }
+        if offline {
+            debug!("Offline mode, not calling webhook");
+            return;
+        }
+        if let Err(e) = send_webhook(&state, config, message).await {
+            warn!("Failed to call webhook: {}", e);
+        }
+    });
+}
+
+/// Post a test message to the webhook, regardless of whether it is enabled.
+/// Uses `webhook` if given, to try settings before saving them.
+#[tauri::command]
+pub async fn send_test_webhook(
+    state: State<'_, AppState>,
+    webhook: Option<WebhookConfig>,
+) -> CommandResult<()> {
+    info!("Sending test webhook");
+    let config = match webhook {
+        Some(config) => config,
+        None => state.config_manager.read().await.config().webhook.clone(),
+    };
+    send_webhook(&state, config, WebhookMessage::test())
+        .await
+        .map_err(map_err)
+}
@@ -0,0 +1,47 @@
+//! First-run setup commands.
+
+use std::path::PathBuf;
+
+use tauri::{AppHandle, State};
+use tracing::{debug, info};
+use youtun4_core::{AppConfig, DownloadQuality, Error, OnboardingState};
+
+use super::config::apply_config_change;
+use super::error::{CommandResult, map_err};
+use super::state::AppState;
+
+/// Get the onboarding state, including detected music folders and known devices.
+#[tauri::command]
+pub async fn get_onboarding_state(state: State<'_, AppState>) -> CommandResult<OnboardingState> {
+    debug!("Getting onboarding state");
+
+    let config = state.config_manager.read().await.config().clone();
+    tokio::task::spawn_blocking(move || OnboardingState::detect(&config))
+        .await
+        .map_err(|e| map_err(Error::internal(format!("Task join error: {e}"))))
+}
+
+/// Finish first-run setup, optionally choosing the playlists directory,
+/// preferred device and download quality.
+#[tauri::command]
+pub async fn complete_onboarding(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    playlists_directory: Option<String>,
+    preferred_device: Option<String>,
+    download_quality: Option<DownloadQuality>,
+) -> CommandResult<AppConfig> {
+    info!(
+        "Completing onboarding (playlists_directory={:?}, preferred_device={:?}, download_quality={:?})",
+        playlists_directory, preferred_device, download_quality
+    );
+
+    apply_config_change(&app, &state, |manager| {
+        manager.complete_onboarding(
+            playlists_directory.map(PathBuf::from),
+            preferred_device.map(PathBuf::from),
+            download_quality,
+        )
+    })
+    .await
+}
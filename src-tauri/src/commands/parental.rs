@@ -0,0 +1,176 @@
+//! Simple mode (parental controls) commands.
+//!
+//! Destructive commands call [`require_parent_pin`] before doing anything, so
+//! simple mode is enforced here in the command layer and not only by hiding
+//! buttons in the UI.
+
+use std::sync::Arc;
+
+use tauri::{AppHandle, State};
+use tracing::{info, warn};
+use youtun4_core::parental::{self, MAX_PIN_LENGTH, MIN_PIN_LENGTH};
+use youtun4_core::secrets::SecretsStore;
+use youtun4_core::{AppConfig, Error, ErrorCode, PinCheck, ProtectedOperation};
+
+use super::config::apply_config_change;
+use super::error::{CommandError, CommandResult, map_err};
+use super::state::AppState;
+
+/// Whether simple mode is on and a parent PIN is set.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ParentalStatus {
+    /// Whether destructive operations need the parent PIN.
+    pub simple_mode: bool,
+    /// Whether a parent PIN has been set.
+    pub pin_set: bool,
+}
+
+/// Run a blocking secrets store operation off the async runtime.
+async fn with_secrets<T, F>(state: &AppState, f: F) -> CommandResult<T>
+where
+    F: FnOnce(&SecretsStore) -> youtun4_core::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let secrets = Arc::clone(&state.secrets);
+    tokio::task::spawn_blocking(move || f(&secrets))
+        .await
+        .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))
+        .map_err(map_err)
+}
+
+/// Fail unless `pin` matches the parent PIN.
+async fn verify_pin(state: &AppState, pin: Option<String>, action: &str) -> CommandResult<()> {
+    let Some(pin) = pin.filter(|pin| !pin.is_empty()) else {
+        return Err(CommandError::new(
+            ErrorCode::PinRequired,
+            format!("{action} requires the parent PIN"),
+        ));
+    };
+    let controls = Arc::clone(&state.parental);
+    match with_secrets(state, move |secrets| controls.check_pin(secrets, &pin)).await? {
+        PinCheck::Accepted => Ok(()),
+        PinCheck::Rejected { attempts_left } => {
+            warn!("Incorrect parent PIN entered: {}", action);
+            Err(CommandError::new(
+                ErrorCode::PinRequired,
+                format!(
+                    "Incorrect parent PIN ({attempts_left} attempt{} left)",
+                    if attempts_left == 1 { "" } else { "s" }
+                ),
+            ))
+        }
+        PinCheck::LockedOut { retry_after } => {
+            warn!("Parent PIN entry locked: {}", action);
+            Err(CommandError::new(
+                ErrorCode::PinRequired,
+                format!(
+                    "Too many incorrect PINs. Try again in {} seconds",
+                    retry_after.as_secs().max(1)
+                ),
+            ))
+        }
+    }
+}
+
+/// Allow a destructive operation if simple mode is off or `pin` is the
+/// parent PIN.
+pub(crate) async fn require_parent_pin(
+    state: &AppState,
+    operation: ProtectedOperation,
+    pin: Option<String>,
+) -> CommandResult<()> {
+    if !state.config_manager.read().await.config().simple_mode {
+        return Ok(());
+    }
+    verify_pin(state, pin, &operation.to_string()).await
+}
+
+/// Get the simple mode status.
+#[tauri::command]
+pub async fn get_parental_status(state: State<'_, AppState>) -> CommandResult<ParentalStatus> {
+    let simple_mode = state.config_manager.read().await.config().simple_mode;
+    let pin_set = with_secrets(&state, parental::pin_is_set).await?;
+    Ok(ParentalStatus {
+        simple_mode,
+        pin_set,
+    })
+}
+
+/// Set or change the parent PIN.
+///
+/// Changing an existing PIN needs `current_pin`.
+#[tauri::command]
+pub async fn set_parent_pin(
+    state: State<'_, AppState>,
+    current_pin: Option<String>,
+    new_pin: String,
+) -> CommandResult<()> {
+    if !parental::is_valid_pin(&new_pin) {
+        return Err(CommandError::invalid_input(format!(
+            "The PIN must be {MIN_PIN_LENGTH} to {MAX_PIN_LENGTH} digits"
+        )));
+    }
+    if with_secrets(&state, parental::pin_is_set).await? {
+        verify_pin(&state, current_pin, "Changing the parent PIN").await?;
+    }
+    with_secrets(&state, move |secrets| parental::set_pin(secrets, &new_pin)).await
+}
+
+/// Remove the parent PIN, turning simple mode off.
+#[tauri::command]
+pub async fn clear_parent_pin(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    pin: String,
+) -> CommandResult<()> {
+    verify_pin(&state, Some(pin), "Removing the parent PIN").await?;
+    set_simple_mode_unchecked(&app, &state, false).await?;
+    with_secrets(&state, parental::clear_pin).await?;
+    Ok(())
+}
+
+/// Turn simple mode on or off. Both directions need the parent PIN, and
+/// turning it on needs a PIN to have been set first.
+#[tauri::command]
+pub async fn set_simple_mode(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    enabled: bool,
+    pin: String,
+) -> CommandResult<AppConfig> {
+    if !with_secrets(&state, parental::pin_is_set).await? {
+        return Err(CommandError::new(
+            ErrorCode::Configuration,
+            "Set a parent PIN before using simple mode",
+        ));
+    }
+    verify_pin(
+        &state,
+        Some(pin),
+        if enabled {
+            "Turning on simple mode"
+        } else {
+            "Turning off simple mode"
+        },
+    )
+    .await?;
+    set_simple_mode_unchecked(&app, &state, enabled).await
+}
+
+async fn set_simple_mode_unchecked(
+    app: &AppHandle,
+    state: &State<'_, AppState>,
+    enabled: bool,
+) -> CommandResult<AppConfig> {
+    let config = apply_config_change(app, state, |manager| {
+        let mut app_config = manager.config().clone();
+        app_config.simple_mode = enabled;
+        manager.update(app_config)
+    })
+    .await?;
+    info!(
+        "Simple mode {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(config)
+}
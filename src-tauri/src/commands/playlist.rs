@@ -4,40 +4,55 @@
 
 use std::path::PathBuf;
 
-use tauri::State;
-use tracing::{debug, info};
-use youtun4_core::Error;
+use tauri::{AppHandle, State};
+use tauri_plugin_opener::OpenerExt;
+use tracing::{debug, info, warn};
+use youtun4_core::change_log::LibraryChange;
+use youtun4_core::lyrics::{Lyrics, LyricsClient, save_lyrics};
 use youtun4_core::metadata::{Mp3Metadata, extract_metadata};
 use youtun4_core::playlist::{
-    FolderStatistics, FolderValidationResult, PlaylistMetadata, SavedPlaylistMetadata, TrackInfo,
-    validate_playlist_name,
+    BatchResult, DownloadProfile, FolderStatistics, FolderValidationResult, LibrarySearchResults,
+    PlaylistMetadata, SavedPlaylistMetadata, TrackInfo, TrackPage, validate_playlist_name,
 };
+use youtun4_core::youtube::archive::{ArchivedVideo, DownloadArchive};
+use youtun4_core::{
+    Error, PlaylistOverrides, PlaylistViewPreferences, ProtectedOperation, TrashEntry, TrashId,
+    TrashedItem, TrashedTracks,
+};
+
+use crate::runtime::{TaskCategory, TaskResource};
 
-use super::error::map_err;
+use super::cache::spawn_cache_warming;
+use super::change_log::record_change;
+use super::config::apply_config_change;
+use super::error::{CommandError, CommandResult, map_err};
+use super::parental::require_parent_pin;
 use super::state::AppState;
 
 /// List all playlists.
 #[tauri::command]
-pub async fn list_playlists(
-    state: State<'_, AppState>,
-) -> std::result::Result<Vec<PlaylistMetadata>, String> {
+pub async fn list_playlists(state: State<'_, AppState>) -> CommandResult<Vec<PlaylistMetadata>> {
     debug!("Listing playlists");
     let manager = state.playlist_manager.read().await;
     manager.list_playlists().map_err(map_err)
 }
 
 /// Create a new playlist.
+///
+/// Playlists created from a YouTube source get their cache warmed in the
+/// background.
 #[tauri::command]
 pub async fn create_playlist(
+    app: AppHandle,
     state: State<'_, AppState>,
     name: String,
     source_url: Option<String>,
     thumbnail_url: Option<String>,
-) -> std::result::Result<String, String> {
+) -> CommandResult<String> {
     info!("Creating playlist: {}", name);
     let manager = state.playlist_manager.read().await;
     let path = manager
-        .create_playlist(&name, source_url)
+        .create_playlist(&name, source_url.clone())
         .map_err(map_err)?;
 
     if let Some(thumb) = thumbnail_url {
@@ -45,19 +60,220 @@ pub async fn create_playlist(
             .update_playlist_metadata_full(&name, None, None, None, Some(Some(thumb)))
             .map_err(map_err)?;
     }
+    drop(manager);
+    record_change(
+        &state,
+        LibraryChange::PlaylistCreated {
+            playlist: name.clone(),
+        },
+    );
+
+    if let Some(url) = source_url
+        && let Err(e) = spawn_cache_warming(app, &state, name, url).await
+    {
+        warn!("Failed to start cache warming: {}", e);
+    }
 
     Ok(path.display().to_string())
 }
 
 /// Delete a playlist.
+///
+/// In simple mode, `pin` must be the parent PIN.
 #[tauri::command]
 pub async fn delete_playlist(
     state: State<'_, AppState>,
     name: String,
-) -> std::result::Result<(), String> {
+    pin: Option<String>,
+) -> CommandResult<()> {
+    require_parent_pin(&state, ProtectedOperation::DeletePlaylist, pin).await?;
     info!("Deleting playlist: {}", name);
     let manager = state.playlist_manager.read().await;
-    manager.delete_playlist(&name).map_err(map_err)
+    manager.delete_playlist(&name).map_err(map_err)?;
+    record_change(&state, LibraryChange::PlaylistDeleted { playlist: name });
+    Ok(())
+}
+
+/// Move a playlist to the trash, so the deletion can be undone this session.
+///
+/// In simple mode, `pin` must be the parent PIN.
+#[tauri::command]
+pub async fn trash_playlist(
+    state: State<'_, AppState>,
+    name: String,
+    pin: Option<String>,
+) -> CommandResult<TrashEntry> {
+    require_parent_pin(&state, ProtectedOperation::DeletePlaylist, pin).await?;
+    info!("Moving playlist to trash: {}", name);
+    let manager = state.playlist_manager.read().await;
+    let entry = manager
+        .trash_playlist(&name, &state.trash)
+        .map_err(map_err)?;
+    record_change(&state, LibraryChange::PlaylistDeleted { playlist: name });
+    Ok(entry)
+}
+
+/// Delete several playlists.
+///
+/// In simple mode, `pin` must be the parent PIN.
+#[tauri::command]
+pub async fn delete_playlists(
+    state: State<'_, AppState>,
+    names: Vec<String>,
+    pin: Option<String>,
+) -> CommandResult<BatchResult> {
+    require_parent_pin(&state, ProtectedOperation::DeletePlaylist, pin).await?;
+    info!("Deleting {} playlist(s)", names.len());
+    let manager = state.playlist_manager.read().await;
+    let result = manager.delete_playlists(&names);
+    for playlist in &result.succeeded {
+        record_change(
+            &state,
+            LibraryChange::PlaylistDeleted {
+                playlist: playlist.clone(),
+            },
+        );
+    }
+    Ok(result)
+}
+
+/// Add a tag to several playlists.
+#[tauri::command]
+pub async fn tag_playlists(
+    state: State<'_, AppState>,
+    names: Vec<String>,
+    tag: String,
+) -> CommandResult<BatchResult> {
+    info!("Tagging {} playlist(s) with '{}'", names.len(), tag);
+    let manager = state.playlist_manager.read().await;
+    manager.tag_playlists(&names, &tag).map_err(map_err)
+}
+
+/// Delete several tracks from a playlist.
+///
+/// In simple mode, `pin` must be the parent PIN.
+#[tauri::command]
+pub async fn delete_tracks(
+    state: State<'_, AppState>,
+    playlist: String,
+    file_names: Vec<String>,
+    pin: Option<String>,
+) -> CommandResult<BatchResult> {
+    require_parent_pin(&state, ProtectedOperation::DeleteTracks, pin).await?;
+    info!(
+        "Deleting {} track(s) from playlist: {}",
+        file_names.len(),
+        playlist
+    );
+    let manager = state.playlist_manager.read().await;
+    let result = manager
+        .delete_tracks(&playlist, &file_names)
+        .map_err(map_err)?;
+    record_tracks_removed(&state, playlist, &result);
+    Ok(result)
+}
+
+/// Move several tracks of a playlist to the trash, so the deletion can be
+/// undone this session.
+///
+/// In simple mode, `pin` must be the parent PIN.
+#[tauri::command]
+pub async fn trash_tracks(
+    state: State<'_, AppState>,
+    playlist: String,
+    file_names: Vec<String>,
+    pin: Option<String>,
+) -> CommandResult<TrashedTracks> {
+    require_parent_pin(&state, ProtectedOperation::DeleteTracks, pin).await?;
+    info!(
+        "Moving {} track(s) of playlist {} to trash",
+        file_names.len(),
+        playlist
+    );
+    let manager = state.playlist_manager.read().await;
+    let trashed = manager
+        .trash_tracks(&playlist, &file_names, &state.trash)
+        .map_err(map_err)?;
+    record_tracks_removed(&state, playlist, &trashed.result);
+    Ok(trashed)
+}
+
+/// Put a trashed playlist or tracks back where they were.
+#[tauri::command]
+pub async fn restore_from_trash(
+    state: State<'_, AppState>,
+    id: TrashId,
+) -> CommandResult<TrashedItem> {
+    info!("Restoring trash entry {}", id);
+    let manager = state.playlist_manager.read().await;
+    let item = manager
+        .restore_from_trash(id, &state.trash)
+        .map_err(map_err)?;
+    let change = match &item {
+        TrashedItem::Playlist { name } => LibraryChange::PlaylistCreated {
+            playlist: name.clone(),
+        },
+        TrashedItem::Tracks {
+            playlist,
+            file_names,
+        } => LibraryChange::TracksAdded {
+            playlist: playlist.clone(),
+            file_names: file_names.clone(),
+        },
+    };
+    record_change(&state, change);
+    Ok(item)
+}
+
+/// Move several tracks to another playlist.
+#[tauri::command]
+pub async fn move_tracks(
+    state: State<'_, AppState>,
+    from: String,
+    to: String,
+    file_names: Vec<String>,
+) -> CommandResult<BatchResult> {
+    info!(
+        "Moving {} track(s) from '{}' to '{}'",
+        file_names.len(),
+        from,
+        to
+    );
+    let manager = state.playlist_manager.read().await;
+    let result = manager
+        .move_tracks(&from, &to, &file_names)
+        .map_err(map_err)?;
+    if !result.succeeded.is_empty() {
+        record_change(
+            &state,
+            LibraryChange::TracksMoved {
+                from,
+                to,
+                file_names: result.succeeded.clone(),
+            },
+        );
+    }
+    Ok(result)
+}
+
+/// Add a tag to several tracks in a playlist.
+#[tauri::command]
+pub async fn tag_tracks(
+    state: State<'_, AppState>,
+    playlist: String,
+    file_names: Vec<String>,
+    tag: String,
+) -> CommandResult<BatchResult> {
+    info!(
+        "Tagging {} track(s) in '{}' with '{}'",
+        file_names.len(),
+        playlist,
+        tag
+    );
+    let manager = state.playlist_manager.read().await;
+    manager
+        .tag_tracks(&playlist, &file_names, &tag)
+        .map_err(map_err)
 }
 
 /// Sync a playlist to a device.
@@ -66,17 +282,31 @@ pub async fn sync_playlist(
     state: State<'_, AppState>,
     playlist_name: String,
     device_mount_point: String,
-) -> std::result::Result<(), String> {
+) -> CommandResult<()> {
     info!(
         "Syncing playlist '{}' to device at '{}'",
         playlist_name, device_mount_point
     );
 
     let mount_point = PathBuf::from(&device_mount_point);
+    let runtime = state.runtime();
+    let _slot = runtime.admit(
+        runtime.generate_task_id(),
+        TaskCategory::FileTransfer,
+        Some(TaskResource::device(&mount_point)),
+    )?;
     let manager = state.playlist_manager.read().await;
     manager
         .sync_to_device(&playlist_name, &mount_point)
-        .map_err(map_err)
+        .map_err(map_err)?;
+    record_change(
+        &state,
+        LibraryChange::SyncPerformed {
+            playlists: vec![playlist_name],
+            device_mount_point,
+        },
+    );
+    Ok(())
 }
 
 /// Get tracks for a playlist.
@@ -84,7 +314,7 @@ pub async fn sync_playlist(
 pub async fn get_playlist_tracks(
     state: State<'_, AppState>,
     name: String,
-) -> std::result::Result<Vec<TrackInfo>, String> {
+) -> CommandResult<Vec<TrackInfo>> {
     debug!("Getting tracks for playlist: {}", name);
     let manager = state.playlist_manager.read().await;
     manager.list_tracks(&name).map_err(map_err)
@@ -95,7 +325,7 @@ pub async fn get_playlist_tracks(
 pub async fn get_playlist_details(
     state: State<'_, AppState>,
     name: String,
-) -> std::result::Result<PlaylistMetadata, String> {
+) -> CommandResult<PlaylistMetadata> {
     debug!("Getting details for playlist: {}", name);
     let manager = state.playlist_manager.read().await;
     let playlist_path = manager.get_playlist_path(&name).map_err(map_err)?;
@@ -114,7 +344,7 @@ pub async fn get_playlist_details(
 pub async fn validate_playlist_folder(
     state: State<'_, AppState>,
     name: String,
-) -> std::result::Result<FolderValidationResult, String> {
+) -> CommandResult<FolderValidationResult> {
     debug!("Validating playlist folder: {}", name);
     let manager = state.playlist_manager.read().await;
     let result = manager.validate_folder(&name);
@@ -135,7 +365,7 @@ pub async fn validate_playlist_folder(
 pub async fn get_playlist_statistics(
     state: State<'_, AppState>,
     name: String,
-) -> std::result::Result<FolderStatistics, String> {
+) -> CommandResult<FolderStatistics> {
     debug!("Getting statistics for playlist: {}", name);
     let manager = state.playlist_manager.read().await;
     let stats = manager.get_folder_statistics(&name).map_err(map_err)?;
@@ -151,7 +381,7 @@ pub async fn get_playlist_statistics(
 pub async fn repair_playlist_folder(
     state: State<'_, AppState>,
     name: String,
-) -> std::result::Result<Vec<String>, String> {
+) -> CommandResult<Vec<String>> {
     info!("Repairing playlist folder: {}", name);
     let manager = state.playlist_manager.read().await;
     let repairs = manager.repair_folder(&name).map_err(map_err)?;
@@ -165,18 +395,56 @@ pub async fn repair_playlist_folder(
 
 /// Extract MP3 metadata (ID3 tags) from a single file.
 #[tauri::command]
-pub async fn extract_track_metadata(path: String) -> std::result::Result<Mp3Metadata, String> {
+pub async fn extract_track_metadata(path: String) -> CommandResult<Mp3Metadata> {
     debug!("Extracting metadata from: {}", path);
     let path_buf = PathBuf::from(&path);
     extract_metadata(&path_buf).map_err(map_err)
 }
 
+/// Look up the lyrics of a track from its tags and save them as configured
+/// (an `.lrc` file next to it and/or its ID3 tag).
+///
+/// Returns `None` when no lyrics were found.
+#[tauri::command]
+pub async fn fetch_track_lyrics(
+    state: State<'_, AppState>,
+    path: String,
+) -> CommandResult<Option<Lyrics>> {
+    info!("Fetching lyrics of: {}", path);
+    if state.offline_mode().await {
+        return Err(map_err(Error::offline("fetching lyrics")));
+    }
+    let config = state.config_manager.read().await.config().lyrics.clone();
+    let path = PathBuf::from(path);
+
+    tokio::task::spawn_blocking(move || -> youtun4_core::Result<Option<Lyrics>> {
+        let metadata = extract_metadata(&path)?;
+        let title = metadata.title.clone().unwrap_or_else(|| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        });
+        let found = LyricsClient::new(config.clone()).fetch(
+            &title,
+            metadata.artist.as_deref(),
+            metadata.duration_secs,
+        )?;
+        if let Some(found) = &found {
+            save_lyrics(&path, found, &config)?;
+        }
+        Ok(found)
+    })
+    .await
+    .map_err(|e| format!("Task join error: {e}"))?
+    .map_err(map_err)
+}
+
 /// Get tracks for a playlist without metadata extraction.
 #[tauri::command]
 pub async fn get_playlist_tracks_fast(
     state: State<'_, AppState>,
     name: String,
-) -> std::result::Result<Vec<TrackInfo>, String> {
+) -> CommandResult<Vec<TrackInfo>> {
     debug!("Getting tracks (fast) for playlist: {}", name);
     let manager = state.playlist_manager.read().await;
     manager
@@ -184,13 +452,47 @@ pub async fn get_playlist_tracks_fast(
         .map_err(map_err)
 }
 
+/// Get one page of tracks for a playlist, with metadata for that page only.
+#[tauri::command]
+pub async fn list_tracks_paged(
+    state: State<'_, AppState>,
+    name: String,
+    offset: usize,
+    limit: usize,
+) -> CommandResult<TrackPage> {
+    debug!(
+        "Getting tracks {}..{} for playlist: {}",
+        offset,
+        offset.saturating_add(limit),
+        name
+    );
+    let manager = state.playlist_manager.read().await;
+    manager
+        .list_tracks_paged(&name, offset, limit)
+        .map_err(map_err)
+}
+
+/// Search the library's playlists and tracks.
+///
+/// Each group holds at most `limit` results, best matches first.
+#[tauri::command]
+pub async fn search_library(
+    state: State<'_, AppState>,
+    query: String,
+    limit: usize,
+) -> CommandResult<LibrarySearchResults> {
+    debug!("Searching library for: {}", query);
+    let manager = state.playlist_manager.read().await;
+    manager.search_library(&query, limit).map_err(map_err)
+}
+
 /// Import an existing folder as a playlist.
 #[tauri::command]
 pub async fn import_playlist_folder(
     state: State<'_, AppState>,
     folder_name: String,
     source_url: Option<String>,
-) -> std::result::Result<String, String> {
+) -> CommandResult<String> {
     info!("Importing folder as playlist: {}", folder_name);
     let manager = state.playlist_manager.read().await;
     let folder_path = manager.base_path().join(&folder_name);
@@ -198,16 +500,121 @@ pub async fn import_playlist_folder(
         .import_folder(&folder_path, source_url)
         .map_err(map_err)?;
     info!("Successfully imported folder '{}' as playlist", name);
+    record_change(
+        &state,
+        LibraryChange::PlaylistCreated {
+            playlist: name.clone(),
+        },
+    );
     Ok(name)
 }
 
+/// Open a playlist's folder in the system file manager.
+#[tauri::command]
+pub async fn open_playlist_folder(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+) -> CommandResult<()> {
+    debug!("Opening folder of playlist '{}'", name);
+    let path = state
+        .playlist_manager
+        .read()
+        .await
+        .get_playlist_path(&name)
+        .map_err(map_err)?;
+    app.opener()
+        .open_path(path.display().to_string(), None::<&str>)
+        .map_err(|e| CommandError::from(format!("Failed to open playlist folder: {e}")))
+}
+
+/// Reveal a file or folder of the library in the system file manager, with
+/// it selected.
+///
+/// Only paths inside the playlists directory can be revealed.
+#[tauri::command]
+pub async fn reveal_in_file_manager(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    path: String,
+) -> CommandResult<()> {
+    debug!("Revealing in file manager: {}", path);
+    let path = PathBuf::from(path);
+    if !state.playlist_manager.read().await.contains_path(&path) {
+        return Err(CommandError::invalid_input(format!(
+            "Not in the library: {}",
+            path.display()
+        )));
+    }
+    app.opener()
+        .reveal_item_in_dir(&path)
+        .map_err(|e| CommandError::from(format!("Failed to reveal {}: {e}", path.display())))
+}
+
+/// Export a playlist as an M3U file in the user's downloads folder.
+///
+/// Returns the path of the written file.
+#[tauri::command]
+pub async fn export_playlist_m3u(
+    state: State<'_, AppState>,
+    name: String,
+) -> CommandResult<String> {
+    info!("Exporting playlist '{}' as M3U", name);
+    let directory = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .ok_or_else(|| CommandError::from("No downloads folder to export to".to_string()))?;
+    let destination = directory.join(format!("{name}.m3u"));
+
+    let manager = state.playlist_manager.read().await;
+    let count = manager.export_m3u(&name, &destination).map_err(map_err)?;
+    info!("Exported {} tracks to {}", count, destination.display());
+    Ok(destination.display().to_string())
+}
+
+/// Videos in a playlist's download archive, oldest first.
+///
+/// Archived videos are skipped when the playlist is downloaded again.
+#[tauri::command]
+pub async fn get_download_archive(
+    state: State<'_, AppState>,
+    name: String,
+) -> CommandResult<Vec<ArchivedVideo>> {
+    debug!("Getting download archive of playlist '{}'", name);
+    let path = state
+        .playlist_manager
+        .read()
+        .await
+        .get_playlist_path(&name)
+        .map_err(map_err)?;
+    Ok(DownloadArchive::open(&path).videos())
+}
+
+/// Clear a playlist's download archive, so its next download only skips
+/// videos whose file name still matches.
+///
+/// Returns how many videos were removed from the archive.
+#[tauri::command]
+pub async fn reset_download_archive(
+    state: State<'_, AppState>,
+    name: String,
+) -> CommandResult<usize> {
+    info!("Resetting download archive of playlist '{}'", name);
+    let path = state
+        .playlist_manager
+        .read()
+        .await
+        .get_playlist_path(&name)
+        .map_err(map_err)?;
+    DownloadArchive::open(&path).reset().map_err(map_err)
+}
+
 /// Rename a playlist.
 #[tauri::command]
 pub async fn rename_playlist(
     state: State<'_, AppState>,
     old_name: String,
     new_name: String,
-) -> std::result::Result<(), String> {
+) -> CommandResult<()> {
     info!("Renaming playlist '{}' to '{}'", old_name, new_name);
 
     validate_playlist_name(&new_name).map_err(map_err)?;
@@ -235,15 +642,19 @@ pub async fn rename_playlist(
         "Successfully renamed playlist '{}' to '{}'",
         old_name, new_name
     );
+    record_change(
+        &state,
+        LibraryChange::PlaylistRenamed {
+            from: old_name,
+            to: new_name,
+        },
+    );
     Ok(())
 }
 
 /// Check if a playlist exists.
 #[tauri::command]
-pub async fn playlist_exists(
-    state: State<'_, AppState>,
-    name: String,
-) -> std::result::Result<bool, String> {
+pub async fn playlist_exists(state: State<'_, AppState>, name: String) -> CommandResult<bool> {
     debug!("Checking if playlist exists: {}", name);
     let manager = state.playlist_manager.read().await;
     let exists = manager.get_playlist_path(&name).is_ok();
@@ -255,7 +666,7 @@ pub async fn playlist_exists(
 pub async fn ensure_playlist_structure(
     state: State<'_, AppState>,
     name: String,
-) -> std::result::Result<(), String> {
+) -> CommandResult<()> {
     debug!("Ensuring folder structure for playlist: {}", name);
     let manager = state.playlist_manager.read().await;
     manager.ensure_folder_structure(&name).map_err(map_err)?;
@@ -268,7 +679,7 @@ pub async fn ensure_playlist_structure(
 pub async fn get_playlist_saved_metadata(
     state: State<'_, AppState>,
     name: String,
-) -> std::result::Result<SavedPlaylistMetadata, String> {
+) -> CommandResult<SavedPlaylistMetadata> {
     debug!("Getting saved metadata for playlist: {}", name);
     let manager = state.playlist_manager.read().await;
     manager.get_saved_metadata(&name).map_err(map_err)
@@ -283,7 +694,7 @@ pub async fn update_playlist_metadata(
     description: Option<String>,
     source_url: Option<Option<String>>,
     thumbnail_url: Option<Option<String>>,
-) -> std::result::Result<SavedPlaylistMetadata, String> {
+) -> CommandResult<SavedPlaylistMetadata> {
     info!("Updating metadata for playlist: {}", name);
     let manager = state.playlist_manager.read().await;
     manager
@@ -296,8 +707,95 @@ pub async fn update_playlist_metadata(
 pub async fn refresh_playlist_stats(
     state: State<'_, AppState>,
     name: String,
-) -> std::result::Result<SavedPlaylistMetadata, String> {
+) -> CommandResult<SavedPlaylistMetadata> {
     debug!("Refreshing stats for playlist: {}", name);
     let manager = state.playlist_manager.read().await;
     manager.refresh_playlist_stats(&name).map_err(map_err)
 }
+
+/// Get the setting overrides for a playlist.
+#[tauri::command]
+pub async fn get_playlist_overrides(
+    state: State<'_, AppState>,
+    name: String,
+) -> CommandResult<PlaylistOverrides> {
+    let config_manager = state.config_manager.read().await;
+    Ok(config_manager.config().playlist_overrides(&name))
+}
+
+/// Save the setting overrides for a playlist.
+#[tauri::command]
+pub async fn set_playlist_overrides(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+    overrides: PlaylistOverrides,
+) -> CommandResult<()> {
+    info!("Saving setting overrides for playlist '{}'", name);
+    apply_config_change(&app, &state, |manager| {
+        manager.set_playlist_overrides(&name, overrides)
+    })
+    .await?;
+    Ok(())
+}
+
+/// Get the download profile of a playlist, if it has one.
+#[tauri::command]
+pub async fn get_playlist_download_profile(
+    state: State<'_, AppState>,
+    name: String,
+) -> CommandResult<Option<DownloadProfile>> {
+    let manager = state.playlist_manager.read().await;
+    manager.get_download_profile(&name).map_err(map_err)
+}
+
+/// Save the download profile of a playlist, used by its next downloads.
+#[tauri::command]
+pub async fn set_playlist_download_profile(
+    state: State<'_, AppState>,
+    name: String,
+    profile: DownloadProfile,
+) -> CommandResult<SavedPlaylistMetadata> {
+    info!("Saving download profile for playlist '{}'", name);
+    let manager = state.playlist_manager.read().await;
+    manager
+        .set_download_profile(&name, Some(profile))
+        .map_err(map_err)
+}
+
+/// Remove the download profile of a playlist, so it uses the global
+/// download settings again.
+#[tauri::command]
+pub async fn delete_playlist_download_profile(
+    state: State<'_, AppState>,
+    name: String,
+) -> CommandResult<SavedPlaylistMetadata> {
+    info!("Removing download profile of playlist '{}'", name);
+    let manager = state.playlist_manager.read().await;
+    manager.set_download_profile(&name, None).map_err(map_err)
+}
+
+/// Save the layout and order of the playlists in the library.
+#[tauri::command]
+pub async fn set_playlist_view(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    view: PlaylistViewPreferences,
+) -> CommandResult<()> {
+    debug!("Saving playlist view: {:?}", view);
+    apply_config_change(&app, &state, |manager| manager.set_playlist_view(view)).await?;
+    Ok(())
+}
+
+/// Add the tracks removed from `playlist` to the change log, if any.
+pub(crate) fn record_tracks_removed(state: &AppState, playlist: String, result: &BatchResult) {
+    if !result.succeeded.is_empty() {
+        record_change(
+            state,
+            LibraryChange::TracksRemoved {
+                playlist,
+                file_names: result.succeeded.clone(),
+            },
+        );
+    }
+}
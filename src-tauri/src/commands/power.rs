@@ -0,0 +1,105 @@
+//! Pausing heavy background work on battery power or metered networks.
+//!
+//! A monitor polls the power state and emits `power-state-changed` whenever
+//! it, or the decision to pause, changes. While heavy work should pause the
+//! download queue is paused, and it resumes once conditions clear unless the
+//! user paused or resumed it in the meantime.
+
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use tauri::{AppHandle, Emitter, Manager, State};
+use tracing::{error, info};
+use youtun4_core::power::{PauseReason, PowerState};
+
+use super::error::CommandResult;
+use super::events::emit_task_event;
+use super::queue::{process_queue, queue_events};
+use super::state::AppState;
+
+/// Event names for power events.
+pub mod power_events {
+    /// Event emitted when the power state or the pause decision changes.
+    pub const POWER_STATE_CHANGED: &str = "power-state-changed";
+}
+
+/// How often the power state is checked.
+const POWER_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The power state and whether heavy work is paused because of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct PowerStatus {
+    /// Detected power state.
+    #[serde(flatten)]
+    pub state: PowerState,
+    /// Why heavy background work is paused, if it is.
+    pub pause_reason: Option<PauseReason>,
+}
+
+async fn current_status(state: &AppState) -> PowerStatus {
+    PowerStatus {
+        state: *state.power_state.read().await,
+        pause_reason: state.heavy_work_pause_reason().await,
+    }
+}
+
+/// Get the power state and whether heavy work is paused.
+#[tauri::command]
+pub async fn get_power_status(state: State<'_, AppState>) -> CommandResult<PowerStatus> {
+    Ok(current_status(&state).await)
+}
+
+/// Poll the power state in the background until the app shuts down.
+///
+/// Changes to the power preferences are picked up on the next poll.
+pub fn start_power_monitor(app: &AppHandle) {
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = handle.state::<AppState>();
+        let mut last_status = None;
+        while !state.is_shutting_down() {
+            match tokio::task::spawn_blocking(PowerState::detect).await {
+                Ok(detected) => *state.power_state.write().await = detected,
+                Err(e) => error!("Power state detection failed: {}", e),
+            }
+
+            let status = current_status(&state).await;
+            if last_status != Some(status) {
+                apply_power_status(&handle, &state, status).await;
+                last_status = Some(status);
+            }
+            tokio::time::sleep(POWER_POLL_INTERVAL).await;
+        }
+    });
+}
+
+/// Notify the frontend of a new power status and pause or resume the queue.
+async fn apply_power_status(app: &AppHandle, state: &State<'_, AppState>, status: PowerStatus) {
+    info!(
+        "Power state: on_battery={}, metered_network={}, pause_reason={:?}",
+        status.state.on_battery, status.state.metered_network, status.pause_reason
+    );
+    if let Err(e) = app.emit(power_events::POWER_STATE_CHANGED, &status) {
+        error!("Failed to emit power-state-changed event: {}", e);
+    }
+
+    let queue = state.download_queue_arc();
+    if let Some(reason) = status.pause_reason {
+        if queue.is_paused().await {
+            return;
+        }
+        info!("Pausing download queue: {}", reason);
+        queue.pause().await;
+        state.power_paused_queue.store(true, Ordering::SeqCst);
+        if let Err(e) = emit_task_event(app, queue_events::QUEUE_PAUSED, &()) {
+            error!("Failed to emit queue-paused event: {}", e);
+        }
+    } else if state.power_paused_queue.swap(false, Ordering::SeqCst) && queue.is_paused().await {
+        info!("Resuming download queue paused for power saving");
+        queue.resume().await;
+        if let Err(e) = emit_task_event(app, queue_events::QUEUE_RESUMED, &()) {
+            error!("Failed to emit queue-resumed event: {}", e);
+        }
+        process_queue(app.clone(), state.clone()).await;
+    }
+}
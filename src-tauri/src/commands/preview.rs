@@ -0,0 +1,134 @@
+//! Track previews and playlist covers served through the asset protocol.
+//!
+//! The asset protocol scope starts empty. Previewing a track adds just that
+//! file to the scope, so the webview can stream it from disk with seeking
+//! support without being able to read anything else in the library. Covers
+//! and other thumbnails are added the same way once they are in the
+//! thumbnail cache.
+
+use std::path::Path;
+
+use tauri::{AppHandle, Manager, State};
+use tracing::debug;
+use youtun4_core::cache::CacheManager;
+use youtun4_core::{
+    CachedThumbnail, Error, ErrorCode, PlaylistError, ThumbnailManager, cover_thumbnail_id,
+};
+
+use super::error::{CommandError, CommandResult, map_err};
+use super::state::AppState;
+
+/// Prepare a downloaded track for in-app playback.
+///
+/// Returns the track's absolute path, which the frontend turns into an
+/// asset protocol URL. Only tracks listed in the playlist can be previewed.
+#[tauri::command]
+pub async fn prepare_track_preview(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    playlist_name: String,
+    file_name: String,
+) -> CommandResult<String> {
+    let manager = state.playlist_manager.read().await;
+    let track = manager
+        .list_tracks(&playlist_name)
+        .map_err(map_err)?
+        .into_iter()
+        .find(|track| track.file_name == file_name)
+        .ok_or_else(|| {
+            map_err(Error::Playlist(PlaylistError::TrackNotFound {
+                playlist: playlist_name.clone(),
+                track: file_name.clone(),
+            }))
+        })?;
+    drop(manager);
+
+    allow_asset(&app, &track.path)?;
+    debug!("Allowed preview of {}", track.path.display());
+    Ok(track.path.to_string_lossy().into_owned())
+}
+
+/// Prepare a playlist's cover art for display.
+///
+/// Fetches the playlist's thumbnail into the cache if needed and returns the
+/// cached file's path. Returns `None` when the playlist has no cover or it
+/// can't be fetched (offline, network errors, caching disabled), so the
+/// library shows its placeholder instead.
+#[tauri::command]
+pub async fn prepare_playlist_cover(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    playlist_name: String,
+) -> CommandResult<Option<String>> {
+    let manager = state.playlist_manager.read().await;
+    let saved = manager
+        .get_saved_metadata(&playlist_name)
+        .map_err(map_err)?;
+    drop(manager);
+    let Some(url) = saved.thumbnail_url else {
+        return Ok(None);
+    };
+
+    let cache_config = state.config_manager.read().await.config().cache.clone();
+    let offline = state.offline_mode().await;
+    let cover = tokio::task::spawn_blocking(move || {
+        let id = cover_thumbnail_id(&url);
+        let mut cache = CacheManager::new(cache_config)?;
+        let mut thumbnails = ThumbnailManager::new(&mut cache).with_offline(offline);
+        thumbnails.fetch_and_cache(&id, &url)?;
+        Ok::<_, Error>(thumbnails.get_thumbnail_path(&id))
+    })
+    .await
+    .map_err(|e| CommandError::new(ErrorCode::Internal, format!("Task join error: {e}")))?;
+
+    let path = match cover {
+        Ok(Some(path)) => path,
+        Ok(None) => return Ok(None),
+        Err(e) => {
+            debug!("No cover for playlist '{}': {}", playlist_name, e);
+            return Ok(None);
+        }
+    };
+    allow_asset(&app, &path)?;
+    Ok(Some(path.to_string_lossy().into_owned()))
+}
+
+/// Get a thumbnail URL or local image ready for display.
+///
+/// URLs are fetched into the thumbnail cache on first use and served from
+/// it afterwards, also offline. Returns the image's path, which the
+/// frontend turns into an asset protocol URL, or a base64 `data:` URL when
+/// thumbnails aren't cached.
+#[tauri::command]
+pub async fn get_cached_thumbnail(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    path_or_url: String,
+) -> CommandResult<CachedThumbnail> {
+    let cache_config = state.config_manager.read().await.config().cache.clone();
+    let offline = state.offline_mode().await;
+    let thumbnail = tokio::task::spawn_blocking(move || {
+        let mut cache = CacheManager::new(cache_config)?;
+        ThumbnailManager::new(&mut cache)
+            .with_offline(offline)
+            .resolve(&path_or_url)
+    })
+    .await
+    .map_err(|e| CommandError::new(ErrorCode::Internal, format!("Task join error: {e}")))?
+    .map_err(map_err)?;
+
+    if let CachedThumbnail::File(path) = &thumbnail {
+        allow_asset(&app, path)?;
+    }
+    Ok(thumbnail)
+}
+
+/// Add a single file to the asset protocol scope.
+fn allow_asset(app: &AppHandle, path: &Path) -> CommandResult<()> {
+    app.asset_protocol_scope().allow_file(path).map_err(|e| {
+        CommandError::new(
+            ErrorCode::Internal,
+            format!("Failed to allow {}: {e}", path.display()),
+        )
+    })
+}
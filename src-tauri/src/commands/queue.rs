@@ -2,30 +2,50 @@
 
 use std::path::PathBuf;
 use std::sync::Arc;
-
-use tauri::{AppHandle, Emitter, State};
-use tracing::{error, info};
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+use tauri::{AppHandle, Manager, State};
+use tracing::{debug, error, info};
+use youtun4_core::ErrorCode;
+use youtun4_core::audio::TranscodeOptions;
+use youtun4_core::history::HistoryEntry;
+use youtun4_core::playlist::DownloadProfile;
 use youtun4_core::queue::{
-    DownloadPriority, DownloadRequest, QueueConfig, QueueItem, QueueItemId, QueueStats,
+    DownloadPriority, DownloadQueueManager, DownloadRequest, QueueConfig, QueueItem, QueueItemId,
+    QueueStats,
 };
+use youtun4_core::task_journal::PersistedTaskKind;
 use youtun4_core::youtube::{
-    DownloadProgress, RustyYtdlConfig, RustyYtdlDownloader, YouTubeDownloader, validate_youtube_url,
+    AsyncYouTubeDownloader, DownloadProgress, PlaylistInfo, RustyYtdlConfig, RustyYtdlDownloader,
+    validate_youtube_url,
 };
 
 use crate::runtime::TaskCategory;
 
-use super::error::map_err;
+use super::change_log::record_downloaded_tracks;
+use super::config::apply_config_change;
+use super::error::{CommandError, CommandResult, map_err};
+use super::events::emit_task_event;
+use super::history::record_history;
+use super::notifications::notify_webhook;
 use super::state::AppState;
+use super::storage::ensure_library_space;
 
 /// Event names for download queue events emitted to the frontend.
+///
+/// Deprecated aliases: every event here is also emitted as a unified `task-event`,
+/// which new frontend code should listen to instead.
 pub mod queue_events {
     pub const QUEUE_ITEM_ADDED: &str = "queue-item-added";
     pub const QUEUE_ITEM_STARTED: &str = "queue-item-started";
     pub const QUEUE_ITEM_PROGRESS: &str = "queue-item-progress";
     pub const QUEUE_ITEM_COMPLETED: &str = "queue-item-completed";
+    pub const QUEUE_ITEM_PLANNED: &str = "queue-item-planned";
     pub const QUEUE_ITEM_FAILED: &str = "queue-item-failed";
     pub const QUEUE_ITEM_CANCELLED: &str = "queue-item-cancelled";
     pub const QUEUE_ITEM_REMOVED: &str = "queue-item-removed";
+    pub const QUEUE_REORDERED: &str = "queue-reordered";
     pub const QUEUE_PAUSED: &str = "queue-paused";
     pub const QUEUE_RESUMED: &str = "queue-resumed";
     pub const QUEUE_CONFIG_UPDATED: &str = "queue-config-updated";
@@ -40,6 +60,11 @@ pub struct AddToQueueRequest {
     pub audio_quality: Option<String>,
     pub embed_thumbnail: Option<bool>,
     pub priority: Option<String>,
+    pub max_speed_bps: Option<u64>,
+    #[serde(default)]
+    pub split_by_chapters: Option<bool>,
+    #[serde(default)]
+    pub dry_run: Option<bool>,
 }
 
 impl AddToQueueRequest {
@@ -63,6 +88,15 @@ impl AddToQueueRequest {
             };
             request = request.with_priority(priority);
         }
+        if let Some(bytes_per_sec) = self.max_speed_bps {
+            request = request.with_max_speed(bytes_per_sec);
+        }
+        if let Some(split) = self.split_by_chapters {
+            request = request.with_split_by_chapters(split);
+        }
+        if let Some(dry_run) = self.dry_run {
+            request = request.with_dry_run(dry_run);
+        }
 
         request
     }
@@ -74,22 +108,33 @@ pub async fn queue_add_download(
     app: AppHandle,
     state: State<'_, AppState>,
     request: AddToQueueRequest,
-) -> std::result::Result<QueueItemId, String> {
+) -> CommandResult<QueueItemId> {
     info!("Adding download to queue: {}", request.url);
 
     let validation = validate_youtube_url(&request.url);
     if !validation.is_valid {
-        return Err(validation
-            .error_message
-            .unwrap_or_else(|| "Invalid URL".to_string()));
+        return Err(CommandError::new(
+            ErrorCode::InvalidUrl,
+            validation
+                .error_message
+                .unwrap_or_else(|| "Invalid URL".to_string()),
+        ));
     }
 
-    let download_request = request.into_download_request();
+    enqueue(app, state, request.into_download_request()).await
+}
+
+/// Add a validated download request to the queue and start processing it.
+async fn enqueue(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    download_request: DownloadRequest,
+) -> CommandResult<QueueItemId> {
     let queue = state.download_queue_arc();
     let item_id = queue.add(download_request).await;
 
     if let Some(item) = queue.get_item(item_id).await
-        && let Err(e) = app.emit(queue_events::QUEUE_ITEM_ADDED, &item)
+        && let Err(e) = emit_task_event(&app, queue_events::QUEUE_ITEM_ADDED, &item)
     {
         error!("Failed to emit queue-item-added event: {}", e);
     }
@@ -107,7 +152,7 @@ pub async fn queue_add_to_playlist(
     url: String,
     playlist_name: String,
     priority: Option<String>,
-) -> std::result::Result<QueueItemId, String> {
+) -> CommandResult<QueueItemId> {
     info!(
         "Adding download to queue for playlist '{}': {}",
         playlist_name, url
@@ -115,9 +160,12 @@ pub async fn queue_add_to_playlist(
 
     let validation = validate_youtube_url(&url);
     if !validation.is_valid {
-        return Err(validation
-            .error_message
-            .unwrap_or_else(|| "Invalid URL".to_string()));
+        return Err(CommandError::new(
+            ErrorCode::InvalidUrl,
+            validation
+                .error_message
+                .unwrap_or_else(|| "Invalid URL".to_string()),
+        ));
     }
 
     let playlist_manager = state.playlist_manager.read().await;
@@ -138,29 +186,86 @@ pub async fn queue_add_to_playlist(
         audio_quality: None,
         embed_thumbnail: None,
         priority,
+        max_speed_bps: None,
+        split_by_chapters: None,
     };
 
     queue_add_download(app, state, request).await
 }
 
+/// Add a single video to a specific local playlist, creating it if needed.
+///
+/// Watch URLs that also carry a playlist only download the video.
+#[tauri::command]
+pub async fn queue_add_video_to_playlist(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    url: String,
+    playlist_name: String,
+    priority: Option<String>,
+) -> CommandResult<QueueItemId> {
+    info!(
+        "Adding video to queue for playlist '{}': {}",
+        playlist_name, url
+    );
+
+    let validation = validate_youtube_url(&url);
+    let Some(video_id) = validation.video_id else {
+        return Err(CommandError::new(
+            ErrorCode::InvalidUrl,
+            validation
+                .error_message
+                .unwrap_or_else(|| "URL does not contain a video ID".to_string()),
+        ));
+    };
+
+    let playlist_manager = state.playlist_manager.read().await;
+    let playlist_path = playlist_manager.base_path().join(&playlist_name);
+
+    if !playlist_path.exists() {
+        // A single video is not the playlist's source
+        playlist_manager
+            .create_playlist(&playlist_name, None)
+            .map_err(map_err)?;
+    }
+
+    drop(playlist_manager);
+
+    let request = AddToQueueRequest {
+        url: format!("https://www.youtube.com/watch?v={video_id}"),
+        output_dir: playlist_path.display().to_string(),
+        playlist_name: Some(playlist_name),
+        audio_quality: None,
+        embed_thumbnail: None,
+        priority,
+        max_speed_bps: None,
+        split_by_chapters: None,
+    };
+
+    enqueue(app, state, request.into_download_request()).await
+}
+
 /// Add multiple download requests to the queue at once.
 #[tauri::command]
 pub async fn queue_add_batch(
     app: AppHandle,
     state: State<'_, AppState>,
     requests: Vec<AddToQueueRequest>,
-) -> std::result::Result<Vec<QueueItemId>, String> {
+) -> CommandResult<Vec<QueueItemId>> {
     info!("Adding {} downloads to queue (batch)", requests.len());
 
     for request in &requests {
         let validation = validate_youtube_url(&request.url);
         if !validation.is_valid {
-            return Err(format!(
-                "Invalid URL '{}': {}",
-                request.url,
-                validation
-                    .error_message
-                    .unwrap_or_else(|| "Invalid".to_string())
+            return Err(CommandError::new(
+                ErrorCode::InvalidUrl,
+                format!(
+                    "Invalid URL '{}': {}",
+                    request.url,
+                    validation
+                        .error_message
+                        .unwrap_or_else(|| "Invalid".to_string())
+                ),
             ));
         }
     }
@@ -175,7 +280,7 @@ pub async fn queue_add_batch(
 
     for &item_id in &item_ids {
         if let Some(item) = queue.get_item(item_id).await
-            && let Err(e) = app.emit(queue_events::QUEUE_ITEM_ADDED, &item)
+            && let Err(e) = emit_task_event(&app, queue_events::QUEUE_ITEM_ADDED, &item)
         {
             error!("Failed to emit queue-item-added event: {}", e);
         }
@@ -192,13 +297,13 @@ pub async fn queue_remove_item(
     app: AppHandle,
     state: State<'_, AppState>,
     item_id: QueueItemId,
-) -> std::result::Result<bool, String> {
+) -> CommandResult<bool> {
     info!("Removing item {} from queue", item_id);
 
     let queue = state.download_queue_arc();
     let removed = queue.remove(item_id).await;
 
-    if removed && let Err(e) = app.emit(queue_events::QUEUE_ITEM_REMOVED, &item_id) {
+    if removed && let Err(e) = emit_task_event(&app, queue_events::QUEUE_ITEM_REMOVED, &item_id) {
         error!("Failed to emit queue-item-removed event: {}", e);
     }
 
@@ -211,14 +316,20 @@ pub async fn queue_cancel_item(
     app: AppHandle,
     state: State<'_, AppState>,
     item_id: QueueItemId,
-) -> std::result::Result<bool, String> {
+) -> CommandResult<bool> {
     info!("Cancelling queue item {}", item_id);
 
     let queue = state.download_queue_arc();
+    let task_id = queue.get_item(item_id).await.and_then(|item| item.task_id);
     let cancelled = queue.cancel(item_id).await;
 
     if cancelled {
-        if let Err(e) = app.emit(queue_events::QUEUE_ITEM_CANCELLED, &item_id) {
+        // Stop the download itself if it already started
+        if let Some(task_id) = task_id {
+            state.cancel_download_task(task_id).await;
+        }
+
+        if let Err(e) = emit_task_event(&app, queue_events::QUEUE_ITEM_CANCELLED, &item_id) {
             error!("Failed to emit queue-item-cancelled event: {}", e);
         }
 
@@ -234,7 +345,7 @@ pub async fn queue_set_priority(
     state: State<'_, AppState>,
     item_id: QueueItemId,
     priority: String,
-) -> std::result::Result<bool, String> {
+) -> CommandResult<bool> {
     info!("Setting priority of item {} to {}", item_id, priority);
 
     let priority = match priority.to_lowercase().as_str() {
@@ -247,25 +358,89 @@ pub async fn queue_set_priority(
     Ok(queue.set_priority(item_id, priority).await)
 }
 
-/// Move an item to the front of the queue (high priority).
+/// Set whether a pending queue item splits videos with chapters into one
+/// track per chapter.
+#[tauri::command]
+pub async fn queue_set_split_by_chapters(
+    state: State<'_, AppState>,
+    item_id: QueueItemId,
+    split: bool,
+) -> CommandResult<bool> {
+    info!("Setting chapter splitting of item {} to {}", item_id, split);
+
+    let queue = state.download_queue_arc();
+    Ok(queue.set_split_by_chapters(item_id, split).await)
+}
+
+/// Tell the frontend the order pending items start in changed.
+async fn emit_reordered(app: &AppHandle, queue: &DownloadQueueManager) {
+    let order: Vec<QueueItemId> = queue
+        .get_pending_items()
+        .await
+        .iter()
+        .map(|item| item.id)
+        .collect();
+    if let Err(e) = emit_task_event(app, queue_events::QUEUE_REORDERED, &order) {
+        error!("Failed to emit queue-reordered event: {}", e);
+    }
+}
+
+/// Put pending queue items in the order of `ordered_ids`, within their
+/// priorities.
+#[tauri::command]
+pub async fn queue_reorder_items(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    ordered_ids: Vec<QueueItemId>,
+) -> CommandResult<bool> {
+    info!("Reordering queue items {:?}", ordered_ids);
+
+    let queue = state.download_queue_arc();
+    let reordered = queue.reorder(&ordered_ids).await;
+    if reordered {
+        emit_reordered(&app, &queue).await;
+    }
+    Ok(reordered)
+}
+
+/// Move a pending queue item to `new_position` among the pending items of
+/// its priority.
+#[tauri::command]
+pub async fn queue_move_item(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    item_id: QueueItemId,
+    new_position: usize,
+) -> CommandResult<bool> {
+    info!("Moving queue item {} to position {}", item_id, new_position);
+
+    let queue = state.download_queue_arc();
+    let moved = queue.move_item(item_id, new_position).await;
+    if moved {
+        emit_reordered(&app, &queue).await;
+    }
+    Ok(moved)
+}
+
+/// Move an item to the front of the queue (high priority, first to start).
 #[tauri::command]
 pub async fn queue_move_to_front(
     state: State<'_, AppState>,
     item_id: QueueItemId,
-) -> std::result::Result<bool, String> {
+) -> CommandResult<bool> {
     info!("Moving item {} to front of queue", item_id);
 
     let queue = state.download_queue_arc();
     Ok(queue.move_to_front(item_id).await)
 }
 
-/// Retry a failed queue item.
+/// Retry a failed queue item, or resume an interrupted one.
 #[tauri::command]
 pub async fn queue_retry_item(
     app: AppHandle,
     state: State<'_, AppState>,
     item_id: QueueItemId,
-) -> std::result::Result<bool, String> {
+) -> CommandResult<bool> {
     info!("Retrying queue item {}", item_id);
 
     let queue = state.download_queue_arc();
@@ -283,25 +458,21 @@ pub async fn queue_retry_item(
 pub async fn queue_get_item(
     state: State<'_, AppState>,
     item_id: QueueItemId,
-) -> std::result::Result<Option<QueueItem>, String> {
+) -> CommandResult<Option<QueueItem>> {
     let queue = state.download_queue_arc();
     Ok(queue.get_item(item_id).await)
 }
 
 /// Get all items in the queue.
 #[tauri::command]
-pub async fn queue_get_all_items(
-    state: State<'_, AppState>,
-) -> std::result::Result<Vec<QueueItem>, String> {
+pub async fn queue_get_all_items(state: State<'_, AppState>) -> CommandResult<Vec<QueueItem>> {
     let queue = state.download_queue_arc();
     Ok(queue.get_all_items().await)
 }
 
 /// Get all pending items in the queue, sorted by priority.
 #[tauri::command]
-pub async fn queue_get_pending_items(
-    state: State<'_, AppState>,
-) -> std::result::Result<Vec<QueueItem>, String> {
+pub async fn queue_get_pending_items(state: State<'_, AppState>) -> CommandResult<Vec<QueueItem>> {
     let queue = state.download_queue_arc();
     Ok(queue.get_pending_items().await)
 }
@@ -310,32 +481,28 @@ pub async fn queue_get_pending_items(
 #[tauri::command]
 pub async fn queue_get_downloading_items(
     state: State<'_, AppState>,
-) -> std::result::Result<Vec<QueueItem>, String> {
+) -> CommandResult<Vec<QueueItem>> {
     let queue = state.download_queue_arc();
     Ok(queue.get_downloading_items().await)
 }
 
 /// Get queue statistics.
 #[tauri::command]
-pub async fn queue_get_stats(
-    state: State<'_, AppState>,
-) -> std::result::Result<QueueStats, String> {
+pub async fn queue_get_stats(state: State<'_, AppState>) -> CommandResult<QueueStats> {
     let queue = state.download_queue_arc();
     Ok(queue.stats().await)
 }
 
 /// Pause the queue (stop starting new downloads).
 #[tauri::command]
-pub async fn queue_pause(
-    app: AppHandle,
-    state: State<'_, AppState>,
-) -> std::result::Result<(), String> {
+pub async fn queue_pause(app: AppHandle, state: State<'_, AppState>) -> CommandResult<()> {
     info!("Pausing download queue");
 
     let queue = state.download_queue_arc();
     queue.pause().await;
+    state.power_paused_queue.store(false, Ordering::SeqCst);
 
-    if let Err(e) = app.emit(queue_events::QUEUE_PAUSED, &()) {
+    if let Err(e) = emit_task_event(&app, queue_events::QUEUE_PAUSED, &()) {
         error!("Failed to emit queue-paused event: {}", e);
     }
 
@@ -344,16 +511,14 @@ pub async fn queue_pause(
 
 /// Resume the queue (allow starting new downloads).
 #[tauri::command]
-pub async fn queue_resume(
-    app: AppHandle,
-    state: State<'_, AppState>,
-) -> std::result::Result<(), String> {
+pub async fn queue_resume(app: AppHandle, state: State<'_, AppState>) -> CommandResult<()> {
     info!("Resuming download queue");
 
     let queue = state.download_queue_arc();
     queue.resume().await;
+    state.power_paused_queue.store(false, Ordering::SeqCst);
 
-    if let Err(e) = app.emit(queue_events::QUEUE_RESUMED, &()) {
+    if let Err(e) = emit_task_event(&app, queue_events::QUEUE_RESUMED, &()) {
         error!("Failed to emit queue-resumed event: {}", e);
     }
 
@@ -364,16 +529,14 @@ pub async fn queue_resume(
 
 /// Check if the queue is paused.
 #[tauri::command]
-pub async fn queue_is_paused(state: State<'_, AppState>) -> std::result::Result<bool, String> {
+pub async fn queue_is_paused(state: State<'_, AppState>) -> CommandResult<bool> {
     let queue = state.download_queue_arc();
     Ok(queue.is_paused().await)
 }
 
 /// Clear all finished items from the queue.
 #[tauri::command]
-pub async fn queue_clear_finished(
-    state: State<'_, AppState>,
-) -> std::result::Result<usize, String> {
+pub async fn queue_clear_finished(state: State<'_, AppState>) -> CommandResult<usize> {
     info!("Clearing finished items from queue");
 
     let queue = state.download_queue_arc();
@@ -382,7 +545,7 @@ pub async fn queue_clear_finished(
 
 /// Clear all non-downloading items from the queue.
 #[tauri::command]
-pub async fn queue_clear_all(state: State<'_, AppState>) -> std::result::Result<usize, String> {
+pub async fn queue_clear_all(state: State<'_, AppState>) -> CommandResult<usize> {
     info!("Clearing all items from queue");
 
     let queue = state.download_queue_arc();
@@ -391,9 +554,7 @@ pub async fn queue_clear_all(state: State<'_, AppState>) -> std::result::Result<
 
 /// Get the queue configuration.
 #[tauri::command]
-pub async fn queue_get_config(
-    state: State<'_, AppState>,
-) -> std::result::Result<QueueConfig, String> {
+pub async fn queue_get_config(state: State<'_, AppState>) -> CommandResult<QueueConfig> {
     let queue = state.download_queue_arc();
     Ok(queue.config().await)
 }
@@ -404,24 +565,25 @@ pub async fn queue_set_config(
     app: AppHandle,
     state: State<'_, AppState>,
     config: QueueConfig,
-) -> std::result::Result<(), String> {
+) -> CommandResult<()> {
     info!("Updating queue configuration");
 
     let queue = state.download_queue_arc();
     queue.set_config(config.clone()).await;
+    let config = queue.config().await;
 
-    let mut config_manager = state.config_manager.write().await;
-    let mut app_config = config_manager.config().clone();
-    app_config.queue = config.clone();
-    config_manager.update(app_config).map_err(map_err)?;
+    let new_queue_config = config.clone();
+    apply_config_change(&app, &state, |manager| {
+        let mut app_config = manager.config().clone();
+        app_config.queue = new_queue_config;
+        manager.update(app_config)
+    })
+    .await?;
 
-    if let Err(e) = app.emit(queue_events::QUEUE_CONFIG_UPDATED, &config) {
+    if let Err(e) = emit_task_event(&app, queue_events::QUEUE_CONFIG_UPDATED, &config) {
         error!("Failed to emit queue-config-updated event: {}", e);
     }
 
-    drop(config_manager);
-    process_queue(app.clone(), state.clone()).await;
-
     Ok(())
 }
 
@@ -431,26 +593,48 @@ pub async fn queue_set_max_concurrent(
     app: AppHandle,
     state: State<'_, AppState>,
     max_concurrent: usize,
-) -> std::result::Result<(), String> {
+) -> CommandResult<()> {
     info!("Setting max concurrent downloads to {}", max_concurrent);
 
     let queue = state.download_queue_arc();
     queue.set_max_concurrent(max_concurrent).await;
 
     let new_config = queue.config().await;
-    let mut config_manager = state.config_manager.write().await;
-    let mut app_config = config_manager.config().clone();
-    app_config.queue = new_config;
-    config_manager.update(app_config).map_err(map_err)?;
-
-    drop(config_manager);
-    process_queue(app.clone(), state.clone()).await;
+    apply_config_change(&app, &state, |manager| {
+        let mut app_config = manager.config().clone();
+        app_config.queue = new_config;
+        manager.update(app_config)
+    })
+    .await?;
 
     Ok(())
 }
 
+/// Start the pending downloads restored from the saved queue. Interrupted
+/// downloads wait for the user to resume them.
+pub fn start_saved_queue(app: &AppHandle) {
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = handle.state::<AppState>();
+        let pending = state.download_queue.stats().await.pending_count;
+        if pending > 0 {
+            info!("Starting {} saved queue download(s)", pending);
+            process_queue(handle.clone(), state).await;
+        }
+    });
+}
+
 /// Internal function to process the queue and start downloads.
 pub async fn process_queue(app: AppHandle, state: State<'_, AppState>) {
+    if state.offline_mode().await {
+        debug!("Offline mode enabled, leaving queued downloads pending");
+        return;
+    }
+    if ensure_library_space(&app, &state).await.is_err() {
+        debug!("Library space limit reached, leaving queued downloads pending");
+        return;
+    }
+
     let queue = state.download_queue_arc();
 
     while queue.can_start_download().await {
@@ -460,131 +644,284 @@ pub async fn process_queue(app: AppHandle, state: State<'_, AppState>) {
             let item_id = item.id;
 
             let config_manager = state.config_manager.read().await;
-            let download_quality = config_manager.config().download_quality;
+            let download_quality = item
+                .request
+                .playlist_name
+                .as_deref()
+                .map_or(config_manager.config().download_quality, |name| {
+                    config_manager.config().download_quality_for(name)
+                });
+            let temp_dir = config_manager.config().cache.temp_directory();
+            let sponsorblock = &config_manager.config().sponsorblock;
+            let sponsorblock = sponsorblock.enabled.then(|| sponsorblock.clone());
+            let concurrency = config_manager.config().download_concurrency;
+            let write_tags = config_manager.config().write_tags;
+            let extraction = config_manager.config().extraction.clone();
+            let embed_cover_art = item
+                .request
+                .embed_thumbnail
+                .unwrap_or(config_manager.config().embed_cover_art);
+            let lyrics = match item.request.playlist_name.as_deref() {
+                Some(name) => config_manager.config().lyrics_for(name),
+                None => {
+                    let lyrics = &config_manager.config().lyrics;
+                    lyrics.enabled.then(|| lyrics.clone())
+                }
+            };
+            let max_speed_bps = item
+                .request
+                .max_speed_bps
+                .or(config_manager.config().max_download_speed_bps);
             drop(config_manager);
 
-            let audio_quality =
-                item.request
-                    .audio_quality
-                    .clone()
-                    .unwrap_or_else(|| match download_quality {
-                        youtun4_core::config::DownloadQuality::Low => "128".to_string(),
-                        youtun4_core::config::DownloadQuality::Medium => "192".to_string(),
-                        youtun4_core::config::DownloadQuality::High => "320".to_string(),
-                    });
-
-            let embed_thumbnail = item.request.embed_thumbnail.unwrap_or(true);
+            let bitrate_kbps = item
+                .request
+                .audio_quality
+                .as_deref()
+                .and_then(|quality| quality.parse().ok())
+                .unwrap_or_else(|| download_quality.bitrate_kbps());
+
             let url = item.request.url.clone();
             let output_dir = item.request.output_dir.clone();
+            let dry_run = item.request.dry_run;
+            // Recorded in the history and change log when the download ends
+            let playlist_name = item.request.playlist_name.clone().unwrap_or_else(|| {
+                output_dir
+                    .file_name()
+                    .map_or_else(|| url.clone(), |name| name.to_string_lossy().into_owned())
+            });
+            let history_entry = {
+                let name = playlist_name.clone();
+                let url = url.clone();
+                let started = Instant::now();
+                move || HistoryEntry::download(name, url).with_elapsed(started.elapsed())
+            };
+
+            let tracked = state.track_task(
+                PersistedTaskKind::QueueDownload {
+                    request: item.request.clone(),
+                },
+                format!("Queue download: {}", item.display_name()),
+            );
+
+            let profile = match item.request.playlist_name.as_deref() {
+                Some(name) => state.download_profile(name).await,
+                None => None,
+            };
 
+            // Create the downloader up front so its control can be registered
+            let mut config = RustyYtdlConfig {
+                temp_dir: Some(temp_dir),
+                transcode: Some(TranscodeOptions::with_bitrate(bitrate_kbps)),
+                sponsorblock,
+                concurrency,
+                max_speed_bps,
+                audio_bitrate_kbps: bitrate_kbps,
+                write_tags,
+                embed_cover_art,
+                lyrics,
+                split_by_chapters: item.request.split_by_chapters,
+                dry_run: item.request.dry_run,
+                extraction,
+                filename_template: state.filename_template().await,
+                ..RustyYtdlConfig::default()
+            };
+            if let Some(profile) = profile {
+                // Settings the request asks for win over the playlist's profile
+                config.apply_profile(&DownloadProfile {
+                    bitrate_kbps: profile
+                        .bitrate_kbps
+                        .filter(|_| item.request.audio_quality.is_none()),
+                    tag_policy: profile
+                        .tag_policy
+                        .filter(|_| item.request.embed_thumbnail.is_none()),
+                    ..profile
+                });
+            }
+            let downloader = Arc::new(RustyYtdlDownloader::with_config(config));
+            let control = downloader.control();
+            let cancel = control.cancellation_token();
+            let download_tasks = Arc::clone(&state.download_tasks);
+            let task_control = control.clone();
+
+            // Hold the lock while spawning so the task can't unregister its
+            // control before it is registered
+            let mut registered = state.download_tasks.write().await;
             let task_id = state.runtime().spawn(
                 TaskCategory::Download,
                 Some(format!("Queue download: {}", item.display_name())),
                 async move {
-                    queue_clone.mark_started(item_id, 0).await;
-
-                    if let Err(e) = app_clone.emit(queue_events::QUEUE_ITEM_STARTED, &serde_json::json!({
-                        "item_id": item_id,
-                        "task_id": 0
-                    })) {
-                        error!("Failed to emit queue-item-started event: {}", e);
-                    }
+                    let _tracked = tracked;
+                    // Early returns leave the inner block, so the flag is always unregistered
+                    async {
+                        queue_clone.mark_started(item_id, 0).await;
 
-                    let config = RustyYtdlConfig::default();
-                    let _ = audio_quality;
-                    let _ = embed_thumbnail;
-
-                    let downloader = RustyYtdlDownloader::with_config(config);
+                        if let Err(e) = emit_task_event(&app_clone, queue_events::QUEUE_ITEM_STARTED, &serde_json::json!({
+                            "item_id": item_id,
+                            "task_id": 0
+                        })) {
+                            error!("Failed to emit queue-item-started event: {}", e);
+                        }
 
-                    let playlist_info = match downloader.parse_playlist_url(&url) {
-                        Ok(info) => info,
-                        Err(e) => {
-                            error!("Failed to parse playlist for queue item {}: {}", item_id, e);
-                            queue_clone.mark_failed(item_id, e.to_string()).await;
-                            if let Err(emit_err) = app_clone.emit(queue_events::QUEUE_ITEM_FAILED, &serde_json::json!({
-                                "item_id": item_id,
-                                "error": e.to_string()
-                            })) {
-                                error!("Failed to emit queue-item-failed event: {}", emit_err);
+                        let single_video = validate_youtube_url(&url).is_single_video();
+                        let parsed = if single_video {
+                            downloader.parse_video_url(&url).await.map(PlaylistInfo::from_video)
+                        } else {
+                            downloader.parse_playlist_url(&url).await
+                        };
+                        let playlist_info = match parsed {
+                            Ok(info) => info,
+                            Err(e) => {
+                                error!("Failed to parse URL for queue item {}: {}", item_id, e);
+                                queue_clone.mark_failed(item_id, e.to_string()).await;
+                                let entry = history_entry().failed(e.to_string());
+                                notify_webhook(&app_clone, &entry);
+                                record_history(&app_clone, entry);
+                                if let Err(emit_err) = emit_task_event(&app_clone, queue_events::QUEUE_ITEM_FAILED, &serde_json::json!({
+                                    "item_id": item_id,
+                                    "error": e.to_string()
+                                })) {
+                                    error!("Failed to emit queue-item-failed event: {}", emit_err);
+                                }
+                                return;
                             }
-                            return;
-                        }
-                    };
-
-                    queue_clone.update_progress(
-                        item_id,
-                        0.0,
-                        None,
-                        Some(playlist_info.video_count),
-                        Some(0),
-                    ).await;
-
-                    let app_for_progress = app_clone.clone();
-                    let queue_for_progress = Arc::clone(&queue_clone);
-                    let progress_callback = move |progress: DownloadProgress| {
-                        let queue_inner = Arc::clone(&queue_for_progress);
-                        let app_inner = app_for_progress.clone();
-
-                        tokio::task::block_in_place(|| {
-                            tokio::runtime::Handle::current().block_on(async {
-                                queue_inner.update_progress(
+                        };
+
+                        queue_clone.update_progress(
+                            item_id,
+                            0.0,
+                            None,
+                            Some(playlist_info.video_count),
+                            Some(0),
+                        ).await;
+
+                        // Progress is reported from the download's blocking
+                        // threads and applied here, in order
+                        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::unbounded_channel();
+                        let progress_callback = move |progress: DownloadProgress| {
+                            // The receiver outlives the download
+                            let _ = progress_tx.send(progress);
+                        };
+                        let forward_progress = async {
+                            while let Some(progress) = progress_rx.recv().await {
+                                queue_clone.update_progress(
                                     item_id,
                                     progress.overall_progress,
                                     Some(progress.current_title.clone()),
                                     Some(progress.total_videos),
                                     Some(progress.videos_completed + progress.videos_skipped),
                                 ).await;
-                            });
-                        });
-
-                        if let Err(e) = app_inner.emit(queue_events::QUEUE_ITEM_PROGRESS, &serde_json::json!({
-                            "item_id": item_id,
-                            "progress": progress.overall_progress,
-                            "current_video": progress.current_title,
-                            "total_videos": progress.total_videos,
-                            "videos_completed": progress.videos_completed + progress.videos_skipped,
-                        })) {
-                            error!("Failed to emit queue-item-progress event: {}", e);
-                        }
-                    };
-
-                    if let Err(e) = std::fs::create_dir_all(&output_dir) {
-                        error!("Failed to create output directory for queue item {}: {}", item_id, e);
-                        queue_clone.mark_failed(item_id, format!("Failed to create output directory: {e}")).await;
-                        if let Err(emit_err) = app_clone.emit(queue_events::QUEUE_ITEM_FAILED, &serde_json::json!({
-                            "item_id": item_id,
-                            "error": format!("Failed to create output directory: {}", e)
-                        })) {
-                            error!("Failed to emit queue-item-failed event: {}", emit_err);
-                        }
-                        return;
-                    }
 
-                    match downloader.download_playlist(
-                        &playlist_info,
-                        &output_dir,
-                        Some(Box::new(progress_callback)),
-                    ) {
-                        Ok(_results) => {
-                            info!("Queue item {} completed successfully", item_id);
-                            queue_clone.mark_completed(item_id).await;
-                            if let Err(e) = app_clone.emit(queue_events::QUEUE_ITEM_COMPLETED, &item_id) {
-                                error!("Failed to emit queue-item-completed event: {}", e);
+                                if let Err(e) = emit_task_event(&app_clone, queue_events::QUEUE_ITEM_PROGRESS, &serde_json::json!({
+                                    "item_id": item_id,
+                                    "progress": progress.overall_progress,
+                                    "current_video": progress.current_title,
+                                    "total_videos": progress.total_videos,
+                                    "videos_completed": progress.videos_completed + progress.videos_skipped,
+                                })) {
+                                    error!("Failed to emit queue-item-progress event: {}", e);
+                                }
                             }
-                        }
-                        Err(e) => {
-                            error!("Queue item {} failed: {}", item_id, e);
-                            queue_clone.mark_failed(item_id, e.to_string()).await;
-                            if let Err(emit_err) = app_clone.emit(queue_events::QUEUE_ITEM_FAILED, &serde_json::json!({
+                        };
+
+                        // Dry runs write nothing, not even the output directory
+                        if !dry_run && let Err(e) = std::fs::create_dir_all(&output_dir) {
+                            error!("Failed to create output directory for queue item {}: {}", item_id, e);
+                            queue_clone.mark_failed(item_id, format!("Failed to create output directory: {e}")).await;
+                            let entry = history_entry().failed(format!("Failed to create output directory: {e}"));
+                            notify_webhook(&app_clone, &entry);
+                            record_history(&app_clone, entry);
+                            if let Err(emit_err) = emit_task_event(&app_clone, queue_events::QUEUE_ITEM_FAILED, &serde_json::json!({
                                 "item_id": item_id,
-                                "error": e.to_string()
+                                "error": format!("Failed to create output directory: {}", e)
                             })) {
                                 error!("Failed to emit queue-item-failed event: {}", emit_err);
                             }
+                            return;
+                        }
+
+                        let download = async {
+                            match playlist_info.videos.as_slice() {
+                                [video] if single_video => downloader
+                                    .download_single_video(video, &output_dir, Some(Box::new(progress_callback)), &cancel)
+                                    .await
+                                    .map(|result| vec![result]),
+                                _ => downloader.download_playlist(
+                                    &playlist_info,
+                                    &output_dir,
+                                    Some(Box::new(progress_callback)),
+                                    &cancel,
+                                ).await,
+                            }
+                        };
+                        // The progress ends once the download drops its callback
+                        let (downloaded, ()) = tokio::join!(download, forward_progress);
+                        match downloaded {
+                            Ok(results) if dry_run => {
+                                // Nothing was downloaded, so there's nothing to record
+                                info!("Queue item {} planned", item_id);
+                                queue_clone.mark_completed(item_id).await;
+                                let planned: Vec<_> = results.iter().map(|r| serde_json::json!({
+                                    "video_id": r.video.id,
+                                    "title": r.video.title,
+                                    "output_path": r.output_path,
+                                    "error": r.error,
+                                    "estimated_size_bytes": r.estimated_size_bytes,
+                                })).collect();
+                                if let Err(e) = emit_task_event(&app_clone, queue_events::QUEUE_ITEM_PLANNED, &serde_json::json!({
+                                    "item_id": item_id,
+                                    "estimated_size_bytes": results.iter().filter_map(|r| r.estimated_size_bytes).sum::<u64>(),
+                                    "videos": planned,
+                                })) {
+                                    error!("Failed to emit queue-item-planned event: {}", e);
+                                }
+                            }
+                            Ok(results) => {
+                                info!("Queue item {} completed successfully", item_id);
+                                queue_clone.mark_completed(item_id).await;
+                                let entry = history_entry().with_download_results(&results);
+                                notify_webhook(&app_clone, &entry);
+                                record_history(&app_clone, entry);
+                                record_downloaded_tracks(&app_clone, &playlist_name, &results);
+                                if let Err(e) = emit_task_event(&app_clone, queue_events::QUEUE_ITEM_COMPLETED, &item_id) {
+                                    error!("Failed to emit queue-item-completed event: {}", e);
+                                }
+                            }
+                            Err(e) if e.code() == ErrorCode::Cancelled => {
+                                info!("Queue item {} cancelled", item_id);
+                                record_history(&app_clone, history_entry().cancelled());
+                                // Already marked if cancelled through the queue
+                                if queue_clone.cancel(item_id).await
+                                    && let Err(emit_err) = emit_task_event(&app_clone, queue_events::QUEUE_ITEM_CANCELLED, &item_id)
+                                {
+                                    error!("Failed to emit queue-item-cancelled event: {}", emit_err);
+                                }
+                            }
+                            Err(e) => {
+                                error!("Queue item {} failed: {}", item_id, e);
+                                queue_clone.mark_failed(item_id, e.to_string()).await;
+                                let entry = history_entry().failed(e.to_string());
+                                notify_webhook(&app_clone, &entry);
+                                record_history(&app_clone, entry);
+                                if let Err(emit_err) = emit_task_event(&app_clone, queue_events::QUEUE_ITEM_FAILED, &serde_json::json!({
+                                    "item_id": item_id,
+                                    "error": e.to_string()
+                                })) {
+                                    error!("Failed to emit queue-item-failed event: {}", emit_err);
+                                }
+                            }
                         }
                     }
+                    .await;
+
+                    download_tasks
+                        .write()
+                        .await
+                        .retain(|_, control| !control.same_download(&task_control));
                 },
             );
+            registered.insert(task_id, control);
+            drop(registered);
 
             queue.mark_started(item_id, task_id).await;
         }
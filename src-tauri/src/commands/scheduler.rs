@@ -0,0 +1,247 @@
+//! Scheduled refreshes of library playlists from their YouTube source.
+//!
+//! A scheduler checks the refresh schedules of the configuration every
+//! minute and downloads the new videos of each due playlist, as
+//! [`update_playlist_from_source`](super::youtube::update_playlist_from_source)
+//! would. Refreshes are skipped in offline mode and while heavy work is
+//! paused, and run once conditions clear. Each refresh is announced with
+//! `scheduled-refresh-started` and `scheduled-refresh-completed`.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use tauri::{AppHandle, Emitter, Manager, State};
+use tracing::{debug, error, info, warn};
+use youtun4_core::ProtectedOperation;
+use youtun4_core::schedule::{PlaylistSchedule, RefreshSchedule, due_refreshes};
+
+use crate::runtime::TaskId;
+
+use super::config::apply_config_change;
+use super::error::{CommandError, CommandResult, map_err};
+use super::parental::require_parent_pin;
+use super::state::AppState;
+use super::youtube::{DownloadResultPayload, spawn_library_download};
+
+/// Event names for scheduler events.
+pub mod scheduler_events {
+    /// Emitted when a scheduled refresh starts.
+    pub const SCHEDULED_REFRESH_STARTED: &str = "scheduled-refresh-started";
+    /// Emitted when a scheduled refresh finishes, successfully or not.
+    pub const SCHEDULED_REFRESH_COMPLETED: &str = "scheduled-refresh-completed";
+}
+
+/// How often the schedules are checked.
+const SCHEDULER_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A playlist's refresh schedule along with when it next runs.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ScheduledRefresh {
+    /// Playlist name.
+    pub playlist: String,
+    /// The playlist's schedule.
+    #[serde(flatten)]
+    pub schedule: PlaylistSchedule,
+    /// When the playlist is next refreshed (seconds since the Unix epoch).
+    pub next_run_at: u64,
+}
+
+/// Payload of scheduled refresh events.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ScheduledRefreshPayload {
+    /// Playlist being refreshed.
+    pub playlist: String,
+    /// Task ID of the refresh's download.
+    pub task_id: TaskId,
+    /// Outcome of the download, once the refresh finished.
+    pub result: Option<DownloadResultPayload>,
+}
+
+/// Get the refresh schedules of every playlist, next to run first.
+#[tauri::command]
+pub async fn get_refresh_schedules(
+    state: State<'_, AppState>,
+) -> CommandResult<Vec<ScheduledRefresh>> {
+    let config_manager = state.config_manager.read().await;
+    Ok(scheduled_refreshes(
+        &config_manager.config().refresh_schedules,
+    ))
+}
+
+/// Set the refresh schedule of a playlist, or remove it when `schedule` is
+/// not given.
+///
+/// The playlist must have a YouTube source. Changing the schedule keeps the
+/// time of the last refresh. In simple mode, `pin` must be the parent PIN
+/// for refreshes to move tracks removed from the source to the trash.
+#[tauri::command]
+pub async fn set_refresh_schedule(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    playlist: String,
+    schedule: Option<RefreshSchedule>,
+    remove_missing: bool,
+    pin: Option<String>,
+) -> CommandResult<()> {
+    info!("Setting refresh schedule of '{}': {:?}", playlist, schedule);
+    if schedule.is_some() && remove_missing {
+        require_parent_pin(&state, ProtectedOperation::DeleteTracks, pin).await?;
+    }
+    if schedule.is_some() {
+        let saved = state
+            .playlist_manager
+            .read()
+            .await
+            .get_saved_metadata(&playlist)
+            .map_err(map_err)?;
+        if saved.source_url.is_none() {
+            return Err(CommandError::invalid_input(format!(
+                "Playlist '{playlist}' has no YouTube source to refresh from"
+            )));
+        }
+    }
+
+    apply_config_change(&app, &state, |manager| {
+        let previous = manager.config().refresh_schedules.get(&playlist).copied();
+        let schedule = schedule.map(|schedule| match previous {
+            Some(previous) => PlaylistSchedule {
+                schedule,
+                remove_missing,
+                ..previous
+            },
+            None => PlaylistSchedule::new(schedule, remove_missing, unix_now()),
+        });
+        manager.set_refresh_schedule(&playlist, schedule)
+    })
+    .await?;
+    Ok(())
+}
+
+/// Check the refresh schedules in the background until the app shuts down.
+///
+/// Changes to the schedules are picked up on the next check.
+pub fn start_refresh_scheduler(app: &AppHandle) {
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = handle.state::<AppState>();
+        while !state.is_shutting_down() {
+            tokio::time::sleep(SCHEDULER_POLL_INTERVAL).await;
+            run_due_refreshes(&handle, &state).await;
+        }
+    });
+}
+
+/// Start the refreshes that are due.
+async fn run_due_refreshes(app: &AppHandle, state: &State<'_, AppState>) {
+    let due = due_refreshes(
+        &state.config_manager.read().await.config().refresh_schedules,
+        unix_now(),
+    );
+    if due.is_empty() {
+        return;
+    }
+    if state.offline_mode().await {
+        debug!(
+            "Offline mode, postponing {} scheduled refresh(es)",
+            due.len()
+        );
+        return;
+    }
+    if let Some(reason) = state.heavy_work_pause_reason().await {
+        debug!("Postponing {} scheduled refresh(es): {}", due.len(), reason);
+        return;
+    }
+
+    for playlist in due {
+        if let Err(e) = start_refresh(app, state, &playlist).await {
+            warn!("Scheduled refresh of '{}' failed to start: {}", playlist, e);
+        }
+    }
+}
+
+/// Start the scheduled refresh of `playlist`, and announce it when it
+/// finishes.
+async fn start_refresh(
+    app: &AppHandle,
+    state: &State<'_, AppState>,
+    playlist: &str,
+) -> CommandResult<()> {
+    // Recorded first, so a refresh that fails to start isn't retried until
+    // its next run
+    let (remove_missing, source_url) = {
+        let mut config_manager = state.config_manager.write().await;
+        config_manager
+            .record_scheduled_refresh(playlist, unix_now())
+            .map_err(map_err)?;
+        let remove_missing = config_manager
+            .config()
+            .refresh_schedules
+            .get(playlist)
+            .is_some_and(|schedule| schedule.remove_missing);
+        drop(config_manager);
+        let saved = state
+            .playlist_manager
+            .read()
+            .await
+            .get_saved_metadata(playlist)
+            .map_err(map_err)?;
+        (remove_missing, saved.source_url)
+    };
+    let source_url = source_url.ok_or_else(|| {
+        CommandError::invalid_input(format!(
+            "Playlist '{playlist}' has no YouTube source to refresh from"
+        ))
+    })?;
+
+    info!("Starting scheduled refresh of '{}'", playlist);
+    let (done_tx, done_rx) = tokio::sync::oneshot::channel();
+    let task_id = spawn_library_download(
+        app,
+        state,
+        source_url,
+        playlist.to_string(),
+        remove_missing,
+        Some(done_tx),
+    )
+    .await?;
+
+    let mut payload = ScheduledRefreshPayload {
+        playlist: playlist.to_string(),
+        task_id,
+        result: None,
+    };
+    if let Err(e) = app.emit(scheduler_events::SCHEDULED_REFRESH_STARTED, &payload) {
+        error!("Failed to emit scheduled-refresh-started event: {}", e);
+    }
+
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        payload.result = done_rx.await.ok();
+        info!("Scheduled refresh of '{}' finished", payload.playlist);
+        if let Err(e) = handle.emit(scheduler_events::SCHEDULED_REFRESH_COMPLETED, &payload) {
+            error!("Failed to emit scheduled-refresh-completed event: {}", e);
+        }
+    });
+    Ok(())
+}
+
+/// The `schedules` with when they next run, next to run first.
+fn scheduled_refreshes(schedules: &BTreeMap<String, PlaylistSchedule>) -> Vec<ScheduledRefresh> {
+    let mut refreshes: Vec<ScheduledRefresh> = schedules
+        .iter()
+        .map(|(playlist, schedule)| ScheduledRefresh {
+            playlist: playlist.clone(),
+            schedule: *schedule,
+            next_run_at: schedule.next_run_at(),
+        })
+        .collect();
+    refreshes.sort_by_key(|refresh| refresh.next_run_at);
+    refreshes
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
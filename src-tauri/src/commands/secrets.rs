@@ -0,0 +1,76 @@
+//! Secrets commands.
+//!
+//! Secret values are write-only from the frontend: they can be set, cleared
+//! and checked for presence, but never read back over IPC.
+
+use tauri::State;
+use tracing::{debug, info};
+use youtun4_core::Error;
+use youtun4_core::notifications::webhook;
+use youtun4_core::secrets::secret_keys;
+
+use super::error::{CommandResult, map_err};
+use super::state::AppState;
+
+/// Reject keys that are not in the known set.
+fn validate_secret_key(key: &str) -> CommandResult<()> {
+    if secret_keys::ALL.contains(&key) {
+        Ok(())
+    } else {
+        Err(map_err(Error::secrets(format!(
+            "Unknown secret key: {key}"
+        ))))
+    }
+}
+
+/// Store a secret value. The webhook URL must be an http(s) URL.
+#[tauri::command]
+pub async fn set_secret(
+    state: State<'_, AppState>,
+    key: String,
+    value: String,
+) -> CommandResult<()> {
+    validate_secret_key(&key)?;
+    if key == secret_keys::WEBHOOK_URL {
+        webhook::validate_url(&value).map_err(map_err)?;
+    }
+    info!("Storing secret '{}'", key);
+
+    let secrets = state.secrets.clone();
+    tokio::task::spawn_blocking(move || secrets.set(&key, &value))
+        .await
+        .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))
+        .map_err(map_err)
+}
+
+/// Delete a secret value. Returns `true` if a value was removed.
+#[tauri::command]
+pub async fn delete_secret(state: State<'_, AppState>, key: String) -> CommandResult<bool> {
+    validate_secret_key(&key)?;
+    info!("Deleting secret '{}'", key);
+
+    let secrets = state.secrets.clone();
+    tokio::task::spawn_blocking(move || secrets.delete(&key))
+        .await
+        .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))
+        .map_err(map_err)
+}
+
+/// Check whether a secret is set.
+#[tauri::command]
+pub async fn has_secret(state: State<'_, AppState>, key: String) -> CommandResult<bool> {
+    validate_secret_key(&key)?;
+    debug!("Checking secret '{}'", key);
+
+    let secrets = state.secrets.clone();
+    tokio::task::spawn_blocking(move || secrets.contains(&key))
+        .await
+        .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))
+        .map_err(map_err)
+}
+
+/// Get the name of the active secrets backend (`keychain` or `encrypted_file`).
+#[tauri::command]
+pub fn get_secrets_backend(state: State<'_, AppState>) -> String {
+    state.secrets.backend_name().to_string()
+}
@@ -0,0 +1,83 @@
+//! Cancelling all running work and exiting safely.
+//!
+//! Quitting while tasks run cancels them and waits for them to stop at their
+//! next file boundary before the process exits, so a quit in the middle of a
+//! sync never leaves half-written files on the device.
+
+use std::time::Duration;
+
+use tauri::{AppHandle, ExitRequestApi, Manager, State};
+use tracing::{error, info, warn};
+
+use super::error::CommandResult;
+use super::events::emit_task_event;
+use super::queue::queue_events;
+use super::state::{AppState, CancelledTasks};
+
+/// Longest time an exit waits for cancelled tasks to stop.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often an exit checks whether cancelled tasks have stopped.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Cancel every running task and notify the frontend about cancelled queue items.
+async fn cancel_everything(app: &AppHandle, state: &AppState) -> CancelledTasks {
+    let cancelled = state.cancel_all_tasks().await;
+    for item_id in &cancelled.queue_items {
+        if let Err(e) = emit_task_event(app, queue_events::QUEUE_ITEM_CANCELLED, item_id) {
+            error!("Failed to emit queue-item-cancelled event: {}", e);
+        }
+    }
+    cancelled
+}
+
+/// Stop everything: cancel all runtime tasks, downloads, syncs, queued
+/// downloads and device cleanups.
+#[tauri::command]
+pub async fn cancel_all_tasks(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> CommandResult<CancelledTasks> {
+    Ok(cancel_everything(&app, &state).await)
+}
+
+/// Delay an exit until running tasks have been cancelled and stopped.
+///
+/// Exits requested while nothing runs, or once shutdown has begun, go ahead.
+pub fn handle_exit_requested(app: &AppHandle, api: &ExitRequestApi) {
+    let state = app.state::<AppState>();
+    if state.is_shutting_down() || state.active_tasks().is_empty() {
+        return;
+    }
+
+    api.prevent_exit();
+    if !state.begin_shutdown() {
+        return;
+    }
+
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = handle.state::<AppState>();
+        info!(
+            "Exit requested with {} task(s) running, cancelling them",
+            state.active_tasks().len()
+        );
+        cancel_everything(&handle, &state).await;
+
+        let wait = async {
+            while !state.active_tasks().is_empty() {
+                tokio::time::sleep(SHUTDOWN_POLL_INTERVAL).await;
+            }
+        };
+        if tokio::time::timeout(SHUTDOWN_TIMEOUT, wait).await.is_err() {
+            warn!(
+                "{} task(s) still running after {:?}, exiting anyway",
+                state.active_tasks().len(),
+                SHUTDOWN_TIMEOUT
+            );
+        } else {
+            info!("All tasks stopped, exiting");
+        }
+        handle.exit(0);
+    });
+}
@@ -6,13 +6,33 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use tokio::sync::RwLock;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 use youtun4_core::{
     Error, Result,
-    config::ConfigManager,
+    audio::TranscodeOptions,
+    cache::CacheManager,
+    change_log::ChangeLog,
+    config::{ConfigManager, ConfigOverrides},
+    crash::CrashReporter,
     device::{DeviceManager, DeviceWatcherHandle, PlatformMountHandler},
-    playlist::PlaylistManager,
-    queue::DownloadQueueManager,
+    history::HistoryStore,
+    i18n::Locale,
+    lyrics::LyricsConfig,
+    parental::ParentalControls,
+    playlist::{DownloadProfile, PlaylistManager},
+    power::{PauseReason, PowerState},
+    queue::{
+        DownloadQueueManager, QueueItemId, QueueItemStatus, default_queue_state_path,
+        load_saved_queue,
+    },
+    secrets::SecretsStore,
+    storage::LibrarySpace,
+    task_journal::{PersistedTask, PersistedTaskKind, TaskJournal, TrackedTask},
+    trash::Trash,
+    youtube::{
+        DownloadControl, sponsorblock::SponsorBlockConfig, template::FilenameTemplate,
+        yt_dlp::ExtractionConfig,
+    },
 };
 
 use crate::runtime::{AsyncRuntime, ProgressSender, TaskCategory, TaskId, TaskStatus};
@@ -35,8 +55,31 @@ pub struct SyncTaskInfo {
 /// Type alias for sync task storage to reduce complexity.
 type SyncTaskMap = HashMap<TaskId, (SyncTaskInfo, Arc<AtomicBool>)>;
 
-/// Type alias for download task storage (task_id -> cancel flag).
-type DownloadTaskMap = HashMap<TaskId, Arc<AtomicBool>>;
+/// Type alias for download task storage (task_id -> download control).
+type DownloadTaskMap = HashMap<TaskId, DownloadControl>;
+
+/// What a [`AppState::cancel_all_tasks`] call cancelled.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct CancelledTasks {
+    /// Cancellable runtime tasks signalled.
+    pub runtime_tasks: usize,
+    /// Downloads whose cancel flag was set.
+    pub downloads: usize,
+    /// Syncs whose cancel flag was set.
+    pub syncs: usize,
+    /// Queue items that were cancelled.
+    pub queue_items: Vec<QueueItemId>,
+    /// Device cleanups whose cancel flag was set.
+    pub cleanups: usize,
+}
+
+impl CancelledTasks {
+    /// Total number of cancelled tasks.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.runtime_tasks + self.downloads + self.syncs + self.queue_items.len() + self.cleanups
+    }
+}
 
 /// Application state managed by Tauri.
 pub struct AppState {
@@ -54,23 +97,65 @@ pub struct AppState {
     pub(crate) mount_handler: Arc<PlatformMountHandler>,
     /// Active sync tasks with their cancellation tokens.
     pub(crate) sync_tasks: Arc<RwLock<SyncTaskMap>>,
-    /// Active download tasks with their cancellation flags.
+    /// Active download tasks with their cancel and pause controls.
     pub(crate) download_tasks: Arc<RwLock<DownloadTaskMap>>,
+    /// Cancellation flags of running device cleanups.
+    pub(crate) cleanup_flags: Arc<RwLock<Vec<Arc<AtomicBool>>>>,
     /// Download queue manager for handling multiple playlist downloads.
     pub(crate) download_queue: Arc<DownloadQueueManager>,
+    /// Secure storage for sensitive settings.
+    pub(crate) secrets: Arc<SecretsStore>,
+    /// Parent PIN checks, with a lockout after repeated wrong guesses.
+    pub(crate) parental: Arc<ParentalControls>,
+    /// Persistent journal of running tasks, for recovery after a crash.
+    pub(crate) task_journal: Arc<TaskJournal>,
+    /// Last crash report, written by the panic hook.
+    pub(crate) crash_reporter: Arc<CrashReporter>,
+    /// History of finished downloads and syncs.
+    pub(crate) history: Arc<HistoryStore>,
+    /// Journal of library changes.
+    pub(crate) change_log: Arc<ChangeLog>,
+    /// Playlists and tracks deleted this session, for undo.
+    pub(crate) trash: Arc<Trash>,
+    /// Whether the main window is hidden and the app runs from the tray.
+    pub(crate) in_background: Arc<AtomicBool>,
+    /// Whether a quit was requested and the app is waiting for tasks to end.
+    pub(crate) quitting: Arc<AtomicBool>,
+    /// Whether the app is exiting and running tasks were cancelled.
+    pub(crate) shutting_down: Arc<AtomicBool>,
+    /// Last detected power state.
+    pub(crate) power_state: Arc<RwLock<PowerState>>,
+    /// Whether the download queue was paused by the power monitor rather
+    /// than by the user.
+    pub(crate) power_paused_queue: Arc<AtomicBool>,
 }
 
 impl AppState {
     /// Create a new application state using configuration.
     ///
+    /// `overrides` (from env vars and CLI flags) are applied on top of the
+    /// config file.
+    ///
     /// # Errors
     ///
     /// Returns an error if the config, playlist manager, or async runtime cannot be created.
-    pub fn new() -> Result<Self> {
-        let config_manager = ConfigManager::new()?;
+    pub fn new(overrides: ConfigOverrides) -> Result<Self> {
+        let config_manager = ConfigManager::with_overrides(overrides)?;
         let playlists_dir = config_manager.playlists_directory().to_path_buf();
         let queue_config = config_manager.config().queue.clone();
 
+        // Nothing is running yet, so anything left in the temp area is debris
+        // from a crash or forced quit
+        match CacheManager::new(config_manager.config().cache.clone())
+            .and_then(|mut cache| cache.cleanup_temp())
+        {
+            Ok(stats) if stats.bytes_freed > 0 => {
+                info!("Removed {} bytes of leftover temp files", stats.bytes_freed)
+            }
+            Ok(_) => {}
+            Err(e) => warn!("Failed to clean up leftover temp files: {}", e),
+        }
+
         info!(
             "Playlists directory from config: {}",
             playlists_dir.display()
@@ -83,9 +168,33 @@ impl AppState {
 
         info!("Async runtime initialized successfully");
 
-        let download_queue = DownloadQueueManager::with_config(queue_config);
+        let queue_path = default_queue_state_path();
+        let saved_items = load_saved_queue(&queue_path);
+
+        let task_journal = TaskJournal::open_default()?;
+        // The queue restores its own interrupted downloads, so they aren't
+        // offered twice
+        for task in task_journal.interrupted() {
+            if let PersistedTaskKind::QueueDownload { request } = &task.kind
+                && saved_items.iter().any(|item| {
+                    item.status == QueueItemStatus::Interrupted && item.request == *request
+                })
+                && let Err(e) = task_journal.resolve(task.id)
+            {
+                warn!("Failed to resolve interrupted queue download: {}", e);
+            }
+        }
+        info!(
+            "Task journal initialized ({} interrupted tasks)",
+            task_journal.interrupted().len()
+        );
+
+        let download_queue = DownloadQueueManager::restore(queue_config, saved_items, queue_path);
         info!("Download queue manager initialized");
 
+        let secrets = SecretsStore::open_default();
+        info!("Secrets store initialized ({})", secrets.backend_name());
+
         Ok(Self {
             config_manager: Arc::new(RwLock::new(config_manager)),
             device_manager: Arc::new(RwLock::new(DeviceManager::new())),
@@ -95,7 +204,20 @@ impl AppState {
             mount_handler: Arc::new(PlatformMountHandler::new()),
             sync_tasks: Arc::new(RwLock::new(HashMap::new())),
             download_tasks: Arc::new(RwLock::new(HashMap::new())),
+            cleanup_flags: Arc::new(RwLock::new(Vec::new())),
             download_queue: Arc::new(download_queue),
+            secrets: Arc::new(secrets),
+            parental: Arc::new(ParentalControls::new()),
+            task_journal: Arc::new(task_journal),
+            crash_reporter: Arc::new(CrashReporter::open_default()),
+            history: Arc::new(HistoryStore::open_default()),
+            change_log: Arc::new(ChangeLog::open_default()),
+            trash: Arc::new(Trash::open_default()),
+            in_background: Arc::new(AtomicBool::new(false)),
+            quitting: Arc::new(AtomicBool::new(false)),
+            shutting_down: Arc::new(AtomicBool::new(false)),
+            power_state: Arc::new(RwLock::new(PowerState::default())),
+            power_paused_queue: Arc::new(AtomicBool::new(false)),
         })
     }
 
@@ -107,6 +229,209 @@ impl AppState {
         Ok(())
     }
 
+    /// Check whether offline mode is enabled in the current configuration.
+    pub async fn offline_mode(&self) -> bool {
+        self.config_manager.read().await.config().offline_mode
+    }
+
+    /// Measure the library's disk usage against the configured limits.
+    pub async fn library_space(&self) -> LibrarySpace {
+        let (library_dir, limits) = {
+            let config_manager = self.config_manager.read().await;
+            (
+                config_manager.playlists_directory().to_path_buf(),
+                config_manager.config().library_limits,
+            )
+        };
+        tokio::task::spawn_blocking(move || LibrarySpace::measure(&library_dir, limits))
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Failed to measure library space: {}", e);
+                LibrarySpace {
+                    library_bytes: 0,
+                    available_bytes: None,
+                    limits,
+                }
+            })
+    }
+
+    /// Record a task in the journal so it can be recovered if the app stops
+    /// before it ends. Journaling failures are logged and never block the task.
+    pub fn track_task(
+        &self,
+        kind: PersistedTaskKind,
+        description: impl Into<String>,
+    ) -> Option<TrackedTask> {
+        self.task_journal
+            .track(kind, description)
+            .inspect_err(|e| warn!("Failed to journal task: {}", e))
+            .ok()
+    }
+
+    /// Tasks currently running, from the task journal.
+    pub fn active_tasks(&self) -> Vec<PersistedTask> {
+        self.task_journal.running()
+    }
+
+    // =========================================================================
+    // Background-mode lifecycle
+    // =========================================================================
+
+    /// Mark the app as running in the background (main window hidden) or not.
+    pub fn set_in_background(&self, in_background: bool) {
+        if self.in_background.swap(in_background, Ordering::SeqCst) != in_background {
+            info!(
+                "Application moved to the {}",
+                if in_background {
+                    "background"
+                } else {
+                    "foreground"
+                }
+            );
+        }
+    }
+
+    /// Check whether the app is running in the background.
+    pub fn is_in_background(&self) -> bool {
+        self.in_background.load(Ordering::SeqCst)
+    }
+
+    /// Start quitting. Returns `false` if a quit was already in progress.
+    pub fn begin_quit(&self) -> bool {
+        !self.quitting.swap(true, Ordering::SeqCst)
+    }
+
+    /// Check whether the app is quitting.
+    pub fn is_quitting(&self) -> bool {
+        self.quitting.load(Ordering::SeqCst)
+    }
+
+    /// Start shutting down. Returns `false` if a shutdown was already in progress.
+    pub fn begin_shutdown(&self) -> bool {
+        !self.shutting_down.swap(true, Ordering::SeqCst)
+    }
+
+    /// Check whether the app is shutting down.
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    /// Why heavy background work should currently be paused, if it should.
+    pub async fn heavy_work_pause_reason(&self) -> Option<PauseReason> {
+        let power_state = *self.power_state.read().await;
+        self.config_manager
+            .read()
+            .await
+            .config()
+            .power
+            .pause_reason(&power_state)
+    }
+
+    /// The language for text the backend renders itself.
+    pub async fn locale(&self) -> Locale {
+        self.config_manager.read().await.config().locale()
+    }
+
+    /// Get the cache temp area used for intermediate files.
+    pub async fn temp_directory(&self) -> PathBuf {
+        self.config_manager
+            .read()
+            .await
+            .config()
+            .cache
+            .temp_directory()
+    }
+
+    /// MP3 conversion options for downloads, at `audio_quality` kbps when
+    /// given and otherwise at the quality configured for `playlist`.
+    pub async fn transcode_options(
+        &self,
+        playlist: Option<&str>,
+        audio_quality: Option<&str>,
+    ) -> TranscodeOptions {
+        if let Some(bitrate_kbps) = audio_quality.and_then(|quality| quality.parse().ok()) {
+            return TranscodeOptions::with_bitrate(bitrate_kbps);
+        }
+        let config_manager = self.config_manager.read().await;
+        let config = config_manager.config();
+        let quality = playlist.map_or(config.download_quality, |name| {
+            config.download_quality_for(name)
+        });
+        TranscodeOptions::with_bitrate(quality.bitrate_kbps())
+    }
+
+    /// Number of videos of a playlist downloaded at the same time.
+    pub async fn download_concurrency(&self) -> usize {
+        self.config_manager
+            .read()
+            .await
+            .config()
+            .download_concurrency
+    }
+
+    /// Download speed limit in bytes per second, if any.
+    pub async fn max_download_speed(&self) -> Option<u64> {
+        self.config_manager
+            .read()
+            .await
+            .config()
+            .max_download_speed_bps
+    }
+
+    /// Whether downloads get ID3 tags.
+    pub async fn write_tags(&self) -> bool {
+        self.config_manager.read().await.config().write_tags
+    }
+
+    /// Whether downloads get the video thumbnail as cover art.
+    pub async fn embed_cover_art(&self) -> bool {
+        self.config_manager.read().await.config().embed_cover_art
+    }
+
+    /// Download profile of the library playlist `name`, if it has one.
+    pub async fn download_profile(&self, name: &str) -> Option<DownloadProfile> {
+        self.playlist_manager
+            .read()
+            .await
+            .get_download_profile(name)
+            .inspect_err(|e| debug!("No download profile for '{}': {}", name, e))
+            .ok()
+            .flatten()
+    }
+
+    /// How playlists and videos are read from `YouTube`.
+    pub async fn extraction_config(&self) -> ExtractionConfig {
+        self.config_manager.read().await.config().extraction.clone()
+    }
+
+    /// Template new downloads are named from. An invalid template, which
+    /// validation keeps out of saved settings, falls back to the default.
+    pub async fn filename_template(&self) -> FilenameTemplate {
+        let config_manager = self.config_manager.read().await;
+        FilenameTemplate::parse(&config_manager.config().filename_template).unwrap_or_else(|e| {
+            warn!("Using the default filename template: {}", e);
+            FilenameTemplate::default()
+        })
+    }
+
+    /// Lyrics settings for new downloads into `playlist`, if lyrics are
+    /// fetched for it.
+    pub async fn lyrics_config(&self, playlist: Option<&str>) -> Option<LyricsConfig> {
+        let config_manager = self.config_manager.read().await;
+        let config = config_manager.config();
+        match playlist {
+            Some(name) => config.lyrics_for(name),
+            None => config.lyrics.enabled.then(|| config.lyrics.clone()),
+        }
+    }
+
+    /// `SponsorBlock` settings for new downloads, if segment removal is enabled.
+    pub async fn sponsorblock_config(&self) -> Option<SponsorBlockConfig> {
+        let config_manager = self.config_manager.read().await;
+        let sponsorblock = &config_manager.config().sponsorblock;
+        sponsorblock.enabled.then(|| sponsorblock.clone())
+    }
+
     /// Get a reference to the async runtime.
     pub fn runtime(&self) -> &AsyncRuntime {
         &self.runtime
@@ -201,10 +526,10 @@ impl AppState {
         Arc::clone(&self.download_queue)
     }
 
-    /// Register a download task with its cancellation flag.
-    pub async fn register_download_task(&self, task_id: TaskId, cancel_flag: Arc<AtomicBool>) {
+    /// Register a download task with its control.
+    pub async fn register_download_task(&self, task_id: TaskId, control: DownloadControl) {
         let mut tasks = self.download_tasks.write().await;
-        tasks.insert(task_id, cancel_flag);
+        tasks.insert(task_id, control);
     }
 
     /// Unregister a download task (called when download completes or fails).
@@ -217,8 +542,8 @@ impl AppState {
     /// Cancel a download task by task ID.
     pub async fn cancel_download_task(&self, task_id: TaskId) -> bool {
         let tasks = self.download_tasks.read().await;
-        if let Some(cancel_flag) = tasks.get(&task_id) {
-            cancel_flag.store(true, Ordering::SeqCst);
+        if let Some(control) = tasks.get(&task_id) {
+            control.cancel();
             info!("Download task {} cancellation requested", task_id);
             true
         } else {
@@ -226,4 +551,108 @@ impl AppState {
             false
         }
     }
+
+    /// Cancel the video a download task is downloading; the task continues
+    /// with its next video.
+    pub async fn cancel_download_video(&self, task_id: TaskId) -> bool {
+        let tasks = self.download_tasks.read().await;
+        if let Some(control) = tasks.get(&task_id) {
+            control.cancel_video();
+            info!("Download task {} video cancellation requested", task_id);
+            true
+        } else {
+            debug!("Download task {} not found for video cancellation", task_id);
+            false
+        }
+    }
+
+    /// Pause or resume a download task.
+    pub async fn set_download_paused(&self, task_id: TaskId, paused: bool) -> bool {
+        let tasks = self.download_tasks.read().await;
+        if let Some(control) = tasks.get(&task_id) {
+            if paused {
+                control.pause();
+            } else {
+                control.resume();
+            }
+            info!(
+                "Download task {} {}",
+                task_id,
+                if paused { "paused" } else { "resumed" }
+            );
+            true
+        } else {
+            debug!("Download task {} not found for pause/resume", task_id);
+            false
+        }
+    }
+
+    /// Register a device cleanup and get the flag that cancels it.
+    pub async fn register_cleanup(&self) -> Arc<AtomicBool> {
+        let flag = Arc::new(AtomicBool::new(false));
+        self.cleanup_flags.write().await.push(Arc::clone(&flag));
+        flag
+    }
+
+    /// Unregister a device cleanup once it ended.
+    pub async fn unregister_cleanup(&self, flag: &Arc<AtomicBool>) {
+        self.cleanup_flags
+            .write()
+            .await
+            .retain(|registered| !Arc::ptr_eq(registered, flag));
+    }
+
+    /// Cancel every running task: runtime tasks, downloads, syncs, unfinished
+    /// queue items and device cleanups.
+    ///
+    /// Downloads, syncs and cleanups stop at their next file boundary, so no
+    /// file is left half-written. When shutting down, queue items are
+    /// suspended instead, to be resumed on the next start.
+    pub async fn cancel_all_tasks(&self) -> CancelledTasks {
+        // Suspended before the downloads stop, so they end up interrupted
+        // rather than cancelled
+        let shutting_down = self.is_shutting_down();
+        if shutting_down {
+            self.download_queue.suspend().await;
+        }
+
+        let mut cancelled = CancelledTasks {
+            runtime_tasks: self.runtime.cancel_all().await,
+            ..CancelledTasks::default()
+        };
+
+        for control in self.download_tasks.read().await.values() {
+            if !control.is_cancelled() {
+                control.cancel();
+                cancelled.downloads += 1;
+            }
+        }
+        for (_, flag) in self.sync_tasks.read().await.values() {
+            if !flag.swap(true, Ordering::SeqCst) {
+                cancelled.syncs += 1;
+            }
+        }
+        if !shutting_down {
+            for item in self.download_queue.get_all_items().await {
+                if !item.is_finished() && self.download_queue.cancel(item.id).await {
+                    cancelled.queue_items.push(item.id);
+                }
+            }
+        }
+        for flag in self.cleanup_flags.read().await.iter() {
+            if !flag.swap(true, Ordering::SeqCst) {
+                cancelled.cleanups += 1;
+            }
+        }
+
+        info!(
+            "Cancelled all tasks: {} runtime, {} downloads, {} syncs, {} queue items, {} cleanups",
+            cancelled.runtime_tasks,
+            cancelled.downloads,
+            cancelled.syncs,
+            cancelled.queue_items.len(),
+            cancelled.cleanups
+        );
+        cancelled
+    }
 }
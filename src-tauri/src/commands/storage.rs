@@ -0,0 +1,132 @@
+//! Library storage commands and disk usage guardrails.
+
+use std::path::PathBuf;
+
+use tauri::{AppHandle, Emitter, State};
+use tracing::{debug, error, info, warn};
+use youtun4_core::cache::{CacheManager, CacheStats};
+use youtun4_core::config::default_archive_directory;
+use youtun4_core::{Error, LibrarySpace};
+
+use super::error::{CommandResult, map_err};
+use super::state::AppState;
+
+/// Event names for storage events emitted to the frontend.
+pub mod storage_events {
+    /// Event emitted when a download is refused because of library space limits.
+    pub const LIBRARY_SPACE_LOW: &str = "library-space-low";
+}
+
+/// Payload for the library-space-low event.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LibrarySpaceLowPayload {
+    /// Current library usage and limits.
+    pub space: LibrarySpace,
+    /// Human-readable reason.
+    pub message: String,
+}
+
+/// Check the library limits before starting a download.
+///
+/// Emits `library-space-low` and returns the error if a limit is reached.
+pub(crate) async fn ensure_library_space(app: &AppHandle, state: &AppState) -> Result<(), Error> {
+    let space = state.library_space().await;
+    let Err(e) = space.check() else {
+        return Ok(());
+    };
+
+    warn!("Library space guardrail triggered: {}", e);
+    let payload = LibrarySpaceLowPayload {
+        space,
+        message: e.to_string(),
+    };
+    if let Err(emit_err) = app.emit(storage_events::LIBRARY_SPACE_LOW, &payload) {
+        error!("Failed to emit library-space-low event: {}", emit_err);
+    }
+    Err(e)
+}
+
+/// Get the library's disk usage and configured limits.
+#[tauri::command]
+pub async fn get_library_space(state: State<'_, AppState>) -> CommandResult<LibrarySpace> {
+    debug!("Getting library space");
+    Ok(state.library_space().await)
+}
+
+/// Disk usage of one playlist.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PlaylistUsage {
+    /// Playlist name.
+    pub name: String,
+    /// Size of the playlist folder in bytes.
+    pub bytes: u64,
+    /// Number of tracks in the playlist.
+    pub track_count: usize,
+}
+
+/// Everything the storage overview shows.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct StorageOverview {
+    /// Library usage, free disk space and limits.
+    pub library: LibrarySpace,
+    /// Per-playlist usage, largest first.
+    pub playlists: Vec<PlaylistUsage>,
+    /// Cache usage.
+    pub cache: CacheStats,
+    /// Directory playlists are stored in.
+    pub playlists_directory: PathBuf,
+    /// Directory archived playlists are moved to.
+    pub archive_directory: PathBuf,
+}
+
+/// Get the library size per playlist, cache usage and free disk space.
+#[tauri::command]
+pub async fn get_storage_overview(state: State<'_, AppState>) -> CommandResult<StorageOverview> {
+    debug!("Getting storage overview");
+
+    let library = state.library_space().await;
+    let (playlists_directory, cache_config) = {
+        let config_manager = state.config_manager.read().await;
+        (
+            config_manager.playlists_directory().to_path_buf(),
+            config_manager.config().cache.clone(),
+        )
+    };
+
+    let mut playlists: Vec<PlaylistUsage> = state
+        .playlist_manager
+        .read()
+        .await
+        .list_playlists()
+        .map_err(map_err)?
+        .into_iter()
+        .map(|playlist| PlaylistUsage {
+            name: playlist.name,
+            bytes: playlist.total_bytes,
+            track_count: playlist.track_count,
+        })
+        .collect();
+    playlists.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+
+    let cache = CacheManager::new(cache_config).map_err(map_err)?.stats();
+
+    Ok(StorageOverview {
+        library,
+        playlists,
+        cache,
+        playlists_directory,
+        archive_directory: default_archive_directory(),
+    })
+}
+
+/// Move a playlist out of the library into the archive directory.
+///
+/// Returns the archived folder.
+#[tauri::command]
+pub async fn archive_playlist(state: State<'_, AppState>, name: String) -> CommandResult<PathBuf> {
+    info!("Archiving playlist: {}", name);
+    let manager = state.playlist_manager.read().await;
+    manager
+        .archive_playlist(&name, &default_archive_directory())
+        .map_err(map_err)
+}
@@ -4,17 +4,25 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
-use tauri::{AppHandle, Emitter, State};
+use tauri::{AppHandle, State};
 use tracing::{debug, error, info};
 use youtun4_core::Error;
+use youtun4_core::history::HistoryEntry;
+use youtun4_core::task_journal::PersistedTaskKind;
 use youtun4_core::transfer::{TransferOptions, TransferProgress};
 
-use crate::runtime::{TaskCategory, TaskId};
+use crate::runtime::{TaskCategory, TaskId, TaskResource};
 
-use super::error::map_err;
+use super::change_log::record_sync_performed;
+use super::error::{CommandResult, map_err};
+use super::events::emit_task_event;
+use super::history::record_history;
 use super::state::{AppState, SyncTaskInfo};
 
 /// Event names for sync events emitted to the frontend.
+///
+/// Deprecated aliases: every event here is also emitted as a unified `task-event`,
+/// which new frontend code should listen to instead.
 pub mod sync_events {
     /// Event emitted when a sync operation starts.
     pub const SYNC_STARTED: &str = "sync-started";
@@ -107,7 +115,7 @@ pub async fn start_sync(
     device_mount_point: String,
     verify_integrity: bool,
     skip_existing: bool,
-) -> std::result::Result<TaskId, String> {
+) -> CommandResult<TaskId> {
     info!(
         "Starting sync: playlist '{}' -> device '{}' (verify={}, skip_existing={})",
         playlist_name, device_mount_point, verify_integrity, skip_existing
@@ -133,6 +141,11 @@ pub async fn start_sync(
         Some(format!("Sync '{playlist_name}' to '{device_mount_point}'")),
         async {},
     );
+    let slot = state.runtime().admit(
+        task_id,
+        TaskCategory::FileTransfer,
+        Some(TaskResource::device(&mount_point)),
+    )?;
 
     let sync_info = SyncTaskInfo {
         task_id,
@@ -145,10 +158,20 @@ pub async fn start_sync(
         .register_sync_task(task_id, sync_info.clone(), cancel_token)
         .await;
 
-    if let Err(e) = app.emit(sync_events::SYNC_STARTED, &sync_info) {
+    if let Err(e) = emit_task_event(&app, sync_events::SYNC_STARTED, &sync_info) {
         error!("Failed to emit sync-started event: {}", e);
     }
 
+    let tracked = state.track_task(
+        PersistedTaskKind::Sync {
+            playlist_name: playlist_name.clone(),
+            device_mount_point: mount_point,
+            verify_integrity,
+            skip_existing,
+        },
+        format!("Sync '{playlist_name}' to '{device_mount_point}'"),
+    );
+
     let playlist_name_clone = playlist_name.clone();
     let device_mount_point_clone = device_mount_point.clone();
     let app_handle = app.clone();
@@ -156,6 +179,8 @@ pub async fn start_sync(
     let sync_tasks = Arc::clone(&state.sync_tasks);
 
     tokio::spawn(async move {
+        let _tracked = tracked;
+        let _slot = slot;
         let playlist_name_for_progress = playlist_name_clone.clone();
         let device_mount_point_for_progress = device_mount_point_clone.clone();
         let app_handle_for_progress = app_handle.clone();
@@ -168,7 +193,11 @@ pub async fn start_sync(
                 &device_mount_point_for_progress,
                 progress,
             );
-            if let Err(e) = app_handle_for_progress.emit(sync_events::SYNC_PROGRESS, &payload) {
+            if let Err(e) = emit_task_event(
+                &app_handle_for_progress,
+                sync_events::SYNC_PROGRESS,
+                &payload,
+            ) {
                 error!("Failed to emit sync-progress event: {}", e);
             }
         };
@@ -187,8 +216,30 @@ pub async fn start_sync(
             tasks.remove(&task_id);
         }
 
+        let history_entry =
+            HistoryEntry::sync(vec![playlist_name_clone.clone()], &device_mount_point_clone);
         match result {
             Ok(transfer_result) => {
+                if !transfer_result.was_cancelled {
+                    record_sync_performed(
+                        &app_handle,
+                        vec![playlist_name_clone.clone()],
+                        &device_mount_point_clone,
+                    );
+                }
+                record_history(
+                    &app_handle,
+                    if transfer_result.was_cancelled {
+                        history_entry.cancelled()
+                    } else {
+                        history_entry.with_counts(
+                            transfer_result.files_transferred,
+                            transfer_result.files_failed,
+                            transfer_result.bytes_transferred,
+                        )
+                    },
+                );
+
                 let payload = SyncResultPayload {
                     task_id,
                     success: transfer_result.success,
@@ -224,12 +275,13 @@ pub async fn start_sync(
                     sync_events::SYNC_FAILED
                 };
 
-                if let Err(e) = app_handle.emit(event, &payload) {
+                if let Err(e) = emit_task_event(&app_handle, event, &payload) {
                     error!("Failed to emit {} event: {}", event, e);
                 }
             }
             Err(e) => {
                 error!("Sync task {} failed with error: {}", task_id, e);
+                record_history(&app_handle, history_entry.failed(e.to_string()));
                 let payload = SyncResultPayload {
                     task_id,
                     success: false,
@@ -245,7 +297,7 @@ pub async fn start_sync(
                     error_message: Some(e.to_string()),
                 };
 
-                if let Err(e) = app_handle.emit(sync_events::SYNC_FAILED, &payload) {
+                if let Err(e) = emit_task_event(&app_handle, sync_events::SYNC_FAILED, &payload) {
                     error!("Failed to emit sync-failed event: {}", e);
                 }
             }
@@ -258,10 +310,7 @@ pub async fn start_sync(
 
 /// Cancel a running sync operation.
 #[tauri::command]
-pub async fn cancel_sync(
-    state: State<'_, AppState>,
-    task_id: TaskId,
-) -> std::result::Result<bool, String> {
+pub async fn cancel_sync(state: State<'_, AppState>, task_id: TaskId) -> CommandResult<bool> {
     info!("Cancelling sync task {}", task_id);
     let cancelled = state.cancel_sync_task(task_id).await;
     if cancelled {
@@ -277,7 +326,7 @@ pub async fn cancel_sync(
 pub async fn get_sync_status(
     state: State<'_, AppState>,
     task_id: TaskId,
-) -> std::result::Result<Option<SyncTaskInfo>, String> {
+) -> CommandResult<Option<SyncTaskInfo>> {
     debug!("Getting sync status for task {}", task_id);
     let info = state.get_sync_task_info(task_id).await;
     Ok(info)
@@ -285,9 +334,7 @@ pub async fn get_sync_status(
 
 /// Get all currently active sync operations.
 #[tauri::command]
-pub async fn list_active_syncs(
-    state: State<'_, AppState>,
-) -> std::result::Result<Vec<SyncTaskInfo>, String> {
+pub async fn list_active_syncs(state: State<'_, AppState>) -> CommandResult<Vec<SyncTaskInfo>> {
     debug!("Listing active syncs");
     let syncs = state.list_sync_tasks().await;
     info!("Found {} active sync operations", syncs.len());
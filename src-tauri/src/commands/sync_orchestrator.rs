@@ -1,24 +1,34 @@
 //! Sync orchestrator commands for multi-playlist syncing.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 
-use tauri::{AppHandle, Emitter, State};
-use tracing::{error, info};
+use tauri::{AppHandle, State};
+use tracing::{error, info, warn};
 use youtun4_core::Error;
+use youtun4_core::device_usage::DeviceSyncRecord;
+use youtun4_core::history::HistoryEntry;
 use youtun4_core::sync::{
-    SyncOptions, SyncOrchestrator, SyncProgress, SyncRequest, SyncResult as CoreSyncResult,
+    SyncOptions, SyncOrchestrator, SyncPreview, SyncProgress, SyncRequest,
+    SyncResult as CoreSyncResult,
 };
 use youtun4_core::transfer::TransferOptions;
 
-use crate::runtime::{TaskCategory, TaskId};
+use crate::runtime::{TaskCategory, TaskId, TaskResource};
 
-use super::error::map_err;
+use super::change_log::record_sync_performed;
+use super::error::{CommandResult, map_err};
+use super::events::emit_task_event;
+use super::history::record_history;
+use super::notifications::notify_webhook;
 use super::state::{AppState, SyncTaskInfo};
 use super::sync::sync_events;
 
 /// Event names for sync orchestrator events.
+///
+/// Deprecated aliases: every event here is also emitted as a unified `task-event`,
+/// which new frontend code should listen to instead.
 pub mod sync_orchestrator_events {
     pub const SYNC_ORCHESTRATOR_PROGRESS: &str = "sync-orchestrator-progress";
     pub const SYNC_ORCHESTRATOR_COMPLETED: &str = "sync-orchestrator-completed";
@@ -27,7 +37,11 @@ pub mod sync_orchestrator_events {
 }
 
 /// Start a multi-playlist sync operation using the sync orchestrator.
+///
+/// `exclusions` lists device files, relative to the mount point, that the
+/// user chose to keep: they are neither deleted nor overwritten.
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn start_orchestrated_sync(
     app: AppHandle,
     state: State<'_, AppState>,
@@ -36,7 +50,8 @@ pub async fn start_orchestrated_sync(
     cleanup_enabled: bool,
     verify_integrity: bool,
     skip_existing: bool,
-) -> std::result::Result<TaskId, String> {
+    exclusions: Option<Vec<String>>,
+) -> CommandResult<TaskId> {
     info!(
         "Starting orchestrated sync: {} playlist(s) -> device '{}' (cleanup={}, verify={}, skip_existing={})",
         playlists.len(),
@@ -65,6 +80,11 @@ pub async fn start_orchestrated_sync(
             skip_existing,
             ..Default::default()
         },
+        exclusions: exclusions
+            .unwrap_or_default()
+            .into_iter()
+            .map(PathBuf::from)
+            .collect(),
         ..Default::default()
     };
 
@@ -80,6 +100,11 @@ pub async fn start_orchestrated_sync(
         )),
         async {},
     );
+    let slot = state.runtime().admit(
+        task_id,
+        TaskCategory::FileTransfer,
+        Some(TaskResource::device(&mount_point)),
+    )?;
 
     let sync_info = SyncTaskInfo {
         task_id,
@@ -92,7 +117,7 @@ pub async fn start_orchestrated_sync(
         .register_sync_task(task_id, sync_info.clone(), Arc::clone(&cancel_token))
         .await;
 
-    if let Err(e) = app.emit(sync_events::SYNC_STARTED, &sync_info) {
+    if let Err(e) = emit_task_event(&app, sync_events::SYNC_STARTED, &sync_info) {
         error!("Failed to emit sync-started event: {}", e);
     }
 
@@ -104,6 +129,7 @@ pub async fn start_orchestrated_sync(
     let sync_tasks = Arc::clone(&state.sync_tasks);
 
     tokio::spawn(async move {
+        let _slot = slot;
         let orchestrator = SyncOrchestrator::with_cancellation(cancel_token_clone);
         let request = SyncRequest::new(
             playlists_clone.clone(),
@@ -112,7 +138,8 @@ pub async fn start_orchestrated_sync(
 
         let app_handle_for_progress = app_handle.clone();
         let progress_callback = move |progress: &SyncProgress| {
-            if let Err(e) = app_handle_for_progress.emit(
+            if let Err(e) = emit_task_event(
+                &app_handle_for_progress,
                 sync_orchestrator_events::SYNC_ORCHESTRATOR_PROGRESS,
                 progress,
             ) {
@@ -139,8 +166,38 @@ pub async fn start_orchestrated_sync(
             tasks.remove(&task_id);
         }
 
+        let history_entry = HistoryEntry::sync(playlists_clone.clone(), &device_mount_point_clone);
         match result {
             Ok(sync_result) => {
+                let history_entry = if sync_result.was_cancelled {
+                    history_entry.cancelled()
+                } else {
+                    history_entry.with_counts(
+                        sync_result.total_files_transferred,
+                        sync_result.total_files_failed,
+                        sync_result.total_bytes_transferred,
+                    )
+                };
+                notify_webhook(&app_handle, &history_entry);
+                record_history(&app_handle, history_entry);
+
+                if !sync_result.was_cancelled {
+                    record_sync_performed(
+                        &app_handle,
+                        playlists_clone.clone(),
+                        &device_mount_point_clone,
+                    );
+                    let record = DeviceSyncRecord::new(
+                        playlists_clone,
+                        sync_result.total_files_transferred,
+                        sync_result.total_files_failed,
+                        sync_result.total_bytes_transferred,
+                    );
+                    if let Err(e) = record.write(Path::new(&device_mount_point_clone)) {
+                        warn!("Failed to record sync on device: {}", e);
+                    }
+                }
+
                 let event = if sync_result.was_cancelled {
                     info!("Orchestrated sync task {} was cancelled", task_id);
                     sync_orchestrator_events::SYNC_ORCHESTRATOR_CANCELLED
@@ -161,7 +218,7 @@ pub async fn start_orchestrated_sync(
                     sync_orchestrator_events::SYNC_ORCHESTRATOR_FAILED
                 };
 
-                if let Err(e) = app_handle.emit(event, &sync_result) {
+                if let Err(e) = emit_task_event(&app_handle, event, &sync_result) {
                     error!("Failed to emit {} event: {}", event, e);
                 }
             }
@@ -170,6 +227,9 @@ pub async fn start_orchestrated_sync(
                     "Orchestrated sync task {} failed with error: {}",
                     task_id, e
                 );
+                let history_entry = history_entry.failed(e.to_string());
+                notify_webhook(&app_handle, &history_entry);
+                record_history(&app_handle, history_entry);
                 let error_result = CoreSyncResult {
                     success: false,
                     was_cancelled: false,
@@ -185,7 +245,8 @@ pub async fn start_orchestrated_sync(
                     error_message: Some(e.to_string()),
                 };
 
-                if let Err(emit_err) = app_handle.emit(
+                if let Err(emit_err) = emit_task_event(
+                    &app_handle,
                     sync_orchestrator_events::SYNC_ORCHESTRATOR_FAILED,
                     &error_result,
                 ) {
@@ -202,6 +263,34 @@ pub async fn start_orchestrated_sync(
     Ok(task_id)
 }
 
+/// Preview what an orchestrated sync with these settings would change on the
+/// device, without touching it.
+#[tauri::command]
+pub async fn preview_sync(
+    state: State<'_, AppState>,
+    playlists: Vec<String>,
+    device_mount_point: String,
+    cleanup_enabled: bool,
+    skip_existing: bool,
+) -> CommandResult<SyncPreview> {
+    let mount_point = PathBuf::from(&device_mount_point);
+    if !mount_point.is_dir() {
+        return Err(map_err(Error::device_not_found(&device_mount_point)));
+    }
+
+    let options = SyncOptions {
+        cleanup_enabled,
+        transfer_options: TransferOptions {
+            skip_existing,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let request = SyncRequest::new(playlists, mount_point);
+    let playlist_manager = state.playlist_manager.read().await;
+    youtun4_core::sync::preview_sync(&playlist_manager, &request, &options).map_err(map_err)
+}
+
 /// Perform a synchronous sync operation (blocking).
 #[tauri::command]
 pub async fn sync_playlists_to_device(
@@ -210,7 +299,7 @@ pub async fn sync_playlists_to_device(
     playlists: Vec<String>,
     device_mount_point: String,
     options: SyncOptions,
-) -> std::result::Result<CoreSyncResult, String> {
+) -> CommandResult<CoreSyncResult> {
     info!(
         "Syncing {} playlist(s) to device '{}' (synchronous)",
         playlists.len(),
@@ -230,11 +319,12 @@ pub async fn sync_playlists_to_device(
     }
 
     let orchestrator = SyncOrchestrator::new();
-    let request = SyncRequest::new(playlists, mount_point);
+    let request = SyncRequest::new(playlists.clone(), mount_point);
 
     let app_handle = app.clone();
     let progress_callback = move |progress: &SyncProgress| {
-        if let Err(e) = app_handle.emit(
+        if let Err(e) = emit_task_event(
+            &app_handle,
             sync_orchestrator_events::SYNC_ORCHESTRATOR_PROGRESS,
             progress,
         ) {
@@ -245,7 +335,7 @@ pub async fn sync_playlists_to_device(
     let playlist_mgr = state.playlist_manager.read().await;
     let device_mgr = state.device_manager.read().await;
 
-    orchestrator
+    let result = orchestrator
         .sync(
             &playlist_mgr,
             &*device_mgr,
@@ -253,7 +343,11 @@ pub async fn sync_playlists_to_device(
             &options,
             Some(progress_callback),
         )
-        .map_err(map_err)
+        .map_err(map_err)?;
+    if !result.was_cancelled {
+        record_sync_performed(&app, playlists, &device_mount_point);
+    }
+    Ok(result)
 }
 
 /// Get default sync options for the orchestrator.
@@ -1,18 +1,25 @@
 //! Task management commands.
 
-use tauri::State;
-use tracing::{debug, info};
+use tauri::{AppHandle, State};
+use tracing::{debug, error, info};
+use youtun4_core::ErrorCode;
+use youtun4_core::task_journal::{JournalTaskId, PersistedTask, PersistedTaskKind};
 
 use crate::runtime::TaskId;
 
+use super::error::{CommandError, CommandResult, map_err};
+use super::events::emit_task_event;
+use super::queue::{process_queue, queue_events};
 use super::state::AppState;
+use super::sync::start_sync;
+use super::youtube::{download_youtube_playlist, download_youtube_to_playlist};
 
 /// Get the status of a running task.
 #[tauri::command]
 pub async fn get_task_status(
     state: State<'_, AppState>,
     task_id: TaskId,
-) -> std::result::Result<Option<String>, String> {
+) -> CommandResult<Option<String>> {
     let status = state.task_status(task_id).await;
     Ok(status.map(|s| format!("{s:?}")))
 }
@@ -22,10 +29,7 @@ pub async fn get_task_status(
 /// This checks both the async runtime's cancellable tasks and registered
 /// download tasks (which use an AtomicBool cancel flag pattern).
 #[tauri::command]
-pub async fn cancel_task(
-    state: State<'_, AppState>,
-    task_id: TaskId,
-) -> std::result::Result<bool, String> {
+pub async fn cancel_task(state: State<'_, AppState>, task_id: TaskId) -> CommandResult<bool> {
     info!("Cancelling task {}", task_id);
 
     // First try the async runtime's cancel mechanism
@@ -58,12 +62,115 @@ pub async fn cancel_task(
 
 /// Get all running tasks count by category.
 #[tauri::command]
-pub async fn get_running_tasks(
-    state: State<'_, AppState>,
-) -> std::result::Result<Vec<(String, usize)>, String> {
+pub async fn get_running_tasks(state: State<'_, AppState>) -> CommandResult<Vec<(String, usize)>> {
     let counts = state.runtime().running_tasks_count().await;
     Ok(counts
         .into_iter()
         .map(|(cat, count)| (cat.to_string(), count))
         .collect())
 }
+
+// =============================================================================
+// Interrupted task recovery
+// =============================================================================
+
+/// Find an interrupted task in the journal.
+fn find_interrupted(state: &AppState, id: JournalTaskId) -> CommandResult<PersistedTask> {
+    state
+        .task_journal
+        .interrupted()
+        .into_iter()
+        .find(|task| task.id == id)
+        .ok_or_else(|| {
+            CommandError::new(
+                ErrorCode::NotFound,
+                format!("No interrupted task with ID {id}"),
+            )
+        })
+}
+
+/// Get tasks that were interrupted by a crash or forced quit in a previous session.
+#[tauri::command]
+pub async fn get_interrupted_tasks(
+    state: State<'_, AppState>,
+) -> CommandResult<Vec<PersistedTask>> {
+    Ok(state.task_journal.interrupted())
+}
+
+/// Resume an interrupted task.
+///
+/// Downloads and syncs are restarted (files already in place are skipped) and
+/// queue downloads are queued again. Returns the new task ID, or the new queue
+/// item ID for queue downloads. The task stays in the journal if it cannot be
+/// restarted.
+#[tauri::command]
+pub async fn resume_interrupted_task(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    id: JournalTaskId,
+) -> CommandResult<u64> {
+    let task = find_interrupted(&state, id)?;
+    info!("Resuming interrupted task {}: {}", id, task.description);
+
+    let resumed = match task.kind {
+        PersistedTaskKind::PlaylistDownload { url, output_dir } => {
+            download_youtube_playlist(
+                app,
+                state.clone(),
+                url,
+                output_dir.to_string_lossy().into_owned(),
+                None,
+                None,
+            )
+            .await?
+        }
+        PersistedTaskKind::LibraryDownload { url, playlist_name } => {
+            download_youtube_to_playlist(app, state.clone(), url, playlist_name).await?
+        }
+        PersistedTaskKind::QueueDownload { request } => {
+            let queue = state.download_queue_arc();
+            let item_id = queue.add(request).await;
+            if let Some(item) = queue.get_item(item_id).await
+                && let Err(e) = emit_task_event(&app, queue_events::QUEUE_ITEM_ADDED, &item)
+            {
+                error!("Failed to emit queue-item-added event: {}", e);
+            }
+            process_queue(app, state.clone()).await;
+            item_id
+        }
+        PersistedTaskKind::Sync {
+            playlist_name,
+            device_mount_point,
+            verify_integrity,
+            skip_existing,
+        } => {
+            start_sync(
+                app,
+                state.clone(),
+                playlist_name,
+                device_mount_point.to_string_lossy().into_owned(),
+                verify_integrity,
+                skip_existing,
+            )
+            .await?
+        }
+    };
+
+    state.task_journal.resolve(id).map_err(map_err)?;
+    Ok(resumed)
+}
+
+/// Mark an interrupted task as failed, removing it from the journal.
+#[tauri::command]
+pub async fn dismiss_interrupted_task(
+    state: State<'_, AppState>,
+    id: JournalTaskId,
+) -> CommandResult<()> {
+    let task = find_interrupted(&state, id)?;
+    info!(
+        "Marking interrupted task {} as failed: {}",
+        id, task.description
+    );
+    state.task_journal.resolve(id).map_err(map_err)?;
+    Ok(())
+}
@@ -2,13 +2,26 @@
 
 use std::path::PathBuf;
 
-use tauri::{AppHandle, Emitter, State};
+use tauri::{AppHandle, State};
 use tracing::{debug, error, info};
 use youtun4_core::transfer::{TransferOptions, TransferProgress, TransferResult};
 
-use super::error::map_err;
+use crate::runtime::{TaskCategory, TaskResource};
+
+use super::change_log::record_sync_performed;
+use super::error::{CommandResult, map_err};
+use super::events::emit_task_event;
 use super::state::AppState;
 
+/// Event names for file transfer events emitted to the frontend.
+///
+/// Deprecated aliases: every event here is also emitted as a unified `task-event`,
+/// which new frontend code should listen to instead.
+pub mod transfer_events {
+    /// Event emitted for file transfer progress updates.
+    pub const TRANSFER_PROGRESS: &str = "transfer-progress";
+}
+
 /// Sync a playlist to a device with progress tracking.
 #[tauri::command]
 pub async fn sync_playlist_with_progress(
@@ -18,13 +31,19 @@ pub async fn sync_playlist_with_progress(
     device_mount_point: String,
     verify_integrity: bool,
     skip_existing: bool,
-) -> std::result::Result<TransferResult, String> {
+) -> CommandResult<TransferResult> {
     info!(
         "Syncing playlist '{}' to device at '{}' with progress tracking (verify={}, skip_existing={})",
         playlist_name, device_mount_point, verify_integrity, skip_existing
     );
 
     let mount_point = PathBuf::from(&device_mount_point);
+    let runtime = state.runtime();
+    let _slot = runtime.admit(
+        runtime.generate_task_id(),
+        TaskCategory::FileTransfer,
+        Some(TaskResource::device(&mount_point)),
+    )?;
 
     let options = TransferOptions {
         verify_integrity,
@@ -34,20 +53,24 @@ pub async fn sync_playlist_with_progress(
 
     let app_handle = app.clone();
     let progress_callback = move |progress: &TransferProgress| {
-        if let Err(e) = app_handle.emit("transfer-progress", progress) {
+        if let Err(e) = emit_task_event(&app_handle, transfer_events::TRANSFER_PROGRESS, progress) {
             error!("Failed to emit transfer-progress event: {}", e);
         }
     };
 
     let manager = state.playlist_manager.read().await;
-    manager
+    let result = manager
         .sync_to_device_with_progress(
             &playlist_name,
             &mount_point,
             &options,
             Some(progress_callback),
         )
-        .map_err(map_err)
+        .map_err(map_err)?;
+    if !result.was_cancelled {
+        record_sync_performed(&app, vec![playlist_name], &device_mount_point);
+    }
+    Ok(result)
 }
 
 /// Get default transfer options.
@@ -76,7 +99,7 @@ pub async fn transfer_files_to_device(
     source_files: Vec<String>,
     device_mount_point: String,
     options: TransferOptions,
-) -> std::result::Result<TransferResult, String> {
+) -> CommandResult<TransferResult> {
     info!(
         "Transferring {} files to device at '{}'",
         source_files.len(),
@@ -90,7 +113,7 @@ pub async fn transfer_files_to_device(
 
     let app_handle = app;
     let progress_callback = move |progress: &TransferProgress| {
-        if let Err(e) = app_handle.emit("transfer-progress", progress) {
+        if let Err(e) = emit_task_event(&app_handle, transfer_events::TRANSFER_PROGRESS, progress) {
             error!("Failed to emit transfer-progress event: {}", e);
         }
     };
@@ -108,7 +131,7 @@ pub async fn transfer_files_to_device(
 
 /// Compute the checksum of a file.
 #[tauri::command]
-pub async fn compute_file_checksum(file_path: String) -> std::result::Result<String, String> {
+pub async fn compute_file_checksum(file_path: String) -> CommandResult<String> {
     debug!("Computing checksum for: {}", file_path);
 
     let path = PathBuf::from(&file_path);
@@ -122,7 +145,7 @@ pub async fn compute_file_checksum(file_path: String) -> std::result::Result<Str
 pub async fn verify_file_integrity(
     source_path: String,
     destination_path: String,
-) -> std::result::Result<bool, String> {
+) -> CommandResult<bool> {
     debug!(
         "Verifying integrity: {} vs {}",
         source_path, destination_path
@@ -0,0 +1,348 @@
+//! System tray integration and background operation.
+//!
+//! The tray icon stays available while the main window is hidden, offers
+//! quick actions (show, sync the connected device, pause the queue, quit) and
+//! shows how many downloads are running. Quitting from the tray waits for
+//! active tasks to finish instead of killing them.
+
+use std::time::Duration;
+
+use tauri::menu::{CheckMenuItem, Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use tauri::{AppHandle, CloseRequestApi, Emitter, Listener, Manager, State, Window};
+use tracing::{debug, error, info, warn};
+use youtun4_core::ErrorCode;
+use youtun4_core::i18n::{Message, keys};
+
+use crate::runtime::TaskId;
+
+use super::device::list_devices;
+use super::error::{CommandError, CommandResult, map_err};
+use super::notifications::show;
+use super::queue::{queue_events, queue_pause, queue_resume};
+use super::state::AppState;
+use super::sync_orchestrator::start_orchestrated_sync;
+
+/// Event names for tray and background operation events.
+pub mod tray_events {
+    /// Emitted while a quit waits for active tasks, with the tasks still running.
+    pub const QUIT_PENDING: &str = "quit-pending";
+}
+
+const TRAY_ID: &str = "main";
+const MAIN_WINDOW: &str = "main";
+
+const MENU_SHOW: &str = "show";
+const MENU_SYNC_DEVICE: &str = "sync_device";
+const MENU_PAUSE_QUEUE: &str = "pause_queue";
+const MENU_QUIT: &str = "quit";
+
+/// How often the tray badge checks the number of running downloads.
+const BADGE_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How often a pending quit checks whether active tasks have finished.
+const QUIT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Create the tray icon and keep its menu and badge in sync with app state.
+pub fn install_tray(app: &AppHandle) -> tauri::Result<()> {
+    let show_item = MenuItem::with_id(app, MENU_SHOW, "Show Youtun4", true, None::<&str>)?;
+    let sync_item = MenuItem::with_id(
+        app,
+        MENU_SYNC_DEVICE,
+        "Sync connected device",
+        true,
+        None::<&str>,
+    )?;
+    let pause_item = CheckMenuItem::with_id(
+        app,
+        MENU_PAUSE_QUEUE,
+        "Pause queue",
+        true,
+        false,
+        None::<&str>,
+    )?;
+    let quit_item = MenuItem::with_id(app, MENU_QUIT, "Quit", true, None::<&str>)?;
+    let menu = Menu::with_items(
+        app,
+        &[
+            &show_item,
+            &sync_item,
+            &pause_item,
+            &PredefinedMenuItem::separator(app)?,
+            &quit_item,
+        ],
+    )?;
+
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .menu(&menu)
+        .tooltip("Youtun4")
+        .show_menu_on_left_click(false)
+        .on_menu_event(|app, event| handle_menu_event(app, event.id().as_ref()))
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                show_main_window(tray.app_handle());
+            }
+        });
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+
+    // Keep the "Pause queue" check mark in sync however the queue is paused
+    for (event, paused) in [
+        (queue_events::QUEUE_PAUSED, true),
+        (queue_events::QUEUE_RESUMED, false),
+    ] {
+        let pause_item = pause_item.clone();
+        app.listen(event, move |_| {
+            if let Err(e) = pause_item.set_checked(paused) {
+                warn!("Failed to update tray pause item: {}", e);
+            }
+        });
+    }
+
+    // Task events fire before a task leaves the journal, so poll for the badge
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut shown = 0;
+        loop {
+            let downloads = running_downloads(&handle);
+            // A pending quit shows its own tooltip
+            if downloads != shown && !handle.state::<AppState>().is_quitting() {
+                update_badge(&handle, downloads);
+                shown = downloads;
+            }
+            tokio::time::sleep(BADGE_REFRESH_INTERVAL).await;
+        }
+    });
+
+    info!("System tray installed");
+    Ok(())
+}
+
+/// Minimize to the tray instead of closing, if enabled, and make closing
+/// wait for active tasks.
+pub fn handle_close_requested(window: &Window, api: &CloseRequestApi) {
+    if window.label() != MAIN_WINDOW {
+        return;
+    }
+    let app = window.app_handle();
+    let state = app.state::<AppState>();
+    if state.is_quitting() {
+        return;
+    }
+
+    // Window events run on the main thread, so don't wait for a config write
+    let minimize_to_tray = state
+        .config_manager
+        .try_read()
+        .is_ok_and(|config_manager| config_manager.config().minimize_to_tray);
+
+    if minimize_to_tray {
+        api.prevent_close();
+        hide_main_window(app);
+    } else if !state.active_tasks().is_empty() {
+        api.prevent_close();
+        hide_main_window(app);
+        let handle = app.clone();
+        tauri::async_runtime::spawn(async move { quit_when_idle(handle).await });
+    }
+}
+
+fn handle_menu_event(app: &AppHandle, id: &str) {
+    debug!("Tray menu item selected: {}", id);
+    let handle = app.clone();
+    match id {
+        MENU_SHOW => show_main_window(app),
+        MENU_SYNC_DEVICE => {
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = sync_connected_device(&handle).await {
+                    warn!("Tray sync failed: {}", e);
+                    let locale = handle.state::<AppState>().locale().await;
+                    let title = Message::new(keys::NOTIFY_SYNC_FAILED).render(locale);
+                    let body = e.message_i18n.render(locale);
+                    if let Err(e) = show(&handle, &title, &body) {
+                        warn!("{}", e);
+                    }
+                }
+            });
+        }
+        MENU_PAUSE_QUEUE => {
+            tauri::async_runtime::spawn(async move {
+                let state = handle.state::<AppState>();
+                let result = if state.download_queue_arc().is_paused().await {
+                    queue_resume(handle.clone(), state).await
+                } else {
+                    queue_pause(handle.clone(), state).await
+                };
+                if let Err(e) = result {
+                    warn!("Failed to toggle queue from tray: {}", e);
+                }
+            });
+        }
+        MENU_QUIT => {
+            tauri::async_runtime::spawn(async move { quit_when_idle(handle).await });
+        }
+        _ => {}
+    }
+}
+
+/// Show and focus the main window, leaving background mode.
+pub(crate) fn show_main_window(app: &AppHandle) {
+    let Some(window) = app.get_webview_window(MAIN_WINDOW) else {
+        return;
+    };
+    if let Err(e) = window.show().and_then(|()| window.set_focus()) {
+        warn!("Failed to show main window: {}", e);
+    }
+    app.state::<AppState>().set_in_background(false);
+}
+
+fn hide_main_window(app: &AppHandle) {
+    let Some(window) = app.get_webview_window(MAIN_WINDOW) else {
+        return;
+    };
+    if let Err(e) = window.hide() {
+        warn!("Failed to hide main window: {}", e);
+    }
+    app.state::<AppState>().set_in_background(true);
+}
+
+/// Number of downloads currently running.
+fn running_downloads(app: &AppHandle) -> usize {
+    app.state::<AppState>()
+        .active_tasks()
+        .iter()
+        .filter(|task| task.kind.category() == "download")
+        .count()
+}
+
+/// Show the number of running downloads on the tray icon.
+fn update_badge(app: &AppHandle, downloads: usize) {
+    let Some(tray) = app.tray_by_id(TRAY_ID) else {
+        return;
+    };
+    let (title, tooltip) = match downloads {
+        0 => (None, "Youtun4".to_string()),
+        1 => (
+            Some("1".to_string()),
+            "Youtun4 - 1 download running".to_string(),
+        ),
+        n => (
+            Some(n.to_string()),
+            format!("Youtun4 - {n} downloads running"),
+        ),
+    };
+    if let Err(e) = tray
+        .set_title(title)
+        .and_then(|()| tray.set_tooltip(Some(tooltip)))
+    {
+        warn!("Failed to update tray badge: {}", e);
+    }
+}
+
+/// Sync every playlist to the preferred device, or the first connected one.
+async fn sync_connected_device(app: &AppHandle) -> CommandResult<TaskId> {
+    let state = app.state::<AppState>();
+    let preferred = state
+        .config_manager
+        .read()
+        .await
+        .config()
+        .preferred_device
+        .clone();
+
+    let devices = list_devices(state.clone()).await?;
+    let device = preferred
+        .and_then(|mount_point| devices.iter().find(|d| d.mount_point == mount_point))
+        .or_else(|| devices.first())
+        .ok_or_else(|| CommandError::new(ErrorCode::DeviceNotFound, "No device connected"))?;
+    let mount_point = device.mount_point.display().to_string();
+
+    let playlists: Vec<String> = state
+        .playlist_manager
+        .read()
+        .await
+        .list_playlists()
+        .map_err(map_err)?
+        .into_iter()
+        .map(|playlist| playlist.name)
+        .collect();
+
+    info!(
+        "Tray sync: {} playlist(s) -> '{}'",
+        playlists.len(),
+        mount_point
+    );
+    start_orchestrated_sync(
+        app.clone(),
+        state,
+        playlists,
+        mount_point,
+        false,
+        true,
+        true,
+        None,
+    )
+    .await
+}
+
+/// Stop starting new work, wait for running tasks to end, then exit.
+async fn quit_when_idle(app: AppHandle) {
+    let state = app.state::<AppState>();
+    if !state.begin_quit() {
+        return;
+    }
+    info!("Quit requested");
+
+    if let Err(e) = queue_pause(app.clone(), state.clone()).await {
+        warn!("Failed to pause queue before quitting: {}", e);
+    }
+
+    let mut last_count = None;
+    loop {
+        let running = state.active_tasks();
+        if running.is_empty() {
+            break;
+        }
+        if last_count != Some(running.len()) {
+            info!(
+                "Waiting for {} task(s) to finish before quitting",
+                running.len()
+            );
+            if let Some(tray) = app.tray_by_id(TRAY_ID)
+                && let Err(e) = tray.set_tooltip(Some(format!(
+                    "Youtun4 - quitting after {} task(s) finish",
+                    running.len()
+                )))
+            {
+                warn!("Failed to update tray tooltip: {}", e);
+            }
+            if let Err(e) = app.emit(tray_events::QUIT_PENDING, &running) {
+                error!("Failed to emit quit-pending event: {}", e);
+            }
+            last_count = Some(running.len());
+        }
+        tokio::time::sleep(QUIT_POLL_INTERVAL).await;
+    }
+
+    info!("No active tasks, exiting");
+    app.exit(0);
+}
+
+/// Quit once active tasks have finished, as the tray "Quit" item does.
+#[tauri::command]
+pub fn quit_when_tasks_finish(app: AppHandle) {
+    tauri::async_runtime::spawn(async move { quit_when_idle(app).await });
+}
+
+/// Check whether the app is running in the background (main window hidden).
+#[tauri::command]
+pub fn is_in_background(state: State<'_, AppState>) -> bool {
+    state.is_in_background()
+}
@@ -0,0 +1,195 @@
+//! Application update checks and installation.
+//!
+//! Updates come from the manifest of the configured release channel (see
+//! [`UpdateChannel::endpoint`]). Update packages are verified against the
+//! public key from the `updater` plugin config, which release builds can
+//! override through the `YOUTUN4_UPDATER_PUBKEY` variable at compile time;
+//! builds without a key cannot update.
+
+use serde_json::Value;
+use tauri::{AppHandle, Emitter, Manager, State, Url};
+use tauri_plugin_updater::{Update, UpdaterExt};
+use tracing::{debug, error, info, warn};
+use youtun4_core::{Error, ErrorCode, UpdateChannel};
+
+use super::error::{CommandError, CommandResult, map_err};
+use super::state::AppState;
+
+/// Event names for update events.
+pub mod update_events {
+    /// Emitted when a newer version is available on the configured channel.
+    pub const UPDATE_AVAILABLE: &str = "update-available";
+    /// Emitted while an update downloads.
+    pub const UPDATE_DOWNLOAD_PROGRESS: &str = "update-download-progress";
+}
+
+/// An available update.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UpdateInfo {
+    /// Version of the update.
+    pub version: String,
+    /// Currently installed version.
+    pub current_version: String,
+    /// Release date, if published in the manifest.
+    pub date: Option<String>,
+    /// Release notes, if published in the manifest.
+    pub notes: Option<String>,
+    /// Channel the update was found on.
+    pub channel: UpdateChannel,
+}
+
+impl UpdateInfo {
+    fn new(update: &Update, channel: UpdateChannel) -> Self {
+        Self {
+            version: update.version.clone(),
+            current_version: update.current_version.clone(),
+            date: update.date.map(|date| date.to_string()),
+            notes: update.body.clone(),
+            channel,
+        }
+    }
+}
+
+/// Progress of an update download.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UpdateDownloadProgress {
+    /// Bytes downloaded so far.
+    pub downloaded_bytes: u64,
+    /// Total size in bytes, if known.
+    pub total_bytes: Option<u64>,
+}
+
+/// Public key used to verify update packages.
+fn updater_pubkey(app: &AppHandle) -> Option<String> {
+    option_env!("YOUTUN4_UPDATER_PUBKEY")
+        .map(String::from)
+        .or_else(|| {
+            app.config()
+                .plugins
+                .0
+                .get("updater")
+                .and_then(|updater| updater.get("pubkey"))
+                .and_then(Value::as_str)
+                .map(String::from)
+        })
+        .filter(|key| !key.trim().is_empty())
+}
+
+fn update_error(e: &tauri_plugin_updater::Error) -> CommandError {
+    error!("Update error: {}", e);
+    CommandError::new(ErrorCode::Network, format!("Update failed: {e}"))
+}
+
+/// Look for an update on the configured channel.
+async fn find_update(
+    app: &AppHandle,
+    state: &AppState,
+) -> CommandResult<Option<(Update, UpdateChannel)>> {
+    if state.offline_mode().await {
+        return Err(map_err(Error::offline("Update check")));
+    }
+
+    let Some(pubkey) = updater_pubkey(app) else {
+        return Err(CommandError::new(
+            ErrorCode::Configuration,
+            "Updates are not available for this build",
+        ));
+    };
+
+    let channel = state.config_manager.read().await.config().update_channel;
+    let endpoint = Url::parse(channel.endpoint())
+        .map_err(|e| CommandError::from(format!("Invalid update endpoint: {e}")))?;
+
+    debug!("Checking for updates on the {} channel", channel);
+    let update = app
+        .updater_builder()
+        .pubkey(pubkey)
+        .endpoints(vec![endpoint])
+        .and_then(tauri_plugin_updater::UpdaterBuilder::build)
+        .map_err(|e| update_error(&e))?
+        .check()
+        .await
+        .map_err(|e| update_error(&e))?;
+
+    Ok(update.map(|update| (update, channel)))
+}
+
+/// Check for an update on the configured release channel.
+///
+/// Emits `update-available` when one is found.
+#[tauri::command]
+pub async fn check_for_updates(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> CommandResult<Option<UpdateInfo>> {
+    let Some((update, channel)) = find_update(&app, &state).await? else {
+        info!("No update available");
+        return Ok(None);
+    };
+
+    let info = UpdateInfo::new(&update, channel);
+    info!(
+        "Update available: {} -> {} ({} channel)",
+        info.current_version, info.version, channel
+    );
+    if let Err(e) = app.emit(update_events::UPDATE_AVAILABLE, &info) {
+        error!("Failed to emit update-available event: {}", e);
+    }
+    Ok(Some(info))
+}
+
+/// Download and install the available update, then restart.
+///
+/// Download progress is reported through `update-download-progress` events.
+/// Returns `false` if no update is available.
+#[tauri::command]
+pub async fn install_update(app: AppHandle, state: State<'_, AppState>) -> CommandResult<bool> {
+    let Some((update, _)) = find_update(&app, &state).await? else {
+        return Ok(false);
+    };
+
+    info!("Installing update {}", update.version);
+    let handle = app.clone();
+    let mut downloaded_bytes = 0u64;
+    update
+        .download_and_install(
+            move |chunk, total_bytes| {
+                downloaded_bytes += chunk as u64;
+                let progress = UpdateDownloadProgress {
+                    downloaded_bytes,
+                    total_bytes,
+                };
+                if let Err(e) = handle.emit(update_events::UPDATE_DOWNLOAD_PROGRESS, &progress) {
+                    error!("Failed to emit update-download-progress event: {}", e);
+                }
+            },
+            || info!("Update downloaded"),
+        )
+        .await
+        .map_err(|e| update_error(&e))?;
+
+    info!("Update installed, restarting");
+    app.restart()
+}
+
+/// Check for updates once in the background after startup.
+///
+/// Skipped for builds without an update key and in offline mode.
+pub fn schedule_update_check(app: &AppHandle) {
+    if updater_pubkey(app).is_none() {
+        debug!("Updates not available for this build, skipping update check");
+        return;
+    }
+
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = handle.state::<AppState>();
+        if state.offline_mode().await {
+            debug!("Offline mode, skipping update check");
+            return;
+        }
+        if let Err(e) = check_for_updates(handle.clone(), state).await {
+            warn!("Background update check failed: {}", e);
+        }
+    });
+}
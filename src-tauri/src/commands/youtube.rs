@@ -2,27 +2,63 @@
 
 use std::path::PathBuf;
 use std::sync::Arc;
-
-use tauri::{AppHandle, Emitter, State};
-use tracing::{debug, error, info};
-use youtun4_core::Error;
+use std::time::Instant;
+
+use tauri::{AppHandle, Manager, State};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tracing::{debug, error, info, warn};
+use youtun4_core::cache::CacheManager;
+use youtun4_core::fs::FileSystem;
+use youtun4_core::history::HistoryEntry;
+use youtun4_core::task_journal::PersistedTaskKind;
 use youtun4_core::youtube::{
-    DownloadProgress, DownloadStatus, PlaylistInfo, RustyYtdlConfig, RustyYtdlDownloader,
-    YouTubeDownloader, YouTubeUrlValidation, validate_youtube_url,
+    AsyncYouTubeDownloader, DownloadPlan, DownloadProgress, DownloadStatus,
+    PlaylistDownloadEstimate, PlaylistFetchProgress, PlaylistHealth, PlaylistInfo,
+    PlaylistSizeEstimate, RustyYtdlConfig, RustyYtdlDownloader, UrlCandidate, UrlCandidateKind,
+    VideoAvailability, YouTubeDownloader, YouTubeUrlValidation,
+    archive::DownloadArchive,
+    diff::{PlaylistDiff, diff_playlist},
+    extract_playlist_id, find_url_candidates,
+    quality::StreamPath,
+    search::{SearchFilter, SearchResult, search},
+    template::FilenameTemplate,
+    validate_youtube_url,
+    yt_dlp::{ExtractionBackend, YtDlpBackend},
 };
+use youtun4_core::{Error, ErrorCode, ProtectedOperation};
 
 use crate::runtime::{TaskCategory, TaskId};
 
-use super::error::map_err;
+use super::change_log::record_downloaded_tracks;
+use super::error::{CommandError, CommandResult, map_err};
+use super::events::emit_task_event;
+use super::history::record_history;
+use super::parental::require_parent_pin;
+use super::playlist::record_tracks_removed;
 use super::state::AppState;
+use super::storage::ensure_library_space;
 
 /// Event names for YouTube download events emitted to the frontend.
+///
+/// Deprecated aliases: every event here is also emitted as a unified `task-event`,
+/// which new frontend code should listen to instead.
 pub mod youtube_events {
     pub const DOWNLOAD_STARTED: &str = "youtube-download-started";
     pub const DOWNLOAD_PROGRESS: &str = "youtube-download-progress";
     pub const DOWNLOAD_COMPLETED: &str = "youtube-download-completed";
     pub const DOWNLOAD_FAILED: &str = "youtube-download-failed";
     pub const DOWNLOAD_CANCELLED: &str = "youtube-download-cancelled";
+    pub const DOWNLOAD_PAUSED: &str = "youtube-download-paused";
+    pub const DOWNLOAD_RESUMED: &str = "youtube-download-resumed";
+    pub const PLAYLIST_FETCH_PROGRESS: &str = "youtube-playlist-fetch-progress";
+}
+
+/// Serializable playlist paging progress for frontend.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PlaylistFetchProgressPayload {
+    pub task_id: TaskId,
+    pub pages_fetched: usize,
+    pub videos_found: usize,
 }
 
 /// Serializable download progress for frontend.
@@ -37,6 +73,7 @@ pub struct DownloadProgressPayload {
     pub status: String,
     pub current_bytes: u64,
     pub current_total_bytes: Option<u64>,
+    pub resumed_from_bytes: u64,
     pub total_bytes_downloaded: u64,
     pub download_speed_bps: f64,
     pub formatted_speed: String,
@@ -47,6 +84,7 @@ pub struct DownloadProgressPayload {
     pub videos_completed: usize,
     pub videos_skipped: usize,
     pub videos_failed: usize,
+    pub active_downloads: usize,
 }
 
 impl DownloadProgressPayload {
@@ -68,6 +106,7 @@ impl DownloadProgressPayload {
             },
             current_bytes: progress.current_bytes,
             current_total_bytes: progress.current_total_bytes,
+            resumed_from_bytes: progress.resumed_from_bytes,
             total_bytes_downloaded: progress.total_bytes_downloaded,
             download_speed_bps: progress.download_speed_bps,
             formatted_speed: progress.formatted_speed(),
@@ -78,6 +117,7 @@ impl DownloadProgressPayload {
             videos_completed: progress.videos_completed,
             videos_skipped: progress.videos_skipped,
             videos_failed: progress.videos_failed,
+            active_downloads: progress.active_downloads,
         }
     }
 }
@@ -96,6 +136,8 @@ pub enum YouTubeErrorCategory {
     AudioExtraction,
     FileSystem,
     Cancelled,
+    Offline,
+    LibrarySpace,
     Unknown,
 }
 
@@ -113,6 +155,8 @@ impl YouTubeErrorCategory {
             Self::AudioExtraction => "Audio Extraction Failed",
             Self::FileSystem => "File System Error",
             Self::Cancelled => "Cancelled",
+            Self::Offline => "Offline Mode",
+            Self::LibrarySpace => "Not Enough Space",
             Self::Unknown => "Error",
         }
     }
@@ -142,6 +186,12 @@ impl YouTubeErrorCategory {
             }
             Self::FileSystem => "Could not save the file. Please check disk space and permissions.",
             Self::Cancelled => "The download was cancelled.",
+            Self::Offline => {
+                "Offline mode is enabled. Disable it in settings to download from YouTube."
+            }
+            Self::LibrarySpace => {
+                "The library has reached its size limit or the disk is almost full. Free up space or adjust the storage limits in settings."
+            }
             Self::Unknown => "An unexpected error occurred.",
         }
     }
@@ -191,7 +241,14 @@ pub fn classify_error(error: &Error) -> YouTubeErrorCategory {
                         YouTubeErrorCategory::YouTubeService
                     }
                 }
+                DownloadError::SearchFailed { .. } | DownloadError::YtDlpFailed { .. } => {
+                    YouTubeErrorCategory::YouTubeService
+                }
                 DownloadError::Cancelled => YouTubeErrorCategory::Cancelled,
+                DownloadError::Offline { .. } => YouTubeErrorCategory::Offline,
+                DownloadError::LibraryLimitReached { .. } | DownloadError::LowDiskSpace { .. } => {
+                    YouTubeErrorCategory::LibrarySpace
+                }
             }
         }
         Error::FileSystem(_) => YouTubeErrorCategory::FileSystem,
@@ -213,6 +270,10 @@ pub struct DownloadResultPayload {
     pub error_category: Option<YouTubeErrorCategory>,
     pub error_title: Option<String>,
     pub error_description: Option<String>,
+    /// URL that was downloaded, so the UI can offer a retry.
+    pub source_url: Option<String>,
+    /// Library playlist the download was saved into, if any.
+    pub playlist_name: Option<String>,
 }
 
 /// Result of downloading a single video.
@@ -223,6 +284,17 @@ pub struct VideoDownloadResult {
     pub success: bool,
     pub output_path: Option<String>,
     pub error: Option<String>,
+    /// Seconds of `SponsorBlock` segments removed from the track.
+    pub sponsor_secs_removed: f64,
+    /// Audio bitrate of the downloaded stream in kbps, if known.
+    pub audio_bitrate_kbps: Option<u32>,
+    /// Audio codec of the downloaded stream, if known.
+    pub audio_codec: Option<String>,
+    /// Kind of stream the video was downloaded from.
+    pub stream_path: Option<StreamPath>,
+    /// Number of tracks the video was split into by chapters, 0 when it was
+    /// kept whole.
+    pub chapter_count: usize,
 }
 
 /// Validate a YouTube URL and extract playlist information.
@@ -252,7 +324,7 @@ pub fn is_valid_youtube_playlist_url(url: String) -> bool {
 
 /// Extract the playlist ID from a YouTube URL.
 #[tauri::command]
-pub fn extract_youtube_playlist_id(url: String) -> std::result::Result<String, String> {
+pub fn extract_youtube_playlist_id(url: String) -> CommandResult<String> {
     debug!("Extracting playlist ID from URL: {}", url);
     let result = validate_youtube_url(&url);
 
@@ -266,26 +338,306 @@ pub fn extract_youtube_playlist_id(url: String) -> std::result::Result<String, S
             .error_message
             .unwrap_or_else(|| "Invalid URL".to_string());
         info!("Failed to extract playlist ID: {}", error_message);
-        Err(error_message)
+        Err(CommandError::new(ErrorCode::InvalidUrl, error_message))
+    }
+}
+
+/// Find and classify the URLs in dropped text or a `text/uri-list`.
+#[tauri::command]
+pub fn parse_dropped_urls(text: String) -> Vec<UrlCandidate> {
+    let candidates = find_url_candidates(&text);
+    debug!(
+        "Found {} URL candidate(s) in dropped text",
+        candidates.len()
+    );
+    candidates
+}
+
+/// Find and classify the URLs in the clipboard.
+///
+/// Returns no candidates if the clipboard holds no text.
+#[tauri::command]
+pub fn read_clipboard_urls(app: AppHandle) -> Vec<UrlCandidate> {
+    let text = match app.clipboard().read_text() {
+        Ok(text) => text,
+        Err(e) => {
+            debug!("Clipboard has no readable text: {}", e);
+            return Vec::new();
+        }
+    };
+
+    let candidates = find_url_candidates(&text);
+    debug!(
+        "Found {} URL candidate(s) in clipboard ({} playlist(s))",
+        candidates.len(),
+        candidates
+            .iter()
+            .filter(|c| c.kind == UrlCandidateKind::Playlist)
+            .count()
+    );
+    candidates
+}
+
+/// Check if the downloader is available, naming the `yt-dlp` version used
+/// when the extraction settings use it.
+#[tauri::command]
+pub async fn check_yt_dlp_available(state: State<'_, AppState>) -> CommandResult<String> {
+    let extraction = state.extraction_config().await;
+    if extraction.backend == ExtractionBackend::PureRust {
+        info!("Checking downloader availability (pure Rust - always available)");
+        return Ok("rusty_ytdl (pure Rust)".to_string());
+    }
+
+    let backend = YtDlpBackend::new(extraction.yt_dlp_path);
+    let version = tokio::task::spawn_blocking(move || backend.version())
+        .await
+        .map_err(|e| format!("Task join error: {e}"))?;
+    match (version, extraction.backend) {
+        (Ok(version), ExtractionBackend::YtDlp) => Ok(format!("yt-dlp {version}")),
+        (Ok(version), _) => Ok(format!(
+            "rusty_ytdl (pure Rust), yt-dlp {version} as fallback"
+        )),
+        (Err(e), ExtractionBackend::YtDlp) => Err(map_err(e)),
+        (Err(e), _) => {
+            warn!("yt-dlp fallback unavailable: {}", e);
+            Ok("rusty_ytdl (pure Rust)".to_string())
+        }
     }
 }
 
-/// Check if the downloader is available.
+/// File name `template` gives an example track, so settings can show it
+/// before the template is saved.
 #[tauri::command]
-#[allow(clippy::unnecessary_wraps)]
-pub fn check_yt_dlp_available() -> std::result::Result<String, String> {
-    info!("Checking downloader availability (pure Rust - always available)");
-    Ok("rusty_ytdl (pure Rust)".to_string())
+pub fn preview_filename_template(template: String) -> CommandResult<String> {
+    FilenameTemplate::preview(&template).map_err(map_err)
 }
 
 /// Fetch playlist information from a YouTube URL.
+///
+/// Successful fetches are cached. In offline mode the cached info is returned
+/// instead, or an offline error if the playlist has never been fetched.
 #[tauri::command]
-pub async fn fetch_youtube_playlist_info(url: String) -> std::result::Result<PlaylistInfo, String> {
+pub async fn fetch_youtube_playlist_info(
+    state: State<'_, AppState>,
+    url: String,
+) -> CommandResult<PlaylistInfo> {
     info!("Fetching playlist info for URL: {}", url);
+    load_playlist_info(&state, url).await
+}
+
+/// Estimate the size and download time of a YouTube playlist at the
+/// configured download quality, before queueing it.
+#[tauri::command]
+pub async fn estimate_playlist_download(
+    state: State<'_, AppState>,
+    url: String,
+) -> CommandResult<PlaylistDownloadEstimate> {
+    info!("Estimating playlist download for URL: {}", url);
+    let info = load_playlist_info(&state, url).await?;
+    let quality = state.config_manager.read().await.config().download_quality;
+    Ok(info.estimate_download(quality.bitrate_kbps()))
+}
+
+/// Estimate the size and audio length of a YouTube playlist from the format
+/// metadata of a sample of its videos, at the quality the library playlist
+/// `playlist_name` (or the global settings) would download it.
+#[tauri::command]
+pub async fn estimate_playlist_size(
+    state: State<'_, AppState>,
+    url: String,
+    playlist_name: Option<String>,
+) -> CommandResult<PlaylistSizeEstimate> {
+    info!("Estimating playlist size for URL: {}", url);
+    let info = load_playlist_info(&state, url).await?;
+
+    let transcode = state
+        .transcode_options(playlist_name.as_deref(), None)
+        .await;
+    let mut config = RustyYtdlConfig {
+        offline: state.offline_mode().await,
+        audio_bitrate_kbps: transcode.bitrate_kbps,
+        transcode: Some(transcode),
+        extraction: state.extraction_config().await,
+        ..RustyYtdlConfig::default()
+    };
+    if let Some(name) = &playlist_name
+        && let Some(profile) = state.download_profile(name).await
+    {
+        config.apply_profile(&profile);
+    }
+    let cache_config = state.config_manager.read().await.config().cache.clone();
+    let downloader = RustyYtdlDownloader::with_config(config);
+    tokio::task::spawn_blocking(move || {
+        let mut cache = CacheManager::new(cache_config)
+            .inspect_err(|e| warn!("Size estimate cache unavailable: {}", e))
+            .ok();
+        downloader.estimate_playlist_size(&info, cache.as_mut())
+    })
+    .await
+    .map_err(|e| CommandError::from(format!("Task join error: {e}")))
+}
+
+/// Work out what downloading a YouTube playlist into the library playlist
+/// `playlist_name` would do, without downloading or writing anything: which
+/// videos are new and how big their files would be, for checking they fit
+/// on a device before syncing.
+#[tauri::command]
+pub async fn plan_playlist_download(
+    state: State<'_, AppState>,
+    url: String,
+    playlist_name: String,
+) -> CommandResult<DownloadPlan> {
+    info!("Planning download of {} into '{}'", url, playlist_name);
+    let info = load_playlist_info(&state, url).await?;
+    let output_dir = state
+        .playlist_manager
+        .read()
+        .await
+        .base_path()
+        .join(&playlist_name);
+
+    let transcode = state.transcode_options(Some(&playlist_name), None).await;
+    let mut config = RustyYtdlConfig {
+        offline: state.offline_mode().await,
+        audio_bitrate_kbps: transcode.bitrate_kbps,
+        transcode: Some(transcode),
+        extraction: state.extraction_config().await,
+        filename_template: state.filename_template().await,
+        dry_run: true,
+        ..RustyYtdlConfig::default()
+    };
+    if let Some(profile) = state.download_profile(&playlist_name).await {
+        config.apply_profile(&profile);
+    }
+    let downloader = RustyYtdlDownloader::with_config(config);
+    tokio::task::spawn_blocking(move || downloader.plan_playlist(&info, &output_dir))
+        .await
+        .map_err(|e| format!("Task join error: {e}"))?
+        .map_err(map_err)
+}
+
+/// Compare a library playlist's YouTube source with the playlist's download
+/// archive, to show what downloading it again would change.
+#[tauri::command]
+pub async fn preview_playlist_update(
+    state: State<'_, AppState>,
+    name: String,
+) -> CommandResult<PlaylistDiff> {
+    info!("Previewing update of playlist '{}'", name);
+    let playlist_manager = state.playlist_manager.read().await;
+    let saved = playlist_manager
+        .get_saved_metadata(&name)
+        .map_err(map_err)?;
+    let path = playlist_manager.get_playlist_path(&name).map_err(map_err)?;
+    drop(playlist_manager);
+
+    let source_url = saved.source_url.ok_or_else(|| {
+        CommandError::invalid_input(format!(
+            "Playlist '{name}' has no YouTube source to update from"
+        ))
+    })?;
+    let info = load_playlist_info(&state, source_url).await?;
+    Ok(diff_playlist(&info, &DownloadArchive::open(&path)))
+}
+
+/// A video of a playlist that can't be downloaded.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UnavailableVideo {
+    pub video_id: String,
+    pub title: String,
+    pub availability: VideoAvailability,
+}
+
+/// Availability of the videos of a playlist.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PlaylistHealthReport {
+    pub playlist_id: String,
+    pub title: String,
+    pub health: PlaylistHealth,
+    pub unavailable: Vec<UnavailableVideo>,
+}
+
+/// Check which videos of a YouTube playlist are private, deleted or blocked
+/// in this country, before downloading or refreshing it.
+#[tauri::command]
+pub async fn check_playlist_health(
+    state: State<'_, AppState>,
+    url: String,
+) -> CommandResult<PlaylistHealthReport> {
+    info!("Checking playlist health for URL: {}", url);
+    let info = load_playlist_info(&state, url).await?;
+    let unavailable = info
+        .unavailable_videos()
+        .map(|video| UnavailableVideo {
+            video_id: video.id.clone(),
+            title: video.title.clone(),
+            availability: video.availability,
+        })
+        .collect();
+    Ok(PlaylistHealthReport {
+        playlist_id: info.id,
+        title: info.title,
+        health: info.health,
+        unavailable,
+    })
+}
+
+/// Search YouTube for videos and playlists matching `query`.
+///
+/// Returns the first page of results, as ranked by YouTube.
+#[tauri::command]
+pub async fn search_youtube(
+    state: State<'_, AppState>,
+    query: String,
+    filter: Option<SearchFilter>,
+) -> CommandResult<Vec<SearchResult>> {
+    info!("Searching YouTube for: {}", query);
+    if state.offline_mode().await {
+        return Err(map_err(Error::offline("searching YouTube")));
+    }
+
+    let filter = filter.unwrap_or_default();
+    let result = tokio::task::spawn_blocking(move || search(&query, filter))
+        .await
+        .map_err(|e| format!("Task join error: {e}"))?;
+
+    result.map_err(map_err)
+}
+
+/// Fetch playlist info, caching it, or serve it from the cache when offline.
+async fn load_playlist_info(state: &AppState, url: String) -> CommandResult<PlaylistInfo> {
+    let config_manager = state.config_manager.read().await;
+    let offline = config_manager.config().offline_mode;
+    let cache_config = config_manager.config().cache.clone();
+    let extraction = config_manager.config().extraction.clone();
+    drop(config_manager);
 
     let result = tokio::task::spawn_blocking(move || {
-        let downloader = RustyYtdlDownloader::new();
-        downloader.parse_playlist_url(&url)
+        let mut cache = CacheManager::new(cache_config)
+            .inspect_err(|e| warn!("Playlist info cache unavailable: {}", e))
+            .ok();
+
+        if offline {
+            let playlist_id = extract_playlist_id(&url)?;
+            return cache
+                .as_mut()
+                .and_then(|c| c.get_playlist_info(&playlist_id).ok().flatten())
+                .ok_or_else(|| {
+                    Error::offline(format!("fetching playlist info for {playlist_id}"))
+                });
+        }
+
+        let downloader = RustyYtdlDownloader::with_config(RustyYtdlConfig {
+            extraction,
+            ..RustyYtdlConfig::default()
+        });
+        let info = downloader.parse_playlist_url(&url)?;
+        if let Some(cache) = cache.as_mut()
+            && let Err(e) = cache.put_playlist_info(&info)
+        {
+            warn!("Failed to cache playlist info for {}: {}", info.id, e);
+        }
+        Ok(info)
     })
     .await
     .map_err(|e| format!("Task join error: {e}"))?;
@@ -302,7 +654,7 @@ pub async fn download_youtube_playlist(
     output_dir: String,
     audio_quality: Option<String>,
     embed_thumbnail: Option<bool>,
-) -> std::result::Result<TaskId, String> {
+) -> CommandResult<TaskId> {
     info!(
         "Starting YouTube playlist download: {} -> {}",
         url, output_dir
@@ -310,43 +662,111 @@ pub async fn download_youtube_playlist(
 
     let validation = validate_youtube_url(&url);
     if !validation.is_valid {
-        return Err(validation
-            .error_message
-            .unwrap_or_else(|| "Invalid URL".to_string()));
+        return Err(CommandError::new(
+            ErrorCode::InvalidUrl,
+            validation
+                .error_message
+                .unwrap_or_else(|| "Invalid URL".to_string()),
+        ));
     }
 
+    ensure_library_space(&app, &state).await.map_err(map_err)?;
+
     let output_path = PathBuf::from(&output_dir);
+    let fs = state.playlist_manager.read().await.file_system();
 
-    if !output_path.exists() {
-        std::fs::create_dir_all(&output_path)
+    if !fs.exists(&output_path) {
+        fs.create_dir_all(&output_path)
             .map_err(|e| format!("Failed to create output directory: {e}"))?;
     }
 
-    let _ = audio_quality;
-    let _ = embed_thumbnail;
-
     let task_id = state.runtime().spawn(
         TaskCategory::Download,
         Some(format!("Download playlist: {url}")),
         async {},
     );
+    let slot = state
+        .runtime()
+        .admit(task_id, TaskCategory::Download, None)?;
 
     // Create the downloader and register its cancel flag before spawning
-    let config = RustyYtdlConfig::default();
-    let downloader = RustyYtdlDownloader::with_config(config);
-    let cancel_flag = downloader.cancel_flag();
-    state.register_download_task(task_id, cancel_flag).await;
+    let transcode = state
+        .transcode_options(None, audio_quality.as_deref())
+        .await;
+    let config = RustyYtdlConfig {
+        offline: state.offline_mode().await,
+        temp_dir: Some(state.temp_directory().await),
+        audio_bitrate_kbps: transcode.bitrate_kbps,
+        transcode: Some(transcode),
+        sponsorblock: state.sponsorblock_config().await,
+        concurrency: state.download_concurrency().await,
+        max_speed_bps: state.max_download_speed().await,
+        write_tags: state.write_tags().await,
+        embed_cover_art: match embed_thumbnail {
+            Some(embed) => embed,
+            None => state.embed_cover_art().await,
+        },
+        lyrics: state.lyrics_config(None).await,
+        extraction: state.extraction_config().await,
+        filename_template: state.filename_template().await,
+        ..RustyYtdlConfig::default()
+    };
+    let downloader = Arc::new(RustyYtdlDownloader::with_config(config));
+    let cancel = downloader.control().cancellation_token();
+    state
+        .register_download_task(task_id, downloader.control())
+        .await;
+
+    let tracked = state.track_task(
+        PersistedTaskKind::PlaylistDownload {
+            url: url.clone(),
+            output_dir: output_path.clone(),
+        },
+        format!("Download playlist: {url}"),
+    );
 
     let url_clone = url;
     let app_handle = app;
     let download_tasks = Arc::clone(&state.download_tasks);
+    // Downloads into a directory are listed in the history under its name
+    let history_name = output_path.file_name().map_or_else(
+        || output_dir.clone(),
+        |name| name.to_string_lossy().into_owned(),
+    );
 
-    std::thread::spawn(move || {
-        if let Err(e) = app_handle.emit(youtube_events::DOWNLOAD_STARTED, &task_id) {
+    tauri::async_runtime::spawn(async move {
+        let _tracked = tracked;
+        let _slot = slot;
+        if let Err(e) = emit_task_event(&app_handle, youtube_events::DOWNLOAD_STARTED, &task_id) {
             error!("Failed to emit download-started event: {}", e);
         }
 
-        let playlist_info = match downloader.parse_playlist_url(&url_clone) {
+        let app_handle_for_paging = app_handle.clone();
+        let on_page = move |progress: &PlaylistFetchProgress| {
+            let payload = PlaylistFetchProgressPayload {
+                task_id,
+                pages_fetched: progress.pages_fetched,
+                videos_found: progress.videos_found,
+            };
+            if let Err(e) = emit_task_event(
+                &app_handle_for_paging,
+                youtube_events::PLAYLIST_FETCH_PROGRESS,
+                &payload,
+            ) {
+                error!("Failed to emit playlist-fetch-progress event: {}", e);
+            }
+        };
+
+        let fetched = {
+            let downloader = Arc::clone(&downloader);
+            let url = url_clone.clone();
+            tokio::task::spawn_blocking(move || {
+                downloader.parse_playlist_url_with_progress(&url, &on_page)
+            })
+            .await
+            .unwrap_or_else(|e| Err(Error::internal(format!("Task join error: {e}"))))
+        };
+        let playlist_info = match fetched {
             Ok(info) => info,
             Err(e) => {
                 error!("Failed to parse playlist: {}", e);
@@ -363,10 +783,16 @@ pub async fn download_youtube_playlist(
                     error_category: Some(category),
                     error_title: Some(category.title().to_string()),
                     error_description: Some(category.description().to_string()),
+                    source_url: Some(url_clone.clone()),
+                    playlist_name: None,
                 };
-                if let Err(emit_err) = app_handle.emit(youtube_events::DOWNLOAD_FAILED, &payload) {
+                if let Err(emit_err) =
+                    emit_task_event(&app_handle, youtube_events::DOWNLOAD_FAILED, &payload)
+                {
                     error!("Failed to emit download-failed event: {}", emit_err);
                 }
+                record_download_failure(&app_handle, &history_name, &url_clone, &e);
+                download_tasks.write().await.remove(&task_id);
                 return;
             }
         };
@@ -379,18 +805,25 @@ pub async fn download_youtube_playlist(
         let app_handle_for_progress = app_handle.clone();
         let progress_callback = move |progress: DownloadProgress| {
             let payload = DownloadProgressPayload::from_progress(task_id, &progress);
-            if let Err(e) =
-                app_handle_for_progress.emit(youtube_events::DOWNLOAD_PROGRESS, &payload)
-            {
+            if let Err(e) = emit_task_event(
+                &app_handle_for_progress,
+                youtube_events::DOWNLOAD_PROGRESS,
+                &payload,
+            ) {
                 error!("Failed to emit download-progress event: {}", e);
             }
         };
 
-        let results = match downloader.download_playlist(
-            &playlist_info,
-            &output_path,
-            Some(Box::new(progress_callback)),
-        ) {
+        let started = Instant::now();
+        let results = match downloader
+            .download_playlist(
+                &playlist_info,
+                &output_path,
+                Some(Box::new(progress_callback)),
+                &cancel,
+            )
+            .await
+        {
             Ok(results) => results,
             Err(e) => {
                 error!("Download failed: {}", e);
@@ -417,10 +850,14 @@ pub async fn download_youtube_playlist(
                     error_category: Some(category),
                     error_title: Some(category.title().to_string()),
                     error_description: Some(category.description().to_string()),
+                    source_url: Some(url_clone.clone()),
+                    playlist_name: None,
                 };
-                if let Err(emit_err) = app_handle.emit(event, &payload) {
+                if let Err(emit_err) = emit_task_event(&app_handle, event, &payload) {
                     error!("Failed to emit {} event: {}", event, emit_err);
                 }
+                record_download_failure(&app_handle, &history_name, &url_clone, &e);
+                download_tasks.write().await.remove(&task_id);
                 return;
             }
         };
@@ -443,6 +880,11 @@ pub async fn download_youtube_playlist(
                     .as_ref()
                     .map(|p: &std::path::PathBuf| p.display().to_string()),
                 error: r.error.clone(),
+                sponsor_secs_removed: r.sponsor_secs_removed,
+                audio_bitrate_kbps: r.audio_bitrate_kbps,
+                audio_codec: r.audio_codec.clone(),
+                stream_path: r.stream_path,
+                chapter_count: r.chapter_paths.len(),
             })
             .collect();
 
@@ -458,6 +900,8 @@ pub async fn download_youtube_playlist(
             error_category: None,
             error_title: None,
             error_description: None,
+            source_url: Some(url_clone.clone()),
+            playlist_name: None,
         };
 
         if failed_count == 0 {
@@ -472,20 +916,19 @@ pub async fn download_youtube_playlist(
             );
         }
 
-        if let Err(e) = app_handle.emit(youtube_events::DOWNLOAD_COMPLETED, &payload) {
+        if let Err(e) = emit_task_event(&app_handle, youtube_events::DOWNLOAD_COMPLETED, &payload) {
             error!("Failed to emit download completed event: {}", e);
         }
+        record_history(
+            &app_handle,
+            HistoryEntry::download(&history_name, &url_clone)
+                .with_download_results(&results)
+                .with_elapsed(started.elapsed()),
+        );
+        record_downloaded_tracks(&app_handle, &history_name, &results);
 
         // Unregister the download task when done
-        let rt = tokio::runtime::Builder::new_current_thread()
-            .enable_all()
-            .build();
-        if let Ok(rt) = rt {
-            rt.block_on(async {
-                let mut tasks = download_tasks.write().await;
-                tasks.remove(&task_id);
-            });
-        }
+        download_tasks.write().await.remove(&task_id);
     });
 
     info!("Download task {} spawned successfully", task_id);
@@ -506,6 +949,8 @@ fn create_failure_payload(
     task_id: TaskId,
     error: &Error,
     total_count: usize,
+    url: &str,
+    playlist_name: &str,
 ) -> DownloadResultPayload {
     let category = classify_error(error);
     DownloadResultPayload {
@@ -520,6 +965,8 @@ fn create_failure_payload(
         error_category: Some(category),
         error_title: Some(category.title().to_string()),
         error_description: Some(category.description().to_string()),
+        source_url: Some(url.to_string()),
+        playlist_name: Some(playlist_name.to_string()),
     }
 }
 
@@ -534,22 +981,37 @@ fn emit_failure_event(app_handle: &AppHandle, error: &Error, payload: &DownloadR
         youtube_events::DOWNLOAD_FAILED
     };
 
-    if let Err(emit_err) = app_handle.emit(event, payload) {
+    if let Err(emit_err) = emit_task_event(&app_handle, event, payload) {
         error!("Failed to emit {} event: {}", event, emit_err);
     }
 }
 
+/// Record a download that ended with `error` in the history.
+fn record_download_failure(app_handle: &AppHandle, playlist_name: &str, url: &str, error: &Error) {
+    let entry = HistoryEntry::download(playlist_name, url);
+    let entry = if matches!(
+        error,
+        Error::Download(youtun4_core::error::DownloadError::Cancelled)
+    ) {
+        entry.cancelled()
+    } else {
+        entry.failed(error.to_string())
+    };
+    record_history(app_handle, entry);
+}
+
 /// Update playlist.json with source URL and thumbnail before download.
 fn update_playlist_metadata_before_download(
+    fs: &dyn FileSystem,
     playlist_json_path: &std::path::Path,
     source_url: &str,
     playlist_info: &PlaylistInfo,
 ) {
-    if !playlist_json_path.exists() {
+    if !fs.exists(playlist_json_path) {
         return;
     }
 
-    let Ok(content) = std::fs::read_to_string(playlist_json_path) else {
+    let Ok(content) = fs.read_to_string(playlist_json_path) else {
         return;
     };
     let Ok(mut metadata) = serde_json::from_str::<serde_json::Value>(&content) else {
@@ -566,22 +1028,21 @@ fn update_playlist_metadata_before_download(
     }
 
     if let Ok(updated) = serde_json::to_string_pretty(&metadata) {
-        let _ = std::fs::write(playlist_json_path, updated);
+        let _ = fs.write(playlist_json_path, &updated);
     }
 }
 
 /// Count audio files and calculate total size in a directory.
-fn count_audio_files(dir: &std::path::Path) -> (usize, u64) {
+fn count_audio_files(fs: &dyn FileSystem, dir: &std::path::Path) -> (usize, u64) {
     let mut track_count = 0usize;
     let mut total_size = 0u64;
 
-    let Ok(entries) = std::fs::read_dir(dir) else {
+    let Ok(entries) = fs.read_dir(dir) else {
         return (track_count, total_size);
     };
 
-    for entry in entries.filter_map(std::result::Result::ok) {
-        let path = entry.path();
-        if !path.is_file() {
+    for path in entries {
+        if !fs.is_file(&path) {
             continue;
         }
 
@@ -591,8 +1052,8 @@ fn count_audio_files(dir: &std::path::Path) -> (usize, u64) {
 
         if AUDIO_EXTENSIONS.contains(&ext.to_lowercase().as_str()) {
             track_count += 1;
-            if let Ok(meta) = std::fs::metadata(&path) {
-                total_size += meta.len();
+            if let Ok(meta) = fs.metadata(&path) {
+                total_size += meta.len;
             }
         }
     }
@@ -610,12 +1071,12 @@ fn build_tracks_metadata(
 
     results
         .iter()
-        .filter(|r| r.success && r.output_path.is_some())
-        .map(|r| {
-            let file_name = r
-                .output_path
-                .as_ref()
-                .and_then(|p: &std::path::PathBuf| p.file_name())
+        .filter(|r| r.success)
+        // A video split by chapters adds one track per chapter
+        .flat_map(|r| r.output_paths().iter().map(move |path| (r, path)))
+        .map(|(r, path)| {
+            let file_name = path
+                .file_name()
                 .and_then(|n: &std::ffi::OsStr| n.to_str())
                 .unwrap_or("")
                 .to_string();
@@ -636,11 +1097,12 @@ fn build_tracks_metadata(
 
 /// Update playlist.json with track count, size, and metadata after download.
 fn update_playlist_metadata_after_download(
+    fs: &dyn FileSystem,
     playlist_json_path: &std::path::Path,
     output_path: &std::path::Path,
     results: &[youtun4_core::youtube::DownloadResult],
 ) {
-    let Ok(content) = std::fs::read_to_string(playlist_json_path) else {
+    let Ok(content) = fs.read_to_string(playlist_json_path) else {
         return;
     };
     let Ok(mut metadata) = serde_json::from_str::<serde_json::Value>(&content) else {
@@ -650,7 +1112,7 @@ fn update_playlist_metadata_after_download(
         return;
     };
 
-    let (track_count, total_size) = count_audio_files(output_path);
+    let (track_count, total_size) = count_audio_files(fs, output_path);
 
     obj.insert("track_count".to_string(), serde_json::json!(track_count));
     obj.insert(
@@ -670,7 +1132,7 @@ fn update_playlist_metadata_after_download(
     obj.insert("tracks".to_string(), serde_json::json!(tracks_metadata));
 
     if let Ok(updated) = serde_json::to_string_pretty(&metadata) {
-        let _ = std::fs::write(playlist_json_path, updated);
+        let _ = fs.write(playlist_json_path, &updated);
     }
 
     info!(
@@ -685,6 +1147,8 @@ fn update_playlist_metadata_after_download(
 fn create_success_payload(
     task_id: TaskId,
     results: &[youtun4_core::youtube::DownloadResult],
+    url: &str,
+    playlist_name: &str,
 ) -> DownloadResultPayload {
     let successful_count = results.iter().filter(|r| r.success).count();
     let failed_count = results
@@ -704,6 +1168,11 @@ fn create_success_payload(
                 .as_ref()
                 .map(|p: &std::path::PathBuf| p.display().to_string()),
             error: r.error.clone(),
+            sponsor_secs_removed: r.sponsor_secs_removed,
+            audio_bitrate_kbps: r.audio_bitrate_kbps,
+            audio_codec: r.audio_codec.clone(),
+            stream_path: r.stream_path,
+            chapter_count: r.chapter_paths.len(),
         })
         .collect();
 
@@ -719,6 +1188,8 @@ fn create_success_payload(
         error_category: None,
         error_title: None,
         error_description: None,
+        source_url: Some(url.to_string()),
+        playlist_name: Some(playlist_name.to_string()),
     }
 }
 
@@ -748,35 +1219,121 @@ pub async fn download_youtube_to_playlist(
     state: State<'_, AppState>,
     url: String,
     playlist_name: String,
-) -> std::result::Result<TaskId, String> {
+) -> CommandResult<TaskId> {
     info!(
         "Downloading YouTube playlist to local playlist: {} -> {}",
         url, playlist_name
     );
+    spawn_library_download(&app, &state, url, playlist_name, false, None).await
+}
 
+/// Download the new videos of a library playlist's YouTube source into the
+/// playlist folder, and refresh its stats.
+///
+/// Videos downloaded before are skipped. With `remove_missing`, the tracks
+/// of videos no longer in the source are moved to the trash; in simple
+/// mode, `pin` must then be the parent PIN.
+#[tauri::command]
+pub async fn update_playlist_from_source(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    name: String,
+    remove_missing: bool,
+    pin: Option<String>,
+) -> CommandResult<TaskId> {
+    info!(
+        "Updating playlist '{}' from its source (remove missing: {})",
+        name, remove_missing
+    );
+    if remove_missing {
+        require_parent_pin(&state, ProtectedOperation::DeleteTracks, pin).await?;
+    }
+    let saved = state
+        .playlist_manager
+        .read()
+        .await
+        .get_saved_metadata(&name)
+        .map_err(map_err)?;
+    let source_url = saved.source_url.ok_or_else(|| {
+        CommandError::invalid_input(format!(
+            "Playlist '{name}' has no YouTube source to update from"
+        ))
+    })?;
+    spawn_library_download(&app, &state, source_url, name, remove_missing, None).await
+}
+
+/// Start downloading the YouTube playlist at `url` into the library playlist
+/// `playlist_name` in the background, moving the tracks of videos removed
+/// from the playlist to the trash if `remove_missing` is set.
+///
+/// The outcome is sent to `done`, if given, once the download finishes.
+pub(crate) async fn spawn_library_download(
+    app: &AppHandle,
+    state: &AppState,
+    url: String,
+    playlist_name: String,
+    remove_missing: bool,
+    done: Option<tokio::sync::oneshot::Sender<DownloadResultPayload>>,
+) -> CommandResult<TaskId> {
     let validation = validate_youtube_url(&url);
     if !validation.is_valid {
-        return Err(validation
-            .error_message
-            .unwrap_or_else(|| "Invalid URL".to_string()));
+        return Err(CommandError::new(
+            ErrorCode::InvalidUrl,
+            validation
+                .error_message
+                .unwrap_or_else(|| "Invalid URL".to_string()),
+        ));
     }
 
+    ensure_library_space(app, state).await.map_err(map_err)?;
+
     let playlist_manager = state.playlist_manager.read().await;
     let playlist_path = playlist_manager.base_path().join(&playlist_name);
+    let fs = playlist_manager.file_system();
     drop(playlist_manager);
 
-    if !playlist_path.exists() {
-        std::fs::create_dir_all(&playlist_path)
+    if !fs.exists(&playlist_path) {
+        fs.create_dir_all(&playlist_path)
             .map_err(|e| format!("Failed to create playlist directory: {e}"))?;
     }
 
     let task_id = state.runtime().generate_task_id();
+    let slot = state
+        .runtime()
+        .admit(task_id, TaskCategory::Download, None)?;
 
     // Create the downloader and register its cancel flag before spawning
-    let config = RustyYtdlConfig::default();
-    let downloader = RustyYtdlDownloader::with_config(config);
-    let cancel_flag = downloader.cancel_flag();
-    state.register_download_task(task_id, cancel_flag).await;
+    let transcode = state.transcode_options(Some(&playlist_name), None).await;
+    let mut config = RustyYtdlConfig {
+        offline: state.offline_mode().await,
+        temp_dir: Some(state.temp_directory().await),
+        audio_bitrate_kbps: transcode.bitrate_kbps,
+        transcode: Some(transcode),
+        sponsorblock: state.sponsorblock_config().await,
+        concurrency: state.download_concurrency().await,
+        max_speed_bps: state.max_download_speed().await,
+        write_tags: state.write_tags().await,
+        embed_cover_art: state.embed_cover_art().await,
+        lyrics: state.lyrics_config(Some(&playlist_name)).await,
+        extraction: state.extraction_config().await,
+        filename_template: state.filename_template().await,
+        ..RustyYtdlConfig::default()
+    };
+    if let Some(profile) = state.download_profile(&playlist_name).await {
+        config.apply_profile(&profile);
+    }
+    let downloader = Arc::new(RustyYtdlDownloader::with_config(config));
+    state
+        .register_download_task(task_id, downloader.control())
+        .await;
+
+    let tracked = state.track_task(
+        PersistedTaskKind::LibraryDownload {
+            url: url.clone(),
+            playlist_name: playlist_name.clone(),
+        },
+        format!("Download playlist: {url} -> '{playlist_name}'"),
+    );
 
     let url_clone = url.clone();
     let playlist_name_clone = playlist_name.clone();
@@ -784,25 +1341,26 @@ pub async fn download_youtube_to_playlist(
     let output_path = playlist_path;
     let download_tasks = Arc::clone(&state.download_tasks);
 
-    std::thread::spawn(move || {
-        run_playlist_download(
+    tauri::async_runtime::spawn(async move {
+        let _tracked = tracked;
+        let _slot = slot;
+        let payload = run_playlist_download(
             task_id,
             &app_handle,
+            fs.as_ref(),
             &url_clone,
             &playlist_name_clone,
             &output_path,
             &downloader,
-        );
-        // Unregister the download task when done
-        let rt = tokio::runtime::Builder::new_current_thread()
-            .enable_all()
-            .build();
-        if let Ok(rt) = rt {
-            rt.block_on(async {
-                let mut tasks = download_tasks.write().await;
-                tasks.remove(&task_id);
-            });
+            remove_missing,
+        )
+        .await;
+        if let Some(done) = done {
+            // The receiver may have stopped waiting
+            let _ = done.send(payload);
         }
+        // Unregister the download task when done
+        download_tasks.write().await.remove(&task_id);
     });
 
     info!(
@@ -812,27 +1370,32 @@ pub async fn download_youtube_to_playlist(
     Ok(task_id)
 }
 
-/// Run the playlist download in a background thread.
-fn run_playlist_download(
+/// Run the playlist download in a background task, returning its outcome.
+#[allow(clippy::too_many_arguments)]
+async fn run_playlist_download(
     task_id: TaskId,
     app_handle: &AppHandle,
+    fs: &dyn FileSystem,
     url: &str,
     playlist_name: &str,
     output_path: &std::path::Path,
-    downloader: &RustyYtdlDownloader,
-) {
-    if let Err(e) = app_handle.emit(youtube_events::DOWNLOAD_STARTED, &task_id) {
+    downloader: &Arc<RustyYtdlDownloader>,
+    remove_missing: bool,
+) -> DownloadResultPayload {
+    let cancel = downloader.control().cancellation_token();
+    if let Err(e) = emit_task_event(&app_handle, youtube_events::DOWNLOAD_STARTED, &task_id) {
         error!("Failed to emit download-started event: {}", e);
     }
 
     // Parse playlist
-    let playlist_info = match downloader.parse_playlist_url(url) {
+    let playlist_info = match downloader.parse_playlist_url(url).await {
         Ok(info) => info,
         Err(e) => {
             error!("Failed to parse playlist: {}", e);
-            let payload = create_failure_payload(task_id, &e, 0);
+            let payload = create_failure_payload(task_id, &e, 0, url, playlist_name);
             emit_failure_event(app_handle, &e, &payload);
-            return;
+            record_download_failure(app_handle, playlist_name, url, &e);
+            return payload;
         }
     };
 
@@ -843,40 +1406,137 @@ fn run_playlist_download(
 
     // Update playlist metadata before download
     let playlist_json_path = output_path.join("playlist.json");
-    update_playlist_metadata_before_download(&playlist_json_path, url, &playlist_info);
+    update_playlist_metadata_before_download(fs, &playlist_json_path, url, &playlist_info);
 
     // Set up progress callback
     let app_handle_for_progress = app_handle.clone();
     let progress_callback = move |progress: DownloadProgress| {
         let payload = DownloadProgressPayload::from_progress(task_id, &progress);
-        if let Err(e) = app_handle_for_progress.emit(youtube_events::DOWNLOAD_PROGRESS, &payload) {
+        if let Err(e) = emit_task_event(
+            &app_handle_for_progress,
+            youtube_events::DOWNLOAD_PROGRESS,
+            &payload,
+        ) {
             error!("Failed to emit download-progress event: {}", e);
         }
     };
 
     // Download playlist
-    let results = match downloader.download_playlist(
-        &playlist_info,
-        output_path,
-        Some(Box::new(progress_callback)),
-    ) {
+    let started = Instant::now();
+    let results = match downloader
+        .download_playlist(
+            &playlist_info,
+            output_path,
+            Some(Box::new(progress_callback)),
+            &cancel,
+        )
+        .await
+    {
         Ok(results) => results,
         Err(e) => {
             error!("Download failed: {}", e);
-            let payload = create_failure_payload(task_id, &e, playlist_info.video_count);
+            let payload =
+                create_failure_payload(task_id, &e, playlist_info.video_count, url, playlist_name);
             emit_failure_event(app_handle, &e, &payload);
-            return;
+            record_download_failure(app_handle, playlist_name, url, &e);
+            return payload;
         }
     };
 
     // Create success payload and log completion
-    let payload = create_success_payload(task_id, &results);
+    let payload = create_success_payload(task_id, &results, url, playlist_name);
     log_download_completion(playlist_name, &payload);
 
     // Update playlist metadata after download
-    update_playlist_metadata_after_download(&playlist_json_path, output_path, &results);
+    update_playlist_metadata_after_download(fs, &playlist_json_path, output_path, &results);
+    if remove_missing {
+        trash_removed_videos(app_handle, playlist_name, &playlist_info, output_path).await;
+    }
+    record_history(
+        app_handle,
+        HistoryEntry::download(playlist_name, url)
+            .with_download_results(&results)
+            .with_elapsed(started.elapsed()),
+    );
+    record_downloaded_tracks(app_handle, playlist_name, &results);
 
-    if let Err(e) = app_handle.emit(youtube_events::DOWNLOAD_COMPLETED, &payload) {
+    if let Err(e) = emit_task_event(&app_handle, youtube_events::DOWNLOAD_COMPLETED, &payload) {
         error!("Failed to emit download-completed event: {}", e);
     }
+    payload
+}
+
+/// Move the tracks of the videos no longer in `playlist_info`, the source of
+/// the library playlist `playlist_name`, to the trash.
+async fn trash_removed_videos(
+    app_handle: &AppHandle,
+    playlist_name: &str,
+    playlist_info: &PlaylistInfo,
+    output_path: &std::path::Path,
+) {
+    let diff = diff_playlist(playlist_info, &DownloadArchive::open(output_path));
+    if diff.removed_videos.is_empty() {
+        return;
+    }
+    let state = app_handle.state::<AppState>();
+    let playlist_manager = state.playlist_manager.read().await;
+    match playlist_manager.trash_removed_videos(playlist_name, &diff, &state.trash) {
+        Ok(trashed) => {
+            info!(
+                "Moved {} track(s) removed from the source of playlist '{}' to trash",
+                trashed.result.succeeded.len(),
+                playlist_name
+            );
+            record_tracks_removed(&state, playlist_name.to_string(), &trashed.result);
+        }
+        Err(e) => warn!(
+            "Failed to remove tracks no longer in the source of playlist '{}': {}",
+            playlist_name, e
+        ),
+    }
+}
+
+// =============================================================================
+// Download control commands
+// =============================================================================
+
+/// Cancel the video a running download is working on.
+///
+/// The download continues with the next video; the cancelled video is
+/// reported as failed and its partial stream is kept for a later resume.
+#[tauri::command]
+pub async fn cancel_download_video(
+    state: State<'_, AppState>,
+    task_id: TaskId,
+) -> CommandResult<bool> {
+    info!("Cancelling current video of download {}", task_id);
+    Ok(state.cancel_download_video(task_id).await)
+}
+
+/// Pause a running download without discarding the bytes downloaded so far.
+#[tauri::command]
+pub async fn pause_download(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    task_id: TaskId,
+) -> CommandResult<bool> {
+    let paused = state.set_download_paused(task_id, true).await;
+    if paused && let Err(e) = emit_task_event(&app, youtube_events::DOWNLOAD_PAUSED, &task_id) {
+        error!("Failed to emit download-paused event: {}", e);
+    }
+    Ok(paused)
+}
+
+/// Resume a paused download.
+#[tauri::command]
+pub async fn resume_download(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    task_id: TaskId,
+) -> CommandResult<bool> {
+    let resumed = state.set_download_paused(task_id, false).await;
+    if resumed && let Err(e) = emit_task_event(&app, youtube_events::DOWNLOAD_RESUMED, &task_id) {
+        error!("Failed to emit download-resumed event: {}", e);
+    }
+    Ok(resumed)
 }
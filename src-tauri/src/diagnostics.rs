@@ -0,0 +1,213 @@
+//! Diagnostics bundles for bug reports.
+//!
+//! A bundle is a zip archive of JSON and text files describing the state of
+//! the app. Everything added to a bundle is redacted: values of
+//! secret-looking fields are replaced and the user's home directory is
+//! shortened to `~`, so bundles can be attached to public issues.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::Value;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Replacement for redacted values.
+pub const REDACTED: &str = "[redacted]";
+
+/// Field name fragments whose values are always redacted.
+const SENSITIVE_FIELDS: &[&str] = &[
+    "password",
+    "secret",
+    "token",
+    "credential",
+    "cookie",
+    "webhook",
+    "api_key",
+];
+
+/// Builder for a diagnostics zip archive.
+#[derive(Debug)]
+pub struct DiagnosticsBundle {
+    home: Option<PathBuf>,
+    files: Vec<(String, Vec<u8>)>,
+}
+
+impl Default for DiagnosticsBundle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DiagnosticsBundle {
+    /// Create an empty bundle that redacts the current user's home directory.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_home(dirs::home_dir())
+    }
+
+    /// Create an empty bundle that redacts the given home directory.
+    #[must_use]
+    pub fn with_home(home: Option<PathBuf>) -> Self {
+        Self {
+            home: home.filter(|home| home.parent().is_some()),
+            files: Vec::new(),
+        }
+    }
+
+    /// Add a value as a pretty-printed JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be serialized.
+    pub fn add_json(&mut self, name: &str, value: &impl Serialize) -> serde_json::Result<()> {
+        let mut value = serde_json::to_value(value)?;
+        self.redact_value(&mut value);
+        let content = serde_json::to_vec_pretty(&value)?;
+        self.files.push((name.to_string(), content));
+        Ok(())
+    }
+
+    /// Add a text file.
+    pub fn add_text(&mut self, name: &str, text: &str) {
+        let content = self.redact_text(text).into_bytes();
+        self.files.push((name.to_string(), content));
+    }
+
+    /// Names of the files added so far.
+    pub fn file_names(&self) -> impl Iterator<Item = &str> {
+        self.files.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Write the bundle as a zip archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the archive cannot be written.
+    pub fn write_zip(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut zip = ZipWriter::new(std::fs::File::create(path)?);
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        for (name, content) in &self.files {
+            zip.start_file(name.as_str(), options)?;
+            zip.write_all(content)?;
+        }
+        zip.finish()?;
+        Ok(())
+    }
+
+    fn redact_value(&self, value: &mut Value) {
+        match value {
+            Value::Object(fields) => {
+                for (key, field) in fields.iter_mut() {
+                    if is_sensitive_field(key) && !field.is_null() {
+                        *field = Value::String(REDACTED.to_string());
+                    } else {
+                        self.redact_value(field);
+                    }
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.redact_value(item);
+                }
+            }
+            Value::String(text) => *text = self.redact_text(text),
+            _ => {}
+        }
+    }
+
+    fn redact_text(&self, text: &str) -> String {
+        match &self.home {
+            Some(home) => text.replace(&*home.to_string_lossy(), "~"),
+            None => text.to_string(),
+        }
+    }
+}
+
+/// Whether a field with this name holds a secret.
+fn is_sensitive_field(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SENSITIVE_FIELDS
+        .iter()
+        .any(|fragment| name.contains(fragment))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    #[test]
+    fn test_sensitive_fields() {
+        assert!(is_sensitive_field("proxy_password"));
+        assert!(is_sensitive_field("Webhook_URL"));
+        assert!(is_sensitive_field("access_token"));
+        assert!(!is_sensitive_field("playlists_directory"));
+    }
+
+    #[test]
+    fn test_json_is_redacted() {
+        let mut bundle = DiagnosticsBundle::with_home(Some(PathBuf::from("/home/alice")));
+        bundle
+            .add_json(
+                "config.json",
+                &serde_json::json!({
+                    "playlists_directory": "/home/alice/Music",
+                    "proxy": { "password": "hunter2", "host": "proxy.local" },
+                    "api_token": null,
+                    "devices": ["/media/alice", "/home/alice/player"],
+                }),
+            )
+            .expect("Should add");
+
+        let value: Value = serde_json::from_slice(&bundle.files[0].1).expect("Should parse");
+        assert_eq!(value["playlists_directory"], "~/Music");
+        assert_eq!(value["proxy"]["password"], REDACTED);
+        assert_eq!(value["proxy"]["host"], "proxy.local");
+        assert!(value["api_token"].is_null());
+        assert_eq!(value["devices"][0], "/media/alice");
+        assert_eq!(value["devices"][1], "~/player");
+    }
+
+    #[test]
+    fn test_write_zip() {
+        let dir = tempfile::TempDir::new().expect("Should create temp dir");
+        let path = dir.path().join("out").join("diagnostics.zip");
+
+        let mut bundle = DiagnosticsBundle::with_home(Some(PathBuf::from("/home/alice")));
+        bundle.add_text("logs.txt", "Saved /home/alice/Music/a.mp3");
+        bundle
+            .add_json("queue.json", &[1, 2, 3])
+            .expect("Should add");
+        assert_eq!(
+            bundle.file_names().collect::<Vec<_>>(),
+            ["logs.txt", "queue.json"]
+        );
+        bundle.write_zip(&path).expect("Should write zip");
+
+        let mut archive =
+            zip::ZipArchive::new(std::fs::File::open(&path).expect("Should open")).expect("Zip");
+        assert_eq!(archive.len(), 2);
+        let mut logs = String::new();
+        archive
+            .by_name("logs.txt")
+            .expect("Should contain logs")
+            .read_to_string(&mut logs)
+            .expect("Should read");
+        assert_eq!(logs, "Saved ~/Music/a.mp3");
+    }
+
+    #[test]
+    fn test_root_home_is_not_redacted() {
+        let mut bundle = DiagnosticsBundle::with_home(Some(PathBuf::from("/")));
+        bundle.add_text("logs.txt", "/media/player");
+        assert_eq!(bundle.files[0].1, b"/media/player");
+    }
+}
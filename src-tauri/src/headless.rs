@@ -0,0 +1,385 @@
+//! Headless mode for running core operations without the GUI.
+//!
+//! `youtun4 --headless <command> [args]` runs a single operation against the
+//! configured library and exits, so playlist refreshes can be scheduled from
+//! cron on a home server:
+//!
+//! ```text
+//! youtun4 --headless download <url> <playlist>
+//! youtun4 --headless refresh [playlist]
+//! youtun4 --headless sync <playlist> <device-mount-point> [--verify]
+//! youtun4 --headless verify <playlist>
+//! ```
+//!
+//! Config overrides such as `--storage-dir` and `--offline` still apply.
+//! `refresh` and `verify` are skipped while the power preferences say heavy
+//! work should pause (on battery power or a metered network).
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use tracing::info;
+use youtun4_core::audio::TranscodeOptions;
+use youtun4_core::config::ConfigManager;
+use youtun4_core::integrity::verify_directory;
+use youtun4_core::playlist::PlaylistManager;
+use youtun4_core::power::PowerState;
+use youtun4_core::storage::LibrarySpace;
+use youtun4_core::transfer::{TransferOptions, TransferProgress};
+use youtun4_core::youtube::{
+    DownloadProgress, RustyYtdlConfig, RustyYtdlDownloader, YouTubeDownloader,
+    template::FilenameTemplate,
+};
+use youtun4_core::{Error, Result};
+
+/// Flag that switches the binary into headless mode.
+pub const HEADLESS_FLAG: &str = "--headless";
+
+/// Usage text printed for invalid headless invocations.
+pub const USAGE: &str = "\
+Usage: youtun4 --headless <command> [args]
+
+Commands:
+  download <url> <playlist>              Download a YouTube playlist into a library playlist
+  refresh [playlist]                     Re-download new tracks from each playlist's YouTube source
+  sync <playlist> <device> [--verify]    Sync a playlist to a mounted device
+  verify <playlist>                      Verify a playlist against its checksum manifest";
+
+/// A single headless operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeadlessCommand {
+    /// Download a `YouTube` playlist into a library playlist.
+    Download {
+        /// Playlist URL.
+        url: String,
+        /// Library playlist name (created if missing).
+        playlist: String,
+    },
+    /// Re-download from the saved `YouTube` source of one or all playlists.
+    Refresh {
+        /// Playlist to refresh, or all playlists with a source if `None`.
+        playlist: Option<String>,
+    },
+    /// Sync a playlist to a device.
+    Sync {
+        /// Library playlist name.
+        playlist: String,
+        /// Device mount point.
+        device: PathBuf,
+        /// Whether to verify transferred files.
+        verify: bool,
+    },
+    /// Verify a playlist against its checksum manifest.
+    Verify {
+        /// Library playlist name.
+        playlist: String,
+    },
+}
+
+impl HeadlessCommand {
+    /// Parse the headless command from the process arguments.
+    ///
+    /// Returns `None` if `--headless` is not present. Config override flags
+    /// (and their values) are skipped.
+    pub fn from_args(args: &[String]) -> Option<std::result::Result<Self, String>> {
+        let start = args.iter().position(|arg| arg == HEADLESS_FLAG)? + 1;
+
+        let mut verify = false;
+        let mut positional = Vec::new();
+        let mut rest = args[start..].iter();
+        while let Some(arg) = rest.next() {
+            match arg.as_str() {
+                "--verify" => verify = true,
+                "--storage-dir" | "--log-level" => {
+                    rest.next();
+                }
+                flag if flag.starts_with("--") => {}
+                value => positional.push(value.to_string()),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        let command = positional.next();
+        let args: Vec<String> = positional.collect();
+
+        let parsed = match (command.as_deref(), args.as_slice()) {
+            (Some("download"), [url, playlist]) => Ok(Self::Download {
+                url: url.clone(),
+                playlist: playlist.clone(),
+            }),
+            (Some("refresh"), []) => Ok(Self::Refresh { playlist: None }),
+            (Some("refresh"), [playlist]) => Ok(Self::Refresh {
+                playlist: Some(playlist.clone()),
+            }),
+            (Some("sync"), [playlist, device]) => Ok(Self::Sync {
+                playlist: playlist.clone(),
+                device: PathBuf::from(device),
+                verify,
+            }),
+            (Some("verify"), [playlist]) => Ok(Self::Verify {
+                playlist: playlist.clone(),
+            }),
+            (Some(command), _) => Err(format!("Invalid arguments for '{command}'")),
+            (None, _) => Err("Missing headless command".to_string()),
+        };
+        Some(parsed)
+    }
+}
+
+/// Run a headless command against the configured library.
+///
+/// Returns `Ok(false)` if the operation finished but some items failed.
+pub fn run(command: &HeadlessCommand, config_manager: &ConfigManager) -> Result<bool> {
+    let manager = PlaylistManager::new(config_manager.playlists_directory().to_path_buf())?;
+    info!("Running headless command: {:?}", command);
+
+    if matches!(
+        command,
+        HeadlessCommand::Refresh { .. } | HeadlessCommand::Verify { .. }
+    ) && let Some(reason) = config_manager
+        .config()
+        .power
+        .pause_reason(&PowerState::detect())
+    {
+        println!("Skipped: {reason}");
+        return Ok(true);
+    }
+
+    match command {
+        HeadlessCommand::Download { url, playlist } => {
+            download(config_manager, &manager, url, playlist)
+        }
+        HeadlessCommand::Refresh { playlist } => {
+            refresh(config_manager, &manager, playlist.as_deref())
+        }
+        HeadlessCommand::Sync {
+            playlist,
+            device,
+            verify,
+        } => sync(&manager, playlist, device, *verify),
+        HeadlessCommand::Verify { playlist } => {
+            let result = verify_directory(&manager.get_playlist_path(playlist)?)?;
+            println!(
+                "{playlist}: {} passed, {} failed, {} missing",
+                result.passed, result.failed, result.missing
+            );
+            Ok(result.failed == 0 && result.missing == 0)
+        }
+    }
+}
+
+fn download(
+    config_manager: &ConfigManager,
+    manager: &PlaylistManager,
+    url: &str,
+    playlist: &str,
+) -> Result<bool> {
+    let config = config_manager.config();
+    LibrarySpace::measure(manager.base_path(), config.library_limits).check()?;
+
+    let path = match manager.get_playlist_path(playlist) {
+        Ok(path) => path,
+        Err(_) => manager.create_playlist(playlist, Some(url.to_string()))?,
+    };
+
+    let bitrate_kbps = config.download_quality_for(playlist).bitrate_kbps();
+    let mut downloader_config = RustyYtdlConfig {
+        offline: config.offline_mode,
+        temp_dir: Some(config.cache.temp_directory()),
+        transcode: Some(TranscodeOptions::with_bitrate(bitrate_kbps)),
+        sponsorblock: config
+            .sponsorblock
+            .enabled
+            .then(|| config.sponsorblock.clone()),
+        concurrency: config.download_concurrency,
+        max_speed_bps: config.max_download_speed_bps,
+        audio_bitrate_kbps: bitrate_kbps,
+        write_tags: config.write_tags,
+        embed_cover_art: config.embed_cover_art,
+        lyrics: config.lyrics_for(playlist),
+        extraction: config.extraction.clone(),
+        filename_template: FilenameTemplate::parse(&config.filename_template)?,
+        ..RustyYtdlConfig::default()
+    };
+    if let Some(profile) = manager.get_download_profile(playlist)? {
+        downloader_config.apply_profile(&profile);
+    }
+    let downloader = RustyYtdlDownloader::with_config(downloader_config);
+    let info = downloader.parse_playlist_url(url)?;
+    println!(
+        "{playlist}: {} videos in '{}'",
+        info.video_count, info.title
+    );
+
+    let last_reported = AtomicUsize::new(usize::MAX);
+    let progress = move |progress: DownloadProgress| {
+        if last_reported.swap(progress.current_index, Ordering::Relaxed) != progress.current_index {
+            println!(
+                "  [{}/{}] {}",
+                progress.current_index + 1,
+                progress.total_videos,
+                progress.current_title
+            );
+        }
+    };
+    let results = downloader.download_playlist(&info, &path, Some(Box::new(progress)))?;
+
+    manager.update_playlist_metadata_full(
+        playlist,
+        None,
+        None,
+        Some(Some(url.to_string())),
+        info.thumbnail_url.clone().map(Some),
+    )?;
+    manager.refresh_playlist_stats(playlist)?;
+
+    let failed = results
+        .iter()
+        .filter(|r| !r.success && r.error.is_some())
+        .count();
+    println!(
+        "{playlist}: {} downloaded or up to date, {failed} failed",
+        results.len() - failed
+    );
+    Ok(failed == 0)
+}
+
+fn refresh(
+    config_manager: &ConfigManager,
+    manager: &PlaylistManager,
+    playlist: Option<&str>,
+) -> Result<bool> {
+    let names = match playlist {
+        Some(name) => vec![name.to_string()],
+        None => manager
+            .list_playlists()?
+            .into_iter()
+            .map(|p| p.name)
+            .collect(),
+    };
+
+    let mut all_ok = true;
+    for name in names {
+        let Some(url) = manager.get_saved_metadata(&name)?.source_url else {
+            if playlist.is_some() {
+                return Err(Error::Configuration(format!(
+                    "Playlist '{name}' has no YouTube source to refresh from"
+                )));
+            }
+            continue;
+        };
+        match download(config_manager, manager, &url, &name) {
+            Ok(ok) => all_ok &= ok,
+            Err(e) if e.is_library_space_low() => return Err(e),
+            Err(e) => {
+                eprintln!("{name}: refresh failed: {e}");
+                all_ok = false;
+            }
+        }
+    }
+    Ok(all_ok)
+}
+
+fn sync(manager: &PlaylistManager, playlist: &str, device: &Path, verify: bool) -> Result<bool> {
+    if !device.is_dir() {
+        return Err(Error::device_not_found(device.display().to_string()));
+    }
+
+    let options = TransferOptions {
+        verify_integrity: verify,
+        skip_existing: true,
+        ..Default::default()
+    };
+    let result = manager.sync_to_device_cancellable(
+        playlist,
+        device,
+        &options,
+        Arc::new(AtomicBool::new(false)),
+        None::<fn(&TransferProgress)>,
+    )?;
+
+    println!(
+        "{playlist}: {} transferred, {} skipped, {} failed",
+        result.files_transferred, result.files_skipped, result.files_failed
+    );
+    Ok(result.files_failed == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_not_headless() {
+        assert!(HeadlessCommand::from_args(&args(&["--offline"])).is_none());
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            HeadlessCommand::from_args(&args(&["--headless", "download", "url", "Mix"])),
+            Some(Ok(HeadlessCommand::Download {
+                url: "url".to_string(),
+                playlist: "Mix".to_string()
+            }))
+        );
+        assert_eq!(
+            HeadlessCommand::from_args(&args(&["--headless", "refresh"])),
+            Some(Ok(HeadlessCommand::Refresh { playlist: None }))
+        );
+        assert_eq!(
+            HeadlessCommand::from_args(&args(&[
+                "--storage-dir",
+                "/music",
+                "--headless",
+                "sync",
+                "--verify",
+                "Mix",
+                "/media/player",
+            ])),
+            Some(Ok(HeadlessCommand::Sync {
+                playlist: "Mix".to_string(),
+                device: PathBuf::from("/media/player"),
+                verify: true
+            }))
+        );
+    }
+
+    #[test]
+    fn test_override_flags_are_skipped() {
+        assert_eq!(
+            HeadlessCommand::from_args(&args(&[
+                "--headless",
+                "verify",
+                "--log-level",
+                "debug",
+                "--offline",
+                "Mix",
+            ])),
+            Some(Ok(HeadlessCommand::Verify {
+                playlist: "Mix".to_string()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_invalid_commands() {
+        assert!(matches!(
+            HeadlessCommand::from_args(&args(&["--headless"])),
+            Some(Err(_))
+        ));
+        assert!(matches!(
+            HeadlessCommand::from_args(&args(&["--headless", "download", "url"])),
+            Some(Err(_))
+        ));
+        assert!(matches!(
+            HeadlessCommand::from_args(&args(&["--headless", "explode"])),
+            Some(Err(_))
+        ));
+    }
+}
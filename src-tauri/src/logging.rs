@@ -5,18 +5,33 @@
 //! - Console output with human-readable formatting
 //! - File output with JSON formatting and rotation
 //! - Log file rotation (daily, with configurable retention)
+//! - Runtime log level changes and reading recent entries back from the log files
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
+use serde::Serialize;
 use tracing::Level;
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_subscriber::{
-    EnvFilter, Layer,
+    EnvFilter, Layer, Registry,
     fmt::{self, format::FmtSpan},
     layer::SubscriberExt,
+    reload,
     util::SubscriberInitExt,
 };
 
+/// State of the initialized logging system, for runtime access.
+struct ActiveLogging {
+    log_directory: PathBuf,
+    log_file_prefix: String,
+    file_level: Level,
+    console_filter: reload::Handle<EnvFilter, Registry>,
+    file_filter: reload::Handle<EnvFilter, Registry>,
+}
+
+static ACTIVE: OnceLock<ActiveLogging> = OnceLock::new();
+
 /// Logging configuration options.
 #[derive(Debug, Clone)]
 pub struct LoggingConfig {
@@ -42,6 +57,8 @@ pub struct LoggingConfig {
     pub include_target: bool,
     /// Whether to log span events (enter/exit).
     pub log_span_events: bool,
+    /// Explicit console level for our crates, taking precedence over `RUST_LOG`.
+    pub level_override: Option<Level>,
 }
 
 /// Log rotation frequency.
@@ -90,6 +107,7 @@ impl LoggingConfig {
             include_file_line: true,
             include_target: true,
             log_span_events: true,
+            level_override: None,
         }
     }
 
@@ -108,6 +126,7 @@ impl LoggingConfig {
             include_file_line: false,
             include_target: false,
             log_span_events: false,
+            level_override: None,
         }
     }
 
@@ -149,6 +168,13 @@ impl LoggingConfig {
         self
     }
 
+    /// Override the console level for our crates (e.g. from `--log-level`).
+    #[must_use]
+    pub const fn with_level_override(mut self, level: Level) -> Self {
+        self.level_override = Some(level);
+        self
+    }
+
     /// Set the log rotation frequency.
     #[must_use]
     pub const fn with_rotation(mut self, rotation: LogRotation) -> Self {
@@ -200,18 +226,25 @@ pub fn init(config: &LoggingConfig) -> Result<LoggingGuard, LoggingError> {
     // Default: INFO for dependencies, DEBUG for our crates only
     // SAFETY: These are compile-time constant strings that always parse successfully
     #[allow(clippy::unwrap_used)]
-    let console_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+    let console_filter = if let Some(level) = config.level_override {
+        let level = level_to_directive(level);
         EnvFilter::new("warn")
-            .add_directive("youtun4=info".parse().unwrap())
-            .add_directive("youtun4_core=info".parse().unwrap())
-    });
+            .add_directive(format!("youtun4={level}").parse().unwrap())
+            .add_directive(format!("youtun4_core={level}").parse().unwrap())
+    } else {
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+            EnvFilter::new("warn")
+                .add_directive("youtun4=info".parse().unwrap())
+                .add_directive("youtun4_core=info".parse().unwrap())
+        })
+    };
 
     // Build environment filter for file (more verbose)
-    // SAFETY: These are compile-time constant strings that always parse successfully
-    #[allow(clippy::unwrap_used)]
-    let file_filter = EnvFilter::new(level_to_directive(config.file_level))
-        .add_directive("youtun4=trace".parse().unwrap())
-        .add_directive("youtun4_core=trace".parse().unwrap());
+    let file_filter = crate_filter(config.file_level, Level::TRACE);
+
+    // Both filters can be swapped at runtime by `set_level`
+    let (console_filter, console_handle) = reload::Layer::new(console_filter);
+    let (file_filter, file_handle) = reload::Layer::new(file_filter);
 
     // Configure span events
     let span_events = if config.log_span_events {
@@ -241,11 +274,20 @@ pub fn init(config: &LoggingConfig) -> Result<LoggingGuard, LoggingError> {
         .with_filter(file_filter);
 
     // Initialize the subscriber
+    // Both layers sit directly on the registry so their reload handles share a type
     tracing_subscriber::registry()
-        .with(console_layer)
-        .with(file_layer)
+        .with(console_layer.and_then(file_layer))
         .init();
 
+    // Ignore a second init attempt; the subscriber above would have panicked
+    let _ = ACTIVE.set(ActiveLogging {
+        log_directory: config.log_directory.clone(),
+        log_file_prefix: config.log_file_prefix.clone(),
+        file_level: config.file_level,
+        console_filter: console_handle,
+        file_filter: file_handle,
+    });
+
     Ok(LoggingGuard {
         _file_guard: file_guard,
     })
@@ -281,6 +323,168 @@ pub fn current_log_path(config: &LoggingConfig) -> PathBuf {
     config.log_directory.join(&config.log_file_prefix)
 }
 
+/// Get the directory logs are being written to.
+///
+/// Falls back to [`default_log_directory`] before logging is initialized.
+#[must_use]
+pub fn log_directory() -> PathBuf {
+    ACTIVE
+        .get()
+        .map_or_else(default_log_directory, |active| active.log_directory.clone())
+}
+
+/// Change the log level of our crates at runtime, for console and file output.
+///
+/// Dependencies keep their startup levels.
+///
+/// # Errors
+///
+/// Returns an error if logging is not initialized or the filters cannot be
+/// replaced.
+pub fn set_level(level: Level) -> Result<(), LoggingError> {
+    let active = ACTIVE.get().ok_or(LoggingError::NotInitialized)?;
+    active
+        .console_filter
+        .reload(crate_filter(Level::WARN, level))
+        .and_then(|()| {
+            active
+                .file_filter
+                .reload(crate_filter(active.file_level, level))
+        })
+        .map_err(|e| LoggingError::ReloadFailed(e.to_string()))
+}
+
+/// Read the most recent entries from the log files, oldest first.
+///
+/// Only entries at `min_level` or more severe are returned if given.
+///
+/// # Errors
+///
+/// Returns an error if the log directory cannot be read.
+pub fn recent_entries(lines: usize, min_level: Option<Level>) -> std::io::Result<Vec<LogEntry>> {
+    let (directory, prefix) = ACTIVE.get().map_or_else(
+        || (default_log_directory(), "youtun4".to_string()),
+        |active| (active.log_directory.clone(), active.log_file_prefix.clone()),
+    );
+    read_recent_entries(&directory, &prefix, lines, min_level)
+}
+
+/// Read the most recent entries from the log files in `directory`, oldest first.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read.
+pub fn read_recent_entries(
+    directory: &Path,
+    prefix: &str,
+    lines: usize,
+    min_level: Option<Level>,
+) -> std::io::Result<Vec<LogEntry>> {
+    if !directory.exists() || lines == 0 {
+        return Ok(Vec::new());
+    }
+
+    // Rotated file names end with their date, so they sort chronologically
+    let mut files: Vec<PathBuf> = std::fs::read_dir(directory)?
+        .filter_map(std::result::Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(prefix))
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect();
+    files.sort();
+
+    let mut entries = Vec::new();
+    'files: for file in files.iter().rev() {
+        // Skip files we can't read (e.g. removed by rotation meanwhile)
+        let Ok(content) = std::fs::read_to_string(file) else {
+            continue;
+        };
+        for line in content.lines().rev() {
+            let Some(entry) = LogEntry::parse(line) else {
+                continue;
+            };
+            if min_level.is_some_and(|min| !entry.is_at_least(min)) {
+                continue;
+            }
+            entries.push(entry);
+            if entries.len() == lines {
+                break 'files;
+            }
+        }
+    }
+
+    entries.reverse();
+    Ok(entries)
+}
+
+/// A log entry read back from a log file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LogEntry {
+    /// Timestamp as written by the logger.
+    pub timestamp: String,
+    /// Level name (e.g. "INFO").
+    pub level: String,
+    /// Module that emitted the entry.
+    pub target: String,
+    /// Log message, followed by any structured fields.
+    pub message: String,
+}
+
+impl LogEntry {
+    /// Parse a JSON log line, returning `None` for anything else.
+    #[must_use]
+    pub fn parse(line: &str) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(line).ok()?;
+        let text = |key: &str| {
+            value
+                .get(key)
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        let mut message = String::new();
+        if let Some(fields) = value.get("fields").and_then(serde_json::Value::as_object) {
+            if let Some(msg) = fields.get("message").and_then(serde_json::Value::as_str) {
+                message.push_str(msg);
+            }
+            for (key, field) in fields.iter().filter(|(key, _)| *key != "message") {
+                let field = field
+                    .as_str()
+                    .map_or_else(|| field.to_string(), ToString::to_string);
+                if !message.is_empty() {
+                    message.push(' ');
+                }
+                message.push_str(&format!("{key}={field}"));
+            }
+        }
+
+        Some(Self {
+            timestamp: text("timestamp"),
+            level: value.get("level")?.as_str()?.to_string(),
+            target: text("target"),
+            message,
+        })
+    }
+
+    /// Whether this entry is at least as severe as `level`.
+    #[must_use]
+    pub fn is_at_least(&self, level: Level) -> bool {
+        // More severe levels compare as smaller in `tracing`
+        self.level.parse::<Level>().is_ok_and(|own| own <= level)
+    }
+}
+
+/// Filter that logs dependencies at `base` and our crates at `crates`.
+fn crate_filter(base: Level, crates: Level) -> EnvFilter {
+    let crates = level_to_directive(crates);
+    // SAFETY: Directives built from level names always parse successfully
+    #[allow(clippy::unwrap_used)]
+    EnvFilter::new(level_to_directive(base))
+        .add_directive(format!("youtun4={crates}").parse().unwrap())
+        .add_directive(format!("youtun4_core={crates}").parse().unwrap())
+}
+
 /// Convert a tracing Level to a filter directive string.
 const fn level_to_directive(level: Level) -> &'static str {
     match level {
@@ -303,9 +507,16 @@ pub enum LoggingError {
         /// The reason for the failure.
         reason: String,
     },
+    /// Logging has not been initialized yet.
+    #[error("Logging is not initialized")]
+    NotInitialized,
+    /// Failed to swap in a new log filter.
+    #[error("Failed to change log level: {0}")]
+    ReloadFailed(String),
 }
 
 #[cfg(test)]
+#[allow(clippy::expect_used)]
 mod tests {
     use super::*;
 
@@ -349,6 +560,14 @@ mod tests {
         assert_eq!(config.rotation, LogRotation::Hourly);
     }
 
+    #[test]
+    fn test_level_override() {
+        assert!(LoggingConfig::production().level_override.is_none());
+
+        let config = LoggingConfig::production().with_level_override(Level::TRACE);
+        assert_eq!(config.level_override, Some(Level::TRACE));
+    }
+
     #[test]
     fn test_log_rotation_conversion() {
         assert!(matches!(
@@ -369,6 +588,58 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_parse_log_entry() {
+        let line = r#"{"timestamp":"2026-01-15T10:00:00.000000Z","level":"WARN","fields":{"message":"Device removed","mount_point":"/media/player"},"target":"youtun4::commands"}"#;
+        let entry = LogEntry::parse(line).expect("Should parse");
+        assert_eq!(entry.level, "WARN");
+        assert_eq!(entry.target, "youtun4::commands");
+        assert_eq!(entry.message, "Device removed mount_point=/media/player");
+        assert!(entry.is_at_least(Level::INFO));
+        assert!(entry.is_at_least(Level::WARN));
+        assert!(!entry.is_at_least(Level::ERROR));
+
+        assert!(LogEntry::parse("not json").is_none());
+        assert!(LogEntry::parse(r#"{"fields":{}}"#).is_none());
+    }
+
+    #[test]
+    fn test_read_recent_entries() {
+        let dir = tempfile::TempDir::new().expect("Should create temp dir");
+        let line = |level: &str, message: &str| {
+            format!(
+                r#"{{"timestamp":"t","level":"{level}","fields":{{"message":"{message}"}},"target":"youtun4"}}"#
+            )
+        };
+        std::fs::write(
+            dir.path().join("youtun4.2026-01-14"),
+            [line("INFO", "a"), line("ERROR", "b")].join("\n"),
+        )
+        .expect("Should write log");
+        std::fs::write(
+            dir.path().join("youtun4.2026-01-15"),
+            [line("DEBUG", "c"), "garbage".to_string(), line("WARN", "d")].join("\n"),
+        )
+        .expect("Should write log");
+        std::fs::write(dir.path().join("other.log"), line("ERROR", "x")).expect("Should write");
+
+        let messages = |entries: Vec<LogEntry>| -> Vec<String> {
+            entries.into_iter().map(|e| e.message).collect()
+        };
+        let read = |lines, level| {
+            read_recent_entries(dir.path(), "youtun4", lines, level).expect("Should read")
+        };
+
+        assert_eq!(messages(read(3, None)), ["b", "c", "d"]);
+        assert_eq!(messages(read(100, Some(Level::WARN))), ["b", "d"]);
+        assert!(read(0, None).is_empty());
+        assert!(
+            read_recent_entries(&dir.path().join("missing"), "youtun4", 10, None)
+                .expect("Should read")
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_default_log_directory() {
         let dir = default_log_directory();
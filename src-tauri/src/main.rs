@@ -10,16 +10,35 @@
 #![allow(clippy::too_many_lines)]
 
 mod commands;
+pub mod diagnostics;
+pub mod headless;
 pub mod logging;
 pub mod runtime;
 
 use commands::AppState;
 use tracing::{error, info};
+use youtun4_core::ConfigOverrides;
 
 fn main() {
+    // Startup overrides: CLI flags take precedence over YOUTUN4_* env vars
+    let overrides = match ConfigOverrides::from_args(std::env::args().skip(1)) {
+        Ok(cli) => ConfigOverrides::from_env().merge(cli),
+        Err(e) => {
+            eprintln!("FATAL: Invalid command-line arguments: {e}");
+            std::process::exit(2);
+        }
+    };
+
     // Initialize structured logging with automatic configuration
     // (development in debug builds, production in release builds)
-    let _logging_guard = match logging::init_auto() {
+    let mut logging_config = logging::LoggingConfig::auto();
+    if let Some(level) = overrides.log_level.as_deref() {
+        match level.parse::<tracing::Level>() {
+            Ok(level) => logging_config = logging_config.with_level_override(level),
+            Err(_) => eprintln!("Ignoring invalid log level override: {level}"),
+        }
+    }
+    let _logging_guard = match logging::init(&logging_config) {
         Ok(guard) => guard,
         Err(e) => {
             eprintln!("FATAL: Failed to initialize logging system: {e}");
@@ -27,14 +46,42 @@ fn main() {
         }
     };
 
+    // Persist a crash report for panics, so the next launch can show it
+    youtun4_core::CrashReporter::open_default().install_panic_hook();
+
+    // Headless mode: run a single operation without the GUI and exit
+    if let Some(command) =
+        headless::HeadlessCommand::from_args(&std::env::args().collect::<Vec<_>>())
+    {
+        let command = match command {
+            Ok(command) => command,
+            Err(e) => {
+                eprintln!("{e}\n\n{}", headless::USAGE);
+                std::process::exit(2);
+            }
+        };
+        let code = match youtun4_core::config::ConfigManager::with_overrides(overrides)
+            .and_then(|config_manager| headless::run(&command, &config_manager))
+        {
+            Ok(true) => 0,
+            Ok(false) => 1,
+            Err(e) => {
+                error!("Headless command failed: {e}");
+                eprintln!("Error: {e}");
+                1
+            }
+        };
+        std::process::exit(code);
+    }
+
     info!("Starting Youtun4 application");
     info!(
-        log_directory = %logging::default_log_directory().display(),
+        log_directory = %logging::log_directory().display(),
         "Logging initialized"
     );
 
     // Create app state (loads config automatically)
-    let app_state = match AppState::new() {
+    let app_state = match AppState::new(overrides) {
         Ok(state) => state,
         Err(e) => {
             error!("Failed to create application state: {e}");
@@ -43,14 +90,44 @@ fn main() {
     };
 
     tauri::Builder::default()
+        // Must be registered first: a second launch (e.g. from a deep link)
+        // hands its arguments to the running instance instead
+        .plugin(tauri_plugin_single_instance::init(|app, _argv, _cwd| {
+            commands::show_main_window(app);
+        }))
+        .plugin(tauri_plugin_deep_link::init())
+        .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(tauri_plugin_opener::init())
         .manage(app_state)
+        .setup(|app| {
+            commands::install_notification_listeners(app.handle());
+            commands::install_tray(app.handle())?;
+            commands::install_deep_link_handler(app.handle());
+            commands::schedule_update_check(app.handle());
+            commands::submit_pending_crash_report(app.handle());
+            commands::start_power_monitor(app.handle());
+            commands::start_refresh_scheduler(app.handle());
+            commands::start_saved_queue(app.handle());
+            Ok(())
+        })
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                commands::handle_close_requested(window, api);
+            }
+        })
         .invoke_handler(tauri::generate_handler![
             // Device API commands
             commands::list_devices,
             commands::get_device_info,
+            commands::open_device_folder,
             commands::check_device_available,
             commands::verify_device_space,
             commands::check_sync_capacity,
+            commands::analyze_device_usage,
+            commands::get_device_profile,
+            commands::set_device_profile,
             commands::start_device_watcher,
             commands::stop_device_watcher,
             commands::is_device_watcher_running,
@@ -70,15 +147,36 @@ fn main() {
             commands::list_playlists,
             commands::create_playlist,
             commands::delete_playlist,
+            commands::delete_playlists,
+            commands::tag_playlists,
             commands::sync_playlist,
             commands::get_playlist_tracks,
             commands::get_playlist_tracks_fast,
+            commands::list_tracks_paged,
+            commands::search_library,
+            commands::delete_tracks,
+            commands::trash_playlist,
+            commands::trash_tracks,
+            commands::restore_from_trash,
+            commands::move_tracks,
+            commands::tag_tracks,
+            commands::get_playlist_overrides,
+            commands::set_playlist_overrides,
+            commands::get_playlist_download_profile,
+            commands::set_playlist_download_profile,
+            commands::delete_playlist_download_profile,
+            commands::set_playlist_view,
             commands::get_playlist_details,
             commands::validate_playlist_folder,
             commands::get_playlist_statistics,
             commands::repair_playlist_folder,
             commands::import_playlist_folder,
             commands::rename_playlist,
+            commands::open_playlist_folder,
+            commands::reveal_in_file_manager,
+            commands::export_playlist_m3u,
+            commands::get_download_archive,
+            commands::reset_download_archive,
             commands::playlist_exists,
             commands::ensure_playlist_structure,
             // Playlist metadata management commands
@@ -87,6 +185,7 @@ fn main() {
             commands::refresh_playlist_stats,
             // MP3 metadata commands
             commands::extract_track_metadata,
+            commands::fetch_track_lyrics,
             // File transfer commands
             commands::sync_playlist_with_progress,
             commands::get_default_transfer_options,
@@ -106,16 +205,28 @@ fn main() {
             commands::get_default_verification_options,
             commands::get_strict_verification_options,
             commands::get_quick_verification_options,
+            // Maintenance commands
+            commands::start_maintenance,
             // Task management commands
             commands::get_task_status,
             commands::get_running_tasks,
             commands::cancel_task,
+            commands::get_interrupted_tasks,
+            commands::resume_interrupted_task,
+            commands::dismiss_interrupted_task,
             // Configuration commands
             commands::get_config,
             commands::update_config,
+            commands::set_ui_state,
             commands::get_storage_directory,
             commands::set_storage_directory,
             commands::get_default_storage_directory,
+            commands::list_config_profiles,
+            commands::save_config_profile,
+            commands::delete_config_profile,
+            commands::switch_profile,
+            commands::export_config,
+            commands::import_config,
             // Sync API commands
             commands::start_sync,
             commands::cancel_sync,
@@ -123,6 +234,7 @@ fn main() {
             commands::list_active_syncs,
             // Sync Orchestrator commands
             commands::start_orchestrated_sync,
+            commands::preview_sync,
             commands::sync_playlists_to_device,
             commands::get_default_sync_options,
             commands::get_fast_sync_options,
@@ -131,11 +243,26 @@ fn main() {
             commands::validate_youtube_playlist_url,
             commands::is_valid_youtube_playlist_url,
             commands::extract_youtube_playlist_id,
+            commands::parse_dropped_urls,
+            commands::read_clipboard_urls,
             // YouTube download commands
             commands::check_yt_dlp_available,
+            commands::preview_filename_template,
             commands::fetch_youtube_playlist_info,
+            commands::estimate_playlist_download,
+            commands::estimate_playlist_size,
+            commands::plan_playlist_download,
+            commands::check_playlist_health,
+            commands::search_youtube,
+            commands::preview_playlist_update,
             commands::download_youtube_playlist,
             commands::download_youtube_to_playlist,
+            commands::update_playlist_from_source,
+            commands::get_refresh_schedules,
+            commands::set_refresh_schedule,
+            commands::cancel_download_video,
+            commands::pause_download,
+            commands::resume_download,
             // Cache management commands
             commands::get_cache_stats,
             commands::get_cache_config,
@@ -143,18 +270,24 @@ fn main() {
             commands::cleanup_cache,
             commands::clear_cache,
             commands::cleanup_cache_temp,
+            commands::verify_cache,
             commands::get_default_cache_directory,
             commands::is_cache_enabled,
             commands::set_cache_enabled,
             commands::set_cache_max_size,
+            commands::warm_cache_for_playlist,
             // Download queue commands
             commands::queue_add_download,
             commands::queue_add_to_playlist,
+            commands::queue_add_video_to_playlist,
             commands::queue_add_batch,
             commands::queue_remove_item,
             commands::queue_cancel_item,
             commands::queue_set_priority,
+            commands::queue_set_split_by_chapters,
             commands::queue_move_to_front,
+            commands::queue_reorder_items,
+            commands::queue_move_item,
             commands::queue_retry_item,
             commands::queue_get_item,
             commands::queue_get_all_items,
@@ -169,10 +302,75 @@ fn main() {
             commands::queue_get_config,
             commands::queue_set_config,
             commands::queue_set_max_concurrent,
+            // Storage commands
+            commands::get_library_space,
+            commands::get_storage_overview,
+            commands::archive_playlist,
+            // Onboarding commands
+            commands::get_onboarding_state,
+            commands::complete_onboarding,
+            // Notification commands
+            commands::send_test_notification,
+            commands::send_test_webhook,
+            // Secrets commands
+            commands::set_secret,
+            commands::delete_secret,
+            commands::has_secret,
+            commands::get_secrets_backend,
+            // Tray and background operation commands
+            commands::quit_when_tasks_finish,
+            commands::is_in_background,
+            // Update commands
+            commands::check_for_updates,
+            commands::install_update,
+            // Log commands
+            commands::get_recent_logs,
+            commands::set_log_level,
+            commands::get_log_directory,
+            commands::open_log_directory,
+            commands::copy_logs_to_clipboard,
+            // Diagnostics commands
+            commands::export_diagnostics,
+            commands::get_performance_metrics,
+            commands::reset_performance_metrics,
+            // Crash report commands
+            commands::get_last_crash_report,
+            commands::dismiss_crash_report,
+            commands::report_frontend_crash,
+            // Shutdown commands
+            commands::cancel_all_tasks,
+            // Simple mode commands
+            commands::get_parental_status,
+            commands::set_parent_pin,
+            commands::clear_parent_pin,
+            commands::set_simple_mode,
+            // Power commands
+            commands::get_power_status,
+            // Localization commands
+            commands::get_translation_catalog,
+            // Track preview commands
+            commands::prepare_track_preview,
+            commands::prepare_playlist_cover,
+            commands::get_cached_thumbnail,
+            // History commands
+            commands::get_history,
+            commands::clear_history,
+            commands::get_history_stats,
+            commands::prune_history,
+            // Change log commands
+            commands::get_change_log,
+            // Backup commands
+            commands::backup_library,
+            commands::restore_library,
         ])
-        .run(tauri::generate_context!())
+        .build(tauri::generate_context!())
         .unwrap_or_else(|e| {
             error!("Tauri application error: {e}");
             std::process::exit(1);
+        })
+        .run(|app, event| {
+            if let tauri::RunEvent::ExitRequested { api, .. } = event {
+                commands::handle_exit_requested(app, &api);
+            }
         });
 }
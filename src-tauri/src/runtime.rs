@@ -2,11 +2,16 @@
 //!
 //! This module provides utilities for managing the Tokio async runtime,
 //! including task spawning, thread pool configuration, and task lifecycle management.
+//!
+//! Work that must not pile up (syncs, device cleanups, downloads) is admitted
+//! through [`AsyncRuntime::admit`], which enforces per-category concurrency
+//! limits and keeps two tasks from using the same device at once.
 
 use std::collections::HashMap;
 use std::future::Future;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
 use tokio::runtime::{Builder, Runtime};
 use tokio::sync::{RwLock, mpsc, oneshot};
@@ -49,6 +54,8 @@ pub enum TaskCategory {
     FileTransfer,
     /// Device monitoring operations.
     DeviceMonitor,
+    /// Deleting files from a device.
+    Cleanup,
     /// General background tasks.
     Background,
 }
@@ -59,6 +66,7 @@ impl std::fmt::Display for TaskCategory {
             Self::Download => write!(f, "download"),
             Self::FileTransfer => write!(f, "file_transfer"),
             Self::DeviceMonitor => write!(f, "device_monitor"),
+            Self::Cleanup => write!(f, "cleanup"),
             Self::Background => write!(f, "background"),
         }
     }
@@ -75,6 +83,199 @@ pub struct TaskInfo {
     pub description: Option<String>,
 }
 
+/// Something only one admitted task may use at a time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TaskResource {
+    /// A device, identified by its mount point.
+    Device(PathBuf),
+}
+
+impl TaskResource {
+    /// The device mounted at `mount_point`.
+    ///
+    /// The path is canonicalized when possible so different spellings of the
+    /// same mount point conflict.
+    #[must_use]
+    pub fn device(mount_point: &Path) -> Self {
+        Self::Device(
+            std::fs::canonicalize(mount_point).unwrap_or_else(|_| mount_point.to_path_buf()),
+        )
+    }
+}
+
+impl std::fmt::Display for TaskResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Device(mount_point) => write!(f, "device '{}'", mount_point.display()),
+        }
+    }
+}
+
+/// Why a task could not be admitted.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TaskBusy {
+    /// Too many tasks of the same category are running.
+    #[error("Only {limit} {category} task(s) can run at once (task {conflicting_task} is running)")]
+    LimitReached {
+        /// Category whose limit was reached.
+        category: TaskCategory,
+        /// The category's limit.
+        limit: usize,
+        /// The oldest running task of the category.
+        conflicting_task: TaskId,
+    },
+    /// Another task is using the same resource.
+    #[error("The {resource} is in use by task {conflicting_task}")]
+    ResourceInUse {
+        /// The contended resource.
+        resource: TaskResource,
+        /// The task using it.
+        conflicting_task: TaskId,
+    },
+}
+
+impl TaskBusy {
+    /// The running task that blocked admission.
+    #[must_use]
+    pub const fn conflicting_task(&self) -> TaskId {
+        match self {
+            Self::LimitReached {
+                conflicting_task, ..
+            }
+            | Self::ResourceInUse {
+                conflicting_task, ..
+            } => *conflicting_task,
+        }
+    }
+}
+
+/// How many admitted tasks of each category may run at once.
+///
+/// Categories without a limit are unlimited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConcurrencyPolicy {
+    limits: HashMap<TaskCategory, usize>,
+}
+
+impl Default for ConcurrencyPolicy {
+    fn default() -> Self {
+        Self::unlimited()
+            .with_limit(TaskCategory::Download, 3)
+            .with_limit(TaskCategory::FileTransfer, 2)
+            .with_limit(TaskCategory::Cleanup, 2)
+    }
+}
+
+impl ConcurrencyPolicy {
+    /// A policy without any limits.
+    #[must_use]
+    pub fn unlimited() -> Self {
+        Self {
+            limits: HashMap::new(),
+        }
+    }
+
+    /// Allow at most `limit` running tasks of `category`.
+    #[must_use]
+    pub fn with_limit(mut self, category: TaskCategory, limit: usize) -> Self {
+        self.limits.insert(category, limit);
+        self
+    }
+
+    /// The limit for `category`, if it has one.
+    #[must_use]
+    pub fn limit(&self, category: TaskCategory) -> Option<usize> {
+        self.limits.get(&category).copied()
+    }
+}
+
+/// An admitted task.
+#[derive(Debug)]
+struct AdmittedTask {
+    category: TaskCategory,
+    resource: Option<TaskResource>,
+}
+
+/// Admitted tasks and the policy they were admitted under.
+#[derive(Debug, Default)]
+struct Admissions {
+    policy: ConcurrencyPolicy,
+    admitted: HashMap<TaskId, AdmittedTask>,
+}
+
+impl Admissions {
+    fn check(
+        &self,
+        category: TaskCategory,
+        resource: Option<&TaskResource>,
+    ) -> Result<(), TaskBusy> {
+        if let Some(resource) = resource
+            && let Some((&task_id, _)) = self
+                .admitted
+                .iter()
+                .filter(|(_, task)| task.resource.as_ref() == Some(resource))
+                .min_by_key(|(task_id, _)| **task_id)
+        {
+            return Err(TaskBusy::ResourceInUse {
+                resource: resource.clone(),
+                conflicting_task: task_id,
+            });
+        }
+
+        if let Some(limit) = self.policy.limit(category) {
+            let mut running: Vec<TaskId> = self
+                .admitted
+                .iter()
+                .filter(|(_, task)| task.category == category)
+                .map(|(task_id, _)| *task_id)
+                .collect();
+            if running.len() >= limit {
+                running.sort_unstable();
+                return Err(TaskBusy::LimitReached {
+                    category,
+                    limit,
+                    conflicting_task: running.first().copied().unwrap_or_default(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Keeps a task admitted until dropped.
+///
+/// Hold it for as long as the task's work runs.
+#[derive(Debug)]
+#[must_use = "the task is released as soon as the slot is dropped"]
+pub struct TaskSlot {
+    task_id: TaskId,
+    admissions: Arc<Mutex<Admissions>>,
+}
+
+impl TaskSlot {
+    /// The admitted task.
+    #[must_use]
+    pub const fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+}
+
+impl Drop for TaskSlot {
+    fn drop(&mut self) {
+        lock_admissions(&self.admissions)
+            .admitted
+            .remove(&self.task_id);
+        debug!("Task {} released its slot", self.task_id);
+    }
+}
+
+/// Lock the admissions, recovering from a poisoned lock.
+fn lock_admissions(admissions: &Mutex<Admissions>) -> std::sync::MutexGuard<'_, Admissions> {
+    admissions
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
 /// Configuration for the async runtime.
 #[derive(Debug, Clone)]
 pub struct RuntimeConfig {
@@ -115,6 +316,8 @@ pub struct AsyncRuntime {
     progress_rx: Arc<RwLock<mpsc::UnboundedReceiver<ProgressUpdate>>>,
     /// Cancellation senders for tasks that support cancellation.
     cancel_senders: Arc<RwLock<HashMap<TaskId, oneshot::Sender<()>>>>,
+    /// Tasks admitted under the concurrency policy.
+    admissions: Arc<Mutex<Admissions>>,
 }
 
 impl AsyncRuntime {
@@ -161,6 +364,7 @@ impl AsyncRuntime {
             progress_tx,
             progress_rx: Arc::new(RwLock::new(progress_rx)),
             cancel_senders: Arc::new(RwLock::new(HashMap::new())),
+            admissions: Arc::new(Mutex::new(Admissions::default())),
         })
     }
 
@@ -239,6 +443,25 @@ impl AsyncRuntime {
         description: Option<String>,
         future_factory: impl FnOnce(oneshot::Receiver<()>) -> F + Send + 'static,
     ) -> TaskId
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.spawn_cancellable_with_id(category, description, move |_, cancel_rx| {
+            future_factory(cancel_rx)
+        })
+    }
+
+    /// Spawn a cancellable async task that is given its own task ID, e.g. to
+    /// include it in the events it emits.
+    ///
+    /// Returns a task ID that can be used to cancel the task via `cancel_task`.
+    pub fn spawn_cancellable_with_id<F, T>(
+        &self,
+        category: TaskCategory,
+        description: Option<String>,
+        future_factory: impl FnOnce(TaskId, oneshot::Receiver<()>) -> F + Send + 'static,
+    ) -> TaskId
     where
         F: Future<Output = T> + Send + 'static,
         T: Send + 'static,
@@ -276,7 +499,7 @@ impl AsyncRuntime {
 
         // Spawn the actual task
         let _handle: JoinHandle<()> = self.runtime.spawn(async move {
-            let future = future_factory(cancel_rx);
+            let future = future_factory(task_id, cancel_rx);
             let result = future.await;
             drop(result);
 
@@ -293,6 +516,50 @@ impl AsyncRuntime {
         task_id
     }
 
+    /// Admit `task_id` to run, or report which running task it conflicts with.
+    ///
+    /// A task is refused if another admitted task uses the same `resource`, or
+    /// if its category is at its concurrency limit. The task stays admitted
+    /// until the returned slot is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskBusy`] naming the conflicting task.
+    pub fn admit(
+        &self,
+        task_id: TaskId,
+        category: TaskCategory,
+        resource: Option<TaskResource>,
+    ) -> Result<TaskSlot, TaskBusy> {
+        let mut admissions = lock_admissions(&self.admissions);
+        if let Err(busy) = admissions.check(category, resource.as_ref()) {
+            info!("Task {} ({}) not admitted: {}", task_id, category, busy);
+            return Err(busy);
+        }
+        admissions
+            .admitted
+            .insert(task_id, AdmittedTask { category, resource });
+        debug!("Task {} ({}) admitted", task_id, category);
+        Ok(TaskSlot {
+            task_id,
+            admissions: Arc::clone(&self.admissions),
+        })
+    }
+
+    /// Replace the concurrency policy.
+    ///
+    /// Tasks already admitted keep running even if they exceed the new limits.
+    pub fn set_concurrency_policy(&self, policy: ConcurrencyPolicy) {
+        info!("Concurrency policy set: {:?}", policy);
+        lock_admissions(&self.admissions).policy = policy;
+    }
+
+    /// The current concurrency policy.
+    #[must_use]
+    pub fn concurrency_policy(&self) -> ConcurrencyPolicy {
+        lock_admissions(&self.admissions).policy.clone()
+    }
+
     /// Cancel a running task.
     ///
     /// Returns `true` if the cancellation signal was sent successfully.
@@ -316,6 +583,29 @@ impl AsyncRuntime {
         false
     }
 
+    /// Cancel every running cancellable task.
+    ///
+    /// Returns the number of tasks the cancellation signal was sent to.
+    pub async fn cancel_all(&self) -> usize {
+        let senders: Vec<(TaskId, oneshot::Sender<()>)> =
+            self.cancel_senders.write().await.drain().collect();
+
+        let mut cancelled = 0;
+        let mut tasks_guard = self.tasks.write().await;
+        for (task_id, sender) in senders {
+            if sender.send(()).is_ok() {
+                if let Some(info) = tasks_guard.get_mut(&task_id) {
+                    info.status = TaskStatus::Cancelled;
+                }
+                cancelled += 1;
+            }
+        }
+        if cancelled > 0 {
+            info!("Cancelled {} task(s)", cancelled);
+        }
+        cancelled
+    }
+
     /// Get the status of a task.
     pub async fn task_status(&self, task_id: TaskId) -> Option<TaskStatus> {
         let tasks_guard = self.tasks.read().await;
@@ -546,4 +836,115 @@ mod tests {
         let status = runtime.block_on(runtime.task_status(task_id));
         assert_eq!(status, Some(TaskStatus::Cancelled));
     }
+
+    #[test]
+    fn test_cancellable_task_knows_its_id() {
+        let runtime = AsyncRuntime::new().expect("Failed to create runtime");
+        let (id_tx, id_rx) = std::sync::mpsc::channel();
+        let task_id = runtime.spawn_cancellable_with_id(
+            TaskCategory::Background,
+            None,
+            move |task_id, _cancel_rx| async move {
+                id_tx.send(task_id).expect("send task ID");
+            },
+        );
+
+        let seen = id_rx
+            .recv_timeout(Duration::from_secs(1))
+            .expect("task ran");
+        assert_eq!(seen, task_id);
+    }
+
+    #[test]
+    fn test_cancel_all() {
+        let runtime = AsyncRuntime::new().expect("Failed to create runtime");
+        let task_ids: Vec<TaskId> = (0..3)
+            .map(|_| {
+                runtime.spawn_cancellable(
+                    TaskCategory::FileTransfer,
+                    None,
+                    |cancel_rx| async move {
+                        tokio::select! {
+                            () = tokio::time::sleep(Duration::from_secs(60)) => {}
+                            _ = cancel_rx => {}
+                        }
+                    },
+                )
+            })
+            .collect();
+
+        assert_eq!(runtime.block_on(runtime.cancel_all()), 3);
+        for task_id in task_ids {
+            let status = runtime.block_on(runtime.task_status(task_id));
+            assert_eq!(status, Some(TaskStatus::Cancelled));
+        }
+
+        // Nothing left to cancel
+        assert_eq!(runtime.block_on(runtime.cancel_all()), 0);
+    }
+
+    #[test]
+    fn test_admit_respects_category_limit() {
+        let runtime = AsyncRuntime::new().expect("Failed to create runtime");
+        runtime.set_concurrency_policy(
+            ConcurrencyPolicy::unlimited().with_limit(TaskCategory::Download, 2),
+        );
+
+        let first = runtime.admit(1, TaskCategory::Download, None).unwrap();
+        let _second = runtime.admit(2, TaskCategory::Download, None).unwrap();
+        let busy = runtime.admit(3, TaskCategory::Download, None).unwrap_err();
+        assert_eq!(
+            busy,
+            TaskBusy::LimitReached {
+                category: TaskCategory::Download,
+                limit: 2,
+                conflicting_task: 1,
+            }
+        );
+
+        // Other categories are not limited
+        let _background = runtime.admit(4, TaskCategory::Background, None).unwrap();
+
+        // Releasing a slot frees room for another task
+        drop(first);
+        let third = runtime.admit(3, TaskCategory::Download, None).unwrap();
+        assert_eq!(third.task_id(), 3);
+    }
+
+    #[test]
+    fn test_admit_excludes_same_device() {
+        let runtime = AsyncRuntime::new().expect("Failed to create runtime");
+        let device = TaskResource::Device(PathBuf::from("/media/player"));
+
+        let sync = runtime
+            .admit(1, TaskCategory::FileTransfer, Some(device.clone()))
+            .unwrap();
+        let busy = runtime
+            .admit(2, TaskCategory::Cleanup, Some(device.clone()))
+            .unwrap_err();
+        assert_eq!(busy.conflicting_task(), 1);
+        assert!(matches!(busy, TaskBusy::ResourceInUse { .. }));
+
+        // A different device is fine
+        let _other = runtime
+            .admit(
+                3,
+                TaskCategory::FileTransfer,
+                Some(TaskResource::Device(PathBuf::from("/media/other"))),
+            )
+            .unwrap();
+
+        drop(sync);
+        let _cleanup = runtime
+            .admit(2, TaskCategory::Cleanup, Some(device))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let policy = ConcurrencyPolicy::default();
+        assert_eq!(policy.limit(TaskCategory::Download), Some(3));
+        assert_eq!(policy.limit(TaskCategory::FileTransfer), Some(2));
+        assert_eq!(policy.limit(TaskCategory::Background), None);
+    }
 }